@@ -0,0 +1,41 @@
+//! Writes the API snapshots the compat adapters are generated against, see [opencv_binding_generator::compat]
+//!
+//! Usage: `compat-api-snapshot <hub dir> <snapshot dir>`, e.g. `compat-api-snapshot src/opencv/hub src/opencv/compat_api`
+
+use std::ffi::OsStr;
+use std::path::PathBuf;
+use std::{env, fs};
+
+use opencv_binding_generator::compat::ModuleApi;
+
+fn main() {
+	let mut args = env::args_os().skip(1);
+	let hub_dir = PathBuf::from(args.next().expect("1st argument must be the dir with the generated modules"));
+	let snapshot_dir = PathBuf::from(args.next().expect("2nd argument must be the dir for the API snapshots"));
+	fs::create_dir_all(&snapshot_dir).expect("Can't create snapshot dir");
+	for entry in snapshot_dir.read_dir().expect("Can't read snapshot dir") {
+		let path = entry.expect("Bad path").path();
+		if path.extension() == Some(OsStr::new("api")) {
+			fs::remove_file(path).expect("Can't remove stale snapshot");
+		}
+	}
+	let mut modules = hub_dir
+		.read_dir()
+		.expect("Can't read hub dir")
+		.map(|e| e.expect("Bad path").path())
+		.filter(|p| p.is_file() && p.extension() == Some(OsStr::new("rs")))
+		.collect::<Vec<_>>();
+	modules.sort_unstable();
+	for module_file in modules {
+		let module = module_file.file_stem().and_then(OsStr::to_str).expect("Bad module filename");
+		if module == "sys" || module == "types" {
+			continue;
+		}
+		let api = ModuleApi::parse(&fs::read_to_string(&module_file).expect("Can't read module file"));
+		if api.is_empty() {
+			continue;
+		}
+		fs::write(snapshot_dir.join(format!("{}.api", module)), api.to_snapshot()).expect("Can't write snapshot");
+		println!("{}", module);
+	}
+}
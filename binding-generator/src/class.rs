@@ -210,7 +210,18 @@ impl<'tu, 'ge> Class<'tu, 'ge> {
 						return true;
 					}
 				}
+				let ref_func = if func.is_return_referenceable() {
+					Some(Func::new_ext(
+						func.entity(),
+						FunctionTypeHint::ReturnReference,
+						None,
+						self.gen_env,
+					))
+				} else {
+					None
+				};
 				out.push(func);
+				out.extend(ref_func);
 			}
 			true
 		});
@@ -263,6 +274,16 @@ impl<'tu, 'ge> Class<'tu, 'ge> {
 				} else {
 					None
 				};
+				let mut ref_yield = if constness_filter != Some(Constness::Mut) && fld_type_ref.is_field_referenceable() {
+					Some(Func::new_ext(
+						fld.entity(),
+						FunctionTypeHint::FieldReference,
+						None,
+						self.gen_env,
+					))
+				} else {
+					None
+				};
 				let mut write_yield = if constness_filter.map_or(true, |c| c.is_mut())
 					&& !fld_type_ref.constness().is_const()
 					&& !fld_type_ref.as_fixed_array().is_some()
@@ -271,7 +292,7 @@ impl<'tu, 'ge> Class<'tu, 'ge> {
 				} else {
 					None
 				};
-				move || read_yield.take().or_else(|| write_yield.take())
+				move || read_yield.take().or_else(|| ref_yield.take()).or_else(|| write_yield.take())
 			})
 		}));
 		out
@@ -0,0 +1,320 @@
+//! Deprecation shims for the free functions whose signatures changed since the pinned API snapshot
+//!
+//! The snapshot of the function signatures of every module is committed to the main crate (`src/opencv/compat_api`), it's
+//! created from the checked-in hub with the `compat-api-snapshot` binary and is only updated on purpose, e.g. when
+//! releasing a version that drops the deprecated adapters. After the regeneration the new signatures are compared with the
+//! snapshot. When a function got additional trailing arguments that have C++ default values, an adapter with the snapshot
+//! signature is generated in the `compat` submodule of the module. `compat` re-exports everything else from its parent
+//! module so that the downstream code can switch to it with a single import change, e.g.
+//! `use opencv::imgproc::compat as imgproc;`, and then migrate the deprecated calls one by one.
+
+use std::fmt::Write;
+
+const DEFAULTS_HEADER: &str = "## C++ default parameters";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Param {
+	pub name: String,
+	pub typ: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct FnSignature {
+	/// Full signature line without the trailing `{`, e.g. `pub fn canny(...) -> Result<()>`
+	pub line: String,
+	pub prefix: String,
+	pub name: String,
+	pub params: Vec<Param>,
+	pub ret: String,
+	/// C++ default values of the arguments as they're listed in the doc comment, in the order of the listing
+	pub defaults: Vec<(String, String)>,
+}
+
+impl FnSignature {
+	pub fn parse(line: &str, defaults: Vec<(String, String)>) -> Option<Self> {
+		let line = line.trim_end().trim_end_matches('{').trim_end();
+		let fn_pos = line.find("fn ")?;
+		let prefix = line[..fn_pos].trim_end().to_string();
+		let rest = &line[fn_pos + 3..];
+		let paren_pos = rest.find('(')?;
+		let name = rest[..paren_pos].trim();
+		// generic functions can't be adapted reliably
+		if name.contains('<') {
+			return None;
+		}
+		let args_end = matching_paren(rest, paren_pos)?;
+		let params = split_top_level(&rest[paren_pos + 1..args_end])
+			.into_iter()
+			.map(|param| {
+				let colon = param.find(':')?;
+				Some(Param {
+					name: param[..colon].trim().to_string(),
+					typ: param[colon + 1..].trim().to_string(),
+				})
+			})
+			.collect::<Option<Vec<_>>>()?;
+		let ret = rest[args_end + 1..].trim().trim_start_matches("->").trim().to_string();
+		Some(Self {
+			line: line.to_string(),
+			prefix,
+			name: name.to_string(),
+			params,
+			ret,
+			defaults,
+		})
+	}
+
+	fn default_value(&self, param_name: &str) -> Option<&str> {
+		self
+			.defaults
+			.iter()
+			.find(|(name, _)| name == param_name)
+			.map(|(_, val)| val.as_str())
+	}
+
+	/// Whether the calls with the `other` signature compile against `self` without changes
+	fn is_call_compatible(&self, other: &FnSignature) -> bool {
+		self.ret == other.ret
+			&& self.params.len() == other.params.len()
+			&& self
+				.params
+				.iter()
+				.zip(&other.params)
+				.all(|(a, b)| normalize_type(&a.typ) == normalize_type(&b.typ))
+	}
+}
+
+/// Signatures of the top-level functions of the module
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ModuleApi {
+	pub(crate) functions: Vec<FnSignature>,
+}
+
+impl ModuleApi {
+	/// Collects the function signatures from the source of the generated module file or from its snapshot
+	pub fn parse(src: &str) -> Self {
+		let mut out = Self::default();
+		let mut defaults = vec![];
+		let mut in_defaults = false;
+		for line in src.lines() {
+			if let Some(doc) = line.strip_prefix("///") {
+				let doc = doc.trim();
+				if doc == DEFAULTS_HEADER {
+					in_defaults = true;
+				} else if in_defaults {
+					match doc
+						.strip_prefix("* ")
+						.and_then(|d| d.find(": ").map(|pos| (&d[..pos], &d[pos + 2..])))
+					{
+						Some((name, val)) => defaults.push((name.to_string(), val.to_string())),
+						None => in_defaults = false,
+					}
+				}
+			} else if line.starts_with("pub fn ") || line.starts_with("pub unsafe fn ") {
+				if let Some(sig) = FnSignature::parse(line, std::mem::take(&mut defaults)) {
+					out.functions.push(sig);
+				}
+				in_defaults = false;
+			} else if !line.starts_with("#[") {
+				defaults.clear();
+				in_defaults = false;
+			}
+		}
+		out
+	}
+
+	/// Renders the snapshot of the API that can be read back with [ModuleApi::parse]
+	pub fn to_snapshot(&self) -> String {
+		let mut out = String::new();
+		for f in &self.functions {
+			if !f.defaults.is_empty() {
+				writeln!(out, "/// {}", DEFAULTS_HEADER).unwrap();
+				for (name, val) in &f.defaults {
+					writeln!(out, "/// * {}: {}", name, val).unwrap();
+				}
+			}
+			writeln!(out, "{}", f.line).unwrap();
+		}
+		out
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.functions.is_empty()
+	}
+
+	fn function(&self, name: &str) -> Option<&FnSignature> {
+		self.functions.iter().find(|f| f.name == name)
+	}
+}
+
+/// Generates the `compat` submodule for the regenerated module, returns `None` if there are no adapters to write
+///
+/// `pinned` is the committed API snapshot of the module, `since` is the crate version that goes into the deprecation
+/// attribute, `module` is used for the diagnostics only.
+pub fn gen_compat_module(module: &str, pinned: &ModuleApi, new: &ModuleApi, since: &str) -> Option<String> {
+	let mut adapters = Vec::<(&str, String)>::new();
+	for old_fn in &pinned.functions {
+		let new_fn = match new.function(&old_fn.name) {
+			Some(new_fn) => new_fn,
+			None => continue,
+		};
+		if old_fn.is_call_compatible(new_fn) {
+			continue;
+		}
+		match gen_adapter(old_fn, new_fn, since) {
+			Some(code) => adapters.push((&old_fn.name, code)),
+			None => eprintln!(
+				"=== No compat adapter can be generated for the changed signature of: {}::{}",
+				module, old_fn.name
+			),
+		}
+	}
+	if adapters.is_empty() {
+		return None;
+	}
+	adapters.sort_unstable_by(|a, b| a.0.cmp(b.0));
+	let mut out = String::new();
+	writeln!(out).unwrap();
+	writeln!(
+		out,
+		"/// Previous signatures of the functions of this module that changed since the pinned API snapshot"
+	)
+	.unwrap();
+	writeln!(out, "///").unwrap();
+	writeln!(
+		out,
+		"/// Re-exports the rest of the module, so it can be used as a drop-in replacement for the module import."
+	)
+	.unwrap();
+	writeln!(out, "pub mod compat {{").unwrap();
+	writeln!(out, "\tpub use super::*;").unwrap();
+	for (_, code) in adapters {
+		writeln!(out).unwrap();
+		out.push_str(&code);
+	}
+	writeln!(out, "}}").unwrap();
+	Some(out)
+}
+
+pub(crate) fn gen_adapter(old_fn: &FnSignature, new_fn: &FnSignature, since: &str) -> Option<String> {
+	if old_fn.ret != new_fn.ret || old_fn.params.len() >= new_fn.params.len() || old_fn.prefix != new_fn.prefix {
+		return None;
+	}
+	let mut args = Vec::with_capacity(new_fn.params.len());
+	for (i, new_param) in new_fn.params.iter().enumerate() {
+		match old_fn.params.get(i) {
+			Some(old_param) => {
+				if normalize_type(&old_param.typ) != normalize_type(&new_param.typ) {
+					return None;
+				}
+				args.push(old_param.name.clone());
+			}
+			None => {
+				let default = new_fn.default_value(&new_param.name)?;
+				args.push(render_default(&new_param.typ, default)?);
+			}
+		}
+	}
+	let added = new_fn.params[old_fn.params.len()..]
+		.iter()
+		.map(|p| format!("`{}`", p.name))
+		.collect::<Vec<_>>()
+		.join(", ");
+	let mut out = String::new();
+	writeln!(
+		out,
+		"\t#[deprecated(since = \"{}\", note = \"added the arguments: {}, use `super::{}` instead\")]",
+		since, added, new_fn.name
+	)
+	.unwrap();
+	writeln!(out, "\t#[inline]").unwrap();
+	writeln!(out, "\t{} {{", old_fn.line).unwrap();
+	let call = format!("super::{}({})", new_fn.name, args.join(", "));
+	if new_fn.prefix.contains("unsafe") {
+		writeln!(out, "\t\tunsafe {{ {} }}", call).unwrap();
+	} else {
+		writeln!(out, "\t\t{}", call).unwrap();
+	}
+	writeln!(out, "\t}}").unwrap();
+	Some(out)
+}
+
+/// Renders the C++ default value as a Rust expression for the argument of type `typ`
+pub(crate) fn render_default(typ: &str, default: &str) -> Option<String> {
+	let typ = normalize_type(typ);
+	let default = default.trim();
+	// the arguments defaulting to `noArray()` or to the null pointer are generated as `Option`
+	if typ.starts_with("Option<") {
+		return match default {
+			"noArray()" | "0" | "NULL" | "nullptr" => Some("None".to_string()),
+			_ => None,
+		};
+	}
+	match typ.as_str() {
+		"bool" => match default {
+			"true" | "false" => Some(default.to_string()),
+			_ => None,
+		},
+		"i8" | "u8" | "i16" | "u16" | "i32" | "u32" | "i64" | "u64" | "isize" | "usize" => {
+			default.parse::<i64>().ok().map(|val| val.to_string())
+		}
+		"f32" | "f64" => default
+			.trim_end_matches(&['f', 'F'][..])
+			.parse::<f64>()
+			.ok()
+			.map(|val| format!("{:?}", val)),
+		_ => None,
+	}
+}
+
+/// Makes the `&dyn core::ToInputArray` and `&(impl core::ToInputArray + ?Sized)` forms of the same type comparable
+pub(crate) fn normalize_type(typ: &str) -> String {
+	typ.replace("(impl ", "")
+		.replace(" + ?Sized)", "")
+		.replace("dyn ", "")
+		.replace("core::", "")
+		.replace(' ', "")
+}
+
+fn matching_paren(s: &str, open_pos: usize) -> Option<usize> {
+	let mut depth = 0;
+	for (i, c) in s.char_indices().skip_while(|(i, _)| *i < open_pos) {
+		match c {
+			'(' => depth += 1,
+			')' => {
+				depth -= 1;
+				if depth == 0 {
+					return Some(i);
+				}
+			}
+			_ => {}
+		}
+	}
+	None
+}
+
+pub(crate) fn split_top_level(s: &str) -> Vec<&str> {
+	let mut out = vec![];
+	let mut depth = 0;
+	let mut start = 0;
+	let mut prev = ' ';
+	for (i, c) in s.char_indices() {
+		match c {
+			'(' | '<' | '[' => depth += 1,
+			// `->` of the callback types
+			'>' if prev == '-' => {}
+			')' | '>' | ']' => depth -= 1,
+			',' if depth == 0 => {
+				out.push(s[start..i].trim());
+				start = i + 1;
+			}
+			_ => {}
+		}
+		prev = c;
+	}
+	let last = s[start..].trim();
+	if !last.is_empty() {
+		out.push(last);
+	}
+	out
+}
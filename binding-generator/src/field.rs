@@ -109,6 +109,35 @@ impl<'tu, 'ge> Field<'tu, 'ge> {
 		None
 	}
 
+	/// Whether the argument defaults to an empty value that can be passed as `None` from Rust
+	///
+	/// That's `noArray()` or a default-constructed `Mat`/`UMat` for the array arguments and a default-constructed
+	/// instance of the class for the const class references, e.g. `const Mat& mask = Mat()`.
+	pub fn is_default_empty(&self) -> bool {
+		let default_value = if let Some(default_value) = self.default_value() {
+			default_value
+		} else {
+			return false;
+		};
+		let type_ref = self.type_ref();
+		if type_ref.is_input_array() || type_ref.is_output_array() || type_ref.is_input_output_array() {
+			return match default_value.as_str() {
+				"noArray()" | "cv::noArray()" => true,
+				// only the input arrays, output arrays can be written to even when they default to a temporary
+				"Mat()" | "cv::Mat()" | "UMat()" | "cv::UMat()" => type_ref.is_input_array(),
+				_ => false,
+			};
+		}
+		if let Some(inner) = type_ref.as_reference() {
+			if inner.constness().is_const() && inner.is_extern_by_ptr() {
+				if let (Some(cls), Some(cls_name)) = (inner.as_class(), default_value.strip_suffix("()")) {
+					return cls_name == cls.cpp_name(CppNameStyle::Declaration) || cls_name == cls.cpp_name(CppNameStyle::Reference);
+				}
+			}
+		}
+		false
+	}
+
 	pub fn parent(&self) -> Class<'tu, 'ge> {
 		let parent_entity = self.entity.get_semantic_parent().expect("Can't get parent of field");
 		match parent_entity.get_kind() {
@@ -7,6 +7,7 @@ use clang::{Availability, Entity, EntityKind, ExceptionSpecification};
 use once_cell::sync::Lazy;
 use regex::Regex;
 
+use crate::settings::ArgOverride;
 use crate::type_ref::{Constness, CppNameStyle, FishStyle, NameStyle, TypeRefTypeHint};
 use crate::{
 	comment, reserved_rename, settings, Class, DefaultElement, Element, EntityElement, EntityExt, Field, FieldTypeHint,
@@ -153,6 +154,10 @@ impl<'tu, 'ge> Kind<'tu, 'ge> {
 pub enum FunctionTypeHint {
 	None,
 	FieldSetter,
+	/// Getter returning the reference to the field instead of its copy
+	FieldReference,
+	/// Variant of the method returning the const reference that returns that reference instead of its copy
+	ReturnReference,
 	Specialized(&'static HashMap<&'static str, &'static str>),
 }
 
@@ -329,11 +334,41 @@ impl<'tu, 'ge> Func<'tu, 'ge> {
 		}
 	}
 
+	pub fn as_field_reference(&self) -> Option<Field<'tu, 'ge>> {
+		if self.type_hint == FunctionTypeHint::FieldReference {
+			self.as_field_accessor()
+		} else {
+			None
+		}
+	}
+
+	/// Type of the value that the `ReturnReference` variant of the method returns the reference to
+	pub fn as_return_reference(&self) -> Option<TypeRef<'tu, 'ge>> {
+		if self.type_hint == FunctionTypeHint::ReturnReference {
+			Some(Func::new(self.entity, self.gen_env).return_type())
+		} else {
+			None
+		}
+	}
+
+	/// Whether the method returns a const reference to the value that's otherwise returned by copy, such methods get the
+	/// additional `ReturnReference` variant
+	pub fn is_return_referenceable(&self) -> bool {
+		if self.type_hint != FunctionTypeHint::None || !matches!(self.kind(), Kind::InstanceMethod(cls) if cls.is_by_ptr()) {
+			return false;
+		}
+		let result_type = TypeRef::new(self.entity.get_result_type().expect("Can't get return type"), self.gen_env);
+		matches!(result_type.as_reference(), Some(inner) if inner.constness().is_const())
+			&& self.return_type().is_field_referenceable()
+	}
+
 	pub fn constness(&self) -> Constness {
 		if settings::FORCE_CONSTANT_METHOD.contains(self.cpp_name(CppNameStyle::Reference).as_ref()) {
 			Constness::Const
 		} else if let Some(fld) = self.as_field_accessor() {
-			if self.type_hint == FunctionTypeHint::FieldSetter {
+			if self.type_hint == FunctionTypeHint::FieldReference {
+				Constness::Const
+			} else if self.type_hint == FunctionTypeHint::FieldSetter {
 				Constness::Mut
 			} else {
 				let type_ref = fld.type_ref();
@@ -367,11 +402,21 @@ impl<'tu, 'ge> Func<'tu, 'ge> {
 	}
 
 	pub fn is_infallible(&self) -> bool {
-		self.as_field_accessor().is_some()
+		(self.as_field_accessor().is_some()
 			|| matches!(
 				self.entity.get_exception_specification(),
 				Some(ExceptionSpecification::BasicNoexcept) | Some(ExceptionSpecification::Unevaluated)
-			) || settings::FORCE_INFALLIBLE.contains(&self.func_id())
+			) || settings::FORCE_INFALLIBLE.contains(&self.func_id()))
+			&& !self.returns_nullable_pointer()
+	}
+
+	/// Returned pointer can be null and has to be checked, such functions are always generated as fallible so that the null
+	/// check can't abort the process. Fixed array returns point to a field and can never be null.
+	fn returns_nullable_pointer(&self) -> bool {
+		let ret_type = self.return_type();
+		matches!(ret_type.as_pointer(), Some(i) if !i.is_void())
+			&& !ret_type.is_pass_by_ptr()
+			&& ret_type.as_fixed_array().is_none()
 	}
 
 	pub fn is_unsafe(&self) -> bool {
@@ -386,6 +431,18 @@ impl<'tu, 'ge> Func<'tu, 'ge> {
 		self.entity.is_default_constructor() && !self.has_arguments()
 	}
 
+	/// Constness of the C++ declaration, differs from `constness()` for the non-const methods in `FORCE_CONSTANT_METHOD`
+	///
+	/// Such methods are exposed through `&self`, but the C++ wrapper must still receive a mutable instance to be able to
+	/// call them.
+	pub fn cpp_constness(&self) -> Constness {
+		if self.as_field_accessor().is_none() && self.kind().as_instance_method().is_some() {
+			Constness::from_is_const(self.entity.is_const_method())
+		} else {
+			self.constness()
+		}
+	}
+
 	pub fn is_clone(&self) -> bool {
 		if self.rust_leafname(FishStyle::No) == "clone" {
 			if let Some(c) = self.kind().as_instance_method() {
@@ -414,6 +471,10 @@ impl<'tu, 'ge> Func<'tu, 'ge> {
 	}
 
 	pub fn return_type(&self) -> TypeRef<'tu, 'ge> {
+		if self.type_hint == FunctionTypeHint::ReturnReference {
+			// the reference is passed as an opaque pointer, see `as_return_reference()` for the referenced type
+			return self.gen_env.resolve_typeref("const void*");
+		}
 		match self.kind() {
 			Kind::Constructor(cls) => cls.type_ref(),
 			Kind::Function
@@ -451,6 +512,9 @@ impl<'tu, 'ge> Func<'tu, 'ge> {
 			Kind::FieldAccessor(..) => {
 				if self.type_hint == FunctionTypeHint::FieldSetter {
 					self.gen_env.resolve_typeref("void")
+				} else if self.type_hint == FunctionTypeHint::FieldReference {
+					// the reference is passed as an opaque pointer, see `as_field_reference()` for the field type
+					self.gen_env.resolve_typeref("const void*")
 				} else {
 					let mut out = Field::new(self.entity, self.gen_env).type_ref();
 					out.set_type_hint(TypeRefTypeHint::PrimitiveRefAsPointer);
@@ -517,6 +581,9 @@ impl<'tu, 'ge> Func<'tu, 'ge> {
 						return Field::new_ext(a, FieldTypeHint::Specialized(spec_type), self.gen_env);
 					}
 				}
+				if out.is_default_empty() {
+					return Field::new_ext(a, FieldTypeHint::ArgOverride(ArgOverride::Nullable), self.gen_env);
+				}
 				out
 			})
 			.collect()
@@ -543,10 +610,14 @@ impl<'tu, 'ge> Func<'tu, 'ge> {
 			let (first_letter, rest) = local_name.split_at(1);
 			if self.as_field_setter().is_some() {
 				write!(out, "setProp{}{}", first_letter.to_uppercase(), rest).expect("write! to String shouldn't fail");
+			} else if self.as_field_reference().is_some() {
+				write!(out, "getProp{}{}Ref", first_letter.to_uppercase(), rest).expect("write! to String shouldn't fail");
 			} else {
 				write!(out, "getProp{}{}", first_letter.to_uppercase(), rest).expect("write! to String shouldn't fail");
 			}
 			out
+		} else if self.type_hint == FunctionTypeHint::ReturnReference {
+			format!("{}Ref", self.cpp_name(CppNameStyle::Reference))
 		} else {
 			self.cpp_name(CppNameStyle::Reference).into_owned()
 		};
@@ -591,6 +662,9 @@ impl Element for Func<'_, '_> {
 		if settings::FUNC_MANUAL.contains_key(identifier.as_ref()) || settings::FUNC_SPECIALIZE.contains_key(identifier.as_ref()) {
 			return false;
 		}
+		if self.type_hint == FunctionTypeHint::ReturnReference && Func::new(self.entity, self.gen_env).is_excluded() {
+			return true;
+		}
 		DefaultElement::is_excluded(self)
 			|| self.is_generic()
 			|| self.kind().as_operator().map_or(false, |kind| {
@@ -613,6 +687,9 @@ impl Element for Func<'_, '_> {
 		if settings::FUNC_MANUAL.contains_key(identifier.as_ref()) || settings::FUNC_SPECIALIZE.contains_key(identifier.as_ref()) {
 			return false;
 		}
+		if self.type_hint == FunctionTypeHint::ReturnReference && Func::new(self.entity, self.gen_env).is_ignored() {
+			return true;
+		}
 		DefaultElement::is_ignored(self)
 			|| self.entity.get_availability() == Availability::Unavailable
 			|| self
@@ -722,6 +799,13 @@ impl Element for Func<'_, '_> {
 	}
 
 	fn rust_leafname(&self, _fish_style: FishStyle) -> Cow<str> {
+		if matches!(
+			self.type_hint,
+			FunctionTypeHint::FieldReference | FunctionTypeHint::ReturnReference
+		) {
+			let getter = Func::new(self.entity, self.gen_env);
+			return format!("{}_ref", getter.rust_leafname(FishStyle::No)).into();
+		}
 		let cpp_name = if let Some(name) = self.gen_env.get_rename_config(self.entity).map(|c| &c.rename) {
 			name.into()
 		} else {
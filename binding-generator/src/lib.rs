@@ -5,6 +5,7 @@
 // todo allow ergonomically combining of enum variants with |
 // todo cv_utils_logging_internal_getGlobalLogTag() returns LogTag**, but Rust interprets it as LogTag*, check why it doesn't crash and fix if needed
 // todo almost everything from the manual module must be connected to the binding generator, not the main crate
+// todo generate subclass shims (C++ subclasses with the trampolines to Rust trait objects) for the classes with virtual methods, use the manual ones for MinProblemSolver::Function (src/manual/core/min_problem_solver.rs) and videostab::IFrameSource (src/manual/videostab.rs) as the reference, including the CallbackPanic handling
 // todo check that FN_FaceDetector works at all (receiving InputArray, passing as callback)
 // fixme vector<Mat*> get's interpreted as Vector<Mat> which should be wrong (e.g. Layer::forward and Layer::apply_halide_scheduler)
 // fixme MatConstIterator::m return Mat**, is it handled correctly?
@@ -54,6 +55,7 @@ pub use walker::{EntityWalker, EntityWalkerVisitor};
 mod abstract_ref_wrapper;
 mod class;
 pub mod comment;
+pub mod compat;
 mod constant;
 mod element;
 mod entity;
@@ -378,6 +378,7 @@ pub static FUNC_RENAME: Lazy<HashMap<&str, &str>> = Lazy::new(|| {
 		"cv_resizeWindow_const_StringR_const_SizeR" => "+_size",
 		"cv_selectROI_const_StringR_const__InputArrayR_bool_bool" => "+_for_window",
 		"cv_selectROIs_const_StringR_const__InputArrayR_vectorLRectGR_bool_bool" => "select_rois",
+		"cv_setMouseCallback_const_StringR_MouseCallback_voidX" => "+_raw",
 
 		// ### imgcodecs ###
 		"cv_imdecode_const__InputArrayR_int_MatX" => "+_to",
@@ -415,6 +416,7 @@ pub static FUNC_RENAME: Lazy<HashMap<&str, &str>> = Lazy::new(|| {
 
 		// ### line_descriptor ###
 		"cv_line_descriptor_LSDDetector_detect_const_const_vectorLMatGR_vectorLvectorLKeyLineGGR_int_int_const_vectorLMatGR" => "+_multiple",
+		"cv_line_descriptor_drawLineMatches_const_MatR_const_vectorLKeyLineGR_const_MatR_const_vectorLKeyLineGR_const_vectorLDMatchGR_MatR_const_ScalarR_const_ScalarR_const_vectorLcharGR_int" => "+_raw", // manual draw_line_matches with typed mask and flags
 
 		// ### ml ###
 		"cv_ml_ParamGrid_ParamGrid_double_double_double" => "for_range",
@@ -554,6 +556,7 @@ pub static ELEMENT_EXCLUDE: Lazy<HashSet<&str>> = Lazy::new(|| {
 		"cv::String",
 		"cv::internal::format", // 3.2 duplicate definition
 		"cv::face::FacemarkLBF::BBox", // not used, not exported in windows dll
+		"cv::line_descriptor::DrawLinesMatchesFlags", // only holds the enum, manual bitflags type with the same name
 	}
 });
 
@@ -710,6 +713,8 @@ pub static FUNC_UNSAFE: Lazy<HashSet<FuncId>> = Lazy::new(|| {
 		FuncId::new("cv::cuda::GpuMat::GpuMat", ["rows", "cols", "type", "s", "allocator"]),
 		FuncId::new("cv::cuda::GpuMat::allocator", ["val"]),
 		FuncId::new("cv::cuda::GpuMat::setDefaultAllocator", ["allocator"]), // fixme, should take 'static
+		// stores the pointer to the value for the lifetime of the trackbar, use create_trackbar_bound() instead
+		FuncId::new("cv::createTrackbar", ["trackbarname", "winname", "value", "count", "onChange", "userdata"]),
 	}
 });
 
@@ -796,6 +801,9 @@ pub static FORCE_CONSTANT_METHOD: Lazy<HashSet<&str>> = Lazy::new(|| {
 		"cv::Mat::step",
 		"cv::UMat::size",
 		"cv::UMat::step",
+		"cv::line_descriptor::BinaryDescriptor::getNumOfOctaves",
+		"cv::line_descriptor::BinaryDescriptor::getReductionRatio",
+		"cv::line_descriptor::BinaryDescriptor::getWidthOfBand",
 	}
 });
 
@@ -852,8 +860,8 @@ pub static FORCE_INFALLIBLE: Lazy<HashSet<FuncId>> = Lazy::new(|| {
 /// cpp_name(Reference) => ( rust_name(Reference(No)), cpp_name(Reference) )
 pub static PRIMITIVE_TYPEDEFS: Lazy<HashMap<&str, (&str, &str)>> = Lazy::new(|| {
 	hashmap! {
-		"size_t" => ("size_t", "size_t"),
-		"ptrdiff_t" => ("ptrdiff_t", "ptrdiff_t"),
+		"size_t" => ("usize", "size_t"),
+		"ptrdiff_t" => ("isize", "ptrdiff_t"),
 		"clock_t" => ("clock_t", "clock_t"),
 		"FILE" => ("FILE", "FILE"),
 		"schar" => ("i8", "signed char"),
@@ -927,6 +935,8 @@ pub enum ArgOverride {
 	StringAsBytes,
 	// when C++ char needs to be represented as Rust char
 	Char8AsChar,
+	// when the string is a file name and should accept anything that can be referenced as Path
+	Path,
 }
 
 pub static ARGUMENT_OVERRIDE: Lazy<HashMap<FuncId, HashMap<&str, ArgOverride>>> = Lazy::new(|| {
@@ -1043,6 +1053,51 @@ pub static ARGUMENT_OVERRIDE: Lazy<HashMap<FuncId, HashMap<&str, ArgOverride>>>
 		FuncId::new("cv::ximgproc::createStructuredEdgeDetection", ["model", "howToGetFeatures"]) => hashmap! {
 			"howToGetFeatures" => ArgOverride::Nullable,
 		},
+		FuncId::new("cv::haveImageReader", ["filename"]) => hashmap! {
+			"filename" => ArgOverride::Path,
+		},
+		FuncId::new("cv::haveImageWriter", ["filename"]) => hashmap! {
+			"filename" => ArgOverride::Path,
+		},
+		FuncId::new("cv::imread", ["filename", "flags"]) => hashmap! {
+			"filename" => ArgOverride::Path,
+		},
+		FuncId::new("cv::imreadmulti", ["filename", "mats", "flags"]) => hashmap! {
+			"filename" => ArgOverride::Path,
+		},
+		FuncId::new("cv::imreadmulti", ["filename", "mats", "start", "count", "flags"]) => hashmap! {
+			"filename" => ArgOverride::Path,
+		},
+		FuncId::new("cv::imcount", ["filename", "flags"]) => hashmap! {
+			"filename" => ArgOverride::Path,
+		},
+		FuncId::new("cv::imwrite", ["filename", "img", "params"]) => hashmap! {
+			"filename" => ArgOverride::Path,
+		},
+		FuncId::new("cv::imwritemulti", ["filename", "img", "params"]) => hashmap! {
+			"filename" => ArgOverride::Path,
+		},
+		FuncId::new("cv::VideoCapture::VideoCapture", ["filename", "apiPreference"]) => hashmap! {
+			"filename" => ArgOverride::Path,
+		},
+		FuncId::new("cv::VideoCapture::VideoCapture", ["filename", "apiPreference", "params"]) => hashmap! {
+			"filename" => ArgOverride::Path,
+		},
+		FuncId::new("cv::VideoWriter::VideoWriter", ["filename", "fourcc", "fps", "frameSize", "isColor"]) => hashmap! {
+			"filename" => ArgOverride::Path,
+		},
+		FuncId::new("cv::VideoWriter::VideoWriter", ["filename", "apiPreference", "fourcc", "fps", "frameSize", "isColor"]) => hashmap! {
+			"filename" => ArgOverride::Path,
+		},
+		FuncId::new("cv::VideoWriter::VideoWriter", ["filename", "fourcc", "fps", "frameSize", "params"]) => hashmap! {
+			"filename" => ArgOverride::Path,
+		},
+		FuncId::new("cv::VideoWriter::VideoWriter", ["filename", "apiPreference", "fourcc", "fps", "frameSize", "params"]) => hashmap! {
+			"filename" => ArgOverride::Path,
+		},
+		FuncId::new("cv::FileStorage::FileStorage", ["filename", "flags", "encoding"]) => hashmap! {
+			"filename" => ArgOverride::Path,
+		},
 	}
 });
 
@@ -1109,6 +1164,8 @@ pub static GENERATOR_MODULE_TWEAKS: Lazy<HashMap<&str, ModuleTweak>> = Lazy::new
 				// return of String
 				"const char*",
 				"void*",
+				// return of property references
+				"const void*",
 				// handling vector of strings
 				"std::vector<cv::String>",
 				"std::vector<std::string>",
@@ -632,3 +632,142 @@ line2
 		assert_eq!(res, &render_doc_comment(comment, "///", "master"));
 	}
 }
+
+#[test]
+fn compat_parse_snapshot() {
+	use crate::compat::ModuleApi;
+
+	let src = "\
+/// Finds edges in an image
+///
+/// ## C++ default parameters
+/// * aperture_size: 3
+/// * l2gradient: false
+#[inline]
+pub fn canny(image: &(impl core::ToInputArray + ?Sized), edges: &mut (impl core::ToOutputArray + ?Sized), threshold1: f64, threshold2: f64, aperture_size: i32, l2gradient: bool) -> Result<()> {
+	unimplemented!()
+}
+
+#[inline]
+pub fn get_elem_size(typ: i32) -> Result<i32> {
+	unimplemented!()
+}
+
+pub trait Algorithm {
+	fn clear(&mut self) -> Result<()>;
+}
+";
+	let api = ModuleApi::parse(src);
+	assert_eq!(2, api.functions.len());
+	let canny = &api.functions[0];
+	assert_eq!("canny", canny.name);
+	assert_eq!("pub", canny.prefix);
+	assert_eq!("Result<()>", canny.ret);
+	assert_eq!(6, canny.params.len());
+	assert_eq!("edges", canny.params[1].name);
+	assert_eq!("&mut (impl core::ToOutputArray + ?Sized)", canny.params[1].typ);
+	assert_eq!(
+		vec![
+			("aperture_size".to_string(), "3".to_string()),
+			("l2gradient".to_string(), "false".to_string())
+		],
+		canny.defaults
+	);
+	assert!(api.functions[1].defaults.is_empty());
+
+	let snapshot = api.to_snapshot();
+	assert!(!snapshot.contains('{'));
+	assert_eq!(api, ModuleApi::parse(&snapshot));
+}
+
+#[test]
+fn compat_split_top_level() {
+	use crate::compat::split_top_level;
+
+	assert_eq!(
+		vec![
+			"a: i32",
+			"f: Option<Box<dyn FnMut(i32, f64) -> bool + Send>>",
+			"v: &core::Vector<(i32, f32)>"
+		],
+		split_top_level("a: i32, f: Option<Box<dyn FnMut(i32, f64) -> bool + Send>>, v: &core::Vector<(i32, f32)>")
+	);
+	assert!(split_top_level("").is_empty());
+}
+
+#[test]
+fn compat_render_default() {
+	use crate::compat::render_default;
+
+	assert_eq!(
+		Some("None"),
+		render_default("Option<&dyn core::ToInputArray>", "noArray()").as_deref()
+	);
+	assert_eq!(
+		Some("None"),
+		render_default("Option<&mut dyn core::ToOutputArray>", "noArray()").as_deref()
+	);
+	assert_eq!(Some("None"), render_default("Option<&mut f32>", "0").as_deref());
+	assert_eq!(None, render_default("Option<&mut f32>", "1").as_deref());
+	assert_eq!(Some("true"), render_default("bool", "true").as_deref());
+	assert_eq!(Some("-1"), render_default("i32", "-1").as_deref());
+	assert_eq!(Some("0.5"), render_default("f32", "0.5f").as_deref());
+	assert_eq!(Some("1.0"), render_default("f64", "1").as_deref());
+	assert_eq!(None, render_default("i32", "INTER_LINEAR").as_deref());
+	assert_eq!(None, render_default("core::Size", "Size()").as_deref());
+}
+
+#[test]
+fn compat_gen_module() {
+	use crate::compat::{gen_compat_module, ModuleApi};
+
+	let pinned = ModuleApi::parse(
+		"\
+pub fn blur(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, ksize: core::Size) -> Result<()>
+pub fn same(a: i32) -> Result<i32>
+",
+	);
+	let new = ModuleApi::parse(
+		"\
+/// ## C++ default parameters
+/// * border_type: BORDER_DEFAULT
+/// * mask: noArray()
+pub fn blur_masked(src: &(impl core::ToInputArray + ?Sized), ksize: core::Size) -> Result<()> {
+/// ## C++ default parameters
+/// * normalize: true
+/// * mask: noArray()
+pub fn blur(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), ksize: core::Size, normalize: bool, mask: Option<&dyn core::ToInputArray>) -> Result<()> {
+pub fn same(a: i32) -> Result<i32> {
+",
+	);
+	let out = gen_compat_module("imgproc", &pinned, &new, "0.75.0").expect("adapter must be generated");
+	assert_eq!(
+		"
+/// Previous signatures of the functions of this module that changed since the pinned API snapshot
+///
+/// Re-exports the rest of the module, so it can be used as a drop-in replacement for the module import.
+pub mod compat {
+	pub use super::*;
+
+	#[deprecated(since = \"0.75.0\", note = \"added the arguments: `normalize`, `mask`, use `super::blur` instead\")]
+	#[inline]
+	pub fn blur(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, ksize: core::Size) -> Result<()> {
+		super::blur(src, dst, ksize, true, None)
+	}
+}
+",
+		out
+	);
+
+	// unchanged API, no compat module
+	assert_eq!(None, gen_compat_module("imgproc", &new, &new, "0.75.0"));
+	// the default of the added argument can't be rendered
+	let new = ModuleApi::parse(
+		"\
+/// ## C++ default parameters
+/// * border_type: BORDER_DEFAULT
+pub fn same(a: i32, border_type: i32) -> Result<i32> {
+",
+	);
+	assert_eq!(None, gen_compat_module("imgproc", &pinned, &new, "0.75.0"));
+}
@@ -418,6 +418,18 @@ impl<'tu, 'ge> TypeRef<'tu, 'ge> {
 		matches!(self.canonical().kind(), Kind::Enum(..))
 	}
 
+	/// Whether the getter returning the reference to the field or to the method result of this type can be generated, it's
+	/// possible for the types that are returned by copy: boxed classes, vectors and text strings
+	pub fn is_field_referenceable(&self) -> bool {
+		if let Some(Dir::In(str_type)) | Some(Dir::Out(str_type)) = self.as_string() {
+			matches!(str_type, StrType::StdString(StrEnc::Text) | StrType::CvString(StrEnc::Text))
+		} else if let Some(cls) = self.as_class() {
+			cls.is_boxed() && !cls.is_abstract()
+		} else {
+			self.as_vector().is_some()
+		}
+	}
+
 	pub fn as_string(&self) -> Option<Dir<StrType>> {
 		let class_string_type = |cls: Class| -> Option<StrType> {
 			let cpp_refname = cls.cpp_name(CppNameStyle::Reference);
@@ -599,6 +611,10 @@ impl<'tu, 'ge> TypeRef<'tu, 'ge> {
 		}
 	}
 
+	pub fn is_path(&self) -> bool {
+		matches!(self.type_hint, TypeRefTypeHint::ArgOverride(ArgOverride::Path)) && self.as_string().is_some()
+	}
+
 	pub fn is_nullable(&self) -> bool {
 		matches!(
 			self.type_hint,
@@ -606,6 +622,11 @@ impl<'tu, 'ge> TypeRef<'tu, 'ge> {
 		)
 	}
 
+	/// Nullable `InputArray`, `OutputArray` or `InputOutputArray`, i.e. the one that defaults to `noArray()` or an empty `Mat`
+	pub fn is_nullable_array(&self) -> bool {
+		self.is_nullable() && (self.is_input_array() || self.is_output_array() || self.is_input_output_array())
+	}
+
 	pub fn as_class(&self) -> Option<Class<'tu, 'ge>> {
 		if let Kind::Class(out) = self.canonical().kind() {
 			Some(out)
@@ -866,6 +887,9 @@ impl<'tu, 'ge> TypeRef<'tu, 'ge> {
 			if let Some(arg_dir) = self.as_string() {
 				break 'typ match dir {
 					ExternDir::ToCpp(_) | ExternDir::Pure => match arg_dir {
+						Dir::In(StrType::StdString(StrEnc::Binary) | StrType::CvString(StrEnc::Binary)) => {
+							format!("*{}c_void", constness.rust_qual(true)).into()
+						}
 						Dir::In(_) => format!("*{}c_char", constness.rust_qual(true)).into(),
 						Dir::Out(_) => "*mut *mut c_void".into(),
 					},
@@ -1001,6 +1025,12 @@ impl<'tu, 'ge> TypeRef<'tu, 'ge> {
 		};
 		if let Some(dir) = self.as_string() {
 			match dir {
+				Dir::In(StrType::StdString(StrEnc::Binary) | StrType::CvString(StrEnc::Binary)) => format!(
+					"{cnst}ocvrs_byte_string_arg*{name}",
+					cnst = self.constness().cpp_qual(),
+					name = space_name
+				)
+				.into(),
 				Dir::In(_) => format!("{cnst}char*{name}", cnst = self.constness().cpp_qual(), name = space_name).into(),
 				Dir::Out(_) => format!("{cnst}void*{name}", cnst = self.constness().cpp_qual(), name = space_name).into(),
 			}
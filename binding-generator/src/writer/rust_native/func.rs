@@ -31,6 +31,10 @@ pub fn disambiguate_num(counter: usize) -> String {
 fn gen_rust_with_name(f: &Func, name: &str, opencv_version: &str) -> String {
 	static TPL: Lazy<CompiledInterpolation> = Lazy::new(|| include_str!("tpl/func/rust.tpl.rs").compile_interpolation());
 
+	if let Some(fld) = f.as_field_reference() {
+		return gen_rust_field_reference(f, &fld, name);
+	}
+
 	let args = Field::rust_disambiguate_names(f.arguments()).collect::<Vec<_>>();
 	let kind = f.kind();
 	let as_instance_method = kind.as_instance_method();
@@ -45,6 +49,8 @@ fn gen_rust_with_name(f: &Func, name: &str, opencv_version: &str) -> String {
 		decl_args.push(cls.type_ref().rust_self_func_decl(method_constness));
 		call_args.push(cls.type_ref().rust_self_func_call(method_constness));
 	}
+	// generic arguments would make the trait methods not object safe
+	let is_generic_allowed = !matches!(as_instance_method, Some(cls) if cls.is_trait());
 	let mut callback_arg_name: Option<String> = None;
 	for (name, arg) in args {
 		let type_ref = arg.type_ref();
@@ -61,7 +67,7 @@ fn gen_rust_with_name(f: &Func, name: &str, opencv_version: &str) -> String {
 				callback_arg_name = Some(name.clone());
 			}
 			if !arg.as_slice_len().is_some() {
-				decl_args.push(type_ref.rust_arg_func_decl(&name));
+				decl_args.push(type_ref.rust_arg_func_decl(&name, is_generic_allowed));
 			}
 			pre_post_arg_handle(type_ref.rust_arg_pre_call(&name, is_infallible), &mut pre_call_args);
 		}
@@ -88,7 +94,15 @@ fn gen_rust_with_name(f: &Func, name: &str, opencv_version: &str) -> String {
 		call_args.push("ocvrs_return.as_mut_ptr()".to_string());
 	}
 
-	let doc_comment = f.rendered_doc_comment(opencv_version);
+	let return_reference = f.as_return_reference();
+	let doc_comment = if return_reference.is_some() {
+		format!(
+			"/// Same as `{method}()`, but returns the reference instead of the copy",
+			method = name.strip_suffix("_ref").unwrap_or(name)
+		)
+	} else {
+		f.rendered_doc_comment(opencv_version)
+	};
 	let debug = get_debug(f);
 	let visibility = if let Some(cls) = as_instance_method {
 		if cls.is_trait() {
@@ -103,17 +117,19 @@ fn gen_rust_with_name(f: &Func, name: &str, opencv_version: &str) -> String {
 	let is_safe = !f.is_unsafe();
 	let is_static_func = matches!(f.kind(), Kind::StaticMethod(..) | Kind::Function);
 	let return_type = f.return_type();
-	let return_type_func_decl = if is_infallible {
-		return_type.rust_return(FishStyle::No, is_static_func)
-	} else {
-		format!("Result<{}>", return_type.rust_return(FishStyle::No, is_static_func)).into()
+	let return_type_func_decl = match &return_reference {
+		Some(ret_ref) if ret_ref.as_string().is_some() => "Result<&str>".into(),
+		Some(ret_ref) if is_infallible => format!("BoxedRef<'_, {}>", ret_ref.rust_return(FishStyle::No, false)).into(),
+		Some(ret_ref) => format!("Result<BoxedRef<'_, {}>>", ret_ref.rust_return(FishStyle::No, false)).into(),
+		None if is_infallible => return_type.rust_return(FishStyle::No, is_static_func),
+		None => format!("Result<{}>", return_type.rust_return(FishStyle::No, is_static_func)).into(),
 	};
 	let return_type_func_decl = if return_type_func_decl == "()" {
 		Cow::Borrowed("")
 	} else {
 		format!(" -> {}", return_type_func_decl).into()
 	};
-	if is_infallible {
+	if is_infallible && !matches!(&return_reference, Some(ret_ref) if ret_ref.as_string().is_some()) {
 		post_call_args.push("ret".to_string());
 	} else {
 		post_call_args.push("Ok(ret)".to_string());
@@ -135,7 +151,11 @@ fn gen_rust_with_name(f: &Func, name: &str, opencv_version: &str) -> String {
 	if !is_infallible {
 		ret_convert.push("let ret = ret.into_result()?;".into())
 	}
-	let ret_map = rust_return_map(&return_type, "ret", is_safe, is_static_func, is_infallible);
+	let ret_map = match &return_reference {
+		Some(ret_ref) if ret_ref.as_string().is_some() => "unsafe { crate::templ::receive_string_ref(ret) }?".into(),
+		Some(_) => "unsafe { BoxedRef::from_raw(ret) }".into(),
+		None => rust_return_map(&return_type, "ret", is_safe, is_static_func, is_infallible),
+	};
 	if !ret_map.is_empty() {
 		ret_convert.push(format!("let ret = {};", ret_map).into());
 	}
@@ -179,6 +199,63 @@ fn gen_rust_with_name(f: &Func, name: &str, opencv_version: &str) -> String {
 	})
 }
 
+/// Getter returning the reference to the field, the generated extern returns an opaque pointer to the field data
+fn gen_rust_field_reference(f: &Func, fld: &Field, name: &str) -> String {
+	static TPL: Lazy<CompiledInterpolation> = Lazy::new(|| include_str!("tpl/func/rust.tpl.rs").compile_interpolation());
+
+	let cls = f
+		.kind()
+		.as_instance_method()
+		.cloned()
+		.expect("Field reference must belong to a class");
+	let constness = f.constness();
+	let field_type = fld.type_ref();
+	let (return_type, ret_convert) = if field_type.as_string().is_some() {
+		(
+			Cow::Borrowed("Result<&str>"),
+			"let ret = unsafe { crate::templ::receive_string_ref(ret) }?;",
+		)
+	} else {
+		(
+			format!("BoxedRef<'_, {typ}>", typ = field_type.rust_return(FishStyle::No, false)).into(),
+			"let ret = unsafe { BoxedRef::from_raw(ret) };",
+		)
+	};
+	let post_call_args = if field_type.as_string().is_some() {
+		"Ok(ret)"
+	} else {
+		"ret"
+	};
+	let doc_comment = format!(
+		"/// Same as `{getter}()`, but returns the reference to the field instead of its copy",
+		getter = name.strip_suffix("_ref").unwrap_or(name)
+	);
+	let decl_args = cls.type_ref().rust_self_func_decl(constness);
+	let call_args = cls.type_ref().rust_self_func_call(constness);
+	let rv_rust_full = format!(" -> {}", return_type);
+	let identifier = f.identifier();
+	let debug = get_debug(f);
+	TPL.interpolate(&hashmap! {
+		"doc_comment" => doc_comment.as_str(),
+		"debug" => &debug,
+		"attributes" => "",
+		"visibility" => if cls.is_trait() { "" } else { "pub " },
+		"unsafety_decl" => "",
+		"name" => name,
+		"generic_decl" => "",
+		"decl_args" => &decl_args,
+		"rv_rust_full" => &rv_rust_full,
+		"pre_call_args" => "",
+		"unsafety_call" => "unsafe ",
+		"identifier" => identifier.as_ref(),
+		"call_args" => &call_args,
+		"forward_args" => "",
+		"ret_receive" => "let ret = ",
+		"ret_convert" => ret_convert,
+		"post_call_args" => post_call_args,
+	})
+}
+
 fn rust_return_map(
 	return_type: &TypeRef,
 	ret_name: &str,
@@ -202,24 +279,34 @@ fn rust_return_map(
 	} else if return_type.as_pointer().map_or(false, |i| !i.is_void()) && !return_type.is_pass_by_ptr()
 		|| return_type.as_fixed_array().is_some()
 	{
-		let ptr_call = if return_type.constness().is_const() {
-			"as_ref"
-		} else {
-			"as_mut"
-		};
-		let error_handling = if is_infallible {
-			".expect(\"Function returned null pointer\")"
+		if is_infallible {
+			// only fixed arrays are infallible, they point to a field and can't be null
+			let deref = if return_type.constness().is_const() {
+				"&*"
+			} else {
+				"&mut *"
+			};
+			format!(
+				"{unsafety_call}{{ {deref}{ret_name} }}",
+				unsafety_call = unsafety_call,
+				deref = deref,
+				ret_name = ret_name,
+			)
+			.into()
 		} else {
-			".ok_or_else(|| Error::new(core::StsNullPtr, \"Function returned null pointer\"))?"
-		};
-		format!(
-			"{unsafety_call}{{ {ret_name}.{ptr_call}() }}{error_handling}",
-			unsafety_call = unsafety_call,
-			ret_name = ret_name,
-			ptr_call = ptr_call,
-			error_handling = error_handling,
-		)
-		.into()
+			let ptr_call = if return_type.constness().is_const() {
+				"as_ref"
+			} else {
+				"as_mut"
+			};
+			format!(
+				"{unsafety_call}{{ {ret_name}.{ptr_call}() }}.ok_or_else(|| Error::new(core::StsNullPtr, \"Function returned null pointer\"))?",
+				unsafety_call = unsafety_call,
+				ret_name = ret_name,
+				ptr_call = ptr_call,
+			)
+			.into()
+		}
 	} else {
 		"".into()
 	}
@@ -367,7 +454,7 @@ impl RustNativeGeneratedElement for Func<'_, '_> {
 impl<'tu, 'ge, 'r> From<&'r Func<'tu, 'ge>> for CppFuncDesc<'tu, 'ge, 'r> {
 	fn from(f: &'r Func<'tu, 'ge>) -> Self {
 		let extern_name = f.identifier();
-		let constness = f.constness();
+		let constness = f.cpp_constness();
 		let is_infallible = f.is_infallible();
 		let is_naked_return = f.is_naked_return();
 		let return_type = f.return_type();
@@ -85,6 +85,14 @@ impl<'tu, 'ge> CppFuncDesc<'tu, 'ge, '_> {
 				FuncDescKind::FieldAccessor(cls, fld) if self.type_hint == FunctionTypeHint::FieldSetter => {
 					cpp_method_call_name(cls.is_by_ptr, &fld.cpp_name(CppNameStyle::Declaration)).into()
 				}
+				FuncDescKind::FieldAccessor(cls, fld) if self.type_hint == FunctionTypeHint::FieldReference => {
+					let name = cpp_method_call_name(cls.is_by_ptr, &fld.cpp_name(CppNameStyle::Declaration));
+					if fld.type_ref().as_string().is_some() {
+						format!("{name}.c_str()", name = name).into()
+					} else {
+						format!("&{name}", name = name).into()
+					}
+				}
 				FuncDescKind::InstanceMethod(cls)
 				| FuncDescKind::FieldAccessor(cls, _)
 				| FuncDescKind::GenericInstanceMethod(cls)
@@ -116,6 +124,11 @@ impl<'tu, 'ge> CppFuncDesc<'tu, 'ge, '_> {
 				FuncDescCppCall::Manual(call) => call,
 			}
 			.interpolate(&inter_vars);
+			let call = if self.type_hint == FunctionTypeHint::ReturnReference {
+				format!("ocvrs_ref({call})", call = call)
+			} else {
+				call
+			};
 			inter_vars.insert("call", call.into());
 			if self.return_type.is_void() {
 				&VOID_TPL
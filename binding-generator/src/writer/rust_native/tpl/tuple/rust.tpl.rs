@@ -10,4 +10,17 @@ tuple_extern! { {{inner_rust_full}},
 	{{getters}}
 }
 
+impl From<{{rust_full}}> for {{inner_rust_full}} {
+	#[inline]
+	fn from(s: {{rust_full}}) -> Self {
+		s.into_tuple()
+	}
+}
+
+impl From<{{inner_rust_full}}> for {{rust_full}} {
+	#[inline]
+	fn from(s: {{inner_rust_full}}) -> Self {
+		Self::new(s)
+	}
+}
 
@@ -2,14 +2,15 @@ use crate::{Field, IteratorExt};
 use std::borrow::Cow;
 
 use crate::type_ref::{
-	Constness, ConstnessOverride, CppNameStyle, Dir, ExternDir, FishStyle, NameStyle, Signedness, StrEnc, StrType, TypeRef,
+	Constness, ConstnessOverride, CppNameStyle, CppRenderer, Dir, ExternDir, FishStyle, NameStyle, Signedness, StrEnc, StrType,
+	TypeRef,
 };
 
 pub trait TypeRefExt {
 	fn format_as_array(&self, elem_type: &str, size: Option<usize>) -> String;
 
 	fn rust_self_func_decl(&self, method_constness: Constness) -> String;
-	fn rust_arg_func_decl(&self, name: &str) -> String;
+	fn rust_arg_func_decl(&self, name: &str, is_generic_allowed: bool) -> String;
 	fn rust_extern_self_func_decl(&self, method_constness: Constness) -> String;
 	fn rust_extern_arg_func_decl(&self, name: &str, constness: ConstnessOverride) -> String;
 	fn rust_arg_pre_call(&self, name: &str, is_function_infallible: bool) -> String;
@@ -51,10 +52,12 @@ impl<'tu, 'ge> TypeRefExt for TypeRef<'tu, 'ge> {
 		}
 	}
 
-	fn rust_arg_func_decl(&self, name: &str) -> String {
+	fn rust_arg_func_decl(&self, name: &str, is_generic_allowed: bool) -> String {
 		#[allow(clippy::never_loop)] // fixme use named block when MSRV is 1.65
 		let typ = 'decl_type: loop {
-			if let Some(dir) = self.as_string() {
+			if self.is_path() {
+				break 'decl_type "impl AsRef<Path>".into();
+			} else if let Some(dir) = self.as_string() {
 				break 'decl_type match dir {
 					Dir::In(StrType::StdString(StrEnc::Text) | StrType::CvString(StrEnc::Text) | StrType::CharPtr) => "&str".into(),
 					Dir::In(StrType::StdString(StrEnc::Binary) | StrType::CvString(StrEnc::Binary)) => "&[u8]".into(),
@@ -63,12 +66,37 @@ impl<'tu, 'ge> TypeRefExt for TypeRef<'tu, 'ge> {
 					}
 					Dir::Out(StrType::StdString(StrEnc::Binary) | StrType::CvString(StrEnc::Binary)) => "&mut Vec<u8>".into(),
 				};
+			} else if self.is_nullable_array() {
+				break 'decl_type match (self.is_input_array(), self.is_output_array(), is_generic_allowed) {
+					(true, _, true) => "Option<&(impl core::ToInputArray + ?Sized)>",
+					(true, _, false) => "Option<&dyn core::ToInputArray>",
+					(false, true, true) => "Option<&mut (impl core::ToOutputArray + ?Sized)>",
+					(false, true, false) => "Option<&mut dyn core::ToOutputArray>",
+					(false, false, true) => "Option<&mut (impl core::ToInputOutputArray + ?Sized)>",
+					(false, false, false) => "Option<&mut dyn core::ToInputOutputArray>",
+				}
+				.into();
 			} else if self.is_input_array() {
-				break 'decl_type "&dyn core::ToInputArray".into();
+				break 'decl_type if is_generic_allowed {
+					"&(impl core::ToInputArray + ?Sized)"
+				} else {
+					"&dyn core::ToInputArray"
+				}
+				.into();
 			} else if self.is_output_array() {
-				break 'decl_type "&mut dyn core::ToOutputArray".into();
+				break 'decl_type if is_generic_allowed {
+					"&mut (impl core::ToOutputArray + ?Sized)"
+				} else {
+					"&mut dyn core::ToOutputArray"
+				}
+				.into();
 			} else if self.is_input_output_array() {
-				break 'decl_type "&mut dyn core::ToInputOutputArray".into();
+				break 'decl_type if is_generic_allowed {
+					"&mut (impl core::ToInputOutputArray + ?Sized)"
+				} else {
+					"&mut dyn core::ToInputOutputArray"
+				}
+				.into();
 			} else if let Some((_, size)) = self.as_string_array() {
 				break 'decl_type self.format_as_array("&str", size).into();
 			} else if self.as_char8().is_some() {
@@ -99,7 +127,13 @@ impl<'tu, 'ge> TypeRefExt for TypeRef<'tu, 'ge> {
 	}
 
 	fn rust_arg_pre_call(&self, name: &str, is_function_infallible: bool) -> String {
-		if let Some(dir) = self.as_string() {
+		if self.is_path() {
+			return if is_function_infallible {
+				format!("path_arg!(nofail {name})", name = name)
+			} else {
+				format!("path_arg!({name})", name = name)
+			};
+		} else if let Some(dir) = self.as_string() {
 			return match dir {
 				Dir::In(_) => {
 					let mut flags = vec![];
@@ -119,12 +153,25 @@ impl<'tu, 'ge> TypeRefExt for TypeRef<'tu, 'ge> {
 					format!("string_arg_output_send!(via {name}_via)", name = name)
 				}
 			};
-		} else if self.is_input_array() {
-			return format!("input_array_arg!({name})", name = name);
-		} else if self.is_output_array() {
-			return format!("output_array_arg!({name})", name = name);
-		} else if self.is_input_output_array() {
-			return format!("input_output_array_arg!({name})", name = name);
+		} else if self.is_input_array() || self.is_output_array() || self.is_input_output_array() {
+			let macro_name = if self.is_input_array() {
+				"input_array_arg"
+			} else if self.is_output_array() {
+				"output_array_arg"
+			} else {
+				"input_output_array_arg"
+			};
+			let flags = if self.is_nullable() {
+				"nullable "
+			} else {
+				""
+			};
+			return format!(
+				"{macro_name}!({flags}{name})",
+				macro_name = macro_name,
+				flags = flags,
+				name = name
+			);
 		} else if self.as_string_array().is_some() {
 			return if self.constness().is_const() {
 				format!("string_array_arg!({name})", name = name)
@@ -213,7 +260,8 @@ impl<'tu, 'ge> TypeRefExt for TypeRef<'tu, 'ge> {
 					rust_safe_id = typ.rust_safe_id(false)
 				)
 			};
-			return if self.is_nullable() {
+			// nullable arrays are replaced with noArray() by the pre-call macro
+			return if self.is_nullable() && !self.is_nullable_array() {
 				format!(
 					"{name}.map_or({null_ptr}, |{name}| {by_ptr})",
 					name = name,
@@ -390,6 +438,12 @@ impl<'tu, 'ge> TypeRefExt for TypeRef<'tu, 'ge> {
 				};
 				return format!("{ptr}{name}_out", ptr = ptr, name = name).into();
 			}
+			Some(Dir::In(StrType::StdString(StrEnc::Binary))) => {
+				return format!("std::string({name}->data, {name}->size)", name = name).into();
+			}
+			Some(Dir::In(StrType::CvString(StrEnc::Binary))) => {
+				return format!("cv::String({name}->data, {name}->size)", name = name).into();
+			}
 			Some(Dir::In(StrType::StdString(_))) => {
 				return format!("std::string({name})", name = name).into();
 			}
@@ -404,6 +458,27 @@ impl<'tu, 'ge> TypeRefExt for TypeRef<'tu, 'ge> {
 		if self.is_extern_by_ptr() {
 			return if self.as_pointer().is_some() {
 				name
+			} else if self.is_nullable() && !self.is_nullable_array() {
+				// null is passed from Rust in place of the default-constructed value
+				let value = self.as_reference().unwrap_or_else(|| self.clone());
+				let default = format!(
+					"{typ}()",
+					typ = value.render(CppRenderer {
+						constness_override: ConstnessOverride::Mut,
+						..CppRenderer::new(CppNameStyle::Reference, "", false)
+					})
+				);
+				// casting the temporary to the reference type keeps both branches lvalues so that the passed value isn't copied
+				let default = if self.as_reference().is_some() {
+					format!(
+						"static_cast<{typ}>({default})",
+						typ = self.cpp_name_ext(CppNameStyle::Reference, "", false),
+						default = default
+					)
+				} else {
+					default
+				};
+				format!("({name} ? *{name} : {default})", name = name, default = default).into()
 			} else {
 				format!("*{name}", name = name).into()
 			};
@@ -36,7 +36,7 @@ static OPENCV_BRANCH_34: Lazy<VersionReq> =
 static OPENCV_BRANCH_4: Lazy<VersionReq> =
 	Lazy::new(|| VersionReq::parse("~4").expect("Can't parse OpenCV 4 version requirement"));
 
-static ENV_VARS: [&str; 14] = [
+static ENV_VARS: [&str; 15] = [
 	"OPENCV_PACKAGE_NAME",
 	"OPENCV_PKGCONFIG_NAME",
 	"OPENCV_CMAKE_NAME",
@@ -46,6 +46,7 @@ static ENV_VARS: [&str; 14] = [
 	"OPENCV_LINK_PATHS",
 	"OPENCV_INCLUDE_PATHS",
 	"OPENCV_DISABLE_PROBES",
+	"OPENCV_PLUGIN_SOURCES",
 	"CMAKE_PREFIX_PATH",
 	"OpenCV_DIR",
 	"PKG_CONFIG_PATH",
@@ -308,22 +309,64 @@ fn build_wrapper(opencv: &Library) {
 			cc.file(manual_cpp);
 		}
 	}
+	for plugin_cpp in plugin_sources().expect("Can't read OPENCV_PLUGIN_SOURCES") {
+		eprintln!("=== Adding plugin source: {}", plugin_cpp.display());
+		println!("cargo:rerun-if-changed={}", plugin_cpp.display());
+		if let Some(plugin_dir) = plugin_cpp.parent() {
+			cc.include(plugin_dir);
+		}
+		cc.file(plugin_cpp);
+	}
+	// list of the compiled modules for the runtime checks, see core::is_module_available()
+	println!("cargo:rustc-env=OCVRS_MODULES={}", modules.join(","));
 	cc.compile("ocvrs");
 }
 
+/// User provided C++ files to compile into the wrapper library, see `raw::opencv_plugin_class!`
+///
+/// `OPENCV_PLUGIN_SOURCES` is a comma separated list of `.cpp` files and directories, all `.cpp` files of the listed
+/// directories are used.
+fn plugin_sources() -> Result<Vec<PathBuf>> {
+	let mut out = vec![];
+	if let Some(sources) = env::var_os("OPENCV_PLUGIN_SOURCES") {
+		for source in sources.to_str().ok_or("OPENCV_PLUGIN_SOURCES is not valid UTF-8")?.split(',') {
+			let source = PathBuf::from(source.trim());
+			if source.as_os_str().is_empty() {
+				continue;
+			}
+			if source.is_dir() {
+				println!("cargo:rerun-if-changed={}", source.display());
+				let mut files = files_with_extension(&source, "cpp")?.collect::<Vec<_>>();
+				files.sort_unstable();
+				out.extend(files);
+			} else if source.is_file() {
+				out.push(source);
+			} else {
+				return Err(format!("Plugin source doesn't exist: {}", source.display()).into());
+			}
+		}
+	}
+	Ok(out)
+}
+
 fn main() -> Result<()> {
 	if cfg!(feature = "docs-only") {
 		// fake setup for docs.rs
 		println!(r#"cargo:rustc-cfg=ocvrs_opencv_branch_4"#);
+		let mut modules = vec![];
 		for entry in SRC_DIR.join("opencv/hub").read_dir().expect("Can't read hub dir") {
 			let entry = entry.expect("Can't read directory entry");
 			let path = entry.path();
 			if entry.file_type().map(|f| f.is_file()).unwrap_or(false) && path.extension().map_or(false, |e| e == "rs") {
 				if let Some(module) = path.file_stem().and_then(OsStr::to_str) {
 					println!("cargo:rustc-cfg=ocvrs_has_module_{}", module);
+					if module != "sys" && module != "types" {
+						modules.push(module.to_string());
+					}
 				}
 			}
 		}
+		println!("cargo:rustc-env=OCVRS_MODULES={}", modules.join(","));
 		return Ok(());
 	}
 
@@ -7,6 +7,8 @@ use std::sync::Arc;
 use std::time::Instant;
 use std::{fs, io, thread};
 
+use binding_generator::compat::{self, ModuleApi};
+
 use super::{files_with_extension, Library, Result, HOST_TRIPLE, MODULES, OUT_DIR, SRC_CPP_DIR, SRC_DIR};
 
 fn is_type_file(path: &Path, module: &str) -> bool {
@@ -53,6 +55,7 @@ pub fn gen_wrapper(
 	let target_hub_dir = SRC_DIR.join("opencv");
 	let target_module_dir = target_hub_dir.join("hub");
 	let manual_dir = SRC_DIR.join("manual");
+	let compat_api_dir = target_hub_dir.join("compat_api");
 
 	eprintln!("=== Generating code in: {}", OUT_DIR.display());
 	eprintln!("=== Placing generated bindings into: {}", target_hub_dir.display());
@@ -192,6 +195,15 @@ pub fn gen_wrapper(
 		let module_filename = format!("{}.rs", module);
 		let target_file = file_move_to_dir(&OUT_DIR.join(&module_filename), &target_module_dir)?;
 		let mut f = OpenOptions::new().append(true).open(&target_file)?;
+		// the compat adapters are generated against the committed API snapshot to keep the output reproducible
+		if let Ok(snapshot) = fs::read_to_string(compat_api_dir.join(format!("{}.api", module))) {
+			let new_api = ModuleApi::parse(&fs::read_to_string(&target_file)?);
+			if let Some(compat_module) =
+				compat::gen_compat_module(module, &ModuleApi::parse(&snapshot), &new_api, env!("CARGO_PKG_VERSION"))
+			{
+				f.write_all(compat_module.as_bytes())?;
+			}
+		}
 		add_manual(&mut f, module)?;
 
 		// merge multiple *-.type.rs files into a single types.rs
@@ -250,5 +262,19 @@ pub fn gen_wrapper(
 	}
 	writeln!(hub_rs, "}}")?;
 
+	// write hub_prelude_v2 with a separate prelude per module that also includes its manual extension traits
+	writeln!(hub_rs, "pub mod hub_prelude_v2 {{")?;
+	for module in modules {
+		write!(hub_rs, "\t")?;
+		write_has_module(&mut hub_rs, module)?;
+		writeln!(hub_rs, "\tpub mod {} {{", module)?;
+		writeln!(hub_rs, "\t\tpub use crate::{}::prelude::*;", module)?;
+		if manual_dir.join(format!("{}.rs", module)).exists() {
+			writeln!(hub_rs, "\t\tpub use crate::manual::prelude::{}::*;", module)?;
+		}
+		writeln!(hub_rs, "\t}}")?;
+	}
+	writeln!(hub_rs, "}}")?;
+
 	Ok(())
 }
@@ -43,7 +43,7 @@ fn main() -> opencv::Result<()> {
 	let mut magI = Mat::default();
 	core::magnitude(&planes.get(0)?, &planes.get(1)?, &mut magI)?;
 	let mut magI_tmp = Mat::default();
-	core::add(&magI, &Scalar::all(1.), &mut magI_tmp, &core::no_array(), -1)?;
+	core::add(&magI, &Scalar::all(1.), &mut magI_tmp, None::<&Mat>, -1)?;
 	magI = magI_tmp;
 	let mut magI_log = Mat::default();
 	core::log(&magI, &mut magI_log)?;
@@ -62,7 +62,7 @@ fn main() -> opencv::Result<()> {
 	q2.copy_to(&mut q1)?;
 	tmp.copy_to(&mut q2)?;
 	let mut magI_tmp = Mat::default();
-	core::normalize(&magI, &mut magI_tmp, 0., 1., core::NORM_MINMAX, -1, &core::no_array())?;
+	core::normalize(&magI, &mut magI_tmp, 0., 1., core::NORM_MINMAX, -1, None::<&Mat>)?;
 	let magI = magI_tmp;
 	highgui::imshow("Input Image", &I)?;
 	highgui::imshow("spectrum magnitude", &magI)?;
@@ -10,7 +10,8 @@ use std::{
 use opencv::{
 	calib3d,
 	core::{self, Point, Scalar, Size},
-	highgui, imgcodecs, imgproc,
+	highgui::{self, MouseEvent},
+	imgcodecs, imgproc,
 	prelude::*,
 	types::VectorOfPoint,
 };
@@ -84,44 +85,39 @@ fn main() -> Result<(), Box<dyn Error>> {
 	highgui::named_window("Warped Image", highgui::WINDOW_AUTOSIZE)?;
 	highgui::move_window("Warped Image", 20, 20)?;
 	highgui::move_window(WINDOW_TITLE, 330, 20)?;
-	highgui::set_mouse_callback(
-		WINDOW_TITLE,
-		Some(Box::new({
-			let mut dragging = false;
-			let mut selected_corner_index = 0;
-			let roi_corners = Arc::clone(&roi_corners);
-			let validation_needed = Arc::clone(&validation_needed);
-			move |event, x, y, _flags| {
-				{
-					let mut roi_corners = roi_corners.lock().unwrap();
-					// Action when left button is pressed
-					if roi_corners.len() == 4 {
-						for i in 0..4 {
-							if event == highgui::EVENT_LBUTTONDOWN
-								&& (roi_corners.get(i).unwrap().x - x).abs() < 10
-								&& (roi_corners.get(i).unwrap().y - y).abs() < 10
-							{
-								selected_corner_index = i;
-								dragging = true;
-							}
-						}
-					} else if event == highgui::EVENT_LBUTTONDOWN {
-						roi_corners.push(Point::new(x, y));
-						validation_needed.store(true, Ordering::Relaxed);
-					}
-					// Action when left button is released
-					if event == highgui::EVENT_LBUTTONUP {
-						dragging = false;
-					}
-					// Action when left button is pressed and mouse has moved over the window
-					if event == highgui::EVENT_MOUSEMOVE && dragging {
-						roi_corners.set(selected_corner_index, Point::new(x, y)).unwrap();
-						validation_needed.store(true, Ordering::Relaxed);
+	highgui::set_mouse_callback(WINDOW_TITLE, {
+		let mut dragging = false;
+		let mut selected_corner_index = 0;
+		let roi_corners = Arc::clone(&roi_corners);
+		let validation_needed = Arc::clone(&validation_needed);
+		move |event, pos, _flags| {
+			let mut roi_corners = roi_corners.lock().unwrap();
+			// Action when left button is pressed
+			if roi_corners.len() == 4 {
+				for i in 0..4 {
+					if event == MouseEvent::LeftButtonDown
+						&& (roi_corners.get(i).unwrap().x - pos.x).abs() < 10
+						&& (roi_corners.get(i).unwrap().y - pos.y).abs() < 10
+					{
+						selected_corner_index = i;
+						dragging = true;
 					}
 				}
+			} else if event == MouseEvent::LeftButtonDown {
+				roi_corners.push(pos);
+				validation_needed.store(true, Ordering::Relaxed);
 			}
-		})),
-	)?;
+			// Action when left button is released
+			if event == MouseEvent::LeftButtonUp {
+				dragging = false;
+			}
+			// Action when left button is pressed and mouse has moved over the window
+			if event == MouseEvent::Move && dragging {
+				roi_corners.set(selected_corner_index, pos).unwrap();
+				validation_needed.store(true, Ordering::Relaxed);
+			}
+		}
+	})?;
 	let mut end_program = false;
 	while !end_program {
 		let roi_corners_len = roi_corners.lock().unwrap().len();
@@ -1,19 +1,145 @@
+#[cfg(feature = "backtrace")]
+use std::backtrace::{Backtrace, BacktraceStatus};
 use std::{char::TryFromCharError, ffi::NulError, fmt};
 
 use crate::core;
 
+/// Error returned by the OpenCV functions and the crate itself
+///
+/// It implements [std::error::Error] and is `Send + Sync + 'static`, so it can be converted into `anyhow::Error` with
+/// `?` and wrapped with `#[from]` in the `thiserror` derived error types.
+///
+/// Use [ResultExt::cv_context] to attach the description of the operation that failed. With the `backtrace` feature
+/// enabled the Rust backtrace is captured when the error is created, the capture follows the `RUST_BACKTRACE` and
+/// `RUST_LIB_BACKTRACE` environment variables.
+///
+/// The struct is `#[non_exhaustive]`, create it with [Error::new], [Error::with_kind] or the `From` conversions.
+///
+/// Match on the [kind](Error::kind) of the error:
+///
+/// ```ignore
+/// match err.kind {
+/// 	ErrorKind::ModuleUnavailable { module } => eprintln!("Missing OpenCV module: {}", module),
+/// 	ErrorKind::Code(core::Code::StsBadArg) => {}
+/// 	ErrorKind::Code(code) => eprintln!("OpenCV error: {:?}", code),
+/// 	ErrorKind::Unknown(code) => eprintln!("Unknown error code: {}", code),
+/// }
+/// ```
 #[derive(Debug)]
+#[non_exhaustive]
 pub struct Error {
-	pub code: i32,
+	/// Kind of the error, the standard OpenCV codes are stored as [core::Code]
+	pub kind: ErrorKind,
 	pub message: String,
+	/// Name of the C++ function that raised the error, `None` when it's unknown or the error originates in Rust
+	pub function: Option<String>,
+	/// Context descriptions from the innermost to the outermost one
+	context: Vec<String>,
+	#[cfg(feature = "backtrace")]
+	backtrace: Option<Box<Backtrace>>,
 }
 
 impl Error {
+	/// Creates the error with the raw error code, usually one of the `core::Sts*` constants, see [ErrorKind::from_code]
 	#[inline]
 	pub fn new(code: i32, message: impl Into<String>) -> Self {
+		Self::with_kind(ErrorKind::from_code(code), message)
+	}
+
+	/// Creates the error of the specified kind
+	#[inline]
+	pub fn with_kind(kind: ErrorKind, message: impl Into<String>) -> Self {
 		Self {
-			code,
+			kind,
 			message: message.into(),
+			function: None,
+			context: vec![],
+			#[cfg(feature = "backtrace")]
+			backtrace: Some(Backtrace::capture())
+				.filter(|backtrace| backtrace.status() == BacktraceStatus::Captured)
+				.map(Box::new),
+		}
+	}
+
+	/// Creates the error signaling that the OpenCV module needed for the call is missing, see
+	/// [core::ensure_module_available]
+	///
+	/// Its [kind](Error::kind) is [ErrorKind::ModuleUnavailable], the code is `StsNotImplemented` and the message
+	/// explains how to get the module.
+	pub fn module_unavailable(module: &str) -> Self {
+		Self::with_kind(
+			ErrorKind::ModuleUnavailable {
+				module: module.to_string(),
+			},
+			format!(
+				"OpenCV module {} is not available, it must be enabled both in the OpenCV build (it's part of opencv_contrib \
+				for the contrib modules) and in the crate features",
+				module
+			),
+		)
+	}
+
+	/// Raw error code, see [ErrorKind::code]
+	#[inline]
+	pub fn code(&self) -> i32 {
+		self.kind.code()
+	}
+
+	/// Sets the name of the function the error originates from
+	#[inline]
+	pub fn with_function(mut self, function: impl Into<String>) -> Self {
+		self.function = Some(function.into());
+		self
+	}
+
+	/// Adds the description of the operation during which the error happened, e.g. the pipeline stage or the frame
+	#[inline]
+	pub fn add_context(mut self, context: impl Into<String>) -> Self {
+		self.context.push(context.into());
+		self
+	}
+
+	/// Context descriptions added with [Error::add_context] from the innermost to the outermost one
+	#[inline]
+	pub fn context(&self) -> &[String] {
+		&self.context
+	}
+
+	/// Rust backtrace captured when the error was created, `None` if the capture is disabled
+	#[cfg(feature = "backtrace")]
+	#[inline]
+	pub fn backtrace(&self) -> Option<&Backtrace> {
+		self.backtrace.as_deref()
+	}
+}
+
+/// Kind of the [Error]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+	/// One of the standard OpenCV error codes (`cv::Error::Code`)
+	Code(core::Code),
+	/// OpenCV module needed for the call is not available, see [core::ensure_module_available]
+	ModuleUnavailable { module: String },
+	/// Code that's not one of the standard OpenCV codes, e.g. a custom one passed to [Error::new]
+	Unknown(i32),
+}
+
+impl ErrorKind {
+	/// Kind for the raw error code, [ErrorKind::Code] for the standard OpenCV codes and [ErrorKind::Unknown] otherwise
+	pub fn from_code(code: i32) -> Self {
+		CODES
+			.iter()
+			.copied()
+			.find(|&known| known as i32 == code)
+			.map_or(Self::Unknown(code), Self::Code)
+	}
+
+	/// Raw error code, `StsNotImplemented` for [ErrorKind::ModuleUnavailable]
+	pub fn code(&self) -> i32 {
+		match self {
+			Self::Code(code) => *code as i32,
+			Self::ModuleUnavailable { .. } => core::StsNotImplemented,
+			Self::Unknown(code) => *code,
 		}
 	}
 }
@@ -21,7 +147,18 @@ impl Error {
 impl fmt::Display for Error {
 	#[inline]
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		write!(f, "{} (code: {})", self.message, self.code)
+		for context in self.context.iter().rev() {
+			write!(f, "{}: ", context)?;
+		}
+		write!(f, "{} (code: {})", self.message, self.code())
+	}
+}
+
+impl From<core::Code> for Error {
+	/// Creates the error with the passed code and its name as the message
+	#[inline]
+	fn from(code: core::Code) -> Self {
+		Self::with_kind(ErrorKind::Code(code), format!("{:?}", code))
 	}
 }
 
@@ -42,3 +179,84 @@ impl From<TryFromCharError> for Error {
 impl std::error::Error for Error {}
 
 pub type Result<T, E = Error> = ::std::result::Result<T, E>;
+
+/// Extension methods for the crate [Result]
+///
+/// The methods are prefixed with `cv_` so that they don't clash with `anyhow::Context` when both traits are imported.
+pub trait ResultExt<T> {
+	/// Adds the context description to the error, see [Error::add_context]
+	fn cv_context(self, context: &str) -> Result<T>;
+
+	/// Adds the lazily evaluated context description to the error, use it when the description is expensive to build
+	fn with_cv_context<C: Into<String>>(self, context: impl FnOnce() -> C) -> Result<T>;
+}
+
+impl<T> ResultExt<T> for Result<T> {
+	#[inline]
+	fn cv_context(self, context: &str) -> Result<T> {
+		self.map_err(|e| e.add_context(context))
+	}
+
+	#[inline]
+	fn with_cv_context<C: Into<String>>(self, context: impl FnOnce() -> C) -> Result<T> {
+		self.map_err(|e| e.add_context(context()))
+	}
+}
+
+const CODES: &[core::Code] = &[
+	core::Code::StsOk,
+	core::Code::StsBackTrace,
+	core::Code::StsError,
+	core::Code::StsInternal,
+	core::Code::StsNoMem,
+	core::Code::StsBadArg,
+	core::Code::StsBadFunc,
+	core::Code::StsNoConv,
+	core::Code::StsAutoTrace,
+	core::Code::HeaderIsNull,
+	core::Code::BadImageSize,
+	core::Code::BadOffset,
+	core::Code::BadDataPtr,
+	core::Code::BadStep,
+	core::Code::BadModelOrChSeq,
+	core::Code::BadNumChannels,
+	core::Code::BadNumChannel1U,
+	core::Code::BadDepth,
+	core::Code::BadAlphaChannel,
+	core::Code::BadOrder,
+	core::Code::BadOrigin,
+	core::Code::BadAlign,
+	core::Code::BadCallBack,
+	core::Code::BadTileSize,
+	core::Code::BadCOI,
+	core::Code::BadROISize,
+	core::Code::MaskIsTiled,
+	core::Code::StsNullPtr,
+	core::Code::StsVecLengthErr,
+	core::Code::StsFilterStructContentErr,
+	core::Code::StsKernelStructContentErr,
+	core::Code::StsFilterOffsetErr,
+	core::Code::StsBadSize,
+	core::Code::StsDivByZero,
+	core::Code::StsInplaceNotSupported,
+	core::Code::StsObjectNotFound,
+	core::Code::StsUnmatchedFormats,
+	core::Code::StsBadFlag,
+	core::Code::StsBadPoint,
+	core::Code::StsBadMask,
+	core::Code::StsUnmatchedSizes,
+	core::Code::StsUnsupportedFormat,
+	core::Code::StsOutOfRange,
+	core::Code::StsParseError,
+	core::Code::StsNotImplemented,
+	core::Code::StsBadMemBlock,
+	core::Code::StsAssert,
+	core::Code::GpuNotSupported,
+	core::Code::GpuApiCallError,
+	core::Code::OpenGlNotSupported,
+	core::Code::OpenGlApiCallError,
+	core::Code::OpenCLApiCallError,
+	core::Code::OpenCLDoubleNotSupported,
+	core::Code::OpenCLInitError,
+	core::Code::OpenCLNoAMDBlasFft,
+];
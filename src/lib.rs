@@ -1,7 +1,7 @@
 #![allow(broken_intra_doc_links)]
 
 pub use cond_macros::*;
-pub use error::{Error, Result};
+pub use error::{Error, ErrorKind, Result, ResultExt};
 
 pub use crate::opencv::hub::*;
 
@@ -11,12 +11,66 @@ mod templ;
 mod error;
 mod manual;
 mod opencv;
-mod traits;
+#[cfg(feature = "unsafe-raw")]
+pub mod raw;
+#[doc(hidden)]
+pub mod traits;
 
+/// All traits of all modules, import it with `use opencv::prelude::*;`
+///
+/// See [prelude::v2] for the granular preludes that import only the traits needed for a specific concern.
 pub mod prelude {
 	#[cfg(ocvrs_has_module_core)]
 	pub use crate::core::{DataType, Mat};
-	pub use crate::{hub_prelude::*, manual::prelude::*, traits::Boxed};
+	pub use crate::{hub_prelude::*, manual::prelude::*, traits::Boxed, ResultExt};
+
+	/// Granular preludes, split by concern and by module
+	///
+	/// The flat [prelude](crate::prelude) brings hundreds of trait names into scope, which can make the method calls
+	/// ambiguous when several traits have a method with the same name. Import only the groups you need instead:
+	/// * [traits](v2::traits) for working with the core types like `Mat` and `Ptr`,
+	/// * [geometry](v2::geometry) for the point, size, rectangle and vector value types,
+	/// * [pipelines](v2::pipelines) for the feature detection, description and matching,
+	/// * `v2::<module>`, e.g. `v2::line_descriptor`, for the generated and the manual extension traits of a single module.
+	///
+	/// ```
+	/// use opencv::prelude::v2::{line_descriptor::*, traits::*};
+	/// ```
+	pub mod v2 {
+		pub use crate::hub_prelude_v2::*;
+
+		/// Traits of the core types and the crate helpers
+		pub mod traits {
+			#[cfg(ocvrs_has_module_core)]
+			pub use crate::core::{DataType, Mat};
+			#[cfg(ocvrs_has_module_core)]
+			pub use crate::hub_prelude_v2::core::*;
+			pub use crate::{traits::Boxed, ResultExt};
+		}
+
+		/// Value types of the geometry, they need no traits for their methods except [MatxTrait](crate::core::MatxTrait)
+		#[cfg(ocvrs_has_module_core)]
+		pub mod geometry {
+			pub use crate::core::{
+				MatxTrait, Point, Point2d, Point2f, Point2i, Point3d, Point3f, Point3i, Rect, Rect2d, Rect2f, Rect2i, RotatedRect,
+				Scalar, Size, Size2d, Size2f, Size2i, Vec2d, Vec2f, Vec2i, Vec3b, Vec3d, Vec3f, Vec3i, Vec4d, Vec4f, Vec4i, VecN,
+			};
+		}
+
+		/// Traits of the feature detection, description and matching, including the core ones and the extra detectors and
+		/// descriptors of `xfeatures2d` (SURF, BEBLID, LATCH, ...)
+		pub mod pipelines {
+			pub use super::traits::*;
+			#[cfg(ocvrs_has_module_features2d)]
+			pub use crate::hub_prelude_v2::features2d::*;
+			#[cfg(ocvrs_has_module_imgproc)]
+			pub use crate::hub_prelude_v2::imgproc::*;
+			#[cfg(ocvrs_has_module_line_descriptor)]
+			pub use crate::hub_prelude_v2::line_descriptor::*;
+			#[cfg(ocvrs_has_module_xfeatures2d)]
+			pub use crate::hub_prelude_v2::xfeatures2d::*;
+		}
+	}
 }
 
 /// Reexported platform types that are used by OpenCV
@@ -28,10 +82,7 @@ pub mod platform_types {
 pub(crate) mod mod_prelude_sys {
 	pub use std::os::raw::{c_char, c_void};
 
-	pub use crate::{
-		platform_types::*,
-		traits::{Boxed, OpenCVType, OpenCVTypeArg, OpenCVTypeExternContainer},
-	};
+	pub use crate::traits::{Boxed, BoxedRef, OpenCVType, OpenCVTypeArg, OpenCVTypeExternContainer};
 }
 
 /// Prelude for generated modules and types
@@ -46,7 +97,7 @@ pub(crate) mod mod_prelude {
 		opencv_type_boxed, opencv_type_enum, opencv_type_simple, ptr_cast_base, ptr_extern, ptr_extern_ctor, tuple_extern,
 		vector_copy_non_bool, vector_extern, vector_non_copy_or_bool, Error, Result,
 	};
-	pub use std::convert::TryFrom;
+	pub use std::{convert::TryFrom, path::Path};
 }
 
 mod cond_macros;
@@ -0,0 +1,57 @@
+use crate::{bgsegm, video::BackgroundSubtractor, Result};
+
+/// Background subtraction algorithm created by [create_background_subtractor]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BackgroundSubtractorKind {
+	/// Gaussian mixture model, see [bgsegm::create_background_subtractor_mog]
+	MOG,
+	/// Per-pixel Bayesian segmentation, see [bgsegm::create_background_subtractor_gmg]
+	GMG,
+	/// Google Summer of Code 2017 algorithm based on the color descriptors, see
+	/// [bgsegm::create_background_subtractor_gsoc]
+	GSOC,
+	/// Counting of the pixel stability, fast on the low-end hardware, see [bgsegm::create_background_subtractor_cnt]
+	CNT,
+	/// Local SVD binary patterns, see [bgsegm::create_background_subtractor_lsbp]
+	LSBP,
+}
+
+/// Creates the background subtractor of the passed kind with the default parameters
+///
+/// The result is used through the common `video::BackgroundSubtractor` interface, use the `create_background_subtractor_*`
+/// functions to tune the parameters of the particular algorithm.
+pub fn create_background_subtractor(kind: BackgroundSubtractorKind) -> Result<Box<dyn BackgroundSubtractor>> {
+	Ok(match kind {
+		BackgroundSubtractorKind::MOG => Box::new(bgsegm::create_background_subtractor_mog(200, 5, 0.7, 0.)?),
+		BackgroundSubtractorKind::GMG => Box::new(bgsegm::create_background_subtractor_gmg(120, 0.8)?),
+		BackgroundSubtractorKind::GSOC => Box::new(bgsegm::create_background_subtractor_gsoc(
+			bgsegm::LSBP_CAMERA_MOTION_COMPENSATION_NONE,
+			20,
+			0.003,
+			0.01,
+			32,
+			0.01,
+			0.0022,
+			0.1,
+			0.1,
+			0.0004,
+			0.0008,
+		)?),
+		BackgroundSubtractorKind::CNT => Box::new(bgsegm::create_background_subtractor_cnt(15, true, 15 * 60, true)?),
+		BackgroundSubtractorKind::LSBP => Box::new(bgsegm::create_background_subtractor_lsbp(
+			bgsegm::LSBP_CAMERA_MOTION_COMPENSATION_NONE,
+			20,
+			16,
+			2.,
+			32.,
+			1.,
+			0.05,
+			10.,
+			0.005,
+			0.0004,
+			0.0008,
+			8,
+			2,
+		)?),
+	})
+}
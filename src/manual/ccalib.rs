@@ -0,0 +1,265 @@
+use std::convert::TryFrom;
+use std::ops::{BitOr, BitOrAssign};
+
+use crate::{
+	ccalib::{self, RandomPatternCornerFinderTrait, RandomPatternGenerator, RandomPatternGeneratorTrait},
+	core::{self, Mat, Point2f, Point3f, Size, TermCriteria, Vec4d, Vector},
+	prelude::*,
+	Error, Result,
+};
+
+/// Calibration flags of [omnidir_calibrate], can be combined with `|`
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct OmnidirCalibFlags(i32);
+
+impl OmnidirCalibFlags {
+	/// All the parameters are estimated from scratch
+	pub const NONE: Self = Self(0);
+	/// The passed camera matrix, xi and distortion are used as the initial estimate
+	pub const USE_GUESS: Self = Self(ccalib::CALIB_USE_GUESS);
+	/// Skew of the camera matrix is kept at its initial value
+	pub const FIX_SKEW: Self = Self(ccalib::CALIB_FIX_SKEW);
+	pub const FIX_K1: Self = Self(ccalib::CALIB_FIX_K1);
+	pub const FIX_K2: Self = Self(ccalib::CALIB_FIX_K2);
+	pub const FIX_P1: Self = Self(ccalib::CALIB_FIX_P1);
+	pub const FIX_P2: Self = Self(ccalib::CALIB_FIX_P2);
+	/// Mirror parameter xi of the CMei's model is kept at its initial value
+	pub const FIX_XI: Self = Self(ccalib::CALIB_FIX_XI);
+	/// Focal lengths are kept at their initial values
+	pub const FIX_GAMMA: Self = Self(ccalib::CALIB_FIX_GAMMA);
+	/// Principal point is kept at its initial value
+	pub const FIX_CENTER: Self = Self(ccalib::CALIB_FIX_CENTER);
+
+	/// Raw value as accepted by the OpenCV functions
+	#[inline]
+	pub fn bits(self) -> i32 {
+		self.0
+	}
+
+	/// Whether all the flags of `other` are set
+	#[inline]
+	pub fn contains(self, other: Self) -> bool {
+		self.0 & other.0 == other.0
+	}
+}
+
+impl BitOr for OmnidirCalibFlags {
+	type Output = Self;
+
+	#[inline]
+	fn bitor(self, rhs: Self) -> Self {
+		Self(self.0 | rhs.0)
+	}
+}
+
+impl BitOrAssign for OmnidirCalibFlags {
+	#[inline]
+	fn bitor_assign(&mut self, rhs: Self) {
+		self.0 |= rhs.0
+	}
+}
+
+impl From<OmnidirCalibFlags> for i32 {
+	#[inline]
+	fn from(s: OmnidirCalibFlags) -> Self {
+		s.bits()
+	}
+}
+
+/// Projection of the undistorted omnidirectional image
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RectifyKind {
+	/// Pinhole projection, only the part of the field of view in front of the camera is kept
+	Perspective,
+	Cylindrical,
+	/// Longitude-latitude (equirectangular) projection of the whole field of view
+	LongLati,
+	Stereographic,
+}
+
+impl From<RectifyKind> for i32 {
+	fn from(s: RectifyKind) -> Self {
+		match s {
+			RectifyKind::Perspective => ccalib::RECTIFY_PERSPECTIVE,
+			RectifyKind::Cylindrical => ccalib::RECTIFY_CYLINDRICAL,
+			RectifyKind::LongLati => ccalib::RECTIFY_LONGLATI,
+			RectifyKind::Stereographic => ccalib::RECTIFY_STEREOGRAPHIC,
+		}
+	}
+}
+
+/// Intrinsics of the omnidirectional camera in the CMei's unified model together with the per view extrinsics
+#[derive(Debug)]
+pub struct OmnidirCalibration {
+	/// RMS reprojection error in pixels
+	pub rms: f64,
+	/// 3x3 `CV_64FC1` camera matrix
+	pub camera_matrix: Mat,
+	/// Mirror parameter of the CMei's model
+	pub xi: f64,
+	/// Distortion coefficients (k1, k2, p1, p2)
+	pub distortion: Vec4d,
+	/// Rodrigues rotation of every used view
+	pub rvecs: Vector<Mat>,
+	/// Translation of every used view
+	pub tvecs: Vector<Mat>,
+	/// Indices of the passed views that passed the initialization and were used for the calibration, `rvecs` and
+	/// `tvecs` correspond to these views
+	pub used_views: Vec<usize>,
+}
+
+/// Calibrates the omnidirectional (fisheye, catadioptric) camera from the pattern views
+///
+/// `object_points` are the pattern points of every view in the pattern coordinate system, `image_points` are the
+/// corresponding detected points.
+pub fn omnidir_calibrate(
+	object_points: &Vector<Vector<Point3f>>,
+	image_points: &Vector<Vector<Point2f>>,
+	image_size: Size,
+	flags: OmnidirCalibFlags,
+	criteria: TermCriteria,
+) -> Result<OmnidirCalibration> {
+	if object_points.len() != image_points.len() {
+		return Err(Error::new(
+			core::StsUnmatchedSizes,
+			format!(
+				"Number of the object point views ({}) doesn't match the number of the image point views ({})",
+				object_points.len(),
+				image_points.len()
+			),
+		));
+	}
+	let mut camera_matrix = Mat::default();
+	let mut xi = Mat::default();
+	let mut distortion = Mat::default();
+	let mut rvecs = Vector::<Mat>::new();
+	let mut tvecs = Vector::<Mat>::new();
+	let mut idx = Mat::default();
+	let rms = ccalib::calibrate(
+		object_points,
+		image_points,
+		image_size,
+		&mut camera_matrix,
+		&mut xi,
+		&mut distortion,
+		&mut rvecs,
+		&mut tvecs,
+		flags.bits(),
+		criteria,
+		Some(&mut idx),
+	)?;
+	let distortion = <[f64; 4]>::try_from(distortion.data_typed::<f64>()?).map_err(|_| {
+		Error::new(
+			core::StsBadSize,
+			format!("Expected 4 distortion coefficients, but got: {}", distortion.total()),
+		)
+	})?;
+	let used_views = idx
+		.data_typed::<i32>()?
+		.iter()
+		.filter_map(|&i| usize::try_from(i).ok())
+		.collect();
+	Ok(OmnidirCalibration {
+		rms,
+		camera_matrix,
+		xi: *xi.at::<f64>(0)?,
+		distortion: Vec4d::from(distortion),
+		rvecs,
+		tvecs,
+		used_views,
+	})
+}
+
+impl OmnidirCalibration {
+	/// Projects the points from the coordinate system of the view with the passed extrinsics to the image
+	pub fn project_points(&self, object_points: &Vector<Point3f>, rvec: &Mat, tvec: &Mat) -> Result<Vector<Point2f>> {
+		let mut out = Vector::new();
+		ccalib::project_points(
+			object_points,
+			&mut out,
+			rvec,
+			tvec,
+			&self.camera_matrix,
+			self.xi,
+			&self.distortion,
+			None::<&mut Mat>,
+		)?;
+		Ok(out)
+	}
+
+	/// Undistorts the image points to the normalized object points
+	pub fn undistort_points(&self, distorted: &Vector<Point2f>) -> Result<Vector<Point2f>> {
+		let mut out = Vector::new();
+		ccalib::undistort_points(
+			distorted,
+			&mut out,
+			&self.camera_matrix,
+			&self.distortion,
+			&Mat::from_slice(&[self.xi])?,
+			&Mat::default(),
+		)?;
+		Ok(out)
+	}
+
+	/// Undistorts the image to the passed projection
+	///
+	/// `new_camera_matrix` is the camera matrix of the output image, it sets the scale and the center of the
+	/// projection, `None` keeps the calibrated one.
+	pub fn undistort_image(
+		&self,
+		distorted: &Mat,
+		kind: RectifyKind,
+		new_camera_matrix: Option<&Mat>,
+		new_size: Size,
+	) -> Result<Mat> {
+		let mut out = Mat::default();
+		ccalib::undistort_image(
+			distorted,
+			&mut out,
+			&self.camera_matrix,
+			&self.distortion,
+			&Mat::from_slice(&[self.xi])?,
+			kind.into(),
+			Some(new_camera_matrix.unwrap_or(&self.camera_matrix)),
+			new_size,
+			&Mat::default(),
+		)?;
+		Ok(out)
+	}
+}
+
+/// Generates the random pattern image for the calibration with [RandomPatternCornerFinderTraitManual]
+///
+/// Returns `CV_8UC1` image of the passed size in pixels, print it and pass its measured physical size to
+/// `RandomPatternCornerFinder`.
+pub fn generate_random_pattern(width: i32, height: i32) -> Result<Mat> {
+	let mut generator = RandomPatternGenerator::new(width, height)?;
+	generator.generate_pattern()?;
+	generator.get_pattern()
+}
+
+/// Point correspondences of the single image of the random pattern
+#[derive(Debug)]
+pub struct RandomPatternPoints {
+	/// Detected points, `N`x1 `CV_32FC2`
+	pub image_points: Mat,
+	/// Corresponding 3D points on the pattern plane, `N`x1 `CV_32FC3`
+	pub object_points: Mat,
+}
+
+/// Single image matching for the random pattern calibration
+pub trait RandomPatternCornerFinderTraitManual: RandomPatternCornerFinderTrait {
+	/// Matches the loaded pattern in the image without accumulating the points in the finder
+	///
+	/// The result is empty if the pattern couldn't be matched.
+	fn find_pattern_points(&mut self, image: Mat) -> Result<RandomPatternPoints> {
+		// OpenCV returns the image points followed by the object points
+		let points = self.compute_object_image_points_for_single(image)?;
+		Ok(RandomPatternPoints {
+			image_points: points.get(0)?,
+			object_points: points.get(1)?,
+		})
+	}
+}
+
+impl<T: RandomPatternCornerFinderTrait + ?Sized> RandomPatternCornerFinderTraitManual for T {}
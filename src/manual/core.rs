@@ -1,36 +1,65 @@
 pub use affine3::*;
+pub use algorithm::*;
 pub use data_type::*;
+pub use error_handler::*;
+pub use features::*;
+pub use file_storage::*;
+pub use frame_cache::*;
 pub use gpumat::*;
 pub use input_output_array::*;
 pub use mat::*;
 pub use mat_ops::*;
+pub use match_filter::*;
 pub use matx::*;
+pub use min_problem_solver::*;
+pub use modules::*;
+pub use parallel::*;
 pub use point::*;
 pub use point3::*;
 pub use ptr::*;
 pub use rect::*;
+pub use returns::*;
 pub use scalar::*;
 pub use size::*;
 pub use sized::*;
+pub use stream::*;
 pub use tuple::*;
+pub use umat::*;
 pub use vec::*;
 pub use vector::*;
 pub use CV_MAKETYPE as CV_MAKE_TYPE;
 
+pub use crate::traits::BoxedRef;
+
 mod affine3;
+mod algorithm;
 mod data_type;
+mod error_handler;
+mod features;
+mod file_storage;
+mod frame_cache;
 mod gpumat;
 mod input_output_array;
 mod mat;
 mod mat_ops;
+mod match_filter;
 mod matx;
+mod min_problem_solver;
+mod modules;
+mod npy;
+mod parallel;
 mod point;
 mod point3;
 pub(crate) mod ptr;
 mod rect;
+mod returns;
 mod scalar;
+#[cfg(feature = "serde")]
+pub(crate) mod serde_support;
 mod size;
 mod sized;
+mod stream;
 mod tuple;
+mod umat;
 mod vec;
 mod vector;
@@ -0,0 +1,99 @@
+use std::{
+	ops::{Deref, DerefMut},
+	sync::{Mutex, PoisonError},
+};
+
+use crate::{
+	core::{FileStorage, MemoryStorage, StorageFormat},
+	prelude::*,
+	Result,
+};
+
+/// Creates a copy of the configured algorithm by serializing its parameters and reading them into a new instance
+///
+/// `create` must construct a fresh instance of the same algorithm type, e.g. `BinaryDescriptor::create_binary_descriptor`.
+/// Only the state that the algorithm stores in its `write()` is copied, that is usually the parameters, but not the
+/// trained or accumulated data. This allows independent copies of detectors and matchers to be used from separate
+/// threads.
+pub fn clone_algorithm<T: AlgorithmTrait>(src: &T, create: impl FnOnce() -> Result<T>) -> Result<T> {
+	let serialized = MemoryStorage::serialize(StorageFormat::Yaml, |fs| src.write(fs))?;
+	let storage = FileStorage::from_string(&serialized)?;
+	let mut out = create()?;
+	out.read(&storage.root(0)?)?;
+	Ok(out)
+}
+
+/// Pool of the configured algorithm instances for the parallel processing
+///
+/// The instances are created lazily with [clone_algorithm] from the prototype, so there are never more of them than
+/// the threads that use the pool at the same time. [DetectorPool::checkout] gives exclusive access to an instance,
+/// allowing to call the `&mut self` methods, the instance is returned to the pool when the guard is dropped.
+pub struct DetectorPool<T> {
+	prototype: Mutex<T>,
+	create: Box<dyn Fn() -> Result<T> + Send + Sync>,
+	idle: Mutex<Vec<T>>,
+	created: Mutex<usize>,
+}
+
+impl<T: AlgorithmTrait + Send> DetectorPool<T> {
+	/// Creates the pool, `create` must construct a fresh instance of the same algorithm type as `prototype`
+	pub fn new(prototype: T, create: impl Fn() -> Result<T> + Send + Sync + 'static) -> Self {
+		Self {
+			prototype: Mutex::new(prototype),
+			create: Box::new(create),
+			idle: Mutex::new(vec![]),
+			created: Mutex::new(0),
+		}
+	}
+
+	/// Takes an idle instance from the pool or creates a new one if all of them are in use
+	pub fn checkout(&self) -> Result<PooledAlgorithm<'_, T>> {
+		let idle = self.idle.lock().unwrap_or_else(PoisonError::into_inner).pop();
+		let algorithm = match idle {
+			Some(algorithm) => algorithm,
+			None => {
+				let prototype = self.prototype.lock().unwrap_or_else(PoisonError::into_inner);
+				let out = clone_algorithm(&*prototype, &self.create)?;
+				*self.created.lock().unwrap_or_else(PoisonError::into_inner) += 1;
+				out
+			}
+		};
+		Ok(PooledAlgorithm {
+			pool: self,
+			algorithm: Some(algorithm),
+		})
+	}
+
+	/// Number of the instances created by the pool so far
+	pub fn created(&self) -> usize {
+		*self.created.lock().unwrap_or_else(PoisonError::into_inner)
+	}
+}
+
+/// Instance checked out from the [DetectorPool], returned to the pool on drop
+pub struct PooledAlgorithm<'p, T> {
+	pool: &'p DetectorPool<T>,
+	algorithm: Option<T>,
+}
+
+impl<T> Deref for PooledAlgorithm<'_, T> {
+	type Target = T;
+
+	fn deref(&self) -> &Self::Target {
+		self.algorithm.as_ref().expect("Pooled algorithm is only taken on drop")
+	}
+}
+
+impl<T> DerefMut for PooledAlgorithm<'_, T> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.algorithm.as_mut().expect("Pooled algorithm is only taken on drop")
+	}
+}
+
+impl<T> Drop for PooledAlgorithm<'_, T> {
+	fn drop(&mut self) {
+		if let Some(algorithm) = self.algorithm.take() {
+			self.pool.idle.lock().unwrap_or_else(PoisonError::into_inner).push(algorithm);
+		}
+	}
+}
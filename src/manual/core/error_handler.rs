@@ -0,0 +1,121 @@
+use std::{
+	cell::Cell,
+	ffi::{c_void, CStr},
+	os::raw::c_char,
+	sync::Mutex,
+};
+
+use once_cell::sync::Lazy;
+
+use crate::{core, sys, templ::callback_abort_on_panic, Error, Result};
+
+/// Information about an OpenCV error passed to the handler installed with [redirect_error]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ErrorInfo {
+	/// Error code, one of the `core::Sts*` constants
+	pub status: i32,
+	/// Name of the function where the error happened
+	pub func: String,
+	/// Error description
+	pub message: String,
+	/// Source file where the error happened
+	pub file: String,
+	/// Line in the source file where the error happened
+	pub line: i32,
+}
+
+type ErrorHandler = Box<dyn FnMut(&ErrorInfo) + Send>;
+
+static ERROR_HANDLER: Lazy<Mutex<Option<ErrorHandler>>> = Lazy::new(|| Mutex::new(None));
+
+thread_local! {
+	/// Set while the handler runs in the current thread
+	static IN_HANDLER: Cell<bool> = Cell::new(false);
+}
+
+type ErrorCallback = unsafe extern "C" fn(i32, *const c_char, *const c_char, *const c_char, i32, *mut c_void) -> i32;
+
+extern "C" {
+	fn cv_manual_redirectError(callback: Option<ErrorCallback>, ocvrs_return: *mut sys::Result_void);
+}
+
+unsafe fn string_from_ptr(s: *const c_char) -> String {
+	if s.is_null() {
+		String::new()
+	} else {
+		CStr::from_ptr(s).to_string_lossy().into_owned()
+	}
+}
+
+unsafe extern "C" fn error_callback_trampoline(
+	status: i32,
+	func_name: *const c_char,
+	err_msg: *const c_char,
+	file_name: *const c_char,
+	line: i32,
+	_userdata: *mut c_void,
+) -> i32 {
+	let info = ErrorInfo {
+		status,
+		func: string_from_ptr(func_name),
+		message: string_from_ptr(err_msg),
+		file: string_from_ptr(file_name),
+		line,
+	};
+	// the errors raised by the handler itself are not passed to it, locking the handler again would deadlock
+	if IN_HANDLER.with(Cell::get) {
+		return 0;
+	}
+	// the other threads wait for the handler to finish and then call it with their errors
+	let mut handler = ERROR_HANDLER.lock().unwrap_or_else(|e| e.into_inner());
+	if let Some(handler) = handler.as_mut() {
+		IN_HANDLER.with(|in_handler| in_handler.set(true));
+		// a panic aborts the process, so the flag is always reset
+		callback_abort_on_panic(|| handler(&info));
+		IN_HANDLER.with(|in_handler| in_handler.set(false));
+	}
+	0
+}
+
+/// The handler is locked while it runs, changing it from the inside would deadlock
+fn ensure_not_in_handler() -> Result<()> {
+	if IN_HANDLER.with(Cell::get) {
+		Err(Error::new(
+			core::StsError,
+			"Error handler can't be changed from inside the handler",
+		))
+	} else {
+		Ok(())
+	}
+}
+
+fn set_error_callback(callback: Option<ErrorCallback>) -> Result<()> {
+	return_send!(via ocvrs_return);
+	unsafe { cv_manual_redirectError(callback, ocvrs_return.as_mut_ptr()) };
+	return_receive!(unsafe ocvrs_return => ret);
+	ret.into_result()
+}
+
+/// Installs the handler that is called for every error raised by OpenCV
+///
+/// The handler is called right before the error is returned as `Err` from the failing function, so it's suitable for
+/// logging or collecting metrics, the error itself is still reported as usual. OpenCV errors can happen in any
+/// thread, so the handler must be `Send`. The previously installed handler is dropped. The handler is not called for
+/// the errors raised by OpenCV functions that the handler itself calls. The errors raised concurrently in the other
+/// threads wait for the running handler to return. The handler has no way to report a failure, so a panic in it aborts
+/// the process. Changing the handler from inside the handler fails with `StsError`.
+pub fn redirect_error(handler: impl FnMut(&ErrorInfo) + Send + 'static) -> Result<()> {
+	ensure_not_in_handler()?;
+	let mut current = ERROR_HANDLER.lock().unwrap_or_else(|e| e.into_inner());
+	*current = Some(Box::new(handler));
+	drop(current);
+	set_error_callback(Some(error_callback_trampoline))
+}
+
+/// Removes the handler installed with [redirect_error] and restores the default OpenCV error reporting
+pub fn reset_error_handler() -> Result<()> {
+	ensure_not_in_handler()?;
+	set_error_callback(None)?;
+	*ERROR_HANDLER.lock().unwrap_or_else(|e| e.into_inner()) = None;
+	Ok(())
+}
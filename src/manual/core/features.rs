@@ -0,0 +1,62 @@
+use std::hash::{Hash, Hasher};
+
+use crate::core::{DMatch, KeyPoint};
+
+/// Wrapper that compares and hashes the value by the bit patterns of its fields, use it to put `KeyPoint`, `DMatch` or
+/// `KeyLine` into the `HashMap` or `HashSet`
+///
+/// The float fields make the wrapped types only `PartialEq`, the wrapper is `Eq` because the bit patterns are compared
+/// instead of the float values: `NaN` is equal to itself and `0.` is not equal to `-0.`.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct BitwiseKey<T>(pub T);
+
+impl BitwiseKey<DMatch> {
+	fn bits(&self) -> (i32, i32, i32, u32) {
+		(self.0.query_idx, self.0.train_idx, self.0.img_idx, self.0.distance.to_bits())
+	}
+}
+
+impl PartialEq for BitwiseKey<DMatch> {
+	fn eq(&self, other: &Self) -> bool {
+		self.bits() == other.bits()
+	}
+}
+
+impl Eq for BitwiseKey<DMatch> {}
+
+impl Hash for BitwiseKey<DMatch> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.bits().hash(state);
+	}
+}
+
+impl BitwiseKey<KeyPoint> {
+	fn bits(&self) -> ([u32; 5], i32, i32) {
+		let kp = &self.0;
+		(
+			[
+				kp.pt.x.to_bits(),
+				kp.pt.y.to_bits(),
+				kp.size.to_bits(),
+				kp.angle.to_bits(),
+				kp.response.to_bits(),
+			],
+			kp.octave,
+			kp.class_id,
+		)
+	}
+}
+
+impl PartialEq for BitwiseKey<KeyPoint> {
+	fn eq(&self, other: &Self) -> bool {
+		self.bits() == other.bits()
+	}
+}
+
+impl Eq for BitwiseKey<KeyPoint> {}
+
+impl Hash for BitwiseKey<KeyPoint> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.bits().hash(state);
+	}
+}
@@ -0,0 +1,81 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::{
+	core::{FileStorage, FileStorage_Mode},
+	prelude::*,
+	Result,
+};
+
+/// Text format of the [MemoryStorage]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StorageFormat {
+	Xml,
+	Yaml,
+	Json,
+}
+
+impl StorageFormat {
+	fn mode(self) -> FileStorage_Mode {
+		match self {
+			StorageFormat::Xml => FileStorage_Mode::FORMAT_XML,
+			StorageFormat::Yaml => FileStorage_Mode::FORMAT_YAML,
+			StorageFormat::Json => FileStorage_Mode::FORMAT_JSON,
+		}
+	}
+}
+
+impl FileStorage {
+	/// Opens the serialized storage content for reading, the format is detected from the content
+	pub fn from_string(content: &str) -> Result<FileStorage> {
+		FileStorage::new(content, FileStorage_Mode::READ as i32 | FileStorage_Mode::MEMORY as i32, "")
+	}
+}
+
+/// [FileStorage] that writes to the internal buffer instead of a file
+///
+/// Dereferences to the underlying [FileStorage] for writing, the serialized content is obtained with
+/// [MemoryStorage::into_string] and can be read back with [FileStorage::from_string].
+pub struct MemoryStorage {
+	storage: FileStorage,
+}
+
+impl MemoryStorage {
+	pub fn new(format: StorageFormat) -> Result<Self> {
+		let flags = FileStorage_Mode::WRITE as i32 | FileStorage_Mode::MEMORY as i32 | format.mode() as i32;
+		FileStorage::new("", flags, "").map(|storage| Self { storage })
+	}
+
+	/// Writes the content with the `write` callback and returns the serialized string
+	///
+	/// Convenient with the `write()` methods of the algorithms and their parameters:
+	/// ```no_run
+	/// # use opencv::{core::{MemoryStorage, StorageFormat}, prelude::*, Result};
+	/// # fn serialize(algorithm: &impl AlgorithmTraitConst) -> Result<String> {
+	/// MemoryStorage::serialize(StorageFormat::Yaml, |fs| algorithm.write(fs))
+	/// # }
+	/// ```
+	pub fn serialize(format: StorageFormat, write: impl FnOnce(&mut FileStorage) -> Result<()>) -> Result<String> {
+		let mut out = Self::new(format)?;
+		write(&mut out)?;
+		out.into_string()
+	}
+
+	/// Closes the storage and returns the serialized content
+	pub fn into_string(mut self) -> Result<String> {
+		self.storage.release_and_get_string()
+	}
+}
+
+impl Deref for MemoryStorage {
+	type Target = FileStorage;
+
+	fn deref(&self) -> &Self::Target {
+		&self.storage
+	}
+}
+
+impl DerefMut for MemoryStorage {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.storage
+	}
+}
@@ -0,0 +1,133 @@
+use std::{
+	collections::{BTreeMap, HashMap},
+	hash::Hash,
+};
+
+use crate::{core::Mat, prelude::*, Result};
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+	for &b in bytes {
+		hash ^= u64::from(b);
+		hash = hash.wrapping_mul(FNV_PRIME);
+	}
+	hash
+}
+
+/// Hash of the array content: type, size and element data
+///
+/// The hash is stable between the program runs, so it can also be used as a persistent key. Non-continuous arrays are
+/// copied into a continuous buffer first.
+pub fn content_hash(m: &Mat) -> Result<u64> {
+	let mut hash = fnv1a(FNV_OFFSET, &m.typ().to_le_bytes());
+	for dim in m.mat_size().iter() {
+		hash = fnv1a(hash, &dim.to_le_bytes());
+	}
+	if m.empty() {
+		return Ok(hash);
+	}
+	if m.is_continuous() {
+		Ok(fnv1a(hash, m.data_bytes()?))
+	} else {
+		Ok(fnv1a(hash, m.try_clone()?.data_bytes()?))
+	}
+}
+
+/// Counters of the [FrameCache] usage
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CacheMetrics {
+	pub hits: u64,
+	pub misses: u64,
+	pub evictions: u64,
+	/// Number of the currently cached results
+	pub entries: usize,
+}
+
+impl CacheMetrics {
+	/// Share of the lookups that were served from the cache, 0 if there were no lookups
+	pub fn hit_rate(&self) -> f64 {
+		let total = self.hits + self.misses;
+		if total == 0 {
+			0.
+		} else {
+			self.hits as f64 / total as f64
+		}
+	}
+}
+
+/// Memoization of the expensive per-image results with LRU eviction
+///
+/// The results are keyed by the [content_hash] of the image and the parameters `K` they were computed with, so the
+/// same image loaded again or decoded from a different file still hits the cache. Suitable for descriptors,
+/// undistortion maps, pyramids and other results that are deterministic for the image and the parameters.
+pub struct FrameCache<K, V> {
+	capacity: usize,
+	entries: HashMap<(u64, K), (V, u64)>,
+	recency: BTreeMap<u64, (u64, K)>,
+	tick: u64,
+	metrics: CacheMetrics,
+}
+
+impl<K: Hash + Eq + Clone, V> FrameCache<K, V> {
+	/// Creates the cache that holds up to `capacity` results, at least 1
+	pub fn new(capacity: usize) -> Self {
+		Self {
+			capacity: capacity.max(1),
+			entries: HashMap::new(),
+			recency: BTreeMap::new(),
+			tick: 0,
+			metrics: CacheMetrics::default(),
+		}
+	}
+
+	/// Returns the cached result for the image and parameters or computes it with `compute` and caches it
+	///
+	/// The errors of `compute` are passed through and nothing is cached in that case.
+	pub fn get_or_try_insert_with(&mut self, image: &Mat, params: K, compute: impl FnOnce() -> Result<V>) -> Result<&V> {
+		let key = (content_hash(image)?, params);
+		self.tick += 1;
+		let tick = self.tick;
+		if let Some((_, last_used)) = self.entries.get_mut(&key) {
+			self.recency.remove(last_used);
+			*last_used = tick;
+			self.recency.insert(tick, key.clone());
+			self.metrics.hits += 1;
+		} else {
+			self.metrics.misses += 1;
+			let val = compute()?;
+			while self.entries.len() >= self.capacity {
+				self.evict_oldest();
+			}
+			self.recency.insert(tick, key.clone());
+			self.entries.insert(key.clone(), (val, tick));
+			self.metrics.entries = self.entries.len();
+		}
+		Ok(&self.entries[&key].0)
+	}
+
+	/// Cached result for the image and parameters, doesn't count as a lookup and doesn't update the recency
+	pub fn peek(&self, image: &Mat, params: K) -> Result<Option<&V>> {
+		Ok(self.entries.get(&(content_hash(image)?, params)).map(|(val, _)| val))
+	}
+
+	pub fn metrics(&self) -> CacheMetrics {
+		self.metrics
+	}
+
+	/// Drops all cached results, the counters are preserved
+	pub fn clear(&mut self) {
+		self.entries.clear();
+		self.recency.clear();
+		self.metrics.entries = 0;
+	}
+
+	fn evict_oldest(&mut self) {
+		let oldest = self.recency.keys().next().copied();
+		if let Some(key) = oldest.and_then(|tick| self.recency.remove(&tick)) {
+			self.entries.remove(&key);
+			self.metrics.evictions += 1;
+		}
+	}
+}
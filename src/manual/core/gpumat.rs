@@ -1,6 +1,8 @@
 use crate::{
-	core::{GpuMat, HostMem},
-	input_output_array, Result,
+	core::{self, DeviceInfo, GpuMat, HostMem, Mat},
+	input_output_array,
+	prelude::*,
+	Result,
 };
 
 impl GpuMat {
@@ -8,7 +10,46 @@ impl GpuMat {
 	pub fn default() -> Result<Self> {
 		unsafe { Self::new(&mut Self::default_allocator()?) }
 	}
+
+	/// Uploads the `Mat` data to the new `GpuMat` on the current device
+	#[inline]
+	pub fn from_mat(mat: &Mat) -> Result<Self> {
+		let mut out = Self::default()?;
+		out.upload(mat)?;
+		Ok(out)
+	}
+}
+
+pub trait GpuMatTraitConstManual: GpuMatTraitConst {
+	/// Downloads the data to the new `Mat`
+	#[inline]
+	fn to_mat(&self) -> Result<Mat> {
+		let mut out = Mat::default();
+		self.download(&mut out)?;
+		Ok(out)
+	}
 }
 
+impl<T: GpuMatTraitConst> GpuMatTraitConstManual for T {}
+
+/// Information about all the CUDA devices usable by OpenCV
+///
+/// Returns an empty list if OpenCV is built without CUDA or there is no CUDA capable device or driver.
+pub fn cuda_devices() -> Result<Vec<DeviceInfo>> {
+	// the count is -1 if the driver is missing or incompatible
+	let count = core::get_cuda_enabled_device_count()?.max(0);
+	(0..count).map(DeviceInfo::new).collect()
+}
+
+pub trait DeviceInfoTraitConstManual: DeviceInfoTraitConst {
+	/// Major and minor version of the compute capability
+	#[inline]
+	fn compute_capability(&self) -> Result<(i32, i32)> {
+		Ok((self.major_version()?, self.minor_version()?))
+	}
+}
+
+impl<T: DeviceInfoTraitConst> DeviceInfoTraitConstManual for T {}
+
 input_output_array! { GpuMat, from_gpumat, from_gpumat_mut }
 input_output_array! { HostMem, from_hostmem, from_hostmem_mut }
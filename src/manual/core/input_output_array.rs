@@ -1,10 +1,12 @@
-use std::os::raw::c_void;
+use std::{convert::TryFrom, os::raw::c_void};
 
 use crate::{
-	core::{_InputArray, _InputArrayTrait, _InputOutputArray, _InputOutputArrayTrait, _OutputArray, _OutputArrayTrait},
+	core::{
+		self, _InputArray, _InputArrayTrait, _InputOutputArray, _InputOutputArrayTrait, _OutputArray, _OutputArrayTrait, DataType,
+	},
 	input_output_array, sys,
-	traits::Boxed,
-	Result,
+	traits::{Boxed, BoxedRef},
+	Error, Result,
 };
 
 /// Trait to serve as a replacement for `InputArray` in C++ OpenCV
@@ -19,6 +21,23 @@ pub trait ToInputArray {
 
 input_output_array! { f64, from_f64 }
 
+macro_rules! scalar_input_array {
+	($($type: ty),+) => {
+		$(
+			impl ToInputArray for $type {
+				#[inline]
+				fn input_array(&self) -> Result<_InputArray> {
+					std::slice::from_ref(self).input_array()
+				}
+			}
+
+			crate::input_array_ref_forward! { $type }
+		)+
+	};
+}
+
+scalar_input_array! { f32, i32, u8, i8, u16, i16 }
+
 /// Trait to serve as a replacement for `OutputArray` in C++ OpenCV
 ///
 /// You can pass reference to the type implementing this trait everywhere where OpenCV API expects
@@ -78,6 +97,127 @@ impl<T: _InputOutputArrayTrait> ToInputOutputArray for T {
 	}
 }
 
+/// Length of the slice as the number of columns of the single row array
+fn slice_len<T>(slice: &[T]) -> Result<i32> {
+	i32::try_from(slice.len()).map_err(|_| {
+		Error::new(
+			core::StsOutOfRange,
+			format!("Slice is too long to be passed as an array: {}", slice.len()),
+		)
+	})
+}
+
+/// Contiguous data is passed as a single row array of the fixed size, OpenCV can't reallocate it
+impl<T: DataType> ToInputArray for [T] {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		extern "C" {
+			fn cv_manual_InputArray_from_data(typ: i32, data: *const c_void, n: i32, ocvrs_return: *mut sys::Result<*mut c_void>);
+		}
+		let n = slice_len(self)?;
+		return_send!(via ocvrs_return);
+		unsafe { cv_manual_InputArray_from_data(T::typ(), self.as_ptr().cast(), n, ocvrs_return.as_mut_ptr()) }
+		return_receive!(unsafe ocvrs_return => ret);
+		ret.into_result().map(|ptr| unsafe { _InputArray::from_raw(ptr) })
+	}
+}
+
+impl<T: DataType> ToOutputArray for [T] {
+	#[inline]
+	fn output_array(&mut self) -> Result<_OutputArray> {
+		extern "C" {
+			fn cv_manual_OutputArray_from_data(typ: i32, data: *mut c_void, n: i32, ocvrs_return: *mut sys::Result<*mut c_void>);
+		}
+		let n = slice_len(self)?;
+		return_send!(via ocvrs_return);
+		unsafe { cv_manual_OutputArray_from_data(T::typ(), self.as_mut_ptr().cast(), n, ocvrs_return.as_mut_ptr()) }
+		return_receive!(unsafe ocvrs_return => ret);
+		ret.into_result().map(|ptr| unsafe { _OutputArray::from_raw(ptr) })
+	}
+}
+
+impl<T: DataType> ToInputOutputArray for [T] {
+	#[inline]
+	fn input_output_array(&mut self) -> Result<_InputOutputArray> {
+		extern "C" {
+			fn cv_manual_InputOutputArray_from_data(
+				typ: i32,
+				data: *mut c_void,
+				n: i32,
+				ocvrs_return: *mut sys::Result<*mut c_void>,
+			);
+		}
+		let n = slice_len(self)?;
+		return_send!(via ocvrs_return);
+		unsafe { cv_manual_InputOutputArray_from_data(T::typ(), self.as_mut_ptr().cast(), n, ocvrs_return.as_mut_ptr()) }
+		return_receive!(unsafe ocvrs_return => ret);
+		ret.into_result().map(|ptr| unsafe { _InputOutputArray::from_raw(ptr) })
+	}
+}
+
+macro_rules! slice_input_output_array {
+	($type: ty, $($gen: tt)*) => {
+		impl<$($gen)*> ToInputArray for $type {
+			#[inline]
+			fn input_array(&self) -> Result<_InputArray> {
+				self.as_slice().input_array()
+			}
+		}
+
+		impl<$($gen)*> ToOutputArray for $type {
+			#[inline]
+			fn output_array(&mut self) -> Result<_OutputArray> {
+				self.as_mut_slice().output_array()
+			}
+		}
+
+		impl<$($gen)*> ToInputOutputArray for $type {
+			#[inline]
+			fn input_output_array(&mut self) -> Result<_InputOutputArray> {
+				self.as_mut_slice().input_output_array()
+			}
+		}
+	};
+}
+
+slice_input_output_array! { [T; N], T: DataType, const N: usize }
+slice_input_output_array! { Vec<T>, T: DataType }
+
+impl<T: DataType> ToInputArray for &[T] {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		(*self).input_array()
+	}
+}
+
+impl<T: DataType> ToOutputArray for &mut [T] {
+	#[inline]
+	fn output_array(&mut self) -> Result<_OutputArray> {
+		(*self).output_array()
+	}
+}
+
+impl<T: DataType> ToInputOutputArray for &mut [T] {
+	#[inline]
+	fn input_output_array(&mut self) -> Result<_InputOutputArray> {
+		(*self).input_output_array()
+	}
+}
+
+impl<T: Boxed + ToInputArray> ToInputArray for BoxedRef<'_, T> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		(**self).input_array()
+	}
+}
+
+impl<T: Boxed + ToInputArray> ToInputArray for &BoxedRef<'_, T> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		(**self).input_array()
+	}
+}
+
 #[macro_export]
 macro_rules! input_output_array {
 	($type: ty, $const_cons: ident) => {
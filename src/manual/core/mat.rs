@@ -3,9 +3,8 @@ use std::{convert::TryInto, ffi::c_void, fmt, marker::PhantomData, ops::Deref, s
 pub use mat_::*;
 
 use crate::{
-	core::{self, MatConstIterator, MatExpr, MatSize, MatStep, Point, Scalar, UMat},
+	core::{self, BoxedRef, MatConstIterator, MatExpr, MatSize, MatStep, Point, Scalar},
 	input_output_array,
-	platform_types::size_t,
 	prelude::*,
 	sys, Error, Result,
 };
@@ -518,6 +517,9 @@ impl<T: MatTrait + ?Sized> MatTraitManual for T {}
 
 input_output_array! { Mat, from_mat, from_mat_mut }
 
+/// Borrowed `Mat` owned by some other object, see [BoxedRef]
+pub type MatRef<'r> = BoxedRef<'r, Mat>;
+
 impl fmt::Debug for Mat {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		let typ = self.typ();
@@ -544,23 +546,6 @@ impl fmt::Debug for Mat {
 	}
 }
 
-pub trait UMatTraitConstManual: UMatTraitConst {
-	#[inline]
-	fn size(&self) -> Result<core::Size> {
-		extern "C" {
-			fn cv_manual_UMat_size(instance: *const c_void, ocvrs_return: *mut sys::Result<core::Size>);
-		}
-		return_send!(via ocvrs_return);
-		unsafe { cv_manual_UMat_size(self.as_raw_UMat(), ocvrs_return.as_mut_ptr()) }
-		return_receive!(unsafe ocvrs_return => ret);
-		ret.into_result()
-	}
-}
-
-impl<T: UMatTraitConst> UMatTraitConstManual for T {}
-
-input_output_array! { UMat, from_umat, from_umat_mut }
-
 #[cfg(ocvrs_opencv_branch_32)]
 pub trait MatSizeTraitConstManual: MatSizeTraitConst {
 	#[inline]
@@ -591,12 +576,12 @@ impl fmt::Debug for MatSize {
 }
 
 impl Deref for MatStep {
-	type Target = [size_t];
+	type Target = [usize];
 
 	#[inline]
 	fn deref(&self) -> &Self::Target {
 		extern "C" {
-			fn cv_manual_MatStep_deref(instance: *const c_void) -> *const size_t;
+			fn cv_manual_MatStep_deref(instance: *const c_void) -> *const usize;
 		}
 		let ptr = unsafe { cv_manual_MatStep_deref(self.as_raw_MatStep()) };
 		unsafe { slice::from_raw_parts(ptr, 2) }
@@ -0,0 +1,44 @@
+use std::collections::HashMap;
+
+use crate::{
+	core::{self, DMatch, Vector},
+	Error, Result,
+};
+
+/// Filters the k-nearest neighbor matches with the Lowe's ratio test
+///
+/// The best match of every query is kept only if its distance is less than `ratio` times the distance of the second
+/// best one, which rejects the ambiguous matches. The queries with a single neighbor can't be tested and their match is
+/// kept, the queries without neighbors are skipped. `ratio` must be in the range `(0, 1]`, the usual values are between
+/// 0.7 and 0.8.
+pub fn ratio_test(knn_matches: &Vector<Vector<DMatch>>, ratio: f32) -> Result<Vector<DMatch>> {
+	if !(ratio > 0. && ratio <= 1.) {
+		return Err(Error::new(
+			core::StsOutOfRange,
+			format!("Ratio must be in the range (0, 1], but got: {}", ratio),
+		));
+	}
+	Ok(knn_matches
+		.iter()
+		.filter_map(|neighbors| {
+			let best = neighbors.get(0).ok()?;
+			match neighbors.get(1) {
+				Ok(second) if best.distance >= ratio * second.distance => None,
+				_ => Some(best),
+			}
+		})
+		.collect())
+}
+
+/// Keeps only the mutual best matches
+///
+/// `forward` are the best matches of the query descriptors among the train ones, `backward` are the best matches of
+/// the train descriptors among the query ones. A forward match is kept if the best match of its train descriptor in
+/// `backward` points back to its query descriptor.
+pub fn cross_check(forward: &Vector<DMatch>, backward: &Vector<DMatch>) -> Vector<DMatch> {
+	let best_back = backward.iter().map(|m| (m.query_idx, m.train_idx)).collect::<HashMap<_, _>>();
+	forward
+		.iter()
+		.filter(|m| best_back.get(&m.train_idx) == Some(&m.query_idx))
+		.collect()
+}
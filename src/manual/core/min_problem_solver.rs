@@ -0,0 +1,142 @@
+use std::{convert::TryFrom, ffi::c_void, slice};
+
+use crate::{
+	core::{self, MinProblemSolver_Function, Ptr},
+	sys,
+	templ::{callback_abort_on_panic, CallbackPanic},
+	traits::Boxed,
+	Error, Result,
+};
+
+/// Rust-side implementation of the `cv::MinProblemSolver::Function` interface
+///
+/// Implement this trait for your type and wrap it with [Ptr::from_impl] to get a function object that can be
+/// passed to `DownhillSolver`, `ConjGradSolver` or `MinProblemSolver::set_function()`. The calls coming from C++
+/// are forwarded to the trait methods through the trampolines in a C++ subclass of `cv::MinProblemSolver::Function`.
+/// A panic in a method doesn't unwind into the C++ code, the solver call that invoked the method fails with `Err`
+/// instead and the function object is not called again after that. A negative `dims()` also fails the call, with
+/// `StsOutOfRange`.
+pub trait MinProblemSolverFunctionImpl: Send + Sync {
+	/// Number of dimensions of the function argument
+	fn dims(&self) -> i32;
+
+	/// Calculate function value at the point `x`, `x.len()` is always equal to `dims()`
+	fn calc(&self, x: &[f64]) -> f64;
+
+	/// Calculate the gradient at the point `x` and store it in `grad`
+	///
+	/// Return `false` to let OpenCV approximate the gradient numerically using `calc()`, this is what the default
+	/// implementation does.
+	fn gradient(&mut self, _x: &[f64], _grad: &mut [f64]) -> bool {
+		false
+	}
+
+	/// Step that OpenCV uses for the numerical gradient approximation
+	fn gradient_eps(&self) -> f64 {
+		1e-3
+	}
+}
+
+struct Callbacks {
+	f: Box<dyn MinProblemSolverFunctionImpl>,
+	panic: CallbackPanic,
+}
+
+extern "C" {
+	fn cv_manual_PtrOfMinProblemSolver_Function_new(
+		userdata: *mut c_void,
+		get_dims: unsafe extern "C" fn(*const c_void, *mut i32) -> i32,
+		get_gradient_eps: unsafe extern "C" fn(*const c_void, *mut f64) -> i32,
+		calc: unsafe extern "C" fn(*const c_void, *const f64, *mut f64) -> i32,
+		get_gradient: unsafe extern "C" fn(*mut c_void, *const f64, *mut f64, *mut bool) -> i32,
+		drop: unsafe extern "C" fn(*mut c_void),
+		ocvrs_return: *mut sys::Result<*mut c_void>,
+	);
+}
+
+/// Number of dimensions as the length of the argument slices, negative values are rejected
+fn dims(f: &dyn MinProblemSolverFunctionImpl) -> Result<usize> {
+	let dims = f.dims();
+	usize::try_from(dims).map_err(|_| {
+		Error::new(
+			core::StsOutOfRange,
+			format!("Number of dimensions must not be negative, but got: {}", dims),
+		)
+	})
+}
+
+/// Calls the method and writes its result to `ocvrs_return`
+///
+/// Returns the status for the C++ side: 0 on success, otherwise the error code of the exception that it throws, a panic
+/// is reported as `StsError`.
+unsafe fn call<R>(panic: &CallbackPanic, ocvrs_return: *mut R, f: impl FnOnce() -> Result<R>) -> i32 {
+	match panic.catch(f) {
+		Some(Ok(ret)) => {
+			ocvrs_return.write(ret);
+			0
+		}
+		Some(Err(e)) => e.code(),
+		None => core::StsError,
+	}
+}
+
+unsafe extern "C" fn get_dims_trampoline(userdata: *const c_void, ocvrs_return: *mut i32) -> i32 {
+	let callbacks = &*(userdata as *const Callbacks);
+	call(&callbacks.panic, ocvrs_return, || dims(&*callbacks.f).map(|dims| dims as i32))
+}
+
+unsafe extern "C" fn get_gradient_eps_trampoline(userdata: *const c_void, ocvrs_return: *mut f64) -> i32 {
+	let callbacks = &*(userdata as *const Callbacks);
+	call(&callbacks.panic, ocvrs_return, || Ok(callbacks.f.gradient_eps()))
+}
+
+unsafe extern "C" fn calc_trampoline(userdata: *const c_void, x: *const f64, ocvrs_return: *mut f64) -> i32 {
+	let callbacks = &*(userdata as *const Callbacks);
+	call(&callbacks.panic, ocvrs_return, || {
+		let dims = dims(&*callbacks.f)?;
+		Ok(callbacks.f.calc(slice::from_raw_parts(x, dims)))
+	})
+}
+
+unsafe extern "C" fn get_gradient_trampoline(
+	userdata: *mut c_void,
+	x: *const f64,
+	grad: *mut f64,
+	ocvrs_return: *mut bool,
+) -> i32 {
+	let Callbacks { f, panic } = &mut *(userdata as *mut Callbacks);
+	call(panic, ocvrs_return, || {
+		let dims = dims(&**f)?;
+		Ok(f.gradient(slice::from_raw_parts(x, dims), slice::from_raw_parts_mut(grad, dims)))
+	})
+}
+
+unsafe extern "C" fn drop_trampoline(userdata: *mut c_void) {
+	callback_abort_on_panic(|| drop(Box::from_raw(userdata as *mut Callbacks)));
+}
+
+impl Ptr<dyn MinProblemSolver_Function> {
+	/// Wrap a Rust implementation of the function into an OpenCV smart pointer
+	///
+	/// The passed object is owned by the resulting C++ object and is dropped together with it.
+	pub fn from_impl(f: impl MinProblemSolverFunctionImpl + 'static) -> Result<Self> {
+		let userdata = Box::into_raw(Box::new(Callbacks {
+			f: Box::new(f),
+			panic: CallbackPanic::new(),
+		})) as *mut c_void;
+		return_send!(via ocvrs_return);
+		unsafe {
+			cv_manual_PtrOfMinProblemSolver_Function_new(
+				userdata,
+				get_dims_trampoline,
+				get_gradient_eps_trampoline,
+				calc_trampoline,
+				get_gradient_trampoline,
+				drop_trampoline,
+				ocvrs_return.as_mut_ptr(),
+			)
+		};
+		return_receive!(unsafe ocvrs_return => ret);
+		ret.into_result().map(|ptr| unsafe { Self::from_raw(ptr) })
+	}
+}
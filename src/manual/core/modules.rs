@@ -0,0 +1,43 @@
+use once_cell::sync::Lazy;
+
+use crate::{core, Error, Result};
+
+/// Modules listed as built in the information of the loaded OpenCV library, `None` if the list can't be found there
+static BUILT_MODULES: Lazy<Option<Vec<String>>> = Lazy::new(|| {
+	let info = core::get_build_information().ok()?;
+	let modules = info.lines().find_map(|line| line.trim().strip_prefix("To be built:"))?;
+	Some(modules.split_whitespace().map(str::to_string).collect())
+});
+
+/// Names of the OpenCV modules the crate was compiled with, e.g. "imgproc" or "line_descriptor"
+pub fn compiled_modules() -> impl Iterator<Item = &'static str> {
+	env!("OCVRS_MODULES").split(',').filter(|module| !module.is_empty())
+}
+
+/// Whether the OpenCV module can be used
+///
+/// The module must be both compiled into the crate, i.e. enabled in the crate features and found in the OpenCV headers
+/// during the build, and built into the loaded OpenCV library according to [get_build_information]. The contrib
+/// modules like `line_descriptor`, `ximgproc` or `aruco` are often missing from the distribution packages of OpenCV, so
+/// check them before offering the functionality that depends on them.
+///
+/// [get_build_information]: core::get_build_information
+pub fn is_module_available(module: &str) -> bool {
+	compiled_modules().any(|m| m == module)
+		&& match &*BUILT_MODULES {
+			Some(built) => built.iter().any(|m| m == module),
+			None => true,
+		}
+}
+
+/// Fails with [Error::module_unavailable] (matched as [ErrorKind::ModuleUnavailable](crate::ErrorKind::ModuleUnavailable)) if the OpenCV module can't be used,
+/// see [is_module_available]
+///
+/// Use it at the entry points of the optional functionality to get a descriptive error instead of a missing symbol.
+pub fn ensure_module_available(module: &str) -> Result<()> {
+	if is_module_available(module) {
+		Ok(())
+	} else {
+		Err(Error::module_unavailable(module))
+	}
+}
@@ -0,0 +1,254 @@
+use std::{convert::TryFrom, fs, path::Path};
+
+use crate::{
+	core::{self, Mat, CV_MAKETYPE},
+	prelude::*,
+	Error, Result,
+};
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+/// The header including the magic, the version and the length is padded to the multiple of this
+const HEADER_ALIGN: usize = 64;
+
+/// Reading and writing of the matrices in the NumPy `.npy` format
+///
+/// Allows exchanging the descriptors and other 2D matrices with the Python scripts that use `numpy.load()` and
+/// `numpy.save()` without going through `FileStorage`. The single channel matrix is stored as the array of the shape
+/// `(rows, cols)`, the multichannel one as `(rows, cols, channels)`. All the depths except `CV_16F` map to the NumPy
+/// types with the same element size, `CV_16F` is stored as `float16`.
+impl Mat {
+	/// Serializes the matrix into the `.npy` format, see [Mat::write_npy]
+	pub fn to_npy(&self) -> Result<Vec<u8>> {
+		if self.dims() > 2 {
+			return Err(Error::new(
+				core::StsNotImplemented,
+				format!(
+					"Only 2D matrices can be exported to .npy, the matrix has {} dimensions",
+					self.dims()
+				),
+			));
+		}
+		let descr = npy_descr(self.depth())?;
+		let channels = self.channels();
+		let shape = if channels == 1 {
+			format!("({}, {})", self.rows(), self.cols())
+		} else {
+			format!("({}, {}, {})", self.rows(), self.cols(), channels)
+		};
+		let mut header = format!("{{'descr': '{}', 'fortran_order': False, 'shape': {}, }}", descr, shape);
+		// magic, 2 version bytes and 2 header length bytes precede the header, it's terminated with a newline
+		let unpadded = MAGIC.len() + 4 + header.len() + 1;
+		header.push_str(&" ".repeat((HEADER_ALIGN - unpadded % HEADER_ALIGN) % HEADER_ALIGN));
+		header.push('\n');
+		let header_len = u16::try_from(header.len())
+			.map_err(|_| Error::new(core::StsOutOfRange, format!("The .npy header is too long: {}", header.len())))?;
+
+		let continuous;
+		let data = if self.is_continuous() {
+			self.data_bytes()?
+		} else {
+			continuous = self.try_clone()?;
+			continuous.data_bytes()?
+		};
+		let mut out = Vec::with_capacity(MAGIC.len() + 4 + header.len() + data.len());
+		out.extend_from_slice(MAGIC);
+		out.extend_from_slice(&[1, 0]);
+		out.extend_from_slice(&header_len.to_le_bytes());
+		out.extend_from_slice(header.as_bytes());
+		out.extend_from_slice(data);
+		Ok(out)
+	}
+
+	/// Writes the matrix to the `.npy` file that can be read with `numpy.load()`
+	pub fn write_npy(&self, path: impl AsRef<Path>) -> Result<()> {
+		let path = path.as_ref();
+		fs::write(path, self.to_npy()?)
+			.map_err(|e| Error::new(core::StsError, format!("Can't write the matrix to {}: {}", path.display(), e)))
+	}
+
+	/// Deserializes the matrix from the `.npy` format, see [Mat::read_npy]
+	pub fn from_npy(bytes: &[u8]) -> Result<Mat> {
+		let header = NpyHeader::parse(bytes)?;
+		let typ = CV_MAKETYPE(header.depth, header.channels);
+		let mut out = unsafe { Mat::new_rows_cols(header.rows, header.cols, typ) }?;
+		let dst = out.data_bytes_mut()?;
+		let src = &bytes[header.data_offset..];
+		if src.len() != dst.len() {
+			return Err(Error::new(
+				core::StsUnmatchedSizes,
+				format!(
+					"The .npy data has {} bytes, but {} bytes are expected from the header",
+					src.len(),
+					dst.len()
+				),
+			));
+		}
+		dst.copy_from_slice(src);
+		if header.swap_bytes {
+			for elem in dst.chunks_exact_mut(header.elem_size) {
+				elem.reverse();
+			}
+		}
+		Ok(out)
+	}
+
+	/// Reads the matrix from the `.npy` file, e.g. written with `numpy.save()`
+	///
+	/// Arrays of 1 to 3 dimensions in the C order are supported, the 1D array of length `n` becomes the `n`×1 matrix and
+	/// the last dimension of the 3D array becomes the channels. Both little and big endian data are accepted.
+	pub fn read_npy(path: impl AsRef<Path>) -> Result<Mat> {
+		let path = path.as_ref();
+		let bytes = fs::read(path).map_err(|e| {
+			Error::new(
+				core::StsObjectNotFound,
+				format!("Can't read the matrix from {}: {}", path.display(), e),
+			)
+		})?;
+		Self::from_npy(&bytes)
+	}
+}
+
+fn npy_descr(depth: i32) -> Result<String> {
+	let (kind, size) = match depth {
+		core::CV_8U => ('u', 1),
+		core::CV_8S => ('i', 1),
+		core::CV_16U => ('u', 2),
+		core::CV_16S => ('i', 2),
+		core::CV_32S => ('i', 4),
+		core::CV_32F => ('f', 4),
+		core::CV_64F => ('f', 8),
+		core::CV_16F => ('f', 2),
+		_ => {
+			return Err(Error::new(
+				core::StsUnsupportedFormat,
+				format!("Unsupported depth: {}", depth),
+			))
+		}
+	};
+	let order = if size == 1 {
+		'|'
+	} else if cfg!(target_endian = "little") {
+		'<'
+	} else {
+		'>'
+	};
+	Ok(format!("{}{}{}", order, kind, size))
+}
+
+#[derive(Debug)]
+struct NpyHeader {
+	depth: i32,
+	elem_size: usize,
+	swap_bytes: bool,
+	rows: i32,
+	cols: i32,
+	channels: i32,
+	data_offset: usize,
+}
+
+impl NpyHeader {
+	fn parse(bytes: &[u8]) -> Result<Self> {
+		let invalid = |msg: &str| Error::new(core::StsParseError, format!("Invalid .npy header: {}", msg));
+		if !bytes.starts_with(MAGIC) || bytes.len() < MAGIC.len() + 4 {
+			return Err(invalid("no magic string"));
+		}
+		let major = bytes[MAGIC.len()];
+		let (header_start, header_len) = match major {
+			1 => (MAGIC.len() + 4, usize::from(u16::from_le_bytes([bytes[8], bytes[9]]))),
+			2 | 3 if bytes.len() >= MAGIC.len() + 6 => (
+				MAGIC.len() + 6,
+				u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]) as usize,
+			),
+			_ => return Err(invalid(&format!("unsupported version {}", major))),
+		};
+		let data_offset = header_start + header_len;
+		let header = bytes
+			.get(header_start..data_offset)
+			.and_then(|header| std::str::from_utf8(header).ok())
+			.ok_or_else(|| invalid("truncated"))?;
+
+		let descr = dict_value(header, "descr").ok_or_else(|| invalid("no descr"))?;
+		let descr = descr.trim_matches(&['\'', '"'][..]);
+		let (depth, elem_size) = match descr.get(1..) {
+			Some("u1") | Some("b1") => (core::CV_8U, 1),
+			Some("i1") => (core::CV_8S, 1),
+			Some("u2") => (core::CV_16U, 2),
+			Some("i2") => (core::CV_16S, 2),
+			Some("i4") => (core::CV_32S, 4),
+			Some("f2") => (core::CV_16F, 2),
+			Some("f4") => (core::CV_32F, 4),
+			Some("f8") => (core::CV_64F, 8),
+			_ => {
+				return Err(Error::new(
+					core::StsUnsupportedFormat,
+					format!("Unsupported .npy element type: {}", descr),
+				))
+			}
+		};
+		let native = if cfg!(target_endian = "little") {
+			'<'
+		} else {
+			'>'
+		};
+		let swap_bytes = match descr.chars().next() {
+			Some('|') | Some('=') => false,
+			Some(order @ '<') | Some(order @ '>') => order != native,
+			_ => return Err(invalid(&format!("unknown byte order in {}", descr))),
+		};
+
+		match dict_value(header, "fortran_order") {
+			Some("False") => {}
+			Some("True") => {
+				return Err(Error::new(
+					core::StsNotImplemented,
+					"Fortran order .npy arrays are not supported, save the array with numpy.ascontiguousarray()",
+				))
+			}
+			_ => return Err(invalid("no fortran_order")),
+		}
+
+		let shape = dict_value(header, "shape").ok_or_else(|| invalid("no shape"))?;
+		let shape = shape
+			.trim_start_matches('(')
+			.trim_end_matches(')')
+			.split(',')
+			.map(str::trim)
+			.filter(|dim| !dim.is_empty())
+			.map(|dim| dim.trim_end_matches('L').parse::<i32>())
+			.collect::<Result<Vec<_>, _>>()
+			.map_err(|_| invalid(&format!("invalid shape {}", shape)))?;
+		let (rows, cols, channels) = match shape.as_slice() {
+			[] => (1, 1, 1),
+			&[rows] => (rows, 1, 1),
+			&[rows, cols] => (rows, cols, 1),
+			&[rows, cols, channels] if (1..=core::CV_CN_MAX).contains(&channels) => (rows, cols, channels),
+			_ => {
+				return Err(Error::new(
+					core::StsNotImplemented,
+					format!("Unsupported .npy shape: {:?}", shape),
+				))
+			}
+		};
+		Ok(Self {
+			depth,
+			elem_size,
+			swap_bytes,
+			rows,
+			cols,
+			channels,
+			data_offset,
+		})
+	}
+}
+
+/// Extracts the raw value of the `key` from the Python dict literal of the `.npy` header
+fn dict_value<'h>(header: &'h str, key: &str) -> Option<&'h str> {
+	let key_pos = header.find(&format!("'{}'", key))?;
+	let rest = header[key_pos + key.len() + 2..].trim_start().strip_prefix(':')?.trim_start();
+	let end = if rest.starts_with('(') {
+		rest.find(')')? + 1
+	} else {
+		rest.find(&[',', '}'][..])?
+	};
+	Some(rest[..end].trim())
+}
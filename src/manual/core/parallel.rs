@@ -0,0 +1,58 @@
+use std::ffi::c_void;
+
+use crate::{
+	core::{Range, RangeTraitConst},
+	sys,
+	templ::CallbackPanic,
+	traits::Boxed,
+	Result,
+};
+
+struct ParallelBody<'f> {
+	body: &'f (dyn Fn(Range) + Send + Sync),
+	panic: CallbackPanic,
+}
+
+extern "C" {
+	fn cv_manual_parallel_for_(
+		range: *const c_void,
+		userdata: *const c_void,
+		body: unsafe extern "C" fn(*const c_void, *mut c_void),
+		nstripes: f64,
+		ocvrs_return: *mut sys::Result_void,
+	);
+}
+
+unsafe extern "C" fn body_trampoline(userdata: *const c_void, range: *mut c_void) {
+	let body = &*(userdata as *const ParallelBody);
+	let range = Range::from_raw(range);
+	body.panic.catch(|| (body.body)(range));
+}
+
+/// Parallel data processor with a Rust closure as the loop body
+///
+/// Same as [parallel_for_](crate::core::parallel_for_), but takes a closure instead of the `ParallelLoopBody` object,
+/// the work is distributed using the OpenCV thread pool. The closure is called with the subranges of `range`, their
+/// number is determined by `nstripes` (pass -1 to let OpenCV decide).
+///
+/// If the closure panics, the remaining stripes are skipped and the panic is resumed in the calling thread after all
+/// of the running stripes finish, unwinding never crosses the FFI boundary.
+pub fn parallel_for_fn(range: &Range, body: impl Fn(Range) + Send + Sync, nstripes: f64) -> Result<()> {
+	let body = ParallelBody {
+		body: &body,
+		panic: CallbackPanic::new(),
+	};
+	return_send!(via ocvrs_return);
+	unsafe {
+		cv_manual_parallel_for_(
+			range.as_raw_Range(),
+			&body as *const ParallelBody as *const c_void,
+			body_trampoline,
+			nstripes,
+			ocvrs_return.as_mut_ptr(),
+		)
+	};
+	return_receive!(unsafe ocvrs_return => ret);
+	body.panic.resume();
+	ret.into_result()
+}
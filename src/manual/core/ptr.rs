@@ -121,3 +121,6 @@ where
 		unsafe { self.extern_delete() }
 	}
 }
+
+/// The pointer can't be cloned from Rust, so sending it also moves the only Rust-side access to the inner object
+unsafe impl<T: ?Sized + Send> Send for Ptr<T> where Self: PtrExtern {}
@@ -0,0 +1,60 @@
+use crate::{
+	core::{self, Mat, Point, Scalar, ToInputArray, CV_64F},
+	prelude::*,
+	Error, Result,
+};
+
+/// Extremums of the array and their locations, returned by [min_max_loc_values]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct MinMaxLoc {
+	pub min_val: f64,
+	pub max_val: f64,
+	pub min_loc: Point,
+	pub max_loc: Point,
+}
+
+/// Finds the global minimum and maximum in an array and returns them with their locations
+///
+/// Same as [core::min_max_loc], but returns the results instead of filling the out-parameters.
+pub fn min_max_loc_values(src: &(impl ToInputArray + ?Sized), mask: Option<&dyn ToInputArray>) -> Result<MinMaxLoc> {
+	let mut out = MinMaxLoc::default();
+	core::min_max_loc(
+		src,
+		Some(&mut out.min_val),
+		Some(&mut out.max_val),
+		Some(&mut out.min_loc),
+		Some(&mut out.max_loc),
+		mask,
+	)?;
+	Ok(out)
+}
+
+/// Calculates the mean and the standard deviation of the array elements and returns them as `(mean, stddev)`
+///
+/// Same as [core::mean_std_dev], but returns the results instead of filling the out-parameters. The value for every
+/// channel is stored in the corresponding element of the [Scalar], an error is returned for the arrays with more than 4
+/// channels.
+pub fn mean_std_dev_values(src: &(impl ToInputArray + ?Sized), mask: Option<&dyn ToInputArray>) -> Result<(Scalar, Scalar)> {
+	let (mut mean, mut stddev) = (Mat::default(), Mat::default());
+	core::mean_std_dev(src, &mut mean, &mut stddev, mask)?;
+	Ok((to_scalar(&mean)?, to_scalar(&stddev)?))
+}
+
+fn to_scalar(m: &Mat) -> Result<Scalar> {
+	let mut out = Scalar::default();
+	if m.empty() {
+		return Ok(out);
+	}
+	if m.total() > out.len() {
+		return Err(Error::new(
+			core::StsOutOfRange,
+			format!("Can't fit the values for {} channels into Scalar", m.total()),
+		));
+	}
+	let mut values = Mat::default();
+	m.convert_to(&mut values, CV_64F, 1., 0.)?;
+	for (dst, src) in out.iter_mut().zip(values.data_typed::<f64>()?) {
+		*dst = *src;
+	}
+	Ok(out)
+}
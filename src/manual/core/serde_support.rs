@@ -0,0 +1,55 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::core::{DMatch, KeyPoint, Point2f};
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "Point2f")]
+pub(crate) struct Point2fDef {
+	x: f32,
+	y: f32,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "DMatch")]
+struct DMatchDef {
+	query_idx: i32,
+	train_idx: i32,
+	img_idx: i32,
+	distance: f32,
+}
+
+impl Serialize for DMatch {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		DMatchDef::serialize(self, serializer)
+	}
+}
+
+impl<'de> Deserialize<'de> for DMatch {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		DMatchDef::deserialize(deserializer)
+	}
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "KeyPoint")]
+struct KeyPointDef {
+	#[serde(with = "Point2fDef")]
+	pt: Point2f,
+	size: f32,
+	angle: f32,
+	response: f32,
+	octave: i32,
+	class_id: i32,
+}
+
+impl Serialize for KeyPoint {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		KeyPointDef::serialize(self, serializer)
+	}
+}
+
+impl<'de> Deserialize<'de> for KeyPoint {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		KeyPointDef::deserialize(deserializer)
+	}
+}
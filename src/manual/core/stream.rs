@@ -0,0 +1,101 @@
+use std::{
+	future::Future,
+	pin::Pin,
+	sync::{Arc, Mutex},
+	task::{Context, Poll, Waker},
+};
+
+use crate::{
+	core::{self, GpuMat, Mat, Stream},
+	prelude::*,
+	Error, Result,
+};
+
+#[derive(Default)]
+struct CompletionState {
+	status: Option<i32>,
+	waker: Option<Waker>,
+}
+
+/// Future resolving when the work enqueued into the `Stream` before its creation is completed, see
+/// [StreamTraitManual::completion]
+///
+/// It's executor agnostic, the task is woken from the CUDA callback thread.
+pub struct StreamCompletion {
+	state: Arc<Mutex<CompletionState>>,
+}
+
+impl Future for StreamCompletion {
+	type Output = Result<()>;
+
+	fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+		match state.status {
+			Some(status) => Poll::Ready(status_to_result(status)),
+			None => {
+				state.waker = Some(cx.waker().clone());
+				Poll::Pending
+			}
+		}
+	}
+}
+
+#[inline]
+fn status_to_result(status: i32) -> Result<()> {
+	// status is the cudaError_t of the stream, cudaSuccess is 0
+	if status == 0 {
+		Ok(())
+	} else {
+		Err(Error::new(
+			core::GpuApiCallError,
+			format!("CUDA stream failed with the status: {}", status),
+		))
+	}
+}
+
+/// Completion notification of the asynchronous operations for `Stream`
+pub trait StreamTraitManual: StreamTrait {
+	/// Returns the future resolving when all the operations currently enqueued into the stream are completed
+	///
+	/// The data passed to the asynchronous operations must be kept alive and unchanged until then.
+	fn completion(&mut self) -> Result<StreamCompletion> {
+		let state = Arc::new(Mutex::new(CompletionState::default()));
+		let callback_state = Arc::clone(&state);
+		self.enqueue_host_callback(Some(Box::new(move |status| {
+			let mut state = callback_state.lock().unwrap_or_else(|e| e.into_inner());
+			state.status = Some(status);
+			if let Some(waker) = state.waker.take() {
+				waker.wake();
+			}
+		})))?;
+		Ok(StreamCompletion { state })
+	}
+
+	/// Calls `callback` on the CUDA callback thread once all the operations currently enqueued into the stream are
+	/// completed
+	///
+	/// The callback must not call any CUDA functions, including the OpenCV ones working with `GpuMat`.
+	fn on_completion(&mut self, callback: impl FnOnce(Result<()>) + Send + 'static) -> Result<()> {
+		let callback = Mutex::new(Some(callback));
+		self.enqueue_host_callback(Some(Box::new(move |status| {
+			if let Some(callback) = callback.lock().unwrap_or_else(|e| e.into_inner()).take() {
+				callback(status_to_result(status));
+			}
+		})))
+	}
+}
+
+impl<T: StreamTrait + ?Sized> StreamTraitManual for T {}
+
+impl GpuMat {
+	/// Enqueues the upload of the `Mat` data to the new `GpuMat` into `stream`
+	///
+	/// `mat` must be kept alive and unchanged until the stream completes, see [StreamTraitManual::completion]. The
+	/// upload only overlaps with the host work if `mat` is allocated in the page-locked `HostMem`.
+	#[inline]
+	pub fn from_mat_async(mat: &Mat, stream: &mut Stream) -> Result<Self> {
+		let mut out = Self::default()?;
+		out.upload_async(mat, stream)?;
+		Ok(out)
+	}
+}
@@ -0,0 +1,255 @@
+use std::{ffi::c_void, fmt, marker::PhantomData, ops::Deref};
+
+use super::mat::mat_forward;
+#[cfg(ocvrs_opencv_branch_4)]
+use crate::core::AccessFlag::{ACCESS_READ, ACCESS_RW};
+#[cfg(not(ocvrs_opencv_branch_4))]
+use crate::core::{ACCESS_READ, ACCESS_RW};
+use crate::{
+	core::{
+		self, _InputArray, _InputOutputArray, _OutputArray, DataType, Mat, Scalar, ToInputArray, ToInputOutputArray, ToOutputArray,
+		UMat, UMatUsageFlags,
+	},
+	input_output_array,
+	prelude::*,
+	sys, Result,
+};
+
+/// Object sharing the data of the immutably borrowed `Mat` or `UMat`, see [UMat::map_mat] and
+/// [UMatTraitConstManual::map]
+///
+/// The borrowed object can't be changed or dropped while the mapping exists, the mapping is released on drop.
+pub struct Mapped<'r, T> {
+	inner: T,
+	borrowed: PhantomData<&'r ()>,
+}
+
+/// Object sharing the data of the mutably borrowed `Mat` or `UMat`, see [UMat::map_mat_mut] and
+/// [UMatTraitManual::map_mut]
+///
+/// The changes done through the mapping are visible in the borrowed object once the mapping is dropped. The mapped
+/// object itself is only exposed immutably so that it can't be moved out and outlive the mapping, the data is
+/// changed with the forwarding methods or by passing the mapping as the output array.
+pub struct MappedMut<'r, T> {
+	inner: T,
+	borrowed: PhantomData<&'r mut ()>,
+}
+
+impl<T> Mapped<'_, T> {
+	#[inline]
+	fn new(inner: T) -> Self {
+		Self {
+			inner,
+			borrowed: PhantomData,
+		}
+	}
+}
+
+impl<T> MappedMut<'_, T> {
+	#[inline]
+	fn new(inner: T) -> Self {
+		Self {
+			inner,
+			borrowed: PhantomData,
+		}
+	}
+}
+
+impl<T> Deref for Mapped<'_, T> {
+	type Target = T;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		&self.inner
+	}
+}
+
+impl<T> Deref for MappedMut<'_, T> {
+	type Target = T;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		&self.inner
+	}
+}
+
+impl MappedMut<'_, Mat> {
+	/// See `Mat::at_mut()`
+	#[inline]
+	pub fn at_mut<T: DataType>(&mut self, i0: i32) -> Result<&mut T> {
+		mat_forward::at_mut(&mut self.inner, i0)
+	}
+
+	/// See `Mat::at_2d_mut()`
+	#[inline]
+	pub fn at_2d_mut<T: DataType>(&mut self, row: i32, col: i32) -> Result<&mut T> {
+		mat_forward::at_2d_mut(&mut self.inner, row, col)
+	}
+
+	/// See `Mat::at_pt_mut()`
+	#[inline]
+	pub fn at_pt_mut<T: DataType>(&mut self, pt: core::Point) -> Result<&mut T> {
+		mat_forward::at_pt_mut(&mut self.inner, pt)
+	}
+
+	/// See [MatTraitManual::at_row_mut]
+	#[inline]
+	pub fn at_row_mut<T: DataType>(&mut self, row: i32) -> Result<&mut [T]> {
+		self.inner.at_row_mut(row)
+	}
+
+	/// See [MatTraitManual::data_bytes_mut]
+	#[inline]
+	pub fn data_bytes_mut(&mut self) -> Result<&mut [u8]> {
+		self.inner.data_bytes_mut()
+	}
+
+	/// See [MatTraitManual::data_typed_mut]
+	#[inline]
+	pub fn data_typed_mut<T: DataType>(&mut self) -> Result<&mut [T]> {
+		self.inner.data_typed_mut()
+	}
+
+	/// See [MatTraitManual::set]
+	#[inline]
+	pub fn set(&mut self, s: Scalar) -> Result<()> {
+		self.inner.set(s)
+	}
+}
+
+impl MappedMut<'_, UMat> {
+	/// Sets all or the `mask`ed elements to `value`, see `UMat::set_to()`
+	#[inline]
+	pub fn set_to(&mut self, value: &dyn ToInputArray, mask: Option<&dyn ToInputArray>) -> Result<()> {
+		self.inner.set_to(value, mask).map(|_| ())
+	}
+}
+
+impl<T: fmt::Debug> fmt::Debug for Mapped<'_, T> {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(&self.inner, f)
+	}
+}
+
+impl<T: fmt::Debug> fmt::Debug for MappedMut<'_, T> {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(&self.inner, f)
+	}
+}
+
+impl<T: ToInputArray> ToInputArray for Mapped<'_, T> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		self.inner.input_array()
+	}
+}
+
+impl<T: ToInputArray> ToInputArray for &Mapped<'_, T> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		self.inner.input_array()
+	}
+}
+
+impl<T: ToInputArray> ToInputArray for MappedMut<'_, T> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		self.inner.input_array()
+	}
+}
+
+impl<T: ToInputArray> ToInputArray for &MappedMut<'_, T> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		self.inner.input_array()
+	}
+}
+
+impl<T: ToOutputArray> ToOutputArray for MappedMut<'_, T> {
+	#[inline]
+	fn output_array(&mut self) -> Result<_OutputArray> {
+		self.inner.output_array()
+	}
+}
+
+impl<T: ToInputOutputArray> ToInputOutputArray for MappedMut<'_, T> {
+	#[inline]
+	fn input_output_array(&mut self) -> Result<_InputOutputArray> {
+		self.inner.input_output_array()
+	}
+}
+
+impl UMat {
+	/// Creates the `UMat` with the copy of the `Mat` data, the result is independent of `mat`
+	#[inline]
+	pub fn from_mat(mat: &Mat) -> Result<Self> {
+		let mut out = Self::new(UMatUsageFlags::USAGE_DEFAULT);
+		mat.copy_to(&mut out)?;
+		Ok(out)
+	}
+
+	/// Creates the `UMat` sharing the data of `mat` for reading, it's the safe version of `Mat::get_umat()`
+	///
+	/// `mat` can't be modified while the result is alive, that would invalidate the data possibly uploaded to the
+	/// OpenCL device.
+	#[inline]
+	pub fn map_mat(mat: &Mat) -> Result<Mapped<'_, UMat>> {
+		mat.get_umat(ACCESS_READ, UMatUsageFlags::USAGE_DEFAULT).map(Mapped::new)
+	}
+
+	/// Creates the `UMat` sharing the data of `mat` for reading and writing, it's the safe version of
+	/// `Mat::get_umat()`
+	///
+	/// The results computed into the returned `UMat` are synchronized back to `mat` when it's dropped.
+	#[inline]
+	pub fn map_mat_mut(mat: &mut Mat) -> Result<MappedMut<'_, UMat>> {
+		mat.get_umat(ACCESS_RW, UMatUsageFlags::USAGE_DEFAULT).map(MappedMut::new)
+	}
+}
+
+pub trait UMatTraitConstManual: UMatTraitConst {
+	#[inline]
+	fn size(&self) -> Result<core::Size> {
+		extern "C" {
+			fn cv_manual_UMat_size(instance: *const c_void, ocvrs_return: *mut sys::Result<core::Size>);
+		}
+		return_send!(via ocvrs_return);
+		unsafe { cv_manual_UMat_size(self.as_raw_UMat(), ocvrs_return.as_mut_ptr()) }
+		return_receive!(unsafe ocvrs_return => ret);
+		ret.into_result()
+	}
+
+	/// Downloads the data into the new `Mat`, the result is independent of `self`
+	#[inline]
+	fn to_mat(&self) -> Result<Mat> {
+		let mut out = Mat::default();
+		self.copy_to(&mut out)?;
+		Ok(out)
+	}
+
+	/// Maps the data into the host memory for reading, it's the safe version of `UMat::get_mat()`
+	///
+	/// `self` can't be used in the OpenCL operations while the result is alive.
+	#[inline]
+	fn map(&self) -> Result<Mapped<'_, Mat>> {
+		self.get_mat(ACCESS_READ).map(Mapped::new)
+	}
+}
+
+pub trait UMatTraitManual: UMatTrait + UMatTraitConstManual {
+	/// Maps the data into the host memory for reading and writing, it's the safe version of `UMat::get_mat()`
+	///
+	/// The changes done through the returned `Mat` are uploaded back when it's dropped.
+	#[inline]
+	fn map_mut(&mut self) -> Result<MappedMut<'_, Mat>> {
+		self.get_mat(ACCESS_RW).map(MappedMut::new)
+	}
+}
+
+impl<T: UMatTraitConst> UMatTraitConstManual for T {}
+
+impl<T: UMatTrait> UMatTraitManual for T {}
+
+input_output_array! { UMat, from_umat, from_umat_mut }
@@ -4,7 +4,6 @@ pub use iter::{VectorIterator, VectorRefIterator};
 pub use vector_extern::{VectorElement, VectorExtern, VectorExternCopyNonBool};
 
 use crate::{
-	platform_types::size_t,
 	traits::{Boxed, OpenCVType, OpenCVTypeArg, OpenCVTypeExternContainer},
 	Result,
 };
@@ -33,7 +32,7 @@ where
 
 	/// Create a Vector with pre-defined capacity
 	#[inline]
-	pub fn with_capacity(capacity: size_t) -> Self {
+	pub fn with_capacity(capacity: usize) -> Self {
 		let mut out = Self::new();
 		out.reserve(capacity);
 		out
@@ -58,7 +57,7 @@ where
 	}
 
 	#[inline]
-	pub fn from_elem<'a>(elem: <T as OpenCVType<'a>>::Arg, n: size_t) -> Self
+	pub fn from_elem<'a>(elem: <T as OpenCVType<'a>>::Arg, n: usize) -> Self
 	where
 		<T as OpenCVType<'a>>::Arg: Clone,
 	{
@@ -71,7 +70,7 @@ where
 
 	/// Return Vector length
 	#[inline]
-	pub fn len(&self) -> size_t {
+	pub fn len(&self) -> usize {
 		unsafe { self.extern_len() }
 	}
 
@@ -83,7 +82,7 @@ where
 
 	/// Return Vector current capacity
 	#[inline]
-	pub fn capacity(&self) -> size_t {
+	pub fn capacity(&self) -> usize {
 		unsafe { self.extern_capacity() }
 	}
 
@@ -95,7 +94,7 @@ where
 
 	/// Reserve capacity for `additional` new elements
 	#[inline]
-	pub fn reserve(&mut self, additional: size_t) {
+	pub fn reserve(&mut self, additional: usize) {
 		unsafe { self.extern_reserve(additional) }
 	}
 
@@ -107,7 +106,7 @@ where
 
 	/// Remove the element at the specified `index`
 	#[inline]
-	pub fn remove(&mut self, index: size_t) -> Result<()> {
+	pub fn remove(&mut self, index: usize) -> Result<()> {
 		vector_index_check(index, self.len())?;
 		unsafe { self.extern_remove(index) }
 		Ok(())
@@ -115,7 +114,7 @@ where
 
 	/// Swap 2 elements in the Vector
 	#[inline]
-	pub fn swap(&mut self, index1: size_t, index2: size_t) -> Result<()> {
+	pub fn swap(&mut self, index1: usize, index2: usize) -> Result<()> {
 		let len = self.len();
 		vector_index_check(index1, len)?;
 		vector_index_check(index2, len)?;
@@ -140,7 +139,7 @@ where
 
 	/// Insert a new element at the specified `index`
 	#[inline]
-	pub fn insert(&mut self, index: size_t, val: <T as OpenCVType>::Arg) -> Result<()> {
+	pub fn insert(&mut self, index: usize, val: <T as OpenCVType>::Arg) -> Result<()> {
 		vector_index_check(index, self.len() + 1)?;
 		let val = val.opencv_into_extern_container()?;
 		unsafe { self.extern_insert(index, val.opencv_as_extern()) }
@@ -149,7 +148,7 @@ where
 
 	/// Set element at the specified `index`
 	#[inline]
-	pub fn set(&mut self, index: size_t, val: <T as OpenCVType>::Arg) -> Result<()> {
+	pub fn set(&mut self, index: usize, val: <T as OpenCVType>::Arg) -> Result<()> {
 		vector_index_check(index, self.len())?;
 		let val = val.opencv_into_extern_container()?;
 		unsafe { self.extern_set(index, val.opencv_as_extern()) }
@@ -160,14 +159,14 @@ where
 	/// # Safety
 	/// Caller must ensure that the specified `index` is within the `Vector` bounds
 	#[inline]
-	pub unsafe fn set_unchecked(&mut self, index: size_t, val: <T as OpenCVType>::Arg) {
+	pub unsafe fn set_unchecked(&mut self, index: usize, val: <T as OpenCVType>::Arg) {
 		let val = val.opencv_into_extern_container_nofail();
 		self.extern_set(index, val.opencv_as_extern())
 	}
 
 	/// Get element at the specified `index`
 	#[inline]
-	pub fn get(&self, index: size_t) -> Result<T> {
+	pub fn get(&self, index: usize) -> Result<T> {
 		vector_index_check(index, self.len())?;
 		Ok(unsafe { self.get_unchecked(index) })
 	}
@@ -176,7 +175,7 @@ where
 	/// # Safety
 	/// Caller must ensure that the specified `index` is within the `Vector` bounds
 	#[inline]
-	pub unsafe fn get_unchecked(&self, index: size_t) -> T {
+	pub unsafe fn get_unchecked(&self, index: usize) -> T {
 		let val = self.extern_get(index);
 		T::opencv_from_extern(val)
 	}
@@ -387,7 +386,7 @@ where
 }
 
 #[inline(always)]
-fn vector_index_check(index: size_t, len: size_t) -> Result<()> {
+fn vector_index_check(index: usize, len: usize) -> Result<()> {
 	if index >= len {
 		Err(crate::Error::new(
 			crate::core::StsOutOfRange,
@@ -1,9 +1,6 @@
 use std::iter::FusedIterator;
 
-use crate::{
-	core::{Vector, VectorElement, VectorExtern},
-	platform_types::size_t,
-};
+use crate::core::{Vector, VectorElement, VectorExtern};
 
 impl<T: VectorElement> IntoIterator for Vector<T>
 where
@@ -36,8 +33,8 @@ where
 	Vector<T>: VectorExtern<T>,
 {
 	vec: Vector<T>,
-	i: size_t,
-	len: size_t,
+	i: usize,
+	len: usize,
 }
 
 impl<T: VectorElement> VectorIterator<T>
@@ -94,8 +91,8 @@ where
 	Vector<T>: VectorExtern<T>,
 {
 	vec: &'v Vector<T>,
-	i: size_t,
-	len: size_t,
+	i: usize,
+	len: usize,
 }
 
 impl<'v, T: VectorElement> VectorRefIterator<'v, T>
@@ -1,5 +1,4 @@
 use crate::core::Vector;
-use crate::platform_types::size_t;
 use crate::traits::{OpenCVType, OpenCVTypeArg, OpenCVTypeExternContainer};
 use crate::{extern_arg_send, extern_container_send, extern_receive, extern_send};
 
@@ -24,31 +23,31 @@ pub trait VectorExtern<T: for<'a> OpenCVType<'a>> {
 	#[doc(hidden)]
 	unsafe fn extern_delete(&mut self);
 	#[doc(hidden)]
-	unsafe fn extern_len(&self) -> size_t;
+	unsafe fn extern_len(&self) -> usize;
 	#[doc(hidden)]
 	unsafe fn extern_is_empty(&self) -> bool;
 	#[doc(hidden)]
-	unsafe fn extern_capacity(&self) -> size_t;
+	unsafe fn extern_capacity(&self) -> usize;
 	#[doc(hidden)]
 	unsafe fn extern_shrink_to_fit(&mut self);
 	#[doc(hidden)]
-	unsafe fn extern_reserve(&mut self, additional: size_t);
+	unsafe fn extern_reserve(&mut self, additional: usize);
 	#[doc(hidden)]
-	unsafe fn extern_remove(&mut self, index: size_t);
+	unsafe fn extern_remove(&mut self, index: usize);
 	#[doc(hidden)]
-	unsafe fn extern_swap(&mut self, index1: size_t, index2: size_t);
+	unsafe fn extern_swap(&mut self, index1: usize, index2: usize);
 	#[doc(hidden)]
 	unsafe fn extern_clear(&mut self);
 	#[doc(hidden)]
-	unsafe fn extern_get(&self, index: size_t) -> extern_receive!(T);
+	unsafe fn extern_get(&self, index: usize) -> extern_receive!(T);
 	#[doc(hidden)]
 	unsafe fn extern_push<'a>(&mut self, val: extern_arg_send!(T: 'a));
 	#[doc(hidden)]
 	unsafe fn extern_push_owned<'a>(&mut self, val: extern_container_send!(T: 'a));
 	#[doc(hidden)]
-	unsafe fn extern_insert<'a>(&mut self, index: size_t, val: extern_arg_send!(T: 'a));
+	unsafe fn extern_insert<'a>(&mut self, index: usize, val: extern_arg_send!(T: 'a));
 	#[doc(hidden)]
-	unsafe fn extern_set<'a>(&mut self, index: size_t, val: extern_arg_send!(T: 'a));
+	unsafe fn extern_set<'a>(&mut self, index: usize, val: extern_arg_send!(T: 'a));
 }
 
 #[macro_export]
@@ -73,18 +72,18 @@ macro_rules! vector_extern {
 		extern "C" {
 			fn $extern_new<'a>() -> extern_receive!($crate::core::Vector<$type>: 'a);
 			fn $extern_delete(instance: extern_send!(mut $crate::core::Vector<$type>));
-			fn $extern_len(instance: extern_send!($crate::core::Vector<$type>)) -> $crate::platform_types::size_t;
+			fn $extern_len(instance: extern_send!($crate::core::Vector<$type>)) -> usize;
 			fn $extern_is_empty(instance: extern_send!($crate::core::Vector<$type>)) -> bool;
-			fn $extern_capacity(instance: extern_send!($crate::core::Vector<$type>)) -> $crate::platform_types::size_t;
+			fn $extern_capacity(instance: extern_send!($crate::core::Vector<$type>)) -> usize;
 			fn $extern_shrink_to_fit(instance: extern_send!(mut $crate::core::Vector<$type>));
-			fn $extern_reserve(instance: extern_send!(mut $crate::core::Vector<$type>), additional: $crate::platform_types::size_t);
-			fn $extern_remove(instance: extern_send!(mut $crate::core::Vector<$type>), index: $crate::platform_types::size_t);
-			fn $extern_swap(instance: extern_send!(mut $crate::core::Vector<$type>), index1: $crate::platform_types::size_t, index2: $crate::platform_types::size_t);
+			fn $extern_reserve(instance: extern_send!(mut $crate::core::Vector<$type>), additional: usize);
+			fn $extern_remove(instance: extern_send!(mut $crate::core::Vector<$type>), index: usize);
+			fn $extern_swap(instance: extern_send!(mut $crate::core::Vector<$type>), index1: usize, index2: usize);
 			fn $extern_clear(instance: extern_send!(mut $crate::core::Vector<$type>));
-			fn $extern_get<'a>(instance: extern_send!($crate::core::Vector<$type>), index: $crate::platform_types::size_t, ocvrs_return: *mut extern_receive!($type: 'a));
+			fn $extern_get<'a>(instance: extern_send!($crate::core::Vector<$type>), index: usize, ocvrs_return: *mut extern_receive!($type: 'a));
 			fn $extern_push<'a>(instance: extern_send!(mut $crate::core::Vector<$type>), val: extern_arg_send!($type: 'a));
-			fn $extern_insert<'a>(instance: extern_send!(mut $crate::core::Vector<$type>), index: $crate::platform_types::size_t, val: extern_arg_send!($type: 'a));
-			fn $extern_set<'a>(instance: extern_send!(mut $crate::core::Vector<$type>), index: $crate::platform_types::size_t, val: extern_arg_send!($type: 'a));
+			fn $extern_insert<'a>(instance: extern_send!(mut $crate::core::Vector<$type>), index: usize, val: extern_arg_send!($type: 'a));
+			fn $extern_set<'a>(instance: extern_send!(mut $crate::core::Vector<$type>), index: usize, val: extern_arg_send!($type: 'a));
 		}
 
 		impl $crate::core::VectorExtern<$type> for $crate::core::Vector<$type> {
@@ -99,7 +98,7 @@ macro_rules! vector_extern {
 			}
 
 			#[inline]
-			unsafe fn extern_len(&self) -> $crate::platform_types::size_t {
+			unsafe fn extern_len(&self) -> usize {
 				$extern_len(self.as_raw())
 			}
 
@@ -109,7 +108,7 @@ macro_rules! vector_extern {
 			}
 
 			#[inline]
-			unsafe fn extern_capacity(&self) -> $crate::platform_types::size_t {
+			unsafe fn extern_capacity(&self) -> usize {
 				$extern_capacity(self.as_raw())
 			}
 
@@ -119,17 +118,17 @@ macro_rules! vector_extern {
 			}
 
 			#[inline]
-			unsafe fn extern_reserve(&mut self, additional: $crate::platform_types::size_t) {
+			unsafe fn extern_reserve(&mut self, additional: usize) {
 				$extern_reserve(self.as_raw_mut(), additional)
 			}
 
 			#[inline]
-			unsafe fn extern_remove(&mut self, index: $crate::platform_types::size_t) {
+			unsafe fn extern_remove(&mut self, index: usize) {
 				$extern_remove(self.as_raw_mut(), index)
 			}
 
 			#[inline]
-			unsafe fn extern_swap(&mut self, index1: $crate::platform_types::size_t, index2: $crate::platform_types::size_t) {
+			unsafe fn extern_swap(&mut self, index1: usize, index2: usize) {
 				$extern_swap(self.as_raw_mut(), index1, index2)
 			}
 
@@ -139,7 +138,7 @@ macro_rules! vector_extern {
 			}
 
 			#[inline]
-			unsafe fn extern_get(&self, index: $crate::platform_types::size_t) -> extern_receive!($type) {
+			unsafe fn extern_get(&self, index: usize) -> extern_receive!($type) {
 				return_send!(via ocvrs_return);
 				$extern_get(self.as_raw(), index, ocvrs_return.as_mut_ptr());
 				return_receive!(ocvrs_return => ret);
@@ -157,12 +156,12 @@ macro_rules! vector_extern {
 			}
 
 			#[inline]
-			unsafe fn extern_insert<'a>(&mut self, index: $crate::platform_types::size_t, val: extern_arg_send!($type: 'a)) {
+			unsafe fn extern_insert<'a>(&mut self, index: usize, val: extern_arg_send!($type: 'a)) {
 				$extern_insert(self.as_raw_mut(), index, val)
 			}
 
 			#[inline]
-			unsafe fn extern_set<'a>(&mut self, index: $crate::platform_types::size_t, val: extern_arg_send!($type: 'a)) {
+			unsafe fn extern_set<'a>(&mut self, index: usize, val: extern_arg_send!($type: 'a)) {
 				$extern_set(self.as_raw_mut(), index, val)
 			}
 		}
@@ -179,7 +178,7 @@ where
 	#[doc(hidden)]
 	unsafe fn extern_data_mut(&mut self) -> *mut T;
 	#[doc(hidden)]
-	unsafe fn extern_from_slice<'a>(data: *const T, len: size_t) -> extern_receive!(Vector<T>: 'a);
+	unsafe fn extern_from_slice<'a>(data: *const T, len: usize) -> extern_receive!(Vector<T>: 'a);
 }
 
 #[macro_export]
@@ -197,10 +196,7 @@ macro_rules! vector_copy_non_bool {
 	) -> extern_receive!($crate::core::Vector<$type>: 'a);
 			fn $extern_data_const(instance: extern_send!($crate::core::Vector<$type>)) -> *const $type;
 	fn $extern_data_mut(instance: extern_send!(mut $crate::core::Vector<$type>)) -> *mut $type;
-			fn $extern_from_slice<'a>(
-				data: *const $type,
-				len: $crate::platform_types::size_t,
-	) -> extern_receive!($crate::core::Vector<$type>: 'a);
+	fn $extern_from_slice<'a>(data: *const $type, len: usize) -> extern_receive!($crate::core::Vector<$type>: 'a);
 		}
 
 		impl $crate::core::Vector<$type>
@@ -245,7 +241,7 @@ macro_rules! vector_copy_non_bool {
 			}
 
 			#[inline]
-			unsafe fn extern_from_slice<'a>(data: *const $type, len: $crate::platform_types::size_t) -> extern_receive!(Self: 'a) {
+			unsafe fn extern_from_slice<'a>(data: *const $type, len: usize) -> extern_receive!(Self: 'a) {
 				$extern_from_slice(data, len)
 			}
 		}
@@ -0,0 +1,74 @@
+use crate::{
+	core::{GpuMat, MinMaxLoc, Scalar, Stream, ToInputArray},
+	cudaarithm,
+	prelude::*,
+	Result,
+};
+
+/// Finds the global minimum and maximum of the single channel `GpuMat` and returns them with their locations
+///
+/// Same as [cudaarithm::min_max_loc], but returns the results instead of filling the out-parameters.
+pub fn cuda_min_max_loc_values(src: &GpuMat, mask: Option<&GpuMat>) -> Result<MinMaxLoc> {
+	let mut out = MinMaxLoc::default();
+	cudaarithm::min_max_loc(
+		src,
+		&mut out.min_val,
+		&mut out.max_val,
+		&mut out.min_loc,
+		&mut out.max_loc,
+		mask,
+	)?;
+	Ok(out)
+}
+
+/// Per element arithmetic and reductions computed on the GPU, the results stay on the GPU
+///
+/// The operations are synchronous, use the `cudaarithm` functions accepting `Stream` to queue them asynchronously.
+pub trait GpuMatArithm: GpuMatTraitConst + ToInputArray {
+	/// `self + other`, saturated to the element type
+	fn add_gpu(&self, other: &GpuMat) -> Result<GpuMat> {
+		let mut out = GpuMat::default()?;
+		cudaarithm::add(self, other, &mut out, None::<&GpuMat>, -1, &mut Stream::null()?)?;
+		Ok(out)
+	}
+
+	/// `self - other`, saturated to the element type
+	fn subtract_gpu(&self, other: &GpuMat) -> Result<GpuMat> {
+		let mut out = GpuMat::default()?;
+		cudaarithm::subtract(self, other, &mut out, None::<&GpuMat>, -1, &mut Stream::null()?)?;
+		Ok(out)
+	}
+
+	/// `scale * self * other` per element, saturated to the element type
+	fn multiply_gpu(&self, other: &GpuMat, scale: f64) -> Result<GpuMat> {
+		let mut out = GpuMat::default()?;
+		cudaarithm::multiply(self, other, &mut out, scale, -1, &mut Stream::null()?)?;
+		Ok(out)
+	}
+
+	/// `scale * self / other` per element, the division by zero gives zero
+	fn divide_gpu(&self, other: &GpuMat, scale: f64) -> Result<GpuMat> {
+		let mut out = GpuMat::default()?;
+		cudaarithm::divide(self, other, &mut out, scale, -1, &mut Stream::null()?)?;
+		Ok(out)
+	}
+
+	/// Sum of the elements of every channel
+	fn sum_gpu(&self) -> Result<Scalar> {
+		cudaarithm::sum(self, None::<&GpuMat>)
+	}
+
+	/// Norm of the single channel array, `norm_type` is one of `NORM_INF`, `NORM_L1` or `NORM_L2`
+	fn norm_gpu(&self, norm_type: i32) -> Result<f64> {
+		cudaarithm::norm(self, norm_type, None::<&GpuMat>)
+	}
+
+	/// Minimum and maximum of the single channel array
+	fn min_max_gpu(&self) -> Result<(f64, f64)> {
+		let (mut min, mut max) = (0., 0.);
+		cudaarithm::min_max(self, &mut min, &mut max, None::<&GpuMat>)?;
+		Ok((min, max))
+	}
+}
+
+impl GpuMatArithm for GpuMat {}
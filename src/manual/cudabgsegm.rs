@@ -0,0 +1,38 @@
+use crate::{
+	core::{GpuMat, Ptr, Stream},
+	cudabgsegm::{self, CUDA_BackgroundSubtractorMOG2, CUDA_BackgroundSubtractorMOG2Const},
+	Result,
+};
+
+/// Creates the GPU MOG2 background subtractor with the OpenCV defaults: 500 frames of history, the variance threshold
+/// of 16 and the shadow detection
+pub fn create_cuda_mog2() -> Result<Ptr<dyn CUDA_BackgroundSubtractorMOG2>> {
+	cudabgsegm::create_background_subtractor_mog2(500, 16., true)
+}
+
+/// Background subtraction of the `GpuMat` frames returning the new masks
+pub trait CUDA_BackgroundSubtractorMOG2TraitManual: CUDA_BackgroundSubtractorMOG2 {
+	/// Updates the background model with `frame` and returns the `CV_8UC1` foreground mask, the shadows are marked
+	/// with 127 if their detection is enabled
+	///
+	/// Negative `learning_rate` picks the rate automatically from the history length.
+	fn apply_gpu(&mut self, frame: &GpuMat, learning_rate: f64) -> Result<GpuMat> {
+		self.enqueue_apply(frame, learning_rate, &mut Stream::null()?)
+	}
+
+	/// Enqueues the model update into `stream`, the returned mask is valid after the stream completes
+	fn enqueue_apply(&mut self, frame: &GpuMat, learning_rate: f64, stream: &mut Stream) -> Result<GpuMat> {
+		let mut fg_mask = GpuMat::default()?;
+		CUDA_BackgroundSubtractorMOG2::apply(self, frame, &mut fg_mask, learning_rate, stream)?;
+		Ok(fg_mask)
+	}
+
+	/// Returns the current background image computed from the model
+	fn background_image_gpu(&self) -> Result<GpuMat> {
+		let mut out = GpuMat::default()?;
+		CUDA_BackgroundSubtractorMOG2Const::get_background_image(self, &mut out, &mut Stream::null()?)?;
+		Ok(out)
+	}
+}
+
+impl<T: CUDA_BackgroundSubtractorMOG2 + ?Sized> CUDA_BackgroundSubtractorMOG2TraitManual for T {}
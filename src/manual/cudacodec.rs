@@ -0,0 +1,81 @@
+use crate::{
+	core::{GpuMat, Ptr, Size, Stream, Vector},
+	cudacodec::{self, SurfaceFormat, VideoReader, VideoReaderInitParams, VideoWriter},
+	Result,
+};
+
+/// Opens the file or the network stream for the hardware decoding with NVDEC, the rest of the parameters are the OpenCV
+/// defaults
+pub fn open_cuda_video_reader(source: &str) -> Result<Ptr<dyn VideoReader>> {
+	cudacodec::create_video_reader(source, &Vector::new(), VideoReaderInitParams::default()?)
+}
+
+/// Creates the file for the hardware encoding of the `CV_8UC3` BGR frames with NVENC
+pub fn create_cuda_video_writer(file_name: &str, frame_size: Size, fps: f64) -> Result<Ptr<dyn VideoWriter>> {
+	cudacodec::create_video_writer(file_name, frame_size, fps, SurfaceFormat::SF_BGR)
+}
+
+/// Iteration over the decoded frames of `VideoReader`
+pub trait VideoReaderTraitManual: VideoReader {
+	/// Decodes the next frame, returns `None` at the end of the video
+	fn read_frame_gpu(&mut self) -> Result<Option<GpuMat>> {
+		let mut frame = GpuMat::default()?;
+		Ok(if self.next_frame(&mut frame, &mut Stream::null()?)? {
+			Some(frame)
+		} else {
+			None
+		})
+	}
+
+	/// Iterator calling `next_frame()` until the end of the video, the frames stay on the GPU
+	fn frames_gpu(&mut self) -> GpuFrames<Self> {
+		GpuFrames {
+			reader: self,
+			done: false,
+		}
+	}
+}
+
+impl<T: VideoReader + ?Sized> VideoReaderTraitManual for T {}
+
+/// Iterator returned by [VideoReaderTraitManual::frames_gpu]
+pub struct GpuFrames<'r, T: ?Sized> {
+	reader: &'r mut T,
+	done: bool,
+}
+
+impl<T: VideoReader + ?Sized> Iterator for GpuFrames<'_, T> {
+	type Item = Result<GpuMat>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done {
+			return None;
+		}
+		match self.reader.read_frame_gpu() {
+			Ok(Some(frame)) => Some(Ok(frame)),
+			Ok(None) => {
+				self.done = true;
+				None
+			}
+			Err(e) => {
+				self.done = true;
+				Some(Err(e))
+			}
+		}
+	}
+}
+
+/// Encoding of the `GpuMat` frames
+pub trait VideoWriterTraitManual: VideoWriter {
+	/// Encodes the next frame, its size and format must match the ones the writer was created with
+	fn write_frame(&mut self, frame: &GpuMat) -> Result<()> {
+		self.write(frame, false)
+	}
+
+	/// Encodes the last frame and flushes the encoder
+	fn write_last_frame(&mut self, frame: &GpuMat) -> Result<()> {
+		self.write(frame, true)
+	}
+}
+
+impl<T: VideoWriter + ?Sized> VideoWriterTraitManual for T {}
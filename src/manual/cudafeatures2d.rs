@@ -0,0 +1,80 @@
+use std::fmt;
+
+use crate::{
+	core::{self, DMatch, GpuMat, KeyPoint, Ptr, Stream, Vector, NORM_HAMMING},
+	cudafeatures2d::{CUDA_DescriptorMatcher, CUDA_FastFeatureDetector, CUDA_Feature2DAsync, CUDA_ORB},
+	features2d::{FastFeatureDetector_DetectorType, ORB_ScoreType},
+	Result,
+};
+
+/// Creates the GPU ORB detector and extractor of up to `nfeatures` keypoints, the rest of the parameters are the OpenCV
+/// defaults
+pub fn create_cuda_orb(nfeatures: i32) -> Result<Ptr<dyn CUDA_ORB>> {
+	<dyn CUDA_ORB>::create(nfeatures, 1.2, 8, 31, 0, 2, ORB_ScoreType::HARRIS_SCORE as i32, 31, 20, false)
+}
+
+/// Creates the GPU FAST detector with the non-maximum suppression and the 9/16 neighborhood
+pub fn create_cuda_fast(threshold: i32) -> Result<Ptr<dyn CUDA_FastFeatureDetector>> {
+	<dyn CUDA_FastFeatureDetector>::create(threshold, true, FastFeatureDetector_DetectorType::TYPE_9_16 as i32, 5000)
+}
+
+/// Creates the brute force matcher for the binary descriptors like ORB
+pub fn create_cuda_hamming_matcher() -> Result<Ptr<dyn CUDA_DescriptorMatcher>> {
+	<dyn CUDA_DescriptorMatcher>::create_bf_matcher(NORM_HAMMING)
+}
+
+/// Keypoints downloaded to the host together with their descriptors kept on the GPU
+pub struct GpuFeatures {
+	pub keypoints: Vector<KeyPoint>,
+	/// One descriptor per row in the order of `keypoints`
+	pub descriptors: GpuMat,
+}
+
+impl fmt::Debug for GpuFeatures {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("GpuFeatures")
+			.field("keypoints", &self.keypoints)
+			.finish_non_exhaustive()
+	}
+}
+
+/// Feature extraction from the `GpuMat` images
+pub trait CUDA_Feature2DAsyncTraitManual: CUDA_Feature2DAsync {
+	/// Detects the keypoints in the `CV_8UC1` image and computes their descriptors, `mask` limits the detection to
+	/// its non-zero pixels
+	fn detect_and_compute_gpu(&mut self, image: &GpuMat, mask: Option<&GpuMat>) -> Result<GpuFeatures> {
+		let mut keypoints = Vector::new();
+		let mut descriptors = GpuMat::default()?;
+		match mask {
+			Some(mask) => self.detect_and_compute(image, mask, &mut keypoints, &mut descriptors, false)?,
+			None => self.detect_and_compute(image, &core::no_array(), &mut keypoints, &mut descriptors, false)?,
+		}
+		Ok(GpuFeatures { keypoints, descriptors })
+	}
+
+	/// Enqueues the detection and the description into `stream`
+	///
+	/// Returns the keypoints in the GPU layout and the descriptors, the keypoints can be downloaded with
+	/// `CUDA_Feature2DAsync::convert()` after the stream completes.
+	fn enqueue_detect_and_compute(&mut self, image: &GpuMat, stream: &mut Stream) -> Result<(GpuMat, GpuMat)> {
+		let mut keypoints = GpuMat::default()?;
+		let mut descriptors = GpuMat::default()?;
+		self.detect_and_compute_async(image, &core::no_array(), &mut keypoints, &mut descriptors, false, stream)?;
+		Ok((keypoints, descriptors))
+	}
+}
+
+impl<T: CUDA_Feature2DAsync + ?Sized> CUDA_Feature2DAsyncTraitManual for T {}
+
+/// Filtered matching of the descriptors on the GPU
+pub trait CUDA_DescriptorMatcherTraitManual: CUDA_DescriptorMatcher {
+	/// Finds the best matches of the query descriptors among the train descriptors that pass the ratio test, see
+	/// [core::ratio_test]
+	fn knn_match_ratio(&mut self, query_descriptors: &GpuMat, train_descriptors: &GpuMat, ratio: f32) -> Result<Vector<DMatch>> {
+		let mut knn_matches = Vector::new();
+		self.knn_match(query_descriptors, train_descriptors, &mut knn_matches, 2, None, false)?;
+		core::ratio_test(&knn_matches, ratio)
+	}
+}
+
+impl<T: CUDA_DescriptorMatcher + ?Sized> CUDA_DescriptorMatcherTraitManual for T {}
@@ -0,0 +1,38 @@
+use crate::{
+	core::{GpuMat, Mat, Point, Ptr, Size, Stream, BORDER_DEFAULT},
+	cudafilters::{self, Filter},
+	imgproc::MorphTypes,
+	Result,
+};
+
+/// Creates the Gaussian blur filter of the images of `src_type`, `sigma` applies to both directions
+///
+/// `ksize` must be odd and at most 32, `Size::default()` derives it from `sigma`.
+pub fn create_cuda_gaussian_filter(src_type: i32, ksize: Size, sigma: f64) -> Result<Ptr<dyn Filter>> {
+	cudafilters::create_gaussian_filter(src_type, src_type, ksize, sigma, 0., BORDER_DEFAULT, -1)
+}
+
+/// Creates the Sobel derivative filter of the order `dx`, `dy` producing the images of `dst_type`
+///
+/// Use the signed `dst_type` like `CV_16S` or `CV_32F` to keep the negative derivatives of the 8-bit images.
+pub fn create_cuda_sobel_filter(src_type: i32, dst_type: i32, dx: i32, dy: i32, ksize: i32) -> Result<Ptr<dyn Filter>> {
+	cudafilters::create_sobel_filter(src_type, dst_type, dx, dy, ksize, 1., BORDER_DEFAULT, -1)
+}
+
+/// Creates the morphology filter of the `CV_8UC1` or `CV_8UC4` images with the structuring element `kernel` anchored
+/// at its center
+pub fn create_cuda_morphology_filter(op: MorphTypes, src_type: i32, kernel: &Mat, iterations: i32) -> Result<Ptr<dyn Filter>> {
+	cudafilters::create_morphology_filter(op as i32, src_type, kernel, Point::new(-1, -1), iterations)
+}
+
+/// Filtering returning the new `GpuMat`
+pub trait FilterTraitManual: Filter {
+	/// Applies the filter to the image on the GPU, the type of `src` must match the one the filter was created for
+	fn apply_gpu(&mut self, src: &GpuMat) -> Result<GpuMat> {
+		let mut out = GpuMat::default()?;
+		self.apply(src, &mut out, &mut Stream::null()?)?;
+		Ok(out)
+	}
+}
+
+impl<T: Filter + ?Sized> FilterTraitManual for T {}
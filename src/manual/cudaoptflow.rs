@@ -0,0 +1,50 @@
+use crate::{
+	core::{GpuMat, Size, Stream},
+	cudaoptflow::{
+		CUDA_BroxOpticalFlow, CUDA_DenseOpticalFlow, CUDA_DensePyrLKOpticalFlow, CUDA_FarnebackOpticalFlow,
+		CUDA_OpticalFlowDual_TVL1,
+	},
+	Result,
+};
+
+/// Dense optical flow algorithm running on the GPU created by [create_cuda_dense_optical_flow]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CudaDenseFlowKind {
+	/// Variational method by Brox et al., the input images must be `CV_32FC1` with the values in the range [0, 1]
+	Brox,
+	/// Polynomial expansion method by Farneback
+	Farneback,
+	/// Dual TV-L1 method, the most precise and the slowest one
+	TVL1,
+	/// Pyramidal Lucas-Kanade computed for every pixel
+	PyrLK,
+}
+
+/// Creates the dense optical flow algorithm of the passed kind with the OpenCV defaults
+pub fn create_cuda_dense_optical_flow(kind: CudaDenseFlowKind) -> Result<Box<dyn CUDA_DenseOpticalFlow>> {
+	Ok(match kind {
+		CudaDenseFlowKind::Brox => Box::new(<dyn CUDA_BroxOpticalFlow>::create(0.197, 50., 0.8, 5, 150, 10)?),
+		CudaDenseFlowKind::Farneback => Box::new(<dyn CUDA_FarnebackOpticalFlow>::create(5, 0.5, false, 13, 10, 5, 1.1, 0)?),
+		CudaDenseFlowKind::TVL1 => Box::new(<dyn CUDA_OpticalFlowDual_TVL1>::create(
+			0.25, 0.15, 0.3, 5, 5, 0.01, 300, 0.8, 0., false,
+		)?),
+		CudaDenseFlowKind::PyrLK => Box::new(<dyn CUDA_DensePyrLKOpticalFlow>::create(Size::new(13, 13), 3, 30, false)?),
+	})
+}
+
+/// Optical flow computation returning the new flow field
+pub trait CUDA_DenseOpticalFlowTraitManual: CUDA_DenseOpticalFlow {
+	/// Computes the flow from `prev` to `next` and returns the `CV_32FC2` field with the displacement of every pixel
+	fn calc_flow(&mut self, prev: &GpuMat, next: &GpuMat) -> Result<GpuMat> {
+		self.enqueue_calc_flow(prev, next, &mut Stream::null()?)
+	}
+
+	/// Enqueues the flow computation into `stream`, the result is valid after the stream completes
+	fn enqueue_calc_flow(&mut self, prev: &GpuMat, next: &GpuMat, stream: &mut Stream) -> Result<GpuMat> {
+		let mut flow = GpuMat::default()?;
+		self.calc(prev, next, &mut flow, stream)?;
+		Ok(flow)
+	}
+}
+
+impl<T: CUDA_DenseOpticalFlow + ?Sized> CUDA_DenseOpticalFlowTraitManual for T {}
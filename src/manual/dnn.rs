@@ -1,7 +1,13 @@
+pub use blob::*;
+pub use net::*;
+
+mod blob;
+mod net;
+
 use std::{ffi::c_void, fmt};
 
 use crate::{
-	dnn::{DictValue, LayerParams},
+	dnn::{DictValue, LayerParams, Net},
 	prelude::*,
 	sys, Result,
 };
@@ -34,3 +40,71 @@ impl LayerParams {
 		ret.into_result().map(|ptr| unsafe { LayerParams::from_raw(ptr) })
 	}
 }
+
+/// Reads a network model stored in the ONNX format from the in-memory buffer
+///
+/// Unlike `read_net_from_onnx_str()` the buffer is passed to OpenCV without copying and can contain 0 bytes.
+#[inline]
+pub fn read_net_from_onnx_bytes(buffer: &[u8]) -> Result<Net> {
+	return_send!(via ocvrs_return);
+	unsafe { sys::cv_dnn_readNetFromONNX_const_charX_size_t(buffer.as_ptr().cast(), buffer.len(), ocvrs_return.as_mut_ptr()) };
+	return_receive!(unsafe ocvrs_return => ret);
+	ret.into_result().map(|ptr| unsafe { Net::from_raw(ptr) })
+}
+
+/// Reads a network model stored in the Caffe format from the in-memory buffers, `proto` can be empty
+///
+/// Unlike `read_net_from_caffe_str()` the buffers are passed to OpenCV without copying and can contain 0 bytes.
+#[inline]
+pub fn read_net_from_caffe_bytes(proto: &[u8], model: &[u8]) -> Result<Net> {
+	return_send!(via ocvrs_return);
+	unsafe {
+		sys::cv_dnn_readNetFromCaffe_const_charX_size_t_const_charX_size_t(
+			proto.as_ptr().cast(),
+			proto.len(),
+			model.as_ptr().cast(),
+			model.len(),
+			ocvrs_return.as_mut_ptr(),
+		)
+	};
+	return_receive!(unsafe ocvrs_return => ret);
+	ret.into_result().map(|ptr| unsafe { Net::from_raw(ptr) })
+}
+
+/// Reads a network model stored in the Darknet format from the in-memory buffers, `model` can be empty
+///
+/// Unlike `read_net_from_darknet_str()` the buffers are passed to OpenCV without copying and can contain 0 bytes.
+#[inline]
+pub fn read_net_from_darknet_bytes(cfg: &[u8], model: &[u8]) -> Result<Net> {
+	return_send!(via ocvrs_return);
+	unsafe {
+		sys::cv_dnn_readNetFromDarknet_const_charX_size_t_const_charX_size_t(
+			cfg.as_ptr().cast(),
+			cfg.len(),
+			model.as_ptr().cast(),
+			model.len(),
+			ocvrs_return.as_mut_ptr(),
+		)
+	};
+	return_receive!(unsafe ocvrs_return => ret);
+	ret.into_result().map(|ptr| unsafe { Net::from_raw(ptr) })
+}
+
+/// Reads a network model stored in the TensorFlow format from the in-memory buffers, `config` can be empty
+///
+/// Unlike `read_net_from_tensorflow_str()` the buffers are passed to OpenCV without copying and can contain 0 bytes.
+#[inline]
+pub fn read_net_from_tensorflow_bytes(model: &[u8], config: &[u8]) -> Result<Net> {
+	return_send!(via ocvrs_return);
+	unsafe {
+		sys::cv_dnn_readNetFromTensorflow_const_charX_size_t_const_charX_size_t(
+			model.as_ptr().cast(),
+			model.len(),
+			config.as_ptr().cast(),
+			config.len(),
+			ocvrs_return.as_mut_ptr(),
+		)
+	};
+	return_receive!(unsafe ocvrs_return => ret);
+	ret.into_result().map(|ptr| unsafe { Net::from_raw(ptr) })
+}
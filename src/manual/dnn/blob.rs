@@ -0,0 +1,83 @@
+use crate::{
+	core::{self, Mat, Scalar, Size, ToInputArray, CV_32F, CV_8U},
+	dnn, Error, Result,
+};
+
+/// Preprocessing of the images converted into the network input blob by [blob_from_image_with_params]
+///
+/// Starts with the same values as the C++ default parameters of `dnn::blob_from_image()`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BlobParams {
+	/// Multiplier of the image values, applied after the `mean` subtraction
+	pub scale_factor: f64,
+	/// Spatial size of the blob, empty size keeps the size of the image
+	pub size: Size,
+	/// Values subtracted from the channels, in the channel order of the blob (i.e. after `swap_rb`)
+	pub mean: Scalar,
+	/// Whether the first and the last channels of the 3-channel image are swapped, e.g. BGR to RGB
+	pub swap_rb: bool,
+	/// Whether the image is resized preserving the aspect ratio and cropped from the center, otherwise it's resized
+	/// directly to `size`
+	pub crop: bool,
+	/// Depth of the blob, `core::CV_32F` or `core::CV_8U`
+	pub ddepth: i32,
+}
+
+impl Default for BlobParams {
+	fn default() -> Self {
+		Self {
+			scale_factor: 1.,
+			size: Size::default(),
+			mean: Scalar::default(),
+			swap_rb: false,
+			crop: false,
+			ddepth: CV_32F,
+		}
+	}
+}
+
+impl BlobParams {
+	fn validate(&self) -> Result<()> {
+		if self.ddepth != CV_32F && self.ddepth != CV_8U {
+			return Err(Error::new(
+				core::StsUnsupportedFormat,
+				format!("Blob depth must be CV_32F or CV_8U, but got: {}", self.ddepth),
+			));
+		}
+		if self.ddepth == CV_8U && (self.scale_factor != 1. || self.mean != Scalar::default()) {
+			return Err(Error::new(
+				core::StsBadArg,
+				"Scale factor and mean can only be applied to the CV_32F blob",
+			));
+		}
+		Ok(())
+	}
+}
+
+/// Creates the 4-dimensional NCHW blob from the image, typed counterpart of `dnn::blob_from_image()`
+pub fn blob_from_image_with_params(image: &(impl ToInputArray + ?Sized), params: &BlobParams) -> Result<Mat> {
+	params.validate()?;
+	dnn::blob_from_image(
+		image,
+		params.scale_factor,
+		params.size,
+		params.mean,
+		params.swap_rb,
+		params.crop,
+		params.ddepth,
+	)
+}
+
+/// Creates the 4-dimensional NCHW blob from the batch of images, typed counterpart of `dnn::blob_from_images()`
+pub fn blob_from_images_with_params(images: &(impl ToInputArray + ?Sized), params: &BlobParams) -> Result<Mat> {
+	params.validate()?;
+	dnn::blob_from_images(
+		images,
+		params.scale_factor,
+		params.size,
+		params.mean,
+		params.swap_rb,
+		params.crop,
+		params.ddepth,
+	)
+}
@@ -0,0 +1,128 @@
+use std::{convert::TryFrom, time::Duration};
+
+use crate::{
+	core::{AsyncArray, AsyncArrayTraitConst, Mat, Vector},
+	dnn::{Backend, LayerTraitConst, MatShape, NetTrait, NetTraitConst, Target},
+	Result,
+};
+
+/// Layer of the network returned by [NetTraitConstManual::layers]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LayerInfo {
+	/// Identifier of the layer in the network
+	pub id: i32,
+	/// Unique name of the layer
+	pub name: String,
+	/// Type of the layer, e.g. `Convolution`
+	pub typ: String,
+}
+
+/// Shapes of the outputs of a single layer returned by [NetTraitConstManual::output_shapes]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LayerShapes {
+	/// Identifier of the layer in the network
+	pub id: i32,
+	/// Unique name of the layer
+	pub name: String,
+	/// Shape of every output blob of the layer, in the NCHW order for the 4-dimensional blobs
+	pub shapes: Vec<Vec<i32>>,
+}
+
+/// Typed queries of the network structure
+pub trait NetTraitConstManual: NetTraitConst {
+	/// All the layers of the network in the order of their identifiers, the implicit input layer is not included
+	fn layers(&self) -> Result<Vec<LayerInfo>> {
+		self
+			.get_layer_names()?
+			.into_iter()
+			.map(|name| {
+				let id = self.get_layer_id(&name)?;
+				let typ = self.get_layer(id)?.typ();
+				Ok(LayerInfo { id, name, typ })
+			})
+			.collect()
+	}
+
+	/// Shapes of the outputs of the unconnected output layers given the shapes of the network inputs
+	///
+	/// The shapes are inferred without running the network, `input_shapes` must have one shape per network input.
+	fn output_shapes(&self, input_shapes: &[&[i32]]) -> Result<Vec<LayerShapes>> {
+		let input_shapes = input_shapes
+			.iter()
+			.map(|shape| MatShape::from_slice(shape))
+			.collect::<Vector<MatShape>>();
+		let ids = self.get_unconnected_out_layers()?;
+		let names = self.get_unconnected_out_layers_names()?;
+		ids.into_iter()
+			.zip(names)
+			.map(|(id, name)| {
+				let mut in_shapes = Vector::new();
+				let mut out_shapes = Vector::new();
+				self.get_layer_shapes_1(&input_shapes, id, &mut in_shapes, &mut out_shapes)?;
+				Ok(LayerShapes {
+					id,
+					name,
+					shapes: out_shapes.into_iter().map(|shape| shape.to_vec()).collect(),
+				})
+			})
+			.collect()
+	}
+}
+
+impl<T: NetTraitConst + ?Sized> NetTraitConstManual for T {}
+
+/// Typed configuration and asynchronous execution of the network
+pub trait NetTraitManual: NetTrait + NetTraitConstManual {
+	/// Typed counterpart of `set_preferable_backend()`
+	fn set_backend(&mut self, backend: Backend) -> Result<()> {
+		self.set_preferable_backend(backend as i32)
+	}
+
+	/// Typed counterpart of `set_preferable_target()`
+	fn set_target(&mut self, target: Target) -> Result<()> {
+		self.set_preferable_target(target as i32)
+	}
+
+	/// Starts the computation of the output of the layer with the passed name, empty for the whole network
+	///
+	/// Only supported by the `DNN_BACKEND_INFERENCE_ENGINE` backend, see `NetTrait::forward_async()`.
+	fn forward_async_output(&mut self, output_name: &str) -> Result<AsyncForward> {
+		self.forward_async(output_name).map(AsyncForward)
+	}
+}
+
+impl<T: NetTrait + ?Sized> NetTraitManual for T {}
+
+/// Pending result of [NetTraitManual::forward_async_output]
+pub struct AsyncForward(AsyncArray);
+
+impl AsyncForward {
+	/// Waits for the result up to `timeout`, returns whether it's ready
+	pub fn wait_for(&self, timeout: Duration) -> Result<bool> {
+		self.0.wait_for(timeout_ns(timeout))
+	}
+
+	/// Waits for the result up to `timeout` and returns it, `None` if it's not ready yet
+	///
+	/// The result can only be retrieved once.
+	pub fn get_timeout(&mut self, timeout: Duration) -> Result<Option<Mat>> {
+		let mut out = Mat::default();
+		Ok(self.0.get_with_timeout(&mut out, timeout_ns(timeout))?.then(|| out))
+	}
+
+	/// Blocks until the result is ready and returns it
+	pub fn get(self) -> Result<Mat> {
+		let mut out = Mat::default();
+		self.0.get(&mut out)?;
+		Ok(out)
+	}
+
+	/// Underlying `AsyncArray`
+	pub fn into_inner(self) -> AsyncArray {
+		self.0
+	}
+}
+
+fn timeout_ns(timeout: Duration) -> i64 {
+	i64::try_from(timeout.as_nanos()).unwrap_or(i64::MAX)
+}
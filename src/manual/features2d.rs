@@ -1,6 +1,12 @@
 use std::ffi::c_void;
 
-use crate::{features2d::ORB, sys, traits::Boxed, types, Result};
+use crate::{
+	core::{self, DMatch, Vector},
+	features2d::{DescriptorMatcherConst, ORB},
+	sys,
+	traits::Boxed,
+	types, Result,
+};
 
 impl dyn ORB + '_ {
 	pub fn default() -> Result<types::PtrOfORB> {
@@ -13,3 +19,40 @@ impl dyn ORB + '_ {
 		ret.into_result().map(|ptr| unsafe { types::PtrOfORB::from_raw(ptr) })
 	}
 }
+
+/// Filtered matching of the point descriptors
+pub trait DescriptorMatcherFilterTrait: DescriptorMatcherConst {
+	/// Finds the best matches of the query descriptors among the train descriptors that pass the ratio test
+	///
+	/// The 2 nearest neighbors of every query descriptor are looked up and the best one is kept only if its distance is
+	/// less than `ratio` times the distance of the second one, see [core::ratio_test].
+	fn knn_match_ratio(
+		&self,
+		query_descriptors: &dyn core::ToInputArray,
+		train_descriptors: &dyn core::ToInputArray,
+		ratio: f32,
+	) -> Result<Vector<DMatch>> {
+		let mut knn_matches = Vector::new();
+		self.knn_train_match(query_descriptors, train_descriptors, &mut knn_matches, 2, None, false)?;
+		core::ratio_test(&knn_matches, ratio)
+	}
+
+	/// Finds the best matches of the query descriptors among the train descriptors that are also the best matches in the
+	/// opposite direction
+	///
+	/// Both directions are matched and only the mutual best matches are kept, see [core::cross_check]. Unlike the
+	/// `cross_check` flag of `BFMatcher` it works with any matcher, e.g. FLANN based.
+	fn cross_check_match(
+		&self,
+		query_descriptors: &dyn core::ToInputArray,
+		train_descriptors: &dyn core::ToInputArray,
+	) -> Result<Vector<DMatch>> {
+		let mut forward = Vector::new();
+		self.train_match(query_descriptors, train_descriptors, &mut forward, None)?;
+		let mut backward = Vector::new();
+		self.train_match(train_descriptors, query_descriptors, &mut backward, None)?;
+		Ok(core::cross_check(&forward, &backward))
+	}
+}
+
+impl<T: DescriptorMatcherConst + ?Sized> DescriptorMatcherFilterTrait for T {}
@@ -0,0 +1,107 @@
+use std::path::Path;
+
+use crate::{
+	core::{self, Mat, Point, Ptr, Scalar, Size},
+	freetype::{self, FreeType2},
+	imgproc::LineTypes,
+	Error, Result,
+};
+
+/// Creates the text renderer with the TrueType/OpenType font loaded from the file
+///
+/// `face_index` selects the face in the font collections (`.ttc`), it's 0 for the single font files.
+pub fn load_font(path: impl AsRef<Path>, face_index: i32) -> Result<Ptr<dyn FreeType2>> {
+	let mut out = freetype::create_free_type2()?;
+	out.load_font_file(path, face_index)?;
+	Ok(out)
+}
+
+/// Appearance of the text drawn with [FreeType2TraitManual::draw_text]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TextStyle {
+	/// Height of the font in pixels
+	pub font_height: i32,
+	pub color: Scalar,
+	/// Thickness of the glyph outlines, negative value fills the glyphs
+	pub thickness: i32,
+	pub line_type: LineTypes,
+	/// Whether the image origin is at the bottom-left corner instead of the top-left one
+	pub bottom_left_origin: bool,
+}
+
+impl Default for TextStyle {
+	/// Filled antialiased black text 20 pixels high
+	fn default() -> Self {
+		Self {
+			font_height: 20,
+			color: Scalar::all(0.),
+			thickness: -1,
+			line_type: LineTypes::LINE_AA,
+			bottom_left_origin: false,
+		}
+	}
+}
+
+impl TextStyle {
+	pub fn with_font_height(self, font_height: i32) -> Self {
+		Self { font_height, ..self }
+	}
+
+	pub fn with_color(self, color: Scalar) -> Self {
+		Self { color, ..self }
+	}
+
+	/// Draws the glyph outlines with the passed thickness instead of filling them
+	pub fn with_outline(self, thickness: i32) -> Self {
+		Self { thickness, ..self }
+	}
+
+	pub fn with_line_type(self, line_type: LineTypes) -> Self {
+		Self { line_type, ..self }
+	}
+}
+
+/// Font loading from the paths and text rendering with [TextStyle]
+pub trait FreeType2TraitManual: FreeType2 {
+	/// Loads the TrueType/OpenType font replacing the previously loaded one, see [load_font]
+	fn load_font_file(&mut self, path: impl AsRef<Path>, face_index: i32) -> Result<()>
+	where
+		Self: Sized,
+	{
+		let path = path.as_ref();
+		// OpenCV only reports the missing font through the FreeType error code
+		if !path.is_file() {
+			return Err(Error::new(
+				core::StsObjectNotFound,
+				format!("Font file not found: {}", path.display()),
+			));
+		}
+		self.load_font_data(&path.to_string_lossy(), face_index)
+	}
+
+	/// Draws the UTF-8 text with its left end of the baseline at `org`, the characters missing in the font are
+	/// rendered as the replacement glyph
+	///
+	/// The image must be `CV_8UC3`.
+	fn draw_text(&mut self, img: &mut Mat, text: &str, org: Point, style: &TextStyle) -> Result<()> {
+		self.put_text(
+			img,
+			text,
+			org,
+			style.font_height,
+			style.color,
+			style.thickness,
+			style.line_type as i32,
+			style.bottom_left_origin,
+		)
+	}
+
+	/// Size of the bounding box of the rendered text and the offset of its lowest point below the baseline
+	fn measure_text(&mut self, text: &str, style: &TextStyle) -> Result<(Size, i32)> {
+		let mut baseline = 0;
+		let size = self.get_text_size(text, style.font_height, style.thickness, &mut baseline)?;
+		Ok((size, baseline))
+	}
+}
+
+impl<T: FreeType2 + ?Sized> FreeType2TraitManual for T {}
@@ -0,0 +1,340 @@
+use std::sync::{
+	atomic::{AtomicI32, Ordering},
+	Arc, Mutex, PoisonError,
+};
+
+#[cfg(not(ocvrs_opencv_branch_32))]
+use crate::core::{Rect, ToInputArray, Vector};
+#[cfg(ocvrs_has_module_imgproc)]
+use crate::{core::Mat, imgproc, prelude::*};
+use crate::{
+	core::Point,
+	highgui::{self, MouseEventFlags},
+	Result,
+};
+
+/// Mouse event passed to the callback set by [set_mouse_callback]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MouseEvent {
+	/// Mouse pointer has moved over the window
+	Move,
+	LeftButtonDown,
+	RightButtonDown,
+	MiddleButtonDown,
+	LeftButtonUp,
+	RightButtonUp,
+	MiddleButtonUp,
+	LeftButtonDoubleClick,
+	RightButtonDoubleClick,
+	MiddleButtonDoubleClick,
+	/// Vertical scrolling, positive and negative deltas mean forward and backward scrolling respectively
+	Wheel(i32),
+	/// Horizontal scrolling, positive and negative deltas mean right and left scrolling respectively
+	HorizontalWheel(i32),
+	/// Event code unknown to this version of the crate
+	Other(i32),
+}
+
+impl MouseEvent {
+	/// Creates the event from the raw `event` and `flags` values as passed to `cv::MouseCallback`
+	pub fn from_raw(event: i32, flags: i32) -> Self {
+		match event {
+			highgui::EVENT_MOUSEMOVE => Self::Move,
+			highgui::EVENT_LBUTTONDOWN => Self::LeftButtonDown,
+			highgui::EVENT_RBUTTONDOWN => Self::RightButtonDown,
+			highgui::EVENT_MBUTTONDOWN => Self::MiddleButtonDown,
+			highgui::EVENT_LBUTTONUP => Self::LeftButtonUp,
+			highgui::EVENT_RBUTTONUP => Self::RightButtonUp,
+			highgui::EVENT_MBUTTONUP => Self::MiddleButtonUp,
+			highgui::EVENT_LBUTTONDBLCLK => Self::LeftButtonDoubleClick,
+			highgui::EVENT_RBUTTONDBLCLK => Self::RightButtonDoubleClick,
+			highgui::EVENT_MBUTTONDBLCLK => Self::MiddleButtonDoubleClick,
+			// same as cv::getMouseWheelDelta()
+			highgui::EVENT_MOUSEWHEEL => Self::Wheel(flags >> 16),
+			highgui::EVENT_MOUSEHWHEEL => Self::HorizontalWheel(flags >> 16),
+			event => Self::Other(event),
+		}
+	}
+}
+
+/// Set of `MouseEventFlags` active during the mouse event
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MouseFlags(i32);
+
+impl MouseFlags {
+	/// Creates the flags from the raw value as passed to `cv::MouseCallback`, the wheel delta in the upper 16 bits
+	/// is dropped
+	pub fn from_bits(flags: i32) -> Self {
+		Self(flags & 0xFFFF)
+	}
+
+	pub fn bits(self) -> i32 {
+		self.0
+	}
+
+	pub fn contains(self, flag: MouseEventFlags) -> bool {
+		self.0 & flag as i32 != 0
+	}
+
+	pub fn left_button(self) -> bool {
+		self.contains(MouseEventFlags::EVENT_FLAG_LBUTTON)
+	}
+
+	pub fn right_button(self) -> bool {
+		self.contains(MouseEventFlags::EVENT_FLAG_RBUTTON)
+	}
+
+	pub fn middle_button(self) -> bool {
+		self.contains(MouseEventFlags::EVENT_FLAG_MBUTTON)
+	}
+
+	pub fn ctrl(self) -> bool {
+		self.contains(MouseEventFlags::EVENT_FLAG_CTRLKEY)
+	}
+
+	pub fn shift(self) -> bool {
+		self.contains(MouseEventFlags::EVENT_FLAG_SHIFTKEY)
+	}
+
+	pub fn alt(self) -> bool {
+		self.contains(MouseEventFlags::EVENT_FLAG_ALTKEY)
+	}
+}
+
+/// Sets mouse handler for the specified window
+///
+/// The closure is stored until it's replaced by another call for the same window or removed with
+/// [remove_mouse_callback]. Use [set_mouse_callback_raw](highgui::set_mouse_callback_raw) to receive the raw values.
+///
+/// ## Parameters
+/// * winname: Name of the window.
+/// * on_mouse: Called with the event, the position of the mouse pointer in the image coordinates and the active flags.
+pub fn set_mouse_callback(
+	winname: &str,
+	mut on_mouse: impl FnMut(MouseEvent, Point, MouseFlags) + Send + Sync + 'static,
+) -> Result<()> {
+	highgui::set_mouse_callback_raw(
+		winname,
+		Some(Box::new(move |event, x, y, flags| {
+			on_mouse(
+				MouseEvent::from_raw(event, flags),
+				Point::new(x, y),
+				MouseFlags::from_bits(flags),
+			)
+		})),
+	)
+}
+
+/// Removes the mouse handler previously set for the specified window by [set_mouse_callback]
+pub fn remove_mouse_callback(winname: &str) -> Result<()> {
+	highgui::set_mouse_callback_raw(winname, None)
+}
+
+/// Creates a trackbar and attaches it to the specified window, the trackbar position is stored into `value`
+///
+/// The slider is initially placed at the current `value`. Every change of the slider position is stored into `value`,
+/// so it can be read from any thread. Storing into `value` doesn't move the slider, use
+/// [set_trackbar_pos](highgui::set_trackbar_pos) for that, it will also update `value`.
+///
+/// ## Parameters
+/// * trackbarname: Name of the created trackbar.
+/// * winname: Name of the window that will be used as a parent of the created trackbar.
+/// * value: Shared value reflecting the position of the slider.
+/// * count: Maximal position of the slider. The minimal position is always 0.
+pub fn create_trackbar_bound(trackbarname: &str, winname: &str, value: Arc<AtomicI32>, count: i32) -> Result<i32> {
+	let initial = value.load(Ordering::Relaxed);
+	create_trackbar_with_callback(trackbarname, winname, initial, count, move |pos| {
+		value.store(pos, Ordering::Relaxed)
+	})
+}
+
+/// Creates a trackbar and attaches it to the specified window, `on_change` is called every time the slider position
+/// changes
+///
+/// ## Parameters
+/// * trackbarname: Name of the created trackbar.
+/// * winname: Name of the window that will be used as a parent of the created trackbar.
+/// * initial: Initial position of the slider.
+/// * count: Maximal position of the slider. The minimal position is always 0.
+/// * on_change: Called with the new position of the slider.
+pub fn create_trackbar_with_callback(
+	trackbarname: &str,
+	winname: &str,
+	initial: i32,
+	count: i32,
+	on_change: impl FnMut(i32) + Send + Sync + 'static,
+) -> Result<i32> {
+	// no value pointer is passed so there is nothing that OpenCV could outlive
+	let ret = unsafe { highgui::create_trackbar(trackbarname, winname, None, count, Some(Box::new(on_change))) }?;
+	highgui::set_trackbar_pos(trackbarname, winname, initial)?;
+	Ok(ret)
+}
+
+/// Allows the user to select a ROI in the image, returns `None` if the selection was canceled
+///
+/// Same as [select_roi_for_window](highgui::select_roi_for_window), which returns an empty rectangle when the
+/// selection is canceled with the `c` key.
+#[cfg(not(ocvrs_opencv_branch_32))]
+pub fn select_roi_confirmed(
+	window_name: &str,
+	img: &(impl ToInputArray + ?Sized),
+	show_crosshair: bool,
+	from_center: bool,
+) -> Result<Option<Rect>> {
+	let roi = highgui::select_roi_for_window(window_name, img, show_crosshair, from_center)?;
+	Ok(if roi.empty() {
+		None
+	} else {
+		Some(roi)
+	})
+}
+
+/// Allows the user to select multiple ROIs in the image and returns them
+///
+/// Same as [select_rois](highgui::select_rois), the selection of every ROI is confirmed with `Space` or `Enter`, the
+/// whole process is finished with `Esc`.
+#[cfg(not(ocvrs_opencv_branch_32))]
+pub fn select_rois_list(
+	window_name: &str,
+	img: &(impl ToInputArray + ?Sized),
+	show_crosshair: bool,
+	from_center: bool,
+) -> Result<Vec<Rect>> {
+	let mut rois = Vector::new();
+	highgui::select_rois(window_name, img, &mut rois, show_crosshair, from_center)?;
+	Ok(rois.to_vec())
+}
+
+/// Meaning of a key pressed during an interactive selection
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SelectionKey {
+	/// `Enter` or `Space`
+	Confirm,
+	/// `Esc` or `c`
+	Cancel,
+	/// `r`, starts the selection over
+	Reset,
+	/// Any other key or no key at all
+	Other,
+}
+
+impl SelectionKey {
+	/// Interprets the code returned by [wait_key](highgui::wait_key)
+	pub fn from_key(key: i32) -> Self {
+		if key < 0 {
+			return Self::Other;
+		}
+		match (key & 0xFF) as u8 {
+			b'\r' | b'\n' | b' ' => Self::Confirm,
+			27 | b'c' => Self::Cancel,
+			b'r' => Self::Reset,
+			_ => Self::Other,
+		}
+	}
+}
+
+/// State of the interactive selection of a segment by clicking its two end points
+///
+/// The first left click sets the start point, the second one sets the end point, the next click starts a new segment.
+/// Can be used to build the custom selection tools, [select_segment] is built on top of it.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct SegmentSelection {
+	start: Option<Point>,
+	end: Option<Point>,
+	hover: Option<Point>,
+}
+
+impl SegmentSelection {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Updates the selection with the mouse event in the image coordinates
+	pub fn handle_mouse(&mut self, event: MouseEvent, pt: Point) {
+		match event {
+			MouseEvent::Move => self.hover = Some(pt),
+			MouseEvent::LeftButtonDown => {
+				if self.start.is_none() || self.end.is_some() {
+					self.start = Some(pt);
+					self.end = None;
+				} else {
+					self.end = Some(pt);
+				}
+			}
+			_ => {}
+		}
+	}
+
+	/// Drops the selected points
+	pub fn reset(&mut self) {
+		self.start = None;
+		self.end = None;
+	}
+
+	/// Selected segment if both points are set
+	pub fn segment(&self) -> Option<(Point, Point)> {
+		self.start.zip(self.end)
+	}
+
+	/// Segment to show to the user, ends at the mouse pointer until the end point is set
+	pub fn preview(&self) -> Option<(Point, Point)> {
+		self.segment().or_else(|| self.start.zip(self.hover))
+	}
+
+	/// Last known position of the mouse pointer
+	pub fn hover(&self) -> Option<Point> {
+		self.hover
+	}
+}
+
+/// Allows the user to select a segment in the image by clicking its two end points
+///
+/// The image is shown in the window `window_name`, the selected segment is confirmed with `Space` or `Enter`, `r`
+/// starts over and `Esc` or `c` cancels the selection. Returns `None` if the selection was canceled or the window was
+/// closed.
+#[cfg(ocvrs_has_module_imgproc)]
+pub fn select_segment(window_name: &str, img: &Mat, show_crosshair: bool) -> Result<Option<(Point, Point)>> {
+	const CROSSHAIR_SIZE: i32 = 10;
+	highgui::named_window(window_name, highgui::WINDOW_AUTOSIZE)?;
+	let selection = Arc::new(Mutex::new(SegmentSelection::new()));
+	set_mouse_callback(window_name, {
+		let selection = Arc::clone(&selection);
+		move |event, pt, _| {
+			selection
+				.lock()
+				.unwrap_or_else(PoisonError::into_inner)
+				.handle_mouse(event, pt)
+		}
+	})?;
+	let color = imgproc::Palette::global().accent();
+	let out = loop {
+		let current = *selection.lock().unwrap_or_else(PoisonError::into_inner);
+		let mut frame = img.try_clone()?;
+		if let Some((start, end)) = current.preview() {
+			imgproc::line(&mut frame, start, end, color, 1, imgproc::LINE_AA, 0)?;
+		}
+		if show_crosshair {
+			if let Some(pt) = current.hover() {
+				let (dx, dy) = (Point::new(CROSSHAIR_SIZE, 0), Point::new(0, CROSSHAIR_SIZE));
+				imgproc::line(&mut frame, pt - dx, pt + dx, color, 1, imgproc::LINE_8, 0)?;
+				imgproc::line(&mut frame, pt - dy, pt + dy, color, 1, imgproc::LINE_8, 0)?;
+			}
+		}
+		highgui::imshow(window_name, &frame)?;
+		match SelectionKey::from_key(highgui::wait_key(20)?) {
+			SelectionKey::Confirm => {
+				if let Some(segment) = current.segment() {
+					break Some(segment);
+				}
+			}
+			SelectionKey::Cancel => break None,
+			SelectionKey::Reset => selection.lock().unwrap_or_else(PoisonError::into_inner).reset(),
+			SelectionKey::Other => {}
+		}
+		if highgui::get_window_property(window_name, highgui::WND_PROP_VISIBLE)? < 1. {
+			break None;
+		}
+	};
+	remove_mouse_callback(window_name)?;
+	Ok(out)
+}
@@ -0,0 +1,7 @@
+pub use contact_sheet::*;
+pub use overlay::*;
+pub use palette::*;
+
+mod contact_sheet;
+mod overlay;
+mod palette;
@@ -0,0 +1,216 @@
+use std::fmt;
+
+use crate::{
+	core::{self, Mat, Point, Rect, Scalar, Size, CV_8U, CV_8UC3},
+	imgproc::{self, Palette},
+	prelude::*,
+	Error, Result,
+};
+
+const CAPTION_FONT: i32 = imgproc::FONT_HERSHEY_SIMPLEX;
+const CAPTION_FONT_SCALE: f64 = 0.45;
+const CAPTION_PADDING: i32 = 4;
+
+type TileOverlay<'a> = Box<dyn Fn(&mut Mat, f64) -> Result<()> + 'a>;
+
+/// Single tile of the sheet created by [contact_sheet]
+pub struct SheetTile<'a> {
+	image: &'a Mat,
+	label: Option<String>,
+	overlay: Option<TileOverlay<'a>>,
+}
+
+impl<'a> SheetTile<'a> {
+	/// Tile showing `image`, which must be `CV_8U` with 1, 3 or 4 channels
+	pub fn new(image: &'a Mat) -> Self {
+		Self {
+			image,
+			label: None,
+			overlay: None,
+		}
+	}
+
+	/// Caption drawn below the tile
+	pub fn with_label(mut self, label: impl Into<String>) -> Self {
+		self.label = Some(label.into());
+		self
+	}
+
+	/// Function that draws over the tile after it's scaled to the tile size, e.g. the detection results
+	///
+	/// It receives the scaled BGR image and the scale factor, so that the coordinates in the original image can be
+	/// multiplied by it to get the coordinates in the tile.
+	pub fn with_overlay(mut self, overlay: impl Fn(&mut Mat, f64) -> Result<()> + 'a) -> Self {
+		self.overlay = Some(Box::new(overlay));
+		self
+	}
+}
+
+impl<'a> From<&'a Mat> for SheetTile<'a> {
+	#[inline]
+	fn from(image: &'a Mat) -> Self {
+		Self::new(image)
+	}
+}
+
+impl fmt::Debug for SheetTile<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("SheetTile")
+			.field("image", &self.image)
+			.field("label", &self.label)
+			.field("overlay", &self.overlay.is_some())
+			.finish()
+	}
+}
+
+/// Grid of the sheet created by [contact_sheet]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SheetGrid {
+	/// Number of the columns, 0 picks the smallest number that makes the grid at least as wide as it's tall
+	pub columns: usize,
+	/// Size of the area the images are scaled to fit into keeping their aspect ratio, the caption is placed below it
+	pub tile_size: Size,
+	/// Gap between the tiles and around the sheet in pixels
+	pub spacing: i32,
+	/// BGR color of the gaps and the parts of the tiles not covered by the images, the global palette background by
+	/// default
+	pub background: Scalar,
+	/// BGR color of the captions, the global palette foreground by default
+	pub label_color: Scalar,
+}
+
+impl Default for SheetGrid {
+	fn default() -> Self {
+		Self {
+			columns: 0,
+			tile_size: Size::new(256, 192),
+			spacing: 4,
+			background: Palette::global().background(),
+			label_color: Palette::global().foreground(),
+		}
+	}
+}
+
+impl SheetGrid {
+	/// Takes the background and the caption colors from the palette
+	pub fn with_palette(self, palette: Palette) -> Self {
+		Self {
+			background: palette.background(),
+			label_color: palette.foreground(),
+			..self
+		}
+	}
+}
+
+/// Tiles the images into a single `CV_8UC3` sheet for a quick review of the batch output
+///
+/// The tiles are placed in the row-major order. Every image is scaled to fit into `grid.tile_size` and centered in its
+/// cell, then its overlay is drawn and the label is put below it. Space for the captions is only reserved if at least one
+/// of the tiles has a label.
+pub fn contact_sheet(tiles: &[SheetTile], grid: SheetGrid) -> Result<Mat> {
+	if tiles.is_empty() {
+		return Err(Error::new(core::StsBadArg, "Contact sheet needs at least one tile"));
+	}
+	if grid.tile_size.width <= 0 || grid.tile_size.height <= 0 || grid.spacing < 0 {
+		return Err(Error::new(
+			core::StsBadArg,
+			format!(
+				"Tile size must be positive and spacing non-negative, but got: {:?} and {}",
+				grid.tile_size, grid.spacing
+			),
+		));
+	}
+	let columns = if grid.columns == 0 {
+		(tiles.len() as f64).sqrt().ceil() as usize
+	} else {
+		grid.columns.min(tiles.len())
+	};
+	let rows = (tiles.len() - 1) / columns + 1;
+	let mut baseline = 0;
+	let caption_height = if tiles.iter().any(|tile| tile.label.is_some()) {
+		let text = imgproc::get_text_size("Ag", CAPTION_FONT, CAPTION_FONT_SCALE, 1, &mut baseline)?;
+		text.height + baseline + 2 * CAPTION_PADDING
+	} else {
+		0
+	};
+	let (cell_width, cell_height) = (grid.tile_size.width, grid.tile_size.height + caption_height);
+	let sheet = Mat::new_rows_cols_with_default(
+		rows as i32 * (cell_height + grid.spacing) + grid.spacing,
+		columns as i32 * (cell_width + grid.spacing) + grid.spacing,
+		CV_8UC3,
+		grid.background,
+	)?;
+	for (i, tile) in tiles.iter().enumerate() {
+		let cell = Point::new(
+			grid.spacing + (i % columns) as i32 * (cell_width + grid.spacing),
+			grid.spacing + (i / columns) as i32 * (cell_height + grid.spacing),
+		);
+		let (mut scaled, scale) = fit_tile(i, tile.image, grid.tile_size)?;
+		if let Some(overlay) = &tile.overlay {
+			overlay(&mut scaled, scale)?;
+		}
+		let offset = Point::new(
+			(grid.tile_size.width - scaled.cols()) / 2,
+			(grid.tile_size.height - scaled.rows()) / 2,
+		);
+		let mut dst = Mat::roi(
+			&sheet,
+			Rect::new(cell.x + offset.x, cell.y + offset.y, scaled.cols(), scaled.rows()),
+		)?;
+		scaled.copy_to(&mut dst)?;
+		if let Some(label) = &tile.label {
+			let mut caption = Mat::roi(
+				&sheet,
+				Rect::new(cell.x, cell.y + grid.tile_size.height, cell_width, caption_height),
+			)?;
+			imgproc::put_text(
+				&mut caption,
+				label,
+				Point::new(CAPTION_PADDING, caption_height - CAPTION_PADDING - baseline),
+				CAPTION_FONT,
+				CAPTION_FONT_SCALE,
+				grid.label_color,
+				1,
+				imgproc::LINE_AA,
+				false,
+			)?;
+		}
+	}
+	Ok(sheet)
+}
+
+/// Converts the image to BGR and scales it to fit into `tile_size`, returns the scaled image and the scale factor
+fn fit_tile(idx: usize, image: &Mat, tile_size: Size) -> Result<(Mat, f64)> {
+	if image.empty() || image.depth() != CV_8U || !matches!(image.channels(), 1 | 3 | 4) {
+		return Err(Error::new(
+			core::StsUnsupportedFormat,
+			format!(
+				"Tile {} must be a non-empty CV_8U image with 1, 3 or 4 channels, but got type: {}",
+				idx,
+				image.typ()
+			),
+		));
+	}
+	let mut bgr = Mat::default();
+	match image.channels() {
+		1 => imgproc::cvt_color(image, &mut bgr, imgproc::COLOR_GRAY2BGR, 0)?,
+		4 => imgproc::cvt_color(image, &mut bgr, imgproc::COLOR_BGRA2BGR, 0)?,
+		_ => bgr = image.try_clone()?,
+	}
+	let scale = (f64::from(tile_size.width) / f64::from(image.cols())).min(f64::from(tile_size.height) / f64::from(image.rows()));
+	let size = Size::new(
+		((f64::from(image.cols()) * scale).round() as i32).clamp(1, tile_size.width),
+		((f64::from(image.rows()) * scale).round() as i32).clamp(1, tile_size.height),
+	);
+	if size == bgr.size()? {
+		return Ok((bgr, scale));
+	}
+	let interpolation = if scale < 1. {
+		imgproc::INTER_AREA
+	} else {
+		imgproc::INTER_LINEAR
+	};
+	let mut scaled = Mat::default();
+	imgproc::resize(&bgr, &mut scaled, size, 0., 0., interpolation)?;
+	Ok((scaled, scale))
+}
@@ -0,0 +1,274 @@
+use crate::{
+	core::{self, Mat, Point, Point2f, Scalar, CV_64F},
+	imgproc::{self, Palette},
+	prelude::*,
+	Error, Result,
+};
+
+/// Appearance of the overlays
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OverlayStyle {
+	/// BGR color of the lines and the text, the foreground of the global palette by default
+	pub color: Scalar,
+	/// Thickness of the lines in pixels
+	pub thickness: i32,
+	/// Scale of the `FONT_HERSHEY_SIMPLEX` font of the labels
+	pub font_scale: f64,
+}
+
+impl Default for OverlayStyle {
+	fn default() -> Self {
+		Self {
+			color: Palette::global().foreground(),
+			thickness: 1,
+			font_scale: 0.35,
+		}
+	}
+}
+
+/// Corner of the image to place the overlay in
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Corner {
+	TopLeft,
+	TopRight,
+	BottomLeft,
+	BottomRight,
+}
+
+/// Ratio between the image and the world distances, used by [draw_scale_bar]
+#[derive(Clone, Debug, PartialEq)]
+pub struct WorldScale {
+	/// Number of pixels covering one unit of the world distance
+	pub pixels_per_unit: f64,
+	/// Name of the world unit for the label, e.g. "mm"
+	pub unit: String,
+}
+
+impl WorldScale {
+	pub fn new(pixels_per_unit: f64, unit: impl Into<String>) -> Self {
+		Self {
+			pixels_per_unit,
+			unit: unit.into(),
+		}
+	}
+
+	/// Scale of a fronto-parallel plane at `distance` from the calibrated camera
+	///
+	/// The focal length in pixels is the average of `fx` and `fy` of the 3x3 `camera_matrix`, `distance` is expressed in
+	/// the world units.
+	pub fn from_camera_matrix(camera_matrix: &Mat, distance: f64, unit: impl Into<String>) -> Result<Self> {
+		if camera_matrix.rows() != 3 || camera_matrix.cols() != 3 || camera_matrix.channels() != 1 {
+			return Err(Error::new(
+				core::StsBadSize,
+				format!(
+					"Camera matrix must be 3x3 single channel, but got: {}x{} with {} channels",
+					camera_matrix.cols(),
+					camera_matrix.rows(),
+					camera_matrix.channels()
+				),
+			));
+		}
+		if !distance.is_finite() || distance <= 0. {
+			return Err(Error::new(
+				core::StsOutOfRange,
+				format!("Distance must be positive, but got: {}", distance),
+			));
+		}
+		let mut k = Mat::default();
+		camera_matrix.convert_to(&mut k, CV_64F, 1., 0.)?;
+		let focal = (*k.at_2d::<f64>(0, 0)? + *k.at_2d::<f64>(1, 1)?) / 2.;
+		Ok(Self::new(focal / distance, unit))
+	}
+
+	/// Largest "nice" distance, 1, 2 or 5 times a power of 10 world units, not longer than `max_pixels` in the image
+	pub fn nice_length(&self, max_pixels: f64) -> Option<f64> {
+		if !self.pixels_per_unit.is_finite() || self.pixels_per_unit <= 0. || !max_pixels.is_finite() || max_pixels <= 0. {
+			return None;
+		}
+		let max_units = max_pixels / self.pixels_per_unit;
+		let magnitude = 10f64.powf(max_units.log10().floor());
+		[5., 2., 1.]
+			.iter()
+			.map(|m| m * magnitude)
+			.find(|&len| len <= max_units * (1. + 1e-9))
+	}
+}
+
+/// Draws the ticks along the top and the left edges of the image every `tick_spacing` pixels
+///
+/// Every `label_every`-th tick is longer and labeled with its pixel coordinate, 0 disables the labels.
+pub fn draw_axes(img: &mut Mat, tick_spacing: i32, label_every: usize, style: OverlayStyle) -> Result<()> {
+	check_spacing(tick_spacing)?;
+	let (width, height) = (img.cols(), img.rows());
+	for (i, x) in (0..width).step_by(tick_spacing as usize).enumerate() {
+		let (len, major) = tick(i, label_every);
+		imgproc::line(
+			img,
+			Point::new(x, 0),
+			Point::new(x, len),
+			style.color,
+			style.thickness,
+			imgproc::LINE_8,
+			0,
+		)?;
+		if major && x > 0 {
+			put_label(img, &x.to_string(), Point::new(x + 2, len + 10), style)?;
+		}
+	}
+	for (i, y) in (0..height).step_by(tick_spacing as usize).enumerate() {
+		let (len, major) = tick(i, label_every);
+		imgproc::line(
+			img,
+			Point::new(0, y),
+			Point::new(len, y),
+			style.color,
+			style.thickness,
+			imgproc::LINE_8,
+			0,
+		)?;
+		if major && y > 0 {
+			put_label(img, &y.to_string(), Point::new(len + 2, y + 4), style)?;
+		}
+	}
+	Ok(())
+}
+
+/// Draws the grid lines every `spacing` pixels starting from the top left corner
+pub fn draw_grid(img: &mut Mat, spacing: i32, style: OverlayStyle) -> Result<()> {
+	check_spacing(spacing)?;
+	let (width, height) = (img.cols(), img.rows());
+	for x in (spacing..width).step_by(spacing as usize) {
+		imgproc::line(
+			img,
+			Point::new(x, 0),
+			Point::new(x, height - 1),
+			style.color,
+			style.thickness,
+			imgproc::LINE_8,
+			0,
+		)?;
+	}
+	for y in (spacing..height).step_by(spacing as usize) {
+		imgproc::line(
+			img,
+			Point::new(0, y),
+			Point::new(width - 1, y),
+			style.color,
+			style.thickness,
+			imgproc::LINE_8,
+			0,
+		)?;
+	}
+	Ok(())
+}
+
+/// Draws the crosshair with the arms `size` pixels long centered at `center` and labels it with the coordinates
+pub fn draw_crosshair(img: &mut Mat, center: Point2f, size: i32, style: OverlayStyle) -> Result<()> {
+	let c = Point::new(center.x.round() as i32, center.y.round() as i32);
+	imgproc::line(
+		img,
+		c - Point::new(size, 0),
+		c + Point::new(size, 0),
+		style.color,
+		style.thickness,
+		imgproc::LINE_8,
+		0,
+	)?;
+	imgproc::line(
+		img,
+		c - Point::new(0, size),
+		c + Point::new(0, size),
+		style.color,
+		style.thickness,
+		imgproc::LINE_8,
+		0,
+	)?;
+	put_label(
+		img,
+		&format!("({:.1}, {:.1})", center.x, center.y),
+		c + Point::new(4, -4),
+		style,
+	)
+}
+
+/// Draws the scale bar of a "nice" world length into the corner of the image and returns the length in world units
+///
+/// The bar is at most a quarter of the image width long and is labeled with its length and the unit.
+pub fn draw_scale_bar(img: &mut Mat, scale: &WorldScale, corner: Corner, style: OverlayStyle) -> Result<f64> {
+	const MARGIN: i32 = 10;
+	let length = scale.nice_length(f64::from(img.cols()) / 4.).ok_or_else(|| {
+		Error::new(
+			core::StsBadArg,
+			format!(
+				"Can't fit a scale bar of {} pixels per unit into the image of width {}",
+				scale.pixels_per_unit,
+				img.cols()
+			),
+		)
+	})?;
+	let pixels = (length * scale.pixels_per_unit).round() as i32;
+	let x = match corner {
+		Corner::TopLeft | Corner::BottomLeft => MARGIN,
+		Corner::TopRight | Corner::BottomRight => img.cols() - MARGIN - pixels,
+	};
+	let y = match corner {
+		Corner::TopLeft | Corner::TopRight => MARGIN + 14,
+		Corner::BottomLeft | Corner::BottomRight => img.rows() - MARGIN,
+	};
+	let thickness = style.thickness.max(2);
+	imgproc::line(
+		img,
+		Point::new(x, y),
+		Point::new(x + pixels, y),
+		style.color,
+		thickness,
+		imgproc::LINE_8,
+		0,
+	)?;
+	for end in &[x, x + pixels] {
+		imgproc::line(
+			img,
+			Point::new(*end, y - 4),
+			Point::new(*end, y + 4),
+			style.color,
+			style.thickness,
+			imgproc::LINE_8,
+			0,
+		)?;
+	}
+	put_label(img, &format!("{} {}", length, scale.unit), Point::new(x, y - 8), style)?;
+	Ok(length)
+}
+
+/// Length of the tick with the index `i` and whether it's labeled
+fn tick(i: usize, label_every: usize) -> (i32, bool) {
+	if i.checked_rem(label_every) == Some(0) {
+		(8, true)
+	} else {
+		(4, false)
+	}
+}
+
+fn check_spacing(spacing: i32) -> Result<()> {
+	if spacing <= 0 {
+		return Err(Error::new(
+			core::StsOutOfRange,
+			format!("Spacing must be positive, but got: {}", spacing),
+		));
+	}
+	Ok(())
+}
+
+fn put_label(img: &mut Mat, text: &str, org: Point, style: OverlayStyle) -> Result<()> {
+	imgproc::put_text(
+		img,
+		text,
+		org,
+		imgproc::FONT_HERSHEY_SIMPLEX,
+		style.font_scale,
+		style.color,
+		1,
+		imgproc::LINE_AA,
+		false,
+	)
+}
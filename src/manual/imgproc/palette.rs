@@ -0,0 +1,180 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use crate::core::Scalar;
+
+/// Okabe-Ito palette, distinguishable with all the common forms of color vision deficiency, RGB
+const COLOR_BLIND_SAFE: [(u8, u8, u8); 7] = [
+	(230, 159, 0),
+	(86, 180, 233),
+	(0, 158, 115),
+	(240, 228, 66),
+	(0, 114, 178),
+	(213, 94, 0),
+	(204, 121, 167),
+];
+
+/// Saturated light colors for drawing over the dark images, RGB
+const HIGH_CONTRAST_DARK: [(u8, u8, u8); 6] = [
+	(255, 255, 0),
+	(0, 255, 255),
+	(255, 0, 255),
+	(0, 255, 0),
+	(255, 128, 0),
+	(255, 255, 255),
+];
+
+/// Saturated dark colors for drawing over the light images, RGB
+const HIGH_CONTRAST_LIGHT: [(u8, u8, u8); 6] = [(0, 0, 192), (192, 0, 0), (0, 112, 0), (128, 0, 160), (160, 80, 0), (0, 0, 0)];
+
+static GLOBAL_PALETTE: AtomicU8 = AtomicU8::new(Palette::Default as u8);
+
+/// Color theme of the drawing helpers
+///
+/// The palette can be set globally with [Palette::set_global] or passed to the individual drawing functions, see
+/// [DrawColor].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Palette {
+	/// Fully saturated hues spread by the golden angle, light text on dark background
+	Default = 0,
+	/// Okabe-Ito colors that stay distinguishable for the color-blind viewers
+	ColorBlindSafe = 1,
+	/// Light colors on dark background
+	HighContrastDark = 2,
+	/// Dark colors on light background
+	HighContrastLight = 3,
+}
+
+impl Default for Palette {
+	#[inline]
+	fn default() -> Self {
+		Self::Default
+	}
+}
+
+impl Palette {
+	/// Palette used by the drawing helpers when none is specified, [Palette::Default] unless changed
+	pub fn global() -> Self {
+		match GLOBAL_PALETTE.load(Ordering::Relaxed) {
+			1 => Self::ColorBlindSafe,
+			2 => Self::HighContrastDark,
+			3 => Self::HighContrastLight,
+			_ => Self::Default,
+		}
+	}
+
+	/// Sets the palette used by the drawing helpers when none is specified
+	pub fn set_global(palette: Palette) {
+		GLOBAL_PALETTE.store(palette as u8, Ordering::Relaxed);
+	}
+
+	/// BGR color for the item with the specified index, e.g. the match or the track id
+	///
+	/// The colors repeat with the period of [Palette::period], [Palette::Default] generates a new hue for every index.
+	pub fn color(self, idx: usize) -> Scalar {
+		match self {
+			Self::Default => golden_angle_color(idx),
+			Self::ColorBlindSafe => from_rgb(COLOR_BLIND_SAFE[idx % COLOR_BLIND_SAFE.len()]),
+			Self::HighContrastDark => from_rgb(HIGH_CONTRAST_DARK[idx % HIGH_CONTRAST_DARK.len()]),
+			Self::HighContrastLight => from_rgb(HIGH_CONTRAST_LIGHT[idx % HIGH_CONTRAST_LIGHT.len()]),
+		}
+	}
+
+	/// Number of the distinct colors in the palette, `None` for the unbounded [Palette::Default]
+	pub fn period(self) -> Option<usize> {
+		match self {
+			Self::Default => None,
+			Self::ColorBlindSafe => Some(COLOR_BLIND_SAFE.len()),
+			Self::HighContrastDark => Some(HIGH_CONTRAST_DARK.len()),
+			Self::HighContrastLight => Some(HIGH_CONTRAST_LIGHT.len()),
+		}
+	}
+
+	/// BGR color for the single-colored elements that don't have an index
+	pub fn accent(self) -> Scalar {
+		match self {
+			Self::Default => Scalar::new(0., 255., 0., 0.),
+			_ => self.color(0),
+		}
+	}
+
+	/// BGR color of the canvas areas not covered by the images
+	pub fn background(self) -> Scalar {
+		match self {
+			Self::HighContrastLight => Scalar::all(255.),
+			_ => Scalar::all(0.),
+		}
+	}
+
+	/// BGR color of the text drawn over [Palette::background]
+	pub fn foreground(self) -> Scalar {
+		match self {
+			Self::HighContrastLight => Scalar::all(0.),
+			_ => Scalar::all(255.),
+		}
+	}
+}
+
+/// Source of the colors for a drawing call
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum DrawColor {
+	/// Colors of the global palette, see [Palette::set_global]
+	GlobalPalette,
+	/// Colors of the specified palette
+	Palette(Palette),
+	/// Same BGR color for all the items
+	Fixed(Scalar),
+}
+
+impl Default for DrawColor {
+	#[inline]
+	fn default() -> Self {
+		Self::GlobalPalette
+	}
+}
+
+impl DrawColor {
+	/// BGR color for the item with the specified index
+	pub fn color(self, idx: usize) -> Scalar {
+		match self {
+			Self::GlobalPalette => Palette::global().color(idx),
+			Self::Palette(palette) => palette.color(idx),
+			Self::Fixed(color) => color,
+		}
+	}
+}
+
+impl From<Palette> for DrawColor {
+	#[inline]
+	fn from(palette: Palette) -> Self {
+		Self::Palette(palette)
+	}
+}
+
+impl From<Scalar> for DrawColor {
+	#[inline]
+	fn from(color: Scalar) -> Self {
+		Self::Fixed(color)
+	}
+}
+
+#[inline]
+fn from_rgb((r, g, b): (u8, u8, u8)) -> Scalar {
+	Scalar::new(f64::from(b), f64::from(g), f64::from(r), 0.)
+}
+
+/// Fully saturated color with the hue advanced by the golden angle for every index, so that the neighboring indices get
+/// well distinguishable colors
+fn golden_angle_color(idx: usize) -> Scalar {
+	const GOLDEN_RATIO_CONJUGATE: f64 = 0.618_033_988_749_895;
+	let hue = (idx as f64 * GOLDEN_RATIO_CONJUGATE).fract() * 6.;
+	let x = 1. - (hue % 2. - 1.).abs();
+	let (r, g, b) = match hue as u32 {
+		0 => (1., x, 0.),
+		1 => (x, 1., 0.),
+		2 => (0., 1., x),
+		3 => (0., x, 1.),
+		4 => (x, 0., 1.),
+		_ => (1., 0., x),
+	};
+	Scalar::new(b * 255., g * 255., r * 255., 0.)
+}
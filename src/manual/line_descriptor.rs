@@ -0,0 +1,93 @@
+pub use annotation::*;
+pub use batch::*;
+pub use cluster::*;
+pub use convert::*;
+pub use descriptor::*;
+pub use draw::*;
+pub use extractor::*;
+#[cfg(ocvrs_has_module_features2d)]
+pub use features2d::*;
+pub use filter::*;
+#[cfg(ocvrs_has_module_imgproc)]
+pub use horizon::*;
+pub use image_match::*;
+pub use match_filter::*;
+pub use match_graph::*;
+#[cfg(ocvrs_has_module_imgproc)]
+pub use match_layout::*;
+pub use migration::*;
+pub use mixed_precision::*;
+pub use model_fit::*;
+pub use paging::*;
+pub use params::*;
+#[cfg(ocvrs_has_module_imgproc)]
+pub use patch::*;
+pub use polyline::*;
+pub use propagation::*;
+#[cfg(ocvrs_has_module_imgproc)]
+pub use pyramid::*;
+#[cfg(ocvrs_has_module_imgproc)]
+pub use rectify::*;
+pub use rerank::*;
+#[cfg(all(ocvrs_has_module_highgui, ocvrs_has_module_imgproc))]
+pub use select::*;
+pub use sink::*;
+#[cfg(ocvrs_has_module_imgproc)]
+pub use styled::*;
+pub use track_stats::*;
+pub use tracker::*;
+pub use vanishing_point::*;
+#[cfg(ocvrs_has_module_imgproc)]
+pub use wire::*;
+#[cfg(ocvrs_has_module_ximgproc)]
+pub use ximgproc::*;
+pub use zone::*;
+
+mod annotation;
+mod batch;
+mod cluster;
+mod convert;
+mod descriptor;
+mod draw;
+pub mod evaluation;
+mod extractor;
+#[cfg(ocvrs_has_module_features2d)]
+mod features2d;
+mod filter;
+#[cfg(ocvrs_has_module_imgproc)]
+mod horizon;
+mod image_match;
+mod json;
+mod keyline;
+mod linalg;
+mod match_filter;
+mod match_graph;
+#[cfg(ocvrs_has_module_imgproc)]
+mod match_layout;
+mod migration;
+mod mixed_precision;
+mod model_fit;
+mod paging;
+mod params;
+#[cfg(ocvrs_has_module_imgproc)]
+mod patch;
+mod polyline;
+mod propagation;
+#[cfg(ocvrs_has_module_imgproc)]
+mod pyramid;
+#[cfg(ocvrs_has_module_imgproc)]
+mod rectify;
+mod rerank;
+#[cfg(all(ocvrs_has_module_highgui, ocvrs_has_module_imgproc))]
+mod select;
+mod sink;
+#[cfg(ocvrs_has_module_imgproc)]
+mod styled;
+mod track_stats;
+mod tracker;
+mod vanishing_point;
+#[cfg(ocvrs_has_module_imgproc)]
+mod wire;
+#[cfg(ocvrs_has_module_ximgproc)]
+mod ximgproc;
+mod zone;
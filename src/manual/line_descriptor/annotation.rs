@@ -0,0 +1,612 @@
+#[cfg(all(ocvrs_has_module_highgui, ocvrs_has_module_imgproc))]
+use std::sync::{Arc, Mutex, PoisonError};
+use std::{cmp::Ordering, fs, path::Path};
+
+use super::{
+	json::{write_json_number, write_json_string, JsonValue},
+	keyline::keyline_from_endpoints,
+};
+#[cfg(all(ocvrs_has_module_highgui, ocvrs_has_module_imgproc))]
+use crate::highgui::{self, MouseEvent, SegmentSelection, SelectionKey};
+use crate::{
+	core::{self, Point2f},
+	line_descriptor::KeyLine,
+	Error, Result,
+};
+#[cfg(ocvrs_has_module_imgproc)]
+use crate::{
+	core::{Mat, Point},
+	imgproc::{self, Palette},
+};
+
+/// Endpoint of an [AnnotatedLine]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LineEndpoint {
+	Start,
+	End,
+}
+
+/// Ground-truth line segment
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnnotatedLine {
+	pub start: Point2f,
+	pub end: Point2f,
+	/// Class of the line, e.g. "horizon" or "lane", can be empty
+	pub label: String,
+}
+
+impl AnnotatedLine {
+	pub fn new(start: Point2f, end: Point2f, label: impl Into<String>) -> Self {
+		Self {
+			start,
+			end,
+			label: label.into(),
+		}
+	}
+
+	/// Creates the annotation from the segment of the keyline in the original image coordinates
+	pub fn from_keyline(keyline: &KeyLine, label: impl Into<String>) -> Self {
+		let (start, end) = keyline.as_segment();
+		Self::new(start, end, label)
+	}
+
+	/// Keyline in the octave 0 with the endpoints of the annotation, e.g. to compare it with the detections
+	pub fn to_keyline(&self) -> KeyLine {
+		keyline_from_endpoints(self.start, self.end)
+	}
+
+	#[inline]
+	pub fn endpoint(&self, endpoint: LineEndpoint) -> Point2f {
+		match endpoint {
+			LineEndpoint::Start => self.start,
+			LineEndpoint::End => self.end,
+		}
+	}
+
+	/// Distance from the point to the closest point of the segment
+	pub fn distance_to_point(&self, pt: Point2f) -> f32 {
+		self.to_keyline().distance_to_point(pt)
+	}
+}
+
+/// Annotations of a single frame of the sequence
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FrameAnnotations {
+	/// Identifier of the frame, e.g. the image file name
+	pub name: String,
+	pub lines: Vec<AnnotatedLine>,
+}
+
+impl FrameAnnotations {
+	pub fn new(name: impl Into<String>) -> Self {
+		Self {
+			name: name.into(),
+			lines: vec![],
+		}
+	}
+}
+
+/// Snapping of the manually placed points and segments to the detected keylines
+///
+/// Clicking the exact end of a line is tedious, with snapping a rough placement is refined to the endpoints found by
+/// the detector, so the annotations are both faster to make and more consistent.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LineSnapping {
+	/// Maximum distance in pixels between the placed point and the keyline endpoint it snaps to, 0 disables snapping
+	pub max_distance: f32,
+}
+
+impl Default for LineSnapping {
+	fn default() -> Self {
+		Self { max_distance: 8. }
+	}
+}
+
+impl LineSnapping {
+	/// Closest keyline endpoint within `max_distance` from the point or the point itself if there is none
+	pub fn snap_point(&self, pt: Point2f, keylines: &[KeyLine]) -> Point2f {
+		keylines
+			.iter()
+			.flat_map(|kl| {
+				let (start, end) = kl.as_segment();
+				[start, end]
+			})
+			.map(|candidate| (candidate, distance(pt, candidate)))
+			.filter(|&(_, dist)| dist <= self.max_distance)
+			.min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+			.map_or(pt, |(candidate, _)| candidate)
+	}
+
+	/// Endpoints of the keyline that best fits the segment, `None` if no keyline has both endpoints within
+	/// `max_distance` from the segment endpoints
+	///
+	/// The keylines are treated as undirected, the returned endpoints are ordered to match `start` and `end`.
+	pub fn snap_segment(&self, start: Point2f, end: Point2f, keylines: &[KeyLine]) -> Option<(Point2f, Point2f)> {
+		keylines
+			.iter()
+			.flat_map(|kl| {
+				let (kl_start, kl_end) = kl.as_segment();
+				[(kl_start, kl_end), (kl_end, kl_start)]
+			})
+			.filter_map(|(s, e)| {
+				let (ds, de) = (distance(start, s), distance(end, e));
+				if ds <= self.max_distance && de <= self.max_distance {
+					Some(((s, e), ds + de))
+				} else {
+					None
+				}
+			})
+			.min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+			.map(|(segment, _)| segment)
+	}
+
+	/// Snaps the whole segment to a keyline if possible, otherwise snaps every endpoint separately
+	pub fn snap(&self, start: Point2f, end: Point2f, keylines: &[KeyLine]) -> (Point2f, Point2f) {
+		self
+			.snap_segment(start, end, keylines)
+			.unwrap_or_else(|| (self.snap_point(start, keylines), self.snap_point(end, keylines)))
+	}
+}
+
+/// Line annotations of a sequence of frames with the navigation and editing operations
+///
+/// It's the model of a line annotation tool, the UI only translates the user input into the calls of its methods, see
+/// [annotate_frame] for a ready to use `highgui` front end. All editing operations work on the current frame. The
+/// annotations can be persisted as JSON with the following layout:
+/// ```json
+/// {
+///   "current": 0,
+///   "frames": [{"name": "000001.png", "lines": [{"start": [x, y], "end": [x, y], "label": "lane"}, ...]}, ...]
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct LineAnnotations {
+	frames: Vec<FrameAnnotations>,
+	current: usize,
+}
+
+impl LineAnnotations {
+	/// Creates the empty annotations of the frames with the passed names, at least one frame is required
+	pub fn new(frame_names: impl IntoIterator<Item = impl Into<String>>) -> Result<Self> {
+		Self::from_frames(frame_names.into_iter().map(FrameAnnotations::new).collect())
+	}
+
+	pub fn from_frames(frames: Vec<FrameAnnotations>) -> Result<Self> {
+		if frames.is_empty() {
+			return Err(Error::new(
+				core::StsBadArg,
+				"At least one frame is required for the annotations",
+			));
+		}
+		Ok(Self { frames, current: 0 })
+	}
+
+	#[inline]
+	pub fn frames(&self) -> &[FrameAnnotations] {
+		&self.frames
+	}
+
+	#[inline]
+	pub fn current_index(&self) -> usize {
+		self.current
+	}
+
+	#[inline]
+	pub fn current(&self) -> &FrameAnnotations {
+		&self.frames[self.current]
+	}
+
+	#[inline]
+	pub fn current_mut(&mut self) -> &mut FrameAnnotations {
+		&mut self.frames[self.current]
+	}
+
+	/// Moves to the next frame, returns `false` if the current frame is the last one
+	pub fn next_frame(&mut self) -> bool {
+		if self.current + 1 < self.frames.len() {
+			self.current += 1;
+			true
+		} else {
+			false
+		}
+	}
+
+	/// Moves to the previous frame, returns `false` if the current frame is the first one
+	pub fn prev_frame(&mut self) -> bool {
+		if self.current > 0 {
+			self.current -= 1;
+			true
+		} else {
+			false
+		}
+	}
+
+	pub fn go_to_frame(&mut self, index: usize) -> Result<()> {
+		if index >= self.frames.len() {
+			return Err(Error::new(
+				core::StsOutOfRange,
+				format!("Frame {} is out of range of {} frames", index, self.frames.len()),
+			));
+		}
+		self.current = index;
+		Ok(())
+	}
+
+	/// Moves to the first frame after the current one without any lines, returns `false` if there is none
+	pub fn next_unannotated(&mut self) -> bool {
+		match self.frames.iter().skip(self.current + 1).position(|f| f.lines.is_empty()) {
+			Some(offset) => {
+				self.current += offset + 1;
+				true
+			}
+			None => false,
+		}
+	}
+
+	/// Adds the line to the current frame and returns its index
+	pub fn add_line(&mut self, line: AnnotatedLine) -> usize {
+		let lines = &mut self.current_mut().lines;
+		lines.push(line);
+		lines.len() - 1
+	}
+
+	/// Adds the line between the roughly placed points snapped to the keylines detected in the current frame
+	pub fn add_line_snapped(
+		&mut self,
+		start: Point2f,
+		end: Point2f,
+		label: impl Into<String>,
+		keylines: &[KeyLine],
+		snapping: LineSnapping,
+	) -> usize {
+		let (start, end) = snapping.snap(start, end, keylines);
+		self.add_line(AnnotatedLine::new(start, end, label))
+	}
+
+	/// Moves the endpoint of the line in the current frame
+	pub fn move_endpoint(&mut self, line: usize, endpoint: LineEndpoint, pt: Point2f) -> Result<()> {
+		let line = self.line_mut(line)?;
+		match endpoint {
+			LineEndpoint::Start => line.start = pt,
+			LineEndpoint::End => line.end = pt,
+		}
+		Ok(())
+	}
+
+	pub fn set_label(&mut self, line: usize, label: impl Into<String>) -> Result<()> {
+		self.line_mut(line)?.label = label.into();
+		Ok(())
+	}
+
+	/// Removes the line from the current frame and returns it, the indices of the following lines are shifted down
+	pub fn delete_line(&mut self, line: usize) -> Result<AnnotatedLine> {
+		self.line_mut(line)?;
+		Ok(self.current_mut().lines.remove(line))
+	}
+
+	/// Index of the line in the current frame closest to the point within `max_distance`
+	pub fn line_at(&self, pt: Point2f, max_distance: f32) -> Option<usize> {
+		self
+			.current()
+			.lines
+			.iter()
+			.map(|line| line.distance_to_point(pt))
+			.enumerate()
+			.filter(|&(_, dist)| dist <= max_distance)
+			.min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+			.map(|(i, _)| i)
+	}
+
+	/// Line endpoint in the current frame closest to the point within `max_distance`, e.g. to start dragging it
+	pub fn endpoint_at(&self, pt: Point2f, max_distance: f32) -> Option<(usize, LineEndpoint)> {
+		self
+			.current()
+			.lines
+			.iter()
+			.enumerate()
+			.flat_map(|(i, line)| {
+				[
+					(i, LineEndpoint::Start, distance(pt, line.start)),
+					(i, LineEndpoint::End, distance(pt, line.end)),
+				]
+			})
+			.filter(|&(_, _, dist)| dist <= max_distance)
+			.min_by(|(_, _, a), (_, _, b)| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+			.map(|(i, endpoint, _)| (i, endpoint))
+	}
+
+	/// Serializes the annotations to JSON, see the type documentation for the layout
+	pub fn to_json(&self) -> String {
+		let mut out = format!("{{\"current\":{},\"frames\":[", self.current);
+		for (i, frame) in self.frames.iter().enumerate() {
+			if i > 0 {
+				out.push(',');
+			}
+			out.push_str("{\"name\":");
+			write_json_string(&mut out, &frame.name);
+			out.push_str(",\"lines\":[");
+			for (j, line) in frame.lines.iter().enumerate() {
+				if j > 0 {
+					out.push(',');
+				}
+				out.push_str("{\"start\":");
+				write_point(&mut out, line.start);
+				out.push_str(",\"end\":");
+				write_point(&mut out, line.end);
+				out.push_str(",\"label\":");
+				write_json_string(&mut out, &line.label);
+				out.push('}');
+			}
+			out.push_str("]}");
+		}
+		out.push_str("]}");
+		out
+	}
+
+	/// Parses the annotations serialized by [LineAnnotations::to_json]
+	///
+	/// `current` and `label` fields are optional, the current frame falls back to the first one.
+	pub fn from_json(json: &str) -> Result<Self> {
+		let root = JsonValue::parse(json)?;
+		let frames = root
+			.field("frames")?
+			.as_array()?
+			.iter()
+			.map(|frame| {
+				let lines = frame
+					.field("lines")?
+					.as_array()?
+					.iter()
+					.map(|line| {
+						let label = match line.field("label") {
+							Ok(label) => label.as_str()?.to_string(),
+							Err(_) => String::new(),
+						};
+						Ok(AnnotatedLine::new(
+							read_point(line.field("start")?)?,
+							read_point(line.field("end")?)?,
+							label,
+						))
+					})
+					.collect::<Result<Vec<_>>>()?;
+				Ok(FrameAnnotations {
+					name: frame.field("name")?.as_str()?.to_string(),
+					lines,
+				})
+			})
+			.collect::<Result<Vec<_>>>()?;
+		let mut out = Self::from_frames(frames)?;
+		if let Ok(current) = root.field("current") {
+			let current = current.as_f64()?;
+			if current >= 0. && current.fract() == 0. {
+				out.go_to_frame(current as usize)?;
+			}
+		}
+		Ok(out)
+	}
+
+	/// Writes the annotations as JSON to the file
+	pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+		let path = path.as_ref();
+		fs::write(path, self.to_json()).map_err(|e| {
+			Error::new(
+				core::StsError,
+				format!("Can't write the annotations to {}: {}", path.display(), e),
+			)
+		})
+	}
+
+	/// Reads the annotations from the JSON file written by [LineAnnotations::save]
+	pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+		let path = path.as_ref();
+		let json = fs::read_to_string(path).map_err(|e| {
+			Error::new(
+				core::StsObjectNotFound,
+				format!("Can't read the annotations from {}: {}", path.display(), e),
+			)
+		})?;
+		Self::from_json(&json)
+	}
+
+	fn line_mut(&mut self, line: usize) -> Result<&mut AnnotatedLine> {
+		let lines = &mut self.frames[self.current].lines;
+		let count = lines.len();
+		lines.get_mut(line).ok_or_else(|| {
+			Error::new(
+				core::StsOutOfRange,
+				format!("Line {} is out of range of {} lines in the current frame", line, count),
+			)
+		})
+	}
+}
+
+/// Draws the annotated lines of the frame over the image, every line in its own palette color
+///
+/// The line with the index `highlight` is drawn thicker with the marked endpoints, e.g. the one under the mouse pointer.
+/// The lines with non-empty labels are labeled near their start points.
+#[cfg(ocvrs_has_module_imgproc)]
+pub fn draw_annotations(img: &Mat, frame: &FrameAnnotations, out_img: &mut Mat, highlight: Option<usize>) -> Result<()> {
+	*out_img = super::match_layout::to_bgr(img)?;
+	let palette = Palette::global();
+	for (i, line) in frame.lines.iter().enumerate() {
+		let color = palette.color(i);
+		let (start, end) = (to_point(line.start), to_point(line.end));
+		let highlighted = highlight == Some(i);
+		imgproc::line(
+			out_img,
+			start,
+			end,
+			color,
+			if highlighted {
+				3
+			} else {
+				1
+			},
+			imgproc::LINE_AA,
+			0,
+		)?;
+		if highlighted {
+			for pt in &[start, end] {
+				imgproc::circle(out_img, *pt, 4, color, 1, imgproc::LINE_AA, 0)?;
+			}
+		}
+		if !line.label.is_empty() {
+			imgproc::put_text(
+				out_img,
+				&line.label,
+				start + Point::new(4, -4),
+				imgproc::FONT_HERSHEY_SIMPLEX,
+				0.4,
+				color,
+				1,
+				imgproc::LINE_AA,
+				false,
+			)?;
+		}
+	}
+	Ok(())
+}
+
+/// Action requested by the user in [annotate_frame]
+#[cfg(all(ocvrs_has_module_highgui, ocvrs_has_module_imgproc))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AnnotationCommand {
+	/// `n`, `Space` or `Enter`
+	NextFrame,
+	/// `p`
+	PreviousFrame,
+	/// `Esc`, `c` or the window was closed
+	Quit,
+}
+
+/// Interactive annotation of the current frame in a `highgui` window
+///
+/// A new line is added by clicking its two end points, the line is snapped to the passed `keylines` detected in the
+/// frame and gets `label`. Right click deletes the line under the mouse pointer, `u` deletes the last added line, `r`
+/// drops the half-placed segment. Returns when the user asks to move to another frame or to quit, the navigation and
+/// the saving are left to the caller, which allows loading the frames lazily:
+/// ```no_run
+/// # use opencv::{imgcodecs, line_descriptor::{self, LineAnnotations, LineSnapping, AnnotationCommand}, Result};
+/// # fn main() -> Result<()> {
+/// let mut annotations = LineAnnotations::new(["1.png", "2.png"])?;
+/// loop {
+///     let img = imgcodecs::imread(&annotations.current().name, imgcodecs::IMREAD_COLOR)?;
+///     let cmd = line_descriptor::annotate_frame("annotate", &img, &mut annotations, &[], LineSnapping::default(), "")?;
+///     annotations.save("annotations.json")?;
+///     match cmd {
+///         AnnotationCommand::NextFrame => { annotations.next_frame(); }
+///         AnnotationCommand::PreviousFrame => { annotations.prev_frame(); }
+///         AnnotationCommand::Quit => break,
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(all(ocvrs_has_module_highgui, ocvrs_has_module_imgproc))]
+pub fn annotate_frame(
+	window_name: &str,
+	img: &Mat,
+	annotations: &mut LineAnnotations,
+	keylines: &[KeyLine],
+	snapping: LineSnapping,
+	label: &str,
+) -> Result<AnnotationCommand> {
+	const PICK_DISTANCE: f32 = 6.;
+
+	#[derive(Default)]
+	struct Input {
+		selection: SegmentSelection,
+		delete_at: Option<Point>,
+	}
+
+	highgui::named_window(window_name, highgui::WINDOW_AUTOSIZE)?;
+	let input = Arc::new(Mutex::new(Input::default()));
+	highgui::set_mouse_callback(window_name, {
+		let input = Arc::clone(&input);
+		move |event, pt, _| {
+			let mut input = input.lock().unwrap_or_else(PoisonError::into_inner);
+			if event == MouseEvent::RightButtonDown {
+				input.delete_at = Some(pt);
+			} else {
+				input.selection.handle_mouse(event, pt);
+			}
+		}
+	})?;
+	let accent = Palette::global().accent();
+	let out = loop {
+		let (selection, delete_at) = {
+			let mut input = input.lock().unwrap_or_else(PoisonError::into_inner);
+			let selection = input.selection;
+			if selection.segment().is_some() {
+				input.selection.reset();
+			}
+			(selection, input.delete_at.take())
+		};
+		if let Some(i) = delete_at.and_then(|pt| annotations.line_at(to_point2f(pt), PICK_DISTANCE)) {
+			annotations.delete_line(i)?;
+		}
+		if let Some((start, end)) = selection.segment().filter(|(start, end)| start != end) {
+			annotations.add_line_snapped(to_point2f(start), to_point2f(end), label, keylines, snapping);
+		}
+		let highlight = selection
+			.hover()
+			.and_then(|pt| annotations.line_at(to_point2f(pt), PICK_DISTANCE));
+		let mut frame = Mat::default();
+		draw_annotations(img, annotations.current(), &mut frame, highlight)?;
+		if let Some((start, end)) = selection.preview().filter(|_| selection.segment().is_none()) {
+			imgproc::line(&mut frame, start, end, accent, 1, imgproc::LINE_AA, 0)?;
+		}
+		highgui::imshow(window_name, &frame)?;
+		let key = highgui::wait_key(20)?;
+		match (SelectionKey::from_key(key), key & 0xFF) {
+			(SelectionKey::Confirm, _) => break AnnotationCommand::NextFrame,
+			(SelectionKey::Cancel, _) => break AnnotationCommand::Quit,
+			(SelectionKey::Reset, _) => input.lock().unwrap_or_else(PoisonError::into_inner).selection.reset(),
+			(_, k) if k == i32::from(b'n') => break AnnotationCommand::NextFrame,
+			(_, k) if k == i32::from(b'p') => break AnnotationCommand::PreviousFrame,
+			(_, k) if k == i32::from(b'u') => {
+				if let Some(last) = annotations.current().lines.len().checked_sub(1) {
+					annotations.delete_line(last)?;
+				}
+			}
+			_ => {}
+		}
+		if highgui::get_window_property(window_name, highgui::WND_PROP_VISIBLE)? < 1. {
+			break AnnotationCommand::Quit;
+		}
+	};
+	highgui::remove_mouse_callback(window_name)?;
+	Ok(out)
+}
+
+fn distance(a: Point2f, b: Point2f) -> f32 {
+	(a.x - b.x).hypot(a.y - b.y)
+}
+
+fn write_point(out: &mut String, pt: Point2f) {
+	out.push('[');
+	write_json_number(out, f64::from(pt.x));
+	out.push(',');
+	write_json_number(out, f64::from(pt.y));
+	out.push(']');
+}
+
+fn read_point(val: &JsonValue) -> Result<Point2f> {
+	match val.as_array()? {
+		[x, y] => Ok(Point2f::new(x.as_f64()? as f32, y.as_f64()? as f32)),
+		coords => Err(Error::new(
+			core::StsParseError,
+			format!("Point must have 2 coordinates, but got: {}", coords.len()),
+		)),
+	}
+}
+
+#[cfg(ocvrs_has_module_imgproc)]
+fn to_point(pt: Point2f) -> Point {
+	Point::new(pt.x.round() as i32, pt.y.round() as i32)
+}
+
+#[cfg(all(ocvrs_has_module_highgui, ocvrs_has_module_imgproc))]
+fn to_point2f(pt: Point) -> Point2f {
+	Point2f::new(pt.x as f32, pt.y as f32)
+}
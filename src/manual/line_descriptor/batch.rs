@@ -0,0 +1,73 @@
+use std::ffi::c_void;
+
+use crate::{
+	core::{self, DMatch, Mat, Vector, CV_8UC1},
+	line_descriptor::BinaryDescriptorMatcherTrait,
+	prelude::*,
+	sys, Error, Result,
+};
+
+extern "C" {
+	fn cv_manual_line_descriptor_BinaryDescriptorMatcher_matchBatch(
+		instance: *mut c_void,
+		queries: *const *const c_void,
+		count: usize,
+		matches: *mut c_void,
+		ocvrs_return: *mut sys::Result_void,
+	);
+}
+
+/// Matching of many query descriptor sets in a single call
+pub trait BinaryDescriptorMatcherBatchTrait: BinaryDescriptorMatcherTrait {
+	/// Finds the best match for every descriptor of every query set among the added and trained descriptors
+	///
+	/// All sets are looked up in a single batched pass over the MIH index, so the call costs about the same as
+	/// [BinaryDescriptorMatcherTrait::match_query] with the concatenated sets, and much less than a separate call per set.
+	/// Gathering of the sets and splitting of the results run in parallel.
+	///
+	/// Returns one vector of matches per query set in the same order, `query_idx` of the matches refers to the row of
+	/// the respective set. Empty sets produce empty vectors. The non-empty sets must be `CV_8UC1` with the same number of
+	/// columns.
+	fn match_batch(&mut self, queries: &[Mat]) -> Result<Vector<Vector<DMatch>>> {
+		let mut cols = None;
+		for (i, query) in queries.iter().enumerate().filter(|(_, q)| !q.empty()) {
+			if query.typ() != CV_8UC1 {
+				return Err(Error::new(
+					core::StsUnsupportedFormat,
+					format!("Query descriptor set {} must be CV_8UC1, but got type: {}", i, query.typ()),
+				));
+			}
+			match cols {
+				Some(cols) if cols != query.cols() => {
+					return Err(Error::new(
+						core::StsUnmatchedSizes,
+						format!(
+							"Query descriptor set {} has {} columns, but the previous sets have: {}",
+							i,
+							query.cols(),
+							cols
+						),
+					));
+				}
+				_ => cols = Some(query.cols()),
+			}
+		}
+		let mut out = Vector::new();
+		let raw_queries = queries.iter().map(|q| q.as_raw_Mat()).collect::<Vec<_>>();
+		return_send!(via ocvrs_return);
+		unsafe {
+			cv_manual_line_descriptor_BinaryDescriptorMatcher_matchBatch(
+				self.as_raw_mut_BinaryDescriptorMatcher(),
+				raw_queries.as_ptr(),
+				raw_queries.len(),
+				out.as_raw_mut_VectorOfVectorOfDMatch(),
+				ocvrs_return.as_mut_ptr(),
+			)
+		};
+		return_receive!(unsafe ocvrs_return => ret);
+		ret.into_result()?;
+		Ok(out)
+	}
+}
+
+impl<T: BinaryDescriptorMatcherTrait + ?Sized> BinaryDescriptorMatcherBatchTrait for T {}
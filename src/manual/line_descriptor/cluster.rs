@@ -0,0 +1,144 @@
+use super::keyline::keyline_from_endpoints;
+use crate::{
+	core::{Point2f, Vector},
+	line_descriptor::KeyLine,
+};
+
+/// Groups the collinear keylines that overlap or are separated by a small gap
+///
+/// Two keylines are linked when the angle between them is at most `angle_tol` radians (the keylines are treated as
+/// undirected), both endpoints of the shorter one are within `dist_tol` pixels from the infinite line of the longer one and
+/// the gap between their projections on that line is at most `dist_tol` pixels. The clusters are the connected components
+/// of the linked keylines, so a long fragmented line ends up in a single cluster even if its first and last fragments are
+/// far apart.
+///
+/// Returns the indices of the keylines in each cluster in the ascending order, the clusters are ordered by their first
+/// index. Every keyline belongs to exactly one cluster, the isolated keylines form the single element clusters.
+pub fn cluster_keylines(keylines: &Vector<KeyLine>, angle_tol: f64, dist_tol: f64) -> Vec<Vec<usize>> {
+	let keylines = keylines.to_vec();
+	let mut parents = (0..keylines.len()).collect::<Vec<_>>();
+	for (i, a) in keylines.iter().enumerate() {
+		for (j, b) in keylines.iter().enumerate().skip(i + 1) {
+			if f64::from(a.angle_between(b)) <= angle_tol && are_collinear_neighbours(a, b, dist_tol) {
+				let (root_a, root_b) = (find_root(&mut parents, i), find_root(&mut parents, j));
+				// the smaller index becomes the root so that the clusters come out ordered
+				parents[root_a.max(root_b)] = root_a.min(root_b);
+			}
+		}
+	}
+	let mut clusters = Vec::<Vec<usize>>::new();
+	let mut cluster_of_root = vec![None::<usize>; keylines.len()];
+	for i in 0..keylines.len() {
+		let root = find_root(&mut parents, i);
+		match cluster_of_root[root] {
+			Some(cluster) => clusters[cluster].push(i),
+			None => {
+				cluster_of_root[root] = Some(clusters.len());
+				clusters.push(vec![i]);
+			}
+		}
+	}
+	clusters
+}
+
+/// Merges the keylines of the cluster into a single keyline spanning all of them
+///
+/// The line is fitted through the midpoints of the keylines weighted by their length, its endpoints are the extreme
+/// projections of the endpoints of the keylines on it. The result is oriented like the longest keyline of the cluster and
+/// takes its `class_id`, the `response` is the maximum one and the octave is 0. Returns `None` for an empty cluster.
+pub fn merge_keyline_cluster(keylines: &Vector<KeyLine>, cluster: &[usize]) -> Option<KeyLine> {
+	let members = cluster.iter().map(|&i| keylines.get(i).ok()).collect::<Option<Vec<_>>>()?;
+	let longest = members.iter().copied().fold(None, |acc: Option<KeyLine>, kl| match acc {
+		Some(acc) if acc.length() >= kl.length() => Some(acc),
+		_ => Some(kl),
+	})?;
+	if members.len() == 1 {
+		return Some(longest);
+	}
+	// average of the doubled angles, so that the opposite directions don't cancel out
+	let (mut sin_sum, mut cos_sum, mut weight_sum, mut cx, mut cy) = (0., 0., 0., 0., 0.);
+	for kl in &members {
+		let weight = f64::from(kl.length()).max(f64::EPSILON);
+		let (start, end) = kl.as_segment();
+		let angle = 2. * f64::from(end.y - start.y).atan2(f64::from(end.x - start.x));
+		sin_sum += weight * angle.sin();
+		cos_sum += weight * angle.cos();
+		weight_sum += weight;
+		cx += weight * f64::from(start.x + end.x) / 2.;
+		cy += weight * f64::from(start.y + end.y) / 2.;
+	}
+	let angle = sin_sum.atan2(cos_sum) / 2.;
+	let (mut dx, mut dy) = (angle.cos(), angle.sin());
+	let (cx, cy) = (cx / weight_sum, cy / weight_sum);
+	if let Some(dir) = longest.direction() {
+		if f64::from(dir.x) * dx + f64::from(dir.y) * dy < 0. {
+			dx = -dx;
+			dy = -dy;
+		}
+	}
+	let (mut t_min, mut t_max) = (f64::INFINITY, f64::NEG_INFINITY);
+	for kl in &members {
+		let (start, end) = kl.as_segment();
+		for p in &[start, end] {
+			let t = (f64::from(p.x) - cx) * dx + (f64::from(p.y) - cy) * dy;
+			t_min = t_min.min(t);
+			t_max = t_max.max(t);
+		}
+	}
+	let at = |t: f64| Point2f::new((cx + t * dx) as f32, (cy + t * dy) as f32);
+	let response = members.iter().map(|kl| kl.response).fold(f32::NEG_INFINITY, f32::max);
+	Some(KeyLine {
+		class_id: longest.class_id,
+		response,
+		..keyline_from_endpoints(at(t_min), at(t_max))
+	})
+}
+
+/// Clusters the keylines with [cluster_keylines] and replaces each cluster with its [merge_keyline_cluster] result
+///
+/// Useful to reassemble the long lines that the detectors like LSD return as multiple fragments. The merged keylines are
+/// ordered like their clusters.
+pub fn merge_keylines(keylines: &Vector<KeyLine>, angle_tol: f64, dist_tol: f64) -> Vector<KeyLine> {
+	cluster_keylines(keylines, angle_tol, dist_tol)
+		.iter()
+		.filter_map(|cluster| merge_keyline_cluster(keylines, cluster))
+		.collect()
+}
+
+/// Checks the perpendicular distance and the gap between the nearly parallel keylines
+fn are_collinear_neighbours(a: &KeyLine, b: &KeyLine, dist_tol: f64) -> bool {
+	let (long, short) = if a.length() >= b.length() {
+		(a, b)
+	} else {
+		(b, a)
+	};
+	let (origin, end) = long.as_segment();
+	let (short_start, short_end) = short.as_segment();
+	let dir = match long.direction() {
+		Some(dir) => dir,
+		// both keylines are points
+		None => return f64::from(long.distance_to_point(short_start)) <= dist_tol,
+	};
+	let (dx, dy) = (f64::from(dir.x), f64::from(dir.y));
+	// coordinates along and across the line of the longer keyline
+	let project = |p: Point2f| {
+		let (px, py) = (f64::from(p.x - origin.x), f64::from(p.y - origin.y));
+		(px * dx + py * dy, px * dy - py * dx)
+	};
+	let long_len = project(end).0;
+	let (start_along, start_across) = project(short_start);
+	let (end_along, end_across) = project(short_end);
+	if start_across.abs() > dist_tol || end_across.abs() > dist_tol {
+		return false;
+	}
+	let gap = (start_along.min(end_along) - long_len).max(-start_along.max(end_along));
+	gap <= dist_tol
+}
+
+fn find_root(parents: &mut [usize], mut i: usize) -> usize {
+	while parents[i] != i {
+		parents[i] = parents[parents[i]];
+		i = parents[i];
+	}
+	i
+}
@@ -0,0 +1,125 @@
+use super::keyline::keyline_from_endpoints;
+use crate::{
+	core::{self, KeyPoint, Point2f, Vector},
+	line_descriptor::KeyLine,
+	Error, Result,
+};
+
+/// Encodes the keyline as a keypoint, so that the point based tooling like RANSAC or `draw_keypoints` can be used
+///
+/// The encoding is the same as the one used by `binary_descriptor_to_feature2d`:
+/// * `pt` is the midpoint of the keyline,
+/// * `size` is its length and `angle` is its direction in degrees in the range [0; 360), both in the original image
+///   coordinates,
+/// * `response`, `octave` and `class_id` are copied from the keyline.
+pub fn keyline_to_keypoint(keyline: &KeyLine) -> KeyPoint {
+	let (dx, dy) = (
+		keyline.end_point_x - keyline.start_point_x,
+		keyline.end_point_y - keyline.start_point_y,
+	);
+	let mut angle = dy.atan2(dx).to_degrees();
+	if angle < 0. {
+		angle += 360.;
+	}
+	KeyPoint {
+		pt: Point2f::new(
+			(keyline.start_point_x + keyline.end_point_x) / 2.,
+			(keyline.start_point_y + keyline.end_point_y) / 2.,
+		),
+		size: dx.hypot(dy),
+		angle,
+		response: keyline.response,
+		octave: keyline.octave,
+		class_id: keyline.class_id,
+	}
+}
+
+/// Encodes every keyline as a keypoint, see [keyline_to_keypoint]
+pub fn keylines_to_keypoints(keylines: &Vector<KeyLine>) -> Vector<KeyPoint> {
+	keylines.iter().map(|kl| keyline_to_keypoint(&kl)).collect()
+}
+
+/// Decodes the keyline from the keypoint encoded by [keyline_to_keypoint]
+///
+/// `angle` is in radians in the range [-π; π] and `size` is the area of the bounding box of the segment, same as for the
+/// keylines created from the endpoints.
+///
+/// The coordinates in the octave are restored from the octave of the keypoint using `reduction_ratio` of the pyramid the
+/// keyline was detected in, it's 2 for `BinaryDescriptor` with the default parameters.
+pub fn keypoint_to_keyline(keypoint: &KeyPoint, reduction_ratio: i32) -> KeyLine {
+	let rad = keypoint.angle.to_radians();
+	let (half_x, half_y) = (rad.cos() * keypoint.size / 2., rad.sin() * keypoint.size / 2.);
+	let octave = keypoint.octave.max(0);
+	let scale = (reduction_ratio as f32).powi(octave);
+	let (start, end) = (
+		Point2f::new(keypoint.pt.x - half_x, keypoint.pt.y - half_y),
+		Point2f::new(keypoint.pt.x + half_x, keypoint.pt.y + half_y),
+	);
+	let line_length = keypoint.size / scale;
+	KeyLine {
+		class_id: keypoint.class_id,
+		octave,
+		pt: keypoint.pt,
+		response: keypoint.response,
+		s_point_in_octave_x: start.x / scale,
+		s_point_in_octave_y: start.y / scale,
+		e_point_in_octave_x: end.x / scale,
+		e_point_in_octave_y: end.y / scale,
+		line_length,
+		num_of_pixels: line_length.ceil() as i32,
+		..keyline_from_endpoints(start, end)
+	}
+}
+
+/// Decodes every keypoint as a keyline, see [keypoint_to_keyline]
+pub fn keypoints_to_keylines(keypoints: &Vector<KeyPoint>, reduction_ratio: i32) -> Vector<KeyLine> {
+	keypoints.iter().map(|kp| keypoint_to_keyline(&kp, reduction_ratio)).collect()
+}
+
+/// Splits the keylines into the keypoints at their start and end points
+///
+/// Every keypoint gets the `response`, `octave` and `class_id` of its keyline, `size` is 0 and `angle` is -1. Allows
+/// running the point based processing on the endpoints, e.g. undistortion or perspective transform, and then joining
+/// the results back with [keylines_from_endpoint_keypoints].
+pub fn keylines_to_endpoint_keypoints(keylines: &Vector<KeyLine>) -> (Vector<KeyPoint>, Vector<KeyPoint>) {
+	let endpoint = |kl: &KeyLine, pt: Point2f| KeyPoint {
+		pt,
+		size: 0.,
+		angle: -1.,
+		response: kl.response,
+		octave: kl.octave,
+		class_id: kl.class_id,
+	};
+	keylines
+		.iter()
+		.map(|kl| {
+			let (start, end) = kl.as_segment();
+			(endpoint(&kl, start), endpoint(&kl, end))
+		})
+		.unzip()
+}
+
+/// Joins the pairs of the endpoint keypoints into the keylines in the octave 0
+///
+/// `response` and `class_id` are taken from the start keypoints. `starts` and `ends` must have the same length.
+pub fn keylines_from_endpoint_keypoints(starts: &Vector<KeyPoint>, ends: &Vector<KeyPoint>) -> Result<Vector<KeyLine>> {
+	if starts.len() != ends.len() {
+		return Err(Error::new(
+			core::StsUnmatchedSizes,
+			format!(
+				"Number of the start keypoints: {} doesn't match the number of the end keypoints: {}",
+				starts.len(),
+				ends.len()
+			),
+		));
+	}
+	Ok(starts
+		.iter()
+		.zip(ends.iter())
+		.map(|(start, end)| KeyLine {
+			response: start.response,
+			class_id: start.class_id,
+			..keyline_from_endpoints(start.pt, end.pt)
+		})
+		.collect())
+}
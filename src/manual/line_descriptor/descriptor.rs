@@ -0,0 +1,55 @@
+use std::ffi::c_void;
+
+use crate::{
+	core::{Mat, ToInputArray, Vector},
+	line_descriptor::{BinaryDescriptorTraitConst, KeyLine},
+	prelude::*,
+	sys, Result,
+};
+
+extern "C" {
+	fn cv_manual_line_descriptor_BinaryDescriptor_detectAndCompute(
+		instance: *const c_void,
+		image: *const c_void,
+		mask: *const c_void,
+		keylines: *mut c_void,
+		descriptors: *mut c_void,
+		ocvrs_return: *mut sys::Result_void,
+	);
+}
+
+/// Combined detection and description with `BinaryDescriptor`
+pub trait BinaryDescriptorTraitConstManual: BinaryDescriptorTraitConst {
+	/// Detects the keylines in the image and computes their LBD descriptors
+	///
+	/// Same as `Feature2D::detect_and_compute()`, the Gaussian pyramid is built only once and shared by the detection
+	/// and the description, unlike calling `detect()` and `compute()` separately.
+	///
+	/// ## Parameters
+	/// * image: input image
+	/// * mask: mask matrix to detect only the keylines of interest
+	///
+	/// Returns the detected keylines and the binary descriptors, one row per keyline.
+	fn detect_and_compute(&self, image: &dyn ToInputArray, mask: Option<&dyn ToInputArray>) -> Result<(Vector<KeyLine>, Mat)> {
+		input_array_arg!(image);
+		input_array_arg!(nullable mask);
+		let mut keylines = Vector::new();
+		let mut descriptors = Mat::default();
+		return_send!(via ocvrs_return);
+		unsafe {
+			cv_manual_line_descriptor_BinaryDescriptor_detectAndCompute(
+				self.as_raw_BinaryDescriptor(),
+				image.as_raw__InputArray(),
+				mask.as_raw__InputArray(),
+				keylines.as_raw_mut_VectorOfKeyLine(),
+				descriptors.as_raw_mut_Mat(),
+				ocvrs_return.as_mut_ptr(),
+			)
+		};
+		return_receive!(unsafe ocvrs_return => ret);
+		ret.into_result()?;
+		Ok((keylines, descriptors))
+	}
+}
+
+impl<T: BinaryDescriptorTraitConst + ?Sized> BinaryDescriptorTraitConstManual for T {}
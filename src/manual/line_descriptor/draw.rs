@@ -0,0 +1,197 @@
+use std::convert::TryFrom;
+use std::ops::{BitOr, BitOrAssign};
+
+use crate::{
+	core::{self, DMatch, Mat, Scalar, Vector, CV_8U},
+	line_descriptor::{
+		self, DrawLinesMatchesFlags_DEFAULT, DrawLinesMatchesFlags_DRAW_OVER_OUTIMG, DrawLinesMatchesFlags_NOT_DRAW_SINGLE_LINES,
+		KeyLine,
+	},
+	prelude::*,
+	Error, Result,
+};
+
+/// Drawing flags of [draw_line_matches], can be combined with `|`
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DrawLinesMatchesFlags(i32);
+
+impl DrawLinesMatchesFlags {
+	/// Output image is created, both images, the matches and the single keylines are drawn
+	pub const DEFAULT: Self = Self(DrawLinesMatchesFlags_DEFAULT);
+	/// Output image is not created, the matches are drawn over its existing content
+	pub const DRAW_OVER_OUTIMG: Self = Self(DrawLinesMatchesFlags_DRAW_OVER_OUTIMG);
+	/// Single keylines are not drawn
+	pub const NOT_DRAW_SINGLE_LINES: Self = Self(DrawLinesMatchesFlags_NOT_DRAW_SINGLE_LINES);
+
+	/// Raw value as accepted by the OpenCV functions
+	#[inline]
+	pub fn bits(self) -> i32 {
+		self.0
+	}
+
+	/// Whether all the flags of `other` are set
+	#[inline]
+	pub fn contains(self, other: Self) -> bool {
+		self.0 & other.0 == other.0
+	}
+}
+
+impl BitOr for DrawLinesMatchesFlags {
+	type Output = Self;
+
+	#[inline]
+	fn bitor(self, rhs: Self) -> Self {
+		Self(self.0 | rhs.0)
+	}
+}
+
+impl BitOrAssign for DrawLinesMatchesFlags {
+	#[inline]
+	fn bitor_assign(&mut self, rhs: Self) {
+		self.0 |= rhs.0
+	}
+}
+
+impl From<DrawLinesMatchesFlags> for i32 {
+	#[inline]
+	fn from(s: DrawLinesMatchesFlags) -> Self {
+		s.bits()
+	}
+}
+
+/// Draws the found matches of keylines from two images
+///
+/// Same as [line_descriptor::draw_line_matches_raw], but takes `matches_mask` as a slice of flags indicating which
+/// matches must be drawn, `None` draws all the matches, and typed `flags`.
+#[allow(clippy::too_many_arguments)]
+pub fn draw_line_matches(
+	img1: &Mat,
+	keylines1: &Vector<KeyLine>,
+	img2: &Mat,
+	keylines2: &Vector<KeyLine>,
+	matches1to2: &Vector<DMatch>,
+	out_img: &mut Mat,
+	match_color: Scalar,
+	single_line_color: Scalar,
+	matches_mask: Option<&[bool]>,
+	flags: DrawLinesMatchesFlags,
+) -> Result<()> {
+	let matches_mask = matches_mask.map_or_else(Vector::new, |mask| mask.iter().map(|&draw| i8::from(draw)).collect());
+	line_descriptor::draw_line_matches_raw(
+		img1,
+		keylines1,
+		img2,
+		keylines2,
+		matches1to2,
+		out_img,
+		match_color,
+		single_line_color,
+		&matches_mask,
+		flags.bits(),
+	)
+}
+
+/// Same as [line_descriptor::draw_line_matches], but validates the arguments first
+///
+/// The C++ function doesn't check the indices of the matches and the image types, so the invalid arguments lead to
+/// reads out of bounds or silently garbled output. This wrapper returns a descriptive error instead:
+/// * the images must be `CV_8U` with 1 or 3 channels and of the same type,
+/// * `query_idx` and `train_idx` of every match must refer to the existing keylines in `keylines1` and `keylines2`,
+/// * `matches_mask` must be `None` or have the same length as `matches1to2`,
+/// * with `DrawLinesMatchesFlags::DRAW_OVER_OUTIMG` the output image must be large enough to hold both images side by
+///   side and have the same type.
+#[allow(clippy::too_many_arguments)]
+pub fn draw_line_matches_checked(
+	img1: &Mat,
+	keylines1: &Vector<KeyLine>,
+	img2: &Mat,
+	keylines2: &Vector<KeyLine>,
+	matches1to2: &Vector<DMatch>,
+	out_img: &mut Mat,
+	match_color: Scalar,
+	single_line_color: Scalar,
+	matches_mask: Option<&[bool]>,
+	flags: DrawLinesMatchesFlags,
+) -> Result<()> {
+	check_image("First", img1)?;
+	check_image("Second", img2)?;
+	if img1.typ() != img2.typ() {
+		return Err(Error::new(
+			core::StsUnmatchedFormats,
+			format!("Images must have the same type, but got: {} and {}", img1.typ(), img2.typ()),
+		));
+	}
+	if matches!(matches_mask, Some(mask) if mask.len() != matches1to2.len()) {
+		return Err(Error::new(
+			core::StsUnmatchedSizes,
+			format!(
+				"Matches mask must have the same length as the matches ({}), but got: {}",
+				matches1to2.len(),
+				matches_mask.map_or(0, |mask| mask.len())
+			),
+		));
+	}
+	for (i, m) in matches1to2.iter().enumerate() {
+		check_index("query_idx", i, m.query_idx, keylines1.len(), "keylines")?;
+		check_index("train_idx", i, m.train_idx, keylines2.len(), "keylines")?;
+	}
+	if flags.contains(DrawLinesMatchesFlags::DRAW_OVER_OUTIMG) {
+		let (rows, cols) = (img1.rows().max(img2.rows()), img1.cols() + img2.cols());
+		if out_img.typ() != img1.typ() || out_img.rows() < rows || out_img.cols() < cols {
+			return Err(Error::new(
+				core::StsBadArg,
+				format!(
+					"Output image for drawing over must be at least {}x{} of type {}, but got {}x{} of type {}",
+					cols,
+					rows,
+					img1.typ(),
+					out_img.cols(),
+					out_img.rows(),
+					out_img.typ()
+				),
+			));
+		}
+	}
+	draw_line_matches(
+		img1,
+		keylines1,
+		img2,
+		keylines2,
+		matches1to2,
+		out_img,
+		match_color,
+		single_line_color,
+		matches_mask,
+		flags,
+	)
+}
+
+pub(super) fn check_image(name: &str, img: &Mat) -> Result<()> {
+	if img.empty() {
+		return Err(Error::new(core::StsBadArg, format!("{} image is empty", name)));
+	}
+	if img.depth() != CV_8U || !matches!(img.channels(), 1 | 3) {
+		return Err(Error::new(
+			core::StsUnsupportedFormat,
+			format!(
+				"{} image must be CV_8U with 1 or 3 channels, but got type: {}",
+				name,
+				img.typ()
+			),
+		));
+	}
+	Ok(())
+}
+
+pub(super) fn check_index(name: &str, match_idx: usize, idx: i32, len: usize, features: &str) -> Result<()> {
+	if usize::try_from(idx).map_or(true, |idx| idx >= len) {
+		return Err(Error::new(
+			core::StsOutOfRange,
+			format!(
+				"Match {} has {} {} out of range of {} {}",
+				match_idx, name, idx, len, features
+			),
+		));
+	}
+	Ok(())
+}
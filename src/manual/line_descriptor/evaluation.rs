@@ -0,0 +1,308 @@
+//! Evaluation of the line detectors and matchers against the ground truth homographies
+//!
+//! Line counterpart of `features2d::evaluate_feature_detector()` and `features2d::compute_recall_precision_curve()`.
+//! The keylines of the first image are mapped into the second one with the homography and compared with the keylines
+//! there using the selected [LineError].
+
+use std::{cmp::Ordering, convert::TryFrom};
+
+use super::linalg::{cross, invert, mat_vec_mul, Mat3, Vec3};
+use crate::{
+	core::{self, DMatch, Point2f, Size},
+	line_descriptor::KeyLine,
+	Error, Result,
+};
+
+/// Geometric error between the keyline mapped with the homography and its counterpart
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LineError {
+	/// Mean distance between the corresponding endpoints, the keylines are treated as undirected
+	///
+	/// Also penalizes the segments that lie on the same line but have different extent.
+	Endpoint,
+	/// Mean distance of the endpoints of each keyline from the line of the other one, symmetric
+	///
+	/// Point-to-line error used by the Sampson-style line correspondence checks, both distances are measured in the second
+	/// image. It only checks that the segments lie on the same line, so it tolerates the fragmented and partially detected
+	/// lines.
+	Sampson,
+}
+
+/// Parameters of the evaluation
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct EvaluationParams {
+	/// Error measure of the correspondence
+	pub error: LineError,
+	/// Maximum error in pixels of the second image for the correspondence to be correct
+	pub threshold: f64,
+	/// Maximum angle in radians between the mapped and the target keyline, the keylines are treated as undirected
+	pub max_angle: f64,
+}
+
+impl Default for EvaluationParams {
+	fn default() -> Self {
+		Self {
+			error: LineError::Endpoint,
+			threshold: 5.,
+			max_angle: 5f64.to_radians(),
+		}
+	}
+}
+
+/// Result of [evaluate_line_matches]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MatchEvaluation {
+	/// Number of the evaluated matches
+	pub matches: usize,
+	/// Number of the matches that agree with the homography
+	pub correct_matches: usize,
+	/// Number of the keylines of the first image that have a correspondence in the second image, the upper bound of
+	/// `correct_matches` for the one-to-one matching
+	pub correspondences: usize,
+}
+
+impl MatchEvaluation {
+	/// Ratio of the correct matches to all matches, 0 if there are no matches
+	pub fn precision(&self) -> f64 {
+		ratio(self.correct_matches, self.matches)
+	}
+
+	/// Ratio of the correct matches to the existing correspondences, 0 if there are no correspondences
+	pub fn recall(&self) -> f64 {
+		ratio(self.correct_matches, self.correspondences)
+	}
+}
+
+/// Result of [evaluate_line_detector]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DetectorEvaluation {
+	/// Number of the keylines of the first image that have a correspondence in the second image
+	pub correspondences: usize,
+	/// `correspondences` relative to the smaller of the keyline counts, only the keylines of the first image that are
+	/// mapped inside the second image are counted
+	pub repeatability: f64,
+}
+
+/// Computes the error of mapping `keyline1` with the homography `h1to2` onto `keyline2`
+///
+/// Returns `None` if the keyline is mapped to infinity or, for [LineError::Sampson], one of the segments is degenerate.
+pub fn line_error(keyline1: &KeyLine, keyline2: &KeyLine, h1to2: &[[f64; 3]; 3], error: LineError) -> Option<f64> {
+	let (a, b) = project_keyline(keyline1, h1to2)?;
+	let (c, d) = segment(keyline2);
+	match error {
+		LineError::Endpoint => {
+			let direct = (dist(a, c) + dist(b, d)) / 2.;
+			let swapped = (dist(a, d) + dist(b, c)) / 2.;
+			Some(direct.min(swapped))
+		}
+		LineError::Sampson => {
+			let forward = (line_distance(c, d, a)? + line_distance(c, d, b)?) / 2.;
+			let backward = (line_distance(a, b, c)? + line_distance(a, b, d)?) / 2.;
+			Some((forward + backward) / 2.)
+		}
+	}
+}
+
+/// Whether `keyline1` mapped with `h1to2` corresponds to `keyline2` according to the `params`
+pub fn is_correspondence(keyline1: &KeyLine, keyline2: &KeyLine, h1to2: &[[f64; 3]; 3], params: &EvaluationParams) -> bool {
+	let (a, b) = match project_keyline(keyline1, h1to2) {
+		Some(projected) => projected,
+		None => return false,
+	};
+	let (c, d) = segment(keyline2);
+	let angle = undirected_angle([b[0] - a[0], b[1] - a[1]], [d[0] - c[0], d[1] - c[1]]);
+	angle <= params.max_angle && matches!(line_error(keyline1, keyline2, h1to2, params.error), Some(e) if e <= params.threshold)
+}
+
+/// Marks the matches that agree with the ground truth homography
+///
+/// ## Parameters
+/// * keylines1: keylines of the first (query) image
+/// * keylines2: keylines of the second (train) image
+/// * matches1to2: matches to check
+/// * h1to2: homography mapping the first image to the second one
+/// * params: correspondence criteria
+pub fn correct_matches_mask(
+	keylines1: &[KeyLine],
+	keylines2: &[KeyLine],
+	matches1to2: &[DMatch],
+	h1to2: &[[f64; 3]; 3],
+	params: &EvaluationParams,
+) -> Result<Vec<bool>> {
+	check_homography(h1to2)?;
+	matches1to2
+		.iter()
+		.map(|m| {
+			let (kl1, kl2) = matched_keylines(keylines1, keylines2, m)?;
+			Ok(is_correspondence(kl1, kl2, h1to2, params))
+		})
+		.collect()
+}
+
+/// Computes the precision and recall of the line matches given the ground truth homography
+///
+/// See [correct_matches_mask] for the parameters.
+pub fn evaluate_line_matches(
+	keylines1: &[KeyLine],
+	keylines2: &[KeyLine],
+	matches1to2: &[DMatch],
+	h1to2: &[[f64; 3]; 3],
+	params: &EvaluationParams,
+) -> Result<MatchEvaluation> {
+	let mask = correct_matches_mask(keylines1, keylines2, matches1to2, h1to2, params)?;
+	Ok(MatchEvaluation {
+		matches: matches1to2.len(),
+		correct_matches: mask.iter().filter(|&&correct| correct).count(),
+		correspondences: count_correspondences(keylines1, keylines2, h1to2, params),
+	})
+}
+
+/// Computes the repeatability of the line detector given the ground truth homography
+///
+/// Only the keylines of the first image whose both endpoints are mapped inside the second image of `size2` are considered.
+pub fn evaluate_line_detector(
+	keylines1: &[KeyLine],
+	keylines2: &[KeyLine],
+	h1to2: &[[f64; 3]; 3],
+	size2: Size,
+	params: &EvaluationParams,
+) -> Result<DetectorEvaluation> {
+	check_homography(h1to2)?;
+	let inside = |p: [f64; 2]| p[0] >= 0. && p[1] >= 0. && p[0] < f64::from(size2.width) && p[1] < f64::from(size2.height);
+	let visible = keylines1
+		.iter()
+		.filter(|kl| matches!(project_keyline(kl, h1to2), Some((a, b)) if inside(a) && inside(b)))
+		.copied()
+		.collect::<Vec<_>>();
+	let correspondences = count_correspondences(&visible, keylines2, h1to2, params);
+	Ok(DetectorEvaluation {
+		correspondences,
+		repeatability: ratio(correspondences, visible.len().min(keylines2.len())),
+	})
+}
+
+/// Recall-precision curve of the matches ordered by the increasing descriptor distance
+///
+/// Same as `features2d::compute_recall_precision_curve()`: the point `i` of the curve is computed for the `i + 1` best
+/// matches, `x` is `1 - precision` and `y` is the recall relative to all the correct matches in `correct_mask`.
+pub fn recall_precision_curve(matches1to2: &[DMatch], correct_mask: &[bool]) -> Result<Vec<Point2f>> {
+	if matches1to2.len() != correct_mask.len() {
+		return Err(Error::new(
+			core::StsUnmatchedSizes,
+			format!(
+				"Correct matches mask must have the same length as the matches ({}), but got: {}",
+				matches1to2.len(),
+				correct_mask.len()
+			),
+		));
+	}
+	let mut order = (0..matches1to2.len()).collect::<Vec<_>>();
+	order.sort_by(|&a, &b| {
+		matches1to2[a]
+			.distance
+			.partial_cmp(&matches1to2[b].distance)
+			.unwrap_or(Ordering::Equal)
+	});
+	let total_correct = correct_mask.iter().filter(|&&correct| correct).count();
+	let mut correct = 0;
+	Ok(order
+		.into_iter()
+		.enumerate()
+		.map(|(i, idx)| {
+			if correct_mask[idx] {
+				correct += 1;
+			}
+			let precision = ratio(correct, i + 1);
+			Point2f::new((1. - precision) as f32, ratio(correct, total_correct) as f32)
+		})
+		.collect())
+}
+
+/// Recall at the given `1 - precision` level of the curve computed by [recall_precision_curve], -1 if the level is never
+/// reached
+///
+/// Same as `features2d::get_recall()`.
+pub fn recall_at(curve: &[Point2f], one_minus_precision: f32) -> f32 {
+	curve
+		.iter()
+		.filter(|pt| pt.x <= one_minus_precision)
+		.map(|pt| pt.y)
+		.fold(-1., f32::max)
+}
+
+fn count_correspondences(keylines1: &[KeyLine], keylines2: &[KeyLine], h1to2: &Mat3, params: &EvaluationParams) -> usize {
+	keylines1
+		.iter()
+		.filter(|kl1| keylines2.iter().any(|kl2| is_correspondence(kl1, kl2, h1to2, params)))
+		.count()
+}
+
+fn matched_keylines<'k>(keylines1: &'k [KeyLine], keylines2: &'k [KeyLine], m: &DMatch) -> Result<(&'k KeyLine, &'k KeyLine)> {
+	let get = |keylines: &'k [KeyLine], idx: i32, name: &str| {
+		usize::try_from(idx).ok().and_then(|idx| keylines.get(idx)).ok_or_else(|| {
+			Error::new(
+				core::StsOutOfRange,
+				format!("Match has {} {} out of range of {} keylines", name, idx, keylines.len()),
+			)
+		})
+	};
+	Ok((
+		get(keylines1, m.query_idx, "query_idx")?,
+		get(keylines2, m.train_idx, "train_idx")?,
+	))
+}
+
+fn check_homography(h: &Mat3) -> Result<()> {
+	if h.iter().flatten().all(|v| v.is_finite()) && invert(h).is_some() {
+		Ok(())
+	} else {
+		Err(Error::new(core::StsBadArg, "Homography must be finite and invertible"))
+	}
+}
+
+fn ratio(num: usize, denom: usize) -> f64 {
+	if denom == 0 {
+		0.
+	} else {
+		num as f64 / denom as f64
+	}
+}
+
+fn segment(kl: &KeyLine) -> ([f64; 2], [f64; 2]) {
+	let (start, end) = kl.as_segment();
+	([f64::from(start.x), f64::from(start.y)], [f64::from(end.x), f64::from(end.y)])
+}
+
+fn project_keyline(kl: &KeyLine, h: &Mat3) -> Option<([f64; 2], [f64; 2])> {
+	let (start, end) = segment(kl);
+	Some((project(h, start)?, project(h, end)?))
+}
+
+fn project(h: &Mat3, p: [f64; 2]) -> Option<[f64; 2]> {
+	let [x, y, w] = mat_vec_mul(h, [p[0], p[1], 1.]);
+	if w.abs() < f64::EPSILON {
+		None
+	} else {
+		Some([x / w, y / w])
+	}
+}
+
+fn dist(a: [f64; 2], b: [f64; 2]) -> f64 {
+	(a[0] - b[0]).hypot(a[1] - b[1])
+}
+
+/// Distance of `p` from the infinite line through `a` and `b`, `None` if the points coincide
+fn line_distance(a: [f64; 2], b: [f64; 2], p: [f64; 2]) -> Option<f64> {
+	let line: Vec3 = cross([a[0], a[1], 1.], [b[0], b[1], 1.]);
+	let norm = line[0].hypot(line[1]);
+	if norm < f64::EPSILON {
+		None
+	} else {
+		Some((line[0] * p[0] + line[1] * p[1] + line[2]).abs() / norm)
+	}
+}
+
+fn undirected_angle(a: [f64; 2], b: [f64; 2]) -> f64 {
+	let diff = (a[1].atan2(a[0]) - b[1].atan2(b[0])).rem_euclid(std::f64::consts::PI);
+	diff.min(std::f64::consts::PI - diff)
+}
@@ -0,0 +1,221 @@
+#[cfg(all(ocvrs_has_module_dnn, ocvrs_has_module_imgproc))]
+use super::patch::{extract_keyline_patches, PatchParams};
+#[cfg(all(ocvrs_has_module_dnn, ocvrs_has_module_imgproc))]
+use crate::{
+	core::{self, Scalar, CV_32F},
+	dnn::{self, Net},
+	prelude::*,
+	Error,
+};
+use crate::{
+	core::{Mat, Ptr, Vector},
+	line_descriptor::{BinaryDescriptor, BinaryDescriptorTraitConst, KeyLine},
+	Result,
+};
+
+/// Backend computing the descriptors of the keylines
+///
+/// Implemented by [BinaryDescriptor] and [DnnLineDescriptor], implement it for a custom extractor to use it with the
+/// code that is generic over the descriptor backend. The descriptors of the different backends are not comparable,
+/// use [LineDescriptorExtractor::default_norm] to match them.
+pub trait LineDescriptorExtractor {
+	/// Computes the descriptors of the keylines
+	///
+	/// ## Parameters
+	/// * image: input image
+	/// * keylines: keylines to compute the descriptors for, the extractor can remove the keylines for which the
+	///   descriptor can't be computed
+	/// * descriptors: output matrix with one descriptor per row, the rows correspond to the `keylines` after the call
+	fn compute(&mut self, image: &Mat, keylines: &mut Vector<KeyLine>, descriptors: &mut Mat) -> Result<()>;
+
+	/// Number of the elements in a single descriptor
+	fn descriptor_size(&self) -> Result<i32>;
+
+	/// Type of the descriptor elements, one of `core::CV_*` depth constants
+	fn descriptor_type(&self) -> Result<i32>;
+
+	/// Norm to compare the descriptors with, one of `core::NORM_*` constants
+	fn default_norm(&self) -> Result<i32>;
+}
+
+macro_rules! binary_descriptor_extractor {
+	($type: ty) => {
+		impl LineDescriptorExtractor for $type {
+			/// Computes the binary descriptors, use `BinaryDescriptorTraitConst::compute()` to get the float ones
+			#[inline]
+			fn compute(&mut self, image: &Mat, keylines: &mut Vector<KeyLine>, descriptors: &mut Mat) -> Result<()> {
+				BinaryDescriptorTraitConst::compute(self, image, keylines, descriptors, false)
+			}
+
+			#[inline]
+			fn descriptor_size(&self) -> Result<i32> {
+				BinaryDescriptorTraitConst::descriptor_size(self)
+			}
+
+			#[inline]
+			fn descriptor_type(&self) -> Result<i32> {
+				BinaryDescriptorTraitConst::descriptor_type(self)
+			}
+
+			#[inline]
+			fn default_norm(&self) -> Result<i32> {
+				BinaryDescriptorTraitConst::default_norm(self)
+			}
+		}
+	};
+}
+
+binary_descriptor_extractor! { BinaryDescriptor }
+binary_descriptor_extractor! { Ptr<BinaryDescriptor> }
+
+impl<E: LineDescriptorExtractor + ?Sized> LineDescriptorExtractor for &mut E {
+	#[inline]
+	fn compute(&mut self, image: &Mat, keylines: &mut Vector<KeyLine>, descriptors: &mut Mat) -> Result<()> {
+		(**self).compute(image, keylines, descriptors)
+	}
+
+	#[inline]
+	fn descriptor_size(&self) -> Result<i32> {
+		(**self).descriptor_size()
+	}
+
+	#[inline]
+	fn descriptor_type(&self) -> Result<i32> {
+		(**self).descriptor_type()
+	}
+
+	#[inline]
+	fn default_norm(&self) -> Result<i32> {
+		(**self).default_norm()
+	}
+}
+
+impl<E: LineDescriptorExtractor + ?Sized> LineDescriptorExtractor for Box<E> {
+	#[inline]
+	fn compute(&mut self, image: &Mat, keylines: &mut Vector<KeyLine>, descriptors: &mut Mat) -> Result<()> {
+		(**self).compute(image, keylines, descriptors)
+	}
+
+	#[inline]
+	fn descriptor_size(&self) -> Result<i32> {
+		(**self).descriptor_size()
+	}
+
+	#[inline]
+	fn descriptor_type(&self) -> Result<i32> {
+		(**self).descriptor_type()
+	}
+
+	#[inline]
+	fn default_norm(&self) -> Result<i32> {
+		(**self).default_norm()
+	}
+}
+
+/// Line descriptor computed by a neural network from the orientation normalized keyline patches
+///
+/// The patches are extracted with [extract_keyline_patches], converted to a blob with `dnn::blob_from_images()` and
+/// passed through the network in a single batch. The network must produce one descriptor vector per input patch, the
+/// output is reshaped to `keylines.len()` rows of `f32` elements.
+#[cfg(all(ocvrs_has_module_dnn, ocvrs_has_module_imgproc))]
+pub struct DnnLineDescriptor {
+	net: Net,
+	descriptor_size: i32,
+	/// Parameters of the patch extraction, `size` is the network input size
+	pub patch_params: PatchParams,
+	/// Multiplier for the patch pixel values
+	pub scale_factor: f64,
+	/// Value subtracted from the patch pixels before the scaling
+	pub mean: Scalar,
+	/// Whether to swap the first and the last channels of the 3-channel patches
+	pub swap_rb: bool,
+	/// Whether to normalize the descriptors to the unit L2 norm
+	pub l2_normalize: bool,
+}
+
+#[cfg(all(ocvrs_has_module_dnn, ocvrs_has_module_imgproc))]
+impl DnnLineDescriptor {
+	/// Creates the extractor from the loaded network
+	///
+	/// ## Parameters
+	/// * net: network that maps a batch of patches to a batch of descriptors
+	/// * descriptor_size: number of elements in the network output for a single patch
+	/// * patch_params: parameters of the patch extraction, `size` must match the network input size
+	pub fn new(net: Net, descriptor_size: i32, patch_params: PatchParams) -> Self {
+		Self {
+			net,
+			descriptor_size,
+			patch_params,
+			scale_factor: 1. / 255.,
+			mean: Scalar::default(),
+			swap_rb: false,
+			l2_normalize: true,
+		}
+	}
+
+	/// Loads the network from the ONNX file, see [DnnLineDescriptor::new]
+	pub fn from_onnx(path: &str, descriptor_size: i32, patch_params: PatchParams) -> Result<Self> {
+		Ok(Self::new(dnn::read_net_from_onnx(path)?, descriptor_size, patch_params))
+	}
+
+	pub fn net(&self) -> &Net {
+		&self.net
+	}
+
+	pub fn net_mut(&mut self) -> &mut Net {
+		&mut self.net
+	}
+}
+
+#[cfg(all(ocvrs_has_module_dnn, ocvrs_has_module_imgproc))]
+impl LineDescriptorExtractor for DnnLineDescriptor {
+	fn compute(&mut self, image: &Mat, keylines: &mut Vector<KeyLine>, descriptors: &mut Mat) -> Result<()> {
+		if keylines.is_empty() {
+			*descriptors = Mat::default();
+			return Ok(());
+		}
+		let size = self.patch_params.size;
+		let patches = extract_keyline_patches(image, &keylines.to_vec(), &self.patch_params)?;
+		let patches = (0..patches.rows())
+			.map(|i| patches.row(i)?.reshape(image.channels(), size.height))
+			.collect::<Result<Vector<Mat>>>()?;
+		let blob = dnn::blob_from_images(&patches, self.scale_factor, size, self.mean, self.swap_rb, false, CV_32F)?;
+		self.net.set_input(&blob, "", 1., Scalar::default())?;
+		let out = self.net.forward_single("")?;
+		if out.total() != keylines.len() * self.descriptor_size as usize {
+			return Err(Error::new(
+				core::StsUnmatchedSizes,
+				format!(
+					"Network output has {} elements, but {} descriptors of size {} are expected",
+					out.total(),
+					keylines.len(),
+					self.descriptor_size
+				),
+			));
+		}
+		let out = out.reshape(1, keylines.len() as i32)?;
+		if self.l2_normalize {
+			for i in 0..out.rows() {
+				let mut row = out.row(i)?;
+				let src = row.try_clone()?;
+				core::normalize(&src, &mut row, 1., 0., core::NORM_L2, -1, None::<&Mat>)?;
+			}
+		}
+		out.copy_to(descriptors)
+	}
+
+	#[inline]
+	fn descriptor_size(&self) -> Result<i32> {
+		Ok(self.descriptor_size)
+	}
+
+	#[inline]
+	fn descriptor_type(&self) -> Result<i32> {
+		Ok(CV_32F)
+	}
+
+	#[inline]
+	fn default_norm(&self) -> Result<i32> {
+		Ok(core::NORM_L2)
+	}
+}
@@ -0,0 +1,130 @@
+use std::{ffi::c_void, mem::ManuallyDrop};
+
+use super::convert::{keyline_to_keypoint, keypoint_to_keyline};
+use crate::{
+	core::{self, KeyPoint, Ptr, Vector},
+	features2d::{DescriptorMatcher, Feature2D},
+	line_descriptor::{BinaryDescriptor, BinaryDescriptorMatcher, KeyLine, LSDDetector},
+	sys,
+	templ::callback_abort_on_panic,
+	traits::Boxed,
+	Error, Result,
+};
+
+extern "C" {
+	fn cv_manual_line_descriptor_BinaryDescriptor_toFeature2D(
+		descriptor: *const c_void,
+		keylines_to_keypoints: unsafe extern "C" fn(*const c_void, *mut c_void),
+		keypoints_to_keylines: unsafe extern "C" fn(*const c_void, i32, *mut c_void),
+		ocvrs_return: *mut sys::Result<*mut c_void>,
+	);
+	fn cv_manual_line_descriptor_LSDDetector_toFeature2D(
+		detector: *const c_void,
+		scale: i32,
+		num_octaves: i32,
+		keylines_to_keypoints: unsafe extern "C" fn(*const c_void, *mut c_void),
+		ocvrs_return: *mut sys::Result<*mut c_void>,
+	);
+	fn cv_manual_line_descriptor_BinaryDescriptorMatcher_toDescriptorMatcher(
+		matcher: *const c_void,
+		ocvrs_return: *mut sys::Result<*mut c_void>,
+	);
+}
+
+/// Fills the `std::vector<KeyPoint>` with the keylines from the `std::vector<KeyLine>`, called by the C++ adapters so that
+/// the encoding is only implemented by [keyline_to_keypoint]
+unsafe extern "C" fn keylines_to_keypoints_trampoline(keylines: *const c_void, keypoints: *mut c_void) {
+	callback_abort_on_panic(|| {
+		let keylines = ManuallyDrop::new(Vector::<KeyLine>::from_raw(keylines as *mut c_void));
+		let mut keypoints = ManuallyDrop::new(Vector::<KeyPoint>::from_raw(keypoints));
+		keypoints.clear();
+		keypoints.reserve(keylines.len());
+		for keyline in keylines.iter() {
+			keypoints.push(keyline_to_keypoint(&keyline));
+		}
+	})
+}
+
+/// Fills the `std::vector<KeyLine>` with the keypoints decoded by [keypoint_to_keyline]
+unsafe extern "C" fn keypoints_to_keylines_trampoline(keypoints: *const c_void, reduction_ratio: i32, keylines: *mut c_void) {
+	callback_abort_on_panic(|| {
+		let keypoints = ManuallyDrop::new(Vector::<KeyPoint>::from_raw(keypoints as *mut c_void));
+		let mut keylines = ManuallyDrop::new(Vector::<KeyLine>::from_raw(keylines));
+		keylines.clear();
+		keylines.reserve(keypoints.len());
+		for keypoint in keypoints.iter() {
+			keylines.push(keypoint_to_keyline(&keypoint, reduction_ratio));
+		}
+	})
+}
+
+/// Wraps `BinaryDescriptor` into the generic `Feature2D` interface
+///
+/// The returned detector shares the state with `descriptor` and can be used by any code written for the point features
+/// through `Feature2DTrait`. The keylines are passed as keypoints, same as [keyline_to_keypoint]:
+/// * `pt` is the midpoint of the keyline,
+/// * `size` is its length and `angle` is its direction in degrees, both in the original image coordinates,
+/// * `response`, `octave` and `class_id` are copied from the keyline.
+///
+/// The keypoints passed to `compute()` are converted back to the keylines with [keypoint_to_keyline] using the reduction
+/// ratio of the descriptor, so the keypoints produced by `detect()` are described the same as the original keylines.
+pub fn binary_descriptor_to_feature2d(descriptor: &Ptr<BinaryDescriptor>) -> Result<Ptr<Feature2D>> {
+	core::ensure_module_available("line_descriptor")?;
+	return_send!(via ocvrs_return);
+	unsafe {
+		cv_manual_line_descriptor_BinaryDescriptor_toFeature2D(
+			descriptor.as_raw(),
+			keylines_to_keypoints_trampoline,
+			keypoints_to_keylines_trampoline,
+			ocvrs_return.as_mut_ptr(),
+		)
+	};
+	return_receive!(unsafe ocvrs_return => ret);
+	ret.into_result().map(|ptr| unsafe { Ptr::from_raw(ptr) })
+}
+
+/// Wraps `LSDDetector` into the generic `Feature2D` interface
+///
+/// Only the detection is supported, `compute()` fails because `LSDDetector` has no descriptor. `scale` and `num_octaves`
+/// are passed to every `detect()` call. The keylines are encoded as keypoints the same way as in
+/// [binary_descriptor_to_feature2d].
+pub fn lsd_detector_to_feature2d(detector: &Ptr<LSDDetector>, scale: i32, num_octaves: i32) -> Result<Ptr<Feature2D>> {
+	core::ensure_module_available("line_descriptor")?;
+	if scale < 1 || num_octaves < 1 {
+		return Err(Error::new(
+			core::StsOutOfRange,
+			format!(
+				"Scale and number of octaves must be positive, but got: {} and {}",
+				scale, num_octaves
+			),
+		));
+	}
+	return_send!(via ocvrs_return);
+	unsafe {
+		cv_manual_line_descriptor_LSDDetector_toFeature2D(
+			detector.as_raw(),
+			scale,
+			num_octaves,
+			keylines_to_keypoints_trampoline,
+			ocvrs_return.as_mut_ptr(),
+		)
+	};
+	return_receive!(unsafe ocvrs_return => ret);
+	ret.into_result().map(|ptr| unsafe { Ptr::from_raw(ptr) })
+}
+
+/// Wraps `BinaryDescriptorMatcher` into the generic `DescriptorMatcher` interface
+///
+/// The returned matcher can be used by any code written for the point matchers through `DescriptorMatcherTrait`,
+/// including the variants taking the train descriptors directly and the masks. The descriptors added through the
+/// returned matcher are loaded into the MIH index of `matcher` on `train()`, replacing the descriptors it had before.
+/// The index is rebuilt only after the set of the train descriptors changes.
+pub fn binary_descriptor_matcher_to_descriptor_matcher(
+	matcher: &Ptr<BinaryDescriptorMatcher>,
+) -> Result<Ptr<dyn DescriptorMatcher>> {
+	core::ensure_module_available("line_descriptor")?;
+	return_send!(via ocvrs_return);
+	unsafe { cv_manual_line_descriptor_BinaryDescriptorMatcher_toDescriptorMatcher(matcher.as_raw(), ocvrs_return.as_mut_ptr()) };
+	return_receive!(unsafe ocvrs_return => ret);
+	ret.into_result().map(|ptr| unsafe { Ptr::from_raw(ptr) })
+}
@@ -0,0 +1,115 @@
+use std::cmp::Ordering;
+
+use crate::{
+	core::{Size, Vector},
+	line_descriptor::KeyLine,
+};
+
+/// Angular non-maximum suppression of the keylines
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LineNms {
+	/// Maximum angle in radians between the suppressed keyline and the stronger one, the keylines are treated as
+	/// undirected
+	pub max_angle_diff: f32,
+	/// Maximum distance from the middle point of the suppressed keyline to the segment of the stronger one
+	pub max_distance: f32,
+}
+
+impl Default for LineNms {
+	fn default() -> Self {
+		Self {
+			max_angle_diff: 5f32.to_radians(),
+			max_distance: 3.,
+		}
+	}
+}
+
+/// Criteria of [filter_keylines], the default value keeps all keylines
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FilterCriteria {
+	/// Minimum length of the keyline in the original image
+	pub min_length: f32,
+	/// Inclusive range of the accepted octaves
+	pub octaves: Option<(i32, i32)>,
+	/// Minimum response of the keyline
+	pub min_response: f32,
+	/// Size of the image the keylines were detected in, required by `border_margin`
+	pub image_size: Size,
+	/// Both endpoints of the keyline must be at least that far from the image border, ignored if `image_size` is empty
+	pub border_margin: f32,
+	/// Suppresses the keylines that are nearly collinear with and close to a keyline with higher response
+	pub nms: Option<LineNms>,
+	/// Maximum number of the keylines to keep, the ones with the highest response are retained
+	pub max_count: Option<usize>,
+}
+
+impl Default for FilterCriteria {
+	fn default() -> Self {
+		Self {
+			min_length: 0.,
+			octaves: None,
+			min_response: f32::NEG_INFINITY,
+			image_size: Size::default(),
+			border_margin: 0.,
+			nms: None,
+			max_count: None,
+		}
+	}
+}
+
+impl FilterCriteria {
+	/// Whether the keyline passes the per-keyline criteria, non-maximum suppression and `max_count` are not checked
+	pub fn accepts(&self, keyline: &KeyLine) -> bool {
+		keyline.length() >= self.min_length
+			&& keyline.response >= self.min_response
+			&& !matches!(self.octaves, Some((min, max)) if !(min..=max).contains(&keyline.octave))
+			&& self.inside_border(keyline)
+	}
+
+	fn inside_border(&self, keyline: &KeyLine) -> bool {
+		if self.image_size.width <= 0 || self.image_size.height <= 0 {
+			return true;
+		}
+		let (width, height) = (self.image_size.width as f32, self.image_size.height as f32);
+		let margin = self.border_margin;
+		let (start, end) = keyline.as_segment();
+		[start, end]
+			.iter()
+			.all(|p| p.x >= margin && p.y >= margin && p.x < width - margin && p.y < height - margin)
+	}
+}
+
+/// Removes the keylines that don't satisfy the criteria, the order of the remaining keylines is preserved
+///
+/// Line counterpart of `KeyPointsFilter`. The per-keyline criteria are applied first, then the non-maximum
+/// suppression and finally the `max_count` limit.
+pub fn filter_keylines(keylines: &mut Vector<KeyLine>, criteria: FilterCriteria) {
+	let candidates = keylines.iter().filter(|kl| criteria.accepts(kl)).collect::<Vec<_>>();
+	// indices of the candidates, strongest first
+	let mut by_response = (0..candidates.len()).collect::<Vec<_>>();
+	by_response.sort_by(|&a, &b| {
+		candidates[b]
+			.response
+			.partial_cmp(&candidates[a].response)
+			.unwrap_or(Ordering::Equal)
+	});
+	let mut kept = Vec::with_capacity(candidates.len());
+	for i in by_response {
+		if let Some(max_count) = criteria.max_count {
+			if kept.len() >= max_count {
+				break;
+			}
+		}
+		let candidate = &candidates[i];
+		let suppressed = matches!(criteria.nms, Some(nms) if kept.iter().any(|&k: &usize| {
+			let stronger = &candidates[k];
+			candidate.angle_between(stronger) <= nms.max_angle_diff
+				&& stronger.distance_to_point(candidate.pt) <= nms.max_distance
+		}));
+		if !suppressed {
+			kept.push(i);
+		}
+	}
+	kept.sort_unstable();
+	*keylines = kept.into_iter().map(|i| candidates[i]).collect();
+}
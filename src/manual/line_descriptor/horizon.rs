@@ -0,0 +1,287 @@
+use std::f64::consts::PI;
+
+use super::keyline::keyline_from_endpoints;
+use crate::{
+	core::{self, Mat, Point2f, Vector, CV_32F},
+	imgproc,
+	line_descriptor::{KeyLine, LSDDetector, LSDDetectorTrait},
+	prelude::*,
+	Error, Result,
+};
+
+/// Maximum tilt of the horizon relative to the image rows (30°)
+const MAX_TILT: f64 = PI / 6.;
+/// Width of the orientation histogram bin (1°)
+const ORIENTATION_BIN: f64 = PI / 180.;
+/// Maximum deviation of a keyline from the dominant orientation to be a part of the orientation cluster (3°)
+const MAX_CLUSTER_DEVIATION: f64 = 3. * PI / 180.;
+/// Lines shorter than this fraction of the image width are ignored
+const MIN_LINE_LENGTH_RATIO: f64 = 0.02;
+/// Maximum number of the longest clustered lines that are used as horizon hypotheses
+const MAX_HYPOTHESES: usize = 32;
+
+/// Horizon line found by [detect_horizon]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Horizon {
+	/// Horizon line spanning the whole image width
+	pub line: KeyLine,
+	/// Confidence of the detection in the range [0; 1]
+	pub confidence: f64,
+}
+
+/// Image gradient in the form of contiguous rows
+struct Gradient {
+	gx: Mat,
+	gy: Mat,
+	width: i32,
+	height: i32,
+	mean_magnitude: f64,
+}
+
+impl Gradient {
+	fn new(gray: &Mat) -> Result<Self> {
+		let mut gx = Mat::default();
+		let mut gy = Mat::default();
+		imgproc::sobel(gray, &mut gx, CV_32F, 1, 0, 3, 1., 0., core::BORDER_DEFAULT)?;
+		imgproc::sobel(gray, &mut gy, CV_32F, 0, 1, 3, 1., 0., core::BORDER_DEFAULT)?;
+		let sum = gx
+			.data_typed::<f32>()?
+			.iter()
+			.zip(gy.data_typed::<f32>()?)
+			.map(|(x, y)| f64::from(x.hypot(*y)))
+			.sum::<f64>();
+		let (width, height) = (gray.cols(), gray.rows());
+		Ok(Self {
+			gx,
+			gy,
+			width,
+			height,
+			mean_magnitude: sum / f64::from(width * height).max(1.),
+		})
+	}
+
+	/// Mean absolute gradient projection onto the normal of the line `y = slope·x + intercept`
+	fn along_line(&self, slope: f64, intercept: f64) -> Result<f64> {
+		let norm = slope.hypot(1.);
+		let (nx, ny) = (-slope / norm, 1. / norm);
+		let (gx, gy) = (self.gx.data_typed::<f32>()?, self.gy.data_typed::<f32>()?);
+		let (mut sum, mut count) = (0., 0usize);
+		for x in 0..self.width {
+			let y = (slope * f64::from(x) + intercept).round();
+			if y >= 0. && y < f64::from(self.height) {
+				let idx = y as usize * self.width as usize + x as usize;
+				sum += (nx * f64::from(gx[idx]) + ny * f64::from(gy[idx])).abs();
+				count += 1;
+			}
+		}
+		Ok(if count > 0 {
+			sum / count as f64
+		} else {
+			0.
+		})
+	}
+
+	/// Score in the range [0; 1) comparing the gradient along the line to the average gradient of the image
+	fn score(&self, slope: f64, intercept: f64) -> Result<f64> {
+		let along = self.along_line(slope, intercept)?;
+		let ratio = if self.mean_magnitude > 0. {
+			along / self.mean_magnitude
+		} else {
+			0.
+		};
+		Ok(ratio / (ratio + 2.))
+	}
+}
+
+/// Line segment with the precomputed orientation
+struct Segment {
+	s: [f64; 2],
+	e: [f64; 2],
+	length: f64,
+	/// Orientation in the range [-π/2; π/2)
+	angle: f64,
+}
+
+impl Segment {
+	fn new(keyline: &KeyLine) -> Self {
+		let s = [f64::from(keyline.start_point_x), f64::from(keyline.start_point_y)];
+		let e = [f64::from(keyline.end_point_x), f64::from(keyline.end_point_y)];
+		let (dx, dy) = (e[0] - s[0], e[1] - s[1]);
+		Self {
+			s,
+			e,
+			length: dx.hypot(dy),
+			angle: (dy.atan2(dx) + PI / 2.).rem_euclid(PI) - PI / 2.,
+		}
+	}
+
+	/// Distance of the point from the line `y = slope·x + intercept`
+	fn distance(p: [f64; 2], slope: f64, intercept: f64) -> f64 {
+		(slope * p[0] - p[1] + intercept).abs() / slope.hypot(1.)
+	}
+}
+
+/// Detects the horizon line in the image
+///
+/// Lines are detected with `LSDDetector` and clustered by the orientation, the dominant near-horizontal orientation
+/// cluster provides the horizon hypotheses. Each hypothesis is scored by the length of the collinear lines supporting
+/// it and by the strength of the intensity gradient across it compared to the rest of the image. When no suitable
+/// lines are found the image row with the strongest vertical gradient is returned with the lowered confidence.
+///
+/// The horizon is assumed to be tilted by less than 30° relative to the image rows.
+pub fn detect_horizon(image: &Mat) -> Result<Horizon> {
+	core::ensure_module_available("line_descriptor")?;
+	if image.empty() {
+		return Err(Error::new(core::StsBadArg, "Image is empty"));
+	}
+	let gray = if image.channels() > 1 {
+		let mut gray = Mat::default();
+		let code = if image.channels() == 4 {
+			imgproc::COLOR_BGRA2GRAY
+		} else {
+			imgproc::COLOR_BGR2GRAY
+		};
+		imgproc::cvt_color(image, &mut gray, code, 0)?;
+		gray
+	} else {
+		image.try_clone()?
+	};
+	let (width, height) = (f64::from(gray.cols()), f64::from(gray.rows()));
+	let gradient = Gradient::new(&gray)?;
+	let mut keylines = Vector::<KeyLine>::new();
+	LSDDetector::default()?.detect(&gray, &mut keylines, 2, 1, None)?;
+	let segs = keylines
+		.iter()
+		.map(|kl| Segment::new(&kl))
+		.filter(|seg| seg.length >= MIN_LINE_LENGTH_RATIO * width && seg.angle.abs() <= MAX_TILT)
+		.collect::<Vec<_>>();
+
+	let cluster = dominant_orientation_cluster(&segs);
+	let inlier_threshold = (0.005 * height).max(2.);
+	let mut best_confidence = 0.;
+	let mut best = None;
+	for &i in cluster.iter().take(MAX_HYPOTHESES) {
+		let seg = &segs[i];
+		let slope = seg.angle.tan();
+		let intercept = seg.s[1] - slope * seg.s[0];
+		let support = cluster
+			.iter()
+			.map(|&k| &segs[k])
+			.filter(|other| {
+				Segment::distance(other.s, slope, intercept) < inlier_threshold
+					&& Segment::distance(other.e, slope, intercept) < inlier_threshold
+			})
+			.map(|other| other.length)
+			.sum::<f64>();
+		let confidence = 0.5 * ((support / width).min(1.) + gradient.score(slope, intercept)?);
+		if best.is_none() || confidence > best_confidence {
+			best_confidence = confidence;
+			best = Some((slope, intercept));
+		}
+	}
+
+	let (confidence, slope, intercept) = match best {
+		Some((slope, intercept)) => {
+			let (slope, intercept) = refine(&segs, &cluster, slope, intercept, inlier_threshold);
+			let support = cluster
+				.iter()
+				.map(|&k| &segs[k])
+				.filter(|seg| {
+					Segment::distance(seg.s, slope, intercept) < inlier_threshold
+						&& Segment::distance(seg.e, slope, intercept) < inlier_threshold
+				})
+				.map(|seg| seg.length)
+				.sum::<f64>();
+			(
+				0.5 * ((support / width).min(1.) + gradient.score(slope, intercept)?),
+				slope,
+				intercept,
+			)
+		}
+		None => {
+			let mut best_row = (0., 0.);
+			for row in 0..gray.rows() {
+				let score = gradient.score(0., f64::from(row))?;
+				if score > best_row.0 {
+					best_row = (score, f64::from(row));
+				}
+			}
+			(0.5 * best_row.0, 0., best_row.1)
+		}
+	};
+	Ok(Horizon {
+		line: horizon_keyline(slope, intercept, width, confidence),
+		confidence,
+	})
+}
+
+/// Indices of the segments belonging to the dominant orientation cluster sorted by length, longest first
+fn dominant_orientation_cluster(segs: &[Segment]) -> Vec<usize> {
+	let bins = (2. * MAX_TILT / ORIENTATION_BIN).ceil() as usize + 1;
+	let bin = |angle: f64| (((angle + MAX_TILT) / ORIENTATION_BIN).round() as usize).min(bins - 1);
+	let mut histogram = vec![0.; bins];
+	for seg in segs {
+		histogram[bin(seg.angle)] += seg.length;
+	}
+	// smooth with the window of the cluster size so that the lines split between the neighbouring bins are counted
+	let radius = (MAX_CLUSTER_DEVIATION / ORIENTATION_BIN).round() as usize;
+	let smoothed = (0..bins).map(|i| {
+		histogram[i.saturating_sub(radius)..(i + radius + 1).min(bins)]
+			.iter()
+			.sum::<f64>()
+	});
+	let dominant = match smoothed
+		.enumerate()
+		.filter(|&(_, weight)| weight > 0.)
+		.max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+	{
+		Some((i, _)) => i as f64 * ORIENTATION_BIN - MAX_TILT,
+		None => return vec![],
+	};
+	let mut out = (0..segs.len())
+		.filter(|&k| (segs[k].angle - dominant).abs() <= MAX_CLUSTER_DEVIATION)
+		.collect::<Vec<_>>();
+	out.sort_unstable_by(|&a, &b| {
+		segs[b]
+			.length
+			.partial_cmp(&segs[a].length)
+			.unwrap_or(std::cmp::Ordering::Equal)
+	});
+	out
+}
+
+/// Weighted least squares refinement of the line using the endpoints of the supporting segments
+fn refine(segs: &[Segment], cluster: &[usize], slope: f64, intercept: f64, inlier_threshold: f64) -> (f64, f64) {
+	let (mut sw, mut sx, mut sy, mut sxx, mut sxy) = (0., 0., 0., 0., 0.);
+	for seg in cluster.iter().map(|&k| &segs[k]) {
+		if Segment::distance(seg.s, slope, intercept) < inlier_threshold
+			&& Segment::distance(seg.e, slope, intercept) < inlier_threshold
+		{
+			for p in [seg.s, seg.e] {
+				let w = seg.length;
+				sw += w;
+				sx += w * p[0];
+				sy += w * p[1];
+				sxx += w * p[0] * p[0];
+				sxy += w * p[0] * p[1];
+			}
+		}
+	}
+	let det = sw * sxx - sx * sx;
+	if det.abs() < f64::EPSILON {
+		return (slope, intercept);
+	}
+	let refined_slope = (sw * sxy - sx * sy) / det;
+	(refined_slope, (sy - refined_slope * sx) / sw)
+}
+
+/// Creates the keyline for the line `y = slope·x + intercept` spanning the whole image width
+fn horizon_keyline(slope: f64, intercept: f64, width: f64, response: f64) -> KeyLine {
+	let x2 = (width - 1.).max(0.);
+	let mut out = keyline_from_endpoints(
+		Point2f::new(0., intercept as f32),
+		Point2f::new(x2 as f32, (slope * x2 + intercept) as f32),
+	);
+	out.response = response as f32;
+	out
+}
@@ -0,0 +1,154 @@
+use std::time::{Duration, Instant};
+
+use crate::{
+	core::{self, DMatch, Mat, Vector},
+	line_descriptor::{
+		BinaryDescriptorMatcher, BinaryDescriptorMatcherTrait, BinaryDescriptorTraitConst, BinaryDescriptor_Params, KeyLine,
+		LSDDetector, LSDDetectorTrait,
+	},
+	prelude::*,
+	Error, Result,
+};
+
+/// Nearest neighbor search used by [match_images]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum LineMatchMode {
+	/// 2 nearest neighbors of every keyline of the first image are looked up
+	Knn,
+	/// All the neighbors within the Hamming distance are looked up, the keylines without any are left unmatched
+	Radius(f32),
+}
+
+/// Parameters of [match_images]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LineMatchParams {
+	/// Scale factor between the octaves, used both by `LSDDetector` and as the `BinaryDescriptor` reduction ratio
+	pub scale: i32,
+	/// Number of the octaves the keylines are detected in
+	pub num_octaves: i32,
+	/// Nearest neighbor search
+	pub mode: LineMatchMode,
+	/// Ratio of the distances of the best and the second best neighbor, see [core::ratio_test]
+	///
+	/// The best neighbor is kept without the test if it's the only one found.
+	pub ratio: f32,
+}
+
+impl Default for LineMatchParams {
+	fn default() -> Self {
+		Self {
+			scale: 2,
+			num_octaves: 1,
+			mode: LineMatchMode::Knn,
+			ratio: 0.8,
+		}
+	}
+}
+
+/// Wall time spent in the stages of [match_images]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct LineMatchTimings {
+	/// Detection of the keylines in both images
+	pub detection: Duration,
+	/// Computation of the descriptors in both images
+	pub description: Duration,
+	/// Building of the index, matching and filtering
+	pub matching: Duration,
+}
+
+impl LineMatchTimings {
+	/// Sum of all the stages
+	pub fn total(&self) -> Duration {
+		self.detection + self.description + self.matching
+	}
+}
+
+/// Result of [match_images]
+#[derive(Debug)]
+pub struct LineMatchResult {
+	/// Keylines of the first image, `query_idx` of the matches refers to them
+	pub keylines1: Vector<KeyLine>,
+	/// Keylines of the second image, `train_idx` of the matches refers to them
+	pub keylines2: Vector<KeyLine>,
+	/// Binary descriptors of `keylines1`, one per row
+	pub descriptors1: Mat,
+	/// Binary descriptors of `keylines2`, one per row
+	pub descriptors2: Mat,
+	/// Matches that passed the ratio test
+	pub matches: Vector<DMatch>,
+	/// Time spent in the stages of the matching
+	pub timings: LineMatchTimings,
+}
+
+/// Detects, describes and matches the lines of 2 images in a single call
+///
+/// The keylines are detected with `LSDDetector`, described with `BinaryDescriptor` and the descriptors of the first
+/// image are looked up in the MIH index of `BinaryDescriptorMatcher` built from the descriptors of the second one. The
+/// neighbors are filtered with the ratio test.
+pub fn match_images(image1: &Mat, image2: &Mat, params: &LineMatchParams) -> Result<LineMatchResult> {
+	core::ensure_module_available("line_descriptor")?;
+	if !(params.ratio > 0. && params.ratio <= 1.) {
+		return Err(Error::new(
+			core::StsOutOfRange,
+			format!("Ratio must be in the range (0, 1], but got: {}", params.ratio),
+		));
+	}
+	if let LineMatchMode::Radius(max_distance) = params.mode {
+		if !(max_distance >= 0.) {
+			return Err(Error::new(
+				core::StsOutOfRange,
+				format!("Radius of the matching must not be negative, but got: {}", max_distance),
+			));
+		}
+	}
+	let descriptor = BinaryDescriptor_Params::builder()
+		.num_octaves(params.num_octaves)
+		.reduction_ratio(params.scale)
+		.build_descriptor()?;
+
+	let start = Instant::now();
+	let mut lsd = LSDDetector::create_lsd_detector()?;
+	let mut keylines1 = Vector::new();
+	lsd.detect(image1, &mut keylines1, params.scale, params.num_octaves, None)?;
+	let mut keylines2 = Vector::new();
+	lsd.detect(image2, &mut keylines2, params.scale, params.num_octaves, None)?;
+	let detection = start.elapsed();
+
+	let start = Instant::now();
+	let mut descriptors1 = Mat::default();
+	descriptor.compute(image1, &mut keylines1, &mut descriptors1, false)?;
+	let mut descriptors2 = Mat::default();
+	descriptor.compute(image2, &mut keylines2, &mut descriptors2, false)?;
+	let description = start.elapsed();
+
+	let start = Instant::now();
+	let matches = if descriptors1.empty() || descriptors2.empty() {
+		Vector::new()
+	} else {
+		let mut matcher = BinaryDescriptorMatcher::create_binary_descriptor_matcher()?;
+		matcher.add(&Vector::from_iter([descriptors2.try_clone()?]))?;
+		matcher.train()?;
+		let mut neighbors = Vector::new();
+		match params.mode {
+			LineMatchMode::Knn => matcher.knn_match_query(&descriptors1, &mut neighbors, 2, &Vector::new(), true)?,
+			LineMatchMode::Radius(max_distance) => {
+				matcher.radius_match_1(&descriptors1, &mut neighbors, max_distance, &Vector::new(), true)?
+			}
+		}
+		core::ratio_test(&neighbors, params.ratio)?
+	};
+	let matching = start.elapsed();
+
+	Ok(LineMatchResult {
+		keylines1,
+		keylines2,
+		descriptors1,
+		descriptors2,
+		matches,
+		timings: LineMatchTimings {
+			detection,
+			description,
+			matching,
+		},
+	})
+}
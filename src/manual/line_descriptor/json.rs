@@ -0,0 +1,223 @@
+use std::{fmt::Write, iter::Peekable, str::Chars};
+
+use crate::{core, Error, Result};
+
+/// Parsed JSON document, used to load the files written by the `to_json` methods of this module
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum JsonValue {
+	Null,
+	Bool(bool),
+	Number(f64),
+	String(String),
+	Array(Vec<JsonValue>),
+	Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+	pub fn parse(src: &str) -> Result<Self> {
+		let mut parser = Parser {
+			chars: src.chars().peekable(),
+		};
+		let out = parser.value()?;
+		parser.skip_whitespace();
+		match parser.chars.next() {
+			None => Ok(out),
+			Some(c) => Err(parse_error(format!("Unexpected trailing character: {:?}", c))),
+		}
+	}
+
+	/// Value of the object field, fails if `self` is not an object or the field is missing
+	pub fn field(&self, name: &str) -> Result<&JsonValue> {
+		match self {
+			JsonValue::Object(fields) => fields
+				.iter()
+				.find(|(key, _)| key == name)
+				.map(|(_, val)| val)
+				.ok_or_else(|| parse_error(format!("Missing field: {}", name))),
+			_ => Err(parse_error(format!("Expected an object with the field: {}", name))),
+		}
+	}
+
+	pub fn as_array(&self) -> Result<&[JsonValue]> {
+		match self {
+			JsonValue::Array(items) => Ok(items),
+			_ => Err(parse_error("Expected an array")),
+		}
+	}
+
+	pub fn as_str(&self) -> Result<&str> {
+		match self {
+			JsonValue::String(s) => Ok(s),
+			_ => Err(parse_error("Expected a string")),
+		}
+	}
+
+	/// Number value, `null` is read as NaN the same way the non-finite numbers are written
+	pub fn as_f64(&self) -> Result<f64> {
+		match self {
+			JsonValue::Number(n) => Ok(*n),
+			JsonValue::Null => Ok(f64::NAN),
+			_ => Err(parse_error("Expected a number")),
+		}
+	}
+}
+
+pub(crate) fn write_json_string(out: &mut String, val: &str) {
+	out.push('"');
+	for c in val.chars() {
+		match c {
+			'"' => out.push_str("\\\""),
+			'\\' => out.push_str("\\\\"),
+			'\n' => out.push_str("\\n"),
+			'\r' => out.push_str("\\r"),
+			'\t' => out.push_str("\\t"),
+			c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+			c => out.push(c),
+		}
+	}
+	out.push('"');
+}
+
+pub(crate) fn write_json_number(out: &mut String, val: f64) {
+	if val.is_finite() {
+		write!(out, "{}", val).unwrap();
+	} else {
+		out.push_str("null");
+	}
+}
+
+fn parse_error(msg: impl Into<String>) -> Error {
+	Error::new(core::StsParseError, msg.into())
+}
+
+struct Parser<'s> {
+	chars: Peekable<Chars<'s>>,
+}
+
+impl Parser<'_> {
+	fn skip_whitespace(&mut self) {
+		while matches!(self.chars.peek(), Some(c) if c.is_ascii_whitespace()) {
+			self.chars.next();
+		}
+	}
+
+	fn expect(&mut self, expected: char) -> Result<()> {
+		self.skip_whitespace();
+		match self.chars.next() {
+			Some(c) if c == expected => Ok(()),
+			Some(c) => Err(parse_error(format!("Expected {:?}, but got: {:?}", expected, c))),
+			None => Err(parse_error(format!("Expected {:?}, but got the end of input", expected))),
+		}
+	}
+
+	fn value(&mut self) -> Result<JsonValue> {
+		self.skip_whitespace();
+		match self.chars.peek() {
+			Some('{') => self.object(),
+			Some('[') => self.array(),
+			Some('"') => self.string().map(JsonValue::String),
+			Some('t') => self.keyword("true", JsonValue::Bool(true)),
+			Some('f') => self.keyword("false", JsonValue::Bool(false)),
+			Some('n') => self.keyword("null", JsonValue::Null),
+			Some(c) if *c == '-' || c.is_ascii_digit() => self.number(),
+			Some(c) => Err(parse_error(format!("Unexpected character: {:?}", c))),
+			None => Err(parse_error("Unexpected end of input")),
+		}
+	}
+
+	fn keyword(&mut self, word: &str, val: JsonValue) -> Result<JsonValue> {
+		for expected in word.chars() {
+			if self.chars.next() != Some(expected) {
+				return Err(parse_error(format!("Invalid literal, expected: {}", word)));
+			}
+		}
+		Ok(val)
+	}
+
+	fn number(&mut self) -> Result<JsonValue> {
+		let mut s = String::new();
+		while let Some(&c) = self.chars.peek() {
+			if c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E') {
+				s.push(c);
+				self.chars.next();
+			} else {
+				break;
+			}
+		}
+		s.parse()
+			.map(JsonValue::Number)
+			.map_err(|_| parse_error(format!("Invalid number: {}", s)))
+	}
+
+	fn string(&mut self) -> Result<String> {
+		self.expect('"')?;
+		let mut out = String::new();
+		loop {
+			match self.chars.next() {
+				Some('"') => break Ok(out),
+				Some('\\') => match self.chars.next() {
+					Some('"') => out.push('"'),
+					Some('\\') => out.push('\\'),
+					Some('/') => out.push('/'),
+					Some('b') => out.push('\u{8}'),
+					Some('f') => out.push('\u{c}'),
+					Some('n') => out.push('\n'),
+					Some('r') => out.push('\r'),
+					Some('t') => out.push('\t'),
+					Some('u') => {
+						let code = (0..4).filter_map(|_| self.chars.next()).collect::<String>();
+						let c = u32::from_str_radix(&code, 16)
+							.ok()
+							.and_then(std::char::from_u32)
+							.ok_or_else(|| parse_error(format!("Invalid unicode escape: \\u{}", code)))?;
+						out.push(c);
+					}
+					c => break Err(parse_error(format!("Invalid escape sequence: {:?}", c))),
+				},
+				Some(c) => out.push(c),
+				None => break Err(parse_error("Unterminated string")),
+			}
+		}
+	}
+
+	fn array(&mut self) -> Result<JsonValue> {
+		self.expect('[')?;
+		let mut items = vec![];
+		self.skip_whitespace();
+		if self.chars.peek() == Some(&']') {
+			self.chars.next();
+			return Ok(JsonValue::Array(items));
+		}
+		loop {
+			items.push(self.value()?);
+			self.skip_whitespace();
+			match self.chars.next() {
+				Some(',') => {}
+				Some(']') => break Ok(JsonValue::Array(items)),
+				c => break Err(parse_error(format!("Expected ',' or ']' in array, but got: {:?}", c))),
+			}
+		}
+	}
+
+	fn object(&mut self) -> Result<JsonValue> {
+		self.expect('{')?;
+		let mut fields = vec![];
+		self.skip_whitespace();
+		if self.chars.peek() == Some(&'}') {
+			self.chars.next();
+			return Ok(JsonValue::Object(fields));
+		}
+		loop {
+			self.skip_whitespace();
+			let key = self.string()?;
+			self.expect(':')?;
+			fields.push((key, self.value()?));
+			self.skip_whitespace();
+			match self.chars.next() {
+				Some(',') => {}
+				Some('}') => break Ok(JsonValue::Object(fields)),
+				c => break Err(parse_error(format!("Expected ',' or '}}' in object, but got: {:?}", c))),
+			}
+		}
+	}
+}
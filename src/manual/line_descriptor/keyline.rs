@@ -0,0 +1,241 @@
+use std::{
+	f64::consts::PI,
+	hash::{Hash, Hasher},
+};
+
+use crate::{
+	core::{BitwiseKey, Point2f},
+	line_descriptor::KeyLine,
+};
+
+/// Creates the keyline in the octave 0 with the passed endpoints, `response` is left at 0
+pub(crate) fn keyline_from_endpoints(start: Point2f, end: Point2f) -> KeyLine {
+	let (dx, dy) = (end.x - start.x, end.y - start.y);
+	KeyLine {
+		angle: dy.atan2(dx),
+		class_id: 0,
+		octave: 0,
+		pt: Point2f::new((start.x + end.x) / 2., (start.y + end.y) / 2.),
+		response: 0.,
+		size: dx.abs() * dy.abs(),
+		start_point_x: start.x,
+		start_point_y: start.y,
+		end_point_x: end.x,
+		end_point_y: end.y,
+		s_point_in_octave_x: start.x,
+		s_point_in_octave_y: start.y,
+		e_point_in_octave_x: end.x,
+		e_point_in_octave_y: end.y,
+		line_length: dx.hypot(dy),
+		num_of_pixels: dx.abs().max(dy.abs()).round() as i32 + 1,
+	}
+}
+
+/// Geometry of the keyline segment in the original image coordinates
+impl KeyLine {
+	/// Start and end points of the segment
+	pub fn as_segment(&self) -> (Point2f, Point2f) {
+		(
+			Point2f::new(self.start_point_x, self.start_point_y),
+			Point2f::new(self.end_point_x, self.end_point_y),
+		)
+	}
+
+	/// Length of the segment
+	///
+	/// Unlike `line_length` it's measured in the original image and not in the octave the keyline was detected in.
+	pub fn length(&self) -> f32 {
+		let (dx, dy) = self.delta();
+		dx.hypot(dy) as f32
+	}
+
+	/// Unit vector pointing from the start to the end of the segment, `None` if the endpoints coincide
+	pub fn direction(&self) -> Option<Point2f> {
+		let (dx, dy) = self.delta();
+		let len = dx.hypot(dy);
+		if len > f64::EPSILON {
+			Some(Point2f::new((dx / len) as f32, (dy / len) as f32))
+		} else {
+			None
+		}
+	}
+
+	/// Distance from the point to the closest point of the segment
+	pub fn distance_to_point(&self, pt: Point2f) -> f32 {
+		let (dx, dy) = self.delta();
+		let (px, py) = (f64::from(pt.x - self.start_point_x), f64::from(pt.y - self.start_point_y));
+		let len_sqr = dx * dx + dy * dy;
+		let t = if len_sqr > f64::EPSILON {
+			((px * dx + py * dy) / len_sqr).clamp(0., 1.)
+		} else {
+			0.
+		};
+		(px - t * dx).hypot(py - t * dy) as f32
+	}
+
+	/// Intersection point of the infinite lines going through both segments, `None` if the lines are parallel
+	///
+	/// The point can lie outside of the segments, check it with [KeyLine::distance_to_point] if needed.
+	pub fn intersection_with(&self, other: &KeyLine) -> Option<Point2f> {
+		let (rx, ry) = self.delta();
+		let (sx, sy) = other.delta();
+		let denom = rx * sy - ry * sx;
+		if denom.abs() <= f64::EPSILON {
+			return None;
+		}
+		let (qx, qy) = (
+			f64::from(other.start_point_x - self.start_point_x),
+			f64::from(other.start_point_y - self.start_point_y),
+		);
+		let t = (qx * sy - qy * sx) / denom;
+		Some(Point2f::new(
+			(f64::from(self.start_point_x) + t * rx) as f32,
+			(f64::from(self.start_point_y) + t * ry) as f32,
+		))
+	}
+
+	/// Angle between the lines of both segments in radians, in the range [0; π/2]
+	///
+	/// The direction of the segments is ignored, so the segments with the swapped endpoints have the angle of 0.
+	pub fn angle_between(&self, other: &KeyLine) -> f32 {
+		let (ax, ay) = self.delta();
+		let (bx, by) = other.delta();
+		let diff = (ay.atan2(ax) - by.atan2(bx)).rem_euclid(PI);
+		diff.min(PI - diff) as f32
+	}
+
+	/// Scale between the octave of the keyline and the original image for the pyramid with `reduction_ratio`
+	///
+	/// `BinaryDescriptor` uses `reduction_ratio` from its parameters, `LSDDetector` the `scale` passed to `detect()`.
+	pub fn octave_scale(&self, reduction_ratio: f32) -> f32 {
+		reduction_ratio.powi(self.octave.max(0))
+	}
+
+	/// Returns the keyline with the octave coordinates recomputed from the original image coordinates
+	///
+	/// Call it after modifying the endpoints of the keyline, e.g. after undistortion, so that the `*_in_octave_*` fields,
+	/// `line_length` and `num_of_pixels` match them again. The middle point, `angle` and `size` are updated too, the
+	/// octave, response and class are kept.
+	pub fn to_octave_coords(&self, reduction_ratio: f32) -> KeyLine {
+		let scale = self.octave_scale(reduction_ratio);
+		let (start, end) = self.as_segment();
+		self.with_coords(start, end, start / scale, end / scale)
+	}
+
+	/// Returns the keyline with the original image coordinates recomputed from the octave coordinates
+	///
+	/// Counterpart of [KeyLine::to_octave_coords] for the keylines modified in the octave coordinates.
+	pub fn to_image_coords(&self, reduction_ratio: f32) -> KeyLine {
+		let scale = self.octave_scale(reduction_ratio);
+		let (start, end) = (
+			Point2f::new(self.s_point_in_octave_x, self.s_point_in_octave_y),
+			Point2f::new(self.e_point_in_octave_x, self.e_point_in_octave_y),
+		);
+		self.with_coords(start * scale, end * scale, start, end)
+	}
+
+	fn with_coords(&self, start: Point2f, end: Point2f, octave_start: Point2f, octave_end: Point2f) -> KeyLine {
+		let (octave_dx, octave_dy) = (octave_end.x - octave_start.x, octave_end.y - octave_start.y);
+		KeyLine {
+			class_id: self.class_id,
+			octave: self.octave,
+			response: self.response,
+			s_point_in_octave_x: octave_start.x,
+			s_point_in_octave_y: octave_start.y,
+			e_point_in_octave_x: octave_end.x,
+			e_point_in_octave_y: octave_end.y,
+			line_length: octave_dx.hypot(octave_dy),
+			num_of_pixels: octave_dx.abs().max(octave_dy.abs()).round() as i32 + 1,
+			..keyline_from_endpoints(start, end)
+		}
+	}
+
+	fn delta(&self) -> (f64, f64) {
+		(
+			f64::from(self.end_point_x - self.start_point_x),
+			f64::from(self.end_point_y - self.start_point_y),
+		)
+	}
+}
+
+impl BitwiseKey<KeyLine> {
+	fn bits(&self) -> ([u32; 14], i32, i32, i32) {
+		let kl = &self.0;
+		(
+			[
+				kl.angle.to_bits(),
+				kl.pt.x.to_bits(),
+				kl.pt.y.to_bits(),
+				kl.response.to_bits(),
+				kl.size.to_bits(),
+				kl.start_point_x.to_bits(),
+				kl.start_point_y.to_bits(),
+				kl.end_point_x.to_bits(),
+				kl.end_point_y.to_bits(),
+				kl.s_point_in_octave_x.to_bits(),
+				kl.s_point_in_octave_y.to_bits(),
+				kl.e_point_in_octave_x.to_bits(),
+				kl.e_point_in_octave_y.to_bits(),
+				kl.line_length.to_bits(),
+			],
+			kl.class_id,
+			kl.octave,
+			kl.num_of_pixels,
+		)
+	}
+}
+
+impl PartialEq for BitwiseKey<KeyLine> {
+	fn eq(&self, other: &Self) -> bool {
+		self.bits() == other.bits()
+	}
+}
+
+impl Eq for BitwiseKey<KeyLine> {}
+
+impl Hash for BitwiseKey<KeyLine> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.bits().hash(state);
+	}
+}
+
+#[cfg(feature = "serde")]
+mod serde_support {
+	use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+	use crate::{core::Point2f, line_descriptor::KeyLine, manual::core::serde_support::Point2fDef};
+
+	#[derive(Serialize, Deserialize)]
+	#[serde(remote = "KeyLine")]
+	struct KeyLineDef {
+		angle: f32,
+		class_id: i32,
+		octave: i32,
+		#[serde(with = "Point2fDef")]
+		pt: Point2f,
+		response: f32,
+		size: f32,
+		start_point_x: f32,
+		start_point_y: f32,
+		end_point_x: f32,
+		end_point_y: f32,
+		s_point_in_octave_x: f32,
+		s_point_in_octave_y: f32,
+		e_point_in_octave_x: f32,
+		e_point_in_octave_y: f32,
+		line_length: f32,
+		num_of_pixels: i32,
+	}
+
+	impl Serialize for KeyLine {
+		fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+			KeyLineDef::serialize(self, serializer)
+		}
+	}
+
+	impl<'de> Deserialize<'de> for KeyLine {
+		fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+			KeyLineDef::deserialize(deserializer)
+		}
+	}
+}
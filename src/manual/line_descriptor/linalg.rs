@@ -0,0 +1,151 @@
+//! Small fixed-size linear algebra helpers for the line geometry code
+
+pub type Vec3 = [f64; 3];
+pub type Mat3 = [[f64; 3]; 3];
+
+#[inline]
+pub fn cross(a: Vec3, b: Vec3) -> Vec3 {
+	[
+		a[1] * b[2] - a[2] * b[1],
+		a[2] * b[0] - a[0] * b[2],
+		a[0] * b[1] - a[1] * b[0],
+	]
+}
+
+#[inline]
+pub fn dot(a: Vec3, b: Vec3) -> f64 {
+	a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+#[inline]
+pub fn norm(a: Vec3) -> f64 {
+	dot(a, a).sqrt()
+}
+
+#[inline]
+pub fn normalize(a: Vec3) -> Vec3 {
+	let n = norm(a);
+	[a[0] / n, a[1] / n, a[2] / n]
+}
+
+#[inline]
+pub fn scale(a: Vec3, s: f64) -> Vec3 {
+	[a[0] * s, a[1] * s, a[2] * s]
+}
+
+#[inline]
+pub fn sub(a: Vec3, b: Vec3) -> Vec3 {
+	[a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+pub fn mat_mul(a: &Mat3, b: &Mat3) -> Mat3 {
+	let mut out = [[0.; 3]; 3];
+	for (i, row) in out.iter_mut().enumerate() {
+		for (j, val) in row.iter_mut().enumerate() {
+			*val = (0..3).map(|k| a[i][k] * b[k][j]).sum();
+		}
+	}
+	out
+}
+
+pub fn mat_vec_mul(a: &Mat3, v: Vec3) -> Vec3 {
+	[dot(a[0], v), dot(a[1], v), dot(a[2], v)]
+}
+
+pub fn transpose(a: &Mat3) -> Mat3 {
+	[
+		[a[0][0], a[1][0], a[2][0]],
+		[a[0][1], a[1][1], a[2][1]],
+		[a[0][2], a[1][2], a[2][2]],
+	]
+}
+
+/// Inverse of the matrix using the adjugate, `None` if the matrix is singular
+pub fn invert(a: &Mat3) -> Option<Mat3> {
+	let cofactors = [cross(a[1], a[2]), cross(a[2], a[0]), cross(a[0], a[1])];
+	let det = dot(a[0], cofactors[0]);
+	if det.abs() < 1e-12 {
+		return None;
+	}
+	let inv = transpose(&cofactors);
+	Some([
+		[inv[0][0] / det, inv[0][1] / det, inv[0][2] / det],
+		[inv[1][0] / det, inv[1][1] / det, inv[1][2] / det],
+		[inv[2][0] / det, inv[2][1] / det, inv[2][2] / det],
+	])
+}
+
+/// Eigenvector corresponding to the smallest eigenvalue of the symmetric matrix, cyclic Jacobi method
+pub fn smallest_eigenvector(m: &Mat3) -> Vec3 {
+	let mut a = *m;
+	let mut v = [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]];
+	for _ in 0..64 {
+		let (mut p, mut q) = (0, 1);
+		for &(i, j) in &[(0, 2), (1, 2)] {
+			if a[i][j].abs() > a[p][q].abs() {
+				p = i;
+				q = j;
+			}
+		}
+		if a[p][q].abs() <= f64::EPSILON * (a[p][p].abs() + a[q][q].abs()) {
+			break;
+		}
+		let theta = (a[q][q] - a[p][p]) / (2. * a[p][q]);
+		let t = theta.signum() / (theta.abs() + (theta * theta + 1.).sqrt());
+		let c = 1. / (t * t + 1.).sqrt();
+		let s = t * c;
+		for row in a.iter_mut() {
+			let (kp, kq) = (row[p], row[q]);
+			row[p] = c * kp - s * kq;
+			row[q] = s * kp + c * kq;
+		}
+		let (row_p, row_q) = (a[p], a[q]);
+		for k in 0..3 {
+			a[p][k] = c * row_p[k] - s * row_q[k];
+			a[q][k] = s * row_p[k] + c * row_q[k];
+		}
+		for row in v.iter_mut() {
+			let (kp, kq) = (row[p], row[q]);
+			row[p] = c * kp - s * kq;
+			row[q] = s * kp + c * kq;
+		}
+	}
+	let mut min = 0;
+	for i in 1..3 {
+		if a[i][i] < a[min][min] {
+			min = i;
+		}
+	}
+	[v[0][min], v[1][min], v[2][min]]
+}
+
+/// Solves the linear system `a·x = b` using Gaussian elimination with partial pivoting, `None` if `a` is singular
+pub fn solve<const N: usize>(mut a: [[f64; N]; N], mut b: [f64; N]) -> Option<[f64; N]> {
+	for col in 0..N {
+		let mut pivot = col;
+		for row in col + 1..N {
+			if a[row][col].abs() > a[pivot][col].abs() {
+				pivot = row;
+			}
+		}
+		if a[pivot][col].abs() < 1e-12 {
+			return None;
+		}
+		a.swap(col, pivot);
+		b.swap(col, pivot);
+		for row in col + 1..N {
+			let factor = a[row][col] / a[col][col];
+			let pivot_row = a[col];
+			for (val, pivot_val) in a[row].iter_mut().zip(&pivot_row).skip(col) {
+				*val -= factor * pivot_val;
+			}
+			b[row] -= factor * b[col];
+		}
+	}
+	let mut x = [0.; N];
+	for row in (0..N).rev() {
+		let sum = (row + 1..N).map(|k| a[row][k] * x[k]).sum::<f64>();
+		x[row] = (b[row] - sum) / a[row][row];
+	}
+	Some(x)
+}
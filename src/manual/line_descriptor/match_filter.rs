@@ -0,0 +1,32 @@
+use crate::{
+	core::{self, DMatch, Mat, Vector},
+	line_descriptor::BinaryDescriptorMatcherTraitConst,
+	Result,
+};
+
+/// Filtered matching of the binary descriptors
+pub trait BinaryDescriptorMatcherFilterTrait: BinaryDescriptorMatcherTraitConst {
+	/// Finds the best matches of the query descriptors among the train descriptors that pass the ratio test
+	///
+	/// The 2 nearest neighbors of every query descriptor are looked up and the best one is kept only if its distance is
+	/// less than `ratio` times the distance of the second one, see [core::ratio_test].
+	fn knn_match_ratio(&self, query_descriptors: &Mat, train_descriptors: &Mat, ratio: f32) -> Result<Vector<DMatch>> {
+		let mut knn_matches = Vector::new();
+		self.knn_match(query_descriptors, train_descriptors, &mut knn_matches, 2, None, false)?;
+		core::ratio_test(&knn_matches, ratio)
+	}
+
+	/// Finds the best matches of the query descriptors among the train descriptors that are also the best matches in the
+	/// opposite direction
+	///
+	/// Both directions are matched and only the mutual best matches are kept, see [core::cross_check].
+	fn cross_check_match(&self, query_descriptors: &Mat, train_descriptors: &Mat) -> Result<Vector<DMatch>> {
+		let mut forward = Vector::new();
+		self.match_(query_descriptors, train_descriptors, &mut forward, None)?;
+		let mut backward = Vector::new();
+		self.match_(train_descriptors, query_descriptors, &mut backward, None)?;
+		Ok(core::cross_check(&forward, &backward))
+	}
+}
+
+impl<T: BinaryDescriptorMatcherTraitConst + ?Sized> BinaryDescriptorMatcherFilterTrait for T {}
@@ -0,0 +1,254 @@
+use std::convert::TryFrom;
+use std::fmt::Write;
+
+use crate::{
+	core::{self, DMatch, Point2f, Vector},
+	line_descriptor::KeyLine,
+	Error, Result,
+};
+
+/// Image node of the [MatchGraph] with the positions of its features
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatchGraphImage {
+	pub name: String,
+	/// Feature nodes of the image, the position of the feature `i` is `features[i]`
+	pub features: Vec<Point2f>,
+}
+
+/// Correspondence between the features of two images
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MatchGraphEdge {
+	pub image1: usize,
+	pub feature1: usize,
+	pub image2: usize,
+	pub feature2: usize,
+	/// Weight of the edge, the distance of the match
+	pub distance: f32,
+}
+
+/// Correspondence graph of the matches between multiple images
+///
+/// The nodes are the features grouped by the images they belong to, the edges are the matches. The graph can be exported
+/// as JSON for the downstream tools, e.g. to build the feature tracks for structure from motion, or as GraphViz DOT for
+/// visual inspection of the multi-view matching.
+///
+/// The JSON has the following layout:
+/// ```json
+/// {
+///   "images": [{"id": 0, "name": "left", "features": [[x, y], ...]}, ...],
+///   "edges": [{"image1": 0, "feature1": 3, "image2": 1, "feature2": 5, "distance": 12}, ...]
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MatchGraph {
+	images: Vec<MatchGraphImage>,
+	edges: Vec<MatchGraphEdge>,
+}
+
+impl MatchGraph {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Adds an image with the features at the specified positions and returns its index in the graph
+	pub fn add_image(&mut self, name: impl Into<String>, features: impl IntoIterator<Item = Point2f>) -> usize {
+		self.images.push(MatchGraphImage {
+			name: name.into(),
+			features: features.into_iter().collect(),
+		});
+		self.images.len() - 1
+	}
+
+	/// Adds an image with the keylines as the features, every keyline is represented by its midpoint
+	pub fn add_keylines(&mut self, name: impl Into<String>, keylines: &Vector<KeyLine>) -> usize {
+		self.add_image(
+			name,
+			keylines.iter().map(|kl| {
+				let (start, end) = kl.as_segment();
+				(start + end) * 0.5
+			}),
+		)
+	}
+
+	/// Adds the matches of the features of `query_image` to the features of the train images
+	///
+	/// `img_idx` of every match is the index into `train_images`, the same as the index of the descriptor set added to
+	/// the matcher. For the matches between a pair of images pass a single train image, `img_idx` is ignored then.
+	/// Nothing is added if any of the matches refers to a missing image or feature.
+	pub fn add_matches(&mut self, query_image: usize, matches: &Vector<DMatch>, train_images: &[usize]) -> Result<()> {
+		let query_features = self.features_count(query_image)?;
+		let mut edges = Vec::with_capacity(matches.len());
+		for (i, m) in matches.iter().enumerate() {
+			let image2 = if train_images.len() == 1 {
+				train_images[0]
+			} else {
+				*usize::try_from(m.img_idx)
+					.ok()
+					.and_then(|img_idx| train_images.get(img_idx))
+					.ok_or_else(|| {
+						Error::new(
+							core::StsOutOfRange,
+							format!(
+								"Match {} has img_idx {} out of range of {} train images",
+								i,
+								m.img_idx,
+								train_images.len()
+							),
+						)
+					})?
+			};
+			let train_features = self.features_count(image2)?;
+			edges.push(MatchGraphEdge {
+				image1: query_image,
+				feature1: check_feature("query_idx", i, m.query_idx, query_features)?,
+				image2,
+				feature2: check_feature("train_idx", i, m.train_idx, train_features)?,
+				distance: m.distance,
+			});
+		}
+		self.edges.extend(edges);
+		Ok(())
+	}
+
+	#[inline]
+	pub fn images(&self) -> &[MatchGraphImage] {
+		&self.images
+	}
+
+	#[inline]
+	pub fn edges(&self) -> &[MatchGraphEdge] {
+		&self.edges
+	}
+
+	/// Serializes the graph to JSON, see the type documentation for the layout
+	pub fn to_json(&self) -> String {
+		let mut out = String::from("{\"images\":[");
+		for (i, image) in self.images.iter().enumerate() {
+			if i > 0 {
+				out.push(',');
+			}
+			write!(out, "{{\"id\":{},\"name\":", i).unwrap();
+			write_json_string(&mut out, &image.name);
+			out.push_str(",\"features\":[");
+			for (j, pt) in image.features.iter().enumerate() {
+				if j > 0 {
+					out.push(',');
+				}
+				out.push('[');
+				write_json_number(&mut out, pt.x);
+				out.push(',');
+				write_json_number(&mut out, pt.y);
+				out.push(']');
+			}
+			out.push_str("]}");
+		}
+		out.push_str("],\"edges\":[");
+		for (i, edge) in self.edges.iter().enumerate() {
+			if i > 0 {
+				out.push(',');
+			}
+			write!(
+				out,
+				"{{\"image1\":{},\"feature1\":{},\"image2\":{},\"feature2\":{},\"distance\":",
+				edge.image1, edge.feature1, edge.image2, edge.feature2
+			)
+			.unwrap();
+			write_json_number(&mut out, edge.distance);
+			out.push('}');
+		}
+		out.push_str("]}");
+		out
+	}
+
+	/// Serializes the graph to GraphViz DOT
+	///
+	/// Every image is a cluster of its feature nodes named `i<image>_f<feature>`, the edges are labeled with the match
+	/// distance. Only the features that have at least one match are included to keep the output readable.
+	pub fn to_dot(&self) -> String {
+		let mut matched = self
+			.images
+			.iter()
+			.map(|image| vec![false; image.features.len()])
+			.collect::<Vec<_>>();
+		for edge in &self.edges {
+			matched[edge.image1][edge.feature1] = true;
+			matched[edge.image2][edge.feature2] = true;
+		}
+		let mut out = String::from("graph matches {\n");
+		for (i, image) in self.images.iter().enumerate() {
+			writeln!(out, "\tsubgraph cluster_{} {{", i).unwrap();
+			out.push_str("\t\tlabel=");
+			write_dot_string(&mut out, &image.name);
+			out.push_str(";\n");
+			for (j, pt) in image.features.iter().enumerate().filter(|&(j, _)| matched[i][j]) {
+				writeln!(out, "\t\ti{}_f{} [label=\"{} ({:.1}, {:.1})\"];", i, j, j, pt.x, pt.y).unwrap();
+			}
+			out.push_str("\t}\n");
+		}
+		for edge in &self.edges {
+			writeln!(
+				out,
+				"\ti{}_f{} -- i{}_f{} [label=\"{}\", distance={}];",
+				edge.image1, edge.feature1, edge.image2, edge.feature2, edge.distance, edge.distance
+			)
+			.unwrap();
+		}
+		out.push_str("}\n");
+		out
+	}
+
+	fn features_count(&self, image: usize) -> Result<usize> {
+		self.images.get(image).map(|image| image.features.len()).ok_or_else(|| {
+			Error::new(
+				core::StsOutOfRange,
+				format!("Image {} is out of range of {} images in the graph", image, self.images.len()),
+			)
+		})
+	}
+}
+
+fn check_feature(name: &str, match_idx: usize, idx: i32, len: usize) -> Result<usize> {
+	match usize::try_from(idx) {
+		Ok(idx) if idx < len => Ok(idx),
+		_ => Err(Error::new(
+			core::StsOutOfRange,
+			format!("Match {} has {} {} out of range of {} features", match_idx, name, idx, len),
+		)),
+	}
+}
+
+fn write_json_number(out: &mut String, val: f32) {
+	if val.is_finite() {
+		write!(out, "{}", val).unwrap();
+	} else {
+		out.push_str("null");
+	}
+}
+
+fn write_json_string(out: &mut String, val: &str) {
+	out.push('"');
+	for c in val.chars() {
+		match c {
+			'"' => out.push_str("\\\""),
+			'\\' => out.push_str("\\\\"),
+			'\n' => out.push_str("\\n"),
+			'\r' => out.push_str("\\r"),
+			'\t' => out.push_str("\\t"),
+			c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+			c => out.push(c),
+		}
+	}
+	out.push('"');
+}
+
+fn write_dot_string(out: &mut String, val: &str) {
+	out.push('"');
+	for c in val.chars() {
+		match c {
+			'"' => out.push_str("\\\""),
+			'\n' => out.push_str("\\n"),
+			c => out.push(c),
+		}
+	}
+	out.push('"');
+}
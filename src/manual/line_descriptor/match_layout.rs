@@ -0,0 +1,335 @@
+use super::draw::{check_image, check_index};
+use crate::{
+	core::{self, DMatch, KeyPoint, Mat, Point, Point2f, Rect, Scalar, Vector, CV_8UC3},
+	imgproc::{self, DrawColor},
+	line_descriptor::KeyLine,
+	prelude::*,
+	Error, Result,
+};
+
+/// Arrangement of the two images in the output of [draw_matches_layout]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum MatchLayout {
+	/// Second image to the right of the first one, same as `draw_line_matches`
+	SideBySide,
+	/// Second image below the first one, better suited for the wide images
+	Vertical,
+	/// Both images overlaid in the same frame, the first one weighted by `alpha` and the second one by `1 - alpha`
+	///
+	/// The correspondences are drawn as the displacement vectors between the features, which makes the motion between
+	/// the frames easy to follow.
+	Blend { alpha: f64 },
+	/// Red-cyan anaglyph of a stereo pair, the first image is the left view and goes into the red channel, the second one
+	/// into the green and blue channels
+	Anaglyph,
+}
+
+/// Feature that can be drawn by [draw_matches_layout]
+pub trait MatchFeature {
+	/// Point the correspondence line is attached to, in the image coordinates
+	fn anchor(&self) -> Point2f;
+
+	/// Draws the feature into `img` shifted by `offset`
+	fn draw(&self, img: &mut Mat, offset: Point2f, color: Scalar) -> Result<()>;
+}
+
+impl MatchFeature for KeyPoint {
+	#[inline]
+	fn anchor(&self) -> Point2f {
+		self.pt
+	}
+
+	fn draw(&self, img: &mut Mat, offset: Point2f, color: Scalar) -> Result<()> {
+		let radius = (self.size / 2.).round().max(3.) as i32;
+		imgproc::circle(img, to_point(self.pt, offset), radius, color, 1, imgproc::LINE_AA, 0)
+	}
+}
+
+impl MatchFeature for KeyLine {
+	#[inline]
+	fn anchor(&self) -> Point2f {
+		let (start, end) = self.as_segment();
+		(start + end) * 0.5
+	}
+
+	fn draw(&self, img: &mut Mat, offset: Point2f, color: Scalar) -> Result<()> {
+		let (start, end) = self.as_segment();
+		imgproc::line(
+			img,
+			to_point(start, offset),
+			to_point(end, offset),
+			color,
+			2,
+			imgproc::LINE_AA,
+			0,
+		)
+	}
+}
+
+/// Draws the matches between the features of two images using the specified layout
+///
+/// Works the same for [KeyPoint] and [KeyLine] matches, the features are passed as slices, e.g. with
+/// `Vector::as_slice()`. Only the matched features are drawn, every match is connected by a line between the anchors of
+/// its features. With the palette colors every match gets its own color, so that the individual correspondences can be
+/// told apart in the dense overlays.
+///
+/// The images must be `CV_8U` with 1 or 3 channels, they don't need to have the same size or type. The output is always
+/// `CV_8UC3`.
+#[allow(clippy::too_many_arguments)]
+pub fn draw_matches_layout<F: MatchFeature>(
+	img1: &Mat,
+	features1: &[F],
+	img2: &Mat,
+	features2: &[F],
+	matches1to2: &Vector<DMatch>,
+	out_img: &mut Mat,
+	layout: MatchLayout,
+	color: DrawColor,
+) -> Result<()> {
+	check_image("First", img1)?;
+	check_image("Second", img2)?;
+	for (i, m) in matches1to2.iter().enumerate() {
+		check_index("query_idx", i, m.query_idx, features1.len(), "features")?;
+		check_index("train_idx", i, m.train_idx, features2.len(), "features")?;
+	}
+	let offset2 = compose(img1, img2, out_img, layout)?;
+	for (i, m) in matches1to2.iter().enumerate() {
+		let color = color.color(i);
+		let (f1, f2) = (&features1[m.query_idx as usize], &features2[m.train_idx as usize]);
+		f1.draw(out_img, Point2f::default(), color)?;
+		f2.draw(out_img, offset2, color)?;
+		imgproc::line(
+			out_img,
+			to_point(f1.anchor(), Point2f::default()),
+			to_point(f2.anchor(), offset2),
+			color,
+			1,
+			imgproc::LINE_AA,
+			0,
+		)?;
+	}
+	Ok(())
+}
+
+/// Options of [draw_line_matches_annotated]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MatchDrawOptions {
+	/// Arrangement of the two images
+	pub layout: MatchLayout,
+	/// Colors of the matches
+	pub color: DrawColor,
+	/// Thickness of the keylines and of the lines connecting them
+	pub thickness: i32,
+	/// Label every correspondence with the descriptor distance of the match
+	pub show_distance: bool,
+	/// Label every matched keyline with its index, `query_idx` in the first image and `train_idx` in the second one
+	pub show_indices: bool,
+	/// Font scale of the labels, relative to the base size of `FONT_HERSHEY_SIMPLEX`
+	pub font_scale: f64,
+	/// Color to draw the unmatched keylines with, they're not drawn if `None`
+	pub single_line_color: Option<Scalar>,
+}
+
+impl Default for MatchDrawOptions {
+	fn default() -> Self {
+		Self {
+			layout: MatchLayout::SideBySide,
+			color: DrawColor::default(),
+			thickness: 1,
+			show_distance: false,
+			show_indices: false,
+			font_scale: 0.4,
+			single_line_color: None,
+		}
+	}
+}
+
+/// Draws the keyline matches with the optional distance and index labels
+///
+/// Extended version of [draw_line_matches](super::draw_line_matches) for the debug output: the images can be stacked
+/// with any [MatchLayout], the thickness of the lines is configurable and the labels are placed automatically, on a dark
+/// background box and kept inside the output image. The distance label is drawn at the middle of the line connecting the
+/// matched keylines, the index labels next to the middle points of the keylines.
+///
+/// The images must be `CV_8U` with 1 or 3 channels, they don't need to have the same size or type. The output is always
+/// `CV_8UC3`.
+#[allow(clippy::too_many_arguments)]
+pub fn draw_line_matches_annotated(
+	img1: &Mat,
+	keylines1: &Vector<KeyLine>,
+	img2: &Mat,
+	keylines2: &Vector<KeyLine>,
+	matches1to2: &Vector<DMatch>,
+	out_img: &mut Mat,
+	options: &MatchDrawOptions,
+) -> Result<()> {
+	check_image("First", img1)?;
+	check_image("Second", img2)?;
+	if options.thickness <= 0 {
+		return Err(Error::new(
+			core::StsOutOfRange,
+			format!("Thickness must be positive, but got: {}", options.thickness),
+		));
+	}
+	for (i, m) in matches1to2.iter().enumerate() {
+		check_index("query_idx", i, m.query_idx, keylines1.len(), "keylines")?;
+		check_index("train_idx", i, m.train_idx, keylines2.len(), "keylines")?;
+	}
+	let offset2 = compose(img1, img2, out_img, options.layout)?;
+	let draw_keyline = |img: &mut Mat, kl: &KeyLine, offset: Point2f, color: Scalar| {
+		let (start, end) = kl.as_segment();
+		imgproc::line(
+			img,
+			to_point(start, offset),
+			to_point(end, offset),
+			color,
+			options.thickness,
+			imgproc::LINE_AA,
+			0,
+		)
+	};
+	if let Some(single_line_color) = options.single_line_color {
+		let mut matched1 = vec![false; keylines1.len()];
+		let mut matched2 = vec![false; keylines2.len()];
+		for m in matches1to2.iter() {
+			matched1[m.query_idx as usize] = true;
+			matched2[m.train_idx as usize] = true;
+		}
+		for (keylines, matched, offset) in [(keylines1, matched1, Point2f::default()), (keylines2, matched2, offset2)] {
+			for (kl, _) in keylines.iter().zip(matched).filter(|(_, matched)| !matched) {
+				draw_keyline(out_img, &kl, offset, single_line_color)?;
+			}
+		}
+	}
+	let mut labels = vec![];
+	for (i, m) in matches1to2.iter().enumerate() {
+		let color = options.color.color(i);
+		let kl1 = keylines1.get(m.query_idx as usize)?;
+		let kl2 = keylines2.get(m.train_idx as usize)?;
+		draw_keyline(out_img, &kl1, Point2f::default(), color)?;
+		draw_keyline(out_img, &kl2, offset2, color)?;
+		let (anchor1, anchor2) = (kl1.anchor(), kl2.anchor() + offset2);
+		imgproc::line(
+			out_img,
+			to_point(anchor1, Point2f::default()),
+			to_point(anchor2, Point2f::default()),
+			color,
+			options.thickness,
+			imgproc::LINE_AA,
+			0,
+		)?;
+		if options.show_indices {
+			labels.push((m.query_idx.to_string(), anchor1, color));
+			labels.push((m.train_idx.to_string(), anchor2, color));
+		}
+		if options.show_distance {
+			labels.push((format!("{:.1}", m.distance), (anchor1 + anchor2) * 0.5, color));
+		}
+	}
+	// labels go on top of all the lines to stay readable
+	for (text, at, color) in labels {
+		draw_label(out_img, &text, at, color, options.font_scale)?;
+	}
+	Ok(())
+}
+
+/// Draws the text on a dark box with the bottom left corner slightly off `at`, shifted to fit into the image
+fn draw_label(img: &mut Mat, text: &str, at: Point2f, color: Scalar, font_scale: f64) -> Result<()> {
+	const MARGIN: i32 = 2;
+	let mut baseline = 0;
+	let size = imgproc::get_text_size(text, imgproc::FONT_HERSHEY_SIMPLEX, font_scale, 1, &mut baseline)?;
+	let (box_width, box_height) = (size.width + 2 * MARGIN, size.height + baseline + 2 * MARGIN);
+	let clamp = |val: i32, max: i32| val.min(max).max(0);
+	let left = clamp(at.x.round() as i32 + MARGIN, img.cols() - box_width);
+	let top = clamp(at.y.round() as i32 - box_height - MARGIN, img.rows() - box_height);
+	imgproc::rectangle(
+		img,
+		Rect::new(left, top, box_width, box_height),
+		Scalar::all(0.),
+		imgproc::FILLED,
+		imgproc::LINE_8,
+		0,
+	)?;
+	imgproc::put_text(
+		img,
+		text,
+		Point::new(left + MARGIN, top + MARGIN + size.height),
+		imgproc::FONT_HERSHEY_SIMPLEX,
+		font_scale,
+		color,
+		1,
+		imgproc::LINE_AA,
+		false,
+	)
+}
+
+/// Composes the images into `out_img` according to `layout` and returns the offset of the second image
+fn compose(img1: &Mat, img2: &Mat, out_img: &mut Mat, layout: MatchLayout) -> Result<Point2f> {
+	let (rows, cols) = (img1.rows().max(img2.rows()), img1.cols().max(img2.cols()));
+	match layout {
+		MatchLayout::SideBySide => {
+			*out_img = Mat::new_rows_cols_with_default(rows, img1.cols() + img2.cols(), CV_8UC3, Scalar::all(0.))?;
+			paste(&to_bgr(img1)?, out_img, Point::new(0, 0))?;
+			paste(&to_bgr(img2)?, out_img, Point::new(img1.cols(), 0))?;
+			Ok(Point2f::new(img1.cols() as f32, 0.))
+		}
+		MatchLayout::Vertical => {
+			*out_img = Mat::new_rows_cols_with_default(img1.rows() + img2.rows(), cols, CV_8UC3, Scalar::all(0.))?;
+			paste(&to_bgr(img1)?, out_img, Point::new(0, 0))?;
+			paste(&to_bgr(img2)?, out_img, Point::new(0, img1.rows()))?;
+			Ok(Point2f::new(0., img1.rows() as f32))
+		}
+		MatchLayout::Blend { alpha } => {
+			if !(0. ..=1.).contains(&alpha) {
+				return Err(Error::new(
+					core::StsOutOfRange,
+					format!("Blending alpha must be between 0 and 1, but got: {}", alpha),
+				));
+			}
+			let mut canvas1 = Mat::new_rows_cols_with_default(rows, cols, CV_8UC3, Scalar::all(0.))?;
+			let mut canvas2 = Mat::new_rows_cols_with_default(rows, cols, CV_8UC3, Scalar::all(0.))?;
+			paste(&to_bgr(img1)?, &mut canvas1, Point::new(0, 0))?;
+			paste(&to_bgr(img2)?, &mut canvas2, Point::new(0, 0))?;
+			core::add_weighted(&canvas1, alpha, &canvas2, 1. - alpha, 0., out_img, -1)?;
+			Ok(Point2f::default())
+		}
+		MatchLayout::Anaglyph => {
+			let mut left = Mat::new_rows_cols_with_default(rows, cols, core::CV_8UC1, Scalar::all(0.))?;
+			let mut right = Mat::new_rows_cols_with_default(rows, cols, core::CV_8UC1, Scalar::all(0.))?;
+			paste(&to_gray(img1)?, &mut left, Point::new(0, 0))?;
+			paste(&to_gray(img2)?, &mut right, Point::new(0, 0))?;
+			let channels = Vector::<Mat>::from_iter([right.try_clone()?, right, left]);
+			core::merge(&channels, out_img)?;
+			Ok(Point2f::default())
+		}
+	}
+}
+
+fn paste(src: &Mat, dst: &mut Mat, at: Point) -> Result<()> {
+	let mut roi = Mat::roi(dst, Rect::new(at.x, at.y, src.cols(), src.rows()))?;
+	src.copy_to(&mut roi)
+}
+
+pub(super) fn to_bgr(img: &Mat) -> Result<Mat> {
+	if img.channels() == 3 {
+		img.try_clone()
+	} else {
+		let mut out = Mat::default();
+		imgproc::cvt_color(img, &mut out, imgproc::COLOR_GRAY2BGR, 0)?;
+		Ok(out)
+	}
+}
+
+fn to_gray(img: &Mat) -> Result<Mat> {
+	if img.channels() == 1 {
+		img.try_clone()
+	} else {
+		let mut out = Mat::default();
+		imgproc::cvt_color(img, &mut out, imgproc::COLOR_BGR2GRAY, 0)?;
+		Ok(out)
+	}
+}
+
+pub(super) fn to_point(pt: Point2f, offset: Point2f) -> Point {
+	Point::new((pt.x + offset.x).round() as i32, (pt.y + offset.y).round() as i32)
+}
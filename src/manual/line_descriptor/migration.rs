@@ -0,0 +1,334 @@
+use std::{collections::HashMap, fmt::Write};
+
+use super::json::{write_json_number, write_json_string, JsonValue};
+use crate::{
+	core::{self, BitwiseKey, Mat, Vector},
+	line_descriptor::{BinaryDescriptor_Params, KeyLine, LineDescriptorExtractor},
+	prelude::*,
+	Error, Result,
+};
+
+/// Version of the [DescriptorMetadata] JSON layout
+pub const DESCRIPTOR_METADATA_FORMAT: u32 = 1;
+
+/// Provenance of the stored descriptors, record it alongside the descriptor database
+///
+/// The line detection and description in OpenCV 3.4 and 4.x differ in small details, so the descriptors computed by
+/// different versions are not guaranteed to match. Compare the stored metadata with the current one using
+/// [DescriptorMetadata::compatibility] after upgrading and run [verify_descriptors] when the result is not conclusive.
+///
+/// The JSON has the following layout:
+/// ```json
+/// {
+///   "format": 1, "opencv_version": "4.5.4", "crate_version": "0.60.0", "extractor": "BinaryDescriptor",
+///   "descriptor_size": 32, "descriptor_type": 0, "norm": 6, "params": {"num_octaves": 1, ...}
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct DescriptorMetadata {
+	/// Version of OpenCV as returned by `core::get_version_string()`
+	pub opencv_version: String,
+	/// Version of this crate
+	pub crate_version: String,
+	/// Name of the extractor, e.g. "BinaryDescriptor"
+	pub extractor: String,
+	pub descriptor_size: i32,
+	/// One of `core::CV_*` depth constants
+	pub descriptor_type: i32,
+	/// One of `core::NORM_*` constants
+	pub norm: i32,
+	/// Parameters of the extractor affecting the descriptors
+	pub params: Vec<(String, f64)>,
+}
+
+/// Result of comparing the stored [DescriptorMetadata] with the current one
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DescriptorCompatibility {
+	/// Produced by the same OpenCV version with the same extractor setup
+	Compatible,
+	/// Same extractor setup, but different OpenCV version, the descriptors may have drifted, check with
+	/// [verify_descriptors]
+	NeedsVerification,
+	/// Different extractor setup, the database must be rebuilt, contains the description of the first difference
+	Incompatible(String),
+}
+
+impl DescriptorMetadata {
+	/// Metadata of the descriptors computed by `extractor` with the OpenCV version the crate is running with
+	pub fn current(extractor_name: impl Into<String>, extractor: &(impl LineDescriptorExtractor + ?Sized)) -> Result<Self> {
+		Ok(Self {
+			opencv_version: core::get_version_string()?,
+			crate_version: env!("CARGO_PKG_VERSION").to_string(),
+			extractor: extractor_name.into(),
+			descriptor_size: extractor.descriptor_size()?,
+			descriptor_type: extractor.descriptor_type()?,
+			norm: extractor.default_norm()?,
+			params: vec![],
+		})
+	}
+
+	/// Records the extractor parameter, replaces the previous value with the same name
+	pub fn with_param(mut self, name: impl Into<String>, value: f64) -> Self {
+		let name = name.into();
+		match self.params.iter_mut().find(|(n, _)| *n == name) {
+			Some((_, v)) => *v = value,
+			None => self.params.push((name, value)),
+		}
+		self
+	}
+
+	/// Records the parameters of `BinaryDescriptor`
+	pub fn with_binary_descriptor_params(self, params: &BinaryDescriptor_Params) -> Self {
+		self
+			.with_param("num_octaves", f64::from(params.num_of_octave_()))
+			.with_param("width_of_band", f64::from(params.width_of_band_()))
+			.with_param("reduction_ratio", f64::from(params.reduction_ratio()))
+			.with_param("ksize", f64::from(params.ksize_()))
+	}
+
+	/// Checks whether the descriptors described by `self` can be used together with the ones described by `current`
+	///
+	/// Only the major and minor parts of the OpenCV versions are compared, the crate version is informational.
+	pub fn compatibility(&self, current: &DescriptorMetadata) -> DescriptorCompatibility {
+		let fields = [
+			("descriptor size", self.descriptor_size, current.descriptor_size),
+			("descriptor type", self.descriptor_type, current.descriptor_type),
+			("norm", self.norm, current.norm),
+		];
+		if self.extractor != current.extractor {
+			return DescriptorCompatibility::Incompatible(format!(
+				"extractor changed from {} to {}",
+				self.extractor, current.extractor
+			));
+		}
+		if let Some((name, stored, now)) = fields.iter().find(|(_, stored, now)| stored != now) {
+			return DescriptorCompatibility::Incompatible(format!("{} changed from {} to {}", name, stored, now));
+		}
+		let mut names = self
+			.params
+			.iter()
+			.chain(&current.params)
+			.map(|(name, _)| name)
+			.collect::<Vec<_>>();
+		names.sort();
+		names.dedup();
+		for name in names {
+			let (stored, now) = (self.param(name), current.param(name));
+			if stored != now {
+				let show = |v: Option<f64>| v.map_or_else(|| "unset".to_string(), |v| v.to_string());
+				return DescriptorCompatibility::Incompatible(format!(
+					"parameter {} changed from {} to {}",
+					name,
+					show(stored),
+					show(now)
+				));
+			}
+		}
+		if major_minor(&self.opencv_version) == major_minor(&current.opencv_version) {
+			DescriptorCompatibility::Compatible
+		} else {
+			DescriptorCompatibility::NeedsVerification
+		}
+	}
+
+	/// Value of the recorded extractor parameter
+	pub fn param(&self, name: &str) -> Option<f64> {
+		self.params.iter().find(|(n, _)| n == name).map(|&(_, v)| v)
+	}
+
+	/// Serializes the metadata to JSON, see the type documentation for the layout
+	pub fn to_json(&self) -> String {
+		let mut out = format!("{{\"format\":{},\"opencv_version\":", DESCRIPTOR_METADATA_FORMAT);
+		write_json_string(&mut out, &self.opencv_version);
+		out.push_str(",\"crate_version\":");
+		write_json_string(&mut out, &self.crate_version);
+		out.push_str(",\"extractor\":");
+		write_json_string(&mut out, &self.extractor);
+		write!(
+			out,
+			",\"descriptor_size\":{},\"descriptor_type\":{},\"norm\":{},\"params\":{{",
+			self.descriptor_size, self.descriptor_type, self.norm
+		)
+		.unwrap();
+		for (i, (name, val)) in self.params.iter().enumerate() {
+			if i > 0 {
+				out.push(',');
+			}
+			write_json_string(&mut out, name);
+			out.push(':');
+			write_json_number(&mut out, *val);
+		}
+		out.push_str("}}");
+		out
+	}
+
+	/// Parses the metadata serialized by [DescriptorMetadata::to_json], fails for the newer format versions
+	pub fn from_json(json: &str) -> Result<Self> {
+		let root = JsonValue::parse(json)?;
+		let format = root.field("format")?.as_f64()?;
+		if format > f64::from(DESCRIPTOR_METADATA_FORMAT) {
+			return Err(Error::new(
+				core::StsUnsupportedFormat,
+				format!(
+					"Descriptor metadata format {} is newer than the supported one: {}",
+					format, DESCRIPTOR_METADATA_FORMAT
+				),
+			));
+		}
+		let int = |name: &str| -> Result<i32> { Ok(root.field(name)?.as_f64()? as i32) };
+		let params = match root.field("params")? {
+			JsonValue::Object(fields) => fields
+				.iter()
+				.map(|(name, val)| Ok((name.clone(), val.as_f64()?)))
+				.collect::<Result<Vec<_>>>()?,
+			_ => return Err(Error::new(core::StsParseError, "Expected an object in the field: params")),
+		};
+		Ok(Self {
+			opencv_version: root.field("opencv_version")?.as_str()?.to_string(),
+			crate_version: root.field("crate_version")?.as_str()?.to_string(),
+			extractor: root.field("extractor")?.as_str()?.to_string(),
+			descriptor_size: int("descriptor_size")?,
+			descriptor_type: int("descriptor_type")?,
+			norm: int("norm")?,
+			params,
+		})
+	}
+}
+
+/// Reference image with the keylines and their stored descriptors, used by [verify_descriptors]
+///
+/// Keep a few representative samples together with the descriptor database, they are much cheaper to check than the
+/// whole database.
+pub struct DescriptorSample {
+	pub image: Mat,
+	pub keylines: Vector<KeyLine>,
+	/// Stored descriptors, one row per keyline
+	pub descriptors: Mat,
+}
+
+impl DescriptorSample {
+	/// Computes the descriptors of the keylines with `extractor` and stores them as the reference
+	///
+	/// The keylines the extractor can't describe are dropped from the sample.
+	pub fn capture(
+		extractor: &mut (impl LineDescriptorExtractor + ?Sized),
+		image: Mat,
+		mut keylines: Vector<KeyLine>,
+	) -> Result<Self> {
+		let mut descriptors = Mat::default();
+		extractor.compute(&image, &mut keylines, &mut descriptors)?;
+		Ok(Self {
+			image,
+			keylines,
+			descriptors,
+		})
+	}
+}
+
+/// Differences between the stored and the recomputed descriptors found by [verify_descriptors]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct DescriptorDrift {
+	/// Number of the descriptors compared
+	pub compared: usize,
+	/// Number of the stored keylines the current extractor could not describe
+	pub dropped: usize,
+	/// Number of the descriptors that are not identical
+	pub changed: usize,
+	/// Mean distance between the stored and the recomputed descriptors
+	pub mean_distance: f64,
+	/// Largest distance between the stored and the recomputed descriptors
+	pub max_distance: f64,
+}
+
+impl DescriptorDrift {
+	/// Whether the recomputed descriptors are exactly the same as the stored ones
+	pub fn is_identical(&self) -> bool {
+		self.dropped == 0 && self.changed == 0
+	}
+
+	/// Whether the drift is large enough for the database to be rebuilt
+	///
+	/// The database must be rebuilt if any keyline was dropped or the mean distance exceeds `max_mean_distance`. For
+	/// the 256-bit binary descriptors the distances up to a few bits are within the noise of matching.
+	pub fn needs_rebuild(&self, max_mean_distance: f64) -> bool {
+		self.dropped > 0 || self.mean_distance > max_mean_distance
+	}
+}
+
+/// Recomputes the descriptors of the samples with the current extractor and reports how they differ from the stored ones
+///
+/// The descriptors are compared with the default norm of the extractor. Fails if the size or the type of the
+/// descriptors changed, the database must be rebuilt in that case.
+pub fn verify_descriptors(
+	extractor: &mut (impl LineDescriptorExtractor + ?Sized),
+	samples: &[DescriptorSample],
+) -> Result<DescriptorDrift> {
+	let norm = extractor.default_norm()?;
+	let mut out = DescriptorDrift::default();
+	let mut total_distance = 0.;
+	for (i, sample) in samples.iter().enumerate() {
+		if sample.descriptors.rows() != sample.keylines.len() as i32 {
+			return Err(Error::new(
+				core::StsUnmatchedSizes,
+				format!(
+					"Sample {} has {} keylines, but {} stored descriptors",
+					i,
+					sample.keylines.len(),
+					sample.descriptors.rows()
+				),
+			));
+		}
+		let mut keylines = sample.keylines.clone();
+		let mut descriptors = Mat::default();
+		extractor.compute(&sample.image, &mut keylines, &mut descriptors)?;
+		if !keylines.is_empty()
+			&& (descriptors.cols() != sample.descriptors.cols() || descriptors.typ() != sample.descriptors.typ())
+		{
+			return Err(Error::new(
+				core::StsUnmatchedFormats,
+				format!(
+					"Descriptors of sample {} changed the format from {} columns of type {} to {} columns of type {}",
+					i,
+					sample.descriptors.cols(),
+					core::type_to_string(sample.descriptors.typ())?,
+					descriptors.cols(),
+					core::type_to_string(descriptors.typ())?
+				),
+			));
+		}
+		let stored_rows = sample
+			.keylines
+			.iter()
+			.enumerate()
+			.map(|(row, kl)| (BitwiseKey(kl), row as i32))
+			.collect::<HashMap<_, _>>();
+		let mut matched = 0;
+		for (row, kl) in keylines.iter().enumerate() {
+			if let Some(&stored_row) = stored_rows.get(&BitwiseKey(kl)) {
+				let distance = core::norm2(
+					&sample.descriptors.row(stored_row)?,
+					&descriptors.row(row as i32)?,
+					norm,
+					None::<&Mat>,
+				)?;
+				matched += 1;
+				total_distance += distance;
+				out.max_distance = out.max_distance.max(distance);
+				if distance > 0. {
+					out.changed += 1;
+				}
+			}
+		}
+		out.compared += matched;
+		out.dropped += sample.keylines.len() - matched;
+	}
+	if out.compared > 0 {
+		out.mean_distance = total_distance / out.compared as f64;
+	}
+	Ok(out)
+}
+
+fn major_minor(version: &str) -> (&str, &str) {
+	let mut parts = version.split('.');
+	(parts.next().unwrap_or(""), parts.next().unwrap_or(""))
+}
@@ -0,0 +1,298 @@
+use crate::{
+	core::{self, DMatch, Mat, Ptr, Scalar, Vector, CV_32F, CV_8UC1},
+	line_descriptor::{rerank, BinaryDescriptorMatcher, BinaryDescriptorMatcherTrait},
+	prelude::*,
+	Error, Result,
+};
+
+/// Number of the bits in the descriptors accepted by [BinaryDescriptorMatcher]
+const MATCHER_BITS: usize = 256;
+
+/// Conversion of the float descriptors into the binary codes accepted by [BinaryDescriptorMatcher]
+///
+/// A bit of the code is set when the corresponding element of the (optionally centered and projected) descriptor is
+/// above the threshold. The codes shorter than 256 bits are padded with zeros, which doesn't affect the Hamming
+/// distance between them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DescriptorBinarizer {
+	/// Subtracted from the descriptors before the projection, empty for no centering
+	mean: Vec<f32>,
+	/// Row-major `dimensions x bits` projection matrix, empty for no projection
+	projection: Vec<f32>,
+	bits: usize,
+	threshold: f32,
+}
+
+impl DescriptorBinarizer {
+	/// Binarizer that thresholds every descriptor element directly, produces one bit per element
+	pub fn threshold(threshold: f32) -> Self {
+		Self {
+			mean: vec![],
+			projection: vec![],
+			bits: 0,
+			threshold,
+		}
+	}
+
+	/// Trains the Iterative Quantization (ITQ) binarizer on the sample descriptors
+	///
+	/// The descriptors are centered, projected onto the `bits` principal components and rotated by the rotation that
+	/// minimizes the quantization error, see Gong and Lazebnik, "Iterative Quantization: A Procrustean Approach to
+	/// Learning Binary Codes".
+	///
+	/// ## Parameters
+	/// * samples: float descriptors, one per row, there must be at least `bits` of them
+	/// * bits: length of the codes, at most the descriptor length and 256
+	/// * iterations: number of the rotation refinement iterations, 50 is usually enough
+	pub fn itq(samples: &Mat, bits: i32, iterations: usize) -> Result<Self> {
+		let samples = to_f32(samples)?;
+		if bits <= 0 || bits > samples.cols() || bits as usize > MATCHER_BITS || samples.rows() < bits {
+			return Err(Error::new(
+				core::StsBadArg,
+				format!(
+					"Can't train {} bit ITQ binarizer on {} samples with {} elements",
+					bits,
+					samples.rows(),
+					samples.cols()
+				),
+			));
+		}
+		let mut mean = Mat::default();
+		let mut components = Mat::default();
+		core::pca_compute(&samples, &mut mean, &mut components, bits)?;
+		let mean_rows = core::repeat(&mean, samples.rows(), 1)?;
+		let mut centered = Mat::default();
+		core::subtract(&samples, &mean_rows, &mut centered, None::<&Mat>, -1)?;
+		let mut projected = Mat::default();
+		core::gemm(
+			&centered,
+			&components,
+			1.,
+			&Mat::default(),
+			0.,
+			&mut projected,
+			core::GEMM_2_T,
+		)?;
+		let mut rotation = Mat::eye(bits, bits, CV_32F)?.to_mat()?;
+		let mut rotated = Mat::default();
+		let mut codes = Mat::default();
+		let mut correlation = Mat::default();
+		let (mut w, mut u, mut vt) = (Mat::default(), Mat::default(), Mat::default());
+		for _ in 0..iterations {
+			core::gemm(&projected, &rotation, 1., &Mat::default(), 0., &mut rotated, 0)?;
+			rotated.copy_to(&mut codes)?;
+			for val in codes.data_typed_mut::<f32>()? {
+				*val = if *val > 0. {
+					1.
+				} else {
+					-1.
+				};
+			}
+			core::gemm(&projected, &codes, 1., &Mat::default(), 0., &mut correlation, core::GEMM_1_T)?;
+			core::sv_decomp(&correlation, &mut w, &mut u, &mut vt, 0)?;
+			core::gemm(&u, &vt, 1., &Mat::default(), 0., &mut rotation, 0)?;
+		}
+		let mut projection = Mat::default();
+		core::gemm(
+			&components,
+			&rotation,
+			1.,
+			&Mat::default(),
+			0.,
+			&mut projection,
+			core::GEMM_1_T,
+		)?;
+		Ok(Self {
+			mean: mean.data_typed::<f32>()?.to_vec(),
+			projection: projection.data_typed::<f32>()?.to_vec(),
+			bits: bits as usize,
+			threshold: 0.,
+		})
+	}
+
+	/// Number of the meaningful bits in the produced codes, 0 if it equals to the descriptor length
+	pub fn bits(&self) -> usize {
+		self.bits
+	}
+
+	/// Converts the float descriptors, one per row, into the 32 byte binary codes
+	pub fn binarize(&self, descriptors: &Mat) -> Result<Mat> {
+		if descriptors.empty() {
+			return Ok(Mat::default());
+		}
+		let descriptors = to_f32(descriptors)?;
+		let dims = descriptors.cols() as usize;
+		if !self.mean.is_empty() && self.mean.len() != dims {
+			return Err(Error::new(
+				core::StsUnmatchedSizes,
+				format!(
+					"Binarizer expects descriptors with {} elements, but got: {}",
+					self.mean.len(),
+					dims
+				),
+			));
+		}
+		let bits = if self.projection.is_empty() {
+			dims
+		} else {
+			self.bits
+		};
+		if bits > MATCHER_BITS {
+			return Err(Error::new(
+				core::StsOutOfRange,
+				format!("Binary codes can have at most {} bits, but got: {}", MATCHER_BITS, bits),
+			));
+		}
+		let mut out = Mat::new_rows_cols_with_default(descriptors.rows(), (MATCHER_BITS / 8) as i32, CV_8UC1, Scalar::default())?;
+		let mut centered = vec![0.; dims];
+		for row in 0..descriptors.rows() {
+			let src = descriptors.at_row::<f32>(row)?;
+			for (i, dst) in centered.iter_mut().enumerate() {
+				*dst = src[i] - self.mean.get(i).copied().unwrap_or(0.);
+			}
+			let code = out.at_row_mut::<u8>(row)?;
+			for bit in 0..bits {
+				let val = if self.projection.is_empty() {
+					centered[bit]
+				} else {
+					centered
+						.iter()
+						.enumerate()
+						.map(|(i, x)| x * self.projection[i * self.bits + bit])
+						.sum()
+				};
+				if val > self.threshold {
+					code[bit / 8] |= 1 << (bit % 8);
+				}
+			}
+		}
+		Ok(out)
+	}
+}
+
+/// [BinaryDescriptorMatcher] that stores the float descriptors and re-ranks the binary matches by them
+///
+/// The float descriptors added with [MixedPrecisionMatcher::add] are binarized lazily, right before the next query.
+/// The queries are binarized the same way, the multi-index hashing retrieves `rerank_candidates` nearest neighbors by
+/// the Hamming distance and they are re-ranked by the L2 distance between the float descriptors, which is also the
+/// `distance` of the returned matches. The query methods mirror the ones of [BinaryDescriptorMatcher].
+pub struct MixedPrecisionMatcher {
+	matcher: Ptr<BinaryDescriptorMatcher>,
+	binarizer: DescriptorBinarizer,
+	train: Vec<Mat>,
+	inserted: usize,
+	/// Number of the binary candidates retrieved for re-ranking, at least `k` candidates are always retrieved
+	pub rerank_candidates: i32,
+}
+
+impl MixedPrecisionMatcher {
+	pub fn new(binarizer: DescriptorBinarizer) -> Result<Self> {
+		Ok(Self {
+			matcher: BinaryDescriptorMatcher::create_binary_descriptor_matcher()?,
+			binarizer,
+			train: vec![],
+			inserted: 0,
+			rerank_candidates: 20,
+		})
+	}
+
+	pub fn binarizer(&self) -> &DescriptorBinarizer {
+		&self.binarizer
+	}
+
+	/// Adds the float descriptors of an image, the index of the call is the `img_idx` of the matches
+	pub fn add(&mut self, descriptors: &Mat) -> Result<()> {
+		let descriptors = to_f32(descriptors)?;
+		if let Some(first) = self.train.first() {
+			if first.cols() != descriptors.cols() {
+				return Err(Error::new(
+					core::StsUnmatchedSizes,
+					format!(
+						"Descriptors must have {} elements like the previously added ones, but got: {}",
+						first.cols(),
+						descriptors.cols()
+					),
+				));
+			}
+		}
+		self.train.push(descriptors);
+		Ok(())
+	}
+
+	/// Float copies of the added descriptors
+	pub fn train_descriptors(&self) -> &[Mat] {
+		&self.train
+	}
+
+	/// Removes all the added descriptors
+	pub fn clear(&mut self) -> Result<()> {
+		BinaryDescriptorMatcherTrait::clear(&mut self.matcher)?;
+		self.train.clear();
+		self.inserted = 0;
+		Ok(())
+	}
+
+	/// For every query descriptor retrieves the best matching one from the added descriptors
+	pub fn match_query(&mut self, query_descriptors: &Mat, matches: &mut Vector<DMatch>, masks: &Vector<Mat>) -> Result<()> {
+		let mut knn = Vector::new();
+		self.knn_match_query(query_descriptors, &mut knn, 1, masks, true)?;
+		matches.clear();
+		for query_matches in knn {
+			if let Some(best) = query_matches.iter().next() {
+				matches.push(best);
+			}
+		}
+		Ok(())
+	}
+
+	/// For every query descriptor retrieves the best `k` matching ones from the added descriptors
+	pub fn knn_match_query(
+		&mut self,
+		query_descriptors: &Mat,
+		matches: &mut Vector<Vector<DMatch>>,
+		k: i32,
+		masks: &Vector<Mat>,
+		compact_result: bool,
+	) -> Result<()> {
+		matches.clear();
+		if query_descriptors.empty() {
+			return Ok(());
+		}
+		self.flush()?;
+		let query = to_f32(query_descriptors)?;
+		let codes = self.binarizer.binarize(&query)?;
+		let mut candidates = Vector::new();
+		self
+			.matcher
+			.knn_match_query(&codes, &mut candidates, k.max(self.rerank_candidates), masks, compact_result)?;
+		*matches = rerank(&candidates, &query, &self.train, core::NORM_L2, k.max(0) as usize, |_| true)?;
+		Ok(())
+	}
+
+	/// Inserts the binary codes of the descriptors added since the last query into the matcher
+	fn flush(&mut self) -> Result<()> {
+		if self.inserted == self.train.len() {
+			return Ok(());
+		}
+		let codes = self.train[self.inserted..]
+			.iter()
+			.map(|descriptors| self.binarizer.binarize(descriptors))
+			.collect::<Result<Vector<Mat>>>()?;
+		self.matcher.add(&codes)?;
+		self.matcher.train()?;
+		self.inserted = self.train.len();
+		Ok(())
+	}
+}
+
+fn to_f32(descriptors: &Mat) -> Result<Mat> {
+	if descriptors.channels() != 1 {
+		return Err(Error::new(
+			core::StsBadArg,
+			format!("Descriptors must be single-channel, but got: {}", descriptors.channels()),
+		));
+	}
+	let mut out = Mat::default();
+	descriptors.convert_to(&mut out, CV_32F, 1., 0.)?;
+	Ok(out)
+}
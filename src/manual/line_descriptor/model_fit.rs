@@ -0,0 +1,1022 @@
+use std::{convert::TryFrom, f64::consts::PI};
+
+use super::{
+	linalg::{invert, mat_mul, mat_vec_mul, solve, Mat3},
+	vanishing_point::detect_vanishing_points,
+};
+use crate::{
+	core::{self, DMatch, Mat, Point2d, Size},
+	line_descriptor::KeyLine,
+	Error, Result,
+};
+
+/// Minimum number of keylines that must support a lane model
+const MIN_LANE_SUPPORT: usize = 2;
+/// Keylines closer to horizontal than this (sine of the angle to the horizontal axis) are not considered lane parts
+const MIN_LANE_SLOPE_SIN: f64 = 0.25;
+/// Maximum number of merged image lines per direction family used to generate court hypotheses
+const MAX_COURT_CANDIDATES: usize = 6;
+
+/// Parameters of the robust estimation shared by the model fitting functions
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ModelFitParams {
+	/// Maximum distance in pixels between a keyline endpoint and the model for the keyline to count as an inlier
+	pub inlier_threshold: f64,
+	/// Maximum angle in radians between a keyline and the model direction for the keyline to count as an inlier
+	pub angle_threshold: f64,
+	/// Number of random hypotheses to evaluate
+	pub iterations: usize,
+	/// Seed for the hypothesis sampling, the results are reproducible for the same seed
+	pub seed: u64,
+}
+
+impl Default for ModelFitParams {
+	fn default() -> Self {
+		Self {
+			inlier_threshold: 3.,
+			angle_threshold: 5f64.to_radians(),
+			iterations: 1000,
+			seed: 0x853c_49e6_748f_ea9b,
+		}
+	}
+}
+
+/// Pair of parallel lines, e.g. both borders of a painted line or a corridor
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParallelLinePair {
+	/// Unit normal shared by both lines
+	pub normal: Point2d,
+	/// Lines are defined as `normal · p = offset`, the offsets are sorted in ascending order
+	pub offsets: [f64; 2],
+	/// Indices of the keylines supporting each of the lines
+	pub inliers: [Vec<usize>; 2],
+}
+
+impl ParallelLinePair {
+	/// Direction of the lines in radians in the range [0; π)
+	pub fn angle(&self) -> f64 {
+		(-self.normal.x).atan2(self.normal.y).rem_euclid(PI)
+	}
+
+	/// Distance between the lines
+	pub fn width(&self) -> f64 {
+		self.offsets[1] - self.offsets[0]
+	}
+}
+
+/// Lane boundary described by the cubic approximation of a clothoid
+///
+/// The image x coordinate is a function of the image y coordinate: `x(y) = c0 + c1·y + c2·y² + c3·y³`. For the
+/// clothoid `c1` corresponds to the heading, `c2` to the half of the curvature and `c3` to the sixth of the curvature
+/// change rate at `y = 0`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LaneModel {
+	/// Polynomial coefficients `[c0, c1, c2, c3]`
+	pub coeffs: [f64; 4],
+	/// Indices of the keylines supporting the model
+	pub inliers: Vec<usize>,
+}
+
+impl LaneModel {
+	/// Image x coordinate of the lane at the image row `y`
+	pub fn x_at(&self, y: f64) -> f64 {
+		let [c0, c1, c2, c3] = self.coeffs;
+		c0 + y * (c1 + y * (c2 + y * c3))
+	}
+
+	/// Angle in radians between the lane and the vertical image axis at the image row `y`
+	pub fn heading_at(&self, y: f64) -> f64 {
+		self.slope_at(y).atan()
+	}
+
+	/// Signed curvature of the lane in 1/pixels at the image row `y`
+	pub fn curvature_at(&self, y: f64) -> f64 {
+		let [_, _, c2, c3] = self.coeffs;
+		let d1 = self.slope_at(y);
+		(2. * c2 + 6. * c3 * y) / (1. + d1 * d1).powf(1.5)
+	}
+
+	fn slope_at(&self, y: f64) -> f64 {
+		let [_, c1, c2, c3] = self.coeffs;
+		c1 + y * (2. * c2 + y * 3. * c3)
+	}
+}
+
+/// Planar line layout of a sports court or a field in its own metric coordinates
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourtTemplate {
+	/// Line segments of the template, every segment must be parallel to one of the coordinate axes
+	pub lines: Vec<[Point2d; 2]>,
+}
+
+impl CourtTemplate {
+	pub fn new(lines: Vec<[Point2d; 2]>) -> Self {
+		Self { lines }
+	}
+
+	/// Tennis court in meters, x axis goes along the baseline, y axis along the sideline, origin is in the corner of the
+	/// doubles court
+	pub fn tennis() -> Self {
+		let (length, width, alley, service) = (23.77, 10.97, 1.37, 6.40);
+		let (net, center) = (length / 2., width / 2.);
+		let seg = |x1, y1, x2, y2| [Point2d::new(x1, y1), Point2d::new(x2, y2)];
+		Self::new(vec![
+			seg(0., 0., width, 0.),
+			seg(0., length, width, length),
+			seg(0., 0., 0., length),
+			seg(width, 0., width, length),
+			seg(alley, 0., alley, length),
+			seg(width - alley, 0., width - alley, length),
+			seg(alley, net - service, width - alley, net - service),
+			seg(alley, net + service, width - alley, net + service),
+			seg(center, net - service, center, net + service),
+		])
+	}
+}
+
+/// Court template fitted to the image
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourtModel {
+	/// Homography mapping the template coordinates to the image coordinates
+	pub homography: [[f64; 3]; 3],
+	/// Indices of the keylines supporting each of the template lines, in the order of `CourtTemplate::lines`
+	pub inliers: Vec<Vec<usize>>,
+	/// Total length of the keylines supporting the model
+	pub score: f64,
+}
+
+impl CourtModel {
+	/// Maps the point in the template coordinates to the image, `None` if the point lies on the horizon
+	pub fn project(&self, p: Point2d) -> Option<Point2d> {
+		project(&self.homography, [p.x, p.y]).map(|[x, y]| Point2d::new(x, y))
+	}
+}
+
+/// Finds the pair of parallel lines with the largest keyline support using RANSAC
+///
+/// ## Parameters
+/// * keylines: detected lines
+/// * min_separation: minimum distance between the lines of the pair in pixels
+/// * params: robust estimation parameters
+pub fn fit_parallel_line_pair(keylines: &[KeyLine], min_separation: f64, params: &ModelFitParams) -> Option<ParallelLinePair> {
+	let segs = keylines.iter().map(Segment::new).collect::<Vec<_>>();
+	if segs.len() < 2 {
+		return None;
+	}
+	let mut rng = Rng::new(params.seed);
+	let mut best_score = 0.;
+	let mut best = None;
+	for _ in 0..params.iterations {
+		let (i, j) = (rng.next_index(segs.len()), rng.next_index(segs.len()));
+		if i == j || angle_diff(segs[i].angle, segs[j].angle) > params.angle_threshold {
+			continue;
+		}
+		let angle = mean_angle([&segs[i], &segs[j]].iter().copied());
+		let normal = [-angle.sin(), angle.cos()];
+		let offsets = sorted_offsets(dot2(normal, segs[i].mid()), dot2(normal, segs[j].mid()));
+		if offsets[1] - offsets[0] < min_separation {
+			continue;
+		}
+		let inliers = parallel_support(&segs, angle, offsets, params);
+		if inliers.iter().any(|inl| inl.is_empty()) {
+			continue;
+		}
+		let score = inliers.iter().flatten().map(|&k| segs[k].length).sum::<f64>();
+		if score > best_score {
+			best_score = score;
+			best = Some((angle, offsets, inliers));
+		}
+	}
+	let (mut angle, mut offsets, mut inliers) = best?;
+	// refine using all inliers
+	let refined_angle = mean_angle(inliers.iter().flatten().map(|&k| &segs[k]));
+	let normal = [-refined_angle.sin(), refined_angle.cos()];
+	let mean_offset = |inl: &[usize]| {
+		let (sum, weight) = inl.iter().fold((0., 0.), |(sum, weight), &k| {
+			(sum + segs[k].length * dot2(normal, segs[k].mid()), weight + segs[k].length)
+		});
+		sum / weight
+	};
+	let refined_offsets = sorted_offsets(mean_offset(&inliers[0]), mean_offset(&inliers[1]));
+	let refined_inliers = parallel_support(&segs, refined_angle, refined_offsets, params);
+	if refined_inliers.iter().all(|inl| !inl.is_empty())
+		&& refined_inliers.iter().map(Vec::len).sum::<usize>() >= inliers.iter().map(Vec::len).sum::<usize>()
+	{
+		angle = refined_angle;
+		offsets = refined_offsets;
+		inliers = refined_inliers;
+	}
+	Some(ParallelLinePair {
+		normal: Point2d::new(-angle.sin(), angle.cos()),
+		offsets,
+		inliers,
+	})
+}
+
+/// Fits up to `max_count` lane boundaries to the keylines
+///
+/// Lanes are found one after another using RANSAC, the inliers of each found lane are excluded from the search for
+/// the next ones. Keylines that are close to horizontal are ignored. The returned models are sorted by their support,
+/// strongest first.
+///
+/// ## Parameters
+/// * keylines: detected lines
+/// * max_count: maximum number of lanes to return
+/// * params: robust estimation parameters
+pub fn fit_lanes(keylines: &[KeyLine], max_count: usize, params: &ModelFitParams) -> Vec<LaneModel> {
+	let segs = keylines.iter().map(Segment::new).collect::<Vec<_>>();
+	// rows are normalized to roughly [-1; 1] for the numerical stability of the polynomial fitting
+	let y_scale = segs
+		.iter()
+		.flat_map(|seg| [seg.s[1].abs(), seg.e[1].abs()])
+		.fold(1., f64::max);
+	let mut remaining = (0..segs.len())
+		.filter(|&k| (segs[k].e[1] - segs[k].s[1]).abs() >= MIN_LANE_SLOPE_SIN * segs[k].length)
+		.collect::<Vec<_>>();
+	let mut rng = Rng::new(params.seed);
+	let mut out = Vec::with_capacity(max_count);
+	while out.len() < max_count && remaining.len() >= MIN_LANE_SUPPORT {
+		let mut best_score = 0.;
+		let mut best = None;
+		for _ in 0..params.iterations {
+			let (i, j) = (
+				remaining[rng.next_index(remaining.len())],
+				remaining[rng.next_index(remaining.len())],
+			);
+			if i == j {
+				continue;
+			}
+			let coeffs = match fit_cubic([&segs[i], &segs[j]].iter().copied(), y_scale) {
+				Some(coeffs) => coeffs,
+				None => continue,
+			};
+			let inliers = lane_support(&segs, &remaining, coeffs, y_scale, params);
+			let score = inliers.iter().map(|&k| segs[k].length).sum::<f64>();
+			if inliers.len() >= MIN_LANE_SUPPORT && score > best_score {
+				best_score = score;
+				best = Some((coeffs, inliers));
+			}
+		}
+		let (mut coeffs, mut inliers) = match best {
+			Some(best) => best,
+			None => break,
+		};
+		if let Some(refined) = fit_cubic(inliers.iter().map(|&k| &segs[k]), y_scale) {
+			let refined_inliers = lane_support(&segs, &remaining, refined, y_scale, params);
+			if refined_inliers.len() >= inliers.len() {
+				coeffs = refined;
+				inliers = refined_inliers;
+			}
+		}
+		remaining.retain(|k| !inliers.contains(k));
+		out.push(LaneModel {
+			coeffs: [
+				coeffs[0],
+				coeffs[1] / y_scale,
+				coeffs[2] / y_scale.powi(2),
+				coeffs[3] / y_scale.powi(3),
+			],
+			inliers,
+		});
+	}
+	out
+}
+
+/// Fits the court template to the keylines
+///
+/// The keylines are split into 2 families by their vanishing points, the families are matched to the 2 axis-parallel
+/// families of the template lines. Homography hypotheses are generated from all combinations of 2 lines from each
+/// family and scored by the total length of the image lines coinciding with the projected template lines. The best
+/// hypothesis is refined by least squares using the intersections of all supported template lines.
+///
+/// ## Parameters
+/// * keylines: detected lines
+/// * image_size: size of the image the lines were detected in
+/// * template: court layout to fit
+/// * params: robust estimation parameters, `iterations` and `seed` are not used because the search is exhaustive
+pub fn fit_court(
+	keylines: &[KeyLine],
+	image_size: Size,
+	template: &CourtTemplate,
+	params: &ModelFitParams,
+) -> Option<CourtModel> {
+	let segs = keylines.iter().map(Segment::new).collect::<Vec<_>>();
+	let template_lines = template
+		.lines
+		.iter()
+		.map(|&[a, b]| Line::through([a.x, a.y], [b.x, b.y]))
+		.collect::<Option<Vec<_>>>()?;
+	let (mut tmpl_h, mut tmpl_v) = (vec![], vec![]);
+	for line in &template_lines {
+		// the normal of a horizontal line is vertical
+		let family = if line.n[1].abs() > line.n[0].abs() {
+			&mut tmpl_h
+		} else {
+			&mut tmpl_v
+		};
+		if !family.iter().any(|other: &Line| other.coincides(line, 1e-9)) {
+			family.push(*line);
+		}
+	}
+	let vps = detect_vanishing_points(keylines, image_size, 2);
+	if vps.len() < 2 || tmpl_h.len() < 2 || tmpl_v.len() < 2 {
+		return None;
+	}
+	let families = [
+		merge_lines(&segs, &vps[0].inliers, params),
+		merge_lines(&segs, &vps[1].inliers, params),
+	];
+	let candidates = families
+		.iter()
+		.map(|f| f.iter().map(|c| (c.line, c.length)).collect::<Vec<_>>())
+		.collect::<Vec<_>>();
+	let mut best_score = 0.;
+	let mut best = None;
+	for &(fh, fv) in &[(0, 1), (1, 0)] {
+		for (img_h1, img_h2) in pairs(&families[fh]) {
+			for (img_v1, img_v2) in pairs(&families[fv]) {
+				for (t_h1, t_h2) in pairs(&tmpl_h) {
+					for (t_v1, t_v2) in pairs(&tmpl_v) {
+						for &(swap_h, swap_v) in &[(false, false), (false, true), (true, false), (true, true)] {
+							let (img_h1, img_h2) = if swap_h {
+								(img_h2, img_h1)
+							} else {
+								(img_h1, img_h2)
+							};
+							let (img_v1, img_v2) = if swap_v {
+								(img_v2, img_v1)
+							} else {
+								(img_v1, img_v2)
+							};
+							let mut correspondences = Vec::with_capacity(4);
+							for &(t_h, img_h) in &[(t_h1, img_h1), (t_h2, img_h2)] {
+								for &(t_v, img_v) in &[(t_v1, img_v1), (t_v2, img_v2)] {
+									if let (Some(src), Some(dst)) = (t_h.intersection(t_v), img_h.line.intersection(&img_v.line)) {
+										correspondences.push((src, dst));
+									}
+								}
+							}
+							let h = match homography_from_points(&correspondences) {
+								Some(h) if correspondences.len() == 4 && keeps_in_front(&h, template) => h,
+								_ => continue,
+							};
+							let score = coarse_court_score(&h, template, &candidates, params);
+							if score > best_score {
+								best_score = score;
+								best = Some(h);
+							}
+						}
+					}
+				}
+			}
+		}
+	}
+	let mut h = best?;
+	let mut inliers = court_support(&h, template, &segs, params);
+	// refine using intersections of the lines fitted to the inliers of each template line
+	let fitted = inliers
+		.iter()
+		.map(|inl| Line::fit(inl.iter().map(|&k| &segs[k])))
+		.collect::<Vec<_>>();
+	let mut correspondences = vec![];
+	for (i, (t_a, img_a)) in template_lines.iter().zip(&fitted).enumerate() {
+		for (t_b, img_b) in template_lines.iter().zip(&fitted).skip(i + 1) {
+			if let (Some(img_a), Some(img_b)) = (img_a, img_b) {
+				if let (Some(src), Some(dst)) = (t_a.intersection(t_b), img_a.intersection(img_b)) {
+					if !correspondences
+						.iter()
+						.any(|&(other, _): &([f64; 2], _)| dist2(other, src) < 1e-9)
+					{
+						correspondences.push((src, dst));
+					}
+				}
+			}
+		}
+	}
+	if let Some(refined) = homography_from_points(&correspondences) {
+		if keeps_in_front(&refined, template) {
+			let refined_inliers = court_support(&refined, template, &segs, params);
+			if support_length(&refined_inliers, &segs) >= support_length(&inliers, &segs) {
+				h = refined;
+				inliers = refined_inliers;
+			}
+		}
+	}
+	let score = support_length(&inliers, &segs);
+	Some(CourtModel {
+		homography: h,
+		inliers,
+		score,
+	})
+}
+
+/// Estimation method for [estimate_homography_from_lines]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum LineHomographyMethod {
+	/// Least squares fit to all of the matches, every match with a non-degenerate keyline is reported as an inlier
+	LeastSquares,
+	/// RANSAC over the samples of 4 matches followed by the least squares refinement on the inliers, `angle_threshold` is
+	/// not used
+	Ransac(ModelFitParams),
+}
+
+/// Homography estimated from the line matches
+#[derive(Clone, Debug, PartialEq)]
+pub struct LineHomography {
+	/// Homography mapping the query image coordinates to the train image coordinates
+	pub homography: [[f64; 3]; 3],
+	/// Inlier mask in the order of the matches
+	pub inliers: Vec<bool>,
+}
+
+impl LineHomography {
+	/// Maps the point in the query image to the train image, `None` if the point lies on the horizon
+	pub fn project(&self, p: Point2d) -> Option<Point2d> {
+		project(&self.homography, [p.x, p.y]).map(|[x, y]| Point2d::new(x, y))
+	}
+
+	pub fn inlier_count(&self) -> usize {
+		self.inliers.iter().filter(|&&inlier| inlier).count()
+	}
+
+	/// Homography as a 3x3 `CV_64F` matrix, e.g. for `imgproc::warp_perspective()`
+	pub fn to_mat(&self) -> Result<Mat> {
+		Mat::from_slice_2d(&self.homography)
+	}
+}
+
+/// Estimates the homography between the images from the keyline matches
+///
+/// Every match constrains the homography to map both endpoints of the query keyline onto the infinite line through the
+/// train keyline and vice versa, so the matched segments don't need to have the corresponding endpoints, which is
+/// rarely the case for the lines detected in the different views. The error of the match is the maximum distance in
+/// pixels between the projected endpoints and the matched line in either image.
+///
+/// ## Parameters
+/// * query: keylines of the first image, indexed by `DMatch::query_idx`
+/// * train: keylines of the second image, indexed by `DMatch::train_idx`
+/// * matches: keyline matches, e.g. from `BinaryDescriptorMatcher::match_()`
+/// * method: estimation method
+///
+/// Returns `None` if there are less than 4 usable matches or the lines are in a degenerate configuration, e.g. all
+/// parallel.
+pub fn estimate_homography_from_lines(
+	query: &[KeyLine],
+	train: &[KeyLine],
+	matches: &[DMatch],
+	method: LineHomographyMethod,
+) -> Result<Option<LineHomography>> {
+	let mut correspondences = Vec::with_capacity(matches.len());
+	for m in matches {
+		let keyline = |keylines: &[KeyLine], idx: i32, name: &str| {
+			usize::try_from(idx)
+				.ok()
+				.and_then(|idx| keylines.get(idx))
+				.map(Segment::new)
+				.ok_or_else(|| {
+					Error::new(
+						core::StsOutOfRange,
+						format!("Match {} index: {} is out of range 0..{}", name, idx, keylines.len()),
+					)
+				})
+		};
+		let (src, dst) = (keyline(query, m.query_idx, "query")?, keyline(train, m.train_idx, "train")?);
+		correspondences.push(match (Line::through(src.s, src.e), Line::through(dst.s, dst.e)) {
+			(Some(src_line), Some(dst_line)) => Some(LineCorrespondence {
+				src: [src.s, src.e],
+				src_line,
+				dst: [dst.s, dst.e],
+				dst_line,
+			}),
+			_ => None,
+		});
+	}
+	let valid = correspondences
+		.iter()
+		.enumerate()
+		.filter_map(|(i, corr)| corr.as_ref().map(|corr| (i, corr)))
+		.collect::<Vec<_>>();
+	if valid.len() < 4 {
+		return Ok(None);
+	}
+	let all = valid.iter().map(|&(_, corr)| corr).collect::<Vec<_>>();
+	let params = match method {
+		LineHomographyMethod::LeastSquares => {
+			return Ok(homography_from_lines(&all).map(|homography| LineHomography {
+				homography,
+				inliers: correspondences.iter().map(Option::is_some).collect(),
+			}))
+		}
+		LineHomographyMethod::Ransac(params) => params,
+	};
+	let inliers_of = |h: &Mat3| -> Vec<usize> {
+		let h_inv = match invert(h) {
+			Some(h_inv) => h_inv,
+			None => return vec![],
+		};
+		valid
+			.iter()
+			.filter(|(_, corr)| corr.error(h, &h_inv) < params.inlier_threshold)
+			.map(|&(i, _)| i)
+			.collect()
+	};
+	let mut rng = Rng::new(params.seed);
+	let mut best: Option<(Mat3, Vec<usize>)> = None;
+	for _ in 0..params.iterations {
+		let mut sample = [0; 4];
+		for i in 0..sample.len() {
+			sample[i] = loop {
+				let idx = rng.next_index(valid.len());
+				if !sample[..i].contains(&idx) {
+					break idx;
+				}
+			};
+		}
+		let h = match homography_from_lines(&sample.iter().map(|&i| valid[i].1).collect::<Vec<_>>()) {
+			Some(h) => h,
+			None => continue,
+		};
+		let inliers = inliers_of(&h);
+		let is_better = match &best {
+			Some((_, best)) => inliers.len() > best.len(),
+			None => true,
+		};
+		if inliers.len() >= 4 && is_better {
+			best = Some((h, inliers));
+		}
+	}
+	let (mut h, mut inliers) = match best {
+		Some(best) => best,
+		None => return Ok(None),
+	};
+	let refined = homography_from_lines(
+		&inliers
+			.iter()
+			.filter_map(|&i| correspondences[i].as_ref())
+			.collect::<Vec<_>>(),
+	);
+	if let Some(refined) = refined {
+		let refined_inliers = inliers_of(&refined);
+		if refined_inliers.len() >= inliers.len() {
+			h = refined;
+			inliers = refined_inliers;
+		}
+	}
+	let mut mask = vec![false; matches.len()];
+	for i in inliers {
+		mask[i] = true;
+	}
+	Ok(Some(LineHomography {
+		homography: h,
+		inliers: mask,
+	}))
+}
+
+/// xorshift64* generator, enough for sampling hypotheses while keeping the results reproducible
+struct Rng(u64);
+
+impl Rng {
+	fn new(seed: u64) -> Self {
+		Self(seed.max(1))
+	}
+
+	fn next_index(&mut self, n: usize) -> usize {
+		self.0 ^= self.0 >> 12;
+		self.0 ^= self.0 << 25;
+		self.0 ^= self.0 >> 27;
+		(self.0.wrapping_mul(0x2545_f491_4f6c_dd1d) >> 32) as usize % n
+	}
+}
+
+/// Line segment in the image coordinates
+struct Segment {
+	s: [f64; 2],
+	e: [f64; 2],
+	length: f64,
+	/// Direction in radians in the range [0; π)
+	angle: f64,
+}
+
+impl Segment {
+	fn new(keyline: &KeyLine) -> Self {
+		let s = [f64::from(keyline.start_point_x), f64::from(keyline.start_point_y)];
+		let e = [f64::from(keyline.end_point_x), f64::from(keyline.end_point_y)];
+		Self {
+			s,
+			e,
+			length: dist2(s, e).sqrt(),
+			angle: (e[1] - s[1]).atan2(e[0] - s[0]).rem_euclid(PI),
+		}
+	}
+
+	fn mid(&self) -> [f64; 2] {
+		[(self.s[0] + self.e[0]) / 2., (self.s[1] + self.e[1]) / 2.]
+	}
+}
+
+/// Infinite line `n · p = c` with the unit normal `n`
+#[derive(Copy, Clone)]
+struct Line {
+	n: [f64; 2],
+	c: f64,
+}
+
+impl Line {
+	fn through(a: [f64; 2], b: [f64; 2]) -> Option<Self> {
+		let len = dist2(a, b).sqrt();
+		if len < f64::EPSILON {
+			return None;
+		}
+		let n = [(a[1] - b[1]) / len, (b[0] - a[0]) / len];
+		Some(Self { n, c: dot2(n, a) })
+	}
+
+	/// Total least squares fit to the segment endpoints weighted by the segment lengths
+	fn fit<'s>(segs: impl Iterator<Item = &'s Segment> + Clone) -> Option<Self> {
+		let (mut weight, mut cx, mut cy) = (0., 0., 0.);
+		for seg in segs.clone() {
+			weight += seg.length;
+			cx += seg.length * (seg.s[0] + seg.e[0]) / 2.;
+			cy += seg.length * (seg.s[1] + seg.e[1]) / 2.;
+		}
+		if weight <= 0. {
+			return None;
+		}
+		let (cx, cy) = (cx / weight, cy / weight);
+		let (mut sxx, mut sxy, mut syy) = (0., 0., 0.);
+		for seg in segs {
+			for p in [seg.s, seg.e] {
+				let (dx, dy) = (p[0] - cx, p[1] - cy);
+				sxx += seg.length * dx * dx;
+				sxy += seg.length * dx * dy;
+				syy += seg.length * dy * dy;
+			}
+		}
+		let angle = (2. * sxy).atan2(sxx - syy) / 2.;
+		let n = [-angle.sin(), angle.cos()];
+		Some(Self { n, c: dot2(n, [cx, cy]) })
+	}
+
+	fn distance(&self, p: [f64; 2]) -> f64 {
+		(dot2(self.n, p) - self.c).abs()
+	}
+
+	fn coincides(&self, other: &Line, eps: f64) -> bool {
+		let det = self.n[0] * other.n[1] - self.n[1] * other.n[0];
+		let sign = dot2(self.n, other.n).signum();
+		det.abs() < eps && (self.c - sign * other.c).abs() < eps
+	}
+
+	fn intersection(&self, other: &Line) -> Option<[f64; 2]> {
+		let det = self.n[0] * other.n[1] - self.n[1] * other.n[0];
+		if det.abs() < 1e-12 {
+			return None;
+		}
+		Some([
+			(self.c * other.n[1] - other.c * self.n[1]) / det,
+			(self.n[0] * other.c - other.n[0] * self.c) / det,
+		])
+	}
+}
+
+/// Matched segments of the query and train images
+struct LineCorrespondence {
+	src: [[f64; 2]; 2],
+	src_line: Line,
+	dst: [[f64; 2]; 2],
+	dst_line: Line,
+}
+
+impl LineCorrespondence {
+	/// Symmetric transfer error, the maximum distance between the projected endpoints and the matched line
+	fn error(&self, h: &Mat3, h_inv: &Mat3) -> f64 {
+		let forward = self.src.iter().map(|&p| match project(h, p) {
+			Some(p) => self.dst_line.distance(p),
+			None => f64::INFINITY,
+		});
+		let backward = self.dst.iter().map(|&p| match project(h_inv, p) {
+			Some(p) => self.src_line.distance(p),
+			None => f64::INFINITY,
+		});
+		forward.chain(backward).fold(0., f64::max)
+	}
+}
+
+/// Collinear keylines merged into a single line
+struct MergedLine {
+	line: Line,
+	length: f64,
+}
+
+#[inline]
+fn dot2(a: [f64; 2], b: [f64; 2]) -> f64 {
+	a[0] * b[0] + a[1] * b[1]
+}
+
+#[inline]
+fn dist2(a: [f64; 2], b: [f64; 2]) -> f64 {
+	(a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2)
+}
+
+/// Absolute difference between 2 line directions taking the π periodicity into account
+fn angle_diff(a: f64, b: f64) -> f64 {
+	let d = (a - b).rem_euclid(PI);
+	d.min(PI - d)
+}
+
+/// Length-weighted mean direction of the segments in the range [0; π)
+fn mean_angle<'s>(segs: impl Iterator<Item = &'s Segment>) -> f64 {
+	let (sin, cos) = segs.fold((0., 0.), |(sin, cos), seg| {
+		(
+			sin + seg.length * (2. * seg.angle).sin(),
+			cos + seg.length * (2. * seg.angle).cos(),
+		)
+	});
+	(sin.atan2(cos) / 2.).rem_euclid(PI)
+}
+
+fn sorted_offsets(a: f64, b: f64) -> [f64; 2] {
+	if a <= b {
+		[a, b]
+	} else {
+		[b, a]
+	}
+}
+
+fn pairs<T>(items: &[T]) -> impl Iterator<Item = (&T, &T)> {
+	items
+		.iter()
+		.enumerate()
+		.flat_map(move |(i, a)| items[i + 1..].iter().map(move |b| (a, b)))
+}
+
+fn parallel_support(segs: &[Segment], angle: f64, offsets: [f64; 2], params: &ModelFitParams) -> [Vec<usize>; 2] {
+	let normal = [-angle.sin(), angle.cos()];
+	let mut out = [vec![], vec![]];
+	for (k, seg) in segs.iter().enumerate() {
+		if angle_diff(seg.angle, angle) > params.angle_threshold {
+			continue;
+		}
+		let (ds, de) = (dot2(normal, seg.s), dot2(normal, seg.e));
+		if let Some(line) = offsets
+			.iter()
+			.position(|&off| (ds - off).abs() < params.inlier_threshold && (de - off).abs() < params.inlier_threshold)
+		{
+			out[line].push(k);
+		}
+	}
+	out
+}
+
+/// Least squares fit of `x(t) = a0 + a1·t + a2·t² + a3·t³` with `t = y / y_scale` to the endpoints and midpoints of the
+/// segments
+fn fit_cubic<'s>(segs: impl Iterator<Item = &'s Segment>, y_scale: f64) -> Option<[f64; 4]> {
+	let mut ata = [[0.; 4]; 4];
+	let mut atb = [0.; 4];
+	for seg in segs {
+		for p in [seg.s, seg.mid(), seg.e] {
+			let t = p[1] / y_scale;
+			let row = [1., t, t * t, t * t * t];
+			for i in 0..4 {
+				for j in 0..4 {
+					ata[i][j] += seg.length * row[i] * row[j];
+				}
+				atb[i] += seg.length * row[i] * p[0];
+			}
+		}
+	}
+	solve(ata, atb)
+}
+
+fn lane_support(segs: &[Segment], candidates: &[usize], coeffs: [f64; 4], y_scale: f64, params: &ModelFitParams) -> Vec<usize> {
+	let x_at = |y: f64| {
+		let t = y / y_scale;
+		coeffs[0] + t * (coeffs[1] + t * (coeffs[2] + t * coeffs[3]))
+	};
+	candidates
+		.iter()
+		.copied()
+		.filter(|&k| {
+			[segs[k].s, segs[k].mid(), segs[k].e]
+				.iter()
+				.all(|p| (x_at(p[1]) - p[0]).abs() < params.inlier_threshold)
+		})
+		.collect()
+}
+
+/// Groups the collinear keylines from `indices`, returns the strongest merged lines
+fn merge_lines(segs: &[Segment], indices: &[usize], params: &ModelFitParams) -> Vec<MergedLine> {
+	let mut sorted = indices.to_vec();
+	sorted.sort_unstable_by(|&a, &b| {
+		segs[b]
+			.length
+			.partial_cmp(&segs[a].length)
+			.unwrap_or(std::cmp::Ordering::Equal)
+	});
+	let mut clusters: Vec<(Line, Vec<usize>)> = vec![];
+	for k in sorted {
+		let seg = &segs[k];
+		let cluster = clusters.iter_mut().find(|(line, _)| {
+			line.distance(seg.s) < 2. * params.inlier_threshold && line.distance(seg.e) < 2. * params.inlier_threshold
+		});
+		match cluster {
+			Some((_, members)) => members.push(k),
+			None => {
+				if let Some(line) = Line::through(seg.s, seg.e) {
+					clusters.push((line, vec![k]));
+				}
+			}
+		}
+	}
+	let mut out = clusters
+		.into_iter()
+		.filter_map(|(_, members)| {
+			Line::fit(members.iter().map(|&k| &segs[k])).map(|line| MergedLine {
+				line,
+				length: members.iter().map(|&k| segs[k].length).sum(),
+			})
+		})
+		.collect::<Vec<_>>();
+	out.sort_unstable_by(|a, b| b.length.partial_cmp(&a.length).unwrap_or(std::cmp::Ordering::Equal));
+	out.truncate(MAX_COURT_CANDIDATES);
+	out
+}
+
+fn project(h: &Mat3, p: [f64; 2]) -> Option<[f64; 2]> {
+	let [x, y, w] = mat_vec_mul(h, [p[0], p[1], 1.]);
+	if w.abs() < f64::EPSILON {
+		None
+	} else {
+		Some([x / w, y / w])
+	}
+}
+
+/// Checks that all template lines are projected in front of the camera
+fn keeps_in_front(h: &Mat3, template: &CourtTemplate) -> bool {
+	template
+		.lines
+		.iter()
+		.flatten()
+		.all(|p| mat_vec_mul(h, [p.x, p.y, 1.])[2] > f64::EPSILON)
+}
+
+/// Normalizing similarity transform moving the centroid of the points to the origin with the mean distance of √2
+fn normalizing_transform<'p>(points: impl Iterator<Item = &'p [f64; 2]> + Clone) -> Mat3 {
+	let count = points.clone().count() as f64;
+	let (cx, cy) = points
+		.clone()
+		.fold((0., 0.), |(cx, cy), p| (cx + p[0] / count, cy + p[1] / count));
+	let mean_dist = points.map(|p| dist2(*p, [cx, cy]).sqrt()).sum::<f64>() / count;
+	let s = if mean_dist > 0. {
+		2f64.sqrt() / mean_dist
+	} else {
+		1.
+	};
+	[[s, 0., -s * cx], [0., s, -s * cy], [0., 0., 1.]]
+}
+
+fn inverse_similarity(t: &Mat3) -> Mat3 {
+	let s = t[0][0];
+	[[1. / s, 0., -t[0][2] / s], [0., 1. / s, -t[1][2] / s], [0., 0., 1.]]
+}
+
+/// Least squares homography estimation from at least 4 point correspondences with `h33 = 1`
+fn homography_from_points(correspondences: &[([f64; 2], [f64; 2])]) -> Option<Mat3> {
+	if correspondences.len() < 4 {
+		return None;
+	}
+	let src_t = normalizing_transform(correspondences.iter().map(|(src, _)| src));
+	let dst_t = normalizing_transform(correspondences.iter().map(|(_, dst)| dst));
+	let mut ata = [[0.; 8]; 8];
+	let mut atb = [0.; 8];
+	for &(src, dst) in correspondences {
+		let [x, y, _] = mat_vec_mul(&src_t, [src[0], src[1], 1.]);
+		let [u, v, _] = mat_vec_mul(&dst_t, [dst[0], dst[1], 1.]);
+		for &(row, rhs) in &[
+			([x, y, 1., 0., 0., 0., -u * x, -u * y], u),
+			([0., 0., 0., x, y, 1., -v * x, -v * y], v),
+		] {
+			for i in 0..8 {
+				for j in 0..8 {
+					ata[i][j] += row[i] * row[j];
+				}
+				atb[i] += row[i] * rhs;
+			}
+		}
+	}
+	let h = solve(ata, atb)?;
+	let h = [[h[0], h[1], h[2]], [h[3], h[4], h[5]], [h[6], h[7], 1.]];
+	let h = mat_mul(&mat_mul(&inverse_similarity(&dst_t), &h), &src_t);
+	if h[2][2].abs() < f64::EPSILON || h.iter().flatten().any(|v| !v.is_finite()) {
+		return None;
+	}
+	let scale = h[2][2];
+	Some([
+		[h[0][0] / scale, h[0][1] / scale, h[0][2] / scale],
+		[h[1][0] / scale, h[1][1] / scale, h[1][2] / scale],
+		[h[2][0] / scale, h[2][1] / scale, 1.],
+	])
+}
+
+/// Least squares homography estimation from at least 4 line correspondences with `h33 = 1`
+///
+/// Each correspondence contributes two equations `l'ᵀ·H·p = 0`, one for each endpoint `p` of the source segment, where `l'`
+/// is the destination line.
+fn homography_from_lines(correspondences: &[&LineCorrespondence]) -> Option<Mat3> {
+	if correspondences.len() < 4 {
+		return None;
+	}
+	let src_t = normalizing_transform(correspondences.iter().flat_map(|corr| corr.src.iter()));
+	let dst_t = normalizing_transform(correspondences.iter().flat_map(|corr| corr.dst.iter()));
+	let normalize = |t: &Mat3, p: [f64; 2]| {
+		let [x, y, _] = mat_vec_mul(t, [p[0], p[1], 1.]);
+		[x, y]
+	};
+	let mut ata = [[0.; 8]; 8];
+	let mut atb = [0.; 8];
+	for corr in correspondences {
+		let dst_line = Line::through(normalize(&dst_t, corr.dst[0]), normalize(&dst_t, corr.dst[1]))?;
+		let [a, b, c] = [dst_line.n[0], dst_line.n[1], -dst_line.c];
+		for &p in &corr.src {
+			let [x, y] = normalize(&src_t, p);
+			let row = [a * x, a * y, a, b * x, b * y, b, c * x, c * y];
+			for i in 0..8 {
+				for j in 0..8 {
+					ata[i][j] += row[i] * row[j];
+				}
+				atb[i] -= row[i] * c;
+			}
+		}
+	}
+	let h = solve(ata, atb)?;
+	let h = [[h[0], h[1], h[2]], [h[3], h[4], h[5]], [h[6], h[7], 1.]];
+	let h = mat_mul(&mat_mul(&inverse_similarity(&dst_t), &h), &src_t);
+	if h[2][2].abs() < f64::EPSILON || h.iter().flatten().any(|v| !v.is_finite()) {
+		return None;
+	}
+	let scale = h[2][2];
+	Some([
+		[h[0][0] / scale, h[0][1] / scale, h[0][2] / scale],
+		[h[1][0] / scale, h[1][1] / scale, h[1][2] / scale],
+		[h[2][0] / scale, h[2][1] / scale, 1.],
+	])
+}
+
+/// Projects the template segment into the image, returns its endpoints
+fn project_segment(h: &Mat3, [a, b]: &[Point2d; 2]) -> Option<([f64; 2], [f64; 2])> {
+	Some((project(h, [a.x, a.y])?, project(h, [b.x, b.y])?))
+}
+
+/// Fast hypothesis score using the merged image lines
+fn coarse_court_score(h: &Mat3, template: &CourtTemplate, candidates: &[Vec<(Line, f64)>], params: &ModelFitParams) -> f64 {
+	let mut score = 0.;
+	for seg in &template.lines {
+		let (a, b) = match project_segment(h, seg) {
+			Some(seg) => seg,
+			None => continue,
+		};
+		let projected_length = dist2(a, b).sqrt();
+		for &(line, length) in candidates.iter().flatten() {
+			if line.distance(a) < params.inlier_threshold && line.distance(b) < params.inlier_threshold {
+				score += length.min(projected_length);
+			}
+		}
+	}
+	score
+}
+
+/// Keylines lying on the projected template segments
+fn court_support(h: &Mat3, template: &CourtTemplate, segs: &[Segment], params: &ModelFitParams) -> Vec<Vec<usize>> {
+	template
+		.lines
+		.iter()
+		.map(|seg| {
+			let (a, b) = match project_segment(h, seg) {
+				Some(seg) => seg,
+				None => return vec![],
+			};
+			let line = match Line::through(a, b) {
+				Some(line) => line,
+				None => return vec![],
+			};
+			let dir = [b[0] - a[0], b[1] - a[1]];
+			let len_sqr = dot2(dir, dir);
+			let tolerance = params.inlier_threshold / len_sqr.sqrt();
+			segs
+				.iter()
+				.enumerate()
+				.filter(|(_, seg)| {
+					let t = dot2(dir, [seg.mid()[0] - a[0], seg.mid()[1] - a[1]]) / len_sqr;
+					line.distance(seg.s) < params.inlier_threshold
+						&& line.distance(seg.e) < params.inlier_threshold
+						&& (-tolerance..=1. + tolerance).contains(&t)
+				})
+				.map(|(k, _)| k)
+				.collect()
+		})
+		.collect()
+}
+
+fn support_length(inliers: &[Vec<usize>], segs: &[Segment]) -> f64 {
+	inliers.iter().flatten().map(|&k| segs[k].length).sum()
+}
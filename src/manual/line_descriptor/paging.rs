@@ -0,0 +1,142 @@
+use crate::{
+	core::{self, DMatch, Mat, Vector},
+	line_descriptor::BinaryDescriptorMatcherTrait,
+	prelude::*,
+	Error, Result,
+};
+
+/// Memory bounds of the pages produced by [BinaryDescriptorMatcherPagingTrait::radius_match_iter]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RadiusMatchPaging {
+	/// Maximum number of the query descriptors matched per page
+	pub max_rows: i32,
+	/// Maximum number of the matches per page, a page that exceeds it is matched again with half of the query
+	/// descriptors
+	///
+	/// The matches of a single query descriptor are never split, so a page with one query descriptor can exceed the limit.
+	pub max_matches: usize,
+}
+
+impl Default for RadiusMatchPaging {
+	fn default() -> Self {
+		Self {
+			max_rows: 1024,
+			max_matches: 1 << 20,
+		}
+	}
+}
+
+/// Matches of the consecutive range of the query descriptors
+#[derive(Clone, Debug)]
+pub struct RadiusMatchPage {
+	/// Index of the first query descriptor of the page
+	pub first_query: i32,
+	/// Matches for every query descriptor of the page, `query_idx` refers to the row of the whole query matrix
+	pub matches: Vector<Vector<DMatch>>,
+}
+
+impl RadiusMatchPage {
+	/// Total number of the matches in the page
+	pub fn match_count(&self) -> usize {
+		self.matches.iter().map(|m| m.len()).sum()
+	}
+}
+
+/// Iterator over the pages of the radius matches, created by [BinaryDescriptorMatcherPagingTrait::radius_match_iter]
+///
+/// Only the current page is kept in memory. The iteration stops after the first error.
+pub struct RadiusMatchIter<'m, 'q, M: ?Sized> {
+	matcher: &'m mut M,
+	queries: &'q Mat,
+	max_distance: f32,
+	paging: RadiusMatchPaging,
+	next_row: i32,
+	rows: i32,
+}
+
+impl<M: BinaryDescriptorMatcherTrait + ?Sized> RadiusMatchIter<'_, '_, M> {
+	fn next_page(&mut self) -> Result<RadiusMatchPage> {
+		let total = self.queries.rows();
+		loop {
+			let first = self.next_row;
+			let end = total.min(first + self.rows);
+			let page = self.queries.row_bounds(first, end)?;
+			let mut matches = Vector::new();
+			self
+				.matcher
+				.radius_match_1(&page, &mut matches, self.max_distance, &Vector::new(), false)?;
+			let count = matches.iter().map(|m| m.len()).sum::<usize>();
+			if count > self.paging.max_matches && end - first > 1 {
+				self.rows = (end - first) / 2;
+				continue;
+			}
+			// the density of the matches varies along the queries, so the page grows back after the sparse regions
+			if count <= self.paging.max_matches / 2 {
+				self.rows = self.paging.max_rows.min(self.rows.saturating_mul(2));
+			}
+			self.next_row = end;
+			let matches = matches
+				.into_iter()
+				.map(|query_matches| {
+					query_matches
+						.into_iter()
+						.map(|m| DMatch {
+							query_idx: m.query_idx + first,
+							..m
+						})
+						.collect()
+				})
+				.collect();
+			return Ok(RadiusMatchPage {
+				first_query: first,
+				matches,
+			});
+		}
+	}
+}
+
+impl<M: BinaryDescriptorMatcherTrait + ?Sized> Iterator for RadiusMatchIter<'_, '_, M> {
+	type Item = Result<RadiusMatchPage>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.next_row >= self.queries.rows() {
+			return None;
+		}
+		let out = self.next_page();
+		if out.is_err() {
+			self.next_row = self.queries.rows();
+		}
+		Some(out)
+	}
+}
+
+/// Paginated radius matching against the added and trained descriptors
+pub trait BinaryDescriptorMatcherPagingTrait: BinaryDescriptorMatcherTrait {
+	/// Same as `radius_match` against the trained descriptors, but produces the matches page by page
+	///
+	/// The query descriptors are matched in consecutive row ranges, so the memory needed for the results is bounded
+	/// by `paging` instead of growing with the total number of the matches.
+	fn radius_match_iter<'q>(
+		&mut self,
+		queries: &'q Mat,
+		max_distance: f32,
+		paging: RadiusMatchPaging,
+	) -> Result<RadiusMatchIter<'_, 'q, Self>> {
+		if paging.max_rows <= 0 || paging.max_matches == 0 {
+			return Err(Error::new(
+				core::StsBadArg,
+				format!("Page bounds must be positive, but got: {:?}", paging),
+			));
+		}
+		Ok(RadiusMatchIter {
+			matcher: self,
+			queries,
+			max_distance,
+			paging,
+			next_row: 0,
+			rows: paging.max_rows,
+		})
+	}
+}
+
+impl<T: BinaryDescriptorMatcherTrait + ?Sized> BinaryDescriptorMatcherPagingTrait for T {}
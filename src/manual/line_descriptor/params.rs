@@ -0,0 +1,103 @@
+use crate::{
+	core::{self, Ptr},
+	line_descriptor::{BinaryDescriptor, BinaryDescriptor_Params},
+	prelude::*,
+	Error, Result,
+};
+
+/// Builder of [BinaryDescriptor_Params], created by [BinaryDescriptor_Params::builder]
+///
+/// Starts with the same values as `BinaryDescriptor_Params::default()`, the values are validated by the terminal
+/// methods.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BinaryDescriptorParamsBuilder {
+	num_octaves: i32,
+	width_of_band: i32,
+	reduction_ratio: i32,
+	ksize: i32,
+}
+
+impl Default for BinaryDescriptorParamsBuilder {
+	fn default() -> Self {
+		Self {
+			num_octaves: 1,
+			width_of_band: 7,
+			reduction_ratio: 2,
+			ksize: 5,
+		}
+	}
+}
+
+impl BinaryDescriptorParamsBuilder {
+	/// Number of the image octaves, at least 1
+	pub fn num_octaves(mut self, num_octaves: i32) -> Self {
+		self.num_octaves = num_octaves;
+		self
+	}
+
+	/// Width of the band of the line support region, at least 1
+	pub fn width_of_band(mut self, width_of_band: i32) -> Self {
+		self.width_of_band = width_of_band;
+		self
+	}
+
+	/// Reduction ratio between the octaves of the Gaussian pyramid, at least 1
+	pub fn reduction_ratio(mut self, reduction_ratio: i32) -> Self {
+		self.reduction_ratio = reduction_ratio;
+		self
+	}
+
+	/// Size of the Gaussian kernel used to build the pyramid, must be positive and odd
+	pub fn ksize(mut self, ksize: i32) -> Self {
+		self.ksize = ksize;
+		self
+	}
+
+	/// Checks the values without creating the parameters
+	pub fn validate(&self) -> Result<()> {
+		let at_least_one = [
+			("Number of octaves", self.num_octaves),
+			("Width of band", self.width_of_band),
+			("Reduction ratio", self.reduction_ratio),
+		];
+		for &(name, val) in &at_least_one {
+			if val < 1 {
+				return Err(Error::new(
+					core::StsOutOfRange,
+					format!("{} must be at least 1, but got: {}", name, val),
+				));
+			}
+		}
+		if self.ksize < 1 || self.ksize.checked_rem(2) != Some(1) {
+			return Err(Error::new(
+				core::StsBadArg,
+				format!("Kernel size must be positive and odd, but got: {}", self.ksize),
+			));
+		}
+		Ok(())
+	}
+
+	/// Validates the values and creates the parameters
+	pub fn build(&self) -> Result<BinaryDescriptor_Params> {
+		self.validate()?;
+		let mut out = BinaryDescriptor_Params::default()?;
+		out.set_num_of_octave_(self.num_octaves);
+		out.set_width_of_band_(self.width_of_band);
+		out.set_reduction_ratio(self.reduction_ratio);
+		out.set_ksize_(self.ksize);
+		Ok(out)
+	}
+
+	/// Validates the values and creates the descriptor with the parameters
+	pub fn build_descriptor(&self) -> Result<Ptr<BinaryDescriptor>> {
+		core::ensure_module_available("line_descriptor")?;
+		BinaryDescriptor::create_binary_descriptor_1(self.build()?)
+	}
+}
+
+impl BinaryDescriptor_Params {
+	/// Creates the builder of the parameters that starts with the default values
+	pub fn builder() -> BinaryDescriptorParamsBuilder {
+		BinaryDescriptorParamsBuilder::default()
+	}
+}
@@ -0,0 +1,216 @@
+use std::convert::TryFrom;
+
+use crate::{
+	core::{self, KeyPoint, Mat, Point2f, Scalar, Size, CV_32F},
+	imgproc,
+	line_descriptor::KeyLine,
+	prelude::*,
+	Error, Result,
+};
+
+/// How the extracted patches are rotated
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PatchOrientation {
+	/// Patches are axis aligned
+	Upright,
+	/// Patches are rotated by the orientation of the feature: the direction of the keyline, so that the keyline becomes
+	/// horizontal, or the `angle` of the keypoint (keypoints without the angle are left upright)
+	Feature,
+	/// Patches are rotated so that the intensity centroid computed from the image moments lies on the positive x
+	/// axis of the patch, see [intensity_centroid_angle]
+	Moments,
+}
+
+/// Parameters of [extract_keyline_patches] and [extract_keypoint_patches]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PatchParams {
+	/// Size of the output patches in pixels
+	pub size: Size,
+	/// Extent of the image area sampled into the patch width relative to the feature size: the keyline length or the
+	/// keypoint diameter
+	pub scale: f64,
+	/// Minimum extent in pixels of the image area sampled into the patch width, prevents upsampling of the tiny
+	/// features
+	pub min_extent: f64,
+	pub orientation: PatchOrientation,
+	/// Pixel extrapolation method for the patches crossing the image border, one of `core::BORDER_*`
+	pub border_mode: i32,
+}
+
+impl Default for PatchParams {
+	fn default() -> Self {
+		Self {
+			size: Size::new(32, 32),
+			scale: 1.,
+			min_extent: 8.,
+			orientation: PatchOrientation::Feature,
+			border_mode: core::BORDER_REPLICATE,
+		}
+	}
+}
+
+/// Extracts the orientation normalized patches around the keylines
+///
+/// The patch is centered at the keyline midpoint and its width covers `scale` times the keyline length, the height
+/// is sampled with the same pixel scale. With [PatchOrientation::Feature] the keyline is horizontal in the patch and
+/// runs from left to right.
+///
+/// ## Returns
+/// Single `Mat` of the same type as `image` with one row per keyline, the row is the patch flattened in the row-major
+/// order. Use `reshape()` to get back the individual patches. Empty `Mat` is returned when there are no keylines.
+pub fn extract_keyline_patches(image: &Mat, keylines: &[KeyLine], params: &PatchParams) -> Result<Mat> {
+	extract_patches(
+		image,
+		params,
+		keylines
+			.iter()
+			.map(|kl| (kl.pt, f64::from(kl.line_length), Some(f64::from(kl.angle)))),
+	)
+}
+
+/// Extracts the orientation normalized patches around the keypoints
+///
+/// The patch is centered at the keypoint and its width covers `scale` times the keypoint diameter (`size`).
+///
+/// ## Returns
+/// Single `Mat` of the same type as `image` with one row per keypoint, see [extract_keyline_patches]
+pub fn extract_keypoint_patches(image: &Mat, keypoints: &[KeyPoint], params: &PatchParams) -> Result<Mat> {
+	extract_patches(
+		image,
+		params,
+		keypoints.iter().map(|kp| {
+			let angle = if kp.angle >= 0. {
+				Some(f64::from(kp.angle).to_radians())
+			} else {
+				None
+			};
+			(kp.pt, f64::from(kp.size), angle)
+		}),
+	)
+}
+
+/// Orientation in radians of the vector from the center to the intensity centroid of the disc
+///
+/// The centroid is computed from the first order image moments of the disc of the passed radius, the same way ORB
+/// computes the keypoint orientation. Multi-channel images are converted to grayscale first. Returns 0 when the
+/// centroid coincides with the center, e.g. for a uniform disc.
+pub fn intensity_centroid_angle(image: &Mat, center: Point2f, radius: f32) -> Result<f32> {
+	let gray = gray_f32(image)?;
+	Ok(centroid_angle(&gray, center, radius)? as f32)
+}
+
+fn extract_patches(
+	image: &Mat,
+	params: &PatchParams,
+	features: impl ExactSizeIterator<Item = (Point2f, f64, Option<f64>)>,
+) -> Result<Mat> {
+	if image.empty() {
+		return Err(Error::new(core::StsBadArg, "Image is empty"));
+	}
+	if params.size.width <= 0 || params.size.height <= 0 {
+		return Err(Error::new(
+			core::StsBadSize,
+			format!("Patch size must be positive, but it's: {:?}", params.size),
+		));
+	}
+	let count = features.len();
+	if count == 0 {
+		return Ok(Mat::default());
+	}
+	let gray = if params.orientation == PatchOrientation::Moments {
+		Some(gray_f32(image)?)
+	} else {
+		None
+	};
+	let (width, height) = (params.size.width, params.size.height);
+	let rows = i32::try_from(count).map_err(|_| {
+		Error::new(
+			core::StsOutOfRange,
+			format!("Too many features to extract patches for: {}", count),
+		)
+	})?;
+	let out = Mat::new_rows_cols_with_default(rows, width * height, image.typ(), Scalar::default())?;
+	let mut patch = Mat::default();
+	for (i, (center, feature_size, feature_angle)) in features.enumerate() {
+		let extent = (feature_size * params.scale).max(params.min_extent);
+		let angle = match (params.orientation, &gray) {
+			(PatchOrientation::Upright, _) => 0.,
+			(PatchOrientation::Feature, _) => feature_angle.unwrap_or(0.),
+			(PatchOrientation::Moments, Some(gray)) => centroid_angle(gray, center, (extent / 2.) as f32)?,
+			(PatchOrientation::Moments, None) => unreachable!("Grayscale image is always prepared for the moments"),
+		};
+		let transform = patch_transform(center, angle, extent / f64::from(width), params.size);
+		imgproc::warp_affine(
+			image,
+			&mut patch,
+			&transform,
+			params.size,
+			imgproc::INTER_LINEAR | imgproc::WARP_INVERSE_MAP,
+			params.border_mode,
+			Scalar::default(),
+		)?;
+		let flat = patch.reshape(0, 1)?;
+		let mut row = out.row(i as i32)?;
+		flat.copy_to(&mut row)?;
+	}
+	Ok(out)
+}
+
+/// Affine transform from the patch coordinates to the image ones
+fn patch_transform(center: Point2f, angle: f64, pixel_scale: f64, size: Size) -> Mat {
+	let (sin, cos) = angle.sin_cos();
+	let (a, b) = (pixel_scale * cos, pixel_scale * sin);
+	// center of the patch, the pixel centers are at the integer coordinates
+	let (ox, oy) = (f64::from(size.width - 1) / 2., f64::from(size.height - 1) / 2.);
+	let (cx, cy) = (f64::from(center.x), f64::from(center.y));
+	Mat::from_slice_2d(&[[a, -b, cx - a * ox + b * oy], [b, a, cy - b * ox - a * oy]])
+		.expect("Creating a 2x3 Mat from the slice can't fail")
+}
+
+fn gray_f32(image: &Mat) -> Result<Mat> {
+	let gray = if image.channels() > 1 {
+		let mut gray = Mat::default();
+		let code = if image.channels() == 4 {
+			imgproc::COLOR_BGRA2GRAY
+		} else {
+			imgproc::COLOR_BGR2GRAY
+		};
+		imgproc::cvt_color(image, &mut gray, code, 0)?;
+		gray
+	} else {
+		image.try_clone()?
+	};
+	let mut out = Mat::default();
+	gray.convert_to(&mut out, CV_32F, 1., 0.)?;
+	Ok(out)
+}
+
+fn centroid_angle(gray: &Mat, center: Point2f, radius: f32) -> Result<f64> {
+	let (width, height) = (gray.cols(), gray.rows());
+	let data = gray.data_typed::<f32>()?;
+	let r = radius.max(1.);
+	let (x0, x1) = (
+		((center.x - r).floor() as i32).max(0),
+		((center.x + r).ceil() as i32).min(width - 1),
+	);
+	let (y0, y1) = (
+		((center.y - r).floor() as i32).max(0),
+		((center.y + r).ceil() as i32).min(height - 1),
+	);
+	let (mut m10, mut m01) = (0., 0.);
+	for y in y0..=y1 {
+		for x in x0..=x1 {
+			let (dx, dy) = (x as f32 - center.x, y as f32 - center.y);
+			if dx * dx + dy * dy <= r * r {
+				let val = f64::from(data[(y * width + x) as usize]);
+				m10 += f64::from(dx) * val;
+				m01 += f64::from(dy) * val;
+			}
+		}
+	}
+	Ok(if m10 == 0. && m01 == 0. {
+		0.
+	} else {
+		m01.atan2(m10)
+	})
+}
@@ -0,0 +1,316 @@
+use std::cmp::Ordering;
+
+use super::keyline::keyline_from_endpoints;
+use crate::{core::Point2f, line_descriptor::KeyLine};
+#[cfg(ocvrs_has_module_imgproc)]
+use crate::{
+	core::{self, Point, Scalar, Vector},
+	imgproc, Result,
+};
+
+/// Number of the fractional bits of the vertex coordinates used when drawing
+#[cfg(ocvrs_has_module_imgproc)]
+const DRAW_SHIFT: i32 = 4;
+
+/// Parameters of [link_keylines]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PolyLineLinkParams {
+	/// Maximum distance in pixels between the endpoints of the keylines that can be linked together
+	pub max_gap: f64,
+	/// Maximum direction change in radians between the linked keylines, it's also the maximum deviation of the bridge
+	/// over the gap from the direction of each of the linked keylines
+	pub max_angle: f64,
+	/// Polylines with the total length of the keylines shorter than this are discarded
+	pub min_length: f64,
+}
+
+impl Default for PolyLineLinkParams {
+	fn default() -> Self {
+		Self {
+			max_gap: 20.,
+			max_angle: 15f64.to_radians(),
+			min_length: 0.,
+		}
+	}
+}
+
+/// Open polyline formed by a chain of connected keylines
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PolyLine {
+	/// Vertices of the polyline, including the bridges over the gaps between the keylines
+	pub points: Vec<Point2f>,
+	/// Straight segments of the polyline in the order along it, each oriented from the previous vertex to the next
+	/// one, the bridges over the gaps are not included
+	pub keylines: Vec<KeyLine>,
+}
+
+impl PolyLine {
+	/// Creates the polyline passing through the `points` with a keyline for each pair of the consecutive points
+	pub fn from_points(points: Vec<Point2f>) -> Self {
+		let keylines = points.windows(2).map(|w| keyline_from_endpoints(w[0], w[1])).collect();
+		Self { points, keylines }
+	}
+
+	/// Total length of the polyline including the gaps between the keylines
+	pub fn length(&self) -> f64 {
+		self.points.windows(2).map(|w| point_distance(w[0], w[1])).sum()
+	}
+
+	/// Total length of the keylines, i.e. the length of the polyline excluding the gaps
+	pub fn keylines_length(&self) -> f64 {
+		self.keylines.iter().map(|kl| f64::from(kl.line_length)).sum()
+	}
+
+	/// Distance between the first and the last vertices divided by the length, 1 for a straight line
+	pub fn straightness(&self) -> f64 {
+		let length = self.length();
+		match (self.points.first(), self.points.last()) {
+			(Some(&first), Some(&last)) if length > 0. => point_distance(first, last) / length,
+			_ => 1.,
+		}
+	}
+
+	/// Signed curvature estimate in 1/pixels at each of the inner vertices
+	///
+	/// The curvature is the turning angle at the vertex divided by the average length of the adjacent edges. It's
+	/// positive when the polyline turns in the direction of the increasing angle (clockwise in the image coordinates
+	/// with the y axis pointing down). The returned vector has 2 elements less than `points`.
+	pub fn curvature(&self) -> Vec<f64> {
+		self
+			.points
+			.windows(3)
+			.map(|w| {
+				let (a, b) = (point_distance(w[0], w[1]), point_distance(w[1], w[2]));
+				if a <= f64::EPSILON || b <= f64::EPSILON {
+					return 0.;
+				}
+				turning_angle(w[0], w[1], w[2]) * 2. / (a + b)
+			})
+			.collect()
+	}
+
+	/// Mean absolute curvature in 1/pixels: the total absolute turning angle divided by the length
+	pub fn mean_curvature(&self) -> f64 {
+		let length = self.length();
+		if length <= f64::EPSILON {
+			return 0.;
+		}
+		self
+			.points
+			.windows(3)
+			.map(|w| turning_angle(w[0], w[1], w[2]).abs())
+			.sum::<f64>()
+			/ length
+	}
+
+	/// Returns the point at the passed distance along the polyline from its first vertex or `None` if the distance is
+	/// outside of the polyline
+	pub fn point_at(&self, distance: f64) -> Option<Point2f> {
+		if distance < 0. {
+			return None;
+		}
+		let mut travelled = 0.;
+		for w in self.points.windows(2) {
+			let len = point_distance(w[0], w[1]);
+			if travelled + len >= distance {
+				return Some(interpolate(
+					w[0],
+					w[1],
+					if len > 0. {
+						(distance - travelled) / len
+					} else {
+						0.
+					},
+				));
+			}
+			travelled += len;
+		}
+		match self.points.as_slice() {
+			[single] if distance == 0. => Some(*single),
+			_ => None,
+		}
+	}
+
+	/// Returns the polyline with the vertices evenly spaced by `step` pixels along this polyline
+	///
+	/// The first and the last vertices are preserved, so the last edge can be shorter than `step`. The gaps are not
+	/// preserved, the resulting polyline has a keyline for each of its edges.
+	pub fn resample(&self, step: f64) -> Self {
+		let (first, last) = match (self.points.first(), self.points.last()) {
+			(Some(&first), Some(&last)) => (first, last),
+			_ => return Self::default(),
+		};
+		let length = self.length();
+		if step <= 0. || length <= f64::EPSILON {
+			return Self::from_points(vec![first]);
+		}
+		let mut out = vec![first];
+		let mut next = step;
+		let mut travelled = 0.;
+		for w in self.points.windows(2) {
+			let len = point_distance(w[0], w[1]);
+			while next < travelled + len && length - next > step * 1e-3 {
+				out.push(interpolate(w[0], w[1], (next - travelled) / len));
+				next += step;
+			}
+			travelled += len;
+		}
+		out.push(last);
+		Self::from_points(out)
+	}
+
+	/// Draws the polyline
+	///
+	/// ## Parameters
+	/// * image: image to draw on
+	/// * color: line color
+	/// * thickness: line thickness
+	/// * draw_gaps: whether to draw the bridges over the gaps between the keylines
+	#[cfg(ocvrs_has_module_imgproc)]
+	pub fn draw(&self, image: &mut dyn core::ToInputOutputArray, color: Scalar, thickness: i32, draw_gaps: bool) -> Result<()> {
+		let scale = (1 << DRAW_SHIFT) as f32;
+		let to_fixed = |x: f32, y: f32| Point::new((x * scale).round() as i32, (y * scale).round() as i32);
+		if draw_gaps {
+			let pts = self.points.iter().map(|p| to_fixed(p.x, p.y)).collect::<Vector<Point>>();
+			imgproc::polylines(image, &pts, false, color, thickness, imgproc::LINE_AA, DRAW_SHIFT)
+		} else {
+			for kl in &self.keylines {
+				imgproc::line(
+					image,
+					to_fixed(kl.start_point_x, kl.start_point_y),
+					to_fixed(kl.end_point_x, kl.end_point_y),
+					color,
+					thickness,
+					imgproc::LINE_AA,
+					DRAW_SHIFT,
+				)?;
+			}
+			Ok(())
+		}
+	}
+}
+
+fn point_distance(a: Point2f, b: Point2f) -> f64 {
+	f64::from((b.x - a.x).hypot(b.y - a.y))
+}
+
+fn interpolate(a: Point2f, b: Point2f, t: f64) -> Point2f {
+	let t = t as f32;
+	Point2f::new(a.x + (b.x - a.x) * t, a.y + (b.y - a.y) * t)
+}
+
+/// Signed angle in the range [-π; π] between the directions `a`→`b` and `b`→`c`
+fn turning_angle(a: Point2f, b: Point2f, c: Point2f) -> f64 {
+	let (d1x, d1y) = (f64::from(b.x - a.x), f64::from(b.y - a.y));
+	let (d2x, d2y) = (f64::from(c.x - b.x), f64::from(c.y - b.y));
+	(d1x * d2y - d1y * d2x).atan2(d1x * d2x + d1y * d2y)
+}
+
+/// Links the keylines into polylines
+///
+/// Two keylines are linked when their endpoints are closer than `max_gap` and the second one continues in the
+/// direction of the first one within `max_angle`, the bridge over the gap between them must follow the same
+/// direction. The candidate links are accepted greedily starting from the shortest and straightest ones, each
+/// keyline endpoint takes part in at most one link and no cycles are formed. The keylines without any links become
+/// single segment polylines.
+///
+/// ## Parameters
+/// * keylines: keylines to link, e.g. the output of `LSDDetector::detect()`
+/// * params: linking parameters
+pub fn link_keylines(keylines: &[KeyLine], params: &PolyLineLinkParams) -> Vec<PolyLine> {
+	let endpoint = |seg: usize, end: usize| {
+		let kl = &keylines[seg];
+		let (s, e) = (
+			Point2f::new(kl.start_point_x, kl.start_point_y),
+			Point2f::new(kl.end_point_x, kl.end_point_y),
+		);
+		let len = point_distance(s, e).max(f64::EPSILON);
+		// outward direction at the endpoint
+		if end == 0 {
+			(s, (f64::from(s.x - e.x) / len, f64::from(s.y - e.y) / len))
+		} else {
+			(e, (f64::from(e.x - s.x) / len, f64::from(e.y - s.y) / len))
+		}
+	};
+	let min_cos = params.max_angle.cos();
+	let mut links = vec![];
+	for i in 0..keylines.len() {
+		for j in i + 1..keylines.len() {
+			for &(ei, ej) in &[(0, 0), (0, 1), (1, 0), (1, 1)] {
+				let ((pi, di), (pj, dj)) = (endpoint(i, ei), endpoint(j, ej));
+				let gap = point_distance(pi, pj);
+				let continuity = -(di.0 * dj.0 + di.1 * dj.1);
+				if gap > params.max_gap || continuity < min_cos {
+					continue;
+				}
+				if gap > 1. {
+					let (gx, gy) = (f64::from(pj.x - pi.x) / gap, f64::from(pj.y - pi.y) / gap);
+					if di.0 * gx + di.1 * gy < min_cos || -(dj.0 * gx + dj.1 * gy) < min_cos {
+						continue;
+					}
+				}
+				links.push((gap * (2. - continuity), (i, ei), (j, ej)));
+			}
+		}
+	}
+	links.sort_unstable_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+	// linked[seg][end] is the (segment, end) the endpoint is linked to
+	let mut linked = vec![[None; 2]; keylines.len()];
+	let mut component = (0..keylines.len()).collect::<Vec<_>>();
+	fn root(component: &mut [usize], mut i: usize) -> usize {
+		while component[i] != i {
+			component[i] = component[component[i]];
+			i = component[i];
+		}
+		i
+	}
+	for (_, (i, ei), (j, ej)) in links {
+		if linked[i][ei].is_some() || linked[j][ej].is_some() {
+			continue;
+		}
+		let (ri, rj) = (root(&mut component, i), root(&mut component, j));
+		if ri == rj {
+			continue;
+		}
+		component[ri] = rj;
+		linked[i][ei] = Some((j, ej));
+		linked[j][ej] = Some((i, ei));
+	}
+	let mut used = vec![false; keylines.len()];
+	let mut out = vec![];
+	for start in 0..keylines.len() {
+		if used[start] {
+			continue;
+		}
+		// chains are acyclic, so every one has a keyline with a free end
+		let free_end = match linked[start].iter().position(Option::is_none) {
+			Some(end) => end,
+			None => continue,
+		};
+		let mut polyline = PolyLine::default();
+		let mut cur = Some((start, free_end));
+		while let Some((seg, entry)) = cur {
+			used[seg] = true;
+			let kl = keylines[seg];
+			let oriented = if entry == 0 {
+				kl
+			} else {
+				keyline_from_endpoints(
+					Point2f::new(kl.end_point_x, kl.end_point_y),
+					Point2f::new(kl.start_point_x, kl.start_point_y),
+				)
+			};
+			polyline
+				.points
+				.push(Point2f::new(oriented.start_point_x, oriented.start_point_y));
+			polyline.points.push(Point2f::new(oriented.end_point_x, oriented.end_point_y));
+			polyline.keylines.push(oriented);
+			cur = linked[seg][1 - entry];
+		}
+		polyline.points.dedup();
+		if polyline.keylines_length() >= params.min_length {
+			out.push(polyline);
+		}
+	}
+	out
+}
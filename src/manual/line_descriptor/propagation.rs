@@ -0,0 +1,342 @@
+use std::{cmp::Ordering, collections::HashMap, convert::TryFrom};
+
+use super::rerank::{convert_descriptors, descriptor_depth, row_distance, RowDistance};
+use crate::{
+	core::{self, DMatch, Mat, Point2f, Vector},
+	line_descriptor::KeyLine,
+	prelude::*,
+	Error, Result,
+};
+
+/// Parameters of [MatchPropagator]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PropagationParams {
+	/// Norm to compare the descriptors with, one of `core::NORM_HAMMING`, `core::NORM_L1`, `core::NORM_L2`,
+	/// `core::NORM_L2SQR`
+	pub norm_type: i32,
+	/// Maximum descriptor distance of the accepted match, both for the local and the global search
+	pub max_distance: f32,
+	/// Radius in pixels of the window around the predicted keyline midpoint that is searched for the match
+	pub search_radius: f32,
+	/// Maximum difference in radians between the predicted and the candidate keyline directions, the keylines are
+	/// treated as undirected
+	pub max_angle_diff: f64,
+	/// Number of the consecutive frames a track can stay unmatched before it's dropped
+	pub max_missed: usize,
+	/// Minimum fraction of the tracks that must be matched by the local search, otherwise the frame is matched
+	/// globally
+	pub min_propagated_ratio: f64,
+	/// The train keylines without a track are matched globally every `reseed_interval` frames, 0 to disable
+	pub reseed_interval: usize,
+}
+
+impl Default for PropagationParams {
+	fn default() -> Self {
+		Self {
+			norm_type: core::NORM_HAMMING,
+			max_distance: 40.,
+			search_radius: 30.,
+			max_angle_diff: 15f64.to_radians(),
+			max_missed: 2,
+			min_propagated_ratio: 0.5,
+			reseed_interval: 10,
+		}
+	}
+}
+
+/// Statistics of the last frame matched with [MatchPropagator::match_frame]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PropagationStats {
+	/// Number of the matches found by the local search around the predicted positions
+	pub propagated: usize,
+	/// Number of the matches found by the global search
+	pub global: usize,
+	/// Number of the descriptor distances computed, the measure of the matching cost
+	pub distance_computations: usize,
+	/// Whether the whole frame was matched globally because too few tracks were propagated
+	pub fallback: bool,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Track {
+	keyline: KeyLine,
+	/// Displacement of the midpoint per frame
+	velocity: Point2f,
+	missed: usize,
+}
+
+/// Temporal propagation of the keyline matches across the video frames
+///
+/// The keylines of every frame are matched against the fixed train set, e.g. the keylines of the reference image or
+/// the map. Every train keyline matched in the previous frame has a track, its position in the next frame is
+/// predicted by the constant velocity model and only the frame keylines in the window around the prediction with the
+/// similar direction are compared with it. The frame is matched globally, comparing all the frame keylines with all
+/// the train ones, when there are no tracks yet or when the local search fails for too many tracks. The train keylines
+/// without a track are periodically matched globally too, so the lost tracks can be recovered.
+///
+/// The returned matches have `query_idx` referring to the frame keylines and `train_idx` referring to the train ones.
+pub struct MatchPropagator {
+	pub params: PropagationParams,
+	train_keylines: Vec<KeyLine>,
+	train_descriptors: Mat,
+	tracks: Vec<Option<Track>>,
+	frame: usize,
+	stats: PropagationStats,
+}
+
+impl MatchPropagator {
+	/// Creates the propagator for the train keylines and their descriptors, one per row
+	pub fn new(train_keylines: Vec<KeyLine>, train_descriptors: &Mat, params: PropagationParams) -> Result<Self> {
+		check_descriptors(&train_keylines, train_descriptors)?;
+		let train_descriptors = convert_descriptors(train_descriptors, descriptor_depth(params.norm_type))?
+			.map_or_else(|| train_descriptors.try_clone(), Ok)?;
+		let tracks = vec![None; train_keylines.len()];
+		Ok(Self {
+			params,
+			train_keylines,
+			train_descriptors,
+			tracks,
+			frame: 0,
+			stats: PropagationStats::default(),
+		})
+	}
+
+	pub fn train_keylines(&self) -> &[KeyLine] {
+		&self.train_keylines
+	}
+
+	/// Number of the currently active tracks
+	pub fn track_count(&self) -> usize {
+		self.tracks.iter().filter(|t| t.is_some()).count()
+	}
+
+	/// Statistics of the last matched frame
+	pub fn stats(&self) -> PropagationStats {
+		self.stats
+	}
+
+	/// Drops all the tracks, the next frame is matched globally
+	pub fn reset(&mut self) {
+		self.tracks.iter_mut().for_each(|t| *t = None);
+		self.frame = 0;
+		self.stats = PropagationStats::default();
+	}
+
+	/// Matches the keylines of the next frame against the train keylines
+	///
+	/// ## Parameters
+	/// * keylines: keylines detected in the frame
+	/// * descriptors: descriptors of the `keylines`, one per row, of the same kind as the train descriptors
+	///
+	/// ## Returns
+	/// At most one match for every frame keyline and every train keyline
+	pub fn match_frame(&mut self, keylines: &[KeyLine], descriptors: &Mat) -> Result<Vector<DMatch>> {
+		check_descriptors(keylines, descriptors)?;
+		let distance = row_distance(self.params.norm_type)?;
+		let converted = convert_descriptors(descriptors, descriptor_depth(self.params.norm_type))?;
+		let descriptors = converted.as_ref().unwrap_or(descriptors);
+		let mut search = Search {
+			query_keylines: keylines,
+			query_descriptors: descriptors,
+			train_descriptors: &self.train_descriptors,
+			distance,
+			max_distance: self.params.max_distance,
+			computations: 0,
+		};
+		let tracked = self.track_count();
+		let mut matches = self.propagate(&mut search)?;
+		let propagated = matches.len();
+		let fallback = tracked == 0 || (propagated as f64) < self.params.min_propagated_ratio * tracked as f64;
+		if fallback {
+			matches = search.global(&mut vec![false; keylines.len()], &mut vec![false; self.train_keylines.len()])?;
+		} else if self.frame.checked_rem(self.params.reseed_interval) == Some(0) {
+			let mut query_used = vec![false; keylines.len()];
+			let mut train_used = self.tracks.iter().map(|t| t.is_some()).collect::<Vec<_>>();
+			for m in &matches {
+				query_used[m.query_idx as usize] = true;
+				train_used[m.train_idx as usize] = true;
+			}
+			matches.extend(search.global(&mut query_used, &mut train_used)?);
+		}
+		self.stats = PropagationStats {
+			propagated: if fallback {
+				0
+			} else {
+				propagated
+			},
+			global: if fallback {
+				matches.len()
+			} else {
+				matches.len() - propagated
+			},
+			distance_computations: search.computations,
+			fallback,
+		};
+		self.update_tracks(keylines, &matches);
+		self.frame += 1;
+		matches.sort_by_key(|m| m.query_idx);
+		Ok(matches.into_iter().collect())
+	}
+
+	/// Local search of the tracked train keylines in the windows around their predicted positions
+	fn propagate(&self, search: &mut Search) -> Result<Vec<DMatch>> {
+		let radius = self.params.search_radius.max(1.);
+		let mut grid = HashMap::<(i32, i32), Vec<usize>>::new();
+		for (i, kl) in search.query_keylines.iter().enumerate() {
+			grid.entry(cell(kl.pt, radius)).or_default().push(i);
+		}
+		let mut proposals = vec![];
+		for (train_idx, track) in self.tracks.iter().enumerate() {
+			let track = match track {
+				Some(track) => track,
+				None => continue,
+			};
+			let steps = (track.missed + 1) as f32;
+			let predicted = Point2f::new(
+				track.keyline.pt.x + track.velocity.x * steps,
+				track.keyline.pt.y + track.velocity.y * steps,
+			);
+			let (cx, cy) = cell(predicted, radius);
+			let mut best: Option<DMatch> = None;
+			for query_idx in (cx - 1..=cx + 1)
+				.flat_map(|x| (cy - 1..=cy + 1).map(move |y| (x, y)))
+				.filter_map(|c| grid.get(&c))
+				.flatten()
+				.copied()
+			{
+				let kl = &search.query_keylines[query_idx];
+				let (dx, dy) = (kl.pt.x - predicted.x, kl.pt.y - predicted.y);
+				if dx * dx + dy * dy > radius * radius || undirected_angle_diff(kl, &track.keyline) > self.params.max_angle_diff {
+					continue;
+				}
+				if let Some(m) = search.compare(query_idx, train_idx)? {
+					if !matches!(best, Some(best) if best.distance <= m.distance) {
+						best = Some(m);
+					}
+				}
+			}
+			proposals.extend(best);
+		}
+		let mut query_used = vec![false; search.query_keylines.len()];
+		Ok(assign_unique(proposals, &mut query_used, &mut vec![false; self.tracks.len()]))
+	}
+
+	fn update_tracks(&mut self, keylines: &[KeyLine], matches: &[DMatch]) {
+		let mut matched = vec![None; self.tracks.len()];
+		for m in matches {
+			matched[m.train_idx as usize] = Some(keylines[m.query_idx as usize]);
+		}
+		for (track, matched) in self.tracks.iter_mut().zip(matched) {
+			*track = match (*track, matched) {
+				(Some(prev), Some(keyline)) => {
+					let frames = (prev.missed + 1) as f32;
+					Some(Track {
+						keyline,
+						velocity: Point2f::new(
+							(keyline.pt.x - prev.keyline.pt.x) / frames,
+							(keyline.pt.y - prev.keyline.pt.y) / frames,
+						),
+						missed: 0,
+					})
+				}
+				(None, Some(keyline)) => Some(Track {
+					keyline,
+					velocity: Point2f::default(),
+					missed: 0,
+				}),
+				(Some(prev), None) if prev.missed < self.params.max_missed => Some(Track {
+					missed: prev.missed + 1,
+					..prev
+				}),
+				(Some(_), None) | (None, None) => None,
+			};
+		}
+	}
+}
+
+struct Search<'s> {
+	query_keylines: &'s [KeyLine],
+	query_descriptors: &'s Mat,
+	train_descriptors: &'s Mat,
+	distance: RowDistance,
+	max_distance: f32,
+	computations: usize,
+}
+
+impl Search<'_> {
+	/// Match between the frame and the train keylines if their descriptors are close enough
+	fn compare(&mut self, query_idx: usize, train_idx: usize) -> Result<Option<DMatch>> {
+		self.computations += 1;
+		let (query_idx, train_idx) = (query_idx as i32, train_idx as i32);
+		let distance = (self.distance)(self.query_descriptors, query_idx, self.train_descriptors, train_idx)?;
+		Ok(if distance <= self.max_distance {
+			Some(DMatch {
+				query_idx,
+				train_idx,
+				img_idx: 0,
+				distance,
+			})
+		} else {
+			None
+		})
+	}
+
+	/// Brute force search among the keylines not used yet
+	fn global(&mut self, query_used: &mut [bool], train_used: &mut [bool]) -> Result<Vec<DMatch>> {
+		let mut proposals = vec![];
+		for query_idx in (0..query_used.len()).filter(|&i| !query_used[i]) {
+			let mut best: Option<DMatch> = None;
+			for train_idx in (0..train_used.len()).filter(|&i| !train_used[i]) {
+				if let Some(m) = self.compare(query_idx, train_idx)? {
+					if !matches!(best, Some(best) if best.distance <= m.distance) {
+						best = Some(m);
+					}
+				}
+			}
+			proposals.extend(best);
+		}
+		Ok(assign_unique(proposals, query_used, train_used))
+	}
+}
+
+/// Greedily accepts the proposals from the best one so that every keyline is used at most once
+pub(super) fn assign_unique(mut proposals: Vec<DMatch>, query_used: &mut [bool], train_used: &mut [bool]) -> Vec<DMatch> {
+	proposals.sort_by(|a, b| a.distance.partial_cmp(&b.distance).unwrap_or(Ordering::Equal));
+	proposals
+		.into_iter()
+		.filter(|m| {
+			let (q, t) = (m.query_idx as usize, m.train_idx as usize);
+			if query_used[q] || train_used[t] {
+				false
+			} else {
+				query_used[q] = true;
+				train_used[t] = true;
+				true
+			}
+		})
+		.collect()
+}
+
+fn cell(pt: Point2f, size: f32) -> (i32, i32) {
+	((pt.x / size).floor() as i32, (pt.y / size).floor() as i32)
+}
+
+pub(super) fn undirected_angle_diff(a: &KeyLine, b: &KeyLine) -> f64 {
+	let diff = f64::from(a.angle - b.angle).rem_euclid(std::f64::consts::PI);
+	diff.min(std::f64::consts::PI - diff)
+}
+
+pub(super) fn check_descriptors(keylines: &[KeyLine], descriptors: &Mat) -> Result<()> {
+	let rows = usize::try_from(descriptors.rows()).unwrap_or(0);
+	if rows != keylines.len() {
+		return Err(Error::new(
+			core::StsUnmatchedSizes,
+			format!(
+				"Number of descriptors: {} doesn't match the number of keylines: {}",
+				rows,
+				keylines.len()
+			),
+		));
+	}
+	Ok(())
+}
@@ -0,0 +1,75 @@
+use crate::{
+	core::{self, Mat, Size, Vector},
+	imgproc,
+	line_descriptor::BinaryDescriptorTraitConst,
+	prelude::*,
+	Error, Result,
+};
+
+/// Gaussian pyramid used by `BinaryDescriptor` to compute the LBD descriptors
+///
+/// The first level is the grayscale input image blurred with the 5×5 Gaussian kernel, every next level is reduced by
+/// `reduction_ratio` with `pyrDown()`.
+#[derive(Clone, Debug)]
+pub struct BinaryDescriptorPyramid {
+	/// Octave images starting from the full resolution one, single channel 8-bit
+	pub images: Vector<Mat>,
+	/// Reduction ratio between the consecutive octaves
+	pub reduction_ratio: i32,
+}
+
+impl BinaryDescriptorPyramid {
+	pub fn num_octaves(&self) -> usize {
+		self.images.len()
+	}
+
+	/// Factor that maps the coordinates in the `octave` image to the original image
+	pub fn scale_factor(&self, octave: usize) -> f64 {
+		f64::from(self.reduction_ratio).powi(octave as i32)
+	}
+
+	/// Scale factors of all octaves, see [BinaryDescriptorPyramid::scale_factor]
+	pub fn scale_factors(&self) -> Vec<f64> {
+		(0..self.num_octaves()).map(|octave| self.scale_factor(octave)).collect()
+	}
+}
+
+/// Gaussian pyramid of `BinaryDescriptor`
+///
+/// The pyramid is built with the public `imgproc` functions using the same steps as `BinaryDescriptor` does internally,
+/// so the octave images match the ones the descriptors are computed on.
+pub trait BinaryDescriptorPyramidTrait: BinaryDescriptorTraitConst {
+	/// Builds the pyramid of the image the same way `compute()` does it
+	///
+	/// Color images are converted to grayscale first. `pyrDown()` only supports halving of the image, so the reduction
+	/// ratio of the descriptor must be 2 for the pyramids with more than one octave.
+	fn compute_pyramid(&self, image: &Mat) -> Result<BinaryDescriptorPyramid> {
+		let (num_octaves, reduction_ratio) = (self.get_num_of_octaves()?, self.get_reduction_ratio()?);
+		if num_octaves > 1 && reduction_ratio < 1 {
+			return Err(Error::new(
+				core::StsOutOfRange,
+				format!("Reduction ratio must be at least 1, but got: {}", reduction_ratio),
+			));
+		}
+		let mut gray = Mat::default();
+		if image.channels() != 1 {
+			imgproc::cvt_color(image, &mut gray, imgproc::COLOR_BGR2GRAY, 0)?;
+		} else {
+			gray = image.try_clone()?;
+		}
+		let mut current = Mat::default();
+		imgproc::gaussian_blur(&gray, &mut current, Size::new(5, 5), 1., 0., core::BORDER_DEFAULT)?;
+		let mut images = Vector::with_capacity(num_octaves.max(1) as usize);
+		for _ in 1..num_octaves {
+			let mut next = Mat::default();
+			let size = Size::new(current.cols() / reduction_ratio, current.rows() / reduction_ratio);
+			imgproc::pyr_down(&current, &mut next, size, core::BORDER_DEFAULT)?;
+			images.push(current);
+			current = next;
+		}
+		images.push(current);
+		Ok(BinaryDescriptorPyramid { images, reduction_ratio })
+	}
+}
+
+impl<T: BinaryDescriptorTraitConst + ?Sized> BinaryDescriptorPyramidTrait for T {}
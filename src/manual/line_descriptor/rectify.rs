@@ -0,0 +1,113 @@
+use super::{
+	linalg::{cross, dot, mat_mul, mat_vec_mul, norm, normalize, scale, sub, transpose, Mat3},
+	vanishing_point::detect_vanishing_points,
+};
+use crate::{
+	core::{self, Mat, Scalar, Size, Vector},
+	imgproc,
+	line_descriptor::{KeyLine, LSDDetector, LSDDetectorTrait},
+	prelude::*,
+	Error, Result,
+};
+
+/// Removes the perspective distortion from an image of a planar scene (document, whiteboard, facade)
+///
+/// Lines are detected with `LSDDetector` and the two dominant vanishing points are assumed to correspond to orthogonal
+/// directions in the scene plane. They are used to estimate the focal length of the camera (with the principal point
+/// in the image center and square pixels), after that the camera is virtually rotated to face the plane. No
+/// calibration target is needed.
+///
+/// Returns the rectified image and the 3x3 `CV_64F` homography that maps the source image onto it.
+pub fn rectify_planar_view(image: &Mat) -> Result<(Mat, Mat)> {
+	let size = image.size()?;
+	let mut keylines = Vector::<KeyLine>::new();
+	LSDDetector::default()?.detect(image, &mut keylines, 2, 1, None)?;
+	let vps = detect_vanishing_points(keylines.as_slice(), size, 2);
+	if vps.len() < 2 {
+		return Err(Error::new(
+			core::StsError,
+			format!("Expected to find 2 vanishing points in the image, but found: {}", vps.len()),
+		));
+	}
+	let h = rectifying_homography(
+		[vps[0].point.x, vps[0].point.y, vps[0].point.z],
+		[vps[1].point.x, vps[1].point.y, vps[1].point.z],
+		size,
+	)?;
+	let (h, out_size) = fit_to_area(&h, size)?;
+	let h = Mat::from_slice_2d(&h)?;
+	let mut out = Mat::default();
+	imgproc::warp_perspective(
+		image,
+		&mut out,
+		&h,
+		out_size,
+		imgproc::INTER_LINEAR,
+		core::BORDER_CONSTANT,
+		Scalar::default(),
+	)?;
+	Ok((out, h))
+}
+
+/// Homography that rotates the camera to face the plane spanned by 2 orthogonal directions with the passed
+/// homogeneous vanishing points
+fn rectifying_homography(vp1: [f64; 3], vp2: [f64; 3], size: Size) -> Result<Mat3> {
+	let (cx, cy) = (f64::from(size.width) / 2., f64::from(size.height) / 2.);
+	let centered = |vp: [f64; 3]| [vp[0] - cx * vp[2], vp[1] - cy * vp[2], vp[2]];
+	let (c1, c2) = (centered(vp1), centered(vp2));
+	// orthogonality of the directions gives f^2 = -(v1 - c)·(v2 - c) when both points are finite
+	let f_sqr = -(c1[0] * c2[0] + c1[1] * c2[1]) / (c1[2] * c2[2]);
+	let f = if f_sqr.is_finite() && f_sqr > 0. {
+		f_sqr.sqrt()
+	} else {
+		f64::from(size.width.max(size.height))
+	};
+	let k = [[f, 0., cx], [0., f, cy], [0., 0., 1.]];
+	let k_inv = [[1. / f, 0., -cx / f], [0., 1. / f, -cy / f], [0., 0., 1.]];
+	let mut r1 = normalize(mat_vec_mul(&k_inv, vp1));
+	let mut r2 = normalize(mat_vec_mul(&k_inv, vp2));
+	if r2[0].abs() > r1[0].abs() {
+		std::mem::swap(&mut r1, &mut r2);
+	}
+	if r1[0] < 0. {
+		r1 = scale(r1, -1.);
+	}
+	let r2 = sub(r2, scale(r1, dot(r1, r2)));
+	if norm(r2) < 1e-6 {
+		return Err(Error::new(
+			core::StsError,
+			"Vanishing points correspond to the same direction",
+		));
+	}
+	let mut r2 = normalize(r2);
+	if r2[1] < 0. {
+		r2 = scale(r2, -1.);
+	}
+	let r3 = cross(r1, r2);
+	let r = [[r1[0], r2[0], r3[0]], [r1[1], r2[1], r3[1]], [r1[2], r2[2], r3[2]]];
+	Ok(mat_mul(&mat_mul(&k, &transpose(&r)), &k_inv))
+}
+
+/// Adjusts the homography so that the transformed image starts at the origin and keeps approximately the source area
+fn fit_to_area(h: &Mat3, size: Size) -> Result<(Mat3, Size)> {
+	let (w, hgt) = (f64::from(size.width), f64::from(size.height));
+	let (mut min_x, mut min_y, mut max_x, mut max_y) = (f64::MAX, f64::MAX, f64::MIN, f64::MIN);
+	for &(x, y) in &[(0., 0.), (w, 0.), (w, hgt), (0., hgt)] {
+		let p = mat_vec_mul(h, [x, y, 1.]);
+		if p[2] <= f64::EPSILON {
+			return Err(Error::new(core::StsError, "Rectified plane doesn't fit into the camera view"));
+		}
+		let (x, y) = (p[0] / p[2], p[1] / p[2]);
+		min_x = min_x.min(x);
+		min_y = min_y.min(y);
+		max_x = max_x.max(x);
+		max_y = max_y.max(y);
+	}
+	let (bw, bh) = (max_x - min_x, max_y - min_y);
+	let s = (w * hgt / (bw * bh)).sqrt();
+	if !s.is_finite() {
+		return Err(Error::new(core::StsError, "Rectified image has zero area"));
+	}
+	let t = [[s, 0., -min_x * s], [0., s, -min_y * s], [0., 0., 1.]];
+	Ok((mat_mul(&t, h), Size::new((bw * s).ceil() as i32, (bh * s).ceil() as i32)))
+}
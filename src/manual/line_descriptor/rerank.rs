@@ -0,0 +1,196 @@
+use std::{cmp::Ordering, convert::TryFrom};
+
+use crate::{
+	core::{self, DMatch, Mat, Vector, CV_32F, CV_8U},
+	line_descriptor::KeyLine,
+	prelude::*,
+	Error, Result,
+};
+
+/// Geometric consistency check of the matched keylines
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct KeyLineGeometryCheck {
+	/// Maximum difference in radians between the directions of the matched keylines, the keylines are treated as
+	/// undirected
+	pub max_angle_diff: f64,
+	/// Maximum ratio between the lengths of the longer and the shorter matched keylines
+	pub max_length_ratio: f64,
+}
+
+impl Default for KeyLineGeometryCheck {
+	fn default() -> Self {
+		Self {
+			max_angle_diff: 20f64.to_radians(),
+			max_length_ratio: 3.,
+		}
+	}
+}
+
+impl KeyLineGeometryCheck {
+	/// Whether the match between the passed keylines is geometrically consistent
+	pub fn accepts(&self, query: &KeyLine, train: &KeyLine) -> bool {
+		let diff = f64::from(query.angle - train.angle).rem_euclid(std::f64::consts::PI);
+		let angle_diff = diff.min(std::f64::consts::PI - diff);
+		let (short, long) = if query.line_length < train.line_length {
+			(query.line_length, train.line_length)
+		} else {
+			(train.line_length, query.line_length)
+		};
+		angle_diff <= self.max_angle_diff && f64::from(long) <= self.max_length_ratio * f64::from(short)
+	}
+
+	/// Returns the `verify` callback for [rerank] that checks the keylines of the candidate matches
+	///
+	/// ## Parameters
+	/// * query_keylines: keylines of the query descriptors
+	/// * train_keylines: keylines of the train descriptors for every train image
+	pub fn verifier<'k>(
+		&'k self,
+		query_keylines: &'k [KeyLine],
+		train_keylines: &'k [Vec<KeyLine>],
+	) -> impl FnMut(&DMatch) -> bool + 'k {
+		move |m| {
+			let query = usize::try_from(m.query_idx).ok().and_then(|i| query_keylines.get(i));
+			let train = usize::try_from(m.img_idx)
+				.ok()
+				.and_then(|i| train_keylines.get(i))
+				.and_then(|keylines| usize::try_from(m.train_idx).ok().and_then(|i| keylines.get(i)));
+			match (query, train) {
+				(Some(query), Some(train)) => self.accepts(query, train),
+				_ => false,
+			}
+		}
+	}
+}
+
+/// Recomputes the exact distances of the approximate match candidates and returns the corrected top-`k` matches
+///
+/// The candidates are usually produced by an approximate matcher, e.g. the multi-index hashing of
+/// `BinaryDescriptorMatcher` on the binarized descriptors, with more candidates retrieved than required. The
+/// `distance` of every candidate is recomputed from the descriptors, the candidates rejected by `verify` are dropped
+/// and the rest are sorted by the new distance.
+///
+/// ## Parameters
+/// * candidates: candidate matches for every query descriptor, as returned by `knn_match()`
+/// * query_descriptors: query descriptors, one per row, `query_idx` of the candidates refers to them
+/// * train_descriptors: train descriptors for every train image, `img_idx` and `train_idx` of the candidates refer to
+///   them, pass a single element slice when matching against a single set of descriptors
+/// * norm_type: one of `core::NORM_HAMMING`, `core::NORM_L1`, `core::NORM_L2`, `core::NORM_L2SQR`, the Hamming
+///   distance requires `CV_8U` descriptors, the other norms work on any single-channel descriptors
+/// * k: maximum number of the matches returned for every query
+/// * verify: additional check of the candidate, e.g. the geometric verification with
+///   [KeyLineGeometryCheck::verifier], return `true` to keep the candidate
+///
+/// ## Returns
+/// The corrected matches, the number and order of the query lists is the same as in `candidates`
+pub fn rerank(
+	candidates: &Vector<Vector<DMatch>>,
+	query_descriptors: &Mat,
+	train_descriptors: &[Mat],
+	norm_type: i32,
+	k: usize,
+	mut verify: impl FnMut(&DMatch) -> bool,
+) -> Result<Vector<Vector<DMatch>>> {
+	let distance = row_distance(norm_type)?;
+	let depth = descriptor_depth(norm_type);
+	let query = convert_descriptors(query_descriptors, depth)?;
+	let query = query.as_ref().unwrap_or(query_descriptors);
+	let train = train_descriptors
+		.iter()
+		.map(|descriptors| convert_descriptors(descriptors, depth))
+		.collect::<Result<Vec<_>>>()?;
+	let train = train
+		.iter()
+		.zip(train_descriptors)
+		.map(|(converted, descriptors)| converted.as_ref().unwrap_or(descriptors))
+		.collect::<Vec<_>>();
+	let mut out = Vector::<Vector<DMatch>>::with_capacity(candidates.len());
+	for query_candidates in candidates {
+		let mut reranked = Vec::with_capacity(query_candidates.len());
+		for mut m in query_candidates {
+			let train = usize::try_from(m.img_idx)
+				.ok()
+				.and_then(|img_idx| train.get(img_idx))
+				.ok_or_else(|| {
+					Error::new(
+						core::StsOutOfRange,
+						format!("Candidate refers to the unknown train image: {}", m.img_idx),
+					)
+				})?;
+			if !verify(&m) {
+				continue;
+			}
+			m.distance = distance(query, m.query_idx, train, m.train_idx)?;
+			reranked.push(m);
+		}
+		reranked.sort_by(|a, b| a.distance.partial_cmp(&b.distance).unwrap_or(Ordering::Equal));
+		reranked.truncate(k);
+		out.push(reranked.into_iter().collect());
+	}
+	Ok(out)
+}
+
+/// Distance between the descriptor rows `qi` of `q` and `ti` of `t` in the passed norm, the descriptors must have the
+/// [descriptor_depth] of that norm
+pub(super) type RowDistance = fn(&Mat, i32, &Mat, i32) -> Result<f32>;
+
+pub(super) fn row_distance(norm_type: i32) -> Result<RowDistance> {
+	Ok(match norm_type {
+		core::NORM_HAMMING => |q, qi, t, ti| {
+			let (q, t) = (q.at_row::<u8>(qi)?, t.at_row::<u8>(ti)?);
+			Ok(q.iter().zip(t).map(|(a, b)| (a ^ b).count_ones()).sum::<u32>() as f32)
+		},
+		core::NORM_L1 => |q, qi, t, ti| {
+			let (q, t) = (q.at_row::<f32>(qi)?, t.at_row::<f32>(ti)?);
+			Ok(q.iter().zip(t).map(|(a, b)| (a - b).abs()).sum())
+		},
+		core::NORM_L2 => |q, qi, t, ti| {
+			let (q, t) = (q.at_row::<f32>(qi)?, t.at_row::<f32>(ti)?);
+			Ok(q.iter().zip(t).map(|(a, b)| (a - b) * (a - b)).sum::<f32>().sqrt())
+		},
+		core::NORM_L2SQR => |q, qi, t, ti| {
+			let (q, t) = (q.at_row::<f32>(qi)?, t.at_row::<f32>(ti)?);
+			Ok(q.iter().zip(t).map(|(a, b)| (a - b) * (a - b)).sum())
+		},
+		_ => {
+			return Err(Error::new(
+				core::StsBadFlag,
+				format!("Unsupported norm type for descriptor distance: {}", norm_type),
+			))
+		}
+	})
+}
+
+/// Depth of the descriptors the [row_distance] of the norm works on
+pub(super) fn descriptor_depth(norm_type: i32) -> i32 {
+	if norm_type == core::NORM_HAMMING {
+		CV_8U
+	} else {
+		CV_32F
+	}
+}
+
+/// Returns `None` when the descriptors already have the requested depth
+pub(super) fn convert_descriptors(descriptors: &Mat, depth: i32) -> Result<Option<Mat>> {
+	if descriptors.channels() != 1 {
+		return Err(Error::new(
+			core::StsBadArg,
+			format!("Descriptors must be single-channel, but got: {}", descriptors.channels()),
+		));
+	}
+	if descriptors.depth() == depth {
+		return Ok(None);
+	}
+	if depth == CV_8U {
+		return Err(Error::new(
+			core::StsUnsupportedFormat,
+			format!(
+				"Hamming distance requires CV_8U descriptors, but got depth: {}",
+				descriptors.depth()
+			),
+		));
+	}
+	let mut out = Mat::default();
+	descriptors.convert_to(&mut out, depth, 1., 0.)?;
+	Ok(Some(out))
+}
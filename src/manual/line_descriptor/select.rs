@@ -0,0 +1,21 @@
+use super::keyline::keyline_from_endpoints;
+use crate::{
+	core::{Mat, Point2f},
+	highgui,
+	line_descriptor::KeyLine,
+	Result,
+};
+
+/// Allows the user to select a line in the image by clicking its two end points, see [highgui::select_segment]
+///
+/// Returns the keyline in the image coordinates or `None` if the selection was canceled or both points coincide.
+pub fn select_keyline(window_name: &str, img: &Mat, show_crosshair: bool) -> Result<Option<KeyLine>> {
+	Ok(highgui::select_segment(window_name, img, show_crosshair)?
+		.filter(|(start, end)| start != end)
+		.map(|(start, end)| {
+			keyline_from_endpoints(
+				Point2f::new(start.x as f32, start.y as f32),
+				Point2f::new(end.x as f32, end.y as f32),
+			)
+		}))
+}
@@ -0,0 +1,367 @@
+use std::fmt::{self, Write as _};
+#[cfg(any(feature = "sink-stdout", feature = "sink-jsonl", feature = "sink-file"))]
+use std::io::{self, Write};
+#[cfg(feature = "sink-file")]
+use std::{
+	fs::{File, OpenOptions},
+	io::BufWriter,
+	path::Path,
+};
+
+#[cfg(any(
+	feature = "sink-stdout",
+	feature = "sink-jsonl",
+	feature = "sink-file",
+	feature = "sink-mqtt"
+))]
+use crate::{core, Error};
+use crate::{core::Point2f, line_descriptor::KeyLine, Result};
+
+/// Direction of the zone or tripwire crossing
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CrossingDirection {
+	/// Into the zone or across the tripwire from its left side to the right one
+	In,
+	/// Out of the zone or across the tripwire from its right side to the left one
+	Out,
+}
+
+impl CrossingDirection {
+	fn as_str(self) -> &'static str {
+		match self {
+			CrossingDirection::In => "in",
+			CrossingDirection::Out => "out",
+		}
+	}
+}
+
+/// Event emitted by a pipeline stage
+#[derive(Clone, Debug, PartialEq)]
+pub enum DetectionEvent {
+	/// New line track started
+	LineAppeared {
+		track_id: u64,
+		timestamp: f64,
+		keyline: KeyLine,
+	},
+	/// Track was not observed for too long and was dropped
+	TrackLost {
+		track_id: u64,
+		timestamp: f64,
+		/// Last observation of the track
+		keyline: KeyLine,
+	},
+	/// Track crossed the boundary of a zone or a tripwire
+	ZoneCrossed {
+		track_id: u64,
+		timestamp: f64,
+		/// Name of the zone or the tripwire
+		zone: String,
+		direction: CrossingDirection,
+		/// Point where the boundary was crossed
+		position: Point2f,
+	},
+}
+
+impl DetectionEvent {
+	/// Identifier of the event type: `line_appeared`, `track_lost` or `zone_crossed`
+	pub fn kind(&self) -> &'static str {
+		match self {
+			DetectionEvent::LineAppeared { .. } => "line_appeared",
+			DetectionEvent::TrackLost { .. } => "track_lost",
+			DetectionEvent::ZoneCrossed { .. } => "zone_crossed",
+		}
+	}
+
+	/// Identifier of the track the event refers to
+	pub fn track_id(&self) -> u64 {
+		match self {
+			DetectionEvent::LineAppeared { track_id, .. }
+			| DetectionEvent::TrackLost { track_id, .. }
+			| DetectionEvent::ZoneCrossed { track_id, .. } => *track_id,
+		}
+	}
+
+	/// Time of the event in the units of the pipeline (frame index, seconds)
+	pub fn timestamp(&self) -> f64 {
+		match self {
+			DetectionEvent::LineAppeared { timestamp, .. }
+			| DetectionEvent::TrackLost { timestamp, .. }
+			| DetectionEvent::ZoneCrossed { timestamp, .. } => *timestamp,
+		}
+	}
+
+	/// Serializes the event into a single line JSON object
+	///
+	/// The object always contains `event`, `track_id` and `timestamp` fields, keylines are represented by their
+	/// `start`, `end` points, `angle` and `response`.
+	pub fn to_json(&self) -> String {
+		let mut out = format!(
+			"{{\"event\":\"{}\",\"track_id\":{},\"timestamp\":{}",
+			self.kind(),
+			self.track_id(),
+			json_number(self.timestamp())
+		);
+		match self {
+			DetectionEvent::LineAppeared { keyline, .. } | DetectionEvent::TrackLost { keyline, .. } => {
+				out.push_str(",\"keyline\":");
+				write_keyline(&mut out, keyline);
+			}
+			DetectionEvent::ZoneCrossed {
+				zone,
+				direction,
+				position,
+				..
+			} => {
+				out.push_str(",\"zone\":");
+				write_string(&mut out, zone);
+				let _ = write!(out, ",\"direction\":\"{}\",\"position\":", direction.as_str());
+				write_point(&mut out, *position);
+			}
+		}
+		out.push('}');
+		out
+	}
+}
+
+/// Consumer of the pipeline events
+///
+/// Implement it to decouple the analytics from the vision loop, the stages push the events as they happen and the
+/// sink decides how to deliver them. `Vec<DetectionEvent>` is a sink that just collects the events.
+pub trait DetectionSink {
+	/// Delivers the event
+	fn push(&mut self, event: &DetectionEvent) -> Result<()>;
+
+	/// Makes sure that all the pushed events are delivered
+	fn flush(&mut self) -> Result<()> {
+		Ok(())
+	}
+}
+
+impl DetectionSink for Vec<DetectionEvent> {
+	#[inline]
+	fn push(&mut self, event: &DetectionEvent) -> Result<()> {
+		Vec::push(self, event.clone());
+		Ok(())
+	}
+}
+
+impl<S: DetectionSink + ?Sized> DetectionSink for &mut S {
+	#[inline]
+	fn push(&mut self, event: &DetectionEvent) -> Result<()> {
+		(**self).push(event)
+	}
+
+	#[inline]
+	fn flush(&mut self) -> Result<()> {
+		(**self).flush()
+	}
+}
+
+impl<S: DetectionSink + ?Sized> DetectionSink for Box<S> {
+	#[inline]
+	fn push(&mut self, event: &DetectionEvent) -> Result<()> {
+		(**self).push(event)
+	}
+
+	#[inline]
+	fn flush(&mut self) -> Result<()> {
+		(**self).flush()
+	}
+}
+
+/// Sink that prints the human-readable events to the standard output
+#[cfg(feature = "sink-stdout")]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct StdoutSink;
+
+#[cfg(feature = "sink-stdout")]
+impl DetectionSink for StdoutSink {
+	fn push(&mut self, event: &DetectionEvent) -> Result<()> {
+		let stdout = io::stdout();
+		let mut stdout = stdout.lock();
+		match event {
+			DetectionEvent::LineAppeared {
+				track_id,
+				timestamp,
+				keyline,
+			}
+			| DetectionEvent::TrackLost {
+				track_id,
+				timestamp,
+				keyline,
+			} => writeln!(
+				stdout,
+				"[{}] {} #{}: ({}, {}) - ({}, {})",
+				timestamp,
+				event.kind(),
+				track_id,
+				keyline.start_point_x,
+				keyline.start_point_y,
+				keyline.end_point_x,
+				keyline.end_point_y
+			),
+			DetectionEvent::ZoneCrossed {
+				track_id,
+				timestamp,
+				zone,
+				direction,
+				position,
+			} => writeln!(
+				stdout,
+				"[{}] {} #{}: {} {} at ({}, {})",
+				timestamp,
+				event.kind(),
+				track_id,
+				direction.as_str(),
+				zone,
+				position.x,
+				position.y
+			),
+		}
+		.map_err(io_error)
+	}
+
+	fn flush(&mut self) -> Result<()> {
+		io::stdout().flush().map_err(io_error)
+	}
+}
+
+/// Sink that writes the events as JSON Lines, one object per line, see [DetectionEvent::to_json]
+#[cfg(any(feature = "sink-jsonl", feature = "sink-file"))]
+#[derive(Debug)]
+pub struct JsonLinesSink<W: Write> {
+	writer: W,
+}
+
+#[cfg(any(feature = "sink-jsonl", feature = "sink-file"))]
+impl<W: Write> JsonLinesSink<W> {
+	pub fn new(writer: W) -> Self {
+		Self { writer }
+	}
+
+	pub fn into_inner(self) -> W {
+		self.writer
+	}
+}
+
+#[cfg(any(feature = "sink-jsonl", feature = "sink-file"))]
+impl<W: Write> DetectionSink for JsonLinesSink<W> {
+	fn push(&mut self, event: &DetectionEvent) -> Result<()> {
+		let mut line = event.to_json();
+		line.push('\n');
+		self.writer.write_all(line.as_bytes()).map_err(io_error)
+	}
+
+	fn flush(&mut self) -> Result<()> {
+		self.writer.flush().map_err(io_error)
+	}
+}
+
+/// Sink that writes the events as JSON Lines into a file
+#[cfg(feature = "sink-file")]
+pub type FileSink = JsonLinesSink<BufWriter<File>>;
+
+#[cfg(feature = "sink-file")]
+impl JsonLinesSink<BufWriter<File>> {
+	/// Creates the file sink, the existing file is truncated
+	pub fn create(path: impl AsRef<Path>) -> Result<Self> {
+		File::create(path).map(|f| Self::new(BufWriter::new(f))).map_err(io_error)
+	}
+
+	/// Creates the file sink that appends to the existing file
+	pub fn append(path: impl AsRef<Path>) -> Result<Self> {
+		OpenOptions::new()
+			.create(true)
+			.append(true)
+			.open(path)
+			.map(|f| Self::new(BufWriter::new(f)))
+			.map_err(io_error)
+	}
+}
+
+/// Sink that publishes the events as JSON objects to an MQTT topic
+///
+/// The event loop of the passed client (`rumqttc::Connection`) must be polled by the caller, e.g. in a separate
+/// thread, for the messages to be actually sent.
+#[cfg(feature = "sink-mqtt")]
+pub struct MqttSink {
+	client: rumqttc::Client,
+	topic: String,
+	qos: rumqttc::QoS,
+}
+
+#[cfg(feature = "sink-mqtt")]
+impl MqttSink {
+	/// Creates the sink publishing to `topic` with at-least-once delivery
+	pub fn new(client: rumqttc::Client, topic: impl Into<String>) -> Self {
+		Self::with_qos(client, topic, rumqttc::QoS::AtLeastOnce)
+	}
+
+	pub fn with_qos(client: rumqttc::Client, topic: impl Into<String>, qos: rumqttc::QoS) -> Self {
+		Self {
+			client,
+			topic: topic.into(),
+			qos,
+		}
+	}
+}
+
+#[cfg(feature = "sink-mqtt")]
+impl DetectionSink for MqttSink {
+	fn push(&mut self, event: &DetectionEvent) -> Result<()> {
+		self
+			.client
+			.publish(self.topic.as_str(), self.qos, false, event.to_json().into_bytes())
+			.map_err(|e| Error::new(core::StsError, format!("Can't publish MQTT message: {}", e)))
+	}
+}
+
+#[cfg(any(feature = "sink-stdout", feature = "sink-jsonl", feature = "sink-file"))]
+fn io_error(e: io::Error) -> Error {
+	Error::new(core::StsError, format!("Can't write event: {}", e))
+}
+
+/// JSON doesn't support non-finite numbers, they are represented as `null`
+fn json_number<T: Into<f64> + fmt::Display + Copy>(val: T) -> String {
+	if val.into().is_finite() {
+		val.to_string()
+	} else {
+		"null".to_string()
+	}
+}
+
+fn write_string(out: &mut String, s: &str) {
+	out.push('"');
+	for c in s.chars() {
+		match c {
+			'"' => out.push_str("\\\""),
+			'\\' => out.push_str("\\\\"),
+			'\n' => out.push_str("\\n"),
+			'\r' => out.push_str("\\r"),
+			'\t' => out.push_str("\\t"),
+			c if (c as u32) < 0x20 => {
+				let _ = write!(out, "\\u{:04x}", c as u32);
+			}
+			c => out.push(c),
+		}
+	}
+	out.push('"');
+}
+
+fn write_point(out: &mut String, p: Point2f) {
+	let _ = write!(out, "[{},{}]", json_number(p.x), json_number(p.y));
+}
+
+fn write_keyline(out: &mut String, keyline: &KeyLine) {
+	out.push_str("{\"start\":");
+	write_point(out, Point2f::new(keyline.start_point_x, keyline.start_point_y));
+	out.push_str(",\"end\":");
+	write_point(out, Point2f::new(keyline.end_point_x, keyline.end_point_y));
+	let _ = write!(
+		out,
+		",\"angle\":{},\"response\":{}}}",
+		json_number(keyline.angle),
+		json_number(keyline.response)
+	);
+}
@@ -0,0 +1,95 @@
+use super::draw::check_image;
+use super::match_layout::{to_bgr, to_point};
+use crate::{
+	core::{self, Mat, Point, Point2f, Scalar},
+	imgproc::{self, Palette},
+	line_descriptor::KeyLine,
+	Error, Result,
+};
+
+/// Length of the arrow heads in pixels
+const ARROW_TIP_LENGTH: f32 = 8.;
+
+/// Appearance of a single keyline drawn by [draw_keylines_styled]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LineStyle {
+	/// BGR color of the line and its label, the accent color of the global palette by default
+	pub color: Scalar,
+	/// Thickness of the line in pixels, must be positive
+	pub thickness: i32,
+	/// Draw an arrow head at the end point to show the direction of the keyline
+	pub arrow: bool,
+	/// Draw the index of the keyline in the slice next to its midpoint
+	pub label: bool,
+}
+
+impl Default for LineStyle {
+	fn default() -> Self {
+		Self {
+			color: Palette::global().accent(),
+			thickness: 1,
+			arrow: false,
+			label: false,
+		}
+	}
+}
+
+impl LineStyle {
+	/// Style with the default settings and the specified color
+	pub fn with_color(color: Scalar) -> Self {
+		Self {
+			color,
+			..Self::default()
+		}
+	}
+}
+
+/// Draws the keylines with the individual styles
+///
+/// Unlike `draw_keylines`, which uses a single color for all the lines, every keyline is drawn with its own color and
+/// thickness, optionally with an arrow head showing its direction and its index in `keylines` as a label. `out_image`
+/// receives a BGR copy of `image`, which must be `CV_8U` with 1 or 3 channels.
+pub fn draw_keylines_styled(image: &Mat, keylines: &[(KeyLine, LineStyle)], out_image: &mut Mat) -> Result<()> {
+	check_image("Input", image)?;
+	if let Some((i, (_, style))) = keylines.iter().enumerate().find(|(_, (_, style))| style.thickness <= 0) {
+		return Err(Error::new(
+			core::StsBadArg,
+			format!("Line thickness must be positive, but keyline {} has: {}", i, style.thickness),
+		));
+	}
+	*out_image = to_bgr(image)?;
+	for (i, (keyline, style)) in keylines.iter().enumerate() {
+		let (start, end) = keyline.as_segment();
+		let (pt1, pt2) = (to_point(start, Point2f::default()), to_point(end, Point2f::default()));
+		if style.arrow {
+			let tip_length = (ARROW_TIP_LENGTH / keyline.length().max(ARROW_TIP_LENGTH)).min(0.5);
+			imgproc::arrowed_line(
+				out_image,
+				pt1,
+				pt2,
+				style.color,
+				style.thickness,
+				imgproc::LINE_AA,
+				0,
+				f64::from(tip_length),
+			)?;
+		} else {
+			imgproc::line(out_image, pt1, pt2, style.color, style.thickness, imgproc::LINE_AA, 0)?;
+		}
+		if style.label {
+			let mid = to_point((start + end) * 0.5, Point2f::default());
+			imgproc::put_text(
+				out_image,
+				&i.to_string(),
+				mid + Point::new(style.thickness + 2, -(style.thickness + 2)),
+				imgproc::FONT_HERSHEY_SIMPLEX,
+				0.4,
+				style.color,
+				1,
+				imgproc::LINE_AA,
+				false,
+			)?;
+		}
+	}
+	Ok(())
+}
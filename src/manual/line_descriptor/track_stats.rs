@@ -0,0 +1,195 @@
+use std::collections::VecDeque;
+
+use super::linalg::solve;
+use crate::{core::Point2d, line_descriptor::KeyLine};
+
+/// Single observation of the track with the unwrapped orientation
+#[derive(Copy, Clone, Debug)]
+struct Sample {
+	t: f64,
+	x: f64,
+	y: f64,
+	orientation: f64,
+}
+
+/// Rolling kinematic statistics of a single track
+///
+/// The statistics are updated with every observation and are estimated from the last `window` observations by the
+/// least squares fit of a quadratic motion model (linear one when there are only 2 observations), so they are
+/// resistant to the per-frame detection jitter. Orientation is unwrapped between the observations, so the rotation
+/// rate and the drift are not disturbed when the angle wraps around.
+///
+/// Timestamps can be in any units (frame indices, seconds), the rates are expressed per the same unit.
+#[derive(Clone, Debug)]
+pub struct TrackStats {
+	window: usize,
+	orientation_period: f64,
+	samples: VecDeque<Sample>,
+	observations: usize,
+	first_orientation: f64,
+	velocity: Option<Point2d>,
+	acceleration: Option<Point2d>,
+	orientation_rate: Option<f64>,
+}
+
+impl TrackStats {
+	/// Creates empty statistics
+	///
+	/// ## Parameters
+	/// * window: number of the most recent observations the estimates are based on, at least 2 are used
+	/// * orientation_period: period of the orientation angle in radians, 2π for the directed objects and π for the
+	///   undirected ones like keylines
+	pub fn new(window: usize, orientation_period: f64) -> Self {
+		Self {
+			window: window.max(2),
+			orientation_period,
+			samples: VecDeque::with_capacity(window.max(2)),
+			observations: 0,
+			first_orientation: 0.,
+			velocity: None,
+			acceleration: None,
+			orientation_rate: None,
+		}
+	}
+
+	/// Adds the observation of the track
+	///
+	/// An observation with the timestamp that is not greater than the one of the previous observation replaces it.
+	pub fn update(&mut self, timestamp: f64, position: Point2d, orientation: f64) {
+		let orientation = match self.samples.back() {
+			Some(last) => {
+				let mut diff = (orientation - last.orientation).rem_euclid(self.orientation_period);
+				if diff > self.orientation_period / 2. {
+					diff -= self.orientation_period;
+				}
+				last.orientation + diff
+			}
+			None => orientation,
+		};
+		if matches!(self.samples.back(), Some(last) if timestamp <= last.t) {
+			self.samples.pop_back();
+		} else {
+			self.observations += 1;
+		}
+		if self.observations == 1 {
+			self.first_orientation = orientation;
+		}
+		if self.samples.len() == self.window {
+			self.samples.pop_front();
+		}
+		self.samples.push_back(Sample {
+			t: timestamp,
+			x: position.x,
+			y: position.y,
+			orientation,
+		});
+		self.estimate();
+	}
+
+	/// Adds the observation of the keyline track, the midpoint of the keyline is used as its position
+	pub fn update_keyline(&mut self, timestamp: f64, keyline: &KeyLine) {
+		self.update(
+			timestamp,
+			Point2d::new(f64::from(keyline.pt.x), f64::from(keyline.pt.y)),
+			f64::from(keyline.angle),
+		);
+	}
+
+	/// Total number of the observations since the track start
+	pub fn observations(&self) -> usize {
+		self.observations
+	}
+
+	/// Timestamp of the latest observation
+	pub fn timestamp(&self) -> Option<f64> {
+		self.samples.back().map(|s| s.t)
+	}
+
+	/// Position from the latest observation
+	pub fn position(&self) -> Option<Point2d> {
+		self.samples.back().map(|s| Point2d::new(s.x, s.y))
+	}
+
+	/// Velocity at the latest observation, requires at least 2 observations
+	pub fn velocity(&self) -> Option<Point2d> {
+		self.velocity
+	}
+
+	/// Magnitude of the velocity
+	pub fn speed(&self) -> Option<f64> {
+		self.velocity.map(|v| v.x.hypot(v.y))
+	}
+
+	/// Direction of the motion in radians, `None` when the track doesn't move
+	pub fn heading(&self) -> Option<f64> {
+		self.velocity.filter(|v| v.x != 0. || v.y != 0.).map(|v| v.y.atan2(v.x))
+	}
+
+	/// Acceleration at the latest observation, requires at least 3 observations
+	pub fn acceleration(&self) -> Option<Point2d> {
+		self.acceleration
+	}
+
+	/// Rate of the orientation change in radians per timestamp unit, requires at least 2 observations
+	pub fn orientation_rate(&self) -> Option<f64> {
+		self.orientation_rate
+	}
+
+	/// Total orientation change in radians since the track start, positive in the direction of the increasing angle
+	pub fn orientation_drift(&self) -> f64 {
+		self.samples.back().map_or(0., |s| s.orientation - self.first_orientation)
+	}
+
+	fn estimate(&mut self) {
+		let last_t = match self.samples.back() {
+			Some(last) => last.t,
+			None => return,
+		};
+		// time relative to the latest observation, so that the fitted derivatives are the ones at that observation
+		let ts = self.samples.iter().map(|s| s.t - last_t).collect::<Vec<_>>();
+		let fit = |values: &[f64]| {
+			if values.len() >= 3 {
+				if let Some([_, slope, curvature]) = fit_polynomial::<3>(&ts, values) {
+					return Some((slope, Some(2. * curvature)));
+				}
+			}
+			fit_polynomial::<2>(&ts, values).map(|[_, slope]| (slope, None))
+		};
+		let xs = self.samples.iter().map(|s| s.x).collect::<Vec<_>>();
+		let ys = self.samples.iter().map(|s| s.y).collect::<Vec<_>>();
+		let orientations = self.samples.iter().map(|s| s.orientation).collect::<Vec<_>>();
+		match (fit(&xs), fit(&ys)) {
+			(Some((vx, ax)), Some((vy, ay))) => {
+				self.velocity = Some(Point2d::new(vx, vy));
+				self.acceleration = ax.zip(ay).map(|(ax, ay)| Point2d::new(ax, ay));
+			}
+			_ => {
+				self.velocity = None;
+				self.acceleration = None;
+			}
+		}
+		self.orientation_rate = fit_polynomial::<2>(&ts, &orientations).map(|[_, slope]| slope);
+	}
+}
+
+/// Least squares fit of the polynomial of degree `N - 1`, returns the coefficients starting from the constant one
+fn fit_polynomial<const N: usize>(ts: &[f64], values: &[f64]) -> Option<[f64; N]> {
+	if ts.len() < N {
+		return None;
+	}
+	let mut a = [[0.; N]; N];
+	let mut b = [0.; N];
+	for (&t, &v) in ts.iter().zip(values) {
+		let mut powers = [1.; N];
+		for i in 1..N {
+			powers[i] = powers[i - 1] * t;
+		}
+		for (i, row) in a.iter_mut().enumerate() {
+			for (j, val) in row.iter_mut().enumerate() {
+				*val += powers[i] * powers[j];
+			}
+			b[i] += powers[i] * v;
+		}
+	}
+	solve(a, b)
+}
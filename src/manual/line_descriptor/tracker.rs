@@ -0,0 +1,256 @@
+use super::{
+	propagation::{assign_unique, check_descriptors, undirected_angle_diff},
+	rerank::{convert_descriptors, descriptor_depth, row_distance},
+};
+use crate::{
+	core::{self, DMatch, Mat, Point2f, Ptr},
+	line_descriptor::{BinaryDescriptor, BinaryDescriptorTraitConstManual, DetectionEvent, DetectionSink, KeyLine},
+	prelude::*,
+	Result,
+};
+
+/// Parameters of [LineTracker]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LineTrackerParams {
+	/// Norm to compare the descriptors with, one of `core::NORM_HAMMING`, `core::NORM_L1`, `core::NORM_L2`,
+	/// `core::NORM_L2SQR`
+	pub norm_type: i32,
+	/// Maximum descriptor distance between the track and the keyline matched to it
+	pub max_distance: f32,
+	/// Radius in pixels of the window around the predicted track midpoint that is searched for the match
+	pub search_radius: f32,
+	/// Maximum difference in radians between the track and the candidate keyline directions, the keylines are treated
+	/// as undirected
+	pub max_angle_diff: f64,
+	/// Number of the consecutive frames a track can stay unmatched before it's dropped
+	pub max_missed: usize,
+	/// Number of the matched frames after which the new track is confirmed and reported
+	pub min_hits: usize,
+}
+
+impl Default for LineTrackerParams {
+	fn default() -> Self {
+		Self {
+			norm_type: core::NORM_HAMMING,
+			max_distance: 40.,
+			search_radius: 30.,
+			max_angle_diff: 15f64.to_radians(),
+			max_missed: 3,
+			min_hits: 2,
+		}
+	}
+}
+
+/// State of the tracked line returned by [LineTracker::update]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LineTrack {
+	/// Identifier of the track, unique for the lifetime of the tracker
+	pub id: u64,
+	/// Last observation of the line
+	pub keyline: KeyLine,
+	/// Index of the keyline in the last frame, `None` if the track was not matched in that frame
+	pub keyline_idx: Option<usize>,
+	/// Displacement of the midpoint per frame
+	pub velocity: Point2f,
+	/// Number of the frames since the track was started
+	pub age: usize,
+	/// Number of the frames the track was matched in
+	pub hits: usize,
+	/// Number of the consecutive frames the track was not matched in
+	pub missed: usize,
+}
+
+struct Track {
+	state: LineTrack,
+	/// Descriptor of the last observation, a single row
+	descriptor: Mat,
+}
+
+/// Tracking of the lines across the video frames
+///
+/// Every frame the keylines are matched with the live tracks by their LBD descriptors. Only the keylines in the window
+/// around the midpoint predicted by the constant velocity model and with the similar direction are compared with the
+/// track, and every keyline is assigned to at most one track starting from the closest pairs. The unmatched keylines
+/// start new tracks, which are reported once they're matched in `min_hits` frames. The tracks not matched for more than
+/// `max_missed` consecutive frames are dropped.
+pub struct LineTracker {
+	pub params: LineTrackerParams,
+	detector: Ptr<BinaryDescriptor>,
+	tracks: Vec<Track>,
+	next_id: u64,
+	frame: usize,
+}
+
+impl LineTracker {
+	/// Creates the tracker detecting the keylines with the default `BinaryDescriptor`
+	pub fn new(params: LineTrackerParams) -> Result<Self> {
+		core::ensure_module_available("line_descriptor")?;
+		Ok(Self::with_detector(BinaryDescriptor::create_binary_descriptor()?, params))
+	}
+
+	/// Creates the tracker detecting the keylines with the passed `BinaryDescriptor`
+	pub fn with_detector(detector: Ptr<BinaryDescriptor>, params: LineTrackerParams) -> Self {
+		Self {
+			params,
+			detector,
+			tracks: vec![],
+			next_id: 0,
+			frame: 0,
+		}
+	}
+
+	/// Number of the frames processed so far
+	pub fn frame(&self) -> usize {
+		self.frame
+	}
+
+	/// All the live tracks including the unconfirmed ones
+	pub fn tracks(&self) -> impl Iterator<Item = &LineTrack> {
+		self.tracks.iter().map(|t| &t.state)
+	}
+
+	/// Drops all the tracks, the identifiers of the new tracks keep increasing
+	pub fn reset(&mut self) {
+		self.tracks.clear();
+		self.frame = 0;
+	}
+
+	/// Detects the keylines in the next frame and updates the tracks
+	///
+	/// Returns the confirmed tracks, including the ones not matched in this frame but not dropped yet.
+	pub fn update(&mut self, image: &Mat) -> Result<Vec<LineTrack>> {
+		let (keylines, descriptors) = self.detector.detect_and_compute(image, None)?;
+		self.update_keylines(&keylines.to_vec(), &descriptors)
+	}
+
+	/// Updates the tracks with the keylines detected in the next frame by an external detector
+	///
+	/// ## Parameters
+	/// * keylines: keylines detected in the frame
+	/// * descriptors: descriptors of the `keylines`, one per row
+	pub fn update_keylines(&mut self, keylines: &[KeyLine], descriptors: &Mat) -> Result<Vec<LineTrack>> {
+		self.update_keylines_with_sink(keylines, descriptors, self.frame as f64, &mut Vec::<DetectionEvent>::new())
+	}
+
+	/// Same as [update_keylines](Self::update_keylines), but also pushes `DetectionEvent::LineAppeared` when a track is
+	/// confirmed and `DetectionEvent::TrackLost` when a confirmed track is dropped
+	pub fn update_keylines_with_sink(
+		&mut self,
+		keylines: &[KeyLine],
+		descriptors: &Mat,
+		timestamp: f64,
+		sink: &mut impl DetectionSink,
+	) -> Result<Vec<LineTrack>> {
+		check_descriptors(keylines, descriptors)?;
+		let distance = row_distance(self.params.norm_type)?;
+		let converted = convert_descriptors(descriptors, descriptor_depth(self.params.norm_type))?;
+		let descriptors = converted.as_ref().unwrap_or(descriptors);
+
+		let radius = self.params.search_radius;
+		let mut proposals = vec![];
+		for (track_idx, track) in self.tracks.iter().enumerate() {
+			let steps = (track.state.missed + 1) as f32;
+			let predicted = Point2f::new(
+				track.state.keyline.pt.x + track.state.velocity.x * steps,
+				track.state.keyline.pt.y + track.state.velocity.y * steps,
+			);
+			for (query_idx, kl) in keylines.iter().enumerate() {
+				let (dx, dy) = (kl.pt.x - predicted.x, kl.pt.y - predicted.y);
+				if dx * dx + dy * dy > radius * radius || undirected_angle_diff(kl, &track.state.keyline) > self.params.max_angle_diff
+				{
+					continue;
+				}
+				let dist = distance(descriptors, query_idx as i32, &track.descriptor, 0)?;
+				if dist <= self.params.max_distance {
+					proposals.push(DMatch {
+						query_idx: query_idx as i32,
+						train_idx: track_idx as i32,
+						img_idx: 0,
+						distance: dist,
+					});
+				}
+			}
+		}
+		let mut keyline_used = vec![false; keylines.len()];
+		let matches = assign_unique(proposals, &mut keyline_used, &mut vec![false; self.tracks.len()]);
+		let mut matched = vec![None; self.tracks.len()];
+		for m in matches {
+			matched[m.train_idx as usize] = Some(m.query_idx as usize);
+		}
+
+		let min_hits = self.params.min_hits.max(1);
+		let mut tracks = Vec::with_capacity(self.tracks.len());
+		for (mut track, matched) in self.tracks.drain(..).zip(matched) {
+			let state = &mut track.state;
+			state.age += 1;
+			match matched {
+				Some(idx) => {
+					let keyline = keylines[idx];
+					let frames = (state.missed + 1) as f32;
+					state.velocity = Point2f::new(
+						(keyline.pt.x - state.keyline.pt.x) / frames,
+						(keyline.pt.y - state.keyline.pt.y) / frames,
+					);
+					state.keyline = keyline;
+					state.keyline_idx = Some(idx);
+					state.hits += 1;
+					state.missed = 0;
+					track.descriptor = descriptors.row(idx as i32)?.try_clone()?;
+					if state.hits == min_hits {
+						sink.push(&DetectionEvent::LineAppeared {
+							track_id: state.id,
+							timestamp,
+							keyline,
+						})?;
+					}
+				}
+				None => {
+					state.keyline_idx = None;
+					state.missed += 1;
+					if state.missed > self.params.max_missed {
+						if state.hits >= min_hits {
+							sink.push(&DetectionEvent::TrackLost {
+								track_id: state.id,
+								timestamp,
+								keyline: state.keyline,
+							})?;
+						}
+						continue;
+					}
+				}
+			}
+			tracks.push(track);
+		}
+		for (idx, keyline) in keylines.iter().enumerate().filter(|&(idx, _)| !keyline_used[idx]) {
+			let state = LineTrack {
+				id: self.next_id,
+				keyline: *keyline,
+				keyline_idx: Some(idx),
+				velocity: Point2f::default(),
+				age: 1,
+				hits: 1,
+				missed: 0,
+			};
+			self.next_id += 1;
+			if min_hits == 1 {
+				sink.push(&DetectionEvent::LineAppeared {
+					track_id: state.id,
+					timestamp,
+					keyline: *keyline,
+				})?;
+			}
+			tracks.push(Track {
+				state,
+				descriptor: descriptors.row(idx as i32)?.try_clone()?,
+			});
+		}
+		self.tracks = tracks;
+		self.frame += 1;
+		Ok(self
+			.tracks
+			.iter()
+			.filter(|t| t.state.hits >= min_hits)
+			.map(|t| t.state)
+			.collect())
+	}
+}
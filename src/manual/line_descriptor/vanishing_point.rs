@@ -0,0 +1,208 @@
+use super::linalg::{cross, dot, normalize, smallest_eigenvector, Mat3, Vec3};
+use crate::{
+	core::{Point2d, Point3d, Size},
+	line_descriptor::KeyLine,
+};
+
+/// Maximum number of the longest lines that are used to generate vanishing point hypotheses
+const MAX_HYPOTHESIS_LINES: usize = 64;
+/// Lines shorter than this fraction of the image diagonal don't take part in the estimation
+const MIN_LINE_LENGTH_RATIO: f64 = 0.02;
+/// Sine of the maximum angle between a line and the direction towards its vanishing point (2°)
+const MAX_ANGULAR_ERROR_SIN: f64 = 0.0349;
+/// Minimum number of lines that must support a vanishing point
+const MIN_SUPPORT: usize = 3;
+
+/// Vanishing point of a family of keylines
+#[derive(Clone, Debug, PartialEq)]
+pub struct VanishingPoint {
+	/// Homogeneous image coordinates of the point normalized to unit length, `z` is 0 for the points at infinity
+	pub point: Point3d,
+	/// Indices of the keylines converging to this point
+	pub inliers: Vec<usize>,
+}
+
+impl VanishingPoint {
+	/// Returns Euclidean image coordinates of the point or `None` if it lies at infinity
+	pub fn to_point(&self) -> Option<Point2d> {
+		if self.point.z.abs() > 1e-9 {
+			Some(Point2d::new(self.point.x / self.point.z, self.point.y / self.point.z))
+		} else {
+			None
+		}
+	}
+
+	/// Returns true if all the supporting lines are parallel in the image
+	pub fn is_at_infinity(&self) -> bool {
+		self.to_point().is_none()
+	}
+}
+
+/// Line segment in the normalized image coordinates (centered and scaled to roughly [-1; 1])
+pub(crate) struct NormalizedLine {
+	pub line: Vec3,
+	pub mid: [f64; 2],
+	pub dir: [f64; 2],
+	pub length: f64,
+}
+
+/// Transformation between image and normalized coordinates used for numerical stability
+#[derive(Copy, Clone)]
+pub(crate) struct Normalization {
+	cx: f64,
+	cy: f64,
+	scale: f64,
+}
+
+impl Normalization {
+	pub fn new(image_size: Size) -> Self {
+		Self {
+			cx: f64::from(image_size.width) / 2.,
+			cy: f64::from(image_size.height) / 2.,
+			scale: f64::from(image_size.width.max(image_size.height).max(1)) / 2.,
+		}
+	}
+
+	pub fn normalize_point(&self, x: f64, y: f64) -> [f64; 2] {
+		[(x - self.cx) / self.scale, (y - self.cy) / self.scale]
+	}
+
+	pub fn homogeneous_to_image(&self, p: Vec3) -> Vec3 {
+		normalize([self.scale * p[0] + self.cx * p[2], self.scale * p[1] + self.cy * p[2], p[2]])
+	}
+
+	pub fn line(&self, keyline: &KeyLine) -> NormalizedLine {
+		let s = self.normalize_point(f64::from(keyline.start_point_x), f64::from(keyline.start_point_y));
+		let e = self.normalize_point(f64::from(keyline.end_point_x), f64::from(keyline.end_point_y));
+		let (dx, dy) = (e[0] - s[0], e[1] - s[1]);
+		let length = dx.hypot(dy);
+		let line = cross([s[0], s[1], 1.], [e[0], e[1], 1.]);
+		let line_norm = line[0].hypot(line[1]);
+		NormalizedLine {
+			line: if line_norm > 0. {
+				[line[0] / line_norm, line[1] / line_norm, line[2] / line_norm]
+			} else {
+				line
+			},
+			mid: [(s[0] + e[0]) / 2., (s[1] + e[1]) / 2.],
+			dir: if length > 0. {
+				[dx / length, dy / length]
+			} else {
+				[0., 0.]
+			},
+			length,
+		}
+	}
+}
+
+impl NormalizedLine {
+	/// Sine of the angle between the line and the direction from its midpoint towards the point `vp`
+	pub fn angular_error(&self, vp: Vec3) -> f64 {
+		let (dx, dy) = if vp[2].abs() > 1e-9 {
+			(vp[0] / vp[2] - self.mid[0], vp[1] / vp[2] - self.mid[1])
+		} else {
+			(vp[0], vp[1])
+		};
+		let len = dx.hypot(dy);
+		if len > 0. {
+			(self.dir[0] * dy - self.dir[1] * dx).abs() / len
+		} else {
+			1.
+		}
+	}
+}
+
+/// Least squares estimate of the point closest to all of the passed lines
+pub(crate) fn refine_intersection<'l>(lines: impl IntoIterator<Item = &'l NormalizedLine>) -> Vec3 {
+	let mut m: Mat3 = [[0.; 3]; 3];
+	for line in lines {
+		for (i, row) in m.iter_mut().enumerate() {
+			for (j, val) in row.iter_mut().enumerate() {
+				*val += line.length * line.line[i] * line.line[j];
+			}
+		}
+	}
+	smallest_eigenvector(&m)
+}
+
+/// Detects up to `max_count` dominant vanishing points of the passed keylines
+///
+/// Vanishing point hypotheses are generated from the intersections of the longest lines, the one supported by the
+/// largest total line length wins and is then refined by least squares using all of its inliers. The inliers are
+/// removed from consideration and the process is repeated for the next vanishing point. The returned points are
+/// sorted by their support, strongest first.
+///
+/// ## Parameters
+/// * keylines: detected lines, e.g. the output of `LSDDetector::detect()`
+/// * image_size: size of the image the lines were detected in
+/// * max_count: maximum number of vanishing points to return
+pub fn detect_vanishing_points(keylines: &[KeyLine], image_size: Size, max_count: usize) -> Vec<VanishingPoint> {
+	let norm = Normalization::new(image_size);
+	let min_length = MIN_LINE_LENGTH_RATIO * f64::from(image_size.width).hypot(f64::from(image_size.height)) / norm.scale;
+	let lines = keylines.iter().map(|kl| norm.line(kl)).collect::<Vec<_>>();
+	let mut remaining = (0..lines.len())
+		.filter(|&i| lines[i].length >= min_length)
+		.collect::<Vec<_>>();
+	let mut out = Vec::with_capacity(max_count);
+	while out.len() < max_count && remaining.len() >= MIN_SUPPORT {
+		let mut candidates = remaining.clone();
+		candidates.sort_unstable_by(|&a, &b| {
+			lines[b]
+				.length
+				.partial_cmp(&lines[a].length)
+				.unwrap_or(std::cmp::Ordering::Equal)
+		});
+		candidates.truncate(MAX_HYPOTHESIS_LINES);
+		let mut best_score = 0.;
+		let mut best = None;
+		for (n, &i) in candidates.iter().enumerate() {
+			for &j in &candidates[n + 1..] {
+				let vp = cross(lines[i].line, lines[j].line);
+				if dot(vp, vp) < 1e-12 {
+					continue;
+				}
+				let vp = normalize(vp);
+				let score = remaining
+					.iter()
+					.filter(|&&k| lines[k].angular_error(vp) < MAX_ANGULAR_ERROR_SIN)
+					.map(|&k| lines[k].length)
+					.sum::<f64>();
+				if score > best_score {
+					best_score = score;
+					best = Some(vp);
+				}
+			}
+		}
+		let vp = match best {
+			Some(vp) => vp,
+			None => break,
+		};
+		let inliers = remaining
+			.iter()
+			.copied()
+			.filter(|&k| lines[k].angular_error(vp) < MAX_ANGULAR_ERROR_SIN)
+			.collect::<Vec<_>>();
+		let refined = refine_intersection(inliers.iter().map(|&k| &lines[k]));
+		let mut refined_inliers = remaining
+			.iter()
+			.copied()
+			.filter(|&k| lines[k].angular_error(refined) < MAX_ANGULAR_ERROR_SIN)
+			.collect::<Vec<_>>();
+		let vp = if refined_inliers.len() >= inliers.len() {
+			refined
+		} else {
+			refined_inliers = inliers;
+			vp
+		};
+		if refined_inliers.len() < MIN_SUPPORT {
+			break;
+		}
+		remaining.retain(|k| !refined_inliers.contains(k));
+		let p = norm.homogeneous_to_image(vp);
+		out.push(VanishingPoint {
+			point: Point3d::new(p[0], p[1], p[2]),
+			inliers: refined_inliers,
+		});
+	}
+	out
+}
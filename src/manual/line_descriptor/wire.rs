@@ -0,0 +1,294 @@
+use std::cmp::Ordering;
+
+use super::keyline::keyline_from_endpoints;
+use crate::{
+	core::{self, Mat, Point2f, Size, CV_32F},
+	imgproc,
+	line_descriptor::{link_keylines, KeyLine, PolyLine, PolyLineLinkParams},
+	prelude::*,
+	Error, Result,
+};
+
+/// Ridges with the smaller eigenvalue exceeding this fraction of the larger one are considered blobs, not wires
+const MAX_EIGENVALUE_RATIO: f32 = 0.5;
+
+/// Intensity profile of the wires to look for
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WirePolarity {
+	/// Wires darker than the background, e.g. power lines against the sky
+	Dark,
+	/// Wires brighter than the background
+	Bright,
+	/// Both dark and bright wires
+	Both,
+}
+
+/// Parameters of [detect_wires]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct WireDetectorParams {
+	/// Scale of the ridge filter, should be about the half of the expected wire width in pixels
+	pub sigma: f64,
+	/// Minimum scale-normalized ridge response, roughly proportional to the contrast between the wire and the background
+	pub ridge_threshold: f64,
+	pub polarity: WirePolarity,
+	/// Maximum distance in pixels between a ridge pixel and the straight segment approximating it
+	pub max_fit_error: f64,
+	/// Straight segments shorter than this are discarded before linking
+	pub min_segment_length: f64,
+	/// Maximum gap in pixels between the segments that can be linked together
+	pub max_link_gap: f64,
+	/// Maximum direction change in radians between the linked segments
+	pub max_link_angle: f64,
+	/// Wires with the total length of the segments shorter than this are discarded
+	pub min_wire_length: f64,
+}
+
+impl Default for WireDetectorParams {
+	fn default() -> Self {
+		Self {
+			sigma: 1.5,
+			ridge_threshold: 4.,
+			polarity: WirePolarity::Dark,
+			max_fit_error: 1.,
+			min_segment_length: 8.,
+			max_link_gap: 40.,
+			max_link_angle: 10f64.to_radians(),
+			min_wire_length: 40.,
+		}
+	}
+}
+
+/// Wire or cable found by [detect_wires]
+pub type Wire = PolyLine;
+
+/// Detects thin elongated structures like wires and cables
+///
+/// Unlike `LSDDetector` that looks for the edges, this detector looks for the ridges (or valleys) of intensity using
+/// the second derivative of Gaussian filter steered along the local structure direction (eigenanalysis of the
+/// Hessian). The ridge pixels are thinned by non-maximum suppression across the ridge, traced into chains and
+/// approximated by straight segments. Finally the collinear segments separated by small gaps (occlusions, weak
+/// contrast) are linked into polylines.
+pub fn detect_wires(image: &Mat, params: &WireDetectorParams) -> Result<Vec<Wire>> {
+	if image.empty() {
+		return Err(Error::new(core::StsBadArg, "Image is empty"));
+	}
+	if params.sigma <= 0. {
+		return Err(Error::new(
+			core::StsOutOfRange,
+			format!("Ridge filter scale must be positive, but it's: {}", params.sigma),
+		));
+	}
+	let gray = if image.channels() > 1 {
+		let mut gray = Mat::default();
+		let code = if image.channels() == 4 {
+			imgproc::COLOR_BGRA2GRAY
+		} else {
+			imgproc::COLOR_BGR2GRAY
+		};
+		imgproc::cvt_color(image, &mut gray, code, 0)?;
+		gray
+	} else {
+		image.try_clone()?
+	};
+	let mut smoothed = Mat::default();
+	gray.convert_to(&mut smoothed, CV_32F, 1., 0.)?;
+	let mut blurred = Mat::default();
+	imgproc::gaussian_blur(
+		&smoothed,
+		&mut blurred,
+		Size::default(),
+		params.sigma,
+		params.sigma,
+		core::BORDER_REFLECT,
+	)?;
+	// γ-normalization with γ = 2 makes the response independent of the filter scale
+	let norm = params.sigma * params.sigma;
+	let mut dxx = Mat::default();
+	let mut dyy = Mat::default();
+	let mut dxy = Mat::default();
+	imgproc::sobel(&blurred, &mut dxx, CV_32F, 2, 0, 3, norm, 0., core::BORDER_REFLECT)?;
+	imgproc::sobel(&blurred, &mut dyy, CV_32F, 0, 2, 3, norm, 0., core::BORDER_REFLECT)?;
+	imgproc::sobel(&blurred, &mut dxy, CV_32F, 1, 1, 3, norm, 0., core::BORDER_REFLECT)?;
+	let ridges = RidgeMap::new(
+		gray.cols() as usize,
+		gray.rows() as usize,
+		dxx.data_typed::<f32>()?,
+		dyy.data_typed::<f32>()?,
+		dxy.data_typed::<f32>()?,
+		params,
+	);
+	let segments = ridges
+		.trace_chains(params.min_segment_length)
+		.iter()
+		.flat_map(|chain| simplify(chain, params.max_fit_error))
+		.filter(|kl| f64::from(kl.line_length) >= params.min_segment_length)
+		.collect::<Vec<_>>();
+	Ok(link_keylines(
+		&segments,
+		&PolyLineLinkParams {
+			max_gap: params.max_link_gap,
+			max_angle: params.max_link_angle,
+			min_length: params.min_wire_length,
+		},
+	))
+}
+
+/// Thinned ridge pixels with the ridge normals
+struct RidgeMap {
+	width: usize,
+	height: usize,
+	ridge: Vec<bool>,
+}
+
+impl RidgeMap {
+	fn new(width: usize, height: usize, dxx: &[f32], dyy: &[f32], dxy: &[f32], params: &WireDetectorParams) -> Self {
+		let mut response = vec![0f32; width * height];
+		let mut normal = vec![[0f32; 2]; width * height];
+		for i in 0..width * height {
+			let (a, c, b) = (dxx[i], dyy[i], dxy[i]);
+			let mean = (a + c) / 2.;
+			let dev = ((a - c) / 2.).hypot(b);
+			let (hi, lo) = (mean + dev, mean - dev);
+			// valley (dark wire) has the large positive curvature across it, ridge (bright wire) the negative one
+			let (strength, other, eigenvalue) = match params.polarity {
+				WirePolarity::Dark => (hi, lo, hi),
+				WirePolarity::Bright => (-lo, hi, lo),
+				WirePolarity::Both if hi.abs() >= lo.abs() => (hi, lo, hi),
+				WirePolarity::Both => (-lo, hi, lo),
+			};
+			if strength <= 0. || other.abs() > MAX_EIGENVALUE_RATIO * strength {
+				continue;
+			}
+			response[i] = strength;
+			normal[i] = if b.abs() > f32::EPSILON {
+				let (nx, ny) = (b, eigenvalue - a);
+				let len = nx.hypot(ny);
+				[nx / len, ny / len]
+			} else if (eigenvalue - a).abs() <= (eigenvalue - c).abs() {
+				[1., 0.]
+			} else {
+				[0., 1.]
+			};
+		}
+		let threshold = params.ridge_threshold as f32;
+		let at = |x: isize, y: isize| {
+			if x >= 0 && y >= 0 && (x as usize) < width && (y as usize) < height {
+				response[y as usize * width + x as usize]
+			} else {
+				0.
+			}
+		};
+		let mut ridge = vec![false; width * height];
+		for y in 0..height {
+			for x in 0..width {
+				let i = y * width + x;
+				if response[i] < threshold {
+					continue;
+				}
+				let (dx, dy) = (normal[i][0].round() as isize, normal[i][1].round() as isize);
+				let (x, y) = (x as isize, y as isize);
+				// strict comparison on one side only keeps exactly one pixel of a plateau
+				ridge[i] = response[i] > at(x - dx, y - dy) && response[i] >= at(x + dx, y + dy);
+			}
+		}
+		Self { width, height, ridge }
+	}
+
+	fn neighbours(&self, idx: usize) -> impl Iterator<Item = usize> + '_ {
+		let (x, y) = ((idx % self.width) as isize, (idx / self.width) as isize);
+		(-1..=1)
+			.flat_map(move |dy| (-1..=1).map(move |dx| (x + dx, y + dy)))
+			.filter(move |&(nx, ny)| {
+				(nx, ny) != (x, y) && nx >= 0 && ny >= 0 && (nx as usize) < self.width && (ny as usize) < self.height
+			})
+			.map(move |(nx, ny)| ny as usize * self.width + nx as usize)
+			.filter(move |&n| self.ridge[n])
+	}
+
+	/// Traces 8-connected ridge pixels into chains preferring the straight continuation at the junctions
+	fn trace_chains(&self, min_length: f64) -> Vec<Vec<Point2f>> {
+		let mut visited = vec![false; self.ridge.len()];
+		let mut out = vec![];
+		let endpoints = (0..self.ridge.len()).filter(|&i| self.ridge[i] && self.neighbours(i).count() <= 1);
+		let starts = endpoints
+			.chain((0..self.ridge.len()).filter(|&i| self.ridge[i]))
+			.collect::<Vec<_>>();
+		for start in starts {
+			if visited[start] {
+				continue;
+			}
+			visited[start] = true;
+			let mut chain = vec![start];
+			let mut cur = start;
+			let mut dir: Option<(f64, f64)> = None;
+			loop {
+				let (cx, cy) = ((cur % self.width) as f64, (cur / self.width) as f64);
+				let step = |n: usize| {
+					let (dx, dy) = ((n % self.width) as f64 - cx, (n / self.width) as f64 - cy);
+					let len = dx.hypot(dy);
+					(dx / len, dy / len)
+				};
+				let next = self
+					.neighbours(cur)
+					.filter(|&n| !visited[n])
+					.map(|n| {
+						let (dx, dy) = step(n);
+						// prefer the straight continuation, without the direction prefer the 4-connected neighbours
+						let score = dir.map_or(-dx.abs() * dy.abs(), |(px, py)| px * dx + py * dy);
+						(n, score)
+					})
+					.max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal));
+				match next {
+					Some((n, _)) => {
+						visited[n] = true;
+						dir = Some(step(n));
+						chain.push(n);
+						cur = n;
+					}
+					None => break,
+				}
+			}
+			if chain.len() as f64 >= min_length {
+				out.push(
+					chain
+						.into_iter()
+						.map(|i| Point2f::new((i % self.width) as f32, (i / self.width) as f32))
+						.collect(),
+				);
+			}
+		}
+		out
+	}
+}
+
+/// Distance from `p` to the segment `a`-`b`
+fn segment_distance(p: Point2f, a: Point2f, b: Point2f) -> f64 {
+	let (dx, dy) = (f64::from(b.x - a.x), f64::from(b.y - a.y));
+	let len_sqr = dx * dx + dy * dy;
+	let (px, py) = (f64::from(p.x - a.x), f64::from(p.y - a.y));
+	if len_sqr <= f64::EPSILON {
+		return px.hypot(py);
+	}
+	let t = ((px * dx + py * dy) / len_sqr).clamp(0., 1.);
+	(px - t * dx).hypot(py - t * dy)
+}
+
+/// Douglas-Peucker approximation of the chain by straight segments
+fn simplify(chain: &[Point2f], max_error: f64) -> Vec<KeyLine> {
+	let mut out = vec![];
+	let mut stack = vec![(0, chain.len() - 1)];
+	while let Some((first, last)) = stack.pop() {
+		let farthest = (first + 1..last)
+			.map(|i| (i, segment_distance(chain[i], chain[first], chain[last])))
+			.max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal));
+		match farthest {
+			Some((i, dist)) if dist > max_error => {
+				// the pushing order keeps the segments ordered along the chain
+				stack.push((i, last));
+				stack.push((first, i));
+			}
+			_ => out.push(keyline_from_endpoints(chain[first], chain[last])),
+		}
+	}
+	out
+}
@@ -0,0 +1,256 @@
+use std::convert::TryFrom;
+
+use super::keyline::keyline_from_endpoints;
+use crate::{
+	core::{self, Mat, Point2f, Ptr, Size, Vec4f, Vec6d, Vector},
+	line_descriptor::KeyLine,
+	prelude::*,
+	ximgproc::{self, EdgeDrawing, EdgeDrawing_GradientOperator, EdgeDrawing_Params, FastLineDetector},
+	Error, Result,
+};
+
+/// Converts the segment `(x1, y1, x2, y2)` as returned by `FastLineDetector` or `LineSegmentDetector` into the keyline
+/// in the octave 0
+pub fn keyline_from_segment(segment: Vec4f) -> KeyLine {
+	keyline_from_endpoints(Point2f::new(segment[0], segment[1]), Point2f::new(segment[2], segment[3]))
+}
+
+/// Converts the segments detected in the image of `image_size` into the keylines
+///
+/// The keylines are filled the same way as by `BinaryDescriptor::detect()`: `class_id` is the index of the segment and
+/// `response` is the length of the segment relative to the larger side of the image.
+pub fn keylines_from_segments(segments: &Vector<Vec4f>, image_size: Size) -> Vector<KeyLine> {
+	let max_side = image_size.width.max(image_size.height).max(1) as f32;
+	segments
+		.iter()
+		.enumerate()
+		.map(|(i, segment)| {
+			let kl = keyline_from_segment(segment);
+			KeyLine {
+				class_id: i as i32,
+				response: kl.line_length / max_side,
+				..kl
+			}
+		})
+		.collect()
+}
+
+/// Converts the keylines into the segments `(x1, y1, x2, y2)` in the original image coordinates, e.g. for
+/// `FastLineDetector::draw_segments()`
+pub fn keylines_to_segments(keylines: &Vector<KeyLine>) -> Vector<Vec4f> {
+	keylines
+		.iter()
+		.map(|kl| Vec4f::from([kl.start_point_x, kl.start_point_y, kl.end_point_x, kl.end_point_y]))
+		.collect()
+}
+
+/// Detection of the keylines with `FastLineDetector`, a much faster alternative to `LSDDetector`
+pub trait FastLineDetectorKeyLineTrait: FastLineDetector {
+	/// Detects the segments in the image and returns them as the keylines, see [keylines_from_segments]
+	///
+	/// The keylines can be described by `BinaryDescriptor::compute()` the same way as the ones detected by
+	/// `BinaryDescriptor` itself.
+	fn detect_keylines(&mut self, image: &Mat) -> Result<Vector<KeyLine>> {
+		let mut segments = Vector::<Vec4f>::new();
+		self.detect(image, &mut segments)?;
+		Ok(keylines_from_segments(&segments, image.size()?))
+	}
+}
+
+impl<T: FastLineDetector + ?Sized> FastLineDetectorKeyLineTrait for T {}
+
+/// Typed version of `EdgeDrawing_Params` with the gradient operator as an enum
+///
+/// The `Default` values are the same as in OpenCV and select the EDLines mode, use [EdgeDrawingParams::parameter_free] for
+/// EDPF.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct EdgeDrawingParams {
+	/// Parameter free mode (EDPF), the gradient and anchor thresholds are ignored and the edge segments are validated
+	/// with NFA instead
+	pub pf_mode: bool,
+	pub edge_detection_operator: EdgeDrawing_GradientOperator,
+	pub gradient_threshold_value: i32,
+	pub anchor_threshold_value: i32,
+	pub scan_interval: i32,
+	pub min_path_length: i32,
+	pub sigma: f32,
+	pub sum_flag: bool,
+	pub nfa_validation: bool,
+	/// Minimum length of the detected line, -1 selects it automatically based on the image size
+	pub min_line_length: i32,
+	pub max_distance_between_two_lines: f64,
+	pub line_fit_error_threshold: f64,
+	pub max_error_threshold: f64,
+}
+
+impl EdgeDrawingParams {
+	/// Default parameters with the parameter free mode (EDPF) enabled
+	pub fn parameter_free() -> Self {
+		Self {
+			pf_mode: true,
+			..Self::default()
+		}
+	}
+
+	/// Checks that the parameters are in the ranges accepted by `EdgeDrawing`
+	pub fn validate(&self) -> Result<()> {
+		let check = |valid: bool, msg: &str| {
+			if valid {
+				Ok(())
+			} else {
+				Err(Error::new(core::StsOutOfRange, msg.to_string()))
+			}
+		};
+		check(
+			self.gradient_threshold_value >= 0,
+			"gradient_threshold_value must not be negative",
+		)?;
+		check(
+			self.anchor_threshold_value >= 0,
+			"anchor_threshold_value must not be negative",
+		)?;
+		check(self.scan_interval >= 1, "scan_interval must be at least 1")?;
+		check(self.min_path_length >= 1, "min_path_length must be at least 1")?;
+		check(self.sigma > 0., "sigma must be positive")?;
+		check(
+			self.min_line_length == -1 || self.min_line_length >= 1,
+			"min_line_length must be -1 or positive",
+		)?;
+		check(
+			self.max_distance_between_two_lines >= 0.,
+			"max_distance_between_two_lines must not be negative",
+		)?;
+		check(
+			self.line_fit_error_threshold > 0.,
+			"line_fit_error_threshold must be positive",
+		)?;
+		check(self.max_error_threshold > 0., "max_error_threshold must be positive")
+	}
+}
+
+impl Default for EdgeDrawingParams {
+	fn default() -> Self {
+		Self {
+			pf_mode: false,
+			edge_detection_operator: EdgeDrawing_GradientOperator::PREWITT,
+			gradient_threshold_value: 20,
+			anchor_threshold_value: 0,
+			scan_interval: 1,
+			min_path_length: 10,
+			sigma: 1.,
+			sum_flag: true,
+			nfa_validation: true,
+			min_line_length: -1,
+			max_distance_between_two_lines: 6.,
+			line_fit_error_threshold: 1.,
+			max_error_threshold: 1.3,
+		}
+	}
+}
+
+impl From<EdgeDrawingParams> for EdgeDrawing_Params {
+	fn from(params: EdgeDrawingParams) -> Self {
+		Self {
+			p_fmode: params.pf_mode,
+			edge_detection_operator: params.edge_detection_operator as i32,
+			gradient_threshold_value: params.gradient_threshold_value,
+			anchor_threshold_value: params.anchor_threshold_value,
+			scan_interval: params.scan_interval,
+			min_path_length: params.min_path_length,
+			sigma: params.sigma,
+			sum_flag: params.sum_flag,
+			nfa_validation: params.nfa_validation,
+			min_line_length: params.min_line_length,
+			max_distance_between_two_lines: params.max_distance_between_two_lines,
+			line_fit_error_threshold: params.line_fit_error_threshold,
+			max_error_threshold: params.max_error_threshold,
+		}
+	}
+}
+
+impl TryFrom<EdgeDrawing_Params> for EdgeDrawingParams {
+	type Error = Error;
+
+	fn try_from(params: EdgeDrawing_Params) -> Result<Self> {
+		let edge_detection_operator = match params.edge_detection_operator {
+			0 => EdgeDrawing_GradientOperator::PREWITT,
+			1 => EdgeDrawing_GradientOperator::SOBEL,
+			2 => EdgeDrawing_GradientOperator::SCHARR,
+			3 => EdgeDrawing_GradientOperator::LSD,
+			op => {
+				return Err(Error::new(
+					core::StsBadArg,
+					format!("Invalid EdgeDrawing gradient operator: {}", op),
+				))
+			}
+		};
+		Ok(Self {
+			pf_mode: params.p_fmode,
+			edge_detection_operator,
+			gradient_threshold_value: params.gradient_threshold_value,
+			anchor_threshold_value: params.anchor_threshold_value,
+			scan_interval: params.scan_interval,
+			min_path_length: params.min_path_length,
+			sigma: params.sigma,
+			sum_flag: params.sum_flag,
+			nfa_validation: params.nfa_validation,
+			min_line_length: params.min_line_length,
+			max_distance_between_two_lines: params.max_distance_between_two_lines,
+			line_fit_error_threshold: params.line_fit_error_threshold,
+			max_error_threshold: params.max_error_threshold,
+		})
+	}
+}
+
+/// Creates `EdgeDrawing` with the validated `params`
+pub fn create_edge_drawing_with_params(params: EdgeDrawingParams) -> Result<Ptr<dyn EdgeDrawing>> {
+	core::ensure_module_available("ximgproc")?;
+	params.validate()?;
+	let mut out = ximgproc::create_edge_drawing()?;
+	out.set_params(params.into());
+	Ok(out)
+}
+
+/// Detection of the keylines with `EdgeDrawing` (EDLines, EDPF) and of the circles and ellipses (EDCircles)
+///
+/// `BinaryDescriptor` uses its own EDLine detector internally, but it's not accessible from outside, so this is the way
+/// to get the EDLines keylines with the custom parameters.
+pub trait EdgeDrawingKeyLineTrait: EdgeDrawing {
+	/// Current parameters of the detector, see [EdgeDrawingParams]
+	fn edge_drawing_params(&self) -> Result<EdgeDrawingParams> {
+		EdgeDrawingParams::try_from(self.params())
+	}
+
+	/// Validates and sets the parameters of the detector
+	fn set_edge_drawing_params(&mut self, params: EdgeDrawingParams) -> Result<()> {
+		params.validate()?;
+		self.set_params(params.into());
+		Ok(())
+	}
+
+	/// Detects the edges and the lines in the grayscale `image` and returns them as the keylines, see
+	/// [keylines_from_segments]
+	fn detect_keylines(&mut self, image: &Mat) -> Result<Vector<KeyLine>> {
+		self.detect_edges(image)?;
+		let mut segments = Vector::<Vec4f>::new();
+		self.detect_lines(&mut segments)?;
+		Ok(keylines_from_segments(&segments, image.size()?))
+	}
+
+	/// Detects the edges and the circles and ellipses in the grayscale `image`
+	///
+	/// Every element is `(center_x, center_y, radius, 0, 0, 0)` for a circle and `(center_x, center_y, 0, axis_a, axis_b,
+	/// angle)` for an ellipse.
+	fn detect_circles(&mut self, image: &Mat) -> Result<Vec<Vec6d>> {
+		self.detect_edges(image)?;
+		let mut ellipses = Mat::default();
+		self.detect_ellipses(&mut ellipses)?;
+		if ellipses.empty() {
+			Ok(vec![])
+		} else {
+			ellipses.data_typed::<Vec6d>().map(|ellipses| ellipses.to_vec())
+		}
+	}
+}
+
+impl<T: EdgeDrawing + ?Sized> EdgeDrawingKeyLineTrait for T {}
@@ -0,0 +1,319 @@
+use std::collections::HashMap;
+
+use super::keyline::keyline_from_endpoints;
+use crate::{
+	core::Point2f,
+	line_descriptor::{CrossingDirection, DetectionEvent, DetectionSink, KeyLine},
+	Result,
+};
+
+/// Named polygonal area of the image
+#[derive(Clone, Debug, PartialEq)]
+pub struct Zone {
+	pub name: String,
+	/// Vertices of the polygon in order, the polygon is closed implicitly
+	pub polygon: Vec<Point2f>,
+}
+
+impl Zone {
+	pub fn new(name: impl Into<String>, polygon: Vec<Point2f>) -> Self {
+		Self {
+			name: name.into(),
+			polygon,
+		}
+	}
+
+	/// Whether the point lies inside the polygon, the even-odd rule is used for self-intersecting polygons
+	pub fn contains(&self, point: Point2f) -> bool {
+		let mut inside = false;
+		for (a, b) in self.edges() {
+			if (a.y > point.y) != (b.y > point.y) {
+				let x = a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x);
+				if point.x < x {
+					inside = !inside;
+				}
+			}
+		}
+		inside
+	}
+
+	/// Whether any part of the keyline lies inside the polygon
+	pub fn intersects(&self, keyline: &KeyLine) -> bool {
+		let (s, e) = keyline.as_segment();
+		self.contains(s) || self.contains(e) || self.edges().any(|(a, b)| segment_intersection(s, e, a, b).is_some())
+	}
+
+	fn edges(&self) -> impl Iterator<Item = (Point2f, Point2f)> + '_ {
+		self
+			.polygon
+			.iter()
+			.zip(self.polygon.iter().cycle().skip(1))
+			.map(|(&a, &b)| (a, b))
+	}
+}
+
+/// Named directed line segment that counts the tracks crossing it
+///
+/// The direction of the crossing is determined relative to the direction of the line from its start point to the end
+/// point: crossing from the left side to the right one (clockwise in the image coordinates with the y axis pointing
+/// down) is [CrossingDirection::In].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tripwire {
+	pub name: String,
+	pub line: KeyLine,
+}
+
+impl Tripwire {
+	pub fn new(name: impl Into<String>, line: KeyLine) -> Self {
+		Self { name: name.into(), line }
+	}
+
+	/// Creates the tripwire from its endpoints
+	pub fn from_points(name: impl Into<String>, start: Point2f, end: Point2f) -> Self {
+		Self::new(name, keyline_from_endpoints(start, end))
+	}
+
+	/// Signed distance of the point from the tripwire line, positive on the right side
+	pub fn side(&self, point: Point2f) -> f32 {
+		let (s, e) = self.line.as_segment();
+		let (dx, dy) = (e.x - s.x, e.y - s.y);
+		let len = dx.hypot(dy);
+		if len <= f32::EPSILON {
+			return 0.;
+		}
+		(dx * (point.y - s.y) - dy * (point.x - s.x)) / len
+	}
+
+	/// Whether the keyline crosses or touches the tripwire
+	pub fn intersects(&self, keyline: &KeyLine) -> bool {
+		let (s, e) = self.line.as_segment();
+		let (ks, ke) = keyline.as_segment();
+		segment_intersection(s, e, ks, ke).is_some()
+	}
+
+	/// Checks whether the motion from `from` to `to` crosses the tripwire
+	///
+	/// Returns the crossing direction and the crossing point, `None` when the motion doesn't cross the tripwire or
+	/// just touches it.
+	pub fn crossing(&self, from: Point2f, to: Point2f) -> Option<(CrossingDirection, Point2f)> {
+		let (side_from, side_to) = (self.side(from), self.side(to));
+		if side_from == 0. || side_to == 0. || (side_from > 0.) == (side_to > 0.) {
+			return None;
+		}
+		let (s, e) = self.line.as_segment();
+		segment_intersection(from, to, s, e).map(|point| {
+			let direction = if side_to > 0. {
+				CrossingDirection::In
+			} else {
+				CrossingDirection::Out
+			};
+			(direction, point)
+		})
+	}
+}
+
+/// Direction of the crossing and the point where it happened
+type Crossing = (CrossingDirection, Point2f);
+
+/// State of a track relative to a single zone or tripwire
+#[derive(Copy, Clone, Debug, Default)]
+struct RegionState {
+	/// Confirmed inside state for the zones, side for the tripwires (`true` for the right one)
+	confirmed: Option<bool>,
+	/// Observed state that differs from the confirmed one with the number of the consecutive observations and the
+	/// event data captured at the first one of them
+	pending: Option<(bool, usize, Option<Crossing>)>,
+}
+
+impl RegionState {
+	/// Registers the observed state, returns the event data once the change of the state is confirmed
+	fn observe(&mut self, state: bool, debounce: usize, change: impl FnOnce() -> Option<Crossing>) -> Option<Crossing> {
+		let confirmed = match self.confirmed {
+			Some(confirmed) => confirmed,
+			None => {
+				self.confirmed = Some(state);
+				return None;
+			}
+		};
+		if state == confirmed {
+			self.pending = None;
+			return None;
+		}
+		let (count, event) = match self.pending {
+			Some((pending_state, count, event)) if pending_state == state => (count + 1, event),
+			_ => (1, change()),
+		};
+		if count >= debounce {
+			self.confirmed = Some(state);
+			self.pending = None;
+			event
+		} else {
+			self.pending = Some((state, count, event));
+			None
+		}
+	}
+}
+
+#[derive(Clone, Debug, Default)]
+struct TrackState {
+	last_position: Option<Point2f>,
+	zones: Vec<RegionState>,
+	tripwires: Vec<RegionState>,
+}
+
+/// Emits [DetectionEvent::ZoneCrossed] events for the tracks entering and leaving the zones and crossing the
+/// tripwires
+///
+/// Feed the positions of the tracks for every frame with [ZoneAnalytics::update], e.g. from the tracker output. The
+/// change of the track state relative to a zone or a tripwire is reported only after it persists for `debounce`
+/// consecutive observations, this suppresses the repeated events from the tracks jittering around the boundary. The
+/// event carries the timestamp of the observation that confirms the change and the position where the change was first
+/// observed, for the tripwires it's the point where the tripwire was crossed. Tracks passing around the ends of a
+/// tripwire don't produce events.
+#[derive(Clone, Debug)]
+pub struct ZoneAnalytics {
+	zones: Vec<Zone>,
+	tripwires: Vec<Tripwire>,
+	debounce: usize,
+	tracks: HashMap<u64, TrackState>,
+}
+
+impl ZoneAnalytics {
+	/// Creates the analytics without any zones and tripwires
+	///
+	/// ## Parameters
+	/// * debounce: number of the consecutive observations required to confirm the change of the track state, values
+	///   below 1 are treated as 1
+	pub fn new(debounce: usize) -> Self {
+		Self {
+			zones: vec![],
+			tripwires: vec![],
+			debounce: debounce.max(1),
+			tracks: HashMap::new(),
+		}
+	}
+
+	pub fn add_zone(&mut self, zone: Zone) {
+		self.zones.push(zone);
+	}
+
+	pub fn add_tripwire(&mut self, tripwire: Tripwire) {
+		self.tripwires.push(tripwire);
+	}
+
+	pub fn zones(&self) -> &[Zone] {
+		&self.zones
+	}
+
+	pub fn tripwires(&self) -> &[Tripwire] {
+		&self.tripwires
+	}
+
+	/// Names of the zones that contain the latest position of the track according to the confirmed state
+	pub fn track_zones(&self, track_id: u64) -> Vec<&str> {
+		self.tracks.get(&track_id).map_or_else(Vec::new, |track| {
+			self
+				.zones
+				.iter()
+				.zip(&track.zones)
+				.filter(|(_, state)| state.confirmed == Some(true))
+				.map(|(zone, _)| zone.name.as_str())
+				.collect()
+		})
+	}
+
+	/// Registers the observation of the track and pushes the resulting events into the sink
+	///
+	/// The first observation of a track only establishes its state, so a track appearing inside a zone doesn't
+	/// produce an event.
+	pub fn update(&mut self, track_id: u64, timestamp: f64, position: Point2f, sink: &mut impl DetectionSink) -> Result<()> {
+		let debounce = self.debounce;
+		let track = self.tracks.entry(track_id).or_default();
+		track.zones.resize_with(self.zones.len(), RegionState::default);
+		track.tripwires.resize_with(self.tripwires.len(), RegionState::default);
+		let mut events = vec![];
+		for (zone, state) in self.zones.iter().zip(&mut track.zones) {
+			let inside = zone.contains(position);
+			let direction = if inside {
+				CrossingDirection::In
+			} else {
+				CrossingDirection::Out
+			};
+			if let Some((direction, point)) = state.observe(inside, debounce, || Some((direction, position))) {
+				events.push((zone.name.as_str(), direction, point));
+			}
+		}
+		for (tripwire, state) in self.tripwires.iter().zip(&mut track.tripwires) {
+			let side = tripwire.side(position);
+			if side == 0. {
+				continue;
+			}
+			let prev = track.last_position;
+			let crossing = state.observe(side > 0., debounce, || {
+				prev.and_then(|prev| tripwire.crossing(prev, position))
+			});
+			if let Some((direction, point)) = crossing {
+				events.push((tripwire.name.as_str(), direction, point));
+			}
+		}
+		track.last_position = Some(position);
+		for (zone, direction, position) in events {
+			sink.push(&DetectionEvent::ZoneCrossed {
+				track_id,
+				timestamp,
+				zone: zone.to_string(),
+				direction,
+				position,
+			})?;
+		}
+		Ok(())
+	}
+
+	/// Registers the observation of the keyline track, the midpoint of the keyline is used as its position
+	pub fn update_keyline(
+		&mut self,
+		track_id: u64,
+		timestamp: f64,
+		keyline: &KeyLine,
+		sink: &mut impl DetectionSink,
+	) -> Result<()> {
+		self.update(track_id, timestamp, keyline.pt, sink)
+	}
+
+	/// Forgets the state of the track, e.g. after it was lost by the tracker
+	pub fn remove_track(&mut self, track_id: u64) {
+		self.tracks.remove(&track_id);
+	}
+}
+
+/// Intersection point of the segments `a1`-`a2` and `b1`-`b2`, touching counts as intersection, collinear overlapping
+/// segments are reported as intersecting at the overlap point closest to `a1`
+fn segment_intersection(a1: Point2f, a2: Point2f, b1: Point2f, b2: Point2f) -> Option<Point2f> {
+	let (rx, ry) = (f64::from(a2.x - a1.x), f64::from(a2.y - a1.y));
+	let (sx, sy) = (f64::from(b2.x - b1.x), f64::from(b2.y - b1.y));
+	let (qx, qy) = (f64::from(b1.x - a1.x), f64::from(b1.y - a1.y));
+	let denom = rx * sy - ry * sx;
+	let at = |t: f64| Point2f::new(a1.x + (rx * t) as f32, a1.y + (ry * t) as f32);
+	if denom.abs() <= f64::EPSILON {
+		if (qx * ry - qy * rx).abs() > f64::EPSILON {
+			// parallel
+			return None;
+		}
+		let len_sqr = rx * rx + ry * ry;
+		if len_sqr <= f64::EPSILON {
+			return if qx.hypot(qy) <= f64::EPSILON {
+				Some(a1)
+			} else {
+				None
+			};
+		}
+		// collinear, project the second segment onto the first one
+		let t0 = (qx * rx + qy * ry) / len_sqr;
+		let t1 = t0 + (sx * rx + sy * ry) / len_sqr;
+		let (lo, hi) = (t0.min(t1), t0.max(t1));
+		return (lo <= 1. && hi >= 0.).then(|| at(lo.max(0.)));
+	}
+	let t = (qx * sy - qy * sx) / denom;
+	let u = (qx * ry - qy * rx) / denom;
+	((0. ..=1.).contains(&t) && (0. ..=1.).contains(&u)).then(|| at(t))
+}
@@ -0,0 +1,94 @@
+#[cfg(ocvrs_has_module_imgproc)]
+use crate::{core::CV_8U, imgproc};
+use crate::{
+	core::{self, Mat, Ptr, Vector, CV_64F, CV_8UC3},
+	mcc::{
+		ColorCorrectionModel, ColorCorrectionModelTrait, MCC_CChecker, MCC_CCheckerDetector, MCC_DetectorParameters, CONST_COLOR,
+		MCC_TYPECHART,
+	},
+	prelude::*,
+	Error, Result,
+};
+
+/// Detects up to `max_charts` color checkers of the passed type in the `CV_8UC3` BGR image
+///
+/// Returns an empty list if there is no chart in the image.
+pub fn detect_color_checkers(image: &Mat, chart_type: MCC_TYPECHART, max_charts: i32) -> Result<Vector<Ptr<dyn MCC_CChecker>>> {
+	if image.typ() != CV_8UC3 {
+		return Err(Error::new(
+			core::StsUnsupportedFormat,
+			format!(
+				"Image must be of type CV_8UC3, but got: {}",
+				core::type_to_string(image.typ())?
+			),
+		));
+	}
+	let mut detector = <dyn MCC_CCheckerDetector>::create()?;
+	if !detector.process(image, chart_type, max_charts, false, &MCC_DetectorParameters::create()?)? {
+		return Ok(Vector::new());
+	}
+	detector.get_list_color_checker()
+}
+
+/// Measured patch colors of the detected color checker
+pub trait MCC_CCheckerTraitManual: MCC_CChecker {
+	/// Mean RGB color of every patch of the chart in the range [0, 1]
+	///
+	/// Returns `N`x1 `CV_64FC3` matrix with one row per patch in the order of the reference chart, it's the `src`
+	/// argument for `ColorCorrectionModel`.
+	fn patch_colors(&mut self) -> Result<Mat> {
+		// every patch has 3 rows, one per channel, the second column is the mean value
+		let charts_rgb = self.get_charts_rgb()?;
+		let mut out = Mat::default();
+		charts_rgb.col(1)?.convert_to(&mut out, CV_64F, 1. / 255., 0.)?;
+		out.reshape(3, charts_rgb.rows() / 3)
+	}
+}
+
+impl<T: MCC_CChecker + ?Sized> MCC_CCheckerTraitManual for T {}
+
+/// Fits the color correction model to the best Macbeth ColorChecker found in the `CV_8UC3` BGR image
+///
+/// Returns `None` if there is no chart in the image. The model keeps the OpenCV defaults: 3x3 matrix in the linear
+/// sRGB space with the gamma linearization.
+pub fn calibrate_colors(image: &Mat) -> Result<Option<ColorCorrectionModel>> {
+	let checkers = detect_color_checkers(image, MCC_TYPECHART::MCC24, 1)?;
+	let mut checker = match checkers.into_iter().next() {
+		Some(checker) => checker,
+		None => return Ok(None),
+	};
+	let mut model = ColorCorrectionModel::new(&checker.patch_colors()?, CONST_COLOR::COLORCHECKER_Macbeth)?;
+	model.run()?;
+	Ok(Some(model))
+}
+
+/// Color correction of the regular 8-bit images
+pub trait ColorCorrectionModelTraitManual: ColorCorrectionModelTrait {
+	/// Applies the fitted model to the `CV_8UC3` BGR image and returns the corrected `CV_8UC3` BGR image
+	///
+	/// `ColorCorrectionModelTrait::infer()` works with the RGB images in the range [0, 1], the conversion is done
+	/// here.
+	#[cfg(ocvrs_has_module_imgproc)]
+	fn correct_bgr(&mut self, image: &Mat) -> Result<Mat> {
+		if image.typ() != CV_8UC3 {
+			return Err(Error::new(
+				core::StsUnsupportedFormat,
+				format!(
+					"Image must be of type CV_8UC3, but got: {}",
+					core::type_to_string(image.typ())?
+				),
+			));
+		}
+		let mut rgb = Mat::default();
+		imgproc::cvt_color(image, &mut rgb, imgproc::COLOR_BGR2RGB, 0)?;
+		let mut rgb_float = Mat::default();
+		rgb.convert_to(&mut rgb_float, CV_64F, 1. / 255., 0.)?;
+		let corrected = self.infer(&rgb_float, false)?;
+		corrected.convert_to(&mut rgb, CV_8U, 255., 0.)?;
+		let mut out = Mat::default();
+		imgproc::cvt_color(&rgb, &mut out, imgproc::COLOR_RGB2BGR, 0)?;
+		Ok(out)
+	}
+}
+
+impl<T: ColorCorrectionModelTrait + ?Sized> ColorCorrectionModelTraitManual for T {}
@@ -0,0 +1,85 @@
+use std::path::Path;
+
+use crate::{
+	core::{self, Mat, Ptr, ToInputArray},
+	ml::{
+		Boost, DTrees, KNearest, LogisticRegression, NormalBayesClassifier, RTrees, SampleTypes, StatModel, StatModelConst,
+		TrainData, ANN_MLP, EM, SVM, SVMSGD,
+	},
+	Error, Result,
+};
+
+/// Statistical model type for [load_stat_model]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StatModelKind {
+	ANNMLP,
+	Boost,
+	DTrees,
+	EM,
+	KNearest,
+	LogisticRegression,
+	NormalBayesClassifier,
+	RTrees,
+	SVM,
+	SVMSGD,
+}
+
+/// Loads the statistical model of the passed type previously saved with `core::AlgorithmTraitConst::save()`
+pub fn load_stat_model(kind: StatModelKind, path: impl AsRef<Path>) -> Result<Box<dyn StatModel>> {
+	let path = path.as_ref();
+	// OpenCV returns an empty model or fails with a parsing error for the missing file
+	if !path.is_file() {
+		return Err(Error::new(
+			core::StsObjectNotFound,
+			format!("Model file not found: {}", path.display()),
+		));
+	}
+	let path = path.to_string_lossy();
+	Ok(match kind {
+		StatModelKind::ANNMLP => Box::new(<dyn ANN_MLP>::load(&path)?),
+		StatModelKind::Boost => Box::new(<dyn Boost>::load(&path, "")?),
+		StatModelKind::DTrees => Box::new(<dyn DTrees>::load(&path, "")?),
+		StatModelKind::EM => Box::new(<dyn EM>::load(&path, "")?),
+		StatModelKind::KNearest => Box::new(<dyn KNearest>::load(&path)?),
+		StatModelKind::LogisticRegression => Box::new(<dyn LogisticRegression>::load(&path, "")?),
+		StatModelKind::NormalBayesClassifier => Box::new(<dyn NormalBayesClassifier>::load(&path, "")?),
+		StatModelKind::RTrees => Box::new(<dyn RTrees>::load(&path, "")?),
+		StatModelKind::SVM => Box::new(<dyn SVM>::load(&path)?),
+		StatModelKind::SVMSGD => Box::new(<dyn SVMSGD>::load(&path, "")?),
+	})
+}
+
+/// Creates the training data from the `CV_32FC1` sample matrix with the passed layout and the responses
+///
+/// The responses are `CV_32FC1` for the regression and `CV_32SC1` for the classification, one per sample.
+pub fn create_train_data(samples: &Mat, layout: SampleTypes, responses: &Mat) -> Result<Ptr<dyn TrainData>> {
+	<dyn TrainData>::create(
+		samples,
+		layout as i32,
+		responses,
+		None::<&Mat>,
+		None::<&Mat>,
+		None::<&Mat>,
+		None::<&Mat>,
+	)
+}
+
+/// Training and batch prediction with the typed sample layout for all the statistical models
+pub trait StatModelTraitManual: StatModel {
+	/// Trains the model on the `CV_32FC1` samples with the passed layout, fails if the training was unsuccessful
+	fn train_samples(&mut self, samples: &Mat, layout: SampleTypes, responses: &Mat) -> Result<()> {
+		if !self.train(samples, layout as i32, responses)? {
+			return Err(Error::new(core::StsError, "Statistical model training failed"));
+		}
+		Ok(())
+	}
+
+	/// Predicts the responses of all the samples, one per row of `samples`, and returns them as the column vector
+	fn predict_all(&self, samples: &dyn ToInputArray) -> Result<Mat> {
+		let mut out = Mat::default();
+		StatModelConst::predict(self, samples, Some(&mut out), 0)?;
+		Ok(out)
+	}
+}
+
+impl<T: StatModel + ?Sized> StatModelTraitManual for T {}
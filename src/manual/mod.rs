@@ -1,15 +1,303 @@
+#[cfg(ocvrs_has_module_bgsegm)]
+pub mod bgsegm;
+#[cfg(ocvrs_has_module_ccalib)]
+pub mod ccalib;
 #[cfg(ocvrs_has_module_core)]
 pub mod core;
+#[cfg(ocvrs_has_module_cudaarithm)]
+pub mod cudaarithm;
+#[cfg(ocvrs_has_module_cudabgsegm)]
+pub mod cudabgsegm;
+#[cfg(ocvrs_has_module_cudacodec)]
+pub mod cudacodec;
+#[cfg(ocvrs_has_module_cudafeatures2d)]
+pub mod cudafeatures2d;
+#[cfg(ocvrs_has_module_cudafilters)]
+pub mod cudafilters;
+#[cfg(ocvrs_has_module_cudaoptflow)]
+pub mod cudaoptflow;
 #[cfg(ocvrs_has_module_dnn)]
 pub mod dnn;
 #[cfg(ocvrs_has_module_features2d)]
 pub mod features2d;
+#[cfg(ocvrs_has_module_freetype)]
+pub mod freetype;
+#[cfg(ocvrs_has_module_highgui)]
+pub mod highgui;
+#[cfg(ocvrs_has_module_imgproc)]
+pub mod imgproc;
+#[cfg(ocvrs_has_module_line_descriptor)]
+pub mod line_descriptor;
+#[cfg(ocvrs_has_module_mcc)]
+pub mod mcc;
+#[cfg(ocvrs_has_module_ml)]
+pub mod ml;
+#[cfg(ocvrs_has_module_objdetect)]
+pub mod objdetect;
+#[cfg(ocvrs_has_module_optflow)]
+pub mod optflow;
+#[cfg(ocvrs_has_module_phase_unwrapping)]
+pub mod phase_unwrapping;
+#[cfg(ocvrs_has_module_photo)]
+pub mod photo;
+#[cfg(ocvrs_has_module_quality)]
+pub mod quality;
+#[cfg(ocvrs_has_module_rapid)]
+pub mod rapid;
+#[cfg(ocvrs_has_module_rgbd)]
+pub mod rgbd;
+#[cfg(ocvrs_has_module_stitching)]
+pub mod stitching;
+#[cfg(ocvrs_has_module_structured_light)]
+pub mod structured_light;
 pub mod sys;
+#[cfg(ocvrs_has_module_text)]
+pub mod text;
+#[cfg(ocvrs_has_module_tracking)]
+pub mod tracking;
 pub mod types;
+#[cfg(ocvrs_has_module_video)]
+pub mod video;
+#[cfg(ocvrs_has_module_videostab)]
+pub mod videostab;
+#[cfg(ocvrs_has_module_viz)]
+pub mod viz;
+#[cfg(ocvrs_has_module_wechat_qrcode)]
+pub mod wechat_qrcode;
+#[cfg(ocvrs_has_module_xfeatures2d)]
+pub mod xfeatures2d;
+#[cfg(ocvrs_has_module_ximgproc)]
+pub mod ximgproc;
 
 pub mod prelude {
-	#[cfg(all(ocvrs_has_module_core, ocvrs_opencv_branch_32))]
-	pub use super::core::MatSizeTraitConstManual;
+	#[cfg(ocvrs_has_module_ccalib)]
+	pub use self::ccalib::*;
 	#[cfg(ocvrs_has_module_core)]
-	pub use super::core::{MatConstIteratorTraitManual, MatTraitConstManual, MatTraitManual, MatxTrait, UMatTraitConstManual};
+	pub use self::core::*;
+	#[cfg(ocvrs_has_module_cudaarithm)]
+	pub use self::cudaarithm::*;
+	#[cfg(ocvrs_has_module_cudabgsegm)]
+	pub use self::cudabgsegm::*;
+	#[cfg(ocvrs_has_module_cudacodec)]
+	pub use self::cudacodec::*;
+	#[cfg(ocvrs_has_module_cudafeatures2d)]
+	pub use self::cudafeatures2d::*;
+	#[cfg(ocvrs_has_module_cudafilters)]
+	pub use self::cudafilters::*;
+	#[cfg(ocvrs_has_module_cudaoptflow)]
+	pub use self::cudaoptflow::*;
+	#[cfg(ocvrs_has_module_dnn)]
+	pub use self::dnn::*;
+	#[cfg(ocvrs_has_module_features2d)]
+	pub use self::features2d::*;
+	#[cfg(ocvrs_has_module_freetype)]
+	pub use self::freetype::*;
+	#[cfg(ocvrs_has_module_line_descriptor)]
+	pub use self::line_descriptor::*;
+	#[cfg(ocvrs_has_module_mcc)]
+	pub use self::mcc::*;
+	#[cfg(ocvrs_has_module_ml)]
+	pub use self::ml::*;
+	#[cfg(ocvrs_has_module_objdetect)]
+	pub use self::objdetect::*;
+	#[cfg(ocvrs_has_module_quality)]
+	pub use self::quality::*;
+	#[cfg(ocvrs_has_module_rapid)]
+	pub use self::rapid::*;
+	#[cfg(ocvrs_has_module_rgbd)]
+	pub use self::rgbd::*;
+	#[cfg(ocvrs_has_module_stitching)]
+	pub use self::stitching::*;
+	#[cfg(ocvrs_has_module_structured_light)]
+	pub use self::structured_light::*;
+	#[cfg(ocvrs_has_module_text)]
+	pub use self::text::*;
+	#[cfg(ocvrs_has_module_tracking)]
+	pub use self::tracking::*;
+	#[cfg(ocvrs_has_module_video)]
+	pub use self::video::*;
+	#[cfg(ocvrs_has_module_videostab)]
+	pub use self::videostab::*;
+	#[cfg(ocvrs_has_module_viz)]
+	pub use self::viz::*;
+	#[cfg(ocvrs_has_module_wechat_qrcode)]
+	pub use self::wechat_qrcode::*;
+	#[cfg(ocvrs_has_module_ximgproc)]
+	pub use self::ximgproc::*;
+
+	// Manual extension traits grouped by module, every manual module has its group, they are also included in the
+	// corresponding module preludes of `prelude::v2`
+
+	#[cfg(ocvrs_has_module_bgsegm)]
+	pub mod bgsegm {}
+
+	#[cfg(ocvrs_has_module_ccalib)]
+	pub mod ccalib {
+		pub use crate::manual::ccalib::RandomPatternCornerFinderTraitManual;
+	}
+
+	#[cfg(ocvrs_has_module_core)]
+	pub mod core {
+		#[cfg(ocvrs_opencv_branch_32)]
+		pub use crate::manual::core::MatSizeTraitConstManual;
+		pub use crate::manual::core::{
+			DeviceInfoTraitConstManual, GpuMatTraitConstManual, MatConstIteratorTraitManual, MatTraitConstManual, MatTraitManual,
+			MatxTrait, StreamTraitManual, UMatTraitConstManual, UMatTraitManual,
+		};
+	}
+
+	#[cfg(ocvrs_has_module_cudaarithm)]
+	pub mod cudaarithm {
+		pub use crate::manual::cudaarithm::GpuMatArithm;
+	}
+
+	#[cfg(ocvrs_has_module_cudabgsegm)]
+	pub mod cudabgsegm {
+		pub use crate::manual::cudabgsegm::CUDA_BackgroundSubtractorMOG2TraitManual;
+	}
+
+	#[cfg(ocvrs_has_module_cudacodec)]
+	pub mod cudacodec {
+		pub use crate::manual::cudacodec::{VideoReaderTraitManual, VideoWriterTraitManual};
+	}
+
+	#[cfg(ocvrs_has_module_cudafeatures2d)]
+	pub mod cudafeatures2d {
+		pub use crate::manual::cudafeatures2d::{CUDA_DescriptorMatcherTraitManual, CUDA_Feature2DAsyncTraitManual};
+	}
+
+	#[cfg(ocvrs_has_module_cudafilters)]
+	pub mod cudafilters {
+		pub use crate::manual::cudafilters::FilterTraitManual;
+	}
+
+	#[cfg(ocvrs_has_module_cudaoptflow)]
+	pub mod cudaoptflow {
+		pub use crate::manual::cudaoptflow::CUDA_DenseOpticalFlowTraitManual;
+	}
+
+	#[cfg(ocvrs_has_module_dnn)]
+	pub mod dnn {
+		pub use crate::manual::dnn::{NetTraitConstManual, NetTraitManual};
+	}
+
+	#[cfg(ocvrs_has_module_features2d)]
+	pub mod features2d {
+		pub use crate::manual::features2d::DescriptorMatcherFilterTrait;
+	}
+
+	#[cfg(ocvrs_has_module_freetype)]
+	pub mod freetype {
+		pub use crate::manual::freetype::FreeType2TraitManual;
+	}
+
+	#[cfg(ocvrs_has_module_highgui)]
+	pub mod highgui {}
+
+	#[cfg(ocvrs_has_module_imgproc)]
+	pub mod imgproc {}
+
+	#[cfg(ocvrs_has_module_line_descriptor)]
+	pub mod line_descriptor {
+		pub use crate::manual::line_descriptor::{
+			BinaryDescriptorMatcherBatchTrait, BinaryDescriptorMatcherFilterTrait, BinaryDescriptorMatcherPagingTrait,
+			BinaryDescriptorTraitConstManual,
+		};
+		#[cfg(ocvrs_has_module_imgproc)]
+		pub use crate::manual::line_descriptor::BinaryDescriptorPyramidTrait;
+		#[cfg(ocvrs_has_module_ximgproc)]
+		pub use crate::manual::line_descriptor::{EdgeDrawingKeyLineTrait, FastLineDetectorKeyLineTrait};
+	}
+
+	#[cfg(ocvrs_has_module_mcc)]
+	pub mod mcc {
+		pub use crate::manual::mcc::{ColorCorrectionModelTraitManual, MCC_CCheckerTraitManual};
+	}
+
+	#[cfg(ocvrs_has_module_ml)]
+	pub mod ml {
+		pub use crate::manual::ml::StatModelTraitManual;
+	}
+
+	#[cfg(ocvrs_has_module_objdetect)]
+	pub mod objdetect {
+		#[cfg(ocvrs_opencv_branch_4)]
+		pub use crate::manual::objdetect::QRCodeEncoderTraitManual;
+	}
+
+	#[cfg(ocvrs_has_module_optflow)]
+	pub mod optflow {}
+
+	#[cfg(ocvrs_has_module_phase_unwrapping)]
+	pub mod phase_unwrapping {}
+
+	#[cfg(ocvrs_has_module_photo)]
+	pub mod photo {}
+
+	#[cfg(ocvrs_has_module_quality)]
+	pub mod quality {
+		pub use crate::manual::quality::QualityBaseTraitManual;
+	}
+
+	#[cfg(ocvrs_has_module_rapid)]
+	pub mod rapid {
+		pub use crate::manual::rapid::TrackerTraitManual;
+	}
+
+	#[cfg(ocvrs_has_module_rgbd)]
+	pub mod rgbd {
+		pub use crate::manual::rgbd::{
+			DepthCleanerTraitConstManual, OdometryTraitConstManual, RgbdNormalsTraitConstManual, RgbdPlaneTraitManual,
+		};
+	}
+
+	#[cfg(ocvrs_has_module_stitching)]
+	pub mod stitching {
+		pub use crate::manual::stitching::StitcherTraitManual;
+	}
+
+	#[cfg(ocvrs_has_module_structured_light)]
+	pub mod structured_light {
+		pub use crate::manual::structured_light::{
+			GrayCodePatternTraitConstManual, StructuredLightPatternTraitConstManual, StructuredLightPatternTraitManual,
+		};
+	}
+
+	#[cfg(ocvrs_has_module_text)]
+	pub mod text {
+		pub use crate::manual::text::BaseOCRTraitManual;
+	}
+
+	#[cfg(ocvrs_has_module_tracking)]
+	pub mod tracking {
+		pub use crate::manual::tracking::DynTracker;
+	}
+
+	#[cfg(ocvrs_has_module_video)]
+	pub mod video {
+		pub use crate::manual::video::BackgroundSubtractorTraitManual;
+	}
+
+	#[cfg(ocvrs_has_module_videostab)]
+	pub mod videostab {
+		pub use crate::manual::videostab::IFrameSourceTraitManual;
+	}
+
+	#[cfg(ocvrs_has_module_viz)]
+	pub mod viz {
+		pub use crate::manual::viz::Viz3dTraitManual;
+	}
+
+	#[cfg(ocvrs_has_module_wechat_qrcode)]
+	pub mod wechat_qrcode {
+		pub use crate::manual::wechat_qrcode::WeChatQRCodeTraitManual;
+	}
+
+	#[cfg(ocvrs_has_module_xfeatures2d)]
+	pub mod xfeatures2d {}
+
+	#[cfg(ocvrs_has_module_ximgproc)]
+	pub mod ximgproc {
+		pub use crate::manual::ximgproc::StructuredEdgeDetectionTraitConstManual;
+	}
 }
@@ -0,0 +1,73 @@
+#[cfg(ocvrs_opencv_branch_4)]
+pub use qr_code_encoder::*;
+
+#[cfg(ocvrs_opencv_branch_4)]
+mod qr_code_encoder {
+	use std::ffi::c_void;
+
+	use crate::{
+		core::{self, _OutputArrayTraitConst},
+		objdetect::QRCodeEncoder,
+		sys,
+		traits::{OpenCVTypeArg, OpenCVTypeExternContainer},
+		Result,
+	};
+
+	/// Encoding of the binary data that can contain 0 bytes and is not valid UTF-8
+	pub trait QRCodeEncoderTraitManual: QRCodeEncoder {
+		/// Generates QR code from the binary data, same as [QRCodeEncoder::encode], but doesn't stop at the first 0 byte
+		///
+		/// Use it with the `QRCodeEncoder_EncodeMode::MODE_BYTE` mode.
+		fn encode_bytes(&mut self, encoded_info: &[u8], qrcode: &mut dyn core::ToOutputArray) -> Result<()> {
+			extern "C" {
+				fn cv_manual_QRCodeEncoder_encode_bytes(
+					instance: *mut c_void,
+					encoded_info: *const c_void,
+					qrcode: *const c_void,
+					ocvrs_return: *mut sys::Result_void,
+				);
+			}
+			let encoded_info = encoded_info.opencv_into_extern_container_nofail();
+			let qrcode = qrcode.output_array()?;
+			return_send!(via ocvrs_return);
+			unsafe {
+				cv_manual_QRCodeEncoder_encode_bytes(
+					self.as_raw_mut_QRCodeEncoder(),
+					encoded_info.opencv_as_extern(),
+					qrcode.as_raw__OutputArray(),
+					ocvrs_return.as_mut_ptr(),
+				)
+			}
+			return_receive!(unsafe ocvrs_return => ret);
+			ret.into_result()
+		}
+
+		/// Generates QR codes from the binary data in Structured Append mode, same as
+		/// [QRCodeEncoder::encode_structured_append], but doesn't stop at the first 0 byte
+		fn encode_structured_append_bytes(&mut self, encoded_info: &[u8], qrcodes: &mut dyn core::ToOutputArray) -> Result<()> {
+			extern "C" {
+				fn cv_manual_QRCodeEncoder_encodeStructuredAppend_bytes(
+					instance: *mut c_void,
+					encoded_info: *const c_void,
+					qrcodes: *const c_void,
+					ocvrs_return: *mut sys::Result_void,
+				);
+			}
+			let encoded_info = encoded_info.opencv_into_extern_container_nofail();
+			let qrcodes = qrcodes.output_array()?;
+			return_send!(via ocvrs_return);
+			unsafe {
+				cv_manual_QRCodeEncoder_encodeStructuredAppend_bytes(
+					self.as_raw_mut_QRCodeEncoder(),
+					encoded_info.opencv_as_extern(),
+					qrcodes.as_raw__OutputArray(),
+					ocvrs_return.as_mut_ptr(),
+				)
+			}
+			return_receive!(unsafe ocvrs_return => ret);
+			ret.into_result()
+		}
+	}
+
+	impl<T: QRCodeEncoder + ?Sized> QRCodeEncoderTraitManual for T {}
+}
@@ -0,0 +1,7 @@
+pub use params::*;
+#[cfg(ocvrs_has_module_imgproc)]
+pub use visualize::*;
+
+mod params;
+#[cfg(ocvrs_has_module_imgproc)]
+mod visualize;
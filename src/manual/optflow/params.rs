@@ -0,0 +1,295 @@
+use std::{
+	ffi::{c_void, CString},
+	os::raw::c_char,
+	ptr,
+};
+
+use crate::{
+	core::{Mat, Ptr, Size, ToInputArray},
+	optflow::{
+		self, DenseRLOFOpticalFlow, InterpolationType, OpticalFlowPCAFlow, RLOFOpticalFlowParameter, RLOFOpticalFlowParameterTrait,
+		SolverType, SparseRLOFOpticalFlow, SupportRegionType,
+	},
+	sys,
+	traits::Boxed,
+	Result,
+};
+
+extern "C" {
+	fn cv_manual_optflow_OpticalFlowPCAFlow_new(
+		prior: *const c_char,
+		basis_size: *const Size,
+		sparse_rate: f32,
+		retained_corners_fraction: f32,
+		occlusions_threshold: f32,
+		damping_factor: f32,
+		clahe_clip: f32,
+		ocvrs_return: *mut sys::Result<*mut c_void>,
+	);
+}
+
+/// Parameters of the RLOF optical flow shared by [DenseRLOFParams] and [SparseRLOFParams]
+///
+/// Typed counterpart of `RLOFOpticalFlowParameter`, the defaults match the ones of OpenCV.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RLOFParams {
+	pub solver_type: SolverType,
+	pub support_region_type: SupportRegionType,
+	/// First sigma of the M-estimator, `f32::MAX` together with `norm_sigma1` disables it
+	pub norm_sigma0: f32,
+	/// Second sigma of the M-estimator
+	pub norm_sigma1: f32,
+	/// Size of the support region for `SupportRegionType::SR_FIXED` and the minimal one for `SR_CROSS`
+	pub small_win_size: i32,
+	/// Maximal size of the support region for `SupportRegionType::SR_CROSS`
+	pub large_win_size: i32,
+	/// Color similarity threshold of the cross-based segmentation, only used with `SupportRegionType::SR_CROSS`
+	pub cross_segmentation_threshold: i32,
+	/// Maximal pyramid level, 0 disables the pyramids
+	pub max_level: i32,
+	pub use_initial_flow: bool,
+	/// Whether the illumination changes are modelled
+	pub use_illumination_model: bool,
+	/// Whether the global motion is used as the prior of the iterative refinement
+	pub use_global_motion_prior: bool,
+	pub max_iteration: i32,
+	pub min_eigen_value: f32,
+	pub global_motion_ransac_threshold: f32,
+}
+
+impl Default for RLOFParams {
+	fn default() -> Self {
+		Self {
+			solver_type: SolverType::ST_BILINEAR,
+			support_region_type: SupportRegionType::SR_CROSS,
+			norm_sigma0: 3.2,
+			norm_sigma1: 7.,
+			small_win_size: 9,
+			large_win_size: 21,
+			cross_segmentation_threshold: 25,
+			max_level: 4,
+			use_initial_flow: false,
+			use_illumination_model: true,
+			use_global_motion_prior: true,
+			max_iteration: 30,
+			min_eigen_value: 0.0001,
+			global_motion_ransac_threshold: 10.,
+		}
+	}
+}
+
+impl RLOFParams {
+	/// Creates `RLOFOpticalFlowParameter` with these values
+	pub fn to_parameter(&self) -> Result<Ptr<RLOFOpticalFlowParameter>> {
+		let mut out = RLOFOpticalFlowParameter::create()?;
+		out.set_solver_type(self.solver_type);
+		out.set_support_region_type(self.support_region_type);
+		out.set_norm_sigma0(self.norm_sigma0);
+		out.set_norm_sigma1(self.norm_sigma1);
+		out.set_small_win_size(self.small_win_size);
+		out.set_large_win_size(self.large_win_size);
+		out.set_cross_segmentation_threshold(self.cross_segmentation_threshold);
+		out.set_max_level(self.max_level);
+		out.set_use_initial_flow(self.use_initial_flow);
+		out.set_use_illumination_model(self.use_illumination_model);
+		out.set_use_global_motion_prior(self.use_global_motion_prior);
+		out.set_max_iteration(self.max_iteration);
+		out.set_min_eigen_value(self.min_eigen_value);
+		out.set_global_motion_ransac_threshold(self.global_motion_ransac_threshold);
+		Ok(out)
+	}
+}
+
+/// Parameters of `DenseRLOFOpticalFlow`, the defaults match the ones of OpenCV
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DenseRLOFParams {
+	pub rlof: RLOFParams,
+	/// Threshold of the forward-backward confidence check, the flow of the points above it is interpolated
+	pub forward_backward_threshold: f32,
+	/// Step of the grid of the points the sparse flow is computed for
+	pub grid_step: Size,
+	/// Interpolation of the sparse flow to the dense one
+	pub interp_type: InterpolationType,
+	/// Number of the nearest neighbors used by `InterpolationType::INTERP_EPIC`
+	pub epic_k: i32,
+	/// Kernel sigma of `InterpolationType::INTERP_EPIC`
+	pub epic_sigma: f32,
+	/// Regularization of `InterpolationType::INTERP_EPIC`
+	pub epic_lambda: f32,
+	/// Superpixel size of `InterpolationType::INTERP_RIC`
+	pub ric_sp_size: i32,
+	/// SLIC algorithm of `InterpolationType::INTERP_RIC`, see `ximgproc::SLICType`
+	pub ric_slic_type: i32,
+	/// Whether the fast global smoother is applied to the interpolated flow
+	pub use_post_proc: bool,
+	pub fgs_lambda: f32,
+	pub fgs_sigma: f32,
+	pub use_variational_refinement: bool,
+}
+
+impl Default for DenseRLOFParams {
+	fn default() -> Self {
+		Self {
+			rlof: RLOFParams::default(),
+			forward_backward_threshold: 1.,
+			grid_step: Size::new(6, 6),
+			interp_type: InterpolationType::INTERP_EPIC,
+			epic_k: 128,
+			epic_sigma: 0.05,
+			epic_lambda: 999.,
+			ric_sp_size: 15,
+			ric_slic_type: 100,
+			use_post_proc: true,
+			fgs_lambda: 500.,
+			fgs_sigma: 1.5,
+			use_variational_refinement: false,
+		}
+	}
+}
+
+impl DenseRLOFParams {
+	/// Creates `DenseRLOFOpticalFlow` with these parameters
+	pub fn create(&self) -> Result<Ptr<dyn DenseRLOFOpticalFlow>> {
+		<dyn DenseRLOFOpticalFlow>::create(
+			self.rlof.to_parameter()?,
+			self.forward_backward_threshold,
+			self.grid_step,
+			self.interp_type,
+			self.epic_k,
+			self.epic_sigma,
+			self.epic_lambda,
+			self.ric_sp_size,
+			self.ric_slic_type,
+			self.use_post_proc,
+			self.fgs_lambda,
+			self.fgs_sigma,
+			self.use_variational_refinement,
+		)
+	}
+}
+
+/// Parameters of `SparseRLOFOpticalFlow`, the defaults match the ones of OpenCV
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SparseRLOFParams {
+	pub rlof: RLOFParams,
+	/// Threshold of the forward-backward confidence check, the status of the points above it is set to 0
+	pub forward_backward_threshold: f32,
+}
+
+impl Default for SparseRLOFParams {
+	fn default() -> Self {
+		Self {
+			rlof: RLOFParams::default(),
+			forward_backward_threshold: 1.,
+		}
+	}
+}
+
+impl SparseRLOFParams {
+	/// Creates `SparseRLOFOpticalFlow` with these parameters
+	pub fn create(&self) -> Result<Ptr<dyn SparseRLOFOpticalFlow>> {
+		<dyn SparseRLOFOpticalFlow>::create(self.rlof.to_parameter()?, self.forward_backward_threshold)
+	}
+}
+
+/// Parameters of `OpticalFlowPCAFlow`, the defaults match the ones of OpenCV
+#[derive(Clone, Debug, PartialEq)]
+pub struct PCAFlowParams {
+	/// Path to the learned prior, see `PCAPrior`, no prior is used if `None`
+	pub prior: Option<String>,
+	/// Number of the basis vectors
+	pub basis_size: Size,
+	/// Density of the sparse matches
+	pub sparse_rate: f32,
+	pub retained_corners_fraction: f32,
+	pub occlusions_threshold: f32,
+	/// Regularization of the least-squares solution, not related to the prior
+	pub damping_factor: f32,
+	/// Clip limit of CLAHE applied to the input images
+	pub clahe_clip: f32,
+}
+
+impl Default for PCAFlowParams {
+	fn default() -> Self {
+		Self {
+			prior: None,
+			basis_size: Size::new(18, 14),
+			sparse_rate: 0.024,
+			retained_corners_fraction: 0.2,
+			occlusions_threshold: 0.0003,
+			damping_factor: 0.00002,
+			clahe_clip: 14.,
+		}
+	}
+}
+
+impl PCAFlowParams {
+	/// Creates `OpticalFlowPCAFlow` with these parameters
+	///
+	/// Unlike `OpticalFlowPCAFlow::new()` it allows to skip the prior.
+	pub fn create(&self) -> Result<OpticalFlowPCAFlow> {
+		let prior = self.prior.as_deref().map(CString::new).transpose()?;
+		return_send!(via ocvrs_return);
+		unsafe {
+			cv_manual_optflow_OpticalFlowPCAFlow_new(
+				prior.as_ref().map_or(ptr::null(), |prior| prior.as_ptr()),
+				&self.basis_size,
+				self.sparse_rate,
+				self.retained_corners_fraction,
+				self.occlusions_threshold,
+				self.damping_factor,
+				self.clahe_clip,
+				ocvrs_return.as_mut_ptr(),
+			)
+		};
+		return_receive!(unsafe ocvrs_return => ret);
+		ret.into_result().map(|ptr| unsafe { OpticalFlowPCAFlow::from_raw(ptr) })
+	}
+}
+
+/// Parameters of [optflow::calc_optical_flow_sparse_to_dense], the defaults match the ones of OpenCV
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SparseToDenseParams {
+	/// Step of the grid of the points the sparse flow is computed for
+	pub grid_step: i32,
+	/// Number of the nearest neighbors used by the edge-aware interpolation
+	pub k: i32,
+	/// Kernel sigma of the edge-aware interpolation
+	pub sigma: f32,
+	/// Whether the fast global smoother is applied to the interpolated flow
+	pub use_post_proc: bool,
+	pub fgs_lambda: f32,
+	pub fgs_sigma: f32,
+}
+
+impl Default for SparseToDenseParams {
+	fn default() -> Self {
+		Self {
+			grid_step: 8,
+			k: 128,
+			sigma: 0.05,
+			use_post_proc: true,
+			fgs_lambda: 500.,
+			fgs_sigma: 1.5,
+		}
+	}
+}
+
+impl SparseToDenseParams {
+	/// Computes the dense `CV_32FC2` flow from `from` to `to` with these parameters
+	pub fn calc(&self, from: &(impl ToInputArray + ?Sized), to: &(impl ToInputArray + ?Sized)) -> Result<Mat> {
+		let mut flow = Mat::default();
+		optflow::calc_optical_flow_sparse_to_dense(
+			from,
+			to,
+			&mut flow,
+			self.grid_step,
+			self.k,
+			self.sigma,
+			self.use_post_proc,
+			self.fgs_lambda,
+			self.fgs_sigma,
+		)?;
+		Ok(flow)
+	}
+}
@@ -0,0 +1,53 @@
+use crate::{
+	core::{self, Mat, Scalar, Vector, CV_32FC2, CV_8U},
+	imgproc,
+	prelude::*,
+	Error, Result,
+};
+
+/// Renders the `CV_32FC2` flow field into the `CV_8UC3` BGR image using the usual color wheel coding
+///
+/// The direction of the flow is encoded as the hue and the magnitude as the brightness, the magnitudes at or above
+/// `max_magnitude` get the full brightness. If `max_magnitude` is `None` the largest magnitude in the field is used.
+pub fn flow_to_bgr(flow: &Mat, max_magnitude: Option<f32>) -> Result<Mat> {
+	if flow.typ() != CV_32FC2 {
+		return Err(Error::new(
+			core::StsUnsupportedFormat,
+			format!(
+				"Flow must be of type CV_32FC2, but got: {}",
+				core::type_to_string(flow.typ())?
+			),
+		));
+	}
+	let mut xy = Vector::<Mat>::new();
+	core::split(flow, &mut xy)?;
+	let mut magnitude = Mat::default();
+	let mut angle = Mat::default();
+	core::cart_to_polar(&xy.get(0)?, &xy.get(1)?, &mut magnitude, &mut angle, true)?;
+	let max_magnitude = match max_magnitude {
+		Some(max_magnitude) => f64::from(max_magnitude),
+		None => {
+			let mut max_magnitude = 0.;
+			core::min_max_loc(&magnitude, None, Some(&mut max_magnitude), None, None, None::<&Mat>)?;
+			max_magnitude
+		}
+	};
+
+	// 8-bit hue is in the range [0, 180)
+	let mut hue = Mat::default();
+	angle.convert_to(&mut hue, CV_8U, 0.5, 0.)?;
+	let saturation = Mat::new_size_with_default(flow.size()?, CV_8U, Scalar::all(255.))?;
+	let mut value = Mat::default();
+	let scale = if max_magnitude > 0. {
+		255. / max_magnitude
+	} else {
+		0.
+	};
+	magnitude.convert_to(&mut value, CV_8U, scale, 0.)?;
+
+	let mut hsv = Mat::default();
+	core::merge(&Vector::<Mat>::from_iter([hue, saturation, value]), &mut hsv)?;
+	let mut bgr = Mat::default();
+	imgproc::cvt_color(&hsv, &mut bgr, imgproc::COLOR_HSV2BGR, 0)?;
+	Ok(bgr)
+}
@@ -0,0 +1,90 @@
+use std::f32::consts::PI;
+
+use crate::{
+	core::{self, Mat, Ptr, Size, CV_32FC1},
+	phase_unwrapping::{HistogramPhaseUnwrapping, HistogramPhaseUnwrapping_Params},
+	prelude::*,
+	Error, Result,
+};
+
+/// Highest edge reliability value, the upper edge of the last large bin of the histogram
+const MAX_RELIABILITY: f32 = 32. * PI * PI;
+
+impl HistogramPhaseUnwrapping_Params {
+	/// Default parameters for the phase maps of the passed size
+	pub fn for_size(size: Size) -> Result<Self> {
+		let mut out = Self::default()?;
+		out.width = size.width;
+		out.height = size.height;
+		Ok(out)
+	}
+
+	/// Checks the values, OpenCV doesn't validate them and fails later with the less obvious errors
+	pub fn validate(&self) -> Result<()> {
+		if self.width < 1 || self.height < 1 {
+			return Err(Error::new(
+				core::StsOutOfRange,
+				format!("Phase map size must be positive, but got: {}x{}", self.width, self.height),
+			));
+		}
+		if !(self.hist_thresh > 0. && self.hist_thresh < MAX_RELIABILITY) {
+			return Err(Error::new(
+				core::StsOutOfRange,
+				format!(
+					"Histogram threshold must be in the range (0, 32*pi^2), but got: {}",
+					self.hist_thresh
+				),
+			));
+		}
+		if self.nbr_of_small_bins < 1 || self.nbr_of_large_bins < 1 {
+			return Err(Error::new(
+				core::StsOutOfRange,
+				format!(
+					"Number of histogram bins must be at least 1, but got: {} small and {} large",
+					self.nbr_of_small_bins, self.nbr_of_large_bins
+				),
+			));
+		}
+		Ok(())
+	}
+
+	/// Validates the values and creates the unwrapping algorithm with them
+	pub fn create_unwrapping(&self) -> Result<Ptr<dyn HistogramPhaseUnwrapping>> {
+		self.validate()?;
+		<dyn HistogramPhaseUnwrapping>::create(*self)
+	}
+}
+
+/// Result of [unwrap_phase]
+#[derive(Debug)]
+pub struct UnwrappedPhase {
+	/// Unwrapped `CV_32FC1` phase map
+	pub phase: Mat,
+	/// Inverse reliability of every pixel of the phase map, the lower the value, the more reliable the pixel is
+	pub inverse_reliability: Mat,
+}
+
+/// Unwraps the `CV_32FC1` phase map with values in the range [-pi, pi] using `HistogramPhaseUnwrapping` with the
+/// default parameters
+///
+/// The pixels that are 0 in the optional `CV_8UC1` `shadow_mask` are left out of the unwrapping.
+pub fn unwrap_phase(wrapped: &Mat, shadow_mask: Option<&Mat>) -> Result<UnwrappedPhase> {
+	if wrapped.typ() != CV_32FC1 {
+		return Err(Error::new(
+			core::StsUnsupportedFormat,
+			format!(
+				"Wrapped phase map must be of type CV_32FC1, but got: {}",
+				core::type_to_string(wrapped.typ())?
+			),
+		));
+	}
+	let mut unwrapping = HistogramPhaseUnwrapping_Params::for_size(wrapped.size()?)?.create_unwrapping()?;
+	let mut phase = Mat::default();
+	unwrapping.unwrap_phase_map(wrapped, &mut phase, shadow_mask.map(|mask| mask as &dyn core::ToInputArray))?;
+	let mut inverse_reliability = Mat::default();
+	unwrapping.get_inverse_reliability_map(&mut inverse_reliability)?;
+	Ok(UnwrappedPhase {
+		phase,
+		inverse_reliability,
+	})
+}
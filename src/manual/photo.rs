@@ -0,0 +1,352 @@
+use crate::{
+	core::{self, Mat, Point, Vector},
+	photo::{self, CalibrateCRF, MergeDebevec, MergeMertens, Tonemap},
+	prelude::*,
+	Error, Result,
+};
+
+/// Parameters of the non-local means denoising, the defaults match the ones of OpenCV
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DenoisingParams {
+	/// Filter strength of the luminance, the higher value removes more noise but also more details
+	pub h: f32,
+	/// Filter strength of the color components, only used for the 3 channel images
+	pub h_color: f32,
+	/// Size of the compared patches in pixels, should be odd
+	pub template_window_size: i32,
+	/// Size of the area the similar patches are searched in, should be odd
+	pub search_window_size: i32,
+}
+
+impl Default for DenoisingParams {
+	fn default() -> Self {
+		Self {
+			h: 3.,
+			h_color: 3.,
+			template_window_size: 7,
+			search_window_size: 21,
+		}
+	}
+}
+
+impl DenoisingParams {
+	/// Sets the filter strength of both the luminance and the color components
+	pub fn with_strength(self, h: f32) -> Self {
+		Self { h, h_color: h, ..self }
+	}
+
+	pub fn with_color_strength(self, h_color: f32) -> Self {
+		Self { h_color, ..self }
+	}
+
+	pub fn with_windows(self, template_window_size: i32, search_window_size: i32) -> Self {
+		Self {
+			template_window_size,
+			search_window_size,
+			..self
+		}
+	}
+
+	/// Denoises the `CV_8U` image, the 3 channel images are treated as BGR and denoised in the CIELAB space
+	pub fn denoise(&self, src: &Mat) -> Result<Mat> {
+		let mut out = Mat::default();
+		match src.channels() {
+			3 => photo::fast_nl_means_denoising_colored(
+				src,
+				&mut out,
+				self.h,
+				self.h_color,
+				self.template_window_size,
+				self.search_window_size,
+			)?,
+			_ => photo::fast_nl_means_denoising(src, &mut out, self.h, self.template_window_size, self.search_window_size)?,
+		}
+		Ok(out)
+	}
+
+	/// Denoises the frame at `index` of the sequence using `temporal_window_size` neighbouring frames, which must be odd
+	pub fn denoise_sequence(&self, frames: &Vector<Mat>, index: i32, temporal_window_size: i32) -> Result<Mat> {
+		let first = frames.get(0)?;
+		let mut out = Mat::default();
+		match first.channels() {
+			3 => photo::fast_nl_means_denoising_colored_multi(
+				frames,
+				&mut out,
+				index,
+				temporal_window_size,
+				self.h,
+				self.h_color,
+				self.template_window_size,
+				self.search_window_size,
+			)?,
+			_ => photo::fast_nl_means_denoising_multi(
+				frames,
+				&mut out,
+				index,
+				temporal_window_size,
+				self.h,
+				self.template_window_size,
+				self.search_window_size,
+			)?,
+		}
+		Ok(out)
+	}
+}
+
+/// Algorithm of [inpaint_image]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InpaintMethod {
+	/// Navier-Stokes based method
+	NavierStokes,
+	/// Fast marching method by Alexandru Telea
+	Telea,
+}
+
+/// Restores the region of the `CV_8UC1`, `CV_8UC3` (or 16/32-bit single channel) image selected by the non-zero pixels
+/// of the `CV_8UC1` mask
+///
+/// `radius` is the neighbourhood considered around every restored pixel.
+pub fn inpaint_image(src: &Mat, mask: &Mat, radius: f64, method: InpaintMethod) -> Result<Mat> {
+	let flags = match method {
+		InpaintMethod::NavierStokes => photo::INPAINT_NS,
+		InpaintMethod::Telea => photo::INPAINT_TELEA,
+	};
+	let mut out = Mat::default();
+	photo::inpaint(src, mask, &mut out, radius, flags)?;
+	Ok(out)
+}
+
+/// Blending mode of [seamless_clone_into]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CloneMode {
+	/// Gradients of the source replace the ones of the destination
+	Normal,
+	/// The stronger of the source and destination gradients is kept, good for the transparent objects and text
+	Mixed,
+	/// Only the structure of the source is transferred, the colors of the destination are kept
+	MonochromeTransfer,
+}
+
+/// Blends the region of the `CV_8UC3` `src` selected by the non-zero pixels of `mask` into `dst`, the center of the
+/// region is placed at `center` of `dst`
+///
+/// Returns the blended copy of `dst`.
+pub fn seamless_clone_into(src: &Mat, dst: &Mat, mask: &Mat, center: Point, mode: CloneMode) -> Result<Mat> {
+	let flags = match mode {
+		CloneMode::Normal => photo::NORMAL_CLONE,
+		CloneMode::Mixed => photo::MIXED_CLONE,
+		CloneMode::MonochromeTransfer => photo::MONOCHROME_TRANSFER,
+	};
+	let mut out = Mat::default();
+	photo::seamless_clone(src, dst, mask, center, &mut out, flags)?;
+	Ok(out)
+}
+
+/// Exposure fusion parameters, the defaults match the ones of OpenCV
+///
+/// Fuses the bracketed exposures directly into the LDR image without the exposure times and the HDR step.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MertensFusion {
+	pub contrast_weight: f32,
+	pub saturation_weight: f32,
+	pub exposure_weight: f32,
+}
+
+impl Default for MertensFusion {
+	fn default() -> Self {
+		Self {
+			contrast_weight: 1.,
+			saturation_weight: 1.,
+			exposure_weight: 0.,
+		}
+	}
+}
+
+impl MertensFusion {
+	pub fn with_weights(self, contrast_weight: f32, saturation_weight: f32, exposure_weight: f32) -> Self {
+		Self {
+			contrast_weight,
+			saturation_weight,
+			exposure_weight,
+		}
+	}
+
+	/// Fuses the `CV_8UC3` exposures into the `CV_32FC3` image with the values roughly in the range [0, 1]
+	pub fn fuse(&self, exposures: &Vector<Mat>) -> Result<Mat> {
+		let mut merge = photo::create_merge_mertens(self.contrast_weight, self.saturation_weight, self.exposure_weight)?;
+		let mut out = Mat::default();
+		MergeMertens::process(&mut merge, exposures, &mut out)?;
+		Ok(out)
+	}
+}
+
+/// HDR reconstruction with the camera response estimated by the Debevec method, the defaults match the ones of OpenCV
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DebevecHdr {
+	/// Number of the pixels sampled to estimate the camera response
+	pub samples: i32,
+	/// Smoothness of the estimated response
+	pub lambda: f32,
+	/// Whether the samples are picked at random instead of the regular grid
+	pub random: bool,
+}
+
+impl Default for DebevecHdr {
+	fn default() -> Self {
+		Self {
+			samples: 70,
+			lambda: 10.,
+			random: false,
+		}
+	}
+}
+
+impl DebevecHdr {
+	pub fn with_samples(self, samples: i32) -> Self {
+		Self { samples, ..self }
+	}
+
+	pub fn with_lambda(self, lambda: f32) -> Self {
+		Self { lambda, ..self }
+	}
+
+	/// Merges the `CV_8UC3` exposures taken with `exposure_times` in seconds into the `CV_32FC3` radiance map
+	pub fn merge(&self, exposures: &Vector<Mat>, exposure_times: &[f32]) -> Result<Mat> {
+		if exposures.len() != exposure_times.len() {
+			return Err(Error::new(
+				core::StsUnmatchedSizes,
+				format!(
+					"Number of the exposures ({}) doesn't match the number of the exposure times ({})",
+					exposures.len(),
+					exposure_times.len()
+				),
+			));
+		}
+		let times = Vector::<f32>::from_slice(exposure_times);
+		let mut calibrate = photo::create_calibrate_debevec(self.samples, self.lambda, self.random)?;
+		let mut response = Mat::default();
+		CalibrateCRF::process(&mut calibrate, exposures, &mut response, &times)?;
+		let mut merge = photo::create_merge_debevec()?;
+		let mut out = Mat::default();
+		MergeDebevec::process_with_response(&mut merge, exposures, &mut out, &times, &response)?;
+		Ok(out)
+	}
+}
+
+/// Tone mapping operator with its parameters, the defaults of the variants match the ones of OpenCV
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TonemapOperator {
+	/// Plain gamma correction
+	Gamma { gamma: f32 },
+	/// Adaptive logarithmic mapping
+	Drago { gamma: f32, saturation: f32, bias: f32 },
+	/// Contrast mapping in the gradient domain
+	Mantiuk { gamma: f32, scale: f32, saturation: f32 },
+	/// Global operator modelled after the photoreceptor physiology
+	Reinhard {
+		gamma: f32,
+		intensity: f32,
+		light_adapt: f32,
+		color_adapt: f32,
+	},
+}
+
+impl TonemapOperator {
+	pub fn gamma() -> Self {
+		Self::Gamma { gamma: 1. }
+	}
+
+	pub fn drago() -> Self {
+		Self::Drago {
+			gamma: 1.,
+			saturation: 1.,
+			bias: 0.85,
+		}
+	}
+
+	pub fn mantiuk() -> Self {
+		Self::Mantiuk {
+			gamma: 1.,
+			scale: 0.7,
+			saturation: 1.,
+		}
+	}
+
+	pub fn reinhard() -> Self {
+		Self::Reinhard {
+			gamma: 1.,
+			intensity: 0.,
+			light_adapt: 1.,
+			color_adapt: 0.,
+		}
+	}
+
+	/// Sets the gamma correction applied after the tone mapping, e.g. 2.2 for the display
+	pub fn with_gamma(self, new_gamma: f32) -> Self {
+		match self {
+			Self::Gamma { .. } => Self::Gamma { gamma: new_gamma },
+			Self::Drago { saturation, bias, .. } => Self::Drago {
+				gamma: new_gamma,
+				saturation,
+				bias,
+			},
+			Self::Mantiuk { scale, saturation, .. } => Self::Mantiuk {
+				gamma: new_gamma,
+				scale,
+				saturation,
+			},
+			Self::Reinhard {
+				intensity,
+				light_adapt,
+				color_adapt,
+				..
+			} => Self::Reinhard {
+				gamma: new_gamma,
+				intensity,
+				light_adapt,
+				color_adapt,
+			},
+		}
+	}
+
+	/// Creates the OpenCV algorithm for the operator
+	pub fn create(&self) -> Result<Box<dyn Tonemap>> {
+		Ok(match *self {
+			Self::Gamma { gamma } => Box::new(photo::create_tonemap(gamma)?) as Box<dyn Tonemap>,
+			Self::Drago { gamma, saturation, bias } => Box::new(photo::create_tonemap_drago(gamma, saturation, bias)?),
+			Self::Mantiuk {
+				gamma,
+				scale,
+				saturation,
+			} => Box::new(photo::create_tonemap_mantiuk(gamma, scale, saturation)?),
+			Self::Reinhard {
+				gamma,
+				intensity,
+				light_adapt,
+				color_adapt,
+			} => Box::new(photo::create_tonemap_reinhard(gamma, intensity, light_adapt, color_adapt)?),
+		})
+	}
+
+	/// Maps the `CV_32FC3` HDR image to the `CV_32FC3` LDR image with the values in the range [0, 1]
+	pub fn apply(&self, hdr: &Mat) -> Result<Mat> {
+		let mut tonemap = self.create()?;
+		let mut out = Mat::default();
+		tonemap.process(hdr, &mut out)?;
+		Ok(out)
+	}
+}
+
+impl Default for TonemapOperator {
+	fn default() -> Self {
+		Self::gamma()
+	}
+}
+
+/// Converts the `CV_32F` image with the values in the range [0, 1], e.g. the result of the tone mapping or the
+/// exposure fusion, to the `CV_8U` one, the values outside the range are saturated
+pub fn to_8bit(image: &Mat) -> Result<Mat> {
+	let mut out = Mat::default();
+	image.convert_to(&mut out, core::CV_8U, 255., 0.)?;
+	Ok(out)
+}
@@ -0,0 +1,65 @@
+use std::path::Path;
+
+use crate::{
+	core::{self, Mat, Ptr},
+	prelude::*,
+	quality::{QualityBRISQUE, QualityBase, QualityGMSD, QualityMSE, QualityPSNR, QualitySSIM},
+	Error, Result,
+};
+
+/// Full-reference quality metric created by [create_quality_metric]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum QualityMetric {
+	/// Mean squared error, lower is better
+	MSE,
+	/// Peak signal-to-noise ratio in dB, higher is better, `max_pixel_value` is 255 for the 8-bit images
+	PSNR { max_pixel_value: f64 },
+	/// Structural similarity in the range [0, 1], higher is better
+	SSIM,
+	/// Gradient magnitude similarity deviation in the range [0, 1], lower is better
+	GMSD,
+}
+
+/// Creates the full-reference quality metric comparing the images with `reference`
+///
+/// The images passed to `QualityBase::compute()` of the result must have the same size and type as `reference`. The
+/// returned `Scalar` has one value per channel.
+pub fn create_quality_metric(metric: QualityMetric, reference: &Mat) -> Result<Box<dyn QualityBase>> {
+	Ok(match metric {
+		QualityMetric::MSE => Box::new(QualityMSE::create(reference)?),
+		QualityMetric::PSNR { max_pixel_value } => Box::new(QualityPSNR::create(reference, max_pixel_value)?),
+		QualityMetric::SSIM => Box::new(QualitySSIM::create(reference)?),
+		QualityMetric::GMSD => Box::new(QualityGMSD::create(reference)?),
+	})
+}
+
+/// Loads the no-reference BRISQUE metric from the trained model and range files
+///
+/// The files are `brisque_model_live.yml` and `brisque_range_live.yml` from the samples of the `quality` module. The
+/// score is in the range [0, 100], lower is better.
+pub fn load_brisque(model_file_path: impl AsRef<Path>, range_file_path: impl AsRef<Path>) -> Result<Ptr<QualityBRISQUE>> {
+	let model_file_path = model_file_path.as_ref();
+	let range_file_path = range_file_path.as_ref();
+	// OpenCV only fails with an assertion on the empty model in this case
+	for path in [model_file_path, range_file_path] {
+		if !path.is_file() {
+			return Err(Error::new(
+				core::StsObjectNotFound,
+				format!("BRISQUE model file not found: {}", path.display()),
+			));
+		}
+	}
+	QualityBRISQUE::create(&model_file_path.to_string_lossy(), &range_file_path.to_string_lossy())
+}
+
+/// Scalar quality score for all the quality metrics
+pub trait QualityBaseTraitManual: QualityBase {
+	/// Computes the quality of the image and returns the mean of the per-channel values
+	fn compute_mean(&mut self, img: &Mat) -> Result<f64> {
+		let channels = img.channels().clamp(1, 4) as usize;
+		let score = QualityBase::compute(self, img)?;
+		Ok(score.0[..channels].iter().sum::<f64>() / channels as f64)
+	}
+}
+
+impl<T: QualityBase + ?Sized> QualityBaseTraitManual for T {}
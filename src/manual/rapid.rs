@@ -0,0 +1,138 @@
+use std::convert::TryFrom;
+
+use crate::{
+	core::{self, Mat, Point3f, TermCriteria, TermCriteria_Type, Vec3d, Vec3i, Vector},
+	prelude::*,
+	rapid::{self, OLSTracker, Rapid, Tracker},
+	Error, Result,
+};
+
+/// Triangle mesh of the tracked object
+#[derive(Debug)]
+pub struct Mesh {
+	/// Vertices in the object coordinate system
+	pub vertices: Vector<Point3f>,
+	/// Vertex indices of every triangle
+	pub triangles: Vector<Vec3i>,
+}
+
+impl Mesh {
+	/// Creates the mesh checking that all the triangles refer to the existing vertices
+	pub fn new(vertices: Vector<Point3f>, triangles: Vector<Vec3i>) -> Result<Self> {
+		let vertex_count = vertices.len();
+		if let Some(triangle) = triangles.iter().find(|triangle| {
+			triangle
+				.iter()
+				.any(|&i| usize::try_from(i).map_or(true, |i| i >= vertex_count))
+		}) {
+			return Err(Error::new(
+				core::StsOutOfRange,
+				format!(
+					"Triangle {:?} refers to a vertex out of the range [0, {})",
+					triangle, vertex_count
+				),
+			));
+		}
+		Ok(Self { vertices, triangles })
+	}
+
+	/// Creates the mesh from the `N`x3 `CV_32FC1` (or `N`x1 `CV_32FC3`) vertex matrix and the `M`x3 `CV_32SC1` (or
+	/// `M`x1 `CV_32SC3`) triangle matrix
+	pub fn from_mats(vertices: &Mat, triangles: &Mat) -> Result<Self> {
+		let mut vertices_out = Vector::new();
+		vertices.reshape(3, 0)?.copy_to(&mut vertices_out)?;
+		let mut triangles_out = Vector::new();
+		triangles.reshape(3, 0)?.copy_to(&mut triangles_out)?;
+		Self::new(vertices_out, triangles_out)
+	}
+}
+
+/// Silhouette based tracking algorithm created by [create_tracker]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TrackerKind {
+	/// Original RAPID tracker based on the edge search along the normals of the silhouette
+	Rapid,
+	/// Optimal local searching tracker using the foreground and background color histograms
+	OLS,
+}
+
+/// Creates the tracker of the passed kind for the mesh with the default parameters
+pub fn create_tracker(kind: TrackerKind, mesh: &Mesh) -> Result<Box<dyn Tracker>> {
+	Ok(match kind {
+		TrackerKind::Rapid => Box::new(<dyn Rapid>::create(&mesh.vertices, &mesh.triangles)?),
+		TrackerKind::OLS => Box::new(<dyn OLSTracker>::create(&mesh.vertices, &mesh.triangles, 8, 10)?),
+	})
+}
+
+/// Pose of the tracked object relative to the camera
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Pose {
+	/// Rotation as the Rodrigues vector
+	pub rvec: Vec3d,
+	pub tvec: Vec3d,
+}
+
+/// Parameters of [TrackerTraitManual::track]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TrackParams {
+	/// Number of the search lines along the silhouette
+	pub num_lines: i32,
+	/// Search radius along every line in pixels
+	pub line_len: i32,
+	pub criteria: TermCriteria,
+}
+
+impl Default for TrackParams {
+	/// Same as the defaults of the OpenCV tracking sample
+	fn default() -> Self {
+		Self {
+			num_lines: 100,
+			line_len: 20,
+			criteria: TermCriteria {
+				typ: TermCriteria_Type::COUNT as i32 + TermCriteria_Type::EPS as i32,
+				max_count: 5,
+				epsilon: 1.5,
+			},
+		}
+	}
+}
+
+/// Pose refinement for all the `rapid` trackers
+pub trait TrackerTraitManual: Tracker {
+	/// Refines the pose of the object in the frame, `pose` is the initial estimate and receives the result
+	///
+	/// `k` is the 3x3 camera matrix. Returns the ratio of the search lines that could be matched, a low value means the
+	/// track is lost.
+	fn track(&mut self, frame: &Mat, k: &Mat, pose: &mut Pose, params: &TrackParams) -> Result<f32> {
+		self.compute(
+			frame,
+			params.num_lines,
+			params.line_len,
+			k,
+			&mut pose.rvec,
+			&mut pose.tvec,
+			params.criteria,
+		)
+	}
+}
+
+impl<T: Tracker + ?Sized> TrackerTraitManual for T {}
+
+/// Single step of the stateless RAPID tracking, see [TrackerTraitManual::track]
+///
+/// Returns the ratio of the matched search lines and the RMS of the 2D reprojection difference.
+pub fn rapid_step(frame: &Mat, mesh: &Mesh, k: &Mat, pose: &mut Pose, num_lines: i32, line_len: i32) -> Result<(f32, f64)> {
+	let mut rmsd = 0.;
+	let ratio = rapid::rapid(
+		frame,
+		num_lines,
+		line_len,
+		&mesh.vertices,
+		&mesh.triangles,
+		k,
+		&mut pose.rvec,
+		&mut pose.tvec,
+		&mut rmsd,
+	)?;
+	Ok((ratio, rmsd))
+}
@@ -0,0 +1,168 @@
+use std::ffi::c_void;
+
+use crate::{
+	core::{Mat, Ptr, ToInputArray, Vec4f, Vector},
+	prelude::*,
+	rgbd::{DepthCleanerTraitConst, Odometry, OdometryConst, RgbdNormalsTraitConst, RgbdPlaneTrait},
+	sys, Result,
+};
+
+extern "C" {
+	fn cv_manual_rgbd_RgbdNormals_apply(
+		instance: *const c_void,
+		points: *const c_void,
+		normals: *mut c_void,
+		ocvrs_return: *mut sys::Result_void,
+	);
+	fn cv_manual_rgbd_RgbdPlane_apply(
+		instance: *mut c_void,
+		points3d: *const c_void,
+		normals: *const c_void,
+		mask: *mut c_void,
+		plane_coefficients: *mut c_void,
+		ocvrs_return: *mut sys::Result_void,
+	);
+	fn cv_manual_rgbd_DepthCleaner_apply(
+		instance: *const c_void,
+		points: *const c_void,
+		depth: *mut c_void,
+		ocvrs_return: *mut sys::Result_void,
+	);
+}
+
+/// Algorithm of the visual odometry created by [create_odometry]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OdometryKind {
+	/// Photometric alignment of the intensity images, `RgbdOdometry`
+	Rgbd,
+	/// Geometric alignment of the point clouds, `ICPOdometry`
+	ICP,
+	/// Combination of both alignments, `RgbdICPOdometry`
+	RgbdICP,
+	/// Faster geometric alignment with the bilateral filtered depth, `FastICPOdometry`
+	FastICP,
+}
+
+impl OdometryKind {
+	fn name(self) -> &'static str {
+		match self {
+			OdometryKind::Rgbd => "RgbdOdometry",
+			OdometryKind::ICP => "ICPOdometry",
+			OdometryKind::RgbdICP => "RgbdICPOdometry",
+			OdometryKind::FastICP => "FastICPOdometry",
+		}
+	}
+}
+
+/// Creates the odometry of the passed kind for the camera with the 3x3 `CV_32FC1` or `CV_64FC1` intrinsic matrix, the
+/// other parameters keep their default values
+pub fn create_odometry(kind: OdometryKind, camera_matrix: &Mat) -> Result<Ptr<dyn Odometry>> {
+	let mut out = <dyn Odometry>::create(kind.name())?;
+	out.set_camera_matrix(camera_matrix)?;
+	Ok(out)
+}
+
+/// Transformation computation without the masks and the initial estimate for all the odometry algorithms
+pub trait OdometryTraitConstManual: OdometryConst {
+	/// Computes the 4x4 `CV_64FC1` rigid transformation from the source frame to the destination one
+	///
+	/// The depth images are `CV_32FC1` in meters, the intensity images are `CV_8UC1` and can be empty for the
+	/// `OdometryKind::ICP` and `OdometryKind::FastICP` algorithms. Returns `None` if the algorithm didn't converge.
+	fn compute_transform(&self, src_image: &Mat, src_depth: &Mat, dst_image: &Mat, dst_depth: &Mat) -> Result<Option<Mat>> {
+		let no_mask = Mat::default();
+		let mut rt = Mat::default();
+		let converged = self.compute(src_image, src_depth, &no_mask, dst_image, dst_depth, &no_mask, &mut rt, None)?;
+		Ok(converged.then(|| rt))
+	}
+}
+
+impl<T: OdometryConst + ?Sized> OdometryTraitConstManual for T {}
+
+/// Normals computation of `RgbdNormals`, `operator()` is not exported by the generated bindings
+pub trait RgbdNormalsTraitConstManual: RgbdNormalsTraitConst {
+	/// Computes the normals of the organized point cloud
+	///
+	/// `points` is a `CV_32FC3` or `CV_64FC3` image of the 3D points with the size and depth of the `RgbdNormals`, e.g.
+	/// from `rgbd::depth_to3d()`. Returns the image of the unit normals of the same type.
+	fn compute_normals(&self, points: &dyn ToInputArray) -> Result<Mat> {
+		input_array_arg!(points);
+		let mut normals = Mat::default();
+		return_send!(via ocvrs_return);
+		unsafe {
+			cv_manual_rgbd_RgbdNormals_apply(
+				self.as_raw_RgbdNormals(),
+				points.as_raw__InputArray(),
+				normals.as_raw_mut_Mat(),
+				ocvrs_return.as_mut_ptr(),
+			)
+		};
+		return_receive!(unsafe ocvrs_return => ret);
+		ret.into_result()?;
+		Ok(normals)
+	}
+}
+
+impl<T: RgbdNormalsTraitConst + ?Sized> RgbdNormalsTraitConstManual for T {}
+
+/// Result of [RgbdPlaneTraitManual::segment_planes]
+#[derive(Debug)]
+pub struct PlaneSegmentation {
+	/// `CV_8UC1` image with the index of the plane every point belongs to, 255 for the points that are not on any plane
+	pub mask: Mat,
+	/// Coefficients `(a, b, c, d)` of every plane `a*x + b*y + c*z + d = 0`, indexed by the values of the mask
+	pub coefficients: Vector<Vec4f>,
+}
+
+/// Plane segmentation of `RgbdPlane`, `operator()` is not exported by the generated bindings
+pub trait RgbdPlaneTraitManual: RgbdPlaneTrait {
+	/// Finds the planes in the organized point cloud
+	///
+	/// `points3d` is a `CV_32FC3` or `CV_64FC3` image of the 3D points, e.g. from `rgbd::depth_to3d()`. The optional
+	/// `normals` of the points, e.g. from [RgbdNormalsTraitConstManual::compute_normals], make the segmentation faster
+	/// and more precise.
+	fn segment_planes(&mut self, points3d: &dyn ToInputArray, normals: Option<&dyn ToInputArray>) -> Result<PlaneSegmentation> {
+		input_array_arg!(points3d);
+		input_array_arg!(nullable normals);
+		let mut mask = Mat::default();
+		let mut coefficients = Vector::new();
+		return_send!(via ocvrs_return);
+		unsafe {
+			cv_manual_rgbd_RgbdPlane_apply(
+				self.as_raw_mut_RgbdPlane(),
+				points3d.as_raw__InputArray(),
+				normals.as_raw__InputArray(),
+				mask.as_raw_mut_Mat(),
+				coefficients.as_raw_mut_VectorOfVec4f(),
+				ocvrs_return.as_mut_ptr(),
+			)
+		};
+		return_receive!(unsafe ocvrs_return => ret);
+		ret.into_result()?;
+		Ok(PlaneSegmentation { mask, coefficients })
+	}
+}
+
+impl<T: RgbdPlaneTrait + ?Sized> RgbdPlaneTraitManual for T {}
+
+/// Depth denoising of `DepthCleaner`, `operator()` is not exported by the generated bindings
+pub trait DepthCleanerTraitConstManual: DepthCleanerTraitConst {
+	/// Returns the denoised version of the depth image with the size and depth of the `DepthCleaner`
+	fn clean_depth(&self, depth: &dyn ToInputArray) -> Result<Mat> {
+		input_array_arg!(depth);
+		let mut out = Mat::default();
+		return_send!(via ocvrs_return);
+		unsafe {
+			cv_manual_rgbd_DepthCleaner_apply(
+				self.as_raw_DepthCleaner(),
+				depth.as_raw__InputArray(),
+				out.as_raw_mut_Mat(),
+				ocvrs_return.as_mut_ptr(),
+			)
+		};
+		return_receive!(unsafe ocvrs_return => ret);
+		ret.into_result()?;
+		Ok(out)
+	}
+}
+
+impl<T: DepthCleanerTraitConst + ?Sized> DepthCleanerTraitConstManual for T {}
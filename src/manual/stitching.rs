@@ -0,0 +1,217 @@
+use std::ffi::c_void;
+
+use crate::{
+	core::{self, Mat, Ptr, ToInputArray},
+	stitching::{
+		Detail_Blender, Detail_ExposureCompensator, Detail_ExposureCompensator_CHANNELS,
+		Detail_ExposureCompensator_CHANNELS_BLOCKS, Detail_ExposureCompensator_GAIN, Detail_ExposureCompensator_GAIN_BLOCKS,
+		Detail_ExposureCompensator_NO, Detail_SeamFinder, Stitcher, StitcherTrait, Stitcher_Mode, Stitcher_Status, WarperCreator,
+	},
+	sys,
+	traits::Boxed,
+	Error, Result,
+};
+
+extern "C" {
+	fn cv_manual_stitching_createWarper(kind: i32, a: f32, b: f32, ocvrs_return: *mut sys::Result<*mut c_void>);
+	fn cv_manual_stitching_createBlender(kind: i32, sharpness: f32, num_bands: i32, ocvrs_return: *mut sys::Result<*mut c_void>);
+	fn cv_manual_stitching_createSeamFinder(kind: i32, ocvrs_return: *mut sys::Result<*mut c_void>);
+}
+
+/// Projection of the panorama surface, the warper component of `Stitcher`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum WarperKind {
+	Plane,
+	/// Default for `Stitcher_Mode::SCANS`
+	Affine,
+	Cylindrical,
+	/// Default for `Stitcher_Mode::PANORAMA`
+	Spherical,
+	Fisheye,
+	Stereographic,
+	CompressedRectilinear {
+		a: f32,
+		b: f32,
+	},
+	CompressedRectilinearPortrait {
+		a: f32,
+		b: f32,
+	},
+	Panini {
+		a: f32,
+		b: f32,
+	},
+	PaniniPortrait {
+		a: f32,
+		b: f32,
+	},
+	Mercator,
+	TransverseMercator,
+}
+
+impl WarperKind {
+	fn to_raw(self) -> (i32, f32, f32) {
+		match self {
+			WarperKind::Plane => (0, 1., 1.),
+			WarperKind::Affine => (1, 1., 1.),
+			WarperKind::Cylindrical => (2, 1., 1.),
+			WarperKind::Spherical => (3, 1., 1.),
+			WarperKind::Fisheye => (4, 1., 1.),
+			WarperKind::Stereographic => (5, 1., 1.),
+			WarperKind::CompressedRectilinear { a, b } => (6, a, b),
+			WarperKind::CompressedRectilinearPortrait { a, b } => (7, a, b),
+			WarperKind::Panini { a, b } => (8, a, b),
+			WarperKind::PaniniPortrait { a, b } => (9, a, b),
+			WarperKind::Mercator => (10, 1., 1.),
+			WarperKind::TransverseMercator => (11, 1., 1.),
+		}
+	}
+}
+
+/// Blending of the overlapping images, the blender component of `Stitcher`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum BlenderKind {
+	/// Images are pasted over each other
+	No,
+	/// Weighted average with the weights decreasing towards the image borders, see `Detail_FeatherBlender`
+	Feather { sharpness: f32 },
+	/// Blending in the Laplacian pyramid with `num_bands` levels, the default, see `Detail_MultiBandBlender`
+	MultiBand { num_bands: i32 },
+}
+
+/// Search of the seams between the overlapping images, the seam finder component of `Stitcher`
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SeamFinderKind {
+	/// Whole overlap is kept in all the images
+	No,
+	/// Seams are placed in the middle of the overlaps
+	Voronoi,
+	/// Dynamic programming minimizing the color difference
+	DpColor,
+	/// Dynamic programming minimizing the color and gradient difference
+	DpColorGrad,
+	/// Graph cut minimizing the color difference
+	GraphCutColor,
+	/// Graph cut minimizing the color and gradient difference, the default
+	GraphCutColorGrad,
+}
+
+/// Compensation of the exposure differences between the images, the exposure compensator component of `Stitcher`
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExposureCompensatorKind {
+	No,
+	/// Single gain per image
+	Gain,
+	/// Gain per image block, the default for `Stitcher_Mode::PANORAMA`
+	GainBlocks,
+	/// Gain per image channel
+	Channels,
+	/// Gain per image block and channel
+	ChannelsBlocks,
+}
+
+/// Creates the warper of the passed kind
+pub fn create_warper(kind: WarperKind) -> Result<Ptr<dyn WarperCreator>> {
+	let (kind, a, b) = kind.to_raw();
+	return_send!(via ocvrs_return);
+	unsafe { cv_manual_stitching_createWarper(kind, a, b, ocvrs_return.as_mut_ptr()) };
+	return_receive!(unsafe ocvrs_return => ret);
+	ret.into_result().map(|ptr| unsafe { Ptr::from_raw(ptr) })
+}
+
+/// Creates the blender of the passed kind
+pub fn create_blender(kind: BlenderKind) -> Result<Ptr<Detail_Blender>> {
+	let (kind, sharpness, num_bands) = match kind {
+		BlenderKind::No => (0, 0., 0),
+		BlenderKind::Feather { sharpness } => (1, sharpness, 0),
+		BlenderKind::MultiBand { num_bands } => (2, 0., num_bands),
+	};
+	return_send!(via ocvrs_return);
+	unsafe { cv_manual_stitching_createBlender(kind, sharpness, num_bands, ocvrs_return.as_mut_ptr()) };
+	return_receive!(unsafe ocvrs_return => ret);
+	ret.into_result().map(|ptr| unsafe { Ptr::from_raw(ptr) })
+}
+
+/// Creates the seam finder of the passed kind
+pub fn create_seam_finder(kind: SeamFinderKind) -> Result<Ptr<dyn Detail_SeamFinder>> {
+	let kind = match kind {
+		SeamFinderKind::No => 0,
+		SeamFinderKind::Voronoi => 1,
+		SeamFinderKind::DpColor => 2,
+		SeamFinderKind::DpColorGrad => 3,
+		SeamFinderKind::GraphCutColor => 4,
+		SeamFinderKind::GraphCutColorGrad => 5,
+	};
+	return_send!(via ocvrs_return);
+	unsafe { cv_manual_stitching_createSeamFinder(kind, ocvrs_return.as_mut_ptr()) };
+	return_receive!(unsafe ocvrs_return => ret);
+	ret.into_result().map(|ptr| unsafe { Ptr::from_raw(ptr) })
+}
+
+/// Creates the exposure compensator of the passed kind
+pub fn create_exposure_compensator(kind: ExposureCompensatorKind) -> Result<Ptr<dyn Detail_ExposureCompensator>> {
+	<dyn Detail_ExposureCompensator>::create_default(match kind {
+		ExposureCompensatorKind::No => Detail_ExposureCompensator_NO,
+		ExposureCompensatorKind::Gain => Detail_ExposureCompensator_GAIN,
+		ExposureCompensatorKind::GainBlocks => Detail_ExposureCompensator_GAIN_BLOCKS,
+		ExposureCompensatorKind::Channels => Detail_ExposureCompensator_CHANNELS,
+		ExposureCompensatorKind::ChannelsBlocks => Detail_ExposureCompensator_CHANNELS_BLOCKS,
+	})
+}
+
+impl Stitcher_Status {
+	/// Converts the status into `Err` unless it's `OK`
+	pub fn into_result(self) -> Result<()> {
+		match self {
+			Stitcher_Status::OK => Ok(()),
+			Stitcher_Status::ERR_NEED_MORE_IMGS => Err(Error::new(
+				core::StsError,
+				"Stitching failed: not enough images or overlapping features between them",
+			)),
+			Stitcher_Status::ERR_HOMOGRAPHY_EST_FAIL => {
+				Err(Error::new(core::StsError, "Stitching failed: homography estimation failed"))
+			}
+			Stitcher_Status::ERR_CAMERA_PARAMS_ADJUST_FAIL => Err(Error::new(
+				core::StsError,
+				"Stitching failed: camera parameters adjustment failed",
+			)),
+		}
+	}
+}
+
+/// Configuration of the `Stitcher` components without the `detail` layer
+pub trait StitcherTraitManual: StitcherTrait {
+	/// Sets the projection of the panorama surface
+	fn set_warper_kind(&mut self, kind: WarperKind) -> Result<()> {
+		self.set_warper(create_warper(kind)?)
+	}
+
+	/// Sets the blending of the overlapping images
+	fn set_blender_kind(&mut self, kind: BlenderKind) -> Result<()> {
+		self.set_blender(create_blender(kind)?)
+	}
+
+	/// Sets the search of the seams between the overlapping images
+	fn set_seam_finder_kind(&mut self, kind: SeamFinderKind) -> Result<()> {
+		self.set_seam_finder(create_seam_finder(kind)?)
+	}
+
+	/// Sets the compensation of the exposure differences between the images
+	fn set_exposure_compensator_kind(&mut self, kind: ExposureCompensatorKind) -> Result<()> {
+		self.set_exposure_compensator(create_exposure_compensator(kind)?)
+	}
+
+	/// Stitches the images into the panorama, the unsuccessful [Stitcher_Status] is returned as `Err`
+	fn stitch_panorama(&mut self, images: &dyn ToInputArray) -> Result<Mat> {
+		let mut pano = Mat::default();
+		self.stitch(images, &mut pano)?.into_result()?;
+		Ok(pano)
+	}
+}
+
+impl<T: StitcherTrait + ?Sized> StitcherTraitManual for T {}
+
+/// Stitches the images into the panorama with the default `Stitcher` for the `mode`
+pub fn stitch_images(images: &dyn ToInputArray, mode: Stitcher_Mode) -> Result<Mat> {
+	Stitcher::create(mode)?.stitch_panorama(images)
+}
@@ -0,0 +1,112 @@
+use crate::{
+	core::{self, Mat, Point, Ptr, Size, ToInputArray, Vector},
+	prelude::*,
+	structured_light::{
+		self, GrayCodePatternConst, SinusoidalPattern, SinusoidalPattern_Params, StructuredLightPattern,
+		StructuredLightPatternConst,
+	},
+	Error, Result,
+};
+
+/// Phase computation method of `SinusoidalPattern`
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SinusoidalMethod {
+	/// Fourier transform profilometry, a single pattern
+	FTP,
+	/// Phase shifting profilometry, 3 shifted patterns
+	PSP,
+	/// Fourier assisted phase shifting profilometry, 3 shifted patterns
+	FAPS,
+}
+
+impl SinusoidalMethod {
+	fn to_raw(self) -> i32 {
+		match self {
+			SinusoidalMethod::FTP => structured_light::FTP,
+			SinusoidalMethod::PSP => structured_light::PSP,
+			SinusoidalMethod::FAPS => structured_light::FAPS,
+		}
+	}
+}
+
+/// Creates `SinusoidalPattern` for the projector of `size`, the other parameters keep their default values
+pub fn create_sinusoidal_pattern(
+	size: Size,
+	nbr_of_periods: i32,
+	method: SinusoidalMethod,
+	horizontal: bool,
+) -> Result<Ptr<dyn SinusoidalPattern>> {
+	let mut params = SinusoidalPattern_Params::default()?;
+	params.set_width(size.width);
+	params.set_height(size.height);
+	params.set_nbr_of_periods(nbr_of_periods);
+	params.set_method_id(method.to_raw());
+	params.set_horizontal(horizontal);
+	<dyn SinusoidalPattern>::create(Ptr::new(params))
+}
+
+/// Pattern generation returning the images for all the structured light patterns
+pub trait StructuredLightPatternTraitManual: StructuredLightPattern {
+	/// Generates the images to be projected in the order of the projection
+	fn generate_patterns(&mut self) -> Result<Vector<Mat>> {
+		let mut out = Vector::new();
+		if !self.generate(&mut out)? {
+			return Err(Error::new(core::StsError, "Structured light pattern generation failed"));
+		}
+		Ok(out)
+	}
+}
+
+impl<T: StructuredLightPattern + ?Sized> StructuredLightPatternTraitManual for T {}
+
+/// Decoding returning the disparity map for all the structured light patterns
+pub trait StructuredLightPatternTraitConstManual: StructuredLightPatternConst {
+	/// Decodes the captured patterns into the disparity map
+	///
+	/// `pattern_images` has one sequence of the captured images per camera, `black_images` and `white_images` have one
+	/// fully unlit and lit image per camera, they are used to compute the shadow masks.
+	fn decode_disparity(
+		&self,
+		pattern_images: &Vector<Vector<Mat>>,
+		black_images: Option<&Vector<Mat>>,
+		white_images: Option<&Vector<Mat>>,
+	) -> Result<Mat> {
+		let mut disparity = Mat::default();
+		let decoded = self.decode(
+			pattern_images,
+			&mut disparity,
+			black_images.map(|images| images as &dyn ToInputArray),
+			white_images.map(|images| images as &dyn ToInputArray),
+			structured_light::DECODE_3D_UNDERWORLD,
+		)?;
+		if !decoded {
+			return Err(Error::new(core::StsError, "Structured light pattern decoding failed"));
+		}
+		Ok(disparity)
+	}
+}
+
+impl<T: StructuredLightPatternConst + ?Sized> StructuredLightPatternTraitConstManual for T {}
+
+/// Typed queries of `GrayCodePattern`
+pub trait GrayCodePatternTraitConstManual: GrayCodePatternConst {
+	/// Fully unlit and fully lit images to be projected in addition to the patterns to compute the shadow masks
+	fn shadow_mask_images(&self) -> Result<(Mat, Mat)> {
+		let mut black = Mat::default();
+		let mut white = Mat::default();
+		self.get_images_for_shadow_masks(&mut black, &mut white)?;
+		Ok((black, white))
+	}
+
+	/// Projector pixel that lit the camera pixel at `(x, y)` in the captured `pattern_images`
+	///
+	/// Returns `None` if the pixel can't be decoded, e.g. because it's in the shadow.
+	fn proj_pixel(&self, pattern_images: &Vector<Mat>, x: i32, y: i32) -> Result<Option<Point>> {
+		let mut proj_pix = Point::default();
+		// OpenCV returns `true` when the pixel is not decodable
+		let failed = self.get_proj_pixel(pattern_images, x, y, &mut proj_pix)?;
+		Ok((!failed).then(|| proj_pix))
+	}
+}
+
+impl<T: GrayCodePatternConst + ?Sized> GrayCodePatternTraitConstManual for T {}
@@ -11,6 +11,8 @@ use crate::{types::Unit, Error, Result as CrateResult};
 pub struct Result<S, O = S> {
 	pub error_code: i32,
 	pub error_msg: *mut c_void,
+	/// Name of the C++ function that raised the error, NULL if it's unknown, only valid when `error_msg` is not NULL
+	pub error_func: *mut c_void,
 	pub result: MaybeUninit<S>,
 	_p: PhantomData<O>,
 }
@@ -21,9 +23,13 @@ impl<S: Into<O>, O> Result<S, O> {
 		if self.error_msg.is_null() {
 			Ok(unsafe { self.result.assume_init() }.into())
 		} else {
-			Err(Error::new(self.error_code, unsafe {
+			let mut err = Error::new(self.error_code, unsafe {
 				crate::templ::receive_string(self.error_msg as *mut String)
-			}))
+			});
+			if !self.error_func.is_null() {
+				err.function = Some(unsafe { crate::templ::receive_string(self.error_func as *mut String) });
+			}
+			Err(err)
 		}
 	}
 }
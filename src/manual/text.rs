@@ -0,0 +1,203 @@
+use crate::{
+	core::{self, Mat, Rect, ToInputArray, Vec2i, Vector},
+	prelude::*,
+	text::{self, BaseOCR, ERStat},
+	Error, Result,
+};
+
+/// Granularity of the components returned by [BaseOCRTraitManual::recognize]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OCRLevel {
+	/// Every recognized word is a separate component
+	Word,
+	/// Every recognized line of text is a separate component
+	TextLine,
+}
+
+impl OCRLevel {
+	fn to_raw(self) -> i32 {
+		match self {
+			OCRLevel::Word => text::OCR_LEVEL_WORD,
+			OCRLevel::TextLine => text::OCR_LEVEL_TEXTLINE,
+		}
+	}
+}
+
+/// Single word or line of the text recognized by [BaseOCRTraitManual::recognize]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OCRComponent {
+	/// Location of the component in the image
+	pub rect: Rect,
+	pub text: String,
+	/// Confidence of the recognition, the range depends on the algorithm, e.g. 0-100 for `OCRTesseract`
+	pub confidence: f32,
+}
+
+/// Result of [BaseOCRTraitManual::recognize]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OCRResult {
+	/// Whole recognized text
+	pub text: String,
+	/// Recognized words or lines with their locations
+	pub components: Vec<OCRComponent>,
+}
+
+/// Recognition returning the text together with its components for all the OCR algorithms (`OCRTesseract`,
+/// `OCRHMMDecoder`, `OCRBeamSearchDecoder`, `OCRHolisticWordRecognizer`)
+pub trait BaseOCRTraitManual: BaseOCR {
+	/// Recognizes the text in the image
+	fn recognize(&mut self, image: &mut Mat, level: OCRLevel) -> Result<OCRResult> {
+		let mut text = String::new();
+		let mut rects = Vector::new();
+		let mut texts = Vector::new();
+		let mut confidences = Vector::new();
+		BaseOCR::run(
+			self,
+			image,
+			&mut text,
+			&mut rects,
+			&mut texts,
+			&mut confidences,
+			level.to_raw(),
+		)?;
+		Ok(OCRResult {
+			text,
+			components: collect_components(rects, texts, confidences),
+		})
+	}
+
+	/// Recognizes the text in the image only considering the non-zero pixels of the mask
+	fn recognize_mask(&mut self, image: &mut Mat, mask: &mut Mat, level: OCRLevel) -> Result<OCRResult> {
+		let mut text = String::new();
+		let mut rects = Vector::new();
+		let mut texts = Vector::new();
+		let mut confidences = Vector::new();
+		BaseOCR::run_mask(
+			self,
+			image,
+			mask,
+			&mut text,
+			&mut rects,
+			&mut texts,
+			&mut confidences,
+			level.to_raw(),
+		)?;
+		Ok(OCRResult {
+			text,
+			components: collect_components(rects, texts, confidences),
+		})
+	}
+}
+
+impl<T: BaseOCR + ?Sized> BaseOCRTraitManual for T {}
+
+fn collect_components(rects: Vector<Rect>, texts: Vector<String>, confidences: Vector<f32>) -> Vec<OCRComponent> {
+	rects
+		.into_iter()
+		.zip(texts)
+		.zip(confidences)
+		.map(|((rect, text), confidence)| OCRComponent { rect, text, confidence })
+		.collect()
+}
+
+/// Parameters of [detect_text_regions], the defaults match the ones of the OpenCV scene text detection sample
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TextDetectionParams {
+	/// Channels the extremal regions are searched in, `text::ERFILTER_NM_RGBLGrad` or `text::ERFILTER_NM_IHSGrad`
+	pub channels_mode: i32,
+	/// Whether the inverted channels are searched too, needed to find both the dark and the light text
+	pub inverted_channels: bool,
+	/// Threshold step of the first stage filter
+	pub threshold_delta: i32,
+	/// Minimum area of the region relative to the image area
+	pub min_area: f32,
+	/// Maximum area of the region relative to the image area
+	pub max_area: f32,
+	/// Minimum probability of the region to pass the first stage filter
+	pub min_probability_nm1: f32,
+	/// Whether the non-maximum suppression is done in the first stage filter
+	pub non_max_suppression: bool,
+	/// Minimum probability difference between the local maxima and minima of the regions
+	pub min_probability_diff: f32,
+	/// Minimum probability of the region to pass the second stage filter
+	pub min_probability_nm2: f32,
+}
+
+impl Default for TextDetectionParams {
+	fn default() -> Self {
+		Self {
+			channels_mode: text::ERFILTER_NM_RGBLGrad,
+			inverted_channels: true,
+			threshold_delta: 16,
+			min_area: 0.00015,
+			max_area: 0.13,
+			min_probability_nm1: 0.2,
+			non_max_suppression: true,
+			min_probability_diff: 0.1,
+			min_probability_nm2: 0.5,
+		}
+	}
+}
+
+/// Detects the horizontal text blocks in the scene image with the 2 stage extremal region filter
+///
+/// `classifier_nm1` and `classifier_nm2` are the paths to the trained classifiers of the stages, e.g.
+/// `trained_classifierNM1.xml` and `trained_classifierNM2.xml` from the samples of the `text` module. The image must
+/// be a `CV_8UC3` one.
+pub fn detect_text_regions(
+	image: &(impl ToInputArray + ?Sized),
+	classifier_nm1: &str,
+	classifier_nm2: &str,
+	params: &TextDetectionParams,
+) -> Result<Vector<Rect>> {
+	if params.min_area > params.max_area {
+		return Err(Error::new(
+			core::StsBadArg,
+			format!(
+				"Minimum area must not exceed the maximum one, but got: {} > {}",
+				params.min_area, params.max_area
+			),
+		));
+	}
+	let mut channels = Vector::<Mat>::new();
+	text::compute_nm_channels(image, &mut channels, params.channels_mode)?;
+	if params.inverted_channels {
+		for i in 0..channels.len() {
+			let mut inverted = Mat::default();
+			core::bitwise_not(&channels.get(i)?, &mut inverted, None::<&Mat>)?;
+			channels.push(inverted);
+		}
+	}
+
+	let mut nm1 = text::create_er_filter_nm1(
+		&text::load_classifier_nm1(classifier_nm1)?,
+		params.threshold_delta,
+		params.min_area,
+		params.max_area,
+		params.min_probability_nm1,
+		params.non_max_suppression,
+		params.min_probability_diff,
+	)?;
+	let mut nm2 = text::create_er_filter_nm2(&text::load_classifier_nm2(classifier_nm2)?, params.min_probability_nm2)?;
+	let mut regions = Vector::<Vector<ERStat>>::new();
+	for channel in &channels {
+		let mut channel_regions = Vector::new();
+		nm1.run(&channel, &mut channel_regions)?;
+		nm2.run(&channel, &mut channel_regions)?;
+		regions.push(channel_regions);
+	}
+
+	let mut groups = Vector::<Vector<Vec2i>>::new();
+	let mut rects = Vector::new();
+	text::er_grouping(
+		image,
+		&channels,
+		&mut regions,
+		&mut groups,
+		&mut rects,
+		text::ERGROUPING_ORIENTATION_HORIZ,
+		"",
+		0.5,
+	)?;
+	Ok(rects)
+}
@@ -0,0 +1,103 @@
+use crate::{
+	core::{Mat, Rect},
+	tracking::{TrackerCSRT, TrackerKCF, TrackerKCF_Params},
+	video::{self, TrackerMIL, TrackerMIL_Params},
+	Result,
+};
+
+/// Object safe single object tracker with the simplified interface
+///
+/// Implemented for all the trackers of the `video` and `tracking` modules (`TrackerCSRT`, `TrackerKCF`, `TrackerMIL`,
+/// ...). The method names differ from the ones of `video::Tracker` so that the calls stay unambiguous with both traits
+/// in scope.
+pub trait DynTracker {
+	/// Initializes the tracker with the bounding box of the target in the first frame
+	fn init_tracking(&mut self, image: &Mat, bounding_box: Rect) -> Result<()>;
+
+	/// Locates the target in the next frame
+	///
+	/// Returns `None` if the target is not found, which doesn't necessarily mean that the tracking has failed, the target
+	/// can be e.g. occluded or out of sight.
+	fn track(&mut self, image: &Mat) -> Result<Option<Rect>>;
+}
+
+impl<T: video::Tracker + ?Sized> DynTracker for T {
+	#[inline]
+	fn init_tracking(&mut self, image: &Mat, bounding_box: Rect) -> Result<()> {
+		video::Tracker::init(self, image, bounding_box)
+	}
+
+	#[inline]
+	fn track(&mut self, image: &Mat) -> Result<Option<Rect>> {
+		let mut bounding_box = Rect::default();
+		Ok(video::Tracker::update(self, image, &mut bounding_box)?.then(|| bounding_box))
+	}
+}
+
+/// Tracking algorithm created by [create_tracker]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TrackerKind {
+	/// Discriminative correlation filter with channel and spatial reliability, slower but more accurate
+	CSRT,
+	/// Kernelized correlation filter, fast
+	KCF,
+	/// Multiple instance learning, robust to the partial occlusions
+	MIL,
+}
+
+/// Creates the tracker of the passed kind with the default parameters
+pub fn create_tracker(kind: TrackerKind) -> Result<Box<dyn DynTracker>> {
+	Ok(match kind {
+		TrackerKind::CSRT => Box::new(<dyn TrackerCSRT>::create(None)?),
+		TrackerKind::KCF => Box::new(<dyn TrackerKCF>::create(TrackerKCF_Params::default()?)?),
+		TrackerKind::MIL => Box::new(<dyn TrackerMIL>::create(TrackerMIL_Params::default()?)?),
+	})
+}
+
+/// Tracking of multiple objects each with its own single object tracker
+///
+/// Counterpart of the legacy `cv::MultiTracker`, the trackers are updated one after another with the same frame.
+#[derive(Default)]
+pub struct MultiTracker {
+	trackers: Vec<Box<dyn DynTracker>>,
+	objects: Vec<Option<Rect>>,
+}
+
+impl MultiTracker {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Initializes the tracker with the bounding box of the new object in the current frame and adds it
+	///
+	/// Returns the index of the object in the result of [MultiTracker::update].
+	pub fn add(&mut self, mut tracker: Box<dyn DynTracker>, image: &Mat, bounding_box: Rect) -> Result<usize> {
+		tracker.init_tracking(image, bounding_box)?;
+		self.trackers.push(tracker);
+		self.objects.push(Some(bounding_box));
+		Ok(self.trackers.len() - 1)
+	}
+
+	/// Locates all the objects in the next frame, `None` for the objects that are not found
+	pub fn update(&mut self, image: &Mat) -> Result<&[Option<Rect>]> {
+		for (tracker, object) in self.trackers.iter_mut().zip(&mut self.objects) {
+			*object = tracker.track(image)?;
+		}
+		Ok(&self.objects)
+	}
+
+	/// Bounding boxes of the objects from the last update, `None` for the objects that were not found
+	pub fn objects(&self) -> &[Option<Rect>] {
+		&self.objects
+	}
+
+	/// Number of the tracked objects
+	pub fn len(&self) -> usize {
+		self.trackers.len()
+	}
+
+	/// Whether no objects are tracked
+	pub fn is_empty(&self) -> bool {
+		self.trackers.is_empty()
+	}
+}
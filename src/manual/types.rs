@@ -13,6 +13,10 @@ impl From<Unit> for () {
 	fn from(_: Unit) -> Self {}
 }
 
+#[allow(non_camel_case_types)]
+#[deprecated(note = "size_t is now exposed as usize, use VectorOfusize")]
+pub type VectorOfsize_t = types::VectorOfusize;
+
 input_output_array! { types::VectorOfMat, from_mat_vec, from_mat_vec_mut }
 
 input_output_array! { types::VectorOfUMat, from_umat_vec, from_umat_vec_mut }
@@ -0,0 +1,27 @@
+use crate::{
+	core::{Mat, ToInputArray},
+	video::{BackgroundSubtractor, BackgroundSubtractorConst},
+	Result,
+};
+
+/// Convenience methods of all the background subtractors, including the ones of the `bgsegm` module
+pub trait BackgroundSubtractorTraitManual: BackgroundSubtractor {
+	/// Updates the background model with the next frame and returns the foreground mask
+	///
+	/// Negative `learning_rate` lets the algorithm choose it automatically, 0 leaves the model unchanged and 1
+	/// reinitializes it from the last frame.
+	fn apply_mask(&mut self, image: &dyn ToInputArray, learning_rate: f64) -> Result<Mat> {
+		let mut fgmask = Mat::default();
+		self.apply(image, &mut fgmask, learning_rate)?;
+		Ok(fgmask)
+	}
+
+	/// Current background image, empty if the algorithm doesn't maintain one
+	fn background_image(&self) -> Result<Mat> {
+		let mut out = Mat::default();
+		BackgroundSubtractorConst::get_background_image(self, &mut out)?;
+		Ok(out)
+	}
+}
+
+impl<T: BackgroundSubtractor + ?Sized> BackgroundSubtractorTraitManual for T {}
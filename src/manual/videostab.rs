@@ -0,0 +1,129 @@
+use std::{ffi::c_void, ptr};
+
+use crate::{
+	core::{Mat, MatTraitConst, Ptr},
+	sys,
+	templ::{callback_abort_on_panic, CallbackPanic},
+	traits::Boxed,
+	videostab::IFrameSource,
+	Result,
+};
+
+/// Rust-side implementation of the `cv::videostab::IFrameSource` interface
+///
+/// Implement this trait for your type or use a closure returning `Option<Mat>` and wrap it with [Ptr::from_impl] to
+/// get a frame source that can be passed to `StabilizerBase::set_frame_source()` of `OnePassStabilizer` or
+/// `TwoPassStabilizer`. A panic in a method doesn't unwind into the C++ code, the stabilizer call that invoked the
+/// method fails with `Err` instead and the frame source is not called again after that.
+pub trait FrameSourceImpl: Send {
+	/// Next frame of the video, `None` when the video has ended
+	fn next_frame(&mut self) -> Option<Mat>;
+
+	/// Rewind the video to the first frame
+	///
+	/// The default implementation does nothing, which is enough for the `OnePassStabilizer`, the `TwoPassStabilizer`
+	/// resets the source before the second pass and expects to get the same frames again.
+	fn reset(&mut self) {}
+}
+
+impl<F: FnMut() -> Option<Mat> + Send> FrameSourceImpl for F {
+	#[inline]
+	fn next_frame(&mut self) -> Option<Mat> {
+		self()
+	}
+}
+
+struct Callbacks {
+	source: Box<dyn FrameSourceImpl>,
+	panic: CallbackPanic,
+}
+
+extern "C" {
+	fn cv_manual_PtrOfIFrameSource_new(
+		userdata: *mut c_void,
+		reset: unsafe extern "C" fn(*mut c_void) -> bool,
+		next_frame: unsafe extern "C" fn(*mut c_void, *mut *mut c_void) -> bool,
+		drop: unsafe extern "C" fn(*mut c_void),
+		ocvrs_return: *mut sys::Result<*mut c_void>,
+	);
+}
+
+/// The trampolines return `false` if the method panicked, the C++ side throws an exception in that case
+unsafe extern "C" fn reset_trampoline(userdata: *mut c_void) -> bool {
+	let Callbacks { source, panic } = &mut *(userdata as *mut Callbacks);
+	panic.catch(|| source.reset()).is_some()
+}
+
+/// Writes the pointer to the heap allocated `cv::Mat` that the C++ side takes ownership of, null for the end of video
+unsafe extern "C" fn next_frame_trampoline(userdata: *mut c_void, ocvrs_return: *mut *mut c_void) -> bool {
+	let Callbacks { source, panic } = &mut *(userdata as *mut Callbacks);
+	panic.catch_into(ocvrs_return, || source.next_frame().map_or(ptr::null_mut(), Mat::into_raw))
+}
+
+unsafe extern "C" fn drop_trampoline(userdata: *mut c_void) {
+	callback_abort_on_panic(|| drop(Box::from_raw(userdata as *mut Callbacks)));
+}
+
+impl Ptr<dyn IFrameSource> {
+	/// Wrap a Rust implementation of the frame source into an OpenCV smart pointer
+	///
+	/// The passed object is owned by the resulting C++ object and is dropped together with it.
+	pub fn from_impl(source: impl FrameSourceImpl + 'static) -> Result<Self> {
+		let userdata = Box::into_raw(Box::new(Callbacks {
+			source: Box::new(source),
+			panic: CallbackPanic::new(),
+		})) as *mut c_void;
+		return_send!(via ocvrs_return);
+		unsafe {
+			cv_manual_PtrOfIFrameSource_new(
+				userdata,
+				reset_trampoline,
+				next_frame_trampoline,
+				drop_trampoline,
+				ocvrs_return.as_mut_ptr(),
+			)
+		};
+		return_receive!(unsafe ocvrs_return => ret);
+		ret.into_result().map(|ptr| unsafe { Self::from_raw(ptr) })
+	}
+}
+
+/// Iteration over the frames of `IFrameSource`, including the stabilizers
+pub trait IFrameSourceTraitManual: IFrameSource {
+	/// Iterator calling `next_frame()` until an empty `Mat` marking the end of video is returned
+	fn frames(&mut self) -> Frames<Self> {
+		Frames {
+			source: self,
+			done: false,
+		}
+	}
+}
+
+impl<T: IFrameSource + ?Sized> IFrameSourceTraitManual for T {}
+
+/// Iterator returned by [IFrameSourceTraitManual::frames]
+pub struct Frames<'s, T: ?Sized> {
+	source: &'s mut T,
+	done: bool,
+}
+
+impl<T: IFrameSource + ?Sized> Iterator for Frames<'_, T> {
+	type Item = Result<Mat>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done {
+			return None;
+		}
+		match self.source.next_frame() {
+			Ok(frame) if frame.empty() => {
+				self.done = true;
+				None
+			}
+			Ok(frame) => Some(Ok(frame)),
+			Err(e) => {
+				self.done = true;
+				Some(Err(e))
+			}
+		}
+	}
+}
@@ -0,0 +1,71 @@
+use std::{ffi::c_void, mem::ManuallyDrop};
+
+use crate::{
+	core::{Affine3d, Matx33d, Point3d, ToInputArray},
+	prelude::*,
+	viz::{Color, KeyboardEvent, MouseEvent, Viz3dTrait, WCameraPosition, WCloud, WLine, Widget},
+	Result,
+};
+
+/// Closure-based event handling and widget display for `Viz3d`
+pub trait Viz3dTraitManual: Viz3dTrait {
+	/// Sets keyboard handler of the window, the closure is called for every key press and release
+	///
+	/// The event is only borrowed for the duration of the call. Use
+	/// [register_keyboard_callback](Viz3dTrait::register_keyboard_callback) to receive the raw event pointer.
+	fn set_keyboard_callback(&mut self, mut on_key: impl FnMut(&KeyboardEvent) + Send + Sync + 'static) -> Result<()> {
+		self.register_keyboard_callback(Some(Box::new(move |event| {
+			// the event is owned by OpenCV, it must not be deleted on our side
+			let event = ManuallyDrop::new(unsafe { KeyboardEvent::from_raw(event as *mut c_void) });
+			on_key(&event)
+		})))
+	}
+
+	/// Sets mouse handler of the window, the closure is called for every mouse move, click and scroll
+	///
+	/// The event is only borrowed for the duration of the call. Use
+	/// [register_mouse_callback](Viz3dTrait::register_mouse_callback) to receive the raw event pointer.
+	fn set_mouse_callback(&mut self, mut on_mouse: impl FnMut(&MouseEvent) + Send + Sync + 'static) -> Result<()> {
+		self.register_mouse_callback(Some(Box::new(move |event| {
+			// the event is owned by OpenCV, it must not be deleted on our side
+			let event = ManuallyDrop::new(unsafe { MouseEvent::from_raw(event as *mut c_void) });
+			on_mouse(&event)
+		})))
+	}
+
+	/// Shows any widget (`WCloud`, `WLine`, `WCube`, etc.) at the origin of the world coordinate system, the widget
+	/// with the same `id` is replaced
+	fn show(&mut self, id: &str, widget: impl Into<Widget>) -> Result<()> {
+		self.show_widget(id, &widget.into(), Affine3d::default())
+	}
+
+	/// Shows any widget with the passed pose, the widget with the same `id` is replaced
+	fn show_posed(&mut self, id: &str, widget: impl Into<Widget>, pose: Affine3d) -> Result<()> {
+		self.show_widget(id, &widget.into(), pose)
+	}
+
+	/// Shows the point cloud, `cloud` is a `CV_32FC3` or `CV_64FC3` image or a vector of points, the optional
+	/// `colors` is a `CV_8UC3` image of the same size with the BGR color of every point
+	///
+	/// The points without colors are shown white.
+	fn show_point_cloud(&mut self, id: &str, cloud: &dyn ToInputArray, colors: Option<&dyn ToInputArray>) -> Result<()> {
+		let widget = match colors {
+			Some(colors) => WCloud::new(cloud, colors)?,
+			None => WCloud::new_1(cloud, &Color::white()?)?,
+		};
+		self.show(id, widget)
+	}
+
+	/// Shows the line segment between the two points
+	fn show_line(&mut self, id: &str, start: Point3d, end: Point3d, color: &Color) -> Result<()> {
+		self.show(id, WLine::new(start, end, color)?)
+	}
+
+	/// Shows the frustum of the camera with the intrinsic matrix `k` at the passed pose, `scale` is the distance of the
+	/// far plane of the frustum from the camera center
+	fn show_camera_frustum(&mut self, id: &str, k: Matx33d, pose: Affine3d, scale: f64, color: &Color) -> Result<()> {
+		self.show_posed(id, WCameraPosition::new_1(k, scale, color)?, pose)
+	}
+}
+
+impl<T: Viz3dTrait + ?Sized> Viz3dTraitManual for T {}
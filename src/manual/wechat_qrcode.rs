@@ -0,0 +1,50 @@
+use std::path::Path;
+
+use crate::{
+	core::{self, Mat, Vector},
+	wechat_qrcode::{WeChatQRCode, WeChatQRCodeTrait},
+	Error, Result,
+};
+
+impl WeChatQRCode {
+	/// Creates the detector with the CNN models from the directory
+	///
+	/// The directory must contain `detect.prototxt`, `detect.caffemodel`, `sr.prototxt` and `sr.caffemodel` as
+	/// distributed in the `opencv_3rdparty` repository (`wechat_qrcode` branch).
+	pub fn from_model_dir(dir: impl AsRef<Path>) -> Result<Self> {
+		let dir = dir.as_ref();
+		let mut paths = Vec::with_capacity(4);
+		for file_name in ["detect.prototxt", "detect.caffemodel", "sr.prototxt", "sr.caffemodel"] {
+			let path = dir.join(file_name);
+			// OpenCV silently falls back to the traditional detector if the models can't be loaded
+			if !path.is_file() {
+				return Err(Error::new(
+					core::StsObjectNotFound,
+					format!("WeChat QR code model file not found: {}", path.display()),
+				));
+			}
+			paths.push(path.to_string_lossy().into_owned());
+		}
+		Self::new(&paths[0], &paths[1], &paths[2], &paths[3])
+	}
+
+	/// Creates the detector without the CNN models, it falls back to the traditional detection which is faster but
+	/// finds fewer codes
+	pub fn without_models() -> Result<Self> {
+		Self::new("", "", "", "")
+	}
+}
+
+/// Detection returning every decoded text together with its location
+pub trait WeChatQRCodeTraitManual: WeChatQRCodeTrait {
+	/// Detects and decodes all the QR codes in the grayscale or BGR image
+	///
+	/// Returns the decoded text and the 4x2 `CV_32FC1` matrix of the corners of every code, one corner per row.
+	fn detect_and_decode_with_points(&mut self, img: &Mat) -> Result<Vec<(String, Mat)>> {
+		let mut points = Vector::<Mat>::new();
+		let texts = self.detect_and_decode(img, Some(&mut points))?;
+		Ok(texts.into_iter().zip(points).collect())
+	}
+}
+
+impl<T: WeChatQRCodeTrait + ?Sized> WeChatQRCodeTraitManual for T {}
@@ -0,0 +1,42 @@
+use std::ffi::c_void;
+
+use crate::{sys, traits::Boxed, types, Result};
+
+/// Descriptor number of bits, each bit is a boosting weak-learner
+///
+/// Same values as `cv::xfeatures2d::TEBLID::TeblidSize`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TEBLID_TeblidSize {
+	SIZE_256_BITS = 102,
+	SIZE_512_BITS = 103,
+}
+
+/// Class implementing TEBLID (Triplet-based Efficient Binary Local Image Descriptor)
+///
+/// TEBLID is the triplet loss trained version of [BEBLID](crate::xfeatures2d::BEBLID) with the same speed and better
+/// matching accuracy. The class is only available since OpenCV 4.7.0, so it's bound manually and the descriptor is
+/// returned as the generic [Feature2D](crate::features2d::Feature2D), [TEBLID::create] fails with `StsNotImplemented`
+/// on the older versions.
+pub struct TEBLID {
+	_private: (),
+}
+
+impl TEBLID {
+	/// Creates the TEBLID descriptor
+	///
+	/// ## Parameters
+	/// * scale_factor: Adjust the sampling window around detected keypoints, same values as for
+	///   [BEBLID::create](crate::xfeatures2d::BEBLID::create)
+	/// * n_bits: Determine the number of bits in the descriptor, [TEBLID_TeblidSize::SIZE_256_BITS] or
+	///   [TEBLID_TeblidSize::SIZE_512_BITS]
+	pub fn create(scale_factor: f32, n_bits: i32) -> Result<types::PtrOfFeature2D> {
+		extern "C" {
+			fn cv_manual_xfeatures2d_TEBLID_create(scale_factor: f32, n_bits: i32, ocvrs_return: *mut sys::Result<*mut c_void>);
+		}
+		return_send!(via ocvrs_return);
+		unsafe { cv_manual_xfeatures2d_TEBLID_create(scale_factor, n_bits, ocvrs_return.as_mut_ptr()) }
+		return_receive!(unsafe ocvrs_return => ret);
+		ret.into_result().map(|ptr| unsafe { types::PtrOfFeature2D::from_raw(ptr) })
+	}
+}
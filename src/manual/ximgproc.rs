@@ -0,0 +1,137 @@
+use crate::{
+	core::{self, Mat, CV_32F, CV_8U},
+	prelude::*,
+	ximgproc::{self, SLICType, StructuredEdgeDetectionConst},
+	Error, Result,
+};
+
+/// Result of the superpixel segmentation by [superpixels_slic], [superpixels_seeds] and [superpixels_lsc]
+#[derive(Debug)]
+pub struct Superpixels {
+	/// `CV_32SC1` image with the superpixel index of every pixel, in the range `[0, count)`
+	pub labels: Mat,
+	/// Number of the superpixels
+	pub count: i32,
+	/// `CV_8UC1` image with the boundaries of the superpixels set to 255
+	pub contour_mask: Mat,
+}
+
+/// Segments the image into superpixels with SLIC, SLICO or MSLIC
+///
+/// `region_size` is the average superpixel size in pixels, `ruler` is the smoothness of the superpixel boundaries, it's
+/// not used by `SLICType::SLICO`. The superpixels smaller than a quarter of the average size are merged into their
+/// neighbours.
+pub fn superpixels_slic(image: &Mat, algorithm: SLICType, region_size: i32, ruler: f32, iterations: i32) -> Result<Superpixels> {
+	core::ensure_module_available("ximgproc")?;
+	let mut slic = ximgproc::create_superpixel_slic(image, algorithm as i32, region_size, ruler)?;
+	slic.iterate(iterations)?;
+	slic.enforce_label_connectivity(25)?;
+	let mut labels = Mat::default();
+	slic.get_labels(&mut labels)?;
+	let mut contour_mask = Mat::default();
+	slic.get_label_contour_mask(&mut contour_mask, true)?;
+	Ok(Superpixels {
+		labels,
+		count: slic.get_number_of_superpixels()?,
+		contour_mask,
+	})
+}
+
+/// Segments the image into at most `num_superpixels` superpixels with SEEDS
+///
+/// `num_levels` is the number of the block levels, more levels give more accurate boundaries but take more memory and
+/// time. The other parameters keep their default values.
+pub fn superpixels_seeds(image: &Mat, num_superpixels: i32, num_levels: i32, iterations: i32) -> Result<Superpixels> {
+	core::ensure_module_available("ximgproc")?;
+	let size = image.size()?;
+	let mut seeds = ximgproc::create_superpixel_seeds(
+		size.width,
+		size.height,
+		image.channels(),
+		num_superpixels,
+		num_levels,
+		2,
+		5,
+		false,
+	)?;
+	seeds.iterate(image, iterations)?;
+	let mut labels = Mat::default();
+	seeds.get_labels(&mut labels)?;
+	let mut contour_mask = Mat::default();
+	seeds.get_label_contour_mask(&mut contour_mask, false)?;
+	Ok(Superpixels {
+		labels,
+		count: seeds.get_number_of_superpixels()?,
+		contour_mask,
+	})
+}
+
+/// Segments the image into superpixels with LSC (Linear Spectral Clustering)
+///
+/// `region_size` is the average superpixel size in pixels, `ratio` is the compactness of the superpixels. The
+/// superpixels smaller than a fifth of the average size are merged into their neighbours.
+pub fn superpixels_lsc(image: &Mat, region_size: i32, ratio: f32, iterations: i32) -> Result<Superpixels> {
+	core::ensure_module_available("ximgproc")?;
+	let mut lsc = ximgproc::create_superpixel_lsc(image, region_size, ratio)?;
+	lsc.iterate(iterations)?;
+	lsc.enforce_label_connectivity(20)?;
+	let mut labels = Mat::default();
+	lsc.get_labels(&mut labels)?;
+	let mut contour_mask = Mat::default();
+	lsc.get_label_contour_mask(&mut contour_mask, true)?;
+	Ok(Superpixels {
+		labels,
+		count: lsc.get_number_of_superpixels()?,
+		contour_mask,
+	})
+}
+
+/// Result of [StructuredEdgeDetectionTraitConstManual::detect_structured_edges]
+#[derive(Debug)]
+pub struct StructuredEdges {
+	/// `CV_32FC1` edge probability of every pixel in the range [0, 1]
+	pub edges: Mat,
+	/// `CV_32FC1` edge orientation of every pixel in radians
+	pub orientation: Mat,
+	/// Edges thinned by the non-maximum suppression along the orientation
+	pub thin_edges: Mat,
+}
+
+/// Edge detection of `StructuredEdgeDetection` taking care of the input conversion and the non-maximum suppression
+pub trait StructuredEdgeDetectionTraitConstManual: StructuredEdgeDetectionConst {
+	/// Detects the edges in the 3 channel image
+	///
+	/// `image` is either `CV_8UC3` or `CV_32FC3` with the values in the range [0, 1], the channel order must match
+	/// the one the model was trained with, RGB for the model from the OpenCV samples.
+	fn detect_structured_edges(&self, image: &Mat) -> Result<StructuredEdges> {
+		if image.channels() != 3 || (image.depth() != CV_8U && image.depth() != CV_32F) {
+			return Err(Error::new(
+				core::StsUnsupportedFormat,
+				format!(
+					"Image must be of type CV_8UC3 or CV_32FC3, but got: {}",
+					core::type_to_string(image.typ())?
+				),
+			));
+		}
+		let mut float_image = Mat::default();
+		let src = if image.depth() == CV_8U {
+			image.convert_to(&mut float_image, CV_32F, 1. / 255., 0.)?;
+			&float_image
+		} else {
+			image
+		};
+		let mut edges = Mat::default();
+		self.detect_edges(src, &mut edges)?;
+		let mut orientation = Mat::default();
+		self.compute_orientation(&edges, &mut orientation)?;
+		let mut thin_edges = Mat::default();
+		self.edges_nms(&edges, &orientation, &mut thin_edges, 2, 0, 1., true)?;
+		Ok(StructuredEdges {
+			edges,
+			orientation,
+			thin_edges,
+		})
+	}
+}
+
+impl<T: StructuredEdgeDetectionConst + ?Sized> StructuredEdgeDetectionTraitConstManual for T {}
@@ -267,3 +267,303 @@ pub mod hub_prelude {
 	#[cfg(ocvrs_has_module_xphoto)]
 	pub use super::xphoto::prelude::*;
 }
+pub mod hub_prelude_v2 {
+	#[cfg(ocvrs_has_module_alphamat)]
+	pub mod alphamat {
+		pub use crate::alphamat::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_aruco)]
+	pub mod aruco {
+		pub use crate::aruco::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_barcode)]
+	pub mod barcode {
+		pub use crate::barcode::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_bgsegm)]
+	pub mod bgsegm {
+		pub use crate::bgsegm::prelude::*;
+		pub use crate::manual::prelude::bgsegm::*;
+	}
+	#[cfg(ocvrs_has_module_bioinspired)]
+	pub mod bioinspired {
+		pub use crate::bioinspired::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_calib3d)]
+	pub mod calib3d {
+		pub use crate::calib3d::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_ccalib)]
+	pub mod ccalib {
+		pub use crate::ccalib::prelude::*;
+		pub use crate::manual::prelude::ccalib::*;
+	}
+	#[cfg(ocvrs_has_module_core)]
+	pub mod core {
+		pub use crate::core::prelude::*;
+		pub use crate::manual::prelude::core::*;
+	}
+	#[cfg(ocvrs_has_module_cudaarithm)]
+	pub mod cudaarithm {
+		pub use crate::cudaarithm::prelude::*;
+		pub use crate::manual::prelude::cudaarithm::*;
+	}
+	#[cfg(ocvrs_has_module_cudabgsegm)]
+	pub mod cudabgsegm {
+		pub use crate::cudabgsegm::prelude::*;
+		pub use crate::manual::prelude::cudabgsegm::*;
+	}
+	#[cfg(ocvrs_has_module_cudacodec)]
+	pub mod cudacodec {
+		pub use crate::cudacodec::prelude::*;
+		pub use crate::manual::prelude::cudacodec::*;
+	}
+	#[cfg(ocvrs_has_module_cudafeatures2d)]
+	pub mod cudafeatures2d {
+		pub use crate::cudafeatures2d::prelude::*;
+		pub use crate::manual::prelude::cudafeatures2d::*;
+	}
+	#[cfg(ocvrs_has_module_cudafilters)]
+	pub mod cudafilters {
+		pub use crate::cudafilters::prelude::*;
+		pub use crate::manual::prelude::cudafilters::*;
+	}
+	#[cfg(ocvrs_has_module_cudaimgproc)]
+	pub mod cudaimgproc {
+		pub use crate::cudaimgproc::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_cudaobjdetect)]
+	pub mod cudaobjdetect {
+		pub use crate::cudaobjdetect::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_cudaoptflow)]
+	pub mod cudaoptflow {
+		pub use crate::cudaoptflow::prelude::*;
+		pub use crate::manual::prelude::cudaoptflow::*;
+	}
+	#[cfg(ocvrs_has_module_cudastereo)]
+	pub mod cudastereo {
+		pub use crate::cudastereo::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_cudawarping)]
+	pub mod cudawarping {
+		pub use crate::cudawarping::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_cvv)]
+	pub mod cvv {
+		pub use crate::cvv::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_dnn)]
+	pub mod dnn {
+		pub use crate::dnn::prelude::*;
+		pub use crate::manual::prelude::dnn::*;
+	}
+	#[cfg(ocvrs_has_module_dnn_superres)]
+	pub mod dnn_superres {
+		pub use crate::dnn_superres::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_dpm)]
+	pub mod dpm {
+		pub use crate::dpm::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_face)]
+	pub mod face {
+		pub use crate::face::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_features2d)]
+	pub mod features2d {
+		pub use crate::features2d::prelude::*;
+		pub use crate::manual::prelude::features2d::*;
+	}
+	#[cfg(ocvrs_has_module_flann)]
+	pub mod flann {
+		pub use crate::flann::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_freetype)]
+	pub mod freetype {
+		pub use crate::freetype::prelude::*;
+		pub use crate::manual::prelude::freetype::*;
+	}
+	#[cfg(ocvrs_has_module_fuzzy)]
+	pub mod fuzzy {
+		pub use crate::fuzzy::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_gapi)]
+	pub mod gapi {
+		pub use crate::gapi::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_hdf)]
+	pub mod hdf {
+		pub use crate::hdf::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_hfs)]
+	pub mod hfs {
+		pub use crate::hfs::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_highgui)]
+	pub mod highgui {
+		pub use crate::highgui::prelude::*;
+		pub use crate::manual::prelude::highgui::*;
+	}
+	#[cfg(ocvrs_has_module_img_hash)]
+	pub mod img_hash {
+		pub use crate::img_hash::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_imgcodecs)]
+	pub mod imgcodecs {
+		pub use crate::imgcodecs::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_imgproc)]
+	pub mod imgproc {
+		pub use crate::imgproc::prelude::*;
+		pub use crate::manual::prelude::imgproc::*;
+	}
+	#[cfg(ocvrs_has_module_intensity_transform)]
+	pub mod intensity_transform {
+		pub use crate::intensity_transform::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_line_descriptor)]
+	pub mod line_descriptor {
+		pub use crate::line_descriptor::prelude::*;
+		pub use crate::manual::prelude::line_descriptor::*;
+	}
+	#[cfg(ocvrs_has_module_mcc)]
+	pub mod mcc {
+		pub use crate::mcc::prelude::*;
+		pub use crate::manual::prelude::mcc::*;
+	}
+	#[cfg(ocvrs_has_module_ml)]
+	pub mod ml {
+		pub use crate::ml::prelude::*;
+		pub use crate::manual::prelude::ml::*;
+	}
+	#[cfg(ocvrs_has_module_objdetect)]
+	pub mod objdetect {
+		pub use crate::objdetect::prelude::*;
+		pub use crate::manual::prelude::objdetect::*;
+	}
+	#[cfg(ocvrs_has_module_optflow)]
+	pub mod optflow {
+		pub use crate::optflow::prelude::*;
+		pub use crate::manual::prelude::optflow::*;
+	}
+	#[cfg(ocvrs_has_module_ovis)]
+	pub mod ovis {
+		pub use crate::ovis::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_phase_unwrapping)]
+	pub mod phase_unwrapping {
+		pub use crate::phase_unwrapping::prelude::*;
+		pub use crate::manual::prelude::phase_unwrapping::*;
+	}
+	#[cfg(ocvrs_has_module_photo)]
+	pub mod photo {
+		pub use crate::photo::prelude::*;
+		pub use crate::manual::prelude::photo::*;
+	}
+	#[cfg(ocvrs_has_module_plot)]
+	pub mod plot {
+		pub use crate::plot::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_quality)]
+	pub mod quality {
+		pub use crate::quality::prelude::*;
+		pub use crate::manual::prelude::quality::*;
+	}
+	#[cfg(ocvrs_has_module_rapid)]
+	pub mod rapid {
+		pub use crate::rapid::prelude::*;
+		pub use crate::manual::prelude::rapid::*;
+	}
+	#[cfg(ocvrs_has_module_rgbd)]
+	pub mod rgbd {
+		pub use crate::rgbd::prelude::*;
+		pub use crate::manual::prelude::rgbd::*;
+	}
+	#[cfg(ocvrs_has_module_saliency)]
+	pub mod saliency {
+		pub use crate::saliency::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_sfm)]
+	pub mod sfm {
+		pub use crate::sfm::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_shape)]
+	pub mod shape {
+		pub use crate::shape::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_stereo)]
+	pub mod stereo {
+		pub use crate::stereo::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_stitching)]
+	pub mod stitching {
+		pub use crate::stitching::prelude::*;
+		pub use crate::manual::prelude::stitching::*;
+	}
+	#[cfg(ocvrs_has_module_structured_light)]
+	pub mod structured_light {
+		pub use crate::structured_light::prelude::*;
+		pub use crate::manual::prelude::structured_light::*;
+	}
+	#[cfg(ocvrs_has_module_superres)]
+	pub mod superres {
+		pub use crate::superres::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_surface_matching)]
+	pub mod surface_matching {
+		pub use crate::surface_matching::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_text)]
+	pub mod text {
+		pub use crate::text::prelude::*;
+		pub use crate::manual::prelude::text::*;
+	}
+	#[cfg(ocvrs_has_module_tracking)]
+	pub mod tracking {
+		pub use crate::tracking::prelude::*;
+		pub use crate::manual::prelude::tracking::*;
+	}
+	#[cfg(ocvrs_has_module_video)]
+	pub mod video {
+		pub use crate::video::prelude::*;
+		pub use crate::manual::prelude::video::*;
+	}
+	#[cfg(ocvrs_has_module_videoio)]
+	pub mod videoio {
+		pub use crate::videoio::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_videostab)]
+	pub mod videostab {
+		pub use crate::videostab::prelude::*;
+		pub use crate::manual::prelude::videostab::*;
+	}
+	#[cfg(ocvrs_has_module_viz)]
+	pub mod viz {
+		pub use crate::viz::prelude::*;
+		pub use crate::manual::prelude::viz::*;
+	}
+	#[cfg(ocvrs_has_module_wechat_qrcode)]
+	pub mod wechat_qrcode {
+		pub use crate::wechat_qrcode::prelude::*;
+		pub use crate::manual::prelude::wechat_qrcode::*;
+	}
+	#[cfg(ocvrs_has_module_xfeatures2d)]
+	pub mod xfeatures2d {
+		pub use crate::xfeatures2d::prelude::*;
+		pub use crate::manual::prelude::xfeatures2d::*;
+	}
+	#[cfg(ocvrs_has_module_ximgproc)]
+	pub mod ximgproc {
+		pub use crate::ximgproc::prelude::*;
+		pub use crate::manual::prelude::ximgproc::*;
+	}
+	#[cfg(ocvrs_has_module_xobjdetect)]
+	pub mod xobjdetect {
+		pub use crate::xobjdetect::prelude::*;
+	}
+	#[cfg(ocvrs_has_module_xphoto)]
+	pub mod xphoto {
+		pub use crate::xphoto::prelude::*;
+	}
+}
@@ -30,7 +30,7 @@ pub mod prelude {
 /// 
 /// The function infoFlow performs alpha matting on a RGB image using a greyscale trimap image, and outputs a greyscale alpha matte image. The output alpha matte can be used to softly extract the foreground object from a background image. Examples can be found in the samples directory.
 #[inline]
-pub fn info_flow(image: &dyn core::ToInputArray, tmap: &dyn core::ToInputArray, result: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn info_flow(image: &(impl core::ToInputArray + ?Sized), tmap: &(impl core::ToInputArray + ?Sized), result: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(image);
 	input_array_arg!(tmap);
 	output_array_arg!(result);
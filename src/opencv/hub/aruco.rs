@@ -240,7 +240,7 @@ opencv_type_enum! { crate::aruco::PatternPos }
 /// * flags: 0
 /// * criteria: TermCriteria(TermCriteria::COUNT+TermCriteria::EPS,30,DBL_EPSILON)
 #[inline]
-pub fn calibrate_camera_aruco_extended(corners: &dyn core::ToInputArray, ids: &dyn core::ToInputArray, counter: &dyn core::ToInputArray, board: &core::Ptr<crate::aruco::Board>, image_size: core::Size, camera_matrix: &mut dyn core::ToInputOutputArray, dist_coeffs: &mut dyn core::ToInputOutputArray, rvecs: &mut dyn core::ToOutputArray, tvecs: &mut dyn core::ToOutputArray, std_deviations_intrinsics: &mut dyn core::ToOutputArray, std_deviations_extrinsics: &mut dyn core::ToOutputArray, per_view_errors: &mut dyn core::ToOutputArray, flags: i32, criteria: core::TermCriteria) -> Result<f64> {
+pub fn calibrate_camera_aruco_extended(corners: &(impl core::ToInputArray + ?Sized), ids: &(impl core::ToInputArray + ?Sized), counter: &(impl core::ToInputArray + ?Sized), board: &core::Ptr<crate::aruco::Board>, image_size: core::Size, camera_matrix: &mut (impl core::ToInputOutputArray + ?Sized), dist_coeffs: &mut (impl core::ToInputOutputArray + ?Sized), rvecs: &mut (impl core::ToOutputArray + ?Sized), tvecs: &mut (impl core::ToOutputArray + ?Sized), std_deviations_intrinsics: &mut (impl core::ToOutputArray + ?Sized), std_deviations_extrinsics: &mut (impl core::ToOutputArray + ?Sized), per_view_errors: &mut (impl core::ToOutputArray + ?Sized), flags: i32, criteria: core::TermCriteria) -> Result<f64> {
 	input_array_arg!(corners);
 	input_array_arg!(ids);
 	input_array_arg!(counter);
@@ -266,14 +266,14 @@ pub fn calibrate_camera_aruco_extended(corners: &dyn core::ToInputArray, ids: &d
 /// * flags: 0
 /// * criteria: TermCriteria(TermCriteria::COUNT+TermCriteria::EPS,30,DBL_EPSILON)
 #[inline]
-pub fn calibrate_camera_aruco(corners: &dyn core::ToInputArray, ids: &dyn core::ToInputArray, counter: &dyn core::ToInputArray, board: &core::Ptr<crate::aruco::Board>, image_size: core::Size, camera_matrix: &mut dyn core::ToInputOutputArray, dist_coeffs: &mut dyn core::ToInputOutputArray, rvecs: &mut dyn core::ToOutputArray, tvecs: &mut dyn core::ToOutputArray, flags: i32, criteria: core::TermCriteria) -> Result<f64> {
+pub fn calibrate_camera_aruco(corners: &(impl core::ToInputArray + ?Sized), ids: &(impl core::ToInputArray + ?Sized), counter: &(impl core::ToInputArray + ?Sized), board: &core::Ptr<crate::aruco::Board>, image_size: core::Size, camera_matrix: &mut (impl core::ToInputOutputArray + ?Sized), dist_coeffs: &mut (impl core::ToInputOutputArray + ?Sized), rvecs: Option<&mut (impl core::ToOutputArray + ?Sized)>, tvecs: Option<&mut (impl core::ToOutputArray + ?Sized)>, flags: i32, criteria: core::TermCriteria) -> Result<f64> {
 	input_array_arg!(corners);
 	input_array_arg!(ids);
 	input_array_arg!(counter);
 	input_output_array_arg!(camera_matrix);
 	input_output_array_arg!(dist_coeffs);
-	output_array_arg!(rvecs);
-	output_array_arg!(tvecs);
+	output_array_arg!(nullable rvecs);
+	output_array_arg!(nullable tvecs);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_aruco_calibrateCameraAruco_const__InputArrayR_const__InputArrayR_const__InputArrayR_const_PtrLBoardGR_Size_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria(corners.as_raw__InputArray(), ids.as_raw__InputArray(), counter.as_raw__InputArray(), board.as_raw_PtrOfBoard(), image_size.opencv_as_extern(), camera_matrix.as_raw__InputOutputArray(), dist_coeffs.as_raw__InputOutputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -318,7 +318,7 @@ pub fn calibrate_camera_aruco(corners: &dyn core::ToInputArray, ids: &dyn core::
 /// * flags: 0
 /// * criteria: TermCriteria(TermCriteria::COUNT+TermCriteria::EPS,30,DBL_EPSILON)
 #[inline]
-pub fn calibrate_camera_charuco_extended(charuco_corners: &dyn core::ToInputArray, charuco_ids: &dyn core::ToInputArray, board: &core::Ptr<crate::aruco::CharucoBoard>, image_size: core::Size, camera_matrix: &mut dyn core::ToInputOutputArray, dist_coeffs: &mut dyn core::ToInputOutputArray, rvecs: &mut dyn core::ToOutputArray, tvecs: &mut dyn core::ToOutputArray, std_deviations_intrinsics: &mut dyn core::ToOutputArray, std_deviations_extrinsics: &mut dyn core::ToOutputArray, per_view_errors: &mut dyn core::ToOutputArray, flags: i32, criteria: core::TermCriteria) -> Result<f64> {
+pub fn calibrate_camera_charuco_extended(charuco_corners: &(impl core::ToInputArray + ?Sized), charuco_ids: &(impl core::ToInputArray + ?Sized), board: &core::Ptr<crate::aruco::CharucoBoard>, image_size: core::Size, camera_matrix: &mut (impl core::ToInputOutputArray + ?Sized), dist_coeffs: &mut (impl core::ToInputOutputArray + ?Sized), rvecs: &mut (impl core::ToOutputArray + ?Sized), tvecs: &mut (impl core::ToOutputArray + ?Sized), std_deviations_intrinsics: &mut (impl core::ToOutputArray + ?Sized), std_deviations_extrinsics: &mut (impl core::ToOutputArray + ?Sized), per_view_errors: &mut (impl core::ToOutputArray + ?Sized), flags: i32, criteria: core::TermCriteria) -> Result<f64> {
 	input_array_arg!(charuco_corners);
 	input_array_arg!(charuco_ids);
 	input_output_array_arg!(camera_matrix);
@@ -343,13 +343,13 @@ pub fn calibrate_camera_charuco_extended(charuco_corners: &dyn core::ToInputArra
 /// * flags: 0
 /// * criteria: TermCriteria(TermCriteria::COUNT+TermCriteria::EPS,30,DBL_EPSILON)
 #[inline]
-pub fn calibrate_camera_charuco(charuco_corners: &dyn core::ToInputArray, charuco_ids: &dyn core::ToInputArray, board: &core::Ptr<crate::aruco::CharucoBoard>, image_size: core::Size, camera_matrix: &mut dyn core::ToInputOutputArray, dist_coeffs: &mut dyn core::ToInputOutputArray, rvecs: &mut dyn core::ToOutputArray, tvecs: &mut dyn core::ToOutputArray, flags: i32, criteria: core::TermCriteria) -> Result<f64> {
+pub fn calibrate_camera_charuco(charuco_corners: &(impl core::ToInputArray + ?Sized), charuco_ids: &(impl core::ToInputArray + ?Sized), board: &core::Ptr<crate::aruco::CharucoBoard>, image_size: core::Size, camera_matrix: &mut (impl core::ToInputOutputArray + ?Sized), dist_coeffs: &mut (impl core::ToInputOutputArray + ?Sized), rvecs: Option<&mut (impl core::ToOutputArray + ?Sized)>, tvecs: Option<&mut (impl core::ToOutputArray + ?Sized)>, flags: i32, criteria: core::TermCriteria) -> Result<f64> {
 	input_array_arg!(charuco_corners);
 	input_array_arg!(charuco_ids);
 	input_output_array_arg!(camera_matrix);
 	input_output_array_arg!(dist_coeffs);
-	output_array_arg!(rvecs);
-	output_array_arg!(tvecs);
+	output_array_arg!(nullable rvecs);
+	output_array_arg!(nullable tvecs);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_aruco_calibrateCameraCharuco_const__InputArrayR_const__InputArrayR_const_PtrLCharucoBoardGR_Size_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria(charuco_corners.as_raw__InputArray(), charuco_ids.as_raw__InputArray(), board.as_raw_PtrOfCharucoBoard(), image_size.opencv_as_extern(), camera_matrix.as_raw__InputOutputArray(), dist_coeffs.as_raw__InputOutputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -385,14 +385,14 @@ pub fn calibrate_camera_charuco(charuco_corners: &dyn core::ToInputArray, charuc
 /// * dist_coeffs: noArray()
 /// * dictionary: cv::aruco::getPredefinedDictionary(cv::aruco::PREDEFINED_DICTIONARY_NAME::DICT_4X4_50)
 #[inline]
-pub fn detect_charuco_diamond(image: &dyn core::ToInputArray, marker_corners: &dyn core::ToInputArray, marker_ids: &dyn core::ToInputArray, square_marker_length_rate: f32, diamond_corners: &mut dyn core::ToOutputArray, diamond_ids: &mut dyn core::ToOutputArray, camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, mut dictionary: core::Ptr<crate::aruco::Dictionary>) -> Result<()> {
+pub fn detect_charuco_diamond(image: &(impl core::ToInputArray + ?Sized), marker_corners: &(impl core::ToInputArray + ?Sized), marker_ids: &(impl core::ToInputArray + ?Sized), square_marker_length_rate: f32, diamond_corners: &mut (impl core::ToOutputArray + ?Sized), diamond_ids: &mut (impl core::ToOutputArray + ?Sized), camera_matrix: Option<&(impl core::ToInputArray + ?Sized)>, dist_coeffs: Option<&(impl core::ToInputArray + ?Sized)>, mut dictionary: core::Ptr<crate::aruco::Dictionary>) -> Result<()> {
 	input_array_arg!(image);
 	input_array_arg!(marker_corners);
 	input_array_arg!(marker_ids);
 	output_array_arg!(diamond_corners);
 	output_array_arg!(diamond_ids);
-	input_array_arg!(camera_matrix);
-	input_array_arg!(dist_coeffs);
+	input_array_arg!(nullable camera_matrix);
+	input_array_arg!(nullable dist_coeffs);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_aruco_detectCharucoDiamond_const__InputArrayR_const__InputArrayR_const__InputArrayR_float_const__OutputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_PtrLDictionaryG(image.as_raw__InputArray(), marker_corners.as_raw__InputArray(), marker_ids.as_raw__InputArray(), square_marker_length_rate, diamond_corners.as_raw__OutputArray(), diamond_ids.as_raw__OutputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), dictionary.as_raw_mut_PtrOfDictionary(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -429,11 +429,11 @@ pub fn detect_charuco_diamond(image: &dyn core::ToInputArray, marker_corners: &d
 /// * parameters: DetectorParameters::create()
 /// * rejected_img_points: noArray()
 #[inline]
-pub fn detect_markers(image: &dyn core::ToInputArray, dictionary: &core::Ptr<crate::aruco::Dictionary>, corners: &mut dyn core::ToOutputArray, ids: &mut dyn core::ToOutputArray, parameters: &core::Ptr<crate::aruco::DetectorParameters>, rejected_img_points: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn detect_markers(image: &(impl core::ToInputArray + ?Sized), dictionary: &core::Ptr<crate::aruco::Dictionary>, corners: &mut (impl core::ToOutputArray + ?Sized), ids: &mut (impl core::ToOutputArray + ?Sized), parameters: &core::Ptr<crate::aruco::DetectorParameters>, rejected_img_points: Option<&mut (impl core::ToOutputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(image);
 	output_array_arg!(corners);
 	output_array_arg!(ids);
-	output_array_arg!(rejected_img_points);
+	output_array_arg!(nullable rejected_img_points);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_aruco_detectMarkers_const__InputArrayR_const_PtrLDictionaryGR_const__OutputArrayR_const__OutputArrayR_const_PtrLDetectorParametersGR_const__OutputArrayR(image.as_raw__InputArray(), dictionary.as_raw_PtrOfDictionary(), corners.as_raw__OutputArray(), ids.as_raw__OutputArray(), parameters.as_raw_PtrOfDetectorParameters(), rejected_img_points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -459,7 +459,7 @@ pub fn detect_markers(image: &dyn core::ToInputArray, dictionary: &core::Ptr<cra
 /// * margin_size: 0
 /// * border_bits: 1
 #[inline]
-pub fn draw_charuco_diamond(dictionary: &core::Ptr<crate::aruco::Dictionary>, ids: core::Vec4i, square_length: i32, marker_length: i32, img: &mut dyn core::ToOutputArray, margin_size: i32, border_bits: i32) -> Result<()> {
+pub fn draw_charuco_diamond(dictionary: &core::Ptr<crate::aruco::Dictionary>, ids: core::Vec4i, square_length: i32, marker_length: i32, img: &mut (impl core::ToOutputArray + ?Sized), margin_size: i32, border_bits: i32) -> Result<()> {
 	output_array_arg!(img);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_aruco_drawCharucoDiamond_const_PtrLDictionaryGR_Vec4i_int_int_const__OutputArrayR_int_int(dictionary.as_raw_PtrOfDictionary(), ids.opencv_as_extern(), square_length, marker_length, img.as_raw__OutputArray(), margin_size, border_bits, ocvrs_return.as_mut_ptr()) };
@@ -483,10 +483,10 @@ pub fn draw_charuco_diamond(dictionary: &core::Ptr<crate::aruco::Dictionary>, id
 /// * charuco_ids: noArray()
 /// * corner_color: Scalar(255,0,0)
 #[inline]
-pub fn draw_detected_corners_charuco(image: &mut dyn core::ToInputOutputArray, charuco_corners: &dyn core::ToInputArray, charuco_ids: &dyn core::ToInputArray, corner_color: core::Scalar) -> Result<()> {
+pub fn draw_detected_corners_charuco(image: &mut (impl core::ToInputOutputArray + ?Sized), charuco_corners: &(impl core::ToInputArray + ?Sized), charuco_ids: Option<&(impl core::ToInputArray + ?Sized)>, corner_color: core::Scalar) -> Result<()> {
 	input_output_array_arg!(image);
 	input_array_arg!(charuco_corners);
-	input_array_arg!(charuco_ids);
+	input_array_arg!(nullable charuco_ids);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_aruco_drawDetectedCornersCharuco_const__InputOutputArrayR_const__InputArrayR_const__InputArrayR_Scalar(image.as_raw__InputOutputArray(), charuco_corners.as_raw__InputArray(), charuco_ids.as_raw__InputArray(), corner_color.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -516,10 +516,10 @@ pub fn draw_detected_corners_charuco(image: &mut dyn core::ToInputOutputArray, c
 /// * diamond_ids: noArray()
 /// * border_color: Scalar(0,0,255)
 #[inline]
-pub fn draw_detected_diamonds(image: &mut dyn core::ToInputOutputArray, diamond_corners: &dyn core::ToInputArray, diamond_ids: &dyn core::ToInputArray, border_color: core::Scalar) -> Result<()> {
+pub fn draw_detected_diamonds(image: &mut (impl core::ToInputOutputArray + ?Sized), diamond_corners: &(impl core::ToInputArray + ?Sized), diamond_ids: Option<&(impl core::ToInputArray + ?Sized)>, border_color: core::Scalar) -> Result<()> {
 	input_output_array_arg!(image);
 	input_array_arg!(diamond_corners);
-	input_array_arg!(diamond_ids);
+	input_array_arg!(nullable diamond_ids);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_aruco_drawDetectedDiamonds_const__InputOutputArrayR_const__InputArrayR_const__InputArrayR_Scalar(image.as_raw__InputOutputArray(), diamond_corners.as_raw__InputArray(), diamond_ids.as_raw__InputArray(), border_color.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -548,10 +548,10 @@ pub fn draw_detected_diamonds(image: &mut dyn core::ToInputOutputArray, diamond_
 /// * ids: noArray()
 /// * border_color: Scalar(0,255,0)
 #[inline]
-pub fn draw_detected_markers(image: &mut dyn core::ToInputOutputArray, corners: &dyn core::ToInputArray, ids: &dyn core::ToInputArray, border_color: core::Scalar) -> Result<()> {
+pub fn draw_detected_markers(image: &mut (impl core::ToInputOutputArray + ?Sized), corners: &(impl core::ToInputArray + ?Sized), ids: Option<&(impl core::ToInputArray + ?Sized)>, border_color: core::Scalar) -> Result<()> {
 	input_output_array_arg!(image);
 	input_array_arg!(corners);
-	input_array_arg!(ids);
+	input_array_arg!(nullable ids);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_aruco_drawDetectedMarkers_const__InputOutputArrayR_const__InputArrayR_const__InputArrayR_Scalar(image.as_raw__InputOutputArray(), corners.as_raw__InputArray(), ids.as_raw__InputArray(), border_color.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -574,7 +574,7 @@ pub fn draw_detected_markers(image: &mut dyn core::ToInputOutputArray, corners:
 /// ## C++ default parameters
 /// * border_bits: 1
 #[inline]
-pub fn draw_marker(dictionary: &core::Ptr<crate::aruco::Dictionary>, id: i32, side_pixels: i32, img: &mut dyn core::ToOutputArray, border_bits: i32) -> Result<()> {
+pub fn draw_marker(dictionary: &core::Ptr<crate::aruco::Dictionary>, id: i32, side_pixels: i32, img: &mut (impl core::ToOutputArray + ?Sized), border_bits: i32) -> Result<()> {
 	output_array_arg!(img);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_aruco_drawMarker_const_PtrLDictionaryGR_int_int_const__OutputArrayR_int(dictionary.as_raw_PtrOfDictionary(), id, side_pixels, img.as_raw__OutputArray(), border_bits, ocvrs_return.as_mut_ptr()) };
@@ -603,7 +603,7 @@ pub fn draw_marker(dictionary: &core::Ptr<crate::aruco::Dictionary>, id: i32, si
 /// * margin_size: 0
 /// * border_bits: 1
 #[inline]
-pub fn draw_planar_board(board: &core::Ptr<crate::aruco::Board>, out_size: core::Size, img: &mut dyn core::ToOutputArray, margin_size: i32, border_bits: i32) -> Result<()> {
+pub fn draw_planar_board(board: &core::Ptr<crate::aruco::Board>, out_size: core::Size, img: &mut (impl core::ToOutputArray + ?Sized), margin_size: i32, border_bits: i32) -> Result<()> {
 	output_array_arg!(img);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_aruco_drawPlanarBoard_const_PtrLBoardGR_Size_const__OutputArrayR_int_int(board.as_raw_PtrOfBoard(), out_size.opencv_as_extern(), img.as_raw__OutputArray(), margin_size, border_bits, ocvrs_return.as_mut_ptr()) };
@@ -645,7 +645,7 @@ pub fn draw_planar_board(board: &core::Ptr<crate::aruco::Board>, out_size: core:
 /// ## C++ default parameters
 /// * use_extrinsic_guess: false
 #[inline]
-pub fn estimate_pose_board(corners: &dyn core::ToInputArray, ids: &dyn core::ToInputArray, board: &core::Ptr<crate::aruco::Board>, camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, rvec: &mut dyn core::ToInputOutputArray, tvec: &mut dyn core::ToInputOutputArray, use_extrinsic_guess: bool) -> Result<i32> {
+pub fn estimate_pose_board(corners: &(impl core::ToInputArray + ?Sized), ids: &(impl core::ToInputArray + ?Sized), board: &core::Ptr<crate::aruco::Board>, camera_matrix: &(impl core::ToInputArray + ?Sized), dist_coeffs: &(impl core::ToInputArray + ?Sized), rvec: &mut (impl core::ToInputOutputArray + ?Sized), tvec: &mut (impl core::ToInputOutputArray + ?Sized), use_extrinsic_guess: bool) -> Result<i32> {
 	input_array_arg!(corners);
 	input_array_arg!(ids);
 	input_array_arg!(camera_matrix);
@@ -682,7 +682,7 @@ pub fn estimate_pose_board(corners: &dyn core::ToInputArray, ids: &dyn core::ToI
 /// ## C++ default parameters
 /// * use_extrinsic_guess: false
 #[inline]
-pub fn estimate_pose_charuco_board(charuco_corners: &dyn core::ToInputArray, charuco_ids: &dyn core::ToInputArray, board: &core::Ptr<crate::aruco::CharucoBoard>, camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, rvec: &mut dyn core::ToInputOutputArray, tvec: &mut dyn core::ToInputOutputArray, use_extrinsic_guess: bool) -> Result<bool> {
+pub fn estimate_pose_charuco_board(charuco_corners: &(impl core::ToInputArray + ?Sized), charuco_ids: &(impl core::ToInputArray + ?Sized), board: &core::Ptr<crate::aruco::CharucoBoard>, camera_matrix: &(impl core::ToInputArray + ?Sized), dist_coeffs: &(impl core::ToInputArray + ?Sized), rvec: &mut (impl core::ToInputOutputArray + ?Sized), tvec: &mut (impl core::ToInputOutputArray + ?Sized), use_extrinsic_guess: bool) -> Result<bool> {
 	input_array_arg!(charuco_corners);
 	input_array_arg!(charuco_ids);
 	input_array_arg!(camera_matrix);
@@ -737,13 +737,13 @@ pub fn estimate_pose_charuco_board(charuco_corners: &dyn core::ToInputArray, cha
 /// * _obj_points: noArray()
 /// * estimate_parameters: EstimateParameters::create()
 #[inline]
-pub fn estimate_pose_single_markers(corners: &dyn core::ToInputArray, marker_length: f32, camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, rvecs: &mut dyn core::ToOutputArray, tvecs: &mut dyn core::ToOutputArray, _obj_points: &mut dyn core::ToOutputArray, mut estimate_parameters: core::Ptr<crate::aruco::EstimateParameters>) -> Result<()> {
+pub fn estimate_pose_single_markers(corners: &(impl core::ToInputArray + ?Sized), marker_length: f32, camera_matrix: &(impl core::ToInputArray + ?Sized), dist_coeffs: &(impl core::ToInputArray + ?Sized), rvecs: &mut (impl core::ToOutputArray + ?Sized), tvecs: &mut (impl core::ToOutputArray + ?Sized), _obj_points: Option<&mut (impl core::ToOutputArray + ?Sized)>, mut estimate_parameters: core::Ptr<crate::aruco::EstimateParameters>) -> Result<()> {
 	input_array_arg!(corners);
 	input_array_arg!(camera_matrix);
 	input_array_arg!(dist_coeffs);
 	output_array_arg!(rvecs);
 	output_array_arg!(tvecs);
-	output_array_arg!(_obj_points);
+	output_array_arg!(nullable _obj_points);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_aruco_estimatePoseSingleMarkers_const__InputArrayR_float_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_PtrLEstimateParametersG(corners.as_raw__InputArray(), marker_length, camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), _obj_points.as_raw__OutputArray(), estimate_parameters.as_raw_mut_PtrOfEstimateParameters(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -801,7 +801,7 @@ pub fn custom_dictionary(n_markers: i32, marker_size: i32, random_seed: i32) ->
 /// * objPoints: Vector of vectors of board marker points in the board coordinate space.
 /// * imgPoints: Vector of vectors of the projections of board marker corner points.
 #[inline]
-pub fn get_board_object_and_image_points(board: &core::Ptr<crate::aruco::Board>, detected_corners: &dyn core::ToInputArray, detected_ids: &dyn core::ToInputArray, obj_points: &mut dyn core::ToOutputArray, img_points: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn get_board_object_and_image_points(board: &core::Ptr<crate::aruco::Board>, detected_corners: &(impl core::ToInputArray + ?Sized), detected_ids: &(impl core::ToInputArray + ?Sized), obj_points: &mut (impl core::ToOutputArray + ?Sized), img_points: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(detected_corners);
 	input_array_arg!(detected_ids);
 	output_array_arg!(obj_points);
@@ -864,14 +864,14 @@ pub fn get_predefined_dictionary_i32(dict: i32) -> Result<core::Ptr<crate::aruco
 /// * dist_coeffs: noArray()
 /// * min_markers: 2
 #[inline]
-pub fn interpolate_corners_charuco(marker_corners: &dyn core::ToInputArray, marker_ids: &dyn core::ToInputArray, image: &dyn core::ToInputArray, board: &core::Ptr<crate::aruco::CharucoBoard>, charuco_corners: &mut dyn core::ToOutputArray, charuco_ids: &mut dyn core::ToOutputArray, camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, min_markers: i32) -> Result<i32> {
+pub fn interpolate_corners_charuco(marker_corners: &(impl core::ToInputArray + ?Sized), marker_ids: &(impl core::ToInputArray + ?Sized), image: &(impl core::ToInputArray + ?Sized), board: &core::Ptr<crate::aruco::CharucoBoard>, charuco_corners: &mut (impl core::ToOutputArray + ?Sized), charuco_ids: &mut (impl core::ToOutputArray + ?Sized), camera_matrix: Option<&(impl core::ToInputArray + ?Sized)>, dist_coeffs: Option<&(impl core::ToInputArray + ?Sized)>, min_markers: i32) -> Result<i32> {
 	input_array_arg!(marker_corners);
 	input_array_arg!(marker_ids);
 	input_array_arg!(image);
 	output_array_arg!(charuco_corners);
 	output_array_arg!(charuco_ids);
-	input_array_arg!(camera_matrix);
-	input_array_arg!(dist_coeffs);
+	input_array_arg!(nullable camera_matrix);
+	input_array_arg!(nullable dist_coeffs);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_aruco_interpolateCornersCharuco_const__InputArrayR_const__InputArrayR_const__InputArrayR_const_PtrLCharucoBoardGR_const__OutputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_int(marker_corners.as_raw__InputArray(), marker_ids.as_raw__InputArray(), image.as_raw__InputArray(), board.as_raw_PtrOfCharucoBoard(), charuco_corners.as_raw__OutputArray(), charuco_ids.as_raw__OutputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), min_markers, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -919,14 +919,14 @@ pub fn interpolate_corners_charuco(marker_corners: &dyn core::ToInputArray, mark
 /// * recovered_idxs: noArray()
 /// * parameters: DetectorParameters::create()
 #[inline]
-pub fn refine_detected_markers(image: &dyn core::ToInputArray, board: &core::Ptr<crate::aruco::Board>, detected_corners: &mut dyn core::ToInputOutputArray, detected_ids: &mut dyn core::ToInputOutputArray, rejected_corners: &mut dyn core::ToInputOutputArray, camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, min_rep_distance: f32, error_correction_rate: f32, check_all_orders: bool, recovered_idxs: &mut dyn core::ToOutputArray, parameters: &core::Ptr<crate::aruco::DetectorParameters>) -> Result<()> {
+pub fn refine_detected_markers(image: &(impl core::ToInputArray + ?Sized), board: &core::Ptr<crate::aruco::Board>, detected_corners: &mut (impl core::ToInputOutputArray + ?Sized), detected_ids: &mut (impl core::ToInputOutputArray + ?Sized), rejected_corners: &mut (impl core::ToInputOutputArray + ?Sized), camera_matrix: Option<&(impl core::ToInputArray + ?Sized)>, dist_coeffs: Option<&(impl core::ToInputArray + ?Sized)>, min_rep_distance: f32, error_correction_rate: f32, check_all_orders: bool, recovered_idxs: Option<&mut (impl core::ToOutputArray + ?Sized)>, parameters: &core::Ptr<crate::aruco::DetectorParameters>) -> Result<()> {
 	input_array_arg!(image);
 	input_output_array_arg!(detected_corners);
 	input_output_array_arg!(detected_ids);
 	input_output_array_arg!(rejected_corners);
-	input_array_arg!(camera_matrix);
-	input_array_arg!(dist_coeffs);
-	output_array_arg!(recovered_idxs);
+	input_array_arg!(nullable camera_matrix);
+	input_array_arg!(nullable dist_coeffs);
+	output_array_arg!(nullable recovered_idxs);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_aruco_refineDetectedMarkers_const__InputArrayR_const_PtrLBoardGR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputArrayR_const__InputArrayR_float_float_bool_const__OutputArrayR_const_PtrLDetectorParametersGR(image.as_raw__InputArray(), board.as_raw_PtrOfBoard(), detected_corners.as_raw__InputOutputArray(), detected_ids.as_raw__InputOutputArray(), rejected_corners.as_raw__InputOutputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), min_rep_distance, error_correction_rate, check_all_orders, recovered_idxs.as_raw__OutputArray(), parameters.as_raw_PtrOfDetectorParameters(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -945,7 +945,7 @@ pub fn refine_detected_markers(image: &dyn core::ToInputArray, board: &core::Ptr
 /// 
 /// The number of ids in charucoIDs should be <= the number of chessboard corners in the board.  This functions checks whether the charuco corners are on a straight line (returns true, if so), or not (false).  Axis parallel, as well as diagonal and other straight lines detected.  Degenerate cases: for number of charucoIDs <= 2, the function returns true.
 #[inline]
-pub fn test_charuco_corners_collinear(_board: &core::Ptr<crate::aruco::CharucoBoard>, _charuco_ids: &dyn core::ToInputArray) -> Result<bool> {
+pub fn test_charuco_corners_collinear(_board: &core::Ptr<crate::aruco::CharucoBoard>, _charuco_ids: &(impl core::ToInputArray + ?Sized)) -> Result<bool> {
 	input_array_arg!(_charuco_ids);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_aruco_testCharucoCornersCollinear_const_PtrLCharucoBoardGR_const__InputArrayR(_board.as_raw_PtrOfCharucoBoard(), _charuco_ids.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
@@ -981,6 +981,14 @@ pub trait BoardTraitConst {
 		ret
 	}
 	
+	/// Same as `obj_points()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn obj_points_ref(&self) -> BoxedRef<'_, core::Vector<core::Vector<core::Point3f>>> {
+		let ret = unsafe { sys::cv_aruco_Board_getPropObjPointsRef_const(self.as_raw_Board()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// vector of the identifiers of the markers in the board (same size than objPoints)
 	/// The identifiers refers to the board dictionary
 	#[inline]
@@ -990,6 +998,14 @@ pub trait BoardTraitConst {
 		ret
 	}
 	
+	/// Same as `ids()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn ids_ref(&self) -> BoxedRef<'_, core::Vector<i32>> {
+		let ret = unsafe { sys::cv_aruco_Board_getPropIdsRef_const(self.as_raw_Board()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// coordinate of the bottom right corner of the board, is set when calling the function create()
 	#[inline]
 	fn right_bottom_border(&self) -> core::Point3f {
@@ -1108,7 +1124,7 @@ impl Board {
 	/// * dictionary: the dictionary of markers employed for this board
 	/// * ids: vector of the identifiers of the markers in the board
 	#[inline]
-	pub fn create(obj_points: &dyn core::ToInputArray, dictionary: &core::Ptr<crate::aruco::Dictionary>, ids: &dyn core::ToInputArray) -> Result<core::Ptr<crate::aruco::Board>> {
+	pub fn create(obj_points: &(impl core::ToInputArray + ?Sized), dictionary: &core::Ptr<crate::aruco::Dictionary>, ids: &(impl core::ToInputArray + ?Sized)) -> Result<core::Ptr<crate::aruco::Board>> {
 		input_array_arg!(obj_points);
 		input_array_arg!(ids);
 		return_send!(via ocvrs_return);
@@ -1137,6 +1153,14 @@ pub trait CharucoBoardTraitConst: crate::aruco::BoardTraitConst {
 		ret
 	}
 	
+	/// Same as `chessboard_corners()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn chessboard_corners_ref(&self) -> BoxedRef<'_, core::Vector<core::Point3f>> {
+		let ret = unsafe { sys::cv_aruco_CharucoBoard_getPropChessboardCornersRef_const(self.as_raw_CharucoBoard()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn nearest_marker_idx(&self) -> core::Vector<core::Vector<i32>> {
 		let ret = unsafe { sys::cv_aruco_CharucoBoard_getPropNearestMarkerIdx_const(self.as_raw_CharucoBoard()) };
@@ -1144,6 +1168,14 @@ pub trait CharucoBoardTraitConst: crate::aruco::BoardTraitConst {
 		ret
 	}
 	
+	/// Same as `nearest_marker_idx()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn nearest_marker_idx_ref(&self) -> BoxedRef<'_, core::Vector<core::Vector<i32>>> {
+		let ret = unsafe { sys::cv_aruco_CharucoBoard_getPropNearestMarkerIdxRef_const(self.as_raw_CharucoBoard()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn nearest_marker_corners(&self) -> core::Vector<core::Vector<i32>> {
 		let ret = unsafe { sys::cv_aruco_CharucoBoard_getPropNearestMarkerCorners_const(self.as_raw_CharucoBoard()) };
@@ -1151,6 +1183,14 @@ pub trait CharucoBoardTraitConst: crate::aruco::BoardTraitConst {
 		ret
 	}
 	
+	/// Same as `nearest_marker_corners()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn nearest_marker_corners_ref(&self) -> BoxedRef<'_, core::Vector<core::Vector<i32>>> {
+		let ret = unsafe { sys::cv_aruco_CharucoBoard_getPropNearestMarkerCornersRef_const(self.as_raw_CharucoBoard()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn get_chessboard_size(&self) -> Result<core::Size> {
 		return_send!(via ocvrs_return);
@@ -1916,6 +1956,14 @@ pub trait DictionaryTraitConst {
 		ret
 	}
 	
+	/// Same as `bytes_list()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn bytes_list_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_aruco_Dictionary_getPropBytesListRef_const(self.as_raw_Dictionary()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn marker_size(&self) -> i32 {
 		let ret = unsafe { sys::cv_aruco_Dictionary_getPropMarkerSize_const(self.as_raw_Dictionary()) };
@@ -2062,9 +2110,9 @@ impl Dictionary {
 	/// * _marker_size: 0
 	/// * _maxcorr: 0
 	#[inline]
-	pub fn new(_bytes_list: &core::Mat, _marker_size: i32, _maxcorr: i32) -> Result<crate::aruco::Dictionary> {
+	pub fn new(_bytes_list: Option<&core::Mat>, _marker_size: i32, _maxcorr: i32) -> Result<crate::aruco::Dictionary> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_aruco_Dictionary_Dictionary_const_MatR_int_int(_bytes_list.as_raw_Mat(), _marker_size, _maxcorr, ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_aruco_Dictionary_Dictionary_const_MatR_int_int(_bytes_list.map_or(::core::ptr::null(), |_bytes_list| _bytes_list.as_raw_Mat()), _marker_size, _maxcorr, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::aruco::Dictionary::opencv_from_extern(ret) };
@@ -189,7 +189,7 @@ pub fn create_background_subtractor_mog(history: i32, nmixtures: i32, background
 /// * wavespeed: 0.2
 /// * objspeed: 6.0
 #[inline]
-pub fn create_synthetic_sequence_generator(background: &dyn core::ToInputArray, object: &dyn core::ToInputArray, amplitude: f64, wavelength: f64, wavespeed: f64, objspeed: f64) -> Result<core::Ptr<crate::bgsegm::SyntheticSequenceGenerator>> {
+pub fn create_synthetic_sequence_generator(background: &(impl core::ToInputArray + ?Sized), object: &(impl core::ToInputArray + ?Sized), amplitude: f64, wavelength: f64, wavespeed: f64, objspeed: f64) -> Result<core::Ptr<crate::bgsegm::SyntheticSequenceGenerator>> {
 	input_array_arg!(background);
 	input_array_arg!(object);
 	return_send!(via ocvrs_return);
@@ -650,7 +650,7 @@ impl crate::bgsegm::BackgroundSubtractorLSBPDescTrait for BackgroundSubtractorLS
 
 impl BackgroundSubtractorLSBPDesc {
 	#[inline]
-	pub fn calc_local_svd_values(local_svd_values: &mut dyn core::ToOutputArray, frame: &core::Mat) -> Result<()> {
+	pub fn calc_local_svd_values(local_svd_values: &mut (impl core::ToOutputArray + ?Sized), frame: &core::Mat) -> Result<()> {
 		output_array_arg!(local_svd_values);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_bgsegm_BackgroundSubtractorLSBPDesc_calcLocalSVDValues_const__OutputArrayR_const_MatR(local_svd_values.as_raw__OutputArray(), frame.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
@@ -660,7 +660,7 @@ impl BackgroundSubtractorLSBPDesc {
 	}
 	
 	#[inline]
-	pub fn compute_from_local_svd_values(desc: &mut dyn core::ToOutputArray, local_svd_values: &core::Mat, lsbp_sample_points: &core::Point2i) -> Result<()> {
+	pub fn compute_from_local_svd_values(desc: &mut (impl core::ToOutputArray + ?Sized), local_svd_values: &core::Mat, lsbp_sample_points: &core::Point2i) -> Result<()> {
 		output_array_arg!(desc);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_bgsegm_BackgroundSubtractorLSBPDesc_computeFromLocalSVDValues_const__OutputArrayR_const_MatR_const_Point2iX(desc.as_raw__OutputArray(), local_svd_values.as_raw_Mat(), lsbp_sample_points, ocvrs_return.as_mut_ptr()) };
@@ -670,7 +670,7 @@ impl BackgroundSubtractorLSBPDesc {
 	}
 	
 	#[inline]
-	pub fn compute(desc: &mut dyn core::ToOutputArray, frame: &core::Mat, lsbp_sample_points: &core::Point2i) -> Result<()> {
+	pub fn compute(desc: &mut (impl core::ToOutputArray + ?Sized), frame: &core::Mat, lsbp_sample_points: &core::Point2i) -> Result<()> {
 		output_array_arg!(desc);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_bgsegm_BackgroundSubtractorLSBPDesc_compute_const__OutputArrayR_const_MatR_const_Point2iX(desc.as_raw__OutputArray(), frame.as_raw_Mat(), lsbp_sample_points, ocvrs_return.as_mut_ptr()) };
@@ -842,7 +842,7 @@ impl SyntheticSequenceGenerator {
 	/// * wavespeed: How fast waves will move.
 	/// * objspeed: How fast object will fly over background.
 	#[inline]
-	pub fn new(background: &dyn core::ToInputArray, object: &dyn core::ToInputArray, amplitude: f64, wavelength: f64, wavespeed: f64, objspeed: f64) -> Result<crate::bgsegm::SyntheticSequenceGenerator> {
+	pub fn new(background: &(impl core::ToInputArray + ?Sized), object: &(impl core::ToInputArray + ?Sized), amplitude: f64, wavelength: f64, wavespeed: f64, objspeed: f64) -> Result<crate::bgsegm::SyntheticSequenceGenerator> {
 		input_array_arg!(background);
 		input_array_arg!(object);
 		return_send!(via ocvrs_return);
@@ -856,3 +856,4 @@ impl SyntheticSequenceGenerator {
 }
 
 boxed_cast_base! { SyntheticSequenceGenerator, core::Algorithm, cv_SyntheticSequenceGenerator_to_Algorithm }
+pub use crate::manual::bgsegm::*;
@@ -575,13 +575,13 @@ pub type CirclesGridFinderParameters2 = crate::calib3d::CirclesGridFinderParamet
 /// * qy: noArray()
 /// * qz: noArray()
 #[inline]
-pub fn rq_decomp3x3(src: &dyn core::ToInputArray, mtx_r: &mut dyn core::ToOutputArray, mtx_q: &mut dyn core::ToOutputArray, qx: &mut dyn core::ToOutputArray, qy: &mut dyn core::ToOutputArray, qz: &mut dyn core::ToOutputArray) -> Result<core::Vec3d> {
+pub fn rq_decomp3x3(src: &(impl core::ToInputArray + ?Sized), mtx_r: &mut (impl core::ToOutputArray + ?Sized), mtx_q: &mut (impl core::ToOutputArray + ?Sized), qx: Option<&mut (impl core::ToOutputArray + ?Sized)>, qy: Option<&mut (impl core::ToOutputArray + ?Sized)>, qz: Option<&mut (impl core::ToOutputArray + ?Sized)>) -> Result<core::Vec3d> {
 	input_array_arg!(src);
 	output_array_arg!(mtx_r);
 	output_array_arg!(mtx_q);
-	output_array_arg!(qx);
-	output_array_arg!(qy);
-	output_array_arg!(qz);
+	output_array_arg!(nullable qx);
+	output_array_arg!(nullable qy);
+	output_array_arg!(nullable qz);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_RQDecomp3x3_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(src.as_raw__InputArray(), mtx_r.as_raw__OutputArray(), mtx_q.as_raw__OutputArray(), qx.as_raw__OutputArray(), qy.as_raw__OutputArray(), qz.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -621,10 +621,10 @@ pub fn rq_decomp3x3(src: &dyn core::ToInputArray, mtx_r: &mut dyn core::ToOutput
 /// ## C++ default parameters
 /// * jacobian: noArray()
 #[inline]
-pub fn rodrigues(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, jacobian: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn rodrigues(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), jacobian: Option<&mut (impl core::ToOutputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
-	output_array_arg!(jacobian);
+	output_array_arg!(nullable jacobian);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_Rodrigues_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), jacobian.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -695,7 +695,7 @@ pub fn rodrigues(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 /// * flags: 0
 /// * criteria: TermCriteria(TermCriteria::COUNT+TermCriteria::EPS,30,DBL_EPSILON)
 #[inline]
-pub fn calibrate_camera_ro_extended(object_points: &dyn core::ToInputArray, image_points: &dyn core::ToInputArray, image_size: core::Size, i_fixed_point: i32, camera_matrix: &mut dyn core::ToInputOutputArray, dist_coeffs: &mut dyn core::ToInputOutputArray, rvecs: &mut dyn core::ToOutputArray, tvecs: &mut dyn core::ToOutputArray, new_obj_points: &mut dyn core::ToOutputArray, std_deviations_intrinsics: &mut dyn core::ToOutputArray, std_deviations_extrinsics: &mut dyn core::ToOutputArray, std_deviations_obj_points: &mut dyn core::ToOutputArray, per_view_errors: &mut dyn core::ToOutputArray, flags: i32, criteria: core::TermCriteria) -> Result<f64> {
+pub fn calibrate_camera_ro_extended(object_points: &(impl core::ToInputArray + ?Sized), image_points: &(impl core::ToInputArray + ?Sized), image_size: core::Size, i_fixed_point: i32, camera_matrix: &mut (impl core::ToInputOutputArray + ?Sized), dist_coeffs: &mut (impl core::ToInputOutputArray + ?Sized), rvecs: &mut (impl core::ToOutputArray + ?Sized), tvecs: &mut (impl core::ToOutputArray + ?Sized), new_obj_points: &mut (impl core::ToOutputArray + ?Sized), std_deviations_intrinsics: &mut (impl core::ToOutputArray + ?Sized), std_deviations_extrinsics: &mut (impl core::ToOutputArray + ?Sized), std_deviations_obj_points: &mut (impl core::ToOutputArray + ?Sized), per_view_errors: &mut (impl core::ToOutputArray + ?Sized), flags: i32, criteria: core::TermCriteria) -> Result<f64> {
 	input_array_arg!(object_points);
 	input_array_arg!(image_points);
 	input_output_array_arg!(camera_matrix);
@@ -779,7 +779,7 @@ pub fn calibrate_camera_ro_extended(object_points: &dyn core::ToInputArray, imag
 /// * flags: 0
 /// * criteria: TermCriteria(TermCriteria::COUNT+TermCriteria::EPS,30,DBL_EPSILON)
 #[inline]
-pub fn calibrate_camera_ro(object_points: &dyn core::ToInputArray, image_points: &dyn core::ToInputArray, image_size: core::Size, i_fixed_point: i32, camera_matrix: &mut dyn core::ToInputOutputArray, dist_coeffs: &mut dyn core::ToInputOutputArray, rvecs: &mut dyn core::ToOutputArray, tvecs: &mut dyn core::ToOutputArray, new_obj_points: &mut dyn core::ToOutputArray, flags: i32, criteria: core::TermCriteria) -> Result<f64> {
+pub fn calibrate_camera_ro(object_points: &(impl core::ToInputArray + ?Sized), image_points: &(impl core::ToInputArray + ?Sized), image_size: core::Size, i_fixed_point: i32, camera_matrix: &mut (impl core::ToInputOutputArray + ?Sized), dist_coeffs: &mut (impl core::ToInputOutputArray + ?Sized), rvecs: &mut (impl core::ToOutputArray + ?Sized), tvecs: &mut (impl core::ToOutputArray + ?Sized), new_obj_points: &mut (impl core::ToOutputArray + ?Sized), flags: i32, criteria: core::TermCriteria) -> Result<f64> {
 	input_array_arg!(object_points);
 	input_array_arg!(image_points);
 	input_output_array_arg!(camera_matrix);
@@ -915,7 +915,7 @@ pub fn calibrate_camera_ro(object_points: &dyn core::ToInputArray, image_points:
 /// * flags: 0
 /// * criteria: TermCriteria(TermCriteria::COUNT+TermCriteria::EPS,30,DBL_EPSILON)
 #[inline]
-pub fn calibrate_camera_extended(object_points: &dyn core::ToInputArray, image_points: &dyn core::ToInputArray, image_size: core::Size, camera_matrix: &mut dyn core::ToInputOutputArray, dist_coeffs: &mut dyn core::ToInputOutputArray, rvecs: &mut dyn core::ToOutputArray, tvecs: &mut dyn core::ToOutputArray, std_deviations_intrinsics: &mut dyn core::ToOutputArray, std_deviations_extrinsics: &mut dyn core::ToOutputArray, per_view_errors: &mut dyn core::ToOutputArray, flags: i32, criteria: core::TermCriteria) -> Result<f64> {
+pub fn calibrate_camera_extended(object_points: &(impl core::ToInputArray + ?Sized), image_points: &(impl core::ToInputArray + ?Sized), image_size: core::Size, camera_matrix: &mut (impl core::ToInputOutputArray + ?Sized), dist_coeffs: &mut (impl core::ToInputOutputArray + ?Sized), rvecs: &mut (impl core::ToOutputArray + ?Sized), tvecs: &mut (impl core::ToOutputArray + ?Sized), std_deviations_intrinsics: &mut (impl core::ToOutputArray + ?Sized), std_deviations_extrinsics: &mut (impl core::ToOutputArray + ?Sized), per_view_errors: &mut (impl core::ToOutputArray + ?Sized), flags: i32, criteria: core::TermCriteria) -> Result<f64> {
 	input_array_arg!(object_points);
 	input_array_arg!(image_points);
 	input_output_array_arg!(camera_matrix);
@@ -1055,7 +1055,7 @@ pub fn calibrate_camera_extended(object_points: &dyn core::ToInputArray, image_p
 /// * flags: 0
 /// * criteria: TermCriteria(TermCriteria::COUNT+TermCriteria::EPS,30,DBL_EPSILON)
 #[inline]
-pub fn calibrate_camera(object_points: &dyn core::ToInputArray, image_points: &dyn core::ToInputArray, image_size: core::Size, camera_matrix: &mut dyn core::ToInputOutputArray, dist_coeffs: &mut dyn core::ToInputOutputArray, rvecs: &mut dyn core::ToOutputArray, tvecs: &mut dyn core::ToOutputArray, flags: i32, criteria: core::TermCriteria) -> Result<f64> {
+pub fn calibrate_camera(object_points: &(impl core::ToInputArray + ?Sized), image_points: &(impl core::ToInputArray + ?Sized), image_size: core::Size, camera_matrix: &mut (impl core::ToInputOutputArray + ?Sized), dist_coeffs: &mut (impl core::ToInputOutputArray + ?Sized), rvecs: &mut (impl core::ToOutputArray + ?Sized), tvecs: &mut (impl core::ToOutputArray + ?Sized), flags: i32, criteria: core::TermCriteria) -> Result<f64> {
 	input_array_arg!(object_points);
 	input_array_arg!(image_points);
 	input_output_array_arg!(camera_matrix);
@@ -1144,7 +1144,7 @@ pub fn calibrate_camera(object_points: &dyn core::ToInputArray, image_points: &d
 /// ## C++ default parameters
 /// * method: CALIB_HAND_EYE_TSAI
 #[inline]
-pub fn calibrate_hand_eye(r_gripper2base: &dyn core::ToInputArray, t_gripper2base: &dyn core::ToInputArray, r_target2cam: &dyn core::ToInputArray, t_target2cam: &dyn core::ToInputArray, r_cam2gripper: &mut dyn core::ToOutputArray, t_cam2gripper: &mut dyn core::ToOutputArray, method: crate::calib3d::HandEyeCalibrationMethod) -> Result<()> {
+pub fn calibrate_hand_eye(r_gripper2base: &(impl core::ToInputArray + ?Sized), t_gripper2base: &(impl core::ToInputArray + ?Sized), r_target2cam: &(impl core::ToInputArray + ?Sized), t_target2cam: &(impl core::ToInputArray + ?Sized), r_cam2gripper: &mut (impl core::ToOutputArray + ?Sized), t_cam2gripper: &mut (impl core::ToOutputArray + ?Sized), method: crate::calib3d::HandEyeCalibrationMethod) -> Result<()> {
 	input_array_arg!(r_gripper2base);
 	input_array_arg!(t_gripper2base);
 	input_array_arg!(r_target2cam);
@@ -1227,7 +1227,7 @@ pub fn calibrate_hand_eye(r_gripper2base: &dyn core::ToInputArray, t_gripper2bas
 /// ## C++ default parameters
 /// * method: CALIB_ROBOT_WORLD_HAND_EYE_SHAH
 #[inline]
-pub fn calibrate_robot_world_hand_eye(r_world2cam: &dyn core::ToInputArray, t_world2cam: &dyn core::ToInputArray, r_base2gripper: &dyn core::ToInputArray, t_base2gripper: &dyn core::ToInputArray, r_base2world: &mut dyn core::ToOutputArray, t_base2world: &mut dyn core::ToOutputArray, r_gripper2cam: &mut dyn core::ToOutputArray, t_gripper2cam: &mut dyn core::ToOutputArray, method: crate::calib3d::RobotWorldHandEyeCalibrationMethod) -> Result<()> {
+pub fn calibrate_robot_world_hand_eye(r_world2cam: &(impl core::ToInputArray + ?Sized), t_world2cam: &(impl core::ToInputArray + ?Sized), r_base2gripper: &(impl core::ToInputArray + ?Sized), t_base2gripper: &(impl core::ToInputArray + ?Sized), r_base2world: &mut (impl core::ToOutputArray + ?Sized), t_base2world: &mut (impl core::ToOutputArray + ?Sized), r_gripper2cam: &mut (impl core::ToOutputArray + ?Sized), t_gripper2cam: &mut (impl core::ToOutputArray + ?Sized), method: crate::calib3d::RobotWorldHandEyeCalibrationMethod) -> Result<()> {
 	input_array_arg!(r_world2cam);
 	input_array_arg!(t_world2cam);
 	input_array_arg!(r_base2gripper);
@@ -1265,7 +1265,7 @@ pub fn calibrate_robot_world_hand_eye(r_world2cam: &dyn core::ToInputArray, t_wo
 ///    Do keep in mind that the unity measure 'mm' stands for whatever unit of measure one chooses for
 ///    the chessboard pitch (it can thus be any value).
 #[inline]
-pub fn calibration_matrix_values(camera_matrix: &dyn core::ToInputArray, image_size: core::Size, aperture_width: f64, aperture_height: f64, fovx: &mut f64, fovy: &mut f64, focal_length: &mut f64, principal_point: &mut core::Point2d, aspect_ratio: &mut f64) -> Result<()> {
+pub fn calibration_matrix_values(camera_matrix: &(impl core::ToInputArray + ?Sized), image_size: core::Size, aperture_width: f64, aperture_height: f64, fovx: &mut f64, fovy: &mut f64, focal_length: &mut f64, principal_point: &mut core::Point2d, aspect_ratio: &mut f64) -> Result<()> {
 	input_array_arg!(camera_matrix);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_calibrationMatrixValues_const__InputArrayR_Size_double_double_doubleR_doubleR_doubleR_Point2dR_doubleR(camera_matrix.as_raw__InputArray(), image_size.opencv_as_extern(), aperture_width, aperture_height, fovx, fovy, focal_length, principal_point, aspect_ratio, ocvrs_return.as_mut_ptr()) };
@@ -1275,7 +1275,7 @@ pub fn calibration_matrix_values(camera_matrix: &dyn core::ToInputArray, image_s
 }
 
 #[inline]
-pub fn check_chessboard(img: &dyn core::ToInputArray, size: core::Size) -> Result<bool> {
+pub fn check_chessboard(img: &(impl core::ToInputArray + ?Sized), size: core::Size) -> Result<bool> {
 	input_array_arg!(img);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_checkChessboard_const__InputArrayR_Size(img.as_raw__InputArray(), size.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
@@ -1324,21 +1324,21 @@ pub fn check_chessboard(img: &dyn core::ToInputArray, size: core::Size) -> Resul
 /// * dt3dr2: noArray()
 /// * dt3dt2: noArray()
 #[inline]
-pub fn compose_rt(rvec1: &dyn core::ToInputArray, tvec1: &dyn core::ToInputArray, rvec2: &dyn core::ToInputArray, tvec2: &dyn core::ToInputArray, rvec3: &mut dyn core::ToOutputArray, tvec3: &mut dyn core::ToOutputArray, dr3dr1: &mut dyn core::ToOutputArray, dr3dt1: &mut dyn core::ToOutputArray, dr3dr2: &mut dyn core::ToOutputArray, dr3dt2: &mut dyn core::ToOutputArray, dt3dr1: &mut dyn core::ToOutputArray, dt3dt1: &mut dyn core::ToOutputArray, dt3dr2: &mut dyn core::ToOutputArray, dt3dt2: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn compose_rt(rvec1: &(impl core::ToInputArray + ?Sized), tvec1: &(impl core::ToInputArray + ?Sized), rvec2: &(impl core::ToInputArray + ?Sized), tvec2: &(impl core::ToInputArray + ?Sized), rvec3: &mut (impl core::ToOutputArray + ?Sized), tvec3: &mut (impl core::ToOutputArray + ?Sized), dr3dr1: Option<&mut (impl core::ToOutputArray + ?Sized)>, dr3dt1: Option<&mut (impl core::ToOutputArray + ?Sized)>, dr3dr2: Option<&mut (impl core::ToOutputArray + ?Sized)>, dr3dt2: Option<&mut (impl core::ToOutputArray + ?Sized)>, dt3dr1: Option<&mut (impl core::ToOutputArray + ?Sized)>, dt3dt1: Option<&mut (impl core::ToOutputArray + ?Sized)>, dt3dr2: Option<&mut (impl core::ToOutputArray + ?Sized)>, dt3dt2: Option<&mut (impl core::ToOutputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(rvec1);
 	input_array_arg!(tvec1);
 	input_array_arg!(rvec2);
 	input_array_arg!(tvec2);
 	output_array_arg!(rvec3);
 	output_array_arg!(tvec3);
-	output_array_arg!(dr3dr1);
-	output_array_arg!(dr3dt1);
-	output_array_arg!(dr3dr2);
-	output_array_arg!(dr3dt2);
-	output_array_arg!(dt3dr1);
-	output_array_arg!(dt3dt1);
-	output_array_arg!(dt3dr2);
-	output_array_arg!(dt3dt2);
+	output_array_arg!(nullable dr3dr1);
+	output_array_arg!(nullable dr3dt1);
+	output_array_arg!(nullable dr3dr2);
+	output_array_arg!(nullable dr3dt2);
+	output_array_arg!(nullable dt3dr1);
+	output_array_arg!(nullable dt3dt1);
+	output_array_arg!(nullable dt3dr2);
+	output_array_arg!(nullable dt3dt2);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_composeRT_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(rvec1.as_raw__InputArray(), tvec1.as_raw__InputArray(), rvec2.as_raw__InputArray(), tvec2.as_raw__InputArray(), rvec3.as_raw__OutputArray(), tvec3.as_raw__OutputArray(), dr3dr1.as_raw__OutputArray(), dr3dt1.as_raw__OutputArray(), dr3dr2.as_raw__OutputArray(), dr3dt2.as_raw__OutputArray(), dt3dr1.as_raw__OutputArray(), dt3dt1.as_raw__OutputArray(), dt3dr2.as_raw__OutputArray(), dt3dt2.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -1370,7 +1370,7 @@ pub fn compose_rt(rvec1: &dyn core::ToInputArray, tvec1: &dyn core::ToInputArray
 /// 
 /// Line coefficients are defined up to a scale. They are normalized so that ![inline formula](https://latex.codecogs.com/png.latex?a%5Fi%5E2%2Bb%5Fi%5E2%3D1) .
 #[inline]
-pub fn compute_correspond_epilines(points: &dyn core::ToInputArray, which_image: i32, f: &dyn core::ToInputArray, lines: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn compute_correspond_epilines(points: &(impl core::ToInputArray + ?Sized), which_image: i32, f: &(impl core::ToInputArray + ?Sized), lines: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(points);
 	input_array_arg!(f);
 	output_array_arg!(lines);
@@ -1391,7 +1391,7 @@ pub fn compute_correspond_epilines(points: &dyn core::ToInputArray, which_image:
 /// each point (x1, x2, ... x(n-1), xn) is converted to (x1/xn, x2/xn, ..., x(n-1)/xn). When xn=0, the
 /// output point coordinates will be (0,0,0,...).
 #[inline]
-pub fn convert_points_from_homogeneous(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn convert_points_from_homogeneous(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -1413,7 +1413,7 @@ pub fn convert_points_from_homogeneous(src: &dyn core::ToInputArray, dst: &mut d
 /// 
 /// Note: The function is obsolete. Use one of the previous two functions instead.
 #[inline]
-pub fn convert_points_homogeneous(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn convert_points_homogeneous(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -1432,7 +1432,7 @@ pub fn convert_points_homogeneous(src: &dyn core::ToInputArray, dst: &mut dyn co
 /// The function converts points from Euclidean to homogeneous space by appending 1's to the tuple of
 /// point coordinates. That is, each point (x1, x2, ..., xn) is converted to (x1, x2, ..., xn, 1).
 #[inline]
-pub fn convert_points_to_homogeneous(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn convert_points_to_homogeneous(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -1458,7 +1458,7 @@ pub fn convert_points_to_homogeneous(src: &dyn core::ToInputArray, dst: &mut dyn
 /// geometric distance between points ![inline formula](https://latex.codecogs.com/png.latex?a) and ![inline formula](https://latex.codecogs.com/png.latex?b) ) subject to the epipolar constraint
 /// ![inline formula](https://latex.codecogs.com/png.latex?newPoints2%5ET%20%2A%20F%20%2A%20newPoints1%20%3D%200) .
 #[inline]
-pub fn correct_matches(f: &dyn core::ToInputArray, points1: &dyn core::ToInputArray, points2: &dyn core::ToInputArray, new_points1: &mut dyn core::ToOutputArray, new_points2: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn correct_matches(f: &(impl core::ToInputArray + ?Sized), points1: &(impl core::ToInputArray + ?Sized), points2: &(impl core::ToInputArray + ?Sized), new_points1: &mut (impl core::ToOutputArray + ?Sized), new_points2: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(f);
 	input_array_arg!(points1);
 	input_array_arg!(points2);
@@ -1490,7 +1490,7 @@ pub fn correct_matches(f: &dyn core::ToInputArray, points1: &dyn core::ToInputAr
 /// can only get the direction of the translation. For this reason, the translation t is returned with
 /// unit length.
 #[inline]
-pub fn decompose_essential_mat(e: &dyn core::ToInputArray, r1: &mut dyn core::ToOutputArray, r2: &mut dyn core::ToOutputArray, t: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn decompose_essential_mat(e: &(impl core::ToInputArray + ?Sized), r1: &mut (impl core::ToOutputArray + ?Sized), r2: &mut (impl core::ToOutputArray + ?Sized), t: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(e);
 	output_array_arg!(r1);
 	output_array_arg!(r2);
@@ -1525,7 +1525,7 @@ pub fn decompose_essential_mat(e: &dyn core::ToInputArray, r1: &mut dyn core::To
 /// If point correspondences are available, at least two solutions may further be invalidated, by
 /// applying positive depth constraint, i.e. all points must be in front of the camera.
 #[inline]
-pub fn decompose_homography_mat(h: &dyn core::ToInputArray, k: &dyn core::ToInputArray, rotations: &mut dyn core::ToOutputArray, translations: &mut dyn core::ToOutputArray, normals: &mut dyn core::ToOutputArray) -> Result<i32> {
+pub fn decompose_homography_mat(h: &(impl core::ToInputArray + ?Sized), k: &(impl core::ToInputArray + ?Sized), rotations: &mut (impl core::ToOutputArray + ?Sized), translations: &mut (impl core::ToOutputArray + ?Sized), normals: &mut (impl core::ToOutputArray + ?Sized)) -> Result<i32> {
 	input_array_arg!(h);
 	input_array_arg!(k);
 	output_array_arg!(rotations);
@@ -1567,15 +1567,15 @@ pub fn decompose_homography_mat(h: &dyn core::ToInputArray, k: &dyn core::ToInpu
 /// * rot_matrix_z: noArray()
 /// * euler_angles: noArray()
 #[inline]
-pub fn decompose_projection_matrix(proj_matrix: &dyn core::ToInputArray, camera_matrix: &mut dyn core::ToOutputArray, rot_matrix: &mut dyn core::ToOutputArray, trans_vect: &mut dyn core::ToOutputArray, rot_matrix_x: &mut dyn core::ToOutputArray, rot_matrix_y: &mut dyn core::ToOutputArray, rot_matrix_z: &mut dyn core::ToOutputArray, euler_angles: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn decompose_projection_matrix(proj_matrix: &(impl core::ToInputArray + ?Sized), camera_matrix: &mut (impl core::ToOutputArray + ?Sized), rot_matrix: &mut (impl core::ToOutputArray + ?Sized), trans_vect: &mut (impl core::ToOutputArray + ?Sized), rot_matrix_x: Option<&mut (impl core::ToOutputArray + ?Sized)>, rot_matrix_y: Option<&mut (impl core::ToOutputArray + ?Sized)>, rot_matrix_z: Option<&mut (impl core::ToOutputArray + ?Sized)>, euler_angles: Option<&mut (impl core::ToOutputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(proj_matrix);
 	output_array_arg!(camera_matrix);
 	output_array_arg!(rot_matrix);
 	output_array_arg!(trans_vect);
-	output_array_arg!(rot_matrix_x);
-	output_array_arg!(rot_matrix_y);
-	output_array_arg!(rot_matrix_z);
-	output_array_arg!(euler_angles);
+	output_array_arg!(nullable rot_matrix_x);
+	output_array_arg!(nullable rot_matrix_y);
+	output_array_arg!(nullable rot_matrix_z);
+	output_array_arg!(nullable euler_angles);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_decomposeProjectionMatrix_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(proj_matrix.as_raw__InputArray(), camera_matrix.as_raw__OutputArray(), rot_matrix.as_raw__OutputArray(), trans_vect.as_raw__OutputArray(), rot_matrix_x.as_raw__OutputArray(), rot_matrix_y.as_raw__OutputArray(), rot_matrix_z.as_raw__OutputArray(), euler_angles.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -1596,7 +1596,7 @@ pub fn decompose_projection_matrix(proj_matrix: &dyn core::ToInputArray, camera_
 /// The function draws individual chessboard corners detected either as red circles if the board was not
 /// found, or as colored corners connected with lines if the board was found.
 #[inline]
-pub fn draw_chessboard_corners(image: &mut dyn core::ToInputOutputArray, pattern_size: core::Size, corners: &dyn core::ToInputArray, pattern_was_found: bool) -> Result<()> {
+pub fn draw_chessboard_corners(image: &mut (impl core::ToInputOutputArray + ?Sized), pattern_size: core::Size, corners: &(impl core::ToInputArray + ?Sized), pattern_was_found: bool) -> Result<()> {
 	input_output_array_arg!(image);
 	input_array_arg!(corners);
 	return_send!(via ocvrs_return);
@@ -1626,7 +1626,7 @@ pub fn draw_chessboard_corners(image: &mut dyn core::ToInputOutputArray, pattern
 /// ## C++ default parameters
 /// * thickness: 3
 #[inline]
-pub fn draw_frame_axes(image: &mut dyn core::ToInputOutputArray, camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, rvec: &dyn core::ToInputArray, tvec: &dyn core::ToInputArray, length: f32, thickness: i32) -> Result<()> {
+pub fn draw_frame_axes(image: &mut (impl core::ToInputOutputArray + ?Sized), camera_matrix: &(impl core::ToInputArray + ?Sized), dist_coeffs: &(impl core::ToInputArray + ?Sized), rvec: &(impl core::ToInputArray + ?Sized), tvec: &(impl core::ToInputArray + ?Sized), length: f32, thickness: i32) -> Result<()> {
 	input_output_array_arg!(image);
 	input_array_arg!(camera_matrix);
 	input_array_arg!(dist_coeffs);
@@ -1640,7 +1640,7 @@ pub fn draw_frame_axes(image: &mut dyn core::ToInputOutputArray, camera_matrix:
 }
 
 #[inline]
-pub fn estimate_affine_2d_1(pts1: &dyn core::ToInputArray, pts2: &dyn core::ToInputArray, inliers: &mut dyn core::ToOutputArray, params: crate::calib3d::UsacParams) -> Result<core::Mat> {
+pub fn estimate_affine_2d_1(pts1: &(impl core::ToInputArray + ?Sized), pts2: &(impl core::ToInputArray + ?Sized), inliers: &mut (impl core::ToOutputArray + ?Sized), params: crate::calib3d::UsacParams) -> Result<core::Mat> {
 	input_array_arg!(pts1);
 	input_array_arg!(pts2);
 	output_array_arg!(inliers);
@@ -1701,10 +1701,10 @@ pub fn estimate_affine_2d_1(pts1: &dyn core::ToInputArray, pts2: &dyn core::ToIn
 /// * confidence: 0.99
 /// * refine_iters: 10
 #[inline]
-pub fn estimate_affine_2d(from: &dyn core::ToInputArray, to: &dyn core::ToInputArray, inliers: &mut dyn core::ToOutputArray, method: i32, ransac_reproj_threshold: f64, max_iters: size_t, confidence: f64, refine_iters: size_t) -> Result<core::Mat> {
+pub fn estimate_affine_2d(from: &(impl core::ToInputArray + ?Sized), to: &(impl core::ToInputArray + ?Sized), inliers: Option<&mut (impl core::ToOutputArray + ?Sized)>, method: i32, ransac_reproj_threshold: f64, max_iters: usize, confidence: f64, refine_iters: usize) -> Result<core::Mat> {
 	input_array_arg!(from);
 	input_array_arg!(to);
-	output_array_arg!(inliers);
+	output_array_arg!(nullable inliers);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_estimateAffine2D_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_double_size_t_double_size_t(from.as_raw__InputArray(), to.as_raw__InputArray(), inliers.as_raw__OutputArray(), method, ransac_reproj_threshold, max_iters, confidence, refine_iters, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -1737,7 +1737,7 @@ pub fn estimate_affine_2d(from: &dyn core::ToInputArray, to: &dyn core::ToInputA
 /// * ransac_threshold: 3
 /// * confidence: 0.99
 #[inline]
-pub fn estimate_affine_3d(src: &dyn core::ToInputArray, dst: &dyn core::ToInputArray, out: &mut dyn core::ToOutputArray, inliers: &mut dyn core::ToOutputArray, ransac_threshold: f64, confidence: f64) -> Result<i32> {
+pub fn estimate_affine_3d(src: &(impl core::ToInputArray + ?Sized), dst: &(impl core::ToInputArray + ?Sized), out: &mut (impl core::ToOutputArray + ?Sized), inliers: &mut (impl core::ToOutputArray + ?Sized), ransac_threshold: f64, confidence: f64) -> Result<i32> {
 	input_array_arg!(src);
 	input_array_arg!(dst);
 	output_array_arg!(out);
@@ -1774,7 +1774,7 @@ pub fn estimate_affine_3d(src: &dyn core::ToInputArray, dst: &dyn core::ToInputA
 /// * scale: nullptr
 /// * force_rotation: true
 #[inline]
-pub fn estimate_affine_3d_1(src: &dyn core::ToInputArray, dst: &dyn core::ToInputArray, scale: &mut f64, force_rotation: bool) -> Result<core::Mat> {
+pub fn estimate_affine_3d_1(src: &(impl core::ToInputArray + ?Sized), dst: &(impl core::ToInputArray + ?Sized), scale: &mut f64, force_rotation: bool) -> Result<core::Mat> {
 	input_array_arg!(src);
 	input_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -1837,10 +1837,10 @@ pub fn estimate_affine_3d_1(src: &dyn core::ToInputArray, dst: &dyn core::ToInpu
 /// * confidence: 0.99
 /// * refine_iters: 10
 #[inline]
-pub fn estimate_affine_partial_2d(from: &dyn core::ToInputArray, to: &dyn core::ToInputArray, inliers: &mut dyn core::ToOutputArray, method: i32, ransac_reproj_threshold: f64, max_iters: size_t, confidence: f64, refine_iters: size_t) -> Result<core::Mat> {
+pub fn estimate_affine_partial_2d(from: &(impl core::ToInputArray + ?Sized), to: &(impl core::ToInputArray + ?Sized), inliers: Option<&mut (impl core::ToOutputArray + ?Sized)>, method: i32, ransac_reproj_threshold: f64, max_iters: usize, confidence: f64, refine_iters: usize) -> Result<core::Mat> {
 	input_array_arg!(from);
 	input_array_arg!(to);
-	output_array_arg!(inliers);
+	output_array_arg!(nullable inliers);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_estimateAffinePartial2D_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_double_size_t_double_size_t(from.as_raw__InputArray(), to.as_raw__InputArray(), inliers.as_raw__OutputArray(), method, ransac_reproj_threshold, max_iters, confidence, refine_iters, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -1883,10 +1883,10 @@ pub fn estimate_affine_partial_2d(from: &dyn core::ToInputArray, to: &dyn core::
 /// * vertical: false
 /// * sharpness: noArray()
 #[inline]
-pub fn estimate_chessboard_sharpness(image: &dyn core::ToInputArray, pattern_size: core::Size, corners: &dyn core::ToInputArray, rise_distance: f32, vertical: bool, sharpness: &mut dyn core::ToOutputArray) -> Result<core::Scalar> {
+pub fn estimate_chessboard_sharpness(image: &(impl core::ToInputArray + ?Sized), pattern_size: core::Size, corners: &(impl core::ToInputArray + ?Sized), rise_distance: f32, vertical: bool, sharpness: Option<&mut (impl core::ToOutputArray + ?Sized)>) -> Result<core::Scalar> {
 	input_array_arg!(image);
 	input_array_arg!(corners);
-	output_array_arg!(sharpness);
+	output_array_arg!(nullable sharpness);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_estimateChessboardSharpness_const__InputArrayR_Size_const__InputArrayR_float_bool_const__OutputArrayR(image.as_raw__InputArray(), pattern_size.opencv_as_extern(), corners.as_raw__InputArray(), rise_distance, vertical, sharpness.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -1918,7 +1918,7 @@ pub fn estimate_chessboard_sharpness(image: &dyn core::ToInputArray, pattern_siz
 /// * ransac_threshold: 3
 /// * confidence: 0.99
 #[inline]
-pub fn estimate_translation_3d(src: &dyn core::ToInputArray, dst: &dyn core::ToInputArray, out: &mut dyn core::ToOutputArray, inliers: &mut dyn core::ToOutputArray, ransac_threshold: f64, confidence: f64) -> Result<i32> {
+pub fn estimate_translation_3d(src: &(impl core::ToInputArray + ?Sized), dst: &(impl core::ToInputArray + ?Sized), out: &mut (impl core::ToOutputArray + ?Sized), inliers: &mut (impl core::ToOutputArray + ?Sized), ransac_threshold: f64, confidence: f64) -> Result<i32> {
 	input_array_arg!(src);
 	input_array_arg!(dst);
 	output_array_arg!(out);
@@ -1951,13 +1951,13 @@ pub fn estimate_translation_3d(src: &dyn core::ToInputArray, dst: &dyn core::ToI
 /// ## C++ default parameters
 /// * points_mask: noArray()
 #[inline]
-pub fn filter_homography_decomp_by_visible_refpoints(rotations: &dyn core::ToInputArray, normals: &dyn core::ToInputArray, before_points: &dyn core::ToInputArray, after_points: &dyn core::ToInputArray, possible_solutions: &mut dyn core::ToOutputArray, points_mask: &dyn core::ToInputArray) -> Result<()> {
+pub fn filter_homography_decomp_by_visible_refpoints(rotations: &(impl core::ToInputArray + ?Sized), normals: &(impl core::ToInputArray + ?Sized), before_points: &(impl core::ToInputArray + ?Sized), after_points: &(impl core::ToInputArray + ?Sized), possible_solutions: &mut (impl core::ToOutputArray + ?Sized), points_mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(rotations);
 	input_array_arg!(normals);
 	input_array_arg!(before_points);
 	input_array_arg!(after_points);
 	output_array_arg!(possible_solutions);
-	input_array_arg!(points_mask);
+	input_array_arg!(nullable points_mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_filterHomographyDecompByVisibleRefpoints_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR(rotations.as_raw__InputArray(), normals.as_raw__InputArray(), before_points.as_raw__InputArray(), after_points.as_raw__InputArray(), possible_solutions.as_raw__OutputArray(), points_mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -1981,9 +1981,9 @@ pub fn filter_homography_decomp_by_visible_refpoints(rotations: &dyn core::ToInp
 /// ## C++ default parameters
 /// * buf: noArray()
 #[inline]
-pub fn filter_speckles(img: &mut dyn core::ToInputOutputArray, new_val: f64, max_speckle_size: i32, max_diff: f64, buf: &mut dyn core::ToInputOutputArray) -> Result<()> {
+pub fn filter_speckles(img: &mut (impl core::ToInputOutputArray + ?Sized), new_val: f64, max_speckle_size: i32, max_diff: f64, buf: Option<&mut (impl core::ToInputOutputArray + ?Sized)>) -> Result<()> {
 	input_output_array_arg!(img);
-	input_output_array_arg!(buf);
+	input_output_array_arg!(nullable buf);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_filterSpeckles_const__InputOutputArrayR_double_int_double_const__InputOutputArrayR(img.as_raw__InputOutputArray(), new_val, max_speckle_size, max_diff, buf.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -1993,7 +1993,7 @@ pub fn filter_speckles(img: &mut dyn core::ToInputOutputArray, new_val: f64, max
 
 /// finds subpixel-accurate positions of the chessboard corners
 #[inline]
-pub fn find4_quad_corner_subpix(img: &dyn core::ToInputArray, corners: &mut dyn core::ToInputOutputArray, region_size: core::Size) -> Result<bool> {
+pub fn find4_quad_corner_subpix(img: &(impl core::ToInputArray + ?Sized), corners: &mut (impl core::ToInputOutputArray + ?Sized), region_size: core::Size) -> Result<bool> {
 	input_array_arg!(img);
 	input_output_array_arg!(corners);
 	return_send!(via ocvrs_return);
@@ -2060,7 +2060,7 @@ pub fn find4_quad_corner_subpix(img: &dyn core::ToInputArray, corners: &mut dyn
 /// ## C++ default parameters
 /// * flags: 0
 #[inline]
-pub fn find_chessboard_corners_sb(image: &dyn core::ToInputArray, pattern_size: core::Size, corners: &mut dyn core::ToOutputArray, flags: i32) -> Result<bool> {
+pub fn find_chessboard_corners_sb(image: &(impl core::ToInputArray + ?Sized), pattern_size: core::Size, corners: &mut (impl core::ToOutputArray + ?Sized), flags: i32) -> Result<bool> {
 	input_array_arg!(image);
 	output_array_arg!(corners);
 	return_send!(via ocvrs_return);
@@ -2122,7 +2122,7 @@ pub fn find_chessboard_corners_sb(image: &dyn core::ToInputArray, pattern_size:
 /// Use gen_pattern.py (@ref tutorial_camera_calibration_pattern) to create checkerboard.
 /// ![Checkerboard](https://docs.opencv.org/4.6.0/checkerboard_radon.png)
 #[inline]
-pub fn find_chessboard_corners_sb_with_meta(image: &dyn core::ToInputArray, pattern_size: core::Size, corners: &mut dyn core::ToOutputArray, flags: i32, meta: &mut dyn core::ToOutputArray) -> Result<bool> {
+pub fn find_chessboard_corners_sb_with_meta(image: &(impl core::ToInputArray + ?Sized), pattern_size: core::Size, corners: &mut (impl core::ToOutputArray + ?Sized), flags: i32, meta: &mut (impl core::ToOutputArray + ?Sized)) -> Result<bool> {
 	input_array_arg!(image);
 	output_array_arg!(corners);
 	output_array_arg!(meta);
@@ -2190,7 +2190,7 @@ pub fn find_chessboard_corners_sb_with_meta(image: &dyn core::ToInputArray, patt
 /// ## C++ default parameters
 /// * flags: CALIB_CB_ADAPTIVE_THRESH+CALIB_CB_NORMALIZE_IMAGE
 #[inline]
-pub fn find_chessboard_corners(image: &dyn core::ToInputArray, pattern_size: core::Size, corners: &mut dyn core::ToOutputArray, flags: i32) -> Result<bool> {
+pub fn find_chessboard_corners(image: &(impl core::ToInputArray + ?Sized), pattern_size: core::Size, corners: &mut (impl core::ToOutputArray + ?Sized), flags: i32) -> Result<bool> {
 	input_array_arg!(image);
 	output_array_arg!(corners);
 	return_send!(via ocvrs_return);
@@ -2242,7 +2242,7 @@ pub fn find_chessboard_corners(image: &dyn core::ToInputArray, pattern_size: cor
 /// * flags: CALIB_CB_SYMMETRIC_GRID
 /// * blob_detector: SimpleBlobDetector::create()
 #[inline]
-pub fn find_circles_grid_1(image: &dyn core::ToInputArray, pattern_size: core::Size, centers: &mut dyn core::ToOutputArray, flags: i32, blob_detector: &core::Ptr<crate::features2d::Feature2D>) -> Result<bool> {
+pub fn find_circles_grid_1(image: &(impl core::ToInputArray + ?Sized), pattern_size: core::Size, centers: &mut (impl core::ToOutputArray + ?Sized), flags: i32, blob_detector: &core::Ptr<crate::features2d::Feature2D>) -> Result<bool> {
 	input_array_arg!(image);
 	output_array_arg!(centers);
 	return_send!(via ocvrs_return);
@@ -2288,7 +2288,7 @@ pub fn find_circles_grid_1(image: &dyn core::ToInputArray, pattern_size: core::S
 /// Note: The function requires white space (like a square-thick border, the wider the better) around
 /// the board to make the detection more robust in various environments.
 #[inline]
-pub fn find_circles_grid(image: &dyn core::ToInputArray, pattern_size: core::Size, centers: &mut dyn core::ToOutputArray, flags: i32, blob_detector: &core::Ptr<crate::features2d::Feature2D>, parameters: crate::calib3d::CirclesGridFinderParameters) -> Result<bool> {
+pub fn find_circles_grid(image: &(impl core::ToInputArray + ?Sized), pattern_size: core::Size, centers: &mut (impl core::ToOutputArray + ?Sized), flags: i32, blob_detector: &core::Ptr<crate::features2d::Feature2D>, parameters: crate::calib3d::CirclesGridFinderParameters) -> Result<bool> {
 	input_array_arg!(image);
 	output_array_arg!(centers);
 	return_send!(via ocvrs_return);
@@ -2299,7 +2299,7 @@ pub fn find_circles_grid(image: &dyn core::ToInputArray, pattern_size: core::Siz
 }
 
 #[inline]
-pub fn find_essential_mat_4(points1: &dyn core::ToInputArray, points2: &dyn core::ToInputArray, camera_matrix1: &dyn core::ToInputArray, camera_matrix2: &dyn core::ToInputArray, dist_coeff1: &dyn core::ToInputArray, dist_coeff2: &dyn core::ToInputArray, mask: &mut dyn core::ToOutputArray, params: crate::calib3d::UsacParams) -> Result<core::Mat> {
+pub fn find_essential_mat_4(points1: &(impl core::ToInputArray + ?Sized), points2: &(impl core::ToInputArray + ?Sized), camera_matrix1: &(impl core::ToInputArray + ?Sized), camera_matrix2: &(impl core::ToInputArray + ?Sized), dist_coeff1: &(impl core::ToInputArray + ?Sized), dist_coeff2: &(impl core::ToInputArray + ?Sized), mask: &mut (impl core::ToOutputArray + ?Sized), params: crate::calib3d::UsacParams) -> Result<core::Mat> {
 	input_array_arg!(points1);
 	input_array_arg!(points2);
 	input_array_arg!(camera_matrix1);
@@ -2366,14 +2366,14 @@ pub fn find_essential_mat_4(points1: &dyn core::ToInputArray, points2: &dyn core
 /// * threshold: 1.0
 /// * mask: noArray()
 #[inline]
-pub fn find_essential_mat_3(points1: &dyn core::ToInputArray, points2: &dyn core::ToInputArray, camera_matrix1: &dyn core::ToInputArray, dist_coeffs1: &dyn core::ToInputArray, camera_matrix2: &dyn core::ToInputArray, dist_coeffs2: &dyn core::ToInputArray, method: i32, prob: f64, threshold: f64, mask: &mut dyn core::ToOutputArray) -> Result<core::Mat> {
+pub fn find_essential_mat_3(points1: &(impl core::ToInputArray + ?Sized), points2: &(impl core::ToInputArray + ?Sized), camera_matrix1: &(impl core::ToInputArray + ?Sized), dist_coeffs1: &(impl core::ToInputArray + ?Sized), camera_matrix2: &(impl core::ToInputArray + ?Sized), dist_coeffs2: &(impl core::ToInputArray + ?Sized), method: i32, prob: f64, threshold: f64, mask: Option<&mut (impl core::ToOutputArray + ?Sized)>) -> Result<core::Mat> {
 	input_array_arg!(points1);
 	input_array_arg!(points2);
 	input_array_arg!(camera_matrix1);
 	input_array_arg!(dist_coeffs1);
 	input_array_arg!(camera_matrix2);
 	input_array_arg!(dist_coeffs2);
-	output_array_arg!(mask);
+	output_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_findEssentialMat_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_double_double_const__OutputArrayR(points1.as_raw__InputArray(), points2.as_raw__InputArray(), camera_matrix1.as_raw__InputArray(), dist_coeffs1.as_raw__InputArray(), camera_matrix2.as_raw__InputArray(), dist_coeffs2.as_raw__InputArray(), method, prob, threshold, mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -2418,7 +2418,7 @@ pub fn find_essential_mat_3(points1: &dyn core::ToInputArray, points2: &dyn core
 /// 
 /// ## Overloaded parameters
 #[inline]
-pub fn find_essential_mat_matrix(points1: &dyn core::ToInputArray, points2: &dyn core::ToInputArray, camera_matrix: &dyn core::ToInputArray, method: i32, prob: f64, threshold: f64, mask: &mut dyn core::ToOutputArray) -> Result<core::Mat> {
+pub fn find_essential_mat_matrix(points1: &(impl core::ToInputArray + ?Sized), points2: &(impl core::ToInputArray + ?Sized), camera_matrix: &(impl core::ToInputArray + ?Sized), method: i32, prob: f64, threshold: f64, mask: &mut (impl core::ToOutputArray + ?Sized)) -> Result<core::Mat> {
 	input_array_arg!(points1);
 	input_array_arg!(points2);
 	input_array_arg!(camera_matrix);
@@ -2472,11 +2472,11 @@ pub fn find_essential_mat_matrix(points1: &dyn core::ToInputArray, points2: &dyn
 /// * max_iters: 1000
 /// * mask: noArray()
 #[inline]
-pub fn find_essential_mat(points1: &dyn core::ToInputArray, points2: &dyn core::ToInputArray, camera_matrix: &dyn core::ToInputArray, method: i32, prob: f64, threshold: f64, max_iters: i32, mask: &mut dyn core::ToOutputArray) -> Result<core::Mat> {
+pub fn find_essential_mat(points1: &(impl core::ToInputArray + ?Sized), points2: &(impl core::ToInputArray + ?Sized), camera_matrix: &(impl core::ToInputArray + ?Sized), method: i32, prob: f64, threshold: f64, max_iters: i32, mask: Option<&mut (impl core::ToOutputArray + ?Sized)>) -> Result<core::Mat> {
 	input_array_arg!(points1);
 	input_array_arg!(points2);
 	input_array_arg!(camera_matrix);
-	output_array_arg!(mask);
+	output_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_findEssentialMat_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_double_double_int_const__OutputArrayR(points1.as_raw__InputArray(), points2.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), method, prob, threshold, max_iters, mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -2521,7 +2521,7 @@ pub fn find_essential_mat(points1: &dyn core::ToInputArray, points2: &dyn core::
 /// 
 /// ## Overloaded parameters
 #[inline]
-pub fn find_essential_mat_2(points1: &dyn core::ToInputArray, points2: &dyn core::ToInputArray, focal: f64, pp: core::Point2d, method: i32, prob: f64, threshold: f64, mask: &mut dyn core::ToOutputArray) -> Result<core::Mat> {
+pub fn find_essential_mat_2(points1: &(impl core::ToInputArray + ?Sized), points2: &(impl core::ToInputArray + ?Sized), focal: f64, pp: core::Point2d, method: i32, prob: f64, threshold: f64, mask: &mut (impl core::ToOutputArray + ?Sized)) -> Result<core::Mat> {
 	input_array_arg!(points1);
 	input_array_arg!(points2);
 	output_array_arg!(mask);
@@ -2602,10 +2602,10 @@ pub fn find_essential_mat_2(points1: &dyn core::ToInputArray, points2: &dyn core
 /// * max_iters: 1000
 /// * mask: noArray()
 #[inline]
-pub fn find_essential_mat_1(points1: &dyn core::ToInputArray, points2: &dyn core::ToInputArray, focal: f64, pp: core::Point2d, method: i32, prob: f64, threshold: f64, max_iters: i32, mask: &mut dyn core::ToOutputArray) -> Result<core::Mat> {
+pub fn find_essential_mat_1(points1: &(impl core::ToInputArray + ?Sized), points2: &(impl core::ToInputArray + ?Sized), focal: f64, pp: core::Point2d, method: i32, prob: f64, threshold: f64, max_iters: i32, mask: Option<&mut (impl core::ToOutputArray + ?Sized)>) -> Result<core::Mat> {
 	input_array_arg!(points1);
 	input_array_arg!(points2);
-	output_array_arg!(mask);
+	output_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_findEssentialMat_const__InputArrayR_const__InputArrayR_double_Point2d_int_double_double_int_const__OutputArrayR(points1.as_raw__InputArray(), points2.as_raw__InputArray(), focal, pp.opencv_as_extern(), method, prob, threshold, max_iters, mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -2615,7 +2615,7 @@ pub fn find_essential_mat_1(points1: &dyn core::ToInputArray, points2: &dyn core
 }
 
 #[inline]
-pub fn find_fundamental_mat_2(points1: &dyn core::ToInputArray, points2: &dyn core::ToInputArray, mask: &mut dyn core::ToOutputArray, params: crate::calib3d::UsacParams) -> Result<core::Mat> {
+pub fn find_fundamental_mat_2(points1: &(impl core::ToInputArray + ?Sized), points2: &(impl core::ToInputArray + ?Sized), mask: &mut (impl core::ToOutputArray + ?Sized), params: crate::calib3d::UsacParams) -> Result<core::Mat> {
 	input_array_arg!(points1);
 	input_array_arg!(points2);
 	output_array_arg!(mask);
@@ -2687,7 +2687,7 @@ pub fn find_fundamental_mat_2(points1: &dyn core::ToInputArray, points2: &dyn co
 /// * ransac_reproj_threshold: 3.
 /// * confidence: 0.99
 #[inline]
-pub fn find_fundamental_mat_mask(points1: &dyn core::ToInputArray, points2: &dyn core::ToInputArray, mask: &mut dyn core::ToOutputArray, method: i32, ransac_reproj_threshold: f64, confidence: f64) -> Result<core::Mat> {
+pub fn find_fundamental_mat_mask(points1: &(impl core::ToInputArray + ?Sized), points2: &(impl core::ToInputArray + ?Sized), mask: &mut (impl core::ToOutputArray + ?Sized), method: i32, ransac_reproj_threshold: f64, confidence: f64) -> Result<core::Mat> {
 	input_array_arg!(points1);
 	input_array_arg!(points2);
 	output_array_arg!(mask);
@@ -2760,10 +2760,10 @@ pub fn find_fundamental_mat_mask(points1: &dyn core::ToInputArray, points2: &dyn
 /// * confidence: 0.99
 /// * mask: noArray()
 #[inline]
-pub fn find_fundamental_mat_1(points1: &dyn core::ToInputArray, points2: &dyn core::ToInputArray, method: i32, ransac_reproj_threshold: f64, confidence: f64, mask: &mut dyn core::ToOutputArray) -> Result<core::Mat> {
+pub fn find_fundamental_mat_1(points1: &(impl core::ToInputArray + ?Sized), points2: &(impl core::ToInputArray + ?Sized), method: i32, ransac_reproj_threshold: f64, confidence: f64, mask: Option<&mut (impl core::ToOutputArray + ?Sized)>) -> Result<core::Mat> {
 	input_array_arg!(points1);
 	input_array_arg!(points2);
-	output_array_arg!(mask);
+	output_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_findFundamentalMat_const__InputArrayR_const__InputArrayR_int_double_double_const__OutputArrayR(points1.as_raw__InputArray(), points2.as_raw__InputArray(), method, ransac_reproj_threshold, confidence, mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -2828,10 +2828,10 @@ pub fn find_fundamental_mat_1(points1: &dyn core::ToInputArray, points2: &dyn co
 /// ## C++ default parameters
 /// * mask: noArray()
 #[inline]
-pub fn find_fundamental_mat(points1: &dyn core::ToInputArray, points2: &dyn core::ToInputArray, method: i32, ransac_reproj_threshold: f64, confidence: f64, max_iters: i32, mask: &mut dyn core::ToOutputArray) -> Result<core::Mat> {
+pub fn find_fundamental_mat(points1: &(impl core::ToInputArray + ?Sized), points2: &(impl core::ToInputArray + ?Sized), method: i32, ransac_reproj_threshold: f64, confidence: f64, max_iters: i32, mask: Option<&mut (impl core::ToOutputArray + ?Sized)>) -> Result<core::Mat> {
 	input_array_arg!(points1);
 	input_array_arg!(points2);
-	output_array_arg!(mask);
+	output_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_findFundamentalMat_const__InputArrayR_const__InputArrayR_int_double_double_int_const__OutputArrayR(points1.as_raw__InputArray(), points2.as_raw__InputArray(), method, ransac_reproj_threshold, confidence, max_iters, mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -2841,7 +2841,7 @@ pub fn find_fundamental_mat(points1: &dyn core::ToInputArray, points2: &dyn core
 }
 
 #[inline]
-pub fn find_homography_1(src_points: &dyn core::ToInputArray, dst_points: &dyn core::ToInputArray, mask: &mut dyn core::ToOutputArray, params: crate::calib3d::UsacParams) -> Result<core::Mat> {
+pub fn find_homography_1(src_points: &(impl core::ToInputArray + ?Sized), dst_points: &(impl core::ToInputArray + ?Sized), mask: &mut (impl core::ToOutputArray + ?Sized), params: crate::calib3d::UsacParams) -> Result<core::Mat> {
 	input_array_arg!(src_points);
 	input_array_arg!(dst_points);
 	output_array_arg!(mask);
@@ -2918,7 +2918,7 @@ pub fn find_homography_1(src_points: &dyn core::ToInputArray, dst_points: &dyn c
 /// * method: 0
 /// * ransac_reproj_threshold: 3
 #[inline]
-pub fn find_homography(src_points: &dyn core::ToInputArray, dst_points: &dyn core::ToInputArray, mask: &mut dyn core::ToOutputArray, method: i32, ransac_reproj_threshold: f64) -> Result<core::Mat> {
+pub fn find_homography(src_points: &(impl core::ToInputArray + ?Sized), dst_points: &(impl core::ToInputArray + ?Sized), mask: &mut (impl core::ToOutputArray + ?Sized), method: i32, ransac_reproj_threshold: f64) -> Result<core::Mat> {
 	input_array_arg!(src_points);
 	input_array_arg!(dst_points);
 	output_array_arg!(mask);
@@ -2996,10 +2996,10 @@ pub fn find_homography(src_points: &dyn core::ToInputArray, dst_points: &dyn cor
 /// * max_iters: 2000
 /// * confidence: 0.995
 #[inline]
-pub fn find_homography_ext(src_points: &dyn core::ToInputArray, dst_points: &dyn core::ToInputArray, method: i32, ransac_reproj_threshold: f64, mask: &mut dyn core::ToOutputArray, max_iters: i32, confidence: f64) -> Result<core::Mat> {
+pub fn find_homography_ext(src_points: &(impl core::ToInputArray + ?Sized), dst_points: &(impl core::ToInputArray + ?Sized), method: i32, ransac_reproj_threshold: f64, mask: Option<&mut (impl core::ToOutputArray + ?Sized)>, max_iters: i32, confidence: f64) -> Result<core::Mat> {
 	input_array_arg!(src_points);
 	input_array_arg!(dst_points);
-	output_array_arg!(mask);
+	output_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_findHomography_const__InputArrayR_const__InputArrayR_int_double_const__OutputArrayR_const_int_const_double(src_points.as_raw__InputArray(), dst_points.as_raw__InputArray(), method, ransac_reproj_threshold, mask.as_raw__OutputArray(), max_iters, confidence, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -3048,7 +3048,7 @@ pub fn find_homography_ext(src_points: &dyn core::ToInputArray, dst_points: &dyn
 /// * flags: 0
 /// * criteria: TermCriteria(TermCriteria::COUNT+TermCriteria::EPS,100,DBL_EPSILON)
 #[inline]
-pub fn calibrate(object_points: &dyn core::ToInputArray, image_points: &dyn core::ToInputArray, image_size: core::Size, k: &mut dyn core::ToInputOutputArray, d: &mut dyn core::ToInputOutputArray, rvecs: &mut dyn core::ToOutputArray, tvecs: &mut dyn core::ToOutputArray, flags: i32, criteria: core::TermCriteria) -> Result<f64> {
+pub fn calibrate(object_points: &(impl core::ToInputArray + ?Sized), image_points: &(impl core::ToInputArray + ?Sized), image_size: core::Size, k: &mut (impl core::ToInputOutputArray + ?Sized), d: &mut (impl core::ToInputOutputArray + ?Sized), rvecs: &mut (impl core::ToOutputArray + ?Sized), tvecs: &mut (impl core::ToOutputArray + ?Sized), flags: i32, criteria: core::TermCriteria) -> Result<f64> {
 	input_array_arg!(object_points);
 	input_array_arg!(image_points);
 	input_output_array_arg!(k);
@@ -3078,7 +3078,7 @@ pub fn calibrate(object_points: &dyn core::ToInputArray, image_points: &dyn core
 /// ## C++ default parameters
 /// * alpha: 0
 #[inline]
-pub fn fisheye_distort_points(undistorted: &dyn core::ToInputArray, distorted: &mut dyn core::ToOutputArray, k: &dyn core::ToInputArray, d: &dyn core::ToInputArray, alpha: f64) -> Result<()> {
+pub fn fisheye_distort_points(undistorted: &(impl core::ToInputArray + ?Sized), distorted: &mut (impl core::ToOutputArray + ?Sized), k: &(impl core::ToInputArray + ?Sized), d: &(impl core::ToInputArray + ?Sized), alpha: f64) -> Result<()> {
 	input_array_arg!(undistorted);
 	output_array_arg!(distorted);
 	input_array_arg!(k);
@@ -3109,7 +3109,7 @@ pub fn fisheye_distort_points(undistorted: &dyn core::ToInputArray, distorted: &
 /// * new_size: Size()
 /// * fov_scale: 1.0
 #[inline]
-pub fn estimate_new_camera_matrix_for_undistort_rectify(k: &dyn core::ToInputArray, d: &dyn core::ToInputArray, image_size: core::Size, r: &dyn core::ToInputArray, p: &mut dyn core::ToOutputArray, balance: f64, new_size: core::Size, fov_scale: f64) -> Result<()> {
+pub fn estimate_new_camera_matrix_for_undistort_rectify(k: &(impl core::ToInputArray + ?Sized), d: &(impl core::ToInputArray + ?Sized), image_size: core::Size, r: &(impl core::ToInputArray + ?Sized), p: &mut (impl core::ToOutputArray + ?Sized), balance: f64, new_size: core::Size, fov_scale: f64) -> Result<()> {
 	input_array_arg!(k);
 	input_array_arg!(d);
 	input_array_arg!(r);
@@ -3136,7 +3136,7 @@ pub fn estimate_new_camera_matrix_for_undistort_rectify(k: &dyn core::ToInputArr
 /// * map1: The first output map.
 /// * map2: The second output map.
 #[inline]
-pub fn fisheye_init_undistort_rectify_map(k: &dyn core::ToInputArray, d: &dyn core::ToInputArray, r: &dyn core::ToInputArray, p: &dyn core::ToInputArray, size: core::Size, m1type: i32, map1: &mut dyn core::ToOutputArray, map2: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn fisheye_init_undistort_rectify_map(k: &(impl core::ToInputArray + ?Sized), d: &(impl core::ToInputArray + ?Sized), r: &(impl core::ToInputArray + ?Sized), p: &(impl core::ToInputArray + ?Sized), size: core::Size, m1type: i32, map1: &mut (impl core::ToOutputArray + ?Sized), map2: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(k);
 	input_array_arg!(d);
 	input_array_arg!(r);
@@ -3175,12 +3175,12 @@ pub fn fisheye_init_undistort_rectify_map(k: &dyn core::ToInputArray, d: &dyn co
 /// * alpha: 0
 /// * jacobian: noArray()
 #[inline]
-pub fn fisheye_project_points(object_points: &dyn core::ToInputArray, image_points: &mut dyn core::ToOutputArray, affine: core::Affine3d, k: &dyn core::ToInputArray, d: &dyn core::ToInputArray, alpha: f64, jacobian: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn fisheye_project_points(object_points: &(impl core::ToInputArray + ?Sized), image_points: &mut (impl core::ToOutputArray + ?Sized), affine: core::Affine3d, k: &(impl core::ToInputArray + ?Sized), d: &(impl core::ToInputArray + ?Sized), alpha: f64, jacobian: Option<&mut (impl core::ToOutputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(object_points);
 	output_array_arg!(image_points);
 	input_array_arg!(k);
 	input_array_arg!(d);
-	output_array_arg!(jacobian);
+	output_array_arg!(nullable jacobian);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_fisheye_projectPoints_const__InputArrayR_const__OutputArrayR_const_Affine3dR_const__InputArrayR_const__InputArrayR_double_const__OutputArrayR(object_points.as_raw__InputArray(), image_points.as_raw__OutputArray(), &affine, k.as_raw__InputArray(), d.as_raw__InputArray(), alpha, jacobian.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -3215,14 +3215,14 @@ pub fn fisheye_project_points(object_points: &dyn core::ToInputArray, image_poin
 /// * alpha: 0
 /// * jacobian: noArray()
 #[inline]
-pub fn fisheye_project_points_vec(object_points: &dyn core::ToInputArray, image_points: &mut dyn core::ToOutputArray, rvec: &dyn core::ToInputArray, tvec: &dyn core::ToInputArray, k: &dyn core::ToInputArray, d: &dyn core::ToInputArray, alpha: f64, jacobian: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn fisheye_project_points_vec(object_points: &(impl core::ToInputArray + ?Sized), image_points: &mut (impl core::ToOutputArray + ?Sized), rvec: &(impl core::ToInputArray + ?Sized), tvec: &(impl core::ToInputArray + ?Sized), k: &(impl core::ToInputArray + ?Sized), d: &(impl core::ToInputArray + ?Sized), alpha: f64, jacobian: Option<&mut (impl core::ToOutputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(object_points);
 	output_array_arg!(image_points);
 	input_array_arg!(rvec);
 	input_array_arg!(tvec);
 	input_array_arg!(k);
 	input_array_arg!(d);
-	output_array_arg!(jacobian);
+	output_array_arg!(nullable jacobian);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_fisheye_projectPoints_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_double_const__OutputArrayR(object_points.as_raw__InputArray(), image_points.as_raw__OutputArray(), rvec.as_raw__InputArray(), tvec.as_raw__InputArray(), k.as_raw__InputArray(), d.as_raw__InputArray(), alpha, jacobian.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -3267,7 +3267,7 @@ pub fn fisheye_project_points_vec(object_points: &dyn core::ToInputArray, image_
 /// * flags: fisheye::CALIB_FIX_INTRINSIC
 /// * criteria: TermCriteria(TermCriteria::COUNT+TermCriteria::EPS,100,DBL_EPSILON)
 #[inline]
-pub fn fisheye_stereo_calibrate(object_points: &dyn core::ToInputArray, image_points1: &dyn core::ToInputArray, image_points2: &dyn core::ToInputArray, k1: &mut dyn core::ToInputOutputArray, d1: &mut dyn core::ToInputOutputArray, k2: &mut dyn core::ToInputOutputArray, d2: &mut dyn core::ToInputOutputArray, image_size: core::Size, r: &mut dyn core::ToOutputArray, t: &mut dyn core::ToOutputArray, flags: i32, criteria: core::TermCriteria) -> Result<f64> {
+pub fn fisheye_stereo_calibrate(object_points: &(impl core::ToInputArray + ?Sized), image_points1: &(impl core::ToInputArray + ?Sized), image_points2: &(impl core::ToInputArray + ?Sized), k1: &mut (impl core::ToInputOutputArray + ?Sized), d1: &mut (impl core::ToInputOutputArray + ?Sized), k2: &mut (impl core::ToInputOutputArray + ?Sized), d2: &mut (impl core::ToInputOutputArray + ?Sized), image_size: core::Size, r: &mut (impl core::ToOutputArray + ?Sized), t: &mut (impl core::ToOutputArray + ?Sized), flags: i32, criteria: core::TermCriteria) -> Result<f64> {
 	input_array_arg!(object_points);
 	input_array_arg!(image_points1);
 	input_array_arg!(image_points2);
@@ -3320,7 +3320,7 @@ pub fn fisheye_stereo_calibrate(object_points: &dyn core::ToInputArray, image_po
 /// * balance: 0.0
 /// * fov_scale: 1.0
 #[inline]
-pub fn fisheye_stereo_rectify(k1: &dyn core::ToInputArray, d1: &dyn core::ToInputArray, k2: &dyn core::ToInputArray, d2: &dyn core::ToInputArray, image_size: core::Size, r: &dyn core::ToInputArray, tvec: &dyn core::ToInputArray, r1: &mut dyn core::ToOutputArray, r2: &mut dyn core::ToOutputArray, p1: &mut dyn core::ToOutputArray, p2: &mut dyn core::ToOutputArray, q: &mut dyn core::ToOutputArray, flags: i32, new_image_size: core::Size, balance: f64, fov_scale: f64) -> Result<()> {
+pub fn fisheye_stereo_rectify(k1: &(impl core::ToInputArray + ?Sized), d1: &(impl core::ToInputArray + ?Sized), k2: &(impl core::ToInputArray + ?Sized), d2: &(impl core::ToInputArray + ?Sized), image_size: core::Size, r: &(impl core::ToInputArray + ?Sized), tvec: &(impl core::ToInputArray + ?Sized), r1: &mut (impl core::ToOutputArray + ?Sized), r2: &mut (impl core::ToOutputArray + ?Sized), p1: &mut (impl core::ToOutputArray + ?Sized), p2: &mut (impl core::ToOutputArray + ?Sized), q: &mut (impl core::ToOutputArray + ?Sized), flags: i32, new_image_size: core::Size, balance: f64, fov_scale: f64) -> Result<()> {
 	input_array_arg!(k1);
 	input_array_arg!(d1);
 	input_array_arg!(k2);
@@ -3372,12 +3372,12 @@ pub fn fisheye_stereo_rectify(k1: &dyn core::ToInputArray, d1: &dyn core::ToInpu
 /// * knew: cv::noArray()
 /// * new_size: Size()
 #[inline]
-pub fn fisheye_undistort_image(distorted: &dyn core::ToInputArray, undistorted: &mut dyn core::ToOutputArray, k: &dyn core::ToInputArray, d: &dyn core::ToInputArray, knew: &dyn core::ToInputArray, new_size: core::Size) -> Result<()> {
+pub fn fisheye_undistort_image(distorted: &(impl core::ToInputArray + ?Sized), undistorted: &mut (impl core::ToOutputArray + ?Sized), k: &(impl core::ToInputArray + ?Sized), d: &(impl core::ToInputArray + ?Sized), knew: Option<&(impl core::ToInputArray + ?Sized)>, new_size: core::Size) -> Result<()> {
 	input_array_arg!(distorted);
 	output_array_arg!(undistorted);
 	input_array_arg!(k);
 	input_array_arg!(d);
-	input_array_arg!(knew);
+	input_array_arg!(nullable knew);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_fisheye_undistortImage_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const_SizeR(distorted.as_raw__InputArray(), undistorted.as_raw__OutputArray(), k.as_raw__InputArray(), d.as_raw__InputArray(), knew.as_raw__InputArray(), &new_size, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -3403,13 +3403,13 @@ pub fn fisheye_undistort_image(distorted: &dyn core::ToInputArray, undistorted:
 /// * p: noArray()
 /// * criteria: TermCriteria(TermCriteria::MAX_ITER+TermCriteria::EPS,10,1e-8)
 #[inline]
-pub fn fisheye_undistort_points(distorted: &dyn core::ToInputArray, undistorted: &mut dyn core::ToOutputArray, k: &dyn core::ToInputArray, d: &dyn core::ToInputArray, r: &dyn core::ToInputArray, p: &dyn core::ToInputArray, criteria: core::TermCriteria) -> Result<()> {
+pub fn fisheye_undistort_points(distorted: &(impl core::ToInputArray + ?Sized), undistorted: &mut (impl core::ToOutputArray + ?Sized), k: &(impl core::ToInputArray + ?Sized), d: &(impl core::ToInputArray + ?Sized), r: Option<&(impl core::ToInputArray + ?Sized)>, p: Option<&(impl core::ToInputArray + ?Sized)>, criteria: core::TermCriteria) -> Result<()> {
 	input_array_arg!(distorted);
 	output_array_arg!(undistorted);
 	input_array_arg!(k);
 	input_array_arg!(d);
-	input_array_arg!(r);
-	input_array_arg!(p);
+	input_array_arg!(nullable r);
+	input_array_arg!(nullable p);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_fisheye_undistortPoints_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_TermCriteria(distorted.as_raw__InputArray(), undistorted.as_raw__OutputArray(), k.as_raw__InputArray(), d.as_raw__InputArray(), r.as_raw__InputArray(), p.as_raw__InputArray(), criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -3444,7 +3444,7 @@ pub fn fisheye_undistort_points(distorted: &dyn core::ToInputArray, undistorted:
 /// * imgsize: Size()
 /// * center_principal_point: false
 #[inline]
-pub fn get_default_new_camera_matrix(camera_matrix: &dyn core::ToInputArray, imgsize: core::Size, center_principal_point: bool) -> Result<core::Mat> {
+pub fn get_default_new_camera_matrix(camera_matrix: &(impl core::ToInputArray + ?Sized), imgsize: core::Size, center_principal_point: bool) -> Result<core::Mat> {
 	input_array_arg!(camera_matrix);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_getDefaultNewCameraMatrix_const__InputArrayR_Size_bool(camera_matrix.as_raw__InputArray(), imgsize.opencv_as_extern(), center_principal_point, ocvrs_return.as_mut_ptr()) };
@@ -3487,7 +3487,7 @@ pub fn get_default_new_camera_matrix(camera_matrix: &dyn core::ToInputArray, img
 /// * valid_pix_roi: 0
 /// * center_principal_point: false
 #[inline]
-pub fn get_optimal_new_camera_matrix(camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, image_size: core::Size, alpha: f64, new_img_size: core::Size, valid_pix_roi: Option<&mut core::Rect>, center_principal_point: bool) -> Result<core::Mat> {
+pub fn get_optimal_new_camera_matrix(camera_matrix: &(impl core::ToInputArray + ?Sized), dist_coeffs: &(impl core::ToInputArray + ?Sized), image_size: core::Size, alpha: f64, new_img_size: core::Size, valid_pix_roi: Option<&mut core::Rect>, center_principal_point: bool) -> Result<core::Mat> {
 	input_array_arg!(camera_matrix);
 	input_array_arg!(dist_coeffs);
 	return_send!(via ocvrs_return);
@@ -3527,7 +3527,7 @@ pub fn get_valid_disparity_roi(roi1: core::Rect, roi2: core::Rect, min_disparity
 /// ## C++ default parameters
 /// * aspect_ratio: 1.0
 #[inline]
-pub fn init_camera_matrix_2d(object_points: &dyn core::ToInputArray, image_points: &dyn core::ToInputArray, image_size: core::Size, aspect_ratio: f64) -> Result<core::Mat> {
+pub fn init_camera_matrix_2d(object_points: &(impl core::ToInputArray + ?Sized), image_points: &(impl core::ToInputArray + ?Sized), image_size: core::Size, aspect_ratio: f64) -> Result<core::Mat> {
 	input_array_arg!(object_points);
 	input_array_arg!(image_points);
 	return_send!(via ocvrs_return);
@@ -3583,7 +3583,7 @@ pub fn init_camera_matrix_2d(object_points: &dyn core::ToInputArray, image_point
 /// * map1: The first output map for #remap.
 /// * map2: The second output map for #remap.
 #[inline]
-pub fn init_inverse_rectification_map(camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, r: &dyn core::ToInputArray, new_camera_matrix: &dyn core::ToInputArray, size: core::Size, m1type: i32, map1: &mut dyn core::ToOutputArray, map2: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn init_inverse_rectification_map(camera_matrix: &(impl core::ToInputArray + ?Sized), dist_coeffs: &(impl core::ToInputArray + ?Sized), r: &(impl core::ToInputArray + ?Sized), new_camera_matrix: &(impl core::ToInputArray + ?Sized), size: core::Size, m1type: i32, map1: &mut (impl core::ToOutputArray + ?Sized), map2: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(camera_matrix);
 	input_array_arg!(dist_coeffs);
 	input_array_arg!(r);
@@ -3641,7 +3641,7 @@ pub fn init_inverse_rectification_map(camera_matrix: &dyn core::ToInputArray, di
 /// * map1: The first output map.
 /// * map2: The second output map.
 #[inline]
-pub fn init_undistort_rectify_map(camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, r: &dyn core::ToInputArray, new_camera_matrix: &dyn core::ToInputArray, size: core::Size, m1type: i32, map1: &mut dyn core::ToOutputArray, map2: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn init_undistort_rectify_map(camera_matrix: &(impl core::ToInputArray + ?Sized), dist_coeffs: &(impl core::ToInputArray + ?Sized), r: &(impl core::ToInputArray + ?Sized), new_camera_matrix: &(impl core::ToInputArray + ?Sized), size: core::Size, m1type: i32, map1: &mut (impl core::ToOutputArray + ?Sized), map2: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(camera_matrix);
 	input_array_arg!(dist_coeffs);
 	input_array_arg!(r);
@@ -3661,7 +3661,7 @@ pub fn init_undistort_rectify_map(camera_matrix: &dyn core::ToInputArray, dist_c
 /// * proj_type: PROJ_SPHERICAL_EQRECT
 /// * alpha: 0
 #[inline]
-pub fn init_wide_angle_proj_map(camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, image_size: core::Size, dest_image_width: i32, m1type: i32, map1: &mut dyn core::ToOutputArray, map2: &mut dyn core::ToOutputArray, proj_type: crate::calib3d::UndistortTypes, alpha: f64) -> Result<f32> {
+pub fn init_wide_angle_proj_map(camera_matrix: &(impl core::ToInputArray + ?Sized), dist_coeffs: &(impl core::ToInputArray + ?Sized), image_size: core::Size, dest_image_width: i32, m1type: i32, map1: &mut (impl core::ToOutputArray + ?Sized), map2: &mut (impl core::ToOutputArray + ?Sized), proj_type: crate::calib3d::UndistortTypes, alpha: f64) -> Result<f32> {
 	input_array_arg!(camera_matrix);
 	input_array_arg!(dist_coeffs);
 	output_array_arg!(map1);
@@ -3687,7 +3687,7 @@ pub fn init_wide_angle_proj_map(camera_matrix: &dyn core::ToInputArray, dist_coe
 /// the elements of each of the two input matrices. The function is used to compute the Jacobian
 /// matrices in #stereoCalibrate but can also be used in any other similar optimization function.
 #[inline]
-pub fn mat_mul_deriv(a: &dyn core::ToInputArray, b: &dyn core::ToInputArray, d_a_bd_a: &mut dyn core::ToOutputArray, d_a_bd_b: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn mat_mul_deriv(a: &(impl core::ToInputArray + ?Sized), b: &(impl core::ToInputArray + ?Sized), d_a_bd_a: &mut (impl core::ToOutputArray + ?Sized), d_a_bd_b: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(a);
 	input_array_arg!(b);
 	output_array_arg!(d_a_bd_a);
@@ -3738,14 +3738,14 @@ pub fn mat_mul_deriv(a: &dyn core::ToInputArray, b: &dyn core::ToInputArray, d_a
 /// * jacobian: noArray()
 /// * aspect_ratio: 0
 #[inline]
-pub fn project_points(object_points: &dyn core::ToInputArray, rvec: &dyn core::ToInputArray, tvec: &dyn core::ToInputArray, camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, image_points: &mut dyn core::ToOutputArray, jacobian: &mut dyn core::ToOutputArray, aspect_ratio: f64) -> Result<()> {
+pub fn project_points(object_points: &(impl core::ToInputArray + ?Sized), rvec: &(impl core::ToInputArray + ?Sized), tvec: &(impl core::ToInputArray + ?Sized), camera_matrix: &(impl core::ToInputArray + ?Sized), dist_coeffs: &(impl core::ToInputArray + ?Sized), image_points: &mut (impl core::ToOutputArray + ?Sized), jacobian: Option<&mut (impl core::ToOutputArray + ?Sized)>, aspect_ratio: f64) -> Result<()> {
 	input_array_arg!(object_points);
 	input_array_arg!(rvec);
 	input_array_arg!(tvec);
 	input_array_arg!(camera_matrix);
 	input_array_arg!(dist_coeffs);
 	output_array_arg!(image_points);
-	output_array_arg!(jacobian);
+	output_array_arg!(nullable jacobian);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_projectPoints_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_double(object_points.as_raw__InputArray(), rvec.as_raw__InputArray(), tvec.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), image_points.as_raw__OutputArray(), jacobian.as_raw__OutputArray(), aspect_ratio, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -3824,7 +3824,7 @@ pub fn project_points(object_points: &dyn core::ToInputArray, rvec: &dyn core::T
 /// * threshold: 1.0
 /// * mask: noArray()
 #[inline]
-pub fn recover_pose_2_cameras(points1: &dyn core::ToInputArray, points2: &dyn core::ToInputArray, camera_matrix1: &dyn core::ToInputArray, dist_coeffs1: &dyn core::ToInputArray, camera_matrix2: &dyn core::ToInputArray, dist_coeffs2: &dyn core::ToInputArray, e: &mut dyn core::ToOutputArray, r: &mut dyn core::ToOutputArray, t: &mut dyn core::ToOutputArray, method: i32, prob: f64, threshold: f64, mask: &mut dyn core::ToInputOutputArray) -> Result<i32> {
+pub fn recover_pose_2_cameras(points1: &(impl core::ToInputArray + ?Sized), points2: &(impl core::ToInputArray + ?Sized), camera_matrix1: &(impl core::ToInputArray + ?Sized), dist_coeffs1: &(impl core::ToInputArray + ?Sized), camera_matrix2: &(impl core::ToInputArray + ?Sized), dist_coeffs2: &(impl core::ToInputArray + ?Sized), e: &mut (impl core::ToOutputArray + ?Sized), r: &mut (impl core::ToOutputArray + ?Sized), t: &mut (impl core::ToOutputArray + ?Sized), method: i32, prob: f64, threshold: f64, mask: Option<&mut (impl core::ToInputOutputArray + ?Sized)>) -> Result<i32> {
 	input_array_arg!(points1);
 	input_array_arg!(points2);
 	input_array_arg!(camera_matrix1);
@@ -3834,7 +3834,7 @@ pub fn recover_pose_2_cameras(points1: &dyn core::ToInputArray, points2: &dyn co
 	output_array_arg!(e);
 	output_array_arg!(r);
 	output_array_arg!(t);
-	input_output_array_arg!(mask);
+	input_output_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_recoverPose_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int_double_double_const__InputOutputArrayR(points1.as_raw__InputArray(), points2.as_raw__InputArray(), camera_matrix1.as_raw__InputArray(), dist_coeffs1.as_raw__InputArray(), camera_matrix2.as_raw__InputArray(), dist_coeffs2.as_raw__InputArray(), e.as_raw__OutputArray(), r.as_raw__OutputArray(), t.as_raw__OutputArray(), method, prob, threshold, mask.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -3897,14 +3897,14 @@ pub fn recover_pose_2_cameras(points1: &dyn core::ToInputArray, points2: &dyn co
 /// ## C++ default parameters
 /// * mask: noArray()
 #[inline]
-pub fn recover_pose_estimated(e: &dyn core::ToInputArray, points1: &dyn core::ToInputArray, points2: &dyn core::ToInputArray, camera_matrix: &dyn core::ToInputArray, r: &mut dyn core::ToOutputArray, t: &mut dyn core::ToOutputArray, mask: &mut dyn core::ToInputOutputArray) -> Result<i32> {
+pub fn recover_pose_estimated(e: &(impl core::ToInputArray + ?Sized), points1: &(impl core::ToInputArray + ?Sized), points2: &(impl core::ToInputArray + ?Sized), camera_matrix: &(impl core::ToInputArray + ?Sized), r: &mut (impl core::ToOutputArray + ?Sized), t: &mut (impl core::ToOutputArray + ?Sized), mask: Option<&mut (impl core::ToInputOutputArray + ?Sized)>) -> Result<i32> {
 	input_array_arg!(e);
 	input_array_arg!(points1);
 	input_array_arg!(points2);
 	input_array_arg!(camera_matrix);
 	output_array_arg!(r);
 	output_array_arg!(t);
-	input_output_array_arg!(mask);
+	input_output_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_recoverPose_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__InputOutputArrayR(e.as_raw__InputArray(), points1.as_raw__InputArray(), points2.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), r.as_raw__OutputArray(), t.as_raw__OutputArray(), mask.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -3994,15 +3994,15 @@ pub fn recover_pose_estimated(e: &dyn core::ToInputArray, points1: &dyn core::To
 /// * mask: noArray()
 /// * triangulated_points: noArray()
 #[inline]
-pub fn recover_pose_triangulated(e: &dyn core::ToInputArray, points1: &dyn core::ToInputArray, points2: &dyn core::ToInputArray, camera_matrix: &dyn core::ToInputArray, r: &mut dyn core::ToOutputArray, t: &mut dyn core::ToOutputArray, distance_thresh: f64, mask: &mut dyn core::ToInputOutputArray, triangulated_points: &mut dyn core::ToOutputArray) -> Result<i32> {
+pub fn recover_pose_triangulated(e: &(impl core::ToInputArray + ?Sized), points1: &(impl core::ToInputArray + ?Sized), points2: &(impl core::ToInputArray + ?Sized), camera_matrix: &(impl core::ToInputArray + ?Sized), r: &mut (impl core::ToOutputArray + ?Sized), t: &mut (impl core::ToOutputArray + ?Sized), distance_thresh: f64, mask: Option<&mut (impl core::ToInputOutputArray + ?Sized)>, triangulated_points: Option<&mut (impl core::ToOutputArray + ?Sized)>) -> Result<i32> {
 	input_array_arg!(e);
 	input_array_arg!(points1);
 	input_array_arg!(points2);
 	input_array_arg!(camera_matrix);
 	output_array_arg!(r);
 	output_array_arg!(t);
-	input_output_array_arg!(mask);
-	output_array_arg!(triangulated_points);
+	input_output_array_arg!(nullable mask);
+	output_array_arg!(nullable triangulated_points);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_recoverPose_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_double_const__InputOutputArrayR_const__OutputArrayR(e.as_raw__InputArray(), points1.as_raw__InputArray(), points2.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), r.as_raw__OutputArray(), t.as_raw__OutputArray(), distance_thresh, mask.as_raw__InputOutputArray(), triangulated_points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -4092,13 +4092,13 @@ pub fn recover_pose_triangulated(e: &dyn core::ToInputArray, points1: &dyn core:
 /// * pp: Point2d(0,0)
 /// * mask: noArray()
 #[inline]
-pub fn recover_pose(e: &dyn core::ToInputArray, points1: &dyn core::ToInputArray, points2: &dyn core::ToInputArray, r: &mut dyn core::ToOutputArray, t: &mut dyn core::ToOutputArray, focal: f64, pp: core::Point2d, mask: &mut dyn core::ToInputOutputArray) -> Result<i32> {
+pub fn recover_pose(e: &(impl core::ToInputArray + ?Sized), points1: &(impl core::ToInputArray + ?Sized), points2: &(impl core::ToInputArray + ?Sized), r: &mut (impl core::ToOutputArray + ?Sized), t: &mut (impl core::ToOutputArray + ?Sized), focal: f64, pp: core::Point2d, mask: Option<&mut (impl core::ToInputOutputArray + ?Sized)>) -> Result<i32> {
 	input_array_arg!(e);
 	input_array_arg!(points1);
 	input_array_arg!(points2);
 	output_array_arg!(r);
 	output_array_arg!(t);
-	input_output_array_arg!(mask);
+	input_output_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_recoverPose_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_double_Point2d_const__InputOutputArrayR(e.as_raw__InputArray(), points1.as_raw__InputArray(), points2.as_raw__InputArray(), r.as_raw__OutputArray(), t.as_raw__OutputArray(), focal, pp.opencv_as_extern(), mask.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -4108,7 +4108,7 @@ pub fn recover_pose(e: &dyn core::ToInputArray, points1: &dyn core::ToInputArray
 
 /// computes the rectification transformations for 3-head camera, where all the heads are on the same line.
 #[inline]
-pub fn rectify3_collinear(camera_matrix1: &dyn core::ToInputArray, dist_coeffs1: &dyn core::ToInputArray, camera_matrix2: &dyn core::ToInputArray, dist_coeffs2: &dyn core::ToInputArray, camera_matrix3: &dyn core::ToInputArray, dist_coeffs3: &dyn core::ToInputArray, imgpt1: &dyn core::ToInputArray, imgpt3: &dyn core::ToInputArray, image_size: core::Size, r12: &dyn core::ToInputArray, t12: &dyn core::ToInputArray, r13: &dyn core::ToInputArray, t13: &dyn core::ToInputArray, r1: &mut dyn core::ToOutputArray, r2: &mut dyn core::ToOutputArray, r3: &mut dyn core::ToOutputArray, p1: &mut dyn core::ToOutputArray, p2: &mut dyn core::ToOutputArray, p3: &mut dyn core::ToOutputArray, q: &mut dyn core::ToOutputArray, alpha: f64, new_img_size: core::Size, roi1: &mut core::Rect, roi2: &mut core::Rect, flags: i32) -> Result<f32> {
+pub fn rectify3_collinear(camera_matrix1: &(impl core::ToInputArray + ?Sized), dist_coeffs1: &(impl core::ToInputArray + ?Sized), camera_matrix2: &(impl core::ToInputArray + ?Sized), dist_coeffs2: &(impl core::ToInputArray + ?Sized), camera_matrix3: &(impl core::ToInputArray + ?Sized), dist_coeffs3: &(impl core::ToInputArray + ?Sized), imgpt1: &(impl core::ToInputArray + ?Sized), imgpt3: &(impl core::ToInputArray + ?Sized), image_size: core::Size, r12: &(impl core::ToInputArray + ?Sized), t12: &(impl core::ToInputArray + ?Sized), r13: &(impl core::ToInputArray + ?Sized), t13: &(impl core::ToInputArray + ?Sized), r1: &mut (impl core::ToOutputArray + ?Sized), r2: &mut (impl core::ToOutputArray + ?Sized), r3: &mut (impl core::ToOutputArray + ?Sized), p1: &mut (impl core::ToOutputArray + ?Sized), p2: &mut (impl core::ToOutputArray + ?Sized), p3: &mut (impl core::ToOutputArray + ?Sized), q: &mut (impl core::ToOutputArray + ?Sized), alpha: f64, new_img_size: core::Size, roi1: &mut core::Rect, roi2: &mut core::Rect, flags: i32) -> Result<f32> {
 	input_array_arg!(camera_matrix1);
 	input_array_arg!(dist_coeffs1);
 	input_array_arg!(camera_matrix2);
@@ -4168,7 +4168,7 @@ pub fn rectify3_collinear(camera_matrix1: &dyn core::ToInputArray, dist_coeffs1:
 /// * handle_missing_values: false
 /// * ddepth: -1
 #[inline]
-pub fn reproject_image_to_3d(disparity: &dyn core::ToInputArray, _3d_image: &mut dyn core::ToOutputArray, q: &dyn core::ToInputArray, handle_missing_values: bool, ddepth: i32) -> Result<()> {
+pub fn reproject_image_to_3d(disparity: &(impl core::ToInputArray + ?Sized), _3d_image: &mut (impl core::ToOutputArray + ?Sized), q: &(impl core::ToInputArray + ?Sized), handle_missing_values: bool, ddepth: i32) -> Result<()> {
 	input_array_arg!(disparity);
 	output_array_arg!(_3d_image);
 	input_array_arg!(q);
@@ -4191,7 +4191,7 @@ pub fn reproject_image_to_3d(disparity: &dyn core::ToInputArray, _3d_image: &mut
 /// ## Returns
 /// The computed Sampson distance.
 #[inline]
-pub fn sampson_distance(pt1: &dyn core::ToInputArray, pt2: &dyn core::ToInputArray, f: &dyn core::ToInputArray) -> Result<f64> {
+pub fn sampson_distance(pt1: &(impl core::ToInputArray + ?Sized), pt2: &(impl core::ToInputArray + ?Sized), f: &(impl core::ToInputArray + ?Sized)) -> Result<f64> {
 	input_array_arg!(pt1);
 	input_array_arg!(pt2);
 	input_array_arg!(f);
@@ -4231,7 +4231,7 @@ pub fn sampson_distance(pt1: &dyn core::ToInputArray, pt2: &dyn core::ToInputArr
 /// Note:
 /// The solutions are sorted by reprojection errors (lowest to highest).
 #[inline]
-pub fn solve_p3p(object_points: &dyn core::ToInputArray, image_points: &dyn core::ToInputArray, camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, rvecs: &mut dyn core::ToOutputArray, tvecs: &mut dyn core::ToOutputArray, flags: i32) -> Result<i32> {
+pub fn solve_p3p(object_points: &(impl core::ToInputArray + ?Sized), image_points: &(impl core::ToInputArray + ?Sized), camera_matrix: &(impl core::ToInputArray + ?Sized), dist_coeffs: &(impl core::ToInputArray + ?Sized), rvecs: &mut (impl core::ToOutputArray + ?Sized), tvecs: &mut (impl core::ToOutputArray + ?Sized), flags: i32) -> Result<i32> {
 	input_array_arg!(object_points);
 	input_array_arg!(image_points);
 	input_array_arg!(camera_matrix);
@@ -4326,16 +4326,16 @@ pub fn solve_p3p(object_points: &dyn core::ToInputArray, image_points: &dyn core
 /// * tvec: noArray()
 /// * reprojection_error: noArray()
 #[inline]
-pub fn solve_pnp_generic(object_points: &dyn core::ToInputArray, image_points: &dyn core::ToInputArray, camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, rvecs: &mut dyn core::ToOutputArray, tvecs: &mut dyn core::ToOutputArray, use_extrinsic_guess: bool, flags: crate::calib3d::SolvePnPMethod, rvec: &dyn core::ToInputArray, tvec: &dyn core::ToInputArray, reprojection_error: &mut dyn core::ToOutputArray) -> Result<i32> {
+pub fn solve_pnp_generic(object_points: &(impl core::ToInputArray + ?Sized), image_points: &(impl core::ToInputArray + ?Sized), camera_matrix: &(impl core::ToInputArray + ?Sized), dist_coeffs: &(impl core::ToInputArray + ?Sized), rvecs: &mut (impl core::ToOutputArray + ?Sized), tvecs: &mut (impl core::ToOutputArray + ?Sized), use_extrinsic_guess: bool, flags: crate::calib3d::SolvePnPMethod, rvec: Option<&(impl core::ToInputArray + ?Sized)>, tvec: Option<&(impl core::ToInputArray + ?Sized)>, reprojection_error: Option<&mut (impl core::ToOutputArray + ?Sized)>) -> Result<i32> {
 	input_array_arg!(object_points);
 	input_array_arg!(image_points);
 	input_array_arg!(camera_matrix);
 	input_array_arg!(dist_coeffs);
 	output_array_arg!(rvecs);
 	output_array_arg!(tvecs);
-	input_array_arg!(rvec);
-	input_array_arg!(tvec);
-	output_array_arg!(reprojection_error);
+	input_array_arg!(nullable rvec);
+	input_array_arg!(nullable tvec);
+	output_array_arg!(nullable reprojection_error);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_solvePnPGeneric_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_bool_SolvePnPMethod_const__InputArrayR_const__InputArrayR_const__OutputArrayR(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), use_extrinsic_guess, flags, rvec.as_raw__InputArray(), tvec.as_raw__InputArray(), reprojection_error.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -4396,14 +4396,14 @@ pub fn solve_pnp_generic(object_points: &dyn core::ToInputArray, image_points: &
 /// * inliers: noArray()
 /// * flags: SOLVEPNP_ITERATIVE
 #[inline]
-pub fn solve_pnp_ransac(object_points: &dyn core::ToInputArray, image_points: &dyn core::ToInputArray, camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, rvec: &mut dyn core::ToOutputArray, tvec: &mut dyn core::ToOutputArray, use_extrinsic_guess: bool, iterations_count: i32, reprojection_error: f32, confidence: f64, inliers: &mut dyn core::ToOutputArray, flags: i32) -> Result<bool> {
+pub fn solve_pnp_ransac(object_points: &(impl core::ToInputArray + ?Sized), image_points: &(impl core::ToInputArray + ?Sized), camera_matrix: &(impl core::ToInputArray + ?Sized), dist_coeffs: &(impl core::ToInputArray + ?Sized), rvec: &mut (impl core::ToOutputArray + ?Sized), tvec: &mut (impl core::ToOutputArray + ?Sized), use_extrinsic_guess: bool, iterations_count: i32, reprojection_error: f32, confidence: f64, inliers: Option<&mut (impl core::ToOutputArray + ?Sized)>, flags: i32) -> Result<bool> {
 	input_array_arg!(object_points);
 	input_array_arg!(image_points);
 	input_array_arg!(camera_matrix);
 	input_array_arg!(dist_coeffs);
 	output_array_arg!(rvec);
 	output_array_arg!(tvec);
-	output_array_arg!(inliers);
+	output_array_arg!(nullable inliers);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_solvePnPRansac_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_bool_int_float_double_const__OutputArrayR_int(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvec.as_raw__OutputArray(), tvec.as_raw__OutputArray(), use_extrinsic_guess, iterations_count, reprojection_error, confidence, inliers.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -4414,7 +4414,7 @@ pub fn solve_pnp_ransac(object_points: &dyn core::ToInputArray, image_points: &d
 /// ## C++ default parameters
 /// * params: UsacParams()
 #[inline]
-pub fn solve_pnp_ransac_1(object_points: &dyn core::ToInputArray, image_points: &dyn core::ToInputArray, camera_matrix: &mut dyn core::ToInputOutputArray, dist_coeffs: &dyn core::ToInputArray, rvec: &mut dyn core::ToOutputArray, tvec: &mut dyn core::ToOutputArray, inliers: &mut dyn core::ToOutputArray, params: crate::calib3d::UsacParams) -> Result<bool> {
+pub fn solve_pnp_ransac_1(object_points: &(impl core::ToInputArray + ?Sized), image_points: &(impl core::ToInputArray + ?Sized), camera_matrix: &mut (impl core::ToInputOutputArray + ?Sized), dist_coeffs: &(impl core::ToInputArray + ?Sized), rvec: &mut (impl core::ToOutputArray + ?Sized), tvec: &mut (impl core::ToOutputArray + ?Sized), inliers: &mut (impl core::ToOutputArray + ?Sized), params: crate::calib3d::UsacParams) -> Result<bool> {
 	input_array_arg!(object_points);
 	input_array_arg!(image_points);
 	input_output_array_arg!(camera_matrix);
@@ -4457,7 +4457,7 @@ pub fn solve_pnp_ransac_1(object_points: &dyn core::ToInputArray, image_points:
 /// ## C++ default parameters
 /// * criteria: TermCriteria(TermCriteria::EPS+TermCriteria::COUNT,20,FLT_EPSILON)
 #[inline]
-pub fn solve_pnp_refine_lm(object_points: &dyn core::ToInputArray, image_points: &dyn core::ToInputArray, camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, rvec: &mut dyn core::ToInputOutputArray, tvec: &mut dyn core::ToInputOutputArray, criteria: core::TermCriteria) -> Result<()> {
+pub fn solve_pnp_refine_lm(object_points: &(impl core::ToInputArray + ?Sized), image_points: &(impl core::ToInputArray + ?Sized), camera_matrix: &(impl core::ToInputArray + ?Sized), dist_coeffs: &(impl core::ToInputArray + ?Sized), rvec: &mut (impl core::ToInputOutputArray + ?Sized), tvec: &mut (impl core::ToInputOutputArray + ?Sized), criteria: core::TermCriteria) -> Result<()> {
 	input_array_arg!(object_points);
 	input_array_arg!(image_points);
 	input_array_arg!(camera_matrix);
@@ -4502,7 +4502,7 @@ pub fn solve_pnp_refine_lm(object_points: &dyn core::ToInputArray, image_points:
 /// * criteria: TermCriteria(TermCriteria::EPS+TermCriteria::COUNT,20,FLT_EPSILON)
 /// * vv_slambda: 1
 #[inline]
-pub fn solve_pnp_refine_vvs(object_points: &dyn core::ToInputArray, image_points: &dyn core::ToInputArray, camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, rvec: &mut dyn core::ToInputOutputArray, tvec: &mut dyn core::ToInputOutputArray, criteria: core::TermCriteria, vv_slambda: f64) -> Result<()> {
+pub fn solve_pnp_refine_vvs(object_points: &(impl core::ToInputArray + ?Sized), image_points: &(impl core::ToInputArray + ?Sized), camera_matrix: &(impl core::ToInputArray + ?Sized), dist_coeffs: &(impl core::ToInputArray + ?Sized), rvec: &mut (impl core::ToInputOutputArray + ?Sized), tvec: &mut (impl core::ToInputOutputArray + ?Sized), criteria: core::TermCriteria, vv_slambda: f64) -> Result<()> {
 	input_array_arg!(object_points);
 	input_array_arg!(image_points);
 	input_array_arg!(camera_matrix);
@@ -4587,7 +4587,7 @@ pub fn solve_pnp_refine_vvs(object_points: &dyn core::ToInputArray, image_points
 /// * use_extrinsic_guess: false
 /// * flags: SOLVEPNP_ITERATIVE
 #[inline]
-pub fn solve_pnp(object_points: &dyn core::ToInputArray, image_points: &dyn core::ToInputArray, camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, rvec: &mut dyn core::ToOutputArray, tvec: &mut dyn core::ToOutputArray, use_extrinsic_guess: bool, flags: i32) -> Result<bool> {
+pub fn solve_pnp(object_points: &(impl core::ToInputArray + ?Sized), image_points: &(impl core::ToInputArray + ?Sized), camera_matrix: &(impl core::ToInputArray + ?Sized), dist_coeffs: &(impl core::ToInputArray + ?Sized), rvec: &mut (impl core::ToOutputArray + ?Sized), tvec: &mut (impl core::ToOutputArray + ?Sized), use_extrinsic_guess: bool, flags: i32) -> Result<bool> {
 	input_array_arg!(object_points);
 	input_array_arg!(image_points);
 	input_array_arg!(camera_matrix);
@@ -4715,7 +4715,7 @@ pub fn solve_pnp(object_points: &dyn core::ToInputArray, image_points: &dyn core
 /// * flags: CALIB_FIX_INTRINSIC
 /// * criteria: TermCriteria(TermCriteria::COUNT+TermCriteria::EPS,30,1e-6)
 #[inline]
-pub fn stereo_calibrate_extended(object_points: &dyn core::ToInputArray, image_points1: &dyn core::ToInputArray, image_points2: &dyn core::ToInputArray, camera_matrix1: &mut dyn core::ToInputOutputArray, dist_coeffs1: &mut dyn core::ToInputOutputArray, camera_matrix2: &mut dyn core::ToInputOutputArray, dist_coeffs2: &mut dyn core::ToInputOutputArray, image_size: core::Size, r: &mut dyn core::ToInputOutputArray, t: &mut dyn core::ToInputOutputArray, e: &mut dyn core::ToOutputArray, f: &mut dyn core::ToOutputArray, per_view_errors: &mut dyn core::ToOutputArray, flags: i32, criteria: core::TermCriteria) -> Result<f64> {
+pub fn stereo_calibrate_extended(object_points: &(impl core::ToInputArray + ?Sized), image_points1: &(impl core::ToInputArray + ?Sized), image_points2: &(impl core::ToInputArray + ?Sized), camera_matrix1: &mut (impl core::ToInputOutputArray + ?Sized), dist_coeffs1: &mut (impl core::ToInputOutputArray + ?Sized), camera_matrix2: &mut (impl core::ToInputOutputArray + ?Sized), dist_coeffs2: &mut (impl core::ToInputOutputArray + ?Sized), image_size: core::Size, r: &mut (impl core::ToInputOutputArray + ?Sized), t: &mut (impl core::ToInputOutputArray + ?Sized), e: &mut (impl core::ToOutputArray + ?Sized), f: &mut (impl core::ToOutputArray + ?Sized), per_view_errors: &mut (impl core::ToOutputArray + ?Sized), flags: i32, criteria: core::TermCriteria) -> Result<f64> {
 	input_array_arg!(object_points);
 	input_array_arg!(image_points1);
 	input_array_arg!(image_points2);
@@ -4851,7 +4851,7 @@ pub fn stereo_calibrate_extended(object_points: &dyn core::ToInputArray, image_p
 /// * flags: CALIB_FIX_INTRINSIC
 /// * criteria: TermCriteria(TermCriteria::COUNT+TermCriteria::EPS,30,1e-6)
 #[inline]
-pub fn stereo_calibrate(object_points: &dyn core::ToInputArray, image_points1: &dyn core::ToInputArray, image_points2: &dyn core::ToInputArray, camera_matrix1: &mut dyn core::ToInputOutputArray, dist_coeffs1: &mut dyn core::ToInputOutputArray, camera_matrix2: &mut dyn core::ToInputOutputArray, dist_coeffs2: &mut dyn core::ToInputOutputArray, image_size: core::Size, r: &mut dyn core::ToOutputArray, t: &mut dyn core::ToOutputArray, e: &mut dyn core::ToOutputArray, f: &mut dyn core::ToOutputArray, flags: i32, criteria: core::TermCriteria) -> Result<f64> {
+pub fn stereo_calibrate(object_points: &(impl core::ToInputArray + ?Sized), image_points1: &(impl core::ToInputArray + ?Sized), image_points2: &(impl core::ToInputArray + ?Sized), camera_matrix1: &mut (impl core::ToInputOutputArray + ?Sized), dist_coeffs1: &mut (impl core::ToInputOutputArray + ?Sized), camera_matrix2: &mut (impl core::ToInputOutputArray + ?Sized), dist_coeffs2: &mut (impl core::ToInputOutputArray + ?Sized), image_size: core::Size, r: &mut (impl core::ToOutputArray + ?Sized), t: &mut (impl core::ToOutputArray + ?Sized), e: &mut (impl core::ToOutputArray + ?Sized), f: &mut (impl core::ToOutputArray + ?Sized), flags: i32, criteria: core::TermCriteria) -> Result<f64> {
 	input_array_arg!(object_points);
 	input_array_arg!(image_points1);
 	input_array_arg!(image_points2);
@@ -4904,7 +4904,7 @@ pub fn stereo_calibrate(object_points: &dyn core::ToInputArray, image_points1: &
 /// ## C++ default parameters
 /// * threshold: 5
 #[inline]
-pub fn stereo_rectify_uncalibrated(points1: &dyn core::ToInputArray, points2: &dyn core::ToInputArray, f: &dyn core::ToInputArray, img_size: core::Size, h1: &mut dyn core::ToOutputArray, h2: &mut dyn core::ToOutputArray, threshold: f64) -> Result<bool> {
+pub fn stereo_rectify_uncalibrated(points1: &(impl core::ToInputArray + ?Sized), points2: &(impl core::ToInputArray + ?Sized), f: &(impl core::ToInputArray + ?Sized), img_size: core::Size, h1: &mut (impl core::ToOutputArray + ?Sized), h2: &mut (impl core::ToOutputArray + ?Sized), threshold: f64) -> Result<bool> {
 	input_array_arg!(points1);
 	input_array_arg!(points2);
 	input_array_arg!(f);
@@ -5014,7 +5014,7 @@ pub fn stereo_rectify_uncalibrated(points1: &dyn core::ToInputArray, points2: &d
 /// * valid_pix_roi1: 0
 /// * valid_pix_roi2: 0
 #[inline]
-pub fn stereo_rectify(camera_matrix1: &dyn core::ToInputArray, dist_coeffs1: &dyn core::ToInputArray, camera_matrix2: &dyn core::ToInputArray, dist_coeffs2: &dyn core::ToInputArray, image_size: core::Size, r: &dyn core::ToInputArray, t: &dyn core::ToInputArray, r1: &mut dyn core::ToOutputArray, r2: &mut dyn core::ToOutputArray, p1: &mut dyn core::ToOutputArray, p2: &mut dyn core::ToOutputArray, q: &mut dyn core::ToOutputArray, flags: i32, alpha: f64, new_image_size: core::Size, valid_pix_roi1: &mut core::Rect, valid_pix_roi2: &mut core::Rect) -> Result<()> {
+pub fn stereo_rectify(camera_matrix1: &(impl core::ToInputArray + ?Sized), dist_coeffs1: &(impl core::ToInputArray + ?Sized), camera_matrix2: &(impl core::ToInputArray + ?Sized), dist_coeffs2: &(impl core::ToInputArray + ?Sized), image_size: core::Size, r: &(impl core::ToInputArray + ?Sized), t: &(impl core::ToInputArray + ?Sized), r1: &mut (impl core::ToOutputArray + ?Sized), r2: &mut (impl core::ToOutputArray + ?Sized), p1: &mut (impl core::ToOutputArray + ?Sized), p2: &mut (impl core::ToOutputArray + ?Sized), q: &mut (impl core::ToOutputArray + ?Sized), flags: i32, alpha: f64, new_image_size: core::Size, valid_pix_roi1: &mut core::Rect, valid_pix_roi2: &mut core::Rect) -> Result<()> {
 	input_array_arg!(camera_matrix1);
 	input_array_arg!(dist_coeffs1);
 	input_array_arg!(camera_matrix2);
@@ -5059,7 +5059,7 @@ pub fn stereo_rectify(camera_matrix1: &dyn core::ToInputArray, dist_coeffs1: &dy
 /// ## See also
 /// reprojectImageTo3D
 #[inline]
-pub fn triangulate_points(proj_matr1: &dyn core::ToInputArray, proj_matr2: &dyn core::ToInputArray, proj_points1: &dyn core::ToInputArray, proj_points2: &dyn core::ToInputArray, points4_d: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn triangulate_points(proj_matr1: &(impl core::ToInputArray + ?Sized), proj_matr2: &(impl core::ToInputArray + ?Sized), proj_points1: &(impl core::ToInputArray + ?Sized), proj_points2: &(impl core::ToInputArray + ?Sized), points4_d: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(proj_matr1);
 	input_array_arg!(proj_matr2);
 	input_array_arg!(proj_points1);
@@ -5084,7 +5084,7 @@ pub fn triangulate_points(proj_matr1: &dyn core::ToInputArray, proj_matr2: &dyn
 /// ## C++ default parameters
 /// * unnamed: TermCriteria(TermCriteria::MAX_ITER+TermCriteria::EPS,5,0.01)
 #[inline]
-pub fn undistort_image_points(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, unnamed: core::TermCriteria) -> Result<()> {
+pub fn undistort_image_points(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), camera_matrix: &(impl core::ToInputArray + ?Sized), dist_coeffs: &(impl core::ToInputArray + ?Sized), unnamed: core::TermCriteria) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	input_array_arg!(camera_matrix);
@@ -5129,13 +5129,13 @@ pub fn undistort_image_points(src: &dyn core::ToInputArray, dst: &mut dyn core::
 /// * r: noArray()
 /// * p: noArray()
 #[inline]
-pub fn undistort_points(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, r: &dyn core::ToInputArray, p: &dyn core::ToInputArray) -> Result<()> {
+pub fn undistort_points(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), camera_matrix: &(impl core::ToInputArray + ?Sized), dist_coeffs: &(impl core::ToInputArray + ?Sized), r: Option<&(impl core::ToInputArray + ?Sized)>, p: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	input_array_arg!(camera_matrix);
 	input_array_arg!(dist_coeffs);
-	input_array_arg!(r);
-	input_array_arg!(p);
+	input_array_arg!(nullable r);
+	input_array_arg!(nullable p);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_undistortPoints_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), r.as_raw__InputArray(), p.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -5177,7 +5177,7 @@ pub fn undistort_points(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutp
 ///     
 /// Note: Default version of #undistortPoints does 5 iterations to compute undistorted points.
 #[inline]
-pub fn undistort_points_iter(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, r: &dyn core::ToInputArray, p: &dyn core::ToInputArray, criteria: core::TermCriteria) -> Result<()> {
+pub fn undistort_points_iter(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), camera_matrix: &(impl core::ToInputArray + ?Sized), dist_coeffs: &(impl core::ToInputArray + ?Sized), r: &(impl core::ToInputArray + ?Sized), p: &(impl core::ToInputArray + ?Sized), criteria: core::TermCriteria) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	input_array_arg!(camera_matrix);
@@ -5223,12 +5223,12 @@ pub fn undistort_points_iter(src: &dyn core::ToInputArray, dst: &mut dyn core::T
 /// ## C++ default parameters
 /// * new_camera_matrix: noArray()
 #[inline]
-pub fn undistort(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, new_camera_matrix: &dyn core::ToInputArray) -> Result<()> {
+pub fn undistort(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), camera_matrix: &(impl core::ToInputArray + ?Sized), dist_coeffs: &(impl core::ToInputArray + ?Sized), new_camera_matrix: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	input_array_arg!(camera_matrix);
 	input_array_arg!(dist_coeffs);
-	input_array_arg!(new_camera_matrix);
+	input_array_arg!(nullable new_camera_matrix);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_undistort_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), new_camera_matrix.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -5241,7 +5241,7 @@ pub fn undistort(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 /// ## C++ default parameters
 /// * disp12_max_disp: 1
 #[inline]
-pub fn validate_disparity(disparity: &mut dyn core::ToInputOutputArray, cost: &dyn core::ToInputArray, min_disparity: i32, number_of_disparities: i32, disp12_max_disp: i32) -> Result<()> {
+pub fn validate_disparity(disparity: &mut (impl core::ToInputOutputArray + ?Sized), cost: &(impl core::ToInputArray + ?Sized), min_disparity: i32, number_of_disparities: i32, disp12_max_disp: i32) -> Result<()> {
 	input_output_array_arg!(disparity);
 	input_array_arg!(cost);
 	return_send!(via ocvrs_return);
@@ -54,7 +54,7 @@ pub const XYZRGB: i32 = 1;
 /// ## C++ default parameters
 /// * idx: noArray()
 #[inline]
-pub fn calibrate(object_points: &dyn core::ToInputArray, image_points: &dyn core::ToInputArray, size: core::Size, k: &mut dyn core::ToInputOutputArray, xi: &mut dyn core::ToInputOutputArray, d: &mut dyn core::ToInputOutputArray, rvecs: &mut dyn core::ToOutputArray, tvecs: &mut dyn core::ToOutputArray, flags: i32, criteria: core::TermCriteria, idx: &mut dyn core::ToOutputArray) -> Result<f64> {
+pub fn calibrate(object_points: &(impl core::ToInputArray + ?Sized), image_points: &(impl core::ToInputArray + ?Sized), size: core::Size, k: &mut (impl core::ToInputOutputArray + ?Sized), xi: &mut (impl core::ToInputOutputArray + ?Sized), d: &mut (impl core::ToInputOutputArray + ?Sized), rvecs: &mut (impl core::ToOutputArray + ?Sized), tvecs: &mut (impl core::ToOutputArray + ?Sized), flags: i32, criteria: core::TermCriteria, idx: Option<&mut (impl core::ToOutputArray + ?Sized)>) -> Result<f64> {
 	input_array_arg!(object_points);
 	input_array_arg!(image_points);
 	input_output_array_arg!(k);
@@ -62,7 +62,7 @@ pub fn calibrate(object_points: &dyn core::ToInputArray, image_points: &dyn core
 	input_output_array_arg!(d);
 	output_array_arg!(rvecs);
 	output_array_arg!(tvecs);
-	output_array_arg!(idx);
+	output_array_arg!(nullable idx);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_omnidir_calibrate_const__InputArrayR_const__InputArrayR_Size_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria_const__OutputArrayR(object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), size.opencv_as_extern(), k.as_raw__InputOutputArray(), xi.as_raw__InputOutputArray(), d.as_raw__InputOutputArray(), rvecs.as_raw__OutputArray(), tvecs.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), idx.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -88,7 +88,7 @@ pub fn calibrate(object_points: &dyn core::ToInputArray, image_points: &dyn core
 /// * flags: Flags indicates the rectification type,  RECTIFY_PERSPECTIVE, RECTIFY_CYLINDRICAL, RECTIFY_LONGLATI and RECTIFY_STEREOGRAPHIC
 /// are supported.
 #[inline]
-pub fn init_undistort_rectify_map(k: &dyn core::ToInputArray, d: &dyn core::ToInputArray, xi: &dyn core::ToInputArray, r: &dyn core::ToInputArray, p: &dyn core::ToInputArray, size: core::Size, m1type: i32, map1: &mut dyn core::ToOutputArray, map2: &mut dyn core::ToOutputArray, flags: i32) -> Result<()> {
+pub fn init_undistort_rectify_map(k: &(impl core::ToInputArray + ?Sized), d: &(impl core::ToInputArray + ?Sized), xi: &(impl core::ToInputArray + ?Sized), r: &(impl core::ToInputArray + ?Sized), p: &(impl core::ToInputArray + ?Sized), size: core::Size, m1type: i32, map1: &mut (impl core::ToOutputArray + ?Sized), map2: &mut (impl core::ToOutputArray + ?Sized), flags: i32) -> Result<()> {
 	input_array_arg!(k);
 	input_array_arg!(d);
 	input_array_arg!(xi);
@@ -128,12 +128,12 @@ pub fn init_undistort_rectify_map(k: &dyn core::ToInputArray, d: &dyn core::ToIn
 /// ## C++ default parameters
 /// * jacobian: noArray()
 #[inline]
-pub fn project_points_1(object_points: &dyn core::ToInputArray, image_points: &mut dyn core::ToOutputArray, affine: core::Affine3d, k: &dyn core::ToInputArray, xi: f64, d: &dyn core::ToInputArray, jacobian: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn project_points_1(object_points: &(impl core::ToInputArray + ?Sized), image_points: &mut (impl core::ToOutputArray + ?Sized), affine: core::Affine3d, k: &(impl core::ToInputArray + ?Sized), xi: f64, d: &(impl core::ToInputArray + ?Sized), jacobian: Option<&mut (impl core::ToOutputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(object_points);
 	output_array_arg!(image_points);
 	input_array_arg!(k);
 	input_array_arg!(d);
-	output_array_arg!(jacobian);
+	output_array_arg!(nullable jacobian);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_omnidir_projectPoints_const__InputArrayR_const__OutputArrayR_const_Affine3dR_const__InputArrayR_double_const__InputArrayR_const__OutputArrayR(object_points.as_raw__InputArray(), image_points.as_raw__OutputArray(), &affine, k.as_raw__InputArray(), xi, d.as_raw__InputArray(), jacobian.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -164,14 +164,14 @@ pub fn project_points_1(object_points: &dyn core::ToInputArray, image_points: &m
 /// ## C++ default parameters
 /// * jacobian: noArray()
 #[inline]
-pub fn project_points(object_points: &dyn core::ToInputArray, image_points: &mut dyn core::ToOutputArray, rvec: &dyn core::ToInputArray, tvec: &dyn core::ToInputArray, k: &dyn core::ToInputArray, xi: f64, d: &dyn core::ToInputArray, jacobian: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn project_points(object_points: &(impl core::ToInputArray + ?Sized), image_points: &mut (impl core::ToOutputArray + ?Sized), rvec: &(impl core::ToInputArray + ?Sized), tvec: &(impl core::ToInputArray + ?Sized), k: &(impl core::ToInputArray + ?Sized), xi: f64, d: &(impl core::ToInputArray + ?Sized), jacobian: Option<&mut (impl core::ToOutputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(object_points);
 	output_array_arg!(image_points);
 	input_array_arg!(rvec);
 	input_array_arg!(tvec);
 	input_array_arg!(k);
 	input_array_arg!(d);
-	output_array_arg!(jacobian);
+	output_array_arg!(nullable jacobian);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_omnidir_projectPoints_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_double_const__InputArrayR_const__OutputArrayR(object_points.as_raw__InputArray(), image_points.as_raw__OutputArray(), rvec.as_raw__InputArray(), tvec.as_raw__InputArray(), k.as_raw__InputArray(), xi, d.as_raw__InputArray(), jacobian.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -210,7 +210,7 @@ pub fn project_points(object_points: &dyn core::ToInputArray, image_points: &mut
 /// ## C++ default parameters
 /// * idx: noArray()
 #[inline]
-pub fn stereo_calibrate(object_points: &mut dyn core::ToInputOutputArray, image_points1: &mut dyn core::ToInputOutputArray, image_points2: &mut dyn core::ToInputOutputArray, image_size1: core::Size, image_size2: core::Size, k1: &mut dyn core::ToInputOutputArray, xi1: &mut dyn core::ToInputOutputArray, d1: &mut dyn core::ToInputOutputArray, k2: &mut dyn core::ToInputOutputArray, xi2: &mut dyn core::ToInputOutputArray, d2: &mut dyn core::ToInputOutputArray, rvec: &mut dyn core::ToOutputArray, tvec: &mut dyn core::ToOutputArray, rvecs_l: &mut dyn core::ToOutputArray, tvecs_l: &mut dyn core::ToOutputArray, flags: i32, criteria: core::TermCriteria, idx: &mut dyn core::ToOutputArray) -> Result<f64> {
+pub fn stereo_calibrate(object_points: &mut (impl core::ToInputOutputArray + ?Sized), image_points1: &mut (impl core::ToInputOutputArray + ?Sized), image_points2: &mut (impl core::ToInputOutputArray + ?Sized), image_size1: core::Size, image_size2: core::Size, k1: &mut (impl core::ToInputOutputArray + ?Sized), xi1: &mut (impl core::ToInputOutputArray + ?Sized), d1: &mut (impl core::ToInputOutputArray + ?Sized), k2: &mut (impl core::ToInputOutputArray + ?Sized), xi2: &mut (impl core::ToInputOutputArray + ?Sized), d2: &mut (impl core::ToInputOutputArray + ?Sized), rvec: &mut (impl core::ToOutputArray + ?Sized), tvec: &mut (impl core::ToOutputArray + ?Sized), rvecs_l: &mut (impl core::ToOutputArray + ?Sized), tvecs_l: &mut (impl core::ToOutputArray + ?Sized), flags: i32, criteria: core::TermCriteria, idx: Option<&mut (impl core::ToOutputArray + ?Sized)>) -> Result<f64> {
 	input_output_array_arg!(object_points);
 	input_output_array_arg!(image_points1);
 	input_output_array_arg!(image_points2);
@@ -224,7 +224,7 @@ pub fn stereo_calibrate(object_points: &mut dyn core::ToInputOutputArray, image_
 	output_array_arg!(tvec);
 	output_array_arg!(rvecs_l);
 	output_array_arg!(tvecs_l);
-	output_array_arg!(idx);
+	output_array_arg!(nullable idx);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_omnidir_stereoCalibrate_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const_SizeR_const_SizeR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_int_TermCriteria_const__OutputArrayR(object_points.as_raw__InputOutputArray(), image_points1.as_raw__InputOutputArray(), image_points2.as_raw__InputOutputArray(), &image_size1, &image_size2, k1.as_raw__InputOutputArray(), xi1.as_raw__InputOutputArray(), d1.as_raw__InputOutputArray(), k2.as_raw__InputOutputArray(), xi2.as_raw__InputOutputArray(), d2.as_raw__InputOutputArray(), rvec.as_raw__OutputArray(), tvec.as_raw__OutputArray(), rvecs_l.as_raw__OutputArray(), tvecs_l.as_raw__OutputArray(), flags, criteria.opencv_as_extern(), idx.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -262,7 +262,7 @@ pub fn stereo_calibrate(object_points: &mut dyn core::ToInputOutputArray, image_
 /// * point_cloud: cv::noArray()
 /// * point_type: XYZRGB
 #[inline]
-pub fn stereo_reconstruct(image1: &dyn core::ToInputArray, image2: &dyn core::ToInputArray, k1: &dyn core::ToInputArray, d1: &dyn core::ToInputArray, xi1: &dyn core::ToInputArray, k2: &dyn core::ToInputArray, d2: &dyn core::ToInputArray, xi2: &dyn core::ToInputArray, r: &dyn core::ToInputArray, t: &dyn core::ToInputArray, flag: i32, num_disparities: i32, sad_window_size: i32, disparity: &mut dyn core::ToOutputArray, image1_rec: &mut dyn core::ToOutputArray, image2_rec: &mut dyn core::ToOutputArray, new_size: core::Size, knew: &dyn core::ToInputArray, point_cloud: &mut dyn core::ToOutputArray, point_type: i32) -> Result<()> {
+pub fn stereo_reconstruct(image1: &(impl core::ToInputArray + ?Sized), image2: &(impl core::ToInputArray + ?Sized), k1: &(impl core::ToInputArray + ?Sized), d1: &(impl core::ToInputArray + ?Sized), xi1: &(impl core::ToInputArray + ?Sized), k2: &(impl core::ToInputArray + ?Sized), d2: &(impl core::ToInputArray + ?Sized), xi2: &(impl core::ToInputArray + ?Sized), r: &(impl core::ToInputArray + ?Sized), t: &(impl core::ToInputArray + ?Sized), flag: i32, num_disparities: i32, sad_window_size: i32, disparity: &mut (impl core::ToOutputArray + ?Sized), image1_rec: &mut (impl core::ToOutputArray + ?Sized), image2_rec: &mut (impl core::ToOutputArray + ?Sized), new_size: core::Size, knew: Option<&(impl core::ToInputArray + ?Sized)>, point_cloud: Option<&mut (impl core::ToOutputArray + ?Sized)>, point_type: i32) -> Result<()> {
 	input_array_arg!(image1);
 	input_array_arg!(image2);
 	input_array_arg!(k1);
@@ -276,8 +276,8 @@ pub fn stereo_reconstruct(image1: &dyn core::ToInputArray, image2: &dyn core::To
 	output_array_arg!(disparity);
 	output_array_arg!(image1_rec);
 	output_array_arg!(image2_rec);
-	input_array_arg!(knew);
-	output_array_arg!(point_cloud);
+	input_array_arg!(nullable knew);
+	output_array_arg!(nullable point_cloud);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_omnidir_stereoReconstruct_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_int_int_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const_SizeR_const__InputArrayR_const__OutputArrayR_int(image1.as_raw__InputArray(), image2.as_raw__InputArray(), k1.as_raw__InputArray(), d1.as_raw__InputArray(), xi1.as_raw__InputArray(), k2.as_raw__InputArray(), d2.as_raw__InputArray(), xi2.as_raw__InputArray(), r.as_raw__InputArray(), t.as_raw__InputArray(), flag, num_disparities, sad_window_size, disparity.as_raw__OutputArray(), image1_rec.as_raw__OutputArray(), image2_rec.as_raw__OutputArray(), &new_size, knew.as_raw__InputArray(), point_cloud.as_raw__OutputArray(), point_type, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -293,7 +293,7 @@ pub fn stereo_reconstruct(image1: &dyn core::ToInputArray, image2: &dyn core::To
 /// * R1: Output 3x3 rotation matrix for the first camera
 /// * R2: Output 3x3 rotation matrix for the second camera
 #[inline]
-pub fn stereo_rectify(r: &dyn core::ToInputArray, t: &dyn core::ToInputArray, r1: &mut dyn core::ToOutputArray, r2: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn stereo_rectify(r: &(impl core::ToInputArray + ?Sized), t: &(impl core::ToInputArray + ?Sized), r1: &mut (impl core::ToOutputArray + ?Sized), r2: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(r);
 	input_array_arg!(t);
 	output_array_arg!(r1);
@@ -323,13 +323,13 @@ pub fn stereo_rectify(r: &dyn core::ToInputArray, t: &dyn core::ToInputArray, r1
 /// * new_size: Size()
 /// * r: Mat::eye(3,3,CV_64F)
 #[inline]
-pub fn undistort_image(distorted: &dyn core::ToInputArray, undistorted: &mut dyn core::ToOutputArray, k: &dyn core::ToInputArray, d: &dyn core::ToInputArray, xi: &dyn core::ToInputArray, flags: i32, knew: &dyn core::ToInputArray, new_size: core::Size, r: &dyn core::ToInputArray) -> Result<()> {
+pub fn undistort_image(distorted: &(impl core::ToInputArray + ?Sized), undistorted: &mut (impl core::ToOutputArray + ?Sized), k: &(impl core::ToInputArray + ?Sized), d: &(impl core::ToInputArray + ?Sized), xi: &(impl core::ToInputArray + ?Sized), flags: i32, knew: Option<&(impl core::ToInputArray + ?Sized)>, new_size: core::Size, r: &(impl core::ToInputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(distorted);
 	output_array_arg!(undistorted);
 	input_array_arg!(k);
 	input_array_arg!(d);
 	input_array_arg!(xi);
-	input_array_arg!(knew);
+	input_array_arg!(nullable knew);
 	input_array_arg!(r);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_omnidir_undistortImage_const__InputArrayR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_int_const__InputArrayR_const_SizeR_const__InputArrayR(distorted.as_raw__InputArray(), undistorted.as_raw__OutputArray(), k.as_raw__InputArray(), d.as_raw__InputArray(), xi.as_raw__InputArray(), flags, knew.as_raw__InputArray(), &new_size, r.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
@@ -351,7 +351,7 @@ pub fn undistort_image(distorted: &dyn core::ToInputArray, undistorted: &mut dyn
 /// * undistorted: array of normalized object points, vector of Vec2f/Vec2d or 1xN/Nx1 2-channel Mat with the same
 /// depth of distorted points.
 #[inline]
-pub fn undistort_points(distorted: &dyn core::ToInputArray, undistorted: &mut dyn core::ToOutputArray, k: &dyn core::ToInputArray, d: &dyn core::ToInputArray, xi: &dyn core::ToInputArray, r: &dyn core::ToInputArray) -> Result<()> {
+pub fn undistort_points(distorted: &(impl core::ToInputArray + ?Sized), undistorted: &mut (impl core::ToOutputArray + ?Sized), k: &(impl core::ToInputArray + ?Sized), d: &(impl core::ToInputArray + ?Sized), xi: &(impl core::ToInputArray + ?Sized), r: &(impl core::ToInputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(distorted);
 	output_array_arg!(undistorted);
 	input_array_arg!(k);
@@ -376,9 +376,9 @@ pub trait CustomPatternTrait: core::AlgorithmTrait + crate::ccalib::CustomPatter
 	/// ## C++ default parameters
 	/// * output: noArray()
 	#[inline]
-	fn create(&mut self, pattern: &dyn core::ToInputArray, board_size: core::Size2f, output: &mut dyn core::ToOutputArray) -> Result<bool> {
+	fn create(&mut self, pattern: &dyn core::ToInputArray, board_size: core::Size2f, output: Option<&mut dyn core::ToOutputArray>) -> Result<bool> {
 		input_array_arg!(pattern);
-		output_array_arg!(output);
+		output_array_arg!(nullable output);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ccalib_CustomPattern_create_const__InputArrayR_const_Size2f_const__OutputArrayR(self.as_raw_mut_CustomPattern(), pattern.as_raw__InputArray(), board_size.opencv_as_extern(), output.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -394,13 +394,13 @@ pub trait CustomPatternTrait: core::AlgorithmTrait + crate::ccalib::CustomPatter
 	/// * h: noArray()
 	/// * pattern_corners: noArray()
 	#[inline]
-	fn find_pattern(&mut self, image: &dyn core::ToInputArray, matched_features: &mut dyn core::ToOutputArray, pattern_points: &mut dyn core::ToOutputArray, ratio: f64, proj_error: f64, refine_position: bool, out: &mut dyn core::ToOutputArray, h: &mut dyn core::ToOutputArray, pattern_corners: &mut dyn core::ToOutputArray) -> Result<bool> {
+	fn find_pattern(&mut self, image: &dyn core::ToInputArray, matched_features: &mut dyn core::ToOutputArray, pattern_points: &mut dyn core::ToOutputArray, ratio: f64, proj_error: f64, refine_position: bool, out: Option<&mut dyn core::ToOutputArray>, h: Option<&mut dyn core::ToOutputArray>, pattern_corners: Option<&mut dyn core::ToOutputArray>) -> Result<bool> {
 		input_array_arg!(image);
 		output_array_arg!(matched_features);
 		output_array_arg!(pattern_points);
-		output_array_arg!(out);
-		output_array_arg!(h);
-		output_array_arg!(pattern_corners);
+		output_array_arg!(nullable out);
+		output_array_arg!(nullable h);
+		output_array_arg!(nullable pattern_corners);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ccalib_CustomPattern_findPattern_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const_double_const_double_const_bool_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_CustomPattern(), image.as_raw__InputArray(), matched_features.as_raw__OutputArray(), pattern_points.as_raw__OutputArray(), ratio, proj_error, refine_position, out.as_raw__OutputArray(), h.as_raw__OutputArray(), pattern_corners.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -553,14 +553,14 @@ pub trait CustomPatternTrait: core::AlgorithmTrait + crate::ccalib::CustomPatter
 	/// * inliers: noArray()
 	/// * flags: SOLVEPNP_ITERATIVE
 	#[inline]
-	fn find_rt_ransac(&mut self, object_points: &dyn core::ToInputArray, image_points: &dyn core::ToInputArray, camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, rvec: &mut dyn core::ToInputOutputArray, tvec: &mut dyn core::ToInputOutputArray, use_extrinsic_guess: bool, iterations_count: i32, reprojection_error: f32, min_inliers_count: i32, inliers: &mut dyn core::ToOutputArray, flags: i32) -> Result<bool> {
+	fn find_rt_ransac(&mut self, object_points: &dyn core::ToInputArray, image_points: &dyn core::ToInputArray, camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, rvec: &mut dyn core::ToInputOutputArray, tvec: &mut dyn core::ToInputOutputArray, use_extrinsic_guess: bool, iterations_count: i32, reprojection_error: f32, min_inliers_count: i32, inliers: Option<&mut dyn core::ToOutputArray>, flags: i32) -> Result<bool> {
 		input_array_arg!(object_points);
 		input_array_arg!(image_points);
 		input_array_arg!(camera_matrix);
 		input_array_arg!(dist_coeffs);
 		input_output_array_arg!(rvec);
 		input_output_array_arg!(tvec);
-		output_array_arg!(inliers);
+		output_array_arg!(nullable inliers);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ccalib_CustomPattern_findRtRANSAC_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_bool_int_float_int_const__OutputArrayR_int(self.as_raw_mut_CustomPattern(), object_points.as_raw__InputArray(), image_points.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvec.as_raw__InputOutputArray(), tvec.as_raw__InputOutputArray(), use_extrinsic_guess, iterations_count, reprojection_error, min_inliers_count, inliers.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -576,13 +576,13 @@ pub trait CustomPatternTrait: core::AlgorithmTrait + crate::ccalib::CustomPatter
 	/// * inliers: noArray()
 	/// * flags: SOLVEPNP_ITERATIVE
 	#[inline]
-	fn find_rt_ransac_1(&mut self, image: &dyn core::ToInputArray, camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, rvec: &mut dyn core::ToInputOutputArray, tvec: &mut dyn core::ToInputOutputArray, use_extrinsic_guess: bool, iterations_count: i32, reprojection_error: f32, min_inliers_count: i32, inliers: &mut dyn core::ToOutputArray, flags: i32) -> Result<bool> {
+	fn find_rt_ransac_1(&mut self, image: &dyn core::ToInputArray, camera_matrix: &dyn core::ToInputArray, dist_coeffs: &dyn core::ToInputArray, rvec: &mut dyn core::ToInputOutputArray, tvec: &mut dyn core::ToInputOutputArray, use_extrinsic_guess: bool, iterations_count: i32, reprojection_error: f32, min_inliers_count: i32, inliers: Option<&mut dyn core::ToOutputArray>, flags: i32) -> Result<bool> {
 		input_array_arg!(image);
 		input_array_arg!(camera_matrix);
 		input_array_arg!(dist_coeffs);
 		input_output_array_arg!(rvec);
 		input_output_array_arg!(tvec);
-		output_array_arg!(inliers);
+		output_array_arg!(nullable inliers);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ccalib_CustomPattern_findRtRANSAC_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_bool_int_float_int_const__OutputArrayR_int(self.as_raw_mut_CustomPattern(), image.as_raw__InputArray(), camera_matrix.as_raw__InputArray(), dist_coeffs.as_raw__InputArray(), rvec.as_raw__InputOutputArray(), tvec.as_raw__InputOutputArray(), use_extrinsic_guess, iterations_count, reprojection_error, min_inliers_count, inliers.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -809,6 +809,14 @@ pub trait MultiCameraCalibration_edgeTraitConst {
 		ret
 	}
 	
+	/// Same as `transform()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn transform_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_multicalib_MultiCameraCalibration_edge_getPropTransformRef_const(self.as_raw_MultiCameraCalibration_edge()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 }
 
 pub trait MultiCameraCalibration_edgeTrait: crate::ccalib::MultiCameraCalibration_edgeTraitConst {
@@ -886,6 +894,14 @@ pub trait MultiCameraCalibration_vertexTraitConst {
 		ret
 	}
 	
+	/// Same as `pose()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn pose_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_multicalib_MultiCameraCalibration_vertex_getPropPoseRef_const(self.as_raw_MultiCameraCalibration_vertex()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn timestamp(&self) -> i32 {
 		let ret = unsafe { sys::cv_multicalib_MultiCameraCalibration_vertex_getPropTimestamp_const(self.as_raw_MultiCameraCalibration_vertex()) };
@@ -1150,3 +1166,4 @@ impl RandomPatternGenerator {
 	}
 	
 }
+pub use crate::manual::ccalib::*;
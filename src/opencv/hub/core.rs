@@ -1642,7 +1642,7 @@ pub type Mat4f = core::Mat_<core::Vec4f>;
 pub type Mat4i = core::Mat_<core::Vec4i>;
 pub type Mat4s = core::Mat_<core::Vec4s>;
 pub type Mat4w = core::Mat_<core::Vec4w>;
-pub type MatConstIterator_difference_type = ptrdiff_t;
+pub type MatConstIterator_difference_type = isize;
 pub type MatConstIterator_pointer<'a, 'b> = &'a mut &'b u8;
 pub type MatConstIterator_reference<'a> = &'a mut u8;
 pub type MatConstIterator_value_type<'a> = &'a mut u8;
@@ -1726,12 +1726,12 @@ pub type Vec6i = core::VecN<i32, 6>;
 pub type Vec8i = core::VecN<i32, 8>;
 pub type GpuMatND_IndexArray = core::Vector<i32>;
 pub type GpuMatND_SizeArray = core::Vector<i32>;
-pub type GpuMatND_StepArray = core::Vector<size_t>;
+pub type GpuMatND_StepArray = core::Vector<usize>;
 pub type Stream_StreamCallback = Option<Box<dyn FnMut(i32) -> () + Send + Sync + 'static>>;
 pub type ProgramSource_hash_t = u64;
 /// proxy for hal::Cholesky
 #[inline]
-pub fn cholesky(a: &mut f64, astep: size_t, m: i32, b: &mut f64, bstep: size_t, n: i32) -> Result<bool> {
+pub fn cholesky(a: &mut f64, astep: usize, m: i32, b: &mut f64, bstep: usize, n: i32) -> Result<bool> {
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_Cholesky_doubleX_size_t_int_doubleX_size_t_int(a, astep, m, b, bstep, n, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -1741,7 +1741,7 @@ pub fn cholesky(a: &mut f64, astep: size_t, m: i32, b: &mut f64, bstep: size_t,
 
 /// proxy for hal::Cholesky
 #[inline]
-pub fn cholesky_f32(a: &mut f32, astep: size_t, m: i32, b: &mut f32, bstep: size_t, n: i32) -> Result<bool> {
+pub fn cholesky_f32(a: &mut f32, astep: usize, m: i32, b: &mut f32, bstep: usize, n: i32) -> Result<bool> {
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_Cholesky_floatX_size_t_int_floatX_size_t_int(a, astep, m, b, bstep, n, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -1765,7 +1765,7 @@ pub fn cholesky_f32(a: &mut f32, astep: size_t, m: i32, b: &mut f32, bstep: size
 /// ## See also
 /// convertScaleAbs, Mat::convertTo
 #[inline]
-pub fn lut(src: &dyn core::ToInputArray, lut: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn lut(src: &(impl core::ToInputArray + ?Sized), lut: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src);
 	input_array_arg!(lut);
 	output_array_arg!(dst);
@@ -1778,7 +1778,7 @@ pub fn lut(src: &dyn core::ToInputArray, lut: &dyn core::ToInputArray, dst: &mut
 
 /// proxy for hal::LU
 #[inline]
-pub fn lu(a: &mut f64, astep: size_t, m: i32, b: &mut f64, bstep: size_t, n: i32) -> Result<i32> {
+pub fn lu(a: &mut f64, astep: usize, m: i32, b: &mut f64, bstep: usize, n: i32) -> Result<i32> {
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_LU_doubleX_size_t_int_doubleX_size_t_int(a, astep, m, b, bstep, n, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -1788,7 +1788,7 @@ pub fn lu(a: &mut f64, astep: size_t, m: i32, b: &mut f64, bstep: size_t, n: i32
 
 /// proxy for hal::LU
 #[inline]
-pub fn lu_f32(a: &mut f32, astep: size_t, m: i32, b: &mut f32, bstep: size_t, n: i32) -> Result<i32> {
+pub fn lu_f32(a: &mut f32, astep: usize, m: i32, b: &mut f32, bstep: usize, n: i32) -> Result<i32> {
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_LU_floatX_size_t_int_floatX_size_t_int(a, astep, m, b, bstep, n, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -1807,7 +1807,7 @@ pub fn lu_f32(a: &mut f32, astep: size_t, m: i32, b: &mut f32, bstep: size_t, n:
 /// * v2: second 1D input vector.
 /// * icovar: inverse covariance matrix.
 #[inline]
-pub fn mahalanobis(v1: &dyn core::ToInputArray, v2: &dyn core::ToInputArray, icovar: &dyn core::ToInputArray) -> Result<f64> {
+pub fn mahalanobis(v1: &(impl core::ToInputArray + ?Sized), v2: &(impl core::ToInputArray + ?Sized), icovar: &(impl core::ToInputArray + ?Sized)) -> Result<f64> {
 	input_array_arg!(v1);
 	input_array_arg!(v2);
 	input_array_arg!(icovar);
@@ -1820,7 +1820,7 @@ pub fn mahalanobis(v1: &dyn core::ToInputArray, v2: &dyn core::ToInputArray, ico
 
 /// wrap PCA::backProject
 #[inline]
-pub fn pca_back_project(data: &dyn core::ToInputArray, mean: &dyn core::ToInputArray, eigenvectors: &dyn core::ToInputArray, result: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn pca_back_project(data: &(impl core::ToInputArray + ?Sized), mean: &(impl core::ToInputArray + ?Sized), eigenvectors: &(impl core::ToInputArray + ?Sized), result: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(data);
 	input_array_arg!(mean);
 	input_array_arg!(eigenvectors);
@@ -1834,7 +1834,7 @@ pub fn pca_back_project(data: &dyn core::ToInputArray, mean: &dyn core::ToInputA
 
 /// wrap PCA::operator() and add eigenvalues output parameter
 #[inline]
-pub fn pca_compute2_variance(data: &dyn core::ToInputArray, mean: &mut dyn core::ToInputOutputArray, eigenvectors: &mut dyn core::ToOutputArray, eigenvalues: &mut dyn core::ToOutputArray, retained_variance: f64) -> Result<()> {
+pub fn pca_compute2_variance(data: &(impl core::ToInputArray + ?Sized), mean: &mut (impl core::ToInputOutputArray + ?Sized), eigenvectors: &mut (impl core::ToOutputArray + ?Sized), eigenvalues: &mut (impl core::ToOutputArray + ?Sized), retained_variance: f64) -> Result<()> {
 	input_array_arg!(data);
 	input_output_array_arg!(mean);
 	output_array_arg!(eigenvectors);
@@ -1851,7 +1851,7 @@ pub fn pca_compute2_variance(data: &dyn core::ToInputArray, mean: &mut dyn core:
 /// ## C++ default parameters
 /// * max_components: 0
 #[inline]
-pub fn pca_compute2(data: &dyn core::ToInputArray, mean: &mut dyn core::ToInputOutputArray, eigenvectors: &mut dyn core::ToOutputArray, eigenvalues: &mut dyn core::ToOutputArray, max_components: i32) -> Result<()> {
+pub fn pca_compute2(data: &(impl core::ToInputArray + ?Sized), mean: &mut (impl core::ToInputOutputArray + ?Sized), eigenvectors: &mut (impl core::ToOutputArray + ?Sized), eigenvalues: &mut (impl core::ToOutputArray + ?Sized), max_components: i32) -> Result<()> {
 	input_array_arg!(data);
 	input_output_array_arg!(mean);
 	output_array_arg!(eigenvectors);
@@ -1865,7 +1865,7 @@ pub fn pca_compute2(data: &dyn core::ToInputArray, mean: &mut dyn core::ToInputO
 
 /// wrap PCA::operator()
 #[inline]
-pub fn pca_compute_variance(data: &dyn core::ToInputArray, mean: &mut dyn core::ToInputOutputArray, eigenvectors: &mut dyn core::ToOutputArray, retained_variance: f64) -> Result<()> {
+pub fn pca_compute_variance(data: &(impl core::ToInputArray + ?Sized), mean: &mut (impl core::ToInputOutputArray + ?Sized), eigenvectors: &mut (impl core::ToOutputArray + ?Sized), retained_variance: f64) -> Result<()> {
 	input_array_arg!(data);
 	input_output_array_arg!(mean);
 	output_array_arg!(eigenvectors);
@@ -1881,7 +1881,7 @@ pub fn pca_compute_variance(data: &dyn core::ToInputArray, mean: &mut dyn core::
 /// ## C++ default parameters
 /// * max_components: 0
 #[inline]
-pub fn pca_compute(data: &dyn core::ToInputArray, mean: &mut dyn core::ToInputOutputArray, eigenvectors: &mut dyn core::ToOutputArray, max_components: i32) -> Result<()> {
+pub fn pca_compute(data: &(impl core::ToInputArray + ?Sized), mean: &mut (impl core::ToInputOutputArray + ?Sized), eigenvectors: &mut (impl core::ToOutputArray + ?Sized), max_components: i32) -> Result<()> {
 	input_array_arg!(data);
 	input_output_array_arg!(mean);
 	output_array_arg!(eigenvectors);
@@ -1894,7 +1894,7 @@ pub fn pca_compute(data: &dyn core::ToInputArray, mean: &mut dyn core::ToInputOu
 
 /// wrap PCA::project
 #[inline]
-pub fn pca_project(data: &dyn core::ToInputArray, mean: &dyn core::ToInputArray, eigenvectors: &dyn core::ToInputArray, result: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn pca_project(data: &(impl core::ToInputArray + ?Sized), mean: &(impl core::ToInputArray + ?Sized), eigenvectors: &(impl core::ToInputArray + ?Sized), result: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(data);
 	input_array_arg!(mean);
 	input_array_arg!(eigenvectors);
@@ -1926,7 +1926,7 @@ pub fn pca_project(data: &dyn core::ToInputArray, mean: &dyn core::ToInputArray,
 /// ## C++ default parameters
 /// * r: 255.
 #[inline]
-pub fn psnr(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, r: f64) -> Result<f64> {
+pub fn psnr(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), r: f64) -> Result<f64> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	return_send!(via ocvrs_return);
@@ -1938,7 +1938,7 @@ pub fn psnr(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, r: f64
 
 /// wrap SVD::backSubst
 #[inline]
-pub fn sv_back_subst(w: &dyn core::ToInputArray, u: &dyn core::ToInputArray, vt: &dyn core::ToInputArray, rhs: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn sv_back_subst(w: &(impl core::ToInputArray + ?Sized), u: &(impl core::ToInputArray + ?Sized), vt: &(impl core::ToInputArray + ?Sized), rhs: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(w);
 	input_array_arg!(u);
 	input_array_arg!(vt);
@@ -1956,7 +1956,7 @@ pub fn sv_back_subst(w: &dyn core::ToInputArray, u: &dyn core::ToInputArray, vt:
 /// ## C++ default parameters
 /// * flags: 0
 #[inline]
-pub fn sv_decomp(src: &dyn core::ToInputArray, w: &mut dyn core::ToOutputArray, u: &mut dyn core::ToOutputArray, vt: &mut dyn core::ToOutputArray, flags: i32) -> Result<()> {
+pub fn sv_decomp(src: &(impl core::ToInputArray + ?Sized), w: &mut (impl core::ToOutputArray + ?Sized), u: &mut (impl core::ToOutputArray + ?Sized), vt: &mut (impl core::ToOutputArray + ?Sized), flags: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(w);
 	output_array_arg!(u);
@@ -2046,7 +2046,7 @@ pub fn abs(m: &core::Mat) -> Result<core::MatExpr> {
 /// ## See also
 /// cv::abs(const Mat&)
 #[inline]
-pub fn absdiff(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn absdiff(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
@@ -2086,7 +2086,7 @@ pub fn absdiff(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst
 /// ## C++ default parameters
 /// * dtype: -1
 #[inline]
-pub fn add_weighted(src1: &dyn core::ToInputArray, alpha: f64, src2: &dyn core::ToInputArray, beta: f64, gamma: f64, dst: &mut dyn core::ToOutputArray, dtype: i32) -> Result<()> {
+pub fn add_weighted(src1: &(impl core::ToInputArray + ?Sized), alpha: f64, src2: &(impl core::ToInputArray + ?Sized), beta: f64, gamma: f64, dst: &mut (impl core::ToOutputArray + ?Sized), dtype: i32) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
@@ -2141,11 +2141,11 @@ pub fn add_weighted(src1: &dyn core::ToInputArray, alpha: f64, src2: &dyn core::
 /// * mask: noArray()
 /// * dtype: -1
 #[inline]
-pub fn add(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray, dtype: i32) -> Result<()> {
+pub fn add(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), mask: Option<&(impl core::ToInputArray + ?Sized)>, dtype: i32) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_add_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR_int(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), dtype, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -2165,12 +2165,12 @@ pub fn add(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &m
 /// * update: 0
 /// * crosscheck: false
 #[inline]
-pub fn batch_distance(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dist: &mut dyn core::ToOutputArray, dtype: i32, nidx: &mut dyn core::ToOutputArray, norm_type: i32, k: i32, mask: &dyn core::ToInputArray, update: i32, crosscheck: bool) -> Result<()> {
+pub fn batch_distance(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dist: &mut (impl core::ToOutputArray + ?Sized), dtype: i32, nidx: &mut (impl core::ToOutputArray + ?Sized), norm_type: i32, k: i32, mask: Option<&(impl core::ToInputArray + ?Sized)>, update: i32, crosscheck: bool) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dist);
 	output_array_arg!(nidx);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_batchDistance_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_const__OutputArrayR_int_int_const__InputArrayR_int_bool(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dist.as_raw__OutputArray(), dtype, nidx.as_raw__OutputArray(), norm_type, k, mask.as_raw__InputArray(), update, crosscheck, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -2207,11 +2207,11 @@ pub fn batch_distance(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArr
 /// ## C++ default parameters
 /// * mask: noArray()
 #[inline]
-pub fn bitwise_and(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray) -> Result<()> {
+pub fn bitwise_and(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_bitwise_and_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -2237,10 +2237,10 @@ pub fn bitwise_and(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray,
 /// ## C++ default parameters
 /// * mask: noArray()
 #[inline]
-pub fn bitwise_not(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray) -> Result<()> {
+pub fn bitwise_not(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_bitwise_not_const__InputArrayR_const__OutputArrayR_const__InputArrayR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -2276,11 +2276,11 @@ pub fn bitwise_not(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArr
 /// ## C++ default parameters
 /// * mask: noArray()
 #[inline]
-pub fn bitwise_or(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray) -> Result<()> {
+pub fn bitwise_or(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_bitwise_or_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -2317,11 +2317,11 @@ pub fn bitwise_or(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray,
 /// ## C++ default parameters
 /// * mask: noArray()
 #[inline]
-pub fn bitwise_xor(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray) -> Result<()> {
+pub fn bitwise_xor(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_bitwise_xor_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -2388,7 +2388,7 @@ pub fn border_interpolate(p: i32, len: i32, border_type: i32) -> Result<i32> {
 /// ## C++ default parameters
 /// * ctype: CV_64F
 #[inline]
-pub fn calc_covar_matrix(samples: &dyn core::ToInputArray, covar: &mut dyn core::ToOutputArray, mean: &mut dyn core::ToInputOutputArray, flags: i32, ctype: i32) -> Result<()> {
+pub fn calc_covar_matrix(samples: &(impl core::ToInputArray + ?Sized), covar: &mut (impl core::ToOutputArray + ?Sized), mean: &mut (impl core::ToInputOutputArray + ?Sized), flags: i32, ctype: i32) -> Result<()> {
 	input_array_arg!(samples);
 	output_array_arg!(covar);
 	input_output_array_arg!(mean);
@@ -2422,7 +2422,7 @@ pub fn calc_covar_matrix(samples: &dyn core::ToInputArray, covar: &mut dyn core:
 /// ## C++ default parameters
 /// * angle_in_degrees: false
 #[inline]
-pub fn cart_to_polar(x: &dyn core::ToInputArray, y: &dyn core::ToInputArray, magnitude: &mut dyn core::ToOutputArray, angle: &mut dyn core::ToOutputArray, angle_in_degrees: bool) -> Result<()> {
+pub fn cart_to_polar(x: &(impl core::ToInputArray + ?Sized), y: &(impl core::ToInputArray + ?Sized), magnitude: &mut (impl core::ToOutputArray + ?Sized), angle: &mut (impl core::ToOutputArray + ?Sized), angle_in_degrees: bool) -> Result<()> {
 	input_array_arg!(x);
 	input_array_arg!(y);
 	output_array_arg!(magnitude);
@@ -2473,7 +2473,7 @@ pub fn check_hardware_support(feature: i32) -> Result<bool> {
 /// * min_val: -DBL_MAX
 /// * max_val: DBL_MAX
 #[inline]
-pub fn check_range(a: &dyn core::ToInputArray, quiet: bool, pos: &mut core::Point, min_val: f64, max_val: f64) -> Result<bool> {
+pub fn check_range(a: &(impl core::ToInputArray + ?Sized), quiet: bool, pos: &mut core::Point, min_val: f64, max_val: f64) -> Result<bool> {
 	input_array_arg!(a);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_checkRange_const__InputArrayR_bool_PointX_double_double(a.as_raw__InputArray(), quiet, pos, min_val, max_val, ocvrs_return.as_mut_ptr()) };
@@ -2511,7 +2511,7 @@ pub fn check_range(a: &dyn core::ToInputArray, quiet: bool, pos: &mut core::Poin
 /// ## See also
 /// checkRange, min, max, threshold
 #[inline]
-pub fn compare(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, cmpop: i32) -> Result<()> {
+pub fn compare(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), cmpop: i32) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
@@ -2541,7 +2541,7 @@ pub fn compare(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst
 /// ## C++ default parameters
 /// * lower_to_upper: false
 #[inline]
-pub fn complete_symm(m: &mut dyn core::ToInputOutputArray, lower_to_upper: bool) -> Result<()> {
+pub fn complete_symm(m: &mut (impl core::ToInputOutputArray + ?Sized), lower_to_upper: bool) -> Result<()> {
 	input_output_array_arg!(m);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_completeSymm_const__InputOutputArrayR_bool(m.as_raw__InputOutputArray(), lower_to_upper, ocvrs_return.as_mut_ptr()) };
@@ -2561,7 +2561,7 @@ pub fn complete_symm(m: &mut dyn core::ToInputOutputArray, lower_to_upper: bool)
 /// * src: input array.
 /// * dst: output array.
 #[inline]
-pub fn convert_fp16(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn convert_fp16(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -2603,7 +2603,7 @@ pub fn convert_fp16(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputAr
 /// * alpha: 1
 /// * beta: 0
 #[inline]
-pub fn convert_scale_abs(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, alpha: f64, beta: f64) -> Result<()> {
+pub fn convert_scale_abs(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), alpha: f64, beta: f64) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -2662,7 +2662,7 @@ pub fn convert_scale_abs(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOut
 /// ## C++ default parameters
 /// * value: Scalar()
 #[inline]
-pub fn copy_make_border(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, top: i32, bottom: i32, left: i32, right: i32, border_type: i32, value: core::Scalar) -> Result<()> {
+pub fn copy_make_border(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), top: i32, bottom: i32, left: i32, right: i32, border_type: i32, value: core::Scalar) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -2682,7 +2682,7 @@ pub fn copy_make_border(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutp
 /// * mask: Operation mask of the same size as \*this. Its non-zero elements indicate which matrix
 /// elements need to be copied. The mask has to be of type CV_8U and can have 1 or multiple channels.
 #[inline]
-pub fn copy_to(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray) -> Result<()> {
+pub fn copy_to(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), mask: &(impl core::ToInputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	input_array_arg!(mask);
@@ -2702,7 +2702,7 @@ pub fn copy_to(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray,
 /// ## See also
 /// mean, meanStdDev, norm, minMaxLoc, calcCovarMatrix
 #[inline]
-pub fn count_non_zero(src: &dyn core::ToInputArray) -> Result<i32> {
+pub fn count_non_zero(src: &(impl core::ToInputArray + ?Sized)) -> Result<i32> {
 	input_array_arg!(src);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_countNonZero_const__InputArrayR(src.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
@@ -2739,7 +2739,7 @@ pub fn cube_root(val: f32) -> Result<f32> {
 /// Matrix is called continuous if its elements are stored continuously, that is, without gaps at the
 /// end of each row.
 #[inline]
-pub fn create_continuous(rows: i32, cols: i32, typ: i32, arr: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn create_continuous(rows: i32, cols: i32, typ: i32, arr: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	output_array_arg!(arr);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_createContinuous_int_int_int_const__OutputArrayR(rows, cols, typ, arr.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
@@ -2768,7 +2768,7 @@ pub fn device_supports(feature_set: core::FeatureSet) -> Result<bool> {
 /// 
 /// The function does not reallocate memory if the matrix has proper attributes already.
 #[inline]
-pub fn ensure_size_is_enough(rows: i32, cols: i32, typ: i32, arr: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn ensure_size_is_enough(rows: i32, cols: i32, typ: i32, arr: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	output_array_arg!(arr);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_ensureSizeIsEnough_int_int_int_const__OutputArrayR(rows, cols, typ, arr.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
@@ -2846,7 +2846,7 @@ pub fn reset_device() -> Result<()> {
 }
 
 #[inline]
-pub fn set_buffer_pool_config(device_id: i32, stack_size: size_t, stack_count: i32) -> Result<()> {
+pub fn set_buffer_pool_config(device_id: i32, stack_size: usize, stack_count: i32) -> Result<()> {
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_setBufferPoolConfig_int_size_t_int(device_id, stack_size, stack_count, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -2956,7 +2956,7 @@ pub fn unregister_page_locked(m: &mut core::Mat) -> Result<()> {
 /// ## C++ default parameters
 /// * flags: 0
 #[inline]
-pub fn dct(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, flags: i32) -> Result<()> {
+pub fn dct(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), flags: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -3104,7 +3104,7 @@ pub fn check_failed_auto(v1: i32, v2: i32, ctx: &core::Detail_CheckContext) -> R
 }
 
 #[inline]
-pub fn check_failed_auto_6(v: size_t, ctx: &core::Detail_CheckContext) -> Result<()> {
+pub fn check_failed_auto_6(v: usize, ctx: &core::Detail_CheckContext) -> Result<()> {
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_detail_check_failed_auto_const_size_t_const_CheckContextR(v, ctx.as_raw_Detail_CheckContext(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -3113,7 +3113,7 @@ pub fn check_failed_auto_6(v: size_t, ctx: &core::Detail_CheckContext) -> Result
 }
 
 #[inline]
-pub fn check_failed_auto_1(v1: size_t, v2: size_t, ctx: &core::Detail_CheckContext) -> Result<()> {
+pub fn check_failed_auto_1(v1: usize, v2: usize, ctx: &core::Detail_CheckContext) -> Result<()> {
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_detail_check_failed_auto_const_size_t_const_size_t_const_CheckContextR(v1, v2, ctx.as_raw_Detail_CheckContext(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -3146,7 +3146,7 @@ pub fn check_failed_auto_10(v1: &str, ctx: &core::Detail_CheckContext) -> Result
 /// ## See also
 /// trace, invert, solve, eigen, @ref MatrixExpressions
 #[inline]
-pub fn determinant(mtx: &dyn core::ToInputArray) -> Result<f64> {
+pub fn determinant(mtx: &(impl core::ToInputArray + ?Sized)) -> Result<f64> {
 	input_array_arg!(mtx);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_determinant_const__InputArrayR(mtx.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
@@ -3295,7 +3295,7 @@ pub fn determinant(mtx: &dyn core::ToInputArray) -> Result<f64> {
 /// * flags: 0
 /// * nonzero_rows: 0
 #[inline]
-pub fn dft(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, flags: i32, nonzero_rows: i32) -> Result<()> {
+pub fn dft(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), flags: i32, nonzero_rows: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -3366,7 +3366,7 @@ pub fn get_type_from_dxgi_format(i_dxgi_format: i32) -> Result<i32> {
 /// * scale: 1
 /// * dtype: -1
 #[inline]
-pub fn divide2(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, scale: f64, dtype: i32) -> Result<()> {
+pub fn divide2(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), scale: f64, dtype: i32) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
@@ -3411,7 +3411,7 @@ pub fn divide2(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst
 /// ## C++ default parameters
 /// * dtype: -1
 #[inline]
-pub fn divide(scale: f64, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, dtype: i32) -> Result<()> {
+pub fn divide(scale: f64, src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), dtype: i32) -> Result<()> {
 	input_array_arg!(src2);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -3439,7 +3439,7 @@ pub fn divide(scale: f64, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToO
 /// ## See also
 /// eigen
 #[inline]
-pub fn eigen_non_symmetric(src: &dyn core::ToInputArray, eigenvalues: &mut dyn core::ToOutputArray, eigenvectors: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn eigen_non_symmetric(src: &(impl core::ToInputArray + ?Sized), eigenvalues: &mut (impl core::ToOutputArray + ?Sized), eigenvectors: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(eigenvalues);
 	output_array_arg!(eigenvectors);
@@ -3476,10 +3476,10 @@ pub fn eigen_non_symmetric(src: &dyn core::ToInputArray, eigenvalues: &mut dyn c
 /// ## C++ default parameters
 /// * eigenvectors: noArray()
 #[inline]
-pub fn eigen(src: &dyn core::ToInputArray, eigenvalues: &mut dyn core::ToOutputArray, eigenvectors: &mut dyn core::ToOutputArray) -> Result<bool> {
+pub fn eigen(src: &(impl core::ToInputArray + ?Sized), eigenvalues: &mut (impl core::ToOutputArray + ?Sized), eigenvectors: Option<&mut (impl core::ToOutputArray + ?Sized)>) -> Result<bool> {
 	input_array_arg!(src);
 	output_array_arg!(eigenvalues);
-	output_array_arg!(eigenvectors);
+	output_array_arg!(nullable eigenvectors);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_eigen_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(src.as_raw__InputArray(), eigenvalues.as_raw__OutputArray(), eigenvectors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -3547,7 +3547,7 @@ pub fn error(_code: i32, _err: &str, _func: &str, _file: &str, _line: i32) -> Re
 /// ## See also
 /// log , cartToPolar , polarToCart , phase , pow , sqrt , magnitude
 #[inline]
-pub fn exp(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn exp(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -3565,7 +3565,7 @@ pub fn exp(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> R
 /// ## See also
 /// mixChannels, split
 #[inline]
-pub fn extract_channel(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, coi: i32) -> Result<()> {
+pub fn extract_channel(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), coi: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -3620,7 +3620,7 @@ pub fn fast_atan2(y: f32, x: f32) -> Result<f32> {
 /// * src: single-channel array
 /// * idx: the output array, type of cv::Mat or std::vector<Point>, corresponding to non-zero indices in the input
 #[inline]
-pub fn find_non_zero(src: &dyn core::ToInputArray, idx: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn find_non_zero(src: &(impl core::ToInputArray + ?Sized), idx: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(idx);
 	return_send!(via ocvrs_return);
@@ -3657,7 +3657,7 @@ pub fn find_non_zero(src: &dyn core::ToInputArray, idx: &mut dyn core::ToOutputA
 /// ## See also
 /// transpose , repeat , completeSymm
 #[inline]
-pub fn flip(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, flip_code: i32) -> Result<()> {
+pub fn flip(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), flip_code: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -3701,7 +3701,7 @@ pub fn flip(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, fli
 /// ## C++ default parameters
 /// * flags: 0
 #[inline]
-pub fn gemm(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, alpha: f64, src3: &dyn core::ToInputArray, beta: f64, dst: &mut dyn core::ToOutputArray, flags: i32) -> Result<()> {
+pub fn gemm(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), alpha: f64, src3: &(impl core::ToInputArray + ?Sized), beta: f64, dst: &mut (impl core::ToOutputArray + ?Sized), flags: i32) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	input_array_arg!(src3);
@@ -3768,7 +3768,7 @@ pub fn get_cpu_tick_count() -> Result<i64> {
 }
 
 #[inline]
-pub fn get_elem_size(typ: i32) -> Result<size_t> {
+pub fn get_elem_size(typ: i32) -> Result<usize> {
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_getElemSize_int(typ, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -4029,7 +4029,7 @@ pub fn have_openvx() -> Result<bool> {
 /// * src2: second input array to be considered for horizontal concatenation.
 /// * dst: output array. It has the same number of rows and depth as the src1 and src2, and the sum of cols of the src1 and src2.
 #[inline]
-pub fn hconcat2(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn hconcat2(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
@@ -4084,7 +4084,7 @@ pub fn hconcat2(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, ds
 /// * dst: output array. It has the same number of rows and depth as the src, and the sum of cols of the src.
 /// same depth.
 #[inline]
-pub fn hconcat(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn hconcat(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -4107,7 +4107,7 @@ pub fn hconcat(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray)
 /// ## C++ default parameters
 /// * flags: 0
 #[inline]
-pub fn idct(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, flags: i32) -> Result<()> {
+pub fn idct(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), flags: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -4136,7 +4136,7 @@ pub fn idct(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, fla
 /// * flags: 0
 /// * nonzero_rows: 0
 #[inline]
-pub fn idft(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, flags: i32, nonzero_rows: i32) -> Result<()> {
+pub fn idft(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), flags: i32, nonzero_rows: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -4166,7 +4166,7 @@ pub fn idft(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, fla
 /// * upperb: inclusive upper boundary array or a scalar.
 /// * dst: output array of the same size as src and CV_8U type.
 #[inline]
-pub fn in_range(src: &dyn core::ToInputArray, lowerb: &dyn core::ToInputArray, upperb: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn in_range(src: &(impl core::ToInputArray + ?Sized), lowerb: &(impl core::ToInputArray + ?Sized), upperb: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src);
 	input_array_arg!(lowerb);
 	input_array_arg!(upperb);
@@ -4186,7 +4186,7 @@ pub fn in_range(src: &dyn core::ToInputArray, lowerb: &dyn core::ToInputArray, u
 /// ## See also
 /// mixChannels, merge
 #[inline]
-pub fn insert_channel(src: &dyn core::ToInputArray, dst: &mut dyn core::ToInputOutputArray, coi: i32) -> Result<()> {
+pub fn insert_channel(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToInputOutputArray + ?Sized), coi: i32) -> Result<()> {
 	input_array_arg!(src);
 	input_output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -4271,7 +4271,7 @@ pub fn use_instrumentation() -> Result<bool> {
 /// ## C++ default parameters
 /// * flags: DECOMP_LU
 #[inline]
-pub fn invert(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, flags: i32) -> Result<f64> {
+pub fn invert(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), flags: i32) -> Result<f64> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -4409,10 +4409,10 @@ pub fn use_ipp_not_exact() -> Result<bool> {
 /// ## C++ default parameters
 /// * centers: noArray()
 #[inline]
-pub fn kmeans(data: &dyn core::ToInputArray, k: i32, best_labels: &mut dyn core::ToInputOutputArray, criteria: core::TermCriteria, attempts: i32, flags: i32, centers: &mut dyn core::ToOutputArray) -> Result<f64> {
+pub fn kmeans(data: &(impl core::ToInputArray + ?Sized), k: i32, best_labels: &mut (impl core::ToInputOutputArray + ?Sized), criteria: core::TermCriteria, attempts: i32, flags: i32, centers: Option<&mut (impl core::ToOutputArray + ?Sized)>) -> Result<f64> {
 	input_array_arg!(data);
 	input_output_array_arg!(best_labels);
-	output_array_arg!(centers);
+	output_array_arg!(nullable centers);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_kmeans_const__InputArrayR_int_const__InputOutputArrayR_TermCriteria_int_int_const__OutputArrayR(data.as_raw__InputArray(), k, best_labels.as_raw__InputOutputArray(), criteria.opencv_as_extern(), attempts, flags, centers.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -4433,7 +4433,7 @@ pub fn kmeans(data: &dyn core::ToInputArray, k: i32, best_labels: &mut dyn core:
 /// ## See also
 /// exp, cartToPolar, polarToCart, phase, pow, sqrt, magnitude
 #[inline]
-pub fn log(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn log(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -4456,7 +4456,7 @@ pub fn log(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> R
 /// ## See also
 /// cartToPolar, polarToCart, phase, sqrt
 #[inline]
-pub fn magnitude(x: &dyn core::ToInputArray, y: &dyn core::ToInputArray, magnitude: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn magnitude(x: &(impl core::ToInputArray + ?Sized), y: &(impl core::ToInputArray + ?Sized), magnitude: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(x);
 	input_array_arg!(y);
 	output_array_arg!(magnitude);
@@ -4550,7 +4550,7 @@ pub fn max_umat_to(src1: &core::UMat, src2: &core::UMat, dst: &mut core::UMat) -
 /// ## See also
 /// min, compare, inRange, minMaxLoc, @ref MatrixExpressions
 #[inline]
-pub fn max(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn max(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
@@ -4597,11 +4597,11 @@ pub fn max_f64_mat(s: f64, a: &core::Mat) -> Result<core::MatExpr> {
 /// ## C++ default parameters
 /// * mask: noArray()
 #[inline]
-pub fn mean_std_dev(src: &dyn core::ToInputArray, mean: &mut dyn core::ToOutputArray, stddev: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray) -> Result<()> {
+pub fn mean_std_dev(src: &(impl core::ToInputArray + ?Sized), mean: &mut (impl core::ToOutputArray + ?Sized), stddev: &mut (impl core::ToOutputArray + ?Sized), mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(mean);
 	output_array_arg!(stddev);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_meanStdDev_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__InputArrayR(src.as_raw__InputArray(), mean.as_raw__OutputArray(), stddev.as_raw__OutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -4625,9 +4625,9 @@ pub fn mean_std_dev(src: &dyn core::ToInputArray, mean: &mut dyn core::ToOutputA
 /// ## C++ default parameters
 /// * mask: noArray()
 #[inline]
-pub fn mean(src: &dyn core::ToInputArray, mask: &dyn core::ToInputArray) -> Result<core::Scalar> {
+pub fn mean(src: &(impl core::ToInputArray + ?Sized), mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<core::Scalar> {
 	input_array_arg!(src);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_mean_const__InputArrayR_const__InputArrayR(src.as_raw__InputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -4663,7 +4663,7 @@ pub fn mean(src: &dyn core::ToInputArray, mask: &dyn core::ToInputArray) -> Resu
 /// * dst: output array of the same size and the same depth as mv[0]; The number of channels will
 /// be the total number of channels in the matrix array.
 #[inline]
-pub fn merge(mv: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn merge(mv: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(mv);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -4704,9 +4704,9 @@ pub fn merge(mv: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) ->
 /// * max_idx: 0
 /// * mask: noArray()
 #[inline]
-pub fn min_max_idx(src: &dyn core::ToInputArray, min_val: Option<&mut f64>, max_val: Option<&mut f64>, min_idx: Option<&mut i32>, max_idx: Option<&mut i32>, mask: &dyn core::ToInputArray) -> Result<()> {
+pub fn min_max_idx(src: &(impl core::ToInputArray + ?Sized), min_val: Option<&mut f64>, max_val: Option<&mut f64>, min_idx: Option<&mut i32>, max_idx: Option<&mut i32>, mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(src);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_minMaxIdx_const__InputArrayR_doubleX_doubleX_intX_intX_const__InputArrayR(src.as_raw__InputArray(), min_val.map_or(::core::ptr::null_mut(), |min_val| min_val as *mut _), max_val.map_or(::core::ptr::null_mut(), |max_val| max_val as *mut _), min_idx.map_or(::core::ptr::null_mut(), |min_idx| min_idx as *mut _), max_idx.map_or(::core::ptr::null_mut(), |max_idx| max_idx as *mut _), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -4782,9 +4782,9 @@ pub fn min_max_loc_sparse(a: &core::SparseMat, min_val: Option<&mut f64>, max_va
 /// * max_loc: 0
 /// * mask: noArray()
 #[inline]
-pub fn min_max_loc(src: &dyn core::ToInputArray, min_val: Option<&mut f64>, max_val: Option<&mut f64>, min_loc: Option<&mut core::Point>, max_loc: Option<&mut core::Point>, mask: &dyn core::ToInputArray) -> Result<()> {
+pub fn min_max_loc(src: &(impl core::ToInputArray + ?Sized), min_val: Option<&mut f64>, max_val: Option<&mut f64>, min_loc: Option<&mut core::Point>, max_loc: Option<&mut core::Point>, mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(src);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_minMaxLoc_const__InputArrayR_doubleX_doubleX_PointX_PointX_const__InputArrayR(src.as_raw__InputArray(), min_val.map_or(::core::ptr::null_mut(), |min_val| min_val as *mut _), max_val.map_or(::core::ptr::null_mut(), |max_val| max_val as *mut _), min_loc.map_or(::core::ptr::null_mut(), |min_loc| min_loc as *mut _), max_loc.map_or(::core::ptr::null_mut(), |max_loc| max_loc as *mut _), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -4875,7 +4875,7 @@ pub fn min_umat_to(src1: &core::UMat, src2: &core::UMat, dst: &mut core::UMat) -
 /// ## See also
 /// max, compare, inRange, minMaxLoc
 #[inline]
-pub fn min(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn min(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
@@ -4956,7 +4956,7 @@ pub fn min_f64_mat(s: f64, a: &core::Mat) -> Result<core::MatExpr> {
 /// filled with zero .
 /// * npairs: number of index pairs in fromTo.
 #[inline]
-pub fn mix_channels(src: &dyn core::ToInputArray, dst: &mut dyn core::ToInputOutputArray, from_to: &[i32]) -> Result<()> {
+pub fn mix_channels(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToInputOutputArray + ?Sized), from_to: &[i32]) -> Result<()> {
 	input_array_arg!(src);
 	input_output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -5025,7 +5025,7 @@ pub fn mix_channels(src: &dyn core::ToInputArray, dst: &mut dyn core::ToInputOut
 /// channels; as a special case, when fromTo[k\*2] is negative, the corresponding output channel is
 /// filled with zero .
 #[inline]
-pub fn mix_channels_vec(src: &dyn core::ToInputArray, dst: &mut dyn core::ToInputOutputArray, from_to: &core::Vector<i32>) -> Result<()> {
+pub fn mix_channels_vec(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToInputOutputArray + ?Sized), from_to: &core::Vector<i32>) -> Result<()> {
 	input_array_arg!(src);
 	input_output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -5056,7 +5056,7 @@ pub fn mix_channels_vec(src: &dyn core::ToInputArray, dst: &mut dyn core::ToInpu
 /// ## C++ default parameters
 /// * conj_b: false
 #[inline]
-pub fn mul_spectrums(a: &dyn core::ToInputArray, b: &dyn core::ToInputArray, c: &mut dyn core::ToOutputArray, flags: i32, conj_b: bool) -> Result<()> {
+pub fn mul_spectrums(a: &(impl core::ToInputArray + ?Sized), b: &(impl core::ToInputArray + ?Sized), c: &mut (impl core::ToOutputArray + ?Sized), flags: i32, conj_b: bool) -> Result<()> {
 	input_array_arg!(a);
 	input_array_arg!(b);
 	output_array_arg!(c);
@@ -5102,10 +5102,10 @@ pub fn mul_spectrums(a: &dyn core::ToInputArray, b: &dyn core::ToInputArray, c:
 /// * scale: 1
 /// * dtype: -1
 #[inline]
-pub fn mul_transposed(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, a_ta: bool, delta: &dyn core::ToInputArray, scale: f64, dtype: i32) -> Result<()> {
+pub fn mul_transposed(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), a_ta: bool, delta: Option<&(impl core::ToInputArray + ?Sized)>, scale: f64, dtype: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
-	input_array_arg!(delta);
+	input_array_arg!(nullable delta);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_mulTransposed_const__InputArrayR_const__OutputArrayR_bool_const__InputArrayR_double_int(src.as_raw__InputArray(), dst.as_raw__OutputArray(), a_ta, delta.as_raw__InputArray(), scale, dtype, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -5141,7 +5141,7 @@ pub fn mul_transposed(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutput
 /// * scale: 1
 /// * dtype: -1
 #[inline]
-pub fn multiply(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, scale: f64, dtype: i32) -> Result<()> {
+pub fn multiply(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), scale: f64, dtype: i32) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
@@ -5200,10 +5200,10 @@ pub fn norm_sparse(src: &core::SparseMat, norm_type: i32) -> Result<f64> {
 /// * norm_type: NORM_L2
 /// * mask: noArray()
 #[inline]
-pub fn norm2(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, norm_type: i32, mask: &dyn core::ToInputArray) -> Result<f64> {
+pub fn norm2(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), norm_type: i32, mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<f64> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_norm_const__InputArrayR_const__InputArrayR_int_const__InputArrayR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), norm_type, mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -5252,9 +5252,9 @@ pub fn norm2(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, norm_
 /// * norm_type: NORM_L2
 /// * mask: noArray()
 #[inline]
-pub fn norm(src1: &dyn core::ToInputArray, norm_type: i32, mask: &dyn core::ToInputArray) -> Result<f64> {
+pub fn norm(src1: &(impl core::ToInputArray + ?Sized), norm_type: i32, mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<f64> {
 	input_array_arg!(src1);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_norm_const__InputArrayR_int_const__InputArrayR(src1.as_raw__InputArray(), norm_type, mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -5407,10 +5407,10 @@ pub fn normalize_sparse(src: &core::SparseMat, dst: &mut core::SparseMat, alpha:
 /// * dtype: -1
 /// * mask: noArray()
 #[inline]
-pub fn normalize(src: &dyn core::ToInputArray, dst: &mut dyn core::ToInputOutputArray, alpha: f64, beta: f64, norm_type: i32, dtype: i32, mask: &dyn core::ToInputArray) -> Result<()> {
+pub fn normalize(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToInputOutputArray + ?Sized), alpha: f64, beta: f64, norm_type: i32, dtype: i32, mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(src);
 	input_output_array_arg!(dst);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_normalize_const__InputArrayR_const__InputOutputArrayR_double_double_int_int_const__InputArrayR(src.as_raw__InputArray(), dst.as_raw__InputOutputArray(), alpha, beta, norm_type, dtype, mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -5440,7 +5440,7 @@ pub unsafe fn attach_context(platform_name: &str, platform_id: *mut c_void, cont
 }
 
 #[inline]
-pub fn build_options_add_matrix_description(build_options: &mut String, name: &str, _m: &dyn core::ToInputArray) -> Result<()> {
+pub fn build_options_add_matrix_description(build_options: &mut String, name: &str, _m: &(impl core::ToInputArray + ?Sized)) -> Result<()> {
 	string_arg_output_send!(via build_options_via);
 	extern_container_arg!(name);
 	input_array_arg!(_m);
@@ -5463,16 +5463,16 @@ pub fn build_options_add_matrix_description(build_options: &mut String, name: &s
 /// * src9: noArray()
 /// * strat: OCL_VECTOR_DEFAULT
 #[inline]
-pub fn check_optimal_vector_width(vector_widths: &i32, src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, src3: &dyn core::ToInputArray, src4: &dyn core::ToInputArray, src5: &dyn core::ToInputArray, src6: &dyn core::ToInputArray, src7: &dyn core::ToInputArray, src8: &dyn core::ToInputArray, src9: &dyn core::ToInputArray, strat: core::OclVectorStrategy) -> Result<i32> {
+pub fn check_optimal_vector_width(vector_widths: &i32, src1: &(impl core::ToInputArray + ?Sized), src2: Option<&(impl core::ToInputArray + ?Sized)>, src3: Option<&(impl core::ToInputArray + ?Sized)>, src4: Option<&(impl core::ToInputArray + ?Sized)>, src5: Option<&(impl core::ToInputArray + ?Sized)>, src6: Option<&(impl core::ToInputArray + ?Sized)>, src7: Option<&(impl core::ToInputArray + ?Sized)>, src8: Option<&(impl core::ToInputArray + ?Sized)>, src9: Option<&(impl core::ToInputArray + ?Sized)>, strat: core::OclVectorStrategy) -> Result<i32> {
 	input_array_arg!(src1);
-	input_array_arg!(src2);
-	input_array_arg!(src3);
-	input_array_arg!(src4);
-	input_array_arg!(src5);
-	input_array_arg!(src6);
-	input_array_arg!(src7);
-	input_array_arg!(src8);
-	input_array_arg!(src9);
+	input_array_arg!(nullable src2);
+	input_array_arg!(nullable src3);
+	input_array_arg!(nullable src4);
+	input_array_arg!(nullable src5);
+	input_array_arg!(nullable src6);
+	input_array_arg!(nullable src7);
+	input_array_arg!(nullable src8);
+	input_array_arg!(nullable src9);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_ocl_checkOptimalVectorWidth_const_intX_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_OclVectorStrategy(vector_widths, src1.as_raw__InputArray(), src2.as_raw__InputArray(), src3.as_raw__InputArray(), src4.as_raw__InputArray(), src5.as_raw__InputArray(), src6.as_raw__InputArray(), src7.as_raw__InputArray(), src8.as_raw__InputArray(), src9.as_raw__InputArray(), strat, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -5494,7 +5494,7 @@ pub fn check_optimal_vector_width(vector_widths: &i32, src1: &dyn core::ToInputA
 /// * type: OpenCV type of image
 /// * dst: destination UMat
 #[inline]
-pub unsafe fn convert_from_buffer(cl_mem_buffer: *mut c_void, step: size_t, rows: i32, cols: i32, typ: i32, dst: &mut core::UMat) -> Result<()> {
+pub unsafe fn convert_from_buffer(cl_mem_buffer: *mut c_void, step: usize, rows: i32, cols: i32, typ: i32, dst: &mut core::UMat) -> Result<()> {
 	return_send!(via ocvrs_return);
 	{ sys::cv_ocl_convertFromBuffer_voidX_size_t_int_int_int_UMatR(cl_mem_buffer, step, rows, cols, typ, dst.as_raw_mut_UMat(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(ocvrs_return => ret);
@@ -5599,7 +5599,7 @@ pub fn have_svm() -> Result<bool> {
 /// * ddepth: -1
 /// * name: NULL
 #[inline]
-pub fn kernel_to_str(_kernel: &dyn core::ToInputArray, ddepth: i32, name: &str) -> Result<String> {
+pub fn kernel_to_str(_kernel: &(impl core::ToInputArray + ?Sized), ddepth: i32, name: &str) -> Result<String> {
 	input_array_arg!(_kernel);
 	extern_container_arg!(name);
 	return_send!(via ocvrs_return);
@@ -5630,16 +5630,16 @@ pub fn memop_type_to_str(t: i32) -> Result<String> {
 /// * src8: noArray()
 /// * src9: noArray()
 #[inline]
-pub fn predict_optimal_vector_width_max(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, src3: &dyn core::ToInputArray, src4: &dyn core::ToInputArray, src5: &dyn core::ToInputArray, src6: &dyn core::ToInputArray, src7: &dyn core::ToInputArray, src8: &dyn core::ToInputArray, src9: &dyn core::ToInputArray) -> Result<i32> {
+pub fn predict_optimal_vector_width_max(src1: &(impl core::ToInputArray + ?Sized), src2: Option<&(impl core::ToInputArray + ?Sized)>, src3: Option<&(impl core::ToInputArray + ?Sized)>, src4: Option<&(impl core::ToInputArray + ?Sized)>, src5: Option<&(impl core::ToInputArray + ?Sized)>, src6: Option<&(impl core::ToInputArray + ?Sized)>, src7: Option<&(impl core::ToInputArray + ?Sized)>, src8: Option<&(impl core::ToInputArray + ?Sized)>, src9: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<i32> {
 	input_array_arg!(src1);
-	input_array_arg!(src2);
-	input_array_arg!(src3);
-	input_array_arg!(src4);
-	input_array_arg!(src5);
-	input_array_arg!(src6);
-	input_array_arg!(src7);
-	input_array_arg!(src8);
-	input_array_arg!(src9);
+	input_array_arg!(nullable src2);
+	input_array_arg!(nullable src3);
+	input_array_arg!(nullable src4);
+	input_array_arg!(nullable src5);
+	input_array_arg!(nullable src6);
+	input_array_arg!(nullable src7);
+	input_array_arg!(nullable src8);
+	input_array_arg!(nullable src9);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_ocl_predictOptimalVectorWidthMax_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), src3.as_raw__InputArray(), src4.as_raw__InputArray(), src5.as_raw__InputArray(), src6.as_raw__InputArray(), src7.as_raw__InputArray(), src8.as_raw__InputArray(), src9.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -5658,16 +5658,16 @@ pub fn predict_optimal_vector_width_max(src1: &dyn core::ToInputArray, src2: &dy
 /// * src9: noArray()
 /// * strat: OCL_VECTOR_DEFAULT
 #[inline]
-pub fn predict_optimal_vector_width(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, src3: &dyn core::ToInputArray, src4: &dyn core::ToInputArray, src5: &dyn core::ToInputArray, src6: &dyn core::ToInputArray, src7: &dyn core::ToInputArray, src8: &dyn core::ToInputArray, src9: &dyn core::ToInputArray, strat: core::OclVectorStrategy) -> Result<i32> {
+pub fn predict_optimal_vector_width(src1: &(impl core::ToInputArray + ?Sized), src2: Option<&(impl core::ToInputArray + ?Sized)>, src3: Option<&(impl core::ToInputArray + ?Sized)>, src4: Option<&(impl core::ToInputArray + ?Sized)>, src5: Option<&(impl core::ToInputArray + ?Sized)>, src6: Option<&(impl core::ToInputArray + ?Sized)>, src7: Option<&(impl core::ToInputArray + ?Sized)>, src8: Option<&(impl core::ToInputArray + ?Sized)>, src9: Option<&(impl core::ToInputArray + ?Sized)>, strat: core::OclVectorStrategy) -> Result<i32> {
 	input_array_arg!(src1);
-	input_array_arg!(src2);
-	input_array_arg!(src3);
-	input_array_arg!(src4);
-	input_array_arg!(src5);
-	input_array_arg!(src6);
-	input_array_arg!(src7);
-	input_array_arg!(src8);
-	input_array_arg!(src9);
+	input_array_arg!(nullable src2);
+	input_array_arg!(nullable src3);
+	input_array_arg!(nullable src4);
+	input_array_arg!(nullable src5);
+	input_array_arg!(nullable src6);
+	input_array_arg!(nullable src7);
+	input_array_arg!(nullable src8);
+	input_array_arg!(nullable src9);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_ocl_predictOptimalVectorWidth_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_OclVectorStrategy(src1.as_raw__InputArray(), src2.as_raw__InputArray(), src3.as_raw__InputArray(), src4.as_raw__InputArray(), src5.as_raw__InputArray(), src6.as_raw__InputArray(), src7.as_raw__InputArray(), src8.as_raw__InputArray(), src9.as_raw__InputArray(), strat, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -5718,7 +5718,7 @@ pub fn vecop_type_to_str(t: i32) -> Result<String> {
 /// * texture: - source Texture2D object.
 /// * dst: - destination OutputArray.
 #[inline]
-pub fn convert_from_gl_texture_2d(texture: &core::Texture2D, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn convert_from_gl_texture_2d(texture: &core::Texture2D, dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_ogl_convertFromGLTexture2D_const_Texture2DR_const__OutputArrayR(texture.as_raw_Texture2D(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
@@ -5732,7 +5732,7 @@ pub fn convert_from_gl_texture_2d(texture: &core::Texture2D, dst: &mut dyn core:
 /// * src: - source InputArray.
 /// * texture: - destination Texture2D object.
 #[inline]
-pub fn convert_to_gl_texture_2d(src: &dyn core::ToInputArray, texture: &mut core::Texture2D) -> Result<()> {
+pub fn convert_to_gl_texture_2d(src: &(impl core::ToInputArray + ?Sized), texture: &mut core::Texture2D) -> Result<()> {
 	input_array_arg!(src);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_ogl_convertToGLTexture2D_const__InputArrayR_Texture2DR(src.as_raw__InputArray(), texture.as_raw_mut_Texture2D(), ocvrs_return.as_mut_ptr()) };
@@ -5796,7 +5796,7 @@ pub fn initialize_context_from_gl() -> Result<core::Context> {
 /// * mode: POINTS
 /// * color: Scalar::all(255)
 #[inline]
-pub fn render_2(arr: &core::Arrays, indices: &dyn core::ToInputArray, mode: i32, color: core::Scalar) -> Result<()> {
+pub fn render_2(arr: &core::Arrays, indices: &(impl core::ToInputArray + ?Sized), mode: i32, color: core::Scalar) -> Result<()> {
 	input_array_arg!(indices);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_ogl_render_const_ArraysR_const__InputArrayR_int_Scalar(arr.as_raw_Arrays(), indices.as_raw__InputArray(), mode, color.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
@@ -6525,7 +6525,7 @@ pub fn parallel_for_(range: &core::Range, body: &dyn core::ParallelLoopBody, nst
 /// ## C++ default parameters
 /// * val: 0
 #[inline]
-pub fn patch_na_ns(a: &mut dyn core::ToInputOutputArray, val: f64) -> Result<()> {
+pub fn patch_na_ns(a: &mut (impl core::ToInputOutputArray + ?Sized), val: f64) -> Result<()> {
 	input_output_array_arg!(a);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_patchNaNs_const__InputOutputArrayR_double(a.as_raw__InputOutputArray(), val, ocvrs_return.as_mut_ptr()) };
@@ -6562,7 +6562,7 @@ pub fn patch_na_ns(a: &mut dyn core::ToInputOutputArray, val: f64) -> Result<()>
 /// ## See also
 /// transform, warpPerspective, getPerspectiveTransform, findHomography
 #[inline]
-pub fn perspective_transform(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, m: &dyn core::ToInputArray) -> Result<()> {
+pub fn perspective_transform(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), m: &(impl core::ToInputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	input_array_arg!(m);
@@ -6593,7 +6593,7 @@ pub fn perspective_transform(src: &dyn core::ToInputArray, dst: &mut dyn core::T
 /// ## C++ default parameters
 /// * angle_in_degrees: false
 #[inline]
-pub fn phase(x: &dyn core::ToInputArray, y: &dyn core::ToInputArray, angle: &mut dyn core::ToOutputArray, angle_in_degrees: bool) -> Result<()> {
+pub fn phase(x: &(impl core::ToInputArray + ?Sized), y: &(impl core::ToInputArray + ?Sized), angle: &mut (impl core::ToOutputArray + ?Sized), angle_in_degrees: bool) -> Result<()> {
 	input_array_arg!(x);
 	input_array_arg!(y);
 	output_array_arg!(angle);
@@ -6629,7 +6629,7 @@ pub fn phase(x: &dyn core::ToInputArray, y: &dyn core::ToInputArray, angle: &mut
 /// ## C++ default parameters
 /// * angle_in_degrees: false
 #[inline]
-pub fn polar_to_cart(magnitude: &dyn core::ToInputArray, angle: &dyn core::ToInputArray, x: &mut dyn core::ToOutputArray, y: &mut dyn core::ToOutputArray, angle_in_degrees: bool) -> Result<()> {
+pub fn polar_to_cart(magnitude: &(impl core::ToInputArray + ?Sized), angle: &(impl core::ToInputArray + ?Sized), x: &mut (impl core::ToOutputArray + ?Sized), y: &mut (impl core::ToOutputArray + ?Sized), angle_in_degrees: bool) -> Result<()> {
 	input_array_arg!(magnitude);
 	input_array_arg!(angle);
 	output_array_arg!(x);
@@ -6667,7 +6667,7 @@ pub fn polar_to_cart(magnitude: &dyn core::ToInputArray, angle: &dyn core::ToInp
 /// ## See also
 /// sqrt, exp, log, cartToPolar, polarToCart
 #[inline]
-pub fn pow(src: &dyn core::ToInputArray, power: f64, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn pow(src: &(impl core::ToInputArray + ?Sized), power: f64, dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -6694,7 +6694,7 @@ pub fn pow(src: &dyn core::ToInputArray, power: f64, dst: &mut dyn core::ToOutpu
 /// * iter_factor: 1.
 /// * rng: 0
 #[inline]
-pub fn rand_shuffle(dst: &mut dyn core::ToInputOutputArray, iter_factor: f64, rng: &mut core::RNG) -> Result<()> {
+pub fn rand_shuffle(dst: &mut (impl core::ToInputOutputArray + ?Sized), iter_factor: f64, rng: &mut core::RNG) -> Result<()> {
 	input_output_array_arg!(dst);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_randShuffle_const__InputOutputArrayR_double_RNGX(dst.as_raw__InputOutputArray(), iter_factor, rng.as_raw_mut_RNG(), ocvrs_return.as_mut_ptr()) };
@@ -6716,7 +6716,7 @@ pub fn rand_shuffle(dst: &mut dyn core::ToInputOutputArray, iter_factor: f64, rn
 /// ## See also
 /// RNG, randu
 #[inline]
-pub fn randn(dst: &mut dyn core::ToInputOutputArray, mean: &dyn core::ToInputArray, stddev: &dyn core::ToInputArray) -> Result<()> {
+pub fn randn(dst: &mut (impl core::ToInputOutputArray + ?Sized), mean: &(impl core::ToInputArray + ?Sized), stddev: &(impl core::ToInputArray + ?Sized)) -> Result<()> {
 	input_output_array_arg!(dst);
 	input_array_arg!(mean);
 	input_array_arg!(stddev);
@@ -6739,7 +6739,7 @@ pub fn randn(dst: &mut dyn core::ToInputOutputArray, mean: &dyn core::ToInputArr
 /// ## See also
 /// RNG, randn, theRNG
 #[inline]
-pub fn randu(dst: &mut dyn core::ToInputOutputArray, low: &dyn core::ToInputArray, high: &dyn core::ToInputArray) -> Result<()> {
+pub fn randu(dst: &mut (impl core::ToInputOutputArray + ?Sized), low: &(impl core::ToInputArray + ?Sized), high: &(impl core::ToInputArray + ?Sized)) -> Result<()> {
 	input_output_array_arg!(dst);
 	input_array_arg!(low);
 	input_array_arg!(high);
@@ -6771,9 +6771,9 @@ pub fn read_keypoint(node: &core::FileNode, value: &mut core::KeyPoint, default_
 /// ## C++ default parameters
 /// * default_mat: Mat()
 #[inline]
-pub fn read_mat(node: &core::FileNode, mat: &mut core::Mat, default_mat: &core::Mat) -> Result<()> {
+pub fn read_mat(node: &core::FileNode, mat: &mut core::Mat, default_mat: Option<&core::Mat>) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_read_const_FileNodeR_MatR_const_MatR(node.as_raw_FileNode(), mat.as_raw_mut_Mat(), default_mat.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+	unsafe { sys::cv_read_const_FileNodeR_MatR_const_MatR(node.as_raw_FileNode(), mat.as_raw_mut_Mat(), default_mat.map_or(::core::ptr::null(), |default_mat| default_mat.as_raw_Mat()), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -6782,9 +6782,9 @@ pub fn read_mat(node: &core::FileNode, mat: &mut core::Mat, default_mat: &core::
 /// ## C++ default parameters
 /// * default_mat: SparseMat()
 #[inline]
-pub fn read_sparsemat(node: &core::FileNode, mat: &mut core::SparseMat, default_mat: &core::SparseMat) -> Result<()> {
+pub fn read_sparsemat(node: &core::FileNode, mat: &mut core::SparseMat, default_mat: Option<&core::SparseMat>) -> Result<()> {
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_read_const_FileNodeR_SparseMatR_const_SparseMatR(node.as_raw_FileNode(), mat.as_raw_mut_SparseMat(), default_mat.as_raw_SparseMat(), ocvrs_return.as_mut_ptr()) };
+	unsafe { sys::cv_read_const_FileNodeR_SparseMatR_const_SparseMatR(node.as_raw_FileNode(), mat.as_raw_mut_SparseMat(), default_mat.map_or(::core::ptr::null(), |default_mat| default_mat.as_raw_SparseMat()), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
@@ -6868,7 +6868,7 @@ pub fn read_keypoint_vec_legacy(node: &core::FileNode, keypoints: &mut core::Vec
 /// ## C++ default parameters
 /// * last_index: false
 #[inline]
-pub fn reduce_arg_max(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, axis: i32, last_index: bool) -> Result<()> {
+pub fn reduce_arg_max(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), axis: i32, last_index: bool) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -6898,7 +6898,7 @@ pub fn reduce_arg_max(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutput
 /// ## C++ default parameters
 /// * last_index: false
 #[inline]
-pub fn reduce_arg_min(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, axis: i32, last_index: bool) -> Result<()> {
+pub fn reduce_arg_min(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), axis: i32, last_index: bool) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -6937,7 +6937,7 @@ pub fn reduce_arg_min(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutput
 /// ## C++ default parameters
 /// * dtype: -1
 #[inline]
-pub fn reduce(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, dim: i32, rtype: i32, dtype: i32) -> Result<()> {
+pub fn reduce(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), dim: i32, rtype: i32, dtype: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -6994,7 +6994,7 @@ pub fn repeat(src: &core::Mat, ny: i32, nx: i32) -> Result<core::Mat> {
 /// ## See also
 /// cv::reduce
 #[inline]
-pub fn repeat_to(src: &dyn core::ToInputArray, ny: i32, nx: i32, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn repeat_to(src: &(impl core::ToInputArray + ?Sized), ny: i32, nx: i32, dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -7017,7 +7017,7 @@ pub fn repeat_to(src: &dyn core::ToInputArray, ny: i32, nx: i32, dst: &mut dyn c
 /// ## See also
 /// transpose , repeat , completeSymm, flip, RotateFlags
 #[inline]
-pub fn rotate(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, rotate_code: i32) -> Result<()> {
+pub fn rotate(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), rotate_code: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -7134,7 +7134,7 @@ pub fn find_file(relative_path: &str, required: bool, silent_mode: bool) -> Resu
 /// ## See also
 /// add, addWeighted, subtract, Mat::dot, Mat::convertTo
 #[inline]
-pub fn scale_add(src1: &dyn core::ToInputArray, alpha: f64, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn scale_add(src1: &(impl core::ToInputArray + ?Sized), alpha: f64, src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
@@ -7181,7 +7181,7 @@ pub fn set_break_on_error(flag: bool) -> Result<bool> {
 /// ## C++ default parameters
 /// * s: Scalar(1)
 #[inline]
-pub fn set_identity(mtx: &mut dyn core::ToInputOutputArray, s: core::Scalar) -> Result<()> {
+pub fn set_identity(mtx: &mut (impl core::ToInputOutputArray + ?Sized), s: core::Scalar) -> Result<()> {
 	input_output_array_arg!(mtx);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_setIdentity_const__InputOutputArrayR_const_ScalarR(mtx.as_raw__InputOutputArray(), &s, ocvrs_return.as_mut_ptr()) };
@@ -7291,7 +7291,7 @@ pub fn set_use_optimized(onoff: bool) -> Result<()> {
 /// ## Returns
 /// number of real roots. It can be 0, 1 or 2.
 #[inline]
-pub fn solve_cubic(coeffs: &dyn core::ToInputArray, roots: &mut dyn core::ToOutputArray) -> Result<i32> {
+pub fn solve_cubic(coeffs: &(impl core::ToInputArray + ?Sized), roots: &mut (impl core::ToOutputArray + ?Sized)) -> Result<i32> {
 	input_array_arg!(coeffs);
 	output_array_arg!(roots);
 	return_send!(via ocvrs_return);
@@ -7331,7 +7331,7 @@ pub fn solve_cubic(coeffs: &dyn core::ToInputArray, roots: &mut dyn core::ToOutp
 /// ## Returns
 /// One of cv::SolveLPResult
 #[inline]
-pub fn solve_lp(func: &dyn core::ToInputArray, constr: &dyn core::ToInputArray, z: &mut dyn core::ToOutputArray) -> Result<i32> {
+pub fn solve_lp(func: &(impl core::ToInputArray + ?Sized), constr: &(impl core::ToInputArray + ?Sized), z: &mut (impl core::ToOutputArray + ?Sized)) -> Result<i32> {
 	input_array_arg!(func);
 	input_array_arg!(constr);
 	output_array_arg!(z);
@@ -7354,7 +7354,7 @@ pub fn solve_lp(func: &dyn core::ToInputArray, constr: &dyn core::ToInputArray,
 /// ## C++ default parameters
 /// * max_iters: 300
 #[inline]
-pub fn solve_poly(coeffs: &dyn core::ToInputArray, roots: &mut dyn core::ToOutputArray, max_iters: i32) -> Result<f64> {
+pub fn solve_poly(coeffs: &(impl core::ToInputArray + ?Sized), roots: &mut (impl core::ToOutputArray + ?Sized), max_iters: i32) -> Result<f64> {
 	input_array_arg!(coeffs);
 	output_array_arg!(roots);
 	return_send!(via ocvrs_return);
@@ -7392,7 +7392,7 @@ pub fn solve_poly(coeffs: &dyn core::ToInputArray, roots: &mut dyn core::ToOutpu
 /// ## C++ default parameters
 /// * flags: DECOMP_LU
 #[inline]
-pub fn solve(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, flags: i32) -> Result<bool> {
+pub fn solve(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), flags: i32) -> Result<bool> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
@@ -7424,7 +7424,7 @@ pub fn solve(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst:
 /// ## See also
 /// sort, randShuffle
 #[inline]
-pub fn sort_idx(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, flags: i32) -> Result<()> {
+pub fn sort_idx(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), flags: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -7449,7 +7449,7 @@ pub fn sort_idx(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray,
 /// ## See also
 /// sortIdx, randShuffle
 #[inline]
-pub fn sort(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, flags: i32) -> Result<()> {
+pub fn sort(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), flags: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -7506,7 +7506,7 @@ pub fn split_slice(src: &core::Mat, mvbegin: &mut core::Mat) -> Result<()> {
 /// * m: input multi-channel array.
 /// * mv: output vector of arrays; the arrays themselves are reallocated, if needed.
 #[inline]
-pub fn split(m: &dyn core::ToInputArray, mv: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn split(m: &(impl core::ToInputArray + ?Sized), mv: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(m);
 	output_array_arg!(mv);
 	return_send!(via ocvrs_return);
@@ -7526,7 +7526,7 @@ pub fn split(m: &dyn core::ToInputArray, mv: &mut dyn core::ToOutputArray) -> Re
 /// * src: input floating-point array.
 /// * dst: output array of the same size and type as src.
 #[inline]
-pub fn sqrt(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn sqrt(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -7581,11 +7581,11 @@ pub fn sqrt(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) ->
 /// * mask: noArray()
 /// * dtype: -1
 #[inline]
-pub fn subtract(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray, dtype: i32) -> Result<()> {
+pub fn subtract(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), mask: Option<&(impl core::ToInputArray + ?Sized)>, dtype: i32) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_subtract_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR_int(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), dtype, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -7602,7 +7602,7 @@ pub fn subtract(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, ds
 /// ## See also
 /// countNonZero, mean, meanStdDev, norm, minMaxLoc, reduce
 #[inline]
-pub fn sum_elems(src: &dyn core::ToInputArray) -> Result<core::Scalar> {
+pub fn sum_elems(src: &(impl core::ToInputArray + ?Sized)) -> Result<core::Scalar> {
 	input_array_arg!(src);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_sum_const__InputArrayR(src.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
@@ -7673,7 +7673,7 @@ pub fn the_rng() -> Result<core::RNG> {
 /// ## Parameters
 /// * mtx: input matrix.
 #[inline]
-pub fn trace(mtx: &dyn core::ToInputArray) -> Result<core::Scalar> {
+pub fn trace(mtx: &(impl core::ToInputArray + ?Sized)) -> Result<core::Scalar> {
 	input_array_arg!(mtx);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_trace_const__InputArrayR(mtx.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
@@ -7708,7 +7708,7 @@ pub fn trace(mtx: &dyn core::ToInputArray) -> Result<core::Scalar> {
 /// ## See also
 /// perspectiveTransform, getAffineTransform, estimateAffine2D, warpAffine, warpPerspective
 #[inline]
-pub fn transform(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, m: &dyn core::ToInputArray) -> Result<()> {
+pub fn transform(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), m: &(impl core::ToInputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	input_array_arg!(m);
@@ -7729,7 +7729,7 @@ pub fn transform(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 /// The i’th axis of dst will correspond to the axis numbered order[i] of the input.
 /// * dst: output array of the same type as src.
 #[inline]
-pub fn transpose_nd(src: &dyn core::ToInputArray, order: &core::Vector<i32>, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn transpose_nd(src: &(impl core::ToInputArray + ?Sized), order: &core::Vector<i32>, dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -7750,7 +7750,7 @@ pub fn transpose_nd(src: &dyn core::ToInputArray, order: &core::Vector<i32>, dst
 /// * src: input array.
 /// * dst: output array of the same type as src.
 #[inline]
-pub fn transpose(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn transpose(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -7835,7 +7835,7 @@ pub fn dump_float(argument: f32) -> Result<String> {
 }
 
 #[inline]
-pub fn dump_input_array_of_arrays(argument: &dyn core::ToInputArray) -> Result<String> {
+pub fn dump_input_array_of_arrays(argument: &(impl core::ToInputArray + ?Sized)) -> Result<String> {
 	input_array_arg!(argument);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_utils_dumpInputArrayOfArrays_const__InputArrayR(argument.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
@@ -7846,7 +7846,7 @@ pub fn dump_input_array_of_arrays(argument: &dyn core::ToInputArray) -> Result<S
 }
 
 #[inline]
-pub fn dump_input_array(argument: &dyn core::ToInputArray) -> Result<String> {
+pub fn dump_input_array(argument: &(impl core::ToInputArray + ?Sized)) -> Result<String> {
 	input_array_arg!(argument);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_utils_dumpInputArray_const__InputArrayR(argument.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
@@ -7857,7 +7857,7 @@ pub fn dump_input_array(argument: &dyn core::ToInputArray) -> Result<String> {
 }
 
 #[inline]
-pub fn dump_input_output_array_of_arrays(argument: &mut dyn core::ToInputOutputArray) -> Result<String> {
+pub fn dump_input_output_array_of_arrays(argument: &mut (impl core::ToInputOutputArray + ?Sized)) -> Result<String> {
 	input_output_array_arg!(argument);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_utils_dumpInputOutputArrayOfArrays_const__InputOutputArrayR(argument.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr()) };
@@ -7868,7 +7868,7 @@ pub fn dump_input_output_array_of_arrays(argument: &mut dyn core::ToInputOutputA
 }
 
 #[inline]
-pub fn dump_input_output_array(argument: &mut dyn core::ToInputOutputArray) -> Result<String> {
+pub fn dump_input_output_array(argument: &mut (impl core::ToInputOutputArray + ?Sized)) -> Result<String> {
 	input_output_array_arg!(argument);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_utils_dumpInputOutputArray_const__InputOutputArrayR(argument.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr()) };
@@ -7919,7 +7919,7 @@ pub fn dump_rotated_rect(argument: &core::RotatedRect) -> Result<String> {
 }
 
 #[inline]
-pub fn dump_size_t(argument: size_t) -> Result<String> {
+pub fn dump_size_t(argument: usize) -> Result<String> {
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_utils_dumpSizeT_size_t(argument, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -7990,7 +7990,7 @@ pub fn get_cache_directory_for_downloads() -> Result<String> {
 }
 
 #[inline]
-pub fn generate_vector_of_int(len: size_t, vec: &mut core::Vector<i32>) -> Result<()> {
+pub fn generate_vector_of_int(len: usize, vec: &mut core::Vector<i32>) -> Result<()> {
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_utils_generateVectorOfInt_size_t_vectorLintGR(len, vec.as_raw_mut_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -7999,7 +7999,7 @@ pub fn generate_vector_of_int(len: size_t, vec: &mut core::Vector<i32>) -> Resul
 }
 
 #[inline]
-pub fn generate_vector_of_mat(len: size_t, rows: i32, cols: i32, dtype: i32, vec: &mut core::Vector<core::Mat>) -> Result<()> {
+pub fn generate_vector_of_mat(len: usize, rows: i32, cols: i32, dtype: i32, vec: &mut core::Vector<core::Mat>) -> Result<()> {
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_utils_generateVectorOfMat_size_t_int_int_int_vectorLMatGR(len, rows, cols, dtype, vec.as_raw_mut_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -8008,7 +8008,7 @@ pub fn generate_vector_of_mat(len: size_t, rows: i32, cols: i32, dtype: i32, vec
 }
 
 #[inline]
-pub fn generate_vector_of_rect(len: size_t, vec: &mut core::Vector<core::Rect>) -> Result<()> {
+pub fn generate_vector_of_rect(len: usize, vec: &mut core::Vector<core::Rect>) -> Result<()> {
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_utils_generateVectorOfRect_size_t_vectorLRectGR(len, vec.as_raw_mut_VectorOfRect(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -8122,7 +8122,7 @@ pub fn test_echo_boolean_function(flag: bool) -> Result<bool> {
 }
 
 #[inline]
-pub fn test_async_array(argument: &dyn core::ToInputArray) -> Result<core::AsyncArray> {
+pub fn test_async_array(argument: &(impl core::ToInputArray + ?Sized)) -> Result<core::AsyncArray> {
 	input_array_arg!(argument);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_utils_testAsyncArray_const__InputArrayR(argument.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
@@ -8222,7 +8222,7 @@ pub fn test_rotated_rect(x: f32, y: f32, w: f32, h: f32, angle: f32) -> Result<c
 /// * size: - size of image represented by VASurfaceID object.
 /// * dst: - destination OutputArray.
 #[inline]
-pub unsafe fn convert_from_va_surface(display: core::va_display, surface: core::va_surface_id, size: core::Size, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+pub unsafe fn convert_from_va_surface(display: core::va_display, surface: core::va_surface_id, size: core::Size, dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
 	{ sys::cv_va_intel_convertFromVASurface_VADisplay_VASurfaceID_Size_const__OutputArrayR(display, surface, size.opencv_as_extern(), dst.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
@@ -8238,7 +8238,7 @@ pub unsafe fn convert_from_va_surface(display: core::va_display, surface: core::
 /// * surface: - destination VASurfaceID object.
 /// * size: - size of image represented by VASurfaceID object.
 #[inline]
-pub unsafe fn convert_to_va_surface(display: core::va_display, src: &dyn core::ToInputArray, surface: core::va_surface_id, size: core::Size) -> Result<()> {
+pub unsafe fn convert_to_va_surface(display: core::va_display, src: &(impl core::ToInputArray + ?Sized), surface: core::va_surface_id, size: core::Size) -> Result<()> {
 	input_array_arg!(src);
 	return_send!(via ocvrs_return);
 	{ sys::cv_va_intel_convertToVASurface_VADisplay_const__InputArrayR_VASurfaceID_Size(display, src.as_raw__InputArray(), surface, size.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
@@ -8314,7 +8314,7 @@ pub unsafe fn initialize_context_from_va(display: core::va_display, try_interop:
 /// * src2: second input array to be considered for vertical concatenation.
 /// * dst: output array. It has the same number of cols and depth as the src1 and src2, and the sum of rows of the src1 and src2.
 #[inline]
-pub fn vconcat2(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn vconcat2(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
@@ -8367,7 +8367,7 @@ pub fn vconcat2(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, ds
 /// * dst: output array. It has the same number of cols and depth as the src, and the sum of rows of the src.
 /// same depth.
 #[inline]
-pub fn vconcat(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn vconcat(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -9473,7 +9473,7 @@ impl dyn DownhillSolver + '_ {
 	/// * init_step: Mat_<double>(1,1,0.0)
 	/// * termcrit: TermCriteria(TermCriteria::MAX_ITER+TermCriteria::EPS,5000,0.000001)
 	#[inline]
-	pub fn create(f: &core::Ptr<dyn core::MinProblemSolver_Function>, init_step: &dyn core::ToInputArray, termcrit: core::TermCriteria) -> Result<core::Ptr<dyn core::DownhillSolver>> {
+	pub fn create(f: &core::Ptr<dyn core::MinProblemSolver_Function>, init_step: &(impl core::ToInputArray + ?Sized), termcrit: core::TermCriteria) -> Result<core::Ptr<dyn core::DownhillSolver>> {
 		input_array_arg!(init_step);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_DownhillSolver_create_const_PtrLFunctionGR_const__InputArrayR_TermCriteria(f.as_raw_PtrOfMinProblemSolver_Function(), init_step.as_raw__InputArray(), termcrit.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
@@ -9502,6 +9502,14 @@ pub trait ExceptionTraitConst {
 		ret
 	}
 	
+	/// Same as `msg()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn msg_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_Exception_getPropMsgRef_const(self.as_raw_Exception()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	/// error code see also: CVStatus
 	#[inline]
 	fn code(&self) -> i32 {
@@ -9517,6 +9525,14 @@ pub trait ExceptionTraitConst {
 		ret
 	}
 	
+	/// Same as `err()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn err_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_Exception_getPropErrRef_const(self.as_raw_Exception()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	/// function name. Available only when the compiler supports getting it
 	#[inline]
 	fn func(&self) -> String {
@@ -9525,6 +9541,14 @@ pub trait ExceptionTraitConst {
 		ret
 	}
 	
+	/// Same as `func()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn func_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_Exception_getPropFuncRef_const(self.as_raw_Exception()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	/// source file name where the error has occurred
 	#[inline]
 	fn file(&self) -> String {
@@ -9533,6 +9557,14 @@ pub trait ExceptionTraitConst {
 		ret
 	}
 	
+	/// Same as `file()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn file_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_Exception_getPropFileRef_const(self.as_raw_Exception()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	/// line number in the source file where the error has occurred
 	#[inline]
 	fn line(&self) -> i32 {
@@ -9690,13 +9722,13 @@ pub trait FileNodeTraitConst {
 	fn as_raw_FileNode(&self) -> *const c_void;
 
 	#[inline]
-	fn block_idx(&self) -> size_t {
+	fn block_idx(&self) -> usize {
 		let ret = unsafe { sys::cv_FileNode_getPropBlockIdx_const(self.as_raw_FileNode()) };
 		ret
 	}
 	
 	#[inline]
-	fn ofs(&self) -> size_t {
+	fn ofs(&self) -> usize {
 		let ret = unsafe { sys::cv_FileNode_getPropOfs_const(self.as_raw_FileNode()) };
 		ret
 	}
@@ -9874,7 +9906,7 @@ pub trait FileNodeTraitConst {
 	
 	/// returns the number of elements in the node, if it is a sequence or mapping, or 1 otherwise.
 	#[inline]
-	fn size(&self) -> Result<size_t> {
+	fn size(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_FileNode_size_const(self.as_raw_FileNode(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -9884,7 +9916,7 @@ pub trait FileNodeTraitConst {
 	
 	/// returns raw size of the FileNode in bytes
 	#[inline]
-	fn raw_size(&self) -> Result<size_t> {
+	fn raw_size(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_FileNode_rawSize_const(self.as_raw_FileNode(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -9973,7 +10005,7 @@ pub trait FileNodeTraitConst {
 	/// * len: Number of bytes to read (buffer size limit). If it is greater than number of
 	///            remaining elements then all of them will be read.
 	#[inline]
-	unsafe fn read_raw(&self, fmt: &str, vec: *mut c_void, len: size_t) -> Result<()> {
+	unsafe fn read_raw(&self, fmt: &str, vec: *mut c_void, len: usize) -> Result<()> {
 		extern_container_arg!(fmt);
 		return_send!(via ocvrs_return);
 		{ sys::cv_FileNode_readRaw_const_const_StringR_voidX_size_t(self.as_raw_FileNode(), fmt.opencv_as_extern(), vec, len, ocvrs_return.as_mut_ptr()) };
@@ -10020,13 +10052,13 @@ pub trait FileNodeTrait: core::FileNodeTraitConst {
 	fn as_raw_mut_FileNode(&mut self) -> *mut c_void;
 
 	#[inline]
-	fn set_block_idx(&mut self, val: size_t) {
+	fn set_block_idx(&mut self, val: usize) {
 		let ret = unsafe { sys::cv_FileNode_setPropBlockIdx_size_t(self.as_raw_mut_FileNode(), val) };
 		ret
 	}
 	
 	#[inline]
-	fn set_ofs(&mut self, val: size_t) {
+	fn set_ofs(&mut self, val: usize) {
 		let ret = unsafe { sys::cv_FileNode_setPropOfs_size_t(self.as_raw_mut_FileNode(), val) };
 		ret
 	}
@@ -10119,7 +10151,7 @@ impl FileNode {
 	/// 
 	///      @deprecated
 	#[inline]
-	pub fn new(fs: &core::FileStorage, block_idx: size_t, ofs: size_t) -> Result<core::FileNode> {
+	pub fn new(fs: &core::FileStorage, block_idx: usize, ofs: usize) -> Result<core::FileNode> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_FileNode_FileNode_const_FileStorageX_size_t_size_t(fs.as_raw_FileStorage(), block_idx, ofs, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -10214,7 +10246,7 @@ pub trait FileNodeIteratorTraitConst {
 	
 	/// returns the number of remaining (not read yet) elements
 	#[inline]
-	fn remaining(&self) -> Result<size_t> {
+	fn remaining(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_FileNodeIterator_remaining_const(self.as_raw_FileNodeIterator(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -10259,7 +10291,7 @@ pub trait FileNodeIteratorTrait: core::FileNodeIteratorTraitConst {
 	/// ## C++ default parameters
 	/// * len: (size_t)INT_MAX
 	#[inline]
-	unsafe fn read_raw(&mut self, fmt: &str, vec: *mut c_void, len: size_t) -> Result<core::FileNodeIterator> {
+	unsafe fn read_raw(&mut self, fmt: &str, vec: *mut c_void, len: usize) -> Result<core::FileNodeIterator> {
 		extern_container_arg!(fmt);
 		return_send!(via ocvrs_return);
 		{ sys::cv_FileNodeIterator_readRaw_const_StringR_voidX_size_t(self.as_raw_mut_FileNodeIterator(), fmt.opencv_as_extern(), vec, len, ocvrs_return.as_mut_ptr()) };
@@ -10376,6 +10408,14 @@ pub trait FileStorageTraitConst {
 		ret
 	}
 	
+	/// Same as `elname()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn elname_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_FileStorage_getPropElnameRef_const(self.as_raw_FileStorage()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	/// Checks whether the file is opened.
 	/// 
 	/// ## Returns
@@ -10629,7 +10669,7 @@ pub trait FileStorageTrait: core::FileStorageTraitConst {
 	/// * vec: Pointer to the written array.
 	/// * len: Number of the uchar elements to write.
 	#[inline]
-	unsafe fn write_raw(&mut self, fmt: &str, vec: *const c_void, len: size_t) -> Result<()> {
+	unsafe fn write_raw(&mut self, fmt: &str, vec: *const c_void, len: usize) -> Result<()> {
 		extern_container_arg!(fmt);
 		return_send!(via ocvrs_return);
 		{ sys::cv_FileStorage_writeRaw_const_StringR_const_voidX_size_t(self.as_raw_mut_FileStorage(), fmt.opencv_as_extern(), vec, len, ocvrs_return.as_mut_ptr()) };
@@ -10743,8 +10783,8 @@ impl FileStorage {
 	/// ## C++ default parameters
 	/// * encoding: String()
 	#[inline]
-	pub fn new(filename: &str, flags: i32, encoding: &str) -> Result<core::FileStorage> {
-		extern_container_arg!(filename);
+	pub fn new(filename: impl AsRef<Path>, flags: i32, encoding: &str) -> Result<core::FileStorage> {
+		path_arg!(filename);
 		extern_container_arg!(encoding);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_FileStorage_FileStorage_const_StringR_int_const_StringR(filename.opencv_as_extern(), flags, encoding.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
@@ -10951,7 +10991,7 @@ opencv_type_simple! { core::KeyPoint }
 
 impl KeyPoint {
 	#[inline]
-	pub fn hash(self) -> Result<size_t> {
+	pub fn hash(self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_KeyPoint_hash_const(self.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -11246,7 +11286,7 @@ impl LDA {
 	/// ## C++ default parameters
 	/// * num_components: 0
 	#[inline]
-	pub fn new_with_data(src: &dyn core::ToInputArray, labels: &dyn core::ToInputArray, num_components: i32) -> Result<core::LDA> {
+	pub fn new_with_data(src: &(impl core::ToInputArray + ?Sized), labels: &(impl core::ToInputArray + ?Sized), num_components: i32) -> Result<core::LDA> {
 		input_array_arg!(src);
 		input_array_arg!(labels);
 		return_send!(via ocvrs_return);
@@ -11258,7 +11298,7 @@ impl LDA {
 	}
 	
 	#[inline]
-	pub fn subspace_project(w: &dyn core::ToInputArray, mean: &dyn core::ToInputArray, src: &dyn core::ToInputArray) -> Result<core::Mat> {
+	pub fn subspace_project(w: &(impl core::ToInputArray + ?Sized), mean: &(impl core::ToInputArray + ?Sized), src: &(impl core::ToInputArray + ?Sized)) -> Result<core::Mat> {
 		input_array_arg!(w);
 		input_array_arg!(mean);
 		input_array_arg!(src);
@@ -11271,7 +11311,7 @@ impl LDA {
 	}
 	
 	#[inline]
-	pub fn subspace_reconstruct(w: &dyn core::ToInputArray, mean: &dyn core::ToInputArray, src: &dyn core::ToInputArray) -> Result<core::Mat> {
+	pub fn subspace_reconstruct(w: &(impl core::ToInputArray + ?Sized), mean: &(impl core::ToInputArray + ?Sized), src: &(impl core::ToInputArray + ?Sized)) -> Result<core::Mat> {
 		input_array_arg!(w);
 		input_array_arg!(mean);
 		input_array_arg!(src);
@@ -11549,6 +11589,14 @@ pub trait MatTraitConst {
 		ret
 	}
 	
+	/// Same as `mat_size()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn mat_size_ref(&self) -> BoxedRef<'_, core::MatSize> {
+		let ret = unsafe { sys::cv_Mat_getPropSizeRef_const(self.as_raw_Mat()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn mat_step(&self) -> core::MatStep {
 		let ret = unsafe { sys::cv_Mat_getPropStep_const(self.as_raw_Mat()) };
@@ -11556,6 +11604,14 @@ pub trait MatTraitConst {
 		ret
 	}
 	
+	/// Same as `mat_step()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn mat_step_ref(&self) -> BoxedRef<'_, core::MatStep> {
+		let ret = unsafe { sys::cv_Mat_getPropStepRef_const(self.as_raw_Mat()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// retrieve UMat from Mat
 	/// 
 	/// ## C++ default parameters
@@ -12207,7 +12263,7 @@ pub trait MatTraitConst {
 	/// The method returns the matrix element size in bytes. For example, if the matrix type is CV_16SC3 ,
 	/// the method returns 3\*sizeof(short) or 6.
 	#[inline]
-	fn elem_size(&self) -> Result<size_t> {
+	fn elem_size(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_Mat_elemSize_const(self.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -12220,7 +12276,7 @@ pub trait MatTraitConst {
 	/// The method returns the matrix element channel size in bytes, that is, it ignores the number of
 	/// channels. For example, if the matrix type is CV_16SC3 , the method returns sizeof(short) or 2.
 	#[inline]
-	fn elem_size1(&self) -> size_t {
+	fn elem_size1(&self) -> usize {
 		let ret = unsafe { sys::cv_Mat_elemSize1_const(self.as_raw_Mat()) };
 		ret
 	}
@@ -12270,7 +12326,7 @@ pub trait MatTraitConst {
 	/// ## C++ default parameters
 	/// * i: 0
 	#[inline]
-	fn step1(&self, i: i32) -> Result<size_t> {
+	fn step1(&self, i: i32) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_Mat_step1_const_int(self.as_raw_Mat(), i, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -12293,7 +12349,7 @@ pub trait MatTraitConst {
 	/// The method returns the number of array elements (a number of pixels if the array represents an
 	/// image).
 	#[inline]
-	fn total(&self) -> size_t {
+	fn total(&self) -> usize {
 		let ret = unsafe { sys::cv_Mat_total_const(self.as_raw_Mat()) };
 		ret
 	}
@@ -12305,7 +12361,7 @@ pub trait MatTraitConst {
 	/// ## C++ default parameters
 	/// * end_dim: INT_MAX
 	#[inline]
-	fn total_slice(&self, start_dim: i32, end_dim: i32) -> Result<size_t> {
+	fn total_slice(&self, start_dim: i32, end_dim: i32) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_Mat_total_const_int_int(self.as_raw_Mat(), start_dim, end_dim, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -12690,9 +12746,9 @@ pub trait MatTrait: core::MatTraitConst {
 	/// ## C++ default parameters
 	/// * mask: noArray()
 	#[inline]
-	fn set_to(&mut self, value: &dyn core::ToInputArray, mask: &dyn core::ToInputArray) -> Result<core::Mat> {
+	fn set_to(&mut self, value: &dyn core::ToInputArray, mask: Option<&dyn core::ToInputArray>) -> Result<core::Mat> {
 		input_array_arg!(value);
-		input_array_arg!(mask);
+		input_array_arg!(nullable mask);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_Mat_setTo_const__InputArrayR_const__InputArrayR(self.as_raw_mut_Mat(), value.as_raw__InputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -12949,7 +13005,7 @@ pub trait MatTrait: core::MatTraitConst {
 	/// ## Parameters
 	/// * sz: Number of rows.
 	#[inline]
-	fn reserve(&mut self, sz: size_t) -> Result<()> {
+	fn reserve(&mut self, sz: usize) -> Result<()> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_Mat_reserve_size_t(self.as_raw_mut_Mat(), sz, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -12964,7 +13020,7 @@ pub trait MatTrait: core::MatTraitConst {
 	/// ## Parameters
 	/// * sz: Number of bytes.
 	#[inline]
-	fn reserve_buffer(&mut self, sz: size_t) -> Result<()> {
+	fn reserve_buffer(&mut self, sz: usize) -> Result<()> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_Mat_reserveBuffer_size_t(self.as_raw_mut_Mat(), sz, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -12980,7 +13036,7 @@ pub trait MatTrait: core::MatTraitConst {
 	/// ## Parameters
 	/// * sz: New number of rows.
 	#[inline]
-	fn resize(&mut self, sz: size_t) -> Result<()> {
+	fn resize(&mut self, sz: usize) -> Result<()> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_Mat_resize_size_t(self.as_raw_mut_Mat(), sz, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -13001,7 +13057,7 @@ pub trait MatTrait: core::MatTraitConst {
 	/// * sz: New number of rows.
 	/// * s: Value assigned to the newly added elements.
 	#[inline]
-	fn resize_with_default(&mut self, sz: size_t, s: core::Scalar) -> Result<()> {
+	fn resize_with_default(&mut self, sz: usize, s: core::Scalar) -> Result<()> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_Mat_resize_size_t_const_ScalarR(self.as_raw_mut_Mat(), sz, &s, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -13039,7 +13095,7 @@ pub trait MatTrait: core::MatTraitConst {
 	/// ## C++ default parameters
 	/// * nelems: 1
 	#[inline]
-	fn pop_back(&mut self, nelems: size_t) -> Result<()> {
+	fn pop_back(&mut self, nelems: usize) -> Result<()> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_Mat_pop_back_size_t(self.as_raw_mut_Mat(), nelems, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -13829,7 +13885,7 @@ impl Mat {
 	/// ## C++ default parameters
 	/// * step: AUTO_STEP
 	#[inline]
-	pub unsafe fn new_rows_cols_with_data(rows: i32, cols: i32, typ: i32, data: *mut c_void, step: size_t) -> Result<core::Mat> {
+	pub unsafe fn new_rows_cols_with_data(rows: i32, cols: i32, typ: i32, data: *mut c_void, step: usize) -> Result<core::Mat> {
 		return_send!(via ocvrs_return);
 		{ sys::cv_Mat_Mat_int_int_int_voidX_size_t(rows, cols, typ, data, step, ocvrs_return.as_mut_ptr()) };
 		return_receive!(ocvrs_return => ret);
@@ -13862,7 +13918,7 @@ impl Mat {
 	/// ## C++ default parameters
 	/// * step: AUTO_STEP
 	#[inline]
-	pub unsafe fn new_size_with_data(size: core::Size, typ: i32, data: *mut c_void, step: size_t) -> Result<core::Mat> {
+	pub unsafe fn new_size_with_data(size: core::Size, typ: i32, data: *mut c_void, step: usize) -> Result<core::Mat> {
 		return_send!(via ocvrs_return);
 		{ sys::cv_Mat_Mat_Size_int_voidX_size_t(size.opencv_as_extern(), typ, data, step, ocvrs_return.as_mut_ptr()) };
 		return_receive!(ocvrs_return => ret);
@@ -13894,7 +13950,7 @@ impl Mat {
 	/// ## C++ default parameters
 	/// * steps: 0
 	#[inline]
-	pub unsafe fn new_nd_with_data(sizes: &[i32], typ: i32, data: *mut c_void, steps: Option<&[size_t]>) -> Result<core::Mat> {
+	pub unsafe fn new_nd_with_data(sizes: &[i32], typ: i32, data: *mut c_void, steps: Option<&[usize]>) -> Result<core::Mat> {
 		return_send!(via ocvrs_return);
 		{ sys::cv_Mat_Mat_int_const_intX_int_voidX_const_size_tX(sizes.len() as _, sizes.as_ptr(), typ, data, steps.map_or(::core::ptr::null(), |steps| steps.as_ptr()), ocvrs_return.as_mut_ptr()) };
 		return_receive!(ocvrs_return => ret);
@@ -13925,7 +13981,7 @@ impl Mat {
 	/// ## C++ default parameters
 	/// * steps: 0
 	#[inline]
-	pub unsafe fn new_nd_vec_with_data(sizes: &core::Vector<i32>, typ: i32, data: *mut c_void, steps: Option<&[size_t]>) -> Result<core::Mat> {
+	pub unsafe fn new_nd_vec_with_data(sizes: &core::Vector<i32>, typ: i32, data: *mut c_void, steps: Option<&[usize]>) -> Result<core::Mat> {
 		return_send!(via ocvrs_return);
 		{ sys::cv_Mat_Mat_const_vectorLintGR_int_voidX_const_size_tX(sizes.as_raw_VectorOfi32(), typ, data, steps.map_or(::core::ptr::null(), |steps| steps.as_ptr()), ocvrs_return.as_mut_ptr()) };
 		return_receive!(ocvrs_return => ret);
@@ -14323,8 +14379,16 @@ pub trait MatConstIteratorTraitConst {
 		ret
 	}
 	
+	/// Same as `m()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn m_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_MatConstIterator_getPropMRef_const(self.as_raw_MatConstIterator()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
-	fn elem_size(&self) -> size_t {
+	fn elem_size(&self) -> usize {
 		let ret = unsafe { sys::cv_MatConstIterator_getPropElemSize_const(self.as_raw_MatConstIterator()) };
 		ret
 	}
@@ -14359,7 +14423,7 @@ pub trait MatConstIteratorTraitConst {
 	
 	/// returns the i-th matrix element, relative to the current
 	#[inline]
-	fn get(&self, i: ptrdiff_t) -> Result<*const u8> {
+	fn get(&self, i: isize) -> Result<*const u8> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_MatConstIterator_operator___const_ptrdiff_t(self.as_raw_MatConstIterator(), i, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -14388,7 +14452,7 @@ pub trait MatConstIteratorTraitConst {
 	}
 	
 	#[inline]
-	fn lpos(&self) -> Result<ptrdiff_t> {
+	fn lpos(&self) -> Result<isize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_MatConstIterator_lpos_const(self.as_raw_MatConstIterator(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -14402,7 +14466,7 @@ pub trait MatConstIteratorTrait: core::MatConstIteratorTraitConst {
 	fn as_raw_mut_MatConstIterator(&mut self) -> *mut c_void;
 
 	#[inline]
-	fn set_elem_size(&mut self, val: size_t) {
+	fn set_elem_size(&mut self, val: usize) {
 		let ret = unsafe { sys::cv_MatConstIterator_setPropElemSize_size_t(self.as_raw_mut_MatConstIterator(), val) };
 		ret
 	}
@@ -14432,7 +14496,7 @@ pub trait MatConstIteratorTrait: core::MatConstIteratorTraitConst {
 	/// ## C++ default parameters
 	/// * relative: false
 	#[inline]
-	fn seek(&mut self, ofs: ptrdiff_t, relative: bool) -> Result<()> {
+	fn seek(&mut self, ofs: isize, relative: bool) -> Result<()> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_MatConstIterator_seek_ptrdiff_t_bool(self.as_raw_mut_MatConstIterator(), ofs, relative, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -14601,6 +14665,14 @@ pub trait MatExprTraitConst {
 		ret
 	}
 	
+	/// Same as `a()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn a_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_MatExpr_getPropARef_const(self.as_raw_MatExpr()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn b(&self) -> core::Mat {
 		let ret = unsafe { sys::cv_MatExpr_getPropB_const(self.as_raw_MatExpr()) };
@@ -14608,6 +14680,14 @@ pub trait MatExprTraitConst {
 		ret
 	}
 	
+	/// Same as `b()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn b_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_MatExpr_getPropBRef_const(self.as_raw_MatExpr()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn c(&self) -> core::Mat {
 		let ret = unsafe { sys::cv_MatExpr_getPropC_const(self.as_raw_MatExpr()) };
@@ -14615,6 +14695,14 @@ pub trait MatExprTraitConst {
 		ret
 	}
 	
+	/// Same as `c()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn c_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_MatExpr_getPropCRef_const(self.as_raw_MatExpr()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn alpha(&self) -> f64 {
 		let ret = unsafe { sys::cv_MatExpr_getPropAlpha_const(self.as_raw_MatExpr()) };
@@ -14917,9 +15005,9 @@ impl MatExpr {
 	/// * _beta: 1
 	/// * _s: Scalar()
 	#[inline]
-	pub fn new(_op: &dyn core::MatOp, _flags: i32, _a: &core::Mat, _b: &core::Mat, _c: &core::Mat, _alpha: f64, _beta: f64, _s: core::Scalar) -> Result<core::MatExpr> {
+	pub fn new(_op: &dyn core::MatOp, _flags: i32, _a: Option<&core::Mat>, _b: Option<&core::Mat>, _c: Option<&core::Mat>, _alpha: f64, _beta: f64, _s: core::Scalar) -> Result<core::MatExpr> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_MatExpr_MatExpr_const_MatOpX_int_const_MatR_const_MatR_const_MatR_double_double_const_ScalarR(_op.as_raw_MatOp(), _flags, _a.as_raw_Mat(), _b.as_raw_Mat(), _c.as_raw_Mat(), _alpha, _beta, &_s, ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_MatExpr_MatExpr_const_MatOpX_int_const_MatR_const_MatR_const_MatR_double_double_const_ScalarR(_op.as_raw_MatOp(), _flags, _a.map_or(::core::ptr::null(), |_a| _a.as_raw_Mat()), _b.map_or(::core::ptr::null(), |_b| _b.as_raw_Mat()), _c.map_or(::core::ptr::null(), |_c| _c.as_raw_Mat()), _alpha, _beta, &_s, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::MatExpr::opencv_from_extern(ret) };
@@ -15272,13 +15360,13 @@ pub trait MatStepTraitConst {
 	fn as_raw_MatStep(&self) -> *const c_void;
 
 	#[inline]
-	fn get(&self, i: i32) -> size_t {
+	fn get(&self, i: i32) -> usize {
 		let ret = unsafe { sys::cv_MatStep_operator___const_int(self.as_raw_MatStep(), i) };
 		ret
 	}
 	
 	#[inline]
-	fn to_size_t(&self) -> Result<size_t> {
+	fn to_size_t(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_MatStep_operator_size_t_const(self.as_raw_MatStep(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -15292,26 +15380,26 @@ pub trait MatStepTrait: core::MatStepTraitConst {
 	fn as_raw_mut_MatStep(&mut self) -> *mut c_void;
 
 	#[inline]
-	fn p(&mut self) -> *mut size_t {
+	fn p(&mut self) -> *mut usize {
 		let ret = unsafe { sys::cv_MatStep_getPropP(self.as_raw_mut_MatStep()) };
 		ret
 	}
 	
 	#[inline]
-	unsafe fn set_p(&mut self, val: *mut size_t) {
+	unsafe fn set_p(&mut self, val: *mut usize) {
 		let ret = { sys::cv_MatStep_setPropP_size_tX(self.as_raw_mut_MatStep(), val) };
 		ret
 	}
 	
 	#[inline]
-	fn buf(&mut self) -> &mut [size_t; 2] {
+	fn buf(&mut self) -> &mut [usize; 2] {
 		let ret = unsafe { sys::cv_MatStep_getPropBuf(self.as_raw_mut_MatStep()) };
-		let ret = unsafe { ret.as_mut() }.expect("Function returned null pointer");
+		let ret = unsafe { &mut *ret };
 		ret
 	}
 	
 	#[inline]
-	fn get_mut(&mut self, i: i32) -> size_t {
+	fn get_mut(&mut self, i: i32) -> usize {
 		let ret = unsafe { sys::cv_MatStep_operator___int(self.as_raw_mut_MatStep(), i) };
 		ret
 	}
@@ -15351,7 +15439,7 @@ impl MatStep {
 	}
 	
 	#[inline]
-	pub fn new(s: size_t) -> core::MatStep {
+	pub fn new(s: usize) -> core::MatStep {
 		let ret = unsafe { sys::cv_MatStep_MatStep_size_t(s) };
 		let ret = unsafe { core::MatStep::opencv_from_extern(ret) };
 		ret
@@ -16095,6 +16183,14 @@ pub trait PCATraitConst {
 		ret
 	}
 	
+	/// Same as `eigenvectors()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn eigenvectors_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_PCA_getPropEigenvectorsRef_const(self.as_raw_PCA()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// eigenvalues of the covariation matrix
 	#[inline]
 	fn eigenvalues(&self) -> core::Mat {
@@ -16103,6 +16199,14 @@ pub trait PCATraitConst {
 		ret
 	}
 	
+	/// Same as `eigenvalues()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn eigenvalues_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_PCA_getPropEigenvaluesRef_const(self.as_raw_PCA()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// mean value subtracted before the projection and added after the back projection
 	#[inline]
 	fn mean(&self) -> core::Mat {
@@ -16111,6 +16215,14 @@ pub trait PCATraitConst {
 		ret
 	}
 	
+	/// Same as `mean()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn mean_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_PCA_getPropMeanRef_const(self.as_raw_PCA()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// Projects vector(s) to the principal component subspace.
 	/// 
 	/// The methods project one or more vectors to the principal component
@@ -16404,7 +16516,7 @@ impl PCA {
 	/// ## C++ default parameters
 	/// * max_components: 0
 	#[inline]
-	pub fn new(data: &dyn core::ToInputArray, mean: &dyn core::ToInputArray, flags: i32, max_components: i32) -> Result<core::PCA> {
+	pub fn new(data: &(impl core::ToInputArray + ?Sized), mean: &(impl core::ToInputArray + ?Sized), flags: i32, max_components: i32) -> Result<core::PCA> {
 		input_array_arg!(data);
 		input_array_arg!(mean);
 		return_send!(via ocvrs_return);
@@ -16432,7 +16544,7 @@ impl PCA {
 	///    Using this parameter will let the PCA decided how many components to
 	///    retain but it will always keep at least 2.
 	#[inline]
-	pub fn new_with_variance(data: &dyn core::ToInputArray, mean: &dyn core::ToInputArray, flags: i32, retained_variance: f64) -> Result<core::PCA> {
+	pub fn new_with_variance(data: &(impl core::ToInputArray + ?Sized), mean: &(impl core::ToInputArray + ?Sized), flags: i32, retained_variance: f64) -> Result<core::PCA> {
 		input_array_arg!(data);
 		input_array_arg!(mean);
 		return_send!(via ocvrs_return);
@@ -17378,6 +17490,14 @@ pub trait SVDTraitConst {
 		ret
 	}
 	
+	/// Same as `u()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn u_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_SVD_getPropURef_const(self.as_raw_SVD()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn w(&self) -> core::Mat {
 		let ret = unsafe { sys::cv_SVD_getPropW_const(self.as_raw_SVD()) };
@@ -17385,6 +17505,14 @@ pub trait SVDTraitConst {
 		ret
 	}
 	
+	/// Same as `w()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn w_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_SVD_getPropWRef_const(self.as_raw_SVD()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn vt(&self) -> core::Mat {
 		let ret = unsafe { sys::cv_SVD_getPropVt_const(self.as_raw_SVD()) };
@@ -17392,6 +17520,14 @@ pub trait SVDTraitConst {
 		ret
 	}
 	
+	/// Same as `vt()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn vt_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_SVD_getPropVtRef_const(self.as_raw_SVD()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// performs a singular value back substitution.
 	/// 
 	/// The method calculates a back substitution for the specified right-hand
@@ -17515,7 +17651,7 @@ impl SVD {
 	/// ## C++ default parameters
 	/// * flags: 0
 	#[inline]
-	pub fn new(src: &dyn core::ToInputArray, flags: i32) -> Result<core::SVD> {
+	pub fn new(src: &(impl core::ToInputArray + ?Sized), flags: i32) -> Result<core::SVD> {
 		input_array_arg!(src);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_SVD_SVD_const__InputArrayR_int(src.as_raw__InputArray(), flags, ocvrs_return.as_mut_ptr()) };
@@ -17547,7 +17683,7 @@ impl SVD {
 	/// ## C++ default parameters
 	/// * flags: 0
 	#[inline]
-	pub fn compute_ext(src: &dyn core::ToInputArray, w: &mut dyn core::ToOutputArray, u: &mut dyn core::ToOutputArray, vt: &mut dyn core::ToOutputArray, flags: i32) -> Result<()> {
+	pub fn compute_ext(src: &(impl core::ToInputArray + ?Sized), w: &mut (impl core::ToOutputArray + ?Sized), u: &mut (impl core::ToOutputArray + ?Sized), vt: &mut (impl core::ToOutputArray + ?Sized), flags: i32) -> Result<()> {
 		input_array_arg!(src);
 		output_array_arg!(w);
 		output_array_arg!(u);
@@ -17588,7 +17724,7 @@ impl SVD {
 	/// ## C++ default parameters
 	/// * flags: 0
 	#[inline]
-	pub fn compute(src: &dyn core::ToInputArray, w: &mut dyn core::ToOutputArray, flags: i32) -> Result<()> {
+	pub fn compute(src: &(impl core::ToInputArray + ?Sized), w: &mut (impl core::ToOutputArray + ?Sized), flags: i32) -> Result<()> {
 		input_array_arg!(src);
 		output_array_arg!(w);
 		return_send!(via ocvrs_return);
@@ -17600,7 +17736,7 @@ impl SVD {
 	
 	/// performs back substitution
 	#[inline]
-	pub fn back_subst_multi(w: &dyn core::ToInputArray, u: &dyn core::ToInputArray, vt: &dyn core::ToInputArray, rhs: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+	pub fn back_subst_multi(w: &(impl core::ToInputArray + ?Sized), u: &(impl core::ToInputArray + ?Sized), vt: &(impl core::ToInputArray + ?Sized), rhs: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 		input_array_arg!(w);
 		input_array_arg!(u);
 		input_array_arg!(vt);
@@ -17624,7 +17760,7 @@ impl SVD {
 	/// * src: left-hand-side matrix.
 	/// * dst: found solution.
 	#[inline]
-	pub fn solve_z(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+	pub fn solve_z(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 		input_array_arg!(src);
 		output_array_arg!(dst);
 		return_send!(via ocvrs_return);
@@ -17807,14 +17943,14 @@ pub trait SparseMatTraitConst {
 	
 	/// returns the size of each element in bytes (not including the overhead - the space occupied by SparseMat::Node elements)
 	#[inline]
-	fn elem_size(&self) -> size_t {
+	fn elem_size(&self) -> usize {
 		let ret = unsafe { sys::cv_SparseMat_elemSize_const(self.as_raw_SparseMat()) };
 		ret
 	}
 	
 	/// returns elemSize()/channels()
 	#[inline]
-	fn elem_size1(&self) -> size_t {
+	fn elem_size1(&self) -> usize {
 		let ret = unsafe { sys::cv_SparseMat_elemSize1_const(self.as_raw_SparseMat()) };
 		ret
 	}
@@ -17872,7 +18008,7 @@ pub trait SparseMatTraitConst {
 	
 	/// returns the number of non-zero elements (=the number of hash table nodes)
 	#[inline]
-	fn nzcount(&self) -> Result<size_t> {
+	fn nzcount(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_SparseMat_nzcount_const(self.as_raw_SparseMat(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -17882,7 +18018,7 @@ pub trait SparseMatTraitConst {
 	
 	/// computes the element hash value (1D case)
 	#[inline]
-	fn hash(&self, i0: i32) -> Result<size_t> {
+	fn hash(&self, i0: i32) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_SparseMat_hash_const_int(self.as_raw_SparseMat(), i0, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -17892,7 +18028,7 @@ pub trait SparseMatTraitConst {
 	
 	/// computes the element hash value (2D case)
 	#[inline]
-	fn hash_1(&self, i0: i32, i1: i32) -> Result<size_t> {
+	fn hash_1(&self, i0: i32, i1: i32) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_SparseMat_hash_const_int_int(self.as_raw_SparseMat(), i0, i1, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -17902,7 +18038,7 @@ pub trait SparseMatTraitConst {
 	
 	/// computes the element hash value (3D case)
 	#[inline]
-	fn hash_2(&self, i0: i32, i1: i32, i2: i32) -> Result<size_t> {
+	fn hash_2(&self, i0: i32, i1: i32, i2: i32) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_SparseMat_hash_const_int_int_int(self.as_raw_SparseMat(), i0, i1, i2, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -17912,7 +18048,7 @@ pub trait SparseMatTraitConst {
 	
 	/// computes the element hash value (nD case)
 	#[inline]
-	fn hash_3(&self, idx: &i32) -> Result<size_t> {
+	fn hash_3(&self, idx: &i32) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_SparseMat_hash_const_const_intX(self.as_raw_SparseMat(), idx, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -17943,7 +18079,7 @@ pub trait SparseMatTraitConst {
 	}
 	
 	#[inline]
-	fn node(&self, nidx: size_t) -> Result<core::SparseMat_Node> {
+	fn node(&self, nidx: usize) -> Result<core::SparseMat_Node> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_SparseMat_node_const_size_t(self.as_raw_SparseMat(), nidx, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -18033,7 +18169,7 @@ pub trait SparseMatTrait: core::SparseMatTraitConst {
 	/// ## C++ default parameters
 	/// * hashval: 0
 	#[inline]
-	fn ptr(&mut self, i0: i32, create_missing: bool, hashval: &mut size_t) -> Result<*mut u8> {
+	fn ptr(&mut self, i0: i32, create_missing: bool, hashval: &mut usize) -> Result<*mut u8> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_SparseMat_ptr_int_bool_size_tX(self.as_raw_mut_SparseMat(), i0, create_missing, hashval, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -18046,7 +18182,7 @@ pub trait SparseMatTrait: core::SparseMatTraitConst {
 	/// ## C++ default parameters
 	/// * hashval: 0
 	#[inline]
-	fn ptr_1(&mut self, i0: i32, i1: i32, create_missing: bool, hashval: &mut size_t) -> Result<*mut u8> {
+	fn ptr_1(&mut self, i0: i32, i1: i32, create_missing: bool, hashval: &mut usize) -> Result<*mut u8> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_SparseMat_ptr_int_int_bool_size_tX(self.as_raw_mut_SparseMat(), i0, i1, create_missing, hashval, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -18059,7 +18195,7 @@ pub trait SparseMatTrait: core::SparseMatTraitConst {
 	/// ## C++ default parameters
 	/// * hashval: 0
 	#[inline]
-	fn ptr_2(&mut self, i0: i32, i1: i32, i2: i32, create_missing: bool, hashval: &mut size_t) -> Result<*mut u8> {
+	fn ptr_2(&mut self, i0: i32, i1: i32, i2: i32, create_missing: bool, hashval: &mut usize) -> Result<*mut u8> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_SparseMat_ptr_int_int_int_bool_size_tX(self.as_raw_mut_SparseMat(), i0, i1, i2, create_missing, hashval, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -18072,7 +18208,7 @@ pub trait SparseMatTrait: core::SparseMatTraitConst {
 	/// ## C++ default parameters
 	/// * hashval: 0
 	#[inline]
-	fn ptr_3(&mut self, idx: &i32, create_missing: bool, hashval: &mut size_t) -> Result<*mut u8> {
+	fn ptr_3(&mut self, idx: &i32, create_missing: bool, hashval: &mut usize) -> Result<*mut u8> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_SparseMat_ptr_const_intX_bool_size_tX(self.as_raw_mut_SparseMat(), idx, create_missing, hashval, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -18085,7 +18221,7 @@ pub trait SparseMatTrait: core::SparseMatTraitConst {
 	/// ## C++ default parameters
 	/// * hashval: 0
 	#[inline]
-	fn erase(&mut self, i0: i32, i1: i32, hashval: &mut size_t) -> Result<()> {
+	fn erase(&mut self, i0: i32, i1: i32, hashval: &mut usize) -> Result<()> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_SparseMat_erase_int_int_size_tX(self.as_raw_mut_SparseMat(), i0, i1, hashval, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -18098,7 +18234,7 @@ pub trait SparseMatTrait: core::SparseMatTraitConst {
 	/// ## C++ default parameters
 	/// * hashval: 0
 	#[inline]
-	fn erase_1(&mut self, i0: i32, i1: i32, i2: i32, hashval: &mut size_t) -> Result<()> {
+	fn erase_1(&mut self, i0: i32, i1: i32, i2: i32, hashval: &mut usize) -> Result<()> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_SparseMat_erase_int_int_int_size_tX(self.as_raw_mut_SparseMat(), i0, i1, i2, hashval, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -18111,7 +18247,7 @@ pub trait SparseMatTrait: core::SparseMatTraitConst {
 	/// ## C++ default parameters
 	/// * hashval: 0
 	#[inline]
-	fn erase_2(&mut self, idx: &i32, hashval: &mut size_t) -> Result<()> {
+	fn erase_2(&mut self, idx: &i32, hashval: &mut usize) -> Result<()> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_SparseMat_erase_const_intX_size_tX(self.as_raw_mut_SparseMat(), idx, hashval, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -18147,7 +18283,7 @@ pub trait SparseMatTrait: core::SparseMatTraitConst {
 	
 	/// /////////// some internal-use methods ///////////////
 	#[inline]
-	fn node_1(&mut self, nidx: size_t) -> Result<core::SparseMat_Node> {
+	fn node_1(&mut self, nidx: usize) -> Result<core::SparseMat_Node> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_SparseMat_node_size_t(self.as_raw_mut_SparseMat(), nidx, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -18157,7 +18293,7 @@ pub trait SparseMatTrait: core::SparseMatTraitConst {
 	}
 	
 	#[inline]
-	fn new_node(&mut self, idx: &i32, hashval: size_t) -> Result<*mut u8> {
+	fn new_node(&mut self, idx: &i32, hashval: usize) -> Result<*mut u8> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_SparseMat_newNode_const_intX_size_t(self.as_raw_mut_SparseMat(), idx, hashval, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -18166,7 +18302,7 @@ pub trait SparseMatTrait: core::SparseMatTraitConst {
 	}
 	
 	#[inline]
-	fn remove_node(&mut self, hidx: size_t, nidx: size_t, previdx: size_t) -> Result<()> {
+	fn remove_node(&mut self, hidx: usize, nidx: usize, previdx: usize) -> Result<()> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_SparseMat_removeNode_size_t_size_t_size_t(self.as_raw_mut_SparseMat(), hidx, nidx, previdx, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -18175,7 +18311,7 @@ pub trait SparseMatTrait: core::SparseMatTraitConst {
 	}
 	
 	#[inline]
-	fn resize_hash_tab(&mut self, newsize: size_t) -> Result<()> {
+	fn resize_hash_tab(&mut self, newsize: usize) -> Result<()> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_SparseMat_resizeHashTab_size_t(self.as_raw_mut_SparseMat(), newsize, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -18386,19 +18522,19 @@ pub trait SparseMat_HdrTraitConst {
 	}
 	
 	#[inline]
-	fn node_size(&self) -> size_t {
+	fn node_size(&self) -> usize {
 		let ret = unsafe { sys::cv_SparseMat_Hdr_getPropNodeSize_const(self.as_raw_SparseMat_Hdr()) };
 		ret
 	}
 	
 	#[inline]
-	fn node_count(&self) -> size_t {
+	fn node_count(&self) -> usize {
 		let ret = unsafe { sys::cv_SparseMat_Hdr_getPropNodeCount_const(self.as_raw_SparseMat_Hdr()) };
 		ret
 	}
 	
 	#[inline]
-	fn free_list(&self) -> size_t {
+	fn free_list(&self) -> usize {
 		let ret = unsafe { sys::cv_SparseMat_Hdr_getPropFreeList_const(self.as_raw_SparseMat_Hdr()) };
 		ret
 	}
@@ -18410,10 +18546,26 @@ pub trait SparseMat_HdrTraitConst {
 		ret
 	}
 	
+	/// Same as `pool()`, but returns the reference to the field instead of its copy
 	#[inline]
-	fn hashtab(&self) -> core::Vector<size_t> {
+	fn pool_ref(&self) -> BoxedRef<'_, core::Vector<u8>> {
+		let ret = unsafe { sys::cv_SparseMat_Hdr_getPropPoolRef_const(self.as_raw_SparseMat_Hdr()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
+	#[inline]
+	fn hashtab(&self) -> core::Vector<usize> {
 		let ret = unsafe { sys::cv_SparseMat_Hdr_getPropHashtab_const(self.as_raw_SparseMat_Hdr()) };
-		let ret = unsafe { core::Vector::<size_t>::opencv_from_extern(ret) };
+		let ret = unsafe { core::Vector::<usize>::opencv_from_extern(ret) };
+		ret
+	}
+	
+	/// Same as `hashtab()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn hashtab_ref(&self) -> BoxedRef<'_, core::Vector<usize>> {
+		let ret = unsafe { sys::cv_SparseMat_Hdr_getPropHashtabRef_const(self.as_raw_SparseMat_Hdr()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
 		ret
 	}
 	
@@ -18441,19 +18593,19 @@ pub trait SparseMat_HdrTrait: core::SparseMat_HdrTraitConst {
 	}
 	
 	#[inline]
-	fn set_node_size(&mut self, val: size_t) {
+	fn set_node_size(&mut self, val: usize) {
 		let ret = unsafe { sys::cv_SparseMat_Hdr_setPropNodeSize_size_t(self.as_raw_mut_SparseMat_Hdr(), val) };
 		ret
 	}
 	
 	#[inline]
-	fn set_node_count(&mut self, val: size_t) {
+	fn set_node_count(&mut self, val: usize) {
 		let ret = unsafe { sys::cv_SparseMat_Hdr_setPropNodeCount_size_t(self.as_raw_mut_SparseMat_Hdr(), val) };
 		ret
 	}
 	
 	#[inline]
-	fn set_free_list(&mut self, val: size_t) {
+	fn set_free_list(&mut self, val: usize) {
 		let ret = unsafe { sys::cv_SparseMat_Hdr_setPropFreeList_size_t(self.as_raw_mut_SparseMat_Hdr(), val) };
 		ret
 	}
@@ -18465,15 +18617,15 @@ pub trait SparseMat_HdrTrait: core::SparseMat_HdrTraitConst {
 	}
 	
 	#[inline]
-	fn set_hashtab(&mut self, mut val: core::Vector<size_t>) {
-		let ret = unsafe { sys::cv_SparseMat_Hdr_setPropHashtab_vectorLsize_tG(self.as_raw_mut_SparseMat_Hdr(), val.as_raw_mut_VectorOfsize_t()) };
+	fn set_hashtab(&mut self, mut val: core::Vector<usize>) {
+		let ret = unsafe { sys::cv_SparseMat_Hdr_setPropHashtab_vectorLsize_tG(self.as_raw_mut_SparseMat_Hdr(), val.as_raw_mut_VectorOfusize()) };
 		ret
 	}
 	
 	#[inline]
 	fn size(&mut self) -> &mut [i32; 32] {
 		let ret = unsafe { sys::cv_SparseMat_Hdr_getPropSize(self.as_raw_mut_SparseMat_Hdr()) };
-		let ret = unsafe { ret.as_mut() }.expect("Function returned null pointer");
+		let ret = unsafe { &mut *ret };
 		ret
 	}
 	
@@ -18531,14 +18683,14 @@ pub trait SparseMat_NodeTraitConst {
 
 	/// hash value
 	#[inline]
-	fn hashval(&self) -> size_t {
+	fn hashval(&self) -> usize {
 		let ret = unsafe { sys::cv_SparseMat_Node_getPropHashval_const(self.as_raw_SparseMat_Node()) };
 		ret
 	}
 	
 	/// index of the next node in the same hash table entry
 	#[inline]
-	fn next(&self) -> size_t {
+	fn next(&self) -> usize {
 		let ret = unsafe { sys::cv_SparseMat_Node_getPropNext_const(self.as_raw_SparseMat_Node()) };
 		ret
 	}
@@ -18550,14 +18702,14 @@ pub trait SparseMat_NodeTrait: core::SparseMat_NodeTraitConst {
 
 	/// hash value
 	#[inline]
-	fn set_hashval(&mut self, val: size_t) {
+	fn set_hashval(&mut self, val: usize) {
 		let ret = unsafe { sys::cv_SparseMat_Node_setPropHashval_size_t(self.as_raw_mut_SparseMat_Node(), val) };
 		ret
 	}
 	
 	/// index of the next node in the same hash table entry
 	#[inline]
-	fn set_next(&mut self, val: size_t) {
+	fn set_next(&mut self, val: usize) {
 		let ret = unsafe { sys::cv_SparseMat_Node_setPropNext_size_t(self.as_raw_mut_SparseMat_Node(), val) };
 		ret
 	}
@@ -18566,7 +18718,7 @@ pub trait SparseMat_NodeTrait: core::SparseMat_NodeTraitConst {
 	#[inline]
 	fn idx(&mut self) -> &mut [i32; 32] {
 		let ret = unsafe { sys::cv_SparseMat_Node_getPropIdx(self.as_raw_mut_SparseMat_Node()) };
-		let ret = unsafe { ret.as_mut() }.expect("Function returned null pointer");
+		let ret = unsafe { &mut *ret };
 		ret
 	}
 	
@@ -18620,8 +18772,16 @@ pub trait SparseMatConstIteratorTraitConst {
 		ret
 	}
 	
+	/// Same as `m()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn m_ref(&self) -> BoxedRef<'_, core::SparseMat> {
+		let ret = unsafe { sys::cv_SparseMatConstIterator_getPropMRef_const(self.as_raw_SparseMatConstIterator()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
-	fn hashidx(&self) -> size_t {
+	fn hashidx(&self) -> usize {
 		let ret = unsafe { sys::cv_SparseMatConstIterator_getPropHashidx_const(self.as_raw_SparseMatConstIterator()) };
 		ret
 	}
@@ -18643,7 +18803,7 @@ pub trait SparseMatConstIteratorTrait: core::SparseMatConstIteratorTraitConst {
 	fn as_raw_mut_SparseMatConstIterator(&mut self) -> *mut c_void;
 
 	#[inline]
-	fn set_hashidx(&mut self, val: size_t) {
+	fn set_hashidx(&mut self, val: usize) {
 		let ret = unsafe { sys::cv_SparseMatConstIterator_setPropHashidx_size_t(self.as_raw_mut_SparseMatConstIterator(), val) };
 		ret
 	}
@@ -19174,7 +19334,7 @@ pub trait UMatTraitConst {
 	
 	/// offset of the submatrix (or 0)
 	#[inline]
-	fn offset(&self) -> size_t {
+	fn offset(&self) -> usize {
 		let ret = unsafe { sys::cv_UMat_getPropOffset_const(self.as_raw_UMat()) };
 		ret
 	}
@@ -19187,6 +19347,14 @@ pub trait UMatTraitConst {
 		ret
 	}
 	
+	/// Same as `mat_size()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn mat_size_ref(&self) -> BoxedRef<'_, core::MatSize> {
+		let ret = unsafe { sys::cv_UMat_getPropSizeRef_const(self.as_raw_UMat()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// number of bytes each matrix element/row/plane/dimension occupies
 	#[inline]
 	fn mat_step(&self) -> core::MatStep {
@@ -19195,6 +19363,14 @@ pub trait UMatTraitConst {
 		ret
 	}
 	
+	/// Same as `mat_step()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn mat_step_ref(&self) -> BoxedRef<'_, core::MatStep> {
+		let ret = unsafe { sys::cv_UMat_getPropStepRef_const(self.as_raw_UMat()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn get_mat(&self, flags: core::AccessFlag) -> Result<core::Mat> {
 		return_send!(via ocvrs_return);
@@ -19448,7 +19624,7 @@ pub trait UMatTraitConst {
 	
 	/// returns element size in bytes,
 	#[inline]
-	fn elem_size(&self) -> Result<size_t> {
+	fn elem_size(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_UMat_elemSize_const(self.as_raw_UMat(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -19458,7 +19634,7 @@ pub trait UMatTraitConst {
 	
 	/// returns the size of element channel in bytes.
 	#[inline]
-	fn elem_size1(&self) -> size_t {
+	fn elem_size1(&self) -> usize {
 		let ret = unsafe { sys::cv_UMat_elemSize1_const(self.as_raw_UMat()) };
 		ret
 	}
@@ -19489,7 +19665,7 @@ pub trait UMatTraitConst {
 	/// ## C++ default parameters
 	/// * i: 0
 	#[inline]
-	fn step1(&self, i: i32) -> Result<size_t> {
+	fn step1(&self, i: i32) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_UMat_step1_const_int(self.as_raw_UMat(), i, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -19506,7 +19682,7 @@ pub trait UMatTraitConst {
 	
 	/// returns the total number of matrix elements
 	#[inline]
-	fn total(&self) -> size_t {
+	fn total(&self) -> usize {
 		let ret = unsafe { sys::cv_UMat_total_const(self.as_raw_UMat()) };
 		ret
 	}
@@ -19538,7 +19714,7 @@ pub trait UMatTraitConst {
 	}
 	
 	#[inline]
-	fn ndoffset(&self, ofs: &mut size_t) -> Result<()> {
+	fn ndoffset(&self, ofs: &mut usize) -> Result<()> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_UMat_ndoffset_const_size_tX(self.as_raw_UMat(), ofs, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -19607,7 +19783,7 @@ pub trait UMatTrait: core::UMatTraitConst {
 	
 	/// offset of the submatrix (or 0)
 	#[inline]
-	fn set_offset(&mut self, val: size_t) {
+	fn set_offset(&mut self, val: usize) {
 		let ret = unsafe { sys::cv_UMat_setPropOffset_size_t(self.as_raw_mut_UMat(), val) };
 		ret
 	}
@@ -19617,9 +19793,9 @@ pub trait UMatTrait: core::UMatTraitConst {
 	/// ## C++ default parameters
 	/// * mask: noArray()
 	#[inline]
-	fn set_to(&mut self, value: &dyn core::ToInputArray, mask: &dyn core::ToInputArray) -> Result<core::UMat> {
+	fn set_to(&mut self, value: &dyn core::ToInputArray, mask: Option<&dyn core::ToInputArray>) -> Result<core::UMat> {
 		input_array_arg!(value);
-		input_array_arg!(mask);
+		input_array_arg!(nullable mask);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_UMat_setTo_const__InputArrayR_const__InputArrayR(self.as_raw_mut_UMat(), value.as_raw__InputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -20122,7 +20298,7 @@ pub trait UMatDataTraitConst {
 	}
 	
 	#[inline]
-	fn size(&self) -> size_t {
+	fn size(&self) -> usize {
 		let ret = unsafe { sys::cv_UMatData_getPropSize_const(self.as_raw_UMatData()) };
 		ret
 	}
@@ -20243,7 +20419,7 @@ pub trait UMatDataTrait: core::UMatDataTraitConst {
 	}
 	
 	#[inline]
-	fn set_size(&mut self, val: size_t) {
+	fn set_size(&mut self, val: usize) {
 		let ret = unsafe { sys::cv_UMatData_setPropSize_size_t(self.as_raw_mut_UMatData(), val) };
 		ret
 	}
@@ -20652,7 +20828,7 @@ pub trait _InputArrayTraitConst {
 	/// ## C++ default parameters
 	/// * i: -1
 	#[inline]
-	fn total(&self, i: i32) -> Result<size_t> {
+	fn total(&self, i: i32) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv__InputArray_total_const_int(self.as_raw__InputArray(), i, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -20748,7 +20924,7 @@ pub trait _InputArrayTraitConst {
 	/// ## C++ default parameters
 	/// * i: -1
 	#[inline]
-	fn offset(&self, i: i32) -> Result<size_t> {
+	fn offset(&self, i: i32) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv__InputArray_offset_const_int(self.as_raw__InputArray(), i, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -20759,7 +20935,7 @@ pub trait _InputArrayTraitConst {
 	/// ## C++ default parameters
 	/// * i: -1
 	#[inline]
-	fn step(&self, i: i32) -> Result<size_t> {
+	fn step(&self, i: i32) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv__InputArray_step_const_int(self.as_raw__InputArray(), i, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -22171,7 +22347,7 @@ pub trait DeviceInfoTraitConst {
 	
 	/// global memory available on device in bytes
 	#[inline]
-	fn total_global_mem(&self) -> Result<size_t> {
+	fn total_global_mem(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_DeviceInfo_totalGlobalMem_const(self.as_raw_DeviceInfo(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -22181,7 +22357,7 @@ pub trait DeviceInfoTraitConst {
 	
 	/// shared memory available per block in bytes
 	#[inline]
-	fn shared_mem_per_block(&self) -> Result<size_t> {
+	fn shared_mem_per_block(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_DeviceInfo_sharedMemPerBlock_const(self.as_raw_DeviceInfo(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -22211,7 +22387,7 @@ pub trait DeviceInfoTraitConst {
 	
 	/// maximum pitch in bytes allowed by memory copies
 	#[inline]
-	fn mem_pitch(&self) -> Result<size_t> {
+	fn mem_pitch(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_DeviceInfo_memPitch_const(self.as_raw_DeviceInfo(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -22261,7 +22437,7 @@ pub trait DeviceInfoTraitConst {
 	
 	/// constant memory available on device in bytes
 	#[inline]
-	fn total_const_mem(&self) -> Result<size_t> {
+	fn total_const_mem(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_DeviceInfo_totalConstMem_const(self.as_raw_DeviceInfo(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -22291,7 +22467,7 @@ pub trait DeviceInfoTraitConst {
 	
 	/// alignment requirement for textures
 	#[inline]
-	fn texture_alignment(&self) -> Result<size_t> {
+	fn texture_alignment(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_DeviceInfo_textureAlignment_const(self.as_raw_DeviceInfo(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -22301,7 +22477,7 @@ pub trait DeviceInfoTraitConst {
 	
 	/// pitch alignment requirement for texture references bound to pitched memory
 	#[inline]
-	fn texture_pitch_alignment(&self) -> Result<size_t> {
+	fn texture_pitch_alignment(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_DeviceInfo_texturePitchAlignment_const(self.as_raw_DeviceInfo(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -22551,7 +22727,7 @@ pub trait DeviceInfoTraitConst {
 	
 	/// alignment requirements for surfaces
 	#[inline]
-	fn surface_alignment(&self) -> Result<size_t> {
+	fn surface_alignment(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_DeviceInfo_surfaceAlignment_const(self.as_raw_DeviceInfo(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -22681,7 +22857,7 @@ pub trait DeviceInfoTraitConst {
 	
 	/// gets free and total device memory
 	#[inline]
-	fn query_memory(&self, total_memory: &mut size_t, free_memory: &mut size_t) -> Result<()> {
+	fn query_memory(&self, total_memory: &mut usize, free_memory: &mut usize) -> Result<()> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_DeviceInfo_queryMemory_const_size_tR_size_tR(self.as_raw_DeviceInfo(), total_memory, free_memory, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -22690,7 +22866,7 @@ pub trait DeviceInfoTraitConst {
 	}
 	
 	#[inline]
-	fn free_memory(&self) -> Result<size_t> {
+	fn free_memory(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_DeviceInfo_freeMemory_const(self.as_raw_DeviceInfo(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -22699,7 +22875,7 @@ pub trait DeviceInfoTraitConst {
 	}
 	
 	#[inline]
-	fn total_memory(&self) -> Result<size_t> {
+	fn total_memory(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_DeviceInfo_totalMemory_const(self.as_raw_DeviceInfo(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -22892,7 +23068,7 @@ pub trait GpuDataTraitConst {
 	fn as_raw_GpuData(&self) -> *const c_void;
 
 	#[inline]
-	fn size(&self) -> size_t {
+	fn size(&self) -> usize {
 		let ret = unsafe { sys::cv_cuda_GpuData_getPropSize_const(self.as_raw_GpuData()) };
 		ret
 	}
@@ -22915,7 +23091,7 @@ pub trait GpuDataTrait: core::GpuDataTraitConst {
 	}
 	
 	#[inline]
-	fn set_size(&mut self, val: size_t) {
+	fn set_size(&mut self, val: usize) {
 		let ret = unsafe { sys::cv_cuda_GpuData_setPropSize_size_t(self.as_raw_mut_GpuData(), val) };
 		ret
 	}
@@ -22947,7 +23123,7 @@ impl core::GpuDataTrait for GpuData {
 
 impl GpuData {
 	#[inline]
-	pub fn new(_size: size_t) -> Result<core::GpuData> {
+	pub fn new(_size: usize) -> Result<core::GpuData> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_GpuData_GpuData_size_t(_size, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -23020,7 +23196,7 @@ pub trait GpuMatTraitConst {
 	
 	/// a distance between successive rows in bytes; includes the gap if any
 	#[inline]
-	fn step(&self) -> size_t {
+	fn step(&self) -> usize {
 		let ret = unsafe { sys::cv_cuda_GpuMat_getPropStep_const(self.as_raw_GpuMat()) };
 		ret
 	}
@@ -23301,7 +23477,7 @@ pub trait GpuMatTraitConst {
 	
 	/// returns element size in bytes
 	#[inline]
-	fn elem_size(&self) -> Result<size_t> {
+	fn elem_size(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_GpuMat_elemSize_const(self.as_raw_GpuMat(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -23311,7 +23487,7 @@ pub trait GpuMatTraitConst {
 	
 	/// returns the size of element channel in bytes
 	#[inline]
-	fn elem_size1(&self) -> Result<size_t> {
+	fn elem_size1(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_GpuMat_elemSize1_const(self.as_raw_GpuMat(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -23351,7 +23527,7 @@ pub trait GpuMatTraitConst {
 	
 	/// returns step/elemSize1()
 	#[inline]
-	fn step1(&self) -> Result<size_t> {
+	fn step1(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_GpuMat_step1_const(self.as_raw_GpuMat(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -23420,7 +23596,7 @@ pub trait GpuMatTrait: core::GpuMatTraitConst {
 	
 	/// a distance between successive rows in bytes; includes the gap if any
 	#[inline]
-	fn set_step(&mut self, val: size_t) {
+	fn set_step(&mut self, val: usize) {
 		let ret = unsafe { sys::cv_cuda_GpuMat_setPropStep_size_t(self.as_raw_mut_GpuMat(), val) };
 		ret
 	}
@@ -23794,7 +23970,7 @@ impl GpuMat {
 	/// ## C++ default parameters
 	/// * step: Mat::AUTO_STEP
 	#[inline]
-	pub unsafe fn new_rows_cols_with_data(rows: i32, cols: i32, typ: i32, data: *mut c_void, step: size_t) -> Result<core::GpuMat> {
+	pub unsafe fn new_rows_cols_with_data(rows: i32, cols: i32, typ: i32, data: *mut c_void, step: usize) -> Result<core::GpuMat> {
 		return_send!(via ocvrs_return);
 		{ sys::cv_cuda_GpuMat_GpuMat_int_int_int_voidX_size_t(rows, cols, typ, data, step, ocvrs_return.as_mut_ptr()) };
 		return_receive!(ocvrs_return => ret);
@@ -23806,7 +23982,7 @@ impl GpuMat {
 	/// ## C++ default parameters
 	/// * step: Mat::AUTO_STEP
 	#[inline]
-	pub unsafe fn new_size_with_data(size: core::Size, typ: i32, data: *mut c_void, step: size_t) -> Result<core::GpuMat> {
+	pub unsafe fn new_size_with_data(size: core::Size, typ: i32, data: *mut c_void, step: usize) -> Result<core::GpuMat> {
 		return_send!(via ocvrs_return);
 		{ sys::cv_cuda_GpuMat_GpuMat_Size_int_voidX_size_t(size.opencv_as_extern(), typ, data, step, ocvrs_return.as_mut_ptr()) };
 		return_receive!(ocvrs_return => ret);
@@ -23841,7 +24017,7 @@ impl GpuMat {
 	/// ## C++ default parameters
 	/// * allocator: GpuMat::defaultAllocator()
 	#[inline]
-	pub unsafe fn from_hostmem(arr: &dyn core::ToInputArray, allocator: &mut dyn core::GpuMat_Allocator) -> Result<core::GpuMat> {
+	pub unsafe fn from_hostmem(arr: &(impl core::ToInputArray + ?Sized), allocator: &mut dyn core::GpuMat_Allocator) -> Result<core::GpuMat> {
 		input_array_arg!(arr);
 		return_send!(via ocvrs_return);
 		{ sys::cv_cuda_GpuMat_GpuMat_const__InputArrayR_AllocatorX(arr.as_raw__InputArray(), allocator.as_raw_mut_GpuMat_Allocator(), ocvrs_return.as_mut_ptr()) };
@@ -23870,7 +24046,7 @@ pub trait GpuMat_Allocator: core::GpuMat_AllocatorConst {
 	fn as_raw_mut_GpuMat_Allocator(&mut self) -> *mut c_void;
 
 	#[inline]
-	fn allocate(&mut self, mat: &mut core::GpuMat, rows: i32, cols: i32, elem_size: size_t) -> Result<bool> {
+	fn allocate(&mut self, mat: &mut core::GpuMat, rows: i32, cols: i32, elem_size: usize) -> Result<bool> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_GpuMat_Allocator_allocate_GpuMatX_int_int_size_t(self.as_raw_mut_GpuMat_Allocator(), mat.as_raw_mut_GpuMat(), rows, cols, elem_size, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -23918,12 +24094,28 @@ pub trait GpuMatNDTraitConst {
 		ret
 	}
 	
+	/// Same as `size()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn size_ref(&self) -> BoxedRef<'_, core::Vector<i32>> {
+		let ret = unsafe { sys::cv_cuda_GpuMatND_getPropSizeRef_const(self.as_raw_GpuMatND()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// ! step values
 	/// Their semantics is identical to the semantics of step for Mat.
 	#[inline]
-	fn step(&self) -> core::Vector<size_t> {
+	fn step(&self) -> core::Vector<usize> {
 		let ret = unsafe { sys::cv_cuda_GpuMatND_getPropStep_const(self.as_raw_GpuMatND()) };
-		let ret = unsafe { core::Vector::<size_t>::opencv_from_extern(ret) };
+		let ret = unsafe { core::Vector::<usize>::opencv_from_extern(ret) };
+		ret
+	}
+	
+	/// Same as `step()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn step_ref(&self) -> BoxedRef<'_, core::Vector<usize>> {
+		let ret = unsafe { sys::cv_cuda_GpuMatND_getPropStepRef_const(self.as_raw_GpuMatND()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
 		ret
 	}
 	
@@ -24057,7 +24249,7 @@ pub trait GpuMatNDTraitConst {
 	
 	/// returns element size in bytes
 	#[inline]
-	fn elem_size(&self) -> Result<size_t> {
+	fn elem_size(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_GpuMatND_elemSize_const(self.as_raw_GpuMatND(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -24067,7 +24259,7 @@ pub trait GpuMatNDTraitConst {
 	
 	/// returns the size of element channel in bytes
 	#[inline]
-	fn elem_size1(&self) -> Result<size_t> {
+	fn elem_size1(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_GpuMatND_elemSize1_const(self.as_raw_GpuMatND(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -24107,7 +24299,7 @@ pub trait GpuMatNDTraitConst {
 	
 	/// returns the total number of array elements
 	#[inline]
-	fn total(&self) -> Result<size_t> {
+	fn total(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_GpuMatND_total_const(self.as_raw_GpuMatND(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -24117,7 +24309,7 @@ pub trait GpuMatNDTraitConst {
 	
 	/// returns the size of underlying memory in bytes
 	#[inline]
-	fn total_mem_size(&self) -> Result<size_t> {
+	fn total_mem_size(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_GpuMatND_totalMemSize_const(self.as_raw_GpuMatND(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -24169,7 +24361,7 @@ pub trait GpuMatNDTrait: core::GpuMatNDTraitConst {
 	/// Their semantics is identical to the semantics of step for Mat.
 	#[inline]
 	fn set_step(&mut self, mut val: core::GpuMatND_StepArray) {
-		let ret = unsafe { sys::cv_cuda_GpuMatND_setPropStep_StepArray(self.as_raw_mut_GpuMatND(), val.as_raw_mut_VectorOfsize_t()) };
+		let ret = unsafe { sys::cv_cuda_GpuMatND_setPropStep_StepArray(self.as_raw_mut_GpuMatND(), val.as_raw_mut_VectorOfusize()) };
 		ret
 	}
 	
@@ -24299,7 +24491,7 @@ impl GpuMatND {
 	#[inline]
 	pub unsafe fn new_1(mut size: core::GpuMatND_SizeArray, typ: i32, data: *mut c_void, mut step: core::GpuMatND_StepArray) -> Result<core::GpuMatND> {
 		return_send!(via ocvrs_return);
-		{ sys::cv_cuda_GpuMatND_GpuMatND_SizeArray_int_voidX_StepArray(size.as_raw_mut_VectorOfi32(), typ, data, step.as_raw_mut_VectorOfsize_t(), ocvrs_return.as_mut_ptr()) };
+		{ sys::cv_cuda_GpuMatND_GpuMatND_SizeArray_int_voidX_StepArray(size.as_raw_mut_VectorOfi32(), typ, data, step.as_raw_mut_VectorOfusize(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = { core::GpuMatND::opencv_from_extern(ret) };
@@ -24367,7 +24559,7 @@ pub trait HostMemTraitConst {
 	}
 	
 	#[inline]
-	fn step(&self) -> size_t {
+	fn step(&self) -> usize {
 		let ret = unsafe { sys::cv_cuda_HostMem_getPropStep_const(self.as_raw_HostMem()) };
 		ret
 	}
@@ -24449,7 +24641,7 @@ pub trait HostMemTraitConst {
 	}
 	
 	#[inline]
-	fn elem_size(&self) -> Result<size_t> {
+	fn elem_size(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_HostMem_elemSize_const(self.as_raw_HostMem(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -24458,7 +24650,7 @@ pub trait HostMemTraitConst {
 	}
 	
 	#[inline]
-	fn elem_size1(&self) -> Result<size_t> {
+	fn elem_size1(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_HostMem_elemSize1_const(self.as_raw_HostMem(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -24494,7 +24686,7 @@ pub trait HostMemTraitConst {
 	}
 	
 	#[inline]
-	fn step1(&self) -> Result<size_t> {
+	fn step1(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_HostMem_step1_const(self.as_raw_HostMem(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -24544,7 +24736,7 @@ pub trait HostMemTrait: core::HostMemTraitConst {
 	}
 	
 	#[inline]
-	fn set_step(&mut self, val: size_t) {
+	fn set_step(&mut self, val: usize) {
 		let ret = unsafe { sys::cv_cuda_HostMem_setPropStep_size_t(self.as_raw_mut_HostMem(), val) };
 		ret
 	}
@@ -24722,7 +24914,7 @@ impl HostMem {
 	/// ## C++ default parameters
 	/// * alloc_type: HostMem::AllocType::PAGE_LOCKED
 	#[inline]
-	pub fn new_3(arr: &dyn core::ToInputArray, alloc_type: core::HostMem_AllocType) -> Result<core::HostMem> {
+	pub fn new_3(arr: &(impl core::ToInputArray + ?Sized), alloc_type: core::HostMem_AllocType) -> Result<core::HostMem> {
 		input_array_arg!(arr);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_HostMem_HostMem_const__InputArrayR_AllocType(arr.as_raw__InputArray(), alloc_type, ocvrs_return.as_mut_ptr()) };
@@ -24929,7 +25121,7 @@ impl Stream {
 	/// ```
 	/// 
 	#[inline]
-	pub fn new_1(cuda_flags: size_t) -> Result<core::Stream> {
+	pub fn new_1(cuda_flags: usize) -> Result<core::Stream> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_Stream_Stream_const_size_t(cuda_flags, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -25089,6 +25281,14 @@ pub trait Detail_CheckContextTraitConst {
 		ret
 	}
 	
+	/// Same as `func()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn func_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_detail_CheckContext_getPropFuncRef_const(self.as_raw_Detail_CheckContext()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	#[inline]
 	fn file(&self) -> String {
 		let ret = unsafe { sys::cv_detail_CheckContext_getPropFile_const(self.as_raw_Detail_CheckContext()) };
@@ -25096,6 +25296,14 @@ pub trait Detail_CheckContextTraitConst {
 		ret
 	}
 	
+	/// Same as `file()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn file_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_detail_CheckContext_getPropFileRef_const(self.as_raw_Detail_CheckContext()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	#[inline]
 	fn line(&self) -> i32 {
 		let ret = unsafe { sys::cv_detail_CheckContext_getPropLine_const(self.as_raw_Detail_CheckContext()) };
@@ -25117,6 +25325,14 @@ pub trait Detail_CheckContextTraitConst {
 		ret
 	}
 	
+	/// Same as `message()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn message_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_detail_CheckContext_getPropMessageRef_const(self.as_raw_Detail_CheckContext()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	#[inline]
 	fn p1_str(&self) -> String {
 		let ret = unsafe { sys::cv_detail_CheckContext_getPropP1_str_const(self.as_raw_Detail_CheckContext()) };
@@ -25124,6 +25340,14 @@ pub trait Detail_CheckContextTraitConst {
 		ret
 	}
 	
+	/// Same as `p1_str()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn p1_str_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_detail_CheckContext_getPropP1_strRef_const(self.as_raw_Detail_CheckContext()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	#[inline]
 	fn p2_str(&self) -> String {
 		let ret = unsafe { sys::cv_detail_CheckContext_getPropP2_str_const(self.as_raw_Detail_CheckContext()) };
@@ -25131,6 +25355,14 @@ pub trait Detail_CheckContextTraitConst {
 		ret
 	}
 	
+	/// Same as `p2_str()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn p2_str_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_detail_CheckContext_getPropP2_strRef_const(self.as_raw_Detail_CheckContext()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 }
 
 pub trait Detail_CheckContextTrait: core::Detail_CheckContextTraitConst {
@@ -25186,6 +25418,14 @@ pub trait NodeDataTraitConst {
 		ret
 	}
 	
+	/// Same as `m_fun_name()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn m_fun_name_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_instr_NodeData_getPropM_funNameRef_const(self.as_raw_NodeData()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	#[inline]
 	fn m_instr_type(&self) -> core::TYPE {
 		return_send!(via ocvrs_return);
@@ -25209,6 +25449,14 @@ pub trait NodeDataTraitConst {
 		ret
 	}
 	
+	/// Same as `m_file_name()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn m_file_name_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_instr_NodeData_getPropM_fileNameRef_const(self.as_raw_NodeData()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	#[inline]
 	fn m_line_num(&self) -> i32 {
 		let ret = unsafe { sys::cv_instr_NodeData_getPropM_lineNum_const(self.as_raw_NodeData()) };
@@ -25447,7 +25695,7 @@ pub trait ContextTraitConst {
 	fn as_raw_Context(&self) -> *const c_void;
 
 	#[inline]
-	fn ndevices(&self) -> Result<size_t> {
+	fn ndevices(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ocl_Context_ndevices_const(self.as_raw_Context(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -25456,7 +25704,7 @@ pub trait ContextTraitConst {
 	}
 	
 	#[inline]
-	fn device(&self, idx: size_t) -> Result<core::Device> {
+	fn device(&self, idx: usize) -> Result<core::Device> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ocl_Context_device_const_size_t(self.as_raw_Context(), idx, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -25934,7 +26182,7 @@ pub trait DeviceTraitConst {
 	}
 	
 	#[inline]
-	fn global_mem_cache_size(&self) -> Result<size_t> {
+	fn global_mem_cache_size(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ocl_Device_globalMemCacheSize_const(self.as_raw_Device(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -25961,7 +26209,7 @@ pub trait DeviceTraitConst {
 	}
 	
 	#[inline]
-	fn global_mem_size(&self) -> Result<size_t> {
+	fn global_mem_size(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ocl_Device_globalMemSize_const(self.as_raw_Device(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -25970,7 +26218,7 @@ pub trait DeviceTraitConst {
 	}
 	
 	#[inline]
-	fn local_mem_size(&self) -> Result<size_t> {
+	fn local_mem_size(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ocl_Device_localMemSize_const(self.as_raw_Device(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -26043,7 +26291,7 @@ pub trait DeviceTraitConst {
 	}
 	
 	#[inline]
-	fn image2_d_max_width(&self) -> Result<size_t> {
+	fn image2_d_max_width(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ocl_Device_image2DMaxWidth_const(self.as_raw_Device(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -26052,7 +26300,7 @@ pub trait DeviceTraitConst {
 	}
 	
 	#[inline]
-	fn image2_d_max_height(&self) -> Result<size_t> {
+	fn image2_d_max_height(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ocl_Device_image2DMaxHeight_const(self.as_raw_Device(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -26061,7 +26309,7 @@ pub trait DeviceTraitConst {
 	}
 	
 	#[inline]
-	fn image3_d_max_width(&self) -> Result<size_t> {
+	fn image3_d_max_width(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ocl_Device_image3DMaxWidth_const(self.as_raw_Device(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -26070,7 +26318,7 @@ pub trait DeviceTraitConst {
 	}
 	
 	#[inline]
-	fn image3_d_max_height(&self) -> Result<size_t> {
+	fn image3_d_max_height(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ocl_Device_image3DMaxHeight_const(self.as_raw_Device(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -26079,7 +26327,7 @@ pub trait DeviceTraitConst {
 	}
 	
 	#[inline]
-	fn image3_d_max_depth(&self) -> Result<size_t> {
+	fn image3_d_max_depth(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ocl_Device_image3DMaxDepth_const(self.as_raw_Device(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -26088,7 +26336,7 @@ pub trait DeviceTraitConst {
 	}
 	
 	#[inline]
-	fn image_max_buffer_size(&self) -> Result<size_t> {
+	fn image_max_buffer_size(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ocl_Device_imageMaxBufferSize_const(self.as_raw_Device(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -26097,7 +26345,7 @@ pub trait DeviceTraitConst {
 	}
 	
 	#[inline]
-	fn image_max_array_size(&self) -> Result<size_t> {
+	fn image_max_array_size(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ocl_Device_imageMaxArraySize_const(self.as_raw_Device(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -26169,7 +26417,7 @@ pub trait DeviceTraitConst {
 	}
 	
 	#[inline]
-	fn max_constant_buffer_size(&self) -> Result<size_t> {
+	fn max_constant_buffer_size(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ocl_Device_maxConstantBufferSize_const(self.as_raw_Device(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -26178,7 +26426,7 @@ pub trait DeviceTraitConst {
 	}
 	
 	#[inline]
-	fn max_mem_alloc_size(&self) -> Result<size_t> {
+	fn max_mem_alloc_size(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ocl_Device_maxMemAllocSize_const(self.as_raw_Device(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -26187,7 +26435,7 @@ pub trait DeviceTraitConst {
 	}
 	
 	#[inline]
-	fn max_parameter_size(&self) -> Result<size_t> {
+	fn max_parameter_size(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ocl_Device_maxParameterSize_const(self.as_raw_Device(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -26223,7 +26471,7 @@ pub trait DeviceTraitConst {
 	}
 	
 	#[inline]
-	fn max_work_group_size(&self) -> Result<size_t> {
+	fn max_work_group_size(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ocl_Device_maxWorkGroupSize_const(self.as_raw_Device(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -26241,7 +26489,7 @@ pub trait DeviceTraitConst {
 	}
 	
 	#[inline]
-	fn max_work_item_sizes(&self, unnamed: &mut size_t) -> Result<()> {
+	fn max_work_item_sizes(&self, unnamed: &mut usize) -> Result<()> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ocl_Device_maxWorkItemSizes_const_size_tX(self.as_raw_Device(), unnamed, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -26385,7 +26633,7 @@ pub trait DeviceTraitConst {
 	}
 	
 	#[inline]
-	fn printf_buffer_size(&self) -> Result<size_t> {
+	fn printf_buffer_size(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ocl_Device_printfBufferSize_const(self.as_raw_Device(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -26394,7 +26642,7 @@ pub trait DeviceTraitConst {
 	}
 	
 	#[inline]
-	fn profiling_timer_resolution(&self) -> Result<size_t> {
+	fn profiling_timer_resolution(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ocl_Device_profilingTimerResolution_const(self.as_raw_Device(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -26652,7 +26900,7 @@ pub trait KernelTraitConst {
 	}
 	
 	#[inline]
-	fn work_group_size(&self) -> Result<size_t> {
+	fn work_group_size(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ocl_Kernel_workGroupSize_const(self.as_raw_Kernel(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -26661,7 +26909,7 @@ pub trait KernelTraitConst {
 	}
 	
 	#[inline]
-	fn prefered_work_group_size_multiple(&self) -> Result<size_t> {
+	fn prefered_work_group_size_multiple(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ocl_Kernel_preferedWorkGroupSizeMultiple_const(self.as_raw_Kernel(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -26670,7 +26918,7 @@ pub trait KernelTraitConst {
 	}
 	
 	#[inline]
-	fn compile_work_group_size(&self, wsz: &mut [size_t]) -> Result<bool> {
+	fn compile_work_group_size(&self, wsz: &mut [usize]) -> Result<bool> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ocl_Kernel_compileWorkGroupSize_const_size_tX(self.as_raw_Kernel(), wsz.as_mut_ptr(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -26679,7 +26927,7 @@ pub trait KernelTraitConst {
 	}
 	
 	#[inline]
-	fn local_mem_size(&self) -> Result<size_t> {
+	fn local_mem_size(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ocl_Kernel_localMemSize_const(self.as_raw_Kernel(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -26727,7 +26975,7 @@ pub trait KernelTrait: core::KernelTraitConst {
 	}
 	
 	#[inline]
-	unsafe fn set(&mut self, i: i32, value: *const c_void, sz: size_t) -> Result<i32> {
+	unsafe fn set(&mut self, i: i32, value: *const c_void, sz: usize) -> Result<i32> {
 		return_send!(via ocvrs_return);
 		{ sys::cv_ocl_Kernel_set_int_const_voidX_size_t(self.as_raw_mut_Kernel(), i, value, sz, ocvrs_return.as_mut_ptr()) };
 		return_receive!(ocvrs_return => ret);
@@ -26780,9 +27028,9 @@ pub trait KernelTrait: core::KernelTraitConst {
 	/// ## C++ default parameters
 	/// * q: Queue()
 	#[inline]
-	fn run(&mut self, dims: i32, globalsize: &mut [size_t], localsize: &mut [size_t], sync: bool, q: &core::Queue) -> Result<bool> {
+	fn run(&mut self, dims: i32, globalsize: &mut [usize], localsize: &mut [usize], sync: bool, q: Option<&core::Queue>) -> Result<bool> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ocl_Kernel_run_int_size_tX_size_tX_bool_const_QueueR(self.as_raw_mut_Kernel(), dims, globalsize.as_mut_ptr(), localsize.as_mut_ptr(), sync, q.as_raw_Queue(), ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_ocl_Kernel_run_int_size_tX_size_tX_bool_const_QueueR(self.as_raw_mut_Kernel(), dims, globalsize.as_mut_ptr(), localsize.as_mut_ptr(), sync, q.map_or(::core::ptr::null(), |q| q.as_raw_Queue()), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -26800,9 +27048,9 @@ pub trait KernelTrait: core::KernelTraitConst {
 	/// ## C++ default parameters
 	/// * q: Queue()
 	#[inline]
-	fn run_(&mut self, dims: i32, globalsize: &mut [size_t], localsize: &mut [size_t], sync: bool, q: &core::Queue) -> Result<bool> {
+	fn run_(&mut self, dims: i32, globalsize: &mut [usize], localsize: &mut [usize], sync: bool, q: Option<&core::Queue>) -> Result<bool> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ocl_Kernel_run__int_size_tX_size_tX_bool_const_QueueR(self.as_raw_mut_Kernel(), dims, globalsize.as_mut_ptr(), localsize.as_mut_ptr(), sync, q.as_raw_Queue(), ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_ocl_Kernel_run__int_size_tX_size_tX_bool_const_QueueR(self.as_raw_mut_Kernel(), dims, globalsize.as_mut_ptr(), localsize.as_mut_ptr(), sync, q.map_or(::core::ptr::null(), |q| q.as_raw_Queue()), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -26811,9 +27059,9 @@ pub trait KernelTrait: core::KernelTraitConst {
 	/// ## C++ default parameters
 	/// * q: Queue()
 	#[inline]
-	fn run_task(&mut self, sync: bool, q: &core::Queue) -> Result<bool> {
+	fn run_task(&mut self, sync: bool, q: Option<&core::Queue>) -> Result<bool> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ocl_Kernel_runTask_bool_const_QueueR(self.as_raw_mut_Kernel(), sync, q.as_raw_Queue(), ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_ocl_Kernel_runTask_bool_const_QueueR(self.as_raw_mut_Kernel(), sync, q.map_or(::core::ptr::null(), |q| q.as_raw_Queue()), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -26828,9 +27076,9 @@ pub trait KernelTrait: core::KernelTraitConst {
 	/// ## C++ default parameters
 	/// * q: Queue()
 	#[inline]
-	fn run_profiling(&mut self, dims: i32, globalsize: &mut [size_t], localsize: &mut [size_t], q: &core::Queue) -> Result<i64> {
+	fn run_profiling(&mut self, dims: i32, globalsize: &mut [usize], localsize: &mut [usize], q: Option<&core::Queue>) -> Result<i64> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ocl_Kernel_runProfiling_int_size_tX_size_tX_const_QueueR(self.as_raw_mut_Kernel(), dims, globalsize.as_mut_ptr(), localsize.as_mut_ptr(), q.as_raw_Queue(), ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_ocl_Kernel_runProfiling_int_size_tX_size_tX_const_QueueR(self.as_raw_mut_Kernel(), dims, globalsize.as_mut_ptr(), localsize.as_mut_ptr(), q.map_or(::core::ptr::null(), |q| q.as_raw_Queue()), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -26940,7 +27188,7 @@ pub trait KernelArgTraitConst {
 	}
 	
 	#[inline]
-	fn sz(&self) -> size_t {
+	fn sz(&self) -> usize {
 		let ret = unsafe { sys::cv_ocl_KernelArg_getPropSz_const(self.as_raw_KernelArg()) };
 		ret
 	}
@@ -26982,7 +27230,7 @@ pub trait KernelArgTrait: core::KernelArgTraitConst {
 	}
 	
 	#[inline]
-	fn set_sz(&mut self, val: size_t) {
+	fn set_sz(&mut self, val: usize) {
 		let ret = unsafe { sys::cv_ocl_KernelArg_setPropSz_size_t(self.as_raw_mut_KernelArg(), val) };
 		ret
 	}
@@ -27031,7 +27279,7 @@ impl KernelArg {
 	/// * _obj: 0
 	/// * _sz: 0
 	#[inline]
-	pub unsafe fn new(_flags: i32, _m: &mut core::UMat, wscale: i32, iwscale: i32, _obj: *const c_void, _sz: size_t) -> Result<core::KernelArg> {
+	pub unsafe fn new(_flags: i32, _m: &mut core::UMat, wscale: i32, iwscale: i32, _obj: *const c_void, _sz: usize) -> Result<core::KernelArg> {
 		return_send!(via ocvrs_return);
 		{ sys::cv_ocl_KernelArg_KernelArg_int_UMatX_int_int_const_voidX_size_t(_flags, _m.as_raw_mut_UMat(), wscale, iwscale, _obj, _sz, ocvrs_return.as_mut_ptr()) };
 		return_receive!(ocvrs_return => ret);
@@ -27048,7 +27296,7 @@ impl KernelArg {
 	}
 	
 	#[inline]
-	pub fn local(local_mem_size: size_t) -> Result<core::KernelArg> {
+	pub fn local(local_mem_size: usize) -> Result<core::KernelArg> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ocl_KernelArg_Local_size_t(local_mem_size, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -27199,6 +27447,17 @@ pub trait OpenCLExecutionContextTraitConst {
 		Ok(ret)
 	}
 	
+	/// Same as `get_context()`, but returns the reference instead of the copy
+	#[inline]
+	fn get_context_ref(&self) -> Result<BoxedRef<'_, core::Context>> {
+		return_send!(via ocvrs_return);
+		unsafe { sys::cv_ocl_OpenCLExecutionContext_getContextRef_const(self.as_raw_OpenCLExecutionContext(), ocvrs_return.as_mut_ptr()) };
+		return_receive!(unsafe ocvrs_return => ret);
+		let ret = ret.into_result()?;
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		Ok(ret)
+	}
+	
 	/// Get the single default associated ocl::Device
 	#[inline]
 	fn get_device(&self) -> Result<core::Device> {
@@ -27210,6 +27469,17 @@ pub trait OpenCLExecutionContextTraitConst {
 		Ok(ret)
 	}
 	
+	/// Same as `get_device()`, but returns the reference instead of the copy
+	#[inline]
+	fn get_device_ref(&self) -> Result<BoxedRef<'_, core::Device>> {
+		return_send!(via ocvrs_return);
+		unsafe { sys::cv_ocl_OpenCLExecutionContext_getDeviceRef_const(self.as_raw_OpenCLExecutionContext(), ocvrs_return.as_mut_ptr()) };
+		return_receive!(unsafe ocvrs_return => ret);
+		let ret = ret.into_result()?;
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		Ok(ret)
+	}
+	
 	/// Get the single ocl::Queue that is associated with the ocl::Context and
 	/// the single default ocl::Device
 	#[inline]
@@ -27222,6 +27492,17 @@ pub trait OpenCLExecutionContextTraitConst {
 		Ok(ret)
 	}
 	
+	/// Same as `get_queue()`, but returns the reference instead of the copy
+	#[inline]
+	fn get_queue_ref(&self) -> Result<BoxedRef<'_, core::Queue>> {
+		return_send!(via ocvrs_return);
+		unsafe { sys::cv_ocl_OpenCLExecutionContext_getQueueRef_const(self.as_raw_OpenCLExecutionContext(), ocvrs_return.as_mut_ptr()) };
+		return_receive!(unsafe ocvrs_return => ret);
+		let ret = ret.into_result()?;
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		Ok(ret)
+	}
+	
 	#[inline]
 	fn use_opencl(&self) -> Result<bool> {
 		return_send!(via ocvrs_return);
@@ -27765,6 +28046,17 @@ pub trait ProgramTraitConst {
 		Ok(ret)
 	}
 	
+	/// Same as `source()`, but returns the reference instead of the copy
+	#[inline]
+	fn source_ref(&self) -> Result<BoxedRef<'_, core::ProgramSource>> {
+		return_send!(via ocvrs_return);
+		unsafe { sys::cv_ocl_Program_sourceRef_const(self.as_raw_Program(), ocvrs_return.as_mut_ptr()) };
+		return_receive!(unsafe ocvrs_return => ret);
+		let ret = ret.into_result()?;
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		Ok(ret)
+	}
+	
 	#[inline]
 	fn get_prefix(&self) -> Result<String> {
 		return_send!(via ocvrs_return);
@@ -27900,6 +28192,17 @@ pub trait ProgramSourceTraitConst {
 		Ok(ret)
 	}
 	
+	/// Same as `source()`, but returns the reference instead of the copy
+	#[inline]
+	fn source_ref(&self) -> Result<&str> {
+		return_send!(via ocvrs_return);
+		unsafe { sys::cv_ocl_ProgramSource_sourceRef_const(self.as_raw_ProgramSource(), ocvrs_return.as_mut_ptr()) };
+		return_receive!(unsafe ocvrs_return => ret);
+		let ret = ret.into_result()?;
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	#[inline]
 	fn hash(&self) -> Result<core::ProgramSource_hash_t> {
 		return_send!(via ocvrs_return);
@@ -28017,7 +28320,7 @@ impl ProgramSource {
 	/// ## C++ default parameters
 	/// * build_options: cv::String()
 	#[inline]
-	pub fn from_binary(module: &str, name: &str, binary: &u8, size: size_t, build_options: &str) -> Result<core::ProgramSource> {
+	pub fn from_binary(module: &str, name: &str, binary: &u8, size: usize, build_options: &str) -> Result<core::ProgramSource> {
 		extern_container_arg!(module);
 		extern_container_arg!(name);
 		extern_container_arg!(build_options);
@@ -28055,7 +28358,7 @@ impl ProgramSource {
 	/// ## C++ default parameters
 	/// * build_options: cv::String()
 	#[inline]
-	pub fn from_spir(module: &str, name: &str, binary: &u8, size: size_t, build_options: &str) -> Result<core::ProgramSource> {
+	pub fn from_spir(module: &str, name: &str, binary: &u8, size: usize, build_options: &str) -> Result<core::ProgramSource> {
 		extern_container_arg!(module);
 		extern_container_arg!(name);
 		extern_container_arg!(build_options);
@@ -28118,9 +28421,9 @@ pub trait QueueTrait: core::QueueTraitConst {
 	/// * c: Context()
 	/// * d: Device()
 	#[inline]
-	fn create(&mut self, c: &core::Context, d: &core::Device) -> Result<bool> {
+	fn create(&mut self, c: Option<&core::Context>, d: Option<&core::Device>) -> Result<bool> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ocl_Queue_create_const_ContextR_const_DeviceR(self.as_raw_mut_Queue(), c.as_raw_Context(), d.as_raw_Device(), ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_ocl_Queue_create_const_ContextR_const_DeviceR(self.as_raw_mut_Queue(), c.map_or(::core::ptr::null(), |c| c.as_raw_Context()), d.map_or(::core::ptr::null(), |d| d.as_raw_Device()), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -28171,9 +28474,9 @@ impl Queue {
 	/// ## C++ default parameters
 	/// * d: Device()
 	#[inline]
-	pub fn new(c: &core::Context, d: &core::Device) -> Result<core::Queue> {
+	pub fn new(c: &core::Context, d: Option<&core::Device>) -> Result<core::Queue> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_ocl_Queue_Queue_const_ContextR_const_DeviceR(c.as_raw_Context(), d.as_raw_Device(), ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_ocl_Queue_Queue_const_ContextR_const_DeviceR(c.as_raw_Context(), d.map_or(::core::ptr::null(), |d| d.as_raw_Device()), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Queue::opencv_from_extern(ret) };
@@ -29026,7 +29329,7 @@ impl Buffer {
 	/// * target: ARRAY_BUFFER
 	/// * auto_release: false
 	#[inline]
-	pub fn new_4(arr: &dyn core::ToInputArray, target: core::Buffer_Target, auto_release: bool) -> Result<core::Buffer> {
+	pub fn new_4(arr: &(impl core::ToInputArray + ?Sized), target: core::Buffer_Target, auto_release: bool) -> Result<core::Buffer> {
 		input_array_arg!(arr);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ogl_Buffer_Buffer_const__InputArrayR_Target_bool(arr.as_raw__InputArray(), target, auto_release, ocvrs_return.as_mut_ptr()) };
@@ -29381,7 +29684,7 @@ impl Texture2D {
 	/// ## C++ default parameters
 	/// * auto_release: false
 	#[inline]
-	pub fn new_4(arr: &dyn core::ToInputArray, auto_release: bool) -> Result<core::Texture2D> {
+	pub fn new_4(arr: &(impl core::ToInputArray + ?Sized), auto_release: bool) -> Result<core::Texture2D> {
 		input_array_arg!(arr);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ogl_Texture2D_Texture2D_const__InputArrayR_bool(arr.as_raw__InputArray(), auto_release, ocvrs_return.as_mut_ptr()) };
@@ -29403,6 +29706,14 @@ pub trait LogTagTraitConst {
 		ret
 	}
 	
+	/// Same as `name()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn name_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_utils_logging_LogTag_getPropNameRef_const(self.as_raw_LogTag()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	#[inline]
 	fn level(&self) -> core::LogLevel {
 		return_send!(via ocvrs_return);
@@ -27,9 +27,9 @@ pub mod prelude {
 /// ## C++ default parameters
 /// * mask: noArray()
 #[inline]
-pub fn abs_sum(src: &dyn core::ToInputArray, mask: &dyn core::ToInputArray) -> Result<core::Scalar> {
+pub fn abs_sum(src: &(impl core::ToInputArray + ?Sized), mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<core::Scalar> {
 	input_array_arg!(src);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_absSum_const__InputArrayR_const__InputArrayR(src.as_raw__InputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -49,7 +49,7 @@ pub fn abs_sum(src: &dyn core::ToInputArray, mask: &dyn core::ToInputArray) -> R
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn abs(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn abs(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -72,7 +72,7 @@ pub fn abs(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, stre
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn absdiff(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn absdiff(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
@@ -109,7 +109,7 @@ pub fn absdiff(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst
 /// * dtype: -1
 /// * stream: Stream::Null()
 #[inline]
-pub fn add_weighted(src1: &dyn core::ToInputArray, alpha: f64, src2: &dyn core::ToInputArray, beta: f64, gamma: f64, dst: &mut dyn core::ToOutputArray, dtype: i32, stream: &mut core::Stream) -> Result<()> {
+pub fn add_weighted(src1: &(impl core::ToInputArray + ?Sized), alpha: f64, src2: &(impl core::ToInputArray + ?Sized), beta: f64, gamma: f64, dst: &mut (impl core::ToOutputArray + ?Sized), dtype: i32, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
@@ -139,11 +139,11 @@ pub fn add_weighted(src1: &dyn core::ToInputArray, alpha: f64, src2: &dyn core::
 /// * dtype: -1
 /// * stream: Stream::Null()
 #[inline]
-pub fn add(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray, dtype: i32, stream: &mut core::Stream) -> Result<()> {
+pub fn add(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), mask: Option<&(impl core::ToInputArray + ?Sized)>, dtype: i32, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_add_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR_int_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), dtype, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -165,11 +165,11 @@ pub fn add(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &m
 /// * mask: noArray()
 /// * stream: Stream::Null()
 #[inline]
-pub fn bitwise_and(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn bitwise_and(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), mask: Option<&(impl core::ToInputArray + ?Sized)>, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_bitwise_and_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -190,10 +190,10 @@ pub fn bitwise_and(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray,
 /// * mask: noArray()
 /// * stream: Stream::Null()
 #[inline]
-pub fn bitwise_not(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn bitwise_not(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), mask: Option<&(impl core::ToInputArray + ?Sized)>, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_bitwise_not_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -215,11 +215,11 @@ pub fn bitwise_not(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArr
 /// * mask: noArray()
 /// * stream: Stream::Null()
 #[inline]
-pub fn bitwise_or(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn bitwise_or(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), mask: Option<&(impl core::ToInputArray + ?Sized)>, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_bitwise_or_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -241,11 +241,11 @@ pub fn bitwise_or(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray,
 /// * mask: noArray()
 /// * stream: Stream::Null()
 #[inline]
-pub fn bitwise_xor(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn bitwise_xor(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), mask: Option<&(impl core::ToInputArray + ?Sized)>, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_bitwise_xor_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -259,10 +259,10 @@ pub fn bitwise_xor(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray,
 /// * mask: noArray()
 /// * stream: Stream::Null()
 #[inline]
-pub fn calc_abs_sum(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn calc_abs_sum(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), mask: Option<&(impl core::ToInputArray + ?Sized)>, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_calcAbsSum_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -276,7 +276,7 @@ pub fn calc_abs_sum(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputAr
 /// * norm_type: NORM_L2
 /// * stream: Stream::Null()
 #[inline]
-pub fn calc_norm_diff(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, norm_type: i32, stream: &mut core::Stream) -> Result<()> {
+pub fn calc_norm_diff(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), norm_type: i32, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
@@ -293,10 +293,10 @@ pub fn calc_norm_diff(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArr
 /// * mask: noArray()
 /// * stream: Stream::Null()
 #[inline]
-pub fn calc_norm(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, norm_type: i32, mask: &dyn core::ToInputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn calc_norm(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), norm_type: i32, mask: Option<&(impl core::ToInputArray + ?Sized)>, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_calcNorm_const__InputArrayR_const__OutputArrayR_int_const__InputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), norm_type, mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -310,10 +310,10 @@ pub fn calc_norm(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 /// * mask: noArray()
 /// * stream: Stream::Null()
 #[inline]
-pub fn calc_sqr_sum(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn calc_sqr_sum(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), mask: Option<&(impl core::ToInputArray + ?Sized)>, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_calcSqrSum_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -327,10 +327,10 @@ pub fn calc_sqr_sum(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputAr
 /// * mask: noArray()
 /// * stream: Stream::Null()
 #[inline]
-pub fn calc_sum(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn calc_sum(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), mask: Option<&(impl core::ToInputArray + ?Sized)>, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_calcSum_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -354,7 +354,7 @@ pub fn calc_sum(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray,
 /// * angle_in_degrees: false
 /// * stream: Stream::Null()
 #[inline]
-pub fn cart_to_polar(x: &dyn core::ToInputArray, y: &dyn core::ToInputArray, magnitude: &mut dyn core::ToOutputArray, angle: &mut dyn core::ToOutputArray, angle_in_degrees: bool, stream: &mut core::Stream) -> Result<()> {
+pub fn cart_to_polar(x: &(impl core::ToInputArray + ?Sized), y: &(impl core::ToInputArray + ?Sized), magnitude: &mut (impl core::ToOutputArray + ?Sized), angle: &mut (impl core::ToOutputArray + ?Sized), angle_in_degrees: bool, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(x);
 	input_array_arg!(y);
 	output_array_arg!(magnitude);
@@ -386,7 +386,7 @@ pub fn cart_to_polar(x: &dyn core::ToInputArray, y: &dyn core::ToInputArray, mag
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn compare(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, cmpop: i32, stream: &mut core::Stream) -> Result<()> {
+pub fn compare(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), cmpop: i32, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
@@ -417,7 +417,7 @@ pub fn compare(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst
 /// * value: Scalar()
 /// * stream: Stream::Null()
 #[inline]
-pub fn copy_make_border(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, top: i32, bottom: i32, left: i32, right: i32, border_type: i32, value: core::Scalar, stream: &mut core::Stream) -> Result<()> {
+pub fn copy_make_border(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), top: i32, bottom: i32, left: i32, right: i32, border_type: i32, value: core::Scalar, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -436,7 +436,7 @@ pub fn copy_make_border(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutp
 /// ## See also
 /// countNonZero
 #[inline]
-pub fn count_non_zero(src: &dyn core::ToInputArray) -> Result<i32> {
+pub fn count_non_zero(src: &(impl core::ToInputArray + ?Sized)) -> Result<i32> {
 	input_array_arg!(src);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_countNonZero_const__InputArrayR(src.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
@@ -459,7 +459,7 @@ pub fn count_non_zero(src: &dyn core::ToInputArray) -> Result<i32> {
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn count_non_zero_1(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn count_non_zero_1(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -516,7 +516,7 @@ pub fn create_dft(dft_size: core::Size, flags: i32) -> Result<core::Ptr<dyn crat
 /// ## Parameters
 /// * lut: Look-up table of 256 elements. It is a continuous CV_8U matrix.
 #[inline]
-pub fn create_look_up_table(lut: &dyn core::ToInputArray) -> Result<core::Ptr<dyn crate::cudaarithm::LookUpTable>> {
+pub fn create_look_up_table(lut: &(impl core::ToInputArray + ?Sized)) -> Result<core::Ptr<dyn crate::cudaarithm::LookUpTable>> {
 	input_array_arg!(lut);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_createLookUpTable_const__InputArrayR(lut.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
@@ -566,7 +566,7 @@ pub fn create_look_up_table(lut: &dyn core::ToInputArray) -> Result<core::Ptr<dy
 /// * flags: 0
 /// * stream: Stream::Null()
 #[inline]
-pub fn dft(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, dft_size: core::Size, flags: i32, stream: &mut core::Stream) -> Result<()> {
+pub fn dft(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), dft_size: core::Size, flags: i32, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -596,7 +596,7 @@ pub fn dft(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, dft_
 /// * dtype: -1
 /// * stream: Stream::Null()
 #[inline]
-pub fn divide(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, scale: f64, dtype: i32, stream: &mut core::Stream) -> Result<()> {
+pub fn divide(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), scale: f64, dtype: i32, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
@@ -619,7 +619,7 @@ pub fn divide(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst:
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn exp(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn exp(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -635,11 +635,11 @@ pub fn exp(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, stre
 /// * mask: noArray()
 /// * stream: Stream::Null()
 #[inline]
-pub fn find_min_max_loc(src: &dyn core::ToInputArray, min_max_vals: &mut dyn core::ToOutputArray, loc: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn find_min_max_loc(src: &(impl core::ToInputArray + ?Sized), min_max_vals: &mut (impl core::ToOutputArray + ?Sized), loc: &mut (impl core::ToOutputArray + ?Sized), mask: Option<&(impl core::ToInputArray + ?Sized)>, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(min_max_vals);
 	output_array_arg!(loc);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_findMinMaxLoc_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(src.as_raw__InputArray(), min_max_vals.as_raw__OutputArray(), loc.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -653,10 +653,10 @@ pub fn find_min_max_loc(src: &dyn core::ToInputArray, min_max_vals: &mut dyn cor
 /// * mask: noArray()
 /// * stream: Stream::Null()
 #[inline]
-pub fn find_min_max(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn find_min_max(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), mask: Option<&(impl core::ToInputArray + ?Sized)>, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_findMinMax_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -681,7 +681,7 @@ pub fn find_min_max(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputAr
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn flip(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, flip_code: i32, stream: &mut core::Stream) -> Result<()> {
+pub fn flip(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), flip_code: i32, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -722,7 +722,7 @@ pub fn flip(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, fli
 /// * flags: 0
 /// * stream: Stream::Null()
 #[inline]
-pub fn gemm(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, alpha: f64, src3: &dyn core::ToInputArray, beta: f64, dst: &mut dyn core::ToOutputArray, flags: i32, stream: &mut core::Stream) -> Result<()> {
+pub fn gemm(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), alpha: f64, src3: &(impl core::ToInputArray + ?Sized), beta: f64, dst: &mut (impl core::ToOutputArray + ?Sized), flags: i32, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	input_array_arg!(src3);
@@ -761,7 +761,7 @@ pub fn gemm(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, alpha:
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn in_range(src: &dyn core::ToInputArray, lowerb: core::Scalar, upperb: core::Scalar, dst: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn in_range(src: &(impl core::ToInputArray + ?Sized), lowerb: core::Scalar, upperb: core::Scalar, dst: &mut (impl core::ToOutputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -783,7 +783,7 @@ pub fn in_range(src: &dyn core::ToInputArray, lowerb: core::Scalar, upperb: core
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn integral(src: &dyn core::ToInputArray, sum: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn integral(src: &(impl core::ToInputArray + ?Sized), sum: &mut (impl core::ToOutputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(sum);
 	return_send!(via ocvrs_return);
@@ -805,7 +805,7 @@ pub fn integral(src: &dyn core::ToInputArray, sum: &mut dyn core::ToOutputArray,
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn log(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn log(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -827,7 +827,7 @@ pub fn log(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, stre
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn lshift(src: &dyn core::ToInputArray, val: core::Scalar_<i32>, dst: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn lshift(src: &(impl core::ToInputArray + ?Sized), val: core::Scalar_<i32>, dst: &mut (impl core::ToOutputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -840,7 +840,7 @@ pub fn lshift(src: &dyn core::ToInputArray, val: core::Scalar_<i32>, dst: &mut d
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn lshift_1(src: &dyn core::ToInputArray, val: core::Scalar, dst: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn lshift_1(src: &(impl core::ToInputArray + ?Sized), val: core::Scalar, dst: &mut (impl core::ToOutputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -869,7 +869,7 @@ pub fn lshift_1(src: &dyn core::ToInputArray, val: core::Scalar, dst: &mut dyn c
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn magnitude_sqr_1(x: &dyn core::ToInputArray, y: &dyn core::ToInputArray, magnitude: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn magnitude_sqr_1(x: &(impl core::ToInputArray + ?Sized), y: &(impl core::ToInputArray + ?Sized), magnitude: &mut (impl core::ToOutputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(x);
 	input_array_arg!(y);
 	output_array_arg!(magnitude);
@@ -890,7 +890,7 @@ pub fn magnitude_sqr_1(x: &dyn core::ToInputArray, y: &dyn core::ToInputArray, m
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn magnitude_sqr(xy: &dyn core::ToInputArray, magnitude: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn magnitude_sqr(xy: &(impl core::ToInputArray + ?Sized), magnitude: &mut (impl core::ToOutputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(xy);
 	output_array_arg!(magnitude);
 	return_send!(via ocvrs_return);
@@ -921,7 +921,7 @@ pub fn magnitude_sqr(xy: &dyn core::ToInputArray, magnitude: &mut dyn core::ToOu
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn magnitude_1(x: &dyn core::ToInputArray, y: &dyn core::ToInputArray, magnitude: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn magnitude_1(x: &(impl core::ToInputArray + ?Sized), y: &(impl core::ToInputArray + ?Sized), magnitude: &mut (impl core::ToOutputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(x);
 	input_array_arg!(y);
 	output_array_arg!(magnitude);
@@ -944,7 +944,7 @@ pub fn magnitude_1(x: &dyn core::ToInputArray, y: &dyn core::ToInputArray, magni
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn magnitude(xy: &dyn core::ToInputArray, magnitude: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn magnitude(xy: &(impl core::ToInputArray + ?Sized), magnitude: &mut (impl core::ToOutputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(xy);
 	output_array_arg!(magnitude);
 	return_send!(via ocvrs_return);
@@ -967,7 +967,7 @@ pub fn magnitude(xy: &dyn core::ToInputArray, magnitude: &mut dyn core::ToOutput
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn max(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn max(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
@@ -994,7 +994,7 @@ pub fn max(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &m
 /// * mean: Mean value.
 /// * stddev: Standard deviation value.
 #[inline]
-pub fn mean_std_dev_3(mtx: &dyn core::ToInputArray, mean: &mut core::Scalar, stddev: &mut core::Scalar) -> Result<()> {
+pub fn mean_std_dev_3(mtx: &(impl core::ToInputArray + ?Sized), mean: &mut core::Scalar, stddev: &mut core::Scalar) -> Result<()> {
 	input_array_arg!(mtx);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_meanStdDev_const__InputArrayR_ScalarR_ScalarR(mtx.as_raw__InputArray(), mean, stddev, ocvrs_return.as_mut_ptr()) };
@@ -1020,7 +1020,7 @@ pub fn mean_std_dev_3(mtx: &dyn core::ToInputArray, mean: &mut core::Scalar, std
 /// * stddev: Standard deviation value.
 /// * mask: Operation mask.
 #[inline]
-pub fn mean_std_dev_2(src: &dyn core::ToInputArray, mean: &mut core::Scalar, stddev: &mut core::Scalar, mask: &dyn core::ToInputArray) -> Result<()> {
+pub fn mean_std_dev_2(src: &(impl core::ToInputArray + ?Sized), mean: &mut core::Scalar, stddev: &mut core::Scalar, mask: &(impl core::ToInputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src);
 	input_array_arg!(mask);
 	return_send!(via ocvrs_return);
@@ -1049,7 +1049,7 @@ pub fn mean_std_dev_2(src: &dyn core::ToInputArray, mean: &mut core::Scalar, std
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn mean_std_dev_1(mtx: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn mean_std_dev_1(mtx: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(mtx);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -1072,7 +1072,7 @@ pub fn mean_std_dev_1(mtx: &dyn core::ToInputArray, dst: &mut dyn core::ToOutput
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn mean_std_dev(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn mean_std_dev(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), mask: &(impl core::ToInputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	input_array_arg!(mask);
@@ -1096,7 +1096,7 @@ pub fn mean_std_dev(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputAr
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn merge(src: &core::GpuMat, n: size_t, dst: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn merge(src: &core::GpuMat, n: usize, dst: &mut (impl core::ToOutputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_merge_const_GpuMatX_size_t_const__OutputArrayR_StreamR(src.as_raw_GpuMat(), n, dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
@@ -1120,7 +1120,7 @@ pub fn merge(src: &core::GpuMat, n: size_t, dst: &mut dyn core::ToOutputArray, s
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn merge_1(src: &core::Vector<core::GpuMat>, dst: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn merge_1(src: &core::Vector<core::GpuMat>, dst: &mut (impl core::ToOutputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_merge_const_vectorLGpuMatGR_const__OutputArrayR_StreamR(src.as_raw_VectorOfGpuMat(), dst.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
@@ -1146,9 +1146,9 @@ pub fn merge_1(src: &core::Vector<core::GpuMat>, dst: &mut dyn core::ToOutputArr
 /// ## C++ default parameters
 /// * mask: noArray()
 #[inline]
-pub fn min_max_loc(src: &dyn core::ToInputArray, min_val: &mut f64, max_val: &mut f64, min_loc: &mut core::Point, max_loc: &mut core::Point, mask: &dyn core::ToInputArray) -> Result<()> {
+pub fn min_max_loc(src: &(impl core::ToInputArray + ?Sized), min_val: &mut f64, max_val: &mut f64, min_loc: &mut core::Point, max_loc: &mut core::Point, mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(src);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_minMaxLoc_const__InputArrayR_doubleX_doubleX_PointX_PointX_const__InputArrayR(src.as_raw__InputArray(), min_val, max_val, min_loc, max_loc, mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -1171,9 +1171,9 @@ pub fn min_max_loc(src: &dyn core::ToInputArray, min_val: &mut f64, max_val: &mu
 /// ## C++ default parameters
 /// * mask: noArray()
 #[inline]
-pub fn min_max(src: &dyn core::ToInputArray, min_val: &mut f64, max_val: &mut f64, mask: &dyn core::ToInputArray) -> Result<()> {
+pub fn min_max(src: &(impl core::ToInputArray + ?Sized), min_val: &mut f64, max_val: &mut f64, mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(src);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_minMax_const__InputArrayR_doubleX_doubleX_const__InputArrayR(src.as_raw__InputArray(), min_val, max_val, mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -1194,7 +1194,7 @@ pub fn min_max(src: &dyn core::ToInputArray, min_val: &mut f64, max_val: &mut f6
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn min(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn min(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
@@ -1225,7 +1225,7 @@ pub fn min(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &m
 /// * conj_b: false
 /// * stream: Stream::Null()
 #[inline]
-pub fn mul_and_scale_spectrums(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, flags: i32, scale: f32, conj_b: bool, stream: &mut core::Stream) -> Result<()> {
+pub fn mul_and_scale_spectrums(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), flags: i32, scale: f32, conj_b: bool, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
@@ -1255,7 +1255,7 @@ pub fn mul_and_scale_spectrums(src1: &dyn core::ToInputArray, src2: &dyn core::T
 /// * conj_b: false
 /// * stream: Stream::Null()
 #[inline]
-pub fn mul_spectrums(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, flags: i32, conj_b: bool, stream: &mut core::Stream) -> Result<()> {
+pub fn mul_spectrums(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), flags: i32, conj_b: bool, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
@@ -1284,7 +1284,7 @@ pub fn mul_spectrums(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArra
 /// * dtype: -1
 /// * stream: Stream::Null()
 #[inline]
-pub fn multiply(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, scale: f64, dtype: i32, stream: &mut core::Stream) -> Result<()> {
+pub fn multiply(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), scale: f64, dtype: i32, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
@@ -1307,7 +1307,7 @@ pub fn multiply(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, ds
 /// ## C++ default parameters
 /// * norm_type: NORM_L2
 #[inline]
-pub fn norm_1(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, norm_type: i32) -> Result<f64> {
+pub fn norm_1(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), norm_type: i32) -> Result<f64> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	return_send!(via ocvrs_return);
@@ -1329,9 +1329,9 @@ pub fn norm_1(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, norm
 /// ## C++ default parameters
 /// * mask: noArray()
 #[inline]
-pub fn norm(src1: &dyn core::ToInputArray, norm_type: i32, mask: &dyn core::ToInputArray) -> Result<f64> {
+pub fn norm(src1: &(impl core::ToInputArray + ?Sized), norm_type: i32, mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<f64> {
 	input_array_arg!(src1);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_norm_const__InputArrayR_int_const__InputArrayR(src1.as_raw__InputArray(), norm_type, mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -1360,10 +1360,10 @@ pub fn norm(src1: &dyn core::ToInputArray, norm_type: i32, mask: &dyn core::ToIn
 /// * mask: noArray()
 /// * stream: Stream::Null()
 #[inline]
-pub fn normalize(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, alpha: f64, beta: f64, norm_type: i32, dtype: i32, mask: &dyn core::ToInputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn normalize(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), alpha: f64, beta: f64, norm_type: i32, dtype: i32, mask: Option<&(impl core::ToInputArray + ?Sized)>, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_normalize_const__InputArrayR_const__OutputArrayR_double_double_int_int_const__InputArrayR_StreamR(src.as_raw__InputArray(), dst.as_raw__OutputArray(), alpha, beta, norm_type, dtype, mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -1386,7 +1386,7 @@ pub fn normalize(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 /// * angle_in_degrees: false
 /// * stream: Stream::Null()
 #[inline]
-pub fn phase(x: &dyn core::ToInputArray, y: &dyn core::ToInputArray, angle: &mut dyn core::ToOutputArray, angle_in_degrees: bool, stream: &mut core::Stream) -> Result<()> {
+pub fn phase(x: &(impl core::ToInputArray + ?Sized), y: &(impl core::ToInputArray + ?Sized), angle: &mut (impl core::ToOutputArray + ?Sized), angle_in_degrees: bool, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(x);
 	input_array_arg!(y);
 	output_array_arg!(angle);
@@ -1411,7 +1411,7 @@ pub fn phase(x: &dyn core::ToInputArray, y: &dyn core::ToInputArray, angle: &mut
 /// * angle_in_degrees: false
 /// * stream: Stream::Null()
 #[inline]
-pub fn polar_to_cart(magnitude: &dyn core::ToInputArray, angle: &dyn core::ToInputArray, x: &mut dyn core::ToOutputArray, y: &mut dyn core::ToOutputArray, angle_in_degrees: bool, stream: &mut core::Stream) -> Result<()> {
+pub fn polar_to_cart(magnitude: &(impl core::ToInputArray + ?Sized), angle: &(impl core::ToInputArray + ?Sized), x: &mut (impl core::ToOutputArray + ?Sized), y: &mut (impl core::ToOutputArray + ?Sized), angle_in_degrees: bool, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(magnitude);
 	input_array_arg!(angle);
 	output_array_arg!(x);
@@ -1440,7 +1440,7 @@ pub fn polar_to_cart(magnitude: &dyn core::ToInputArray, angle: &dyn core::ToInp
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn pow(src: &dyn core::ToInputArray, power: f64, dst: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn pow(src: &(impl core::ToInputArray + ?Sized), power: f64, dst: &mut (impl core::ToOutputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -1462,7 +1462,7 @@ pub fn pow(src: &dyn core::ToInputArray, power: f64, dst: &mut dyn core::ToOutpu
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn rect_std_dev(src: &dyn core::ToInputArray, sqr: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, rect: core::Rect, stream: &mut core::Stream) -> Result<()> {
+pub fn rect_std_dev(src: &(impl core::ToInputArray + ?Sized), sqr: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), rect: core::Rect, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	input_array_arg!(sqr);
 	output_array_arg!(dst);
@@ -1504,7 +1504,7 @@ pub fn rect_std_dev(src: &dyn core::ToInputArray, sqr: &dyn core::ToInputArray,
 /// * dtype: -1
 /// * stream: Stream::Null()
 #[inline]
-pub fn reduce(mtx: &dyn core::ToInputArray, vec: &mut dyn core::ToOutputArray, dim: i32, reduce_op: i32, dtype: i32, stream: &mut core::Stream) -> Result<()> {
+pub fn reduce(mtx: &(impl core::ToInputArray + ?Sized), vec: &mut (impl core::ToOutputArray + ?Sized), dim: i32, reduce_op: i32, dtype: i32, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(mtx);
 	output_array_arg!(vec);
 	return_send!(via ocvrs_return);
@@ -1525,7 +1525,7 @@ pub fn reduce(mtx: &dyn core::ToInputArray, vec: &mut dyn core::ToOutputArray, d
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn rshift(src: &dyn core::ToInputArray, val: core::Scalar_<i32>, dst: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn rshift(src: &(impl core::ToInputArray + ?Sized), val: core::Scalar_<i32>, dst: &mut (impl core::ToOutputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -1538,7 +1538,7 @@ pub fn rshift(src: &dyn core::ToInputArray, val: core::Scalar_<i32>, dst: &mut d
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn rshift_1(src: &dyn core::ToInputArray, val: core::Scalar, dst: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn rshift_1(src: &(impl core::ToInputArray + ?Sized), val: core::Scalar, dst: &mut (impl core::ToOutputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -1560,7 +1560,7 @@ pub fn rshift_1(src: &dyn core::ToInputArray, val: core::Scalar, dst: &mut dyn c
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn split(src: &dyn core::ToInputArray, dst: &mut core::GpuMat, stream: &mut core::Stream) -> Result<()> {
+pub fn split(src: &(impl core::ToInputArray + ?Sized), dst: &mut core::GpuMat, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_split_const__InputArrayR_GpuMatX_StreamR(src.as_raw__InputArray(), dst.as_raw_mut_GpuMat(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
@@ -1583,7 +1583,7 @@ pub fn split(src: &dyn core::ToInputArray, dst: &mut core::GpuMat, stream: &mut
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn split_1(src: &dyn core::ToInputArray, dst: &mut core::Vector<core::GpuMat>, stream: &mut core::Stream) -> Result<()> {
+pub fn split_1(src: &(impl core::ToInputArray + ?Sized), dst: &mut core::Vector<core::GpuMat>, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_split_const__InputArrayR_vectorLGpuMatGR_StreamR(src.as_raw__InputArray(), dst.as_raw_mut_VectorOfGpuMat(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
@@ -1603,7 +1603,7 @@ pub fn split_1(src: &dyn core::ToInputArray, dst: &mut core::Vector<core::GpuMat
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn sqr_integral(src: &dyn core::ToInputArray, sqsum: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn sqr_integral(src: &(impl core::ToInputArray + ?Sized), sqsum: &mut (impl core::ToOutputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(sqsum);
 	return_send!(via ocvrs_return);
@@ -1622,9 +1622,9 @@ pub fn sqr_integral(src: &dyn core::ToInputArray, sqsum: &mut dyn core::ToOutput
 /// ## C++ default parameters
 /// * mask: noArray()
 #[inline]
-pub fn sqr_sum(src: &dyn core::ToInputArray, mask: &dyn core::ToInputArray) -> Result<core::Scalar> {
+pub fn sqr_sum(src: &(impl core::ToInputArray + ?Sized), mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<core::Scalar> {
 	input_array_arg!(src);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_sqrSum_const__InputArrayR_const__InputArrayR(src.as_raw__InputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -1642,7 +1642,7 @@ pub fn sqr_sum(src: &dyn core::ToInputArray, mask: &dyn core::ToInputArray) -> R
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn sqr(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn sqr(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -1664,7 +1664,7 @@ pub fn sqr(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, stre
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn sqrt(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn sqrt(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -1693,11 +1693,11 @@ pub fn sqrt(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, str
 /// * dtype: -1
 /// * stream: Stream::Null()
 #[inline]
-pub fn subtract(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray, dtype: i32, stream: &mut core::Stream) -> Result<()> {
+pub fn subtract(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), mask: Option<&(impl core::ToInputArray + ?Sized)>, dtype: i32, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_subtract_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR_int_StreamR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__OutputArray(), mask.as_raw__InputArray(), dtype, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -1716,9 +1716,9 @@ pub fn subtract(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, ds
 /// ## C++ default parameters
 /// * mask: noArray()
 #[inline]
-pub fn sum(src: &dyn core::ToInputArray, mask: &dyn core::ToInputArray) -> Result<core::Scalar> {
+pub fn sum(src: &(impl core::ToInputArray + ?Sized), mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<core::Scalar> {
 	input_array_arg!(src);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_sum_const__InputArrayR_const__InputArrayR(src.as_raw__InputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -1742,7 +1742,7 @@ pub fn sum(src: &dyn core::ToInputArray, mask: &dyn core::ToInputArray) -> Resul
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn threshold(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, thresh: f64, maxval: f64, typ: i32, stream: &mut core::Stream) -> Result<f64> {
+pub fn threshold(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), thresh: f64, maxval: f64, typ: i32, stream: &mut core::Stream) -> Result<f64> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -1764,7 +1764,7 @@ pub fn threshold(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn transpose(src1: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn transpose(src1: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src1);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -1873,3 +1873,4 @@ pub trait LookUpTable: core::AlgorithmTrait + crate::cudaarithm::LookUpTableCons
 	}
 	
 }
+pub use crate::manual::cudaarithm::*;
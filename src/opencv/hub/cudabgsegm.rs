@@ -232,3 +232,4 @@ pub trait CUDA_BackgroundSubtractorMOG2: crate::cudabgsegm::CUDA_BackgroundSubtr
 	}
 	
 }
+pub use crate::manual::cudabgsegm::*;
@@ -916,7 +916,7 @@ pub trait RawVideoSource: crate::cudacodec::RawVideoSourceConst {
 	/// * data: Pointer to frame data.
 	/// * size: Size in bytes of current frame.
 	#[inline]
-	fn get_next_packet(&mut self, data: &mut &mut u8, size: &mut size_t) -> Result<bool> {
+	fn get_next_packet(&mut self, data: &mut &mut u8, size: &mut usize) -> Result<bool> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cudacodec_RawVideoSource_getNextPacket_unsigned_charXX_size_tX(self.as_raw_mut_RawVideoSource(), data as *mut  _ as *mut  *mut  _, size, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -972,7 +972,7 @@ pub trait VideoReaderConst {
 	/// ## C++ default parameters
 	/// * idx: static_cast<size_t>(VideoReaderProps::PROP_DECODED_FRAME_IDX)
 	#[inline]
-	fn retrieve(&self, frame: &mut dyn core::ToOutputArray, idx: size_t) -> Result<bool> {
+	fn retrieve(&self, frame: &mut dyn core::ToOutputArray, idx: usize) -> Result<bool> {
 		output_array_arg!(frame);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cudacodec_VideoReader_retrieve_const_const__OutputArrayR_const_size_t(self.as_raw_VideoReader(), frame.as_raw__OutputArray(), idx, ocvrs_return.as_mut_ptr()) };
@@ -994,7 +994,7 @@ pub trait VideoReaderConst {
 	/// The method returns data associated with the current video source since the last call to grab() or the creation of the VideoReader. If no data is present
 	/// the method returns false and the function returns an empty image.
 	#[inline]
-	fn retrieve_1(&self, frame: &mut core::Mat, idx: size_t) -> Result<bool> {
+	fn retrieve_1(&self, frame: &mut core::Mat, idx: usize) -> Result<bool> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cudacodec_VideoReader_retrieve_const_MatR_const_size_t(self.as_raw_VideoReader(), frame.as_raw_mut_Mat(), idx, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -1245,3 +1245,4 @@ pub trait VideoWriter: crate::cudacodec::VideoWriterConst {
 	}
 	
 }
+pub use crate::manual::cudacodec::*;
@@ -116,10 +116,10 @@ pub trait CUDA_DescriptorMatcher: core::AlgorithmTrait + crate::cudafeatures2d::
 	/// ## C++ default parameters
 	/// * mask: noArray()
 	#[inline]
-	fn match_(&mut self, query_descriptors: &dyn core::ToInputArray, train_descriptors: &dyn core::ToInputArray, matches: &mut core::Vector<core::DMatch>, mask: &dyn core::ToInputArray) -> Result<()> {
+	fn match_(&mut self, query_descriptors: &dyn core::ToInputArray, train_descriptors: &dyn core::ToInputArray, matches: &mut core::Vector<core::DMatch>, mask: Option<&dyn core::ToInputArray>) -> Result<()> {
 		input_array_arg!(query_descriptors);
 		input_array_arg!(train_descriptors);
-		input_array_arg!(mask);
+		input_array_arg!(nullable mask);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_DescriptorMatcher_match_const__InputArrayR_const__InputArrayR_vectorLDMatchGR_const__InputArrayR(self.as_raw_mut_CUDA_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), train_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfDMatch(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -180,11 +180,11 @@ pub trait CUDA_DescriptorMatcher: core::AlgorithmTrait + crate::cudafeatures2d::
 	/// * mask: noArray()
 	/// * stream: Stream::Null()
 	#[inline]
-	fn match_async(&mut self, query_descriptors: &dyn core::ToInputArray, train_descriptors: &dyn core::ToInputArray, matches: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray, stream: &mut core::Stream) -> Result<()> {
+	fn match_async(&mut self, query_descriptors: &dyn core::ToInputArray, train_descriptors: &dyn core::ToInputArray, matches: &mut dyn core::ToOutputArray, mask: Option<&dyn core::ToInputArray>, stream: &mut core::Stream) -> Result<()> {
 		input_array_arg!(query_descriptors);
 		input_array_arg!(train_descriptors);
 		output_array_arg!(matches);
-		input_array_arg!(mask);
+		input_array_arg!(nullable mask);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_DescriptorMatcher_matchAsync_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(self.as_raw_mut_CUDA_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), train_descriptors.as_raw__InputArray(), matches.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -267,10 +267,10 @@ pub trait CUDA_DescriptorMatcher: core::AlgorithmTrait + crate::cudafeatures2d::
 	/// * mask: noArray()
 	/// * compact_result: false
 	#[inline]
-	fn knn_match(&mut self, query_descriptors: &dyn core::ToInputArray, train_descriptors: &dyn core::ToInputArray, matches: &mut core::Vector<core::Vector<core::DMatch>>, k: i32, mask: &dyn core::ToInputArray, compact_result: bool) -> Result<()> {
+	fn knn_match(&mut self, query_descriptors: &dyn core::ToInputArray, train_descriptors: &dyn core::ToInputArray, matches: &mut core::Vector<core::Vector<core::DMatch>>, k: i32, mask: Option<&dyn core::ToInputArray>, compact_result: bool) -> Result<()> {
 		input_array_arg!(query_descriptors);
 		input_array_arg!(train_descriptors);
-		input_array_arg!(mask);
+		input_array_arg!(nullable mask);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_DescriptorMatcher_knnMatch_const__InputArrayR_const__InputArrayR_vectorLvectorLDMatchGGR_int_const__InputArrayR_bool(self.as_raw_mut_CUDA_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), train_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfVectorOfDMatch(), k, mask.as_raw__InputArray(), compact_result, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -334,11 +334,11 @@ pub trait CUDA_DescriptorMatcher: core::AlgorithmTrait + crate::cudafeatures2d::
 	/// * mask: noArray()
 	/// * stream: Stream::Null()
 	#[inline]
-	fn knn_match_async(&mut self, query_descriptors: &dyn core::ToInputArray, train_descriptors: &dyn core::ToInputArray, matches: &mut dyn core::ToOutputArray, k: i32, mask: &dyn core::ToInputArray, stream: &mut core::Stream) -> Result<()> {
+	fn knn_match_async(&mut self, query_descriptors: &dyn core::ToInputArray, train_descriptors: &dyn core::ToInputArray, matches: &mut dyn core::ToOutputArray, k: i32, mask: Option<&dyn core::ToInputArray>, stream: &mut core::Stream) -> Result<()> {
 		input_array_arg!(query_descriptors);
 		input_array_arg!(train_descriptors);
 		output_array_arg!(matches);
-		input_array_arg!(mask);
+		input_array_arg!(nullable mask);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_DescriptorMatcher_knnMatchAsync_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_const__InputArrayR_StreamR(self.as_raw_mut_CUDA_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), train_descriptors.as_raw__InputArray(), matches.as_raw__OutputArray(), k, mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -428,10 +428,10 @@ pub trait CUDA_DescriptorMatcher: core::AlgorithmTrait + crate::cudafeatures2d::
 	/// * mask: noArray()
 	/// * compact_result: false
 	#[inline]
-	fn radius_match(&mut self, query_descriptors: &dyn core::ToInputArray, train_descriptors: &dyn core::ToInputArray, matches: &mut core::Vector<core::Vector<core::DMatch>>, max_distance: f32, mask: &dyn core::ToInputArray, compact_result: bool) -> Result<()> {
+	fn radius_match(&mut self, query_descriptors: &dyn core::ToInputArray, train_descriptors: &dyn core::ToInputArray, matches: &mut core::Vector<core::Vector<core::DMatch>>, max_distance: f32, mask: Option<&dyn core::ToInputArray>, compact_result: bool) -> Result<()> {
 		input_array_arg!(query_descriptors);
 		input_array_arg!(train_descriptors);
-		input_array_arg!(mask);
+		input_array_arg!(nullable mask);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_DescriptorMatcher_radiusMatch_const__InputArrayR_const__InputArrayR_vectorLvectorLDMatchGGR_float_const__InputArrayR_bool(self.as_raw_mut_CUDA_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), train_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfVectorOfDMatch(), max_distance, mask.as_raw__InputArray(), compact_result, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -497,11 +497,11 @@ pub trait CUDA_DescriptorMatcher: core::AlgorithmTrait + crate::cudafeatures2d::
 	/// * mask: noArray()
 	/// * stream: Stream::Null()
 	#[inline]
-	fn radius_match_async(&mut self, query_descriptors: &dyn core::ToInputArray, train_descriptors: &dyn core::ToInputArray, matches: &mut dyn core::ToOutputArray, max_distance: f32, mask: &dyn core::ToInputArray, stream: &mut core::Stream) -> Result<()> {
+	fn radius_match_async(&mut self, query_descriptors: &dyn core::ToInputArray, train_descriptors: &dyn core::ToInputArray, matches: &mut dyn core::ToOutputArray, max_distance: f32, mask: Option<&dyn core::ToInputArray>, stream: &mut core::Stream) -> Result<()> {
 		input_array_arg!(query_descriptors);
 		input_array_arg!(train_descriptors);
 		output_array_arg!(matches);
-		input_array_arg!(mask);
+		input_array_arg!(nullable mask);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_DescriptorMatcher_radiusMatchAsync_const__InputArrayR_const__InputArrayR_const__OutputArrayR_float_const__InputArrayR_StreamR(self.as_raw_mut_CUDA_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), train_descriptors.as_raw__InputArray(), matches.as_raw__OutputArray(), max_distance, mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -676,10 +676,10 @@ pub trait CUDA_Feature2DAsync: crate::cudafeatures2d::CUDA_Feature2DAsyncConst +
 	/// * mask: noArray()
 	/// * stream: Stream::Null()
 	#[inline]
-	fn detect_async(&mut self, image: &dyn core::ToInputArray, keypoints: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray, stream: &mut core::Stream) -> Result<()> {
+	fn detect_async(&mut self, image: &dyn core::ToInputArray, keypoints: &mut dyn core::ToOutputArray, mask: Option<&dyn core::ToInputArray>, stream: &mut core::Stream) -> Result<()> {
 		input_array_arg!(image);
 		output_array_arg!(keypoints);
-		input_array_arg!(mask);
+		input_array_arg!(nullable mask);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_Feature2DAsync_detectAsync_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(self.as_raw_mut_CUDA_Feature2DAsync(), image.as_raw__InputArray(), keypoints.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -963,4 +963,5 @@ impl dyn CUDA_ORB + '_ {
 		Ok(ret)
 	}
 	
-}
\ No newline at end of file
+}
+pub use crate::manual::cudafeatures2d::*;
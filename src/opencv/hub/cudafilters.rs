@@ -226,7 +226,7 @@ pub fn create_laplacian_filter(src_type: i32, dst_type: i32, ksize: i32, scale:
 /// * border_mode: BORDER_DEFAULT
 /// * border_val: Scalar::all(0)
 #[inline]
-pub fn create_linear_filter(src_type: i32, dst_type: i32, kernel: &dyn core::ToInputArray, anchor: core::Point, border_mode: i32, border_val: core::Scalar) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
+pub fn create_linear_filter(src_type: i32, dst_type: i32, kernel: &(impl core::ToInputArray + ?Sized), anchor: core::Point, border_mode: i32, border_val: core::Scalar) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
 	input_array_arg!(kernel);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_createLinearFilter_int_int_const__InputArrayR_Point_int_Scalar(src_type, dst_type, kernel.as_raw__InputArray(), anchor.opencv_as_extern(), border_mode, border_val.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
@@ -284,7 +284,7 @@ pub fn create_median_filter(src_type: i32, window_size: i32, partition: i32) ->
 /// * anchor: Point(-1,-1)
 /// * iterations: 1
 #[inline]
-pub fn create_morphology_filter(op: i32, src_type: i32, kernel: &dyn core::ToInputArray, anchor: core::Point, iterations: i32) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
+pub fn create_morphology_filter(op: i32, src_type: i32, kernel: &(impl core::ToInputArray + ?Sized), anchor: core::Point, iterations: i32) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
 	input_array_arg!(kernel);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_createMorphologyFilter_int_int_const__InputArrayR_Point_int(op, src_type, kernel.as_raw__InputArray(), anchor.opencv_as_extern(), iterations, ocvrs_return.as_mut_ptr()) };
@@ -367,7 +367,7 @@ pub fn create_scharr_filter(src_type: i32, dst_type: i32, dx: i32, dy: i32, scal
 /// * row_border_mode: BORDER_DEFAULT
 /// * column_border_mode: -1
 #[inline]
-pub fn create_separable_linear_filter(src_type: i32, dst_type: i32, row_kernel: &dyn core::ToInputArray, column_kernel: &dyn core::ToInputArray, anchor: core::Point, row_border_mode: i32, column_border_mode: i32) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
+pub fn create_separable_linear_filter(src_type: i32, dst_type: i32, row_kernel: &(impl core::ToInputArray + ?Sized), column_kernel: &(impl core::ToInputArray + ?Sized), anchor: core::Point, row_border_mode: i32, column_border_mode: i32) -> Result<core::Ptr<dyn crate::cudafilters::Filter>> {
 	input_array_arg!(row_kernel);
 	input_array_arg!(column_kernel);
 	return_send!(via ocvrs_return);
@@ -439,3 +439,4 @@ pub trait Filter: core::AlgorithmTrait + crate::cudafilters::FilterConst {
 	}
 	
 }
+pub use crate::manual::cudafilters::*;
@@ -152,7 +152,7 @@ opencv_type_enum! { crate::cudaimgproc::CUDA_DemosaicTypes }
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn alpha_comp(img1: &dyn core::ToInputArray, img2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, alpha_op: i32, stream: &mut core::Stream) -> Result<()> {
+pub fn alpha_comp(img1: &(impl core::ToInputArray + ?Sized), img2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), alpha_op: i32, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(img1);
 	input_array_arg!(img2);
 	output_array_arg!(dst);
@@ -182,7 +182,7 @@ pub fn alpha_comp(img1: &dyn core::ToInputArray, img2: &dyn core::ToInputArray,
 /// * border_mode: BORDER_DEFAULT
 /// * stream: Stream::Null()
 #[inline]
-pub fn bilateral_filter(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, kernel_size: i32, sigma_color: f32, sigma_spatial: f32, border_mode: i32, stream: &mut core::Stream) -> Result<()> {
+pub fn bilateral_filter(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), kernel_size: i32, sigma_color: f32, sigma_spatial: f32, border_mode: i32, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -207,7 +207,7 @@ pub fn bilateral_filter(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutp
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn blend_linear(img1: &dyn core::ToInputArray, img2: &dyn core::ToInputArray, weights1: &dyn core::ToInputArray, weights2: &dyn core::ToInputArray, result: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn blend_linear(img1: &(impl core::ToInputArray + ?Sized), img2: &(impl core::ToInputArray + ?Sized), weights1: &(impl core::ToInputArray + ?Sized), weights2: &(impl core::ToInputArray + ?Sized), result: &mut (impl core::ToOutputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(img1);
 	input_array_arg!(img2);
 	input_array_arg!(weights1);
@@ -231,7 +231,7 @@ pub fn blend_linear(img1: &dyn core::ToInputArray, img2: &dyn core::ToInputArray
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn calc_hist_1(src: &dyn core::ToInputArray, mask: &dyn core::ToInputArray, hist: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn calc_hist_1(src: &(impl core::ToInputArray + ?Sized), mask: &(impl core::ToInputArray + ?Sized), hist: &mut (impl core::ToOutputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	input_array_arg!(mask);
 	output_array_arg!(hist);
@@ -252,7 +252,7 @@ pub fn calc_hist_1(src: &dyn core::ToInputArray, mask: &dyn core::ToInputArray,
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn calc_hist(src: &dyn core::ToInputArray, hist: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn calc_hist(src: &(impl core::ToInputArray + ?Sized), hist: &mut (impl core::ToOutputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(hist);
 	return_send!(via ocvrs_return);
@@ -296,7 +296,7 @@ pub fn calc_hist(src: &dyn core::ToInputArray, hist: &mut dyn core::ToOutputArra
 /// * connectivity: 8
 /// * ltype: CV_32S
 #[inline]
-pub fn connected_components(image: &dyn core::ToInputArray, labels: &mut dyn core::ToOutputArray, connectivity: i32, ltype: i32) -> Result<()> {
+pub fn connected_components(image: &(impl core::ToInputArray + ?Sized), labels: &mut (impl core::ToOutputArray + ?Sized), connectivity: i32, ltype: i32) -> Result<()> {
 	input_array_arg!(image);
 	output_array_arg!(labels);
 	return_send!(via ocvrs_return);
@@ -328,7 +328,7 @@ pub fn connected_components(image: &dyn core::ToInputArray, labels: &mut dyn cor
 /// 
 /// opencv_contrib_source_code/modules/cudaimgproc/samples/connected_components.cpp
 #[inline]
-pub fn connected_components_with_algorithm(image: &dyn core::ToInputArray, labels: &mut dyn core::ToOutputArray, connectivity: i32, ltype: i32, ccltype: crate::cudaimgproc::CUDA_ConnectedComponentsAlgorithmsTypes) -> Result<()> {
+pub fn connected_components_with_algorithm(image: &(impl core::ToInputArray + ?Sized), labels: &mut (impl core::ToOutputArray + ?Sized), connectivity: i32, ltype: i32, ccltype: crate::cudaimgproc::CUDA_ConnectedComponentsAlgorithmsTypes) -> Result<()> {
 	input_array_arg!(image);
 	output_array_arg!(labels);
 	return_send!(via ocvrs_return);
@@ -619,7 +619,7 @@ pub fn create_template_matching(src_type: i32, method: i32, user_block_size: cor
 /// * dcn: 0
 /// * stream: Stream::Null()
 #[inline]
-pub fn cvt_color(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, code: i32, dcn: i32, stream: &mut core::Stream) -> Result<()> {
+pub fn cvt_color(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), code: i32, dcn: i32, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -659,7 +659,7 @@ pub fn cvt_color(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 /// * dcn: -1
 /// * stream: Stream::Null()
 #[inline]
-pub fn demosaicing(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, code: i32, dcn: i32, stream: &mut core::Stream) -> Result<()> {
+pub fn demosaicing(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), code: i32, dcn: i32, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -681,7 +681,7 @@ pub fn demosaicing(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArr
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn equalize_hist(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn equalize_hist(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -703,7 +703,7 @@ pub fn equalize_hist(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputA
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn even_levels(levels: &mut dyn core::ToOutputArray, n_levels: i32, lower_level: i32, upper_level: i32, stream: &mut core::Stream) -> Result<()> {
+pub fn even_levels(levels: &mut (impl core::ToOutputArray + ?Sized), n_levels: i32, lower_level: i32, upper_level: i32, stream: &mut core::Stream) -> Result<()> {
 	output_array_arg!(levels);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_evenLevels_const__OutputArrayR_int_int_int_StreamR(levels.as_raw__OutputArray(), n_levels, lower_level, upper_level, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
@@ -724,7 +724,7 @@ pub fn even_levels(levels: &mut dyn core::ToOutputArray, n_levels: i32, lower_le
 /// * forward: true
 /// * stream: Stream::Null()
 #[inline]
-pub fn gamma_correction(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, forward: bool, stream: &mut core::Stream) -> Result<()> {
+pub fn gamma_correction(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), forward: bool, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -748,7 +748,7 @@ pub fn gamma_correction(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutp
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn hist_even(src: &dyn core::ToInputArray, hist: &mut dyn core::ToOutputArray, hist_size: i32, lower_level: i32, upper_level: i32, stream: &mut core::Stream) -> Result<()> {
+pub fn hist_even(src: &(impl core::ToInputArray + ?Sized), hist: &mut (impl core::ToOutputArray + ?Sized), hist_size: i32, lower_level: i32, upper_level: i32, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(hist);
 	return_send!(via ocvrs_return);
@@ -770,7 +770,7 @@ pub fn hist_even(src: &dyn core::ToInputArray, hist: &mut dyn core::ToOutputArra
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn hist_range(src: &dyn core::ToInputArray, hist: &mut dyn core::ToOutputArray, levels: &dyn core::ToInputArray, stream: &mut core::Stream) -> Result<()> {
+pub fn hist_range(src: &(impl core::ToInputArray + ?Sized), hist: &mut (impl core::ToOutputArray + ?Sized), levels: &(impl core::ToInputArray + ?Sized), stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(hist);
 	input_array_arg!(levels);
@@ -799,7 +799,7 @@ pub fn hist_range(src: &dyn core::ToInputArray, hist: &mut dyn core::ToOutputArr
 /// * criteria: TermCriteria(TermCriteria::MAX_ITER+TermCriteria::EPS,5,1)
 /// * stream: Stream::Null()
 #[inline]
-pub fn mean_shift_filtering(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, sp: i32, sr: i32, criteria: core::TermCriteria, stream: &mut core::Stream) -> Result<()> {
+pub fn mean_shift_filtering(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), sp: i32, sr: i32, criteria: core::TermCriteria, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -829,7 +829,7 @@ pub fn mean_shift_filtering(src: &dyn core::ToInputArray, dst: &mut dyn core::To
 /// * criteria: TermCriteria(TermCriteria::MAX_ITER+TermCriteria::EPS,5,1)
 /// * stream: Stream::Null()
 #[inline]
-pub fn mean_shift_proc(src: &dyn core::ToInputArray, dstr: &mut dyn core::ToOutputArray, dstsp: &mut dyn core::ToOutputArray, sp: i32, sr: i32, criteria: core::TermCriteria, stream: &mut core::Stream) -> Result<()> {
+pub fn mean_shift_proc(src: &(impl core::ToInputArray + ?Sized), dstr: &mut (impl core::ToOutputArray + ?Sized), dstsp: &mut (impl core::ToOutputArray + ?Sized), sp: i32, sr: i32, criteria: core::TermCriteria, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dstr);
 	output_array_arg!(dstsp);
@@ -855,7 +855,7 @@ pub fn mean_shift_proc(src: &dyn core::ToInputArray, dstr: &mut dyn core::ToOutp
 /// * criteria: TermCriteria(TermCriteria::MAX_ITER+TermCriteria::EPS,5,1)
 /// * stream: Stream::Null()
 #[inline]
-pub fn mean_shift_segmentation(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, sp: i32, sr: i32, minsize: i32, criteria: core::TermCriteria, stream: &mut core::Stream) -> Result<()> {
+pub fn mean_shift_segmentation(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), sp: i32, sr: i32, minsize: i32, criteria: core::TermCriteria, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -879,7 +879,7 @@ pub fn mean_shift_segmentation(src: &dyn core::ToInputArray, dst: &mut dyn core:
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn swap_channels(image: &mut dyn core::ToInputOutputArray, dst_order: &[i32; 4], stream: &mut core::Stream) -> Result<()> {
+pub fn swap_channels(image: &mut (impl core::ToInputOutputArray + ?Sized), dst_order: &[i32; 4], stream: &mut core::Stream) -> Result<()> {
 	input_output_array_arg!(image);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_cuda_swapChannels_const__InputOutputArrayR_const_intXX_StreamR(image.as_raw__InputOutputArray(), dst_order, stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
@@ -1102,10 +1102,10 @@ pub trait CUDA_CornersDetector: core::AlgorithmTrait + crate::cudaimgproc::CUDA_
 	/// * mask: noArray()
 	/// * stream: Stream::Null()
 	#[inline]
-	fn detect(&mut self, image: &dyn core::ToInputArray, corners: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray, stream: &mut core::Stream) -> Result<()> {
+	fn detect(&mut self, image: &dyn core::ToInputArray, corners: &mut dyn core::ToOutputArray, mask: Option<&dyn core::ToInputArray>, stream: &mut core::Stream) -> Result<()> {
 		input_array_arg!(image);
 		output_array_arg!(corners);
-		input_array_arg!(mask);
+		input_array_arg!(nullable mask);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_CornersDetector_detect_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(self.as_raw_mut_CUDA_CornersDetector(), image.as_raw__InputArray(), corners.as_raw__OutputArray(), mask.as_raw__InputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -1366,10 +1366,10 @@ pub trait CUDA_HoughLinesDetector: core::AlgorithmTrait + crate::cudaimgproc::CU
 	/// * h_votes: noArray()
 	/// * stream: Stream::Null()
 	#[inline]
-	fn download_results(&mut self, d_lines: &dyn core::ToInputArray, h_lines: &mut dyn core::ToOutputArray, h_votes: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+	fn download_results(&mut self, d_lines: &dyn core::ToInputArray, h_lines: &mut dyn core::ToOutputArray, h_votes: Option<&mut dyn core::ToOutputArray>, stream: &mut core::Stream) -> Result<()> {
 		input_array_arg!(d_lines);
 		output_array_arg!(h_lines);
-		output_array_arg!(h_votes);
+		output_array_arg!(nullable h_votes);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_HoughLinesDetector_downloadResults_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_HoughLinesDetector(), d_lines.as_raw__InputArray(), h_lines.as_raw__OutputArray(), h_votes.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
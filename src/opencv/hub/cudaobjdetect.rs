@@ -345,7 +345,7 @@ pub trait HOGConst: core::AlgorithmTraitConst {
 	
 	/// Returns the number of coefficients required for the classification.
 	#[inline]
-	fn get_descriptor_size(&self) -> Result<size_t> {
+	fn get_descriptor_size(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_HOG_getDescriptorSize_const(self.as_raw_HOG(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -355,7 +355,7 @@ pub trait HOGConst: core::AlgorithmTraitConst {
 	
 	/// Returns the block histogram size.
 	#[inline]
-	fn get_block_histogram_size(&self) -> Result<size_t> {
+	fn get_block_histogram_size(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_HOG_getBlockHistogramSize_const(self.as_raw_HOG(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -581,12 +581,12 @@ pub trait CUDA_NvidiaHWOpticalFlow: core::AlgorithmTrait + crate::cudaoptflow::C
 	/// * hint: cv::noArray()
 	/// * cost: cv::noArray()
 	#[inline]
-	fn calc(&mut self, input_image: &dyn core::ToInputArray, reference_image: &dyn core::ToInputArray, flow: &mut dyn core::ToInputOutputArray, stream: &mut core::Stream, hint: &dyn core::ToInputArray, cost: &mut dyn core::ToOutputArray) -> Result<()> {
+	fn calc(&mut self, input_image: &dyn core::ToInputArray, reference_image: &dyn core::ToInputArray, flow: &mut dyn core::ToInputOutputArray, stream: &mut core::Stream, hint: Option<&dyn core::ToInputArray>, cost: Option<&mut dyn core::ToOutputArray>) -> Result<()> {
 		input_array_arg!(input_image);
 		input_array_arg!(reference_image);
 		input_output_array_arg!(flow);
-		input_array_arg!(hint);
-		output_array_arg!(cost);
+		input_array_arg!(nullable hint);
+		output_array_arg!(nullable cost);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_NvidiaHWOpticalFlow_calc_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_StreamR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_CUDA_NvidiaHWOpticalFlow(), input_image.as_raw__InputArray(), reference_image.as_raw__InputArray(), flow.as_raw__InputOutputArray(), stream.as_raw_mut_Stream(), hint.as_raw__InputArray(), cost.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -1070,13 +1070,13 @@ pub trait CUDA_SparseOpticalFlow: core::AlgorithmTrait + crate::cudaoptflow::CUD
 	/// * err: cv::noArray()
 	/// * stream: Stream::Null()
 	#[inline]
-	fn calc(&mut self, prev_img: &dyn core::ToInputArray, next_img: &dyn core::ToInputArray, prev_pts: &dyn core::ToInputArray, next_pts: &mut dyn core::ToInputOutputArray, status: &mut dyn core::ToOutputArray, err: &mut dyn core::ToOutputArray, stream: &mut core::Stream) -> Result<()> {
+	fn calc(&mut self, prev_img: &dyn core::ToInputArray, next_img: &dyn core::ToInputArray, prev_pts: &dyn core::ToInputArray, next_pts: &mut dyn core::ToInputOutputArray, status: &mut dyn core::ToOutputArray, err: Option<&mut dyn core::ToOutputArray>, stream: &mut core::Stream) -> Result<()> {
 		input_array_arg!(prev_img);
 		input_array_arg!(next_img);
 		input_array_arg!(prev_pts);
 		input_output_array_arg!(next_pts);
 		output_array_arg!(status);
-		output_array_arg!(err);
+		output_array_arg!(nullable err);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_cuda_SparseOpticalFlow_calc_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_StreamR(self.as_raw_mut_CUDA_SparseOpticalFlow(), prev_img.as_raw__InputArray(), next_img.as_raw__InputArray(), prev_pts.as_raw__InputArray(), next_pts.as_raw__InputOutputArray(), status.as_raw__OutputArray(), err.as_raw__OutputArray(), stream.as_raw_mut_Stream(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -1195,4 +1195,5 @@ impl dyn CUDA_SparsePyrLKOpticalFlow + '_ {
 		Ok(ret)
 	}
 	
-}
\ No newline at end of file
+}
+pub use crate::manual::cudaoptflow::*;
@@ -155,7 +155,7 @@ pub fn create_stereo_sgm(min_disparity: i32, num_disparities: i32, p1: i32, p2:
 /// ## C++ default parameters
 /// * stream: Stream::Null()
 #[inline]
-pub fn draw_color_disp(src_disp: &dyn core::ToInputArray, dst_disp: &mut dyn core::ToOutputArray, ndisp: i32, stream: &mut core::Stream) -> Result<()> {
+pub fn draw_color_disp(src_disp: &(impl core::ToInputArray + ?Sized), dst_disp: &mut (impl core::ToOutputArray + ?Sized), ndisp: i32, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src_disp);
 	output_array_arg!(dst_disp);
 	return_send!(via ocvrs_return);
@@ -196,7 +196,7 @@ pub fn reproject_image_to_3d_1(mut disp: core::GpuMat, xyzw: &mut core::GpuMat,
 /// * dst_cn: 4
 /// * stream: Stream::Null()
 #[inline]
-pub fn reproject_image_to_3d(disp: &dyn core::ToInputArray, xyzw: &mut dyn core::ToOutputArray, q: &dyn core::ToInputArray, dst_cn: i32, stream: &mut core::Stream) -> Result<()> {
+pub fn reproject_image_to_3d(disp: &(impl core::ToInputArray + ?Sized), xyzw: &mut (impl core::ToOutputArray + ?Sized), q: &(impl core::ToInputArray + ?Sized), dst_cn: i32, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(disp);
 	output_array_arg!(xyzw);
 	input_array_arg!(q);
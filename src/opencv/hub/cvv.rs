@@ -25,7 +25,7 @@ pub mod prelude {
 }
 
 #[inline]
-pub fn debug_d_match(img1: &dyn core::ToInputArray, mut keypoints1: core::Vector<core::KeyPoint>, img2: &dyn core::ToInputArray, mut keypoints2: core::Vector<core::KeyPoint>, mut matches: core::Vector<core::DMatch>, data: &crate::cvv::CallMetaData, description: &str, view: &str, use_train_descriptor: bool) -> Result<()> {
+pub fn debug_d_match(img1: &(impl core::ToInputArray + ?Sized), mut keypoints1: core::Vector<core::KeyPoint>, img2: &(impl core::ToInputArray + ?Sized), mut keypoints2: core::Vector<core::KeyPoint>, mut matches: core::Vector<core::DMatch>, data: &crate::cvv::CallMetaData, description: &str, view: &str, use_train_descriptor: bool) -> Result<()> {
 	input_array_arg!(img1);
 	input_array_arg!(img2);
 	extern_container_arg!(description);
@@ -38,7 +38,7 @@ pub fn debug_d_match(img1: &dyn core::ToInputArray, mut keypoints1: core::Vector
 }
 
 #[inline]
-pub fn debug_filter(original: &dyn core::ToInputArray, result: &dyn core::ToInputArray, data: &crate::cvv::CallMetaData, description: &str, view: &str) -> Result<()> {
+pub fn debug_filter(original: &(impl core::ToInputArray + ?Sized), result: &(impl core::ToInputArray + ?Sized), data: &crate::cvv::CallMetaData, description: &str, view: &str) -> Result<()> {
 	input_array_arg!(original);
 	input_array_arg!(result);
 	extern_container_arg!(description);
@@ -60,7 +60,7 @@ pub fn final_show() -> Result<()> {
 }
 
 #[inline]
-pub fn show_image(img: &dyn core::ToInputArray, data: &crate::cvv::CallMetaData, description: &str, view: &str) -> Result<()> {
+pub fn show_image(img: &(impl core::ToInputArray + ?Sized), data: &crate::cvv::CallMetaData, description: &str, view: &str) -> Result<()> {
 	input_array_arg!(img);
 	extern_container_arg!(description);
 	extern_container_arg!(view);
@@ -83,7 +83,7 @@ pub trait CallMetaDataTraitConst {
 	}
 	
 	#[inline]
-	fn line(&self) -> size_t {
+	fn line(&self) -> usize {
 		let ret = unsafe { sys::cvv_impl_CallMetaData_getPropLine_const(self.as_raw_CallMetaData()) };
 		ret
 	}
@@ -158,7 +158,7 @@ impl CallMetaData {
 	/// 
 	/// Argument should be self-explaining.
 	#[inline]
-	pub fn new(file: &str, line: size_t, function: &str) -> Result<crate::cvv::CallMetaData> {
+	pub fn new(file: &str, line: usize, function: &str) -> Result<crate::cvv::CallMetaData> {
 		extern_container_arg!(file);
 		extern_container_arg!(function);
 		return_send!(via ocvrs_return);
@@ -188,7 +188,7 @@ pub fn nms_boxes_rotated(bboxes: &core::Vector<core::RotatedRect>, scores: &core
 /// * crop: false
 /// * ddepth: CV_32F
 #[inline]
-pub fn blob_from_image_to(image: &dyn core::ToInputArray, blob: &mut dyn core::ToOutputArray, scalefactor: f64, size: core::Size, mean: core::Scalar, swap_rb: bool, crop: bool, ddepth: i32) -> Result<()> {
+pub fn blob_from_image_to(image: &(impl core::ToInputArray + ?Sized), blob: &mut (impl core::ToOutputArray + ?Sized), scalefactor: f64, size: core::Size, mean: core::Scalar, swap_rb: bool, crop: bool, ddepth: i32) -> Result<()> {
 	input_array_arg!(image);
 	output_array_arg!(blob);
 	return_send!(via ocvrs_return);
@@ -224,7 +224,7 @@ pub fn blob_from_image_to(image: &dyn core::ToInputArray, blob: &mut dyn core::T
 /// * crop: false
 /// * ddepth: CV_32F
 #[inline]
-pub fn blob_from_image(image: &dyn core::ToInputArray, scalefactor: f64, size: core::Size, mean: core::Scalar, swap_rb: bool, crop: bool, ddepth: i32) -> Result<core::Mat> {
+pub fn blob_from_image(image: &(impl core::ToInputArray + ?Sized), scalefactor: f64, size: core::Size, mean: core::Scalar, swap_rb: bool, crop: bool, ddepth: i32) -> Result<core::Mat> {
 	input_array_arg!(image);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_dnn_blobFromImage_const__InputArrayR_double_const_SizeR_const_ScalarR_bool_bool_int(image.as_raw__InputArray(), scalefactor, &size, &mean, swap_rb, crop, ddepth, ocvrs_return.as_mut_ptr()) };
@@ -246,7 +246,7 @@ pub fn blob_from_image(image: &dyn core::ToInputArray, scalefactor: f64, size: c
 /// * crop: false
 /// * ddepth: CV_32F
 #[inline]
-pub fn blob_from_images_to(images: &dyn core::ToInputArray, blob: &mut dyn core::ToOutputArray, scalefactor: f64, size: core::Size, mean: core::Scalar, swap_rb: bool, crop: bool, ddepth: i32) -> Result<()> {
+pub fn blob_from_images_to(images: &(impl core::ToInputArray + ?Sized), blob: &mut (impl core::ToOutputArray + ?Sized), scalefactor: f64, size: core::Size, mean: core::Scalar, swap_rb: bool, crop: bool, ddepth: i32) -> Result<()> {
 	input_array_arg!(images);
 	output_array_arg!(blob);
 	return_send!(via ocvrs_return);
@@ -283,7 +283,7 @@ pub fn blob_from_images_to(images: &dyn core::ToInputArray, blob: &mut dyn core:
 /// * crop: false
 /// * ddepth: CV_32F
 #[inline]
-pub fn blob_from_images(images: &dyn core::ToInputArray, scalefactor: f64, size: core::Size, mean: core::Scalar, swap_rb: bool, crop: bool, ddepth: i32) -> Result<core::Mat> {
+pub fn blob_from_images(images: &(impl core::ToInputArray + ?Sized), scalefactor: f64, size: core::Size, mean: core::Scalar, swap_rb: bool, crop: bool, ddepth: i32) -> Result<core::Mat> {
 	input_array_arg!(images);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_dnn_blobFromImages_const__InputArrayR_double_Size_const_ScalarR_bool_bool_int(images.as_raw__InputArray(), scalefactor, size.opencv_as_extern(), &mean, swap_rb, crop, ddepth, ocvrs_return.as_mut_ptr()) };
@@ -404,7 +404,7 @@ pub fn get_plane(m: &core::Mat, n: i32, cn: i32) -> Result<core::Mat> {
 /// (CV_32F). They are non normalized neither mean added. The number of returned images equals the first dimension
 /// of the blob (batch size). Every image has a number of channels equals to the second dimension of the blob (depth).
 #[inline]
-pub fn images_from_blob(blob_: &core::Mat, images_: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn images_from_blob(blob_: &core::Mat, images_: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	output_array_arg!(images_);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_dnn_imagesFromBlob_const_MatR_const__OutputArrayR(blob_.as_raw_Mat(), images_.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
@@ -449,7 +449,7 @@ pub fn read_net_from_caffe(prototxt: &str, caffe_model: &str) -> Result<crate::d
 /// * buffer_model: NULL
 /// * len_model: 0
 #[inline]
-pub fn read_net_from_caffe_str(buffer_proto: &str, len_proto: size_t, buffer_model: &str, len_model: size_t) -> Result<crate::dnn::Net> {
+pub fn read_net_from_caffe_str(buffer_proto: &str, len_proto: usize, buffer_model: &str, len_model: usize) -> Result<crate::dnn::Net> {
 	extern_container_arg!(buffer_proto);
 	extern_container_arg!(buffer_model);
 	return_send!(via ocvrs_return);
@@ -515,7 +515,7 @@ pub fn read_net_from_darknet(cfg_file: &str, darknet_model: &str) -> Result<crat
 /// * buffer_model: NULL
 /// * len_model: 0
 #[inline]
-pub fn read_net_from_darknet_str(buffer_cfg: &str, len_cfg: size_t, buffer_model: &str, len_model: size_t) -> Result<crate::dnn::Net> {
+pub fn read_net_from_darknet_str(buffer_cfg: &str, len_cfg: usize, buffer_model: &str, len_model: usize) -> Result<crate::dnn::Net> {
 	extern_container_arg!(buffer_cfg);
 	extern_container_arg!(buffer_model);
 	return_send!(via ocvrs_return);
@@ -576,7 +576,7 @@ pub fn read_net_from_model_optimizer(xml: &str, bin: &str) -> Result<crate::dnn:
 /// Networks imported from Intel's Model Optimizer are launched in Intel's Inference Engine
 /// backend.
 #[inline]
-pub fn read_net_from_model_optimizer_2(buffer_model_config_ptr: &u8, buffer_model_config_size: size_t, buffer_weights_ptr: &u8, buffer_weights_size: size_t) -> Result<crate::dnn::Net> {
+pub fn read_net_from_model_optimizer_2(buffer_model_config_ptr: &u8, buffer_model_config_size: usize, buffer_weights_ptr: &u8, buffer_weights_size: usize) -> Result<crate::dnn::Net> {
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_dnn_readNetFromModelOptimizer_const_unsigned_charX_size_t_const_unsigned_charX_size_t(buffer_model_config_ptr, buffer_model_config_size, buffer_weights_ptr, buffer_weights_size, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -628,7 +628,7 @@ pub fn read_net_from_onnx(onnx_file: &str) -> Result<crate::dnn::Net> {
 /// Network object that ready to do forward, throw an exception
 ///       in failure cases.
 #[inline]
-pub fn read_net_from_onnx_str(buffer: &str, size_buffer: size_t) -> Result<crate::dnn::Net> {
+pub fn read_net_from_onnx_str(buffer: &str, size_buffer: usize) -> Result<crate::dnn::Net> {
 	extern_container_arg!(buffer);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_dnn_readNetFromONNX_const_charX_size_t(buffer.opencv_as_extern(), size_buffer, ocvrs_return.as_mut_ptr()) };
@@ -691,7 +691,7 @@ pub fn read_net_from_tensorflow(model: &str, config: &str) -> Result<crate::dnn:
 /// * buffer_config: NULL
 /// * len_config: 0
 #[inline]
-pub fn read_net_from_tensorflow_str(buffer_model: &str, len_model: size_t, buffer_config: &str, len_config: size_t) -> Result<crate::dnn::Net> {
+pub fn read_net_from_tensorflow_str(buffer_model: &str, len_model: usize, buffer_config: &str, len_config: usize) -> Result<crate::dnn::Net> {
 	extern_container_arg!(buffer_model);
 	extern_container_arg!(buffer_config);
 	return_send!(via ocvrs_return);
@@ -1043,7 +1043,7 @@ pub fn slice_3(m: &core::Mat, r0: &crate::dnn::_Range, r1: &crate::dnn::_Range,
 /// * sigma: 0.5
 /// * method: SoftNMSMethod::SOFTNMS_GAUSSIAN
 #[inline]
-pub fn soft_nms_boxes(bboxes: &core::Vector<core::Rect>, scores: &core::Vector<f32>, updated_scores: &mut core::Vector<f32>, score_threshold: f32, nms_threshold: f32, indices: &mut core::Vector<i32>, top_k: size_t, sigma: f32, method: crate::dnn::SoftNMSMethod) -> Result<()> {
+pub fn soft_nms_boxes(bboxes: &core::Vector<core::Rect>, scores: &core::Vector<f32>, updated_scores: &mut core::Vector<f32>, score_threshold: f32, nms_threshold: f32, indices: &mut core::Vector<i32>, top_k: usize, sigma: f32, method: crate::dnn::SoftNMSMethod) -> Result<()> {
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_dnn_softNMSBoxes_const_vectorLRectGR_const_vectorLfloatGR_vectorLfloatGR_const_float_const_float_vectorLintGR_size_t_const_float_SoftNMSMethod(bboxes.as_raw_VectorOfRect(), scores.as_raw_VectorOff32(), updated_scores.as_raw_mut_VectorOff32(), score_threshold, nms_threshold, indices.as_raw_mut_VectorOfi32(), top_k, sigma, method, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -1379,7 +1379,7 @@ pub trait ActivationLayerTraitConst: crate::dnn::LayerTraitConst {
 	fn as_raw_ActivationLayer(&self) -> *const c_void;
 
 	#[inline]
-	fn forward_slice(&self, src: &f32, dst: &mut f32, len: i32, out_plane_size: size_t, cn0: i32, cn1: i32) -> Result<()> {
+	fn forward_slice(&self, src: &f32, dst: &mut f32, len: i32, out_plane_size: usize, cn0: i32, cn1: i32) -> Result<()> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_dnn_ActivationLayer_forwardSlice_const_const_floatX_floatX_int_size_t_int_int(self.as_raw_ActivationLayer(), src, dst, len, out_plane_size, cn0, cn1, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -1388,7 +1388,7 @@ pub trait ActivationLayerTraitConst: crate::dnn::LayerTraitConst {
 	}
 	
 	#[inline]
-	fn forward_slice_1(&self, src: &i32, lut: &i32, dst: &mut i32, len: i32, out_plane_size: size_t, cn0: i32, cn1: i32) -> Result<()> {
+	fn forward_slice_1(&self, src: &i32, lut: &i32, dst: &mut i32, len: i32, out_plane_size: usize, cn0: i32, cn1: i32) -> Result<()> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_dnn_ActivationLayer_forwardSlice_const_const_intX_const_intX_intX_int_size_t_int_int(self.as_raw_ActivationLayer(), src, lut, dst, len, out_plane_size, cn0, cn1, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -2216,44 +2216,92 @@ pub trait BaseConvolutionLayerTraitConst: crate::dnn::LayerTraitConst {
 	}
 	
 	#[inline]
-	fn adjust_pads(&self) -> core::Vector<size_t> {
+	fn adjust_pads(&self) -> core::Vector<usize> {
 		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_getPropAdjust_pads_const(self.as_raw_BaseConvolutionLayer()) };
-		let ret = unsafe { core::Vector::<size_t>::opencv_from_extern(ret) };
+		let ret = unsafe { core::Vector::<usize>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Same as `adjust_pads()`, but returns the reference to the field instead of its copy
 	#[inline]
-	fn kernel_size(&self) -> core::Vector<size_t> {
+	fn adjust_pads_ref(&self) -> BoxedRef<'_, core::Vector<usize>> {
+		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_getPropAdjust_padsRef_const(self.as_raw_BaseConvolutionLayer()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
+	#[inline]
+	fn kernel_size(&self) -> core::Vector<usize> {
 		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_getPropKernel_size_const(self.as_raw_BaseConvolutionLayer()) };
-		let ret = unsafe { core::Vector::<size_t>::opencv_from_extern(ret) };
+		let ret = unsafe { core::Vector::<usize>::opencv_from_extern(ret) };
+		ret
+	}
+	
+	/// Same as `kernel_size()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn kernel_size_ref(&self) -> BoxedRef<'_, core::Vector<usize>> {
+		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_getPropKernel_sizeRef_const(self.as_raw_BaseConvolutionLayer()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
 		ret
 	}
 	
 	#[inline]
-	fn strides(&self) -> core::Vector<size_t> {
+	fn strides(&self) -> core::Vector<usize> {
 		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_getPropStrides_const(self.as_raw_BaseConvolutionLayer()) };
-		let ret = unsafe { core::Vector::<size_t>::opencv_from_extern(ret) };
+		let ret = unsafe { core::Vector::<usize>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Same as `strides()`, but returns the reference to the field instead of its copy
 	#[inline]
-	fn dilations(&self) -> core::Vector<size_t> {
+	fn strides_ref(&self) -> BoxedRef<'_, core::Vector<usize>> {
+		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_getPropStridesRef_const(self.as_raw_BaseConvolutionLayer()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
+	#[inline]
+	fn dilations(&self) -> core::Vector<usize> {
 		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_getPropDilations_const(self.as_raw_BaseConvolutionLayer()) };
-		let ret = unsafe { core::Vector::<size_t>::opencv_from_extern(ret) };
+		let ret = unsafe { core::Vector::<usize>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Same as `dilations()`, but returns the reference to the field instead of its copy
 	#[inline]
-	fn pads_begin(&self) -> core::Vector<size_t> {
+	fn dilations_ref(&self) -> BoxedRef<'_, core::Vector<usize>> {
+		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_getPropDilationsRef_const(self.as_raw_BaseConvolutionLayer()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
+	#[inline]
+	fn pads_begin(&self) -> core::Vector<usize> {
 		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_getPropPads_begin_const(self.as_raw_BaseConvolutionLayer()) };
-		let ret = unsafe { core::Vector::<size_t>::opencv_from_extern(ret) };
+		let ret = unsafe { core::Vector::<usize>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Same as `pads_begin()`, but returns the reference to the field instead of its copy
 	#[inline]
-	fn pads_end(&self) -> core::Vector<size_t> {
+	fn pads_begin_ref(&self) -> BoxedRef<'_, core::Vector<usize>> {
+		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_getPropPads_beginRef_const(self.as_raw_BaseConvolutionLayer()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
+	#[inline]
+	fn pads_end(&self) -> core::Vector<usize> {
 		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_getPropPads_end_const(self.as_raw_BaseConvolutionLayer()) };
-		let ret = unsafe { core::Vector::<size_t>::opencv_from_extern(ret) };
+		let ret = unsafe { core::Vector::<usize>::opencv_from_extern(ret) };
+		ret
+	}
+	
+	/// Same as `pads_end()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn pads_end_ref(&self) -> BoxedRef<'_, core::Vector<usize>> {
+		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_getPropPads_endRef_const(self.as_raw_BaseConvolutionLayer()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
 		ret
 	}
 	
@@ -2264,6 +2312,14 @@ pub trait BaseConvolutionLayerTraitConst: crate::dnn::LayerTraitConst {
 		ret
 	}
 	
+	/// Same as `pad_mode()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn pad_mode_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_getPropPadModeRef_const(self.as_raw_BaseConvolutionLayer()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	#[inline]
 	fn num_output(&self) -> i32 {
 		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_getPropNumOutput_const(self.as_raw_BaseConvolutionLayer()) };
@@ -2306,38 +2362,38 @@ pub trait BaseConvolutionLayerTrait: crate::dnn::BaseConvolutionLayerTraitConst
 	}
 	
 	#[inline]
-	fn set_adjust_pads(&mut self, mut val: core::Vector<size_t>) {
-		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_setPropAdjust_pads_vectorLsize_tG(self.as_raw_mut_BaseConvolutionLayer(), val.as_raw_mut_VectorOfsize_t()) };
+	fn set_adjust_pads(&mut self, mut val: core::Vector<usize>) {
+		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_setPropAdjust_pads_vectorLsize_tG(self.as_raw_mut_BaseConvolutionLayer(), val.as_raw_mut_VectorOfusize()) };
 		ret
 	}
 	
 	#[inline]
-	fn set_kernel_size(&mut self, mut val: core::Vector<size_t>) {
-		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_setPropKernel_size_vectorLsize_tG(self.as_raw_mut_BaseConvolutionLayer(), val.as_raw_mut_VectorOfsize_t()) };
+	fn set_kernel_size(&mut self, mut val: core::Vector<usize>) {
+		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_setPropKernel_size_vectorLsize_tG(self.as_raw_mut_BaseConvolutionLayer(), val.as_raw_mut_VectorOfusize()) };
 		ret
 	}
 	
 	#[inline]
-	fn set_strides(&mut self, mut val: core::Vector<size_t>) {
-		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_setPropStrides_vectorLsize_tG(self.as_raw_mut_BaseConvolutionLayer(), val.as_raw_mut_VectorOfsize_t()) };
+	fn set_strides(&mut self, mut val: core::Vector<usize>) {
+		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_setPropStrides_vectorLsize_tG(self.as_raw_mut_BaseConvolutionLayer(), val.as_raw_mut_VectorOfusize()) };
 		ret
 	}
 	
 	#[inline]
-	fn set_dilations(&mut self, mut val: core::Vector<size_t>) {
-		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_setPropDilations_vectorLsize_tG(self.as_raw_mut_BaseConvolutionLayer(), val.as_raw_mut_VectorOfsize_t()) };
+	fn set_dilations(&mut self, mut val: core::Vector<usize>) {
+		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_setPropDilations_vectorLsize_tG(self.as_raw_mut_BaseConvolutionLayer(), val.as_raw_mut_VectorOfusize()) };
 		ret
 	}
 	
 	#[inline]
-	fn set_pads_begin(&mut self, mut val: core::Vector<size_t>) {
-		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_setPropPads_begin_vectorLsize_tG(self.as_raw_mut_BaseConvolutionLayer(), val.as_raw_mut_VectorOfsize_t()) };
+	fn set_pads_begin(&mut self, mut val: core::Vector<usize>) {
+		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_setPropPads_begin_vectorLsize_tG(self.as_raw_mut_BaseConvolutionLayer(), val.as_raw_mut_VectorOfusize()) };
 		ret
 	}
 	
 	#[inline]
-	fn set_pads_end(&mut self, mut val: core::Vector<size_t>) {
-		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_setPropPads_end_vectorLsize_tG(self.as_raw_mut_BaseConvolutionLayer(), val.as_raw_mut_VectorOfsize_t()) };
+	fn set_pads_end(&mut self, mut val: core::Vector<usize>) {
+		let ret = unsafe { sys::cv_dnn_BaseConvolutionLayer_setPropPads_end_vectorLsize_tG(self.as_raw_mut_BaseConvolutionLayer(), val.as_raw_mut_VectorOfusize()) };
 		ret
 	}
 	
@@ -4534,6 +4590,18 @@ pub trait DictTraitConst {
 		Ok(ret)
 	}
 	
+	/// Same as `get()`, but returns the reference instead of the copy
+	#[inline]
+	fn get_ref(&self, key: &str) -> Result<BoxedRef<'_, crate::dnn::DictValue>> {
+		extern_container_arg!(key);
+		return_send!(via ocvrs_return);
+		unsafe { sys::cv_dnn_Dict_getRef_const_const_StringR(self.as_raw_Dict(), key.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
+		return_receive!(unsafe ocvrs_return => ret);
+		let ret = ret.into_result()?;
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		Ok(ret)
+	}
+	
 }
 
 pub trait DictTrait: crate::dnn::DictTraitConst {
@@ -6466,6 +6534,14 @@ pub trait LayerTraitConst: core::AlgorithmTraitConst {
 		ret
 	}
 	
+	/// Same as `blobs()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn blobs_ref(&self) -> BoxedRef<'_, core::Vector<core::Mat>> {
+		let ret = unsafe { sys::cv_dnn_Layer_getPropBlobsRef_const(self.as_raw_Layer()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// Name of the layer instance, can be used for logging or other internal purposes.
 	#[inline]
 	fn name(&self) -> String {
@@ -6474,6 +6550,14 @@ pub trait LayerTraitConst: core::AlgorithmTraitConst {
 		ret
 	}
 	
+	/// Same as `name()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn name_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_dnn_Layer_getPropNameRef_const(self.as_raw_Layer()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	/// Type name which was used for creating layer by layer factory.
 	#[inline]
 	fn typ(&self) -> String {
@@ -6482,6 +6566,14 @@ pub trait LayerTraitConst: core::AlgorithmTraitConst {
 		ret
 	}
 	
+	/// Same as `typ()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn typ_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_dnn_Layer_getPropTypeRef_const(self.as_raw_Layer()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	/// prefer target for layer forwarding
 	#[inline]
 	fn preferable_target(&self) -> i32 {
@@ -7199,6 +7291,14 @@ pub trait LayerParamsTraitConst: crate::dnn::DictTraitConst {
 		ret
 	}
 	
+	/// Same as `blobs()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn blobs_ref(&self) -> BoxedRef<'_, core::Vector<core::Mat>> {
+		let ret = unsafe { sys::cv_dnn_LayerParams_getPropBlobsRef_const(self.as_raw_LayerParams()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// Name of the layer instance (optional, can be used internal purposes).
 	#[inline]
 	fn name(&self) -> String {
@@ -7207,6 +7307,14 @@ pub trait LayerParamsTraitConst: crate::dnn::DictTraitConst {
 		ret
 	}
 	
+	/// Same as `name()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn name_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_dnn_LayerParams_getPropNameRef_const(self.as_raw_LayerParams()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	/// Type name which was used for creating layer by layer factory (optional).
 	#[inline]
 	fn typ(&self) -> String {
@@ -7215,6 +7323,14 @@ pub trait LayerParamsTraitConst: crate::dnn::DictTraitConst {
 		ret
 	}
 	
+	/// Same as `typ()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn typ_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_dnn_LayerParams_getPropTypeRef_const(self.as_raw_LayerParams()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 }
 
 pub trait LayerParamsTrait: crate::dnn::DictTrait + crate::dnn::LayerParamsTraitConst {
@@ -8278,7 +8394,7 @@ pub trait NetTraitConst {
 	/// * weights: output parameter to store resulting bytes for weights.
 	/// * blobs: output parameter to store resulting bytes for intermediate blobs.
 	#[inline]
-	fn get_memory_consumption(&self, net_input_shapes: &core::Vector<crate::dnn::MatShape>, weights: &mut size_t, blobs: &mut size_t) -> Result<()> {
+	fn get_memory_consumption(&self, net_input_shapes: &core::Vector<crate::dnn::MatShape>, weights: &mut usize, blobs: &mut usize) -> Result<()> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_dnn_Net_getMemoryConsumption_const_const_vectorLMatShapeGR_size_tR_size_tR(self.as_raw_Net(), net_input_shapes.as_raw_VectorOfMatShape(), weights, blobs, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -8295,7 +8411,7 @@ pub trait NetTraitConst {
 	/// 
 	/// ## Overloaded parameters
 	#[inline]
-	fn get_memory_consumption_1(&self, net_input_shape: &crate::dnn::MatShape, weights: &mut size_t, blobs: &mut size_t) -> Result<()> {
+	fn get_memory_consumption_1(&self, net_input_shape: &crate::dnn::MatShape, weights: &mut usize, blobs: &mut usize) -> Result<()> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_dnn_Net_getMemoryConsumption_const_const_MatShapeR_size_tR_size_tR(self.as_raw_Net(), net_input_shape.as_raw_VectorOfi32(), weights, blobs, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -8312,7 +8428,7 @@ pub trait NetTraitConst {
 	/// 
 	/// ## Overloaded parameters
 	#[inline]
-	fn get_memory_consumption_for_layer(&self, layer_id: i32, net_input_shapes: &core::Vector<crate::dnn::MatShape>, weights: &mut size_t, blobs: &mut size_t) -> Result<()> {
+	fn get_memory_consumption_for_layer(&self, layer_id: i32, net_input_shapes: &core::Vector<crate::dnn::MatShape>, weights: &mut usize, blobs: &mut usize) -> Result<()> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_dnn_Net_getMemoryConsumption_const_const_int_const_vectorLMatShapeGR_size_tR_size_tR(self.as_raw_Net(), layer_id, net_input_shapes.as_raw_VectorOfMatShape(), weights, blobs, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -8329,7 +8445,7 @@ pub trait NetTraitConst {
 	/// 
 	/// ## Overloaded parameters
 	#[inline]
-	fn get_memory_consumption_2(&self, layer_id: i32, net_input_shape: &crate::dnn::MatShape, weights: &mut size_t, blobs: &mut size_t) -> Result<()> {
+	fn get_memory_consumption_2(&self, layer_id: i32, net_input_shape: &crate::dnn::MatShape, weights: &mut usize, blobs: &mut usize) -> Result<()> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_dnn_Net_getMemoryConsumption_const_const_int_const_MatShapeR_size_tR_size_tR(self.as_raw_Net(), layer_id, net_input_shape.as_raw_VectorOfi32(), weights, blobs, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -8345,9 +8461,9 @@ pub trait NetTraitConst {
 	/// * weights: output parameter to store resulting bytes for weights.
 	/// * blobs: output parameter to store resulting bytes for intermediate blobs.
 	#[inline]
-	fn get_memory_consumption_for_layers(&self, net_input_shapes: &core::Vector<crate::dnn::MatShape>, layer_ids: &mut core::Vector<i32>, weights: &mut core::Vector<size_t>, blobs: &mut core::Vector<size_t>) -> Result<()> {
+	fn get_memory_consumption_for_layers(&self, net_input_shapes: &core::Vector<crate::dnn::MatShape>, layer_ids: &mut core::Vector<i32>, weights: &mut core::Vector<usize>, blobs: &mut core::Vector<usize>) -> Result<()> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_getMemoryConsumption_const_const_vectorLMatShapeGR_vectorLintGR_vectorLsize_tGR_vectorLsize_tGR(self.as_raw_Net(), net_input_shapes.as_raw_VectorOfMatShape(), layer_ids.as_raw_mut_VectorOfi32(), weights.as_raw_mut_VectorOfsize_t(), blobs.as_raw_mut_VectorOfsize_t(), ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_dnn_Net_getMemoryConsumption_const_const_vectorLMatShapeGR_vectorLintGR_vectorLsize_tGR_vectorLsize_tGR(self.as_raw_Net(), net_input_shapes.as_raw_VectorOfMatShape(), layer_ids.as_raw_mut_VectorOfi32(), weights.as_raw_mut_VectorOfusize(), blobs.as_raw_mut_VectorOfusize(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8363,9 +8479,9 @@ pub trait NetTraitConst {
 	/// 
 	/// ## Overloaded parameters
 	#[inline]
-	fn get_memory_consumption_3(&self, net_input_shape: &crate::dnn::MatShape, layer_ids: &mut core::Vector<i32>, weights: &mut core::Vector<size_t>, blobs: &mut core::Vector<size_t>) -> Result<()> {
+	fn get_memory_consumption_3(&self, net_input_shape: &crate::dnn::MatShape, layer_ids: &mut core::Vector<i32>, weights: &mut core::Vector<usize>, blobs: &mut core::Vector<usize>) -> Result<()> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_dnn_Net_getMemoryConsumption_const_const_MatShapeR_vectorLintGR_vectorLsize_tGR_vectorLsize_tGR(self.as_raw_Net(), net_input_shape.as_raw_VectorOfi32(), layer_ids.as_raw_mut_VectorOfi32(), weights.as_raw_mut_VectorOfsize_t(), blobs.as_raw_mut_VectorOfsize_t(), ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_dnn_Net_getMemoryConsumption_const_const_MatShapeR_vectorLintGR_vectorLsize_tGR_vectorLsize_tGR(self.as_raw_Net(), net_input_shape.as_raw_VectorOfi32(), layer_ids.as_raw_mut_VectorOfi32(), weights.as_raw_mut_VectorOfusize(), blobs.as_raw_mut_VectorOfusize(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -8902,7 +9018,7 @@ impl Net {
 	/// ## Returns
 	/// Net object.
 	#[inline]
-	pub fn read_from_model_optimizer_2(buffer_model_config_ptr: &u8, buffer_model_config_size: size_t, buffer_weights_ptr: &u8, buffer_weights_size: size_t) -> Result<crate::dnn::Net> {
+	pub fn read_from_model_optimizer_2(buffer_model_config_ptr: &u8, buffer_model_config_size: usize, buffer_weights_ptr: &u8, buffer_weights_size: usize) -> Result<crate::dnn::Net> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_dnn_Net_readFromModelOptimizer_const_unsigned_charX_size_t_const_unsigned_charX_size_t(buffer_model_config_ptr, buffer_model_config_size, buffer_weights_ptr, buffer_weights_size, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -9321,30 +9437,62 @@ pub trait PoolingLayerTraitConst: crate::dnn::LayerTraitConst {
 	}
 	
 	#[inline]
-	fn kernel_size(&self) -> core::Vector<size_t> {
+	fn kernel_size(&self) -> core::Vector<usize> {
 		let ret = unsafe { sys::cv_dnn_PoolingLayer_getPropKernel_size_const(self.as_raw_PoolingLayer()) };
-		let ret = unsafe { core::Vector::<size_t>::opencv_from_extern(ret) };
+		let ret = unsafe { core::Vector::<usize>::opencv_from_extern(ret) };
+		ret
+	}
+	
+	/// Same as `kernel_size()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn kernel_size_ref(&self) -> BoxedRef<'_, core::Vector<usize>> {
+		let ret = unsafe { sys::cv_dnn_PoolingLayer_getPropKernel_sizeRef_const(self.as_raw_PoolingLayer()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
 		ret
 	}
 	
 	#[inline]
-	fn strides(&self) -> core::Vector<size_t> {
+	fn strides(&self) -> core::Vector<usize> {
 		let ret = unsafe { sys::cv_dnn_PoolingLayer_getPropStrides_const(self.as_raw_PoolingLayer()) };
-		let ret = unsafe { core::Vector::<size_t>::opencv_from_extern(ret) };
+		let ret = unsafe { core::Vector::<usize>::opencv_from_extern(ret) };
+		ret
+	}
+	
+	/// Same as `strides()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn strides_ref(&self) -> BoxedRef<'_, core::Vector<usize>> {
+		let ret = unsafe { sys::cv_dnn_PoolingLayer_getPropStridesRef_const(self.as_raw_PoolingLayer()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
 		ret
 	}
 	
 	#[inline]
-	fn pads_begin(&self) -> core::Vector<size_t> {
+	fn pads_begin(&self) -> core::Vector<usize> {
 		let ret = unsafe { sys::cv_dnn_PoolingLayer_getPropPads_begin_const(self.as_raw_PoolingLayer()) };
-		let ret = unsafe { core::Vector::<size_t>::opencv_from_extern(ret) };
+		let ret = unsafe { core::Vector::<usize>::opencv_from_extern(ret) };
 		ret
 	}
 	
+	/// Same as `pads_begin()`, but returns the reference to the field instead of its copy
 	#[inline]
-	fn pads_end(&self) -> core::Vector<size_t> {
+	fn pads_begin_ref(&self) -> BoxedRef<'_, core::Vector<usize>> {
+		let ret = unsafe { sys::cv_dnn_PoolingLayer_getPropPads_beginRef_const(self.as_raw_PoolingLayer()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
+	#[inline]
+	fn pads_end(&self) -> core::Vector<usize> {
 		let ret = unsafe { sys::cv_dnn_PoolingLayer_getPropPads_end_const(self.as_raw_PoolingLayer()) };
-		let ret = unsafe { core::Vector::<size_t>::opencv_from_extern(ret) };
+		let ret = unsafe { core::Vector::<usize>::opencv_from_extern(ret) };
+		ret
+	}
+	
+	/// Same as `pads_end()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn pads_end_ref(&self) -> BoxedRef<'_, core::Vector<usize>> {
+		let ret = unsafe { sys::cv_dnn_PoolingLayer_getPropPads_endRef_const(self.as_raw_PoolingLayer()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
 		ret
 	}
 	
@@ -9362,6 +9510,14 @@ pub trait PoolingLayerTraitConst: crate::dnn::LayerTraitConst {
 		ret
 	}
 	
+	/// Same as `is_global_pooling()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn is_global_pooling_ref(&self) -> BoxedRef<'_, core::Vector<bool>> {
+		let ret = unsafe { sys::cv_dnn_PoolingLayer_getPropIsGlobalPoolingRef_const(self.as_raw_PoolingLayer()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn compute_max_idx(&self) -> bool {
 		let ret = unsafe { sys::cv_dnn_PoolingLayer_getPropComputeMaxIdx_const(self.as_raw_PoolingLayer()) };
@@ -9375,6 +9531,14 @@ pub trait PoolingLayerTraitConst: crate::dnn::LayerTraitConst {
 		ret
 	}
 	
+	/// Same as `pad_mode()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn pad_mode_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_dnn_PoolingLayer_getPropPadModeRef_const(self.as_raw_PoolingLayer()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	#[inline]
 	fn ceil_mode(&self) -> bool {
 		let ret = unsafe { sys::cv_dnn_PoolingLayer_getPropCeilMode_const(self.as_raw_PoolingLayer()) };
@@ -9419,26 +9583,26 @@ pub trait PoolingLayerTrait: crate::dnn::LayerTrait + crate::dnn::PoolingLayerTr
 	}
 	
 	#[inline]
-	fn set_kernel_size(&mut self, mut val: core::Vector<size_t>) {
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_setPropKernel_size_vectorLsize_tG(self.as_raw_mut_PoolingLayer(), val.as_raw_mut_VectorOfsize_t()) };
+	fn set_kernel_size(&mut self, mut val: core::Vector<usize>) {
+		let ret = unsafe { sys::cv_dnn_PoolingLayer_setPropKernel_size_vectorLsize_tG(self.as_raw_mut_PoolingLayer(), val.as_raw_mut_VectorOfusize()) };
 		ret
 	}
 	
 	#[inline]
-	fn set_strides(&mut self, mut val: core::Vector<size_t>) {
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_setPropStrides_vectorLsize_tG(self.as_raw_mut_PoolingLayer(), val.as_raw_mut_VectorOfsize_t()) };
+	fn set_strides(&mut self, mut val: core::Vector<usize>) {
+		let ret = unsafe { sys::cv_dnn_PoolingLayer_setPropStrides_vectorLsize_tG(self.as_raw_mut_PoolingLayer(), val.as_raw_mut_VectorOfusize()) };
 		ret
 	}
 	
 	#[inline]
-	fn set_pads_begin(&mut self, mut val: core::Vector<size_t>) {
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_setPropPads_begin_vectorLsize_tG(self.as_raw_mut_PoolingLayer(), val.as_raw_mut_VectorOfsize_t()) };
+	fn set_pads_begin(&mut self, mut val: core::Vector<usize>) {
+		let ret = unsafe { sys::cv_dnn_PoolingLayer_setPropPads_begin_vectorLsize_tG(self.as_raw_mut_PoolingLayer(), val.as_raw_mut_VectorOfusize()) };
 		ret
 	}
 	
 	#[inline]
-	fn set_pads_end(&mut self, mut val: core::Vector<size_t>) {
-		let ret = unsafe { sys::cv_dnn_PoolingLayer_setPropPads_end_vectorLsize_tG(self.as_raw_mut_PoolingLayer(), val.as_raw_mut_VectorOfsize_t()) };
+	fn set_pads_end(&mut self, mut val: core::Vector<usize>) {
+		let ret = unsafe { sys::cv_dnn_PoolingLayer_setPropPads_end_vectorLsize_tG(self.as_raw_mut_PoolingLayer(), val.as_raw_mut_VectorOfusize()) };
 		ret
 	}
 	
@@ -10360,9 +10524,17 @@ pub trait ReduceLayerTraitConst: crate::dnn::LayerTraitConst {
 	}
 	
 	#[inline]
-	fn reduce_dims(&self) -> core::Vector<size_t> {
+	fn reduce_dims(&self) -> core::Vector<usize> {
 		let ret = unsafe { sys::cv_dnn_ReduceLayer_getPropReduceDims_const(self.as_raw_ReduceLayer()) };
-		let ret = unsafe { core::Vector::<size_t>::opencv_from_extern(ret) };
+		let ret = unsafe { core::Vector::<usize>::opencv_from_extern(ret) };
+		ret
+	}
+	
+	/// Same as `reduce_dims()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn reduce_dims_ref(&self) -> BoxedRef<'_, core::Vector<usize>> {
+		let ret = unsafe { sys::cv_dnn_ReduceLayer_getPropReduceDimsRef_const(self.as_raw_ReduceLayer()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
 		ret
 	}
 	
@@ -10378,8 +10550,8 @@ pub trait ReduceLayerTrait: crate::dnn::LayerTrait + crate::dnn::ReduceLayerTrai
 	}
 	
 	#[inline]
-	fn set_reduce_dims(&mut self, mut val: core::Vector<size_t>) {
-		let ret = unsafe { sys::cv_dnn_ReduceLayer_setPropReduceDims_vectorLsize_tG(self.as_raw_mut_ReduceLayer(), val.as_raw_mut_VectorOfsize_t()) };
+	fn set_reduce_dims(&mut self, mut val: core::Vector<usize>) {
+		let ret = unsafe { sys::cv_dnn_ReduceLayer_setPropReduceDims_vectorLsize_tG(self.as_raw_mut_ReduceLayer(), val.as_raw_mut_VectorOfusize()) };
 		ret
 	}
 	
@@ -10761,6 +10933,14 @@ pub trait ReshapeLayerTraitConst: crate::dnn::LayerTraitConst {
 		ret
 	}
 	
+	/// Same as `new_shape_desc()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn new_shape_desc_ref(&self) -> BoxedRef<'_, core::Vector<i32>> {
+		let ret = unsafe { sys::cv_dnn_ReshapeLayer_getPropNewShapeDescRef_const(self.as_raw_ReshapeLayer()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn new_shape_range(&self) -> core::Range {
 		let ret = unsafe { sys::cv_dnn_ReshapeLayer_getPropNewShapeRange_const(self.as_raw_ReshapeLayer()) };
@@ -10768,6 +10948,14 @@ pub trait ReshapeLayerTraitConst: crate::dnn::LayerTraitConst {
 		ret
 	}
 	
+	/// Same as `new_shape_range()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn new_shape_range_ref(&self) -> BoxedRef<'_, core::Range> {
+		let ret = unsafe { sys::cv_dnn_ReshapeLayer_getPropNewShapeRangeRef_const(self.as_raw_ReshapeLayer()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 }
 
 pub trait ReshapeLayerTrait: crate::dnn::LayerTrait + crate::dnn::ReshapeLayerTraitConst {
@@ -11013,6 +11201,14 @@ pub trait ScaleLayerTraitConst: crate::dnn::LayerTraitConst {
 		ret
 	}
 	
+	/// Same as `mode()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn mode_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_dnn_ScaleLayer_getPropModeRef_const(self.as_raw_ScaleLayer()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 }
 
 pub trait ScaleLayerTrait: crate::dnn::LayerTrait + crate::dnn::ScaleLayerTraitConst {
@@ -12066,6 +12262,14 @@ pub trait SliceLayerTraitConst: crate::dnn::LayerTraitConst {
 		ret
 	}
 	
+	/// Same as `slice_ranges()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn slice_ranges_ref(&self) -> BoxedRef<'_, core::Vector<core::Vector<core::Range>>> {
+		let ret = unsafe { sys::cv_dnn_SliceLayer_getPropSliceRangesRef_const(self.as_raw_SliceLayer()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn slice_steps(&self) -> core::Vector<core::Vector<i32>> {
 		let ret = unsafe { sys::cv_dnn_SliceLayer_getPropSliceSteps_const(self.as_raw_SliceLayer()) };
@@ -12073,6 +12277,14 @@ pub trait SliceLayerTraitConst: crate::dnn::LayerTraitConst {
 		ret
 	}
 	
+	/// Same as `slice_steps()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn slice_steps_ref(&self) -> BoxedRef<'_, core::Vector<core::Vector<i32>>> {
+		let ret = unsafe { sys::cv_dnn_SliceLayer_getPropSliceStepsRef_const(self.as_raw_SliceLayer()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn axis(&self) -> i32 {
 		let ret = unsafe { sys::cv_dnn_SliceLayer_getPropAxis_const(self.as_raw_SliceLayer()) };
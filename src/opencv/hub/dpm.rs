@@ -71,7 +71,7 @@ pub trait DPMDetectorConst {
 	
 	/// Return a count of loaded models (classes).
 	#[inline]
-	fn get_class_count(&self) -> Result<size_t> {
+	fn get_class_count(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_dpm_DPMDetector_getClassCount_const(self.as_raw_DPMDetector(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -73,7 +73,7 @@ pub fn create_facemark_lbf() -> Result<core::Ptr<dyn crate::face::Facemark>> {
 /// ## C++ default parameters
 /// * color: Scalar(255,0,0)
 #[inline]
-pub fn draw_facemarks(image: &mut dyn core::ToInputOutputArray, points: &dyn core::ToInputArray, color: core::Scalar) -> Result<()> {
+pub fn draw_facemarks(image: &mut (impl core::ToInputOutputArray + ?Sized), points: &(impl core::ToInputArray + ?Sized), color: core::Scalar) -> Result<()> {
 	input_output_array_arg!(image);
 	input_array_arg!(points);
 	return_send!(via ocvrs_return);
@@ -84,7 +84,7 @@ pub fn draw_facemarks(image: &mut dyn core::ToInputOutputArray, points: &dyn cor
 }
 
 #[inline]
-pub fn get_faces_haar(image: &dyn core::ToInputArray, faces: &mut dyn core::ToOutputArray, face_cascade_name: &str) -> Result<bool> {
+pub fn get_faces_haar(image: &(impl core::ToInputArray + ?Sized), faces: &mut (impl core::ToOutputArray + ?Sized), face_cascade_name: &str) -> Result<bool> {
 	input_array_arg!(image);
 	output_array_arg!(faces);
 	extern_container_arg!(face_cascade_name);
@@ -118,7 +118,7 @@ pub fn get_faces_haar(image: &dyn core::ToInputArray, faces: &mut dyn core::ToOu
 /// ```
 /// 
 #[inline]
-pub fn get_faces(image: &dyn core::ToInputArray, faces: &mut dyn core::ToOutputArray, params: &mut crate::face::CParams) -> Result<bool> {
+pub fn get_faces(image: &(impl core::ToInputArray + ?Sized), faces: &mut (impl core::ToOutputArray + ?Sized), params: &mut crate::face::CParams) -> Result<bool> {
 	input_array_arg!(image);
 	output_array_arg!(faces);
 	return_send!(via ocvrs_return);
@@ -186,7 +186,7 @@ pub fn load_dataset_list(image_list: &str, annotation_list: &str, images: &mut c
 /// ## C++ default parameters
 /// * offset: 0.0f
 #[inline]
-pub fn load_face_points(filename: &str, points: &mut dyn core::ToOutputArray, offset: f32) -> Result<bool> {
+pub fn load_face_points(filename: &str, points: &mut (impl core::ToOutputArray + ?Sized), offset: f32) -> Result<bool> {
 	extern_container_arg!(mut filename);
 	output_array_arg!(points);
 	return_send!(via ocvrs_return);
@@ -239,7 +239,7 @@ pub fn load_face_points(filename: &str, points: &mut dyn core::ToOutputArray, of
 /// ## C++ default parameters
 /// * offset: 0.0f
 #[inline]
-pub fn load_training_data_1(image_list: &str, ground_truth: &str, images: &mut core::Vector<String>, face_points: &mut dyn core::ToOutputArray, offset: f32) -> Result<bool> {
+pub fn load_training_data_1(image_list: &str, ground_truth: &str, images: &mut core::Vector<String>, face_points: &mut (impl core::ToOutputArray + ?Sized), offset: f32) -> Result<bool> {
 	extern_container_arg!(mut image_list);
 	extern_container_arg!(mut ground_truth);
 	output_array_arg!(face_points);
@@ -282,7 +282,7 @@ pub fn load_training_data_1(image_list: &str, ground_truth: &str, images: &mut c
 /// * delim: ' '
 /// * offset: 0.0f
 #[inline]
-pub fn load_training_data(filename: &str, images: &mut core::Vector<String>, face_points: &mut dyn core::ToOutputArray, delim: i8, offset: f32) -> Result<bool> {
+pub fn load_training_data(filename: &str, images: &mut core::Vector<String>, face_points: &mut (impl core::ToOutputArray + ?Sized), delim: i8, offset: f32) -> Result<bool> {
 	extern_container_arg!(mut filename);
 	output_array_arg!(face_points);
 	return_send!(via ocvrs_return);
@@ -531,6 +531,14 @@ pub trait CParamsTraitConst {
 		ret
 	}
 	
+	/// Same as `cascade()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn cascade_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_face_CParams_getPropCascadeRef_const(self.as_raw_CParams()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	/// Parameter specifying how much the image size is reduced at each image scale.
 	#[inline]
 	fn scale_factor(&self) -> f64 {
@@ -570,6 +578,14 @@ pub trait CParamsTraitConst {
 		ret
 	}
 	
+	/// Same as `face_cascade()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn face_cascade_ref(&self) -> BoxedRef<'_, crate::objdetect::CascadeClassifier> {
+		let ret = unsafe { sys::cv_face_CParams_getPropFace_cascadeRef_const(self.as_raw_CParams()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 }
 
 pub trait CParamsTrait: crate::face::CParamsTraitConst {
@@ -1319,9 +1335,9 @@ impl dyn FacemarkAAM + '_ {
 	/// ## C++ default parameters
 	/// * parameters: FacemarkAAM::Params()
 	#[inline]
-	pub fn create(parameters: &crate::face::FacemarkAAM_Params) -> Result<core::Ptr<dyn crate::face::FacemarkAAM>> {
+	pub fn create(parameters: Option<&crate::face::FacemarkAAM_Params>) -> Result<core::Ptr<dyn crate::face::FacemarkAAM>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FacemarkAAM_create_const_ParamsR(parameters.as_raw_FacemarkAAM_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_face_FacemarkAAM_create_const_ParamsR(parameters.map_or(::core::ptr::null(), |parameters| parameters.as_raw_FacemarkAAM_Params()), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::face::FacemarkAAM>::opencv_from_extern(ret) };
@@ -1340,6 +1356,14 @@ pub trait FacemarkAAM_ConfigTraitConst {
 		ret
 	}
 	
+	/// Same as `r()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn r_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_face_FacemarkAAM_Config_getPropRRef_const(self.as_raw_FacemarkAAM_Config()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn t(&self) -> core::Point2f {
 		return_send!(via ocvrs_return);
@@ -1444,6 +1468,14 @@ pub trait FacemarkAAM_DataTraitConst {
 		ret
 	}
 	
+	/// Same as `s0()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn s0_ref(&self) -> BoxedRef<'_, core::Vector<core::Point2f>> {
+		let ret = unsafe { sys::cv_face_FacemarkAAM_Data_getPropS0Ref_const(self.as_raw_FacemarkAAM_Data()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 }
 
 pub trait FacemarkAAM_DataTrait: crate::face::FacemarkAAM_DataTraitConst {
@@ -1495,6 +1527,14 @@ pub trait FacemarkAAM_ModelTraitConst {
 		ret
 	}
 	
+	/// Same as `scales()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn scales_ref(&self) -> BoxedRef<'_, core::Vector<f32>> {
+		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_getPropScalesRef_const(self.as_raw_FacemarkAAM_Model()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn triangles(&self) -> core::Vector<core::Vec3i> {
 		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_getPropTriangles_const(self.as_raw_FacemarkAAM_Model()) };
@@ -1502,6 +1542,14 @@ pub trait FacemarkAAM_ModelTraitConst {
 		ret
 	}
 	
+	/// Same as `triangles()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn triangles_ref(&self) -> BoxedRef<'_, core::Vector<core::Vec3i>> {
+		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_getPropTrianglesRef_const(self.as_raw_FacemarkAAM_Model()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn textures(&self) -> core::Vector<crate::face::FacemarkAAM_Model_Texture> {
 		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_getPropTextures_const(self.as_raw_FacemarkAAM_Model()) };
@@ -1509,6 +1557,14 @@ pub trait FacemarkAAM_ModelTraitConst {
 		ret
 	}
 	
+	/// Same as `textures()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn textures_ref(&self) -> BoxedRef<'_, core::Vector<crate::face::FacemarkAAM_Model_Texture>> {
+		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_getPropTexturesRef_const(self.as_raw_FacemarkAAM_Model()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn s0(&self) -> core::Vector<core::Point2f> {
 		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_getPropS0_const(self.as_raw_FacemarkAAM_Model()) };
@@ -1516,6 +1572,14 @@ pub trait FacemarkAAM_ModelTraitConst {
 		ret
 	}
 	
+	/// Same as `s0()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn s0_ref(&self) -> BoxedRef<'_, core::Vector<core::Point2f>> {
+		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_getPropS0Ref_const(self.as_raw_FacemarkAAM_Model()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn s(&self) -> core::Mat {
 		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_getPropS_const(self.as_raw_FacemarkAAM_Model()) };
@@ -1523,6 +1587,14 @@ pub trait FacemarkAAM_ModelTraitConst {
 		ret
 	}
 	
+	/// Same as `s()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn s_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_getPropSRef_const(self.as_raw_FacemarkAAM_Model()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn q(&self) -> core::Mat {
 		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_getPropQ_const(self.as_raw_FacemarkAAM_Model()) };
@@ -1530,6 +1602,14 @@ pub trait FacemarkAAM_ModelTraitConst {
 		ret
 	}
 	
+	/// Same as `q()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn q_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_getPropQRef_const(self.as_raw_FacemarkAAM_Model()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 }
 
 pub trait FacemarkAAM_ModelTrait: crate::face::FacemarkAAM_ModelTraitConst {
@@ -1625,6 +1705,14 @@ pub trait FacemarkAAM_Model_TextureTraitConst {
 		ret
 	}
 	
+	/// Same as `a()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn a_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_getPropARef_const(self.as_raw_FacemarkAAM_Model_Texture()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn a0(&self) -> core::Mat {
 		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_getPropA0_const(self.as_raw_FacemarkAAM_Model_Texture()) };
@@ -1632,6 +1720,14 @@ pub trait FacemarkAAM_Model_TextureTraitConst {
 		ret
 	}
 	
+	/// Same as `a0()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn a0_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_getPropA0Ref_const(self.as_raw_FacemarkAAM_Model_Texture()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn aa(&self) -> core::Mat {
 		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_getPropAA_const(self.as_raw_FacemarkAAM_Model_Texture()) };
@@ -1639,6 +1735,14 @@ pub trait FacemarkAAM_Model_TextureTraitConst {
 		ret
 	}
 	
+	/// Same as `aa()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn aa_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_getPropAARef_const(self.as_raw_FacemarkAAM_Model_Texture()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn aa0(&self) -> core::Mat {
 		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_getPropAA0_const(self.as_raw_FacemarkAAM_Model_Texture()) };
@@ -1646,6 +1750,14 @@ pub trait FacemarkAAM_Model_TextureTraitConst {
 		ret
 	}
 	
+	/// Same as `aa0()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn aa0_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_getPropAA0Ref_const(self.as_raw_FacemarkAAM_Model_Texture()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn texture_idx(&self) -> core::Vector<core::Vector<core::Point>> {
 		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_getPropTextureIdx_const(self.as_raw_FacemarkAAM_Model_Texture()) };
@@ -1653,6 +1765,14 @@ pub trait FacemarkAAM_Model_TextureTraitConst {
 		ret
 	}
 	
+	/// Same as `texture_idx()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn texture_idx_ref(&self) -> BoxedRef<'_, core::Vector<core::Vector<core::Point>>> {
+		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_getPropTextureIdxRef_const(self.as_raw_FacemarkAAM_Model_Texture()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn base_shape(&self) -> core::Vector<core::Point2f> {
 		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_getPropBase_shape_const(self.as_raw_FacemarkAAM_Model_Texture()) };
@@ -1660,6 +1780,14 @@ pub trait FacemarkAAM_Model_TextureTraitConst {
 		ret
 	}
 	
+	/// Same as `base_shape()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn base_shape_ref(&self) -> BoxedRef<'_, core::Vector<core::Point2f>> {
+		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_getPropBase_shapeRef_const(self.as_raw_FacemarkAAM_Model_Texture()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn ind1(&self) -> core::Vector<i32> {
 		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_getPropInd1_const(self.as_raw_FacemarkAAM_Model_Texture()) };
@@ -1667,6 +1795,14 @@ pub trait FacemarkAAM_Model_TextureTraitConst {
 		ret
 	}
 	
+	/// Same as `ind1()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn ind1_ref(&self) -> BoxedRef<'_, core::Vector<i32>> {
+		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_getPropInd1Ref_const(self.as_raw_FacemarkAAM_Model_Texture()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn ind2(&self) -> core::Vector<i32> {
 		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_getPropInd2_const(self.as_raw_FacemarkAAM_Model_Texture()) };
@@ -1674,6 +1810,14 @@ pub trait FacemarkAAM_Model_TextureTraitConst {
 		ret
 	}
 	
+	/// Same as `ind2()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn ind2_ref(&self) -> BoxedRef<'_, core::Vector<i32>> {
+		let ret = unsafe { sys::cv_face_FacemarkAAM_Model_Texture_getPropInd2Ref_const(self.as_raw_FacemarkAAM_Model_Texture()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 }
 
 pub trait FacemarkAAM_Model_TextureTrait: crate::face::FacemarkAAM_Model_TextureTraitConst {
@@ -1778,6 +1922,14 @@ pub trait FacemarkAAM_ParamsTraitConst {
 		ret
 	}
 	
+	/// Same as `model_filename()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn model_filename_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_face_FacemarkAAM_Params_getPropModel_filenameRef_const(self.as_raw_FacemarkAAM_Params()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	#[inline]
 	fn m(&self) -> i32 {
 		let ret = unsafe { sys::cv_face_FacemarkAAM_Params_getPropM_const(self.as_raw_FacemarkAAM_Params()) };
@@ -1833,6 +1985,14 @@ pub trait FacemarkAAM_ParamsTraitConst {
 		ret
 	}
 	
+	/// Same as `scales()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn scales_ref(&self) -> BoxedRef<'_, core::Vector<f32>> {
+		let ret = unsafe { sys::cv_face_FacemarkAAM_Params_getPropScalesRef_const(self.as_raw_FacemarkAAM_Params()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// \brief Read parameters from file, currently unused
 	#[inline]
 	fn write(&self, unnamed: &mut core::FileStorage) -> Result<()> {
@@ -2020,9 +2180,9 @@ impl dyn FacemarkKazemi + '_ {
 	/// ## C++ default parameters
 	/// * parameters: FacemarkKazemi::Params()
 	#[inline]
-	pub fn create(parameters: &crate::face::FacemarkKazemi_Params) -> Result<core::Ptr<dyn crate::face::FacemarkKazemi>> {
+	pub fn create(parameters: Option<&crate::face::FacemarkKazemi_Params>) -> Result<core::Ptr<dyn crate::face::FacemarkKazemi>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FacemarkKazemi_create_const_ParamsR(parameters.as_raw_FacemarkKazemi_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_face_FacemarkKazemi_create_const_ParamsR(parameters.map_or(::core::ptr::null(), |parameters| parameters.as_raw_FacemarkKazemi_Params()), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::face::FacemarkKazemi>::opencv_from_extern(ret) };
@@ -2097,6 +2257,14 @@ pub trait FacemarkKazemi_ParamsTraitConst {
 		ret
 	}
 	
+	/// Same as `configfile()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn configfile_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_face_FacemarkKazemi_Params_getPropConfigfileRef_const(self.as_raw_FacemarkKazemi_Params()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 }
 
 pub trait FacemarkKazemi_ParamsTrait: crate::face::FacemarkKazemi_ParamsTraitConst {
@@ -2219,9 +2387,9 @@ impl dyn FacemarkLBF + '_ {
 	/// ## C++ default parameters
 	/// * parameters: FacemarkLBF::Params()
 	#[inline]
-	pub fn create(parameters: &crate::face::FacemarkLBF_Params) -> Result<core::Ptr<dyn crate::face::FacemarkLBF>> {
+	pub fn create(parameters: Option<&crate::face::FacemarkLBF_Params>) -> Result<core::Ptr<dyn crate::face::FacemarkLBF>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_face_FacemarkLBF_create_const_ParamsR(parameters.as_raw_FacemarkLBF_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_face_FacemarkLBF_create_const_ParamsR(parameters.map_or(::core::ptr::null(), |parameters| parameters.as_raw_FacemarkLBF_Params()), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::face::FacemarkLBF>::opencv_from_extern(ret) };
@@ -2245,6 +2413,14 @@ pub trait FacemarkLBF_ParamsTraitConst {
 		ret
 	}
 	
+	/// Same as `cascade_face()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn cascade_face_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_getPropCascade_faceRef_const(self.as_raw_FacemarkLBF_Params()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	#[inline]
 	fn verbose(&self) -> bool {
 		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_getPropVerbose_const(self.as_raw_FacemarkLBF_Params()) };
@@ -2294,6 +2470,14 @@ pub trait FacemarkLBF_ParamsTraitConst {
 		ret
 	}
 	
+	/// Same as `model_filename()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn model_filename_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_getPropModel_filenameRef_const(self.as_raw_FacemarkLBF_Params()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	/// flag to save the trained model or not
 	#[inline]
 	fn save_model(&self) -> bool {
@@ -2315,6 +2499,14 @@ pub trait FacemarkLBF_ParamsTraitConst {
 		ret
 	}
 	
+	/// Same as `feats_m()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn feats_m_ref(&self) -> BoxedRef<'_, core::Vector<i32>> {
+		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_getPropFeats_mRef_const(self.as_raw_FacemarkLBF_Params()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn radius_m(&self) -> core::Vector<f64> {
 		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_getPropRadius_m_const(self.as_raw_FacemarkLBF_Params()) };
@@ -2322,6 +2514,14 @@ pub trait FacemarkLBF_ParamsTraitConst {
 		ret
 	}
 	
+	/// Same as `radius_m()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn radius_m_ref(&self) -> BoxedRef<'_, core::Vector<f64>> {
+		let ret = unsafe { sys::cv_face_FacemarkLBF_Params_getPropRadius_mRef_const(self.as_raw_FacemarkLBF_Params()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn detect_roi(&self) -> core::Rect {
 		return_send!(via ocvrs_return);
@@ -3100,7 +3300,7 @@ pub trait PredictCollector: crate::face::PredictCollectorConst {
 	/// ## Parameters
 	/// * size: total size of prediction evaluation that recognizer could perform
 	#[inline]
-	fn init(&mut self, size: size_t) -> Result<()> {
+	fn init(&mut self, size: usize) -> Result<()> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_face_PredictCollector_init_size_t(self.as_raw_mut_PredictCollector(), size, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -3173,7 +3373,7 @@ pub trait StandardCollectorTrait: crate::face::PredictCollector + crate::face::S
 
 	/// overloaded interface method
 	#[inline]
-	fn init(&mut self, size: size_t) -> Result<()> {
+	fn init(&mut self, size: usize) -> Result<()> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_face_StandardCollector_init_size_t(self.as_raw_mut_StandardCollector(), size, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
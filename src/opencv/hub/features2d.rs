@@ -172,7 +172,7 @@ pub type SiftFeatureDetector = crate::features2d::SIFT;
 /// ## C++ default parameters
 /// * nonmax_suppression: true
 #[inline]
-pub fn agast(image: &dyn core::ToInputArray, keypoints: &mut core::Vector<core::KeyPoint>, threshold: i32, nonmax_suppression: bool) -> Result<()> {
+pub fn agast(image: &(impl core::ToInputArray + ?Sized), keypoints: &mut core::Vector<core::KeyPoint>, threshold: i32, nonmax_suppression: bool) -> Result<()> {
 	input_array_arg!(image);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_AGAST_const__InputArrayR_vectorLKeyPointGR_int_bool(image.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfKeyPoint(), threshold, nonmax_suppression, ocvrs_return.as_mut_ptr()) };
@@ -199,7 +199,7 @@ pub fn agast(image: &dyn core::ToInputArray, keypoints: &mut core::Vector<core::
 /// The perl script and examples of tree generation are placed in features2d/doc folder.
 /// Detects corners using the AGAST algorithm by [mair2010_agast](https://docs.opencv.org/4.6.0/d0/de3/citelist.html#CITEREF_mair2010_agast) .
 #[inline]
-pub fn agast_with_type(image: &dyn core::ToInputArray, keypoints: &mut core::Vector<core::KeyPoint>, threshold: i32, nonmax_suppression: bool, typ: crate::features2d::AgastFeatureDetector_DetectorType) -> Result<()> {
+pub fn agast_with_type(image: &(impl core::ToInputArray + ?Sized), keypoints: &mut core::Vector<core::KeyPoint>, threshold: i32, nonmax_suppression: bool, typ: crate::features2d::AgastFeatureDetector_DetectorType) -> Result<()> {
 	input_array_arg!(image);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_AGAST_const__InputArrayR_vectorLKeyPointGR_int_bool_DetectorType(image.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfKeyPoint(), threshold, nonmax_suppression, typ, ocvrs_return.as_mut_ptr()) };
@@ -233,7 +233,7 @@ pub fn agast_with_type(image: &dyn core::ToInputArray, keypoints: &mut core::Vec
 /// ## C++ default parameters
 /// * nonmax_suppression: true
 #[inline]
-pub fn fast(image: &dyn core::ToInputArray, keypoints: &mut core::Vector<core::KeyPoint>, threshold: i32, nonmax_suppression: bool) -> Result<()> {
+pub fn fast(image: &(impl core::ToInputArray + ?Sized), keypoints: &mut core::Vector<core::KeyPoint>, threshold: i32, nonmax_suppression: bool) -> Result<()> {
 	input_array_arg!(image);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_FAST_const__InputArrayR_vectorLKeyPointGR_int_bool(image.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfKeyPoint(), threshold, nonmax_suppression, ocvrs_return.as_mut_ptr()) };
@@ -262,7 +262,7 @@ pub fn fast(image: &dyn core::ToInputArray, keypoints: &mut core::Vector<core::K
 /// cv.FAST_FEATURE_DETECTOR_TYPE_7_12 and cv.FAST_FEATURE_DETECTOR_TYPE_9_16. For corner
 /// detection, use cv.FAST.detect() method.
 #[inline]
-pub fn fast_with_type(image: &dyn core::ToInputArray, keypoints: &mut core::Vector<core::KeyPoint>, threshold: i32, nonmax_suppression: bool, typ: crate::features2d::FastFeatureDetector_DetectorType) -> Result<()> {
+pub fn fast_with_type(image: &(impl core::ToInputArray + ?Sized), keypoints: &mut core::Vector<core::KeyPoint>, threshold: i32, nonmax_suppression: bool, typ: crate::features2d::FastFeatureDetector_DetectorType) -> Result<()> {
 	input_array_arg!(image);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_FAST_const__InputArrayR_vectorLKeyPointGR_int_bool_DetectorType(image.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfKeyPoint(), threshold, nonmax_suppression, typ, ocvrs_return.as_mut_ptr()) };
@@ -301,7 +301,7 @@ pub fn compute_recall_precision_curve(matches1to2: &core::Vector<core::Vector<co
 /// * color: Scalar::all(-1)
 /// * flags: DrawMatchesFlags::DEFAULT
 #[inline]
-pub fn draw_keypoints(image: &dyn core::ToInputArray, keypoints: &core::Vector<core::KeyPoint>, out_image: &mut dyn core::ToInputOutputArray, color: core::Scalar, flags: crate::features2d::DrawMatchesFlags) -> Result<()> {
+pub fn draw_keypoints(image: &(impl core::ToInputArray + ?Sized), keypoints: &core::Vector<core::KeyPoint>, out_image: &mut (impl core::ToInputOutputArray + ?Sized), color: core::Scalar, flags: crate::features2d::DrawMatchesFlags) -> Result<()> {
 	input_array_arg!(image);
 	input_output_array_arg!(out_image);
 	return_send!(via ocvrs_return);
@@ -340,7 +340,7 @@ pub fn draw_keypoints(image: &dyn core::ToInputArray, keypoints: &core::Vector<c
 /// * matches_mask: std::vector<char>()
 /// * flags: DrawMatchesFlags::DEFAULT
 #[inline]
-pub fn draw_matches(img1: &dyn core::ToInputArray, keypoints1: &core::Vector<core::KeyPoint>, img2: &dyn core::ToInputArray, keypoints2: &core::Vector<core::KeyPoint>, matches1to2: &core::Vector<core::DMatch>, out_img: &mut dyn core::ToInputOutputArray, match_color: core::Scalar, single_point_color: core::Scalar, matches_mask: &core::Vector<i8>, flags: crate::features2d::DrawMatchesFlags) -> Result<()> {
+pub fn draw_matches(img1: &(impl core::ToInputArray + ?Sized), keypoints1: &core::Vector<core::KeyPoint>, img2: &(impl core::ToInputArray + ?Sized), keypoints2: &core::Vector<core::KeyPoint>, matches1to2: &core::Vector<core::DMatch>, out_img: &mut (impl core::ToInputOutputArray + ?Sized), match_color: core::Scalar, single_point_color: core::Scalar, matches_mask: &core::Vector<i8>, flags: crate::features2d::DrawMatchesFlags) -> Result<()> {
 	input_array_arg!(img1);
 	input_array_arg!(img2);
 	input_output_array_arg!(out_img);
@@ -382,7 +382,7 @@ pub fn draw_matches(img1: &dyn core::ToInputArray, keypoints1: &core::Vector<cor
 /// * matches_mask: std::vector<char>()
 /// * flags: DrawMatchesFlags::DEFAULT
 #[inline]
-pub fn draw_matches_1(img1: &dyn core::ToInputArray, keypoints1: &core::Vector<core::KeyPoint>, img2: &dyn core::ToInputArray, keypoints2: &core::Vector<core::KeyPoint>, matches1to2: &core::Vector<core::DMatch>, out_img: &mut dyn core::ToInputOutputArray, matches_thickness: i32, match_color: core::Scalar, single_point_color: core::Scalar, matches_mask: &core::Vector<i8>, flags: crate::features2d::DrawMatchesFlags) -> Result<()> {
+pub fn draw_matches_1(img1: &(impl core::ToInputArray + ?Sized), keypoints1: &core::Vector<core::KeyPoint>, img2: &(impl core::ToInputArray + ?Sized), keypoints2: &core::Vector<core::KeyPoint>, matches1to2: &core::Vector<core::DMatch>, out_img: &mut (impl core::ToInputOutputArray + ?Sized), matches_thickness: i32, match_color: core::Scalar, single_point_color: core::Scalar, matches_mask: &core::Vector<i8>, flags: crate::features2d::DrawMatchesFlags) -> Result<()> {
 	input_array_arg!(img1);
 	input_array_arg!(img2);
 	input_output_array_arg!(out_img);
@@ -399,7 +399,7 @@ pub fn draw_matches_1(img1: &dyn core::ToInputArray, keypoints1: &core::Vector<c
 /// * matches_mask: std::vector<std::vector<char>>()
 /// * flags: DrawMatchesFlags::DEFAULT
 #[inline]
-pub fn draw_matches_knn(img1: &dyn core::ToInputArray, keypoints1: &core::Vector<core::KeyPoint>, img2: &dyn core::ToInputArray, keypoints2: &core::Vector<core::KeyPoint>, matches1to2: &core::Vector<core::Vector<core::DMatch>>, out_img: &mut dyn core::ToInputOutputArray, match_color: core::Scalar, single_point_color: core::Scalar, matches_mask: &core::Vector<core::Vector<i8>>, flags: crate::features2d::DrawMatchesFlags) -> Result<()> {
+pub fn draw_matches_knn(img1: &(impl core::ToInputArray + ?Sized), keypoints1: &core::Vector<core::KeyPoint>, img2: &(impl core::ToInputArray + ?Sized), keypoints2: &core::Vector<core::KeyPoint>, matches1to2: &core::Vector<core::Vector<core::DMatch>>, out_img: &mut (impl core::ToInputOutputArray + ?Sized), match_color: core::Scalar, single_point_color: core::Scalar, matches_mask: &core::Vector<core::Vector<i8>>, flags: crate::features2d::DrawMatchesFlags) -> Result<()> {
 	input_array_arg!(img1);
 	input_array_arg!(img2);
 	input_output_array_arg!(out_img);
@@ -1552,10 +1552,10 @@ pub trait DescriptorMatcherConst: core::AlgorithmTraitConst {
 	/// ## C++ default parameters
 	/// * mask: noArray()
 	#[inline]
-	fn train_match(&self, query_descriptors: &dyn core::ToInputArray, train_descriptors: &dyn core::ToInputArray, matches: &mut core::Vector<core::DMatch>, mask: &dyn core::ToInputArray) -> Result<()> {
+	fn train_match(&self, query_descriptors: &dyn core::ToInputArray, train_descriptors: &dyn core::ToInputArray, matches: &mut core::Vector<core::DMatch>, mask: Option<&dyn core::ToInputArray>) -> Result<()> {
 		input_array_arg!(query_descriptors);
 		input_array_arg!(train_descriptors);
-		input_array_arg!(mask);
+		input_array_arg!(nullable mask);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_DescriptorMatcher_match_const_const__InputArrayR_const__InputArrayR_vectorLDMatchGR_const__InputArrayR(self.as_raw_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), train_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfDMatch(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -1586,10 +1586,10 @@ pub trait DescriptorMatcherConst: core::AlgorithmTraitConst {
 	/// * mask: noArray()
 	/// * compact_result: false
 	#[inline]
-	fn knn_train_match(&self, query_descriptors: &dyn core::ToInputArray, train_descriptors: &dyn core::ToInputArray, matches: &mut core::Vector<core::Vector<core::DMatch>>, k: i32, mask: &dyn core::ToInputArray, compact_result: bool) -> Result<()> {
+	fn knn_train_match(&self, query_descriptors: &dyn core::ToInputArray, train_descriptors: &dyn core::ToInputArray, matches: &mut core::Vector<core::Vector<core::DMatch>>, k: i32, mask: Option<&dyn core::ToInputArray>, compact_result: bool) -> Result<()> {
 		input_array_arg!(query_descriptors);
 		input_array_arg!(train_descriptors);
-		input_array_arg!(mask);
+		input_array_arg!(nullable mask);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_DescriptorMatcher_knnMatch_const_const__InputArrayR_const__InputArrayR_vectorLvectorLDMatchGGR_int_const__InputArrayR_bool(self.as_raw_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), train_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfVectorOfDMatch(), k, mask.as_raw__InputArray(), compact_result, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -1621,10 +1621,10 @@ pub trait DescriptorMatcherConst: core::AlgorithmTraitConst {
 	/// * mask: noArray()
 	/// * compact_result: false
 	#[inline]
-	fn radius_train_match(&self, query_descriptors: &dyn core::ToInputArray, train_descriptors: &dyn core::ToInputArray, matches: &mut core::Vector<core::Vector<core::DMatch>>, max_distance: f32, mask: &dyn core::ToInputArray, compact_result: bool) -> Result<()> {
+	fn radius_train_match(&self, query_descriptors: &dyn core::ToInputArray, train_descriptors: &dyn core::ToInputArray, matches: &mut core::Vector<core::Vector<core::DMatch>>, max_distance: f32, mask: Option<&dyn core::ToInputArray>, compact_result: bool) -> Result<()> {
 		input_array_arg!(query_descriptors);
 		input_array_arg!(train_descriptors);
-		input_array_arg!(mask);
+		input_array_arg!(nullable mask);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_DescriptorMatcher_radiusMatch_const_const__InputArrayR_const__InputArrayR_vectorLvectorLDMatchGGR_float_const__InputArrayR_bool(self.as_raw_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), train_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfVectorOfDMatch(), max_distance, mask.as_raw__InputArray(), compact_result, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -1759,9 +1759,9 @@ pub trait DescriptorMatcher: core::AlgorithmTrait + crate::features2d::Descripto
 	/// ## C++ default parameters
 	/// * masks: noArray()
 	#[inline]
-	fn match_(&mut self, query_descriptors: &dyn core::ToInputArray, matches: &mut core::Vector<core::DMatch>, masks: &dyn core::ToInputArray) -> Result<()> {
+	fn match_(&mut self, query_descriptors: &dyn core::ToInputArray, matches: &mut core::Vector<core::DMatch>, masks: Option<&dyn core::ToInputArray>) -> Result<()> {
 		input_array_arg!(query_descriptors);
-		input_array_arg!(masks);
+		input_array_arg!(nullable masks);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_DescriptorMatcher_match_const__InputArrayR_vectorLDMatchGR_const__InputArrayR(self.as_raw_mut_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfDMatch(), masks.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -1804,9 +1804,9 @@ pub trait DescriptorMatcher: core::AlgorithmTrait + crate::features2d::Descripto
 	/// * masks: noArray()
 	/// * compact_result: false
 	#[inline]
-	fn knn_match(&mut self, query_descriptors: &dyn core::ToInputArray, matches: &mut core::Vector<core::Vector<core::DMatch>>, k: i32, masks: &dyn core::ToInputArray, compact_result: bool) -> Result<()> {
+	fn knn_match(&mut self, query_descriptors: &dyn core::ToInputArray, matches: &mut core::Vector<core::Vector<core::DMatch>>, k: i32, masks: Option<&dyn core::ToInputArray>, compact_result: bool) -> Result<()> {
 		input_array_arg!(query_descriptors);
-		input_array_arg!(masks);
+		input_array_arg!(nullable masks);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_DescriptorMatcher_knnMatch_const__InputArrayR_vectorLvectorLDMatchGGR_int_const__InputArrayR_bool(self.as_raw_mut_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfVectorOfDMatch(), k, masks.as_raw__InputArray(), compact_result, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -1851,9 +1851,9 @@ pub trait DescriptorMatcher: core::AlgorithmTrait + crate::features2d::Descripto
 	/// * masks: noArray()
 	/// * compact_result: false
 	#[inline]
-	fn radius_match(&mut self, query_descriptors: &dyn core::ToInputArray, matches: &mut core::Vector<core::Vector<core::DMatch>>, max_distance: f32, masks: &dyn core::ToInputArray, compact_result: bool) -> Result<()> {
+	fn radius_match(&mut self, query_descriptors: &dyn core::ToInputArray, matches: &mut core::Vector<core::Vector<core::DMatch>>, max_distance: f32, masks: Option<&dyn core::ToInputArray>, compact_result: bool) -> Result<()> {
 		input_array_arg!(query_descriptors);
-		input_array_arg!(masks);
+		input_array_arg!(nullable masks);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_DescriptorMatcher_radiusMatch_const__InputArrayR_vectorLvectorLDMatchGGR_float_const__InputArrayR_bool(self.as_raw_mut_DescriptorMatcher(), query_descriptors.as_raw__InputArray(), matches.as_raw_mut_VectorOfVectorOfDMatch(), max_distance, masks.as_raw__InputArray(), compact_result, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -2105,9 +2105,9 @@ pub trait Feature2DTrait: core::AlgorithmTrait + crate::features2d::Feature2DTra
 	/// ## C++ default parameters
 	/// * mask: noArray()
 	#[inline]
-	fn detect(&mut self, image: &dyn core::ToInputArray, keypoints: &mut core::Vector<core::KeyPoint>, mask: &dyn core::ToInputArray) -> Result<()> {
+	fn detect(&mut self, image: &dyn core::ToInputArray, keypoints: &mut core::Vector<core::KeyPoint>, mask: Option<&dyn core::ToInputArray>) -> Result<()> {
 		input_array_arg!(image);
-		input_array_arg!(mask);
+		input_array_arg!(nullable mask);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_Feature2D_detect_const__InputArrayR_vectorLKeyPointGR_const__InputArrayR(self.as_raw_mut_Feature2D(), image.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfKeyPoint(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -2135,9 +2135,9 @@ pub trait Feature2DTrait: core::AlgorithmTrait + crate::features2d::Feature2DTra
 	/// ## C++ default parameters
 	/// * masks: noArray()
 	#[inline]
-	fn detect_multiple(&mut self, images: &dyn core::ToInputArray, keypoints: &mut core::Vector<core::Vector<core::KeyPoint>>, masks: &dyn core::ToInputArray) -> Result<()> {
+	fn detect_multiple(&mut self, images: &dyn core::ToInputArray, keypoints: &mut core::Vector<core::Vector<core::KeyPoint>>, masks: Option<&dyn core::ToInputArray>) -> Result<()> {
 		input_array_arg!(images);
-		input_array_arg!(masks);
+		input_array_arg!(nullable masks);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_Feature2D_detect_const__InputArrayR_vectorLvectorLKeyPointGGR_const__InputArrayR(self.as_raw_mut_Feature2D(), images.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfVectorOfKeyPoint(), masks.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -3557,7 +3557,7 @@ pub struct SimpleBlobDetector_Params {
 	pub threshold_step: f32,
 	pub min_threshold: f32,
 	pub max_threshold: f32,
-	pub min_repeatability: size_t,
+	pub min_repeatability: usize,
 	pub min_dist_between_blobs: f32,
 	pub filter_by_color: bool,
 	pub blob_color: u8,
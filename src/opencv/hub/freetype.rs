@@ -172,3 +172,4 @@ pub trait FreeType2: core::AlgorithmTrait + crate::freetype::FreeType2Const {
 	}
 	
 }
+pub use crate::manual::freetype::*;
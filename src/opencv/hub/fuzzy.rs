@@ -45,7 +45,7 @@ pub const SINUS: i32 = 2;
 /// 
 /// This function computes F-transfrom and inverse F-transfotm using linear basic function in one step. It is ~10 times faster than `ft::FT02D_process` method.
 #[inline]
-pub fn ft02_d_fl_process(matrix: &dyn core::ToInputArray, radius: i32, output: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn ft02_d_fl_process(matrix: &(impl core::ToInputArray + ?Sized), radius: i32, output: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(matrix);
 	output_array_arg!(output);
 	return_send!(via ocvrs_return);
@@ -63,7 +63,7 @@ pub fn ft02_d_fl_process(matrix: &dyn core::ToInputArray, radius: i32, output: &
 /// 
 /// This function computes F-transfrom and inverse F-transfotm using linear basic function in one step. It is ~9 times faster then `ft::FT02D_process` method and more accurate than `ft::FT02D_FL_process` method.
 #[inline]
-pub fn ft02_d_fl_process_float(matrix: &dyn core::ToInputArray, radius: i32, output: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn ft02_d_fl_process_float(matrix: &(impl core::ToInputArray + ?Sized), radius: i32, output: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(matrix);
 	output_array_arg!(output);
 	return_send!(via ocvrs_return);
@@ -85,11 +85,11 @@ pub fn ft02_d_fl_process_float(matrix: &dyn core::ToInputArray, radius: i32, out
 /// ## C++ default parameters
 /// * mask: noArray()
 #[inline]
-pub fn ft02_d_components(matrix: &dyn core::ToInputArray, kernel: &dyn core::ToInputArray, components: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray) -> Result<()> {
+pub fn ft02_d_components(matrix: &(impl core::ToInputArray + ?Sized), kernel: &(impl core::ToInputArray + ?Sized), components: &mut (impl core::ToOutputArray + ?Sized), mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(matrix);
 	input_array_arg!(kernel);
 	output_array_arg!(components);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_ft_FT02D_components_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR(matrix.as_raw__InputArray(), kernel.as_raw__InputArray(), components.as_raw__OutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -107,7 +107,7 @@ pub fn ft02_d_components(matrix: &dyn core::ToInputArray, kernel: &dyn core::ToI
 /// 
 /// Computation of inverse F-transform.
 #[inline]
-pub fn ft02_d_inverse_ft(components: &dyn core::ToInputArray, kernel: &dyn core::ToInputArray, output: &mut dyn core::ToOutputArray, width: i32, height: i32) -> Result<()> {
+pub fn ft02_d_inverse_ft(components: &(impl core::ToInputArray + ?Sized), kernel: &(impl core::ToInputArray + ?Sized), output: &mut (impl core::ToOutputArray + ?Sized), width: i32, height: i32) -> Result<()> {
 	input_array_arg!(components);
 	input_array_arg!(kernel);
 	output_array_arg!(output);
@@ -129,7 +129,7 @@ pub fn ft02_d_inverse_ft(components: &dyn core::ToInputArray, kernel: &dyn core:
 /// 
 /// This function computes iteration of F-transfrom and inverse F-transfotm and handle image and mask change. The function is used in `ft::inpaint` function.
 #[inline]
-pub fn ft02_d_iteration(matrix: &dyn core::ToInputArray, kernel: &dyn core::ToInputArray, output: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray, mask_output: &mut dyn core::ToOutputArray, first_stop: bool) -> Result<i32> {
+pub fn ft02_d_iteration(matrix: &(impl core::ToInputArray + ?Sized), kernel: &(impl core::ToInputArray + ?Sized), output: &mut (impl core::ToOutputArray + ?Sized), mask: &(impl core::ToInputArray + ?Sized), mask_output: &mut (impl core::ToOutputArray + ?Sized), first_stop: bool) -> Result<i32> {
 	input_array_arg!(matrix);
 	input_array_arg!(kernel);
 	output_array_arg!(output);
@@ -154,11 +154,11 @@ pub fn ft02_d_iteration(matrix: &dyn core::ToInputArray, kernel: &dyn core::ToIn
 /// ## C++ default parameters
 /// * mask: noArray()
 #[inline]
-pub fn ft02_d_process(matrix: &dyn core::ToInputArray, kernel: &dyn core::ToInputArray, output: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray) -> Result<()> {
+pub fn ft02_d_process(matrix: &(impl core::ToInputArray + ?Sized), kernel: &(impl core::ToInputArray + ?Sized), output: &mut (impl core::ToOutputArray + ?Sized), mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(matrix);
 	input_array_arg!(kernel);
 	output_array_arg!(output);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_ft_FT02D_process_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR(matrix.as_raw__InputArray(), kernel.as_raw__InputArray(), output.as_raw__OutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -174,7 +174,7 @@ pub fn ft02_d_process(matrix: &dyn core::ToInputArray, kernel: &dyn core::ToInpu
 /// 
 /// The function computes linear components using predefined kernel.
 #[inline]
-pub fn ft12_d_components(matrix: &dyn core::ToInputArray, kernel: &dyn core::ToInputArray, components: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn ft12_d_components(matrix: &(impl core::ToInputArray + ?Sized), kernel: &(impl core::ToInputArray + ?Sized), components: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(matrix);
 	input_array_arg!(kernel);
 	output_array_arg!(components);
@@ -193,7 +193,7 @@ pub fn ft12_d_components(matrix: &dyn core::ToInputArray, kernel: &dyn core::ToI
 /// 
 /// The function creates helper horizontal matrix for ![inline formula](https://latex.codecogs.com/png.latex?F%5E1)-transfrom processing. It is used for gradient computation.
 #[inline]
-pub fn ft12_d_create_polynom_matrix_horizontal(radius: i32, matrix: &mut dyn core::ToOutputArray, chn: i32) -> Result<()> {
+pub fn ft12_d_create_polynom_matrix_horizontal(radius: i32, matrix: &mut (impl core::ToOutputArray + ?Sized), chn: i32) -> Result<()> {
 	output_array_arg!(matrix);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_ft_FT12D_createPolynomMatrixHorizontal_int_const__OutputArrayR_const_int(radius, matrix.as_raw__OutputArray(), chn, ocvrs_return.as_mut_ptr()) };
@@ -210,7 +210,7 @@ pub fn ft12_d_create_polynom_matrix_horizontal(radius: i32, matrix: &mut dyn cor
 /// 
 /// The function creates helper vertical matrix for ![inline formula](https://latex.codecogs.com/png.latex?F%5E1)-transfrom processing. It is used for gradient computation.
 #[inline]
-pub fn ft12_d_create_polynom_matrix_vertical(radius: i32, matrix: &mut dyn core::ToOutputArray, chn: i32) -> Result<()> {
+pub fn ft12_d_create_polynom_matrix_vertical(radius: i32, matrix: &mut (impl core::ToOutputArray + ?Sized), chn: i32) -> Result<()> {
 	output_array_arg!(matrix);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_ft_FT12D_createPolynomMatrixVertical_int_const__OutputArrayR_const_int(radius, matrix.as_raw__OutputArray(), chn, ocvrs_return.as_mut_ptr()) };
@@ -229,7 +229,7 @@ pub fn ft12_d_create_polynom_matrix_vertical(radius: i32, matrix: &mut dyn core:
 /// 
 /// Computation of inverse ![inline formula](https://latex.codecogs.com/png.latex?F%5E1)-transform.
 #[inline]
-pub fn ft12_d_inverse_ft(components: &dyn core::ToInputArray, kernel: &dyn core::ToInputArray, output: &mut dyn core::ToOutputArray, width: i32, height: i32) -> Result<()> {
+pub fn ft12_d_inverse_ft(components: &(impl core::ToInputArray + ?Sized), kernel: &(impl core::ToInputArray + ?Sized), output: &mut (impl core::ToOutputArray + ?Sized), width: i32, height: i32) -> Result<()> {
 	input_array_arg!(components);
 	input_array_arg!(kernel);
 	output_array_arg!(output);
@@ -255,14 +255,14 @@ pub fn ft12_d_inverse_ft(components: &dyn core::ToInputArray, kernel: &dyn core:
 /// ## C++ default parameters
 /// * mask: noArray()
 #[inline]
-pub fn ft12_d_polynomial(matrix: &dyn core::ToInputArray, kernel: &dyn core::ToInputArray, c00: &mut dyn core::ToOutputArray, c10: &mut dyn core::ToOutputArray, c01: &mut dyn core::ToOutputArray, components: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray) -> Result<()> {
+pub fn ft12_d_polynomial(matrix: &(impl core::ToInputArray + ?Sized), kernel: &(impl core::ToInputArray + ?Sized), c00: &mut (impl core::ToOutputArray + ?Sized), c10: &mut (impl core::ToOutputArray + ?Sized), c01: &mut (impl core::ToOutputArray + ?Sized), components: &mut (impl core::ToOutputArray + ?Sized), mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(matrix);
 	input_array_arg!(kernel);
 	output_array_arg!(c00);
 	output_array_arg!(c10);
 	output_array_arg!(c01);
 	output_array_arg!(components);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_ft_FT12D_polynomial_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__InputArrayR(matrix.as_raw__InputArray(), kernel.as_raw__InputArray(), c00.as_raw__OutputArray(), c10.as_raw__OutputArray(), c01.as_raw__OutputArray(), components.as_raw__OutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -286,11 +286,11 @@ pub fn ft12_d_polynomial(matrix: &dyn core::ToInputArray, kernel: &dyn core::ToI
 /// ## C++ default parameters
 /// * mask: noArray()
 #[inline]
-pub fn ft12_d_process(matrix: &dyn core::ToInputArray, kernel: &dyn core::ToInputArray, output: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray) -> Result<()> {
+pub fn ft12_d_process(matrix: &(impl core::ToInputArray + ?Sized), kernel: &(impl core::ToInputArray + ?Sized), output: &mut (impl core::ToOutputArray + ?Sized), mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(matrix);
 	input_array_arg!(kernel);
 	output_array_arg!(output);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_ft_FT12D_process_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR(matrix.as_raw__InputArray(), kernel.as_raw__InputArray(), output.as_raw__OutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -307,7 +307,7 @@ pub fn ft12_d_process(matrix: &dyn core::ToInputArray, kernel: &dyn core::ToInpu
 /// 
 /// The function creates kernel usable for latter fuzzy image processing.
 #[inline]
-pub fn create_kernel1(a: &dyn core::ToInputArray, b: &dyn core::ToInputArray, kernel: &mut dyn core::ToOutputArray, chn: i32) -> Result<()> {
+pub fn create_kernel1(a: &(impl core::ToInputArray + ?Sized), b: &(impl core::ToInputArray + ?Sized), kernel: &mut (impl core::ToOutputArray + ?Sized), chn: i32) -> Result<()> {
 	input_array_arg!(a);
 	input_array_arg!(b);
 	output_array_arg!(kernel);
@@ -328,7 +328,7 @@ pub fn create_kernel1(a: &dyn core::ToInputArray, b: &dyn core::ToInputArray, ke
 /// 
 /// The function creates kernel from predefined functions.
 #[inline]
-pub fn create_kernel(function: i32, radius: i32, kernel: &mut dyn core::ToOutputArray, chn: i32) -> Result<()> {
+pub fn create_kernel(function: i32, radius: i32, kernel: &mut (impl core::ToOutputArray + ?Sized), chn: i32) -> Result<()> {
 	output_array_arg!(kernel);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_ft_createKernel_int_int_const__OutputArrayR_const_int(function, radius, kernel.as_raw__OutputArray(), chn, ocvrs_return.as_mut_ptr()) };
@@ -345,7 +345,7 @@ pub fn create_kernel(function: i32, radius: i32, kernel: &mut dyn core::ToOutput
 /// 
 /// Filtering of the input image by means of F-transform.
 #[inline]
-pub fn filter(image: &dyn core::ToInputArray, kernel: &dyn core::ToInputArray, output: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn filter(image: &(impl core::ToInputArray + ?Sized), kernel: &(impl core::ToInputArray + ?Sized), output: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(image);
 	input_array_arg!(kernel);
 	output_array_arg!(output);
@@ -375,7 +375,7 @@ pub fn filter(image: &dyn core::ToInputArray, kernel: &dyn core::ToInputArray, o
 /// Note:
 ///    The algorithms are described in paper [Perf:rec](https://docs.opencv.org/4.6.0/d0/de3/citelist.html#CITEREF_Perf:rec).
 #[inline]
-pub fn inpaint(image: &dyn core::ToInputArray, mask: &dyn core::ToInputArray, output: &mut dyn core::ToOutputArray, radius: i32, function: i32, algorithm: i32) -> Result<()> {
+pub fn inpaint(image: &(impl core::ToInputArray + ?Sized), mask: &(impl core::ToInputArray + ?Sized), output: &mut (impl core::ToOutputArray + ?Sized), radius: i32, function: i32, algorithm: i32) -> Result<()> {
 	input_array_arg!(image);
 	input_array_arg!(mask);
 	output_array_arg!(output);
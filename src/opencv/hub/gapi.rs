@@ -140,7 +140,7 @@ pub type GKinds = core::Vector<crate::gapi::OpaqueKind>;
 pub type GRunArgs = core::Vector<crate::gapi::GRunArg>;
 pub type GShapes = core::Vector<crate::gapi::GShape>;
 pub type RMat_Adapter = dyn crate::gapi::RMat_IAdapter;
-pub type RMat_View_stepsT = core::Vector<size_t>;
+pub type RMat_View_stepsT = core::Vector<usize>;
 pub type GMat2 = core::Tuple<(crate::gapi::GMat, crate::gapi::GMat)>;
 pub type GMat3 = core::Tuple<(crate::gapi::GMat, crate::gapi::GMat, crate::gapi::GMat)>;
 pub type GMat4 = core::Tuple<(crate::gapi::GMat, crate::gapi::GMat, crate::gapi::GMat, crate::gapi::GMat)>;
@@ -4705,6 +4705,14 @@ pub trait GCompileArgTraitConst {
 		ret
 	}
 	
+	/// Same as `tag()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn tag_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_GCompileArg_getPropTagRef_const(self.as_raw_GCompileArg()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 }
 
 pub trait GCompileArgTrait: crate::gapi::GCompileArgTraitConst {
@@ -5665,6 +5673,14 @@ pub trait GKernelTraitConst {
 		ret
 	}
 	
+	/// Same as `name()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn name_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_GKernel_getPropNameRef_const(self.as_raw_GKernel()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	#[inline]
 	fn tag(&self) -> String {
 		let ret = unsafe { sys::cv_GKernel_getPropTag_const(self.as_raw_GKernel()) };
@@ -5672,6 +5688,14 @@ pub trait GKernelTraitConst {
 		ret
 	}
 	
+	/// Same as `tag()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn tag_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_GKernel_getPropTagRef_const(self.as_raw_GKernel()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	#[inline]
 	fn out_shapes(&self) -> core::Vector<crate::gapi::GShape> {
 		let ret = unsafe { sys::cv_GKernel_getPropOutShapes_const(self.as_raw_GKernel()) };
@@ -5679,6 +5703,14 @@ pub trait GKernelTraitConst {
 		ret
 	}
 	
+	/// Same as `out_shapes()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn out_shapes_ref(&self) -> BoxedRef<'_, core::Vector<crate::gapi::GShape>> {
+		let ret = unsafe { sys::cv_GKernel_getPropOutShapesRef_const(self.as_raw_GKernel()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn in_kinds(&self) -> core::Vector<crate::gapi::OpaqueKind> {
 		let ret = unsafe { sys::cv_GKernel_getPropInKinds_const(self.as_raw_GKernel()) };
@@ -5686,6 +5718,14 @@ pub trait GKernelTraitConst {
 		ret
 	}
 	
+	/// Same as `in_kinds()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn in_kinds_ref(&self) -> BoxedRef<'_, core::Vector<crate::gapi::OpaqueKind>> {
+		let ret = unsafe { sys::cv_GKernel_getPropInKindsRef_const(self.as_raw_GKernel()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 }
 
 pub trait GKernelTrait: crate::gapi::GKernelTraitConst {
@@ -6146,6 +6186,14 @@ pub trait GMatDescTraitConst {
 		ret
 	}
 	
+	/// Same as `dims()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn dims_ref(&self) -> BoxedRef<'_, core::Vector<i32>> {
+		let ret = unsafe { sys::cv_GMatDesc_getPropDimsRef_const(self.as_raw_GMatDesc()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn equals(&self, rhs: &crate::gapi::GMatDesc) -> Result<bool> {
 		return_send!(via ocvrs_return);
@@ -6966,6 +7014,14 @@ pub trait GTransformTraitConst {
 		ret
 	}
 	
+	/// Same as `description()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn description_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_GTransform_getPropDescriptionRef_const(self.as_raw_GTransform()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 }
 
 pub trait GTransformTrait: crate::gapi::GTransformTraitConst {
@@ -7425,7 +7481,7 @@ pub trait RMat_ViewTraitConst {
 	}
 	
 	#[inline]
-	fn elem_size(&self) -> Result<size_t> {
+	fn elem_size(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_RMat_View_elemSize_const(self.as_raw_RMat_View(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -7436,7 +7492,7 @@ pub trait RMat_ViewTraitConst {
 	/// ## C++ default parameters
 	/// * i: 0
 	#[inline]
-	fn step(&self, i: size_t) -> Result<size_t> {
+	fn step(&self, i: usize) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_RMat_View_step_const_size_t(self.as_raw_RMat_View(), i, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -7445,12 +7501,12 @@ pub trait RMat_ViewTraitConst {
 	}
 	
 	#[inline]
-	fn steps(&self) -> Result<core::Vector<size_t>> {
+	fn steps(&self) -> Result<core::Vector<usize>> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_RMat_View_steps_const(self.as_raw_RMat_View(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
-		let ret = unsafe { core::Vector::<size_t>::opencv_from_extern(ret) };
+		let ret = unsafe { core::Vector::<usize>::opencv_from_extern(ret) };
 		Ok(ret)
 	}
 	
@@ -7771,7 +7827,7 @@ pub trait ScalarTrait: crate::gapi::ScalarTraitConst {
 	#[inline]
 	fn val(&mut self) -> &mut [f64; 4] {
 		let ret = unsafe { sys::cv_gapi_own_Scalar_getPropVal(self.as_raw_mut_Scalar()) };
-		let ret = unsafe { ret.as_mut() }.expect("Function returned null pointer");
+		let ret = unsafe { &mut *ret };
 		ret
 	}
 	
@@ -7867,7 +7923,7 @@ impl Default for Scalar {
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct queue_capacity {
-	pub capacity: size_t,
+	pub capacity: usize,
 }
 
 opencv_type_simple! { crate::gapi::queue_capacity }
@@ -7876,7 +7932,7 @@ impl queue_capacity {
 	/// ## C++ default parameters
 	/// * cap: 1
 	#[inline]
-	pub fn new(cap: size_t) -> Result<crate::gapi::queue_capacity> {
+	pub fn new(cap: usize) -> Result<crate::gapi::queue_capacity> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_gapi_streaming_queue_capacity_queue_capacity_size_t(cap, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -7902,6 +7958,14 @@ pub trait use_onlyTraitConst {
 		ret
 	}
 	
+	/// Same as `pkg()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn pkg_ref(&self) -> BoxedRef<'_, crate::gapi::GKernelPackage> {
+		let ret = unsafe { sys::cv_gapi_use_only_getPropPkgRef_const(self.as_raw_use_only()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 }
 
 pub trait use_onlyTrait: crate::gapi::use_onlyTraitConst {
@@ -8090,6 +8154,14 @@ pub trait ImageTraitConst {
 		ret
 	}
 	
+	/// Same as `img()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn img_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_gapi_wip_draw_Image_getPropImgRef_const(self.as_raw_Image()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// Alpha channel for image to draw (same size and number of channels)
 	#[inline]
 	fn alpha(&self) -> core::Mat {
@@ -8098,6 +8170,14 @@ pub trait ImageTraitConst {
 		ret
 	}
 	
+	/// Same as `alpha()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn alpha_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_gapi_wip_draw_Image_getPropAlphaRef_const(self.as_raw_Image()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 }
 
 pub trait ImageTrait: crate::gapi::ImageTraitConst {
@@ -8305,6 +8385,14 @@ pub trait PolyTraitConst {
 		ret
 	}
 	
+	/// Same as `points()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn points_ref(&self) -> BoxedRef<'_, core::Vector<core::Point>> {
+		let ret = unsafe { sys::cv_gapi_wip_draw_Poly_getPropPointsRef_const(self.as_raw_Poly()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// The line color
 	#[inline]
 	fn color(&self) -> core::Scalar {
@@ -8517,6 +8605,14 @@ pub trait TextTraitConst {
 		ret
 	}
 	
+	/// Same as `text()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn text_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_gapi_wip_draw_Text_getPropTextRef_const(self.as_raw_Text()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	/// The bottom-left corner of the text string in the image
 	#[inline]
 	fn org(&self) -> core::Point {
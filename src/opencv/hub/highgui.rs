@@ -516,14 +516,14 @@ pub fn create_button(bar_name: &str, on_change: crate::highgui::ButtonCallback,
 /// * on_change: 0
 /// * userdata: 0
 #[inline]
-pub fn create_trackbar(trackbarname: &str, winname: &str, value: Option<&mut i32>, count: i32, on_change: crate::highgui::TrackbarCallback) -> Result<i32> {
+pub unsafe fn create_trackbar(trackbarname: &str, winname: &str, value: Option<&mut i32>, count: i32, on_change: crate::highgui::TrackbarCallback) -> Result<i32> {
 	extern_container_arg!(trackbarname);
 	extern_container_arg!(winname);
 	callback_arg!(on_change_trampoline(pos: i32, userdata: *mut c_void) -> () => userdata in callbacks => on_change(pos: i32) -> ());
 	userdata_arg!(userdata in callbacks => on_change);
 	return_send!(via ocvrs_return);
-	unsafe { sys::cv_createTrackbar_const_StringR_const_StringR_intX_int_TrackbarCallback_voidX(trackbarname.opencv_as_extern(), winname.opencv_as_extern(), value.map_or(::core::ptr::null_mut(), |value| value as *mut _), count, on_change_trampoline, userdata, ocvrs_return.as_mut_ptr()) };
-	return_receive!(unsafe ocvrs_return => ret);
+	{ sys::cv_createTrackbar_const_StringR_const_StringR_intX_int_TrackbarCallback_voidX(trackbarname.opencv_as_extern(), winname.opencv_as_extern(), value.map_or(::core::ptr::null_mut(), |value| value as *mut _), count, on_change_trampoline, userdata, ocvrs_return.as_mut_ptr()) };
+	return_receive!(ocvrs_return => ret);
 	let ret = ret.into_result()?;
 	Ok(ret)
 }
@@ -776,7 +776,7 @@ pub fn get_window_property(winname: &str, prop_id: i32) -> Result<f64> {
 /// * winname: Name of the window.
 /// * mat: Image to be shown.
 #[inline]
-pub fn imshow(winname: &str, mat: &dyn core::ToInputArray) -> Result<()> {
+pub fn imshow(winname: &str, mat: &(impl core::ToInputArray + ?Sized)) -> Result<()> {
 	extern_container_arg!(winname);
 	input_array_arg!(mat);
 	return_send!(via ocvrs_return);
@@ -969,7 +969,7 @@ pub fn save_window_parameters(window_name: &str) -> Result<()> {
 /// * show_crosshair: true
 /// * from_center: false
 #[inline]
-pub fn select_roi_for_window(window_name: &str, img: &dyn core::ToInputArray, show_crosshair: bool, from_center: bool) -> Result<core::Rect> {
+pub fn select_roi_for_window(window_name: &str, img: &(impl core::ToInputArray + ?Sized), show_crosshair: bool, from_center: bool) -> Result<core::Rect> {
 	extern_container_arg!(window_name);
 	input_array_arg!(img);
 	return_send!(via ocvrs_return);
@@ -1003,7 +1003,7 @@ pub fn select_roi_for_window(window_name: &str, img: &dyn core::ToInputArray, sh
 /// * show_crosshair: true
 /// * from_center: false
 #[inline]
-pub fn select_roi(img: &dyn core::ToInputArray, show_crosshair: bool, from_center: bool) -> Result<core::Rect> {
+pub fn select_roi(img: &(impl core::ToInputArray + ?Sized), show_crosshair: bool, from_center: bool) -> Result<core::Rect> {
 	input_array_arg!(img);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_selectROI_const__InputArrayR_bool_bool(img.as_raw__InputArray(), show_crosshair, from_center, ocvrs_return.as_mut_ptr()) };
@@ -1034,7 +1034,7 @@ pub fn select_roi(img: &dyn core::ToInputArray, show_crosshair: bool, from_cente
 /// * show_crosshair: true
 /// * from_center: false
 #[inline]
-pub fn select_rois(window_name: &str, img: &dyn core::ToInputArray, bounding_boxes: &mut core::Vector<core::Rect>, show_crosshair: bool, from_center: bool) -> Result<()> {
+pub fn select_rois(window_name: &str, img: &(impl core::ToInputArray + ?Sized), bounding_boxes: &mut core::Vector<core::Rect>, show_crosshair: bool, from_center: bool) -> Result<()> {
 	extern_container_arg!(window_name);
 	input_array_arg!(img);
 	return_send!(via ocvrs_return);
@@ -1057,7 +1057,7 @@ pub fn select_rois(window_name: &str, img: &dyn core::ToInputArray, bounding_box
 /// ## C++ default parameters
 /// * userdata: 0
 #[inline]
-pub fn set_mouse_callback(winname: &str, on_mouse: crate::highgui::MouseCallback) -> Result<()> {
+pub fn set_mouse_callback_raw(winname: &str, on_mouse: crate::highgui::MouseCallback) -> Result<()> {
 	extern_container_arg!(winname);
 	callback_arg!(on_mouse_trampoline(event: i32, x: i32, y: i32, flags: i32, userdata: *mut c_void) -> () => userdata in callbacks => on_mouse(event: i32, x: i32, y: i32, flags: i32) -> ());
 	userdata_arg!(userdata in callbacks => on_mouse);
@@ -1350,6 +1350,14 @@ pub trait QtFontTraitConst {
 		ret
 	}
 	
+	/// Same as `name_font()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn name_font_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_QtFont_getPropNameFontRef_const(self.as_raw_QtFont()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	/// Color of the font. Scalar(blue_component, green_component, red_component[, alpha_component])
 	#[inline]
 	fn color(&self) -> core::Scalar {
@@ -1512,3 +1520,4 @@ impl crate::highgui::QtFontTrait for QtFont {
 
 impl QtFont {
 }
+pub use crate::manual::highgui::*;
@@ -92,7 +92,7 @@ opencv_type_enum! { crate::img_hash::BlockMeanHashMode }
 /// * inputArr: input image want to compute hash value, type should be CV_8UC4, CV_8UC3 or CV_8UC1.
 /// * outputArr: Hash value of input, it will contain 16 hex decimal number, return type is CV_8U
 #[inline]
-pub fn average_hash(input_arr: &dyn core::ToInputArray, output_arr: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn average_hash(input_arr: &(impl core::ToInputArray + ?Sized), output_arr: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(input_arr);
 	output_array_arg!(output_arr);
 	return_send!(via ocvrs_return);
@@ -111,7 +111,7 @@ pub fn average_hash(input_arr: &dyn core::ToInputArray, output_arr: &mut dyn cor
 /// ## C++ default parameters
 /// * mode: BLOCK_MEAN_HASH_MODE_0
 #[inline]
-pub fn block_mean_hash(input_arr: &dyn core::ToInputArray, output_arr: &mut dyn core::ToOutputArray, mode: i32) -> Result<()> {
+pub fn block_mean_hash(input_arr: &(impl core::ToInputArray + ?Sized), output_arr: &mut (impl core::ToOutputArray + ?Sized), mode: i32) -> Result<()> {
 	input_array_arg!(input_arr);
 	output_array_arg!(output_arr);
 	return_send!(via ocvrs_return);
@@ -129,7 +129,7 @@ pub fn block_mean_hash(input_arr: &dyn core::ToInputArray, output_arr: &mut dyn
 /// type should be CV_8UC4, CV_8UC3 or CV_8UC1.
 /// * outputArr: 42 hash values with type CV_64F(double)
 #[inline]
-pub fn color_moment_hash(input_arr: &dyn core::ToInputArray, output_arr: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn color_moment_hash(input_arr: &(impl core::ToInputArray + ?Sized), output_arr: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(input_arr);
 	output_array_arg!(output_arr);
 	return_send!(via ocvrs_return);
@@ -152,7 +152,7 @@ pub fn color_moment_hash(input_arr: &dyn core::ToInputArray, output_arr: &mut dy
 /// * alpha: 2.0f
 /// * scale: 1.0f
 #[inline]
-pub fn marr_hildreth_hash(input_arr: &dyn core::ToInputArray, output_arr: &mut dyn core::ToOutputArray, alpha: f32, scale: f32) -> Result<()> {
+pub fn marr_hildreth_hash(input_arr: &(impl core::ToInputArray + ?Sized), output_arr: &mut (impl core::ToOutputArray + ?Sized), alpha: f32, scale: f32) -> Result<()> {
 	input_array_arg!(input_arr);
 	output_array_arg!(output_arr);
 	return_send!(via ocvrs_return);
@@ -168,7 +168,7 @@ pub fn marr_hildreth_hash(input_arr: &dyn core::ToInputArray, output_arr: &mut d
 ///  type should be CV_8UC4, CV_8UC3, CV_8UC1.
 /// * outputArr: Hash value of input, it will contain 8 uchar value
 #[inline]
-pub fn p_hash(input_arr: &dyn core::ToInputArray, output_arr: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn p_hash(input_arr: &(impl core::ToInputArray + ?Sized), output_arr: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(input_arr);
 	output_array_arg!(output_arr);
 	return_send!(via ocvrs_return);
@@ -190,7 +190,7 @@ pub fn p_hash(input_arr: &dyn core::ToInputArray, output_arr: &mut dyn core::ToO
 /// * sigma: 1
 /// * num_of_angle_line: 180
 #[inline]
-pub fn radial_variance_hash(input_arr: &dyn core::ToInputArray, output_arr: &mut dyn core::ToOutputArray, sigma: f64, num_of_angle_line: i32) -> Result<()> {
+pub fn radial_variance_hash(input_arr: &(impl core::ToInputArray + ?Sized), output_arr: &mut (impl core::ToOutputArray + ?Sized), sigma: f64, num_of_angle_line: i32) -> Result<()> {
 	input_array_arg!(input_arr);
 	output_array_arg!(output_arr);
 	return_send!(via ocvrs_return);
@@ -283,8 +283,8 @@ opencv_type_enum! { crate::imgcodecs::ImwritePNGFlags }
 /// ## Parameters
 /// * filename: File name of the image
 #[inline]
-pub fn have_image_reader(filename: &str) -> Result<bool> {
-	extern_container_arg!(filename);
+pub fn have_image_reader(filename: impl AsRef<Path>) -> Result<bool> {
+	path_arg!(filename);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_haveImageReader_const_StringR(filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -297,8 +297,8 @@ pub fn have_image_reader(filename: &str) -> Result<bool> {
 /// ## Parameters
 /// * filename: File name of the image
 #[inline]
-pub fn have_image_writer(filename: &str) -> Result<bool> {
-	extern_container_arg!(filename);
+pub fn have_image_writer(filename: impl AsRef<Path>) -> Result<bool> {
+	path_arg!(filename);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_haveImageWriter_const_StringR(filename.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -316,8 +316,8 @@ pub fn have_image_writer(filename: &str) -> Result<bool> {
 /// ## C++ default parameters
 /// * flags: IMREAD_ANYCOLOR
 #[inline]
-pub fn imcount(filename: &str, flags: i32) -> Result<size_t> {
-	extern_container_arg!(filename);
+pub fn imcount(filename: impl AsRef<Path>, flags: i32) -> Result<usize> {
+	path_arg!(filename);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_imcount_const_StringR_int(filename.opencv_as_extern(), flags, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -338,7 +338,7 @@ pub fn imcount(filename: &str, flags: i32) -> Result<size_t> {
 /// * buf: Input array or vector of bytes.
 /// * flags: The same flags as in cv::imread, see cv::ImreadModes.
 #[inline]
-pub fn imdecode(buf: &dyn core::ToInputArray, flags: i32) -> Result<core::Mat> {
+pub fn imdecode(buf: &(impl core::ToInputArray + ?Sized), flags: i32) -> Result<core::Mat> {
 	input_array_arg!(buf);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_imdecode_const__InputArrayR_int(buf.as_raw__InputArray(), flags, ocvrs_return.as_mut_ptr()) };
@@ -368,7 +368,7 @@ pub fn imdecode(buf: &dyn core::ToInputArray, flags: i32) -> Result<core::Mat> {
 /// * dst: The optional output placeholder for the decoded matrix. It can save the image
 /// reallocations when the function is called repeatedly for images of the same size.
 #[inline]
-pub fn imdecode_to(buf: &dyn core::ToInputArray, flags: i32, dst: &mut core::Mat) -> Result<core::Mat> {
+pub fn imdecode_to(buf: &(impl core::ToInputArray + ?Sized), flags: i32, dst: &mut core::Mat) -> Result<core::Mat> {
 	input_array_arg!(buf);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_imdecode_const__InputArrayR_int_MatX(buf.as_raw__InputArray(), flags, dst.as_raw_mut_Mat(), ocvrs_return.as_mut_ptr()) };
@@ -392,7 +392,7 @@ pub fn imdecode_to(buf: &dyn core::ToInputArray, flags: i32, dst: &mut core::Mat
 /// ## C++ default parameters
 /// * params: std::vector<int>()
 #[inline]
-pub fn imencode(ext: &str, img: &dyn core::ToInputArray, buf: &mut core::Vector<u8>, params: &core::Vector<i32>) -> Result<bool> {
+pub fn imencode(ext: &str, img: &(impl core::ToInputArray + ?Sized), buf: &mut core::Vector<u8>, params: &core::Vector<i32>) -> Result<bool> {
 	extern_container_arg!(ext);
 	input_array_arg!(img);
 	return_send!(via ocvrs_return);
@@ -458,8 +458,8 @@ pub fn imencode(ext: &str, img: &dyn core::ToInputArray, buf: &mut core::Vector<
 /// ## C++ default parameters
 /// * flags: IMREAD_COLOR
 #[inline]
-pub fn imread(filename: &str, flags: i32) -> Result<core::Mat> {
-	extern_container_arg!(filename);
+pub fn imread(filename: impl AsRef<Path>, flags: i32) -> Result<core::Mat> {
+	path_arg!(filename);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_imread_const_StringR_int(filename.opencv_as_extern(), flags, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -481,8 +481,8 @@ pub fn imread(filename: &str, flags: i32) -> Result<core::Mat> {
 /// ## C++ default parameters
 /// * flags: IMREAD_ANYCOLOR
 #[inline]
-pub fn imreadmulti(filename: &str, mats: &mut core::Vector<core::Mat>, flags: i32) -> Result<bool> {
-	extern_container_arg!(filename);
+pub fn imreadmulti(filename: impl AsRef<Path>, mats: &mut core::Vector<core::Mat>, flags: i32) -> Result<bool> {
+	path_arg!(filename);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_imreadmulti_const_StringR_vectorLMatGR_int(filename.opencv_as_extern(), mats.as_raw_mut_VectorOfMat(), flags, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -505,8 +505,8 @@ pub fn imreadmulti(filename: &str, mats: &mut core::Vector<core::Mat>, flags: i3
 /// ## C++ default parameters
 /// * flags: IMREAD_ANYCOLOR
 #[inline]
-pub fn imreadmulti_range(filename: &str, mats: &mut core::Vector<core::Mat>, start: i32, count: i32, flags: i32) -> Result<bool> {
-	extern_container_arg!(filename);
+pub fn imreadmulti_range(filename: impl AsRef<Path>, mats: &mut core::Vector<core::Mat>, start: i32, count: i32, flags: i32) -> Result<bool> {
+	path_arg!(filename);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_imreadmulti_const_StringR_vectorLMatGR_int_int_int(filename.opencv_as_extern(), mats.as_raw_mut_VectorOfMat(), start, count, flags, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -547,8 +547,8 @@ pub fn imreadmulti_range(filename: &str, mats: &mut core::Vector<core::Mat>, sta
 /// ## C++ default parameters
 /// * params: std::vector<int>()
 #[inline]
-pub fn imwrite(filename: &str, img: &dyn core::ToInputArray, params: &core::Vector<i32>) -> Result<bool> {
-	extern_container_arg!(filename);
+pub fn imwrite(filename: impl AsRef<Path>, img: &(impl core::ToInputArray + ?Sized), params: &core::Vector<i32>) -> Result<bool> {
+	path_arg!(filename);
 	input_array_arg!(img);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_imwrite_const_StringR_const__InputArrayR_const_vectorLintGR(filename.opencv_as_extern(), img.as_raw__InputArray(), params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
@@ -562,8 +562,8 @@ pub fn imwrite(filename: &str, img: &dyn core::ToInputArray, params: &core::Vect
 /// ## C++ default parameters
 /// * params: std::vector<int>()
 #[inline]
-pub fn imwritemulti(filename: &str, img: &dyn core::ToInputArray, params: &core::Vector<i32>) -> Result<bool> {
-	extern_container_arg!(filename);
+pub fn imwritemulti(filename: impl AsRef<Path>, img: &(impl core::ToInputArray + ?Sized), params: &core::Vector<i32>) -> Result<bool> {
+	path_arg!(filename);
 	input_array_arg!(img);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_imwritemulti_const_StringR_const__InputArrayR_const_vectorLintGR(filename.opencv_as_extern(), img.as_raw__InputArray(), params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
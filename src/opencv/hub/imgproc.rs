@@ -1932,7 +1932,7 @@ opencv_type_enum! { crate::imgproc::WarpPolarMode }
 /// ## C++ default parameters
 /// * l2gradient: false
 #[inline]
-pub fn canny_derivative(dx: &dyn core::ToInputArray, dy: &dyn core::ToInputArray, edges: &mut dyn core::ToOutputArray, threshold1: f64, threshold2: f64, l2gradient: bool) -> Result<()> {
+pub fn canny_derivative(dx: &(impl core::ToInputArray + ?Sized), dy: &(impl core::ToInputArray + ?Sized), edges: &mut (impl core::ToOutputArray + ?Sized), threshold1: f64, threshold2: f64, l2gradient: bool) -> Result<()> {
 	input_array_arg!(dx);
 	input_array_arg!(dy);
 	output_array_arg!(edges);
@@ -1965,7 +1965,7 @@ pub fn canny_derivative(dx: &dyn core::ToInputArray, dy: &dyn core::ToInputArray
 /// * aperture_size: 3
 /// * l2gradient: false
 #[inline]
-pub fn canny(image: &dyn core::ToInputArray, edges: &mut dyn core::ToOutputArray, threshold1: f64, threshold2: f64, aperture_size: i32, l2gradient: bool) -> Result<()> {
+pub fn canny(image: &(impl core::ToInputArray + ?Sized), edges: &mut (impl core::ToOutputArray + ?Sized), threshold1: f64, threshold2: f64, aperture_size: i32, l2gradient: bool) -> Result<()> {
 	input_array_arg!(image);
 	output_array_arg!(edges);
 	return_send!(via ocvrs_return);
@@ -2015,11 +2015,11 @@ pub fn canny(image: &dyn core::ToInputArray, edges: &mut dyn core::ToOutputArray
 /// * lower_bound: 0
 /// * flow: noArray()
 #[inline]
-pub fn emd(signature1: &dyn core::ToInputArray, signature2: &dyn core::ToInputArray, dist_type: i32, cost: &dyn core::ToInputArray, lower_bound: Option<&mut f32>, flow: &mut dyn core::ToOutputArray) -> Result<f32> {
+pub fn emd(signature1: &(impl core::ToInputArray + ?Sized), signature2: &(impl core::ToInputArray + ?Sized), dist_type: i32, cost: Option<&(impl core::ToInputArray + ?Sized)>, lower_bound: Option<&mut f32>, flow: Option<&mut (impl core::ToOutputArray + ?Sized)>) -> Result<f32> {
 	input_array_arg!(signature1);
 	input_array_arg!(signature2);
-	input_array_arg!(cost);
-	output_array_arg!(flow);
+	input_array_arg!(nullable cost);
+	output_array_arg!(nullable flow);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_EMD_const__InputArrayR_const__InputArrayR_int_const__InputArrayR_floatX_const__OutputArrayR(signature1.as_raw__InputArray(), signature2.as_raw__InputArray(), dist_type, cost.as_raw__InputArray(), lower_bound.map_or(::core::ptr::null_mut(), |lower_bound| lower_bound as *mut _), flow.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -2052,7 +2052,7 @@ pub fn emd(signature1: &dyn core::ToInputArray, signature2: &dyn core::ToInputAr
 /// * sigma_y: 0
 /// * border_type: BORDER_DEFAULT
 #[inline]
-pub fn gaussian_blur(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, ksize: core::Size, sigma_x: f64, sigma_y: f64, border_type: i32) -> Result<()> {
+pub fn gaussian_blur(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), ksize: core::Size, sigma_x: f64, sigma_y: f64, border_type: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -2113,7 +2113,7 @@ pub fn gaussian_blur(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputA
 /// * min_radius: 0
 /// * max_radius: 0
 #[inline]
-pub fn hough_circles(image: &dyn core::ToInputArray, circles: &mut dyn core::ToOutputArray, method: i32, dp: f64, min_dist: f64, param1: f64, param2: f64, min_radius: i32, max_radius: i32) -> Result<()> {
+pub fn hough_circles(image: &(impl core::ToInputArray + ?Sized), circles: &mut (impl core::ToOutputArray + ?Sized), method: i32, dp: f64, min_dist: f64, param1: f64, param2: f64, min_radius: i32, max_radius: i32) -> Result<()> {
 	input_array_arg!(image);
 	output_array_arg!(circles);
 	return_send!(via ocvrs_return);
@@ -2156,7 +2156,7 @@ pub fn hough_circles(image: &dyn core::ToInputArray, circles: &mut dyn core::ToO
 /// * min_line_length: 0
 /// * max_line_gap: 0
 #[inline]
-pub fn hough_lines_p(image: &dyn core::ToInputArray, lines: &mut dyn core::ToOutputArray, rho: f64, theta: f64, threshold: i32, min_line_length: f64, max_line_gap: f64) -> Result<()> {
+pub fn hough_lines_p(image: &(impl core::ToInputArray + ?Sized), lines: &mut (impl core::ToOutputArray + ?Sized), rho: f64, theta: f64, threshold: i32, min_line_length: f64, max_line_gap: f64) -> Result<()> {
 	input_array_arg!(image);
 	output_array_arg!(lines);
 	return_send!(via ocvrs_return);
@@ -2184,7 +2184,7 @@ pub fn hough_lines_p(image: &dyn core::ToInputArray, lines: &mut dyn core::ToOut
 /// * max_theta: Maximum angle value of the accumulator in radians.
 /// * theta_step: Angle resolution of the accumulator in radians.
 #[inline]
-pub fn hough_lines_point_set(point: &dyn core::ToInputArray, lines: &mut dyn core::ToOutputArray, lines_max: i32, threshold: i32, min_rho: f64, max_rho: f64, rho_step: f64, min_theta: f64, max_theta: f64, theta_step: f64) -> Result<()> {
+pub fn hough_lines_point_set(point: &(impl core::ToInputArray + ?Sized), lines: &mut (impl core::ToOutputArray + ?Sized), lines_max: i32, threshold: i32, min_rho: f64, max_rho: f64, rho_step: f64, min_theta: f64, max_theta: f64, theta_step: f64) -> Result<()> {
 	input_array_arg!(point);
 	output_array_arg!(lines);
 	return_send!(via ocvrs_return);
@@ -2227,7 +2227,7 @@ pub fn hough_lines_point_set(point: &dyn core::ToInputArray, lines: &mut dyn cor
 /// * min_theta: 0
 /// * max_theta: CV_PI
 #[inline]
-pub fn hough_lines(image: &dyn core::ToInputArray, lines: &mut dyn core::ToOutputArray, rho: f64, theta: f64, threshold: i32, srn: f64, stn: f64, min_theta: f64, max_theta: f64) -> Result<()> {
+pub fn hough_lines(image: &(impl core::ToInputArray + ?Sized), lines: &mut (impl core::ToOutputArray + ?Sized), rho: f64, theta: f64, threshold: i32, srn: f64, stn: f64, min_theta: f64, max_theta: f64) -> Result<()> {
 	input_array_arg!(image);
 	output_array_arg!(lines);
 	return_send!(via ocvrs_return);
@@ -2259,7 +2259,7 @@ pub fn hough_lines(image: &dyn core::ToInputArray, lines: &mut dyn core::ToOutpu
 /// 
 /// ## Overloaded parameters
 #[inline]
-pub fn hu_moments_1(m: core::Moments, hu: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn hu_moments_1(m: core::Moments, hu: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	output_array_arg!(hu);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_HuMoments_const_MomentsR_const__OutputArrayR(&m, hu.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
@@ -2327,7 +2327,7 @@ pub fn hu_moments(moments: core::Moments, hu: &mut [f64; 7]) -> Result<()> {
 /// * delta: 0
 /// * border_type: BORDER_DEFAULT
 #[inline]
-pub fn laplacian(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, ddepth: i32, ksize: i32, scale: f64, delta: f64, border_type: i32) -> Result<()> {
+pub fn laplacian(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), ddepth: i32, ksize: i32, scale: f64, delta: f64, border_type: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -2366,7 +2366,7 @@ pub fn laplacian(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 /// * delta: 0
 /// * border_type: BORDER_DEFAULT
 #[inline]
-pub fn scharr(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, ddepth: i32, dx: i32, dy: i32, scale: f64, delta: f64, border_type: i32) -> Result<()> {
+pub fn scharr(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), ddepth: i32, dx: i32, dy: i32, scale: f64, delta: f64, border_type: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -2426,7 +2426,7 @@ pub fn scharr(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, d
 /// * delta: 0
 /// * border_type: BORDER_DEFAULT
 #[inline]
-pub fn sobel(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, ddepth: i32, dx: i32, dy: i32, ksize: i32, scale: f64, delta: f64, border_type: i32) -> Result<()> {
+pub fn sobel(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), ddepth: i32, dx: i32, dy: i32, ksize: i32, scale: f64, delta: f64, border_type: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -2456,11 +2456,11 @@ pub fn sobel(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, dd
 /// ## C++ default parameters
 /// * mask: noArray()
 #[inline]
-pub fn accumulate_product(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToInputOutputArray, mask: &dyn core::ToInputArray) -> Result<()> {
+pub fn accumulate_product(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToInputOutputArray + ?Sized), mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	input_output_array_arg!(dst);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_accumulateProduct_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__InputArrayR(src1.as_raw__InputArray(), src2.as_raw__InputArray(), dst.as_raw__InputOutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -2488,10 +2488,10 @@ pub fn accumulate_product(src1: &dyn core::ToInputArray, src2: &dyn core::ToInpu
 /// ## C++ default parameters
 /// * mask: noArray()
 #[inline]
-pub fn accumulate_square(src: &dyn core::ToInputArray, dst: &mut dyn core::ToInputOutputArray, mask: &dyn core::ToInputArray) -> Result<()> {
+pub fn accumulate_square(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToInputOutputArray + ?Sized), mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(src);
 	input_output_array_arg!(dst);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_accumulateSquare_const__InputArrayR_const__InputOutputArrayR_const__InputArrayR(src.as_raw__InputArray(), dst.as_raw__InputOutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -2521,10 +2521,10 @@ pub fn accumulate_square(src: &dyn core::ToInputArray, dst: &mut dyn core::ToInp
 /// ## C++ default parameters
 /// * mask: noArray()
 #[inline]
-pub fn accumulate_weighted(src: &dyn core::ToInputArray, dst: &mut dyn core::ToInputOutputArray, alpha: f64, mask: &dyn core::ToInputArray) -> Result<()> {
+pub fn accumulate_weighted(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToInputOutputArray + ?Sized), alpha: f64, mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(src);
 	input_output_array_arg!(dst);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_accumulateWeighted_const__InputArrayR_const__InputOutputArrayR_double_const__InputArrayR(src.as_raw__InputArray(), dst.as_raw__InputOutputArray(), alpha, mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -2553,10 +2553,10 @@ pub fn accumulate_weighted(src: &dyn core::ToInputArray, dst: &mut dyn core::ToI
 /// ## C++ default parameters
 /// * mask: noArray()
 #[inline]
-pub fn accumulate(src: &dyn core::ToInputArray, dst: &mut dyn core::ToInputOutputArray, mask: &dyn core::ToInputArray) -> Result<()> {
+pub fn accumulate(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToInputOutputArray + ?Sized), mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(src);
 	input_output_array_arg!(dst);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_accumulate_const__InputArrayR_const__InputOutputArrayR_const__InputArrayR(src.as_raw__InputArray(), dst.as_raw__InputOutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -2590,7 +2590,7 @@ pub fn accumulate(src: &dyn core::ToInputArray, dst: &mut dyn core::ToInputOutpu
 /// ## See also
 /// threshold, blur, GaussianBlur
 #[inline]
-pub fn adaptive_threshold(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, max_value: f64, adaptive_method: i32, threshold_type: i32, block_size: i32, c: f64) -> Result<()> {
+pub fn adaptive_threshold(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), max_value: f64, adaptive_method: i32, threshold_type: i32, block_size: i32, c: f64) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -2607,7 +2607,7 @@ pub fn adaptive_threshold(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOu
 /// * dst: The result is the colormapped source image. Note: Mat::create is called on dst.
 /// * userColor: The colormap to apply of type CV_8UC1 or CV_8UC3 and size 256
 #[inline]
-pub fn apply_color_map_user(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, user_color: &dyn core::ToInputArray) -> Result<()> {
+pub fn apply_color_map_user(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), user_color: &(impl core::ToInputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	input_array_arg!(user_color);
@@ -2625,7 +2625,7 @@ pub fn apply_color_map_user(src: &dyn core::ToInputArray, dst: &mut dyn core::To
 /// * dst: The result is the colormapped source image. Note: Mat::create is called on dst.
 /// * colormap: The colormap to apply, see #ColormapTypes
 #[inline]
-pub fn apply_color_map(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, colormap: i32) -> Result<()> {
+pub fn apply_color_map(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), colormap: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -2649,7 +2649,7 @@ pub fn apply_color_map(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutpu
 /// * closed: If true, the approximated curve is closed (its first and last vertices are
 /// connected). Otherwise, it is not closed.
 #[inline]
-pub fn approx_poly_dp(curve: &dyn core::ToInputArray, approx_curve: &mut dyn core::ToOutputArray, epsilon: f64, closed: bool) -> Result<()> {
+pub fn approx_poly_dp(curve: &(impl core::ToInputArray + ?Sized), approx_curve: &mut (impl core::ToOutputArray + ?Sized), epsilon: f64, closed: bool) -> Result<()> {
 	input_array_arg!(curve);
 	output_array_arg!(approx_curve);
 	return_send!(via ocvrs_return);
@@ -2667,7 +2667,7 @@ pub fn approx_poly_dp(curve: &dyn core::ToInputArray, approx_curve: &mut dyn cor
 /// * curve: Input vector of 2D points, stored in std::vector or Mat.
 /// * closed: Flag indicating whether the curve is closed or not.
 #[inline]
-pub fn arc_length(curve: &dyn core::ToInputArray, closed: bool) -> Result<f64> {
+pub fn arc_length(curve: &(impl core::ToInputArray + ?Sized), closed: bool) -> Result<f64> {
 	input_array_arg!(curve);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_arcLength_const__InputArrayR_bool(curve.as_raw__InputArray(), closed, ocvrs_return.as_mut_ptr()) };
@@ -2696,7 +2696,7 @@ pub fn arc_length(curve: &dyn core::ToInputArray, closed: bool) -> Result<f64> {
 /// * shift: 0
 /// * tip_length: 0.1
 #[inline]
-pub fn arrowed_line(img: &mut dyn core::ToInputOutputArray, pt1: core::Point, pt2: core::Point, color: core::Scalar, thickness: i32, line_type: i32, shift: i32, tip_length: f64) -> Result<()> {
+pub fn arrowed_line(img: &mut (impl core::ToInputOutputArray + ?Sized), pt1: core::Point, pt2: core::Point, color: core::Scalar, thickness: i32, line_type: i32, shift: i32, tip_length: f64) -> Result<()> {
 	input_output_array_arg!(img);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_arrowedLine_const__InputOutputArrayR_Point_Point_const_ScalarR_int_int_int_double(img.as_raw__InputOutputArray(), pt1.opencv_as_extern(), pt2.opencv_as_extern(), &color, thickness, line_type, shift, tip_length, ocvrs_return.as_mut_ptr()) };
@@ -2737,7 +2737,7 @@ pub fn arrowed_line(img: &mut dyn core::ToInputOutputArray, pt1: core::Point, pt
 /// ## C++ default parameters
 /// * border_type: BORDER_DEFAULT
 #[inline]
-pub fn bilateral_filter(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, d: i32, sigma_color: f64, sigma_space: f64, border_type: i32) -> Result<()> {
+pub fn bilateral_filter(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), d: i32, sigma_color: f64, sigma_space: f64, border_type: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -2756,7 +2756,7 @@ pub fn bilateral_filter(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutp
 /// * weights2: It has a type of CV_32FC1 and the same size with src1.
 /// * dst: It is created if it does not have the same size and type with src1.
 #[inline]
-pub fn blend_linear(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, weights1: &dyn core::ToInputArray, weights2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn blend_linear(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), weights1: &(impl core::ToInputArray + ?Sized), weights2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	input_array_arg!(weights1);
@@ -2793,7 +2793,7 @@ pub fn blend_linear(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray
 /// * anchor: Point(-1,-1)
 /// * border_type: BORDER_DEFAULT
 #[inline]
-pub fn blur(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, ksize: core::Size, anchor: core::Point, border_type: i32) -> Result<()> {
+pub fn blur(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), ksize: core::Size, anchor: core::Point, border_type: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -2811,7 +2811,7 @@ pub fn blur(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, ksi
 /// ## Parameters
 /// * array: Input gray-scale image or 2D point set, stored in std::vector or Mat.
 #[inline]
-pub fn bounding_rect(array: &dyn core::ToInputArray) -> Result<core::Rect> {
+pub fn bounding_rect(array: &(impl core::ToInputArray + ?Sized)) -> Result<core::Rect> {
 	input_array_arg!(array);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_boundingRect_const__InputArrayR(array.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
@@ -2851,7 +2851,7 @@ pub fn bounding_rect(array: &dyn core::ToInputArray) -> Result<core::Rect> {
 /// * normalize: true
 /// * border_type: BORDER_DEFAULT
 #[inline]
-pub fn box_filter(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, ddepth: i32, ksize: core::Size, anchor: core::Point, normalize: bool, border_type: i32) -> Result<()> {
+pub fn box_filter(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), ddepth: i32, ksize: core::Size, anchor: core::Point, normalize: bool, border_type: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -2871,7 +2871,7 @@ pub fn box_filter(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArra
 /// * box: The input rotated rectangle. It may be the output of
 /// * points: The output array of four vertices of rectangles.
 #[inline]
-pub fn box_points(mut box_: core::RotatedRect, points: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn box_points(mut box_: core::RotatedRect, points: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	output_array_arg!(points);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_boxPoints_RotatedRect_const__OutputArrayR(box_.as_raw_mut_RotatedRect(), points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
@@ -2895,7 +2895,7 @@ pub fn box_points(mut box_: core::RotatedRect, points: &mut dyn core::ToOutputAr
 /// ## C++ default parameters
 /// * border_type: BORDER_DEFAULT
 #[inline]
-pub fn build_pyramid(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, maxlevel: i32, border_type: i32) -> Result<()> {
+pub fn build_pyramid(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), maxlevel: i32, border_type: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -2947,7 +2947,7 @@ pub fn build_pyramid(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputA
 /// 
 /// ## Overloaded parameters
 #[inline]
-pub fn calc_back_project(images: &dyn core::ToInputArray, channels: &core::Vector<i32>, hist: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, ranges: &core::Vector<f32>, scale: f64) -> Result<()> {
+pub fn calc_back_project(images: &(impl core::ToInputArray + ?Sized), channels: &core::Vector<i32>, hist: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), ranges: &core::Vector<f32>, scale: f64) -> Result<()> {
 	input_array_arg!(images);
 	input_array_arg!(hist);
 	output_array_arg!(dst);
@@ -2997,7 +2997,7 @@ pub fn calc_back_project(images: &dyn core::ToInputArray, channels: &core::Vecto
 /// ## C++ default parameters
 /// * accumulate: false
 #[inline]
-pub fn calc_hist(images: &dyn core::ToInputArray, channels: &core::Vector<i32>, mask: &dyn core::ToInputArray, hist: &mut dyn core::ToOutputArray, hist_size: &core::Vector<i32>, ranges: &core::Vector<f32>, accumulate: bool) -> Result<()> {
+pub fn calc_hist(images: &(impl core::ToInputArray + ?Sized), channels: &core::Vector<i32>, mask: &(impl core::ToInputArray + ?Sized), hist: &mut (impl core::ToOutputArray + ?Sized), hist_size: &core::Vector<i32>, ranges: &core::Vector<f32>, accumulate: bool) -> Result<()> {
 	input_array_arg!(images);
 	input_array_arg!(mask);
 	output_array_arg!(hist);
@@ -3026,7 +3026,7 @@ pub fn calc_hist(images: &dyn core::ToInputArray, channels: &core::Vector<i32>,
 /// * line_type: LINE_8
 /// * shift: 0
 #[inline]
-pub fn circle(img: &mut dyn core::ToInputOutputArray, center: core::Point, radius: i32, color: core::Scalar, thickness: i32, line_type: i32, shift: i32) -> Result<()> {
+pub fn circle(img: &mut (impl core::ToInputOutputArray + ?Sized), center: core::Point, radius: i32, color: core::Scalar, thickness: i32, line_type: i32, shift: i32) -> Result<()> {
 	input_output_array_arg!(img);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_circle_const__InputOutputArrayR_Point_int_const_ScalarR_int_int_int(img.as_raw__InputOutputArray(), center.opencv_as_extern(), radius, &color, thickness, line_type, shift, ocvrs_return.as_mut_ptr()) };
@@ -3143,7 +3143,7 @@ pub fn compare_hist_1(h1: &core::SparseMat, h2: &core::SparseMat, method: i32) -
 /// * H2: Second compared histogram of the same size as H1 .
 /// * method: Comparison method, see #HistCompMethods
 #[inline]
-pub fn compare_hist(h1: &dyn core::ToInputArray, h2: &dyn core::ToInputArray, method: i32) -> Result<f64> {
+pub fn compare_hist(h1: &(impl core::ToInputArray + ?Sized), h2: &(impl core::ToInputArray + ?Sized), method: i32) -> Result<f64> {
 	input_array_arg!(h1);
 	input_array_arg!(h2);
 	return_send!(via ocvrs_return);
@@ -3193,7 +3193,7 @@ pub fn compare_hist(h1: &dyn core::ToInputArray, h2: &dyn core::ToInputArray, me
 /// * connectivity: 8
 /// * ltype: CV_32S
 #[inline]
-pub fn connected_components_with_stats(image: &dyn core::ToInputArray, labels: &mut dyn core::ToOutputArray, stats: &mut dyn core::ToOutputArray, centroids: &mut dyn core::ToOutputArray, connectivity: i32, ltype: i32) -> Result<i32> {
+pub fn connected_components_with_stats(image: &(impl core::ToInputArray + ?Sized), labels: &mut (impl core::ToOutputArray + ?Sized), stats: &mut (impl core::ToOutputArray + ?Sized), centroids: &mut (impl core::ToOutputArray + ?Sized), connectivity: i32, ltype: i32) -> Result<i32> {
 	input_array_arg!(image);
 	output_array_arg!(labels);
 	output_array_arg!(stats);
@@ -3229,7 +3229,7 @@ pub fn connected_components_with_stats(image: &dyn core::ToInputArray, labels: &
 /// * ltype: output image label type. Currently CV_32S and CV_16U are supported.
 /// * ccltype: connected components algorithm type (see #ConnectedComponentsAlgorithmsTypes).
 #[inline]
-pub fn connected_components_with_stats_with_algorithm(image: &dyn core::ToInputArray, labels: &mut dyn core::ToOutputArray, stats: &mut dyn core::ToOutputArray, centroids: &mut dyn core::ToOutputArray, connectivity: i32, ltype: i32, ccltype: i32) -> Result<i32> {
+pub fn connected_components_with_stats_with_algorithm(image: &(impl core::ToInputArray + ?Sized), labels: &mut (impl core::ToOutputArray + ?Sized), stats: &mut (impl core::ToOutputArray + ?Sized), centroids: &mut (impl core::ToOutputArray + ?Sized), connectivity: i32, ltype: i32, ccltype: i32) -> Result<i32> {
 	input_array_arg!(image);
 	output_array_arg!(labels);
 	output_array_arg!(stats);
@@ -3272,7 +3272,7 @@ pub fn connected_components_with_stats_with_algorithm(image: &dyn core::ToInputA
 /// * connectivity: 8
 /// * ltype: CV_32S
 #[inline]
-pub fn connected_components(image: &dyn core::ToInputArray, labels: &mut dyn core::ToOutputArray, connectivity: i32, ltype: i32) -> Result<i32> {
+pub fn connected_components(image: &(impl core::ToInputArray + ?Sized), labels: &mut (impl core::ToOutputArray + ?Sized), connectivity: i32, ltype: i32) -> Result<i32> {
 	input_array_arg!(image);
 	output_array_arg!(labels);
 	return_send!(via ocvrs_return);
@@ -3301,7 +3301,7 @@ pub fn connected_components(image: &dyn core::ToInputArray, labels: &mut dyn cor
 /// * ltype: output image label type. Currently CV_32S and CV_16U are supported.
 /// * ccltype: connected components algorithm type (see the #ConnectedComponentsAlgorithmsTypes).
 #[inline]
-pub fn connected_components_with_algorithm(image: &dyn core::ToInputArray, labels: &mut dyn core::ToOutputArray, connectivity: i32, ltype: i32, ccltype: i32) -> Result<i32> {
+pub fn connected_components_with_algorithm(image: &(impl core::ToInputArray + ?Sized), labels: &mut (impl core::ToOutputArray + ?Sized), connectivity: i32, ltype: i32, ccltype: i32) -> Result<i32> {
 	input_array_arg!(image);
 	output_array_arg!(labels);
 	return_send!(via ocvrs_return);
@@ -3346,7 +3346,7 @@ pub fn connected_components_with_algorithm(image: &dyn core::ToInputArray, label
 /// ## C++ default parameters
 /// * oriented: false
 #[inline]
-pub fn contour_area(contour: &dyn core::ToInputArray, oriented: bool) -> Result<f64> {
+pub fn contour_area(contour: &(impl core::ToInputArray + ?Sized), oriented: bool) -> Result<f64> {
 	input_array_arg!(contour);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_contourArea_const__InputArrayR_bool(contour.as_raw__InputArray(), oriented, ocvrs_return.as_mut_ptr()) };
@@ -3389,7 +3389,7 @@ pub fn contour_area(contour: &dyn core::ToInputArray, oriented: bool) -> Result<
 /// ## C++ default parameters
 /// * nninterpolation: false
 #[inline]
-pub fn convert_maps(map1: &dyn core::ToInputArray, map2: &dyn core::ToInputArray, dstmap1: &mut dyn core::ToOutputArray, dstmap2: &mut dyn core::ToOutputArray, dstmap1type: i32, nninterpolation: bool) -> Result<()> {
+pub fn convert_maps(map1: &(impl core::ToInputArray + ?Sized), map2: &(impl core::ToInputArray + ?Sized), dstmap1: &mut (impl core::ToOutputArray + ?Sized), dstmap2: &mut (impl core::ToOutputArray + ?Sized), dstmap1type: i32, nninterpolation: bool) -> Result<()> {
 	input_array_arg!(map1);
 	input_array_arg!(map2);
 	output_array_arg!(dstmap1);
@@ -3434,7 +3434,7 @@ pub fn convert_maps(map1: &dyn core::ToInputArray, map2: &dyn core::ToInputArray
 /// * clockwise: false
 /// * return_points: true
 #[inline]
-pub fn convex_hull(points: &dyn core::ToInputArray, hull: &mut dyn core::ToOutputArray, clockwise: bool, return_points: bool) -> Result<()> {
+pub fn convex_hull(points: &(impl core::ToInputArray + ?Sized), hull: &mut (impl core::ToOutputArray + ?Sized), clockwise: bool, return_points: bool) -> Result<()> {
 	input_array_arg!(points);
 	output_array_arg!(hull);
 	return_send!(via ocvrs_return);
@@ -3462,7 +3462,7 @@ pub fn convex_hull(points: &dyn core::ToInputArray, hull: &mut dyn core::ToOutpu
 /// farthest contour point and the hull. That is, to get the floating-point value of the depth will be
 /// fixpt_depth/256.0.
 #[inline]
-pub fn convexity_defects(contour: &dyn core::ToInputArray, convexhull: &dyn core::ToInputArray, convexity_defects: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn convexity_defects(contour: &(impl core::ToInputArray + ?Sized), convexhull: &(impl core::ToInputArray + ?Sized), convexity_defects: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(contour);
 	input_array_arg!(convexhull);
 	output_array_arg!(convexity_defects);
@@ -3503,7 +3503,7 @@ pub fn convexity_defects(contour: &dyn core::ToInputArray, convexhull: &dyn core
 /// ## C++ default parameters
 /// * border_type: BORDER_DEFAULT
 #[inline]
-pub fn corner_eigen_vals_and_vecs(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, block_size: i32, ksize: i32, border_type: i32) -> Result<()> {
+pub fn corner_eigen_vals_and_vecs(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), block_size: i32, ksize: i32, border_type: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -3536,7 +3536,7 @@ pub fn corner_eigen_vals_and_vecs(src: &dyn core::ToInputArray, dst: &mut dyn co
 /// ## C++ default parameters
 /// * border_type: BORDER_DEFAULT
 #[inline]
-pub fn corner_harris(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, block_size: i32, ksize: i32, k: f64, border_type: i32) -> Result<()> {
+pub fn corner_harris(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), block_size: i32, ksize: i32, k: f64, border_type: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -3564,7 +3564,7 @@ pub fn corner_harris(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputA
 /// * ksize: 3
 /// * border_type: BORDER_DEFAULT
 #[inline]
-pub fn corner_min_eigen_val(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, block_size: i32, ksize: i32, border_type: i32) -> Result<()> {
+pub fn corner_min_eigen_val(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), block_size: i32, ksize: i32, border_type: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -3615,7 +3615,7 @@ pub fn corner_min_eigen_val(src: &dyn core::ToInputArray, dst: &mut dyn core::To
 /// the process of corner position refinement stops either after criteria.maxCount iterations or when
 /// the corner position moves by less than criteria.epsilon on some iteration.
 #[inline]
-pub fn corner_sub_pix(image: &dyn core::ToInputArray, corners: &mut dyn core::ToInputOutputArray, win_size: core::Size, zero_zone: core::Size, criteria: core::TermCriteria) -> Result<()> {
+pub fn corner_sub_pix(image: &(impl core::ToInputArray + ?Sized), corners: &mut (impl core::ToInputOutputArray + ?Sized), win_size: core::Size, zero_zone: core::Size, criteria: core::TermCriteria) -> Result<()> {
 	input_array_arg!(image);
 	input_output_array_arg!(corners);
 	return_send!(via ocvrs_return);
@@ -3684,7 +3684,7 @@ pub fn create_generalized_hough_guil() -> Result<core::Ptr<dyn crate::imgproc::G
 /// * winSize: The window size specifications (both width and height must be > 1)
 /// * type: Created array type
 #[inline]
-pub fn create_hanning_window(dst: &mut dyn core::ToOutputArray, win_size: core::Size, typ: i32) -> Result<()> {
+pub fn create_hanning_window(dst: &mut (impl core::ToOutputArray + ?Sized), win_size: core::Size, typ: i32) -> Result<()> {
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_createHanningWindow_const__OutputArrayR_Size_int(dst.as_raw__OutputArray(), win_size.opencv_as_extern(), typ, ocvrs_return.as_mut_ptr()) };
@@ -3746,7 +3746,7 @@ pub fn create_line_segment_detector(refine: i32, scale: f64, sigma_scale: f64, q
 /// - #COLOR_YUV2BGRA_NV21
 /// - #COLOR_YUV2RGBA_NV21
 #[inline]
-pub fn cvt_color_two_plane(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, code: i32) -> Result<()> {
+pub fn cvt_color_two_plane(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), code: i32) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
@@ -3803,7 +3803,7 @@ pub fn cvt_color_two_plane(src1: &dyn core::ToInputArray, src2: &dyn core::ToInp
 /// ## C++ default parameters
 /// * dst_cn: 0
 #[inline]
-pub fn cvt_color(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, code: i32, dst_cn: i32) -> Result<()> {
+pub fn cvt_color(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), code: i32, dst_cn: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -3847,7 +3847,7 @@ pub fn cvt_color(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 /// ## C++ default parameters
 /// * dst_cn: 0
 #[inline]
-pub fn demosaicing(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, code: i32, dst_cn: i32) -> Result<()> {
+pub fn demosaicing(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), code: i32, dst_cn: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -3886,7 +3886,7 @@ pub fn demosaicing(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArr
 /// * border_type: BORDER_CONSTANT
 /// * border_value: morphologyDefaultBorderValue()
 #[inline]
-pub fn dilate(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, kernel: &dyn core::ToInputArray, anchor: core::Point, iterations: i32, border_type: i32, border_value: core::Scalar) -> Result<()> {
+pub fn dilate(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), kernel: &(impl core::ToInputArray + ?Sized), anchor: core::Point, iterations: i32, border_type: i32, border_value: core::Scalar) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	input_array_arg!(kernel);
@@ -3953,7 +3953,7 @@ pub fn dilate(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, k
 /// ## C++ default parameters
 /// * label_type: DIST_LABEL_CCOMP
 #[inline]
-pub fn distance_transform_with_labels(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, labels: &mut dyn core::ToOutputArray, distance_type: i32, mask_size: i32, label_type: i32) -> Result<()> {
+pub fn distance_transform_with_labels(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), labels: &mut (impl core::ToOutputArray + ?Sized), distance_type: i32, mask_size: i32, label_type: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	output_array_arg!(labels);
@@ -4032,7 +4032,7 @@ pub fn distance_transform_with_labels(src: &dyn core::ToInputArray, dst: &mut dy
 /// ## C++ default parameters
 /// * dst_type: CV_32F
 #[inline]
-pub fn distance_transform(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, distance_type: i32, mask_size: i32, dst_type: i32) -> Result<()> {
+pub fn distance_transform(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), distance_type: i32, mask_size: i32, dst_type: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -4059,7 +4059,7 @@ pub fn distance_transform(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOu
 /// ## C++ default parameters
 /// * conj_b: false
 #[inline]
-pub fn div_spectrums(a: &dyn core::ToInputArray, b: &dyn core::ToInputArray, c: &mut dyn core::ToOutputArray, flags: i32, conj_b: bool) -> Result<()> {
+pub fn div_spectrums(a: &(impl core::ToInputArray + ?Sized), b: &(impl core::ToInputArray + ?Sized), c: &mut (impl core::ToOutputArray + ?Sized), flags: i32, conj_b: bool) -> Result<()> {
 	input_array_arg!(a);
 	input_array_arg!(b);
 	output_array_arg!(c);
@@ -4107,10 +4107,10 @@ pub fn div_spectrums(a: &dyn core::ToInputArray, b: &dyn core::ToInputArray, c:
 /// * max_level: INT_MAX
 /// * offset: Point()
 #[inline]
-pub fn draw_contours(image: &mut dyn core::ToInputOutputArray, contours: &dyn core::ToInputArray, contour_idx: i32, color: core::Scalar, thickness: i32, line_type: i32, hierarchy: &dyn core::ToInputArray, max_level: i32, offset: core::Point) -> Result<()> {
+pub fn draw_contours(image: &mut (impl core::ToInputOutputArray + ?Sized), contours: &(impl core::ToInputArray + ?Sized), contour_idx: i32, color: core::Scalar, thickness: i32, line_type: i32, hierarchy: Option<&(impl core::ToInputArray + ?Sized)>, max_level: i32, offset: core::Point) -> Result<()> {
 	input_output_array_arg!(image);
 	input_array_arg!(contours);
-	input_array_arg!(hierarchy);
+	input_array_arg!(nullable hierarchy);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_drawContours_const__InputOutputArrayR_const__InputArrayR_int_const_ScalarR_int_int_const__InputArrayR_int_Point(image.as_raw__InputOutputArray(), contours.as_raw__InputArray(), contour_idx, &color, thickness, line_type, hierarchy.as_raw__InputArray(), max_level, offset.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -4138,7 +4138,7 @@ pub fn draw_contours(image: &mut dyn core::ToInputOutputArray, contours: &dyn co
 /// * thickness: 1
 /// * line_type: 8
 #[inline]
-pub fn draw_marker(img: &mut dyn core::ToInputOutputArray, position: core::Point, color: core::Scalar, marker_type: i32, marker_size: i32, thickness: i32, line_type: i32) -> Result<()> {
+pub fn draw_marker(img: &mut (impl core::ToInputOutputArray + ?Sized), position: core::Point, color: core::Scalar, marker_type: i32, marker_size: i32, thickness: i32, line_type: i32) -> Result<()> {
 	input_output_array_arg!(img);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_drawMarker_const__InputOutputArrayR_Point_const_ScalarR_int_int_int_int(img.as_raw__InputOutputArray(), position.opencv_as_extern(), &color, marker_type, marker_size, thickness, line_type, ocvrs_return.as_mut_ptr()) };
@@ -4234,7 +4234,7 @@ pub fn ellipse_2_poly(center: core::Point, axes: core::Size, angle: i32, arc_sta
 /// * line_type: LINE_8
 /// * shift: 0
 #[inline]
-pub fn ellipse(img: &mut dyn core::ToInputOutputArray, center: core::Point, axes: core::Size, angle: f64, start_angle: f64, end_angle: f64, color: core::Scalar, thickness: i32, line_type: i32, shift: i32) -> Result<()> {
+pub fn ellipse(img: &mut (impl core::ToInputOutputArray + ?Sized), center: core::Point, axes: core::Size, angle: f64, start_angle: f64, end_angle: f64, color: core::Scalar, thickness: i32, line_type: i32, shift: i32) -> Result<()> {
 	input_output_array_arg!(img);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_ellipse_const__InputOutputArrayR_Point_Size_double_double_double_const_ScalarR_int_int_int(img.as_raw__InputOutputArray(), center.opencv_as_extern(), axes.opencv_as_extern(), angle, start_angle, end_angle, &color, thickness, line_type, shift, ocvrs_return.as_mut_ptr()) };
@@ -4283,7 +4283,7 @@ pub fn ellipse(img: &mut dyn core::ToInputOutputArray, center: core::Point, axes
 /// * thickness: 1
 /// * line_type: LINE_8
 #[inline]
-pub fn ellipse_rotated_rect(img: &mut dyn core::ToInputOutputArray, box_: &core::RotatedRect, color: core::Scalar, thickness: i32, line_type: i32) -> Result<()> {
+pub fn ellipse_rotated_rect(img: &mut (impl core::ToInputOutputArray + ?Sized), box_: &core::RotatedRect, color: core::Scalar, thickness: i32, line_type: i32) -> Result<()> {
 	input_output_array_arg!(img);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_ellipse_const__InputOutputArrayR_const_RotatedRectR_const_ScalarR_int_int(img.as_raw__InputOutputArray(), box_.as_raw_RotatedRect(), &color, thickness, line_type, ocvrs_return.as_mut_ptr()) };
@@ -4308,7 +4308,7 @@ pub fn ellipse_rotated_rect(img: &mut dyn core::ToInputOutputArray, box_: &core:
 /// * src: Source 8-bit single channel image.
 /// * dst: Destination image of the same size and type as src .
 #[inline]
-pub fn equalize_hist(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn equalize_hist(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -4348,7 +4348,7 @@ pub fn equalize_hist(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputA
 /// * border_type: BORDER_CONSTANT
 /// * border_value: morphologyDefaultBorderValue()
 #[inline]
-pub fn erode(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, kernel: &dyn core::ToInputArray, anchor: core::Point, iterations: i32, border_type: i32, border_value: core::Scalar) -> Result<()> {
+pub fn erode(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), kernel: &(impl core::ToInputArray + ?Sized), anchor: core::Point, iterations: i32, border_type: i32, border_value: core::Scalar) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	input_array_arg!(kernel);
@@ -4377,7 +4377,7 @@ pub fn erode(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, ke
 /// * line_type: LINE_8
 /// * shift: 0
 #[inline]
-pub fn fill_convex_poly(img: &mut dyn core::ToInputOutputArray, points: &dyn core::ToInputArray, color: core::Scalar, line_type: i32, shift: i32) -> Result<()> {
+pub fn fill_convex_poly(img: &mut (impl core::ToInputOutputArray + ?Sized), points: &(impl core::ToInputArray + ?Sized), color: core::Scalar, line_type: i32, shift: i32) -> Result<()> {
 	input_output_array_arg!(img);
 	input_array_arg!(points);
 	return_send!(via ocvrs_return);
@@ -4406,7 +4406,7 @@ pub fn fill_convex_poly(img: &mut dyn core::ToInputOutputArray, points: &dyn cor
 /// * shift: 0
 /// * offset: Point()
 #[inline]
-pub fn fill_poly(img: &mut dyn core::ToInputOutputArray, pts: &dyn core::ToInputArray, color: core::Scalar, line_type: i32, shift: i32, offset: core::Point) -> Result<()> {
+pub fn fill_poly(img: &mut (impl core::ToInputOutputArray + ?Sized), pts: &(impl core::ToInputArray + ?Sized), color: core::Scalar, line_type: i32, shift: i32, offset: core::Point) -> Result<()> {
 	input_output_array_arg!(img);
 	input_array_arg!(pts);
 	return_send!(via ocvrs_return);
@@ -4453,7 +4453,7 @@ pub fn fill_poly(img: &mut dyn core::ToInputOutputArray, pts: &dyn core::ToInput
 /// * delta: 0
 /// * border_type: BORDER_DEFAULT
 #[inline]
-pub fn filter_2d(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, ddepth: i32, kernel: &dyn core::ToInputArray, anchor: core::Point, delta: f64, border_type: i32) -> Result<()> {
+pub fn filter_2d(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), ddepth: i32, kernel: &(impl core::ToInputArray + ?Sized), anchor: core::Point, delta: f64, border_type: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	input_array_arg!(kernel);
@@ -4496,7 +4496,7 @@ pub fn filter_2d(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 /// ## C++ default parameters
 /// * offset: Point()
 #[inline]
-pub fn find_contours_with_hierarchy(image: &dyn core::ToInputArray, contours: &mut dyn core::ToOutputArray, hierarchy: &mut dyn core::ToOutputArray, mode: i32, method: i32, offset: core::Point) -> Result<()> {
+pub fn find_contours_with_hierarchy(image: &(impl core::ToInputArray + ?Sized), contours: &mut (impl core::ToOutputArray + ?Sized), hierarchy: &mut (impl core::ToOutputArray + ?Sized), mode: i32, method: i32, offset: core::Point) -> Result<()> {
 	input_array_arg!(image);
 	output_array_arg!(contours);
 	output_array_arg!(hierarchy);
@@ -4541,7 +4541,7 @@ pub fn find_contours_with_hierarchy(image: &dyn core::ToInputArray, contours: &m
 /// ## C++ default parameters
 /// * offset: Point()
 #[inline]
-pub fn find_contours(image: &dyn core::ToInputArray, contours: &mut dyn core::ToOutputArray, mode: i32, method: i32, offset: core::Point) -> Result<()> {
+pub fn find_contours(image: &(impl core::ToInputArray + ?Sized), contours: &mut (impl core::ToOutputArray + ?Sized), mode: i32, method: i32, offset: core::Point) -> Result<()> {
 	input_array_arg!(image);
 	output_array_arg!(contours);
 	return_send!(via ocvrs_return);
@@ -4587,7 +4587,7 @@ pub fn find_contours(image: &dyn core::ToInputArray, contours: &mut dyn core::To
 /// ## Parameters
 /// * points: Input 2D point set, stored in std::vector\<\> or Mat
 #[inline]
-pub fn fit_ellipse_ams(points: &dyn core::ToInputArray) -> Result<core::RotatedRect> {
+pub fn fit_ellipse_ams(points: &(impl core::ToInputArray + ?Sized)) -> Result<core::RotatedRect> {
 	input_array_arg!(points);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_fitEllipseAMS_const__InputArrayR(points.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
@@ -4640,7 +4640,7 @@ pub fn fit_ellipse_ams(points: &dyn core::ToInputArray) -> Result<core::RotatedR
 /// ## Parameters
 /// * points: Input 2D point set, stored in std::vector\<\> or Mat
 #[inline]
-pub fn fit_ellipse_direct(points: &dyn core::ToInputArray) -> Result<core::RotatedRect> {
+pub fn fit_ellipse_direct(points: &(impl core::ToInputArray + ?Sized)) -> Result<core::RotatedRect> {
 	input_array_arg!(points);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_fitEllipseDirect_const__InputArrayR(points.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
@@ -4661,7 +4661,7 @@ pub fn fit_ellipse_direct(points: &dyn core::ToInputArray) -> Result<core::Rotat
 /// ## Parameters
 /// * points: Input 2D point set, stored in std::vector\<\> or Mat
 #[inline]
-pub fn fit_ellipse(points: &dyn core::ToInputArray) -> Result<core::RotatedRect> {
+pub fn fit_ellipse(points: &(impl core::ToInputArray + ?Sized)) -> Result<core::RotatedRect> {
 	input_array_arg!(points);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_fitEllipse_const__InputArrayR(points.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
@@ -4706,7 +4706,7 @@ pub fn fit_ellipse(points: &dyn core::ToInputArray) -> Result<core::RotatedRect>
 /// * reps: Sufficient accuracy for the radius (distance between the coordinate origin and the line).
 /// * aeps: Sufficient accuracy for the angle. 0.01 would be a good default value for reps and aeps.
 #[inline]
-pub fn fit_line(points: &dyn core::ToInputArray, line: &mut dyn core::ToOutputArray, dist_type: i32, param: f64, reps: f64, aeps: f64) -> Result<()> {
+pub fn fit_line(points: &(impl core::ToInputArray + ?Sized), line: &mut (impl core::ToOutputArray + ?Sized), dist_type: i32, param: f64, reps: f64, aeps: f64) -> Result<()> {
 	input_array_arg!(points);
 	output_array_arg!(line);
 	return_send!(via ocvrs_return);
@@ -4801,7 +4801,7 @@ pub fn fit_line(points: &dyn core::ToInputArray, line: &mut dyn core::ToOutputAr
 /// * up_diff: Scalar()
 /// * flags: 4
 #[inline]
-pub fn flood_fill(image: &mut dyn core::ToInputOutputArray, seed_point: core::Point, new_val: core::Scalar, rect: &mut core::Rect, lo_diff: core::Scalar, up_diff: core::Scalar, flags: i32) -> Result<i32> {
+pub fn flood_fill(image: &mut (impl core::ToInputOutputArray + ?Sized), seed_point: core::Point, new_val: core::Scalar, rect: &mut core::Rect, lo_diff: core::Scalar, up_diff: core::Scalar, flags: i32) -> Result<i32> {
 	input_output_array_arg!(image);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_floodFill_const__InputOutputArrayR_Point_Scalar_RectX_Scalar_Scalar_int(image.as_raw__InputOutputArray(), seed_point.opencv_as_extern(), new_val.opencv_as_extern(), rect, lo_diff.opencv_as_extern(), up_diff.opencv_as_extern(), flags, ocvrs_return.as_mut_ptr()) };
@@ -4890,7 +4890,7 @@ pub fn flood_fill(image: &mut dyn core::ToInputOutputArray, seed_point: core::Po
 /// * up_diff: Scalar()
 /// * flags: 4
 #[inline]
-pub fn flood_fill_mask(image: &mut dyn core::ToInputOutputArray, mask: &mut dyn core::ToInputOutputArray, seed_point: core::Point, new_val: core::Scalar, rect: &mut core::Rect, lo_diff: core::Scalar, up_diff: core::Scalar, flags: i32) -> Result<i32> {
+pub fn flood_fill_mask(image: &mut (impl core::ToInputOutputArray + ?Sized), mask: &mut (impl core::ToInputOutputArray + ?Sized), seed_point: core::Point, new_val: core::Scalar, rect: &mut core::Rect, lo_diff: core::Scalar, up_diff: core::Scalar, flags: i32) -> Result<i32> {
 	input_output_array_arg!(image);
 	input_output_array_arg!(mask);
 	return_send!(via ocvrs_return);
@@ -4926,7 +4926,7 @@ pub fn get_affine_transform_slice(src: &[core::Point2f], dst: &[core::Point2f])
 }
 
 #[inline]
-pub fn get_affine_transform(src: &dyn core::ToInputArray, dst: &dyn core::ToInputArray) -> Result<core::Mat> {
+pub fn get_affine_transform(src: &(impl core::ToInputArray + ?Sized), dst: &(impl core::ToInputArray + ?Sized)) -> Result<core::Mat> {
 	input_array_arg!(src);
 	input_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -4960,7 +4960,7 @@ pub fn get_affine_transform(src: &dyn core::ToInputArray, dst: &dyn core::ToInpu
 /// * normalize: false
 /// * ktype: CV_32F
 #[inline]
-pub fn get_deriv_kernels(kx: &mut dyn core::ToOutputArray, ky: &mut dyn core::ToOutputArray, dx: i32, dy: i32, ksize: i32, normalize: bool, ktype: i32) -> Result<()> {
+pub fn get_deriv_kernels(kx: &mut (impl core::ToOutputArray + ?Sized), ky: &mut (impl core::ToOutputArray + ?Sized), dx: i32, dy: i32, ksize: i32, normalize: bool, ktype: i32) -> Result<()> {
 	output_array_arg!(kx);
 	output_array_arg!(ky);
 	return_send!(via ocvrs_return);
@@ -5102,7 +5102,7 @@ pub fn get_perspective_transform_slice(src: &[core::Point2f], dst: &[core::Point
 /// ## C++ default parameters
 /// * solve_method: DECOMP_LU
 #[inline]
-pub fn get_perspective_transform(src: &dyn core::ToInputArray, dst: &dyn core::ToInputArray, solve_method: i32) -> Result<core::Mat> {
+pub fn get_perspective_transform(src: &(impl core::ToInputArray + ?Sized), dst: &(impl core::ToInputArray + ?Sized), solve_method: i32) -> Result<core::Mat> {
 	input_array_arg!(src);
 	input_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -5137,7 +5137,7 @@ pub fn get_perspective_transform(src: &dyn core::ToInputArray, dst: &dyn core::T
 /// ## C++ default parameters
 /// * patch_type: -1
 #[inline]
-pub fn get_rect_sub_pix(image: &dyn core::ToInputArray, patch_size: core::Size, center: core::Point2f, patch: &mut dyn core::ToOutputArray, patch_type: i32) -> Result<()> {
+pub fn get_rect_sub_pix(image: &(impl core::ToInputArray + ?Sized), patch_size: core::Size, center: core::Point2f, patch: &mut (impl core::ToOutputArray + ?Sized), patch_type: i32) -> Result<()> {
 	input_array_arg!(image);
 	output_array_arg!(patch);
 	return_send!(via ocvrs_return);
@@ -5303,7 +5303,7 @@ pub fn get_text_size(text: &str, font_face: i32, font_scale: f64, thickness: i32
 /// * use_harris_detector: false
 /// * k: 0.04
 #[inline]
-pub fn good_features_to_track_with_quality(image: &dyn core::ToInputArray, corners: &mut dyn core::ToOutputArray, max_corners: i32, quality_level: f64, min_distance: f64, mask: &dyn core::ToInputArray, corners_quality: &mut dyn core::ToOutputArray, block_size: i32, gradient_size: i32, use_harris_detector: bool, k: f64) -> Result<()> {
+pub fn good_features_to_track_with_quality(image: &(impl core::ToInputArray + ?Sized), corners: &mut (impl core::ToOutputArray + ?Sized), max_corners: i32, quality_level: f64, min_distance: f64, mask: &(impl core::ToInputArray + ?Sized), corners_quality: &mut (impl core::ToOutputArray + ?Sized), block_size: i32, gradient_size: i32, use_harris_detector: bool, k: f64) -> Result<()> {
 	input_array_arg!(image);
 	output_array_arg!(corners);
 	input_array_arg!(mask);
@@ -5366,10 +5366,10 @@ pub fn good_features_to_track_with_quality(image: &dyn core::ToInputArray, corne
 /// * use_harris_detector: false
 /// * k: 0.04
 #[inline]
-pub fn good_features_to_track(image: &dyn core::ToInputArray, corners: &mut dyn core::ToOutputArray, max_corners: i32, quality_level: f64, min_distance: f64, mask: &dyn core::ToInputArray, block_size: i32, use_harris_detector: bool, k: f64) -> Result<()> {
+pub fn good_features_to_track(image: &(impl core::ToInputArray + ?Sized), corners: &mut (impl core::ToOutputArray + ?Sized), max_corners: i32, quality_level: f64, min_distance: f64, mask: Option<&(impl core::ToInputArray + ?Sized)>, block_size: i32, use_harris_detector: bool, k: f64) -> Result<()> {
 	input_array_arg!(image);
 	output_array_arg!(corners);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_goodFeaturesToTrack_const__InputArrayR_const__OutputArrayR_int_double_double_const__InputArrayR_int_bool_double(image.as_raw__InputArray(), corners.as_raw__OutputArray(), max_corners, quality_level, min_distance, mask.as_raw__InputArray(), block_size, use_harris_detector, k, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -5381,7 +5381,7 @@ pub fn good_features_to_track(image: &dyn core::ToInputArray, corners: &mut dyn
 /// * use_harris_detector: false
 /// * k: 0.04
 #[inline]
-pub fn good_features_to_track_with_gradient(image: &dyn core::ToInputArray, corners: &mut dyn core::ToOutputArray, max_corners: i32, quality_level: f64, min_distance: f64, mask: &dyn core::ToInputArray, block_size: i32, gradient_size: i32, use_harris_detector: bool, k: f64) -> Result<()> {
+pub fn good_features_to_track_with_gradient(image: &(impl core::ToInputArray + ?Sized), corners: &mut (impl core::ToOutputArray + ?Sized), max_corners: i32, quality_level: f64, min_distance: f64, mask: &(impl core::ToInputArray + ?Sized), block_size: i32, gradient_size: i32, use_harris_detector: bool, k: f64) -> Result<()> {
 	input_array_arg!(image);
 	output_array_arg!(corners);
 	input_array_arg!(mask);
@@ -5414,7 +5414,7 @@ pub fn good_features_to_track_with_gradient(image: &dyn core::ToInputArray, corn
 /// ## C++ default parameters
 /// * mode: GC_EVAL
 #[inline]
-pub fn grab_cut(img: &dyn core::ToInputArray, mask: &mut dyn core::ToInputOutputArray, rect: core::Rect, bgd_model: &mut dyn core::ToInputOutputArray, fgd_model: &mut dyn core::ToInputOutputArray, iter_count: i32, mode: i32) -> Result<()> {
+pub fn grab_cut(img: &(impl core::ToInputArray + ?Sized), mask: &mut (impl core::ToInputOutputArray + ?Sized), rect: core::Rect, bgd_model: &mut (impl core::ToInputOutputArray + ?Sized), fgd_model: &mut (impl core::ToInputOutputArray + ?Sized), iter_count: i32, mode: i32) -> Result<()> {
 	input_array_arg!(img);
 	input_output_array_arg!(mask);
 	input_output_array_arg!(bgd_model);
@@ -5465,7 +5465,7 @@ pub fn grab_cut(img: &dyn core::ToInputArray, mask: &mut dyn core::ToInputOutput
 /// * sdepth: -1
 /// * sqdepth: -1
 #[inline]
-pub fn integral3(src: &dyn core::ToInputArray, sum: &mut dyn core::ToOutputArray, sqsum: &mut dyn core::ToOutputArray, tilted: &mut dyn core::ToOutputArray, sdepth: i32, sqdepth: i32) -> Result<()> {
+pub fn integral3(src: &(impl core::ToInputArray + ?Sized), sum: &mut (impl core::ToOutputArray + ?Sized), sqsum: &mut (impl core::ToOutputArray + ?Sized), tilted: &mut (impl core::ToOutputArray + ?Sized), sdepth: i32, sqdepth: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(sum);
 	output_array_arg!(sqsum);
@@ -5518,7 +5518,7 @@ pub fn integral3(src: &dyn core::ToInputArray, sum: &mut dyn core::ToOutputArray
 /// * sdepth: -1
 /// * sqdepth: -1
 #[inline]
-pub fn integral2(src: &dyn core::ToInputArray, sum: &mut dyn core::ToOutputArray, sqsum: &mut dyn core::ToOutputArray, sdepth: i32, sqdepth: i32) -> Result<()> {
+pub fn integral2(src: &(impl core::ToInputArray + ?Sized), sum: &mut (impl core::ToOutputArray + ?Sized), sqsum: &mut (impl core::ToOutputArray + ?Sized), sdepth: i32, sqdepth: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(sum);
 	output_array_arg!(sqsum);
@@ -5569,7 +5569,7 @@ pub fn integral2(src: &dyn core::ToInputArray, sum: &mut dyn core::ToOutputArray
 /// ## C++ default parameters
 /// * sdepth: -1
 #[inline]
-pub fn integral(src: &dyn core::ToInputArray, sum: &mut dyn core::ToOutputArray, sdepth: i32) -> Result<()> {
+pub fn integral(src: &(impl core::ToInputArray + ?Sized), sum: &mut (impl core::ToOutputArray + ?Sized), sdepth: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(sum);
 	return_send!(via ocvrs_return);
@@ -5598,7 +5598,7 @@ pub fn integral(src: &dyn core::ToInputArray, sum: &mut dyn core::ToOutputArray,
 /// ## C++ default parameters
 /// * handle_nested: true
 #[inline]
-pub fn intersect_convex_convex(p1: &dyn core::ToInputArray, p2: &dyn core::ToInputArray, p12: &mut dyn core::ToOutputArray, handle_nested: bool) -> Result<f32> {
+pub fn intersect_convex_convex(p1: &(impl core::ToInputArray + ?Sized), p2: &(impl core::ToInputArray + ?Sized), p12: &mut (impl core::ToOutputArray + ?Sized), handle_nested: bool) -> Result<f32> {
 	input_array_arg!(p1);
 	input_array_arg!(p2);
 	output_array_arg!(p12);
@@ -5621,7 +5621,7 @@ pub fn intersect_convex_convex(p1: &dyn core::ToInputArray, p2: &dyn core::ToInp
 /// * M: Original affine transformation.
 /// * iM: Output reverse affine transformation.
 #[inline]
-pub fn invert_affine_transform(m: &dyn core::ToInputArray, i_m: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn invert_affine_transform(m: &(impl core::ToInputArray + ?Sized), i_m: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(m);
 	output_array_arg!(i_m);
 	return_send!(via ocvrs_return);
@@ -5639,7 +5639,7 @@ pub fn invert_affine_transform(m: &dyn core::ToInputArray, i_m: &mut dyn core::T
 /// ## Parameters
 /// * contour: Input vector of 2D points, stored in std::vector\<\> or Mat
 #[inline]
-pub fn is_contour_convex(contour: &dyn core::ToInputArray) -> Result<bool> {
+pub fn is_contour_convex(contour: &(impl core::ToInputArray + ?Sized)) -> Result<bool> {
 	input_array_arg!(contour);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_isContourConvex_const__InputArrayR(contour.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
@@ -5669,7 +5669,7 @@ pub fn is_contour_convex(contour: &dyn core::ToInputArray) -> Result<bool> {
 /// * line_type: LINE_8
 /// * shift: 0
 #[inline]
-pub fn line(img: &mut dyn core::ToInputOutputArray, pt1: core::Point, pt2: core::Point, color: core::Scalar, thickness: i32, line_type: i32, shift: i32) -> Result<()> {
+pub fn line(img: &mut (impl core::ToInputOutputArray + ?Sized), pt1: core::Point, pt2: core::Point, color: core::Scalar, thickness: i32, line_type: i32, shift: i32) -> Result<()> {
 	input_output_array_arg!(img);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_line_const__InputOutputArrayR_Point_Point_const_ScalarR_int_int_int(img.as_raw__InputOutputArray(), pt1.opencv_as_extern(), pt2.opencv_as_extern(), &color, thickness, line_type, shift, ocvrs_return.as_mut_ptr()) };
@@ -5710,7 +5710,7 @@ pub fn line(img: &mut dyn core::ToInputOutputArray, pt1: core::Point, pt2: core:
 /// @endinternal
 #[deprecated = "This function produces same result as cv::warpPolar(src, dst, src.size(), center, maxRadius, flags)"]
 #[inline]
-pub fn linear_polar(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, center: core::Point2f, max_radius: f64, flags: i32) -> Result<()> {
+pub fn linear_polar(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), center: core::Point2f, max_radius: f64, flags: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -5753,7 +5753,7 @@ pub fn linear_polar(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputAr
 /// @endinternal
 #[deprecated = "This function produces same result as cv::warpPolar(src, dst, src.size(), center, maxRadius, flags+WARP_POLAR_LOG);"]
 #[inline]
-pub fn log_polar(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, center: core::Point2f, m: f64, flags: i32) -> Result<()> {
+pub fn log_polar(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), center: core::Point2f, m: f64, flags: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -5773,7 +5773,7 @@ pub fn log_polar(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 /// * method: Comparison method, see #ShapeMatchModes
 /// * parameter: Method-specific parameter (not supported now).
 #[inline]
-pub fn match_shapes(contour1: &dyn core::ToInputArray, contour2: &dyn core::ToInputArray, method: i32, parameter: f64) -> Result<f64> {
+pub fn match_shapes(contour1: &(impl core::ToInputArray + ?Sized), contour2: &(impl core::ToInputArray + ?Sized), method: i32, parameter: f64) -> Result<f64> {
 	input_array_arg!(contour1);
 	input_array_arg!(contour2);
 	return_send!(via ocvrs_return);
@@ -5815,11 +5815,11 @@ pub fn match_shapes(contour1: &dyn core::ToInputArray, contour2: &dyn core::ToIn
 /// ## C++ default parameters
 /// * mask: noArray()
 #[inline]
-pub fn match_template(image: &dyn core::ToInputArray, templ: &dyn core::ToInputArray, result: &mut dyn core::ToOutputArray, method: i32, mask: &dyn core::ToInputArray) -> Result<()> {
+pub fn match_template(image: &(impl core::ToInputArray + ?Sized), templ: &(impl core::ToInputArray + ?Sized), result: &mut (impl core::ToOutputArray + ?Sized), method: i32, mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(image);
 	input_array_arg!(templ);
 	output_array_arg!(result);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_matchTemplate_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_const__InputArrayR(image.as_raw__InputArray(), templ.as_raw__InputArray(), result.as_raw__OutputArray(), method, mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -5843,7 +5843,7 @@ pub fn match_template(image: &dyn core::ToInputArray, templ: &dyn core::ToInputA
 /// ## See also
 /// bilateralFilter, blur, boxFilter, GaussianBlur
 #[inline]
-pub fn median_blur(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, ksize: i32) -> Result<()> {
+pub fn median_blur(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), ksize: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -5862,7 +5862,7 @@ pub fn median_blur(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArr
 /// ## Parameters
 /// * points: Input vector of 2D points, stored in std::vector\<\> or Mat
 #[inline]
-pub fn min_area_rect(points: &dyn core::ToInputArray) -> Result<core::RotatedRect> {
+pub fn min_area_rect(points: &(impl core::ToInputArray + ?Sized)) -> Result<core::RotatedRect> {
 	input_array_arg!(points);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_minAreaRect_const__InputArrayR(points.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
@@ -5881,7 +5881,7 @@ pub fn min_area_rect(points: &dyn core::ToInputArray) -> Result<core::RotatedRec
 /// * center: Output center of the circle.
 /// * radius: Output radius of the circle.
 #[inline]
-pub fn min_enclosing_circle(points: &dyn core::ToInputArray, center: &mut core::Point2f, radius: &mut f32) -> Result<()> {
+pub fn min_enclosing_circle(points: &(impl core::ToInputArray + ?Sized), center: &mut core::Point2f, radius: &mut f32) -> Result<()> {
 	input_array_arg!(points);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_minEnclosingCircle_const__InputArrayR_Point2fR_floatR(points.as_raw__InputArray(), center, radius, ocvrs_return.as_mut_ptr()) };
@@ -5910,7 +5910,7 @@ pub fn min_enclosing_circle(points: &dyn core::ToInputArray, center: &mut core::
 /// * triangle: Output vector of three 2D points defining the vertices of the triangle. The depth
 /// of the OutputArray must be CV_32F.
 #[inline]
-pub fn min_enclosing_triangle(points: &dyn core::ToInputArray, triangle: &mut dyn core::ToOutputArray) -> Result<f64> {
+pub fn min_enclosing_triangle(points: &(impl core::ToInputArray + ?Sized), triangle: &mut (impl core::ToOutputArray + ?Sized)) -> Result<f64> {
 	input_array_arg!(points);
 	output_array_arg!(triangle);
 	return_send!(via ocvrs_return);
@@ -5942,7 +5942,7 @@ pub fn min_enclosing_triangle(points: &dyn core::ToInputArray, triangle: &mut dy
 /// ## C++ default parameters
 /// * binary_image: false
 #[inline]
-pub fn moments(array: &dyn core::ToInputArray, binary_image: bool) -> Result<core::Moments> {
+pub fn moments(array: &(impl core::ToInputArray + ?Sized), binary_image: bool) -> Result<core::Moments> {
 	input_array_arg!(array);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_moments_const__InputArrayR_bool(array.as_raw__InputArray(), binary_image, ocvrs_return.as_mut_ptr()) };
@@ -5994,7 +5994,7 @@ pub fn morphology_default_border_value() -> Result<core::Scalar> {
 /// * border_type: BORDER_CONSTANT
 /// * border_value: morphologyDefaultBorderValue()
 #[inline]
-pub fn morphology_ex(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, op: i32, kernel: &dyn core::ToInputArray, anchor: core::Point, iterations: i32, border_type: i32, border_value: core::Scalar) -> Result<()> {
+pub fn morphology_ex(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), op: i32, kernel: &(impl core::ToInputArray + ?Sized), anchor: core::Point, iterations: i32, border_type: i32, border_value: core::Scalar) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	input_array_arg!(kernel);
@@ -6046,10 +6046,10 @@ pub fn morphology_ex(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputA
 /// * window: noArray()
 /// * response: 0
 #[inline]
-pub fn phase_correlate(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, window: &dyn core::ToInputArray, response: &mut f64) -> Result<core::Point2d> {
+pub fn phase_correlate(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), window: Option<&(impl core::ToInputArray + ?Sized)>, response: &mut f64) -> Result<core::Point2d> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
-	input_array_arg!(window);
+	input_array_arg!(nullable window);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_phaseCorrelate_const__InputArrayR_const__InputArrayR_const__InputArrayR_doubleX(src1.as_raw__InputArray(), src2.as_raw__InputArray(), window.as_raw__InputArray(), response, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -6074,7 +6074,7 @@ pub fn phase_correlate(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputAr
 /// * measureDist: If true, the function estimates the signed distance from the point to the
 /// nearest contour edge. Otherwise, the function only checks if the point is inside a contour or not.
 #[inline]
-pub fn point_polygon_test(contour: &dyn core::ToInputArray, pt: core::Point2f, measure_dist: bool) -> Result<f64> {
+pub fn point_polygon_test(contour: &(impl core::ToInputArray + ?Sized), pt: core::Point2f, measure_dist: bool) -> Result<f64> {
 	input_array_arg!(contour);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_pointPolygonTest_const__InputArrayR_Point2f_bool(contour.as_raw__InputArray(), pt.opencv_as_extern(), measure_dist, ocvrs_return.as_mut_ptr()) };
@@ -6102,7 +6102,7 @@ pub fn point_polygon_test(contour: &dyn core::ToInputArray, pt: core::Point2f, m
 /// * line_type: LINE_8
 /// * shift: 0
 #[inline]
-pub fn polylines(img: &mut dyn core::ToInputOutputArray, pts: &dyn core::ToInputArray, is_closed: bool, color: core::Scalar, thickness: i32, line_type: i32, shift: i32) -> Result<()> {
+pub fn polylines(img: &mut (impl core::ToInputOutputArray + ?Sized), pts: &(impl core::ToInputArray + ?Sized), is_closed: bool, color: core::Scalar, thickness: i32, line_type: i32, shift: i32) -> Result<()> {
 	input_output_array_arg!(img);
 	input_array_arg!(pts);
 	return_send!(via ocvrs_return);
@@ -6140,7 +6140,7 @@ pub fn polylines(img: &mut dyn core::ToInputOutputArray, pts: &dyn core::ToInput
 /// ## C++ default parameters
 /// * border_type: BORDER_DEFAULT
 #[inline]
-pub fn pre_corner_detect(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, ksize: i32, border_type: i32) -> Result<()> {
+pub fn pre_corner_detect(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), ksize: i32, border_type: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -6173,7 +6173,7 @@ pub fn pre_corner_detect(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOut
 /// * line_type: LINE_8
 /// * bottom_left_origin: false
 #[inline]
-pub fn put_text(img: &mut dyn core::ToInputOutputArray, text: &str, org: core::Point, font_face: i32, font_scale: f64, color: core::Scalar, thickness: i32, line_type: i32, bottom_left_origin: bool) -> Result<()> {
+pub fn put_text(img: &mut (impl core::ToInputOutputArray + ?Sized), text: &str, org: core::Point, font_face: i32, font_scale: f64, color: core::Scalar, thickness: i32, line_type: i32, bottom_left_origin: bool) -> Result<()> {
 	input_output_array_arg!(img);
 	extern_container_arg!(text);
 	return_send!(via ocvrs_return);
@@ -6207,7 +6207,7 @@ pub fn put_text(img: &mut dyn core::ToInputOutputArray, text: &str, org: core::P
 /// * dstsize: Size()
 /// * border_type: BORDER_DEFAULT
 #[inline]
-pub fn pyr_down(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, dstsize: core::Size, border_type: i32) -> Result<()> {
+pub fn pyr_down(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), dstsize: core::Size, border_type: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -6258,7 +6258,7 @@ pub fn pyr_down(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray,
 /// * max_level: 1
 /// * termcrit: TermCriteria(TermCriteria::MAX_ITER+TermCriteria::EPS,5,1)
 #[inline]
-pub fn pyr_mean_shift_filtering(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, sp: f64, sr: f64, max_level: i32, termcrit: core::TermCriteria) -> Result<()> {
+pub fn pyr_mean_shift_filtering(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), sp: f64, sr: f64, max_level: i32, termcrit: core::TermCriteria) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -6290,7 +6290,7 @@ pub fn pyr_mean_shift_filtering(src: &dyn core::ToInputArray, dst: &mut dyn core
 /// * dstsize: Size()
 /// * border_type: BORDER_DEFAULT
 #[inline]
-pub fn pyr_up(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, dstsize: core::Size, border_type: i32) -> Result<()> {
+pub fn pyr_up(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), dstsize: core::Size, border_type: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -6320,7 +6320,7 @@ pub fn pyr_up(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, d
 /// * line_type: LINE_8
 /// * shift: 0
 #[inline]
-pub fn rectangle_points(img: &mut dyn core::ToInputOutputArray, pt1: core::Point, pt2: core::Point, color: core::Scalar, thickness: i32, line_type: i32, shift: i32) -> Result<()> {
+pub fn rectangle_points(img: &mut (impl core::ToInputOutputArray + ?Sized), pt1: core::Point, pt2: core::Point, color: core::Scalar, thickness: i32, line_type: i32, shift: i32) -> Result<()> {
 	input_output_array_arg!(img);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_rectangle_const__InputOutputArrayR_Point_Point_const_ScalarR_int_int_int(img.as_raw__InputOutputArray(), pt1.opencv_as_extern(), pt2.opencv_as_extern(), &color, thickness, line_type, shift, ocvrs_return.as_mut_ptr()) };
@@ -6355,7 +6355,7 @@ pub fn rectangle_points(img: &mut dyn core::ToInputOutputArray, pt1: core::Point
 /// * line_type: LINE_8
 /// * shift: 0
 #[inline]
-pub fn rectangle(img: &mut dyn core::ToInputOutputArray, rec: core::Rect, color: core::Scalar, thickness: i32, line_type: i32, shift: i32) -> Result<()> {
+pub fn rectangle(img: &mut (impl core::ToInputOutputArray + ?Sized), rec: core::Rect, color: core::Scalar, thickness: i32, line_type: i32, shift: i32) -> Result<()> {
 	input_output_array_arg!(img);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_rectangle_const__InputOutputArrayR_Rect_const_ScalarR_int_int_int(img.as_raw__InputOutputArray(), rec.opencv_as_extern(), &color, thickness, line_type, shift, ocvrs_return.as_mut_ptr()) };
@@ -6402,7 +6402,7 @@ pub fn rectangle(img: &mut dyn core::ToInputOutputArray, rec: core::Rect, color:
 /// * border_mode: BORDER_CONSTANT
 /// * border_value: Scalar()
 #[inline]
-pub fn remap(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, map1: &dyn core::ToInputArray, map2: &dyn core::ToInputArray, interpolation: i32, border_mode: i32, border_value: core::Scalar) -> Result<()> {
+pub fn remap(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), map1: &(impl core::ToInputArray + ?Sized), map2: &(impl core::ToInputArray + ?Sized), interpolation: i32, border_mode: i32, border_value: core::Scalar) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	input_array_arg!(map1);
@@ -6456,7 +6456,7 @@ pub fn remap(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, ma
 /// * fy: 0
 /// * interpolation: INTER_LINEAR
 #[inline]
-pub fn resize(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, dsize: core::Size, fx: f64, fy: f64, interpolation: i32) -> Result<()> {
+pub fn resize(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), dsize: core::Size, fx: f64, fy: f64, interpolation: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -6483,7 +6483,7 @@ pub fn resize(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, d
 /// ## Returns
 /// One of #RectanglesIntersectTypes
 #[inline]
-pub fn rotated_rectangle_intersection(rect1: &core::RotatedRect, rect2: &core::RotatedRect, intersecting_region: &mut dyn core::ToOutputArray) -> Result<i32> {
+pub fn rotated_rectangle_intersection(rect1: &core::RotatedRect, rect2: &core::RotatedRect, intersecting_region: &mut (impl core::ToOutputArray + ?Sized)) -> Result<i32> {
 	output_array_arg!(intersecting_region);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_rotatedRectangleIntersection_const_RotatedRectR_const_RotatedRectR_const__OutputArrayR(rect1.as_raw_RotatedRect(), rect2.as_raw_RotatedRect(), intersecting_region.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
@@ -6516,7 +6516,7 @@ pub fn rotated_rectangle_intersection(rect1: &core::RotatedRect, rect2: &core::R
 /// * delta: 0
 /// * border_type: BORDER_DEFAULT
 #[inline]
-pub fn sep_filter_2d(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, ddepth: i32, kernel_x: &dyn core::ToInputArray, kernel_y: &dyn core::ToInputArray, anchor: core::Point, delta: f64, border_type: i32) -> Result<()> {
+pub fn sep_filter_2d(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), ddepth: i32, kernel_x: &(impl core::ToInputArray + ?Sized), kernel_y: &(impl core::ToInputArray + ?Sized), anchor: core::Point, delta: f64, border_type: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	input_array_arg!(kernel_x);
@@ -6552,7 +6552,7 @@ pub fn sep_filter_2d(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputA
 /// * ksize: 3
 /// * border_type: BORDER_DEFAULT
 #[inline]
-pub fn spatial_gradient(src: &dyn core::ToInputArray, dx: &mut dyn core::ToOutputArray, dy: &mut dyn core::ToOutputArray, ksize: i32, border_type: i32) -> Result<()> {
+pub fn spatial_gradient(src: &(impl core::ToInputArray + ?Sized), dx: &mut (impl core::ToOutputArray + ?Sized), dy: &mut (impl core::ToOutputArray + ?Sized), ksize: i32, border_type: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dx);
 	output_array_arg!(dy);
@@ -6588,7 +6588,7 @@ pub fn spatial_gradient(src: &dyn core::ToInputArray, dx: &mut dyn core::ToOutpu
 /// * normalize: true
 /// * border_type: BORDER_DEFAULT
 #[inline]
-pub fn sqr_box_filter(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, ddepth: i32, ksize: core::Size, anchor: core::Point, normalize: bool, border_type: i32) -> Result<()> {
+pub fn sqr_box_filter(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), ddepth: i32, ksize: core::Size, anchor: core::Point, normalize: bool, border_type: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -6625,7 +6625,7 @@ pub fn sqr_box_filter(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutput
 /// ## See also
 /// adaptiveThreshold, findContours, compare, min, max
 #[inline]
-pub fn threshold(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, thresh: f64, maxval: f64, typ: i32) -> Result<f64> {
+pub fn threshold(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), thresh: f64, maxval: f64, typ: i32) -> Result<f64> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -6665,7 +6665,7 @@ pub fn threshold(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 /// * border_mode: BORDER_CONSTANT
 /// * border_value: Scalar()
 #[inline]
-pub fn warp_affine(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, m: &dyn core::ToInputArray, dsize: core::Size, flags: i32, border_mode: i32, border_value: core::Scalar) -> Result<()> {
+pub fn warp_affine(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), m: &(impl core::ToInputArray + ?Sized), dsize: core::Size, flags: i32, border_mode: i32, border_value: core::Scalar) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	input_array_arg!(m);
@@ -6703,7 +6703,7 @@ pub fn warp_affine(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArr
 /// * border_mode: BORDER_CONSTANT
 /// * border_value: Scalar()
 #[inline]
-pub fn warp_perspective(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, m: &dyn core::ToInputArray, dsize: core::Size, flags: i32, border_mode: i32, border_value: core::Scalar) -> Result<()> {
+pub fn warp_perspective(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), m: &(impl core::ToInputArray + ?Sized), dsize: core::Size, flags: i32, border_mode: i32, border_value: core::Scalar) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	input_array_arg!(m);
@@ -6779,7 +6779,7 @@ pub fn warp_perspective(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutp
 /// ## See also
 /// cv::remap
 #[inline]
-pub fn warp_polar(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, dsize: core::Size, center: core::Point2f, max_radius: f64, flags: i32) -> Result<()> {
+pub fn warp_polar(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), dsize: core::Size, center: core::Point2f, max_radius: f64, flags: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -6815,7 +6815,7 @@ pub fn warp_polar(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArra
 /// ## See also
 /// findContours
 #[inline]
-pub fn watershed(image: &dyn core::ToInputArray, markers: &mut dyn core::ToInputOutputArray) -> Result<()> {
+pub fn watershed(image: &(impl core::ToInputArray + ?Sized), markers: &mut (impl core::ToInputOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(image);
 	input_output_array_arg!(markers);
 	return_send!(via ocvrs_return);
@@ -6830,11 +6830,11 @@ pub fn watershed(image: &dyn core::ToInputArray, markers: &mut dyn core::ToInput
 /// * lower_bound: Ptr<float>()
 /// * flow: noArray()
 #[inline]
-pub fn emd_1(signature1: &dyn core::ToInputArray, signature2: &dyn core::ToInputArray, dist_type: i32, cost: &dyn core::ToInputArray, mut lower_bound: core::Ptr<f32>, flow: &mut dyn core::ToOutputArray) -> Result<f32> {
+pub fn emd_1(signature1: &(impl core::ToInputArray + ?Sized), signature2: &(impl core::ToInputArray + ?Sized), dist_type: i32, cost: Option<&(impl core::ToInputArray + ?Sized)>, mut lower_bound: core::Ptr<f32>, flow: Option<&mut (impl core::ToOutputArray + ?Sized)>) -> Result<f32> {
 	input_array_arg!(signature1);
 	input_array_arg!(signature2);
-	input_array_arg!(cost);
-	output_array_arg!(flow);
+	input_array_arg!(nullable cost);
+	output_array_arg!(nullable flow);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_wrapperEMD_const__InputArrayR_const__InputArrayR_int_const__InputArrayR_PtrLfloatG_const__OutputArrayR(signature1.as_raw__InputArray(), signature2.as_raw__InputArray(), dist_type, cost.as_raw__InputArray(), lower_bound.as_raw_mut_PtrOff32(), flow.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -7012,10 +7012,10 @@ pub trait GeneralizedHough: core::AlgorithmTrait + crate::imgproc::GeneralizedHo
 	/// ## C++ default parameters
 	/// * votes: noArray()
 	#[inline]
-	fn detect(&mut self, image: &dyn core::ToInputArray, positions: &mut dyn core::ToOutputArray, votes: &mut dyn core::ToOutputArray) -> Result<()> {
+	fn detect(&mut self, image: &dyn core::ToInputArray, positions: &mut dyn core::ToOutputArray, votes: Option<&mut dyn core::ToOutputArray>) -> Result<()> {
 		input_array_arg!(image);
 		output_array_arg!(positions);
-		output_array_arg!(votes);
+		output_array_arg!(nullable votes);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_GeneralizedHough_detect_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_GeneralizedHough(), image.as_raw__InputArray(), positions.as_raw__OutputArray(), votes.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -7026,12 +7026,12 @@ pub trait GeneralizedHough: core::AlgorithmTrait + crate::imgproc::GeneralizedHo
 	/// ## C++ default parameters
 	/// * votes: noArray()
 	#[inline]
-	fn detect_with_edges(&mut self, edges: &dyn core::ToInputArray, dx: &dyn core::ToInputArray, dy: &dyn core::ToInputArray, positions: &mut dyn core::ToOutputArray, votes: &mut dyn core::ToOutputArray) -> Result<()> {
+	fn detect_with_edges(&mut self, edges: &dyn core::ToInputArray, dx: &dyn core::ToInputArray, dy: &dyn core::ToInputArray, positions: &mut dyn core::ToOutputArray, votes: Option<&mut dyn core::ToOutputArray>) -> Result<()> {
 		input_array_arg!(edges);
 		input_array_arg!(dx);
 		input_array_arg!(dy);
 		output_array_arg!(positions);
-		output_array_arg!(votes);
+		output_array_arg!(nullable votes);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_GeneralizedHough_detect_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_GeneralizedHough(), edges.as_raw__InputArray(), dx.as_raw__InputArray(), dy.as_raw__InputArray(), positions.as_raw__OutputArray(), votes.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -7793,12 +7793,12 @@ pub trait LineSegmentDetector: core::AlgorithmTrait + crate::imgproc::LineSegmen
 	/// * prec: noArray()
 	/// * nfa: noArray()
 	#[inline]
-	fn detect(&mut self, image: &dyn core::ToInputArray, lines: &mut dyn core::ToOutputArray, width: &mut dyn core::ToOutputArray, prec: &mut dyn core::ToOutputArray, nfa: &mut dyn core::ToOutputArray) -> Result<()> {
+	fn detect(&mut self, image: &dyn core::ToInputArray, lines: &mut dyn core::ToOutputArray, width: Option<&mut dyn core::ToOutputArray>, prec: Option<&mut dyn core::ToOutputArray>, nfa: Option<&mut dyn core::ToOutputArray>) -> Result<()> {
 		input_array_arg!(image);
 		output_array_arg!(lines);
-		output_array_arg!(width);
-		output_array_arg!(prec);
-		output_array_arg!(nfa);
+		output_array_arg!(nullable width);
+		output_array_arg!(nullable prec);
+		output_array_arg!(nullable nfa);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_LineSegmentDetector_detect_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_LineSegmentDetector(), image.as_raw__InputArray(), lines.as_raw__OutputArray(), width.as_raw__OutputArray(), prec.as_raw__OutputArray(), nfa.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -7834,10 +7834,10 @@ pub trait LineSegmentDetector: core::AlgorithmTrait + crate::imgproc::LineSegmen
 	/// ## C++ default parameters
 	/// * image: noArray()
 	#[inline]
-	fn compare_segments(&mut self, size: core::Size, lines1: &dyn core::ToInputArray, lines2: &dyn core::ToInputArray, image: &mut dyn core::ToInputOutputArray) -> Result<i32> {
+	fn compare_segments(&mut self, size: core::Size, lines1: &dyn core::ToInputArray, lines2: &dyn core::ToInputArray, image: Option<&mut dyn core::ToInputOutputArray>) -> Result<i32> {
 		input_array_arg!(lines1);
 		input_array_arg!(lines2);
-		input_output_array_arg!(image);
+		input_output_array_arg!(nullable image);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_LineSegmentDetector_compareSegments_const_SizeR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR(self.as_raw_mut_LineSegmentDetector(), &size, lines1.as_raw__InputArray(), lines2.as_raw__InputArray(), image.as_raw__InputOutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -8378,11 +8378,11 @@ pub trait IntelligentScissorsMBTrait: crate::imgproc::IntelligentScissorsMBTrait
 	/// ## C++ default parameters
 	/// * image: noArray()
 	#[inline]
-	fn apply_image_features(&mut self, non_edge: &dyn core::ToInputArray, gradient_direction: &dyn core::ToInputArray, gradient_magnitude: &dyn core::ToInputArray, image: &dyn core::ToInputArray) -> Result<crate::imgproc::IntelligentScissorsMB> {
+	fn apply_image_features(&mut self, non_edge: &dyn core::ToInputArray, gradient_direction: &dyn core::ToInputArray, gradient_magnitude: &dyn core::ToInputArray, image: Option<&dyn core::ToInputArray>) -> Result<crate::imgproc::IntelligentScissorsMB> {
 		input_array_arg!(non_edge);
 		input_array_arg!(gradient_direction);
 		input_array_arg!(gradient_magnitude);
-		input_array_arg!(image);
+		input_array_arg!(nullable image);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_segmentation_IntelligentScissorsMB_applyImageFeatures_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_IntelligentScissorsMB(), non_edge.as_raw__InputArray(), gradient_direction.as_raw__InputArray(), gradient_magnitude.as_raw__InputArray(), image.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -8455,3 +8455,4 @@ impl IntelligentScissorsMB {
 	}
 	
 }
+pub use crate::manual::imgproc::*;
@@ -46,7 +46,7 @@ pub mod prelude {
 /// * a: -0.3293f
 /// * b: 1.1258f
 #[inline]
-pub fn bimef(input: &dyn core::ToInputArray, output: &mut dyn core::ToOutputArray, mu: f32, a: f32, b: f32) -> Result<()> {
+pub fn bimef(input: &(impl core::ToInputArray + ?Sized), output: &mut (impl core::ToOutputArray + ?Sized), mu: f32, a: f32, b: f32) -> Result<()> {
 	input_array_arg!(input);
 	output_array_arg!(output);
 	return_send!(via ocvrs_return);
@@ -72,7 +72,7 @@ pub fn bimef(input: &dyn core::ToInputArray, output: &mut dyn core::ToOutputArra
 /// Compared to the original code, this implementation is a little bit slower and does not provide the same results.
 /// In particular, quality of the image enhancement is degraded for the bright areas in certain conditions.
 #[inline]
-pub fn bimef2(input: &dyn core::ToInputArray, output: &mut dyn core::ToOutputArray, k: f32, mu: f32, a: f32, b: f32) -> Result<()> {
+pub fn bimef2(input: &(impl core::ToInputArray + ?Sized), output: &mut (impl core::ToOutputArray + ?Sized), k: f32, mu: f32, a: f32, b: f32) -> Result<()> {
 	input_array_arg!(input);
 	output_array_arg!(output);
 	return_send!(via ocvrs_return);
@@ -145,7 +145,7 @@ pub fn draw_keylines(image: &core::Mat, keylines: &core::Vector<crate::line_desc
 /// * matches_mask: std::vector<char>()
 /// * flags: DrawLinesMatchesFlags::DEFAULT
 #[inline]
-pub fn draw_line_matches(img1: &core::Mat, keylines1: &core::Vector<crate::line_descriptor::KeyLine>, img2: &core::Mat, keylines2: &core::Vector<crate::line_descriptor::KeyLine>, matches1to2: &core::Vector<core::DMatch>, out_img: &mut core::Mat, match_color: core::Scalar, single_line_color: core::Scalar, matches_mask: &core::Vector<i8>, flags: i32) -> Result<()> {
+pub fn draw_line_matches_raw(img1: &core::Mat, keylines1: &core::Vector<crate::line_descriptor::KeyLine>, img2: &core::Mat, keylines2: &core::Vector<crate::line_descriptor::KeyLine>, matches1to2: &core::Vector<core::DMatch>, out_img: &mut core::Mat, match_color: core::Scalar, single_line_color: core::Scalar, matches_mask: &core::Vector<i8>, flags: i32) -> Result<()> {
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_line_descriptor_drawLineMatches_const_MatR_const_vectorLKeyLineGR_const_MatR_const_vectorLKeyLineGR_const_vectorLDMatchGR_MatR_const_ScalarR_const_ScalarR_const_vectorLcharGR_int(img1.as_raw_Mat(), keylines1.as_raw_VectorOfKeyLine(), img2.as_raw_Mat(), keylines2.as_raw_VectorOfKeyLine(), matches1to2.as_raw_VectorOfDMatch(), out_img.as_raw_mut_Mat(), &match_color, &single_line_color, matches_mask.as_raw_VectorOfi8(), flags, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -162,6 +162,36 @@ pub fn draw_line_matches(img1: &core::Mat, keylines1: &core::Vector<crate::line_
 pub trait BinaryDescriptorTraitConst: core::AlgorithmTraitConst {
 	fn as_raw_BinaryDescriptor(&self) -> *const c_void;
 
+	/// Get current number of octaves
+	#[inline]
+	fn get_num_of_octaves(&self) -> Result<i32> {
+		return_send!(via ocvrs_return);
+		unsafe { sys::cv_line_descriptor_BinaryDescriptor_getNumOfOctaves_const(self.as_raw_BinaryDescriptor(), ocvrs_return.as_mut_ptr()) };
+		return_receive!(unsafe ocvrs_return => ret);
+		let ret = ret.into_result()?;
+		Ok(ret)
+	}
+	
+	/// Get current width of bands
+	#[inline]
+	fn get_width_of_band(&self) -> Result<i32> {
+		return_send!(via ocvrs_return);
+		unsafe { sys::cv_line_descriptor_BinaryDescriptor_getWidthOfBand_const(self.as_raw_BinaryDescriptor(), ocvrs_return.as_mut_ptr()) };
+		return_receive!(unsafe ocvrs_return => ret);
+		let ret = ret.into_result()?;
+		Ok(ret)
+	}
+	
+	/// Get current reduction ratio (used in Gaussian pyramids)
+	#[inline]
+	fn get_reduction_ratio(&self) -> Result<i32> {
+		return_send!(via ocvrs_return);
+		unsafe { sys::cv_line_descriptor_BinaryDescriptor_getReductionRatio_const(self.as_raw_BinaryDescriptor(), ocvrs_return.as_mut_ptr()) };
+		return_receive!(unsafe ocvrs_return => ret);
+		let ret = ret.into_result()?;
+		Ok(ret)
+	}
+	
 	/// Store parameters to a FileStorage object
 	/// 
 	/// ## Parameters
@@ -281,16 +311,6 @@ pub trait BinaryDescriptorTraitConst: core::AlgorithmTraitConst {
 pub trait BinaryDescriptorTrait: core::AlgorithmTrait + crate::line_descriptor::BinaryDescriptorTraitConst {
 	fn as_raw_mut_BinaryDescriptor(&mut self) -> *mut c_void;
 
-	/// Get current number of octaves
-	#[inline]
-	fn get_num_of_octaves(&mut self) -> Result<i32> {
-		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptor_getNumOfOctaves(self.as_raw_mut_BinaryDescriptor(), ocvrs_return.as_mut_ptr()) };
-		return_receive!(unsafe ocvrs_return => ret);
-		let ret = ret.into_result()?;
-		Ok(ret)
-	}
-	
 	/// Set number of octaves
 	/// ## Parameters
 	/// * octaves: number of octaves
@@ -303,16 +323,6 @@ pub trait BinaryDescriptorTrait: core::AlgorithmTrait + crate::line_descriptor::
 		Ok(ret)
 	}
 	
-	/// Get current width of bands
-	#[inline]
-	fn get_width_of_band(&mut self) -> Result<i32> {
-		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptor_getWidthOfBand(self.as_raw_mut_BinaryDescriptor(), ocvrs_return.as_mut_ptr()) };
-		return_receive!(unsafe ocvrs_return => ret);
-		let ret = ret.into_result()?;
-		Ok(ret)
-	}
-	
 	/// Set width of bands
 	/// ## Parameters
 	/// * width: width of bands
@@ -325,16 +335,6 @@ pub trait BinaryDescriptorTrait: core::AlgorithmTrait + crate::line_descriptor::
 		Ok(ret)
 	}
 	
-	/// Get current reduction ratio (used in Gaussian pyramids)
-	#[inline]
-	fn get_reduction_ratio(&mut self) -> Result<i32> {
-		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptor_getReductionRatio(self.as_raw_mut_BinaryDescriptor(), ocvrs_return.as_mut_ptr()) };
-		return_receive!(unsafe ocvrs_return => ret);
-		let ret = ret.into_result()?;
-		Ok(ret)
-	}
-	
 	/// Set reduction ratio (used in Gaussian pyramids)
 	/// ## Parameters
 	/// * rRatio: reduction ratio
@@ -370,9 +370,9 @@ pub trait BinaryDescriptorTrait: core::AlgorithmTrait + crate::line_descriptor::
 	/// ## C++ default parameters
 	/// * mask: Mat()
 	#[inline]
-	fn detect_1(&mut self, image: &core::Mat, keypoints: &mut core::Vector<crate::line_descriptor::KeyLine>, mask: &core::Mat) -> Result<()> {
+	fn detect_1(&mut self, image: &core::Mat, keypoints: &mut core::Vector<crate::line_descriptor::KeyLine>, mask: Option<&core::Mat>) -> Result<()> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptor_detect_const_MatR_vectorLKeyLineGR_const_MatR(self.as_raw_mut_BinaryDescriptor(), image.as_raw_Mat(), keypoints.as_raw_mut_VectorOfKeyLine(), mask.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_line_descriptor_BinaryDescriptor_detect_const_MatR_vectorLKeyLineGR_const_MatR(self.as_raw_mut_BinaryDescriptor(), image.as_raw_Mat(), keypoints.as_raw_mut_VectorOfKeyLine(), mask.map_or(::core::ptr::null(), |mask| mask.as_raw_Mat()), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -429,9 +429,9 @@ impl BinaryDescriptor {
 	/// ## C++ default parameters
 	/// * parameters: BinaryDescriptor::Params()
 	#[inline]
-	pub fn new(parameters: &crate::line_descriptor::BinaryDescriptor_Params) -> Result<crate::line_descriptor::BinaryDescriptor> {
+	pub fn new(parameters: Option<&crate::line_descriptor::BinaryDescriptor_Params>) -> Result<crate::line_descriptor::BinaryDescriptor> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptor_BinaryDescriptor_const_ParamsR(parameters.as_raw_BinaryDescriptor_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_line_descriptor_BinaryDescriptor_BinaryDescriptor_const_ParamsR(parameters.map_or(::core::ptr::null(), |parameters| parameters.as_raw_BinaryDescriptor_Params()), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::line_descriptor::BinaryDescriptor::opencv_from_extern(ret) };
@@ -638,9 +638,9 @@ pub trait BinaryDescriptorMatcherTraitConst: core::AlgorithmTraitConst {
 	/// ## C++ default parameters
 	/// * mask: Mat()
 	#[inline]
-	fn match_(&self, query_descriptors: &core::Mat, train_descriptors: &core::Mat, matches: &mut core::Vector<core::DMatch>, mask: &core::Mat) -> Result<()> {
+	fn match_(&self, query_descriptors: &core::Mat, train_descriptors: &core::Mat, matches: &mut core::Vector<core::DMatch>, mask: Option<&core::Mat>) -> Result<()> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptorMatcher_match_const_const_MatR_const_MatR_vectorLDMatchGR_const_MatR(self.as_raw_BinaryDescriptorMatcher(), query_descriptors.as_raw_Mat(), train_descriptors.as_raw_Mat(), matches.as_raw_mut_VectorOfDMatch(), mask.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_line_descriptor_BinaryDescriptorMatcher_match_const_const_MatR_const_MatR_vectorLDMatchGR_const_MatR(self.as_raw_BinaryDescriptorMatcher(), query_descriptors.as_raw_Mat(), train_descriptors.as_raw_Mat(), matches.as_raw_mut_VectorOfDMatch(), mask.map_or(::core::ptr::null(), |mask| mask.as_raw_Mat()), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -662,9 +662,9 @@ pub trait BinaryDescriptorMatcherTraitConst: core::AlgorithmTraitConst {
 	/// * mask: Mat()
 	/// * compact_result: false
 	#[inline]
-	fn knn_match(&self, query_descriptors: &core::Mat, train_descriptors: &core::Mat, matches: &mut core::Vector<core::Vector<core::DMatch>>, k: i32, mask: &core::Mat, compact_result: bool) -> Result<()> {
+	fn knn_match(&self, query_descriptors: &core::Mat, train_descriptors: &core::Mat, matches: &mut core::Vector<core::Vector<core::DMatch>>, k: i32, mask: Option<&core::Mat>, compact_result: bool) -> Result<()> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptorMatcher_knnMatch_const_const_MatR_const_MatR_vectorLvectorLDMatchGGR_int_const_MatR_bool(self.as_raw_BinaryDescriptorMatcher(), query_descriptors.as_raw_Mat(), train_descriptors.as_raw_Mat(), matches.as_raw_mut_VectorOfVectorOfDMatch(), k, mask.as_raw_Mat(), compact_result, ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_line_descriptor_BinaryDescriptorMatcher_knnMatch_const_const_MatR_const_MatR_vectorLvectorLDMatchGGR_int_const_MatR_bool(self.as_raw_BinaryDescriptorMatcher(), query_descriptors.as_raw_Mat(), train_descriptors.as_raw_Mat(), matches.as_raw_mut_VectorOfVectorOfDMatch(), k, mask.map_or(::core::ptr::null(), |mask| mask.as_raw_Mat()), compact_result, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -686,9 +686,9 @@ pub trait BinaryDescriptorMatcherTraitConst: core::AlgorithmTraitConst {
 	/// * mask: Mat()
 	/// * compact_result: false
 	#[inline]
-	fn radius_match(&self, query_descriptors: &core::Mat, train_descriptors: &core::Mat, matches: &mut core::Vector<core::Vector<core::DMatch>>, max_distance: f32, mask: &core::Mat, compact_result: bool) -> Result<()> {
+	fn radius_match(&self, query_descriptors: &core::Mat, train_descriptors: &core::Mat, matches: &mut core::Vector<core::Vector<core::DMatch>>, max_distance: f32, mask: Option<&core::Mat>, compact_result: bool) -> Result<()> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_BinaryDescriptorMatcher_radiusMatch_const_const_MatR_const_MatR_vectorLvectorLDMatchGGR_float_const_MatR_bool(self.as_raw_BinaryDescriptorMatcher(), query_descriptors.as_raw_Mat(), train_descriptors.as_raw_Mat(), matches.as_raw_mut_VectorOfVectorOfDMatch(), max_distance, mask.as_raw_Mat(), compact_result, ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_line_descriptor_BinaryDescriptorMatcher_radiusMatch_const_const_MatR_const_MatR_vectorLvectorLDMatchGGR_float_const_MatR_bool(self.as_raw_BinaryDescriptorMatcher(), query_descriptors.as_raw_Mat(), train_descriptors.as_raw_Mat(), matches.as_raw_mut_VectorOfVectorOfDMatch(), max_distance, mask.map_or(::core::ptr::null(), |mask| mask.as_raw_Mat()), compact_result, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -937,18 +937,6 @@ impl BinaryDescriptorMatcher {
 
 boxed_cast_base! { BinaryDescriptorMatcher, core::Algorithm, cv_BinaryDescriptorMatcher_to_Algorithm }
 
-/// struct for drawing options
-#[repr(C)]
-#[derive(Copy, Clone, Debug, PartialEq)]
-pub struct DrawLinesMatchesFlags {
-	__rust_private: [u8; 0],
-}
-
-opencv_type_simple! { crate::line_descriptor::DrawLinesMatchesFlags }
-
-impl DrawLinesMatchesFlags {
-}
-
 /// A class to represent a line
 /// 
 /// As aformentioned, it is been necessary to design a class that fully stores the information needed to
@@ -1106,9 +1094,9 @@ pub trait LSDDetectorTrait: core::AlgorithmTrait + crate::line_descriptor::LSDDe
 	/// ## C++ default parameters
 	/// * mask: Mat()
 	#[inline]
-	fn detect(&mut self, image: &core::Mat, keypoints: &mut core::Vector<crate::line_descriptor::KeyLine>, scale: i32, num_octaves: i32, mask: &core::Mat) -> Result<()> {
+	fn detect(&mut self, image: &core::Mat, keypoints: &mut core::Vector<crate::line_descriptor::KeyLine>, scale: i32, num_octaves: i32, mask: Option<&core::Mat>) -> Result<()> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_line_descriptor_LSDDetector_detect_const_MatR_vectorLKeyLineGR_int_int_const_MatR(self.as_raw_mut_LSDDetector(), image.as_raw_Mat(), keypoints.as_raw_mut_VectorOfKeyLine(), scale, num_octaves, mask.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_line_descriptor_LSDDetector_detect_const_MatR_vectorLKeyLineGR_int_int_const_MatR(self.as_raw_mut_LSDDetector(), image.as_raw_Mat(), keypoints.as_raw_mut_VectorOfKeyLine(), scale, num_octaves, mask.map_or(::core::ptr::null(), |mask| mask.as_raw_Mat()), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -1233,3 +1221,4 @@ impl LSDParam {
 	}
 	
 }
+pub use crate::manual::line_descriptor::*;
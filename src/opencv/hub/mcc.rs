@@ -1556,3 +1556,4 @@ impl MCC_DetectorParameters {
 	}
 	
 }
+pub use crate::manual::mcc::*;
@@ -343,7 +343,7 @@ opencv_type_enum! { crate::ml::VariableTypes }
 pub type ANN_MLP_ANNEAL = dyn crate::ml::ANN_MLP;
 /// Creates test set
 #[inline]
-pub fn create_concentric_spheres_test_set(nsamples: i32, nfeatures: i32, nclasses: i32, samples: &mut dyn core::ToOutputArray, responses: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn create_concentric_spheres_test_set(nsamples: i32, nfeatures: i32, nclasses: i32, samples: &mut (impl core::ToOutputArray + ?Sized), responses: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	output_array_arg!(samples);
 	output_array_arg!(responses);
 	return_send!(via ocvrs_return);
@@ -361,7 +361,7 @@ pub fn create_concentric_spheres_test_set(nsamples: i32, nfeatures: i32, nclasse
 /// * nsamples: returned samples count
 /// * samples: returned samples array
 #[inline]
-pub fn rand_mv_normal(mean: &dyn core::ToInputArray, cov: &dyn core::ToInputArray, nsamples: i32, samples: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn rand_mv_normal(mean: &(impl core::ToInputArray + ?Sized), cov: &(impl core::ToInputArray + ?Sized), nsamples: i32, samples: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(mean);
 	input_array_arg!(cov);
 	output_array_arg!(samples);
@@ -1802,9 +1802,9 @@ pub trait EMConst: crate::ml::StatModelConst {
 	/// * results: noArray()
 	/// * flags: 0
 	#[inline]
-	fn predict(&self, samples: &dyn core::ToInputArray, results: &mut dyn core::ToOutputArray, flags: i32) -> Result<f32> {
+	fn predict(&self, samples: &dyn core::ToInputArray, results: Option<&mut dyn core::ToOutputArray>, flags: i32) -> Result<f32> {
 		input_array_arg!(samples);
-		output_array_arg!(results);
+		output_array_arg!(nullable results);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ml_EM_predict_const_const__InputArrayR_const__OutputArrayR_int(self.as_raw_EM(), samples.as_raw__InputArray(), results.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -1917,11 +1917,11 @@ pub trait EM: crate::ml::EMConst + crate::ml::StatModel {
 	/// * labels: noArray()
 	/// * probs: noArray()
 	#[inline]
-	fn train_em(&mut self, samples: &dyn core::ToInputArray, log_likelihoods: &mut dyn core::ToOutputArray, labels: &mut dyn core::ToOutputArray, probs: &mut dyn core::ToOutputArray) -> Result<bool> {
+	fn train_em(&mut self, samples: &dyn core::ToInputArray, log_likelihoods: Option<&mut dyn core::ToOutputArray>, labels: Option<&mut dyn core::ToOutputArray>, probs: Option<&mut dyn core::ToOutputArray>) -> Result<bool> {
 		input_array_arg!(samples);
-		output_array_arg!(log_likelihoods);
-		output_array_arg!(labels);
-		output_array_arg!(probs);
+		output_array_arg!(nullable log_likelihoods);
+		output_array_arg!(nullable labels);
+		output_array_arg!(nullable probs);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ml_EM_trainEM_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_EM(), samples.as_raw__InputArray(), log_likelihoods.as_raw__OutputArray(), labels.as_raw__OutputArray(), probs.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -1964,14 +1964,14 @@ pub trait EM: crate::ml::EMConst + crate::ml::StatModel {
 	/// * labels: noArray()
 	/// * probs: noArray()
 	#[inline]
-	fn train_e(&mut self, samples: &dyn core::ToInputArray, means0: &dyn core::ToInputArray, covs0: &dyn core::ToInputArray, weights0: &dyn core::ToInputArray, log_likelihoods: &mut dyn core::ToOutputArray, labels: &mut dyn core::ToOutputArray, probs: &mut dyn core::ToOutputArray) -> Result<bool> {
+	fn train_e(&mut self, samples: &dyn core::ToInputArray, means0: &dyn core::ToInputArray, covs0: Option<&dyn core::ToInputArray>, weights0: Option<&dyn core::ToInputArray>, log_likelihoods: Option<&mut dyn core::ToOutputArray>, labels: Option<&mut dyn core::ToOutputArray>, probs: Option<&mut dyn core::ToOutputArray>) -> Result<bool> {
 		input_array_arg!(samples);
 		input_array_arg!(means0);
-		input_array_arg!(covs0);
-		input_array_arg!(weights0);
-		output_array_arg!(log_likelihoods);
-		output_array_arg!(labels);
-		output_array_arg!(probs);
+		input_array_arg!(nullable covs0);
+		input_array_arg!(nullable weights0);
+		output_array_arg!(nullable log_likelihoods);
+		output_array_arg!(nullable labels);
+		output_array_arg!(nullable probs);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ml_EM_trainE_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_EM(), samples.as_raw__InputArray(), means0.as_raw__InputArray(), covs0.as_raw__InputArray(), weights0.as_raw__InputArray(), log_likelihoods.as_raw__OutputArray(), labels.as_raw__OutputArray(), probs.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -2003,12 +2003,12 @@ pub trait EM: crate::ml::EMConst + crate::ml::StatModel {
 	/// * labels: noArray()
 	/// * probs: noArray()
 	#[inline]
-	fn train_m(&mut self, samples: &dyn core::ToInputArray, probs0: &dyn core::ToInputArray, log_likelihoods: &mut dyn core::ToOutputArray, labels: &mut dyn core::ToOutputArray, probs: &mut dyn core::ToOutputArray) -> Result<bool> {
+	fn train_m(&mut self, samples: &dyn core::ToInputArray, probs0: &dyn core::ToInputArray, log_likelihoods: Option<&mut dyn core::ToOutputArray>, labels: Option<&mut dyn core::ToOutputArray>, probs: Option<&mut dyn core::ToOutputArray>) -> Result<bool> {
 		input_array_arg!(samples);
 		input_array_arg!(probs0);
-		output_array_arg!(log_likelihoods);
-		output_array_arg!(labels);
-		output_array_arg!(probs);
+		output_array_arg!(nullable log_likelihoods);
+		output_array_arg!(nullable labels);
+		output_array_arg!(nullable probs);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ml_EM_trainM_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_EM(), samples.as_raw__InputArray(), probs0.as_raw__InputArray(), log_likelihoods.as_raw__OutputArray(), labels.as_raw__OutputArray(), probs.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -2142,11 +2142,11 @@ pub trait KNearestConst: crate::ml::StatModelConst {
 	/// * neighbor_responses: noArray()
 	/// * dist: noArray()
 	#[inline]
-	fn find_nearest(&self, samples: &dyn core::ToInputArray, k: i32, results: &mut dyn core::ToOutputArray, neighbor_responses: &mut dyn core::ToOutputArray, dist: &mut dyn core::ToOutputArray) -> Result<f32> {
+	fn find_nearest(&self, samples: &dyn core::ToInputArray, k: i32, results: &mut dyn core::ToOutputArray, neighbor_responses: Option<&mut dyn core::ToOutputArray>, dist: Option<&mut dyn core::ToOutputArray>) -> Result<f32> {
 		input_array_arg!(samples);
 		output_array_arg!(results);
-		output_array_arg!(neighbor_responses);
-		output_array_arg!(dist);
+		output_array_arg!(nullable neighbor_responses);
+		output_array_arg!(nullable dist);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ml_KNearest_findNearest_const_const__InputArrayR_int_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_KNearest(), samples.as_raw__InputArray(), k, results.as_raw__OutputArray(), neighbor_responses.as_raw__OutputArray(), dist.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -2334,9 +2334,9 @@ pub trait LogisticRegressionConst: crate::ml::StatModelConst {
 	/// * results: noArray()
 	/// * flags: 0
 	#[inline]
-	fn predict(&self, samples: &dyn core::ToInputArray, results: &mut dyn core::ToOutputArray, flags: i32) -> Result<f32> {
+	fn predict(&self, samples: &dyn core::ToInputArray, results: Option<&mut dyn core::ToOutputArray>, flags: i32) -> Result<f32> {
 		input_array_arg!(samples);
-		output_array_arg!(results);
+		output_array_arg!(nullable results);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ml_LogisticRegression_predict_const_const__InputArrayR_const__OutputArrayR_int(self.as_raw_LogisticRegression(), samples.as_raw__InputArray(), results.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -3809,9 +3809,9 @@ pub trait StatModelConst: core::AlgorithmTraitConst {
 	/// * results: noArray()
 	/// * flags: 0
 	#[inline]
-	fn predict(&self, samples: &dyn core::ToInputArray, results: &mut dyn core::ToOutputArray, flags: i32) -> Result<f32> {
+	fn predict(&self, samples: &dyn core::ToInputArray, results: Option<&mut dyn core::ToOutputArray>, flags: i32) -> Result<f32> {
 		input_array_arg!(samples);
-		output_array_arg!(results);
+		output_array_arg!(nullable results);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ml_StatModel_predict_const_const__InputArrayR_const__OutputArrayR_int(self.as_raw_StatModel(), samples.as_raw__InputArray(), results.as_raw__OutputArray(), flags, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -4400,13 +4400,13 @@ impl dyn TrainData + '_ {
 	/// * sample_weights: noArray()
 	/// * var_type: noArray()
 	#[inline]
-	pub fn create(samples: &dyn core::ToInputArray, layout: i32, responses: &dyn core::ToInputArray, var_idx: &dyn core::ToInputArray, sample_idx: &dyn core::ToInputArray, sample_weights: &dyn core::ToInputArray, var_type: &dyn core::ToInputArray) -> Result<core::Ptr<dyn crate::ml::TrainData>> {
+	pub fn create(samples: &(impl core::ToInputArray + ?Sized), layout: i32, responses: &(impl core::ToInputArray + ?Sized), var_idx: Option<&(impl core::ToInputArray + ?Sized)>, sample_idx: Option<&(impl core::ToInputArray + ?Sized)>, sample_weights: Option<&(impl core::ToInputArray + ?Sized)>, var_type: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<core::Ptr<dyn crate::ml::TrainData>> {
 		input_array_arg!(samples);
 		input_array_arg!(responses);
-		input_array_arg!(var_idx);
-		input_array_arg!(sample_idx);
-		input_array_arg!(sample_weights);
-		input_array_arg!(var_type);
+		input_array_arg!(nullable var_idx);
+		input_array_arg!(nullable sample_idx);
+		input_array_arg!(nullable sample_weights);
+		input_array_arg!(nullable var_type);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ml_TrainData_create_const__InputArrayR_int_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR(samples.as_raw__InputArray(), layout, responses.as_raw__InputArray(), var_idx.as_raw__InputArray(), sample_idx.as_raw__InputArray(), sample_weights.as_raw__InputArray(), var_type.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -4415,4 +4415,5 @@ impl dyn TrainData + '_ {
 		Ok(ret)
 	}
 	
-}
\ No newline at end of file
+}
+pub use crate::manual::ml::*;
@@ -1148,6 +1148,14 @@ pub trait DetectionROITraitConst {
 		ret
 	}
 	
+	/// Same as `locations()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn locations_ref(&self) -> BoxedRef<'_, core::Vector<core::Point>> {
+		let ret = unsafe { sys::cv_DetectionROI_getPropLocationsRef_const(self.as_raw_DetectionROI()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// vector that will contain confidence values for each location
 	#[inline]
 	fn confidences(&self) -> core::Vector<f64> {
@@ -1156,6 +1164,14 @@ pub trait DetectionROITraitConst {
 		ret
 	}
 	
+	/// Same as `confidences()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn confidences_ref(&self) -> BoxedRef<'_, core::Vector<f64>> {
+		let ret = unsafe { sys::cv_DetectionROI_getPropConfidencesRef_const(self.as_raw_DetectionROI()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 }
 
 pub trait DetectionROITrait: crate::objdetect::DetectionROITraitConst {
@@ -1554,6 +1570,14 @@ pub trait HOGDescriptorTraitConst {
 		ret
 	}
 	
+	/// Same as `svm_detector()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn svm_detector_ref(&self) -> BoxedRef<'_, core::Vector<f32>> {
+		let ret = unsafe { sys::cv_HOGDescriptor_getPropSvmDetectorRef_const(self.as_raw_HOGDescriptor()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// coefficients for the linear SVM classifier used when OpenCL is enabled
 	#[inline]
 	fn ocl_svm_detector(&self) -> core::UMat {
@@ -1562,6 +1586,14 @@ pub trait HOGDescriptorTraitConst {
 		ret
 	}
 	
+	/// Same as `ocl_svm_detector()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn ocl_svm_detector_ref(&self) -> BoxedRef<'_, core::UMat> {
+		let ret = unsafe { sys::cv_HOGDescriptor_getPropOclSvmDetectorRef_const(self.as_raw_HOGDescriptor()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// not documented
 	#[inline]
 	fn free_coef(&self) -> f32 {
@@ -1585,7 +1617,7 @@ pub trait HOGDescriptorTraitConst {
 	
 	/// Returns the number of coefficients required for the classification.
 	#[inline]
-	fn get_descriptor_size(&self) -> Result<size_t> {
+	fn get_descriptor_size(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_HOGDescriptor_getDescriptorSize_const(self.as_raw_HOGDescriptor(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -2240,10 +2272,10 @@ pub trait QRCodeDetectorTraitConst {
 	/// ## C++ default parameters
 	/// * straight_qrcode: noArray()
 	#[inline]
-	fn decode_multi(&self, img: &dyn core::ToInputArray, points: &dyn core::ToInputArray, decoded_info: &mut core::Vector<String>, straight_qrcode: &mut dyn core::ToOutputArray) -> Result<bool> {
+	fn decode_multi(&self, img: &dyn core::ToInputArray, points: &dyn core::ToInputArray, decoded_info: &mut core::Vector<String>, straight_qrcode: Option<&mut dyn core::ToOutputArray>) -> Result<bool> {
 		input_array_arg!(img);
 		input_array_arg!(points);
-		output_array_arg!(straight_qrcode);
+		output_array_arg!(nullable straight_qrcode);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_QRCodeDetector_decodeMulti_const_const__InputArrayR_const__InputArrayR_vectorLstringGR_const__OutputArrayR(self.as_raw_QRCodeDetector(), img.as_raw__InputArray(), points.as_raw__InputArray(), decoded_info.as_raw_mut_VectorOfString(), straight_qrcode.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -2293,10 +2325,10 @@ pub trait QRCodeDetectorTrait: crate::objdetect::QRCodeDetectorTraitConst {
 	/// ## C++ default parameters
 	/// * straight_qrcode: noArray()
 	#[inline]
-	fn decode(&mut self, img: &dyn core::ToInputArray, points: &dyn core::ToInputArray, straight_qrcode: &mut dyn core::ToOutputArray) -> Result<Vec<u8>> {
+	fn decode(&mut self, img: &dyn core::ToInputArray, points: &dyn core::ToInputArray, straight_qrcode: Option<&mut dyn core::ToOutputArray>) -> Result<Vec<u8>> {
 		input_array_arg!(img);
 		input_array_arg!(points);
-		output_array_arg!(straight_qrcode);
+		output_array_arg!(nullable straight_qrcode);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_QRCodeDetector_decode_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_QRCodeDetector(), img.as_raw__InputArray(), points.as_raw__InputArray(), straight_qrcode.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -2316,10 +2348,10 @@ pub trait QRCodeDetectorTrait: crate::objdetect::QRCodeDetectorTraitConst {
 	/// ## C++ default parameters
 	/// * straight_qrcode: noArray()
 	#[inline]
-	fn decode_curved(&mut self, img: &dyn core::ToInputArray, points: &dyn core::ToInputArray, straight_qrcode: &mut dyn core::ToOutputArray) -> Result<Vec<u8>> {
+	fn decode_curved(&mut self, img: &dyn core::ToInputArray, points: &dyn core::ToInputArray, straight_qrcode: Option<&mut dyn core::ToOutputArray>) -> Result<Vec<u8>> {
 		input_array_arg!(img);
 		input_array_arg!(points);
-		output_array_arg!(straight_qrcode);
+		output_array_arg!(nullable straight_qrcode);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_QRCodeDetector_decodeCurved_const__InputArrayR_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_QRCodeDetector(), img.as_raw__InputArray(), points.as_raw__InputArray(), straight_qrcode.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -2339,10 +2371,10 @@ pub trait QRCodeDetectorTrait: crate::objdetect::QRCodeDetectorTraitConst {
 	/// * points: noArray()
 	/// * straight_qrcode: noArray()
 	#[inline]
-	fn detect_and_decode(&mut self, img: &dyn core::ToInputArray, points: &mut dyn core::ToOutputArray, straight_qrcode: &mut dyn core::ToOutputArray) -> Result<Vec<u8>> {
+	fn detect_and_decode(&mut self, img: &dyn core::ToInputArray, points: Option<&mut dyn core::ToOutputArray>, straight_qrcode: Option<&mut dyn core::ToOutputArray>) -> Result<Vec<u8>> {
 		input_array_arg!(img);
-		output_array_arg!(points);
-		output_array_arg!(straight_qrcode);
+		output_array_arg!(nullable points);
+		output_array_arg!(nullable straight_qrcode);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_QRCodeDetector_detectAndDecode_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_QRCodeDetector(), img.as_raw__InputArray(), points.as_raw__OutputArray(), straight_qrcode.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -2362,10 +2394,10 @@ pub trait QRCodeDetectorTrait: crate::objdetect::QRCodeDetectorTraitConst {
 	/// * points: noArray()
 	/// * straight_qrcode: noArray()
 	#[inline]
-	fn detect_and_decode_curved(&mut self, img: &dyn core::ToInputArray, points: &mut dyn core::ToOutputArray, straight_qrcode: &mut dyn core::ToOutputArray) -> Result<Vec<u8>> {
+	fn detect_and_decode_curved(&mut self, img: &dyn core::ToInputArray, points: Option<&mut dyn core::ToOutputArray>, straight_qrcode: Option<&mut dyn core::ToOutputArray>) -> Result<Vec<u8>> {
 		input_array_arg!(img);
-		output_array_arg!(points);
-		output_array_arg!(straight_qrcode);
+		output_array_arg!(nullable points);
+		output_array_arg!(nullable straight_qrcode);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_QRCodeDetector_detectAndDecodeCurved_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_QRCodeDetector(), img.as_raw__InputArray(), points.as_raw__OutputArray(), straight_qrcode.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -2563,3 +2595,4 @@ impl SimilarRects {
 	}
 	
 }
+pub use crate::manual::objdetect::*;
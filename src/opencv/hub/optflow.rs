@@ -116,7 +116,7 @@ opencv_type_enum! { crate::optflow::SupportRegionType }
 /// histogram, where a recent motion has a larger weight and the motion occurred in the past has a
 /// smaller weight, as recorded in mhi .
 #[inline]
-pub fn calc_global_orientation(orientation: &dyn core::ToInputArray, mask: &dyn core::ToInputArray, mhi: &dyn core::ToInputArray, timestamp: f64, duration: f64) -> Result<f64> {
+pub fn calc_global_orientation(orientation: &(impl core::ToInputArray + ?Sized), mask: &(impl core::ToInputArray + ?Sized), mhi: &(impl core::ToInputArray + ?Sized), timestamp: f64, duration: f64) -> Result<f64> {
 	input_array_arg!(orientation);
 	input_array_arg!(mask);
 	input_array_arg!(mhi);
@@ -160,7 +160,7 @@ pub fn calc_global_orientation(orientation: &dyn core::ToInputArray, mask: &dyn
 /// ## C++ default parameters
 /// * aperture_size: 3
 #[inline]
-pub fn calc_motion_gradient(mhi: &dyn core::ToInputArray, mask: &mut dyn core::ToOutputArray, orientation: &mut dyn core::ToOutputArray, delta1: f64, delta2: f64, aperture_size: i32) -> Result<()> {
+pub fn calc_motion_gradient(mhi: &(impl core::ToInputArray + ?Sized), mask: &mut (impl core::ToOutputArray + ?Sized), orientation: &mut (impl core::ToOutputArray + ?Sized), delta1: f64, delta2: f64, aperture_size: i32) -> Result<()> {
 	input_array_arg!(mhi);
 	output_array_arg!(mask);
 	output_array_arg!(orientation);
@@ -187,7 +187,7 @@ pub fn calc_motion_gradient(mhi: &dyn core::ToInputArray, mask: &mut dyn core::T
 /// direction for every component can be calculated with calcGlobalOrientation using the extracted mask
 /// of the particular component.
 #[inline]
-pub fn segment_motion(mhi: &dyn core::ToInputArray, segmask: &mut dyn core::ToOutputArray, bounding_rects: &mut core::Vector<core::Rect>, timestamp: f64, seg_thresh: f64) -> Result<()> {
+pub fn segment_motion(mhi: &(impl core::ToInputArray + ?Sized), segmask: &mut (impl core::ToOutputArray + ?Sized), bounding_rects: &mut core::Vector<core::Rect>, timestamp: f64, seg_thresh: f64) -> Result<()> {
 	input_array_arg!(mhi);
 	output_array_arg!(segmask);
 	return_send!(via ocvrs_return);
@@ -216,7 +216,7 @@ pub fn segment_motion(mhi: &dyn core::ToInputArray, segmask: &mut dyn core::ToOu
 /// The function, together with calcMotionGradient and calcGlobalOrientation , implements a motion
 /// templates technique described in [Davis97](https://docs.opencv.org/4.6.0/d0/de3/citelist.html#CITEREF_Davis97) and [Bradski00](https://docs.opencv.org/4.6.0/d0/de3/citelist.html#CITEREF_Bradski00) .
 #[inline]
-pub fn update_motion_history(silhouette: &dyn core::ToInputArray, mhi: &mut dyn core::ToInputOutputArray, timestamp: f64, duration: f64) -> Result<()> {
+pub fn update_motion_history(silhouette: &(impl core::ToInputArray + ?Sized), mhi: &mut (impl core::ToInputOutputArray + ?Sized), timestamp: f64, duration: f64) -> Result<()> {
 	input_array_arg!(silhouette);
 	input_output_array_arg!(mhi);
 	return_send!(via ocvrs_return);
@@ -299,7 +299,7 @@ pub fn update_motion_history(silhouette: &dyn core::ToInputArray, mhi: &mut dyn
 /// * fgs_sigma: 1.5f
 /// * use_variational_refinement: false
 #[inline]
-pub fn calc_optical_flow_dense_rlof(i0: &dyn core::ToInputArray, i1: &dyn core::ToInputArray, flow: &mut dyn core::ToInputOutputArray, mut rlof_param: core::Ptr<crate::optflow::RLOFOpticalFlowParameter>, forward_backward_threshold: f32, grid_step: core::Size, interp_type: crate::optflow::InterpolationType, epic_k: i32, epic_sigma: f32, epic_lambda: f32, ric_sp_size: i32, ric_slic_type: i32, use_post_proc: bool, fgs_lambda: f32, fgs_sigma: f32, use_variational_refinement: bool) -> Result<()> {
+pub fn calc_optical_flow_dense_rlof(i0: &(impl core::ToInputArray + ?Sized), i1: &(impl core::ToInputArray + ?Sized), flow: &mut (impl core::ToInputOutputArray + ?Sized), mut rlof_param: core::Ptr<crate::optflow::RLOFOpticalFlowParameter>, forward_backward_threshold: f32, grid_step: core::Size, interp_type: crate::optflow::InterpolationType, epic_k: i32, epic_sigma: f32, epic_lambda: f32, ric_sp_size: i32, ric_slic_type: i32, use_post_proc: bool, fgs_lambda: f32, fgs_sigma: f32, use_variational_refinement: bool) -> Result<()> {
 	input_array_arg!(i0);
 	input_array_arg!(i1);
 	input_output_array_arg!(flow);
@@ -340,7 +340,7 @@ pub fn calc_optical_flow_dense_rlof(i0: &dyn core::ToInputArray, i1: &dyn core::
 /// 
 /// ## Overloaded parameters
 #[inline]
-pub fn calc_optical_flow_sf(from: &dyn core::ToInputArray, to: &dyn core::ToInputArray, flow: &mut dyn core::ToOutputArray, layers: i32, averaging_block_size: i32, max_flow: i32) -> Result<()> {
+pub fn calc_optical_flow_sf(from: &(impl core::ToInputArray + ?Sized), to: &(impl core::ToInputArray + ?Sized), flow: &mut (impl core::ToOutputArray + ?Sized), layers: i32, averaging_block_size: i32, max_flow: i32) -> Result<()> {
 	input_array_arg!(from);
 	input_array_arg!(to);
 	output_array_arg!(flow);
@@ -379,7 +379,7 @@ pub fn calc_optical_flow_sf(from: &dyn core::ToInputArray, to: &dyn core::ToInpu
 /// Note:
 ///    *   An example using the simpleFlow algorithm can be found at samples/simpleflow_demo.cpp
 #[inline]
-pub fn calc_optical_flow_sf_1(from: &dyn core::ToInputArray, to: &dyn core::ToInputArray, flow: &mut dyn core::ToOutputArray, layers: i32, averaging_block_size: i32, max_flow: i32, sigma_dist: f64, sigma_color: f64, postprocess_window: i32, sigma_dist_fix: f64, sigma_color_fix: f64, occ_thr: f64, upscale_averaging_radius: i32, upscale_sigma_dist: f64, upscale_sigma_color: f64, speed_up_thr: f64) -> Result<()> {
+pub fn calc_optical_flow_sf_1(from: &(impl core::ToInputArray + ?Sized), to: &(impl core::ToInputArray + ?Sized), flow: &mut (impl core::ToOutputArray + ?Sized), layers: i32, averaging_block_size: i32, max_flow: i32, sigma_dist: f64, sigma_color: f64, postprocess_window: i32, sigma_dist_fix: f64, sigma_color_fix: f64, occ_thr: f64, upscale_averaging_radius: i32, upscale_sigma_dist: f64, upscale_sigma_color: f64, speed_up_thr: f64) -> Result<()> {
 	input_array_arg!(from);
 	input_array_arg!(to);
 	output_array_arg!(flow);
@@ -424,7 +424,7 @@ pub fn calc_optical_flow_sf_1(from: &dyn core::ToInputArray, to: &dyn core::ToIn
 /// * rlof_param: Ptr<RLOFOpticalFlowParameter>()
 /// * forward_backward_threshold: 0
 #[inline]
-pub fn calc_optical_flow_sparse_rlof(prev_img: &dyn core::ToInputArray, next_img: &dyn core::ToInputArray, prev_pts: &dyn core::ToInputArray, next_pts: &mut dyn core::ToInputOutputArray, status: &mut dyn core::ToOutputArray, err: &mut dyn core::ToOutputArray, mut rlof_param: core::Ptr<crate::optflow::RLOFOpticalFlowParameter>, forward_backward_threshold: f32) -> Result<()> {
+pub fn calc_optical_flow_sparse_rlof(prev_img: &(impl core::ToInputArray + ?Sized), next_img: &(impl core::ToInputArray + ?Sized), prev_pts: &(impl core::ToInputArray + ?Sized), next_pts: &mut (impl core::ToInputOutputArray + ?Sized), status: &mut (impl core::ToOutputArray + ?Sized), err: &mut (impl core::ToOutputArray + ?Sized), mut rlof_param: core::Ptr<crate::optflow::RLOFOpticalFlowParameter>, forward_backward_threshold: f32) -> Result<()> {
 	input_array_arg!(prev_img);
 	input_array_arg!(next_img);
 	input_array_arg!(prev_pts);
@@ -465,7 +465,7 @@ pub fn calc_optical_flow_sparse_rlof(prev_img: &dyn core::ToInputArray, next_img
 /// * fgs_lambda: 500.0f
 /// * fgs_sigma: 1.5f
 #[inline]
-pub fn calc_optical_flow_sparse_to_dense(from: &dyn core::ToInputArray, to: &dyn core::ToInputArray, flow: &mut dyn core::ToOutputArray, grid_step: i32, k: i32, sigma: f32, use_post_proc: bool, fgs_lambda: f32, fgs_sigma: f32) -> Result<()> {
+pub fn calc_optical_flow_sparse_to_dense(from: &(impl core::ToInputArray + ?Sized), to: &(impl core::ToInputArray + ?Sized), flow: &mut (impl core::ToOutputArray + ?Sized), grid_step: i32, k: i32, sigma: f32, use_post_proc: bool, fgs_lambda: f32, fgs_sigma: f32) -> Result<()> {
 	input_array_arg!(from);
 	input_array_arg!(to);
 	output_array_arg!(flow);
@@ -1460,7 +1460,7 @@ impl GPCDetails {
 	}
 	
 	#[inline]
-	pub fn get_coordinates_from_index(index: size_t, sz: core::Size, x: &mut i32, y: &mut i32) -> Result<()> {
+	pub fn get_coordinates_from_index(index: usize, sz: core::Size, x: &mut i32, y: &mut i32) -> Result<()> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_optflow_GPCDetails_getCoordinatesFromIndex_size_t_Size_intR_intR(index, sz.opencv_as_extern(), x, y, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -1592,6 +1592,14 @@ pub trait GPCPatchSampleTraitConst {
 		ret
 	}
 	
+	/// Same as `ref_()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn ref__ref(&self) -> BoxedRef<'_, crate::optflow::GPCPatchDescriptor> {
+		let ret = unsafe { sys::cv_optflow_GPCPatchSample_getPropRefRef_const(self.as_raw_GPCPatchSample()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn pos(&self) -> crate::optflow::GPCPatchDescriptor {
 		let ret = unsafe { sys::cv_optflow_GPCPatchSample_getPropPos_const(self.as_raw_GPCPatchSample()) };
@@ -1599,6 +1607,14 @@ pub trait GPCPatchSampleTraitConst {
 		ret
 	}
 	
+	/// Same as `pos()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn pos_ref(&self) -> BoxedRef<'_, crate::optflow::GPCPatchDescriptor> {
+		let ret = unsafe { sys::cv_optflow_GPCPatchSample_getPropPosRef_const(self.as_raw_GPCPatchSample()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn neg(&self) -> crate::optflow::GPCPatchDescriptor {
 		let ret = unsafe { sys::cv_optflow_GPCPatchSample_getPropNeg_const(self.as_raw_GPCPatchSample()) };
@@ -1606,6 +1622,14 @@ pub trait GPCPatchSampleTraitConst {
 		ret
 	}
 	
+	/// Same as `neg()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn neg_ref(&self) -> BoxedRef<'_, crate::optflow::GPCPatchDescriptor> {
+		let ret = unsafe { sys::cv_optflow_GPCPatchSample_getPropNegRef_const(self.as_raw_GPCPatchSample()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn get_directions(&self, refdir: &mut bool, posdir: &mut bool, negdir: &mut bool, coef: core::VecN<f64, 18>, rhs: f64) -> Result<()> {
 		return_send!(via ocvrs_return);
@@ -1713,7 +1737,7 @@ pub trait GPCTrainingSamplesTraitConst {
 	fn as_raw_GPCTrainingSamples(&self) -> *const c_void;
 
 	#[inline]
-	fn size(&self) -> Result<size_t> {
+	fn size(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_optflow_GPCTrainingSamples_size_const(self.as_raw_GPCTrainingSamples(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -1775,7 +1799,7 @@ impl GPCTrainingSamples {
 	}
 	
 	#[inline]
-	pub fn create_1(images_from: &dyn core::ToInputArray, images_to: &dyn core::ToInputArray, gt: &dyn core::ToInputArray, descriptor_type: i32) -> Result<core::Ptr<crate::optflow::GPCTrainingSamples>> {
+	pub fn create_1(images_from: &(impl core::ToInputArray + ?Sized), images_to: &(impl core::ToInputArray + ?Sized), gt: &(impl core::ToInputArray + ?Sized), descriptor_type: i32) -> Result<core::Ptr<crate::optflow::GPCTrainingSamples>> {
 		input_array_arg!(images_from);
 		input_array_arg!(images_to);
 		input_array_arg!(gt);
@@ -2763,4 +2787,5 @@ impl dyn SparseRLOFOpticalFlow + '_ {
 		Ok(ret)
 	}
 	
-}
\ No newline at end of file
+}
+pub use crate::manual::optflow::*;
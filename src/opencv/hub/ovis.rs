@@ -161,9 +161,9 @@ pub fn create_grid_mesh(name: &str, size: core::Size2f, segments: core::Size) ->
 /// ## C++ default parameters
 /// * image: noArray()
 #[inline]
-pub fn create_plane_mesh(name: &str, size: core::Size2f, image: &dyn core::ToInputArray) -> Result<()> {
+pub fn create_plane_mesh(name: &str, size: core::Size2f, image: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	extern_container_arg!(name);
-	input_array_arg!(image);
+	input_array_arg!(nullable image);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_ovis_createPlaneMesh_const_StringR_const_Size2fR_const__InputArrayR(name.opencv_as_extern(), &size, image.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -182,10 +182,10 @@ pub fn create_plane_mesh(name: &str, size: core::Size2f, image: &dyn core::ToInp
 /// ## C++ default parameters
 /// * colors: noArray()
 #[inline]
-pub fn create_point_cloud_mesh(name: &str, vertices: &dyn core::ToInputArray, colors: &dyn core::ToInputArray) -> Result<()> {
+pub fn create_point_cloud_mesh(name: &str, vertices: &(impl core::ToInputArray + ?Sized), colors: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	extern_container_arg!(name);
 	input_array_arg!(vertices);
-	input_array_arg!(colors);
+	input_array_arg!(nullable colors);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_ovis_createPointCloudMesh_const_StringR_const__InputArrayR_const__InputArrayR(name.opencv_as_extern(), vertices.as_raw__InputArray(), colors.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -206,11 +206,11 @@ pub fn create_point_cloud_mesh(name: &str, vertices: &dyn core::ToInputArray, co
 /// * normals: noArray()
 /// * indices: noArray()
 #[inline]
-pub fn create_triangle_mesh(name: &str, vertices: &dyn core::ToInputArray, normals: &dyn core::ToInputArray, indices: &dyn core::ToInputArray) -> Result<()> {
+pub fn create_triangle_mesh(name: &str, vertices: &(impl core::ToInputArray + ?Sized), normals: Option<&(impl core::ToInputArray + ?Sized)>, indices: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	extern_container_arg!(name);
 	input_array_arg!(vertices);
-	input_array_arg!(normals);
-	input_array_arg!(indices);
+	input_array_arg!(nullable normals);
+	input_array_arg!(nullable indices);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_ovis_createTriangleMesh_const_StringR_const__InputArrayR_const__InputArrayR_const__InputArrayR(name.opencv_as_extern(), vertices.as_raw__InputArray(), normals.as_raw__InputArray(), indices.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -297,7 +297,7 @@ pub fn set_material_property_1(name: &str, prop: i32, value: &str) -> Result<()>
 /// * prop: @ref MaterialProperty
 /// * value: the texture data
 #[inline]
-pub fn set_material_texture(name: &str, prop: i32, value: &dyn core::ToInputArray) -> Result<()> {
+pub fn set_material_texture(name: &str, prop: i32, value: &(impl core::ToInputArray + ?Sized)) -> Result<()> {
 	extern_container_arg!(name);
 	input_array_arg!(value);
 	return_send!(via ocvrs_return);
@@ -311,7 +311,7 @@ pub fn set_material_texture(name: &str, prop: i32, value: &dyn core::ToInputArra
 /// **Deprecated**: use setMaterialProperty
 #[deprecated = "use setMaterialProperty"]
 #[inline]
-pub fn update_texture(name: &str, image: &dyn core::ToInputArray) -> Result<()> {
+pub fn update_texture(name: &str, image: &(impl core::ToInputArray + ?Sized)) -> Result<()> {
 	extern_container_arg!(name);
 	input_array_arg!(image);
 	return_send!(via ocvrs_return);
@@ -409,11 +409,11 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// * tvec: noArray()
 	/// * rot: noArray()
 	#[inline]
-	fn create_entity(&mut self, name: &str, meshname: &str, tvec: &dyn core::ToInputArray, rot: &dyn core::ToInputArray) -> Result<()> {
+	fn create_entity(&mut self, name: &str, meshname: &str, tvec: Option<&dyn core::ToInputArray>, rot: Option<&dyn core::ToInputArray>) -> Result<()> {
 		extern_container_arg!(name);
 		extern_container_arg!(meshname);
-		input_array_arg!(tvec);
-		input_array_arg!(rot);
+		input_array_arg!(nullable tvec);
+		input_array_arg!(nullable rot);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ovis_WindowScene_createEntity_const_StringR_const_StringR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), meshname.opencv_as_extern(), tvec.as_raw__InputArray(), rot.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -507,11 +507,11 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// * rot: noArray()
 	/// * color: Scalar::all(1)
 	#[inline]
-	fn create_camera_entity(&mut self, name: &str, k: &dyn core::ToInputArray, imsize: core::Size, z_far: f32, tvec: &dyn core::ToInputArray, rot: &dyn core::ToInputArray, color: core::Scalar) -> Result<core::Rect2d> {
+	fn create_camera_entity(&mut self, name: &str, k: &dyn core::ToInputArray, imsize: core::Size, z_far: f32, tvec: Option<&dyn core::ToInputArray>, rot: Option<&dyn core::ToInputArray>, color: core::Scalar) -> Result<core::Rect2d> {
 		extern_container_arg!(name);
 		input_array_arg!(k);
-		input_array_arg!(tvec);
-		input_array_arg!(rot);
+		input_array_arg!(nullable tvec);
+		input_array_arg!(nullable rot);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ovis_WindowScene_createCameraEntity_const_StringR_const__InputArrayR_const_SizeR_float_const__InputArrayR_const__InputArrayR_const_ScalarR(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), k.as_raw__InputArray(), &imsize, z_far, tvec.as_raw__InputArray(), rot.as_raw__InputArray(), &color, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -533,10 +533,10 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// * diffuse_color: Scalar::all(1)
 	/// * specular_color: Scalar::all(1)
 	#[inline]
-	fn create_light_entity(&mut self, name: &str, tvec: &dyn core::ToInputArray, rot: &dyn core::ToInputArray, diffuse_color: core::Scalar, specular_color: core::Scalar) -> Result<()> {
+	fn create_light_entity(&mut self, name: &str, tvec: Option<&dyn core::ToInputArray>, rot: Option<&dyn core::ToInputArray>, diffuse_color: core::Scalar, specular_color: core::Scalar) -> Result<()> {
 		extern_container_arg!(name);
-		input_array_arg!(tvec);
-		input_array_arg!(rot);
+		input_array_arg!(nullable tvec);
+		input_array_arg!(nullable rot);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ovis_WindowScene_createLightEntity_const_StringR_const__InputArrayR_const__InputArrayR_const_ScalarR_const_ScalarR(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), tvec.as_raw__InputArray(), rot.as_raw__InputArray(), &diffuse_color, &specular_color, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -554,10 +554,10 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// * tvec: noArray()
 	/// * rot: noArray()
 	#[inline]
-	fn update_entity_pose(&mut self, name: &str, tvec: &dyn core::ToInputArray, rot: &dyn core::ToInputArray) -> Result<()> {
+	fn update_entity_pose(&mut self, name: &str, tvec: Option<&dyn core::ToInputArray>, rot: Option<&dyn core::ToInputArray>) -> Result<()> {
 		extern_container_arg!(name);
-		input_array_arg!(tvec);
-		input_array_arg!(rot);
+		input_array_arg!(nullable tvec);
+		input_array_arg!(nullable rot);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ovis_WindowScene_updateEntityPose_const_StringR_const__InputArrayR_const__InputArrayR(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), tvec.as_raw__InputArray(), rot.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -577,10 +577,10 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// * rot: noArray()
 	/// * invert: false
 	#[inline]
-	fn set_entity_pose(&mut self, name: &str, tvec: &dyn core::ToInputArray, rot: &dyn core::ToInputArray, invert: bool) -> Result<()> {
+	fn set_entity_pose(&mut self, name: &str, tvec: Option<&dyn core::ToInputArray>, rot: Option<&dyn core::ToInputArray>, invert: bool) -> Result<()> {
 		extern_container_arg!(name);
-		input_array_arg!(tvec);
-		input_array_arg!(rot);
+		input_array_arg!(nullable tvec);
+		input_array_arg!(nullable rot);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ovis_WindowScene_setEntityPose_const_StringR_const__InputArrayR_const__InputArrayR_bool(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), tvec.as_raw__InputArray(), rot.as_raw__InputArray(), invert, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -600,10 +600,10 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// * tvec: noArray()
 	/// * invert: false
 	#[inline]
-	fn get_entity_pose(&mut self, name: &str, r: &mut dyn core::ToOutputArray, tvec: &mut dyn core::ToOutputArray, invert: bool) -> Result<()> {
+	fn get_entity_pose(&mut self, name: &str, r: Option<&mut dyn core::ToOutputArray>, tvec: Option<&mut dyn core::ToOutputArray>, invert: bool) -> Result<()> {
 		extern_container_arg!(name);
-		output_array_arg!(r);
-		output_array_arg!(tvec);
+		output_array_arg!(nullable r);
+		output_array_arg!(nullable tvec);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ovis_WindowScene_getEntityPose_const_StringR_const__OutputArrayR_const__OutputArrayR_bool(self.as_raw_mut_WindowScene(), name.opencv_as_extern(), r.as_raw__OutputArray(), tvec.as_raw__OutputArray(), invert, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -716,8 +716,8 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// ## C++ default parameters
 	/// * up: noArray()
 	#[inline]
-	fn fix_camera_yaw_axis(&mut self, use_fixed: bool, up: &dyn core::ToInputArray) -> Result<()> {
-		input_array_arg!(up);
+	fn fix_camera_yaw_axis(&mut self, use_fixed: bool, up: Option<&dyn core::ToInputArray>) -> Result<()> {
+		input_array_arg!(nullable up);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ovis_WindowScene_fixCameraYawAxis_bool_const__InputArrayR(self.as_raw_mut_WindowScene(), use_fixed, up.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -736,9 +736,9 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// * rot: noArray()
 	/// * invert: false
 	#[inline]
-	fn set_camera_pose(&mut self, tvec: &dyn core::ToInputArray, rot: &dyn core::ToInputArray, invert: bool) -> Result<()> {
-		input_array_arg!(tvec);
-		input_array_arg!(rot);
+	fn set_camera_pose(&mut self, tvec: Option<&dyn core::ToInputArray>, rot: Option<&dyn core::ToInputArray>, invert: bool) -> Result<()> {
+		input_array_arg!(nullable tvec);
+		input_array_arg!(nullable rot);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ovis_WindowScene_setCameraPose_const__InputArrayR_const__InputArrayR_bool(self.as_raw_mut_WindowScene(), tvec.as_raw__InputArray(), rot.as_raw__InputArray(), invert, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -754,9 +754,9 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// ## C++ default parameters
 	/// * offset: noArray()
 	#[inline]
-	fn set_camera_look_at(&mut self, target: &str, offset: &dyn core::ToInputArray) -> Result<()> {
+	fn set_camera_look_at(&mut self, target: &str, offset: Option<&dyn core::ToInputArray>) -> Result<()> {
 		extern_container_arg!(target);
-		input_array_arg!(offset);
+		input_array_arg!(nullable offset);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ovis_WindowScene_setCameraLookAt_const_StringR_const__InputArrayR(self.as_raw_mut_WindowScene(), target.opencv_as_extern(), offset.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -774,10 +774,10 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// ## C++ default parameters
 	/// * offset: noArray()
 	#[inline]
-	fn set_entity_look_at(&mut self, origin: &str, target: &str, offset: &dyn core::ToInputArray) -> Result<()> {
+	fn set_entity_look_at(&mut self, origin: &str, target: &str, offset: Option<&dyn core::ToInputArray>) -> Result<()> {
 		extern_container_arg!(origin);
 		extern_container_arg!(target);
-		input_array_arg!(offset);
+		input_array_arg!(nullable offset);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ovis_WindowScene_setEntityLookAt_const_StringR_const_StringR_const__InputArrayR(self.as_raw_mut_WindowScene(), origin.opencv_as_extern(), target.opencv_as_extern(), offset.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -796,9 +796,9 @@ pub trait WindowScene: crate::ovis::WindowSceneConst {
 	/// * tvec: noArray()
 	/// * invert: false
 	#[inline]
-	fn get_camera_pose(&mut self, r: &mut dyn core::ToOutputArray, tvec: &mut dyn core::ToOutputArray, invert: bool) -> Result<()> {
-		output_array_arg!(r);
-		output_array_arg!(tvec);
+	fn get_camera_pose(&mut self, r: Option<&mut dyn core::ToOutputArray>, tvec: Option<&mut dyn core::ToOutputArray>, invert: bool) -> Result<()> {
+		output_array_arg!(nullable r);
+		output_array_arg!(nullable tvec);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ovis_WindowScene_getCameraPose_const__OutputArrayR_const__OutputArrayR_bool(self.as_raw_mut_WindowScene(), r.as_raw__OutputArray(), tvec.as_raw__OutputArray(), invert, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -132,10 +132,10 @@ pub trait PhaseUnwrapping: core::AlgorithmTrait + crate::phase_unwrapping::Phase
 	/// ## C++ default parameters
 	/// * shadow_mask: noArray()
 	#[inline]
-	fn unwrap_phase_map(&mut self, wrapped_phase_map: &dyn core::ToInputArray, unwrapped_phase_map: &mut dyn core::ToOutputArray, shadow_mask: &dyn core::ToInputArray) -> Result<()> {
+	fn unwrap_phase_map(&mut self, wrapped_phase_map: &dyn core::ToInputArray, unwrapped_phase_map: &mut dyn core::ToOutputArray, shadow_mask: Option<&dyn core::ToInputArray>) -> Result<()> {
 		input_array_arg!(wrapped_phase_map);
 		output_array_arg!(unwrapped_phase_map);
-		input_array_arg!(shadow_mask);
+		input_array_arg!(nullable shadow_mask);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_phase_unwrapping_PhaseUnwrapping_unwrapPhaseMap_const__InputArrayR_const__OutputArrayR_const__InputArrayR(self.as_raw_mut_PhaseUnwrapping(), wrapped_phase_map.as_raw__InputArray(), unwrapped_phase_map.as_raw__OutputArray(), shadow_mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -144,3 +144,4 @@ pub trait PhaseUnwrapping: core::AlgorithmTrait + crate::phase_unwrapping::Phase
 	}
 	
 }
+pub use crate::manual::phase_unwrapping::*;
@@ -78,7 +78,7 @@ pub const RECURS_FILTER: i32 = 1;
 /// * green_mul: 1.0f
 /// * blue_mul: 1.0f
 #[inline]
-pub fn color_change(src: &dyn core::ToInputArray, mask: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, red_mul: f32, green_mul: f32, blue_mul: f32) -> Result<()> {
+pub fn color_change(src: &(impl core::ToInputArray + ?Sized), mask: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), red_mul: f32, green_mul: f32, blue_mul: f32) -> Result<()> {
 	input_array_arg!(src);
 	input_array_arg!(mask);
 	output_array_arg!(dst);
@@ -312,7 +312,7 @@ pub fn create_tonemap(gamma: f32) -> Result<core::Ptr<dyn crate::photo::Tonemap>
 /// * block_size: 7
 /// * stream: Stream::Null()
 #[inline]
-pub fn fast_nl_means_denoising_colored_cuda(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, h_luminance: f32, photo_render: f32, search_window: i32, block_size: i32, stream: &mut core::Stream) -> Result<()> {
+pub fn fast_nl_means_denoising_colored_cuda(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), h_luminance: f32, photo_render: f32, search_window: i32, block_size: i32, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -348,7 +348,7 @@ pub fn fast_nl_means_denoising_colored_cuda(src: &dyn core::ToInputArray, dst: &
 /// * block_size: 7
 /// * stream: Stream::Null()
 #[inline]
-pub fn fast_nl_means_denoising_cuda(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, h: f32, search_window: i32, block_size: i32, stream: &mut core::Stream) -> Result<()> {
+pub fn fast_nl_means_denoising_cuda(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), h: f32, search_window: i32, block_size: i32, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -378,7 +378,7 @@ pub fn fast_nl_means_denoising_cuda(src: &dyn core::ToInputArray, dst: &mut dyn
 /// * border_mode: BORDER_DEFAULT
 /// * stream: Stream::Null()
 #[inline]
-pub fn non_local_means(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, h: f32, search_window: i32, block_size: i32, border_mode: i32, stream: &mut core::Stream) -> Result<()> {
+pub fn non_local_means(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), h: f32, search_window: i32, block_size: i32, border_mode: i32, stream: &mut core::Stream) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -399,7 +399,7 @@ pub fn non_local_means(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutpu
 /// 
 /// This function is to be applied on color images.
 #[inline]
-pub fn decolor(src: &dyn core::ToInputArray, grayscale: &mut dyn core::ToOutputArray, color_boost: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn decolor(src: &(impl core::ToInputArray + ?Sized), grayscale: &mut (impl core::ToOutputArray + ?Sized), color_boost: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(grayscale);
 	output_array_arg!(color_boost);
@@ -472,7 +472,7 @@ pub fn denoise_tvl1(observations: &core::Vector<core::Mat>, result: &mut core::M
 /// * sigma_s: 10
 /// * sigma_r: 0.15f
 #[inline]
-pub fn detail_enhance(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, sigma_s: f32, sigma_r: f32) -> Result<()> {
+pub fn detail_enhance(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), sigma_s: f32, sigma_r: f32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -497,7 +497,7 @@ pub fn detail_enhance(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutput
 /// * sigma_s: 60
 /// * sigma_r: 0.4f
 #[inline]
-pub fn edge_preserving_filter(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, flags: i32, sigma_s: f32, sigma_r: f32) -> Result<()> {
+pub fn edge_preserving_filter(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), flags: i32, sigma_s: f32, sigma_r: f32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -537,7 +537,7 @@ pub fn edge_preserving_filter(src: &dyn core::ToInputArray, dst: &mut dyn core::
 /// * template_window_size: 7
 /// * search_window_size: 21
 #[inline]
-pub fn fast_nl_means_denoising_colored_multi(src_imgs: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, img_to_denoise_index: i32, temporal_window_size: i32, h: f32, h_color: f32, template_window_size: i32, search_window_size: i32) -> Result<()> {
+pub fn fast_nl_means_denoising_colored_multi(src_imgs: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), img_to_denoise_index: i32, temporal_window_size: i32, h: f32, h_color: f32, template_window_size: i32, search_window_size: i32) -> Result<()> {
 	input_array_arg!(src_imgs);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -572,7 +572,7 @@ pub fn fast_nl_means_denoising_colored_multi(src_imgs: &dyn core::ToInputArray,
 /// * template_window_size: 7
 /// * search_window_size: 21
 #[inline]
-pub fn fast_nl_means_denoising_colored(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, h: f32, h_color: f32, template_window_size: i32, search_window_size: i32) -> Result<()> {
+pub fn fast_nl_means_denoising_colored(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), h: f32, h_color: f32, template_window_size: i32, search_window_size: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -613,7 +613,7 @@ pub fn fast_nl_means_denoising_colored(src: &dyn core::ToInputArray, dst: &mut d
 /// * search_window_size: 21
 /// * norm_type: NORM_L2
 #[inline]
-pub fn fast_nl_means_denoising_multi_vec(src_imgs: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, img_to_denoise_index: i32, temporal_window_size: i32, h: &core::Vector<f32>, template_window_size: i32, search_window_size: i32, norm_type: i32) -> Result<()> {
+pub fn fast_nl_means_denoising_multi_vec(src_imgs: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), img_to_denoise_index: i32, temporal_window_size: i32, h: &core::Vector<f32>, template_window_size: i32, search_window_size: i32, norm_type: i32) -> Result<()> {
 	input_array_arg!(src_imgs);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -652,7 +652,7 @@ pub fn fast_nl_means_denoising_multi_vec(src_imgs: &dyn core::ToInputArray, dst:
 /// * template_window_size: 7
 /// * search_window_size: 21
 #[inline]
-pub fn fast_nl_means_denoising_multi(src_imgs: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, img_to_denoise_index: i32, temporal_window_size: i32, h: f32, template_window_size: i32, search_window_size: i32) -> Result<()> {
+pub fn fast_nl_means_denoising_multi(src_imgs: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), img_to_denoise_index: i32, temporal_window_size: i32, h: f32, template_window_size: i32, search_window_size: i32) -> Result<()> {
 	input_array_arg!(src_imgs);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -692,7 +692,7 @@ pub fn fast_nl_means_denoising_multi(src_imgs: &dyn core::ToInputArray, dst: &mu
 /// * search_window_size: 21
 /// * norm_type: NORM_L2
 #[inline]
-pub fn fast_nl_means_denoising_vec(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, h: &core::Vector<f32>, template_window_size: i32, search_window_size: i32, norm_type: i32) -> Result<()> {
+pub fn fast_nl_means_denoising_vec(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), h: &core::Vector<f32>, template_window_size: i32, search_window_size: i32, norm_type: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -728,7 +728,7 @@ pub fn fast_nl_means_denoising_vec(src: &dyn core::ToInputArray, dst: &mut dyn c
 /// * template_window_size: 7
 /// * search_window_size: 21
 #[inline]
-pub fn fast_nl_means_denoising(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, h: f32, template_window_size: i32, search_window_size: i32) -> Result<()> {
+pub fn fast_nl_means_denoising(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), h: f32, template_window_size: i32, search_window_size: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -754,7 +754,7 @@ pub fn fast_nl_means_denoising(src: &dyn core::ToInputArray, dst: &mut dyn core:
 /// * alpha: 0.2f
 /// * beta: 0.4f
 #[inline]
-pub fn illumination_change(src: &dyn core::ToInputArray, mask: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, alpha: f32, beta: f32) -> Result<()> {
+pub fn illumination_change(src: &(impl core::ToInputArray + ?Sized), mask: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), alpha: f32, beta: f32) -> Result<()> {
 	input_array_arg!(src);
 	input_array_arg!(mask);
 	output_array_arg!(dst);
@@ -787,7 +787,7 @@ pub fn illumination_change(src: &dyn core::ToInputArray, mask: &dyn core::ToInpu
 ///    *   (Python) An example using the inpainting technique can be found at
 ///        opencv_source_code/samples/python/inpaint.py
 #[inline]
-pub fn inpaint(src: &dyn core::ToInputArray, inpaint_mask: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, inpaint_radius: f64, flags: i32) -> Result<()> {
+pub fn inpaint(src: &(impl core::ToInputArray + ?Sized), inpaint_mask: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), inpaint_radius: f64, flags: i32) -> Result<()> {
 	input_array_arg!(src);
 	input_array_arg!(inpaint_mask);
 	output_array_arg!(dst);
@@ -816,7 +816,7 @@ pub fn inpaint(src: &dyn core::ToInputArray, inpaint_mask: &dyn core::ToInputArr
 /// * sigma_r: 0.07f
 /// * shade_factor: 0.02f
 #[inline]
-pub fn pencil_sketch(src: &dyn core::ToInputArray, dst1: &mut dyn core::ToOutputArray, dst2: &mut dyn core::ToOutputArray, sigma_s: f32, sigma_r: f32, shade_factor: f32) -> Result<()> {
+pub fn pencil_sketch(src: &(impl core::ToInputArray + ?Sized), dst1: &mut (impl core::ToOutputArray + ?Sized), dst2: &mut (impl core::ToOutputArray + ?Sized), sigma_s: f32, sigma_r: f32, shade_factor: f32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst1);
 	output_array_arg!(dst2);
@@ -844,7 +844,7 @@ pub fn pencil_sketch(src: &dyn core::ToInputArray, dst1: &mut dyn core::ToOutput
 /// * blend: Output image with the same size and type as dst.
 /// * flags: Cloning method that could be cv::NORMAL_CLONE, cv::MIXED_CLONE or cv::MONOCHROME_TRANSFER
 #[inline]
-pub fn seamless_clone(src: &dyn core::ToInputArray, dst: &dyn core::ToInputArray, mask: &dyn core::ToInputArray, p: core::Point, blend: &mut dyn core::ToOutputArray, flags: i32) -> Result<()> {
+pub fn seamless_clone(src: &(impl core::ToInputArray + ?Sized), dst: &(impl core::ToInputArray + ?Sized), mask: &(impl core::ToInputArray + ?Sized), p: core::Point, blend: &mut (impl core::ToOutputArray + ?Sized), flags: i32) -> Result<()> {
 	input_array_arg!(src);
 	input_array_arg!(dst);
 	input_array_arg!(mask);
@@ -870,7 +870,7 @@ pub fn seamless_clone(src: &dyn core::ToInputArray, dst: &dyn core::ToInputArray
 /// * sigma_s: 60
 /// * sigma_r: 0.45f
 #[inline]
-pub fn stylization(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, sigma_s: f32, sigma_r: f32) -> Result<()> {
+pub fn stylization(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), sigma_s: f32, sigma_r: f32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -902,7 +902,7 @@ pub fn stylization(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArr
 /// * high_threshold: 45
 /// * kernel_size: 3
 #[inline]
-pub fn texture_flattening(src: &dyn core::ToInputArray, mask: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, low_threshold: f32, high_threshold: f32, kernel_size: i32) -> Result<()> {
+pub fn texture_flattening(src: &(impl core::ToInputArray + ?Sized), mask: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), low_threshold: f32, high_threshold: f32, kernel_size: i32) -> Result<()> {
 	input_array_arg!(src);
 	input_array_arg!(mask);
 	output_array_arg!(dst);
@@ -1686,3 +1686,4 @@ pub trait TonemapReinhard: crate::photo::Tonemap + crate::photo::TonemapReinhard
 	}
 	
 }
+pub use crate::manual::photo::*;
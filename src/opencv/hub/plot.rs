@@ -203,7 +203,7 @@ impl dyn Plot2d + '_ {
 	/// * data: ![inline formula](https://latex.codecogs.com/png.latex?1xN) or ![inline formula](https://latex.codecogs.com/png.latex?Nx1) matrix containing ![inline formula](https://latex.codecogs.com/png.latex?Y) values of points to plot. ![inline formula](https://latex.codecogs.com/png.latex?X) values
 	/// will be equal to indexes of correspondind elements in data matrix.
 	#[inline]
-	pub fn create(data: &dyn core::ToInputArray) -> Result<core::Ptr<dyn crate::plot::Plot2d>> {
+	pub fn create(data: &(impl core::ToInputArray + ?Sized)) -> Result<core::Ptr<dyn crate::plot::Plot2d>> {
 		input_array_arg!(data);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_plot_Plot2d_create_const__InputArrayR(data.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
@@ -219,7 +219,7 @@ impl dyn Plot2d + '_ {
 	/// * dataX: ![inline formula](https://latex.codecogs.com/png.latex?1xN) or ![inline formula](https://latex.codecogs.com/png.latex?Nx1) matrix ![inline formula](https://latex.codecogs.com/png.latex?X) values of points to plot.
 	/// * dataY: ![inline formula](https://latex.codecogs.com/png.latex?1xN) or ![inline formula](https://latex.codecogs.com/png.latex?Nx1) matrix containing ![inline formula](https://latex.codecogs.com/png.latex?Y) values of points to plot.
 	#[inline]
-	pub fn create_1(data_x: &dyn core::ToInputArray, data_y: &dyn core::ToInputArray) -> Result<core::Ptr<dyn crate::plot::Plot2d>> {
+	pub fn create_1(data_x: &(impl core::ToInputArray + ?Sized), data_y: &(impl core::ToInputArray + ?Sized)) -> Result<core::Ptr<dyn crate::plot::Plot2d>> {
 		input_array_arg!(data_x);
 		input_array_arg!(data_y);
 		return_send!(via ocvrs_return);
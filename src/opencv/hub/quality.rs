@@ -132,7 +132,7 @@ impl QualityBRISQUE {
 	/// ## Returns
 	/// cv::Scalar with the score in the first element.  The score ranges from 0 (best quality) to 100 (worst quality)
 	#[inline]
-	pub fn compute(img: &dyn core::ToInputArray, model_file_path: &str, range_file_path: &str) -> Result<core::Scalar> {
+	pub fn compute(img: &(impl core::ToInputArray + ?Sized), model_file_path: &str, range_file_path: &str) -> Result<core::Scalar> {
 		input_array_arg!(img);
 		extern_container_arg!(model_file_path);
 		extern_container_arg!(range_file_path);
@@ -148,7 +148,7 @@ impl QualityBRISQUE {
 	/// * img: image (BGR(A) or grayscale) for which to compute features
 	/// * features: output row vector of features to cv::Mat or cv::UMat
 	#[inline]
-	pub fn compute_features(img: &dyn core::ToInputArray, features: &mut dyn core::ToOutputArray) -> Result<()> {
+	pub fn compute_features(img: &(impl core::ToInputArray + ?Sized), features: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 		input_array_arg!(img);
 		output_array_arg!(features);
 		return_send!(via ocvrs_return);
@@ -310,7 +310,7 @@ impl QualityGMSD {
 	/// ## Parameters
 	/// * ref: reference image
 	#[inline]
-	pub fn create(ref_: &dyn core::ToInputArray) -> Result<core::Ptr<crate::quality::QualityGMSD>> {
+	pub fn create(ref_: &(impl core::ToInputArray + ?Sized)) -> Result<core::Ptr<crate::quality::QualityGMSD>> {
 		input_array_arg!(ref_);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_quality_QualityGMSD_create_const__InputArrayR(ref_.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
@@ -328,7 +328,7 @@ impl QualityGMSD {
 	/// ## Returns
 	/// cv::Scalar with per-channel quality value.  Values range from 0 (worst) to 1 (best)
 	#[inline]
-	pub fn compute(ref_: &dyn core::ToInputArray, cmp: &dyn core::ToInputArray, quality_map: &mut dyn core::ToOutputArray) -> Result<core::Scalar> {
+	pub fn compute(ref_: &(impl core::ToInputArray + ?Sized), cmp: &(impl core::ToInputArray + ?Sized), quality_map: &mut (impl core::ToOutputArray + ?Sized)) -> Result<core::Scalar> {
 		input_array_arg!(ref_);
 		input_array_arg!(cmp);
 		output_array_arg!(quality_map);
@@ -434,7 +434,7 @@ impl QualityMSE {
 	/// ## Parameters
 	/// * ref: input image to use as the reference for comparison
 	#[inline]
-	pub fn create(ref_: &dyn core::ToInputArray) -> Result<core::Ptr<crate::quality::QualityMSE>> {
+	pub fn create(ref_: &(impl core::ToInputArray + ?Sized)) -> Result<core::Ptr<crate::quality::QualityMSE>> {
 		input_array_arg!(ref_);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_quality_QualityMSE_create_const__InputArrayR(ref_.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
@@ -452,7 +452,7 @@ impl QualityMSE {
 	/// ## Returns
 	/// cv::Scalar with per-channel quality values.  Values range from 0 (best) to max float (worst)
 	#[inline]
-	pub fn compute(ref_: &dyn core::ToInputArray, cmp: &dyn core::ToInputArray, quality_map: &mut dyn core::ToOutputArray) -> Result<core::Scalar> {
+	pub fn compute(ref_: &(impl core::ToInputArray + ?Sized), cmp: &(impl core::ToInputArray + ?Sized), quality_map: &mut (impl core::ToOutputArray + ?Sized)) -> Result<core::Scalar> {
 		input_array_arg!(ref_);
 		input_array_arg!(cmp);
 		output_array_arg!(quality_map);
@@ -585,7 +585,7 @@ impl QualityPSNR {
 	/// ## C++ default parameters
 	/// * max_pixel_value: QualityPSNR::MAX_PIXEL_VALUE_DEFAULT
 	#[inline]
-	pub fn create(ref_: &dyn core::ToInputArray, max_pixel_value: f64) -> Result<core::Ptr<crate::quality::QualityPSNR>> {
+	pub fn create(ref_: &(impl core::ToInputArray + ?Sized), max_pixel_value: f64) -> Result<core::Ptr<crate::quality::QualityPSNR>> {
 		input_array_arg!(ref_);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_quality_QualityPSNR_create_const__InputArrayR_double(ref_.as_raw__InputArray(), max_pixel_value, ocvrs_return.as_mut_ptr()) };
@@ -607,7 +607,7 @@ impl QualityPSNR {
 	/// ## C++ default parameters
 	/// * max_pixel_value: QualityPSNR::MAX_PIXEL_VALUE_DEFAULT
 	#[inline]
-	pub fn compute(ref_: &dyn core::ToInputArray, cmp: &dyn core::ToInputArray, quality_map: &mut dyn core::ToOutputArray, max_pixel_value: f64) -> Result<core::Scalar> {
+	pub fn compute(ref_: &(impl core::ToInputArray + ?Sized), cmp: &(impl core::ToInputArray + ?Sized), quality_map: &mut (impl core::ToOutputArray + ?Sized), max_pixel_value: f64) -> Result<core::Scalar> {
 		input_array_arg!(ref_);
 		input_array_arg!(cmp);
 		output_array_arg!(quality_map);
@@ -713,7 +713,7 @@ impl QualitySSIM {
 	/// ## Parameters
 	/// * ref: input image to use as the reference image for comparison
 	#[inline]
-	pub fn create(ref_: &dyn core::ToInputArray) -> Result<core::Ptr<crate::quality::QualitySSIM>> {
+	pub fn create(ref_: &(impl core::ToInputArray + ?Sized)) -> Result<core::Ptr<crate::quality::QualitySSIM>> {
 		input_array_arg!(ref_);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_quality_QualitySSIM_create_const__InputArrayR(ref_.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
@@ -731,7 +731,7 @@ impl QualitySSIM {
 	/// ## Returns
 	/// cv::Scalar with per-channel quality values.  Values range from 0 (worst) to 1 (best)
 	#[inline]
-	pub fn compute(ref_: &dyn core::ToInputArray, cmp: &dyn core::ToInputArray, quality_map: &mut dyn core::ToOutputArray) -> Result<core::Scalar> {
+	pub fn compute(ref_: &(impl core::ToInputArray + ?Sized), cmp: &(impl core::ToInputArray + ?Sized), quality_map: &mut (impl core::ToOutputArray + ?Sized)) -> Result<core::Scalar> {
 		input_array_arg!(ref_);
 		input_array_arg!(cmp);
 		output_array_arg!(quality_map);
@@ -745,3 +745,4 @@ impl QualitySSIM {
 }
 
 boxed_cast_base! { QualitySSIM, core::Algorithm, cv_QualitySSIM_to_Algorithm }
+pub use crate::manual::quality::*;
@@ -28,12 +28,12 @@ pub mod prelude {
 /// * pts3d: noArray()
 /// * mask: noArray()
 #[inline]
-pub fn convert_correspondencies(cols: &dyn core::ToInputArray, src_locations: &dyn core::ToInputArray, pts2d: &mut dyn core::ToOutputArray, pts3d: &mut dyn core::ToInputOutputArray, mask: &dyn core::ToInputArray) -> Result<()> {
+pub fn convert_correspondencies(cols: &(impl core::ToInputArray + ?Sized), src_locations: &(impl core::ToInputArray + ?Sized), pts2d: &mut (impl core::ToOutputArray + ?Sized), pts3d: Option<&mut (impl core::ToInputOutputArray + ?Sized)>, mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(cols);
 	input_array_arg!(src_locations);
 	output_array_arg!(pts2d);
-	input_output_array_arg!(pts3d);
-	input_array_arg!(mask);
+	input_output_array_arg!(nullable pts3d);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_rapid_convertCorrespondencies_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputOutputArrayR_const__InputArrayR(cols.as_raw__InputArray(), src_locations.as_raw__InputArray(), pts2d.as_raw__OutputArray(), pts3d.as_raw__InputOutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -50,10 +50,10 @@ pub fn convert_correspondencies(cols: &dyn core::ToInputArray, src_locations: &d
 /// ## C++ default parameters
 /// * colors: noArray()
 #[inline]
-pub fn draw_correspondencies(bundle: &mut dyn core::ToInputOutputArray, cols: &dyn core::ToInputArray, colors: &dyn core::ToInputArray) -> Result<()> {
+pub fn draw_correspondencies(bundle: &mut (impl core::ToInputOutputArray + ?Sized), cols: &(impl core::ToInputArray + ?Sized), colors: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	input_output_array_arg!(bundle);
 	input_array_arg!(cols);
-	input_array_arg!(colors);
+	input_array_arg!(nullable colors);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_rapid_drawCorrespondencies_const__InputOutputArrayR_const__InputArrayR_const__InputArrayR(bundle.as_raw__InputOutputArray(), cols.as_raw__InputArray(), colors.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -67,7 +67,7 @@ pub fn draw_correspondencies(bundle: &mut dyn core::ToInputOutputArray, cols: &d
 /// * locations: the source locations of a line bundle
 /// * color: the line color
 #[inline]
-pub fn draw_search_lines(img: &mut dyn core::ToInputOutputArray, locations: &dyn core::ToInputArray, color: core::Scalar) -> Result<()> {
+pub fn draw_search_lines(img: &mut (impl core::ToInputOutputArray + ?Sized), locations: &(impl core::ToInputArray + ?Sized), color: core::Scalar) -> Result<()> {
 	input_output_array_arg!(img);
 	input_array_arg!(locations);
 	return_send!(via ocvrs_return);
@@ -90,7 +90,7 @@ pub fn draw_search_lines(img: &mut dyn core::ToInputOutputArray, locations: &dyn
 /// * typ: LINE_8
 /// * cull_backface: false
 #[inline]
-pub fn draw_wireframe(img: &mut dyn core::ToInputOutputArray, pts2d: &dyn core::ToInputArray, tris: &dyn core::ToInputArray, color: core::Scalar, typ: i32, cull_backface: bool) -> Result<()> {
+pub fn draw_wireframe(img: &mut (impl core::ToInputOutputArray + ?Sized), pts2d: &(impl core::ToInputArray + ?Sized), tris: &(impl core::ToInputArray + ?Sized), color: core::Scalar, typ: i32, cull_backface: bool) -> Result<()> {
 	input_output_array_arg!(img);
 	input_array_arg!(pts2d);
 	input_array_arg!(tris);
@@ -116,7 +116,7 @@ pub fn draw_wireframe(img: &mut dyn core::ToInputOutputArray, pts2d: &dyn core::
 /// * ctl2d: the 2D locations of the control points
 /// * ctl3d: matching 3D points of the mesh
 #[inline]
-pub fn extract_control_points(num: i32, len: i32, pts3d: &dyn core::ToInputArray, rvec: &dyn core::ToInputArray, tvec: &dyn core::ToInputArray, k: &dyn core::ToInputArray, imsize: core::Size, tris: &dyn core::ToInputArray, ctl2d: &mut dyn core::ToOutputArray, ctl3d: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn extract_control_points(num: i32, len: i32, pts3d: &(impl core::ToInputArray + ?Sized), rvec: &(impl core::ToInputArray + ?Sized), tvec: &(impl core::ToInputArray + ?Sized), k: &(impl core::ToInputArray + ?Sized), imsize: core::Size, tris: &(impl core::ToInputArray + ?Sized), ctl2d: &mut (impl core::ToOutputArray + ?Sized), ctl3d: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(pts3d);
 	input_array_arg!(rvec);
 	input_array_arg!(tvec);
@@ -140,7 +140,7 @@ pub fn extract_control_points(num: i32, len: i32, pts3d: &dyn core::ToInputArray
 /// * bundle: line bundle image with size `ctl2d.rows() x (2 * len + 1)` and the same type as @p img
 /// * srcLocations: the source pixel locations of @p bundle in @p img as CV_16SC2
 #[inline]
-pub fn extract_line_bundle(len: i32, ctl2d: &dyn core::ToInputArray, img: &dyn core::ToInputArray, bundle: &mut dyn core::ToOutputArray, src_locations: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn extract_line_bundle(len: i32, ctl2d: &(impl core::ToInputArray + ?Sized), img: &(impl core::ToInputArray + ?Sized), bundle: &mut (impl core::ToOutputArray + ?Sized), src_locations: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(ctl2d);
 	input_array_arg!(img);
 	output_array_arg!(bundle);
@@ -162,10 +162,10 @@ pub fn extract_line_bundle(len: i32, ctl2d: &dyn core::ToInputArray, img: &dyn c
 /// ## C++ default parameters
 /// * response: noArray()
 #[inline]
-pub fn find_correspondencies(bundle: &dyn core::ToInputArray, cols: &mut dyn core::ToOutputArray, response: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn find_correspondencies(bundle: &(impl core::ToInputArray + ?Sized), cols: &mut (impl core::ToOutputArray + ?Sized), response: Option<&mut (impl core::ToOutputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(bundle);
 	output_array_arg!(cols);
-	output_array_arg!(response);
+	output_array_arg!(nullable response);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_rapid_findCorrespondencies_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(bundle.as_raw__InputArray(), cols.as_raw__OutputArray(), response.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -197,7 +197,7 @@ pub fn find_correspondencies(bundle: &dyn core::ToInputArray, cols: &mut dyn cor
 /// ## C++ default parameters
 /// * rmsd: 0
 #[inline]
-pub fn rapid(img: &dyn core::ToInputArray, num: i32, len: i32, pts3d: &dyn core::ToInputArray, tris: &dyn core::ToInputArray, k: &dyn core::ToInputArray, rvec: &mut dyn core::ToInputOutputArray, tvec: &mut dyn core::ToInputOutputArray, rmsd: &mut f64) -> Result<f32> {
+pub fn rapid(img: &(impl core::ToInputArray + ?Sized), num: i32, len: i32, pts3d: &(impl core::ToInputArray + ?Sized), tris: &(impl core::ToInputArray + ?Sized), k: &(impl core::ToInputArray + ?Sized), rvec: &mut (impl core::ToInputOutputArray + ?Sized), tvec: &mut (impl core::ToInputOutputArray + ?Sized), rmsd: &mut f64) -> Result<f32> {
 	input_array_arg!(img);
 	input_array_arg!(pts3d);
 	input_array_arg!(tris);
@@ -227,7 +227,7 @@ impl dyn GOSTracker + '_ {
 	/// * hist_bins: 4
 	/// * sobel_thesh: 10
 	#[inline]
-	pub fn create(pts3d: &dyn core::ToInputArray, tris: &dyn core::ToInputArray, hist_bins: i32, sobel_thesh: u8) -> Result<core::Ptr<dyn crate::rapid::OLSTracker>> {
+	pub fn create(pts3d: &(impl core::ToInputArray + ?Sized), tris: &(impl core::ToInputArray + ?Sized), hist_bins: i32, sobel_thesh: u8) -> Result<core::Ptr<dyn crate::rapid::OLSTracker>> {
 		input_array_arg!(pts3d);
 		input_array_arg!(tris);
 		return_send!(via ocvrs_return);
@@ -256,7 +256,7 @@ impl dyn OLSTracker + '_ {
 	/// * hist_bins: 8
 	/// * sobel_thesh: 10
 	#[inline]
-	pub fn create(pts3d: &dyn core::ToInputArray, tris: &dyn core::ToInputArray, hist_bins: i32, sobel_thesh: u8) -> Result<core::Ptr<dyn crate::rapid::OLSTracker>> {
+	pub fn create(pts3d: &(impl core::ToInputArray + ?Sized), tris: &(impl core::ToInputArray + ?Sized), hist_bins: i32, sobel_thesh: u8) -> Result<core::Ptr<dyn crate::rapid::OLSTracker>> {
 		input_array_arg!(pts3d);
 		input_array_arg!(tris);
 		return_send!(via ocvrs_return);
@@ -281,7 +281,7 @@ pub trait Rapid: crate::rapid::RapidConst + crate::rapid::Tracker {
 
 impl dyn Rapid + '_ {
 	#[inline]
-	pub fn create(pts3d: &dyn core::ToInputArray, tris: &dyn core::ToInputArray) -> Result<core::Ptr<dyn crate::rapid::Rapid>> {
+	pub fn create(pts3d: &(impl core::ToInputArray + ?Sized), tris: &(impl core::ToInputArray + ?Sized)) -> Result<core::Ptr<dyn crate::rapid::Rapid>> {
 		input_array_arg!(pts3d);
 		input_array_arg!(tris);
 		return_send!(via ocvrs_return);
@@ -327,3 +327,4 @@ pub trait Tracker: core::AlgorithmTrait + crate::rapid::TrackerConst {
 	}
 	
 }
+pub use crate::manual::rapid::*;
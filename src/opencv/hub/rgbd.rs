@@ -96,7 +96,7 @@ pub fn colormap(quantized: &core::Mat, dst: &mut core::Mat) -> Result<()> {
 /// ## C++ default parameters
 /// * size: 10
 #[inline]
-pub fn draw_features(img: &mut dyn core::ToInputOutputArray, templates: &core::Vector<crate::rgbd::Linemod_Template>, tl: core::Point2i, size: i32) -> Result<()> {
+pub fn draw_features(img: &mut (impl core::ToInputOutputArray + ?Sized), templates: &core::Vector<crate::rgbd::Linemod_Template>, tl: core::Point2i, size: i32) -> Result<()> {
 	input_output_array_arg!(img);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_linemod_drawFeatures_const__InputOutputArrayR_const_vectorLTemplateGR_const_Point2iR_int(img.as_raw__InputOutputArray(), templates.as_raw_VectorOfLinemod_Template(), &tl, size, ocvrs_return.as_mut_ptr()) };
@@ -138,7 +138,7 @@ pub fn get_default_linemod() -> Result<core::Ptr<crate::rgbd::Linemod_Detector>>
 /// * in_points: the list of xy coordinates
 /// * points3d: the resulting 3d points
 #[inline]
-pub fn depth_to3d_sparse(depth: &dyn core::ToInputArray, in_k: &dyn core::ToInputArray, in_points: &dyn core::ToInputArray, points3d: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn depth_to3d_sparse(depth: &(impl core::ToInputArray + ?Sized), in_k: &(impl core::ToInputArray + ?Sized), in_points: &(impl core::ToInputArray + ?Sized), points3d: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(depth);
 	input_array_arg!(in_k);
 	input_array_arg!(in_points);
@@ -163,11 +163,11 @@ pub fn depth_to3d_sparse(depth: &dyn core::ToInputArray, in_k: &dyn core::ToInpu
 /// ## C++ default parameters
 /// * mask: noArray()
 #[inline]
-pub fn depth_to3d(depth: &dyn core::ToInputArray, k: &dyn core::ToInputArray, points3d: &mut dyn core::ToOutputArray, mask: &dyn core::ToInputArray) -> Result<()> {
+pub fn depth_to3d(depth: &(impl core::ToInputArray + ?Sized), k: &(impl core::ToInputArray + ?Sized), points3d: &mut (impl core::ToOutputArray + ?Sized), mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(depth);
 	input_array_arg!(k);
 	output_array_arg!(points3d);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_rgbd_depthTo3d_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR(depth.as_raw__InputArray(), k.as_raw__InputArray(), points3d.as_raw__OutputArray(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -255,7 +255,7 @@ pub fn is_valid_depth_3(depth: &u16) -> Result<bool> {
 /// ## C++ default parameters
 /// * depth_dilation: false
 #[inline]
-pub fn register_depth(unregistered_camera_matrix: &dyn core::ToInputArray, registered_camera_matrix: &dyn core::ToInputArray, registered_dist_coeffs: &dyn core::ToInputArray, rt: &dyn core::ToInputArray, unregistered_depth: &dyn core::ToInputArray, output_image_plane_size: core::Size, registered_depth: &mut dyn core::ToOutputArray, depth_dilation: bool) -> Result<()> {
+pub fn register_depth(unregistered_camera_matrix: &(impl core::ToInputArray + ?Sized), registered_camera_matrix: &(impl core::ToInputArray + ?Sized), registered_dist_coeffs: &(impl core::ToInputArray + ?Sized), rt: &(impl core::ToInputArray + ?Sized), unregistered_depth: &(impl core::ToInputArray + ?Sized), output_image_plane_size: core::Size, registered_depth: &mut (impl core::ToOutputArray + ?Sized), depth_dilation: bool) -> Result<()> {
 	input_array_arg!(unregistered_camera_matrix);
 	input_array_arg!(registered_camera_matrix);
 	input_array_arg!(registered_dist_coeffs);
@@ -282,7 +282,7 @@ pub fn register_depth(unregistered_camera_matrix: &dyn core::ToInputArray, regis
 /// ## C++ default parameters
 /// * depth_factor: 1000.0
 #[inline]
-pub fn rescale_depth(in_: &dyn core::ToInputArray, depth: i32, out: &mut dyn core::ToOutputArray, depth_factor: f64) -> Result<()> {
+pub fn rescale_depth(in_: &(impl core::ToInputArray + ?Sized), depth: i32, out: &mut (impl core::ToOutputArray + ?Sized), depth_factor: f64) -> Result<()> {
 	input_array_arg!(in_);
 	output_array_arg!(out);
 	return_send!(via ocvrs_return);
@@ -310,10 +310,10 @@ pub fn rescale_depth(in_: &dyn core::ToInputArray, depth: i32, out: &mut dyn cor
 /// * warped_depth: noArray()
 /// * warped_mask: noArray()
 #[inline]
-pub fn warp_frame(image: &core::Mat, depth: &core::Mat, mask: &core::Mat, rt: &core::Mat, camera_matrix: &core::Mat, dist_coeff: &core::Mat, warped_image: &mut dyn core::ToOutputArray, warped_depth: &mut dyn core::ToOutputArray, warped_mask: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn warp_frame(image: &core::Mat, depth: &core::Mat, mask: &core::Mat, rt: &core::Mat, camera_matrix: &core::Mat, dist_coeff: &core::Mat, warped_image: &mut (impl core::ToOutputArray + ?Sized), warped_depth: Option<&mut (impl core::ToOutputArray + ?Sized)>, warped_mask: Option<&mut (impl core::ToOutputArray + ?Sized)>) -> Result<()> {
 	output_array_arg!(warped_image);
-	output_array_arg!(warped_depth);
-	output_array_arg!(warped_mask);
+	output_array_arg!(nullable warped_depth);
+	output_array_arg!(nullable warped_mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_rgbd_warpFrame_const_MatR_const_MatR_const_MatR_const_MatR_const_MatR_const_MatR_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(image.as_raw_Mat(), depth.as_raw_Mat(), mask.as_raw_Mat(), rt.as_raw_Mat(), camera_matrix.as_raw_Mat(), dist_coeff.as_raw_Mat(), warped_image.as_raw__OutputArray(), warped_depth.as_raw__OutputArray(), warped_mask.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -407,10 +407,10 @@ pub trait ColoredKinfu_ColoredKinFuConst {
 	/// ## C++ default parameters
 	/// * colors: noArray()
 	#[inline]
-	fn get_cloud(&self, points: &mut dyn core::ToOutputArray, normals: &mut dyn core::ToOutputArray, colors: &mut dyn core::ToOutputArray) -> Result<()> {
+	fn get_cloud(&self, points: &mut dyn core::ToOutputArray, normals: &mut dyn core::ToOutputArray, colors: Option<&mut dyn core::ToOutputArray>) -> Result<()> {
 		output_array_arg!(points);
 		output_array_arg!(normals);
-		output_array_arg!(colors);
+		output_array_arg!(nullable colors);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_colored_kinfu_ColoredKinFu_getCloud_const_const__OutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_ColoredKinfu_ColoredKinFu(), points.as_raw__OutputArray(), normals.as_raw__OutputArray(), colors.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -689,6 +689,14 @@ pub trait ColoredKinfu_ParamsTraitConst {
 		ret
 	}
 	
+	/// Same as `icp_iterations()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn icp_iterations_ref(&self) -> BoxedRef<'_, core::Vector<i32>> {
+		let ret = unsafe { sys::cv_colored_kinfu_Params_getPropIcpIterationsRef_const(self.as_raw_ColoredKinfu_Params()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// Threshold for depth truncation in meters
 	/// 
 	/// All depth values beyond this threshold will be set to zero
@@ -1688,6 +1696,14 @@ pub trait Kinfu_ParamsTraitConst {
 		ret
 	}
 	
+	/// Same as `icp_iterations()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn icp_iterations_ref(&self) -> BoxedRef<'_, core::Vector<i32>> {
+		let ret = unsafe { sys::cv_kinfu_Params_getPropIcpIterationsRef_const(self.as_raw_Kinfu_Params()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// Threshold for depth truncation in meters
 	/// 
 	/// All depth values beyond this threshold will be set to zero
@@ -2357,7 +2373,7 @@ pub trait Kinfu_Detail_PoseGraphConst {
 	fn as_raw_Kinfu_Detail_PoseGraph(&self) -> *const c_void;
 
 	#[inline]
-	fn is_node_exist(&self, node_id: size_t) -> Result<bool> {
+	fn is_node_exist(&self, node_id: usize) -> Result<bool> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_kinfu_detail_PoseGraph_isNodeExist_const_size_t(self.as_raw_Kinfu_Detail_PoseGraph(), node_id, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -2366,7 +2382,7 @@ pub trait Kinfu_Detail_PoseGraphConst {
 	}
 	
 	#[inline]
-	fn is_node_fixed(&self, node_id: size_t) -> Result<bool> {
+	fn is_node_fixed(&self, node_id: usize) -> Result<bool> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_kinfu_detail_PoseGraph_isNodeFixed_const_size_t(self.as_raw_Kinfu_Detail_PoseGraph(), node_id, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -2375,7 +2391,7 @@ pub trait Kinfu_Detail_PoseGraphConst {
 	}
 	
 	#[inline]
-	fn get_node_pose(&self, node_id: size_t) -> Result<core::Affine3d> {
+	fn get_node_pose(&self, node_id: usize) -> Result<core::Affine3d> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_kinfu_detail_PoseGraph_getNodePose_const_size_t(self.as_raw_Kinfu_Detail_PoseGraph(), node_id, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -2384,17 +2400,17 @@ pub trait Kinfu_Detail_PoseGraphConst {
 	}
 	
 	#[inline]
-	fn get_nodes_ids(&self) -> Result<core::Vector<size_t>> {
+	fn get_nodes_ids(&self) -> Result<core::Vector<usize>> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_kinfu_detail_PoseGraph_getNodesIds_const(self.as_raw_Kinfu_Detail_PoseGraph(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
-		let ret = unsafe { core::Vector::<size_t>::opencv_from_extern(ret) };
+		let ret = unsafe { core::Vector::<usize>::opencv_from_extern(ret) };
 		Ok(ret)
 	}
 	
 	#[inline]
-	fn get_num_nodes(&self) -> Result<size_t> {
+	fn get_num_nodes(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_kinfu_detail_PoseGraph_getNumNodes_const(self.as_raw_Kinfu_Detail_PoseGraph(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -2403,7 +2419,7 @@ pub trait Kinfu_Detail_PoseGraphConst {
 	}
 	
 	#[inline]
-	fn get_edge_start(&self, i: size_t) -> Result<size_t> {
+	fn get_edge_start(&self, i: usize) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_kinfu_detail_PoseGraph_getEdgeStart_const_size_t(self.as_raw_Kinfu_Detail_PoseGraph(), i, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -2412,7 +2428,7 @@ pub trait Kinfu_Detail_PoseGraphConst {
 	}
 	
 	#[inline]
-	fn get_edge_end(&self, i: size_t) -> Result<size_t> {
+	fn get_edge_end(&self, i: usize) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_kinfu_detail_PoseGraph_getEdgeEnd_const_size_t(self.as_raw_Kinfu_Detail_PoseGraph(), i, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -2421,7 +2437,7 @@ pub trait Kinfu_Detail_PoseGraphConst {
 	}
 	
 	#[inline]
-	fn get_num_edges(&self) -> Result<size_t> {
+	fn get_num_edges(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_kinfu_detail_PoseGraph_getNumEdges_const(self.as_raw_Kinfu_Detail_PoseGraph(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -2453,7 +2469,7 @@ pub trait Kinfu_Detail_PoseGraph: crate::rgbd::Kinfu_Detail_PoseGraphConst {
 	fn as_raw_mut_Kinfu_Detail_PoseGraph(&mut self) -> *mut c_void;
 
 	#[inline]
-	fn add_node(&mut self, _node_id: size_t, _pose: core::Affine3d, fixed: bool) -> Result<()> {
+	fn add_node(&mut self, _node_id: usize, _pose: core::Affine3d, fixed: bool) -> Result<()> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_kinfu_detail_PoseGraph_addNode_size_t_const_Affine3dR_bool(self.as_raw_mut_Kinfu_Detail_PoseGraph(), _node_id, &_pose, fixed, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -2462,7 +2478,7 @@ pub trait Kinfu_Detail_PoseGraph: crate::rgbd::Kinfu_Detail_PoseGraphConst {
 	}
 	
 	#[inline]
-	fn set_node_fixed(&mut self, node_id: size_t, fixed: bool) -> Result<bool> {
+	fn set_node_fixed(&mut self, node_id: usize, fixed: bool) -> Result<bool> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_kinfu_detail_PoseGraph_setNodeFixed_size_t_bool(self.as_raw_mut_Kinfu_Detail_PoseGraph(), node_id, fixed, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -2473,7 +2489,7 @@ pub trait Kinfu_Detail_PoseGraph: crate::rgbd::Kinfu_Detail_PoseGraphConst {
 	/// ## C++ default parameters
 	/// * _information: Matx66f::eye()
 	#[inline]
-	fn add_edge(&mut self, _source_node_id: size_t, _target_node_id: size_t, _transformation: core::Affine3f, _information: core::Matx66f) -> Result<()> {
+	fn add_edge(&mut self, _source_node_id: usize, _target_node_id: usize, _transformation: core::Affine3f, _information: core::Matx66f) -> Result<()> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_kinfu_detail_PoseGraph_addEdge_size_t_size_t_const_Affine3fR_const_Matx66fR(self.as_raw_mut_Kinfu_Detail_PoseGraph(), _source_node_id, _target_node_id, &_transformation, &_information, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -2755,6 +2771,14 @@ pub trait ParamsTraitConst {
 		ret
 	}
 	
+	/// Same as `icp_iterations()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn icp_iterations_ref(&self) -> BoxedRef<'_, core::Vector<i32>> {
+		let ret = unsafe { sys::cv_large_kinfu_Params_getPropIcpIterationsRef_const(self.as_raw_Params()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// Threshold for depth truncation in meters
 	/// All depth values beyond this threshold will be set to zero
 	#[inline]
@@ -2771,6 +2795,14 @@ pub trait ParamsTraitConst {
 		ret
 	}
 	
+	/// Same as `volume_params()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn volume_params_ref(&self) -> BoxedRef<'_, crate::rgbd::Kinfu_VolumeParams> {
+		let ret = unsafe { sys::cv_large_kinfu_Params_getPropVolumeParamsRef_const(self.as_raw_Params()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 }
 
 pub trait ParamsTrait: crate::rgbd::ParamsTraitConst {
@@ -2963,7 +2995,7 @@ pub trait Linemod_ColorGradientTraitConst: crate::rgbd::Linemod_ModalityConst {
 	}
 	
 	#[inline]
-	fn num_features(&self) -> size_t {
+	fn num_features(&self) -> usize {
 		let ret = unsafe { sys::cv_linemod_ColorGradient_getPropNum_features_const(self.as_raw_Linemod_ColorGradient()) };
 		ret
 	}
@@ -3005,7 +3037,7 @@ pub trait Linemod_ColorGradientTrait: crate::rgbd::Linemod_ColorGradientTraitCon
 	}
 	
 	#[inline]
-	fn set_num_features(&mut self, val: size_t) {
+	fn set_num_features(&mut self, val: usize) {
 		let ret = unsafe { sys::cv_linemod_ColorGradient_setPropNum_features_size_t(self.as_raw_mut_Linemod_ColorGradient(), val) };
 		ret
 	}
@@ -3078,7 +3110,7 @@ impl Linemod_ColorGradient {
 	/// \param strong_threshold Consider as candidate features only gradients whose norms are
 	///                         larger than this.
 	#[inline]
-	pub fn new(weak_threshold: f32, num_features: size_t, strong_threshold: f32) -> Result<crate::rgbd::Linemod_ColorGradient> {
+	pub fn new(weak_threshold: f32, num_features: usize, strong_threshold: f32) -> Result<crate::rgbd::Linemod_ColorGradient> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_linemod_ColorGradient_ColorGradient_float_size_t_float(weak_threshold, num_features, strong_threshold, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -3088,7 +3120,7 @@ impl Linemod_ColorGradient {
 	}
 	
 	#[inline]
-	pub fn create(weak_threshold: f32, num_features: size_t, strong_threshold: f32) -> Result<core::Ptr<crate::rgbd::Linemod_ColorGradient>> {
+	pub fn create(weak_threshold: f32, num_features: usize, strong_threshold: f32) -> Result<core::Ptr<crate::rgbd::Linemod_ColorGradient>> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_linemod_ColorGradient_create_float_size_t_float(weak_threshold, num_features, strong_threshold, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -3116,7 +3148,7 @@ pub trait Linemod_DepthNormalTraitConst: crate::rgbd::Linemod_ModalityConst {
 	}
 	
 	#[inline]
-	fn num_features(&self) -> size_t {
+	fn num_features(&self) -> usize {
 		let ret = unsafe { sys::cv_linemod_DepthNormal_getPropNum_features_const(self.as_raw_Linemod_DepthNormal()) };
 		ret
 	}
@@ -3164,7 +3196,7 @@ pub trait Linemod_DepthNormalTrait: crate::rgbd::Linemod_DepthNormalTraitConst +
 	}
 	
 	#[inline]
-	fn set_num_features(&mut self, val: size_t) {
+	fn set_num_features(&mut self, val: usize) {
 		let ret = unsafe { sys::cv_linemod_DepthNormal_setPropNum_features_size_t(self.as_raw_mut_Linemod_DepthNormal(), val) };
 		ret
 	}
@@ -3239,7 +3271,7 @@ impl Linemod_DepthNormal {
 	/// \param extract_threshold    Consider as candidate feature only if there are no differing
 	///                             orientations within a distance of extract_threshold.
 	#[inline]
-	pub fn new(distance_threshold: i32, difference_threshold: i32, num_features: size_t, extract_threshold: i32) -> Result<crate::rgbd::Linemod_DepthNormal> {
+	pub fn new(distance_threshold: i32, difference_threshold: i32, num_features: usize, extract_threshold: i32) -> Result<crate::rgbd::Linemod_DepthNormal> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_linemod_DepthNormal_DepthNormal_int_int_size_t_int(distance_threshold, difference_threshold, num_features, extract_threshold, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -3249,7 +3281,7 @@ impl Linemod_DepthNormal {
 	}
 	
 	#[inline]
-	pub fn create(distance_threshold: i32, difference_threshold: i32, num_features: size_t, extract_threshold: i32) -> Result<core::Ptr<crate::rgbd::Linemod_DepthNormal>> {
+	pub fn create(distance_threshold: i32, difference_threshold: i32, num_features: usize, extract_threshold: i32) -> Result<core::Ptr<crate::rgbd::Linemod_DepthNormal>> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_linemod_DepthNormal_create_int_int_size_t_int(distance_threshold, difference_threshold, num_features, extract_threshold, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -3284,8 +3316,8 @@ pub trait Linemod_DetectorTraitConst {
 	/// * quantized_images: noArray()
 	/// * masks: std::vector<Mat>()
 	#[inline]
-	fn match_(&self, sources: &core::Vector<core::Mat>, threshold: f32, matches: &mut core::Vector<crate::rgbd::Linemod_Match>, class_ids: &core::Vector<String>, quantized_images: &mut dyn core::ToOutputArray, masks: &core::Vector<core::Mat>) -> Result<()> {
-		output_array_arg!(quantized_images);
+	fn match_(&self, sources: &core::Vector<core::Mat>, threshold: f32, matches: &mut core::Vector<crate::rgbd::Linemod_Match>, class_ids: &core::Vector<String>, quantized_images: Option<&mut dyn core::ToOutputArray>, masks: &core::Vector<core::Mat>) -> Result<()> {
+		output_array_arg!(nullable quantized_images);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_linemod_Detector_match_const_const_vectorLMatGR_float_vectorLMatchGR_const_vectorLStringGR_const__OutputArrayR_const_vectorLMatGR(self.as_raw_Linemod_Detector(), sources.as_raw_VectorOfMat(), threshold, matches.as_raw_mut_VectorOfLinemod_Match(), class_ids.as_raw_VectorOfString(), quantized_images.as_raw__OutputArray(), masks.as_raw_VectorOfMat(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -3620,6 +3652,14 @@ pub trait Linemod_MatchTraitConst {
 		ret
 	}
 	
+	/// Same as `class_id()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn class_id_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_linemod_Match_getPropClass_idRef_const(self.as_raw_Linemod_Match()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	#[inline]
 	fn template_id(&self) -> i32 {
 		let ret = unsafe { sys::cv_linemod_Match_getPropTemplate_id_const(self.as_raw_Linemod_Match()) };
@@ -3746,9 +3786,9 @@ pub trait Linemod_ModalityConst {
 	/// ## C++ default parameters
 	/// * mask: Mat()
 	#[inline]
-	fn process(&self, src: &core::Mat, mask: &core::Mat) -> Result<core::Ptr<dyn crate::rgbd::Linemod_QuantizedPyramid>> {
+	fn process(&self, src: &core::Mat, mask: Option<&core::Mat>) -> Result<core::Ptr<dyn crate::rgbd::Linemod_QuantizedPyramid>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_linemod_Modality_process_const_const_MatR_const_MatR(self.as_raw_Linemod_Modality(), src.as_raw_Mat(), mask.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_linemod_Modality_process_const_const_MatR_const_MatR(self.as_raw_Linemod_Modality(), src.as_raw_Mat(), mask.map_or(::core::ptr::null(), |mask| mask.as_raw_Mat()), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::rgbd::Linemod_QuantizedPyramid>::opencv_from_extern(ret) };
@@ -3895,6 +3935,14 @@ pub trait Linemod_TemplateTraitConst {
 		ret
 	}
 	
+	/// Same as `features()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn features_ref(&self) -> BoxedRef<'_, core::Vector<crate::rgbd::Linemod_Feature>> {
+		let ret = unsafe { sys::cv_linemod_Template_getPropFeaturesRef_const(self.as_raw_Linemod_Template()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn write(&self, fs: &mut core::FileStorage) -> Result<()> {
 		return_send!(via ocvrs_return);
@@ -4694,9 +4742,9 @@ impl ICPOdometry {
 	/// * iter_counts: std::vector<int>()
 	/// * transform_type: Odometry::RIGID_BODY_MOTION
 	#[inline]
-	pub fn create(camera_matrix: &core::Mat, min_depth: f32, max_depth: f32, max_depth_diff: f32, max_points_part: f32, iter_counts: &core::Vector<i32>, transform_type: i32) -> Result<core::Ptr<crate::rgbd::ICPOdometry>> {
+	pub fn create(camera_matrix: Option<&core::Mat>, min_depth: f32, max_depth: f32, max_depth_diff: f32, max_points_part: f32, iter_counts: &core::Vector<i32>, transform_type: i32) -> Result<core::Ptr<crate::rgbd::ICPOdometry>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_ICPOdometry_create_const_MatR_float_float_float_float_const_vectorLintGR_int(camera_matrix.as_raw_Mat(), min_depth, max_depth, max_depth_diff, max_points_part, iter_counts.as_raw_VectorOfi32(), transform_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_rgbd_ICPOdometry_create_const_MatR_float_float_float_float_const_vectorLintGR_int(camera_matrix.map_or(::core::ptr::null(), |camera_matrix| camera_matrix.as_raw_Mat()), min_depth, max_depth, max_depth_diff, max_points_part, iter_counts.as_raw_VectorOfi32(), transform_type, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::ICPOdometry>::opencv_from_extern(ret) };
@@ -4733,10 +4781,10 @@ pub trait OdometryConst: core::AlgorithmTraitConst {
 	/// ## C++ default parameters
 	/// * init_rt: Mat()
 	#[inline]
-	fn compute(&self, src_image: &core::Mat, src_depth: &core::Mat, src_mask: &core::Mat, dst_image: &core::Mat, dst_depth: &core::Mat, dst_mask: &core::Mat, rt: &mut dyn core::ToOutputArray, init_rt: &core::Mat) -> Result<bool> {
+	fn compute(&self, src_image: &core::Mat, src_depth: &core::Mat, src_mask: &core::Mat, dst_image: &core::Mat, dst_depth: &core::Mat, dst_mask: &core::Mat, rt: &mut dyn core::ToOutputArray, init_rt: Option<&core::Mat>) -> Result<bool> {
 		output_array_arg!(rt);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_Odometry_compute_const_const_MatR_const_MatR_const_MatR_const_MatR_const_MatR_const_MatR_const__OutputArrayR_const_MatR(self.as_raw_Odometry(), src_image.as_raw_Mat(), src_depth.as_raw_Mat(), src_mask.as_raw_Mat(), dst_image.as_raw_Mat(), dst_depth.as_raw_Mat(), dst_mask.as_raw_Mat(), rt.as_raw__OutputArray(), init_rt.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_rgbd_Odometry_compute_const_const_MatR_const_MatR_const_MatR_const_MatR_const_MatR_const_MatR_const__OutputArrayR_const_MatR(self.as_raw_Odometry(), src_image.as_raw_Mat(), src_depth.as_raw_Mat(), src_mask.as_raw_Mat(), dst_image.as_raw_Mat(), dst_depth.as_raw_Mat(), dst_mask.as_raw_Mat(), rt.as_raw__OutputArray(), init_rt.map_or(::core::ptr::null(), |init_rt| init_rt.as_raw_Mat()), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4748,10 +4796,10 @@ pub trait OdometryConst: core::AlgorithmTraitConst {
 	/// ## C++ default parameters
 	/// * init_rt: Mat()
 	#[inline]
-	fn compute2(&self, src_frame: &mut core::Ptr<crate::rgbd::OdometryFrame>, dst_frame: &mut core::Ptr<crate::rgbd::OdometryFrame>, rt: &mut dyn core::ToOutputArray, init_rt: &core::Mat) -> Result<bool> {
+	fn compute2(&self, src_frame: &mut core::Ptr<crate::rgbd::OdometryFrame>, dst_frame: &mut core::Ptr<crate::rgbd::OdometryFrame>, rt: &mut dyn core::ToOutputArray, init_rt: Option<&core::Mat>) -> Result<bool> {
 		output_array_arg!(rt);
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_Odometry_compute_const_PtrLOdometryFrameGR_PtrLOdometryFrameGR_const__OutputArrayR_const_MatR(self.as_raw_Odometry(), src_frame.as_raw_mut_PtrOfOdometryFrame(), dst_frame.as_raw_mut_PtrOfOdometryFrame(), rt.as_raw__OutputArray(), init_rt.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_rgbd_Odometry_compute_const_PtrLOdometryFrameGR_PtrLOdometryFrameGR_const__OutputArrayR_const_MatR(self.as_raw_Odometry(), src_frame.as_raw_mut_PtrOfOdometryFrame(), dst_frame.as_raw_mut_PtrOfOdometryFrame(), rt.as_raw__OutputArray(), init_rt.map_or(::core::ptr::null(), |init_rt| init_rt.as_raw_Mat()), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		Ok(ret)
@@ -4904,6 +4952,14 @@ pub trait OdometryFrameTraitConst: crate::rgbd::RgbdFrameTraitConst {
 		ret
 	}
 	
+	/// Same as `pyramid_image()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn pyramid_image_ref(&self) -> BoxedRef<'_, core::Vector<core::Mat>> {
+		let ret = unsafe { sys::cv_rgbd_OdometryFrame_getPropPyramidImageRef_const(self.as_raw_OdometryFrame()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn pyramid_depth(&self) -> core::Vector<core::Mat> {
 		let ret = unsafe { sys::cv_rgbd_OdometryFrame_getPropPyramidDepth_const(self.as_raw_OdometryFrame()) };
@@ -4911,6 +4967,14 @@ pub trait OdometryFrameTraitConst: crate::rgbd::RgbdFrameTraitConst {
 		ret
 	}
 	
+	/// Same as `pyramid_depth()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn pyramid_depth_ref(&self) -> BoxedRef<'_, core::Vector<core::Mat>> {
+		let ret = unsafe { sys::cv_rgbd_OdometryFrame_getPropPyramidDepthRef_const(self.as_raw_OdometryFrame()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn pyramid_mask(&self) -> core::Vector<core::Mat> {
 		let ret = unsafe { sys::cv_rgbd_OdometryFrame_getPropPyramidMask_const(self.as_raw_OdometryFrame()) };
@@ -4918,6 +4982,14 @@ pub trait OdometryFrameTraitConst: crate::rgbd::RgbdFrameTraitConst {
 		ret
 	}
 	
+	/// Same as `pyramid_mask()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn pyramid_mask_ref(&self) -> BoxedRef<'_, core::Vector<core::Mat>> {
+		let ret = unsafe { sys::cv_rgbd_OdometryFrame_getPropPyramidMaskRef_const(self.as_raw_OdometryFrame()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn pyramid_cloud(&self) -> core::Vector<core::Mat> {
 		let ret = unsafe { sys::cv_rgbd_OdometryFrame_getPropPyramidCloud_const(self.as_raw_OdometryFrame()) };
@@ -4925,6 +4997,14 @@ pub trait OdometryFrameTraitConst: crate::rgbd::RgbdFrameTraitConst {
 		ret
 	}
 	
+	/// Same as `pyramid_cloud()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn pyramid_cloud_ref(&self) -> BoxedRef<'_, core::Vector<core::Mat>> {
+		let ret = unsafe { sys::cv_rgbd_OdometryFrame_getPropPyramidCloudRef_const(self.as_raw_OdometryFrame()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn pyramid_d_i_dx(&self) -> core::Vector<core::Mat> {
 		let ret = unsafe { sys::cv_rgbd_OdometryFrame_getPropPyramid_dI_dx_const(self.as_raw_OdometryFrame()) };
@@ -4932,6 +5012,14 @@ pub trait OdometryFrameTraitConst: crate::rgbd::RgbdFrameTraitConst {
 		ret
 	}
 	
+	/// Same as `pyramid_d_i_dx()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn pyramid_d_i_dx_ref(&self) -> BoxedRef<'_, core::Vector<core::Mat>> {
+		let ret = unsafe { sys::cv_rgbd_OdometryFrame_getPropPyramid_dI_dxRef_const(self.as_raw_OdometryFrame()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn pyramid_d_i_dy(&self) -> core::Vector<core::Mat> {
 		let ret = unsafe { sys::cv_rgbd_OdometryFrame_getPropPyramid_dI_dy_const(self.as_raw_OdometryFrame()) };
@@ -4939,6 +5027,14 @@ pub trait OdometryFrameTraitConst: crate::rgbd::RgbdFrameTraitConst {
 		ret
 	}
 	
+	/// Same as `pyramid_d_i_dy()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn pyramid_d_i_dy_ref(&self) -> BoxedRef<'_, core::Vector<core::Mat>> {
+		let ret = unsafe { sys::cv_rgbd_OdometryFrame_getPropPyramid_dI_dyRef_const(self.as_raw_OdometryFrame()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn pyramid_textured_mask(&self) -> core::Vector<core::Mat> {
 		let ret = unsafe { sys::cv_rgbd_OdometryFrame_getPropPyramidTexturedMask_const(self.as_raw_OdometryFrame()) };
@@ -4946,6 +5042,14 @@ pub trait OdometryFrameTraitConst: crate::rgbd::RgbdFrameTraitConst {
 		ret
 	}
 	
+	/// Same as `pyramid_textured_mask()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn pyramid_textured_mask_ref(&self) -> BoxedRef<'_, core::Vector<core::Mat>> {
+		let ret = unsafe { sys::cv_rgbd_OdometryFrame_getPropPyramidTexturedMaskRef_const(self.as_raw_OdometryFrame()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn pyramid_normals(&self) -> core::Vector<core::Mat> {
 		let ret = unsafe { sys::cv_rgbd_OdometryFrame_getPropPyramidNormals_const(self.as_raw_OdometryFrame()) };
@@ -4953,6 +5057,14 @@ pub trait OdometryFrameTraitConst: crate::rgbd::RgbdFrameTraitConst {
 		ret
 	}
 	
+	/// Same as `pyramid_normals()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn pyramid_normals_ref(&self) -> BoxedRef<'_, core::Vector<core::Mat>> {
+		let ret = unsafe { sys::cv_rgbd_OdometryFrame_getPropPyramidNormalsRef_const(self.as_raw_OdometryFrame()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn pyramid_normals_mask(&self) -> core::Vector<core::Mat> {
 		let ret = unsafe { sys::cv_rgbd_OdometryFrame_getPropPyramidNormalsMask_const(self.as_raw_OdometryFrame()) };
@@ -4960,6 +5072,14 @@ pub trait OdometryFrameTraitConst: crate::rgbd::RgbdFrameTraitConst {
 		ret
 	}
 	
+	/// Same as `pyramid_normals_mask()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn pyramid_normals_mask_ref(&self) -> BoxedRef<'_, core::Vector<core::Mat>> {
+		let ret = unsafe { sys::cv_rgbd_OdometryFrame_getPropPyramidNormalsMaskRef_const(self.as_raw_OdometryFrame()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 }
 
 pub trait OdometryFrameTrait: crate::rgbd::OdometryFrameTraitConst + crate::rgbd::RgbdFrameTrait {
@@ -5089,9 +5209,9 @@ impl OdometryFrame {
 	/// * normals: Mat()
 	/// * id: -1
 	#[inline]
-	pub fn new(image: &core::Mat, depth: &core::Mat, mask: &core::Mat, normals: &core::Mat, id: i32) -> Result<crate::rgbd::OdometryFrame> {
+	pub fn new(image: &core::Mat, depth: &core::Mat, mask: Option<&core::Mat>, normals: Option<&core::Mat>, id: i32) -> Result<crate::rgbd::OdometryFrame> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_OdometryFrame_OdometryFrame_const_MatR_const_MatR_const_MatR_const_MatR_int(image.as_raw_Mat(), depth.as_raw_Mat(), mask.as_raw_Mat(), normals.as_raw_Mat(), id, ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_rgbd_OdometryFrame_OdometryFrame_const_MatR_const_MatR_const_MatR_const_MatR_int(image.as_raw_Mat(), depth.as_raw_Mat(), mask.map_or(::core::ptr::null(), |mask| mask.as_raw_Mat()), normals.map_or(::core::ptr::null(), |normals| normals.as_raw_Mat()), id, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::OdometryFrame::opencv_from_extern(ret) };
@@ -5105,9 +5225,9 @@ impl OdometryFrame {
 	/// * normals: Mat()
 	/// * id: -1
 	#[inline]
-	pub fn create(image: &core::Mat, depth: &core::Mat, mask: &core::Mat, normals: &core::Mat, id: i32) -> Result<core::Ptr<crate::rgbd::OdometryFrame>> {
+	pub fn create(image: Option<&core::Mat>, depth: Option<&core::Mat>, mask: Option<&core::Mat>, normals: Option<&core::Mat>, id: i32) -> Result<core::Ptr<crate::rgbd::OdometryFrame>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_OdometryFrame_create_const_MatR_const_MatR_const_MatR_const_MatR_int(image.as_raw_Mat(), depth.as_raw_Mat(), mask.as_raw_Mat(), normals.as_raw_Mat(), id, ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_rgbd_OdometryFrame_create_const_MatR_const_MatR_const_MatR_const_MatR_int(image.map_or(::core::ptr::null(), |image| image.as_raw_Mat()), depth.map_or(::core::ptr::null(), |depth| depth.as_raw_Mat()), mask.map_or(::core::ptr::null(), |mask| mask.as_raw_Mat()), normals.map_or(::core::ptr::null(), |normals| normals.as_raw_Mat()), id, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::OdometryFrame>::opencv_from_extern(ret) };
@@ -5135,6 +5255,14 @@ pub trait RgbdFrameTraitConst {
 		ret
 	}
 	
+	/// Same as `image()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn image_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_rgbd_RgbdFrame_getPropImageRef_const(self.as_raw_RgbdFrame()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn depth(&self) -> core::Mat {
 		let ret = unsafe { sys::cv_rgbd_RgbdFrame_getPropDepth_const(self.as_raw_RgbdFrame()) };
@@ -5142,6 +5270,14 @@ pub trait RgbdFrameTraitConst {
 		ret
 	}
 	
+	/// Same as `depth()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn depth_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_rgbd_RgbdFrame_getPropDepthRef_const(self.as_raw_RgbdFrame()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn mask(&self) -> core::Mat {
 		let ret = unsafe { sys::cv_rgbd_RgbdFrame_getPropMask_const(self.as_raw_RgbdFrame()) };
@@ -5149,6 +5285,14 @@ pub trait RgbdFrameTraitConst {
 		ret
 	}
 	
+	/// Same as `mask()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn mask_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_rgbd_RgbdFrame_getPropMaskRef_const(self.as_raw_RgbdFrame()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn normals(&self) -> core::Mat {
 		let ret = unsafe { sys::cv_rgbd_RgbdFrame_getPropNormals_const(self.as_raw_RgbdFrame()) };
@@ -5156,6 +5300,14 @@ pub trait RgbdFrameTraitConst {
 		ret
 	}
 	
+	/// Same as `normals()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn normals_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_rgbd_RgbdFrame_getPropNormalsRef_const(self.as_raw_RgbdFrame()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 }
 
 pub trait RgbdFrameTrait: crate::rgbd::RgbdFrameTraitConst {
@@ -5242,9 +5394,9 @@ impl RgbdFrame {
 	/// * normals: Mat()
 	/// * id: -1
 	#[inline]
-	pub fn new(image: &core::Mat, depth: &core::Mat, mask: &core::Mat, normals: &core::Mat, id: i32) -> Result<crate::rgbd::RgbdFrame> {
+	pub fn new(image: &core::Mat, depth: &core::Mat, mask: Option<&core::Mat>, normals: Option<&core::Mat>, id: i32) -> Result<crate::rgbd::RgbdFrame> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdFrame_RgbdFrame_const_MatR_const_MatR_const_MatR_const_MatR_int(image.as_raw_Mat(), depth.as_raw_Mat(), mask.as_raw_Mat(), normals.as_raw_Mat(), id, ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_rgbd_RgbdFrame_RgbdFrame_const_MatR_const_MatR_const_MatR_const_MatR_int(image.as_raw_Mat(), depth.as_raw_Mat(), mask.map_or(::core::ptr::null(), |mask| mask.as_raw_Mat()), normals.map_or(::core::ptr::null(), |normals| normals.as_raw_Mat()), id, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { crate::rgbd::RgbdFrame::opencv_from_extern(ret) };
@@ -5258,9 +5410,9 @@ impl RgbdFrame {
 	/// * normals: Mat()
 	/// * id: -1
 	#[inline]
-	pub fn create(image: &core::Mat, depth: &core::Mat, mask: &core::Mat, normals: &core::Mat, id: i32) -> Result<core::Ptr<crate::rgbd::RgbdFrame>> {
+	pub fn create(image: Option<&core::Mat>, depth: Option<&core::Mat>, mask: Option<&core::Mat>, normals: Option<&core::Mat>, id: i32) -> Result<core::Ptr<crate::rgbd::RgbdFrame>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdFrame_create_const_MatR_const_MatR_const_MatR_const_MatR_int(image.as_raw_Mat(), depth.as_raw_Mat(), mask.as_raw_Mat(), normals.as_raw_Mat(), id, ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_rgbd_RgbdFrame_create_const_MatR_const_MatR_const_MatR_const_MatR_int(image.map_or(::core::ptr::null(), |image| image.as_raw_Mat()), depth.map_or(::core::ptr::null(), |depth| depth.as_raw_Mat()), mask.map_or(::core::ptr::null(), |mask| mask.as_raw_Mat()), normals.map_or(::core::ptr::null(), |normals| normals.as_raw_Mat()), id, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::RgbdFrame>::opencv_from_extern(ret) };
@@ -5576,9 +5728,9 @@ impl RgbdICPOdometry {
 	/// * min_gradient_magnitudes: std::vector<float>()
 	/// * transform_type: Odometry::RIGID_BODY_MOTION
 	#[inline]
-	pub fn create(camera_matrix: &core::Mat, min_depth: f32, max_depth: f32, max_depth_diff: f32, max_points_part: f32, iter_counts: &core::Vector<i32>, min_gradient_magnitudes: &core::Vector<f32>, transform_type: i32) -> Result<core::Ptr<crate::rgbd::RgbdICPOdometry>> {
+	pub fn create(camera_matrix: Option<&core::Mat>, min_depth: f32, max_depth: f32, max_depth_diff: f32, max_points_part: f32, iter_counts: &core::Vector<i32>, min_gradient_magnitudes: &core::Vector<f32>, transform_type: i32) -> Result<core::Ptr<crate::rgbd::RgbdICPOdometry>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdICPOdometry_create_const_MatR_float_float_float_float_const_vectorLintGR_const_vectorLfloatGR_int(camera_matrix.as_raw_Mat(), min_depth, max_depth, max_depth_diff, max_points_part, iter_counts.as_raw_VectorOfi32(), min_gradient_magnitudes.as_raw_VectorOff32(), transform_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_rgbd_RgbdICPOdometry_create_const_MatR_float_float_float_float_const_vectorLintGR_const_vectorLfloatGR_int(camera_matrix.map_or(::core::ptr::null(), |camera_matrix| camera_matrix.as_raw_Mat()), min_depth, max_depth, max_depth_diff, max_points_part, iter_counts.as_raw_VectorOfi32(), min_gradient_magnitudes.as_raw_VectorOff32(), transform_type, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::RgbdICPOdometry>::opencv_from_extern(ret) };
@@ -5792,7 +5944,7 @@ impl RgbdNormals {
 	/// * window_size: 5
 	/// * method: RgbdNormals::RGBD_NORMALS_METHOD_FALS
 	#[inline]
-	pub fn new(rows: i32, cols: i32, depth: i32, k: &dyn core::ToInputArray, window_size: i32, method: i32) -> Result<crate::rgbd::RgbdNormals> {
+	pub fn new(rows: i32, cols: i32, depth: i32, k: &(impl core::ToInputArray + ?Sized), window_size: i32, method: i32) -> Result<crate::rgbd::RgbdNormals> {
 		input_array_arg!(k);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_rgbd_RgbdNormals_RgbdNormals_int_int_int_const__InputArrayR_int_int(rows, cols, depth, k.as_raw__InputArray(), window_size, method, ocvrs_return.as_mut_ptr()) };
@@ -5806,7 +5958,7 @@ impl RgbdNormals {
 	/// * window_size: 5
 	/// * method: RgbdNormals::RGBD_NORMALS_METHOD_FALS
 	#[inline]
-	pub fn create(rows: i32, cols: i32, depth: i32, k: &dyn core::ToInputArray, window_size: i32, method: i32) -> Result<core::Ptr<crate::rgbd::RgbdNormals>> {
+	pub fn create(rows: i32, cols: i32, depth: i32, k: &(impl core::ToInputArray + ?Sized), window_size: i32, method: i32) -> Result<core::Ptr<crate::rgbd::RgbdNormals>> {
 		input_array_arg!(k);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_rgbd_RgbdNormals_create_int_int_int_const__InputArrayR_int_int(rows, cols, depth, k.as_raw__InputArray(), window_size, method, ocvrs_return.as_mut_ptr()) };
@@ -6117,9 +6269,9 @@ impl RgbdOdometry {
 	/// * max_points_part: Odometry::DEFAULT_MAX_POINTS_PART()
 	/// * transform_type: Odometry::RIGID_BODY_MOTION
 	#[inline]
-	pub fn create(camera_matrix: &core::Mat, min_depth: f32, max_depth: f32, max_depth_diff: f32, iter_counts: &core::Vector<i32>, min_gradient_magnitudes: &core::Vector<f32>, max_points_part: f32, transform_type: i32) -> Result<core::Ptr<crate::rgbd::RgbdOdometry>> {
+	pub fn create(camera_matrix: Option<&core::Mat>, min_depth: f32, max_depth: f32, max_depth_diff: f32, iter_counts: &core::Vector<i32>, min_gradient_magnitudes: &core::Vector<f32>, max_points_part: f32, transform_type: i32) -> Result<core::Ptr<crate::rgbd::RgbdOdometry>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_rgbd_RgbdOdometry_create_const_MatR_float_float_float_const_vectorLintGR_const_vectorLfloatGR_float_int(camera_matrix.as_raw_Mat(), min_depth, max_depth, max_depth_diff, iter_counts.as_raw_VectorOfi32(), min_gradient_magnitudes.as_raw_VectorOff32(), max_points_part, transform_type, ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_rgbd_RgbdOdometry_create_const_MatR_float_float_float_const_vectorLintGR_const_vectorLfloatGR_float_int(camera_matrix.map_or(::core::ptr::null(), |camera_matrix| camera_matrix.as_raw_Mat()), min_depth, max_depth, max_depth_diff, iter_counts.as_raw_VectorOfi32(), min_gradient_magnitudes.as_raw_VectorOff32(), max_points_part, transform_type, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<crate::rgbd::RgbdOdometry>::opencv_from_extern(ret) };
@@ -6353,3 +6505,4 @@ impl RgbdPlane {
 }
 
 boxed_cast_base! { RgbdPlane, core::Algorithm, cv_RgbdPlane_to_Algorithm }
+pub use crate::manual::rgbd::*;
@@ -87,7 +87,7 @@ pub const SFM_REFINE_RADIAL_DISTORTION_K2: i32 = 16;
 /// 
 /// Reference: [HartleyZ00](https://docs.opencv.org/4.6.0/d0/de3/citelist.html#CITEREF_HartleyZ00) A4.1.1 pag.579
 #[inline]
-pub fn k_rt_from_projection(p: &dyn core::ToInputArray, k: &mut dyn core::ToOutputArray, r: &mut dyn core::ToOutputArray, t: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn k_rt_from_projection(p: &(impl core::ToInputArray + ?Sized), k: &mut (impl core::ToOutputArray + ?Sized), r: &mut (impl core::ToOutputArray + ?Sized), t: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(p);
 	output_array_arg!(k);
 	output_array_arg!(r);
@@ -105,7 +105,7 @@ pub fn k_rt_from_projection(p: &dyn core::ToInputArray, k: &mut dyn core::ToOutp
 /// * T: Input 3x3 transformation matrix such that ![inline formula](https://latex.codecogs.com/png.latex?x%20%3D%20T%2AX), where ![inline formula](https://latex.codecogs.com/png.latex?X) are the points to transform and ![inline formula](https://latex.codecogs.com/png.latex?x) the transformed points.
 /// * transformed_points: Output vector of N-dimensional transformed points.
 #[inline]
-pub fn apply_transformation_to_points(points: &dyn core::ToInputArray, t: &dyn core::ToInputArray, transformed_points: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn apply_transformation_to_points(points: &(impl core::ToInputArray + ?Sized), t: &(impl core::ToInputArray + ?Sized), transformed_points: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(points);
 	input_array_arg!(t);
 	output_array_arg!(transformed_points);
@@ -127,7 +127,7 @@ pub fn apply_transformation_to_points(points: &dyn core::ToInputArray, t: &dyn c
 /// Find the best transformation such that xp=projection*(s*R*x+t) (same as Pose Estimation, ePNP).
 /// The routines below are only for the orthographic case for now.
 #[inline]
-pub fn compute_orientation(x1: &dyn core::ToInputArray, x2: &dyn core::ToInputArray, r: &mut dyn core::ToOutputArray, t: &mut dyn core::ToOutputArray, s: f64) -> Result<()> {
+pub fn compute_orientation(x1: &(impl core::ToInputArray + ?Sized), x2: &(impl core::ToInputArray + ?Sized), r: &mut (impl core::ToOutputArray + ?Sized), t: &mut (impl core::ToOutputArray + ?Sized), s: f64) -> Result<()> {
 	input_array_arg!(x1);
 	input_array_arg!(x2);
 	output_array_arg!(r);
@@ -145,7 +145,7 @@ pub fn compute_orientation(x1: &dyn core::ToInputArray, x2: &dyn core::ToInputAr
 /// * t: Input 3x1 translation vector.
 /// * X: Input 3x1 or 4x1 vector with the 3d point.
 #[inline]
-pub fn depth(r: &dyn core::ToInputArray, t: &dyn core::ToInputArray, x: &dyn core::ToInputArray) -> Result<f64> {
+pub fn depth(r: &(impl core::ToInputArray + ?Sized), t: &(impl core::ToInputArray + ?Sized), x: &(impl core::ToInputArray + ?Sized)) -> Result<f64> {
 	input_array_arg!(r);
 	input_array_arg!(t);
 	input_array_arg!(x);
@@ -165,7 +165,7 @@ pub fn depth(r: &dyn core::ToInputArray, t: &dyn core::ToInputArray, x: &dyn cor
 /// 
 /// Reference: [HartleyZ00](https://docs.opencv.org/4.6.0/d0/de3/citelist.html#CITEREF_HartleyZ00) 9.6 pag 257 (formula 9.12)
 #[inline]
-pub fn essential_from_fundamental(f: &dyn core::ToInputArray, k1: &dyn core::ToInputArray, k2: &dyn core::ToInputArray, e: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn essential_from_fundamental(f: &(impl core::ToInputArray + ?Sized), k1: &(impl core::ToInputArray + ?Sized), k2: &(impl core::ToInputArray + ?Sized), e: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(f);
 	input_array_arg!(k1);
 	input_array_arg!(k2);
@@ -187,7 +187,7 @@ pub fn essential_from_fundamental(f: &dyn core::ToInputArray, k1: &dyn core::ToI
 /// 
 /// Reference: [HartleyZ00](https://docs.opencv.org/4.6.0/d0/de3/citelist.html#CITEREF_HartleyZ00) 9.6 pag 257 (formula 9.12)
 #[inline]
-pub fn essential_from_rt(r1: &dyn core::ToInputArray, t1: &dyn core::ToInputArray, r2: &dyn core::ToInputArray, t2: &dyn core::ToInputArray, e: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn essential_from_rt(r1: &(impl core::ToInputArray + ?Sized), t1: &(impl core::ToInputArray + ?Sized), r2: &(impl core::ToInputArray + ?Sized), t2: &(impl core::ToInputArray + ?Sized), e: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(r1);
 	input_array_arg!(t1);
 	input_array_arg!(r2);
@@ -205,7 +205,7 @@ pub fn essential_from_rt(r1: &dyn core::ToInputArray, t1: &dyn core::ToInputArra
 /// * src: Input vector of N-dimensional points.
 /// * dst: Output vector of N+1-dimensional points.
 #[inline]
-pub fn euclidean_to_homogeneous(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn euclidean_to_homogeneous(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -233,7 +233,7 @@ pub fn euclidean_to_homogeneous(src: &dyn core::ToInputArray, dst: &mut dyn core
 /// ## C++ default parameters
 /// * outliers_probability: 1e-2
 #[inline]
-pub fn fundamental_from_correspondences7_point_robust(x1: &dyn core::ToInputArray, x2: &dyn core::ToInputArray, max_error: f64, f: &mut dyn core::ToOutputArray, inliers: &mut dyn core::ToOutputArray, outliers_probability: f64) -> Result<f64> {
+pub fn fundamental_from_correspondences7_point_robust(x1: &(impl core::ToInputArray + ?Sized), x2: &(impl core::ToInputArray + ?Sized), max_error: f64, f: &mut (impl core::ToOutputArray + ?Sized), inliers: &mut (impl core::ToOutputArray + ?Sized), outliers_probability: f64) -> Result<f64> {
 	input_array_arg!(x1);
 	input_array_arg!(x2);
 	output_array_arg!(f);
@@ -263,7 +263,7 @@ pub fn fundamental_from_correspondences7_point_robust(x1: &dyn core::ToInputArra
 /// ## C++ default parameters
 /// * outliers_probability: 1e-2
 #[inline]
-pub fn fundamental_from_correspondences8_point_robust(x1: &dyn core::ToInputArray, x2: &dyn core::ToInputArray, max_error: f64, f: &mut dyn core::ToOutputArray, inliers: &mut dyn core::ToOutputArray, outliers_probability: f64) -> Result<f64> {
+pub fn fundamental_from_correspondences8_point_robust(x1: &(impl core::ToInputArray + ?Sized), x2: &(impl core::ToInputArray + ?Sized), max_error: f64, f: &mut (impl core::ToOutputArray + ?Sized), inliers: &mut (impl core::ToOutputArray + ?Sized), outliers_probability: f64) -> Result<f64> {
 	input_array_arg!(x1);
 	input_array_arg!(x2);
 	output_array_arg!(f);
@@ -284,7 +284,7 @@ pub fn fundamental_from_correspondences8_point_robust(x1: &dyn core::ToInputArra
 /// 
 /// Reference: [HartleyZ00](https://docs.opencv.org/4.6.0/d0/de3/citelist.html#CITEREF_HartleyZ00) 9.6 pag 257 (formula 9.12) or http://ai.stanford.edu/~birch/projective/node20.html
 #[inline]
-pub fn fundamental_from_essential(e: &dyn core::ToInputArray, k1: &dyn core::ToInputArray, k2: &dyn core::ToInputArray, f: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn fundamental_from_essential(e: &(impl core::ToInputArray + ?Sized), k1: &(impl core::ToInputArray + ?Sized), k2: &(impl core::ToInputArray + ?Sized), f: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(e);
 	input_array_arg!(k1);
 	input_array_arg!(k2);
@@ -302,7 +302,7 @@ pub fn fundamental_from_essential(e: &dyn core::ToInputArray, k1: &dyn core::ToI
 /// * P2: Input 3x4 second projection matrix.
 /// * F: Output 3x3 fundamental matrix.
 #[inline]
-pub fn fundamental_from_projections(p1: &dyn core::ToInputArray, p2: &dyn core::ToInputArray, f: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn fundamental_from_projections(p1: &(impl core::ToInputArray + ?Sized), p2: &(impl core::ToInputArray + ?Sized), f: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(p1);
 	input_array_arg!(p2);
 	output_array_arg!(f);
@@ -318,7 +318,7 @@ pub fn fundamental_from_projections(p1: &dyn core::ToInputArray, p2: &dyn core::
 /// * src: Input vector of N-dimensional points.
 /// * dst: Output vector of N-1-dimensional points.
 #[inline]
-pub fn homogeneous_to_euclidean(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn homogeneous_to_euclidean(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -342,7 +342,7 @@ pub fn homogeneous_to_euclidean(src: &dyn core::ToInputArray, dst: &mut dyn core
 /// ## C++ default parameters
 /// * file_format: SFM_IO_BUNDLER
 #[inline]
-pub fn import_reconstruction(file: &str, rs: &mut dyn core::ToOutputArray, ts: &mut dyn core::ToOutputArray, ks: &mut dyn core::ToOutputArray, points3d: &mut dyn core::ToOutputArray, file_format: i32) -> Result<()> {
+pub fn import_reconstruction(file: &str, rs: &mut (impl core::ToOutputArray + ?Sized), ts: &mut (impl core::ToOutputArray + ?Sized), ks: &mut (impl core::ToOutputArray + ?Sized), points3d: &mut (impl core::ToOutputArray + ?Sized), file_format: i32) -> Result<()> {
 	extern_container_arg!(file);
 	output_array_arg!(rs);
 	output_array_arg!(ts);
@@ -365,7 +365,7 @@ pub fn import_reconstruction(file: &str, rs: &mut dyn core::ToOutputArray, ts: &
 /// 
 /// Reference: [HartleyZ00](https://docs.opencv.org/4.6.0/d0/de3/citelist.html#CITEREF_HartleyZ00) 4.4.4 pag.107.
 #[inline]
-pub fn isotropic_preconditioner_from_points(points: &dyn core::ToInputArray, t: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn isotropic_preconditioner_from_points(points: &(impl core::ToInputArray + ?Sized), t: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(points);
 	output_array_arg!(t);
 	return_send!(via ocvrs_return);
@@ -383,7 +383,7 @@ pub fn isotropic_preconditioner_from_points(points: &dyn core::ToInputArray, t:
 /// 
 /// It computes in the same way as woud do @ref reduce but with \a Variance function.
 #[inline]
-pub fn mean_and_variance_along_rows(a: &dyn core::ToInputArray, mean: &mut dyn core::ToOutputArray, variance: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn mean_and_variance_along_rows(a: &(impl core::ToInputArray + ?Sized), mean: &mut (impl core::ToOutputArray + ?Sized), variance: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(a);
 	output_array_arg!(mean);
 	output_array_arg!(variance);
@@ -408,7 +408,7 @@ pub fn mean_and_variance_along_rows(a: &dyn core::ToInputArray, mean: &mut dyn c
 /// 
 /// Reference: See [HartleyZ00](https://docs.opencv.org/4.6.0/d0/de3/citelist.html#CITEREF_HartleyZ00) 9.6 pag 259 (9.6.3 Geometrical interpretation of the 4 solutions).
 #[inline]
-pub fn motion_from_essential_choose_solution(rs: &dyn core::ToInputArray, ts: &dyn core::ToInputArray, k1: &dyn core::ToInputArray, x1: &dyn core::ToInputArray, k2: &dyn core::ToInputArray, x2: &dyn core::ToInputArray) -> Result<i32> {
+pub fn motion_from_essential_choose_solution(rs: &(impl core::ToInputArray + ?Sized), ts: &(impl core::ToInputArray + ?Sized), k1: &(impl core::ToInputArray + ?Sized), x1: &(impl core::ToInputArray + ?Sized), k2: &(impl core::ToInputArray + ?Sized), x2: &(impl core::ToInputArray + ?Sized)) -> Result<i32> {
 	input_array_arg!(rs);
 	input_array_arg!(ts);
 	input_array_arg!(k1);
@@ -430,7 +430,7 @@ pub fn motion_from_essential_choose_solution(rs: &dyn core::ToInputArray, ts: &d
 /// 
 /// Reference: [HartleyZ00](https://docs.opencv.org/4.6.0/d0/de3/citelist.html#CITEREF_HartleyZ00) 9.6 pag 259 (Result 9.19)
 #[inline]
-pub fn motion_from_essential(e: &dyn core::ToInputArray, rs: &mut dyn core::ToOutputArray, ts: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn motion_from_essential(e: &(impl core::ToInputArray + ?Sized), rs: &mut (impl core::ToOutputArray + ?Sized), ts: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(e);
 	output_array_arg!(rs);
 	output_array_arg!(ts);
@@ -448,7 +448,7 @@ pub fn motion_from_essential(e: &dyn core::ToInputArray, rs: &mut dyn core::ToOu
 /// 
 /// By default divides the fundamental matrix by its L2 norm.
 #[inline]
-pub fn normalize_fundamental(f: &dyn core::ToInputArray, f_normalized: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn normalize_fundamental(f: &(impl core::ToInputArray + ?Sized), f_normalized: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(f);
 	output_array_arg!(f_normalized);
 	return_send!(via ocvrs_return);
@@ -469,7 +469,7 @@ pub fn normalize_fundamental(f: &dyn core::ToInputArray, f_normalized: &mut dyn
 /// 
 /// Reference: [HartleyZ00](https://docs.opencv.org/4.6.0/d0/de3/citelist.html#CITEREF_HartleyZ00) 4.4.4 pag.107.
 #[inline]
-pub fn normalize_isotropic_points(points: &dyn core::ToInputArray, normalized_points: &mut dyn core::ToOutputArray, t: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn normalize_isotropic_points(points: &(impl core::ToInputArray + ?Sized), normalized_points: &mut (impl core::ToOutputArray + ?Sized), t: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(points);
 	output_array_arg!(normalized_points);
 	output_array_arg!(t);
@@ -491,7 +491,7 @@ pub fn normalize_isotropic_points(points: &dyn core::ToInputArray, normalized_po
 /// 
 /// Reference: [HartleyZ00](https://docs.opencv.org/4.6.0/d0/de3/citelist.html#CITEREF_HartleyZ00) 4.4.4 pag.109
 #[inline]
-pub fn normalize_points(points: &dyn core::ToInputArray, normalized_points: &mut dyn core::ToOutputArray, t: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn normalize_points(points: &(impl core::ToInputArray + ?Sized), normalized_points: &mut (impl core::ToOutputArray + ?Sized), t: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(points);
 	output_array_arg!(normalized_points);
 	output_array_arg!(t);
@@ -511,7 +511,7 @@ pub fn normalize_points(points: &dyn core::ToInputArray, normalized_points: &mut
 /// Uses the normalized 8-point fundamental matrix solver.
 /// Reference: [HartleyZ00](https://docs.opencv.org/4.6.0/d0/de3/citelist.html#CITEREF_HartleyZ00) 11.2 pag.281 (x1 = x, x2 = x')
 #[inline]
-pub fn normalized_eight_point_solver(x1: &dyn core::ToInputArray, x2: &dyn core::ToInputArray, f: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn normalized_eight_point_solver(x1: &(impl core::ToInputArray + ?Sized), x2: &(impl core::ToInputArray + ?Sized), f: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(x1);
 	input_array_arg!(x2);
 	output_array_arg!(f);
@@ -532,7 +532,7 @@ pub fn normalized_eight_point_solver(x1: &dyn core::ToInputArray, x2: &dyn core:
 /// 
 /// Reference: [HartleyZ00](https://docs.opencv.org/4.6.0/d0/de3/citelist.html#CITEREF_HartleyZ00) 4.4.4 pag.109
 #[inline]
-pub fn preconditioner_from_points(points: &dyn core::ToInputArray, t: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn preconditioner_from_points(points: &(impl core::ToInputArray + ?Sized), t: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(points);
 	output_array_arg!(t);
 	return_send!(via ocvrs_return);
@@ -551,7 +551,7 @@ pub fn preconditioner_from_points(points: &dyn core::ToInputArray, t: &mut dyn c
 /// 
 /// This function estimate the projection matrix by solving the following equation: ![inline formula](https://latex.codecogs.com/png.latex?P%20%3D%20K%20%2A%20%5BR%7Ct%5D)
 #[inline]
-pub fn projection_from_k_rt(k: &dyn core::ToInputArray, r: &dyn core::ToInputArray, t: &dyn core::ToInputArray, p: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn projection_from_k_rt(k: &(impl core::ToInputArray + ?Sized), r: &(impl core::ToInputArray + ?Sized), t: &(impl core::ToInputArray + ?Sized), p: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(k);
 	input_array_arg!(r);
 	input_array_arg!(t);
@@ -569,7 +569,7 @@ pub fn projection_from_k_rt(k: &dyn core::ToInputArray, r: &dyn core::ToInputArr
 /// * P1: Output 3x4 one possible projection matrix.
 /// * P2: Output 3x4 another possible projection matrix.
 #[inline]
-pub fn projections_from_fundamental(f: &dyn core::ToInputArray, p1: &mut dyn core::ToOutputArray, p2: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn projections_from_fundamental(f: &(impl core::ToInputArray + ?Sized), p1: &mut (impl core::ToOutputArray + ?Sized), p2: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(f);
 	output_array_arg!(p1);
 	output_array_arg!(p2);
@@ -597,7 +597,7 @@ pub fn projections_from_fundamental(f: &dyn core::ToInputArray, p1: &mut dyn cor
 /// ## C++ default parameters
 /// * is_projective: false
 #[inline]
-pub fn reconstruct(points2d: &dyn core::ToInputArray, ps: &mut dyn core::ToOutputArray, points3d: &mut dyn core::ToOutputArray, k: &mut dyn core::ToInputOutputArray, is_projective: bool) -> Result<()> {
+pub fn reconstruct(points2d: &(impl core::ToInputArray + ?Sized), ps: &mut (impl core::ToOutputArray + ?Sized), points3d: &mut (impl core::ToOutputArray + ?Sized), k: &mut (impl core::ToInputOutputArray + ?Sized), is_projective: bool) -> Result<()> {
 	input_array_arg!(points2d);
 	output_array_arg!(ps);
 	output_array_arg!(points3d);
@@ -628,7 +628,7 @@ pub fn reconstruct(points2d: &dyn core::ToInputArray, ps: &mut dyn core::ToOutpu
 /// ## C++ default parameters
 /// * is_projective: false
 #[inline]
-pub fn reconstruct_1(points2d: &dyn core::ToInputArray, rs: &mut dyn core::ToOutputArray, ts: &mut dyn core::ToOutputArray, k: &mut dyn core::ToInputOutputArray, points3d: &mut dyn core::ToOutputArray, is_projective: bool) -> Result<()> {
+pub fn reconstruct_1(points2d: &(impl core::ToInputArray + ?Sized), rs: &mut (impl core::ToOutputArray + ?Sized), ts: &mut (impl core::ToOutputArray + ?Sized), k: &mut (impl core::ToInputOutputArray + ?Sized), points3d: &mut (impl core::ToOutputArray + ?Sized), is_projective: bool) -> Result<()> {
 	input_array_arg!(points2d);
 	output_array_arg!(rs);
 	output_array_arg!(ts);
@@ -659,7 +659,7 @@ pub fn reconstruct_1(points2d: &dyn core::ToInputArray, rs: &mut dyn core::ToOut
 /// ## C++ default parameters
 /// * is_projective: false
 #[inline]
-pub fn reconstruct_2(images: core::Vector<String>, ps: &mut dyn core::ToOutputArray, points3d: &mut dyn core::ToOutputArray, k: &mut dyn core::ToInputOutputArray, is_projective: bool) -> Result<()> {
+pub fn reconstruct_2(images: core::Vector<String>, ps: &mut (impl core::ToOutputArray + ?Sized), points3d: &mut (impl core::ToOutputArray + ?Sized), k: &mut (impl core::ToInputOutputArray + ?Sized), is_projective: bool) -> Result<()> {
 	output_array_arg!(ps);
 	output_array_arg!(points3d);
 	input_output_array_arg!(k);
@@ -690,7 +690,7 @@ pub fn reconstruct_2(images: core::Vector<String>, ps: &mut dyn core::ToOutputAr
 /// ## C++ default parameters
 /// * is_projective: false
 #[inline]
-pub fn reconstruct_3(images: core::Vector<String>, rs: &mut dyn core::ToOutputArray, ts: &mut dyn core::ToOutputArray, k: &mut dyn core::ToInputOutputArray, points3d: &mut dyn core::ToOutputArray, is_projective: bool) -> Result<()> {
+pub fn reconstruct_3(images: core::Vector<String>, rs: &mut (impl core::ToOutputArray + ?Sized), ts: &mut (impl core::ToOutputArray + ?Sized), k: &mut (impl core::ToInputOutputArray + ?Sized), points3d: &mut (impl core::ToOutputArray + ?Sized), is_projective: bool) -> Result<()> {
 	output_array_arg!(rs);
 	output_array_arg!(ts);
 	input_output_array_arg!(k);
@@ -715,7 +715,7 @@ pub fn reconstruct_3(images: core::Vector<String>, rs: &mut dyn core::ToOutputAr
 /// of the second one assuming the first one to be at the origin.
 /// If T1 and T2 are the camera motions, the computed relative motion is ![inline formula](https://latex.codecogs.com/png.latex?T%20%3D%20T%5F2%20T%5F1%5E%7B%2D1%7D)
 #[inline]
-pub fn relative_camera_motion(r1: &dyn core::ToInputArray, t1: &dyn core::ToInputArray, r2: &dyn core::ToInputArray, t2: &dyn core::ToInputArray, r: &mut dyn core::ToOutputArray, t: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn relative_camera_motion(r1: &(impl core::ToInputArray + ?Sized), t1: &(impl core::ToInputArray + ?Sized), r2: &(impl core::ToInputArray + ?Sized), t2: &(impl core::ToInputArray + ?Sized), r: &mut (impl core::ToOutputArray + ?Sized), t: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(r1);
 	input_array_arg!(t1);
 	input_array_arg!(r2);
@@ -735,7 +735,7 @@ pub fn relative_camera_motion(r1: &dyn core::ToInputArray, t1: &dyn core::ToInpu
 /// 
 /// Reference: [HartleyZ00](https://docs.opencv.org/4.6.0/d0/de3/citelist.html#CITEREF_HartleyZ00), p581, equation (A4.5).
 #[inline]
-pub fn skew(x: &dyn core::ToInputArray) -> Result<core::Mat> {
+pub fn skew(x: &(impl core::ToInputArray + ?Sized)) -> Result<core::Mat> {
 	input_array_arg!(x);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_sfm_skew_const__InputArrayR(x.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
@@ -754,7 +754,7 @@ pub fn skew(x: &dyn core::ToInputArray) -> Result<core::Mat> {
 /// Triangulates the 3d position of 2d correspondences between several images.
 /// Reference: Internally it uses DLT method [HartleyZ00](https://docs.opencv.org/4.6.0/d0/de3/citelist.html#CITEREF_HartleyZ00) 12.2 pag.312
 #[inline]
-pub fn triangulate_points(points2d: &dyn core::ToInputArray, projection_matrices: &dyn core::ToInputArray, points3d: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn triangulate_points(points2d: &(impl core::ToInputArray + ?Sized), projection_matrices: &(impl core::ToInputArray + ?Sized), points3d: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(points2d);
 	input_array_arg!(projection_matrices);
 	output_array_arg!(points3d);
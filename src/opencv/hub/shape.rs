@@ -24,7 +24,7 @@ pub mod prelude {
 /// the histogram in each bin.
 /// * signature2: Second signature of the same format and size as signature1.
 #[inline]
-pub fn emdl1(signature1: &dyn core::ToInputArray, signature2: &dyn core::ToInputArray) -> Result<f32> {
+pub fn emdl1(signature1: &(impl core::ToInputArray + ?Sized), signature2: &(impl core::ToInputArray + ?Sized)) -> Result<f32> {
 	input_array_arg!(signature1);
 	input_array_arg!(signature2);
 	return_send!(via ocvrs_return);
@@ -784,9 +784,9 @@ pub trait ShapeTransformer: core::AlgorithmTrait + crate::shape::ShapeTransforme
 	/// ## C++ default parameters
 	/// * output: noArray()
 	#[inline]
-	fn apply_transformation(&mut self, input: &dyn core::ToInputArray, output: &mut dyn core::ToOutputArray) -> Result<f32> {
+	fn apply_transformation(&mut self, input: &dyn core::ToInputArray, output: Option<&mut dyn core::ToOutputArray>) -> Result<f32> {
 		input_array_arg!(input);
-		output_array_arg!(output);
+		output_array_arg!(nullable output);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ShapeTransformer_applyTransformation_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_ShapeTransformer(), input.as_raw__InputArray(), output.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
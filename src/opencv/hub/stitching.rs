@@ -150,9 +150,9 @@ pub fn auto_detect_wave_correct_kind(rmats: &core::Vector<core::Mat>) -> Result<
 /// ## C++ default parameters
 /// * mask: noArray()
 #[inline]
-pub fn compute_image_features2(features_finder: &core::Ptr<crate::features2d::Feature2D>, image: &dyn core::ToInputArray, features: &mut crate::stitching::Detail_ImageFeatures, mask: &dyn core::ToInputArray) -> Result<()> {
+pub fn compute_image_features2(features_finder: &core::Ptr<crate::features2d::Feature2D>, image: &(impl core::ToInputArray + ?Sized), features: &mut crate::stitching::Detail_ImageFeatures, mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(image);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_detail_computeImageFeatures_const_PtrLFeature2DGR_const__InputArrayR_ImageFeaturesR_const__InputArrayR(features_finder.as_raw_PtrOfFeature2D(), image.as_raw__InputArray(), features.as_raw_mut_Detail_ImageFeatures(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -171,9 +171,9 @@ pub fn compute_image_features2(features_finder: &core::Ptr<crate::features2d::Fe
 /// ## C++ default parameters
 /// * masks: noArray()
 #[inline]
-pub fn compute_image_features(features_finder: &core::Ptr<crate::features2d::Feature2D>, images: &dyn core::ToInputArray, features: &mut core::Vector<crate::stitching::Detail_ImageFeatures>, masks: &dyn core::ToInputArray) -> Result<()> {
+pub fn compute_image_features(features_finder: &core::Ptr<crate::features2d::Feature2D>, images: &(impl core::ToInputArray + ?Sized), features: &mut core::Vector<crate::stitching::Detail_ImageFeatures>, masks: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(images);
-	input_array_arg!(masks);
+	input_array_arg!(nullable masks);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_detail_computeImageFeatures_const_PtrLFeature2DGR_const__InputArrayR_vectorLImageFeaturesGR_const__InputArrayR(features_finder.as_raw_PtrOfFeature2D(), images.as_raw__InputArray(), features.as_raw_mut_VectorOfDetail_ImageFeatures(), masks.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -182,7 +182,7 @@ pub fn compute_image_features(features_finder: &core::Ptr<crate::features2d::Fea
 }
 
 #[inline]
-pub fn create_laplace_pyr_gpu(img: &dyn core::ToInputArray, num_levels: i32, pyr: &mut core::Vector<core::UMat>) -> Result<()> {
+pub fn create_laplace_pyr_gpu(img: &(impl core::ToInputArray + ?Sized), num_levels: i32, pyr: &mut core::Vector<core::UMat>) -> Result<()> {
 	input_array_arg!(img);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_detail_createLaplacePyrGpu_const__InputArrayR_int_vectorLUMatGR(img.as_raw__InputArray(), num_levels, pyr.as_raw_mut_VectorOfUMat(), ocvrs_return.as_mut_ptr()) };
@@ -192,7 +192,7 @@ pub fn create_laplace_pyr_gpu(img: &dyn core::ToInputArray, num_levels: i32, pyr
 }
 
 #[inline]
-pub fn create_laplace_pyr(img: &dyn core::ToInputArray, num_levels: i32, pyr: &mut core::Vector<core::UMat>) -> Result<()> {
+pub fn create_laplace_pyr(img: &(impl core::ToInputArray + ?Sized), num_levels: i32, pyr: &mut core::Vector<core::UMat>) -> Result<()> {
 	input_array_arg!(img);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_detail_createLaplacePyr_const__InputArrayR_int_vectorLUMatGR(img.as_raw__InputArray(), num_levels, pyr.as_raw_mut_VectorOfUMat(), ocvrs_return.as_mut_ptr()) };
@@ -202,7 +202,7 @@ pub fn create_laplace_pyr(img: &dyn core::ToInputArray, num_levels: i32, pyr: &m
 }
 
 #[inline]
-pub fn create_weight_map(mask: &dyn core::ToInputArray, sharpness: f32, weight: &mut dyn core::ToInputOutputArray) -> Result<()> {
+pub fn create_weight_map(mask: &(impl core::ToInputArray + ?Sized), sharpness: f32, weight: &mut (impl core::ToInputOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(mask);
 	input_output_array_arg!(weight);
 	return_send!(via ocvrs_return);
@@ -244,7 +244,7 @@ pub fn matches_graph_as_string(pathes: &mut core::Vector<String>, pairwise_match
 
 /// ///////////////////////////////////////////////////////////////////////////
 #[inline]
-pub fn normalize_using_weight_map(weight: &dyn core::ToInputArray, src: &mut dyn core::ToInputOutputArray) -> Result<()> {
+pub fn normalize_using_weight_map(weight: &(impl core::ToInputArray + ?Sized), src: &mut (impl core::ToInputOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(weight);
 	input_output_array_arg!(src);
 	return_send!(via ocvrs_return);
@@ -1823,9 +1823,9 @@ pub trait StitcherTrait: crate::stitching::StitcherTraitConst {
 	/// ## C++ default parameters
 	/// * masks: noArray()
 	#[inline]
-	fn estimate_transform(&mut self, images: &dyn core::ToInputArray, masks: &dyn core::ToInputArray) -> Result<crate::stitching::Stitcher_Status> {
+	fn estimate_transform(&mut self, images: &dyn core::ToInputArray, masks: Option<&dyn core::ToInputArray>) -> Result<crate::stitching::Stitcher_Status> {
 		input_array_arg!(images);
-		input_array_arg!(masks);
+		input_array_arg!(nullable masks);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_Stitcher_estimateTransform_const__InputArrayR_const__InputArrayR(self.as_raw_mut_Stitcher(), images.as_raw__InputArray(), masks.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -3462,6 +3462,14 @@ pub trait Detail_CameraParamsTraitConst {
 		ret
 	}
 	
+	/// Same as `r()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn r_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_detail_CameraParams_getPropRRef_const(self.as_raw_Detail_CameraParams()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn t(&self) -> core::Mat {
 		let ret = unsafe { sys::cv_detail_CameraParams_getPropT_const(self.as_raw_Detail_CameraParams()) };
@@ -3469,6 +3477,14 @@ pub trait Detail_CameraParamsTraitConst {
 		ret
 	}
 	
+	/// Same as `t()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn t_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_detail_CameraParams_getPropTRef_const(self.as_raw_Detail_CameraParams()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn k(&self) -> Result<core::Mat> {
 		return_send!(via ocvrs_return);
@@ -4398,6 +4414,14 @@ pub trait Detail_DisjointSetsTraitConst {
 		ret
 	}
 	
+	/// Same as `parent()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn parent_ref(&self) -> BoxedRef<'_, core::Vector<i32>> {
+		let ret = unsafe { sys::cv_detail_DisjointSets_getPropParentRef_const(self.as_raw_Detail_DisjointSets()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn size(&self) -> core::Vector<i32> {
 		let ret = unsafe { sys::cv_detail_DisjointSets_getPropSize_const(self.as_raw_Detail_DisjointSets()) };
@@ -4405,6 +4429,14 @@ pub trait Detail_DisjointSetsTraitConst {
 		ret
 	}
 	
+	/// Same as `size()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn size_ref(&self) -> BoxedRef<'_, core::Vector<i32>> {
+		let ret = unsafe { sys::cv_detail_DisjointSets_getPropSizeRef_const(self.as_raw_Detail_DisjointSets()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 }
 
 pub trait Detail_DisjointSetsTrait: crate::stitching::Detail_DisjointSetsTraitConst {
@@ -5382,7 +5414,7 @@ pub trait Detail_GraphCutSeamFinderGpuTrait: crate::stitching::Detail_GraphCutSe
 	}
 	
 	#[inline]
-	fn find_in_pair(&mut self, first: size_t, second: size_t, roi: core::Rect) -> Result<()> {
+	fn find_in_pair(&mut self, first: usize, second: usize, roi: core::Rect) -> Result<()> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_detail_GraphCutSeamFinderGpu_findInPair_size_t_size_t_Rect(self.as_raw_mut_Detail_GraphCutSeamFinderGpu(), first, second, roi.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -5641,6 +5673,14 @@ pub trait Detail_ImageFeaturesTraitConst {
 		ret
 	}
 	
+	/// Same as `keypoints()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn keypoints_ref(&self) -> BoxedRef<'_, core::Vector<core::KeyPoint>> {
+		let ret = unsafe { sys::cv_detail_ImageFeatures_getPropKeypointsRef_const(self.as_raw_Detail_ImageFeatures()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn descriptors(&self) -> core::UMat {
 		let ret = unsafe { sys::cv_detail_ImageFeatures_getPropDescriptors_const(self.as_raw_Detail_ImageFeatures()) };
@@ -5648,6 +5688,14 @@ pub trait Detail_ImageFeaturesTraitConst {
 		ret
 	}
 	
+	/// Same as `descriptors()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn descriptors_ref(&self) -> BoxedRef<'_, core::UMat> {
+		let ret = unsafe { sys::cv_detail_ImageFeatures_getPropDescriptorsRef_const(self.as_raw_Detail_ImageFeatures()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 }
 
 pub trait Detail_ImageFeaturesTrait: crate::stitching::Detail_ImageFeaturesTraitConst {
@@ -5745,6 +5793,14 @@ pub trait Detail_MatchesInfoTraitConst {
 		ret
 	}
 	
+	/// Same as `matches()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn matches_ref(&self) -> BoxedRef<'_, core::Vector<core::DMatch>> {
+		let ret = unsafe { sys::cv_detail_MatchesInfo_getPropMatchesRef_const(self.as_raw_Detail_MatchesInfo()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// Geometrically consistent matches mask
 	#[inline]
 	fn inliers_mask(&self) -> core::Vector<u8> {
@@ -5753,6 +5809,14 @@ pub trait Detail_MatchesInfoTraitConst {
 		ret
 	}
 	
+	/// Same as `inliers_mask()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn inliers_mask_ref(&self) -> BoxedRef<'_, core::Vector<u8>> {
+		let ret = unsafe { sys::cv_detail_MatchesInfo_getPropInliers_maskRef_const(self.as_raw_Detail_MatchesInfo()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// Number of geometrically consistent matches
 	#[inline]
 	fn num_inliers(&self) -> i32 {
@@ -5768,6 +5832,14 @@ pub trait Detail_MatchesInfoTraitConst {
 		ret
 	}
 	
+	/// Same as `h()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn h_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_detail_MatchesInfo_getPropHRef_const(self.as_raw_Detail_MatchesInfo()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// Confidence two images are from the same panorama
 	#[inline]
 	fn confidence(&self) -> f64 {
@@ -7205,35 +7277,35 @@ pub trait Detail_ProjectorBaseTrait: crate::stitching::Detail_ProjectorBaseTrait
 	#[inline]
 	fn k(&mut self) -> &mut [f32; 9] {
 		let ret = unsafe { sys::cv_detail_ProjectorBase_getPropK(self.as_raw_mut_Detail_ProjectorBase()) };
-		let ret = unsafe { ret.as_mut() }.expect("Function returned null pointer");
+		let ret = unsafe { &mut *ret };
 		ret
 	}
 	
 	#[inline]
 	fn rinv(&mut self) -> &mut [f32; 9] {
 		let ret = unsafe { sys::cv_detail_ProjectorBase_getPropRinv(self.as_raw_mut_Detail_ProjectorBase()) };
-		let ret = unsafe { ret.as_mut() }.expect("Function returned null pointer");
+		let ret = unsafe { &mut *ret };
 		ret
 	}
 	
 	#[inline]
 	fn r_kinv(&mut self) -> &mut [f32; 9] {
 		let ret = unsafe { sys::cv_detail_ProjectorBase_getPropR_kinv(self.as_raw_mut_Detail_ProjectorBase()) };
-		let ret = unsafe { ret.as_mut() }.expect("Function returned null pointer");
+		let ret = unsafe { &mut *ret };
 		ret
 	}
 	
 	#[inline]
 	fn k_rinv(&mut self) -> &mut [f32; 9] {
 		let ret = unsafe { sys::cv_detail_ProjectorBase_getPropK_rinv(self.as_raw_mut_Detail_ProjectorBase()) };
-		let ret = unsafe { ret.as_mut() }.expect("Function returned null pointer");
+		let ret = unsafe { &mut *ret };
 		ret
 	}
 	
 	#[inline]
 	fn t(&mut self) -> &mut [f32; 3] {
 		let ret = unsafe { sys::cv_detail_ProjectorBase_getPropT(self.as_raw_mut_Detail_ProjectorBase()) };
-		let ret = unsafe { ret.as_mut() }.expect("Function returned null pointer");
+		let ret = unsafe { &mut *ret };
 		ret
 	}
 	
@@ -8129,3 +8201,4 @@ impl crate::stitching::Detail_VoronoiSeamFinderTrait for Detail_VoronoiSeamFinde
 
 impl Detail_VoronoiSeamFinder {
 }
+pub use crate::manual::stitching::*;
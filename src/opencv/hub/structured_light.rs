@@ -56,7 +56,7 @@ pub trait GrayCodePatternConst: crate::structured_light::StructuredLightPatternC
 	/// ## Returns
 	/// The number of pattern images needed for the graycode pattern.
 	#[inline]
-	fn get_number_of_pattern_images(&self) -> Result<size_t> {
+	fn get_number_of_pattern_images(&self) -> Result<usize> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_structured_light_GrayCodePattern_getNumberOfPatternImages_const(self.as_raw_GrayCodePattern(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -116,7 +116,7 @@ pub trait GrayCodePattern: crate::structured_light::GrayCodePatternConst + crate
 	/// ## Parameters
 	/// * value: The desired white threshold value.
 	#[inline]
-	fn set_white_threshold(&mut self, value: size_t) -> Result<()> {
+	fn set_white_threshold(&mut self, value: usize) -> Result<()> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_structured_light_GrayCodePattern_setWhiteThreshold_size_t(self.as_raw_mut_GrayCodePattern(), value, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -131,7 +131,7 @@ pub trait GrayCodePattern: crate::structured_light::GrayCodePatternConst + crate
 	/// ## Parameters
 	/// * value: The desired black threshold value.
 	#[inline]
-	fn set_black_threshold(&mut self, value: size_t) -> Result<()> {
+	fn set_black_threshold(&mut self, value: usize) -> Result<()> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_structured_light_GrayCodePattern_setBlackThreshold_size_t(self.as_raw_mut_GrayCodePattern(), value, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -149,9 +149,9 @@ impl dyn GrayCodePattern + '_ {
 	/// ## C++ default parameters
 	/// * parameters: GrayCodePattern::Params()
 	#[inline]
-	pub fn create(parameters: &crate::structured_light::GrayCodePattern_Params) -> Result<core::Ptr<dyn crate::structured_light::GrayCodePattern>> {
+	pub fn create(parameters: Option<&crate::structured_light::GrayCodePattern_Params>) -> Result<core::Ptr<dyn crate::structured_light::GrayCodePattern>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_structured_light_GrayCodePattern_create_const_ParamsR(parameters.as_raw_GrayCodePattern_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_structured_light_GrayCodePattern_create_const_ParamsR(parameters.map_or(::core::ptr::null(), |parameters| parameters.as_raw_GrayCodePattern_Params()), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::structured_light::GrayCodePattern>::opencv_from_extern(ret) };
@@ -270,11 +270,11 @@ pub trait SinusoidalPattern: crate::structured_light::SinusoidalPatternConst + c
 	/// * shadow_mask: noArray()
 	/// * fundamental: noArray()
 	#[inline]
-	fn compute_phase_map(&mut self, pattern_images: &dyn core::ToInputArray, wrapped_phase_map: &mut dyn core::ToOutputArray, shadow_mask: &mut dyn core::ToOutputArray, fundamental: &dyn core::ToInputArray) -> Result<()> {
+	fn compute_phase_map(&mut self, pattern_images: &dyn core::ToInputArray, wrapped_phase_map: &mut dyn core::ToOutputArray, shadow_mask: Option<&mut dyn core::ToOutputArray>, fundamental: Option<&dyn core::ToInputArray>) -> Result<()> {
 		input_array_arg!(pattern_images);
 		output_array_arg!(wrapped_phase_map);
-		output_array_arg!(shadow_mask);
-		input_array_arg!(fundamental);
+		output_array_arg!(nullable shadow_mask);
+		input_array_arg!(nullable fundamental);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_structured_light_SinusoidalPattern_computePhaseMap_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_const__InputArrayR(self.as_raw_mut_SinusoidalPattern(), pattern_images.as_raw__InputArray(), wrapped_phase_map.as_raw__OutputArray(), shadow_mask.as_raw__OutputArray(), fundamental.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -292,10 +292,10 @@ pub trait SinusoidalPattern: crate::structured_light::SinusoidalPatternConst + c
 	/// ## C++ default parameters
 	/// * shadow_mask: noArray()
 	#[inline]
-	fn unwrap_phase_map(&mut self, wrapped_phase_map: &dyn core::ToInputArray, unwrapped_phase_map: &mut dyn core::ToOutputArray, cam_size: core::Size, shadow_mask: &dyn core::ToInputArray) -> Result<()> {
+	fn unwrap_phase_map(&mut self, wrapped_phase_map: &dyn core::ToInputArray, unwrapped_phase_map: &mut dyn core::ToOutputArray, cam_size: core::Size, shadow_mask: Option<&dyn core::ToInputArray>) -> Result<()> {
 		input_array_arg!(wrapped_phase_map);
 		output_array_arg!(unwrapped_phase_map);
-		input_array_arg!(shadow_mask);
+		input_array_arg!(nullable shadow_mask);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_structured_light_SinusoidalPattern_unwrapPhaseMap_const__InputArrayR_const__OutputArrayR_Size_const__InputArrayR(self.as_raw_mut_SinusoidalPattern(), wrapped_phase_map.as_raw__InputArray(), unwrapped_phase_map.as_raw__OutputArray(), cam_size.opencv_as_extern(), shadow_mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -425,6 +425,14 @@ pub trait SinusoidalPattern_ParamsTraitConst {
 		ret
 	}
 	
+	/// Same as `markers_location()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn markers_location_ref(&self) -> BoxedRef<'_, core::Vector<core::Point2f>> {
+		let ret = unsafe { sys::cv_structured_light_SinusoidalPattern_Params_getPropMarkersLocationRef_const(self.as_raw_SinusoidalPattern_Params()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 }
 
 pub trait SinusoidalPattern_ParamsTrait: crate::structured_light::SinusoidalPattern_ParamsTraitConst {
@@ -552,10 +560,10 @@ pub trait StructuredLightPatternConst: core::AlgorithmTraitConst {
 	/// * white_images: noArray()
 	/// * flags: DECODE_3D_UNDERWORLD
 	#[inline]
-	fn decode(&self, pattern_images: &core::Vector<core::Vector<core::Mat>>, disparity_map: &mut dyn core::ToOutputArray, black_images: &dyn core::ToInputArray, white_images: &dyn core::ToInputArray, flags: i32) -> Result<bool> {
+	fn decode(&self, pattern_images: &core::Vector<core::Vector<core::Mat>>, disparity_map: &mut dyn core::ToOutputArray, black_images: Option<&dyn core::ToInputArray>, white_images: Option<&dyn core::ToInputArray>, flags: i32) -> Result<bool> {
 		output_array_arg!(disparity_map);
-		input_array_arg!(black_images);
-		input_array_arg!(white_images);
+		input_array_arg!(nullable black_images);
+		input_array_arg!(nullable white_images);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_structured_light_StructuredLightPattern_decode_const_const_vectorLvectorLMatGGR_const__OutputArrayR_const__InputArrayR_const__InputArrayR_int(self.as_raw_StructuredLightPattern(), pattern_images.as_raw_VectorOfVectorOfMat(), disparity_map.as_raw__OutputArray(), black_images.as_raw__InputArray(), white_images.as_raw__InputArray(), flags, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -583,3 +591,4 @@ pub trait StructuredLightPattern: core::AlgorithmTrait + crate::structured_light
 	}
 	
 }
+pub use crate::manual::structured_light::*;
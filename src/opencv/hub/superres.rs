@@ -325,11 +325,11 @@ pub trait Superres_DenseOpticalFlowExt: core::AlgorithmTrait + crate::superres::
 	/// ## C++ default parameters
 	/// * flow2: noArray()
 	#[inline]
-	fn calc(&mut self, frame0: &dyn core::ToInputArray, frame1: &dyn core::ToInputArray, flow1: &mut dyn core::ToOutputArray, flow2: &mut dyn core::ToOutputArray) -> Result<()> {
+	fn calc(&mut self, frame0: &dyn core::ToInputArray, frame1: &dyn core::ToInputArray, flow1: &mut dyn core::ToOutputArray, flow2: Option<&mut dyn core::ToOutputArray>) -> Result<()> {
 		input_array_arg!(frame0);
 		input_array_arg!(frame1);
 		output_array_arg!(flow1);
-		output_array_arg!(flow2);
+		output_array_arg!(nullable flow2);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_superres_DenseOpticalFlowExt_calc_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_Superres_DenseOpticalFlowExt(), frame0.as_raw__InputArray(), frame1.as_raw__InputArray(), flow1.as_raw__OutputArray(), flow2.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
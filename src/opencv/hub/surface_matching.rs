@@ -681,13 +681,13 @@ pub trait Pose3DTraitConst {
 	}
 	
 	#[inline]
-	fn model_index(&self) -> size_t {
+	fn model_index(&self) -> usize {
 		let ret = unsafe { sys::cv_ppf_match_3d_Pose3D_getPropModelIndex_const(self.as_raw_Pose3D()) };
 		ret
 	}
 	
 	#[inline]
-	fn num_votes(&self) -> size_t {
+	fn num_votes(&self) -> usize {
 		let ret = unsafe { sys::cv_ppf_match_3d_Pose3D_getPropNumVotes_const(self.as_raw_Pose3D()) };
 		ret
 	}
@@ -740,13 +740,13 @@ pub trait Pose3DTrait: crate::surface_matching::Pose3DTraitConst {
 	}
 	
 	#[inline]
-	fn set_model_index(&mut self, val: size_t) {
+	fn set_model_index(&mut self, val: usize) {
 		let ret = unsafe { sys::cv_ppf_match_3d_Pose3D_setPropModelIndex_size_t(self.as_raw_mut_Pose3D(), val) };
 		ret
 	}
 	
 	#[inline]
-	fn set_num_votes(&mut self, val: size_t) {
+	fn set_num_votes(&mut self, val: usize) {
 		let ret = unsafe { sys::cv_ppf_match_3d_Pose3D_setPropNumVotes_size_t(self.as_raw_mut_Pose3D(), val) };
 		ret
 	}
@@ -899,7 +899,7 @@ impl Pose3D {
 	/// * model_index: 0
 	/// * num_votes: 0
 	#[inline]
-	pub fn new(alpha: f64, model_index: size_t, num_votes: size_t) -> Result<crate::surface_matching::Pose3D> {
+	pub fn new(alpha: f64, model_index: usize, num_votes: usize) -> Result<crate::surface_matching::Pose3D> {
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ppf_match_3d_Pose3D_Pose3D_double_size_t_size_t(alpha, model_index, num_votes, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -923,8 +923,16 @@ pub trait PoseCluster3DTraitConst {
 		ret
 	}
 	
+	/// Same as `pose_list()`, but returns the reference to the field instead of its copy
 	#[inline]
-	fn num_votes(&self) -> size_t {
+	fn pose_list_ref(&self) -> BoxedRef<'_, core::Vector<crate::surface_matching::Pose3DPtr>> {
+		let ret = unsafe { sys::cv_ppf_match_3d_PoseCluster3D_getPropPoseListRef_const(self.as_raw_PoseCluster3D()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
+	#[inline]
+	fn num_votes(&self) -> usize {
 		let ret = unsafe { sys::cv_ppf_match_3d_PoseCluster3D_getPropNumVotes_const(self.as_raw_PoseCluster3D()) };
 		ret
 	}
@@ -947,7 +955,7 @@ pub trait PoseCluster3DTrait: crate::surface_matching::PoseCluster3DTraitConst {
 	}
 	
 	#[inline]
-	fn set_num_votes(&mut self, val: size_t) {
+	fn set_num_votes(&mut self, val: usize) {
 		let ret = unsafe { sys::cv_ppf_match_3d_PoseCluster3D_setPropNumVotes_size_t(self.as_raw_mut_PoseCluster3D(), val) };
 		ret
 	}
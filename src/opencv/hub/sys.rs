@@ -40,20 +40,25 @@ mod aruco_sys {
 		pub fn cv_aruco_refineDetectedMarkers_const__InputArrayR_const_PtrLBoardGR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputOutputArrayR_const__InputArrayR_const__InputArrayR_float_float_bool_const__OutputArrayR_const_PtrLDetectorParametersGR(image: *const c_void, board: *const c_void, detected_corners: *const c_void, detected_ids: *const c_void, rejected_corners: *const c_void, camera_matrix: *const c_void, dist_coeffs: *const c_void, min_rep_distance: f32, error_correction_rate: f32, check_all_orders: bool, recovered_idxs: *const c_void, parameters: *const c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_aruco_testCharucoCornersCollinear_const_PtrLCharucoBoardGR_const__InputArrayR(_board: *const c_void, _charuco_ids: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_aruco_Board_getPropObjPoints_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_aruco_Board_getPropObjPointsRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_aruco_Board_setPropObjPoints_vectorLvectorLPoint3fGG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_aruco_Board_getPropDictionary(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_aruco_Board_setPropDictionary_PtrLDictionaryG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_aruco_Board_getPropIds_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_aruco_Board_getPropIdsRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_aruco_Board_setPropIds_vectorLintG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_aruco_Board_getPropRightBottomBorder_const(instance: *const c_void, ocvrs_return: *mut core::Point3f);
 		pub fn cv_aruco_Board_setPropRightBottomBorder_Point3f(instance: *mut c_void, val: *const core::Point3f);
 		pub fn cv_aruco_Board_create_const__InputArrayR_const_PtrLDictionaryGR_const__InputArrayR(obj_points: *const c_void, dictionary: *const c_void, ids: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_aruco_Board_setIds_const__InputArrayR(instance: *mut c_void, ids: *const c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_aruco_CharucoBoard_getPropChessboardCorners_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_aruco_CharucoBoard_getPropChessboardCornersRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_aruco_CharucoBoard_setPropChessboardCorners_vectorLPoint3fG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_aruco_CharucoBoard_getPropNearestMarkerIdx_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_aruco_CharucoBoard_getPropNearestMarkerIdxRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_aruco_CharucoBoard_setPropNearestMarkerIdx_vectorLvectorLintGG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_aruco_CharucoBoard_getPropNearestMarkerCorners_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_aruco_CharucoBoard_getPropNearestMarkerCornersRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_aruco_CharucoBoard_setPropNearestMarkerCorners_vectorLvectorLintGG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_aruco_CharucoBoard_draw_Size_const__OutputArrayR_int_int(instance: *mut c_void, out_size: *const core::Size, img: *const c_void, margin_size: i32, border_bits: i32, ocvrs_return: *mut Result_void);
 		pub fn cv_aruco_CharucoBoard_create_int_int_float_float_const_PtrLDictionaryGR(squares_x: i32, squares_y: i32, square_length: f32, marker_length: f32, dictionary: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
@@ -128,6 +133,7 @@ mod aruco_sys {
 		pub fn cv_aruco_DetectorParameters_create(ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_aruco_DetectorParameters_readDetectorParameters_const_FileNodeR(instance: *mut c_void, fn_: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_aruco_Dictionary_getPropBytesList_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_aruco_Dictionary_getPropBytesListRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_aruco_Dictionary_setPropBytesList_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_aruco_Dictionary_getPropMarkerSize_const(instance: *const c_void) -> i32;
 		pub fn cv_aruco_Dictionary_setPropMarkerSize_int(instance: *mut c_void, val: i32);
@@ -324,10 +330,10 @@ mod calib3d_sys {
 		pub fn cv_drawChessboardCorners_const__InputOutputArrayR_Size_const__InputArrayR_bool(image: *const c_void, pattern_size: *const core::Size, corners: *const c_void, pattern_was_found: bool, ocvrs_return: *mut Result_void);
 		pub fn cv_drawFrameAxes_const__InputOutputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_float_int(image: *const c_void, camera_matrix: *const c_void, dist_coeffs: *const c_void, rvec: *const c_void, tvec: *const c_void, length: f32, thickness: i32, ocvrs_return: *mut Result_void);
 		pub fn cv_estimateAffine2D_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const_UsacParamsR(pts1: *const c_void, pts2: *const c_void, inliers: *const c_void, params: *const crate::calib3d::UsacParams, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_estimateAffine2D_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_double_size_t_double_size_t(from: *const c_void, to: *const c_void, inliers: *const c_void, method: i32, ransac_reproj_threshold: f64, max_iters: size_t, confidence: f64, refine_iters: size_t, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_estimateAffine2D_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_double_size_t_double_size_t(from: *const c_void, to: *const c_void, inliers: *const c_void, method: i32, ransac_reproj_threshold: f64, max_iters: usize, confidence: f64, refine_iters: usize, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_estimateAffine3D_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_double_double(src: *const c_void, dst: *const c_void, out: *const c_void, inliers: *const c_void, ransac_threshold: f64, confidence: f64, ocvrs_return: *mut Result<i32>);
 		pub fn cv_estimateAffine3D_const__InputArrayR_const__InputArrayR_doubleX_bool(src: *const c_void, dst: *const c_void, scale: *mut f64, force_rotation: bool, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_estimateAffinePartial2D_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_double_size_t_double_size_t(from: *const c_void, to: *const c_void, inliers: *const c_void, method: i32, ransac_reproj_threshold: f64, max_iters: size_t, confidence: f64, refine_iters: size_t, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_estimateAffinePartial2D_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_double_size_t_double_size_t(from: *const c_void, to: *const c_void, inliers: *const c_void, method: i32, ransac_reproj_threshold: f64, max_iters: usize, confidence: f64, refine_iters: usize, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_estimateChessboardSharpness_const__InputArrayR_Size_const__InputArrayR_float_bool_const__OutputArrayR(image: *const c_void, pattern_size: *const core::Size, corners: *const c_void, rise_distance: f32, vertical: bool, sharpness: *const c_void, ocvrs_return: *mut Result<core::Scalar>);
 		pub fn cv_estimateTranslation3D_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__OutputArrayR_double_double(src: *const c_void, dst: *const c_void, out: *const c_void, inliers: *const c_void, ransac_threshold: f64, confidence: f64, ocvrs_return: *mut Result<i32>);
 		pub fn cv_filterHomographyDecompByVisibleRefpoints_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR(rotations: *const c_void, normals: *const c_void, before_points: *const c_void, after_points: *const c_void, possible_solutions: *const c_void, points_mask: *const c_void, ocvrs_return: *mut Result_void);
@@ -493,9 +499,11 @@ mod ccalib_sys {
 		pub fn cv_multicalib_MultiCameraCalibration_edge_getPropPhotoIndex_const(instance: *const c_void) -> i32;
 		pub fn cv_multicalib_MultiCameraCalibration_edge_setPropPhotoIndex_int(instance: *mut c_void, val: i32);
 		pub fn cv_multicalib_MultiCameraCalibration_edge_getPropTransform_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_multicalib_MultiCameraCalibration_edge_getPropTransformRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_multicalib_MultiCameraCalibration_edge_setPropTransform_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_multicalib_MultiCameraCalibration_edge_edge_int_int_int_Mat(cv: i32, pv: i32, pi: i32, trans: *mut c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_multicalib_MultiCameraCalibration_vertex_getPropPose_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_multicalib_MultiCameraCalibration_vertex_getPropPoseRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_multicalib_MultiCameraCalibration_vertex_setPropPose_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_multicalib_MultiCameraCalibration_vertex_getPropTimestamp_const(instance: *const c_void) -> i32;
 		pub fn cv_multicalib_MultiCameraCalibration_vertex_setPropTimestamp_int(instance: *mut c_void, val: i32);
@@ -521,11 +529,11 @@ mod core_sys {
 	use super::*;
 
 	extern "C" {
-		pub fn cv_Cholesky_doubleX_size_t_int_doubleX_size_t_int(a: *mut f64, astep: size_t, m: i32, b: *mut f64, bstep: size_t, n: i32, ocvrs_return: *mut Result<bool>);
-		pub fn cv_Cholesky_floatX_size_t_int_floatX_size_t_int(a: *mut f32, astep: size_t, m: i32, b: *mut f32, bstep: size_t, n: i32, ocvrs_return: *mut Result<bool>);
+		pub fn cv_Cholesky_doubleX_size_t_int_doubleX_size_t_int(a: *mut f64, astep: usize, m: i32, b: *mut f64, bstep: usize, n: i32, ocvrs_return: *mut Result<bool>);
+		pub fn cv_Cholesky_floatX_size_t_int_floatX_size_t_int(a: *mut f32, astep: usize, m: i32, b: *mut f32, bstep: usize, n: i32, ocvrs_return: *mut Result<bool>);
 		pub fn cv_LUT_const__InputArrayR_const__InputArrayR_const__OutputArrayR(src: *const c_void, lut: *const c_void, dst: *const c_void, ocvrs_return: *mut Result_void);
-		pub fn cv_LU_doubleX_size_t_int_doubleX_size_t_int(a: *mut f64, astep: size_t, m: i32, b: *mut f64, bstep: size_t, n: i32, ocvrs_return: *mut Result<i32>);
-		pub fn cv_LU_floatX_size_t_int_floatX_size_t_int(a: *mut f32, astep: size_t, m: i32, b: *mut f32, bstep: size_t, n: i32, ocvrs_return: *mut Result<i32>);
+		pub fn cv_LU_doubleX_size_t_int_doubleX_size_t_int(a: *mut f64, astep: usize, m: i32, b: *mut f64, bstep: usize, n: i32, ocvrs_return: *mut Result<i32>);
+		pub fn cv_LU_floatX_size_t_int_floatX_size_t_int(a: *mut f32, astep: usize, m: i32, b: *mut f32, bstep: usize, n: i32, ocvrs_return: *mut Result<i32>);
 		pub fn cv_Mahalanobis_const__InputArrayR_const__InputArrayR_const__InputArrayR(v1: *const c_void, v2: *const c_void, icovar: *const c_void, ocvrs_return: *mut Result<f64>);
 		pub fn cv_PCABackProject_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__OutputArrayR(data: *const c_void, mean: *const c_void, eigenvectors: *const c_void, result: *const c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_PCACompute_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR_double(data: *const c_void, mean: *const c_void, eigenvectors: *const c_void, eigenvalues: *const c_void, retained_variance: f64, ocvrs_return: *mut Result_void);
@@ -568,7 +576,7 @@ mod core_sys {
 		pub fn cv_cuda_printShortCudaDeviceInfo_int(device: i32, ocvrs_return: *mut Result_void);
 		pub fn cv_cuda_registerPageLocked_MatR(m: *mut c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_cuda_resetDevice(ocvrs_return: *mut Result_void);
-		pub fn cv_cuda_setBufferPoolConfig_int_size_t_int(device_id: i32, stack_size: size_t, stack_count: i32, ocvrs_return: *mut Result_void);
+		pub fn cv_cuda_setBufferPoolConfig_int_size_t_int(device_id: i32, stack_size: usize, stack_count: i32, ocvrs_return: *mut Result_void);
 		pub fn cv_cuda_setBufferPoolUsage_bool(on: bool, ocvrs_return: *mut Result_void);
 		pub fn cv_cuda_setDevice_int(device: i32, ocvrs_return: *mut Result_void);
 		pub fn cv_cuda_setGlDevice_int(device: i32, ocvrs_return: *mut Result_void);
@@ -589,8 +597,8 @@ mod core_sys {
 		pub fn cv_detail_check_failed_auto_const_float_const_float_const_CheckContextR(v1: f32, v2: f32, ctx: *const c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_detail_check_failed_auto_const_int_const_CheckContextR(v: i32, ctx: *const c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_detail_check_failed_auto_const_int_const_int_const_CheckContextR(v1: i32, v2: i32, ctx: *const c_void, ocvrs_return: *mut Result_void);
-		pub fn cv_detail_check_failed_auto_const_size_t_const_CheckContextR(v: size_t, ctx: *const c_void, ocvrs_return: *mut Result_void);
-		pub fn cv_detail_check_failed_auto_const_size_t_const_size_t_const_CheckContextR(v1: size_t, v2: size_t, ctx: *const c_void, ocvrs_return: *mut Result_void);
+		pub fn cv_detail_check_failed_auto_const_size_t_const_CheckContextR(v: usize, ctx: *const c_void, ocvrs_return: *mut Result_void);
+		pub fn cv_detail_check_failed_auto_const_size_t_const_size_t_const_CheckContextR(v1: usize, v2: usize, ctx: *const c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_detail_check_failed_auto_const_stringR_const_CheckContextR(v1: *const c_char, ctx: *const c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_determinant_const__InputArrayR(mtx: *const c_void, ocvrs_return: *mut Result<f64>);
 		pub fn cv_dft_const__InputArrayR_const__OutputArrayR_int_int(src: *const c_void, dst: *const c_void, flags: i32, nonzero_rows: i32, ocvrs_return: *mut Result_void);
@@ -611,7 +619,7 @@ mod core_sys {
 		pub fn cv_getBuildInformation(ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_getCPUFeaturesLine(ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_getCPUTickCount(ocvrs_return: *mut Result<i64>);
-		pub fn cv_getElemSize_int(typ: i32, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_getElemSize_int(typ: i32, ocvrs_return: *mut Result<usize>);
 		pub fn cv_getHardwareFeatureName_int(feature: i32, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_getLogLevel(ocvrs_return: *mut Result<i32>);
 		pub fn cv_getNumThreads(ocvrs_return: *mut Result<i32>);
@@ -668,7 +676,7 @@ mod core_sys {
 		pub fn cv_min_const_UMatR_const_UMatR_UMatR(src1: *const c_void, src2: *const c_void, dst: *mut c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_min_const__InputArrayR_const__InputArrayR_const__OutputArrayR(src1: *const c_void, src2: *const c_void, dst: *const c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_min_double_const_MatR(s: f64, a: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_mixChannels_const__InputArrayR_const__InputOutputArrayR_const_intX_size_t(src: *const c_void, dst: *const c_void, from_to: *const i32, npairs: size_t, ocvrs_return: *mut Result_void);
+		pub fn cv_mixChannels_const__InputArrayR_const__InputOutputArrayR_const_intX_size_t(src: *const c_void, dst: *const c_void, from_to: *const i32, npairs: usize, ocvrs_return: *mut Result_void);
 		pub fn cv_mixChannels_const__InputArrayR_const__InputOutputArrayR_const_vectorLintGR(src: *const c_void, dst: *const c_void, from_to: *const c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_mulSpectrums_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_bool(a: *const c_void, b: *const c_void, c: *const c_void, flags: i32, conj_b: bool, ocvrs_return: *mut Result_void);
 		pub fn cv_mulTransposed_const__InputArrayR_const__OutputArrayR_bool_const__InputArrayR_double_int(src: *const c_void, dst: *const c_void, a_ta: bool, delta: *const c_void, scale: f64, dtype: i32, ocvrs_return: *mut Result_void);
@@ -682,7 +690,7 @@ mod core_sys {
 		pub fn cv_ocl_attachContext_const_StringR_voidX_voidX_voidX(platform_name: *const c_char, platform_id: *mut c_void, context: *mut c_void, device_id: *mut c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_ocl_buildOptionsAddMatrixDescription_StringR_const_StringR_const__InputArrayR(build_options: *mut *mut c_void, name: *const c_char, _m: *const c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_ocl_checkOptimalVectorWidth_const_intX_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR_OclVectorStrategy(vector_widths: *const i32, src1: *const c_void, src2: *const c_void, src3: *const c_void, src4: *const c_void, src5: *const c_void, src6: *const c_void, src7: *const c_void, src8: *const c_void, src9: *const c_void, strat: core::OclVectorStrategy, ocvrs_return: *mut Result<i32>);
-		pub fn cv_ocl_convertFromBuffer_voidX_size_t_int_int_int_UMatR(cl_mem_buffer: *mut c_void, step: size_t, rows: i32, cols: i32, typ: i32, dst: *mut c_void, ocvrs_return: *mut Result_void);
+		pub fn cv_ocl_convertFromBuffer_voidX_size_t_int_int_int_UMatR(cl_mem_buffer: *mut c_void, step: usize, rows: i32, cols: i32, typ: i32, dst: *mut c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_ocl_convertFromImage_voidX_UMatR(cl_mem_image: *mut c_void, dst: *mut c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_ocl_convertTypeStr_int_int_int_charX(sdepth: i32, ddepth: i32, cn: i32, buf: *mut *mut c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_ocl_finish(ocvrs_return: *mut Result_void);
@@ -843,16 +851,16 @@ mod core_sys {
 		pub fn cv_utils_dumpRange_const_RangeR(argument: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_utils_dumpRect_const_RectR(argument: *const core::Rect, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_utils_dumpRotatedRect_const_RotatedRectR(argument: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_utils_dumpSizeT_size_t(argument: size_t, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_utils_dumpSizeT_size_t(argument: usize, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_utils_dumpString_const_StringR(argument: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_utils_dumpTermCriteria_const_TermCriteriaR(argument: *const core::TermCriteria, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_utils_dumpVectorOfDouble_const_vectorLdoubleGR(vec: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_utils_dumpVectorOfInt_const_vectorLintGR(vec: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_utils_dumpVectorOfRect_const_vectorLRectGR(vec: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_utils_fs_getCacheDirectoryForDownloads(ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_utils_generateVectorOfInt_size_t_vectorLintGR(len: size_t, vec: *mut c_void, ocvrs_return: *mut Result_void);
-		pub fn cv_utils_generateVectorOfMat_size_t_int_int_int_vectorLMatGR(len: size_t, rows: i32, cols: i32, dtype: i32, vec: *mut c_void, ocvrs_return: *mut Result_void);
-		pub fn cv_utils_generateVectorOfRect_size_t_vectorLRectGR(len: size_t, vec: *mut c_void, ocvrs_return: *mut Result_void);
+		pub fn cv_utils_generateVectorOfInt_size_t_vectorLintGR(len: usize, vec: *mut c_void, ocvrs_return: *mut Result_void);
+		pub fn cv_utils_generateVectorOfMat_size_t_int_int_int_vectorLMatGR(len: usize, rows: i32, cols: i32, dtype: i32, vec: *mut c_void, ocvrs_return: *mut Result_void);
+		pub fn cv_utils_generateVectorOfRect_size_t_vectorLRectGR(len: usize, vec: *mut c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_utils_getThreadID(ocvrs_return: *mut Result<i32>);
 		pub fn cv_utils_logging_getLogLevel(ocvrs_return: *mut Result<core::LogLevel>);
 		pub fn cv_utils_logging_getLogTagLevel_const_charX(tag: *const c_char, ocvrs_return: *mut Result<core::LogLevel>);
@@ -932,14 +940,18 @@ mod core_sys {
 		pub fn cv_DownhillSolver_setInitStep_const__InputArrayR(instance: *mut c_void, step: *const c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_DownhillSolver_create_const_PtrLFunctionGR_const__InputArrayR_TermCriteria(f: *const c_void, init_step: *const c_void, termcrit: *const core::TermCriteria, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_Exception_getPropMsg_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_Exception_getPropMsgRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_Exception_setPropMsg_String(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_Exception_getPropCode_const(instance: *const c_void) -> i32;
 		pub fn cv_Exception_setPropCode_int(instance: *mut c_void, val: i32);
 		pub fn cv_Exception_getPropErr_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_Exception_getPropErrRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_Exception_setPropErr_String(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_Exception_getPropFunc_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_Exception_getPropFuncRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_Exception_setPropFunc_String(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_Exception_getPropFile_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_Exception_getPropFileRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_Exception_setPropFile_String(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_Exception_getPropLine_const(instance: *const c_void) -> i32;
 		pub fn cv_Exception_setPropLine_int(instance: *mut c_void, val: i32);
@@ -947,12 +959,12 @@ mod core_sys {
 		pub fn cv_Exception_Exception_int_const_StringR_const_StringR_const_StringR_int(_code: i32, _err: *const c_char, _func: *const c_char, _file: *const c_char, _line: i32, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_Exception_what_const(instance: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_Exception_formatMessage(instance: *mut c_void, ocvrs_return: *mut Result_void);
-		pub fn cv_FileNode_getPropBlockIdx_const(instance: *const c_void) -> size_t;
-		pub fn cv_FileNode_setPropBlockIdx_size_t(instance: *mut c_void, val: size_t);
-		pub fn cv_FileNode_getPropOfs_const(instance: *const c_void) -> size_t;
-		pub fn cv_FileNode_setPropOfs_size_t(instance: *mut c_void, val: size_t);
+		pub fn cv_FileNode_getPropBlockIdx_const(instance: *const c_void) -> usize;
+		pub fn cv_FileNode_setPropBlockIdx_size_t(instance: *mut c_void, val: usize);
+		pub fn cv_FileNode_getPropOfs_const(instance: *const c_void) -> usize;
+		pub fn cv_FileNode_setPropOfs_size_t(instance: *mut c_void, val: usize);
 		pub fn cv_FileNode_FileNode(ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_FileNode_FileNode_const_FileStorageX_size_t_size_t(fs: *const c_void, block_idx: size_t, ofs: size_t, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_FileNode_FileNode_const_FileStorageX_size_t_size_t(fs: *const c_void, block_idx: usize, ofs: usize, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_FileNode_FileNode_const_FileNodeR(node: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_FileNode_operator___const_const_StringR(instance: *const c_void, nodename: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_FileNode_operator___const_const_charX(instance: *const c_void, nodename: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
@@ -968,8 +980,8 @@ mod core_sys {
 		pub fn cv_FileNode_isString_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_FileNode_isNamed_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_FileNode_name_const(instance: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_FileNode_size_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_FileNode_rawSize_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_FileNode_size_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
+		pub fn cv_FileNode_rawSize_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
 		pub fn cv_FileNode_operator_int_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_FileNode_operator_float_const(instance: *const c_void, ocvrs_return: *mut Result<f32>);
 		pub fn cv_FileNode_operator_double_const(instance: *const c_void, ocvrs_return: *mut Result<f64>);
@@ -983,7 +995,7 @@ mod core_sys {
 		pub fn cv_FileNode_ptr_const(instance: *const c_void, ocvrs_return: *mut Result<*const u8>);
 		pub fn cv_FileNode_begin_const(instance: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_FileNode_end_const(instance: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_FileNode_readRaw_const_const_StringR_voidX_size_t(instance: *const c_void, fmt: *const c_char, vec: *mut c_void, len: size_t, ocvrs_return: *mut Result_void);
+		pub fn cv_FileNode_readRaw_const_const_StringR_voidX_size_t(instance: *const c_void, fmt: *const c_char, vec: *mut c_void, len: usize, ocvrs_return: *mut Result_void);
 		pub fn cv_FileNode_setValue_int_const_voidX_int(instance: *mut c_void, typ: i32, value: *const c_void, len: i32, ocvrs_return: *mut Result_void);
 		pub fn cv_FileNode_real_const(instance: *const c_void, ocvrs_return: *mut Result<f64>);
 		pub fn cv_FileNode_string_const(instance: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
@@ -993,12 +1005,13 @@ mod core_sys {
 		pub fn cv_FileNodeIterator_FileNodeIterator_const_FileNodeIteratorR(it: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_FileNodeIterator_operatorX_const(instance: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_FileNodeIterator_operatorAA(instance: *mut c_void, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_FileNodeIterator_readRaw_const_StringR_voidX_size_t(instance: *mut c_void, fmt: *const c_char, vec: *mut c_void, len: size_t, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_FileNodeIterator_remaining_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_FileNodeIterator_readRaw_const_StringR_voidX_size_t(instance: *mut c_void, fmt: *const c_char, vec: *mut c_void, len: usize, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_FileNodeIterator_remaining_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
 		pub fn cv_FileNodeIterator_equalTo_const_const_FileNodeIteratorR(instance: *const c_void, it: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_FileStorage_getPropState_const(instance: *const c_void) -> i32;
 		pub fn cv_FileStorage_setPropState_int(instance: *mut c_void, val: i32);
 		pub fn cv_FileStorage_getPropElname_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_FileStorage_getPropElnameRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_FileStorage_setPropElname_string(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_FileStorage_FileStorage(ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_FileStorage_FileStorage_const_StringR_int_const_StringR(filename: *const c_char, flags: i32, encoding: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
@@ -1015,7 +1028,7 @@ mod core_sys {
 		pub fn cv_FileStorage_write_const_StringR_const_StringR(instance: *mut c_void, name: *const c_char, val: *const c_char, ocvrs_return: *mut Result_void);
 		pub fn cv_FileStorage_write_const_StringR_const_MatR(instance: *mut c_void, name: *const c_char, val: *const c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_FileStorage_write_const_StringR_const_vectorLStringGR(instance: *mut c_void, name: *const c_char, val: *const c_void, ocvrs_return: *mut Result_void);
-		pub fn cv_FileStorage_writeRaw_const_StringR_const_voidX_size_t(instance: *mut c_void, fmt: *const c_char, vec: *const c_void, len: size_t, ocvrs_return: *mut Result_void);
+		pub fn cv_FileStorage_writeRaw_const_StringR_const_voidX_size_t(instance: *mut c_void, fmt: *const c_char, vec: *const c_void, len: usize, ocvrs_return: *mut Result_void);
 		pub fn cv_FileStorage_writeComment_const_StringR_bool(instance: *mut c_void, comment: *const c_char, append: bool, ocvrs_return: *mut Result_void);
 		pub fn cv_FileStorage_startWriteStruct_const_StringR_int_const_StringR(instance: *mut c_void, name: *const c_char, flags: i32, type_name: *const c_char, ocvrs_return: *mut Result_void);
 		pub fn cv_FileStorage_endWriteStruct(instance: *mut c_void, ocvrs_return: *mut Result_void);
@@ -1032,7 +1045,7 @@ mod core_sys {
 		pub fn cv_KeyPoint_KeyPoint(ocvrs_return: *mut Result<core::KeyPoint>);
 		pub fn cv_KeyPoint_KeyPoint_Point2f_float_float_float_int_int(pt: *const core::Point2f, size: f32, angle: f32, response: f32, octave: i32, class_id: i32, ocvrs_return: *mut Result<core::KeyPoint>);
 		pub fn cv_KeyPoint_KeyPoint_float_float_float_float_float_int_int(x: f32, y: f32, size: f32, angle: f32, response: f32, octave: i32, class_id: i32, ocvrs_return: *mut Result<core::KeyPoint>);
-		pub fn cv_KeyPoint_hash_const(instance: *const core::KeyPoint, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_KeyPoint_hash_const(instance: *const core::KeyPoint, ocvrs_return: *mut Result<usize>);
 		pub fn cv_KeyPoint_convert_const_vectorLKeyPointGR_vectorLPoint2fGR_const_vectorLintGR(keypoints: *const c_void, points2f: *mut c_void, keypoint_indexes: *const c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_KeyPoint_convert_const_vectorLPoint2fGR_vectorLKeyPointGR_float_float_int_int(points2f: *const c_void, keypoints: *mut c_void, size: f32, response: f32, octave: i32, class_id: i32, ocvrs_return: *mut Result_void);
 		pub fn cv_KeyPoint_overlap_const_KeyPointR_const_KeyPointR(kp1: *const core::KeyPoint, kp2: *const core::KeyPoint, ocvrs_return: *mut Result<f32>);
@@ -1065,7 +1078,9 @@ mod core_sys {
 		pub fn cv_Mat_getPropU(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_Mat_setPropU_UMatDataX(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_Mat_getPropSize_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_Mat_getPropSizeRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_Mat_getPropStep_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_Mat_getPropStepRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_Mat_Mat() -> *mut c_void;
 		pub fn cv_Mat_Mat_int_int_int(rows: i32, cols: i32, typ: i32, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_Mat_Mat_Size_int(size: *const core::Size, typ: i32, ocvrs_return: *mut Result<*mut c_void>);
@@ -1076,10 +1091,10 @@ mod core_sys {
 		pub fn cv_Mat_Mat_int_const_intX_int_const_ScalarR(ndims: i32, sizes: *const i32, typ: i32, s: *const core::Scalar, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_Mat_Mat_const_vectorLintGR_int_const_ScalarR(sizes: *const c_void, typ: i32, s: *const core::Scalar, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_Mat_Mat_const_MatR(m: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_Mat_Mat_int_int_int_voidX_size_t(rows: i32, cols: i32, typ: i32, data: *mut c_void, step: size_t, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_Mat_Mat_Size_int_voidX_size_t(size: *const core::Size, typ: i32, data: *mut c_void, step: size_t, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_Mat_Mat_int_const_intX_int_voidX_const_size_tX(ndims: i32, sizes: *const i32, typ: i32, data: *mut c_void, steps: *const size_t, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_Mat_Mat_const_vectorLintGR_int_voidX_const_size_tX(sizes: *const c_void, typ: i32, data: *mut c_void, steps: *const size_t, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_Mat_Mat_int_int_int_voidX_size_t(rows: i32, cols: i32, typ: i32, data: *mut c_void, step: usize, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_Mat_Mat_Size_int_voidX_size_t(size: *const core::Size, typ: i32, data: *mut c_void, step: usize, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_Mat_Mat_int_const_intX_int_voidX_const_size_tX(ndims: i32, sizes: *const i32, typ: i32, data: *mut c_void, steps: *const usize, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_Mat_Mat_const_vectorLintGR_int_voidX_const_size_tX(sizes: *const c_void, typ: i32, data: *mut c_void, steps: *const usize, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_Mat_Mat_const_MatR_const_RangeR_const_RangeR(m: *const c_void, row_range: *const c_void, col_range: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_Mat_Mat_const_MatR_const_RectR(m: *const c_void, roi: *const core::Rect, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_Mat_Mat_const_MatR_const_vectorLRangeGR(m: *const c_void, ranges: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
@@ -1122,25 +1137,25 @@ mod core_sys {
 		pub fn cv_Mat_addref(instance: *mut c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_Mat_release(instance: *mut c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_Mat_deallocate(instance: *mut c_void, ocvrs_return: *mut Result_void);
-		pub fn cv_Mat_reserve_size_t(instance: *mut c_void, sz: size_t, ocvrs_return: *mut Result_void);
-		pub fn cv_Mat_reserveBuffer_size_t(instance: *mut c_void, sz: size_t, ocvrs_return: *mut Result_void);
-		pub fn cv_Mat_resize_size_t(instance: *mut c_void, sz: size_t, ocvrs_return: *mut Result_void);
-		pub fn cv_Mat_resize_size_t_const_ScalarR(instance: *mut c_void, sz: size_t, s: *const core::Scalar, ocvrs_return: *mut Result_void);
+		pub fn cv_Mat_reserve_size_t(instance: *mut c_void, sz: usize, ocvrs_return: *mut Result_void);
+		pub fn cv_Mat_reserveBuffer_size_t(instance: *mut c_void, sz: usize, ocvrs_return: *mut Result_void);
+		pub fn cv_Mat_resize_size_t(instance: *mut c_void, sz: usize, ocvrs_return: *mut Result_void);
+		pub fn cv_Mat_resize_size_t_const_ScalarR(instance: *mut c_void, sz: usize, s: *const core::Scalar, ocvrs_return: *mut Result_void);
 		pub fn cv_Mat_push_back_const_MatR(instance: *mut c_void, m: *const c_void, ocvrs_return: *mut Result_void);
-		pub fn cv_Mat_pop_back_size_t(instance: *mut c_void, nelems: size_t, ocvrs_return: *mut Result_void);
+		pub fn cv_Mat_pop_back_size_t(instance: *mut c_void, nelems: usize, ocvrs_return: *mut Result_void);
 		pub fn cv_Mat_locateROI_const_SizeR_PointR(instance: *const c_void, whole_size: *mut core::Size, ofs: *mut core::Point, ocvrs_return: *mut Result_void);
 		pub fn cv_Mat_adjustROI_int_int_int_int(instance: *mut c_void, dtop: i32, dbottom: i32, dleft: i32, dright: i32, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_Mat_isContinuous_const(instance: *const c_void) -> bool;
 		pub fn cv_Mat_isSubmatrix_const(instance: *const c_void) -> bool;
-		pub fn cv_Mat_elemSize_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_Mat_elemSize1_const(instance: *const c_void) -> size_t;
+		pub fn cv_Mat_elemSize_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
+		pub fn cv_Mat_elemSize1_const(instance: *const c_void) -> usize;
 		pub fn cv_Mat_type_const(instance: *const c_void) -> i32;
 		pub fn cv_Mat_depth_const(instance: *const c_void) -> i32;
 		pub fn cv_Mat_channels_const(instance: *const c_void) -> i32;
-		pub fn cv_Mat_step1_const_int(instance: *const c_void, i: i32, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_Mat_step1_const_int(instance: *const c_void, i: i32, ocvrs_return: *mut Result<usize>);
 		pub fn cv_Mat_empty_const(instance: *const c_void) -> bool;
-		pub fn cv_Mat_total_const(instance: *const c_void) -> size_t;
-		pub fn cv_Mat_total_const_int_int(instance: *const c_void, start_dim: i32, end_dim: i32, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_Mat_total_const(instance: *const c_void) -> usize;
+		pub fn cv_Mat_total_const_int_int(instance: *const c_void, start_dim: i32, end_dim: i32, ocvrs_return: *mut Result<usize>);
 		pub fn cv_Mat_checkVector_const_int_int_bool(instance: *const c_void, elem_channels: i32, depth: i32, require_continuous: bool, ocvrs_return: *mut Result<i32>);
 		pub fn cv_Mat_ptr_int(instance: *mut c_void, i0: i32, ocvrs_return: *mut Result<*mut u8>);
 		pub fn cv_Mat_ptr_const_int(instance: *const c_void, i0: i32, ocvrs_return: *mut Result<*const u8>);
@@ -1153,8 +1168,9 @@ mod core_sys {
 		pub fn cv_Mat_Mat_MatRR(m: *mut c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_Mat_updateContinuityFlag(instance: *mut c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_MatConstIterator_getPropM_const(instance: *const c_void) -> *mut c_void;
-		pub fn cv_MatConstIterator_getPropElemSize_const(instance: *const c_void) -> size_t;
-		pub fn cv_MatConstIterator_setPropElemSize_size_t(instance: *mut c_void, val: size_t);
+		pub fn cv_MatConstIterator_getPropMRef_const(instance: *const c_void) -> *const c_void;
+		pub fn cv_MatConstIterator_getPropElemSize_const(instance: *const c_void) -> usize;
+		pub fn cv_MatConstIterator_setPropElemSize_size_t(instance: *mut c_void, val: usize);
 		pub fn cv_MatConstIterator_getPropPtr_const(instance: *const c_void) -> *const u8;
 		pub fn cv_MatConstIterator_getPropSliceStart_const(instance: *const c_void) -> *const u8;
 		pub fn cv_MatConstIterator_getPropSliceEnd_const(instance: *const c_void) -> *const u8;
@@ -1164,21 +1180,24 @@ mod core_sys {
 		pub fn cv_MatConstIterator_MatConstIterator_const_MatX_Point(_m: *const c_void, _pt: *const core::Point, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_MatConstIterator_MatConstIterator_const_MatConstIteratorR(it: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_MatConstIterator_operatorX_const(instance: *const c_void, ocvrs_return: *mut Result<*const u8>);
-		pub fn cv_MatConstIterator_operator___const_ptrdiff_t(instance: *const c_void, i: ptrdiff_t, ocvrs_return: *mut Result<*const u8>);
+		pub fn cv_MatConstIterator_operator___const_ptrdiff_t(instance: *const c_void, i: isize, ocvrs_return: *mut Result<*const u8>);
 		pub fn cv_MatConstIterator_operatorSS(instance: *mut c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_MatConstIterator_operatorAA(instance: *mut c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_MatConstIterator_pos_const(instance: *const c_void, ocvrs_return: *mut Result<core::Point>);
 		pub fn cv_MatConstIterator_pos_const_intX(instance: *const c_void, _idx: *mut i32, ocvrs_return: *mut Result_void);
-		pub fn cv_MatConstIterator_lpos_const(instance: *const c_void, ocvrs_return: *mut Result<ptrdiff_t>);
-		pub fn cv_MatConstIterator_seek_ptrdiff_t_bool(instance: *mut c_void, ofs: ptrdiff_t, relative: bool, ocvrs_return: *mut Result_void);
+		pub fn cv_MatConstIterator_lpos_const(instance: *const c_void, ocvrs_return: *mut Result<isize>);
+		pub fn cv_MatConstIterator_seek_ptrdiff_t_bool(instance: *mut c_void, ofs: isize, relative: bool, ocvrs_return: *mut Result_void);
 		pub fn cv_MatConstIterator_seek_const_intX_bool(instance: *mut c_void, _idx: *const i32, relative: bool, ocvrs_return: *mut Result_void);
 		pub fn cv_MatExpr_getPropFlags_const(instance: *const c_void) -> i32;
 		pub fn cv_MatExpr_setPropFlags_int(instance: *mut c_void, val: i32);
 		pub fn cv_MatExpr_getPropA_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_MatExpr_getPropARef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_MatExpr_setPropA_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_MatExpr_getPropB_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_MatExpr_getPropBRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_MatExpr_setPropB_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_MatExpr_getPropC_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_MatExpr_getPropCRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_MatExpr_setPropC_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_MatExpr_getPropAlpha_const(instance: *const c_void) -> f64;
 		pub fn cv_MatExpr_setPropAlpha_double(instance: *mut c_void, val: f64);
@@ -1236,14 +1255,14 @@ mod core_sys {
 		pub fn cv_MatSize_operator_const_intX_const(instance: *const c_void) -> *const i32;
 		pub fn cv_MatSize_operatorEQ_const_const_MatSizeR(instance: *const c_void, sz: *const c_void) -> bool;
 		pub fn cv_MatSize_operatorNE_const_const_MatSizeR(instance: *const c_void, sz: *const c_void) -> bool;
-		pub fn cv_MatStep_getPropP(instance: *mut c_void) -> *mut size_t;
-		pub fn cv_MatStep_setPropP_size_tX(instance: *mut c_void, val: *mut size_t);
-		pub fn cv_MatStep_getPropBuf(instance: *mut c_void) -> *mut [size_t; 2];
+		pub fn cv_MatStep_getPropP(instance: *mut c_void) -> *mut usize;
+		pub fn cv_MatStep_setPropP_size_tX(instance: *mut c_void, val: *mut usize);
+		pub fn cv_MatStep_getPropBuf(instance: *mut c_void) -> *mut [usize; 2];
 		pub fn cv_MatStep_MatStep() -> *mut c_void;
-		pub fn cv_MatStep_MatStep_size_t(s: size_t) -> *mut c_void;
-		pub fn cv_MatStep_operator___const_int(instance: *const c_void, i: i32) -> size_t;
-		pub fn cv_MatStep_operator___int(instance: *mut c_void, i: i32) -> size_t;
-		pub fn cv_MatStep_operator_size_t_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_MatStep_MatStep_size_t(s: usize) -> *mut c_void;
+		pub fn cv_MatStep_operator___const_int(instance: *const c_void, i: i32) -> usize;
+		pub fn cv_MatStep_operator___int(instance: *mut c_void, i: i32) -> usize;
+		pub fn cv_MatStep_operator_size_t_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
 		pub fn cv_Matx_AddOp_Matx_AddOp(ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_Matx_AddOp_Matx_AddOp_const_Matx_AddOpR(unnamed: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_Matx_DivOp_Matx_DivOp(ocvrs_return: *mut Result<*mut c_void>);
@@ -1270,10 +1289,13 @@ mod core_sys {
 		pub fn cv_Moments_Moments(ocvrs_return: *mut Result<core::Moments>);
 		pub fn cv_Moments_Moments_double_double_double_double_double_double_double_double_double_double(m00: f64, m10: f64, m01: f64, m20: f64, m11: f64, m02: f64, m30: f64, m21: f64, m12: f64, m03: f64, ocvrs_return: *mut Result<core::Moments>);
 		pub fn cv_PCA_getPropEigenvectors_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_PCA_getPropEigenvectorsRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_PCA_setPropEigenvectors_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_PCA_getPropEigenvalues_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_PCA_getPropEigenvaluesRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_PCA_setPropEigenvalues_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_PCA_getPropMean_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_PCA_getPropMeanRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_PCA_setPropMean_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_PCA_PCA(ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_PCA_PCA_const__InputArrayR_const__InputArrayR_int_int(data: *const c_void, mean: *const c_void, flags: i32, max_components: i32, ocvrs_return: *mut Result<*mut c_void>);
@@ -1336,10 +1358,13 @@ mod core_sys {
 		pub fn cv_RotatedRect_boundingRect_const(instance: *const c_void, ocvrs_return: *mut Result<core::Rect>);
 		pub fn cv_RotatedRect_boundingRect2f_const(instance: *const c_void, ocvrs_return: *mut Result<core::Rect_<f32>>);
 		pub fn cv_SVD_getPropU_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_SVD_getPropURef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_SVD_setPropU_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_SVD_getPropW_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_SVD_getPropWRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_SVD_setPropW_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_SVD_getPropVt_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_SVD_getPropVtRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_SVD_setPropVt_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_SVD_SVD(ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_SVD_SVD_const__InputArrayR_int(src: *const c_void, flags: i32, ocvrs_return: *mut Result<*mut c_void>);
@@ -1366,62 +1391,65 @@ mod core_sys {
 		pub fn cv_SparseMat_clear(instance: *mut c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_SparseMat_addref(instance: *mut c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_SparseMat_release(instance: *mut c_void, ocvrs_return: *mut Result_void);
-		pub fn cv_SparseMat_elemSize_const(instance: *const c_void) -> size_t;
-		pub fn cv_SparseMat_elemSize1_const(instance: *const c_void) -> size_t;
+		pub fn cv_SparseMat_elemSize_const(instance: *const c_void) -> usize;
+		pub fn cv_SparseMat_elemSize1_const(instance: *const c_void) -> usize;
 		pub fn cv_SparseMat_type_const(instance: *const c_void) -> i32;
 		pub fn cv_SparseMat_depth_const(instance: *const c_void) -> i32;
 		pub fn cv_SparseMat_channels_const(instance: *const c_void) -> i32;
 		pub fn cv_SparseMat_size_const(instance: *const c_void, ocvrs_return: *mut Result<*const i32>);
 		pub fn cv_SparseMat_size_const_int(instance: *const c_void, i: i32, ocvrs_return: *mut Result<i32>);
 		pub fn cv_SparseMat_dims_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
-		pub fn cv_SparseMat_nzcount_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_SparseMat_hash_const_int(instance: *const c_void, i0: i32, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_SparseMat_hash_const_int_int(instance: *const c_void, i0: i32, i1: i32, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_SparseMat_hash_const_int_int_int(instance: *const c_void, i0: i32, i1: i32, i2: i32, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_SparseMat_hash_const_const_intX(instance: *const c_void, idx: *const i32, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_SparseMat_ptr_int_bool_size_tX(instance: *mut c_void, i0: i32, create_missing: bool, hashval: *mut size_t, ocvrs_return: *mut Result<*mut u8>);
-		pub fn cv_SparseMat_ptr_int_int_bool_size_tX(instance: *mut c_void, i0: i32, i1: i32, create_missing: bool, hashval: *mut size_t, ocvrs_return: *mut Result<*mut u8>);
-		pub fn cv_SparseMat_ptr_int_int_int_bool_size_tX(instance: *mut c_void, i0: i32, i1: i32, i2: i32, create_missing: bool, hashval: *mut size_t, ocvrs_return: *mut Result<*mut u8>);
-		pub fn cv_SparseMat_ptr_const_intX_bool_size_tX(instance: *mut c_void, idx: *const i32, create_missing: bool, hashval: *mut size_t, ocvrs_return: *mut Result<*mut u8>);
-		pub fn cv_SparseMat_erase_int_int_size_tX(instance: *mut c_void, i0: i32, i1: i32, hashval: *mut size_t, ocvrs_return: *mut Result_void);
-		pub fn cv_SparseMat_erase_int_int_int_size_tX(instance: *mut c_void, i0: i32, i1: i32, i2: i32, hashval: *mut size_t, ocvrs_return: *mut Result_void);
-		pub fn cv_SparseMat_erase_const_intX_size_tX(instance: *mut c_void, idx: *const i32, hashval: *mut size_t, ocvrs_return: *mut Result_void);
+		pub fn cv_SparseMat_nzcount_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
+		pub fn cv_SparseMat_hash_const_int(instance: *const c_void, i0: i32, ocvrs_return: *mut Result<usize>);
+		pub fn cv_SparseMat_hash_const_int_int(instance: *const c_void, i0: i32, i1: i32, ocvrs_return: *mut Result<usize>);
+		pub fn cv_SparseMat_hash_const_int_int_int(instance: *const c_void, i0: i32, i1: i32, i2: i32, ocvrs_return: *mut Result<usize>);
+		pub fn cv_SparseMat_hash_const_const_intX(instance: *const c_void, idx: *const i32, ocvrs_return: *mut Result<usize>);
+		pub fn cv_SparseMat_ptr_int_bool_size_tX(instance: *mut c_void, i0: i32, create_missing: bool, hashval: *mut usize, ocvrs_return: *mut Result<*mut u8>);
+		pub fn cv_SparseMat_ptr_int_int_bool_size_tX(instance: *mut c_void, i0: i32, i1: i32, create_missing: bool, hashval: *mut usize, ocvrs_return: *mut Result<*mut u8>);
+		pub fn cv_SparseMat_ptr_int_int_int_bool_size_tX(instance: *mut c_void, i0: i32, i1: i32, i2: i32, create_missing: bool, hashval: *mut usize, ocvrs_return: *mut Result<*mut u8>);
+		pub fn cv_SparseMat_ptr_const_intX_bool_size_tX(instance: *mut c_void, idx: *const i32, create_missing: bool, hashval: *mut usize, ocvrs_return: *mut Result<*mut u8>);
+		pub fn cv_SparseMat_erase_int_int_size_tX(instance: *mut c_void, i0: i32, i1: i32, hashval: *mut usize, ocvrs_return: *mut Result_void);
+		pub fn cv_SparseMat_erase_int_int_int_size_tX(instance: *mut c_void, i0: i32, i1: i32, i2: i32, hashval: *mut usize, ocvrs_return: *mut Result_void);
+		pub fn cv_SparseMat_erase_const_intX_size_tX(instance: *mut c_void, idx: *const i32, hashval: *mut usize, ocvrs_return: *mut Result_void);
 		pub fn cv_SparseMat_begin(instance: *mut c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_SparseMat_begin_const(instance: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_SparseMat_end(instance: *mut c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_SparseMat_end_const(instance: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_SparseMat_node_size_t(instance: *mut c_void, nidx: size_t, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_SparseMat_node_const_size_t(instance: *const c_void, nidx: size_t, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_SparseMat_newNode_const_intX_size_t(instance: *mut c_void, idx: *const i32, hashval: size_t, ocvrs_return: *mut Result<*mut u8>);
-		pub fn cv_SparseMat_removeNode_size_t_size_t_size_t(instance: *mut c_void, hidx: size_t, nidx: size_t, previdx: size_t, ocvrs_return: *mut Result_void);
-		pub fn cv_SparseMat_resizeHashTab_size_t(instance: *mut c_void, newsize: size_t, ocvrs_return: *mut Result_void);
+		pub fn cv_SparseMat_node_size_t(instance: *mut c_void, nidx: usize, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_SparseMat_node_const_size_t(instance: *const c_void, nidx: usize, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_SparseMat_newNode_const_intX_size_t(instance: *mut c_void, idx: *const i32, hashval: usize, ocvrs_return: *mut Result<*mut u8>);
+		pub fn cv_SparseMat_removeNode_size_t_size_t_size_t(instance: *mut c_void, hidx: usize, nidx: usize, previdx: usize, ocvrs_return: *mut Result_void);
+		pub fn cv_SparseMat_resizeHashTab_size_t(instance: *mut c_void, newsize: usize, ocvrs_return: *mut Result_void);
 		pub fn cv_SparseMat_Hdr_getPropRefcount_const(instance: *const c_void) -> i32;
 		pub fn cv_SparseMat_Hdr_setPropRefcount_int(instance: *mut c_void, val: i32);
 		pub fn cv_SparseMat_Hdr_getPropDims_const(instance: *const c_void) -> i32;
 		pub fn cv_SparseMat_Hdr_setPropDims_int(instance: *mut c_void, val: i32);
 		pub fn cv_SparseMat_Hdr_getPropValueOffset_const(instance: *const c_void) -> i32;
 		pub fn cv_SparseMat_Hdr_setPropValueOffset_int(instance: *mut c_void, val: i32);
-		pub fn cv_SparseMat_Hdr_getPropNodeSize_const(instance: *const c_void) -> size_t;
-		pub fn cv_SparseMat_Hdr_setPropNodeSize_size_t(instance: *mut c_void, val: size_t);
-		pub fn cv_SparseMat_Hdr_getPropNodeCount_const(instance: *const c_void) -> size_t;
-		pub fn cv_SparseMat_Hdr_setPropNodeCount_size_t(instance: *mut c_void, val: size_t);
-		pub fn cv_SparseMat_Hdr_getPropFreeList_const(instance: *const c_void) -> size_t;
-		pub fn cv_SparseMat_Hdr_setPropFreeList_size_t(instance: *mut c_void, val: size_t);
+		pub fn cv_SparseMat_Hdr_getPropNodeSize_const(instance: *const c_void) -> usize;
+		pub fn cv_SparseMat_Hdr_setPropNodeSize_size_t(instance: *mut c_void, val: usize);
+		pub fn cv_SparseMat_Hdr_getPropNodeCount_const(instance: *const c_void) -> usize;
+		pub fn cv_SparseMat_Hdr_setPropNodeCount_size_t(instance: *mut c_void, val: usize);
+		pub fn cv_SparseMat_Hdr_getPropFreeList_const(instance: *const c_void) -> usize;
+		pub fn cv_SparseMat_Hdr_setPropFreeList_size_t(instance: *mut c_void, val: usize);
 		pub fn cv_SparseMat_Hdr_getPropPool_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_SparseMat_Hdr_getPropPoolRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_SparseMat_Hdr_setPropPool_vectorLunsigned_charG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_SparseMat_Hdr_getPropHashtab_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_SparseMat_Hdr_getPropHashtabRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_SparseMat_Hdr_setPropHashtab_vectorLsize_tG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_SparseMat_Hdr_getPropSize(instance: *mut c_void) -> *mut [i32; 32];
 		pub fn cv_SparseMat_Hdr_Hdr_int_const_intX_int(_dims: i32, _sizes: *const i32, _type: i32, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_SparseMat_Hdr_clear(instance: *mut c_void, ocvrs_return: *mut Result_void);
-		pub fn cv_SparseMat_Node_getPropHashval_const(instance: *const c_void) -> size_t;
-		pub fn cv_SparseMat_Node_setPropHashval_size_t(instance: *mut c_void, val: size_t);
-		pub fn cv_SparseMat_Node_getPropNext_const(instance: *const c_void) -> size_t;
-		pub fn cv_SparseMat_Node_setPropNext_size_t(instance: *mut c_void, val: size_t);
+		pub fn cv_SparseMat_Node_getPropHashval_const(instance: *const c_void) -> usize;
+		pub fn cv_SparseMat_Node_setPropHashval_size_t(instance: *mut c_void, val: usize);
+		pub fn cv_SparseMat_Node_getPropNext_const(instance: *const c_void) -> usize;
+		pub fn cv_SparseMat_Node_setPropNext_size_t(instance: *mut c_void, val: usize);
 		pub fn cv_SparseMat_Node_getPropIdx(instance: *mut c_void) -> *mut [i32; 32];
 		pub fn cv_SparseMatConstIterator_getPropM_const(instance: *const c_void) -> *mut c_void;
-		pub fn cv_SparseMatConstIterator_getPropHashidx_const(instance: *const c_void) -> size_t;
-		pub fn cv_SparseMatConstIterator_setPropHashidx_size_t(instance: *mut c_void, val: size_t);
+		pub fn cv_SparseMatConstIterator_getPropMRef_const(instance: *const c_void) -> *const c_void;
+		pub fn cv_SparseMatConstIterator_getPropHashidx_const(instance: *const c_void) -> usize;
+		pub fn cv_SparseMatConstIterator_setPropHashidx_size_t(instance: *mut c_void, val: usize);
 		pub fn cv_SparseMatConstIterator_getPropPtr(instance: *mut c_void) -> *mut u8;
 		pub fn cv_SparseMatConstIterator_setPropPtr_unsigned_charX(instance: *mut c_void, val: *mut u8);
 		pub fn cv_SparseMatConstIterator_SparseMatConstIterator(ocvrs_return: *mut Result<*mut c_void>);
@@ -1465,10 +1493,12 @@ mod core_sys {
 		pub fn cv_UMat_setPropUsageFlags_UMatUsageFlags(instance: *mut c_void, val: core::UMatUsageFlags);
 		pub fn cv_UMat_getPropU(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_UMat_setPropU_UMatDataX(instance: *mut c_void, val: *mut c_void);
-		pub fn cv_UMat_getPropOffset_const(instance: *const c_void) -> size_t;
-		pub fn cv_UMat_setPropOffset_size_t(instance: *mut c_void, val: size_t);
+		pub fn cv_UMat_getPropOffset_const(instance: *const c_void) -> usize;
+		pub fn cv_UMat_setPropOffset_size_t(instance: *mut c_void, val: usize);
 		pub fn cv_UMat_getPropSize_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_UMat_getPropSizeRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_UMat_getPropStep_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_UMat_getPropStepRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_UMat_UMat_UMatUsageFlags(usage_flags: core::UMatUsageFlags) -> *mut c_void;
 		pub fn cv_UMat_UMat_int_int_int_UMatUsageFlags(rows: i32, cols: i32, typ: i32, usage_flags: core::UMatUsageFlags, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_UMat_UMat_Size_int_UMatUsageFlags(size: *const core::Size, typ: i32, usage_flags: core::UMatUsageFlags, ocvrs_return: *mut Result<*mut c_void>);
@@ -1529,18 +1559,18 @@ mod core_sys {
 		pub fn cv_UMat_adjustROI_int_int_int_int(instance: *mut c_void, dtop: i32, dbottom: i32, dleft: i32, dright: i32, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_UMat_isContinuous_const(instance: *const c_void) -> bool;
 		pub fn cv_UMat_isSubmatrix_const(instance: *const c_void) -> bool;
-		pub fn cv_UMat_elemSize_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_UMat_elemSize1_const(instance: *const c_void) -> size_t;
+		pub fn cv_UMat_elemSize_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
+		pub fn cv_UMat_elemSize1_const(instance: *const c_void) -> usize;
 		pub fn cv_UMat_type_const(instance: *const c_void) -> i32;
 		pub fn cv_UMat_depth_const(instance: *const c_void) -> i32;
 		pub fn cv_UMat_channels_const(instance: *const c_void) -> i32;
-		pub fn cv_UMat_step1_const_int(instance: *const c_void, i: i32, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_UMat_step1_const_int(instance: *const c_void, i: i32, ocvrs_return: *mut Result<usize>);
 		pub fn cv_UMat_empty_const(instance: *const c_void) -> bool;
-		pub fn cv_UMat_total_const(instance: *const c_void) -> size_t;
+		pub fn cv_UMat_total_const(instance: *const c_void) -> usize;
 		pub fn cv_UMat_checkVector_const_int_int_bool(instance: *const c_void, elem_channels: i32, depth: i32, require_continuous: bool, ocvrs_return: *mut Result<i32>);
 		pub fn cv_UMat_UMat_UMatRR(m: *mut c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_UMat_handle_const_AccessFlag(instance: *const c_void, access_flags: core::AccessFlag, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_UMat_ndoffset_const_size_tX(instance: *const c_void, ofs: *mut size_t, ocvrs_return: *mut Result_void);
+		pub fn cv_UMat_ndoffset_const_size_tX(instance: *const c_void, ofs: *mut usize, ocvrs_return: *mut Result_void);
 		pub fn cv_UMat_updateContinuityFlag(instance: *mut c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_UMatData_getPropUrefcount_const(instance: *const c_void) -> i32;
 		pub fn cv_UMatData_setPropUrefcount_int(instance: *mut c_void, val: i32);
@@ -1550,8 +1580,8 @@ mod core_sys {
 		pub fn cv_UMatData_setPropData_unsigned_charX(instance: *mut c_void, val: *mut u8);
 		pub fn cv_UMatData_getPropOrigdata(instance: *mut c_void) -> *mut u8;
 		pub fn cv_UMatData_setPropOrigdata_unsigned_charX(instance: *mut c_void, val: *mut u8);
-		pub fn cv_UMatData_getPropSize_const(instance: *const c_void) -> size_t;
-		pub fn cv_UMatData_setPropSize_size_t(instance: *mut c_void, val: size_t);
+		pub fn cv_UMatData_getPropSize_const(instance: *const c_void) -> usize;
+		pub fn cv_UMatData_setPropSize_size_t(instance: *mut c_void, val: usize);
 		pub fn cv_UMatData_getPropFlags_const(instance: *const c_void, ocvrs_return: *mut core::UMatData_MemoryFlag);
 		pub fn cv_UMatData_setPropFlags_MemoryFlag(instance: *mut c_void, val: core::UMatData_MemoryFlag);
 		pub fn cv_UMatData_getPropHandle(instance: *mut c_void) -> *mut c_void;
@@ -1606,7 +1636,7 @@ mod core_sys {
 		pub fn cv__InputArray_size_const_int(instance: *const c_void, i: i32, ocvrs_return: *mut Result<core::Size>);
 		pub fn cv__InputArray_sizend_const_intX_int(instance: *const c_void, sz: *mut i32, i: i32, ocvrs_return: *mut Result<i32>);
 		pub fn cv__InputArray_sameSize_const_const__InputArrayR(instance: *const c_void, arr: *const c_void, ocvrs_return: *mut Result<bool>);
-		pub fn cv__InputArray_total_const_int(instance: *const c_void, i: i32, ocvrs_return: *mut Result<size_t>);
+		pub fn cv__InputArray_total_const_int(instance: *const c_void, i: i32, ocvrs_return: *mut Result<usize>);
 		pub fn cv__InputArray_type_const_int(instance: *const c_void, i: i32, ocvrs_return: *mut Result<i32>);
 		pub fn cv__InputArray_depth_const_int(instance: *const c_void, i: i32, ocvrs_return: *mut Result<i32>);
 		pub fn cv__InputArray_channels_const_int(instance: *const c_void, i: i32, ocvrs_return: *mut Result<i32>);
@@ -1615,8 +1645,8 @@ mod core_sys {
 		pub fn cv__InputArray_empty_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv__InputArray_copyTo_const_const__OutputArrayR(instance: *const c_void, arr: *const c_void, ocvrs_return: *mut Result_void);
 		pub fn cv__InputArray_copyTo_const_const__OutputArrayR_const__InputArrayR(instance: *const c_void, arr: *const c_void, mask: *const c_void, ocvrs_return: *mut Result_void);
-		pub fn cv__InputArray_offset_const_int(instance: *const c_void, i: i32, ocvrs_return: *mut Result<size_t>);
-		pub fn cv__InputArray_step_const_int(instance: *const c_void, i: i32, ocvrs_return: *mut Result<size_t>);
+		pub fn cv__InputArray_offset_const_int(instance: *const c_void, i: i32, ocvrs_return: *mut Result<usize>);
+		pub fn cv__InputArray_step_const_int(instance: *const c_void, i: i32, ocvrs_return: *mut Result<usize>);
 		pub fn cv__InputArray_isMat_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv__InputArray_isUMat_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv__InputArray_isMatVector_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
@@ -1691,20 +1721,20 @@ mod core_sys {
 		pub fn cv_cuda_DeviceInfo_DeviceInfo_int(device_id: i32, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_cuda_DeviceInfo_deviceID_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_cuda_DeviceInfo_name_const(instance: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_cuda_DeviceInfo_totalGlobalMem_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_cuda_DeviceInfo_sharedMemPerBlock_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_cuda_DeviceInfo_totalGlobalMem_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
+		pub fn cv_cuda_DeviceInfo_sharedMemPerBlock_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
 		pub fn cv_cuda_DeviceInfo_regsPerBlock_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_cuda_DeviceInfo_warpSize_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
-		pub fn cv_cuda_DeviceInfo_memPitch_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_cuda_DeviceInfo_memPitch_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
 		pub fn cv_cuda_DeviceInfo_maxThreadsPerBlock_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_cuda_DeviceInfo_maxThreadsDim_const(instance: *const c_void, ocvrs_return: *mut Result<core::Vec3i>);
 		pub fn cv_cuda_DeviceInfo_maxGridSize_const(instance: *const c_void, ocvrs_return: *mut Result<core::Vec3i>);
 		pub fn cv_cuda_DeviceInfo_clockRate_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
-		pub fn cv_cuda_DeviceInfo_totalConstMem_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_cuda_DeviceInfo_totalConstMem_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
 		pub fn cv_cuda_DeviceInfo_majorVersion_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_cuda_DeviceInfo_minorVersion_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
-		pub fn cv_cuda_DeviceInfo_textureAlignment_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_cuda_DeviceInfo_texturePitchAlignment_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_cuda_DeviceInfo_textureAlignment_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
+		pub fn cv_cuda_DeviceInfo_texturePitchAlignment_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
 		pub fn cv_cuda_DeviceInfo_multiProcessorCount_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_cuda_DeviceInfo_kernelExecTimeoutEnabled_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_cuda_DeviceInfo_integrated_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
@@ -1729,7 +1759,7 @@ mod core_sys {
 		pub fn cv_cuda_DeviceInfo_maxSurface2DLayered_const(instance: *const c_void, ocvrs_return: *mut Result<core::Vec3i>);
 		pub fn cv_cuda_DeviceInfo_maxSurfaceCubemap_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_cuda_DeviceInfo_maxSurfaceCubemapLayered_const(instance: *const c_void, ocvrs_return: *mut Result<core::Vec2i>);
-		pub fn cv_cuda_DeviceInfo_surfaceAlignment_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_cuda_DeviceInfo_surfaceAlignment_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
 		pub fn cv_cuda_DeviceInfo_concurrentKernels_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_cuda_DeviceInfo_ECCEnabled_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_cuda_DeviceInfo_pciBusID_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
@@ -1742,9 +1772,9 @@ mod core_sys {
 		pub fn cv_cuda_DeviceInfo_memoryBusWidth_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_cuda_DeviceInfo_l2CacheSize_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_cuda_DeviceInfo_maxThreadsPerMultiProcessor_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
-		pub fn cv_cuda_DeviceInfo_queryMemory_const_size_tR_size_tR(instance: *const c_void, total_memory: *mut size_t, free_memory: *mut size_t, ocvrs_return: *mut Result_void);
-		pub fn cv_cuda_DeviceInfo_freeMemory_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_cuda_DeviceInfo_totalMemory_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_cuda_DeviceInfo_queryMemory_const_size_tR_size_tR(instance: *const c_void, total_memory: *mut usize, free_memory: *mut usize, ocvrs_return: *mut Result_void);
+		pub fn cv_cuda_DeviceInfo_freeMemory_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
+		pub fn cv_cuda_DeviceInfo_totalMemory_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
 		pub fn cv_cuda_DeviceInfo_supports_const_FeatureSet(instance: *const c_void, feature_set: core::FeatureSet, ocvrs_return: *mut Result<bool>);
 		pub fn cv_cuda_DeviceInfo_isCompatible_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_cuda_Event_Event_const_CreateFlags(flags: core::Event_CreateFlags, ocvrs_return: *mut Result<*mut c_void>);
@@ -1754,17 +1784,17 @@ mod core_sys {
 		pub fn cv_cuda_Event_elapsedTime_const_EventR_const_EventR(start: *const c_void, end: *const c_void, ocvrs_return: *mut Result<f32>);
 		pub fn cv_cuda_GpuData_getPropData(instance: *mut c_void) -> *mut u8;
 		pub fn cv_cuda_GpuData_setPropData_unsigned_charX(instance: *mut c_void, val: *mut u8);
-		pub fn cv_cuda_GpuData_getPropSize_const(instance: *const c_void) -> size_t;
-		pub fn cv_cuda_GpuData_setPropSize_size_t(instance: *mut c_void, val: size_t);
-		pub fn cv_cuda_GpuData_GpuData_size_t(_size: size_t, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_cuda_GpuData_getPropSize_const(instance: *const c_void) -> usize;
+		pub fn cv_cuda_GpuData_setPropSize_size_t(instance: *mut c_void, val: usize);
+		pub fn cv_cuda_GpuData_GpuData_size_t(_size: usize, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_cuda_GpuMat_getPropFlags_const(instance: *const c_void) -> i32;
 		pub fn cv_cuda_GpuMat_setPropFlags_int(instance: *mut c_void, val: i32);
 		pub fn cv_cuda_GpuMat_getPropRows_const(instance: *const c_void) -> i32;
 		pub fn cv_cuda_GpuMat_setPropRows_int(instance: *mut c_void, val: i32);
 		pub fn cv_cuda_GpuMat_getPropCols_const(instance: *const c_void) -> i32;
 		pub fn cv_cuda_GpuMat_setPropCols_int(instance: *mut c_void, val: i32);
-		pub fn cv_cuda_GpuMat_getPropStep_const(instance: *const c_void) -> size_t;
-		pub fn cv_cuda_GpuMat_setPropStep_size_t(instance: *mut c_void, val: size_t);
+		pub fn cv_cuda_GpuMat_getPropStep_const(instance: *const c_void) -> usize;
+		pub fn cv_cuda_GpuMat_setPropStep_size_t(instance: *mut c_void, val: usize);
 		pub fn cv_cuda_GpuMat_getPropData(instance: *mut c_void) -> *mut u8;
 		pub fn cv_cuda_GpuMat_setPropData_unsigned_charX(instance: *mut c_void, val: *mut u8);
 		pub fn cv_cuda_GpuMat_getPropRefcount(instance: *mut c_void) -> *mut i32;
@@ -1782,8 +1812,8 @@ mod core_sys {
 		pub fn cv_cuda_GpuMat_GpuMat_int_int_int_Scalar_AllocatorX(rows: i32, cols: i32, typ: i32, s: *const core::Scalar, allocator: *mut c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_cuda_GpuMat_GpuMat_Size_int_Scalar_AllocatorX(size: *const core::Size, typ: i32, s: *const core::Scalar, allocator: *mut c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_cuda_GpuMat_GpuMat_const_GpuMatR(m: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_cuda_GpuMat_GpuMat_int_int_int_voidX_size_t(rows: i32, cols: i32, typ: i32, data: *mut c_void, step: size_t, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_cuda_GpuMat_GpuMat_Size_int_voidX_size_t(size: *const core::Size, typ: i32, data: *mut c_void, step: size_t, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_cuda_GpuMat_GpuMat_int_int_int_voidX_size_t(rows: i32, cols: i32, typ: i32, data: *mut c_void, step: usize, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_cuda_GpuMat_GpuMat_Size_int_voidX_size_t(size: *const core::Size, typ: i32, data: *mut c_void, step: usize, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_cuda_GpuMat_GpuMat_const_GpuMatR_Range_Range(m: *const c_void, row_range: *mut c_void, col_range: *mut c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_cuda_GpuMat_GpuMat_const_GpuMatR_Rect(m: *const c_void, roi: *const core::Rect, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_cuda_GpuMat_GpuMat_const__InputArrayR_AllocatorX(arr: *const c_void, allocator: *mut c_void, ocvrs_return: *mut Result<*mut c_void>);
@@ -1822,25 +1852,27 @@ mod core_sys {
 		pub fn cv_cuda_GpuMat_locateROI_const_SizeR_PointR(instance: *const c_void, whole_size: *mut core::Size, ofs: *mut core::Point, ocvrs_return: *mut Result_void);
 		pub fn cv_cuda_GpuMat_adjustROI_int_int_int_int(instance: *mut c_void, dtop: i32, dbottom: i32, dleft: i32, dright: i32, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_cuda_GpuMat_isContinuous_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
-		pub fn cv_cuda_GpuMat_elemSize_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_cuda_GpuMat_elemSize1_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_cuda_GpuMat_elemSize_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
+		pub fn cv_cuda_GpuMat_elemSize1_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
 		pub fn cv_cuda_GpuMat_type_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_cuda_GpuMat_depth_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_cuda_GpuMat_channels_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
-		pub fn cv_cuda_GpuMat_step1_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_cuda_GpuMat_step1_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
 		pub fn cv_cuda_GpuMat_size_const(instance: *const c_void, ocvrs_return: *mut Result<core::Size>);
 		pub fn cv_cuda_GpuMat_empty_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_cuda_GpuMat_cudaPtr_const(instance: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_cuda_GpuMat_updateContinuityFlag(instance: *mut c_void, ocvrs_return: *mut Result_void);
-		pub fn cv_cuda_GpuMat_Allocator_allocate_GpuMatX_int_int_size_t(instance: *mut c_void, mat: *mut c_void, rows: i32, cols: i32, elem_size: size_t, ocvrs_return: *mut Result<bool>);
+		pub fn cv_cuda_GpuMat_Allocator_allocate_GpuMatX_int_int_size_t(instance: *mut c_void, mat: *mut c_void, rows: i32, cols: i32, elem_size: usize, ocvrs_return: *mut Result<bool>);
 		pub fn cv_cuda_GpuMat_Allocator_free_GpuMatX(instance: *mut c_void, mat: *mut c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_cuda_GpuMatND_getPropFlags_const(instance: *const c_void) -> i32;
 		pub fn cv_cuda_GpuMatND_setPropFlags_int(instance: *mut c_void, val: i32);
 		pub fn cv_cuda_GpuMatND_getPropDims_const(instance: *const c_void) -> i32;
 		pub fn cv_cuda_GpuMatND_setPropDims_int(instance: *mut c_void, val: i32);
 		pub fn cv_cuda_GpuMatND_getPropSize_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_cuda_GpuMatND_getPropSizeRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_cuda_GpuMatND_setPropSize_SizeArray(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_cuda_GpuMatND_getPropStep_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_cuda_GpuMatND_getPropStepRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_cuda_GpuMatND_setPropStep_StepArray(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_cuda_GpuMatND_GpuMatND(ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_cuda_GpuMatND_GpuMatND_SizeArray_int(size: *mut c_void, typ: i32, ocvrs_return: *mut Result<*mut c_void>);
@@ -1861,13 +1893,13 @@ mod core_sys {
 		pub fn cv_cuda_GpuMatND_download_const_const__OutputArrayR_StreamR(instance: *const c_void, dst: *const c_void, stream: *mut c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_cuda_GpuMatND_isContinuous_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_cuda_GpuMatND_isSubmatrix_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
-		pub fn cv_cuda_GpuMatND_elemSize_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_cuda_GpuMatND_elemSize1_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_cuda_GpuMatND_elemSize_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
+		pub fn cv_cuda_GpuMatND_elemSize1_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
 		pub fn cv_cuda_GpuMatND_empty_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_cuda_GpuMatND_external_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_cuda_GpuMatND_getDevicePtr_const(instance: *const c_void, ocvrs_return: *mut Result<*mut u8>);
-		pub fn cv_cuda_GpuMatND_total_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_cuda_GpuMatND_totalMemSize_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_cuda_GpuMatND_total_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
+		pub fn cv_cuda_GpuMatND_totalMemSize_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
 		pub fn cv_cuda_GpuMatND_type_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_cuda_HostMem_getPropFlags_const(instance: *const c_void) -> i32;
 		pub fn cv_cuda_HostMem_setPropFlags_int(instance: *mut c_void, val: i32);
@@ -1875,8 +1907,8 @@ mod core_sys {
 		pub fn cv_cuda_HostMem_setPropRows_int(instance: *mut c_void, val: i32);
 		pub fn cv_cuda_HostMem_getPropCols_const(instance: *const c_void) -> i32;
 		pub fn cv_cuda_HostMem_setPropCols_int(instance: *mut c_void, val: i32);
-		pub fn cv_cuda_HostMem_getPropStep_const(instance: *const c_void) -> size_t;
-		pub fn cv_cuda_HostMem_setPropStep_size_t(instance: *mut c_void, val: size_t);
+		pub fn cv_cuda_HostMem_getPropStep_const(instance: *const c_void) -> usize;
+		pub fn cv_cuda_HostMem_setPropStep_size_t(instance: *mut c_void, val: usize);
 		pub fn cv_cuda_HostMem_getPropData(instance: *mut c_void) -> *mut u8;
 		pub fn cv_cuda_HostMem_setPropData_unsigned_charX(instance: *mut c_void, val: *mut u8);
 		pub fn cv_cuda_HostMem_getPropRefcount(instance: *mut c_void) -> *mut i32;
@@ -1900,17 +1932,17 @@ mod core_sys {
 		pub fn cv_cuda_HostMem_createMatHeader_const(instance: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_cuda_HostMem_createGpuMatHeader_const(instance: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_cuda_HostMem_isContinuous_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
-		pub fn cv_cuda_HostMem_elemSize_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_cuda_HostMem_elemSize1_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_cuda_HostMem_elemSize_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
+		pub fn cv_cuda_HostMem_elemSize1_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
 		pub fn cv_cuda_HostMem_type_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_cuda_HostMem_depth_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_cuda_HostMem_channels_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
-		pub fn cv_cuda_HostMem_step1_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_cuda_HostMem_step1_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
 		pub fn cv_cuda_HostMem_size_const(instance: *const c_void, ocvrs_return: *mut Result<core::Size>);
 		pub fn cv_cuda_HostMem_empty_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_cuda_Stream_Stream(ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_cuda_Stream_Stream_const_PtrLAllocatorGR(allocator: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_cuda_Stream_Stream_const_size_t(cuda_flags: size_t, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_cuda_Stream_Stream_const_size_t(cuda_flags: usize, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_cuda_Stream_queryIfComplete_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_cuda_Stream_waitForCompletion(instance: *mut c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_cuda_Stream_waitEvent_const_EventR(instance: *mut c_void, event: *const c_void, ocvrs_return: *mut Result_void);
@@ -1926,21 +1958,28 @@ mod core_sys {
 		pub fn cv_cuda_TargetArchs_hasEqualOrGreaterPtx_int_int(major: i32, minor: i32, ocvrs_return: *mut Result<bool>);
 		pub fn cv_cuda_TargetArchs_hasEqualOrGreaterBin_int_int(major: i32, minor: i32, ocvrs_return: *mut Result<bool>);
 		pub fn cv_detail_CheckContext_getPropFunc_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_detail_CheckContext_getPropFuncRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_detail_CheckContext_getPropFile_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_detail_CheckContext_getPropFileRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_detail_CheckContext_getPropLine_const(instance: *const c_void) -> i32;
 		pub fn cv_detail_CheckContext_setPropLine_int(instance: *mut c_void, val: i32);
 		pub fn cv_detail_CheckContext_getPropTestOp_const(instance: *const c_void, ocvrs_return: *mut core::Detail_TestOp);
 		pub fn cv_detail_CheckContext_setPropTestOp_TestOp(instance: *mut c_void, val: core::Detail_TestOp);
 		pub fn cv_detail_CheckContext_getPropMessage_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_detail_CheckContext_getPropMessageRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_detail_CheckContext_getPropP1_str_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_detail_CheckContext_getPropP1_strRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_detail_CheckContext_getPropP2_str_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_detail_CheckContext_getPropP2_strRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_instr_NodeData_getPropM_funName_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_instr_NodeData_getPropM_funNameRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_instr_NodeData_setPropM_funName_String(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_instr_NodeData_getPropM_instrType_const(instance: *const c_void, ocvrs_return: *mut core::TYPE);
 		pub fn cv_instr_NodeData_setPropM_instrType_TYPE(instance: *mut c_void, val: core::TYPE);
 		pub fn cv_instr_NodeData_getPropM_implType_const(instance: *const c_void, ocvrs_return: *mut core::IMPL);
 		pub fn cv_instr_NodeData_setPropM_implType_IMPL(instance: *mut c_void, val: core::IMPL);
 		pub fn cv_instr_NodeData_getPropM_fileName_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_instr_NodeData_getPropM_fileNameRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_instr_NodeData_getPropM_lineNum_const(instance: *const c_void) -> i32;
 		pub fn cv_instr_NodeData_setPropM_lineNum_int(instance: *mut c_void, val: i32);
 		pub fn cv_instr_NodeData_getPropM_retAddress(instance: *mut c_void) -> *mut c_void;
@@ -1966,8 +2005,8 @@ mod core_sys {
 		pub fn cv_ocl_Context_Context_ContextRR(c: *mut c_void) -> *mut c_void;
 		pub fn cv_ocl_Context_create(instance: *mut c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_ocl_Context_create_int(instance: *mut c_void, dtype: i32, ocvrs_return: *mut Result<bool>);
-		pub fn cv_ocl_Context_ndevices_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_ocl_Context_device_const_size_t(instance: *const c_void, idx: size_t, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_ocl_Context_ndevices_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
+		pub fn cv_ocl_Context_device_const_size_t(instance: *const c_void, idx: usize, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_ocl_Context_getProg_const_ProgramSourceR_const_StringR_StringR(instance: *mut c_void, prog: *const c_void, buildopt: *const c_char, errmsg: *mut *mut c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_ocl_Context_unloadProg_ProgramR(instance: *mut c_void, prog: *mut c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_ocl_Context_getDefault_bool(initialize: bool, ocvrs_return: *mut Result<*mut c_void>);
@@ -2007,11 +2046,11 @@ mod core_sys {
 		pub fn cv_ocl_Device_endianLittle_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_ocl_Device_errorCorrectionSupport_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_ocl_Device_executionCapabilities_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
-		pub fn cv_ocl_Device_globalMemCacheSize_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_ocl_Device_globalMemCacheSize_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
 		pub fn cv_ocl_Device_globalMemCacheType_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_ocl_Device_globalMemCacheLineSize_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
-		pub fn cv_ocl_Device_globalMemSize_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_ocl_Device_localMemSize_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_ocl_Device_globalMemSize_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
+		pub fn cv_ocl_Device_localMemSize_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
 		pub fn cv_ocl_Device_localMemType_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_ocl_Device_hostUnifiedMemory_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_ocl_Device_imageSupport_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
@@ -2019,13 +2058,13 @@ mod core_sys {
 		pub fn cv_ocl_Device_imagePitchAlignment_const(instance: *const c_void, ocvrs_return: *mut Result<u32>);
 		pub fn cv_ocl_Device_imageBaseAddressAlignment_const(instance: *const c_void, ocvrs_return: *mut Result<u32>);
 		pub fn cv_ocl_Device_intelSubgroupsSupport_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
-		pub fn cv_ocl_Device_image2DMaxWidth_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_ocl_Device_image2DMaxHeight_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_ocl_Device_image3DMaxWidth_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_ocl_Device_image3DMaxHeight_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_ocl_Device_image3DMaxDepth_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_ocl_Device_imageMaxBufferSize_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_ocl_Device_imageMaxArraySize_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_ocl_Device_image2DMaxWidth_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
+		pub fn cv_ocl_Device_image2DMaxHeight_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
+		pub fn cv_ocl_Device_image3DMaxWidth_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
+		pub fn cv_ocl_Device_image3DMaxHeight_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
+		pub fn cv_ocl_Device_image3DMaxDepth_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
+		pub fn cv_ocl_Device_imageMaxBufferSize_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
+		pub fn cv_ocl_Device_imageMaxArraySize_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
 		pub fn cv_ocl_Device_vendorID_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_ocl_Device_isAMD_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_ocl_Device_isIntel_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
@@ -2033,15 +2072,15 @@ mod core_sys {
 		pub fn cv_ocl_Device_maxClockFrequency_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_ocl_Device_maxComputeUnits_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_ocl_Device_maxConstantArgs_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
-		pub fn cv_ocl_Device_maxConstantBufferSize_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_ocl_Device_maxMemAllocSize_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_ocl_Device_maxParameterSize_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_ocl_Device_maxConstantBufferSize_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
+		pub fn cv_ocl_Device_maxMemAllocSize_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
+		pub fn cv_ocl_Device_maxParameterSize_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
 		pub fn cv_ocl_Device_maxReadImageArgs_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_ocl_Device_maxWriteImageArgs_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_ocl_Device_maxSamplers_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
-		pub fn cv_ocl_Device_maxWorkGroupSize_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_ocl_Device_maxWorkGroupSize_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
 		pub fn cv_ocl_Device_maxWorkItemDims_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
-		pub fn cv_ocl_Device_maxWorkItemSizes_const_size_tX(instance: *const c_void, unnamed: *mut size_t, ocvrs_return: *mut Result_void);
+		pub fn cv_ocl_Device_maxWorkItemSizes_const_size_tX(instance: *const c_void, unnamed: *mut usize, ocvrs_return: *mut Result_void);
 		pub fn cv_ocl_Device_memBaseAddrAlign_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_ocl_Device_nativeVectorWidthChar_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_ocl_Device_nativeVectorWidthShort_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
@@ -2057,8 +2096,8 @@ mod core_sys {
 		pub fn cv_ocl_Device_preferredVectorWidthFloat_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_ocl_Device_preferredVectorWidthDouble_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_ocl_Device_preferredVectorWidthHalf_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
-		pub fn cv_ocl_Device_printfBufferSize_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_ocl_Device_profilingTimerResolution_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_ocl_Device_printfBufferSize_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
+		pub fn cv_ocl_Device_profilingTimerResolution_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
 		pub fn cv_ocl_Device_getDefault(ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_ocl_Device_fromHandle_voidX(d: *mut c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_ocl_Device_empty_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
@@ -2077,33 +2116,33 @@ mod core_sys {
 		pub fn cv_ocl_Kernel_empty_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_ocl_Kernel_create_const_charX_const_ProgramR(instance: *mut c_void, kname: *const c_char, prog: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_ocl_Kernel_create_const_charX_const_ProgramSourceR_const_StringR_StringX(instance: *mut c_void, kname: *const c_char, prog: *const c_void, buildopts: *const c_char, errmsg: *mut *mut c_void, ocvrs_return: *mut Result<bool>);
-		pub fn cv_ocl_Kernel_set_int_const_voidX_size_t(instance: *mut c_void, i: i32, value: *const c_void, sz: size_t, ocvrs_return: *mut Result<i32>);
+		pub fn cv_ocl_Kernel_set_int_const_voidX_size_t(instance: *mut c_void, i: i32, value: *const c_void, sz: usize, ocvrs_return: *mut Result<i32>);
 		pub fn cv_ocl_Kernel_set_int_const_Image2DR(instance: *mut c_void, i: i32, image_2d: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_ocl_Kernel_set_int_const_UMatR(instance: *mut c_void, i: i32, m: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_ocl_Kernel_set_int_const_KernelArgR(instance: *mut c_void, i: i32, arg: *const c_void, ocvrs_return: *mut Result<i32>);
-		pub fn cv_ocl_Kernel_run_int_size_tX_size_tX_bool_const_QueueR(instance: *mut c_void, dims: i32, globalsize: *mut size_t, localsize: *mut size_t, sync: bool, q: *const c_void, ocvrs_return: *mut Result<bool>);
-		pub fn cv_ocl_Kernel_run__int_size_tX_size_tX_bool_const_QueueR(instance: *mut c_void, dims: i32, globalsize: *mut size_t, localsize: *mut size_t, sync: bool, q: *const c_void, ocvrs_return: *mut Result<bool>);
+		pub fn cv_ocl_Kernel_run_int_size_tX_size_tX_bool_const_QueueR(instance: *mut c_void, dims: i32, globalsize: *mut usize, localsize: *mut usize, sync: bool, q: *const c_void, ocvrs_return: *mut Result<bool>);
+		pub fn cv_ocl_Kernel_run__int_size_tX_size_tX_bool_const_QueueR(instance: *mut c_void, dims: i32, globalsize: *mut usize, localsize: *mut usize, sync: bool, q: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_ocl_Kernel_runTask_bool_const_QueueR(instance: *mut c_void, sync: bool, q: *const c_void, ocvrs_return: *mut Result<bool>);
-		pub fn cv_ocl_Kernel_runProfiling_int_size_tX_size_tX_const_QueueR(instance: *mut c_void, dims: i32, globalsize: *mut size_t, localsize: *mut size_t, q: *const c_void, ocvrs_return: *mut Result<i64>);
-		pub fn cv_ocl_Kernel_workGroupSize_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_ocl_Kernel_preferedWorkGroupSizeMultiple_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_ocl_Kernel_compileWorkGroupSize_const_size_tX(instance: *const c_void, wsz: *mut size_t, ocvrs_return: *mut Result<bool>);
-		pub fn cv_ocl_Kernel_localMemSize_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_ocl_Kernel_runProfiling_int_size_tX_size_tX_const_QueueR(instance: *mut c_void, dims: i32, globalsize: *mut usize, localsize: *mut usize, q: *const c_void, ocvrs_return: *mut Result<i64>);
+		pub fn cv_ocl_Kernel_workGroupSize_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
+		pub fn cv_ocl_Kernel_preferedWorkGroupSizeMultiple_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
+		pub fn cv_ocl_Kernel_compileWorkGroupSize_const_size_tX(instance: *const c_void, wsz: *mut usize, ocvrs_return: *mut Result<bool>);
+		pub fn cv_ocl_Kernel_localMemSize_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
 		pub fn cv_ocl_Kernel_ptr_const(instance: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_ocl_KernelArg_getPropFlags_const(instance: *const c_void) -> i32;
 		pub fn cv_ocl_KernelArg_setPropFlags_int(instance: *mut c_void, val: i32);
 		pub fn cv_ocl_KernelArg_getPropM(instance: *mut c_void) -> *mut c_void;
 		pub fn cv_ocl_KernelArg_setPropM_UMatX(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_ocl_KernelArg_getPropObj_const(instance: *const c_void) -> *const c_void;
-		pub fn cv_ocl_KernelArg_getPropSz_const(instance: *const c_void) -> size_t;
-		pub fn cv_ocl_KernelArg_setPropSz_size_t(instance: *mut c_void, val: size_t);
+		pub fn cv_ocl_KernelArg_getPropSz_const(instance: *const c_void) -> usize;
+		pub fn cv_ocl_KernelArg_setPropSz_size_t(instance: *mut c_void, val: usize);
 		pub fn cv_ocl_KernelArg_getPropWscale_const(instance: *const c_void) -> i32;
 		pub fn cv_ocl_KernelArg_setPropWscale_int(instance: *mut c_void, val: i32);
 		pub fn cv_ocl_KernelArg_getPropIwscale_const(instance: *const c_void) -> i32;
 		pub fn cv_ocl_KernelArg_setPropIwscale_int(instance: *mut c_void, val: i32);
-		pub fn cv_ocl_KernelArg_KernelArg_int_UMatX_int_int_const_voidX_size_t(_flags: i32, _m: *mut c_void, wscale: i32, iwscale: i32, _obj: *const c_void, _sz: size_t, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_ocl_KernelArg_KernelArg_int_UMatX_int_int_const_voidX_size_t(_flags: i32, _m: *mut c_void, wscale: i32, iwscale: i32, _obj: *const c_void, _sz: usize, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_ocl_KernelArg_KernelArg() -> *mut c_void;
-		pub fn cv_ocl_KernelArg_Local_size_t(local_mem_size: size_t, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_ocl_KernelArg_Local_size_t(local_mem_size: usize, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_ocl_KernelArg_PtrWriteOnly_const_UMatR(m: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_ocl_KernelArg_PtrReadOnly_const_UMatR(m: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_ocl_KernelArg_PtrReadWrite_const_UMatR(m: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
@@ -2118,8 +2157,11 @@ mod core_sys {
 		pub fn cv_ocl_OpenCLExecutionContext_OpenCLExecutionContext_const_OpenCLExecutionContextR(unnamed: *const c_void) -> *mut c_void;
 		pub fn cv_ocl_OpenCLExecutionContext_OpenCLExecutionContext_OpenCLExecutionContextRR(unnamed: *mut c_void) -> *mut c_void;
 		pub fn cv_ocl_OpenCLExecutionContext_getContext_const(instance: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_ocl_OpenCLExecutionContext_getContextRef_const(instance: *const c_void, ocvrs_return: *mut Result<*const c_void>);
 		pub fn cv_ocl_OpenCLExecutionContext_getDevice_const(instance: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_ocl_OpenCLExecutionContext_getDeviceRef_const(instance: *const c_void, ocvrs_return: *mut Result<*const c_void>);
 		pub fn cv_ocl_OpenCLExecutionContext_getQueue_const(instance: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_ocl_OpenCLExecutionContext_getQueueRef_const(instance: *const c_void, ocvrs_return: *mut Result<*const c_void>);
 		pub fn cv_ocl_OpenCLExecutionContext_useOpenCL_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_ocl_OpenCLExecutionContext_setUseOpenCL_bool(instance: *mut c_void, flag: bool, ocvrs_return: *mut Result_void);
 		pub fn cv_ocl_OpenCLExecutionContext_getCurrent(ocvrs_return: *mut Result<*mut c_void>);
@@ -2161,6 +2203,7 @@ mod core_sys {
 		pub fn cv_ocl_Program_read_const_StringR_const_StringR(instance: *mut c_void, buf: *const c_char, buildflags: *const c_char, ocvrs_return: *mut Result<bool>);
 		pub fn cv_ocl_Program_write_const_StringR(instance: *const c_void, buf: *mut *mut c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_ocl_Program_source_const(instance: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_ocl_Program_sourceRef_const(instance: *const c_void, ocvrs_return: *mut Result<*const c_void>);
 		pub fn cv_ocl_Program_getPrefix_const(instance: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_ocl_Program_getPrefix_const_StringR(buildflags: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_ocl_ProgramSource_ProgramSource() -> *mut c_void;
@@ -2169,9 +2212,10 @@ mod core_sys {
 		pub fn cv_ocl_ProgramSource_ProgramSource_const_ProgramSourceR(prog: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_ocl_ProgramSource_ProgramSource_ProgramSourceRR(prog: *mut c_void) -> *mut c_void;
 		pub fn cv_ocl_ProgramSource_source_const(instance: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_ocl_ProgramSource_sourceRef_const(instance: *const c_void, ocvrs_return: *mut Result<*const c_void>);
 		pub fn cv_ocl_ProgramSource_hash_const(instance: *const c_void, ocvrs_return: *mut Result<core::ProgramSource_hash_t>);
-		pub fn cv_ocl_ProgramSource_fromBinary_const_StringR_const_StringR_const_unsigned_charX_const_size_t_const_StringR(module: *const c_char, name: *const c_char, binary: *const u8, size: size_t, build_options: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_ocl_ProgramSource_fromSPIR_const_StringR_const_StringR_const_unsigned_charX_const_size_t_const_StringR(module: *const c_char, name: *const c_char, binary: *const u8, size: size_t, build_options: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_ocl_ProgramSource_fromBinary_const_StringR_const_StringR_const_unsigned_charX_const_size_t_const_StringR(module: *const c_char, name: *const c_char, binary: *const u8, size: usize, build_options: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_ocl_ProgramSource_fromSPIR_const_StringR_const_StringR_const_unsigned_charX_const_size_t_const_StringR(module: *const c_char, name: *const c_char, binary: *const u8, size: usize, build_options: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_ocl_ProgramSource_empty_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_ocl_Queue_Queue() -> *mut c_void;
 		pub fn cv_ocl_Queue_Queue_const_ContextR_const_DeviceR(c: *const c_void, d: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
@@ -2254,6 +2298,7 @@ mod core_sys {
 		pub fn cv_ogl_Texture2D_format_const(instance: *const c_void, ocvrs_return: *mut Result<core::Texture2D_Format>);
 		pub fn cv_ogl_Texture2D_texId_const(instance: *const c_void, ocvrs_return: *mut Result<u32>);
 		pub fn cv_utils_logging_LogTag_getPropName_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_utils_logging_LogTag_getPropNameRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_utils_logging_LogTag_getPropLevel_const(instance: *const c_void, ocvrs_return: *mut core::LogLevel);
 		pub fn cv_utils_logging_LogTag_setPropLevel_LogLevel(instance: *mut c_void, val: core::LogLevel);
 		pub fn cv_utils_logging_LogTag_LogTag_const_charX_LogLevel(_name: *const c_char, _level: core::LogLevel, ocvrs_return: *mut Result<*mut c_void>);
@@ -2316,7 +2361,7 @@ mod cudaarithm_sys {
 		pub fn cv_cuda_meanStdDev_const__InputArrayR_ScalarR_ScalarR_const__InputArrayR(src: *const c_void, mean: *mut core::Scalar, stddev: *mut core::Scalar, mask: *const c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_cuda_meanStdDev_const__InputArrayR_const__OutputArrayR_StreamR(mtx: *const c_void, dst: *const c_void, stream: *mut c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_cuda_meanStdDev_const__InputArrayR_const__OutputArrayR_const__InputArrayR_StreamR(src: *const c_void, dst: *const c_void, mask: *const c_void, stream: *mut c_void, ocvrs_return: *mut Result_void);
-		pub fn cv_cuda_merge_const_GpuMatX_size_t_const__OutputArrayR_StreamR(src: *const c_void, n: size_t, dst: *const c_void, stream: *mut c_void, ocvrs_return: *mut Result_void);
+		pub fn cv_cuda_merge_const_GpuMatX_size_t_const__OutputArrayR_StreamR(src: *const c_void, n: usize, dst: *const c_void, stream: *mut c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_cuda_merge_const_vectorLGpuMatGR_const__OutputArrayR_StreamR(src: *const c_void, dst: *const c_void, stream: *mut c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_cuda_minMaxLoc_const__InputArrayR_doubleX_doubleX_PointX_PointX_const__InputArrayR(src: *const c_void, min_val: *mut f64, max_val: *mut f64, min_loc: *mut core::Point, max_loc: *mut core::Point, mask: *const c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_cuda_minMax_const__InputArrayR_doubleX_doubleX_const__InputArrayR(src: *const c_void, min_val: *mut f64, max_val: *mut f64, mask: *const c_void, ocvrs_return: *mut Result_void);
@@ -2436,7 +2481,7 @@ mod cudacodec_sys {
 		pub fn cv_cudacodec_EncoderParams_load_const_StringR(instance: *mut c_void, config_file: *const c_char, ocvrs_return: *mut Result_void);
 		pub fn cv_cudacodec_EncoderParams_save_const_const_StringR(instance: *const c_void, config_file: *const c_char, ocvrs_return: *mut Result_void);
 		pub fn cv_cudacodec_FormatInfo_FormatInfo(ocvrs_return: *mut Result<crate::cudacodec::FormatInfo>);
-		pub fn cv_cudacodec_RawVideoSource_getNextPacket_unsigned_charXX_size_tX(instance: *mut c_void, data: *mut *mut u8, size: *mut size_t, ocvrs_return: *mut Result<bool>);
+		pub fn cv_cudacodec_RawVideoSource_getNextPacket_unsigned_charXX_size_tX(instance: *mut c_void, data: *mut *mut u8, size: *mut usize, ocvrs_return: *mut Result<bool>);
 		pub fn cv_cudacodec_RawVideoSource_lastPacketContainsKeyFrame_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_cudacodec_RawVideoSource_format_const(instance: *const c_void, ocvrs_return: *mut Result<crate::cudacodec::FormatInfo>);
 		pub fn cv_cudacodec_RawVideoSource_updateFormat_const_FormatInfoR(instance: *mut c_void, video_format: *const crate::cudacodec::FormatInfo, ocvrs_return: *mut Result_void);
@@ -2445,8 +2490,8 @@ mod cudacodec_sys {
 		pub fn cv_cudacodec_VideoReader_nextFrame_GpuMatR_StreamR(instance: *mut c_void, frame: *mut c_void, stream: *mut c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_cudacodec_VideoReader_format_const(instance: *const c_void, ocvrs_return: *mut Result<crate::cudacodec::FormatInfo>);
 		pub fn cv_cudacodec_VideoReader_grab_StreamR(instance: *mut c_void, stream: *mut c_void, ocvrs_return: *mut Result<bool>);
-		pub fn cv_cudacodec_VideoReader_retrieve_const_const__OutputArrayR_const_size_t(instance: *const c_void, frame: *const c_void, idx: size_t, ocvrs_return: *mut Result<bool>);
-		pub fn cv_cudacodec_VideoReader_retrieve_const_MatR_const_size_t(instance: *const c_void, frame: *mut c_void, idx: size_t, ocvrs_return: *mut Result<bool>);
+		pub fn cv_cudacodec_VideoReader_retrieve_const_const__OutputArrayR_const_size_t(instance: *const c_void, frame: *const c_void, idx: usize, ocvrs_return: *mut Result<bool>);
+		pub fn cv_cudacodec_VideoReader_retrieve_const_MatR_const_size_t(instance: *const c_void, frame: *mut c_void, idx: usize, ocvrs_return: *mut Result<bool>);
 		pub fn cv_cudacodec_VideoReader_retrieve_const_GpuMatR(instance: *const c_void, frame: *mut c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_cudacodec_VideoReader_set_const_VideoReaderProps_const_double(instance: *mut c_void, property_id: crate::cudacodec::VideoReaderProps, property_val: f64, ocvrs_return: *mut Result<bool>);
 		pub fn cv_cudacodec_VideoReader_setVideoReaderProps_const_VideoReaderProps_double(instance: *mut c_void, property_id: crate::cudacodec::VideoReaderProps, property_val: f64, ocvrs_return: *mut Result<bool>);
@@ -2680,8 +2725,8 @@ mod cudaobjdetect_sys {
 		pub fn cv_cuda_HOG_getGroupThreshold_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_cuda_HOG_setDescriptorFormat_DescriptorStorageFormat(instance: *mut c_void, descr_format: crate::objdetect::HOGDescriptor_DescriptorStorageFormat, ocvrs_return: *mut Result_void);
 		pub fn cv_cuda_HOG_getDescriptorFormat_const(instance: *const c_void, ocvrs_return: *mut Result<crate::objdetect::HOGDescriptor_DescriptorStorageFormat>);
-		pub fn cv_cuda_HOG_getDescriptorSize_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_cuda_HOG_getBlockHistogramSize_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_cuda_HOG_getDescriptorSize_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
+		pub fn cv_cuda_HOG_getBlockHistogramSize_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
 		pub fn cv_cuda_HOG_setSVMDetector_const__InputArrayR(instance: *mut c_void, detector: *const c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_cuda_HOG_getDefaultPeopleDetector_const(instance: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_cuda_HOG_detect_const__InputArrayR_vectorLPointGR_vectorLdoubleGX(instance: *mut c_void, img: *const c_void, found_locations: *mut c_void, confidences: *mut c_void, ocvrs_return: *mut Result_void);
@@ -2878,11 +2923,11 @@ mod cvv_sys {
 		pub fn cvv_impl_finalShow(ocvrs_return: *mut Result_void);
 		pub fn cvv_impl_showImage_const__InputArrayR_const_CallMetaDataR_const_charX_const_charX(img: *const c_void, data: *const c_void, description: *const c_char, view: *const c_char, ocvrs_return: *mut Result_void);
 		pub fn cvv_impl_CallMetaData_getPropFile_const(instance: *const c_void) -> *mut c_void;
-		pub fn cvv_impl_CallMetaData_getPropLine_const(instance: *const c_void) -> size_t;
+		pub fn cvv_impl_CallMetaData_getPropLine_const(instance: *const c_void) -> usize;
 		pub fn cvv_impl_CallMetaData_getPropFunction_const(instance: *const c_void) -> *mut c_void;
 		pub fn cvv_impl_CallMetaData_getPropIsKnown_const(instance: *const c_void) -> bool;
 		pub fn cvv_impl_CallMetaData_CallMetaData(ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cvv_impl_CallMetaData_CallMetaData_const_charX_size_t_const_charX(file: *const c_char, line: size_t, function: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cvv_impl_CallMetaData_CallMetaData_const_charX_size_t_const_charX(file: *const c_char, line: usize, function: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cvv_impl_CallMetaData_operator_bool(instance: *mut c_void, ocvrs_return: *mut Result<bool>);
 	}
 }
@@ -2911,19 +2956,19 @@ mod dnn_sys {
 		pub fn cv_dnn_getPlane_const_MatR_int_int(m: *const c_void, n: i32, cn: i32, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_imagesFromBlob_const_MatR_const__OutputArrayR(blob_: *const c_void, images_: *const c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_dnn_readNetFromCaffe_const_StringR_const_StringR(prototxt: *const c_char, caffe_model: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_dnn_readNetFromCaffe_const_charX_size_t_const_charX_size_t(buffer_proto: *const c_char, len_proto: size_t, buffer_model: *const c_char, len_model: size_t, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_dnn_readNetFromCaffe_const_charX_size_t_const_charX_size_t(buffer_proto: *const c_char, len_proto: usize, buffer_model: *const c_char, len_model: usize, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_readNetFromCaffe_const_vectorLunsigned_charGR_const_vectorLunsigned_charGR(buffer_proto: *const c_void, buffer_model: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_readNetFromDarknet_const_StringR_const_StringR(cfg_file: *const c_char, darknet_model: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_dnn_readNetFromDarknet_const_charX_size_t_const_charX_size_t(buffer_cfg: *const c_char, len_cfg: size_t, buffer_model: *const c_char, len_model: size_t, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_dnn_readNetFromDarknet_const_charX_size_t_const_charX_size_t(buffer_cfg: *const c_char, len_cfg: usize, buffer_model: *const c_char, len_model: usize, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_readNetFromDarknet_const_vectorLunsigned_charGR_const_vectorLunsigned_charGR(buffer_cfg: *const c_void, buffer_model: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_readNetFromModelOptimizer_const_StringR_const_StringR(xml: *const c_char, bin: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_dnn_readNetFromModelOptimizer_const_unsigned_charX_size_t_const_unsigned_charX_size_t(buffer_model_config_ptr: *const u8, buffer_model_config_size: size_t, buffer_weights_ptr: *const u8, buffer_weights_size: size_t, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_dnn_readNetFromModelOptimizer_const_unsigned_charX_size_t_const_unsigned_charX_size_t(buffer_model_config_ptr: *const u8, buffer_model_config_size: usize, buffer_weights_ptr: *const u8, buffer_weights_size: usize, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_readNetFromModelOptimizer_const_vectorLunsigned_charGR_const_vectorLunsigned_charGR(buffer_model_config: *const c_void, buffer_weights: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_readNetFromONNX_const_StringR(onnx_file: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_dnn_readNetFromONNX_const_charX_size_t(buffer: *const c_char, size_buffer: size_t, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_dnn_readNetFromONNX_const_charX_size_t(buffer: *const c_char, size_buffer: usize, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_readNetFromONNX_const_vectorLunsigned_charGR(buffer: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_readNetFromTensorflow_const_StringR_const_StringR(model: *const c_char, config: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_dnn_readNetFromTensorflow_const_charX_size_t_const_charX_size_t(buffer_model: *const c_char, len_model: size_t, buffer_config: *const c_char, len_config: size_t, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_dnn_readNetFromTensorflow_const_charX_size_t_const_charX_size_t(buffer_model: *const c_char, len_model: usize, buffer_config: *const c_char, len_config: usize, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_readNetFromTensorflow_const_vectorLunsigned_charGR_const_vectorLunsigned_charGR(buffer_model: *const c_void, buffer_config: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_readNetFromTorch_const_StringR_bool_bool(model: *const c_char, is_binary: bool, evaluate: bool, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_readNet_const_StringR_const_StringR_const_StringR(model: *const c_char, config: *const c_char, framework: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
@@ -2943,15 +2988,15 @@ mod dnn_sys {
 		pub fn cv_dnn_slice_const_MatR_const__RangeR_const__RangeR(m: *const c_void, r0: *const c_void, r1: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_slice_const_MatR_const__RangeR_const__RangeR_const__RangeR(m: *const c_void, r0: *const c_void, r1: *const c_void, r2: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_slice_const_MatR_const__RangeR_const__RangeR_const__RangeR_const__RangeR(m: *const c_void, r0: *const c_void, r1: *const c_void, r2: *const c_void, r3: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_dnn_softNMSBoxes_const_vectorLRectGR_const_vectorLfloatGR_vectorLfloatGR_const_float_const_float_vectorLintGR_size_t_const_float_SoftNMSMethod(bboxes: *const c_void, scores: *const c_void, updated_scores: *mut c_void, score_threshold: f32, nms_threshold: f32, indices: *mut c_void, top_k: size_t, sigma: f32, method: crate::dnn::SoftNMSMethod, ocvrs_return: *mut Result_void);
+		pub fn cv_dnn_softNMSBoxes_const_vectorLRectGR_const_vectorLfloatGR_vectorLfloatGR_const_float_const_float_vectorLintGR_size_t_const_float_SoftNMSMethod(bboxes: *const c_void, scores: *const c_void, updated_scores: *mut c_void, score_threshold: f32, nms_threshold: f32, indices: *mut c_void, top_k: usize, sigma: f32, method: crate::dnn::SoftNMSMethod, ocvrs_return: *mut Result_void);
 		pub fn cv_dnn_total_const_MatShapeR_int_int(shape: *const c_void, start: i32, end: i32, ocvrs_return: *mut Result<i32>);
 		pub fn cv_dnn_writeTextGraph_const_StringR_const_StringR(model: *const c_char, output: *const c_char, ocvrs_return: *mut Result_void);
 		pub fn cv_dnn_AbsLayer_create_const_LayerParamsR(params: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_AccumLayer_create_const_LayerParamsR(params: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_AcosLayer_create_const_LayerParamsR(params: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_AcoshLayer_create_const_LayerParamsR(params: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_dnn_ActivationLayer_forwardSlice_const_const_floatX_floatX_int_size_t_int_int(instance: *const c_void, src: *const f32, dst: *mut f32, len: i32, out_plane_size: size_t, cn0: i32, cn1: i32, ocvrs_return: *mut Result_void);
-		pub fn cv_dnn_ActivationLayer_forwardSlice_const_const_intX_const_intX_intX_int_size_t_int_int(instance: *const c_void, src: *const i32, lut: *const i32, dst: *mut i32, len: i32, out_plane_size: size_t, cn0: i32, cn1: i32, ocvrs_return: *mut Result_void);
+		pub fn cv_dnn_ActivationLayer_forwardSlice_const_const_floatX_floatX_int_size_t_int_int(instance: *const c_void, src: *const f32, dst: *mut f32, len: i32, out_plane_size: usize, cn0: i32, cn1: i32, ocvrs_return: *mut Result_void);
+		pub fn cv_dnn_ActivationLayer_forwardSlice_const_const_intX_const_intX_intX_int_size_t_int_int(instance: *const c_void, src: *const i32, lut: *const i32, dst: *mut i32, len: i32, out_plane_size: usize, cn0: i32, cn1: i32, ocvrs_return: *mut Result_void);
 		pub fn cv_dnn_ActivationLayerInt8_create_const_LayerParamsR(params: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_ArgLayer_create_const_LayerParamsR(params: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_AsinLayer_create_const_LayerParamsR(params: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
@@ -2978,18 +3023,25 @@ mod dnn_sys {
 		pub fn cv_dnn_BaseConvolutionLayer_getPropAdjustPad_const(instance: *const c_void, ocvrs_return: *mut core::Size);
 		pub fn cv_dnn_BaseConvolutionLayer_setPropAdjustPad_Size(instance: *mut c_void, val: *const core::Size);
 		pub fn cv_dnn_BaseConvolutionLayer_getPropAdjust_pads_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_dnn_BaseConvolutionLayer_getPropAdjust_padsRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_dnn_BaseConvolutionLayer_setPropAdjust_pads_vectorLsize_tG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_dnn_BaseConvolutionLayer_getPropKernel_size_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_dnn_BaseConvolutionLayer_getPropKernel_sizeRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_dnn_BaseConvolutionLayer_setPropKernel_size_vectorLsize_tG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_dnn_BaseConvolutionLayer_getPropStrides_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_dnn_BaseConvolutionLayer_getPropStridesRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_dnn_BaseConvolutionLayer_setPropStrides_vectorLsize_tG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_dnn_BaseConvolutionLayer_getPropDilations_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_dnn_BaseConvolutionLayer_getPropDilationsRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_dnn_BaseConvolutionLayer_setPropDilations_vectorLsize_tG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_dnn_BaseConvolutionLayer_getPropPads_begin_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_dnn_BaseConvolutionLayer_getPropPads_beginRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_dnn_BaseConvolutionLayer_setPropPads_begin_vectorLsize_tG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_dnn_BaseConvolutionLayer_getPropPads_end_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_dnn_BaseConvolutionLayer_getPropPads_endRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_dnn_BaseConvolutionLayer_setPropPads_end_vectorLsize_tG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_dnn_BaseConvolutionLayer_getPropPadMode_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_dnn_BaseConvolutionLayer_getPropPadModeRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_dnn_BaseConvolutionLayer_setPropPadMode_String(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_dnn_BaseConvolutionLayer_getPropNumOutput_const(instance: *const c_void) -> i32;
 		pub fn cv_dnn_BaseConvolutionLayer_setPropNumOutput_int(instance: *mut c_void, val: i32);
@@ -3069,6 +3121,7 @@ mod dnn_sys {
 		pub fn cv_dnn_Dict_ptr_const_StringR(instance: *mut c_void, key: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_Dict_ptr_const_const_StringR(instance: *const c_void, key: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_Dict_get_const_const_StringR(instance: *const c_void, key: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_dnn_Dict_getRef_const_const_StringR(instance: *const c_void, key: *const c_char, ocvrs_return: *mut Result<*const c_void>);
 		pub fn cv_dnn_Dict_set_cv_String_const_StringR_const_StringR(instance: *mut c_void, key: *const c_char, value: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_Dict_set_cv_dnn_DictValue_const_StringR_const_DictValueR(instance: *mut c_void, key: *const c_char, value: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_Dict_set_double_const_StringR_const_doubleR(instance: *mut c_void, key: *const c_char, value: *const f64, ocvrs_return: *mut Result<f64>);
@@ -3152,10 +3205,13 @@ mod dnn_sys {
 		pub fn cv_dnn_LSTMLayer_inputNameToIndex_String(instance: *mut c_void, input_name: *mut c_char, ocvrs_return: *mut Result<i32>);
 		pub fn cv_dnn_LSTMLayer_outputNameToIndex_const_StringR(instance: *mut c_void, output_name: *const c_char, ocvrs_return: *mut Result<i32>);
 		pub fn cv_dnn_Layer_getPropBlobs_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_dnn_Layer_getPropBlobsRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_dnn_Layer_setPropBlobs_vectorLMatG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_dnn_Layer_getPropName_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_dnn_Layer_getPropNameRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_dnn_Layer_setPropName_String(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_dnn_Layer_getPropType_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_dnn_Layer_getPropTypeRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_dnn_Layer_setPropType_String(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_dnn_Layer_getPropPreferableTarget_const(instance: *const c_void) -> i32;
 		pub fn cv_dnn_Layer_setPropPreferableTarget_int(instance: *mut c_void, val: i32);
@@ -3194,10 +3250,13 @@ mod dnn_sys {
 		pub fn cv_dnn_LayerFactory_isLayerRegistered_const_stringR(typ: *const c_char, ocvrs_return: *mut Result<bool>);
 		pub fn cv_dnn_LayerFactory_createLayerInstance_const_StringR_LayerParamsR(typ: *const c_char, params: *mut c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_LayerParams_getPropBlobs_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_dnn_LayerParams_getPropBlobsRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_dnn_LayerParams_setPropBlobs_vectorLMatG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_dnn_LayerParams_getPropName_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_dnn_LayerParams_getPropNameRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_dnn_LayerParams_setPropName_String(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_dnn_LayerParams_getPropType_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_dnn_LayerParams_getPropTypeRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_dnn_LayerParams_setPropType_String(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_dnn_LogLayer_create_const_LayerParamsR(params: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_MVNLayer_getPropEps_const(instance: *const c_void) -> f32;
@@ -3235,7 +3294,7 @@ mod dnn_sys {
 		pub fn cv_dnn_Net_Net(ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_Net_readFromModelOptimizer_const_StringR_const_StringR(xml: *const c_char, bin: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_Net_readFromModelOptimizer_const_vectorLunsigned_charGR_const_vectorLunsigned_charGR(buffer_model_config: *const c_void, buffer_weights: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_dnn_Net_readFromModelOptimizer_const_unsigned_charX_size_t_const_unsigned_charX_size_t(buffer_model_config_ptr: *const u8, buffer_model_config_size: size_t, buffer_weights_ptr: *const u8, buffer_weights_size: size_t, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_dnn_Net_readFromModelOptimizer_const_unsigned_charX_size_t_const_unsigned_charX_size_t(buffer_model_config_ptr: *const u8, buffer_model_config_size: usize, buffer_weights_ptr: *const u8, buffer_weights_size: usize, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_Net_empty_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_dnn_Net_dump(instance: *mut c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_Net_dumpToFile_const_StringR(instance: *mut c_void, path: *const c_char, ocvrs_return: *mut Result_void);
@@ -3282,10 +3341,10 @@ mod dnn_sys {
 		pub fn cv_dnn_Net_getFLOPS_const_const_int_const_MatShapeR(instance: *const c_void, layer_id: i32, net_input_shape: *const c_void, ocvrs_return: *mut Result<i64>);
 		pub fn cv_dnn_Net_getLayerTypes_const_vectorLStringGR(instance: *const c_void, layers_types: *mut c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_dnn_Net_getLayersCount_const_const_StringR(instance: *const c_void, layer_type: *const c_char, ocvrs_return: *mut Result<i32>);
-		pub fn cv_dnn_Net_getMemoryConsumption_const_const_vectorLMatShapeGR_size_tR_size_tR(instance: *const c_void, net_input_shapes: *const c_void, weights: *mut size_t, blobs: *mut size_t, ocvrs_return: *mut Result_void);
-		pub fn cv_dnn_Net_getMemoryConsumption_const_const_MatShapeR_size_tR_size_tR(instance: *const c_void, net_input_shape: *const c_void, weights: *mut size_t, blobs: *mut size_t, ocvrs_return: *mut Result_void);
-		pub fn cv_dnn_Net_getMemoryConsumption_const_const_int_const_vectorLMatShapeGR_size_tR_size_tR(instance: *const c_void, layer_id: i32, net_input_shapes: *const c_void, weights: *mut size_t, blobs: *mut size_t, ocvrs_return: *mut Result_void);
-		pub fn cv_dnn_Net_getMemoryConsumption_const_const_int_const_MatShapeR_size_tR_size_tR(instance: *const c_void, layer_id: i32, net_input_shape: *const c_void, weights: *mut size_t, blobs: *mut size_t, ocvrs_return: *mut Result_void);
+		pub fn cv_dnn_Net_getMemoryConsumption_const_const_vectorLMatShapeGR_size_tR_size_tR(instance: *const c_void, net_input_shapes: *const c_void, weights: *mut usize, blobs: *mut usize, ocvrs_return: *mut Result_void);
+		pub fn cv_dnn_Net_getMemoryConsumption_const_const_MatShapeR_size_tR_size_tR(instance: *const c_void, net_input_shape: *const c_void, weights: *mut usize, blobs: *mut usize, ocvrs_return: *mut Result_void);
+		pub fn cv_dnn_Net_getMemoryConsumption_const_const_int_const_vectorLMatShapeGR_size_tR_size_tR(instance: *const c_void, layer_id: i32, net_input_shapes: *const c_void, weights: *mut usize, blobs: *mut usize, ocvrs_return: *mut Result_void);
+		pub fn cv_dnn_Net_getMemoryConsumption_const_const_int_const_MatShapeR_size_tR_size_tR(instance: *const c_void, layer_id: i32, net_input_shape: *const c_void, weights: *mut usize, blobs: *mut usize, ocvrs_return: *mut Result_void);
 		pub fn cv_dnn_Net_getMemoryConsumption_const_const_vectorLMatShapeGR_vectorLintGR_vectorLsize_tGR_vectorLsize_tGR(instance: *const c_void, net_input_shapes: *const c_void, layer_ids: *mut c_void, weights: *mut c_void, blobs: *mut c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_dnn_Net_getMemoryConsumption_const_const_MatShapeR_vectorLintGR_vectorLsize_tGR_vectorLsize_tGR(instance: *const c_void, net_input_shape: *const c_void, layer_ids: *mut c_void, weights: *mut c_void, blobs: *mut c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_dnn_Net_enableFusion_bool(instance: *mut c_void, fusion: bool, ocvrs_return: *mut Result_void);
@@ -3303,20 +3362,26 @@ mod dnn_sys {
 		pub fn cv_dnn_PoolingLayer_getPropType_const(instance: *const c_void) -> i32;
 		pub fn cv_dnn_PoolingLayer_setPropType_int(instance: *mut c_void, val: i32);
 		pub fn cv_dnn_PoolingLayer_getPropKernel_size_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_dnn_PoolingLayer_getPropKernel_sizeRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_dnn_PoolingLayer_setPropKernel_size_vectorLsize_tG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_dnn_PoolingLayer_getPropStrides_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_dnn_PoolingLayer_getPropStridesRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_dnn_PoolingLayer_setPropStrides_vectorLsize_tG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_dnn_PoolingLayer_getPropPads_begin_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_dnn_PoolingLayer_getPropPads_beginRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_dnn_PoolingLayer_setPropPads_begin_vectorLsize_tG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_dnn_PoolingLayer_getPropPads_end_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_dnn_PoolingLayer_getPropPads_endRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_dnn_PoolingLayer_setPropPads_end_vectorLsize_tG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_dnn_PoolingLayer_getPropGlobalPooling_const(instance: *const c_void) -> bool;
 		pub fn cv_dnn_PoolingLayer_setPropGlobalPooling_bool(instance: *mut c_void, val: bool);
 		pub fn cv_dnn_PoolingLayer_getPropIsGlobalPooling_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_dnn_PoolingLayer_getPropIsGlobalPoolingRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_dnn_PoolingLayer_setPropIsGlobalPooling_vectorLboolG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_dnn_PoolingLayer_getPropComputeMaxIdx_const(instance: *const c_void) -> bool;
 		pub fn cv_dnn_PoolingLayer_setPropComputeMaxIdx_bool(instance: *mut c_void, val: bool);
 		pub fn cv_dnn_PoolingLayer_getPropPadMode_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_dnn_PoolingLayer_getPropPadModeRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_dnn_PoolingLayer_setPropPadMode_String(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_dnn_PoolingLayer_getPropCeilMode_const(instance: *const c_void) -> bool;
 		pub fn cv_dnn_PoolingLayer_setPropCeilMode_bool(instance: *mut c_void, val: bool);
@@ -3367,6 +3432,7 @@ mod dnn_sys {
 		pub fn cv_dnn_ReduceLayer_getPropReduceType_const(instance: *const c_void) -> i32;
 		pub fn cv_dnn_ReduceLayer_setPropReduceType_int(instance: *mut c_void, val: i32);
 		pub fn cv_dnn_ReduceLayer_getPropReduceDims_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_dnn_ReduceLayer_getPropReduceDimsRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_dnn_ReduceLayer_setPropReduceDims_vectorLsize_tG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_dnn_ReduceLayer_create_const_LayerParamsR(params: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_ReduceLayerInt8_create_const_LayerParamsR(params: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
@@ -3380,8 +3446,10 @@ mod dnn_sys {
 		pub fn cv_dnn_RequantizeLayer_setPropShift_float(instance: *mut c_void, val: f32);
 		pub fn cv_dnn_RequantizeLayer_create_const_LayerParamsR(params: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_ReshapeLayer_getPropNewShapeDesc_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_dnn_ReshapeLayer_getPropNewShapeDescRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_dnn_ReshapeLayer_setPropNewShapeDesc_MatShape(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_dnn_ReshapeLayer_getPropNewShapeRange_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_dnn_ReshapeLayer_getPropNewShapeRangeRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_dnn_ReshapeLayer_setPropNewShapeRange_Range(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_dnn_ReshapeLayer_create_const_LayerParamsR(params: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_ResizeLayer_create_const_LayerParamsR(params: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
@@ -3391,6 +3459,7 @@ mod dnn_sys {
 		pub fn cv_dnn_ScaleLayer_getPropAxis_const(instance: *const c_void) -> i32;
 		pub fn cv_dnn_ScaleLayer_setPropAxis_int(instance: *mut c_void, val: i32);
 		pub fn cv_dnn_ScaleLayer_getPropMode_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_dnn_ScaleLayer_getPropModeRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_dnn_ScaleLayer_setPropMode_String(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_dnn_ScaleLayer_create_const_LayerParamsR(params: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_ScaleLayerInt8_getPropOutput_sc_const(instance: *const c_void) -> f32;
@@ -3421,8 +3490,10 @@ mod dnn_sys {
 		pub fn cv_dnn_SinLayer_create_const_LayerParamsR(params: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_SinhLayer_create_const_LayerParamsR(params: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dnn_SliceLayer_getPropSliceRanges_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_dnn_SliceLayer_getPropSliceRangesRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_dnn_SliceLayer_setPropSliceRanges_vectorLvectorLRangeGG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_dnn_SliceLayer_getPropSliceSteps_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_dnn_SliceLayer_getPropSliceStepsRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_dnn_SliceLayer_setPropSliceSteps_vectorLvectorLintGG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_dnn_SliceLayer_getPropAxis_const(instance: *const c_void) -> i32;
 		pub fn cv_dnn_SliceLayer_setPropAxis_int(instance: *mut c_void, val: i32);
@@ -3518,7 +3589,7 @@ mod dpm_sys {
 		pub fn cv_dpm_DPMDetector_isEmpty_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_dpm_DPMDetector_detect_MatR_vectorLObjectDetectionGR(instance: *mut c_void, image: *mut c_void, objects: *mut c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_dpm_DPMDetector_getClassNames_const(instance: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_dpm_DPMDetector_getClassCount_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_dpm_DPMDetector_getClassCount_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
 		pub fn cv_dpm_DPMDetector_create_const_vectorLstringGR_const_vectorLstringGR(filenames: *const c_void, class_names: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_dpm_DPMDetector_ObjectDetection_getPropRect_const(instance: *const c_void, ocvrs_return: *mut core::Rect);
 		pub fn cv_dpm_DPMDetector_ObjectDetection_setPropRect_Rect(instance: *mut c_void, val: *const core::Rect);
@@ -3566,6 +3637,7 @@ mod face_sys {
 		pub fn cv_face_BasicFaceRecognizer_write_const_FileStorageR(instance: *const c_void, fs: *mut c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_face_BasicFaceRecognizer_empty_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_face_CParams_getPropCascade_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_face_CParams_getPropCascadeRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_face_CParams_setPropCascade_String(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_face_CParams_getPropScaleFactor_const(instance: *const c_void) -> f64;
 		pub fn cv_face_CParams_setPropScaleFactor_double(instance: *mut c_void, val: f64);
@@ -3576,6 +3648,7 @@ mod face_sys {
 		pub fn cv_face_CParams_getPropMaxSize_const(instance: *const c_void, ocvrs_return: *mut core::Size);
 		pub fn cv_face_CParams_setPropMaxSize_Size(instance: *mut c_void, val: *const core::Size);
 		pub fn cv_face_CParams_getPropFace_cascade_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_face_CParams_getPropFace_cascadeRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_face_CParams_setPropFace_cascade_CascadeClassifier(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_face_CParams_CParams_String_double_int_Size_Size(cascade_model: *mut c_char, sf: f64, min_n: i32, min_sz: *const core::Size, max_sz: *const core::Size, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_face_EigenFaceRecognizer_create_int_double(num_components: i32, threshold: f64, ocvrs_return: *mut Result<*mut c_void>);
@@ -3599,6 +3672,7 @@ mod face_sys {
 		pub fn cv_face_FacemarkAAM_fitConfig_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const_vectorLConfigGR(instance: *mut c_void, image: *const c_void, roi: *const c_void, _landmarks: *const c_void, runtime_params: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_face_FacemarkAAM_create_const_ParamsR(parameters: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_face_FacemarkAAM_Config_getPropR_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_face_FacemarkAAM_Config_getPropRRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_face_FacemarkAAM_Config_setPropR_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_face_FacemarkAAM_Config_getPropT_const(instance: *const c_void, ocvrs_return: *mut core::Point2f);
 		pub fn cv_face_FacemarkAAM_Config_setPropT_Point2f(instance: *mut c_void, val: *const core::Point2f);
@@ -3608,40 +3682,56 @@ mod face_sys {
 		pub fn cv_face_FacemarkAAM_Config_setPropModel_scale_idx_int(instance: *mut c_void, val: i32);
 		pub fn cv_face_FacemarkAAM_Config_Config_Mat_Point2f_float_int(rot: *mut c_void, trans: *const core::Point2f, scaling: f32, scale_id: i32, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_face_FacemarkAAM_Data_getPropS0_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_face_FacemarkAAM_Data_getPropS0Ref_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_face_FacemarkAAM_Data_setPropS0_vectorLPoint2fG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_face_FacemarkAAM_Model_getPropScales_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_face_FacemarkAAM_Model_getPropScalesRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_face_FacemarkAAM_Model_setPropScales_vectorLfloatG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_face_FacemarkAAM_Model_getPropTriangles_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_face_FacemarkAAM_Model_getPropTrianglesRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_face_FacemarkAAM_Model_setPropTriangles_vectorLVec3iG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_face_FacemarkAAM_Model_getPropTextures_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_face_FacemarkAAM_Model_getPropTexturesRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_face_FacemarkAAM_Model_setPropTextures_vectorLTextureG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_face_FacemarkAAM_Model_getPropS0_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_face_FacemarkAAM_Model_getPropS0Ref_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_face_FacemarkAAM_Model_setPropS0_vectorLPoint2fG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_face_FacemarkAAM_Model_getPropS_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_face_FacemarkAAM_Model_getPropSRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_face_FacemarkAAM_Model_setPropS_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_face_FacemarkAAM_Model_getPropQ_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_face_FacemarkAAM_Model_getPropQRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_face_FacemarkAAM_Model_setPropQ_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_face_FacemarkAAM_Model_Texture_getPropMax_m_const(instance: *const c_void) -> i32;
 		pub fn cv_face_FacemarkAAM_Model_Texture_setPropMax_m_int(instance: *mut c_void, val: i32);
 		pub fn cv_face_FacemarkAAM_Model_Texture_getPropResolution_const(instance: *const c_void, ocvrs_return: *mut core::Rect);
 		pub fn cv_face_FacemarkAAM_Model_Texture_setPropResolution_Rect(instance: *mut c_void, val: *const core::Rect);
 		pub fn cv_face_FacemarkAAM_Model_Texture_getPropA_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_face_FacemarkAAM_Model_Texture_getPropARef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_face_FacemarkAAM_Model_Texture_setPropA_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_face_FacemarkAAM_Model_Texture_getPropA0_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_face_FacemarkAAM_Model_Texture_getPropA0Ref_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_face_FacemarkAAM_Model_Texture_setPropA0_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_face_FacemarkAAM_Model_Texture_getPropAA_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_face_FacemarkAAM_Model_Texture_getPropAARef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_face_FacemarkAAM_Model_Texture_setPropAA_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_face_FacemarkAAM_Model_Texture_getPropAA0_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_face_FacemarkAAM_Model_Texture_getPropAA0Ref_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_face_FacemarkAAM_Model_Texture_setPropAA0_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_face_FacemarkAAM_Model_Texture_getPropTextureIdx_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_face_FacemarkAAM_Model_Texture_getPropTextureIdxRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_face_FacemarkAAM_Model_Texture_setPropTextureIdx_vectorLvectorLPointGG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_face_FacemarkAAM_Model_Texture_getPropBase_shape_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_face_FacemarkAAM_Model_Texture_getPropBase_shapeRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_face_FacemarkAAM_Model_Texture_setPropBase_shape_vectorLPoint2fG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_face_FacemarkAAM_Model_Texture_getPropInd1_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_face_FacemarkAAM_Model_Texture_getPropInd1Ref_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_face_FacemarkAAM_Model_Texture_setPropInd1_vectorLintG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_face_FacemarkAAM_Model_Texture_getPropInd2_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_face_FacemarkAAM_Model_Texture_getPropInd2Ref_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_face_FacemarkAAM_Model_Texture_setPropInd2_vectorLintG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_face_FacemarkAAM_Params_getPropModel_filename_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_face_FacemarkAAM_Params_getPropModel_filenameRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_face_FacemarkAAM_Params_setPropModel_filename_string(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_face_FacemarkAAM_Params_getPropM_const(instance: *const c_void) -> i32;
 		pub fn cv_face_FacemarkAAM_Params_setPropM_int(instance: *mut c_void, val: i32);
@@ -3660,6 +3750,7 @@ mod face_sys {
 		pub fn cv_face_FacemarkAAM_Params_getPropTexture_max_m_const(instance: *const c_void) -> i32;
 		pub fn cv_face_FacemarkAAM_Params_setPropTexture_max_m_int(instance: *mut c_void, val: i32);
 		pub fn cv_face_FacemarkAAM_Params_getPropScales_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_face_FacemarkAAM_Params_getPropScalesRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_face_FacemarkAAM_Params_setPropScales_vectorLfloatG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_face_FacemarkAAM_Params_Params(ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_face_FacemarkAAM_Params_read_const_FileNodeR(instance: *mut c_void, unnamed: *const c_void, ocvrs_return: *mut Result_void);
@@ -3685,12 +3776,14 @@ mod face_sys {
 		pub fn cv_face_FacemarkKazemi_Params_getPropNum_test_splits_const(instance: *const c_void) -> u32;
 		pub fn cv_face_FacemarkKazemi_Params_setPropNum_test_splits_unsigned_long(instance: *mut c_void, val: u32);
 		pub fn cv_face_FacemarkKazemi_Params_getPropConfigfile_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_face_FacemarkKazemi_Params_getPropConfigfileRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_face_FacemarkKazemi_Params_setPropConfigfile_String(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_face_FacemarkKazemi_Params_Params(ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_face_FacemarkLBF_create_const_ParamsR(parameters: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_face_FacemarkLBF_Params_getPropShape_offset_const(instance: *const c_void) -> f64;
 		pub fn cv_face_FacemarkLBF_Params_setPropShape_offset_double(instance: *mut c_void, val: f64);
 		pub fn cv_face_FacemarkLBF_Params_getPropCascade_face_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_face_FacemarkLBF_Params_getPropCascade_faceRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_face_FacemarkLBF_Params_setPropCascade_face_String(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_face_FacemarkLBF_Params_getPropVerbose_const(instance: *const c_void) -> bool;
 		pub fn cv_face_FacemarkLBF_Params_setPropVerbose_bool(instance: *mut c_void, val: bool);
@@ -3707,14 +3800,17 @@ mod face_sys {
 		pub fn cv_face_FacemarkLBF_Params_getPropBagging_overlap_const(instance: *const c_void) -> f64;
 		pub fn cv_face_FacemarkLBF_Params_setPropBagging_overlap_double(instance: *mut c_void, val: f64);
 		pub fn cv_face_FacemarkLBF_Params_getPropModel_filename_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_face_FacemarkLBF_Params_getPropModel_filenameRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_face_FacemarkLBF_Params_setPropModel_filename_string(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_face_FacemarkLBF_Params_getPropSave_model_const(instance: *const c_void) -> bool;
 		pub fn cv_face_FacemarkLBF_Params_setPropSave_model_bool(instance: *mut c_void, val: bool);
 		pub fn cv_face_FacemarkLBF_Params_getPropSeed_const(instance: *const c_void) -> u32;
 		pub fn cv_face_FacemarkLBF_Params_setPropSeed_unsigned_int(instance: *mut c_void, val: u32);
 		pub fn cv_face_FacemarkLBF_Params_getPropFeats_m_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_face_FacemarkLBF_Params_getPropFeats_mRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_face_FacemarkLBF_Params_setPropFeats_m_vectorLintG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_face_FacemarkLBF_Params_getPropRadius_m_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_face_FacemarkLBF_Params_getPropRadius_mRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_face_FacemarkLBF_Params_setPropRadius_m_vectorLdoubleG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_face_FacemarkLBF_Params_getPropDetectROI_const(instance: *const c_void, ocvrs_return: *mut core::Rect);
 		pub fn cv_face_FacemarkLBF_Params_setPropDetectROI_Rect(instance: *mut c_void, val: *const core::Rect);
@@ -3745,10 +3841,10 @@ mod face_sys {
 		pub fn cv_face_MACE_same_const_const__InputArrayR(instance: *const c_void, query: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_face_MACE_load_const_StringR_const_StringR(filename: *const c_char, objname: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_face_MACE_create_int(imgsize: i32, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_face_PredictCollector_init_size_t(instance: *mut c_void, size: size_t, ocvrs_return: *mut Result_void);
+		pub fn cv_face_PredictCollector_init_size_t(instance: *mut c_void, size: usize, ocvrs_return: *mut Result_void);
 		pub fn cv_face_PredictCollector_collect_int_double(instance: *mut c_void, label: i32, dist: f64, ocvrs_return: *mut Result<bool>);
 		pub fn cv_face_StandardCollector_StandardCollector_double(threshold_: f64, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_face_StandardCollector_init_size_t(instance: *mut c_void, size: size_t, ocvrs_return: *mut Result_void);
+		pub fn cv_face_StandardCollector_init_size_t(instance: *mut c_void, size: usize, ocvrs_return: *mut Result_void);
 		pub fn cv_face_StandardCollector_collect_int_double(instance: *mut c_void, label: i32, dist: f64, ocvrs_return: *mut Result<bool>);
 		pub fn cv_face_StandardCollector_getMinLabel_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_face_StandardCollector_getMinDist_const(instance: *const c_void, ocvrs_return: *mut Result<f64>);
@@ -4244,6 +4340,7 @@ mod gapi_sys {
 		pub fn cv_GCall_params(instance: *mut c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_GCall_setArgs_vectorLGArgGRR(instance: *mut c_void, args: *mut c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_GCompileArg_getPropTag_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_GCompileArg_getPropTagRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_GCompileArg_setPropTag_string(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_GCompileArg_GCompileArg() -> *mut c_void;
 		pub fn cv_GCompiled_GCompiled(ocvrs_return: *mut Result<*mut c_void>);
@@ -4271,12 +4368,16 @@ mod gapi_sys {
 		pub fn cv_GFrameDesc_setPropSize_Size(instance: *mut c_void, val: *const core::Size);
 		pub fn cv_GFrameDesc_operatorEQ_const_const_GFrameDescR(instance: *const c_void, unnamed: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_GKernel_getPropName_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_GKernel_getPropNameRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_GKernel_setPropName_string(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_GKernel_getPropTag_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_GKernel_getPropTagRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_GKernel_setPropTag_string(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_GKernel_getPropOutShapes_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_GKernel_getPropOutShapesRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_GKernel_setPropOutShapes_GShapes(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_GKernel_getPropInKinds_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_GKernel_getPropInKindsRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_GKernel_setPropInKinds_GKinds(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_GKernelImpl_getPropOpaque_const(instance: *const c_void) -> *mut c_void;
 		pub fn cv_GKernelImpl_setPropOpaque_any(instance: *mut c_void, val: *mut c_void);
@@ -4298,6 +4399,7 @@ mod gapi_sys {
 		pub fn cv_GMatDesc_getPropPlanar_const(instance: *const c_void) -> bool;
 		pub fn cv_GMatDesc_setPropPlanar_bool(instance: *mut c_void, val: bool);
 		pub fn cv_GMatDesc_getPropDims_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_GMatDesc_getPropDimsRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_GMatDesc_setPropDims_vectorLintG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_GMatDesc_GMatDesc_int_int_Size_bool(d: i32, c: i32, s: *const core::Size, p: bool, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_GMatDesc_GMatDesc_int_const_vectorLintGR(d: i32, dd: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
@@ -4334,6 +4436,7 @@ mod gapi_sys {
 		pub fn cv_GStreamingCompiled_running_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_GStreamingCompiled_operator_bool_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_GTransform_getPropDescription_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_GTransform_getPropDescriptionRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_GTransform_setPropDescription_string(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_MediaFrame_MediaFrame(ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_MediaFrame_desc_const(instance: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
@@ -4353,8 +4456,8 @@ mod gapi_sys {
 		pub fn cv_RMat_View_type_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_RMat_View_depth_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_RMat_View_chan_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
-		pub fn cv_RMat_View_elemSize_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_RMat_View_step_const_size_t(instance: *const c_void, i: size_t, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_RMat_View_elemSize_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
+		pub fn cv_RMat_View_step_const_size_t(instance: *const c_void, i: usize, ocvrs_return: *mut Result<usize>);
 		pub fn cv_RMat_View_steps_const(instance: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_gapi_GBackend_GBackend(ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_gapi_GBackend_operatorEQ_const_const_GBackendR(instance: *const c_void, rhs: *const c_void, ocvrs_return: *mut Result<bool>);
@@ -4368,16 +4471,19 @@ mod gapi_sys {
 		pub fn cv_gapi_own_Scalar_operator___const_int(instance: *const c_void, i: i32, ocvrs_return: *mut Result<f64>);
 		pub fn cv_gapi_own_Scalar_operator___int(instance: *mut c_void, i: i32, ocvrs_return: *mut Result<f64>);
 		pub fn cv_gapi_own_Scalar_all_double(v0: f64, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_gapi_streaming_queue_capacity_queue_capacity_size_t(cap: size_t, ocvrs_return: *mut Result<crate::gapi::queue_capacity>);
+		pub fn cv_gapi_streaming_queue_capacity_queue_capacity_size_t(cap: usize, ocvrs_return: *mut Result<crate::gapi::queue_capacity>);
 		pub fn cv_gapi_use_only_getPropPkg_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_gapi_use_only_getPropPkgRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_gapi_use_only_setPropPkg_GKernelPackage(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_gapi_wip_draw_Circle_Circle_const_PointR_int_const_ScalarR_int_int_int(center_: *const core::Point, radius_: i32, color_: *const core::Scalar, thick_: i32, lt_: i32, shift_: i32, ocvrs_return: *mut Result<crate::gapi::Circle>);
 		pub fn cv_gapi_wip_draw_Circle_Circle(ocvrs_return: *mut crate::gapi::Circle);
 		pub fn cv_gapi_wip_draw_Image_getPropOrg_const(instance: *const c_void, ocvrs_return: *mut core::Point);
 		pub fn cv_gapi_wip_draw_Image_setPropOrg_Point(instance: *mut c_void, val: *const core::Point);
 		pub fn cv_gapi_wip_draw_Image_getPropImg_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_gapi_wip_draw_Image_getPropImgRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_gapi_wip_draw_Image_setPropImg_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_gapi_wip_draw_Image_getPropAlpha_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_gapi_wip_draw_Image_getPropAlphaRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_gapi_wip_draw_Image_setPropAlpha_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_gapi_wip_draw_Image_Image_const_PointR_const_MatR_const_MatR(org_: *const core::Point, img_: *const c_void, alpha_: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_gapi_wip_draw_Image_Image() -> *mut c_void;
@@ -4386,6 +4492,7 @@ mod gapi_sys {
 		pub fn cv_gapi_wip_draw_Mosaic_Mosaic_const_RectR_int_int(mos_: *const core::Rect, cell_sz_: i32, decim_: i32, ocvrs_return: *mut Result<crate::gapi::Mosaic>);
 		pub fn cv_gapi_wip_draw_Mosaic_Mosaic(ocvrs_return: *mut Result<crate::gapi::Mosaic>);
 		pub fn cv_gapi_wip_draw_Poly_getPropPoints_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_gapi_wip_draw_Poly_getPropPointsRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_gapi_wip_draw_Poly_setPropPoints_vectorLPointG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_gapi_wip_draw_Poly_getPropColor_const(instance: *const c_void, ocvrs_return: *mut core::Scalar);
 		pub fn cv_gapi_wip_draw_Poly_setPropColor_Scalar(instance: *mut c_void, val: *const core::Scalar);
@@ -4400,6 +4507,7 @@ mod gapi_sys {
 		pub fn cv_gapi_wip_draw_Rect_Rect_const_RectR_const_ScalarR_int_int_int(rect_: *const core::Rect, color_: *const core::Scalar, thick_: i32, lt_: i32, shift_: i32, ocvrs_return: *mut Result<crate::gapi::Rect>);
 		pub fn cv_gapi_wip_draw_Rect_Rect(ocvrs_return: *mut crate::gapi::Rect);
 		pub fn cv_gapi_wip_draw_Text_getPropText_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_gapi_wip_draw_Text_getPropTextRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_gapi_wip_draw_Text_setPropText_string(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_gapi_wip_draw_Text_getPropOrg_const(instance: *const c_void, ocvrs_return: *mut core::Point);
 		pub fn cv_gapi_wip_draw_Text_setPropOrg_Point(instance: *mut c_void, val: *const core::Point);
@@ -4548,6 +4656,7 @@ mod highgui_sys {
 		pub fn cv_waitKeyEx_int(delay: i32, ocvrs_return: *mut Result<i32>);
 		pub fn cv_waitKey_int(delay: i32, ocvrs_return: *mut Result<i32>);
 		pub fn cv_QtFont_getPropNameFont_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_QtFont_getPropNameFontRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_QtFont_getPropColor_const(instance: *const c_void, ocvrs_return: *mut core::Scalar);
 		pub fn cv_QtFont_setPropColor_Scalar(instance: *mut c_void, val: *const core::Scalar);
 		pub fn cv_QtFont_getPropFont_face_const(instance: *const c_void) -> i32;
@@ -4616,7 +4725,7 @@ mod imgcodecs_sys {
 	extern "C" {
 		pub fn cv_haveImageReader_const_StringR(filename: *const c_char, ocvrs_return: *mut Result<bool>);
 		pub fn cv_haveImageWriter_const_StringR(filename: *const c_char, ocvrs_return: *mut Result<bool>);
-		pub fn cv_imcount_const_StringR_int(filename: *const c_char, flags: i32, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_imcount_const_StringR_int(filename: *const c_char, flags: i32, ocvrs_return: *mut Result<usize>);
 		pub fn cv_imdecode_const__InputArrayR_int(buf: *const c_void, flags: i32, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_imdecode_const__InputArrayR_int_MatX(buf: *const c_void, flags: i32, dst: *mut c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_imencode_const_StringR_const__InputArrayR_vectorLunsigned_charGR_const_vectorLintGR(ext: *const c_char, img: *const c_void, buf: *mut c_void, params: *const c_void, ocvrs_return: *mut Result<bool>);
@@ -4918,11 +5027,11 @@ mod line_descriptor_sys {
 		pub fn cv_line_descriptor_BinaryDescriptor_BinaryDescriptor_const_ParamsR(parameters: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_line_descriptor_BinaryDescriptor_createBinaryDescriptor(ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_line_descriptor_BinaryDescriptor_createBinaryDescriptor_Params(parameters: *mut c_void, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_line_descriptor_BinaryDescriptor_getNumOfOctaves(instance: *mut c_void, ocvrs_return: *mut Result<i32>);
+		pub fn cv_line_descriptor_BinaryDescriptor_getNumOfOctaves_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_line_descriptor_BinaryDescriptor_setNumOfOctaves_int(instance: *mut c_void, octaves: i32, ocvrs_return: *mut Result_void);
-		pub fn cv_line_descriptor_BinaryDescriptor_getWidthOfBand(instance: *mut c_void, ocvrs_return: *mut Result<i32>);
+		pub fn cv_line_descriptor_BinaryDescriptor_getWidthOfBand_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_line_descriptor_BinaryDescriptor_setWidthOfBand_int(instance: *mut c_void, width: i32, ocvrs_return: *mut Result_void);
-		pub fn cv_line_descriptor_BinaryDescriptor_getReductionRatio(instance: *mut c_void, ocvrs_return: *mut Result<i32>);
+		pub fn cv_line_descriptor_BinaryDescriptor_getReductionRatio_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_line_descriptor_BinaryDescriptor_setReductionRatio_int(instance: *mut c_void, r_ratio: i32, ocvrs_return: *mut Result_void);
 		pub fn cv_line_descriptor_BinaryDescriptor_read_const_FileNodeR(instance: *mut c_void, fn_: *const c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_line_descriptor_BinaryDescriptor_write_const_FileStorageR(instance: *const c_void, fs: *mut c_void, ocvrs_return: *mut Result_void);
@@ -5410,8 +5519,10 @@ mod objdetect_sys {
 		pub fn cv_DetectionROI_getPropScale_const(instance: *const c_void) -> f64;
 		pub fn cv_DetectionROI_setPropScale_double(instance: *mut c_void, val: f64);
 		pub fn cv_DetectionROI_getPropLocations_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_DetectionROI_getPropLocationsRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_DetectionROI_setPropLocations_vectorLPointG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_DetectionROI_getPropConfidences_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_DetectionROI_getPropConfidencesRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_DetectionROI_setPropConfidences_vectorLdoubleG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_FaceDetectorYN_setInputSize_const_SizeR(instance: *mut c_void, input_size: *const core::Size, ocvrs_return: *mut Result_void);
 		pub fn cv_FaceDetectorYN_getInputSize(instance: *mut c_void, ocvrs_return: *mut Result<core::Size>);
@@ -5448,8 +5559,10 @@ mod objdetect_sys {
 		pub fn cv_HOGDescriptor_getPropGammaCorrection_const(instance: *const c_void) -> bool;
 		pub fn cv_HOGDescriptor_setPropGammaCorrection_bool(instance: *mut c_void, val: bool);
 		pub fn cv_HOGDescriptor_getPropSvmDetector_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_HOGDescriptor_getPropSvmDetectorRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_HOGDescriptor_setPropSvmDetector_vectorLfloatG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_HOGDescriptor_getPropOclSvmDetector_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_HOGDescriptor_getPropOclSvmDetectorRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_HOGDescriptor_setPropOclSvmDetector_UMat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_HOGDescriptor_getPropFree_coef_const(instance: *const c_void) -> f32;
 		pub fn cv_HOGDescriptor_setPropFree_coef_float(instance: *mut c_void, val: f32);
@@ -5461,7 +5574,7 @@ mod objdetect_sys {
 		pub fn cv_HOGDescriptor_HOGDescriptor_Size_Size_Size_Size_int_int_double_HistogramNormType_double_bool_int_bool(_win_size: *const core::Size, _block_size: *const core::Size, _block_stride: *const core::Size, _cell_size: *const core::Size, _nbins: i32, _deriv_aperture: i32, _win_sigma: f64, _histogram_norm_type: crate::objdetect::HOGDescriptor_HistogramNormType, _l2_hys_threshold: f64, _gamma_correction: bool, _nlevels: i32, _signed_gradient: bool, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_HOGDescriptor_HOGDescriptor_const_StringR(filename: *const c_char, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_HOGDescriptor_HOGDescriptor_const_HOGDescriptorR(d: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_HOGDescriptor_getDescriptorSize_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_HOGDescriptor_getDescriptorSize_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
 		pub fn cv_HOGDescriptor_checkDetectorSize_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_HOGDescriptor_getWinSigma_const(instance: *const c_void, ocvrs_return: *mut Result<f64>);
 		pub fn cv_HOGDescriptor_setSVMDetector_const__InputArrayR(instance: *mut c_void, svmdetector: *const c_void, ocvrs_return: *mut Result_void);
@@ -5581,7 +5694,7 @@ mod optflow_sys {
 		pub fn cv_optflow_DualTVL1OpticalFlow_create_double_double_double_int_int_double_int_int_double_double_int_bool(tau: f64, lambda: f64, theta: f64, nscales: i32, warps: i32, epsilon: f64, innner_iterations: i32, outer_iterations: i32, scale_step: f64, gamma: f64, median_filtering: i32, use_initial_flow: bool, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_optflow_GPCDetails_dropOutliers_vectorLpairLcv_Point2i__cv_Point2iGGR(corr: *mut c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_optflow_GPCDetails_getAllDescriptorsForImage_const_MatX_vectorLGPCPatchDescriptorGR_const_GPCMatchingParamsR_int(img_ch: *const c_void, descr: *mut c_void, mp: *const crate::optflow::GPCMatchingParams, typ: i32, ocvrs_return: *mut Result_void);
-		pub fn cv_optflow_GPCDetails_getCoordinatesFromIndex_size_t_Size_intR_intR(index: size_t, sz: *const core::Size, x: *mut i32, y: *mut i32, ocvrs_return: *mut Result_void);
+		pub fn cv_optflow_GPCDetails_getCoordinatesFromIndex_size_t_Size_intR_intR(index: usize, sz: *const core::Size, x: *mut i32, y: *mut i32, ocvrs_return: *mut Result_void);
 		pub fn cv_optflow_GPCMatchingParams_GPCMatchingParams_bool(_use_opencl: bool, ocvrs_return: *mut Result<crate::optflow::GPCMatchingParams>);
 		pub fn cv_optflow_GPCMatchingParams_GPCMatchingParams_const_GPCMatchingParamsR(params: *const crate::optflow::GPCMatchingParams, ocvrs_return: *mut Result<crate::optflow::GPCMatchingParams>);
 		pub fn cv_optflow_GPCPatchDescriptor_getPropFeature_const(instance: *const c_void, ocvrs_return: *mut core::VecN<f64, 18>);
@@ -5590,17 +5703,20 @@ mod optflow_sys {
 		pub fn cv_optflow_GPCPatchDescriptor_markAsSeparated(instance: *mut c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_optflow_GPCPatchDescriptor_isSeparated_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_optflow_GPCPatchSample_getPropRef_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_optflow_GPCPatchSample_getPropRefRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_optflow_GPCPatchSample_setPropRef_GPCPatchDescriptor(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_optflow_GPCPatchSample_getPropPos_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_optflow_GPCPatchSample_getPropPosRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_optflow_GPCPatchSample_setPropPos_GPCPatchDescriptor(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_optflow_GPCPatchSample_getPropNeg_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_optflow_GPCPatchSample_getPropNegRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_optflow_GPCPatchSample_setPropNeg_GPCPatchDescriptor(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_optflow_GPCPatchSample_getDirections_const_boolR_boolR_boolR_const_VecLdouble__18GR_double(instance: *const c_void, refdir: *mut bool, posdir: *mut bool, negdir: *mut bool, coef: *const core::VecN<f64, 18>, rhs: f64, ocvrs_return: *mut Result_void);
 		pub fn cv_optflow_GPCTrainingParams_GPCTrainingParams_unsigned_int_int_GPCDescType_bool(_max_tree_depth: u32, _min_number_of_samples: i32, _descriptor_type: crate::optflow::GPCDescType, _print_progress: bool, ocvrs_return: *mut Result<crate::optflow::GPCTrainingParams>);
 		pub fn cv_optflow_GPCTrainingParams_check_const(instance: *const crate::optflow::GPCTrainingParams, ocvrs_return: *mut Result<bool>);
 		pub fn cv_optflow_GPCTrainingSamples_create_const_vectorLStringGR_const_vectorLStringGR_const_vectorLStringGR_int(images_from: *const c_void, images_to: *const c_void, gt: *const c_void, descriptor_type: i32, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_optflow_GPCTrainingSamples_create_const__InputArrayR_const__InputArrayR_const__InputArrayR_int(images_from: *const c_void, images_to: *const c_void, gt: *const c_void, descriptor_type: i32, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_optflow_GPCTrainingSamples_size_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_optflow_GPCTrainingSamples_size_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
 		pub fn cv_optflow_GPCTrainingSamples_type_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_optflow_GPCTree_train_GPCTrainingSamplesR_const_GPCTrainingParams(instance: *mut c_void, samples: *mut c_void, params: *const crate::optflow::GPCTrainingParams, ocvrs_return: *mut Result_void);
 		pub fn cv_optflow_GPCTree_write_const_FileStorageR(instance: *const c_void, fs: *mut c_void, ocvrs_return: *mut Result_void);
@@ -6008,6 +6124,7 @@ mod rgbd_sys {
 		pub fn cv_colored_kinfu_Params_getPropIcpAngleThresh_const(instance: *const c_void) -> f32;
 		pub fn cv_colored_kinfu_Params_setPropIcpAngleThresh_float(instance: *mut c_void, val: f32);
 		pub fn cv_colored_kinfu_Params_getPropIcpIterations_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_colored_kinfu_Params_getPropIcpIterationsRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_colored_kinfu_Params_setPropIcpIterations_vectorLintG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_colored_kinfu_Params_getPropTruncateThreshold_const(instance: *const c_void) -> f32;
 		pub fn cv_colored_kinfu_Params_setPropTruncateThreshold_float(instance: *mut c_void, val: f32);
@@ -6091,6 +6208,7 @@ mod rgbd_sys {
 		pub fn cv_kinfu_Params_getPropIcpAngleThresh_const(instance: *const c_void) -> f32;
 		pub fn cv_kinfu_Params_setPropIcpAngleThresh_float(instance: *mut c_void, val: f32);
 		pub fn cv_kinfu_Params_getPropIcpIterations_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_kinfu_Params_getPropIcpIterationsRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_kinfu_Params_setPropIcpIterations_vectorLintG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_kinfu_Params_getPropTruncateThreshold_const(instance: *const c_void) -> f32;
 		pub fn cv_kinfu_Params_setPropTruncateThreshold_float(instance: *mut c_void, val: f32);
@@ -6136,17 +6254,17 @@ mod rgbd_sys {
 		pub fn cv_kinfu_VolumeParams_defaultParams_VolumeType(_volume_type: crate::rgbd::Kinfu_VolumeType, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_kinfu_VolumeParams_coarseParams_VolumeType(_volume_type: crate::rgbd::Kinfu_VolumeType, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_kinfu_detail_PoseGraph_create(ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_kinfu_detail_PoseGraph_addNode_size_t_const_Affine3dR_bool(instance: *mut c_void, _node_id: size_t, _pose: *const core::Affine3d, fixed: bool, ocvrs_return: *mut Result_void);
-		pub fn cv_kinfu_detail_PoseGraph_isNodeExist_const_size_t(instance: *const c_void, node_id: size_t, ocvrs_return: *mut Result<bool>);
-		pub fn cv_kinfu_detail_PoseGraph_setNodeFixed_size_t_bool(instance: *mut c_void, node_id: size_t, fixed: bool, ocvrs_return: *mut Result<bool>);
-		pub fn cv_kinfu_detail_PoseGraph_isNodeFixed_const_size_t(instance: *const c_void, node_id: size_t, ocvrs_return: *mut Result<bool>);
-		pub fn cv_kinfu_detail_PoseGraph_getNodePose_const_size_t(instance: *const c_void, node_id: size_t, ocvrs_return: *mut Result<core::Affine3d>);
+		pub fn cv_kinfu_detail_PoseGraph_addNode_size_t_const_Affine3dR_bool(instance: *mut c_void, _node_id: usize, _pose: *const core::Affine3d, fixed: bool, ocvrs_return: *mut Result_void);
+		pub fn cv_kinfu_detail_PoseGraph_isNodeExist_const_size_t(instance: *const c_void, node_id: usize, ocvrs_return: *mut Result<bool>);
+		pub fn cv_kinfu_detail_PoseGraph_setNodeFixed_size_t_bool(instance: *mut c_void, node_id: usize, fixed: bool, ocvrs_return: *mut Result<bool>);
+		pub fn cv_kinfu_detail_PoseGraph_isNodeFixed_const_size_t(instance: *const c_void, node_id: usize, ocvrs_return: *mut Result<bool>);
+		pub fn cv_kinfu_detail_PoseGraph_getNodePose_const_size_t(instance: *const c_void, node_id: usize, ocvrs_return: *mut Result<core::Affine3d>);
 		pub fn cv_kinfu_detail_PoseGraph_getNodesIds_const(instance: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_kinfu_detail_PoseGraph_getNumNodes_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_kinfu_detail_PoseGraph_addEdge_size_t_size_t_const_Affine3fR_const_Matx66fR(instance: *mut c_void, _source_node_id: size_t, _target_node_id: size_t, _transformation: *const core::Affine3f, _information: *const core::Matx66f, ocvrs_return: *mut Result_void);
-		pub fn cv_kinfu_detail_PoseGraph_getEdgeStart_const_size_t(instance: *const c_void, i: size_t, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_kinfu_detail_PoseGraph_getEdgeEnd_const_size_t(instance: *const c_void, i: size_t, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_kinfu_detail_PoseGraph_getNumEdges_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
+		pub fn cv_kinfu_detail_PoseGraph_getNumNodes_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
+		pub fn cv_kinfu_detail_PoseGraph_addEdge_size_t_size_t_const_Affine3fR_const_Matx66fR(instance: *mut c_void, _source_node_id: usize, _target_node_id: usize, _transformation: *const core::Affine3f, _information: *const core::Matx66f, ocvrs_return: *mut Result_void);
+		pub fn cv_kinfu_detail_PoseGraph_getEdgeStart_const_size_t(instance: *const c_void, i: usize, ocvrs_return: *mut Result<usize>);
+		pub fn cv_kinfu_detail_PoseGraph_getEdgeEnd_const_size_t(instance: *const c_void, i: usize, ocvrs_return: *mut Result<usize>);
+		pub fn cv_kinfu_detail_PoseGraph_getNumEdges_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
 		pub fn cv_kinfu_detail_PoseGraph_isValid_const(instance: *const c_void, ocvrs_return: *mut Result<bool>);
 		pub fn cv_kinfu_detail_PoseGraph_optimize_const_TermCriteriaR(instance: *mut c_void, tc: *const core::TermCriteria, ocvrs_return: *mut Result<i32>);
 		pub fn cv_kinfu_detail_PoseGraph_calcEnergy_const(instance: *const c_void, ocvrs_return: *mut Result<f64>);
@@ -6185,23 +6303,25 @@ mod rgbd_sys {
 		pub fn cv_large_kinfu_Params_getPropIcpAngleThresh_const(instance: *const c_void) -> f32;
 		pub fn cv_large_kinfu_Params_setPropIcpAngleThresh_float(instance: *mut c_void, val: f32);
 		pub fn cv_large_kinfu_Params_getPropIcpIterations_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_large_kinfu_Params_getPropIcpIterationsRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_large_kinfu_Params_setPropIcpIterations_vectorLintG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_large_kinfu_Params_getPropTruncateThreshold_const(instance: *const c_void) -> f32;
 		pub fn cv_large_kinfu_Params_setPropTruncateThreshold_float(instance: *mut c_void, val: f32);
 		pub fn cv_large_kinfu_Params_getPropVolumeParams_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_large_kinfu_Params_getPropVolumeParamsRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_large_kinfu_Params_setPropVolumeParams_VolumeParams(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_large_kinfu_Params_defaultParams(ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_large_kinfu_Params_coarseParams(ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_large_kinfu_Params_hashTSDFParams_bool(is_coarse: bool, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_linemod_ColorGradient_getPropWeak_threshold_const(instance: *const c_void) -> f32;
 		pub fn cv_linemod_ColorGradient_setPropWeak_threshold_float(instance: *mut c_void, val: f32);
-		pub fn cv_linemod_ColorGradient_getPropNum_features_const(instance: *const c_void) -> size_t;
-		pub fn cv_linemod_ColorGradient_setPropNum_features_size_t(instance: *mut c_void, val: size_t);
+		pub fn cv_linemod_ColorGradient_getPropNum_features_const(instance: *const c_void) -> usize;
+		pub fn cv_linemod_ColorGradient_setPropNum_features_size_t(instance: *mut c_void, val: usize);
 		pub fn cv_linemod_ColorGradient_getPropStrong_threshold_const(instance: *const c_void) -> f32;
 		pub fn cv_linemod_ColorGradient_setPropStrong_threshold_float(instance: *mut c_void, val: f32);
 		pub fn cv_linemod_ColorGradient_ColorGradient(ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_linemod_ColorGradient_ColorGradient_float_size_t_float(weak_threshold: f32, num_features: size_t, strong_threshold: f32, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_linemod_ColorGradient_create_float_size_t_float(weak_threshold: f32, num_features: size_t, strong_threshold: f32, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_linemod_ColorGradient_ColorGradient_float_size_t_float(weak_threshold: f32, num_features: usize, strong_threshold: f32, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_linemod_ColorGradient_create_float_size_t_float(weak_threshold: f32, num_features: usize, strong_threshold: f32, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_linemod_ColorGradient_name_const(instance: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_linemod_ColorGradient_read_const_FileNodeR(instance: *mut c_void, fn_: *const c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_linemod_ColorGradient_write_const_FileStorageR(instance: *const c_void, fs: *mut c_void, ocvrs_return: *mut Result_void);
@@ -6209,13 +6329,13 @@ mod rgbd_sys {
 		pub fn cv_linemod_DepthNormal_setPropDistance_threshold_int(instance: *mut c_void, val: i32);
 		pub fn cv_linemod_DepthNormal_getPropDifference_threshold_const(instance: *const c_void) -> i32;
 		pub fn cv_linemod_DepthNormal_setPropDifference_threshold_int(instance: *mut c_void, val: i32);
-		pub fn cv_linemod_DepthNormal_getPropNum_features_const(instance: *const c_void) -> size_t;
-		pub fn cv_linemod_DepthNormal_setPropNum_features_size_t(instance: *mut c_void, val: size_t);
+		pub fn cv_linemod_DepthNormal_getPropNum_features_const(instance: *const c_void) -> usize;
+		pub fn cv_linemod_DepthNormal_setPropNum_features_size_t(instance: *mut c_void, val: usize);
 		pub fn cv_linemod_DepthNormal_getPropExtract_threshold_const(instance: *const c_void) -> i32;
 		pub fn cv_linemod_DepthNormal_setPropExtract_threshold_int(instance: *mut c_void, val: i32);
 		pub fn cv_linemod_DepthNormal_DepthNormal(ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_linemod_DepthNormal_DepthNormal_int_int_size_t_int(distance_threshold: i32, difference_threshold: i32, num_features: size_t, extract_threshold: i32, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_linemod_DepthNormal_create_int_int_size_t_int(distance_threshold: i32, difference_threshold: i32, num_features: size_t, extract_threshold: i32, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_linemod_DepthNormal_DepthNormal_int_int_size_t_int(distance_threshold: i32, difference_threshold: i32, num_features: usize, extract_threshold: i32, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_linemod_DepthNormal_create_int_int_size_t_int(distance_threshold: i32, difference_threshold: i32, num_features: usize, extract_threshold: i32, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_linemod_DepthNormal_name_const(instance: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_linemod_DepthNormal_read_const_FileNodeR(instance: *mut c_void, fn_: *const c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_linemod_DepthNormal_write_const_FileStorageR(instance: *const c_void, fs: *mut c_void, ocvrs_return: *mut Result_void);
@@ -6249,6 +6369,7 @@ mod rgbd_sys {
 		pub fn cv_linemod_Match_getPropSimilarity_const(instance: *const c_void) -> f32;
 		pub fn cv_linemod_Match_setPropSimilarity_float(instance: *mut c_void, val: f32);
 		pub fn cv_linemod_Match_getPropClass_id_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_linemod_Match_getPropClass_idRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_linemod_Match_setPropClass_id_String(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_linemod_Match_getPropTemplate_id_const(instance: *const c_void) -> i32;
 		pub fn cv_linemod_Match_setPropTemplate_id_int(instance: *mut c_void, val: i32);
@@ -6272,6 +6393,7 @@ mod rgbd_sys {
 		pub fn cv_linemod_Template_getPropPyramid_level_const(instance: *const c_void) -> i32;
 		pub fn cv_linemod_Template_setPropPyramid_level_int(instance: *mut c_void, val: i32);
 		pub fn cv_linemod_Template_getPropFeatures_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_linemod_Template_getPropFeaturesRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_linemod_Template_setPropFeatures_vectorLFeatureG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_linemod_Template_read_const_FileNodeR(instance: *mut c_void, fn_: *const c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_linemod_Template_write_const_FileStorageR(instance: *const c_void, fs: *mut c_void, ocvrs_return: *mut Result_void);
@@ -6343,22 +6465,31 @@ mod rgbd_sys {
 		pub fn cv_rgbd_Odometry_getTransformType_const(instance: *const c_void, ocvrs_return: *mut Result<i32>);
 		pub fn cv_rgbd_Odometry_setTransformType_int(instance: *mut c_void, val: i32, ocvrs_return: *mut Result_void);
 		pub fn cv_rgbd_OdometryFrame_getPropPyramidImage_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_rgbd_OdometryFrame_getPropPyramidImageRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_rgbd_OdometryFrame_setPropPyramidImage_vectorLMatG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_rgbd_OdometryFrame_getPropPyramidDepth_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_rgbd_OdometryFrame_getPropPyramidDepthRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_rgbd_OdometryFrame_setPropPyramidDepth_vectorLMatG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_rgbd_OdometryFrame_getPropPyramidMask_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_rgbd_OdometryFrame_getPropPyramidMaskRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_rgbd_OdometryFrame_setPropPyramidMask_vectorLMatG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_rgbd_OdometryFrame_getPropPyramidCloud_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_rgbd_OdometryFrame_getPropPyramidCloudRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_rgbd_OdometryFrame_setPropPyramidCloud_vectorLMatG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_rgbd_OdometryFrame_getPropPyramid_dI_dx_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_rgbd_OdometryFrame_getPropPyramid_dI_dxRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_rgbd_OdometryFrame_setPropPyramid_dI_dx_vectorLMatG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_rgbd_OdometryFrame_getPropPyramid_dI_dy_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_rgbd_OdometryFrame_getPropPyramid_dI_dyRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_rgbd_OdometryFrame_setPropPyramid_dI_dy_vectorLMatG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_rgbd_OdometryFrame_getPropPyramidTexturedMask_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_rgbd_OdometryFrame_getPropPyramidTexturedMaskRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_rgbd_OdometryFrame_setPropPyramidTexturedMask_vectorLMatG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_rgbd_OdometryFrame_getPropPyramidNormals_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_rgbd_OdometryFrame_getPropPyramidNormalsRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_rgbd_OdometryFrame_setPropPyramidNormals_vectorLMatG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_rgbd_OdometryFrame_getPropPyramidNormalsMask_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_rgbd_OdometryFrame_getPropPyramidNormalsMaskRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_rgbd_OdometryFrame_setPropPyramidNormalsMask_vectorLMatG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_rgbd_OdometryFrame_OdometryFrame(ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_rgbd_OdometryFrame_OdometryFrame_const_MatR_const_MatR_const_MatR_const_MatR_int(image: *const c_void, depth: *const c_void, mask: *const c_void, normals: *const c_void, id: i32, ocvrs_return: *mut Result<*mut c_void>);
@@ -6368,12 +6499,16 @@ mod rgbd_sys {
 		pub fn cv_rgbd_RgbdFrame_getPropID_const(instance: *const c_void) -> i32;
 		pub fn cv_rgbd_RgbdFrame_setPropID_int(instance: *mut c_void, val: i32);
 		pub fn cv_rgbd_RgbdFrame_getPropImage_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_rgbd_RgbdFrame_getPropImageRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_rgbd_RgbdFrame_setPropImage_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_rgbd_RgbdFrame_getPropDepth_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_rgbd_RgbdFrame_getPropDepthRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_rgbd_RgbdFrame_setPropDepth_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_rgbd_RgbdFrame_getPropMask_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_rgbd_RgbdFrame_getPropMaskRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_rgbd_RgbdFrame_setPropMask_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_rgbd_RgbdFrame_getPropNormals_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_rgbd_RgbdFrame_getPropNormalsRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_rgbd_RgbdFrame_setPropNormals_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_rgbd_RgbdFrame_RgbdFrame(ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_rgbd_RgbdFrame_RgbdFrame_const_MatR_const_MatR_const_MatR_const_MatR_int(image: *const c_void, depth: *const c_void, mask: *const c_void, normals: *const c_void, id: i32, ocvrs_return: *mut Result<*mut c_void>);
@@ -6836,8 +6971,10 @@ mod stitching_sys {
 		pub fn cv_detail_CameraParams_getPropPpy_const(instance: *const c_void) -> f64;
 		pub fn cv_detail_CameraParams_setPropPpy_double(instance: *mut c_void, val: f64);
 		pub fn cv_detail_CameraParams_getPropR_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_detail_CameraParams_getPropRRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_detail_CameraParams_setPropR_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_detail_CameraParams_getPropT_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_detail_CameraParams_getPropTRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_detail_CameraParams_setPropT_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_detail_CameraParams_CameraParams(ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_detail_CameraParams_CameraParams_const_CameraParamsR(other: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
@@ -6880,8 +7017,10 @@ mod stitching_sys {
 		pub fn cv_detail_CylindricalWarperGpu_buildMaps_Size_const__InputArrayR_const__InputArrayR_GpuMatR_GpuMatR(instance: *mut c_void, src_size: *const core::Size, k: *const c_void, r: *const c_void, xmap: *mut c_void, ymap: *mut c_void, ocvrs_return: *mut Result<core::Rect>);
 		pub fn cv_detail_CylindricalWarperGpu_warp_const_GpuMatR_const__InputArrayR_const__InputArrayR_int_int_GpuMatR(instance: *mut c_void, src: *const c_void, k: *const c_void, r: *const c_void, interp_mode: i32, border_mode: i32, dst: *mut c_void, ocvrs_return: *mut Result<core::Point>);
 		pub fn cv_detail_DisjointSets_getPropParent_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_detail_DisjointSets_getPropParentRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_detail_DisjointSets_setPropParent_vectorLintG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_detail_DisjointSets_getPropSize_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_detail_DisjointSets_getPropSizeRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_detail_DisjointSets_setPropSize_vectorLintG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_detail_DisjointSets_DisjointSets_int(elem_count: i32, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_detail_DisjointSets_createOneElemSets_int(instance: *mut c_void, elem_count: i32, ocvrs_return: *mut Result_void);
@@ -6935,7 +7074,7 @@ mod stitching_sys {
 		pub fn cv_detail_GraphCutSeamFinder_find_const_vectorLUMatGR_const_vectorLPointGR_vectorLUMatGR(instance: *mut c_void, src: *const c_void, corners: *const c_void, masks: *mut c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_detail_GraphCutSeamFinderGpu_GraphCutSeamFinderGpu_int_float_float(cost_type: i32, terminal_cost: f32, bad_region_penalty: f32, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_detail_GraphCutSeamFinderGpu_find_const_vectorLUMatGR_const_vectorLPointGR_vectorLUMatGR(instance: *mut c_void, src: *const c_void, corners: *const c_void, masks: *mut c_void, ocvrs_return: *mut Result_void);
-		pub fn cv_detail_GraphCutSeamFinderGpu_findInPair_size_t_size_t_Rect(instance: *mut c_void, first: size_t, second: size_t, roi: *const core::Rect, ocvrs_return: *mut Result_void);
+		pub fn cv_detail_GraphCutSeamFinderGpu_findInPair_size_t_size_t_Rect(instance: *mut c_void, first: usize, second: usize, roi: *const core::Rect, ocvrs_return: *mut Result_void);
 		pub fn cv_detail_GraphEdge_getPropFrom_const(instance: *const c_void) -> i32;
 		pub fn cv_detail_GraphEdge_setPropFrom_int(instance: *mut c_void, val: i32);
 		pub fn cv_detail_GraphEdge_getPropTo_const(instance: *const c_void) -> i32;
@@ -6951,8 +7090,10 @@ mod stitching_sys {
 		pub fn cv_detail_ImageFeatures_getPropImg_size_const(instance: *const c_void, ocvrs_return: *mut core::Size);
 		pub fn cv_detail_ImageFeatures_setPropImg_size_Size(instance: *mut c_void, val: *const core::Size);
 		pub fn cv_detail_ImageFeatures_getPropKeypoints_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_detail_ImageFeatures_getPropKeypointsRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_detail_ImageFeatures_setPropKeypoints_vectorLKeyPointG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_detail_ImageFeatures_getPropDescriptors_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_detail_ImageFeatures_getPropDescriptorsRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_detail_ImageFeatures_setPropDescriptors_UMat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_detail_ImageFeatures_getKeypoints(instance: *mut c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_detail_MatchesInfo_getPropSrc_img_idx_const(instance: *const c_void) -> i32;
@@ -6960,12 +7101,15 @@ mod stitching_sys {
 		pub fn cv_detail_MatchesInfo_getPropDst_img_idx_const(instance: *const c_void) -> i32;
 		pub fn cv_detail_MatchesInfo_setPropDst_img_idx_int(instance: *mut c_void, val: i32);
 		pub fn cv_detail_MatchesInfo_getPropMatches_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_detail_MatchesInfo_getPropMatchesRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_detail_MatchesInfo_setPropMatches_vectorLDMatchG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_detail_MatchesInfo_getPropInliers_mask_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_detail_MatchesInfo_getPropInliers_maskRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_detail_MatchesInfo_setPropInliers_mask_vectorLunsigned_charG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_detail_MatchesInfo_getPropNum_inliers_const(instance: *const c_void) -> i32;
 		pub fn cv_detail_MatchesInfo_setPropNum_inliers_int(instance: *mut c_void, val: i32);
 		pub fn cv_detail_MatchesInfo_getPropH_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_detail_MatchesInfo_getPropHRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_detail_MatchesInfo_setPropH_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_detail_MatchesInfo_getPropConfidence_const(instance: *const c_void) -> f64;
 		pub fn cv_detail_MatchesInfo_setPropConfidence_double(instance: *mut c_void, val: f64);
@@ -7079,9 +7223,9 @@ mod structured_light_sys {
 	extern "C" {
 		pub fn cv_structured_light_GrayCodePattern_create_const_ParamsR(parameters: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_structured_light_GrayCodePattern_create_int_int(width: i32, height: i32, ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_structured_light_GrayCodePattern_getNumberOfPatternImages_const(instance: *const c_void, ocvrs_return: *mut Result<size_t>);
-		pub fn cv_structured_light_GrayCodePattern_setWhiteThreshold_size_t(instance: *mut c_void, value: size_t, ocvrs_return: *mut Result_void);
-		pub fn cv_structured_light_GrayCodePattern_setBlackThreshold_size_t(instance: *mut c_void, value: size_t, ocvrs_return: *mut Result_void);
+		pub fn cv_structured_light_GrayCodePattern_getNumberOfPatternImages_const(instance: *const c_void, ocvrs_return: *mut Result<usize>);
+		pub fn cv_structured_light_GrayCodePattern_setWhiteThreshold_size_t(instance: *mut c_void, value: usize, ocvrs_return: *mut Result_void);
+		pub fn cv_structured_light_GrayCodePattern_setBlackThreshold_size_t(instance: *mut c_void, value: usize, ocvrs_return: *mut Result_void);
 		pub fn cv_structured_light_GrayCodePattern_getImagesForShadowMasks_const_const__InputOutputArrayR_const__InputOutputArrayR(instance: *const c_void, black_image: *const c_void, white_image: *const c_void, ocvrs_return: *mut Result_void);
 		pub fn cv_structured_light_GrayCodePattern_getProjPixel_const_const__InputArrayR_int_int_PointR(instance: *const c_void, pattern_images: *const c_void, x: i32, y: i32, proj_pix: *mut core::Point, ocvrs_return: *mut Result<bool>);
 		pub fn cv_structured_light_GrayCodePattern_Params_getPropWidth_const(instance: *const c_void) -> i32;
@@ -7111,6 +7255,7 @@ mod structured_light_sys {
 		pub fn cv_structured_light_SinusoidalPattern_Params_getPropSetMarkers_const(instance: *const c_void) -> bool;
 		pub fn cv_structured_light_SinusoidalPattern_Params_setPropSetMarkers_bool(instance: *mut c_void, val: bool);
 		pub fn cv_structured_light_SinusoidalPattern_Params_getPropMarkersLocation_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_structured_light_SinusoidalPattern_Params_getPropMarkersLocationRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_structured_light_SinusoidalPattern_Params_setPropMarkersLocation_vectorLPoint2fG(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_structured_light_SinusoidalPattern_Params_Params(ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_structured_light_StructuredLightPattern_generate_const__OutputArrayR(instance: *mut c_void, pattern_images: *const c_void, ocvrs_return: *mut Result<bool>);
@@ -7236,10 +7381,10 @@ mod surface_matching_sys {
 		pub fn cv_ppf_match_3d_Pose3D_setPropAlpha_double(instance: *mut c_void, val: f64);
 		pub fn cv_ppf_match_3d_Pose3D_getPropResidual_const(instance: *const c_void) -> f64;
 		pub fn cv_ppf_match_3d_Pose3D_setPropResidual_double(instance: *mut c_void, val: f64);
-		pub fn cv_ppf_match_3d_Pose3D_getPropModelIndex_const(instance: *const c_void) -> size_t;
-		pub fn cv_ppf_match_3d_Pose3D_setPropModelIndex_size_t(instance: *mut c_void, val: size_t);
-		pub fn cv_ppf_match_3d_Pose3D_getPropNumVotes_const(instance: *const c_void) -> size_t;
-		pub fn cv_ppf_match_3d_Pose3D_setPropNumVotes_size_t(instance: *mut c_void, val: size_t);
+		pub fn cv_ppf_match_3d_Pose3D_getPropModelIndex_const(instance: *const c_void) -> usize;
+		pub fn cv_ppf_match_3d_Pose3D_setPropModelIndex_size_t(instance: *mut c_void, val: usize);
+		pub fn cv_ppf_match_3d_Pose3D_getPropNumVotes_const(instance: *const c_void) -> usize;
+		pub fn cv_ppf_match_3d_Pose3D_setPropNumVotes_size_t(instance: *mut c_void, val: usize);
 		pub fn cv_ppf_match_3d_Pose3D_getPropPose_const(instance: *const c_void, ocvrs_return: *mut core::Matx44d);
 		pub fn cv_ppf_match_3d_Pose3D_setPropPose_Matx44d(instance: *mut c_void, val: *const core::Matx44d);
 		pub fn cv_ppf_match_3d_Pose3D_getPropAngle_const(instance: *const c_void) -> f64;
@@ -7249,7 +7394,7 @@ mod surface_matching_sys {
 		pub fn cv_ppf_match_3d_Pose3D_getPropQ_const(instance: *const c_void, ocvrs_return: *mut core::Vec4d);
 		pub fn cv_ppf_match_3d_Pose3D_setPropQ_Vec4d(instance: *mut c_void, val: *const core::Vec4d);
 		pub fn cv_ppf_match_3d_Pose3D_Pose3D(ocvrs_return: *mut Result<*mut c_void>);
-		pub fn cv_ppf_match_3d_Pose3D_Pose3D_double_size_t_size_t(alpha: f64, model_index: size_t, num_votes: size_t, ocvrs_return: *mut Result<*mut c_void>);
+		pub fn cv_ppf_match_3d_Pose3D_Pose3D_double_size_t_size_t(alpha: f64, model_index: usize, num_votes: usize, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_ppf_match_3d_Pose3D_updatePose_Matx44dR(instance: *mut c_void, new_pose: *mut core::Matx44d, ocvrs_return: *mut Result_void);
 		pub fn cv_ppf_match_3d_Pose3D_updatePose_Matx33dR_Vec3dR(instance: *mut c_void, new_r: *mut core::Matx33d, new_t: *mut core::Vec3d, ocvrs_return: *mut Result_void);
 		pub fn cv_ppf_match_3d_Pose3D_updatePoseQuat_Vec4dR_Vec3dR(instance: *mut c_void, q: *mut core::Vec4d, new_t: *mut core::Vec3d, ocvrs_return: *mut Result_void);
@@ -7259,9 +7404,10 @@ mod surface_matching_sys {
 		pub fn cv_ppf_match_3d_Pose3D_writePose_const_stringR(instance: *mut c_void, file_name: *const c_char, ocvrs_return: *mut Result<i32>);
 		pub fn cv_ppf_match_3d_Pose3D_readPose_const_stringR(instance: *mut c_void, file_name: *const c_char, ocvrs_return: *mut Result<i32>);
 		pub fn cv_ppf_match_3d_PoseCluster3D_getPropPoseList_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_ppf_match_3d_PoseCluster3D_getPropPoseListRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_ppf_match_3d_PoseCluster3D_setPropPoseList_vectorLPose3DPtrG(instance: *mut c_void, val: *mut c_void);
-		pub fn cv_ppf_match_3d_PoseCluster3D_getPropNumVotes_const(instance: *const c_void) -> size_t;
-		pub fn cv_ppf_match_3d_PoseCluster3D_setPropNumVotes_size_t(instance: *mut c_void, val: size_t);
+		pub fn cv_ppf_match_3d_PoseCluster3D_getPropNumVotes_const(instance: *const c_void) -> usize;
+		pub fn cv_ppf_match_3d_PoseCluster3D_setPropNumVotes_size_t(instance: *mut c_void, val: usize);
 		pub fn cv_ppf_match_3d_PoseCluster3D_getPropId_const(instance: *const c_void) -> i32;
 		pub fn cv_ppf_match_3d_PoseCluster3D_setPropId_int(instance: *mut c_void, val: i32);
 		pub fn cv_ppf_match_3d_PoseCluster3D_PoseCluster3D(ocvrs_return: *mut Result<*mut c_void>);
@@ -7404,6 +7550,7 @@ mod tracking_sys {
 		pub fn cv_tracking_TrackerCSRT_Params_getPropUse_segmentation_const(instance: *const c_void) -> bool;
 		pub fn cv_tracking_TrackerCSRT_Params_setPropUse_segmentation_bool(instance: *mut c_void, val: bool);
 		pub fn cv_tracking_TrackerCSRT_Params_getPropWindow_function_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_tracking_TrackerCSRT_Params_getPropWindow_functionRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_tracking_TrackerCSRT_Params_setPropWindow_function_string(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_tracking_TrackerCSRT_Params_getPropKaiser_alpha_const(instance: *const c_void) -> f32;
 		pub fn cv_tracking_TrackerCSRT_Params_setPropKaiser_alpha_float(instance: *mut c_void, val: f32);
@@ -7554,34 +7701,49 @@ mod video_sys {
 		pub fn cv_FarnebackOpticalFlow_setFlags_int(instance: *mut c_void, flags: i32, ocvrs_return: *mut Result_void);
 		pub fn cv_FarnebackOpticalFlow_create_int_double_bool_int_int_int_double_int(num_levels: i32, pyr_scale: f64, fast_pyramids: bool, win_size: i32, num_iters: i32, poly_n: i32, poly_sigma: f64, flags: i32, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_KalmanFilter_getPropStatePre_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_KalmanFilter_getPropStatePreRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_KalmanFilter_setPropStatePre_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_KalmanFilter_getPropStatePost_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_KalmanFilter_getPropStatePostRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_KalmanFilter_setPropStatePost_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_KalmanFilter_getPropTransitionMatrix_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_KalmanFilter_getPropTransitionMatrixRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_KalmanFilter_setPropTransitionMatrix_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_KalmanFilter_getPropControlMatrix_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_KalmanFilter_getPropControlMatrixRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_KalmanFilter_setPropControlMatrix_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_KalmanFilter_getPropMeasurementMatrix_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_KalmanFilter_getPropMeasurementMatrixRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_KalmanFilter_setPropMeasurementMatrix_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_KalmanFilter_getPropProcessNoiseCov_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_KalmanFilter_getPropProcessNoiseCovRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_KalmanFilter_setPropProcessNoiseCov_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_KalmanFilter_getPropMeasurementNoiseCov_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_KalmanFilter_getPropMeasurementNoiseCovRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_KalmanFilter_setPropMeasurementNoiseCov_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_KalmanFilter_getPropErrorCovPre_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_KalmanFilter_getPropErrorCovPreRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_KalmanFilter_setPropErrorCovPre_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_KalmanFilter_getPropGain_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_KalmanFilter_getPropGainRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_KalmanFilter_setPropGain_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_KalmanFilter_getPropErrorCovPost_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_KalmanFilter_getPropErrorCovPostRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_KalmanFilter_setPropErrorCovPost_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_KalmanFilter_getPropTemp1_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_KalmanFilter_getPropTemp1Ref_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_KalmanFilter_setPropTemp1_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_KalmanFilter_getPropTemp2_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_KalmanFilter_getPropTemp2Ref_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_KalmanFilter_setPropTemp2_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_KalmanFilter_getPropTemp3_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_KalmanFilter_getPropTemp3Ref_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_KalmanFilter_setPropTemp3_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_KalmanFilter_getPropTemp4_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_KalmanFilter_getPropTemp4Ref_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_KalmanFilter_setPropTemp4_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_KalmanFilter_getPropTemp5_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_KalmanFilter_getPropTemp5Ref_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_KalmanFilter_setPropTemp5_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_KalmanFilter_KalmanFilter(ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_KalmanFilter_KalmanFilter_int_int_int_int(dynam_params: i32, measure_params: i32, control_params: i32, typ: i32, ocvrs_return: *mut Result<*mut c_void>);
@@ -7605,10 +7767,13 @@ mod video_sys {
 		pub fn cv_TrackerDaSiamRPN_create_const_ParamsR(parameters: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_TrackerDaSiamRPN_getTrackingScore(instance: *mut c_void, ocvrs_return: *mut Result<f32>);
 		pub fn cv_TrackerDaSiamRPN_Params_getPropModel_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_TrackerDaSiamRPN_Params_getPropModelRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_TrackerDaSiamRPN_Params_setPropModel_string(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_TrackerDaSiamRPN_Params_getPropKernel_cls1_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_TrackerDaSiamRPN_Params_getPropKernel_cls1Ref_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_TrackerDaSiamRPN_Params_setPropKernel_cls1_string(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_TrackerDaSiamRPN_Params_getPropKernel_r1_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_TrackerDaSiamRPN_Params_getPropKernel_r1Ref_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_TrackerDaSiamRPN_Params_setPropKernel_r1_string(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_TrackerDaSiamRPN_Params_getPropBackend_const(instance: *const c_void) -> i32;
 		pub fn cv_TrackerDaSiamRPN_Params_setPropBackend_int(instance: *mut c_void, val: i32);
@@ -7617,8 +7782,10 @@ mod video_sys {
 		pub fn cv_TrackerDaSiamRPN_Params_Params(ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_TrackerGOTURN_create_const_ParamsR(parameters: *const c_void, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_TrackerGOTURN_Params_getPropModelTxt_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_TrackerGOTURN_Params_getPropModelTxtRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_TrackerGOTURN_Params_setPropModelTxt_string(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_TrackerGOTURN_Params_getPropModelBin_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_TrackerGOTURN_Params_getPropModelBinRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_TrackerGOTURN_Params_setPropModelBin_string(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_TrackerGOTURN_Params_Params(ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_TrackerMIL_create_const_ParamsR(parameters: *const crate::video::TrackerMIL_Params, ocvrs_return: *mut Result<*mut c_void>);
@@ -8017,6 +8184,7 @@ mod viz_sys {
 		pub fn cv_viz_KeyboardEvent_getPropAction_const(instance: *const c_void, ocvrs_return: *mut crate::viz::KeyboardEvent_Action);
 		pub fn cv_viz_KeyboardEvent_setPropAction_Action(instance: *mut c_void, val: crate::viz::KeyboardEvent_Action);
 		pub fn cv_viz_KeyboardEvent_getPropSymbol_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_viz_KeyboardEvent_getPropSymbolRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_viz_KeyboardEvent_setPropSymbol_String(instance: *mut c_void, val: *mut c_char);
 		pub fn cv_viz_KeyboardEvent_getPropCode_const(instance: *const c_void) -> u8;
 		pub fn cv_viz_KeyboardEvent_setPropCode_unsigned_char(instance: *mut c_void, val: u8);
@@ -8024,16 +8192,22 @@ mod viz_sys {
 		pub fn cv_viz_KeyboardEvent_setPropModifiers_int(instance: *mut c_void, val: i32);
 		pub fn cv_viz_KeyboardEvent_KeyboardEvent_Action_const_StringR_unsigned_char_int(action: crate::viz::KeyboardEvent_Action, symbol: *const c_char, code: u8, modifiers: i32, ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_viz_Mesh_getPropCloud_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_viz_Mesh_getPropCloudRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_viz_Mesh_setPropCloud_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_viz_Mesh_getPropColors_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_viz_Mesh_getPropColorsRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_viz_Mesh_setPropColors_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_viz_Mesh_getPropNormals_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_viz_Mesh_getPropNormalsRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_viz_Mesh_setPropNormals_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_viz_Mesh_getPropPolygons_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_viz_Mesh_getPropPolygonsRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_viz_Mesh_setPropPolygons_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_viz_Mesh_getPropTexture_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_viz_Mesh_getPropTextureRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_viz_Mesh_setPropTexture_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_viz_Mesh_getPropTcoords_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_viz_Mesh_getPropTcoordsRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_viz_Mesh_setPropTcoords_Mat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_viz_Mesh_Mesh(ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_viz_Mesh_load_const_StringR_int(file: *const c_char, typ: i32, ocvrs_return: *mut Result<*mut c_void>);
@@ -8192,16 +8366,22 @@ mod xfeatures2d_sys {
 		pub fn cv_cuda_SURF_CUDA_getPropKeypointsRatio_const(instance: *const c_void) -> f32;
 		pub fn cv_cuda_SURF_CUDA_setPropKeypointsRatio_float(instance: *mut c_void, val: f32);
 		pub fn cv_cuda_SURF_CUDA_getPropSum_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_cuda_SURF_CUDA_getPropSumRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_cuda_SURF_CUDA_setPropSum_GpuMat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_cuda_SURF_CUDA_getPropMask1_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_cuda_SURF_CUDA_getPropMask1Ref_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_cuda_SURF_CUDA_setPropMask1_GpuMat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_cuda_SURF_CUDA_getPropMaskSum_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_cuda_SURF_CUDA_getPropMaskSumRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_cuda_SURF_CUDA_setPropMaskSum_GpuMat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_cuda_SURF_CUDA_getPropDet_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_cuda_SURF_CUDA_getPropDetRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_cuda_SURF_CUDA_setPropDet_GpuMat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_cuda_SURF_CUDA_getPropTrace_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_cuda_SURF_CUDA_getPropTraceRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_cuda_SURF_CUDA_setPropTrace_GpuMat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_cuda_SURF_CUDA_getPropMaxPosBuffer_const(instance: *const c_void) -> *mut c_void;
+		pub fn cv_cuda_SURF_CUDA_getPropMaxPosBufferRef_const(instance: *const c_void) -> *const c_void;
 		pub fn cv_cuda_SURF_CUDA_setPropMaxPosBuffer_GpuMat(instance: *mut c_void, val: *mut c_void);
 		pub fn cv_cuda_SURF_CUDA_SURF_CUDA(ocvrs_return: *mut Result<*mut c_void>);
 		pub fn cv_cuda_SURF_CUDA_SURF_CUDA_double_int_int_bool_float_bool(_hessian_threshold: f64, _n_octaves: i32, _n_octave_layers: i32, _extended: bool, _keypoints_ratio: f32, _upright: bool, ocvrs_return: *mut Result<*mut c_void>);
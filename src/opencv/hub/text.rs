@@ -201,7 +201,7 @@ opencv_type_enum! { crate::text::page_seg_mode }
 /// An example of MSERsToERStats in use can be found in the text detection webcam_demo:
 /// <https://github.com/opencv/opencv_contrib/blob/master/modules/text/samples/webcam_demo.cpp>
 #[inline]
-pub fn mse_rs_to_er_stats(image: &dyn core::ToInputArray, contours: &mut core::Vector<core::Vector<core::Point>>, regions: &mut core::Vector<core::Vector<crate::text::ERStat>>) -> Result<()> {
+pub fn mse_rs_to_er_stats(image: &(impl core::ToInputArray + ?Sized), contours: &mut core::Vector<core::Vector<core::Point>>, regions: &mut core::Vector<core::Vector<crate::text::ERStat>>) -> Result<()> {
 	input_array_arg!(image);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_text_MSERsToERStats_const__InputArrayR_vectorLvectorLPointGGR_vectorLvectorLERStatGGR(image.as_raw__InputArray(), contours.as_raw_mut_VectorOfVectorOfPoint(), regions.as_raw_mut_VectorOfVectorOfERStat(), ocvrs_return.as_mut_ptr()) };
@@ -228,7 +228,7 @@ pub fn mse_rs_to_er_stats(image: &dyn core::ToInputArray, contours: &mut core::V
 /// ## C++ default parameters
 /// * _mode: ERFILTER_NM_RGBLGrad
 #[inline]
-pub fn compute_nm_channels(_src: &dyn core::ToInputArray, _channels: &mut dyn core::ToOutputArray, _mode: i32) -> Result<()> {
+pub fn compute_nm_channels(_src: &(impl core::ToInputArray + ?Sized), _channels: &mut (impl core::ToOutputArray + ?Sized), _mode: i32) -> Result<()> {
 	input_array_arg!(_src);
 	output_array_arg!(_channels);
 	return_send!(via ocvrs_return);
@@ -400,7 +400,7 @@ pub fn create_ocrhmm_transitions_table_1(vocabulary: &str, lexicon: &mut core::V
 ///    *   (C++) An alternative would be to load the default generic language transition table provided in the text module samples folder (created from ispell 42869 english words list) :
 ///            <https://github.com/opencv/opencv_contrib/blob/master/modules/text/samples/OCRHMM_transitions_table.xml>
 #[inline]
-pub fn create_ocrhmm_transitions_table(vocabulary: &mut String, lexicon: &mut core::Vector<String>, transition_probabilities_table: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn create_ocrhmm_transitions_table(vocabulary: &mut String, lexicon: &mut core::Vector<String>, transition_probabilities_table: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	string_arg_output_send!(via vocabulary_via);
 	output_array_arg!(transition_probabilities_table);
 	return_send!(via ocvrs_return);
@@ -427,7 +427,7 @@ pub fn create_ocrhmm_transitions_table(vocabulary: &mut String, lexicon: &mut co
 /// * filename: String()
 /// * min_probability: (float)0.5
 #[inline]
-pub fn detect_regions_from_file(image: &dyn core::ToInputArray, er_filter1: &core::Ptr<dyn crate::text::ERFilter>, er_filter2: &core::Ptr<dyn crate::text::ERFilter>, groups_rects: &mut core::Vector<core::Rect>, method: i32, filename: &str, min_probability: f32) -> Result<()> {
+pub fn detect_regions_from_file(image: &(impl core::ToInputArray + ?Sized), er_filter1: &core::Ptr<dyn crate::text::ERFilter>, er_filter2: &core::Ptr<dyn crate::text::ERFilter>, groups_rects: &mut core::Vector<core::Rect>, method: i32, filename: &str, min_probability: f32) -> Result<()> {
 	input_array_arg!(image);
 	extern_container_arg!(filename);
 	return_send!(via ocvrs_return);
@@ -438,7 +438,7 @@ pub fn detect_regions_from_file(image: &dyn core::ToInputArray, er_filter1: &cor
 }
 
 #[inline]
-pub fn detect_regions(image: &dyn core::ToInputArray, er_filter1: &core::Ptr<dyn crate::text::ERFilter>, er_filter2: &core::Ptr<dyn crate::text::ERFilter>, regions: &mut core::Vector<core::Vector<core::Point>>) -> Result<()> {
+pub fn detect_regions(image: &(impl core::ToInputArray + ?Sized), er_filter1: &core::Ptr<dyn crate::text::ERFilter>, er_filter2: &core::Ptr<dyn crate::text::ERFilter>, regions: &mut core::Vector<core::Vector<core::Point>>) -> Result<()> {
 	input_array_arg!(image);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_text_detectRegions_const__InputArrayR_const_PtrLERFilterGR_const_PtrLERFilterGR_vectorLvectorLPointGGR(image.as_raw__InputArray(), er_filter1.as_raw_PtrOfERFilter(), er_filter2.as_raw_PtrOfERFilter(), regions.as_raw_mut_VectorOfVectorOfPoint(), ocvrs_return.as_mut_ptr()) };
@@ -459,10 +459,10 @@ pub fn detect_regions(image: &dyn core::ToInputArray, er_filter1: &core::Ptr<dyn
 /// * draw: noArray()
 /// * chain_b_bs: noArray()
 #[inline]
-pub fn detect_text_swt(input: &dyn core::ToInputArray, result: &mut core::Vector<core::Rect>, dark_on_light: bool, draw: &mut dyn core::ToOutputArray, chain_b_bs: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn detect_text_swt(input: &(impl core::ToInputArray + ?Sized), result: &mut core::Vector<core::Rect>, dark_on_light: bool, draw: Option<&mut (impl core::ToOutputArray + ?Sized)>, chain_b_bs: Option<&mut (impl core::ToOutputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(input);
-	output_array_arg!(draw);
-	output_array_arg!(chain_b_bs);
+	output_array_arg!(nullable draw);
+	output_array_arg!(nullable chain_b_bs);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_text_detectTextSWT_const__InputArrayR_vectorLRectGR_bool_const__OutputArrayR_const__OutputArrayR(input.as_raw__InputArray(), result.as_raw_mut_VectorOfRect(), dark_on_light, draw.as_raw__OutputArray(), chain_b_bs.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -499,7 +499,7 @@ pub fn detect_text_swt(input: &dyn core::ToInputArray, result: &mut core::Vector
 /// * filename: std::string()
 /// * min_probablity: 0.5
 #[inline]
-pub fn er_grouping(img: &dyn core::ToInputArray, channels: &dyn core::ToInputArray, regions: &mut core::Vector<core::Vector<crate::text::ERStat>>, groups: &mut core::Vector<core::Vector<core::Vec2i>>, groups_rects: &mut core::Vector<core::Rect>, method: i32, filename: &str, min_probablity: f32) -> Result<()> {
+pub fn er_grouping(img: &(impl core::ToInputArray + ?Sized), channels: &(impl core::ToInputArray + ?Sized), regions: &mut core::Vector<core::Vector<crate::text::ERStat>>, groups: &mut core::Vector<core::Vector<core::Vec2i>>, groups_rects: &mut core::Vector<core::Rect>, method: i32, filename: &str, min_probablity: f32) -> Result<()> {
 	input_array_arg!(img);
 	input_array_arg!(channels);
 	extern_container_arg!(filename);
@@ -515,7 +515,7 @@ pub fn er_grouping(img: &dyn core::ToInputArray, channels: &dyn core::ToInputArr
 /// * filename: String()
 /// * min_probablity: (float)0.5
 #[inline]
-pub fn er_grouping_1(image: &dyn core::ToInputArray, channel: &dyn core::ToInputArray, mut regions: core::Vector<core::Vector<core::Point>>, groups_rects: &mut core::Vector<core::Rect>, method: i32, filename: &str, min_probablity: f32) -> Result<()> {
+pub fn er_grouping_1(image: &(impl core::ToInputArray + ?Sized), channel: &(impl core::ToInputArray + ?Sized), mut regions: core::Vector<core::Vector<core::Point>>, groups_rects: &mut core::Vector<core::Rect>, method: i32, filename: &str, min_probablity: f32) -> Result<()> {
 	input_array_arg!(image);
 	input_array_arg!(channel);
 	extern_container_arg!(filename);
@@ -962,7 +962,7 @@ pub trait ERStatTrait: crate::text::ERStatTraitConst {
 	#[inline]
 	fn raw_moments(&mut self) -> &mut [f64; 2] {
 		let ret = unsafe { sys::cv_text_ERStat_getPropRaw_moments(self.as_raw_mut_ERStat()) };
-		let ret = unsafe { ret.as_mut() }.expect("Function returned null pointer");
+		let ret = unsafe { &mut *ret };
 		ret
 	}
 	
@@ -970,7 +970,7 @@ pub trait ERStatTrait: crate::text::ERStatTraitConst {
 	#[inline]
 	fn central_moments(&mut self) -> &mut [f64; 3] {
 		let ret = unsafe { sys::cv_text_ERStat_getPropCentral_moments(self.as_raw_mut_ERStat()) };
-		let ret = unsafe { ret.as_mut() }.expect("Function returned null pointer");
+		let ret = unsafe { &mut *ret };
 		ret
 	}
 	
@@ -1304,7 +1304,7 @@ impl OCRBeamSearchDecoder {
 	/// * mode: OCR_DECODER_VITERBI
 	/// * beam_size: 500
 	#[inline]
-	pub fn create(classifier: core::Ptr<crate::text::OCRBeamSearchDecoder_ClassifierCallback>, vocabulary: &str, transition_probabilities_table: &dyn core::ToInputArray, emission_probabilities_table: &dyn core::ToInputArray, mode: crate::text::decoder_mode, beam_size: i32) -> Result<core::Ptr<crate::text::OCRBeamSearchDecoder>> {
+	pub fn create(classifier: core::Ptr<crate::text::OCRBeamSearchDecoder_ClassifierCallback>, vocabulary: &str, transition_probabilities_table: &(impl core::ToInputArray + ?Sized), emission_probabilities_table: &(impl core::ToInputArray + ?Sized), mode: crate::text::decoder_mode, beam_size: i32) -> Result<core::Ptr<crate::text::OCRBeamSearchDecoder>> {
 		extern_container_arg!(vocabulary);
 		input_array_arg!(transition_probabilities_table);
 		input_array_arg!(emission_probabilities_table);
@@ -1343,7 +1343,7 @@ impl OCRBeamSearchDecoder {
 	/// * mode: OCR_DECODER_VITERBI
 	/// * beam_size: 500
 	#[inline]
-	pub fn create_from_file(filename: &str, vocabulary: &str, transition_probabilities_table: &dyn core::ToInputArray, emission_probabilities_table: &dyn core::ToInputArray, mode: crate::text::decoder_mode, beam_size: i32) -> Result<core::Ptr<crate::text::OCRBeamSearchDecoder>> {
+	pub fn create_from_file(filename: &str, vocabulary: &str, transition_probabilities_table: &(impl core::ToInputArray + ?Sized), emission_probabilities_table: &(impl core::ToInputArray + ?Sized), mode: crate::text::decoder_mode, beam_size: i32) -> Result<core::Ptr<crate::text::OCRBeamSearchDecoder>> {
 		extern_container_arg!(filename);
 		extern_container_arg!(vocabulary);
 		input_array_arg!(transition_probabilities_table);
@@ -1627,7 +1627,7 @@ impl OCRHMMDecoder {
 	/// ## C++ default parameters
 	/// * mode: OCR_DECODER_VITERBI
 	#[inline]
-	pub fn create(classifier: core::Ptr<crate::text::OCRHMMDecoder_ClassifierCallback>, vocabulary: &str, transition_probabilities_table: &dyn core::ToInputArray, emission_probabilities_table: &dyn core::ToInputArray, mode: i32) -> Result<core::Ptr<crate::text::OCRHMMDecoder>> {
+	pub fn create(classifier: core::Ptr<crate::text::OCRHMMDecoder_ClassifierCallback>, vocabulary: &str, transition_probabilities_table: &(impl core::ToInputArray + ?Sized), emission_probabilities_table: &(impl core::ToInputArray + ?Sized), mode: i32) -> Result<core::Ptr<crate::text::OCRHMMDecoder>> {
 		extern_container_arg!(vocabulary);
 		input_array_arg!(transition_probabilities_table);
 		input_array_arg!(emission_probabilities_table);
@@ -1664,7 +1664,7 @@ impl OCRHMMDecoder {
 	/// * mode: OCR_DECODER_VITERBI
 	/// * classifier: OCR_KNN_CLASSIFIER
 	#[inline]
-	pub fn create_from_file(filename: &str, vocabulary: &str, transition_probabilities_table: &dyn core::ToInputArray, emission_probabilities_table: &dyn core::ToInputArray, mode: i32, classifier: i32) -> Result<core::Ptr<crate::text::OCRHMMDecoder>> {
+	pub fn create_from_file(filename: &str, vocabulary: &str, transition_probabilities_table: &(impl core::ToInputArray + ?Sized), emission_probabilities_table: &(impl core::ToInputArray + ?Sized), mode: i32, classifier: i32) -> Result<core::Ptr<crate::text::OCRHMMDecoder>> {
 		extern_container_arg!(filename);
 		extern_container_arg!(vocabulary);
 		input_array_arg!(transition_probabilities_table);
@@ -2082,4 +2082,5 @@ impl dyn TextDetectorCNN + '_ {
 		Ok(ret)
 	}
 	
-}
\ No newline at end of file
+}
+pub use crate::manual::text::*;
@@ -62,9 +62,9 @@ impl dyn TrackerCSRT + '_ {
 	/// ## C++ default parameters
 	/// * parameters: TrackerCSRT::Params()
 	#[inline]
-	pub fn create(parameters: &crate::tracking::TrackerCSRT_Params) -> Result<core::Ptr<dyn crate::tracking::TrackerCSRT>> {
+	pub fn create(parameters: Option<&crate::tracking::TrackerCSRT_Params>) -> Result<core::Ptr<dyn crate::tracking::TrackerCSRT>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_tracking_TrackerCSRT_create_const_ParamsR(parameters.as_raw_TrackerCSRT_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_tracking_TrackerCSRT_create_const_ParamsR(parameters.map_or(::core::ptr::null(), |parameters| parameters.as_raw_TrackerCSRT_Params()), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::tracking::TrackerCSRT>::opencv_from_extern(ret) };
@@ -119,6 +119,14 @@ pub trait TrackerCSRT_ParamsTraitConst {
 		ret
 	}
 	
+	/// Same as `window_function()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn window_function_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_getPropWindow_functionRef_const(self.as_raw_TrackerCSRT_Params()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	#[inline]
 	fn kaiser_alpha(&self) -> f32 {
 		let ret = unsafe { sys::cv_tracking_TrackerCSRT_Params_getPropKaiser_alpha_const(self.as_raw_TrackerCSRT_Params()) };
@@ -540,3 +548,4 @@ impl TrackerKCF_Params {
 	}
 	
 }
+pub use crate::manual::tracking::*;
@@ -864,6 +864,20 @@ mod core_types {
 		1 = arg_1: core::Point2i, get_1 via cv_TupleOfPoint2i_Point2i_get_1
 	}
 	
+	impl From<core::Tuple<(core::Point2i, core::Point2i)>> for (core::Point2i, core::Point2i) {
+		#[inline]
+		fn from(s: core::Tuple<(core::Point2i, core::Point2i)>) -> Self {
+			s.into_tuple()
+		}
+	}
+	
+	impl From<(core::Point2i, core::Point2i)> for core::Tuple<(core::Point2i, core::Point2i)> {
+		#[inline]
+		fn from(s: (core::Point2i, core::Point2i)) -> Self {
+			Self::new(s)
+		}
+	}
+	
 	pub type TupleOfRect_i32 = core::Tuple<(core::Rect, i32)>;
 	
 	impl core::Tuple<(core::Rect, i32)> {
@@ -877,6 +891,20 @@ mod core_types {
 		1 = arg_1: i32, get_1 via cv_TupleOfRect_i32_get_1
 	}
 	
+	impl From<core::Tuple<(core::Rect, i32)>> for (core::Rect, i32) {
+		#[inline]
+		fn from(s: core::Tuple<(core::Rect, i32)>) -> Self {
+			s.into_tuple()
+		}
+	}
+	
+	impl From<(core::Rect, i32)> for core::Tuple<(core::Rect, i32)> {
+		#[inline]
+		fn from(s: (core::Rect, i32)) -> Self {
+			Self::new(s)
+		}
+	}
+	
 	pub type TupleOfUMat_u8 = core::Tuple<(core::UMat, u8)>;
 	
 	impl core::Tuple<(core::UMat, u8)> {
@@ -890,6 +918,20 @@ mod core_types {
 		1 = arg_1: u8, get_1 via cv_TupleOfUMat_u8_get_1
 	}
 	
+	impl From<core::Tuple<(core::UMat, u8)>> for (core::UMat, u8) {
+		#[inline]
+		fn from(s: core::Tuple<(core::UMat, u8)>) -> Self {
+			s.into_tuple()
+		}
+	}
+	
+	impl From<(core::UMat, u8)> for core::Tuple<(core::UMat, u8)> {
+		#[inline]
+		fn from(s: (core::UMat, u8)) -> Self {
+			Self::new(s)
+		}
+	}
+	
 	pub type TupleOfi32_f32 = core::Tuple<(i32, f32)>;
 	
 	impl core::Tuple<(i32, f32)> {
@@ -903,6 +945,20 @@ mod core_types {
 		1 = arg_1: f32, get_1 via cv_TupleOfi32_f32_get_1
 	}
 	
+	impl From<core::Tuple<(i32, f32)>> for (i32, f32) {
+		#[inline]
+		fn from(s: core::Tuple<(i32, f32)>) -> Self {
+			s.into_tuple()
+		}
+	}
+	
+	impl From<(i32, f32)> for core::Tuple<(i32, f32)> {
+		#[inline]
+		fn from(s: (i32, f32)) -> Self {
+			Self::new(s)
+		}
+	}
+	
 	pub type TupleOfi32_f64 = core::Tuple<(i32, f64)>;
 	
 	impl core::Tuple<(i32, f64)> {
@@ -916,6 +972,20 @@ mod core_types {
 		1 = arg_1: f64, get_1 via cv_TupleOfi32_f64_get_1
 	}
 	
+	impl From<core::Tuple<(i32, f64)>> for (i32, f64) {
+		#[inline]
+		fn from(s: core::Tuple<(i32, f64)>) -> Self {
+			s.into_tuple()
+		}
+	}
+	
+	impl From<(i32, f64)> for core::Tuple<(i32, f64)> {
+		#[inline]
+		fn from(s: (i32, f64)) -> Self {
+			Self::new(s)
+		}
+	}
+	
 	pub type VectorOfDMatch = core::Vector<core::DMatch>;
 	
 	impl core::Vector<core::DMatch> {
@@ -3362,25 +3432,25 @@ mod core_types {
 	
 	input_output_array_ref_forward! { core::Vector<i8> }
 	
-	pub type VectorOfsize_t = core::Vector<size_t>;
+	pub type VectorOfusize = core::Vector<usize>;
 	
-	impl core::Vector<size_t> {
-		pub fn as_raw_VectorOfsize_t(&self) -> extern_send!(Self) { self.as_raw() }
-		pub fn as_raw_mut_VectorOfsize_t(&mut self) -> extern_send!(mut Self) { self.as_raw_mut() }
+	impl core::Vector<usize> {
+		pub fn as_raw_VectorOfusize(&self) -> extern_send!(Self) { self.as_raw() }
+		pub fn as_raw_mut_VectorOfusize(&mut self) -> extern_send!(mut Self) { self.as_raw_mut() }
 	}
 	
-	vector_extern! { size_t,
-		cv_VectorOfsize_t_new, cv_VectorOfsize_t_delete,
-		cv_VectorOfsize_t_len, cv_VectorOfsize_t_is_empty,
-		cv_VectorOfsize_t_capacity, cv_VectorOfsize_t_shrink_to_fit,
-		cv_VectorOfsize_t_reserve, cv_VectorOfsize_t_remove,
-		cv_VectorOfsize_t_swap, cv_VectorOfsize_t_clear,
-		cv_VectorOfsize_t_get, cv_VectorOfsize_t_set,
-		cv_VectorOfsize_t_push, cv_VectorOfsize_t_insert,
+	vector_extern! { usize,
+		cv_VectorOfusize_new, cv_VectorOfusize_delete,
+		cv_VectorOfusize_len, cv_VectorOfusize_is_empty,
+		cv_VectorOfusize_capacity, cv_VectorOfusize_shrink_to_fit,
+		cv_VectorOfusize_reserve, cv_VectorOfusize_remove,
+		cv_VectorOfusize_swap, cv_VectorOfusize_clear,
+		cv_VectorOfusize_get, cv_VectorOfusize_set,
+		cv_VectorOfusize_push, cv_VectorOfusize_insert,
 	}
-	vector_copy_non_bool! { size_t,
-		cv_VectorOfsize_t_data, cv_VectorOfsize_t_data_mut, cv_VectorOfsize_t_from_slice,
-		cv_VectorOfsize_t_clone,
+	vector_copy_non_bool! { usize,
+		cv_VectorOfusize_data, cv_VectorOfusize_data_mut, cv_VectorOfusize_from_slice,
+		cv_VectorOfusize_clone,
 	}
 	
 	pub type VectorOfu8 = core::Vector<u8>;
@@ -8221,6 +8291,20 @@ mod dnn_types {
 		1 = arg_1: crate::dnn::Target, get_1 via cv_TupleOfBackend_Target_get_1
 	}
 	
+	impl From<core::Tuple<(crate::dnn::Backend, crate::dnn::Target)>> for (crate::dnn::Backend, crate::dnn::Target) {
+		#[inline]
+		fn from(s: core::Tuple<(crate::dnn::Backend, crate::dnn::Target)>) -> Self {
+			s.into_tuple()
+		}
+	}
+	
+	impl From<(crate::dnn::Backend, crate::dnn::Target)> for core::Tuple<(crate::dnn::Backend, crate::dnn::Target)> {
+		#[inline]
+		fn from(s: (crate::dnn::Backend, crate::dnn::Target)) -> Self {
+			Self::new(s)
+		}
+	}
+	
 	pub type VectorOfMatShape = core::Vector<crate::dnn::MatShape>;
 	
 	impl core::Vector<crate::dnn::MatShape> {
@@ -9516,6 +9600,20 @@ mod gapi_types {
 		1 = arg_1: crate::gapi::GKernelImpl, get_1 via cv_TupleOfGBackend_GKernelImpl_get_1
 	}
 	
+	impl From<core::Tuple<(crate::gapi::GBackend, crate::gapi::GKernelImpl)>> for (crate::gapi::GBackend, crate::gapi::GKernelImpl) {
+		#[inline]
+		fn from(s: core::Tuple<(crate::gapi::GBackend, crate::gapi::GKernelImpl)>) -> Self {
+			s.into_tuple()
+		}
+	}
+	
+	impl From<(crate::gapi::GBackend, crate::gapi::GKernelImpl)> for core::Tuple<(crate::gapi::GBackend, crate::gapi::GKernelImpl)> {
+		#[inline]
+		fn from(s: (crate::gapi::GBackend, crate::gapi::GKernelImpl)) -> Self {
+			Self::new(s)
+		}
+	}
+	
 	pub type TupleOfGMat_GMat = core::Tuple<(crate::gapi::GMat, crate::gapi::GMat)>;
 	
 	impl core::Tuple<(crate::gapi::GMat, crate::gapi::GMat)> {
@@ -9529,6 +9627,20 @@ mod gapi_types {
 		1 = arg_1: crate::gapi::GMat, get_1 via cv_TupleOfGMat_GMat_get_1
 	}
 	
+	impl From<core::Tuple<(crate::gapi::GMat, crate::gapi::GMat)>> for (crate::gapi::GMat, crate::gapi::GMat) {
+		#[inline]
+		fn from(s: core::Tuple<(crate::gapi::GMat, crate::gapi::GMat)>) -> Self {
+			s.into_tuple()
+		}
+	}
+	
+	impl From<(crate::gapi::GMat, crate::gapi::GMat)> for core::Tuple<(crate::gapi::GMat, crate::gapi::GMat)> {
+		#[inline]
+		fn from(s: (crate::gapi::GMat, crate::gapi::GMat)) -> Self {
+			Self::new(s)
+		}
+	}
+	
 	pub type TupleOfGMat_GMat_GMat = core::Tuple<(crate::gapi::GMat, crate::gapi::GMat, crate::gapi::GMat)>;
 	
 	impl core::Tuple<(crate::gapi::GMat, crate::gapi::GMat, crate::gapi::GMat)> {
@@ -9543,6 +9655,20 @@ mod gapi_types {
 		2 = arg_2: crate::gapi::GMat, get_2 via cv_TupleOfGMat_GMat_GMat_get_2
 	}
 	
+	impl From<core::Tuple<(crate::gapi::GMat, crate::gapi::GMat, crate::gapi::GMat)>> for (crate::gapi::GMat, crate::gapi::GMat, crate::gapi::GMat) {
+		#[inline]
+		fn from(s: core::Tuple<(crate::gapi::GMat, crate::gapi::GMat, crate::gapi::GMat)>) -> Self {
+			s.into_tuple()
+		}
+	}
+	
+	impl From<(crate::gapi::GMat, crate::gapi::GMat, crate::gapi::GMat)> for core::Tuple<(crate::gapi::GMat, crate::gapi::GMat, crate::gapi::GMat)> {
+		#[inline]
+		fn from(s: (crate::gapi::GMat, crate::gapi::GMat, crate::gapi::GMat)) -> Self {
+			Self::new(s)
+		}
+	}
+	
 	pub type TupleOfGMat_GMat_GMat_GMat = core::Tuple<(crate::gapi::GMat, crate::gapi::GMat, crate::gapi::GMat, crate::gapi::GMat)>;
 	
 	impl core::Tuple<(crate::gapi::GMat, crate::gapi::GMat, crate::gapi::GMat, crate::gapi::GMat)> {
@@ -9558,6 +9684,20 @@ mod gapi_types {
 		3 = arg_3: crate::gapi::GMat, get_3 via cv_TupleOfGMat_GMat_GMat_GMat_get_3
 	}
 	
+	impl From<core::Tuple<(crate::gapi::GMat, crate::gapi::GMat, crate::gapi::GMat, crate::gapi::GMat)>> for (crate::gapi::GMat, crate::gapi::GMat, crate::gapi::GMat, crate::gapi::GMat) {
+		#[inline]
+		fn from(s: core::Tuple<(crate::gapi::GMat, crate::gapi::GMat, crate::gapi::GMat, crate::gapi::GMat)>) -> Self {
+			s.into_tuple()
+		}
+	}
+	
+	impl From<(crate::gapi::GMat, crate::gapi::GMat, crate::gapi::GMat, crate::gapi::GMat)> for core::Tuple<(crate::gapi::GMat, crate::gapi::GMat, crate::gapi::GMat, crate::gapi::GMat)> {
+		#[inline]
+		fn from(s: (crate::gapi::GMat, crate::gapi::GMat, crate::gapi::GMat, crate::gapi::GMat)) -> Self {
+			Self::new(s)
+		}
+	}
+	
 	pub type TupleOfGMat_GScalar = core::Tuple<(crate::gapi::GMat, crate::gapi::GScalar)>;
 	
 	impl core::Tuple<(crate::gapi::GMat, crate::gapi::GScalar)> {
@@ -9571,6 +9711,20 @@ mod gapi_types {
 		1 = arg_1: crate::gapi::GScalar, get_1 via cv_TupleOfGMat_GScalar_get_1
 	}
 	
+	impl From<core::Tuple<(crate::gapi::GMat, crate::gapi::GScalar)>> for (crate::gapi::GMat, crate::gapi::GScalar) {
+		#[inline]
+		fn from(s: core::Tuple<(crate::gapi::GMat, crate::gapi::GScalar)>) -> Self {
+			s.into_tuple()
+		}
+	}
+	
+	impl From<(crate::gapi::GMat, crate::gapi::GScalar)> for core::Tuple<(crate::gapi::GMat, crate::gapi::GScalar)> {
+		#[inline]
+		fn from(s: (crate::gapi::GMat, crate::gapi::GScalar)) -> Self {
+			Self::new(s)
+		}
+	}
+	
 	pub type VectorOfGArg = core::Vector<crate::gapi::GArg>;
 	
 	impl core::Vector<crate::gapi::GArg> {
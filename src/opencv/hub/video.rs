@@ -47,7 +47,7 @@ pub const OPTFLOW_USE_INITIAL_FLOW: i32 = 4;
 /// *   (Python) A sample explaining the camshift tracking algorithm can be found at
 ///    opencv_source_code/samples/python/camshift.py
 #[inline]
-pub fn cam_shift(prob_image: &dyn core::ToInputArray, window: &mut core::Rect, criteria: core::TermCriteria) -> Result<core::RotatedRect> {
+pub fn cam_shift(prob_image: &(impl core::ToInputArray + ?Sized), window: &mut core::Rect, criteria: core::TermCriteria) -> Result<core::RotatedRect> {
 	input_array_arg!(prob_image);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_CamShift_const__InputArrayR_RectR_TermCriteria(prob_image.as_raw__InputArray(), window, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
@@ -80,7 +80,7 @@ pub fn cam_shift(prob_image: &dyn core::ToInputArray, window: &mut core::Rect, c
 /// * deriv_border: BORDER_CONSTANT
 /// * try_reuse_input_image: true
 #[inline]
-pub fn build_optical_flow_pyramid(img: &dyn core::ToInputArray, pyramid: &mut dyn core::ToOutputArray, win_size: core::Size, max_level: i32, with_derivatives: bool, pyr_border: i32, deriv_border: i32, try_reuse_input_image: bool) -> Result<i32> {
+pub fn build_optical_flow_pyramid(img: &(impl core::ToInputArray + ?Sized), pyramid: &mut (impl core::ToOutputArray + ?Sized), win_size: core::Size, max_level: i32, with_derivatives: bool, pyr_border: i32, deriv_border: i32, try_reuse_input_image: bool) -> Result<i32> {
 	input_array_arg!(img);
 	output_array_arg!(pyramid);
 	return_send!(via ocvrs_return);
@@ -130,7 +130,7 @@ pub fn build_optical_flow_pyramid(img: &dyn core::ToInputArray, pyramid: &mut dy
 /// *   (Python) An example using the optical flow algorithm described by Gunnar Farneback can be
 ///    found at opencv_source_code/samples/python/opt_flow.py
 #[inline]
-pub fn calc_optical_flow_farneback(prev: &dyn core::ToInputArray, next: &dyn core::ToInputArray, flow: &mut dyn core::ToInputOutputArray, pyr_scale: f64, levels: i32, winsize: i32, iterations: i32, poly_n: i32, poly_sigma: f64, flags: i32) -> Result<()> {
+pub fn calc_optical_flow_farneback(prev: &(impl core::ToInputArray + ?Sized), next: &(impl core::ToInputArray + ?Sized), flow: &mut (impl core::ToInputOutputArray + ?Sized), pyr_scale: f64, levels: i32, winsize: i32, iterations: i32, poly_n: i32, poly_sigma: f64, flags: i32) -> Result<()> {
 	input_array_arg!(prev);
 	input_array_arg!(next);
 	input_output_array_arg!(flow);
@@ -197,7 +197,7 @@ pub fn calc_optical_flow_farneback(prev: &dyn core::ToInputArray, next: &dyn cor
 /// * flags: 0
 /// * min_eig_threshold: 1e-4
 #[inline]
-pub fn calc_optical_flow_pyr_lk(prev_img: &dyn core::ToInputArray, next_img: &dyn core::ToInputArray, prev_pts: &dyn core::ToInputArray, next_pts: &mut dyn core::ToInputOutputArray, status: &mut dyn core::ToOutputArray, err: &mut dyn core::ToOutputArray, win_size: core::Size, max_level: i32, criteria: core::TermCriteria, flags: i32, min_eig_threshold: f64) -> Result<()> {
+pub fn calc_optical_flow_pyr_lk(prev_img: &(impl core::ToInputArray + ?Sized), next_img: &(impl core::ToInputArray + ?Sized), prev_pts: &(impl core::ToInputArray + ?Sized), next_pts: &mut (impl core::ToInputOutputArray + ?Sized), status: &mut (impl core::ToOutputArray + ?Sized), err: &mut (impl core::ToOutputArray + ?Sized), win_size: core::Size, max_level: i32, criteria: core::TermCriteria, flags: i32, min_eig_threshold: f64) -> Result<()> {
 	input_array_arg!(prev_img);
 	input_array_arg!(next_img);
 	input_array_arg!(prev_pts);
@@ -224,10 +224,10 @@ pub fn calc_optical_flow_pyr_lk(prev_img: &dyn core::ToInputArray, next_img: &dy
 /// ## C++ default parameters
 /// * input_mask: noArray()
 #[inline]
-pub fn compute_ecc(template_image: &dyn core::ToInputArray, input_image: &dyn core::ToInputArray, input_mask: &dyn core::ToInputArray) -> Result<f64> {
+pub fn compute_ecc(template_image: &(impl core::ToInputArray + ?Sized), input_image: &(impl core::ToInputArray + ?Sized), input_mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<f64> {
 	input_array_arg!(template_image);
 	input_array_arg!(input_image);
-	input_array_arg!(input_mask);
+	input_array_arg!(nullable input_mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_computeECC_const__InputArrayR_const__InputArrayR_const__InputArrayR(template_image.as_raw__InputArray(), input_image.as_raw__InputArray(), input_mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -314,7 +314,7 @@ pub fn create_background_subtractor_mog2(history: i32, var_threshold: f64, detec
 /// estimateAffine2D, estimateAffinePartial2D, getAffineTransform, getPerspectiveTransform, findHomography
 #[deprecated = "Use cv::estimateAffine2D, cv::estimateAffinePartial2D instead. If you are using this function"]
 #[inline]
-pub fn estimate_rigid_transform(src: &dyn core::ToInputArray, dst: &dyn core::ToInputArray, full_affine: bool) -> Result<core::Mat> {
+pub fn estimate_rigid_transform(src: &(impl core::ToInputArray + ?Sized), dst: &(impl core::ToInputArray + ?Sized), full_affine: bool) -> Result<core::Mat> {
 	input_array_arg!(src);
 	input_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -383,11 +383,11 @@ pub fn estimate_rigid_transform(src: &dyn core::ToInputArray, dst: &dyn core::To
 /// * criteria: TermCriteria(TermCriteria::COUNT+TermCriteria::EPS,50,0.001)
 /// * input_mask: noArray()
 #[inline]
-pub fn find_transform_ecc_1(template_image: &dyn core::ToInputArray, input_image: &dyn core::ToInputArray, warp_matrix: &mut dyn core::ToInputOutputArray, motion_type: i32, criteria: core::TermCriteria, input_mask: &dyn core::ToInputArray) -> Result<f64> {
+pub fn find_transform_ecc_1(template_image: &(impl core::ToInputArray + ?Sized), input_image: &(impl core::ToInputArray + ?Sized), warp_matrix: &mut (impl core::ToInputOutputArray + ?Sized), motion_type: i32, criteria: core::TermCriteria, input_mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<f64> {
 	input_array_arg!(template_image);
 	input_array_arg!(input_image);
 	input_output_array_arg!(warp_matrix);
-	input_array_arg!(input_mask);
+	input_array_arg!(nullable input_mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_findTransformECC_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_int_TermCriteria_const__InputArrayR(template_image.as_raw__InputArray(), input_image.as_raw__InputArray(), warp_matrix.as_raw__InputOutputArray(), motion_type, criteria.opencv_as_extern(), input_mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -446,7 +446,7 @@ pub fn find_transform_ecc_1(template_image: &dyn core::ToInputArray, input_image
 /// ## See also
 /// computeECC, estimateAffine2D, estimateAffinePartial2D, findHomography
 #[inline]
-pub fn find_transform_ecc(template_image: &dyn core::ToInputArray, input_image: &dyn core::ToInputArray, warp_matrix: &mut dyn core::ToInputOutputArray, motion_type: i32, criteria: core::TermCriteria, input_mask: &dyn core::ToInputArray, gauss_filt_size: i32) -> Result<f64> {
+pub fn find_transform_ecc(template_image: &(impl core::ToInputArray + ?Sized), input_image: &(impl core::ToInputArray + ?Sized), warp_matrix: &mut (impl core::ToInputOutputArray + ?Sized), motion_type: i32, criteria: core::TermCriteria, input_mask: &(impl core::ToInputArray + ?Sized), gauss_filt_size: i32) -> Result<f64> {
 	input_array_arg!(template_image);
 	input_array_arg!(input_image);
 	input_output_array_arg!(warp_matrix);
@@ -477,7 +477,7 @@ pub fn find_transform_ecc(template_image: &dyn core::ToInputArray, input_image:
 /// with findContours , throwing away contours with small area ( contourArea ), and rendering the
 /// remaining contours with drawContours.
 #[inline]
-pub fn mean_shift(prob_image: &dyn core::ToInputArray, window: &mut core::Rect, criteria: core::TermCriteria) -> Result<i32> {
+pub fn mean_shift(prob_image: &(impl core::ToInputArray + ?Sized), window: &mut core::Rect, criteria: core::TermCriteria) -> Result<i32> {
 	input_array_arg!(prob_image);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_meanShift_const__InputArrayR_RectR_TermCriteria(prob_image.as_raw__InputArray(), window, criteria.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
@@ -515,7 +515,7 @@ pub fn read_optical_flow(path: &str) -> Result<core::Mat> {
 /// The flow field must be a 2-channel, floating-point matrix (CV_32FC2). First channel corresponds
 /// to the flow in the horizontal direction (u), second - vertical (v).
 #[inline]
-pub fn write_optical_flow(path: &str, flow: &dyn core::ToInputArray) -> Result<bool> {
+pub fn write_optical_flow(path: &str, flow: &(impl core::ToInputArray + ?Sized)) -> Result<bool> {
 	extern_container_arg!(path);
 	input_array_arg!(flow);
 	return_send!(via ocvrs_return);
@@ -1593,6 +1593,14 @@ pub trait KalmanFilterTraitConst {
 		ret
 	}
 	
+	/// Same as `state_pre()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn state_pre_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_KalmanFilter_getPropStatePreRef_const(self.as_raw_KalmanFilter()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// corrected state (x(k)): x(k)=x'(k)+K(k)*(z(k)-H*x'(k))
 	#[inline]
 	fn state_post(&self) -> core::Mat {
@@ -1601,6 +1609,14 @@ pub trait KalmanFilterTraitConst {
 		ret
 	}
 	
+	/// Same as `state_post()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn state_post_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_KalmanFilter_getPropStatePostRef_const(self.as_raw_KalmanFilter()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// state transition matrix (A)
 	#[inline]
 	fn transition_matrix(&self) -> core::Mat {
@@ -1609,6 +1625,14 @@ pub trait KalmanFilterTraitConst {
 		ret
 	}
 	
+	/// Same as `transition_matrix()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn transition_matrix_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_KalmanFilter_getPropTransitionMatrixRef_const(self.as_raw_KalmanFilter()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// control matrix (B) (not used if there is no control)
 	#[inline]
 	fn control_matrix(&self) -> core::Mat {
@@ -1617,6 +1641,14 @@ pub trait KalmanFilterTraitConst {
 		ret
 	}
 	
+	/// Same as `control_matrix()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn control_matrix_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_KalmanFilter_getPropControlMatrixRef_const(self.as_raw_KalmanFilter()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// measurement matrix (H)
 	#[inline]
 	fn measurement_matrix(&self) -> core::Mat {
@@ -1625,6 +1657,14 @@ pub trait KalmanFilterTraitConst {
 		ret
 	}
 	
+	/// Same as `measurement_matrix()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn measurement_matrix_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_KalmanFilter_getPropMeasurementMatrixRef_const(self.as_raw_KalmanFilter()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// process noise covariance matrix (Q)
 	#[inline]
 	fn process_noise_cov(&self) -> core::Mat {
@@ -1633,6 +1673,14 @@ pub trait KalmanFilterTraitConst {
 		ret
 	}
 	
+	/// Same as `process_noise_cov()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn process_noise_cov_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_KalmanFilter_getPropProcessNoiseCovRef_const(self.as_raw_KalmanFilter()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// measurement noise covariance matrix (R)
 	#[inline]
 	fn measurement_noise_cov(&self) -> core::Mat {
@@ -1641,6 +1689,14 @@ pub trait KalmanFilterTraitConst {
 		ret
 	}
 	
+	/// Same as `measurement_noise_cov()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn measurement_noise_cov_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_KalmanFilter_getPropMeasurementNoiseCovRef_const(self.as_raw_KalmanFilter()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// priori error estimate covariance matrix (P'(k)): P'(k)=A*P(k-1)*At + Q)
 	#[inline]
 	fn error_cov_pre(&self) -> core::Mat {
@@ -1649,6 +1705,14 @@ pub trait KalmanFilterTraitConst {
 		ret
 	}
 	
+	/// Same as `error_cov_pre()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn error_cov_pre_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_KalmanFilter_getPropErrorCovPreRef_const(self.as_raw_KalmanFilter()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// Kalman gain matrix (K(k)): K(k)=P'(k)*Ht*inv(H*P'(k)*Ht+R)
 	#[inline]
 	fn gain(&self) -> core::Mat {
@@ -1657,6 +1721,14 @@ pub trait KalmanFilterTraitConst {
 		ret
 	}
 	
+	/// Same as `gain()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn gain_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_KalmanFilter_getPropGainRef_const(self.as_raw_KalmanFilter()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// posteriori error estimate covariance matrix (P(k)): P(k)=(I-K(k)*H)*P'(k)
 	#[inline]
 	fn error_cov_post(&self) -> core::Mat {
@@ -1665,6 +1737,14 @@ pub trait KalmanFilterTraitConst {
 		ret
 	}
 	
+	/// Same as `error_cov_post()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn error_cov_post_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_KalmanFilter_getPropErrorCovPostRef_const(self.as_raw_KalmanFilter()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn temp1(&self) -> core::Mat {
 		let ret = unsafe { sys::cv_KalmanFilter_getPropTemp1_const(self.as_raw_KalmanFilter()) };
@@ -1672,6 +1752,14 @@ pub trait KalmanFilterTraitConst {
 		ret
 	}
 	
+	/// Same as `temp1()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn temp1_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_KalmanFilter_getPropTemp1Ref_const(self.as_raw_KalmanFilter()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn temp2(&self) -> core::Mat {
 		let ret = unsafe { sys::cv_KalmanFilter_getPropTemp2_const(self.as_raw_KalmanFilter()) };
@@ -1679,6 +1767,14 @@ pub trait KalmanFilterTraitConst {
 		ret
 	}
 	
+	/// Same as `temp2()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn temp2_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_KalmanFilter_getPropTemp2Ref_const(self.as_raw_KalmanFilter()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn temp3(&self) -> core::Mat {
 		let ret = unsafe { sys::cv_KalmanFilter_getPropTemp3_const(self.as_raw_KalmanFilter()) };
@@ -1686,6 +1782,14 @@ pub trait KalmanFilterTraitConst {
 		ret
 	}
 	
+	/// Same as `temp3()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn temp3_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_KalmanFilter_getPropTemp3Ref_const(self.as_raw_KalmanFilter()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn temp4(&self) -> core::Mat {
 		let ret = unsafe { sys::cv_KalmanFilter_getPropTemp4_const(self.as_raw_KalmanFilter()) };
@@ -1693,6 +1797,14 @@ pub trait KalmanFilterTraitConst {
 		ret
 	}
 	
+	/// Same as `temp4()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn temp4_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_KalmanFilter_getPropTemp4Ref_const(self.as_raw_KalmanFilter()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn temp5(&self) -> core::Mat {
 		let ret = unsafe { sys::cv_KalmanFilter_getPropTemp5_const(self.as_raw_KalmanFilter()) };
@@ -1700,6 +1812,14 @@ pub trait KalmanFilterTraitConst {
 		ret
 	}
 	
+	/// Same as `temp5()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn temp5_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_KalmanFilter_getPropTemp5Ref_const(self.as_raw_KalmanFilter()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 }
 
 pub trait KalmanFilterTrait: crate::video::KalmanFilterTraitConst {
@@ -1833,9 +1953,9 @@ pub trait KalmanFilterTrait: crate::video::KalmanFilterTraitConst {
 	/// ## C++ default parameters
 	/// * control: Mat()
 	#[inline]
-	fn predict(&mut self, control: &core::Mat) -> Result<core::Mat> {
+	fn predict(&mut self, control: Option<&core::Mat>) -> Result<core::Mat> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_KalmanFilter_predict_const_MatR(self.as_raw_mut_KalmanFilter(), control.as_raw_Mat(), ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_KalmanFilter_predict_const_MatR(self.as_raw_mut_KalmanFilter(), control.map_or(::core::ptr::null(), |control| control.as_raw_Mat()), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Mat::opencv_from_extern(ret) };
@@ -1945,13 +2065,13 @@ pub trait SparseOpticalFlow: core::AlgorithmTrait + crate::video::SparseOpticalF
 	/// ## C++ default parameters
 	/// * err: cv::noArray()
 	#[inline]
-	fn calc(&mut self, prev_img: &dyn core::ToInputArray, next_img: &dyn core::ToInputArray, prev_pts: &dyn core::ToInputArray, next_pts: &mut dyn core::ToInputOutputArray, status: &mut dyn core::ToOutputArray, err: &mut dyn core::ToOutputArray) -> Result<()> {
+	fn calc(&mut self, prev_img: &dyn core::ToInputArray, next_img: &dyn core::ToInputArray, prev_pts: &dyn core::ToInputArray, next_pts: &mut dyn core::ToInputOutputArray, status: &mut dyn core::ToOutputArray, err: Option<&mut dyn core::ToOutputArray>) -> Result<()> {
 		input_array_arg!(prev_img);
 		input_array_arg!(next_img);
 		input_array_arg!(prev_pts);
 		input_output_array_arg!(next_pts);
 		output_array_arg!(status);
-		output_array_arg!(err);
+		output_array_arg!(nullable err);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_SparseOpticalFlow_calc_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputOutputArrayR_const__OutputArrayR_const__OutputArrayR(self.as_raw_mut_SparseOpticalFlow(), prev_img.as_raw__InputArray(), next_img.as_raw__InputArray(), prev_pts.as_raw__InputArray(), next_pts.as_raw__InputOutputArray(), status.as_raw__OutputArray(), err.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -2158,9 +2278,9 @@ impl dyn TrackerDaSiamRPN + '_ {
 	/// ## C++ default parameters
 	/// * parameters: TrackerDaSiamRPN::Params()
 	#[inline]
-	pub fn create(parameters: &crate::video::TrackerDaSiamRPN_Params) -> Result<core::Ptr<dyn crate::video::TrackerDaSiamRPN>> {
+	pub fn create(parameters: Option<&crate::video::TrackerDaSiamRPN_Params>) -> Result<core::Ptr<dyn crate::video::TrackerDaSiamRPN>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_TrackerDaSiamRPN_create_const_ParamsR(parameters.as_raw_TrackerDaSiamRPN_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_TrackerDaSiamRPN_create_const_ParamsR(parameters.map_or(::core::ptr::null(), |parameters| parameters.as_raw_TrackerDaSiamRPN_Params()), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::video::TrackerDaSiamRPN>::opencv_from_extern(ret) };
@@ -2178,6 +2298,14 @@ pub trait TrackerDaSiamRPN_ParamsTraitConst {
 		ret
 	}
 	
+	/// Same as `model()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn model_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_TrackerDaSiamRPN_Params_getPropModelRef_const(self.as_raw_TrackerDaSiamRPN_Params()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	#[inline]
 	fn kernel_cls1(&self) -> String {
 		let ret = unsafe { sys::cv_TrackerDaSiamRPN_Params_getPropKernel_cls1_const(self.as_raw_TrackerDaSiamRPN_Params()) };
@@ -2185,6 +2313,14 @@ pub trait TrackerDaSiamRPN_ParamsTraitConst {
 		ret
 	}
 	
+	/// Same as `kernel_cls1()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn kernel_cls1_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_TrackerDaSiamRPN_Params_getPropKernel_cls1Ref_const(self.as_raw_TrackerDaSiamRPN_Params()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	#[inline]
 	fn kernel_r1(&self) -> String {
 		let ret = unsafe { sys::cv_TrackerDaSiamRPN_Params_getPropKernel_r1_const(self.as_raw_TrackerDaSiamRPN_Params()) };
@@ -2192,6 +2328,14 @@ pub trait TrackerDaSiamRPN_ParamsTraitConst {
 		ret
 	}
 	
+	/// Same as `kernel_r1()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn kernel_r1_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_TrackerDaSiamRPN_Params_getPropKernel_r1Ref_const(self.as_raw_TrackerDaSiamRPN_Params()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	#[inline]
 	fn backend(&self) -> i32 {
 		let ret = unsafe { sys::cv_TrackerDaSiamRPN_Params_getPropBackend_const(self.as_raw_TrackerDaSiamRPN_Params()) };
@@ -2312,9 +2456,9 @@ impl dyn TrackerGOTURN + '_ {
 	/// ## C++ default parameters
 	/// * parameters: TrackerGOTURN::Params()
 	#[inline]
-	pub fn create(parameters: &crate::video::TrackerGOTURN_Params) -> Result<core::Ptr<dyn crate::video::TrackerGOTURN>> {
+	pub fn create(parameters: Option<&crate::video::TrackerGOTURN_Params>) -> Result<core::Ptr<dyn crate::video::TrackerGOTURN>> {
 		return_send!(via ocvrs_return);
-		unsafe { sys::cv_TrackerGOTURN_create_const_ParamsR(parameters.as_raw_TrackerGOTURN_Params(), ocvrs_return.as_mut_ptr()) };
+		unsafe { sys::cv_TrackerGOTURN_create_const_ParamsR(parameters.map_or(::core::ptr::null(), |parameters| parameters.as_raw_TrackerGOTURN_Params()), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
 		let ret = ret.into_result()?;
 		let ret = unsafe { core::Ptr::<dyn crate::video::TrackerGOTURN>::opencv_from_extern(ret) };
@@ -2332,6 +2476,14 @@ pub trait TrackerGOTURN_ParamsTraitConst {
 		ret
 	}
 	
+	/// Same as `model_txt()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn model_txt_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_TrackerGOTURN_Params_getPropModelTxtRef_const(self.as_raw_TrackerGOTURN_Params()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	#[inline]
 	fn model_bin(&self) -> String {
 		let ret = unsafe { sys::cv_TrackerGOTURN_Params_getPropModelBin_const(self.as_raw_TrackerGOTURN_Params()) };
@@ -2339,6 +2491,14 @@ pub trait TrackerGOTURN_ParamsTraitConst {
 		ret
 	}
 	
+	/// Same as `model_bin()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn model_bin_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_TrackerGOTURN_Params_getPropModelBinRef_const(self.as_raw_TrackerGOTURN_Params()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 }
 
 pub trait TrackerGOTURN_ParamsTrait: crate::video::TrackerGOTURN_ParamsTraitConst {
@@ -2656,4 +2816,5 @@ impl dyn VariationalRefinement + '_ {
 		Ok(ret)
 	}
 	
-}
\ No newline at end of file
+}
+pub use crate::manual::video::*;
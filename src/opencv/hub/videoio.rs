@@ -1490,8 +1490,8 @@ impl VideoCapture {
 	/// ## C++ default parameters
 	/// * api_preference: CAP_ANY
 	#[inline]
-	pub fn from_file(filename: &str, api_preference: i32) -> Result<crate::videoio::VideoCapture> {
-		extern_container_arg!(filename);
+	pub fn from_file(filename: impl AsRef<Path>, api_preference: i32) -> Result<crate::videoio::VideoCapture> {
+		path_arg!(filename);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_VideoCapture_VideoCapture_const_StringR_int(filename.opencv_as_extern(), api_preference, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -1513,8 +1513,8 @@ impl VideoCapture {
 	///    The `params` parameter allows to specify extra parameters encoded as pairs `(paramId_1, paramValue_1, paramId_2, paramValue_2, ...)`.
 	///    See cv::VideoCaptureProperties
 	#[inline]
-	pub fn from_file_with_params(filename: &str, api_preference: i32, params: &core::Vector<i32>) -> Result<crate::videoio::VideoCapture> {
-		extern_container_arg!(filename);
+	pub fn from_file_with_params(filename: impl AsRef<Path>, api_preference: i32, params: &core::Vector<i32>) -> Result<crate::videoio::VideoCapture> {
+		path_arg!(filename);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_VideoCapture_VideoCapture_const_StringR_int_const_vectorLintGR(filename.opencv_as_extern(), api_preference, params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -1867,8 +1867,8 @@ impl VideoWriter {
 	/// ## C++ default parameters
 	/// * is_color: true
 	#[inline]
-	pub fn new(filename: &str, fourcc: i32, fps: f64, frame_size: core::Size, is_color: bool) -> Result<crate::videoio::VideoWriter> {
-		extern_container_arg!(filename);
+	pub fn new(filename: impl AsRef<Path>, fourcc: i32, fps: f64, frame_size: core::Size, is_color: bool) -> Result<crate::videoio::VideoWriter> {
+		path_arg!(filename);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_VideoWriter_VideoWriter_const_StringR_int_double_Size_bool(filename.opencv_as_extern(), fourcc, fps, frame_size.opencv_as_extern(), is_color, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -1892,8 +1892,8 @@ impl VideoWriter {
 	/// ## C++ default parameters
 	/// * is_color: true
 	#[inline]
-	pub fn new_with_backend(filename: &str, api_preference: i32, fourcc: i32, fps: f64, frame_size: core::Size, is_color: bool) -> Result<crate::videoio::VideoWriter> {
-		extern_container_arg!(filename);
+	pub fn new_with_backend(filename: impl AsRef<Path>, api_preference: i32, fourcc: i32, fps: f64, frame_size: core::Size, is_color: bool) -> Result<crate::videoio::VideoWriter> {
+		path_arg!(filename);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_VideoWriter_VideoWriter_const_StringR_int_int_double_Size_bool(filename.opencv_as_extern(), api_preference, fourcc, fps, frame_size.opencv_as_extern(), is_color, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -1914,8 +1914,8 @@ impl VideoWriter {
 	///      * The `params` parameter allows to specify extra encoder parameters encoded as pairs (paramId_1, paramValue_1, paramId_2, paramValue_2, ... .)
 	///      * see cv::VideoWriterProperties
 	#[inline]
-	pub fn new_1(filename: &str, fourcc: i32, fps: f64, frame_size: core::Size, params: &core::Vector<i32>) -> Result<crate::videoio::VideoWriter> {
-		extern_container_arg!(filename);
+	pub fn new_1(filename: impl AsRef<Path>, fourcc: i32, fps: f64, frame_size: core::Size, params: &core::Vector<i32>) -> Result<crate::videoio::VideoWriter> {
+		path_arg!(filename);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_VideoWriter_VideoWriter_const_StringR_int_double_const_SizeR_const_vectorLintGR(filename.opencv_as_extern(), fourcc, fps, &frame_size, params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -1933,8 +1933,8 @@ impl VideoWriter {
 	/// 
 	/// ## Overloaded parameters
 	#[inline]
-	pub fn new_2(filename: &str, api_preference: i32, fourcc: i32, fps: f64, frame_size: core::Size, params: &core::Vector<i32>) -> Result<crate::videoio::VideoWriter> {
-		extern_container_arg!(filename);
+	pub fn new_2(filename: impl AsRef<Path>, api_preference: i32, fourcc: i32, fps: f64, frame_size: core::Size, params: &core::Vector<i32>) -> Result<crate::videoio::VideoWriter> {
+		path_arg!(filename);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_VideoWriter_VideoWriter_const_StringR_int_int_double_const_SizeR_const_vectorLintGR(filename.opencv_as_extern(), api_preference, fourcc, fps, &frame_size, params.as_raw_VectorOfi32(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
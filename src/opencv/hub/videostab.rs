@@ -115,7 +115,7 @@ pub fn ensure_inclusion_constraint(m: &core::Mat, size: core::Size, trim_ratio:
 /// * model: MM_AFFINE
 /// * rmse: 0
 #[inline]
-pub fn estimate_global_motion_least_squares(points0: &mut dyn core::ToInputOutputArray, points1: &mut dyn core::ToInputOutputArray, model: i32, rmse: &mut f32) -> Result<core::Mat> {
+pub fn estimate_global_motion_least_squares(points0: &mut (impl core::ToInputOutputArray + ?Sized), points1: &mut (impl core::ToInputOutputArray + ?Sized), model: i32, rmse: &mut f32) -> Result<core::Mat> {
 	input_output_array_arg!(points0);
 	input_output_array_arg!(points1);
 	return_send!(via ocvrs_return);
@@ -142,7 +142,7 @@ pub fn estimate_global_motion_least_squares(points0: &mut dyn core::ToInputOutpu
 /// * rmse: 0
 /// * ninliers: 0
 #[inline]
-pub fn estimate_global_motion_ransac(points0: &dyn core::ToInputArray, points1: &dyn core::ToInputArray, model: i32, params: &crate::videostab::RansacParams, rmse: &mut f32, ninliers: &mut i32) -> Result<core::Mat> {
+pub fn estimate_global_motion_ransac(points0: &(impl core::ToInputArray + ?Sized), points1: &(impl core::ToInputArray + ?Sized), model: i32, params: &crate::videostab::RansacParams, rmse: &mut f32, ninliers: &mut i32) -> Result<core::Mat> {
 	input_array_arg!(points0);
 	input_array_arg!(points1);
 	return_send!(via ocvrs_return);
@@ -4143,3 +4143,4 @@ pub trait WobbleSuppressorBase: crate::videostab::WobbleSuppressorBaseConst {
 	}
 	
 }
+pub use crate::manual::videostab::*;
@@ -143,7 +143,7 @@ pub type Viz3d_MouseCallback = Option<Box<dyn FnMut(*const c_void) -> () + Send
 /// * mesh: Input mesh.
 /// * normals: Normals at very point in the mesh of type CV_64FC3.
 #[inline]
-pub fn compute_normals(mesh: &crate::viz::Mesh, normals: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn compute_normals(mesh: &crate::viz::Mesh, normals: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	output_array_arg!(normals);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_viz_computeNormals_const_MeshR_const__OutputArrayR(mesh.as_raw_Mesh(), normals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
@@ -178,7 +178,7 @@ pub fn get_window_by_name(window_name: &str) -> Result<crate::viz::Viz3d> {
 /// ## C++ default parameters
 /// * window_size: Size(-1,-1)
 #[inline]
-pub fn imshow(window_name: &str, image: &dyn core::ToInputArray, window_size: core::Size) -> Result<crate::viz::Viz3d> {
+pub fn imshow(window_name: &str, image: &(impl core::ToInputArray + ?Sized), window_size: core::Size) -> Result<crate::viz::Viz3d> {
 	extern_container_arg!(window_name);
 	input_array_arg!(image);
 	return_send!(via ocvrs_return);
@@ -244,10 +244,10 @@ pub fn make_transform_to_global(axis_x: core::Vec3d, axis_y: core::Vec3d, axis_z
 /// * colors: noArray()
 /// * normals: noArray()
 #[inline]
-pub fn read_cloud(file: &str, colors: &mut dyn core::ToOutputArray, normals: &mut dyn core::ToOutputArray) -> Result<core::Mat> {
+pub fn read_cloud(file: &str, colors: Option<&mut (impl core::ToOutputArray + ?Sized)>, normals: Option<&mut (impl core::ToOutputArray + ?Sized)>) -> Result<core::Mat> {
 	extern_container_arg!(file);
-	output_array_arg!(colors);
-	output_array_arg!(normals);
+	output_array_arg!(nullable colors);
+	output_array_arg!(nullable normals);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_viz_readCloud_const_StringR_const__OutputArrayR_const__OutputArrayR(file.opencv_as_extern(), colors.as_raw__OutputArray(), normals.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -305,7 +305,7 @@ pub fn read_pose(file: &str, pose: &mut core::Affine3d, tag: &str) -> Result<boo
 /// * end: INT_MAX
 /// * tag: "pose"
 #[inline]
-pub fn read_trajectory(traj: &mut dyn core::ToOutputArray, files_format: &str, start: i32, end: i32, tag: &str) -> Result<()> {
+pub fn read_trajectory(traj: &mut (impl core::ToOutputArray + ?Sized), files_format: &str, start: i32, end: i32, tag: &str) -> Result<()> {
 	output_array_arg!(traj);
 	extern_container_arg!(files_format);
 	extern_container_arg!(tag);
@@ -339,11 +339,11 @@ pub fn unregister_all_windows() -> Result<()> {
 /// * normals: noArray()
 /// * binary: false
 #[inline]
-pub fn write_cloud(file: &str, cloud: &dyn core::ToInputArray, colors: &dyn core::ToInputArray, normals: &dyn core::ToInputArray, binary: bool) -> Result<()> {
+pub fn write_cloud(file: &str, cloud: &(impl core::ToInputArray + ?Sized), colors: Option<&(impl core::ToInputArray + ?Sized)>, normals: Option<&(impl core::ToInputArray + ?Sized)>, binary: bool) -> Result<()> {
 	extern_container_arg!(file);
 	input_array_arg!(cloud);
-	input_array_arg!(colors);
-	input_array_arg!(normals);
+	input_array_arg!(nullable colors);
+	input_array_arg!(nullable normals);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_viz_writeCloud_const_StringR_const__InputArrayR_const__InputArrayR_const__InputArrayR_bool(file.opencv_as_extern(), cloud.as_raw__InputArray(), colors.as_raw__InputArray(), normals.as_raw__InputArray(), binary, ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -385,7 +385,7 @@ pub fn write_pose(file: &str, pose: core::Affine3d, tag: &str) -> Result<()> {
 /// * start: 0
 /// * tag: "pose"
 #[inline]
-pub fn write_trajectory(traj: &dyn core::ToInputArray, files_format: &str, start: i32, tag: &str) -> Result<()> {
+pub fn write_trajectory(traj: &(impl core::ToInputArray + ?Sized), files_format: &str, start: i32, tag: &str) -> Result<()> {
 	input_array_arg!(traj);
 	extern_container_arg!(files_format);
 	extern_container_arg!(tag);
@@ -1101,6 +1101,14 @@ pub trait KeyboardEventTraitConst {
 		ret
 	}
 	
+	/// Same as `symbol()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn symbol_ref(&self) -> Result<&str> {
+		let ret = unsafe { sys::cv_viz_KeyboardEvent_getPropSymbolRef_const(self.as_raw_KeyboardEvent()) };
+		let ret = unsafe { crate::templ::receive_string_ref(ret) }?;
+		Ok(ret)
+	}
+	
 	#[inline]
 	fn code(&self) -> u8 {
 		let ret = unsafe { sys::cv_viz_KeyboardEvent_getPropCode_const(self.as_raw_KeyboardEvent()) };
@@ -1202,6 +1210,14 @@ pub trait MeshTraitConst {
 		ret
 	}
 	
+	/// Same as `cloud()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn cloud_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_viz_Mesh_getPropCloudRef_const(self.as_raw_Mesh()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// point color of type CV_8UC3 or CV_8UC4 with only 1 row
 	#[inline]
 	fn colors(&self) -> core::Mat {
@@ -1210,6 +1226,14 @@ pub trait MeshTraitConst {
 		ret
 	}
 	
+	/// Same as `colors()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn colors_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_viz_Mesh_getPropColorsRef_const(self.as_raw_Mesh()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// point normals of type CV_32FC3, CV_32FC4, CV_64FC3 or CV_64FC4 with only 1 row
 	#[inline]
 	fn normals(&self) -> core::Mat {
@@ -1218,6 +1242,14 @@ pub trait MeshTraitConst {
 		ret
 	}
 	
+	/// Same as `normals()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn normals_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_viz_Mesh_getPropNormalsRef_const(self.as_raw_Mesh()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// CV_32SC1 with only 1 row
 	#[inline]
 	fn polygons(&self) -> core::Mat {
@@ -1226,6 +1258,14 @@ pub trait MeshTraitConst {
 		ret
 	}
 	
+	/// Same as `polygons()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn polygons_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_viz_Mesh_getPropPolygonsRef_const(self.as_raw_Mesh()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn texture(&self) -> core::Mat {
 		let ret = unsafe { sys::cv_viz_Mesh_getPropTexture_const(self.as_raw_Mesh()) };
@@ -1233,6 +1273,14 @@ pub trait MeshTraitConst {
 		ret
 	}
 	
+	/// Same as `texture()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn texture_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_viz_Mesh_getPropTextureRef_const(self.as_raw_Mesh()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// CV_32FC2 or CV_64FC2 with only 1 row
 	#[inline]
 	fn tcoords(&self) -> core::Mat {
@@ -1241,6 +1289,14 @@ pub trait MeshTraitConst {
 		ret
 	}
 	
+	/// Same as `tcoords()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn tcoords_ref(&self) -> BoxedRef<'_, core::Mat> {
+		let ret = unsafe { sys::cv_viz_Mesh_getPropTcoordsRef_const(self.as_raw_Mesh()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 }
 
 pub trait MeshTrait: crate::viz::MeshTraitConst {
@@ -1805,8 +1861,8 @@ pub trait Viz3dTrait: crate::viz::Viz3dTraitConst {
 	/// ## C++ default parameters
 	/// * image: noArray()
 	#[inline]
-	fn set_background_texture(&mut self, image: &dyn core::ToInputArray) -> Result<()> {
-		input_array_arg!(image);
+	fn set_background_texture(&mut self, image: Option<&dyn core::ToInputArray>) -> Result<()> {
+		input_array_arg!(nullable image);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_viz_Viz3d_setBackgroundTexture_const__InputArrayR(self.as_raw_mut_Viz3d(), image.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -2312,7 +2368,7 @@ impl WCameraPosition {
 	/// * scale: 1.0
 	/// * color: Color::white()
 	#[inline]
-	pub fn new_3(k: core::Matx33d, image: &dyn core::ToInputArray, scale: f64, color: &crate::viz::Color) -> Result<crate::viz::WCameraPosition> {
+	pub fn new_3(k: core::Matx33d, image: &(impl core::ToInputArray + ?Sized), scale: f64, color: &crate::viz::Color) -> Result<crate::viz::WCameraPosition> {
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_viz_WCameraPosition_WCameraPosition_const_Matx33dR_const__InputArrayR_double_const_ColorR(&k, image.as_raw__InputArray(), scale, color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
@@ -2339,7 +2395,7 @@ impl WCameraPosition {
 	/// * scale: 1.0
 	/// * color: Color::white()
 	#[inline]
-	pub fn new_4(fov: core::Vec2d, image: &dyn core::ToInputArray, scale: f64, color: &crate::viz::Color) -> Result<crate::viz::WCameraPosition> {
+	pub fn new_4(fov: core::Vec2d, image: &(impl core::ToInputArray + ?Sized), scale: f64, color: &crate::viz::Color) -> Result<crate::viz::WCameraPosition> {
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_viz_WCameraPosition_WCameraPosition_const_Vec2dR_const__InputArrayR_double_const_ColorR(&fov, image.as_raw__InputArray(), scale, color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
@@ -2521,7 +2577,7 @@ impl WCloud {
 	/// 
 	/// Points in the cloud belong to mask when they are set to (NaN, NaN, NaN).
 	#[inline]
-	pub fn new(cloud: &dyn core::ToInputArray, colors: &dyn core::ToInputArray) -> Result<crate::viz::WCloud> {
+	pub fn new(cloud: &(impl core::ToInputArray + ?Sized), colors: &(impl core::ToInputArray + ?Sized)) -> Result<crate::viz::WCloud> {
 		input_array_arg!(cloud);
 		input_array_arg!(colors);
 		return_send!(via ocvrs_return);
@@ -2542,7 +2598,7 @@ impl WCloud {
 	/// ## C++ default parameters
 	/// * color: Color::white()
 	#[inline]
-	pub fn new_1(cloud: &dyn core::ToInputArray, color: &crate::viz::Color) -> Result<crate::viz::WCloud> {
+	pub fn new_1(cloud: &(impl core::ToInputArray + ?Sized), color: &crate::viz::Color) -> Result<crate::viz::WCloud> {
 		input_array_arg!(cloud);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_viz_WCloud_WCloud_const__InputArrayR_const_ColorR(cloud.as_raw__InputArray(), color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
@@ -2560,7 +2616,7 @@ impl WCloud {
 	/// 
 	/// Points in the cloud belong to mask when they are set to (NaN, NaN, NaN).
 	#[inline]
-	pub fn new_2(cloud: &dyn core::ToInputArray, colors: &dyn core::ToInputArray, normals: &dyn core::ToInputArray) -> Result<crate::viz::WCloud> {
+	pub fn new_2(cloud: &(impl core::ToInputArray + ?Sized), colors: &(impl core::ToInputArray + ?Sized), normals: &(impl core::ToInputArray + ?Sized)) -> Result<crate::viz::WCloud> {
 		input_array_arg!(cloud);
 		input_array_arg!(colors);
 		input_array_arg!(normals);
@@ -2581,7 +2637,7 @@ impl WCloud {
 	/// Size and type should match with the cloud parameter.
 	/// Points in the cloud belong to mask when they are set to (NaN, NaN, NaN).
 	#[inline]
-	pub fn new_3(cloud: &dyn core::ToInputArray, color: &crate::viz::Color, normals: &dyn core::ToInputArray) -> Result<crate::viz::WCloud> {
+	pub fn new_3(cloud: &(impl core::ToInputArray + ?Sized), color: &crate::viz::Color, normals: &(impl core::ToInputArray + ?Sized)) -> Result<crate::viz::WCloud> {
 		input_array_arg!(cloud);
 		input_array_arg!(normals);
 		return_send!(via ocvrs_return);
@@ -2791,7 +2847,7 @@ impl WCloudNormals {
 	/// * scale: 0.1
 	/// * color: Color::white()
 	#[inline]
-	pub fn new(cloud: &dyn core::ToInputArray, normals: &dyn core::ToInputArray, level: i32, scale: f64, color: &crate::viz::Color) -> Result<crate::viz::WCloudNormals> {
+	pub fn new(cloud: &(impl core::ToInputArray + ?Sized), normals: &(impl core::ToInputArray + ?Sized), level: i32, scale: f64, color: &crate::viz::Color) -> Result<crate::viz::WCloudNormals> {
 		input_array_arg!(cloud);
 		input_array_arg!(normals);
 		return_send!(via ocvrs_return);
@@ -3327,7 +3383,7 @@ impl WImage3D {
 	/// * image: BGR or Gray-Scale image.
 	/// * size: Size of the image.
 	#[inline]
-	pub fn new(image: &dyn core::ToInputArray, size: core::Size2d) -> Result<crate::viz::WImage3D> {
+	pub fn new(image: &(impl core::ToInputArray + ?Sized), size: core::Size2d) -> Result<crate::viz::WImage3D> {
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_viz_WImage3D_WImage3D_const__InputArrayR_const_Size2dR(image.as_raw__InputArray(), &size, ocvrs_return.as_mut_ptr()) };
@@ -3346,7 +3402,7 @@ impl WImage3D {
 	/// * normal: Normal of the plane that represents the image.
 	/// * up_vector: Determines orientation of the image.
 	#[inline]
-	pub fn new_1(image: &dyn core::ToInputArray, size: core::Size2d, center: core::Vec3d, normal: core::Vec3d, up_vector: core::Vec3d) -> Result<crate::viz::WImage3D> {
+	pub fn new_1(image: &(impl core::ToInputArray + ?Sized), size: core::Size2d, center: core::Vec3d, normal: core::Vec3d, up_vector: core::Vec3d) -> Result<crate::viz::WImage3D> {
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_viz_WImage3D_WImage3D_const__InputArrayR_const_Size2dR_const_Vec3dR_const_Vec3dR_const_Vec3dR(image.as_raw__InputArray(), &size, &center, &normal, &up_vector, ocvrs_return.as_mut_ptr()) };
@@ -3434,7 +3490,7 @@ impl WImageOverlay {
 	/// * image: BGR or Gray-Scale image.
 	/// * rect: Image is scaled and positioned based on rect.
 	#[inline]
-	pub fn new(image: &dyn core::ToInputArray, rect: core::Rect) -> Result<crate::viz::WImageOverlay> {
+	pub fn new(image: &(impl core::ToInputArray + ?Sized), rect: core::Rect) -> Result<crate::viz::WImageOverlay> {
 		input_array_arg!(image);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_viz_WImageOverlay_WImageOverlay_const__InputArrayR_const_RectR(image.as_raw__InputArray(), &rect, ocvrs_return.as_mut_ptr()) };
@@ -3607,11 +3663,11 @@ impl WMesh {
 	/// * colors: noArray()
 	/// * normals: noArray()
 	#[inline]
-	pub fn new_1(cloud: &dyn core::ToInputArray, polygons: &dyn core::ToInputArray, colors: &dyn core::ToInputArray, normals: &dyn core::ToInputArray) -> Result<crate::viz::WMesh> {
+	pub fn new_1(cloud: &(impl core::ToInputArray + ?Sized), polygons: &(impl core::ToInputArray + ?Sized), colors: Option<&(impl core::ToInputArray + ?Sized)>, normals: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<crate::viz::WMesh> {
 		input_array_arg!(cloud);
 		input_array_arg!(polygons);
-		input_array_arg!(colors);
-		input_array_arg!(normals);
+		input_array_arg!(nullable colors);
+		input_array_arg!(nullable normals);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_viz_WMesh_WMesh_const__InputArrayR_const__InputArrayR_const__InputArrayR_const__InputArrayR(cloud.as_raw__InputArray(), polygons.as_raw__InputArray(), colors.as_raw__InputArray(), normals.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -3678,7 +3734,7 @@ impl crate::viz::WPaintedCloudTrait for WPaintedCloud {
 impl WPaintedCloud {
 	/// Paint cloud with default gradient between cloud bounds points
 	#[inline]
-	pub fn new(cloud: &dyn core::ToInputArray) -> Result<crate::viz::WPaintedCloud> {
+	pub fn new(cloud: &(impl core::ToInputArray + ?Sized)) -> Result<crate::viz::WPaintedCloud> {
 		input_array_arg!(cloud);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_viz_WPaintedCloud_WPaintedCloud_const__InputArrayR(cloud.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
@@ -3690,7 +3746,7 @@ impl WPaintedCloud {
 	
 	/// Paint cloud with default gradient between given points
 	#[inline]
-	pub fn new_1(cloud: &dyn core::ToInputArray, p1: core::Point3d, p2: core::Point3d) -> Result<crate::viz::WPaintedCloud> {
+	pub fn new_1(cloud: &(impl core::ToInputArray + ?Sized), p1: core::Point3d, p2: core::Point3d) -> Result<crate::viz::WPaintedCloud> {
 		input_array_arg!(cloud);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_viz_WPaintedCloud_WPaintedCloud_const__InputArrayR_const_Point3dR_const_Point3dR(cloud.as_raw__InputArray(), &p1, &p2, ocvrs_return.as_mut_ptr()) };
@@ -3702,7 +3758,7 @@ impl WPaintedCloud {
 	
 	/// Paint cloud with gradient specified by given colors between given points
 	#[inline]
-	pub fn new_2(cloud: &dyn core::ToInputArray, p1: core::Point3d, p2: core::Point3d, c1: &crate::viz::Color, c2: crate::viz::Color) -> Result<crate::viz::WPaintedCloud> {
+	pub fn new_2(cloud: &(impl core::ToInputArray + ?Sized), p1: core::Point3d, p2: core::Point3d, c1: &crate::viz::Color, c2: crate::viz::Color) -> Result<crate::viz::WPaintedCloud> {
 		input_array_arg!(cloud);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_viz_WPaintedCloud_WPaintedCloud_const__InputArrayR_const_Point3dR_const_Point3dR_const_ColorR_const_Color(cloud.as_raw__InputArray(), &p1, &p2, c1.as_raw_Color(), c2.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
@@ -3870,7 +3926,7 @@ impl crate::viz::WPolyLineTrait for WPolyLine {
 
 impl WPolyLine {
 	#[inline]
-	pub fn new(points: &dyn core::ToInputArray, colors: &dyn core::ToInputArray) -> Result<crate::viz::WPolyLine> {
+	pub fn new(points: &(impl core::ToInputArray + ?Sized), colors: &(impl core::ToInputArray + ?Sized)) -> Result<crate::viz::WPolyLine> {
 		input_array_arg!(points);
 		input_array_arg!(colors);
 		return_send!(via ocvrs_return);
@@ -3890,7 +3946,7 @@ impl WPolyLine {
 	/// ## C++ default parameters
 	/// * color: Color::white()
 	#[inline]
-	pub fn new_1(points: &dyn core::ToInputArray, color: &crate::viz::Color) -> Result<crate::viz::WPolyLine> {
+	pub fn new_1(points: &(impl core::ToInputArray + ?Sized), color: &crate::viz::Color) -> Result<crate::viz::WPolyLine> {
 		input_array_arg!(points);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_viz_WPolyLine_WPolyLine_const__InputArrayR_const_ColorR(points.as_raw__InputArray(), color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
@@ -4268,7 +4324,7 @@ impl WTrajectory {
 	/// * scale: 1.0
 	/// * color: Color::white()
 	#[inline]
-	pub fn new(path: &dyn core::ToInputArray, display_mode: i32, scale: f64, color: &crate::viz::Color) -> Result<crate::viz::WTrajectory> {
+	pub fn new(path: &(impl core::ToInputArray + ?Sized), display_mode: i32, scale: f64, color: &crate::viz::Color) -> Result<crate::viz::WTrajectory> {
 		input_array_arg!(path);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_viz_WTrajectory_WTrajectory_const__InputArrayR_int_double_const_ColorR(path.as_raw__InputArray(), display_mode, scale, color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
@@ -4350,7 +4406,7 @@ impl WTrajectoryFrustums {
 	/// * scale: 1.
 	/// * color: Color::white()
 	#[inline]
-	pub fn new(path: &dyn core::ToInputArray, k: core::Matx33d, scale: f64, color: &crate::viz::Color) -> Result<crate::viz::WTrajectoryFrustums> {
+	pub fn new(path: &(impl core::ToInputArray + ?Sized), k: core::Matx33d, scale: f64, color: &crate::viz::Color) -> Result<crate::viz::WTrajectoryFrustums> {
 		input_array_arg!(path);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_viz_WTrajectoryFrustums_WTrajectoryFrustums_const__InputArrayR_const_Matx33dR_double_const_ColorR(path.as_raw__InputArray(), &k, scale, color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
@@ -4374,7 +4430,7 @@ impl WTrajectoryFrustums {
 	/// * scale: 1.
 	/// * color: Color::white()
 	#[inline]
-	pub fn new_1(path: &dyn core::ToInputArray, fov: core::Vec2d, scale: f64, color: &crate::viz::Color) -> Result<crate::viz::WTrajectoryFrustums> {
+	pub fn new_1(path: &(impl core::ToInputArray + ?Sized), fov: core::Vec2d, scale: f64, color: &crate::viz::Color) -> Result<crate::viz::WTrajectoryFrustums> {
 		input_array_arg!(path);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_viz_WTrajectoryFrustums_WTrajectoryFrustums_const__InputArrayR_const_Vec2dR_double_const_ColorR(path.as_raw__InputArray(), &fov, scale, color.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
@@ -4463,7 +4519,7 @@ impl WTrajectorySpheres {
 	/// * from: Color::red()
 	/// * to: Color::white()
 	#[inline]
-	pub fn new(path: &dyn core::ToInputArray, line_length: f64, radius: f64, from: &crate::viz::Color, to: &crate::viz::Color) -> Result<crate::viz::WTrajectorySpheres> {
+	pub fn new(path: &(impl core::ToInputArray + ?Sized), line_length: f64, radius: f64, from: &crate::viz::Color, to: &crate::viz::Color) -> Result<crate::viz::WTrajectorySpheres> {
 		input_array_arg!(path);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_viz_WTrajectorySpheres_WTrajectorySpheres_const__InputArrayR_double_double_const_ColorR_const_ColorR(path.as_raw__InputArray(), line_length, radius, from.as_raw_Color(), to.as_raw_Color(), ocvrs_return.as_mut_ptr()) };
@@ -4911,3 +4967,4 @@ impl Widget3D {
 }
 
 boxed_cast_base! { Widget3D, crate::viz::Widget, cv_Widget3D_to_Widget }
+pub use crate::manual::viz::*;
@@ -40,9 +40,9 @@ pub trait WeChatQRCodeTrait: crate::wechat_qrcode::WeChatQRCodeTraitConst {
 	/// ## C++ default parameters
 	/// * points: noArray()
 	#[inline]
-	fn detect_and_decode(&mut self, img: &dyn core::ToInputArray, points: &mut dyn core::ToOutputArray) -> Result<core::Vector<String>> {
+	fn detect_and_decode(&mut self, img: &dyn core::ToInputArray, points: Option<&mut dyn core::ToOutputArray>) -> Result<core::Vector<String>> {
 		input_array_arg!(img);
-		output_array_arg!(points);
+		output_array_arg!(nullable points);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_wechat_qrcode_WeChatQRCode_detectAndDecode_const__InputArrayR_const__OutputArrayR(self.as_raw_mut_WeChatQRCode(), img.as_raw__InputArray(), points.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -112,3 +112,4 @@ impl WeChatQRCode {
 	}
 	
 }
+pub use crate::manual::wechat_qrcode::*;
@@ -149,7 +149,7 @@ pub type SurfFeatureDetector = dyn crate::xfeatures2d::SURF;
 /// * nonmax_suppression: true
 /// * typ: FastFeatureDetector::TYPE_9_16
 #[inline]
-pub fn fast_for_point_set(image: &dyn core::ToInputArray, keypoints: &mut core::Vector<core::KeyPoint>, threshold: i32, nonmax_suppression: bool, typ: crate::features2d::FastFeatureDetector_DetectorType) -> Result<()> {
+pub fn fast_for_point_set(image: &(impl core::ToInputArray + ?Sized), keypoints: &mut core::Vector<core::KeyPoint>, threshold: i32, nonmax_suppression: bool, typ: crate::features2d::FastFeatureDetector_DetectorType) -> Result<()> {
 	input_array_arg!(image);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_xfeatures2d_FASTForPointSet_const__InputArrayR_vectorLKeyPointGR_int_bool_DetectorType(image.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfKeyPoint(), threshold, nonmax_suppression, typ, ocvrs_return.as_mut_ptr()) };
@@ -288,6 +288,14 @@ pub trait SURF_CUDATraitConst {
 		ret
 	}
 	
+	/// Same as `sum()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn sum_ref(&self) -> BoxedRef<'_, core::GpuMat> {
+		let ret = unsafe { sys::cv_cuda_SURF_CUDA_getPropSumRef_const(self.as_raw_SURF_CUDA()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn mask1(&self) -> core::GpuMat {
 		let ret = unsafe { sys::cv_cuda_SURF_CUDA_getPropMask1_const(self.as_raw_SURF_CUDA()) };
@@ -295,6 +303,14 @@ pub trait SURF_CUDATraitConst {
 		ret
 	}
 	
+	/// Same as `mask1()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn mask1_ref(&self) -> BoxedRef<'_, core::GpuMat> {
+		let ret = unsafe { sys::cv_cuda_SURF_CUDA_getPropMask1Ref_const(self.as_raw_SURF_CUDA()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn mask_sum(&self) -> core::GpuMat {
 		let ret = unsafe { sys::cv_cuda_SURF_CUDA_getPropMaskSum_const(self.as_raw_SURF_CUDA()) };
@@ -302,6 +318,14 @@ pub trait SURF_CUDATraitConst {
 		ret
 	}
 	
+	/// Same as `mask_sum()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn mask_sum_ref(&self) -> BoxedRef<'_, core::GpuMat> {
+		let ret = unsafe { sys::cv_cuda_SURF_CUDA_getPropMaskSumRef_const(self.as_raw_SURF_CUDA()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn det(&self) -> core::GpuMat {
 		let ret = unsafe { sys::cv_cuda_SURF_CUDA_getPropDet_const(self.as_raw_SURF_CUDA()) };
@@ -309,6 +333,14 @@ pub trait SURF_CUDATraitConst {
 		ret
 	}
 	
+	/// Same as `det()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn det_ref(&self) -> BoxedRef<'_, core::GpuMat> {
+		let ret = unsafe { sys::cv_cuda_SURF_CUDA_getPropDetRef_const(self.as_raw_SURF_CUDA()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn trace(&self) -> core::GpuMat {
 		let ret = unsafe { sys::cv_cuda_SURF_CUDA_getPropTrace_const(self.as_raw_SURF_CUDA()) };
@@ -316,6 +348,14 @@ pub trait SURF_CUDATraitConst {
 		ret
 	}
 	
+	/// Same as `trace()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn trace_ref(&self) -> BoxedRef<'_, core::GpuMat> {
+		let ret = unsafe { sys::cv_cuda_SURF_CUDA_getPropTraceRef_const(self.as_raw_SURF_CUDA()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	#[inline]
 	fn max_pos_buffer(&self) -> core::GpuMat {
 		let ret = unsafe { sys::cv_cuda_SURF_CUDA_getPropMaxPosBuffer_const(self.as_raw_SURF_CUDA()) };
@@ -323,6 +363,14 @@ pub trait SURF_CUDATraitConst {
 		ret
 	}
 	
+	/// Same as `max_pos_buffer()`, but returns the reference to the field instead of its copy
+	#[inline]
+	fn max_pos_buffer_ref(&self) -> BoxedRef<'_, core::GpuMat> {
+		let ret = unsafe { sys::cv_cuda_SURF_CUDA_getPropMaxPosBufferRef_const(self.as_raw_SURF_CUDA()) };
+		let ret = unsafe { BoxedRef::from_raw(ret) };
+		ret
+	}
+	
 	/// returns the descriptor size in float's (64 or 128)
 	#[inline]
 	fn descriptor_size(&self) -> Result<i32> {
@@ -632,9 +680,9 @@ pub trait AffineFeature2D: crate::features2d::Feature2DTrait + crate::xfeatures2
 	/// ## C++ default parameters
 	/// * mask: noArray()
 	#[inline]
-	fn detect(&mut self, image: &dyn core::ToInputArray, keypoints: &mut core::Vector<crate::xfeatures2d::Elliptic_KeyPoint>, mask: &dyn core::ToInputArray) -> Result<()> {
+	fn detect(&mut self, image: &dyn core::ToInputArray, keypoints: &mut core::Vector<crate::xfeatures2d::Elliptic_KeyPoint>, mask: Option<&dyn core::ToInputArray>) -> Result<()> {
 		input_array_arg!(image);
-		input_array_arg!(mask);
+		input_array_arg!(nullable mask);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_xfeatures2d_AffineFeature2D_detect_const__InputArrayR_vectorLElliptic_KeyPointGR_const__InputArrayR(self.as_raw_mut_AffineFeature2D(), image.as_raw__InputArray(), keypoints.as_raw_mut_VectorOfElliptic_KeyPoint(), mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -1126,8 +1174,8 @@ impl dyn DAISY + '_ {
 	/// * interpolation: true
 	/// * use_orientation: false
 	#[inline]
-	pub fn create(radius: f32, q_radius: i32, q_theta: i32, q_hist: i32, norm: crate::xfeatures2d::DAISY_NormalizationType, h: &dyn core::ToInputArray, interpolation: bool, use_orientation: bool) -> Result<core::Ptr<dyn crate::xfeatures2d::DAISY>> {
-		input_array_arg!(h);
+	pub fn create(radius: f32, q_radius: i32, q_theta: i32, q_hist: i32, norm: crate::xfeatures2d::DAISY_NormalizationType, h: Option<&(impl core::ToInputArray + ?Sized)>, interpolation: bool, use_orientation: bool) -> Result<core::Ptr<dyn crate::xfeatures2d::DAISY>> {
+		input_array_arg!(nullable h);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_xfeatures2d_DAISY_create_float_int_int_int_NormalizationType_const__InputArrayR_bool_bool(radius, q_radius, q_theta, q_hist, norm, h.as_raw__InputArray(), interpolation, use_orientation, ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -2341,7 +2389,7 @@ impl dyn PCTSignatures + '_ {
 	/// * radius_to_shorter_side_ratio: 1.0/8
 	/// * border_thickness: 1
 	#[inline]
-	pub fn draw_signature(source: &dyn core::ToInputArray, signature: &dyn core::ToInputArray, result: &mut dyn core::ToOutputArray, radius_to_shorter_side_ratio: f32, border_thickness: i32) -> Result<()> {
+	pub fn draw_signature(source: &(impl core::ToInputArray + ?Sized), signature: &(impl core::ToInputArray + ?Sized), result: &mut (impl core::ToOutputArray + ?Sized), radius_to_shorter_side_ratio: f32, border_thickness: i32) -> Result<()> {
 		input_array_arg!(source);
 		input_array_arg!(signature);
 		output_array_arg!(result);
@@ -2923,4 +2971,5 @@ impl dyn VGG + '_ {
 		Ok(ret)
 	}
 	
-}
\ No newline at end of file
+}
+pub use crate::manual::xfeatures2d::*;
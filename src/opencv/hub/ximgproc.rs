@@ -288,7 +288,7 @@ pub fn bright_edges(_original: &mut core::Mat, _edgeview: &mut core::Mat, contra
 /// * op: FHT_ADD
 /// * make_skew: HDO_DESKEW
 #[inline]
-pub fn fast_hough_transform(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, dst_mat_depth: i32, angle_range: i32, op: i32, make_skew: i32) -> Result<()> {
+pub fn fast_hough_transform(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), dst_mat_depth: i32, angle_range: i32, op: i32, make_skew: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -308,7 +308,7 @@ pub fn fast_hough_transform(src: &dyn core::ToInputArray, dst: &mut dyn core::To
 /// * alpha: double see paper
 /// * omega: double see paper
 #[inline]
-pub fn gradient_deriche_x(op: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, alpha: f64, omega: f64) -> Result<()> {
+pub fn gradient_deriche_x(op: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), alpha: f64, omega: f64) -> Result<()> {
 	input_array_arg!(op);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -328,7 +328,7 @@ pub fn gradient_deriche_x(op: &dyn core::ToInputArray, dst: &mut dyn core::ToOut
 /// * alpha: double see paper
 /// * omega: double see paper
 #[inline]
-pub fn gradient_deriche_y(op: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, alpha: f64, omega: f64) -> Result<()> {
+pub fn gradient_deriche_y(op: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), alpha: f64, omega: f64) -> Result<()> {
 	input_array_arg!(op);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -339,7 +339,7 @@ pub fn gradient_deriche_y(op: &dyn core::ToInputArray, dst: &mut dyn core::ToOut
 }
 
 #[inline]
-pub fn gradient_paillou_x(op: &dyn core::ToInputArray, _dst: &mut dyn core::ToOutputArray, alpha: f64, omega: f64) -> Result<()> {
+pub fn gradient_paillou_x(op: &(impl core::ToInputArray + ?Sized), _dst: &mut (impl core::ToOutputArray + ?Sized), alpha: f64, omega: f64) -> Result<()> {
 	input_array_arg!(op);
 	output_array_arg!(_dst);
 	return_send!(via ocvrs_return);
@@ -361,7 +361,7 @@ pub fn gradient_paillou_x(op: &dyn core::ToInputArray, _dst: &mut dyn core::ToOu
 /// ## See also
 /// GradientPaillouX, GradientPaillouY
 #[inline]
-pub fn gradient_paillou_y(op: &dyn core::ToInputArray, _dst: &mut dyn core::ToOutputArray, alpha: f64, omega: f64) -> Result<()> {
+pub fn gradient_paillou_y(op: &(impl core::ToInputArray + ?Sized), _dst: &mut (impl core::ToOutputArray + ?Sized), alpha: f64, omega: f64) -> Result<()> {
 	input_array_arg!(op);
 	output_array_arg!(_dst);
 	return_send!(via ocvrs_return);
@@ -391,7 +391,7 @@ pub fn gradient_paillou_y(op: &dyn core::ToInputArray, _dst: &mut dyn core::ToOu
 /// * make_skew: HDO_DESKEW
 /// * rules: RO_IGNORE_BORDERS
 #[inline]
-pub fn hough_point2_line(hough_point: core::Point, src_img_info: &dyn core::ToInputArray, angle_range: i32, make_skew: i32, rules: i32) -> Result<core::Vec4i> {
+pub fn hough_point2_line(hough_point: core::Point, src_img_info: &(impl core::ToInputArray + ?Sized), angle_range: i32, make_skew: i32, rules: i32) -> Result<core::Vec4i> {
 	input_array_arg!(src_img_info);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_ximgproc_HoughPoint2Line_const_PointR_const__InputArrayR_int_int_int(&hough_point, src_img_info.as_raw__InputArray(), angle_range, make_skew, rules, ocvrs_return.as_mut_ptr()) };
@@ -412,7 +412,7 @@ pub fn hough_point2_line(hough_point: core::Point, src_img_info: &dyn core::ToIn
 /// ## Returns
 /// Transformation matrix corresponding to inversed image transformation
 #[inline]
-pub fn pei_lin_normalization(i: &dyn core::ToInputArray) -> Result<core::Matx23d> {
+pub fn pei_lin_normalization(i: &(impl core::ToInputArray + ?Sized)) -> Result<core::Matx23d> {
 	input_array_arg!(i);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_ximgproc_PeiLinNormalization_const__InputArrayR(i.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
@@ -435,7 +435,7 @@ pub fn pei_lin_normalization(i: &dyn core::ToInputArray) -> Result<core::Matx23d
 /// 
 /// ## Overloaded parameters
 #[inline]
-pub fn pei_lin_normalization_1(i: &dyn core::ToInputArray, t: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn pei_lin_normalization_1(i: &(impl core::ToInputArray + ?Sized), t: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(i);
 	output_array_arg!(t);
 	return_send!(via ocvrs_return);
@@ -470,7 +470,7 @@ pub fn pei_lin_normalization_1(i: &dyn core::ToInputArray, t: &mut dyn core::ToO
 /// * crop: false
 /// * norm: false
 #[inline]
-pub fn radon_transform(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, theta: f64, start_angle: f64, end_angle: f64, crop: bool, norm: bool) -> Result<()> {
+pub fn radon_transform(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), theta: f64, start_angle: f64, end_angle: f64, crop: bool, norm: bool) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -505,7 +505,7 @@ pub fn radon_transform(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutpu
 /// ## C++ default parameters
 /// * adjust_outliers: false
 #[inline]
-pub fn am_filter(joint: &dyn core::ToInputArray, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, sigma_s: f64, sigma_r: f64, adjust_outliers: bool) -> Result<()> {
+pub fn am_filter(joint: &(impl core::ToInputArray + ?Sized), src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), sigma_s: f64, sigma_r: f64, adjust_outliers: bool) -> Result<()> {
 	input_array_arg!(joint);
 	input_array_arg!(src);
 	output_array_arg!(dst);
@@ -537,7 +537,7 @@ pub fn am_filter(joint: &dyn core::ToInputArray, src: &dyn core::ToInputArray, d
 /// * K: sensitivity to the edges
 /// * niters: The number of iterations
 #[inline]
-pub fn anisotropic_diffusion(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, alpha: f32, k: f32, niters: i32) -> Result<()> {
+pub fn anisotropic_diffusion(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), alpha: f32, k: f32, niters: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -573,7 +573,7 @@ pub fn anisotropic_diffusion(src: &dyn core::ToInputArray, dst: &mut dyn core::T
 /// * sigma_alpha: -1.
 /// * sigma_avg: -1.
 #[inline]
-pub fn bilateral_texture_filter(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, fr: i32, num_iter: i32, sigma_alpha: f64, sigma_avg: f64) -> Result<()> {
+pub fn bilateral_texture_filter(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), fr: i32, num_iter: i32, sigma_alpha: f64, sigma_avg: f64) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -590,7 +590,7 @@ pub fn bilateral_texture_filter(src: &dyn core::ToInputArray, dst: &mut dyn core
 /// * templ: Searched template. It must be not greater than the source image and have 3 channels
 /// * result: Map of comparison results. It must be single-channel 64-bit floating-point
 #[inline]
-pub fn color_match_template(img: &dyn core::ToInputArray, templ: &dyn core::ToInputArray, result: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn color_match_template(img: &(impl core::ToInputArray + ?Sized), templ: &(impl core::ToInputArray + ?Sized), result: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(img);
 	input_array_arg!(templ);
 	output_array_arg!(result);
@@ -618,7 +618,7 @@ pub fn color_match_template(img: &dyn core::ToInputArray, templ: &dyn core::ToIn
 /// ## C++ default parameters
 /// * thresh: 24
 #[inline]
-pub fn compute_bad_pixel_percent(gt: &dyn core::ToInputArray, src: &dyn core::ToInputArray, roi: core::Rect, thresh: i32) -> Result<f64> {
+pub fn compute_bad_pixel_percent(gt: &(impl core::ToInputArray + ?Sized), src: &(impl core::ToInputArray + ?Sized), roi: core::Rect, thresh: i32) -> Result<f64> {
 	input_array_arg!(gt);
 	input_array_arg!(src);
 	return_send!(via ocvrs_return);
@@ -639,7 +639,7 @@ pub fn compute_bad_pixel_percent(gt: &dyn core::ToInputArray, src: &dyn core::To
 /// 
 /// @result returns mean square error between GT and src
 #[inline]
-pub fn compute_mse(gt: &dyn core::ToInputArray, src: &dyn core::ToInputArray, roi: core::Rect) -> Result<f64> {
+pub fn compute_mse(gt: &(impl core::ToInputArray + ?Sized), src: &(impl core::ToInputArray + ?Sized), roi: core::Rect) -> Result<f64> {
 	input_array_arg!(gt);
 	input_array_arg!(src);
 	return_send!(via ocvrs_return);
@@ -656,7 +656,7 @@ pub fn compute_mse(gt: &dyn core::ToInputArray, src: &dyn core::ToInputArray, ro
 /// * out: Mat of type CV_64FC2 and nbElt rows
 /// * nbElt: number of points in out contour
 #[inline]
-pub fn contour_sampling(src: &dyn core::ToInputArray, out: &mut dyn core::ToOutputArray, nb_elt: i32) -> Result<()> {
+pub fn contour_sampling(src: &(impl core::ToInputArray + ?Sized), out: &mut (impl core::ToOutputArray + ?Sized), nb_elt: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(out);
 	return_send!(via ocvrs_return);
@@ -681,7 +681,7 @@ pub fn contour_sampling(src: &dyn core::ToInputArray, out: &mut dyn core::ToOutp
 /// For all other cases, the sizes of the window will impact the number of samples
 /// and the number of elements in the estimated covariance matrix.
 #[inline]
-pub fn covariance_estimation(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, window_rows: i32, window_cols: i32) -> Result<()> {
+pub fn covariance_estimation(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), window_rows: i32, window_cols: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -764,7 +764,7 @@ pub fn create_contour_fitting(ctr: i32, fd: i32) -> Result<core::Ptr<crate::ximg
 /// * mode: DTF_NC
 /// * num_iters: 3
 #[inline]
-pub fn create_dt_filter(guide: &dyn core::ToInputArray, sigma_spatial: f64, sigma_color: f64, mode: i32, num_iters: i32) -> Result<core::Ptr<dyn crate::ximgproc::DTFilter>> {
+pub fn create_dt_filter(guide: &(impl core::ToInputArray + ?Sized), sigma_spatial: f64, sigma_color: f64, mode: i32, num_iters: i32) -> Result<core::Ptr<dyn crate::ximgproc::DTFilter>> {
 	input_array_arg!(guide);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_ximgproc_createDTFilter_const__InputArrayR_double_double_int_int(guide.as_raw__InputArray(), sigma_spatial, sigma_color, mode, num_iters, ocvrs_return.as_mut_ptr()) };
@@ -892,7 +892,7 @@ pub fn create_edge_drawing() -> Result<core::Ptr<dyn crate::ximgproc::EdgeDrawin
 /// * num_iter: 25
 /// * max_tol: 1e-5
 #[inline]
-pub fn create_fast_bilateral_solver_filter(guide: &dyn core::ToInputArray, sigma_spatial: f64, sigma_luma: f64, sigma_chroma: f64, lambda: f64, num_iter: i32, max_tol: f64) -> Result<core::Ptr<dyn crate::ximgproc::FastBilateralSolverFilter>> {
+pub fn create_fast_bilateral_solver_filter(guide: &(impl core::ToInputArray + ?Sized), sigma_spatial: f64, sigma_luma: f64, sigma_chroma: f64, lambda: f64, num_iter: i32, max_tol: f64) -> Result<core::Ptr<dyn crate::ximgproc::FastBilateralSolverFilter>> {
 	input_array_arg!(guide);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_ximgproc_createFastBilateralSolverFilter_const__InputArrayR_double_double_double_double_int_double(guide.as_raw__InputArray(), sigma_spatial, sigma_luma, sigma_chroma, lambda, num_iter, max_tol, ocvrs_return.as_mut_ptr()) };
@@ -927,7 +927,7 @@ pub fn create_fast_bilateral_solver_filter(guide: &dyn core::ToInputArray, sigma
 /// * lambda_attenuation: 0.25
 /// * num_iter: 3
 #[inline]
-pub fn create_fast_global_smoother_filter(guide: &dyn core::ToInputArray, lambda: f64, sigma_color: f64, lambda_attenuation: f64, num_iter: i32) -> Result<core::Ptr<dyn crate::ximgproc::FastGlobalSmootherFilter>> {
+pub fn create_fast_global_smoother_filter(guide: &(impl core::ToInputArray + ?Sized), lambda: f64, sigma_color: f64, lambda_attenuation: f64, num_iter: i32) -> Result<core::Ptr<dyn crate::ximgproc::FastGlobalSmootherFilter>> {
 	input_array_arg!(guide);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_ximgproc_createFastGlobalSmootherFilter_const__InputArrayR_double_double_double_int(guide.as_raw__InputArray(), lambda, sigma_color, lambda_attenuation, num_iter, ocvrs_return.as_mut_ptr()) };
@@ -979,7 +979,7 @@ pub fn create_fast_line_detector(length_threshold: i32, distance_threshold: f32,
 /// 
 /// For more details about Guided Filter parameters, see the original article [Kaiming10](https://docs.opencv.org/4.6.0/d0/de3/citelist.html#CITEREF_Kaiming10) .
 #[inline]
-pub fn create_guided_filter(guide: &dyn core::ToInputArray, radius: i32, eps: f64) -> Result<core::Ptr<dyn crate::ximgproc::GuidedFilter>> {
+pub fn create_guided_filter(guide: &(impl core::ToInputArray + ?Sized), radius: i32, eps: f64) -> Result<core::Ptr<dyn crate::ximgproc::GuidedFilter>> {
 	input_array_arg!(guide);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_ximgproc_createGuidedFilter_const__InputArrayR_int_double(guide.as_raw__InputArray(), radius, eps, ocvrs_return.as_mut_ptr()) };
@@ -995,7 +995,7 @@ pub fn create_guided_filter(guide: &dyn core::ToInputArray, radius: i32, eps: f6
 /// * img: Source 8-bit, 32-bit or 64-bit image, with 3-channel image.
 /// * qimg: result CV_64FC4 a quaternion image( 4 chanels zero channel and B,G,R).
 #[inline]
-pub fn create_quaternion_image(img: &dyn core::ToInputArray, qimg: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn create_quaternion_image(img: &(impl core::ToInputArray + ?Sized), qimg: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(img);
 	output_array_arg!(qimg);
 	return_send!(via ocvrs_return);
@@ -1112,7 +1112,7 @@ pub fn create_structured_edge_detection(model: &str, how_to_get_features: Option
 /// * region_size: 10
 /// * ratio: 0.075f
 #[inline]
-pub fn create_superpixel_lsc(image: &dyn core::ToInputArray, region_size: i32, ratio: f32) -> Result<core::Ptr<dyn crate::ximgproc::SuperpixelLSC>> {
+pub fn create_superpixel_lsc(image: &(impl core::ToInputArray + ?Sized), region_size: i32, ratio: f32) -> Result<core::Ptr<dyn crate::ximgproc::SuperpixelLSC>> {
 	input_array_arg!(image);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_ximgproc_createSuperpixelLSC_const__InputArrayR_int_float(image.as_raw__InputArray(), region_size, ratio, ocvrs_return.as_mut_ptr()) };
@@ -1189,7 +1189,7 @@ pub fn create_superpixel_seeds(image_width: i32, image_height: i32, image_channe
 /// * region_size: 10
 /// * ruler: 10.0f
 #[inline]
-pub fn create_superpixel_slic(image: &dyn core::ToInputArray, algorithm: i32, region_size: i32, ruler: f32) -> Result<core::Ptr<dyn crate::ximgproc::SuperpixelSLIC>> {
+pub fn create_superpixel_slic(image: &(impl core::ToInputArray + ?Sized), algorithm: i32, region_size: i32, ruler: f32) -> Result<core::Ptr<dyn crate::ximgproc::SuperpixelSLIC>> {
 	input_array_arg!(image);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_ximgproc_createSuperpixelSLIC_const__InputArrayR_int_int_float(image.as_raw__InputArray(), algorithm, region_size, ruler, ocvrs_return.as_mut_ptr()) };
@@ -1221,7 +1221,7 @@ pub fn create_superpixel_slic(image: &dyn core::ToInputArray, algorithm: i32, re
 /// * mode: DTF_NC
 /// * num_iters: 3
 #[inline]
-pub fn dt_filter(guide: &dyn core::ToInputArray, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, sigma_spatial: f64, sigma_color: f64, mode: i32, num_iters: i32) -> Result<()> {
+pub fn dt_filter(guide: &(impl core::ToInputArray + ?Sized), src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), sigma_spatial: f64, sigma_color: f64, mode: i32, num_iters: i32) -> Result<()> {
 	input_array_arg!(guide);
 	input_array_arg!(src);
 	output_array_arg!(dst);
@@ -1244,7 +1244,7 @@ pub fn dt_filter(guide: &dyn core::ToInputArray, src: &dyn core::ToInputArray, d
 /// * d: Diameter of each pixel neighborhood that is used during filtering. Must be greater or equal 3.
 /// * threshold: Threshold, which distinguishes between noise, outliers, and data.
 #[inline]
-pub fn edge_preserving_filter(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, d: i32, threshold: f64) -> Result<()> {
+pub fn edge_preserving_filter(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), d: i32, threshold: f64) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -1291,7 +1291,7 @@ pub fn edge_preserving_filter(src: &dyn core::ToInputArray, dst: &mut dyn core::
 /// * num_iter: 25
 /// * max_tol: 1e-5
 #[inline]
-pub fn fast_bilateral_solver_filter(guide: &dyn core::ToInputArray, src: &dyn core::ToInputArray, confidence: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, sigma_spatial: f64, sigma_luma: f64, sigma_chroma: f64, lambda: f64, num_iter: i32, max_tol: f64) -> Result<()> {
+pub fn fast_bilateral_solver_filter(guide: &(impl core::ToInputArray + ?Sized), src: &(impl core::ToInputArray + ?Sized), confidence: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), sigma_spatial: f64, sigma_luma: f64, sigma_chroma: f64, lambda: f64, num_iter: i32, max_tol: f64) -> Result<()> {
 	input_array_arg!(guide);
 	input_array_arg!(src);
 	input_array_arg!(confidence);
@@ -1326,7 +1326,7 @@ pub fn fast_bilateral_solver_filter(guide: &dyn core::ToInputArray, src: &dyn co
 /// * lambda_attenuation: 0.25
 /// * num_iter: 3
 #[inline]
-pub fn fast_global_smoother_filter(guide: &dyn core::ToInputArray, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, lambda: f64, sigma_color: f64, lambda_attenuation: f64, num_iter: i32) -> Result<()> {
+pub fn fast_global_smoother_filter(guide: &(impl core::ToInputArray + ?Sized), src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), lambda: f64, sigma_color: f64, lambda_attenuation: f64, num_iter: i32) -> Result<()> {
 	input_array_arg!(guide);
 	input_array_arg!(src);
 	output_array_arg!(dst);
@@ -1351,7 +1351,7 @@ pub fn fast_global_smoother_filter(guide: &dyn core::ToInputArray, src: &dyn cor
 /// * nb_elt: -1
 /// * nb_fd: -1
 #[inline]
-pub fn fourier_descriptor(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, nb_elt: i32, nb_fd: i32) -> Result<()> {
+pub fn fourier_descriptor(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), nb_elt: i32, nb_fd: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -1373,7 +1373,7 @@ pub fn fourier_descriptor(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOu
 /// ## C++ default parameters
 /// * scale: 1.0
 #[inline]
-pub fn get_disparity_vis(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, scale: f64) -> Result<()> {
+pub fn get_disparity_vis(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), scale: f64) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -1408,7 +1408,7 @@ pub fn get_disparity_vis(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOut
 /// ## C++ default parameters
 /// * d_depth: -1
 #[inline]
-pub fn guided_filter(guide: &dyn core::ToInputArray, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, radius: i32, eps: f64, d_depth: i32) -> Result<()> {
+pub fn guided_filter(guide: &(impl core::ToInputArray + ?Sized), src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), radius: i32, eps: f64, d_depth: i32) -> Result<()> {
 	input_array_arg!(guide);
 	input_array_arg!(src);
 	output_array_arg!(dst);
@@ -1451,7 +1451,7 @@ pub fn guided_filter(guide: &dyn core::ToInputArray, src: &dyn core::ToInputArra
 /// ## C++ default parameters
 /// * border_type: BORDER_DEFAULT
 #[inline]
-pub fn joint_bilateral_filter(joint: &dyn core::ToInputArray, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, d: i32, sigma_color: f64, sigma_space: f64, border_type: i32) -> Result<()> {
+pub fn joint_bilateral_filter(joint: &(impl core::ToInputArray + ?Sized), src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), d: i32, sigma_color: f64, sigma_space: f64, border_type: i32) -> Result<()> {
 	input_array_arg!(joint);
 	input_array_arg!(src);
 	output_array_arg!(dst);
@@ -1479,7 +1479,7 @@ pub fn joint_bilateral_filter(joint: &dyn core::ToInputArray, src: &dyn core::To
 /// * lambda: 0.02
 /// * kappa: 2.0
 #[inline]
-pub fn l0_smooth(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, lambda: f64, kappa: f64) -> Result<()> {
+pub fn l0_smooth(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), lambda: f64, kappa: f64) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -1527,7 +1527,7 @@ pub fn l0_smooth(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray
 /// * binarization_method: BINARIZATION_NIBLACK
 /// * r: 128
 #[inline]
-pub fn ni_black_threshold(_src: &dyn core::ToInputArray, _dst: &mut dyn core::ToOutputArray, max_value: f64, typ: i32, block_size: i32, k: f64, binarization_method: i32, r: f64) -> Result<()> {
+pub fn ni_black_threshold(_src: &(impl core::ToInputArray + ?Sized), _dst: &mut (impl core::ToOutputArray + ?Sized), max_value: f64, typ: i32, block_size: i32, k: f64, binarization_method: i32, r: f64) -> Result<()> {
 	input_array_arg!(_src);
 	output_array_arg!(_dst);
 	return_send!(via ocvrs_return);
@@ -1543,7 +1543,7 @@ pub fn ni_black_threshold(_src: &dyn core::ToInputArray, _dst: &mut dyn core::To
 /// * qimg: quaternion image.
 /// * qcimg: conjugate of qimg
 #[inline]
-pub fn qconj(qimg: &dyn core::ToInputArray, qcimg: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn qconj(qimg: &(impl core::ToInputArray + ?Sized), qcimg: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(qimg);
 	output_array_arg!(qcimg);
 	return_send!(via ocvrs_return);
@@ -1561,7 +1561,7 @@ pub fn qconj(qimg: &dyn core::ToInputArray, qcimg: &mut dyn core::ToOutputArray)
 /// * flags: quaternion image in dual space. only DFT_INVERSE flags is supported
 /// * sideLeft: true the hypercomplex exponential is to be multiplied on the left (false on the right ).
 #[inline]
-pub fn qdft(img: &dyn core::ToInputArray, qimg: &mut dyn core::ToOutputArray, flags: i32, side_left: bool) -> Result<()> {
+pub fn qdft(img: &(impl core::ToInputArray + ?Sized), qimg: &mut (impl core::ToOutputArray + ?Sized), flags: i32, side_left: bool) -> Result<()> {
 	input_array_arg!(img);
 	output_array_arg!(qimg);
 	return_send!(via ocvrs_return);
@@ -1578,7 +1578,7 @@ pub fn qdft(img: &dyn core::ToInputArray, qimg: &mut dyn core::ToOutputArray, fl
 /// * src2: quaternion image.
 /// * dst: product dst(I)=src1(I) . src2(I)
 #[inline]
-pub fn qmultiply(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn qmultiply(src1: &(impl core::ToInputArray + ?Sized), src2: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(src1);
 	input_array_arg!(src2);
 	output_array_arg!(dst);
@@ -1595,7 +1595,7 @@ pub fn qmultiply(src1: &dyn core::ToInputArray, src2: &dyn core::ToInputArray, d
 /// * qimg: quaternion image.
 /// * qnimg: conjugate of qimg
 #[inline]
-pub fn qunitary(qimg: &dyn core::ToInputArray, qnimg: &mut dyn core::ToOutputArray) -> Result<()> {
+pub fn qunitary(qimg: &(impl core::ToInputArray + ?Sized), qnimg: &mut (impl core::ToOutputArray + ?Sized)) -> Result<()> {
 	input_array_arg!(qimg);
 	output_array_arg!(qnimg);
 	return_send!(via ocvrs_return);
@@ -1615,7 +1615,7 @@ pub fn qunitary(qimg: &dyn core::ToInputArray, qnimg: &mut dyn core::ToOutputArr
 /// 
 /// @result returns zero if successfully read the ground truth
 #[inline]
-pub fn read_gt(src_path: &str, dst: &mut dyn core::ToOutputArray) -> Result<i32> {
+pub fn read_gt(src_path: &str, dst: &mut (impl core::ToOutputArray + ?Sized)) -> Result<i32> {
 	extern_container_arg!(mut src_path);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -1636,7 +1636,7 @@ pub fn read_gt(src_path: &str, dst: &mut dyn core::ToOutputArray) -> Result<i32>
 /// ## C++ default parameters
 /// * size: Size(0,0)
 #[inline]
-pub fn create_rle_image(runs: &core::Vector<core::Point3i>, res: &mut dyn core::ToOutputArray, size: core::Size) -> Result<()> {
+pub fn create_rle_image(runs: &core::Vector<core::Point3i>, res: &mut (impl core::ToOutputArray + ?Sized), size: core::Size) -> Result<()> {
 	output_array_arg!(res);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_ximgproc_rl_createRLEImage_const_vectorLPoint3iGR_const__OutputArrayR_Size(runs.as_raw_VectorOfPoint3i(), res.as_raw__OutputArray(), size.opencv_as_extern(), ocvrs_return.as_mut_ptr()) };
@@ -1658,7 +1658,7 @@ pub fn create_rle_image(runs: &core::Vector<core::Point3i>, res: &mut dyn core::
 /// ## C++ default parameters
 /// * anchor: Point(0,0)
 #[inline]
-pub fn dilate(rl_src: &dyn core::ToInputArray, rl_dest: &mut dyn core::ToOutputArray, rl_kernel: &dyn core::ToInputArray, anchor: core::Point) -> Result<()> {
+pub fn dilate(rl_src: &(impl core::ToInputArray + ?Sized), rl_dest: &mut (impl core::ToOutputArray + ?Sized), rl_kernel: &(impl core::ToInputArray + ?Sized), anchor: core::Point) -> Result<()> {
 	input_array_arg!(rl_src);
 	output_array_arg!(rl_dest);
 	input_array_arg!(rl_kernel);
@@ -1685,7 +1685,7 @@ pub fn dilate(rl_src: &dyn core::ToInputArray, rl_dest: &mut dyn core::ToOutputA
 /// * b_boundary_on: true
 /// * anchor: Point(0,0)
 #[inline]
-pub fn erode(rl_src: &dyn core::ToInputArray, rl_dest: &mut dyn core::ToOutputArray, rl_kernel: &dyn core::ToInputArray, b_boundary_on: bool, anchor: core::Point) -> Result<()> {
+pub fn erode(rl_src: &(impl core::ToInputArray + ?Sized), rl_dest: &mut (impl core::ToOutputArray + ?Sized), rl_kernel: &(impl core::ToInputArray + ?Sized), b_boundary_on: bool, anchor: core::Point) -> Result<()> {
 	input_array_arg!(rl_src);
 	output_array_arg!(rl_dest);
 	input_array_arg!(rl_kernel);
@@ -1718,7 +1718,7 @@ pub fn get_structuring_element(shape: i32, ksize: core::Size) -> Result<core::Ma
 /// ## Parameters
 /// * rlStructuringElement: mask to be tested
 #[inline]
-pub fn is_rl_morphology_possible(rl_structuring_element: &dyn core::ToInputArray) -> Result<bool> {
+pub fn is_rl_morphology_possible(rl_structuring_element: &(impl core::ToInputArray + ?Sized)) -> Result<bool> {
 	input_array_arg!(rl_structuring_element);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_ximgproc_rl_isRLMorphologyPossible_const__InputArrayR(rl_structuring_element.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
@@ -1744,7 +1744,7 @@ pub fn is_rl_morphology_possible(rl_structuring_element: &dyn core::ToInputArray
 /// * b_boundary_on_for_erosion: true
 /// * anchor: Point(0,0)
 #[inline]
-pub fn morphology_ex(rl_src: &dyn core::ToInputArray, rl_dest: &mut dyn core::ToOutputArray, op: i32, rl_kernel: &dyn core::ToInputArray, b_boundary_on_for_erosion: bool, anchor: core::Point) -> Result<()> {
+pub fn morphology_ex(rl_src: &(impl core::ToInputArray + ?Sized), rl_dest: &mut (impl core::ToOutputArray + ?Sized), op: i32, rl_kernel: &(impl core::ToInputArray + ?Sized), b_boundary_on_for_erosion: bool, anchor: core::Point) -> Result<()> {
 	input_array_arg!(rl_src);
 	output_array_arg!(rl_dest);
 	input_array_arg!(rl_kernel);
@@ -1763,7 +1763,7 @@ pub fn morphology_ex(rl_src: &dyn core::ToInputArray, rl_dest: &mut dyn core::To
 /// * rlSrc: run length encoded image
 /// * value: all foreground pixel of the binary image are set to this value
 #[inline]
-pub fn paint(image: &mut dyn core::ToInputOutputArray, rl_src: &dyn core::ToInputArray, value: core::Scalar) -> Result<()> {
+pub fn paint(image: &mut (impl core::ToInputOutputArray + ?Sized), rl_src: &(impl core::ToInputArray + ?Sized), value: core::Scalar) -> Result<()> {
 	input_output_array_arg!(image);
 	input_array_arg!(rl_src);
 	return_send!(via ocvrs_return);
@@ -1782,7 +1782,7 @@ pub fn paint(image: &mut dyn core::ToInputOutputArray, rl_src: &dyn core::ToInpu
 /// * thresh: threshold value.
 /// * type: thresholding type (only cv::THRESH_BINARY and cv::THRESH_BINARY_INV are supported)
 #[inline]
-pub fn threshold(src: &dyn core::ToInputArray, rl_dest: &mut dyn core::ToOutputArray, thresh: f64, typ: i32) -> Result<()> {
+pub fn threshold(src: &(impl core::ToInputArray + ?Sized), rl_dest: &mut (impl core::ToOutputArray + ?Sized), thresh: f64, typ: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(rl_dest);
 	return_send!(via ocvrs_return);
@@ -1829,7 +1829,7 @@ pub fn threshold(src: &dyn core::ToInputArray, rl_dest: &mut dyn core::ToOutputA
 /// * num_of_iter: 4
 /// * border_type: BORDER_DEFAULT
 #[inline]
-pub fn rolling_guidance_filter(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, d: i32, sigma_color: f64, sigma_space: f64, num_of_iter: i32, border_type: i32) -> Result<()> {
+pub fn rolling_guidance_filter(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), d: i32, sigma_color: f64, sigma_space: f64, num_of_iter: i32, border_type: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -1995,7 +1995,7 @@ pub fn create_selective_search_segmentation_strategy_texture() -> Result<core::P
 /// ## C++ default parameters
 /// * thinning_type: THINNING_ZHANGSUEN
 #[inline]
-pub fn thinning(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, thinning_type: i32) -> Result<()> {
+pub fn thinning(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), thinning_type: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -2016,7 +2016,7 @@ pub fn thinning(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray,
 /// ## C++ default parameters
 /// * fd_contour: true
 #[inline]
-pub fn transform_fd(src: &dyn core::ToInputArray, t: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, fd_contour: bool) -> Result<()> {
+pub fn transform_fd(src: &(impl core::ToInputArray + ?Sized), t: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), fd_contour: bool) -> Result<()> {
 	input_array_arg!(src);
 	input_array_arg!(t);
 	output_array_arg!(dst);
@@ -2048,11 +2048,11 @@ pub fn transform_fd(src: &dyn core::ToInputArray, t: &dyn core::ToInputArray, ds
 /// * weight_type: WMF_EXP
 /// * mask: noArray()
 #[inline]
-pub fn weighted_median_filter(joint: &dyn core::ToInputArray, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, r: i32, sigma: f64, weight_type: i32, mask: &dyn core::ToInputArray) -> Result<()> {
+pub fn weighted_median_filter(joint: &(impl core::ToInputArray + ?Sized), src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), r: i32, sigma: f64, weight_type: i32, mask: Option<&(impl core::ToInputArray + ?Sized)>) -> Result<()> {
 	input_array_arg!(joint);
 	input_array_arg!(src);
 	output_array_arg!(dst);
-	input_array_arg!(mask);
+	input_array_arg!(nullable mask);
 	return_send!(via ocvrs_return);
 	unsafe { sys::cv_ximgproc_weightedMedianFilter_const__InputArrayR_const__InputArrayR_const__OutputArrayR_int_double_int_const__InputArrayR(joint.as_raw__InputArray(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), r, sigma, weight_type, mask.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 	return_receive!(unsafe ocvrs_return => ret);
@@ -2163,10 +2163,10 @@ pub trait AdaptiveManifoldFilter: core::AlgorithmTrait + crate::ximgproc::Adapti
 	/// ## C++ default parameters
 	/// * joint: noArray()
 	#[inline]
-	fn filter(&mut self, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, joint: &dyn core::ToInputArray) -> Result<()> {
+	fn filter(&mut self, src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, joint: Option<&dyn core::ToInputArray>) -> Result<()> {
 		input_array_arg!(src);
 		output_array_arg!(dst);
-		input_array_arg!(joint);
+		input_array_arg!(nullable joint);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ximgproc_AdaptiveManifoldFilter_filter_const__InputArrayR_const__OutputArrayR_const__InputArrayR(self.as_raw_mut_AdaptiveManifoldFilter(), src.as_raw__InputArray(), dst.as_raw__OutputArray(), joint.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -2499,12 +2499,12 @@ pub trait DisparityFilter: core::AlgorithmTrait + crate::ximgproc::DisparityFilt
 	/// * roi: Rect()
 	/// * right_view: Mat()
 	#[inline]
-	fn filter(&mut self, disparity_map_left: &dyn core::ToInputArray, left_view: &dyn core::ToInputArray, filtered_disparity_map: &mut dyn core::ToOutputArray, disparity_map_right: &dyn core::ToInputArray, roi: core::Rect, right_view: &dyn core::ToInputArray) -> Result<()> {
+	fn filter(&mut self, disparity_map_left: &dyn core::ToInputArray, left_view: &dyn core::ToInputArray, filtered_disparity_map: &mut dyn core::ToOutputArray, disparity_map_right: Option<&dyn core::ToInputArray>, roi: core::Rect, right_view: Option<&dyn core::ToInputArray>) -> Result<()> {
 		input_array_arg!(disparity_map_left);
 		input_array_arg!(left_view);
 		output_array_arg!(filtered_disparity_map);
-		input_array_arg!(disparity_map_right);
-		input_array_arg!(right_view);
+		input_array_arg!(nullable disparity_map_right);
+		input_array_arg!(nullable right_view);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ximgproc_DisparityFilter_filter_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR_Rect_const__InputArrayR(self.as_raw_mut_DisparityFilter(), disparity_map_left.as_raw__InputArray(), left_view.as_raw__InputArray(), filtered_disparity_map.as_raw__OutputArray(), disparity_map_right.as_raw__InputArray(), roi.opencv_as_extern(), right_view.as_raw__InputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -2946,10 +2946,10 @@ pub trait EdgeBoxes: core::AlgorithmTrait + crate::ximgproc::EdgeBoxesConst {
 	/// ## C++ default parameters
 	/// * scores: noArray()
 	#[inline]
-	fn get_bounding_boxes(&mut self, edge_map: &dyn core::ToInputArray, orientation_map: &dyn core::ToInputArray, boxes: &mut core::Vector<core::Rect>, scores: &mut dyn core::ToOutputArray) -> Result<()> {
+	fn get_bounding_boxes(&mut self, edge_map: &dyn core::ToInputArray, orientation_map: &dyn core::ToInputArray, boxes: &mut core::Vector<core::Rect>, scores: Option<&mut dyn core::ToOutputArray>) -> Result<()> {
 		input_array_arg!(edge_map);
 		input_array_arg!(orientation_map);
-		output_array_arg!(scores);
+		output_array_arg!(nullable scores);
 		return_send!(via ocvrs_return);
 		unsafe { sys::cv_ximgproc_EdgeBoxes_getBoundingBoxes_const__InputArrayR_const__InputArrayR_vectorLRectGR_const__OutputArrayR(self.as_raw_mut_EdgeBoxes(), edge_map.as_raw__InputArray(), orientation_map.as_raw__InputArray(), boxes.as_raw_mut_VectorOfRect(), scores.as_raw__OutputArray(), ocvrs_return.as_mut_ptr()) };
 		return_receive!(unsafe ocvrs_return => ret);
@@ -4867,3 +4867,4 @@ pub trait SelectiveSearchSegmentationStrategyTexture: crate::ximgproc::Selective
 	fn as_raw_mut_SelectiveSearchSegmentationStrategyTexture(&mut self) -> *mut c_void;
 
 }
+pub use crate::manual::ximgproc::*;
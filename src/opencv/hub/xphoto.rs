@@ -116,7 +116,7 @@ opencv_type_enum! { crate::xphoto::TransformTypes }
 /// * gainG: gain for the G channel
 /// * gainR: gain for the R channel
 #[inline]
-pub fn apply_channel_gains(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, gain_b: f32, gain_g: f32, gain_r: f32) -> Result<()> {
+pub fn apply_channel_gains(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), gain_b: f32, gain_g: f32, gain_r: f32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -176,7 +176,7 @@ pub fn apply_channel_gains(src: &dyn core::ToInputArray, dst: &mut dyn core::ToO
 /// * step: cv::xphoto::BM3D_STEPALL
 /// * transform_type: cv::xphoto::HAAR
 #[inline]
-pub fn bm3d_denoising(src: &dyn core::ToInputArray, dst_step1: &mut dyn core::ToInputOutputArray, dst_step2: &mut dyn core::ToOutputArray, h: f32, template_window_size: i32, search_window_size: i32, block_matching_step1: i32, block_matching_step2: i32, group_size: i32, sliding_step: i32, beta: f32, norm_type: i32, step: i32, transform_type: i32) -> Result<()> {
+pub fn bm3d_denoising(src: &(impl core::ToInputArray + ?Sized), dst_step1: &mut (impl core::ToInputOutputArray + ?Sized), dst_step2: &mut (impl core::ToOutputArray + ?Sized), h: f32, template_window_size: i32, search_window_size: i32, block_matching_step1: i32, block_matching_step2: i32, group_size: i32, sliding_step: i32, beta: f32, norm_type: i32, step: i32, transform_type: i32) -> Result<()> {
 	input_array_arg!(src);
 	input_output_array_arg!(dst_step1);
 	output_array_arg!(dst_step2);
@@ -237,7 +237,7 @@ pub fn bm3d_denoising(src: &dyn core::ToInputArray, dst_step1: &mut dyn core::To
 /// * step: cv::xphoto::BM3D_STEPALL
 /// * transform_type: cv::xphoto::HAAR
 #[inline]
-pub fn bm3d_denoising_1(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, h: f32, template_window_size: i32, search_window_size: i32, block_matching_step1: i32, block_matching_step2: i32, group_size: i32, sliding_step: i32, beta: f32, norm_type: i32, step: i32, transform_type: i32) -> Result<()> {
+pub fn bm3d_denoising_1(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), h: f32, template_window_size: i32, search_window_size: i32, block_matching_step1: i32, block_matching_step2: i32, group_size: i32, sliding_step: i32, beta: f32, norm_type: i32, step: i32, transform_type: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -369,7 +369,7 @@ pub fn inpaint(src: &core::Mat, mask: &core::Mat, dst: &mut core::Mat, algorithm
 /// * size: neighbouring size is 2-size+1
 /// * dynRatio: image is divided by dynRatio before histogram processing
 #[inline]
-pub fn oil_painting_1(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, size: i32, dyn_ratio: i32) -> Result<()> {
+pub fn oil_painting_1(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), size: i32, dyn_ratio: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -388,7 +388,7 @@ pub fn oil_painting_1(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutput
 /// * dynRatio: image is divided by dynRatio before histogram processing
 /// * code: 	color space conversion code(see ColorConversionCodes). Histogram will used only first plane
 #[inline]
-pub fn oil_painting(src: &dyn core::ToInputArray, dst: &mut dyn core::ToOutputArray, size: i32, dyn_ratio: i32, code: i32) -> Result<()> {
+pub fn oil_painting(src: &(impl core::ToInputArray + ?Sized), dst: &mut (impl core::ToOutputArray + ?Sized), size: i32, dyn_ratio: i32, code: i32) -> Result<()> {
 	input_array_arg!(src);
 	output_array_arg!(dst);
 	return_send!(via ocvrs_return);
@@ -0,0 +1,155 @@
+//! Raw `extern "C"` functions of the C++ wrapper, for calling the OpenCV APIs that don't have a safe binding yet
+//!
+//! Everything in this module is unsafe and has no stability guarantees beyond the naming convention described below, the
+//! functions are regenerated together with the bindings. Prefer the safe API whenever it exists.
+//!
+//! # Naming
+//!
+//! The name of the extern function is built from the C++ name of the wrapped function:
+//! * `cv_` followed by the namespace without the leading `cv`, the class name (if any) and the function name, joined with
+//!   `_`, e.g. `cv_ximgproc_createEdgeDrawing` or `cv_ximgproc_EdgeDrawing_detectLines`,
+//! * constructors use the class name as the function name, e.g. `cv_Mat_Mat`,
+//! * the field accessors are named `getProp<Field>` and `setProp<Field>`, e.g. `cv_Mat_getPropRows_const`,
+//! * overloads get the C++ argument types appended, with `const_` for `const`, `R` for a reference, `X` for a pointer
+//!   and `L`/`G` for the template brackets, e.g. `cv_Mat_Mat_int_int_int` or
+//!   `cv_ximgproc_EdgeDrawing_detectLines_const__OutputArrayR`,
+//! * const methods end with `_const`.
+//!
+//! # Calling convention
+//!
+//! * methods take the object pointer as the first `instance` argument, use [Boxed::as_raw](crate::prelude::Boxed::as_raw)
+//!   or [Boxed::as_raw_mut](crate::prelude::Boxed::as_raw_mut) to get it,
+//! * the functions that can throw take the `ocvrs_return: *mut Result<T>` as the last argument, call them with
+//!   [raw_call](crate::raw_call) to convert it into the crate [Result](crate::Result),
+//! * the class instances are returned as `*mut c_void` owned by the caller, wrap them with
+//!   [Boxed::from_raw](crate::prelude::Boxed::from_raw) to have them freed on drop,
+//! * strings are passed as the NUL-terminated `*const c_char`.
+//!
+//! # Plugins
+//!
+//! The own C++ functions can be compiled into the wrapper library by listing their `.cpp` files or directories in the
+//! comma separated `OPENCV_PLUGIN_SOURCES` environment variable, e.g. in `.cargo/config.toml`:
+//! ```toml
+//! [env]
+//! OPENCV_PLUGIN_SOURCES = { value = "cpp", relative = true }
+//! ```
+//! The files are compiled with the same OpenCV headers and flags as the bindings and can include `ocvrs_common.hpp`,
+//! which provides the `Result` type with the `Ok()` and `OCVRS_CATCH()` helpers to report the C++ exceptions:
+//! ```cpp
+//! #include "ocvrs_common.hpp"
+//! #include <opencv2/imgproc.hpp>
+//!
+//! struct MyFilter {
+//!     int ksize;
+//!     void apply(const cv::Mat& src, cv::Mat& dst) const { cv::medianBlur(src, dst, ksize); }
+//! };
+//!
+//! extern "C" {
+//!     void my_filter_new(int ksize, Result<void*>* ocvrs_return) {
+//!         try {
+//!             Ok<void*>(new MyFilter { ksize }, ocvrs_return);
+//!         } OCVRS_CATCH(Result<void*>, ocvrs_return)
+//!     }
+//!
+//!     void my_filter_apply(const MyFilter* instance, const cv::Mat* src, cv::Mat* dst, Result_void* ocvrs_return) {
+//!         try {
+//!             instance->apply(*src, *dst);
+//!             Ok(ocvrs_return);
+//!         } OCVRS_CATCH(Result_void, ocvrs_return)
+//!     }
+//!
+//!     void my_filter_delete(MyFilter* instance) {
+//!         delete instance;
+//!     }
+//! }
+//! ```
+//! On the Rust side the class is registered with [opencv_plugin_class](crate::opencv_plugin_class) and the functions are
+//! called with [raw_call](crate::raw_call):
+//! ```no_run
+//! use std::ffi::c_void;
+//!
+//! use opencv::{core::Mat, opencv_plugin_class, prelude::*, raw, raw_call, Result};
+//!
+//! extern "C" {
+//!     fn my_filter_new(ksize: i32, ocvrs_return: *mut raw::Result<*mut c_void>);
+//!     fn my_filter_apply(instance: *const c_void, src: *const c_void, dst: *mut c_void, ocvrs_return: *mut raw::Result_void);
+//! }
+//!
+//! opencv_plugin_class! {
+//!     /// Median filter implemented in C++
+//!     pub struct MyFilter: my_filter_delete;
+//! }
+//!
+//! impl MyFilter {
+//!     pub fn new(ksize: i32) -> Result<Self> {
+//!         unsafe { raw_call!(my_filter_new(ksize) => MyFilter) }
+//!     }
+//!
+//!     pub fn apply(&self, src: &Mat, dst: &mut Mat) -> Result<()> {
+//!         unsafe { raw_call!(my_filter_apply(self.as_raw(), src.as_raw(), dst.as_raw_mut())) }
+//!     }
+//! }
+//! ```
+
+pub use crate::sys::*;
+
+/// Calls the raw function that reports the errors through the trailing `ocvrs_return` argument
+///
+/// The `ocvrs_return` argument must be omitted, the macro evaluates to the crate [Result](crate::Result). With `=> Type`
+/// the returned object pointer is wrapped into `Type` using [Boxed::from_raw](crate::prelude::Boxed::from_raw). Must be
+/// called inside an `unsafe` block, the caller is responsible for the validity of the arguments.
+///
+/// ```no_run
+/// use opencv::{core::Mat, prelude::*, raw, raw_call, Result};
+///
+/// fn new_mat(rows: i32, cols: i32, typ: i32) -> Result<Mat> {
+///     unsafe { raw_call!(raw::cv_Mat_Mat_int_int_int(rows, cols, typ) => Mat) }
+/// }
+/// ```
+#[macro_export]
+macro_rules! raw_call {
+	($($func: ident)::+ ( $($arg: expr),* $(,)? ) => $typ: ty) => {
+		$crate::raw_call!($($func)::+($($arg),*)).map(|ptr| <$typ as $crate::prelude::Boxed>::from_raw(ptr))
+	};
+	($($func: ident)::+ ( $($arg: expr),* $(,)? )) => {{
+		let mut ocvrs_return = ::std::mem::MaybeUninit::uninit();
+		$($func)::+($($arg,)* ocvrs_return.as_mut_ptr());
+		ocvrs_return.assume_init().into_result()
+	}};
+}
+
+/// Declares the Rust type owning an instance of the C++ class from the plugin sources
+///
+/// The type gets the same marshalling as the generated classes: [Boxed](crate::prelude::Boxed) for the raw pointer
+/// access, the conversions needed to pass it to and return it from the raw functions and `Drop` calling the passed
+/// `extern "C"` deleter, which must free the instance, e.g. `void my_filter_delete(MyFilter* instance) { delete instance; }`.
+/// Add `+ Send` after the deleter if the C++ class can be used from the different threads. See the [module
+/// docs](crate::raw) for the complete example.
+#[macro_export]
+macro_rules! opencv_plugin_class {
+	($(#[$attr: meta])* $vis: vis struct $name: ident: $delete: ident + Send;) => {
+		$crate::opencv_plugin_class! { $(#[$attr])* $vis struct $name: $delete; }
+
+		unsafe impl ::std::marker::Send for $name {}
+	};
+	($(#[$attr: meta])* $vis: vis struct $name: ident: $delete: ident;) => {
+		$(#[$attr])*
+		$vis struct $name {
+			ptr: *mut ::std::ffi::c_void,
+		}
+
+		const _: () = {
+			use $crate::traits::Boxed as _;
+			$crate::opencv_type_boxed! { $name }
+		};
+
+		impl ::std::ops::Drop for $name {
+			fn drop(&mut self) {
+				extern "C" {
+					fn $delete(instance: *mut ::std::ffi::c_void);
+				}
+				unsafe { $delete(<Self as $crate::traits::Boxed>::as_raw_mut(self)) };
+			}
+		}
+	};
+}
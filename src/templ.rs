@@ -1,6 +1,16 @@
-use std::{ffi::CStr, os::raw::c_char, slice};
+use std::{
+	any::Any,
+	ffi::CStr,
+	os::raw::{c_char, c_void},
+	panic::{self, AssertUnwindSafe},
+	process, slice,
+	sync::{
+		atomic::{AtomicBool, Ordering},
+		Mutex, PoisonError,
+	},
+};
 
-use crate::platform_types::size_t;
+use crate::{core, Error, Result};
 
 macro_rules! extern_container_arg {
 	(nofail mut $name: ident) => {
@@ -17,6 +27,15 @@ macro_rules! extern_container_arg {
 	};
 }
 
+macro_rules! path_arg {
+	(nofail $name: ident) => {
+		let $name = $name.as_ref().opencv_into_extern_container_nofail();
+	};
+	($name: ident) => {
+		let $name = $name.as_ref().opencv_into_extern_container()?;
+	};
+}
+
 macro_rules! string_arg_output_send {
 	(via $name_via: ident) => {
 		let mut $name_via = ::std::ptr::null_mut();
@@ -40,10 +59,8 @@ macro_rules! byte_string_arg_output_receive {
 macro_rules! callback_arg {
 	($tr_name: ident($($tr_arg_name: ident: $tr_arg_type: ty),*) -> $tr_ret: ty => $tr_userdata_name: ident in $callbacks_name: ident => $callback_name: ident($($fw_arg_name: ident: $fw_arg_type: ty),*) -> $fw_ret: ty) => {
 		unsafe extern "C" fn trampoline($($tr_arg_name: $tr_arg_type),*) -> $tr_ret {
-			let mut callback: Box<Box<dyn FnMut($($fw_arg_type),*) -> $fw_ret + Send + Sync>> = Box::from_raw($tr_userdata_name as _);
-			let out = callback($($fw_arg_name),*);
-			Box::into_raw(callback);
-			out
+			let callback = &mut *($tr_userdata_name as *mut Box<dyn FnMut($($fw_arg_type),*) -> $fw_ret + Send + Sync>);
+			$crate::templ::callback_abort_on_panic(|| callback($($fw_arg_name),*))
 		}
 
 		let $tr_name = if $callback_name.is_some() {
@@ -65,18 +82,36 @@ macro_rules! userdata_arg {
 }
 
 macro_rules! input_array_arg {
+	(nullable $name: ident) => {
+		let $name = match $name {
+			Some($name) => $name.input_array()?,
+			None => $crate::core::_InputArray::from($crate::core::no_array()),
+		};
+	};
 	($name: ident) => {
 		let $name = $name.input_array()?;
 	};
 }
 
 macro_rules! output_array_arg {
+	(nullable $name: ident) => {
+		let $name = match $name {
+			Some($name) => $name.output_array()?,
+			None => $crate::core::_OutputArray::from($crate::core::no_array()),
+		};
+	};
 	($name: ident) => {
 		let $name = $name.output_array()?;
 	};
 }
 
 macro_rules! input_output_array_arg {
+	(nullable $name: ident) => {
+		let $name = match $name {
+			Some($name) => $name.input_output_array()?,
+			None => $crate::core::no_array(),
+		};
+	};
 	($name: ident) => {
 		let $name = $name.input_output_array()?;
 	};
@@ -109,6 +144,95 @@ macro_rules! return_receive {
 	};
 }
 
+/// Slot for the panic caught in a Rust callback called by OpenCV
+///
+/// Panics never unwind into the C++ code and the callbacks never return made-up values to it. The callbacks that can
+/// report a failure to their caller run through [CallbackPanic::catch] of the slot owned by the Rust side of that
+/// callback:
+/// * the callbacks that only live for the duration of a Rust call (`parallel_for_fn`) keep the slot in that call, the
+///   panic is resumed by [CallbackPanic::resume] once OpenCV returns;
+/// * the callback objects owned by OpenCV (`MinProblemSolver::Function`, `IFrameSource`) keep the slot next to the
+///   Rust implementation, the trampoline reports the failure and the C++ shim throws `cv::Exception`, so the OpenCV
+///   call that invoked the callback returns `Err` at its call site, including the case when the callback was invoked in
+///   a worker thread.
+///
+/// After the first panic the callback is not called again through the same slot. The callbacks that have no way to
+/// report a failure (the plain function pointer callbacks like the `highgui` ones, the error handler and the
+/// destructors) go through [callback_abort_on_panic] instead.
+pub struct CallbackPanic {
+	panicked: AtomicBool,
+	payload: Mutex<Option<Box<dyn Any + Send>>>,
+}
+
+impl CallbackPanic {
+	#[inline]
+	pub fn new() -> Self {
+		Self {
+			panicked: AtomicBool::new(false),
+			payload: Mutex::new(None),
+		}
+	}
+
+	/// Calls the callback body, returns `None` if it panics or if one of the previous calls through this slot panicked
+	///
+	/// Only the first panic is kept, it can be caught concurrently in several threads.
+	#[inline]
+	pub fn catch<R>(&self, f: impl FnOnce() -> R) -> Option<R> {
+		if self.panicked.load(Ordering::Relaxed) {
+			return None;
+		}
+		match panic::catch_unwind(AssertUnwindSafe(f)) {
+			Ok(ret) => Some(ret),
+			Err(e) => {
+				self.panicked.store(true, Ordering::Relaxed);
+				self.payload.lock().unwrap_or_else(PoisonError::into_inner).get_or_insert(e);
+				None
+			}
+		}
+	}
+
+	/// Same as [CallbackPanic::catch], but writes the result of the callback to `ocvrs_return`
+	///
+	/// Returns `false` without writing anything if the callback didn't complete, the C++ side reports the failure then.
+	///
+	/// # Safety
+	/// `ocvrs_return` must be valid for writes.
+	#[inline]
+	pub unsafe fn catch_into<R>(&self, ocvrs_return: *mut R, f: impl FnOnce() -> R) -> bool {
+		match self.catch(f) {
+			Some(ret) => {
+				ocvrs_return.write(ret);
+				true
+			}
+			None => false,
+		}
+	}
+
+	/// Resumes the panic caught by [CallbackPanic::catch], if any
+	#[inline]
+	pub fn resume(self) {
+		if let Some(e) = self.payload.into_inner().unwrap_or_else(PoisonError::into_inner) {
+			panic::resume_unwind(e);
+		}
+	}
+}
+
+impl Default for CallbackPanic {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Calls the body of the Rust callback that can't report a failure to OpenCV, aborts the process if it panics
+///
+/// There is neither a value to return to the C++ side nor a call to resume the panic in, so the behavior is the same as
+/// for a panic escaping an `extern "C"` function. The panic message is printed by the panic hook before the abort.
+#[inline]
+pub fn callback_abort_on_panic<R>(f: impl FnOnce() -> R) -> R {
+	panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|_| process::abort())
+}
+
 /// The return type of this function goes into `receive_string`
 #[no_mangle]
 unsafe extern "C" fn ocvrs_create_string(s: *const c_char) -> *mut String {
@@ -118,7 +242,7 @@ unsafe extern "C" fn ocvrs_create_string(s: *const c_char) -> *mut String {
 
 /// The return type of this function goes into `receive_byte_string`
 #[no_mangle]
-unsafe extern "C" fn ocvrs_create_byte_string(v: *const u8, len: size_t) -> *mut Vec<u8> {
+unsafe extern "C" fn ocvrs_create_byte_string(v: *const u8, len: usize) -> *mut Vec<u8> {
 	let v = slice::from_raw_parts(v, len).to_vec();
 	Box::into_raw(Box::new(v))
 }
@@ -131,6 +255,17 @@ pub unsafe fn receive_string(s: *mut String) -> String {
 	*Box::from_raw(s)
 }
 
+/// Borrows the string data owned by the C++ side, the caller must ensure that the data outlives the chosen lifetime
+#[inline]
+pub unsafe fn receive_string_ref<'r>(s: *const c_void) -> Result<&'r str> {
+	if s.is_null() {
+		return Err(Error::new(core::StsNullPtr, "Got null pointer for receive_string_ref()"));
+	}
+	CStr::from_ptr(s as *const c_char)
+		.to_str()
+		.map_err(|e| Error::new(core::StsBadArg, format!("C++ string is not valid UTF-8: {}", e)))
+}
+
 #[inline]
 pub unsafe fn receive_byte_string(s: *mut Vec<u8>) -> Vec<u8> {
 	if s.is_null() {
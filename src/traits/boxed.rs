@@ -1,4 +1,4 @@
-use std::ffi::c_void;
+use std::{ffi::c_void, fmt, marker::PhantomData, mem::ManuallyDrop, ops::Deref};
 
 pub trait Boxed: Sized {
 	/// Wrap the specified raw pointer
@@ -25,6 +25,45 @@ pub trait Boxed: Sized {
 	fn as_raw_mut(&mut self) -> *mut c_void;
 }
 
+/// Borrowed view of the boxed object owned by some other object, e.g. a class field
+///
+/// It dereferences to the target type so all the const methods are available, but the underlying object is never
+/// freed by it. The lifetime ties the view to the owner, so the owner can't be modified or dropped while the view is
+/// alive.
+pub struct BoxedRef<'r, T: Boxed> {
+	reference: ManuallyDrop<T>,
+	referenced_object: PhantomData<&'r T>,
+}
+
+impl<T: Boxed> BoxedRef<'_, T> {
+	/// Wrap the specified raw pointer
+	/// # Safety
+	/// Caller must ensure that the passed pointer is pointing to a valid object data that outlives the returned reference
+	#[inline]
+	pub unsafe fn from_raw(ptr: *const c_void) -> Self {
+		Self {
+			reference: ManuallyDrop::new(T::from_raw(ptr as *mut c_void)),
+			referenced_object: PhantomData,
+		}
+	}
+}
+
+impl<T: Boxed> Deref for BoxedRef<'_, T> {
+	type Target = T;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		&self.reference
+	}
+}
+
+impl<T: Boxed + fmt::Debug> fmt::Debug for BoxedRef<'_, T> {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(&**self, f)
+	}
+}
+
 #[macro_export]
 macro_rules! opencv_type_boxed {
 	($type: ty) => {
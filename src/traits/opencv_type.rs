@@ -1,7 +1,10 @@
-use std::ffi::{c_void, CString};
+use std::borrow::Cow;
+use std::ffi::{c_void, CStr, CString};
+use std::marker::PhantomData;
 use std::os::raw::c_char;
+use std::path::Path;
 
-use crate::Result;
+use crate::{core, Error, Result};
 
 /// Common trait of all OpenCV related types, helps with generic handling of FFI marshalling
 ///
@@ -268,6 +271,177 @@ impl OpenCVTypeArg<'_> for &str {
 	}
 }
 
+/// The borrowed string that is already nul-terminated is passed without copying, the owned string is moved into the
+/// [CString] and only the other borrowed strings are copied
+impl<'a> OpenCVTypeArg<'a> for Cow<'a, str> {
+	type ExternContainer = Cow<'a, CStr>;
+
+	#[inline]
+	fn opencv_into_extern_container(self) -> Result<Self::ExternContainer> {
+		match self {
+			Cow::Borrowed(s) => match CStr::from_bytes_with_nul(s.as_bytes()) {
+				Ok(s) => Ok(Cow::Borrowed(s)),
+				Err(_) => CString::new(s).map(Cow::Owned).map_err(|e| e.into()),
+			},
+			Cow::Owned(s) => CString::new(s).map(Cow::Owned).map_err(|e| e.into()),
+		}
+	}
+
+	#[inline]
+	fn opencv_into_extern_container_nofail(self) -> Self::ExternContainer {
+		match self {
+			Cow::Borrowed(s) => match CStr::from_bytes_with_nul(s.as_bytes()) {
+				Ok(s) => Cow::Borrowed(s),
+				Err(_) => Cow::Owned(cstring_new_nofail(s)),
+			},
+			Cow::Owned(s) => Cow::Owned(cstring_new_nofail(s)),
+		}
+	}
+}
+
+/// Borrowed binary data passed to the C++ functions taking the string with the binary data
+///
+/// Unlike [CString] it carries the length, so the data can contain 0 bytes and is not copied. The layout matches
+/// `ocvrs_byte_string_arg` in `ocvrs_common.hpp`.
+#[doc(hidden)]
+#[repr(C)]
+pub struct ByteStringArg<'a> {
+	data: *const u8,
+	size: usize,
+	_d: PhantomData<&'a [u8]>,
+}
+
+impl<'a> OpenCVTypeArg<'a> for &'a [u8] {
+	type ExternContainer = ByteStringArg<'a>;
+
+	#[inline]
+	fn opencv_into_extern_container_nofail(self) -> Self::ExternContainer {
+		ByteStringArg {
+			data: self.as_ptr(),
+			size: self.len(),
+			_d: PhantomData,
+		}
+	}
+}
+
+impl<'a> OpenCVTypeExternContainer<'a> for ByteStringArg<'a> {
+	type ExternSend = *const c_void;
+	type ExternSendMut = *mut c_void;
+
+	#[inline]
+	fn opencv_as_extern(&self) -> Self::ExternSend {
+		self as *const Self as _
+	}
+
+	#[inline]
+	fn opencv_as_extern_mut(&mut self) -> Self::ExternSendMut {
+		self as *mut Self as _
+	}
+
+	#[inline]
+	fn opencv_into_extern(self) -> Self::ExternSendMut {
+		unimplemented!("Borrowed binary data can't be passed by value")
+	}
+}
+
+/// Converts the path into the narrow string representation that OpenCV passes to the C runtime file functions
+///
+/// On Unix the raw bytes of the path are used as is. On Windows the path is converted to the active ANSI code page
+/// because that is how the narrow C runtime functions interpret the file names, `None` is returned if the path
+/// contains characters that can't be represented in that code page.
+#[cfg(unix)]
+fn path_to_bytes(path: &Path) -> Option<Vec<u8>> {
+	use std::os::unix::ffi::OsStrExt;
+	Some(path.as_os_str().as_bytes().to_vec())
+}
+
+#[cfg(windows)]
+fn path_to_bytes(path: &Path) -> Option<Vec<u8>> {
+	use std::convert::TryFrom;
+	use std::os::windows::ffi::OsStrExt;
+	use std::ptr;
+
+	const CP_ACP: u32 = 0;
+
+	extern "system" {
+		fn WideCharToMultiByte(
+			code_page: u32,
+			flags: u32,
+			wide_char_str: *const u16,
+			wide_char_len: i32,
+			multi_byte_str: *mut u8,
+			multi_byte_len: i32,
+			default_char: *const u8,
+			used_default_char: *mut i32,
+		) -> i32;
+	}
+
+	let wide = path.as_os_str().encode_wide().collect::<Vec<_>>();
+	if wide.is_empty() {
+		return Some(vec![]);
+	}
+	let wide_len = i32::try_from(wide.len()).ok()?;
+	let mut used_default = 0;
+	let len = unsafe {
+		WideCharToMultiByte(
+			CP_ACP,
+			0,
+			wide.as_ptr(),
+			wide_len,
+			ptr::null_mut(),
+			0,
+			ptr::null(),
+			&mut used_default,
+		)
+	};
+	if len <= 0 || used_default != 0 {
+		return None;
+	}
+	let mut out = vec![0; len as usize];
+	let len = unsafe {
+		WideCharToMultiByte(
+			CP_ACP,
+			0,
+			wide.as_ptr(),
+			wide_len,
+			out.as_mut_ptr(),
+			len,
+			ptr::null(),
+			ptr::null_mut(),
+		)
+	};
+	if len <= 0 {
+		return None;
+	}
+	out.truncate(len as usize);
+	Some(out)
+}
+
+#[cfg(not(any(unix, windows)))]
+fn path_to_bytes(path: &Path) -> Option<Vec<u8>> {
+	path.to_str().map(|s| s.as_bytes().to_vec())
+}
+
+impl OpenCVTypeArg<'_> for &Path {
+	type ExternContainer = CString;
+
+	#[inline]
+	fn opencv_into_extern_container(self) -> Result<Self::ExternContainer> {
+		let bytes = path_to_bytes(self).ok_or_else(|| {
+			Error::new(
+				core::StsBadArg,
+				format!("Passed path can't be represented in the system encoding: {}", self.display()),
+			)
+		})?;
+		CString::new(bytes).map_err(|e| e.into())
+	}
+
+	#[inline]
+	fn opencv_into_extern_container_nofail(self) -> Self::ExternContainer {
+		cstring_new_nofail(path_to_bytes(self).unwrap_or_else(|| self.to_string_lossy().into_owned().into_bytes()))
+	}
+}
+
 impl OpenCVTypeExternContainer<'_> for CString {
 	type ExternSend = *const c_char;
 	type ExternSendMut = *mut c_char;
@@ -288,6 +462,26 @@ impl OpenCVTypeExternContainer<'_> for CString {
 	}
 }
 
+impl OpenCVTypeExternContainer<'_> for Cow<'_, CStr> {
+	type ExternSend = *const c_char;
+	type ExternSendMut = *mut c_char;
+
+	#[inline]
+	fn opencv_as_extern(&self) -> Self::ExternSend {
+		self.as_ptr()
+	}
+
+	#[inline]
+	fn opencv_as_extern_mut(&mut self) -> Self::ExternSendMut {
+		self.as_ptr() as _
+	}
+
+	#[inline]
+	fn opencv_into_extern(self) -> Self::ExternSendMut {
+		unimplemented!("Same as for CString")
+	}
+}
+
 impl OpenCVType<'_> for Vec<u8> {
 	type Arg = Self;
 	type ExternReceive = *mut c_void;
@@ -39,6 +39,87 @@ template<typename T> inline void ocvrs_input_output_array(T* instance, Result<vo
 	ocvrs_ioa(base##s) \
 	ocvrs_ioa(base##w)
 
+// The Rust callbacks return 0 on success, otherwise the error code of the failure, a panic in the Rust implementation
+// is reported as StsError. The failure is turned into an exception so the solver call that invoked the callback fails.
+class RustMinProblemSolverFunction : public cv::MinProblemSolver::Function {
+public:
+	typedef int (*GetDims)(const void*, int*);
+	typedef int (*GetGradientEps)(const void*, double*);
+	typedef int (*Calc)(const void*, const double*, double*);
+	typedef int (*GetGradient)(void*, const double*, double*, bool*);
+	typedef void (*Drop)(void*);
+
+	RustMinProblemSolverFunction(void* userdata, GetDims get_dims, GetGradientEps get_gradient_eps, Calc calc, GetGradient get_gradient, Drop drop)
+		: userdata(userdata), get_dims(get_dims), get_gradient_eps(get_gradient_eps), calc_(calc), get_gradient(get_gradient), drop(drop) {}
+
+	~RustMinProblemSolverFunction() {
+		drop(userdata);
+	}
+
+	int getDims() const override {
+		int out;
+		check(get_dims(userdata, &out), "getDims");
+		return out;
+	}
+
+	double getGradientEps() const override {
+		double out;
+		check(get_gradient_eps(userdata, &out), "getGradientEps");
+		return out;
+	}
+
+	double calc(const double* x) const override {
+		double out;
+		check(calc_(userdata, x, &out), "calc");
+		return out;
+	}
+
+	void getGradient(const double* x, double* grad) override {
+		bool implemented;
+		check(get_gradient(userdata, x, grad, &implemented), "getGradient");
+		if (!implemented) {
+			cv::MinProblemSolver::Function::getGradient(x, grad);
+		}
+	}
+
+private:
+	void* userdata;
+	GetDims get_dims;
+	GetGradientEps get_gradient_eps;
+	Calc calc_;
+	GetGradient get_gradient;
+	Drop drop;
+
+	static void check(int status, const char* method) {
+		if (status != 0) {
+			CV_Error_(status, ("Rust implementation of MinProblemSolver::Function::%s() failed", method));
+		}
+	}
+};
+
+class RustParallelLoopBody : public cv::ParallelLoopBody {
+public:
+	typedef void (*Body)(const void*, cv::Range*);
+
+	RustParallelLoopBody(const void* userdata, Body body) : userdata(userdata), body(body) {}
+
+	void operator()(const cv::Range& range) const override {
+		body(userdata, new cv::Range(range));
+	}
+
+private:
+	const void* userdata;
+	Body body;
+};
+
+// _InputArray::init() is protected, this class allows constructing the arrays pointing to the contiguous Rust data
+class RustDataArray : public cv::_InputOutputArray {
+public:
+	RustDataArray(int flags, void* data, int n) {
+		init(cv::_InputArray::FIXED_TYPE + cv::_InputArray::FIXED_SIZE + cv::_InputArray::MATX + flags, data, cv::Size(n, 1));
+	}
+};
+
 extern "C" {
 	void cv_manual_Mat_size(const cv::Mat* instance, Result<cv::Size>* ocvrs_return) {
 		try {
@@ -79,6 +160,53 @@ extern "C" {
 		return instance->ptr != instance->sliceEnd;
 	}
 
+	void cv_manual_PtrOfMinProblemSolver_Function_new(
+		void* userdata,
+		RustMinProblemSolverFunction::GetDims get_dims,
+		RustMinProblemSolverFunction::GetGradientEps get_gradient_eps,
+		RustMinProblemSolverFunction::Calc calc,
+		RustMinProblemSolverFunction::GetGradient get_gradient,
+		RustMinProblemSolverFunction::Drop drop,
+		Result<void*>* ocvrs_return
+	) {
+		try {
+			cv::Ptr<cv::MinProblemSolver::Function> f = cv::makePtr<RustMinProblemSolverFunction>(userdata, get_dims, get_gradient_eps, calc, get_gradient, drop);
+			Ok<void*>(new cv::Ptr<cv::MinProblemSolver::Function>(f), ocvrs_return);
+		} OCVRS_CATCH(Result<void*>, ocvrs_return)
+	}
+
+	void cv_manual_parallel_for_(const cv::Range* range, const void* userdata, RustParallelLoopBody::Body body, double nstripes, Result_void* ocvrs_return) {
+		try {
+			cv::parallel_for_(*range, RustParallelLoopBody(userdata, body), nstripes);
+			Ok(ocvrs_return);
+		} OCVRS_CATCH(Result_void, ocvrs_return)
+	}
+
+	void cv_manual_redirectError(cv::ErrorCallback callback, Result_void* ocvrs_return) {
+		try {
+			cv::redirectError(callback);
+			Ok(ocvrs_return);
+		} OCVRS_CATCH(Result_void, ocvrs_return)
+	}
+
+	void cv_manual_InputArray_from_data(int type, const void* data, int n, Result<void*>* ocvrs_return) {
+		try {
+			Ok<void*>(new cv::_InputArray(RustDataArray(type + cv::ACCESS_READ, const_cast<void*>(data), n)), ocvrs_return);
+		} OCVRS_CATCH(Result<void*>, ocvrs_return)
+	}
+
+	void cv_manual_OutputArray_from_data(int type, void* data, int n, Result<void*>* ocvrs_return) {
+		try {
+			Ok<void*>(new cv::_OutputArray(RustDataArray(type + cv::ACCESS_WRITE, data, n)), ocvrs_return);
+		} OCVRS_CATCH(Result<void*>, ocvrs_return)
+	}
+
+	void cv_manual_InputOutputArray_from_data(int type, void* data, int n, Result<void*>* ocvrs_return) {
+		try {
+			Ok<void*>(new cv::_InputOutputArray(RustDataArray(type + cv::ACCESS_RW, data, n)), ocvrs_return);
+		} OCVRS_CATCH(Result<void*>, ocvrs_return)
+	}
+
 	void cv_InputArray_input_array(cv::_InputArray* instance, Result<void*>* ocvrs_return) { return ocvrs_input_array(instance, ocvrs_return); }
 	void cv_OutputArray_output_array(cv::_OutputArray* instance, Result<void*>* ocvrs_return) { return ocvrs_output_array(instance, ocvrs_return); }
 	void cv_InputOutputArray_input_output_array(cv::_InputOutputArray* instance, Result<void*>* ocvrs_return) { return ocvrs_input_output_array(instance, ocvrs_return); }
@@ -0,0 +1,220 @@
+#include "ocvrs_common.hpp"
+#include <algorithm>
+#include <opencv2/line_descriptor.hpp>
+
+template struct Result<void*>;
+
+typedef cv::line_descriptor::BinaryDescriptorMatcher ocvrs_BinaryDescriptorMatcher;
+
+// Line detectors exposed through the cv::Feature2D interface. The keylines are encoded as keypoints by the conversion
+// functions implemented on the Rust side, see `keyline_to_keypoint()` and `keypoint_to_keyline()`.
+typedef void (*OcvrsKeyLinesToKeyPoints)(const std::vector<cv::line_descriptor::KeyLine>*, std::vector<cv::KeyPoint>*);
+typedef void (*OcvrsKeyPointsToKeyLines)(const std::vector<cv::KeyPoint>*, int, std::vector<cv::line_descriptor::KeyLine>*);
+
+class OcvrsBinaryDescriptorFeature2D : public cv::Feature2D {
+	cv::Ptr<cv::line_descriptor::BinaryDescriptor> descriptor;
+	OcvrsKeyLinesToKeyPoints keylines_to_keypoints;
+	OcvrsKeyPointsToKeyLines keypoints_to_keylines;
+
+	std::vector<cv::line_descriptor::KeyLine> to_keylines(const std::vector<cv::KeyPoint>& keypoints) const {
+		std::vector<cv::line_descriptor::KeyLine> out;
+		keypoints_to_keylines(&keypoints, descriptor->getReductionRatio(), &out);
+		return out;
+	}
+
+public:
+	OcvrsBinaryDescriptorFeature2D(const cv::Ptr<cv::line_descriptor::BinaryDescriptor>& descriptor, OcvrsKeyLinesToKeyPoints keylines_to_keypoints, OcvrsKeyPointsToKeyLines keypoints_to_keylines) : descriptor(descriptor), keylines_to_keypoints(keylines_to_keypoints), keypoints_to_keylines(keypoints_to_keylines) {}
+
+	void detect(cv::InputArray image, std::vector<cv::KeyPoint>& keypoints, cv::InputArray mask) override {
+		std::vector<cv::line_descriptor::KeyLine> keylines;
+		descriptor->detect(image.getMat(), keylines, mask.getMat());
+		keylines_to_keypoints(&keylines, &keypoints);
+	}
+
+	void compute(cv::InputArray image, std::vector<cv::KeyPoint>& keypoints, cv::OutputArray descriptors) override {
+		std::vector<cv::line_descriptor::KeyLine> keylines = to_keylines(keypoints);
+		cv::Mat out;
+		descriptor->compute(image.getMat(), keylines, out);
+		keylines_to_keypoints(&keylines, &keypoints);
+		out.copyTo(descriptors);
+	}
+
+	void detectAndCompute(cv::InputArray image, cv::InputArray mask, std::vector<cv::KeyPoint>& keypoints, cv::OutputArray descriptors, bool useProvidedKeypoints) override {
+		std::vector<cv::line_descriptor::KeyLine> keylines;
+		cv::Mat out;
+		if (useProvidedKeypoints) {
+			keylines = to_keylines(keypoints);
+			descriptor->compute(image.getMat(), keylines, out);
+		} else {
+			(*descriptor)(image, mask, keylines, out, false, false);
+		}
+		keylines_to_keypoints(&keylines, &keypoints);
+		out.copyTo(descriptors);
+	}
+
+	// in bytes as expected from Feature2D, the binary LBD descriptor always has 256 bits
+	int descriptorSize() const override { return 32; }
+	int descriptorType() const override { return descriptor->descriptorType(); }
+	int defaultNorm() const override { return descriptor->defaultNorm(); }
+	bool empty() const override { return descriptor.empty(); }
+	cv::String getDefaultName() const override { return "Feature2D.BinaryDescriptor"; }
+};
+
+class OcvrsLSDDetectorFeature2D : public cv::Feature2D {
+	cv::Ptr<cv::line_descriptor::LSDDetector> detector;
+	int scale;
+	int num_octaves;
+	OcvrsKeyLinesToKeyPoints keylines_to_keypoints;
+
+public:
+	OcvrsLSDDetectorFeature2D(const cv::Ptr<cv::line_descriptor::LSDDetector>& detector, int scale, int num_octaves, OcvrsKeyLinesToKeyPoints keylines_to_keypoints) : detector(detector), scale(scale), num_octaves(num_octaves), keylines_to_keypoints(keylines_to_keypoints) {}
+
+	void detect(cv::InputArray image, std::vector<cv::KeyPoint>& keypoints, cv::InputArray mask) override {
+		std::vector<cv::line_descriptor::KeyLine> keylines;
+		detector->detect(image.getMat(), keylines, scale, num_octaves, mask.getMat());
+		keylines_to_keypoints(&keylines, &keypoints);
+	}
+
+	bool empty() const override { return detector.empty(); }
+	cv::String getDefaultName() const override { return "Feature2D.LSDDetector"; }
+};
+
+// BinaryDescriptorMatcher exposed through the cv::DescriptorMatcher interface. The train descriptors are kept in the
+// collection of the base class and loaded into the MIH index of the wrapped matcher on train(), which the base class
+// calls before every query, so the index is only rebuilt after the collection changes.
+class OcvrsBinaryDescriptorMatcherAdapter : public cv::DescriptorMatcher {
+	cv::Ptr<ocvrs_BinaryDescriptorMatcher> matcher;
+	bool dirty;
+
+public:
+	explicit OcvrsBinaryDescriptorMatcherAdapter(const cv::Ptr<ocvrs_BinaryDescriptorMatcher>& matcher) : matcher(matcher), dirty(true) {}
+
+	void add(cv::InputArrayOfArrays descriptors) override {
+		cv::DescriptorMatcher::add(descriptors);
+		dirty = true;
+	}
+
+	void clear() override {
+		cv::DescriptorMatcher::clear();
+		matcher->clear();
+		dirty = true;
+	}
+
+	bool empty() const override {
+		return trainDescCollection.empty();
+	}
+
+	void train() override {
+		if (!dirty) {
+			return;
+		}
+		matcher->clear();
+		matcher->add(trainDescCollection);
+		matcher->train();
+		dirty = false;
+	}
+
+	bool isMaskSupported() const override {
+		return true;
+	}
+
+	cv::Ptr<cv::DescriptorMatcher> clone(bool emptyTrainData) const override {
+		cv::Ptr<ocvrs_BinaryDescriptorMatcher> copy = cv::makePtr<ocvrs_BinaryDescriptorMatcher>();
+		cv::Ptr<OcvrsBinaryDescriptorMatcherAdapter> out = cv::makePtr<OcvrsBinaryDescriptorMatcherAdapter>(copy);
+		if (!emptyTrainData) {
+			out->add(trainDescCollection);
+		}
+		return out;
+	}
+
+protected:
+	void knnMatchImpl(cv::InputArray queryDescriptors, std::vector<std::vector<cv::DMatch>>& matches, int k, cv::InputArrayOfArrays masks, bool compactResult) override {
+		std::vector<cv::Mat> mask_vec;
+		if (!masks.empty()) {
+			masks.getMatVector(mask_vec);
+		}
+		matcher->knnMatch(queryDescriptors.getMat(), matches, k, mask_vec, compactResult);
+	}
+
+	void radiusMatchImpl(cv::InputArray queryDescriptors, std::vector<std::vector<cv::DMatch>>& matches, float maxDistance, cv::InputArrayOfArrays masks, bool compactResult) override {
+		std::vector<cv::Mat> mask_vec;
+		if (!masks.empty()) {
+			masks.getMatVector(mask_vec);
+		}
+		matcher->radiusMatch(queryDescriptors.getMat(), matches, maxDistance, mask_vec, compactResult);
+	}
+};
+
+extern "C" {
+	// Matches the query sets with a single batched lookup in the MIH index. Copying of the sets into the combined query and
+	// distribution of the matches back to the sets is done in parallel.
+	void cv_manual_line_descriptor_BinaryDescriptorMatcher_matchBatch(ocvrs_BinaryDescriptorMatcher* instance, const cv::Mat* const* queries, size_t count, std::vector<std::vector<cv::DMatch>>* matches, Result_void* ocvrs_return) {
+		try {
+			const int sets = (int) count;
+			std::vector<int> offsets(count + 1, 0);
+			int cols = 0;
+			for (int i = 0; i < sets; i++) {
+				offsets[i + 1] = offsets[i] + queries[i]->rows;
+				if (queries[i]->rows > 0) {
+					cols = queries[i]->cols;
+				}
+			}
+			matches->assign(count, std::vector<cv::DMatch>());
+			if (offsets[sets] > 0) {
+				cv::Mat all(offsets[sets], cols, CV_8UC1);
+				cv::parallel_for_(cv::Range(0, sets), [&](const cv::Range& range) {
+					for (int i = range.start; i < range.end; i++) {
+						if (queries[i]->rows > 0) {
+							queries[i]->copyTo(all.rowRange(offsets[i], offsets[i + 1]));
+						}
+					}
+				});
+				std::vector<cv::DMatch> flat;
+				instance->match(all, flat);
+				std::stable_sort(flat.begin(), flat.end(), [](const cv::DMatch& a, const cv::DMatch& b) { return a.queryIdx < b.queryIdx; });
+				const auto by_query = [](const cv::DMatch& m, int query_idx) { return m.queryIdx < query_idx; };
+				cv::parallel_for_(cv::Range(0, sets), [&](const cv::Range& range) {
+					for (int i = range.start; i < range.end; i++) {
+						auto begin = std::lower_bound(flat.begin(), flat.end(), offsets[i], by_query);
+						auto end = std::lower_bound(begin, flat.end(), offsets[i + 1], by_query);
+						std::vector<cv::DMatch>& out = (*matches)[i];
+						out.assign(begin, end);
+						for (cv::DMatch& m : out) {
+							m.queryIdx -= offsets[i];
+						}
+					}
+				});
+			}
+			Ok(ocvrs_return);
+		} OCVRS_CATCH(Result_void, ocvrs_return)
+	}
+
+	void cv_manual_line_descriptor_BinaryDescriptor_detectAndCompute(const cv::line_descriptor::BinaryDescriptor* instance, const cv::_InputArray* image, const cv::_InputArray* mask, std::vector<cv::line_descriptor::KeyLine>* keylines, cv::Mat* descriptors, Result_void* ocvrs_return) {
+		try {
+			// operator() detects the keylines and computes the descriptors on the same pyramid
+			(*instance)(*image, *mask, *keylines, *descriptors, false, false);
+			Ok(ocvrs_return);
+		} OCVRS_CATCH(Result_void, ocvrs_return)
+	}
+
+	void cv_manual_line_descriptor_BinaryDescriptor_toFeature2D(const cv::Ptr<cv::line_descriptor::BinaryDescriptor>* descriptor, OcvrsKeyLinesToKeyPoints keylines_to_keypoints, OcvrsKeyPointsToKeyLines keypoints_to_keylines, Result<void*>* ocvrs_return) {
+		try {
+			CV_Assert(!descriptor->empty());
+			Ok<void*>(new cv::Ptr<cv::Feature2D>(cv::makePtr<OcvrsBinaryDescriptorFeature2D>(*descriptor, keylines_to_keypoints, keypoints_to_keylines)), ocvrs_return);
+		} OCVRS_CATCH(Result<void*>, ocvrs_return)
+	}
+
+	void cv_manual_line_descriptor_LSDDetector_toFeature2D(const cv::Ptr<cv::line_descriptor::LSDDetector>* detector, int scale, int num_octaves, OcvrsKeyLinesToKeyPoints keylines_to_keypoints, Result<void*>* ocvrs_return) {
+		try {
+			CV_Assert(!detector->empty());
+			Ok<void*>(new cv::Ptr<cv::Feature2D>(cv::makePtr<OcvrsLSDDetectorFeature2D>(*detector, scale, num_octaves, keylines_to_keypoints)), ocvrs_return);
+		} OCVRS_CATCH(Result<void*>, ocvrs_return)
+	}
+
+	void cv_manual_line_descriptor_BinaryDescriptorMatcher_toDescriptorMatcher(const cv::Ptr<ocvrs_BinaryDescriptorMatcher>* matcher, Result<void*>* ocvrs_return) {
+		try {
+			CV_Assert(!matcher->empty());
+			Ok<void*>(new cv::Ptr<cv::DescriptorMatcher>(cv::makePtr<OcvrsBinaryDescriptorMatcherAdapter>(*matcher)), ocvrs_return);
+		} OCVRS_CATCH(Result<void*>, ocvrs_return)
+	}
+}
@@ -0,0 +1,23 @@
+#include "ocvrs_common.hpp"
+#include <opencv2/objdetect.hpp>
+
+// QRCodeEncoder was added in OpenCV 4.5.3
+#if (CV_VERSION_MAJOR == 4 && (CV_VERSION_MINOR > 5 || (CV_VERSION_MINOR == 5 && CV_VERSION_REVISION >= 3))) || CV_VERSION_MAJOR > 4
+
+extern "C" {
+	void cv_manual_QRCodeEncoder_encode_bytes(cv::QRCodeEncoder* instance, const ocvrs_byte_string_arg* encoded_info, const cv::_OutputArray* qrcode, Result_void* ocvrs_return) {
+		try {
+			instance->encode(cv::String(encoded_info->data, encoded_info->size), *qrcode);
+			Ok(ocvrs_return);
+		} OCVRS_CATCH(Result_void, ocvrs_return)
+	}
+
+	void cv_manual_QRCodeEncoder_encodeStructuredAppend_bytes(cv::QRCodeEncoder* instance, const ocvrs_byte_string_arg* encoded_info, const cv::_OutputArray* qrcodes, Result_void* ocvrs_return) {
+		try {
+			instance->encodeStructuredAppend(cv::String(encoded_info->data, encoded_info->size), *qrcodes);
+			Ok(ocvrs_return);
+		} OCVRS_CATCH(Result_void, ocvrs_return)
+	}
+}
+
+#endif
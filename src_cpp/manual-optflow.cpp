@@ -0,0 +1,17 @@
+#include "ocvrs_common.hpp"
+#include <opencv2/optflow.hpp>
+
+template struct Result<void*>;
+
+extern "C" {
+	// `prior` is nullable, the generated constructor can't express the default empty prior
+	void cv_manual_optflow_OpticalFlowPCAFlow_new(const char* prior, const cv::Size* basis_size, float sparse_rate, float retained_corners_fraction, float occlusions_threshold, float damping_factor, float clahe_clip, Result<void*>* ocvrs_return) {
+		try {
+			cv::Ptr<const cv::optflow::PCAPrior> pca_prior;
+			if (prior) {
+				pca_prior = cv::makePtr<const cv::optflow::PCAPrior>(prior);
+			}
+			Ok<void*>(new cv::optflow::OpticalFlowPCAFlow(pca_prior, *basis_size, sparse_rate, retained_corners_fraction, occlusions_threshold, damping_factor, clahe_clip), ocvrs_return);
+		} OCVRS_CATCH(Result<void*>, ocvrs_return)
+	}
+}
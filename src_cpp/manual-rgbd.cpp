@@ -0,0 +1,31 @@
+#include "ocvrs_common.hpp"
+#include <opencv2/rgbd.hpp>
+
+extern "C" {
+	// `operator()` of the following classes is not exported by the binding generator
+
+	void cv_manual_rgbd_RgbdNormals_apply(const cv::rgbd::RgbdNormals* instance, const cv::_InputArray* points, cv::Mat* normals, Result_void* ocvrs_return) {
+		try {
+			(*instance)(*points, *normals);
+			Ok(ocvrs_return);
+		} OCVRS_CATCH(Result_void, ocvrs_return)
+	}
+
+	void cv_manual_rgbd_RgbdPlane_apply(cv::rgbd::RgbdPlane* instance, const cv::_InputArray* points3d, const cv::_InputArray* normals, cv::Mat* mask, std::vector<cv::Vec4f>* plane_coefficients, Result_void* ocvrs_return) {
+		try {
+			if (normals->empty()) {
+				(*instance)(*points3d, *mask, *plane_coefficients);
+			} else {
+				(*instance)(*points3d, *normals, *mask, *plane_coefficients);
+			}
+			Ok(ocvrs_return);
+		} OCVRS_CATCH(Result_void, ocvrs_return)
+	}
+
+	void cv_manual_rgbd_DepthCleaner_apply(const cv::rgbd::DepthCleaner* instance, const cv::_InputArray* points, cv::Mat* depth, Result_void* ocvrs_return) {
+		try {
+			(*instance)(*points, *depth);
+			Ok(ocvrs_return);
+		} OCVRS_CATCH(Result_void, ocvrs_return)
+	}
+}
@@ -0,0 +1,62 @@
+#include "ocvrs_common.hpp"
+#include <opencv2/stitching.hpp>
+
+template struct Result<void*>;
+
+extern "C" {
+	// The kind values are assigned by `WarperKind::to_raw()`, `a` and `b` are only used by the Compressed and Panini
+	// projections
+	void cv_manual_stitching_createWarper(int kind, float a, float b, Result<void*>* ocvrs_return) {
+		try {
+			cv::Ptr<cv::WarperCreator> warper;
+			switch (kind) {
+				case 0: warper = cv::makePtr<cv::PlaneWarper>(); break;
+				case 1: warper = cv::makePtr<cv::AffineWarper>(); break;
+				case 2: warper = cv::makePtr<cv::CylindricalWarper>(); break;
+				case 3: warper = cv::makePtr<cv::SphericalWarper>(); break;
+				case 4: warper = cv::makePtr<cv::FisheyeWarper>(); break;
+				case 5: warper = cv::makePtr<cv::StereographicWarper>(); break;
+				case 6: warper = cv::makePtr<cv::CompressedRectilinearWarper>(a, b); break;
+				case 7: warper = cv::makePtr<cv::CompressedRectilinearPortraitWarper>(a, b); break;
+				case 8: warper = cv::makePtr<cv::PaniniWarper>(a, b); break;
+				case 9: warper = cv::makePtr<cv::PaniniPortraitWarper>(a, b); break;
+				case 10: warper = cv::makePtr<cv::MercatorWarper>(); break;
+				case 11: warper = cv::makePtr<cv::TransverseMercatorWarper>(); break;
+				default: CV_Error(cv::Error::StsOutOfRange, "Unknown warper kind");
+			}
+			Ok<void*>(new cv::Ptr<cv::WarperCreator>(warper), ocvrs_return);
+		} OCVRS_CATCH(Result<void*>, ocvrs_return)
+	}
+
+	void cv_manual_stitching_createBlender(int kind, float sharpness, int num_bands, Result<void*>* ocvrs_return) {
+		try {
+			cv::Ptr<cv::detail::Blender> blender;
+			switch (kind) {
+				case 0: blender = cv::detail::Blender::createDefault(cv::detail::Blender::NO); break;
+				case 1: blender = cv::makePtr<cv::detail::FeatherBlender>(sharpness); break;
+				case 2:
+					CV_Assert(num_bands > 0);
+					blender = cv::makePtr<cv::detail::MultiBandBlender>(false, num_bands);
+					break;
+				default: CV_Error(cv::Error::StsOutOfRange, "Unknown blender kind");
+			}
+			Ok<void*>(new cv::Ptr<cv::detail::Blender>(blender), ocvrs_return);
+		} OCVRS_CATCH(Result<void*>, ocvrs_return)
+	}
+
+	void cv_manual_stitching_createSeamFinder(int kind, Result<void*>* ocvrs_return) {
+		try {
+			cv::Ptr<cv::detail::SeamFinder> seam_finder;
+			switch (kind) {
+				case 0: seam_finder = cv::makePtr<cv::detail::NoSeamFinder>(); break;
+				case 1: seam_finder = cv::makePtr<cv::detail::VoronoiSeamFinder>(); break;
+				case 2: seam_finder = cv::makePtr<cv::detail::DpSeamFinder>(cv::detail::DpSeamFinder::COLOR); break;
+				case 3: seam_finder = cv::makePtr<cv::detail::DpSeamFinder>(cv::detail::DpSeamFinder::COLOR_GRAD); break;
+				case 4: seam_finder = cv::makePtr<cv::detail::GraphCutSeamFinder>(cv::detail::GraphCutSeamFinderBase::COST_COLOR); break;
+				case 5: seam_finder = cv::makePtr<cv::detail::GraphCutSeamFinder>(cv::detail::GraphCutSeamFinderBase::COST_COLOR_GRAD); break;
+				default: CV_Error(cv::Error::StsOutOfRange, "Unknown seam finder kind");
+			}
+			Ok<void*>(new cv::Ptr<cv::detail::SeamFinder>(seam_finder), ocvrs_return);
+		} OCVRS_CATCH(Result<void*>, ocvrs_return)
+	}
+}
@@ -0,0 +1,61 @@
+#include "ocvrs_common.hpp"
+#include <opencv2/videostab.hpp>
+
+template struct Result<void*>;
+
+// The Rust callbacks return false when the implementation panicked, the panic is turned into an exception so the
+// stabilizer call that invoked the callback fails
+class RustFrameSource : public cv::videostab::IFrameSource {
+public:
+	typedef bool (*Reset)(void*);
+	typedef bool (*NextFrame)(void*, cv::Mat**);
+	typedef void (*Drop)(void*);
+
+	RustFrameSource(void* userdata, Reset reset, NextFrame next_frame, Drop drop)
+		: userdata(userdata), reset_(reset), next_frame(next_frame), drop(drop) {}
+
+	~RustFrameSource() {
+		drop(userdata);
+	}
+
+	void reset() override {
+		if (!reset_(userdata)) {
+			CV_Error(cv::Error::StsError, "Rust implementation of IFrameSource::reset() panicked");
+		}
+	}
+
+	// null returned from Rust marks the end of video, the stabilizers expect an empty Mat in this case
+	cv::Mat nextFrame() override {
+		cv::Mat* frame;
+		if (!next_frame(userdata, &frame)) {
+			CV_Error(cv::Error::StsError, "Rust implementation of IFrameSource::nextFrame() panicked");
+		}
+		if (!frame) {
+			return cv::Mat();
+		}
+		cv::Mat out = *frame;
+		delete frame;
+		return out;
+	}
+
+private:
+	void* userdata;
+	Reset reset_;
+	NextFrame next_frame;
+	Drop drop;
+};
+
+extern "C" {
+	void cv_manual_PtrOfIFrameSource_new(
+		void* userdata,
+		RustFrameSource::Reset reset,
+		RustFrameSource::NextFrame next_frame,
+		RustFrameSource::Drop drop,
+		Result<void*>* ocvrs_return
+	) {
+		try {
+			cv::Ptr<cv::videostab::IFrameSource> source = cv::makePtr<RustFrameSource>(userdata, reset, next_frame, drop);
+			Ok<void*>(new cv::Ptr<cv::videostab::IFrameSource>(source), ocvrs_return);
+		} OCVRS_CATCH(Result<void*>, ocvrs_return)
+	}
+}
@@ -0,0 +1,24 @@
+#include "ocvrs_common.hpp"
+#include <opencv2/xfeatures2d.hpp>
+
+template struct Result<void*>;
+
+// TEBLID was added in OpenCV 4.7.0
+#if (CV_VERSION_MAJOR == 4 && CV_VERSION_MINOR >= 7) || CV_VERSION_MAJOR > 4
+	#define OCVRS_HAS_TEBLID
+#endif
+
+extern "C" {
+	void cv_manual_xfeatures2d_TEBLID_create(float scale_factor, int n_bits, Result<void*>* ocvrs_return) {
+		try {
+#ifdef OCVRS_HAS_TEBLID
+			cv::Ptr<cv::Feature2D> ret = cv::xfeatures2d::TEBLID::create(scale_factor, n_bits);
+			return Ok<void*>(new cv::Ptr<cv::Feature2D>(ret), ocvrs_return);
+#else
+			(void) scale_factor;
+			(void) n_bits;
+			return Err<Result<void*>>(cv::Error::StsNotImplemented, "TEBLID requires OpenCV 4.7.0 or newer", ocvrs_return);
+#endif
+		} OCVRS_CATCH(Result<void*>, ocvrs_return)
+	}
+}
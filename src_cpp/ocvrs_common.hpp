@@ -21,7 +21,7 @@
 #define OCVRS_HANDLE(code, msg, return_type, return_name) Err<return_type>(code, msg, return_name)
 
 #define OCVRS_HANDLE_OPENCV(e, return_type, return_name) \
-OCVRS_HANDLE(e.code, e.what(), OCVRS_TYPE(return_type), return_name)
+Err<OCVRS_TYPE(return_type)>(e.code, e.what(), e.func.c_str(), return_name)
 
 #define OCVRS_HANDLE_UNSPECIFIED(return_type, return_name) \
 OCVRS_HANDLE(-99999, "unspecified error in OpenCV guts", OCVRS_TYPE(return_type), return_name)
@@ -37,15 +37,23 @@ catch (cv::Exception& e) { \
 extern "C" void* ocvrs_create_string(const char*);
 extern "C" void* ocvrs_create_byte_string(const char*, size_t);
 
+// binary string argument, defined as ByteStringArg in src/traits/opencv_type.rs
+struct ocvrs_byte_string_arg {
+	const char* data;
+	size_t size;
+};
+
 template<typename T> struct Result {
 	int error_code;
 	void* error_msg;
+	void* error_func;
 	T result;
 };
 
 struct Result_void {
 	int error_code;
 	void* error_msg;
+	void* error_func;
 };
 
 template<typename T, typename R> inline void Ok(T result, Result<R>* ocvrs_return) {
@@ -59,10 +67,30 @@ inline void Ok(Result_void* ocvrs_return) {
 	ocvrs_return->error_msg = NULL;
 }
 
-template<typename T> inline void Err(int code, const char* msg, T* ocvrs_return) {
+template<typename T> inline void Err(int code, const char* msg, const char* func, T* ocvrs_return) {
 	ocvrs_return->error_code = code;
 	ocvrs_return->error_msg = ocvrs_create_string(msg);
+	ocvrs_return->error_func = func && *func ? ocvrs_create_string(func) : NULL;
 	// it's ok to leave result uninitialized because the Rust implementation only assumes it as init if error_msg is NULL
 }
 
+template<typename T> inline void Err(int code, const char* msg, T* ocvrs_return) {
+	Err<T>(code, msg, NULL, ocvrs_return);
+}
+
+// pointer returned by the *_ref variants of the methods returning const references, strings are passed as C strings
+template<typename T> inline const void* ocvrs_ref(const T& val) {
+	return &val;
+}
+
+inline const void* ocvrs_ref(const std::string& val) {
+	return val.c_str();
+}
+
+#if CV_VERSION_MAJOR < 4
+inline const void* ocvrs_ref(const cv::String& val) {
+	return val.c_str();
+}
+#endif
+
 #endif
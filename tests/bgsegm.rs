@@ -0,0 +1,68 @@
+#![cfg(ocvrs_has_module_bgsegm)]
+
+use opencv::{
+	bgsegm::{self, BackgroundSubtractorKind},
+	core::{self, Mat, Rect, Scalar, CV_8U, CV_8UC3},
+	imgproc,
+	prelude::*,
+	Result,
+};
+
+/// Static gray background with a white square moving to the right
+fn frame(i: i32) -> Result<Mat> {
+	let mut frame = Mat::new_rows_cols_with_default(120, 160, CV_8UC3, Scalar::all(60.))?;
+	imgproc::rectangle(
+		&mut frame,
+		Rect::new(10 + i * 4, 40, 30, 30),
+		Scalar::all(255.),
+		imgproc::FILLED,
+		imgproc::LINE_8,
+		0,
+	)?;
+	Ok(frame)
+}
+
+#[test]
+fn common_interface() -> Result<()> {
+	for kind in [
+		BackgroundSubtractorKind::MOG,
+		BackgroundSubtractorKind::GMG,
+		BackgroundSubtractorKind::GSOC,
+		BackgroundSubtractorKind::CNT,
+		BackgroundSubtractorKind::LSBP,
+	] {
+		let mut subtractor = bgsegm::create_background_subtractor(kind)?;
+		for i in 0..20 {
+			let mask = subtractor.apply_mask(&frame(i)?, -1.)?;
+			assert_eq!(CV_8U, mask.typ(), "{:?}", kind);
+			assert_eq!(frame(i)?.size()?, mask.size()?, "{:?}", kind);
+		}
+	}
+	Ok(())
+}
+
+#[test]
+fn mog_foreground() -> Result<()> {
+	let mut mog = bgsegm::create_background_subtractor_mog(200, 5, 0.7, 0.)?;
+	let mut mask = Mat::default();
+	for i in 0..20 {
+		mask = mog.apply_mask(&frame(i)?, -1.)?;
+	}
+	// the leading edge of the square in the last frame is the foreground, the far corner is the background
+	assert_eq!(255, *mask.at_2d::<u8>(55, 10 + 19 * 4 + 27)?);
+	assert_eq!(0, *mask.at_2d::<u8>(110, 150)?);
+	assert!(core::count_non_zero(&mask)? > 0);
+	Ok(())
+}
+
+#[test]
+fn background_image() -> Result<()> {
+	let mut cnt = bgsegm::create_background_subtractor(BackgroundSubtractorKind::CNT)?;
+	for i in 0..20 {
+		cnt.apply_mask(&frame(i)?, -1.)?;
+	}
+	let background = cnt.background_image()?;
+	assert!(!background.empty());
+	assert_eq!(frame(0)?.size()?, background.size()?);
+	Ok(())
+}
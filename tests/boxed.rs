@@ -128,9 +128,7 @@ fn cast_descendant() -> Result<()> {
 
 	let image = Mat::new_rows_cols_with_default(1, 2, i32::typ(), Scalar::from(1.))?;
 	let depth = Mat::default();
-	let mask = Mat::default();
-	let normals = Mat::default();
-	let child = OdometryFrame::new(&image, &depth, &mask, &normals, 345)?;
+	let child = OdometryFrame::new(&image, &depth, None, None, 345)?;
 	assert_eq!(345, child.id());
 	assert_eq!(2, child.image().cols());
 	let mut base = RgbdFrame::from(child);
@@ -150,7 +148,7 @@ fn cast_descendant_fail() -> Result<()> {
 	use opencv::{
 		core,
 		stitching::{Detail_Blender, Detail_FeatherBlender, Detail_MultiBandBlender},
-		Error,
+		Error, ErrorKind,
 	};
 	use std::convert::TryFrom;
 
@@ -163,7 +161,7 @@ fn cast_descendant_fail() -> Result<()> {
 	if !matches!(
 		incorrect_child,
 		Err(Error {
-			code: core::StsBadArg,
+			kind: ErrorKind::Code(core::Code::StsBadArg),
 			..
 		})
 	) {
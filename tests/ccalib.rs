@@ -0,0 +1,65 @@
+#![cfg(ocvrs_has_module_ccalib)]
+
+use opencv::{
+	ccalib::{self, OmnidirCalibFlags, OmnidirCalibration, RectifyKind},
+	core::{Mat, Point2f, Point3f, Scalar, Size, Vec4d, Vector, CV_8UC1},
+	prelude::*,
+	Result,
+};
+
+fn camera() -> Result<OmnidirCalibration> {
+	Ok(OmnidirCalibration {
+		rms: 0.,
+		camera_matrix: Mat::from_slice_2d(&[[400., 0., 320.], [0., 400., 240.], [0., 0., 1.]])?,
+		xi: 1.,
+		distortion: Vec4d::default(),
+		rvecs: Vector::new(),
+		tvecs: Vector::new(),
+		used_views: vec![],
+	})
+}
+
+#[test]
+fn calib_flags() {
+	let mut flags = OmnidirCalibFlags::FIX_SKEW | OmnidirCalibFlags::FIX_K1;
+	flags |= OmnidirCalibFlags::FIX_XI;
+	assert!(flags.contains(OmnidirCalibFlags::FIX_SKEW | OmnidirCalibFlags::FIX_XI));
+	assert!(!flags.contains(OmnidirCalibFlags::USE_GUESS));
+	assert_eq!(
+		ccalib::CALIB_FIX_SKEW | ccalib::CALIB_FIX_K1 | ccalib::CALIB_FIX_XI,
+		i32::from(flags)
+	);
+	assert_eq!(OmnidirCalibFlags::NONE, OmnidirCalibFlags::default());
+}
+
+#[test]
+fn omnidir_project_undistort() -> Result<()> {
+	let camera = camera()?;
+	let zero = Mat::from_slice(&[0., 0., 0.])?;
+	let object_points = Vector::from_slice(&[Point3f::new(0.1, -0.2, 1.), Point3f::new(0., 0., 2.)]);
+	let image_points = camera.project_points(&object_points, &zero, &zero)?;
+	assert_eq!(2, image_points.len());
+	let center = image_points.get(1)?;
+	assert!(
+		(center.x - 320.).abs() < 1e-3 && (center.y - 240.).abs() < 1e-3,
+		"{:?}",
+		center
+	);
+
+	let undistorted = camera.undistort_points(&image_points)?;
+	let point = undistorted.get(0)?;
+	assert!((point - Point2f::new(0.1, -0.2)).norm() < 1e-4, "{:?}", point);
+
+	let image = Mat::new_rows_cols_with_default(480, 640, CV_8UC1, Scalar::all(128.))?;
+	let out = camera.undistort_image(&image, RectifyKind::Perspective, None, Size::new(320, 240))?;
+	assert_eq!(Size::new(320, 240), out.size()?);
+	Ok(())
+}
+
+#[test]
+fn random_pattern() -> Result<()> {
+	let pattern = ccalib::generate_random_pattern(200, 100)?;
+	assert_eq!(Size::new(200, 100), pattern.size()?);
+	assert_eq!(CV_8UC1, pattern.typ());
+	Ok(())
+}
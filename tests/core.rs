@@ -1,8 +1,8 @@
 use opencv::{
-	core::{self, Moments, Point2f, RotatedRect, Scalar, Size2f, Vec3b, CV_32S, CV_64F, CV_8U, CV_MAKETYPE},
+	core::{self, Moments, Point, Point2f, RotatedRect, Scalar, Size2f, Vec3b, CV_32S, CV_64F, CV_8U, CV_MAKETYPE},
 	prelude::*,
 	types::VectorOfMat,
-	Result,
+	Error, ErrorKind, Result, ResultExt,
 };
 
 #[test]
@@ -87,14 +87,315 @@ fn file_storage() -> Result<()> {
 	Ok(())
 }
 
+#[test]
+#[cfg(ocvrs_opencv_branch_4)]
+fn memory_storage() -> Result<()> {
+	use opencv::core::{FileStorage, MemoryStorage, StorageFormat};
+
+	let json = MemoryStorage::serialize(StorageFormat::Json, |fs| {
+		fs.write_i32("answer", 42)?;
+		fs.write_str("name", "memory")
+	})?;
+	assert!(json.trim_start().starts_with('{'));
+	let st = FileStorage::from_string(&json)?;
+	assert_eq!(42, st.get("answer")?.to_i32()?);
+	assert_eq!("memory", st.get("name")?.to_string()?);
+
+	let mut st = MemoryStorage::new(StorageFormat::Yaml)?;
+	st.write_f64("pi", 3.5)?;
+	let yaml = st.into_string()?;
+	assert!(yaml.starts_with("%YAML"));
+	assert_eq!(3.5, FileStorage::from_string(&yaml)?.get("pi")?.to_f64()?);
+	Ok(())
+}
+
 /// Make sure that arguments to min_max_loc are nullable
 #[test]
 fn min_max_loc() -> Result<()> {
 	let mut m = Mat::new_rows_cols_with_default(10, 10, Vec3b::typ(), Scalar::all(5.))?;
 	let (mut min_val, mut max_val) = (90., 90.);
 	*m.at_2d_mut(5, 5)? = Vec3b::from([10, 20, 30]);
-	core::min_max_loc(&m, Some(&mut min_val), Some(&mut max_val), None, None, &core::no_array())?;
+	core::min_max_loc(&m, Some(&mut min_val), Some(&mut max_val), None, None, None::<&Mat>)?;
 	assert_eq!(5., min_val);
 	assert_eq!(30., max_val);
 	Ok(())
 }
+
+/// Results of the functions with several out-parameters returned as a struct or tuple
+#[test]
+fn out_param_returns() -> Result<()> {
+	let mut m = Mat::new_rows_cols_with_default(4, 5, f64::typ(), Scalar::all(2.))?;
+	*m.at_2d_mut::<f64>(1, 3)? = -1.;
+	*m.at_2d_mut::<f64>(2, 0)? = 7.;
+	let res = core::min_max_loc_values(&m, None)?;
+	assert_eq!(-1., res.min_val);
+	assert_eq!(7., res.max_val);
+	assert_eq!(Point::new(3, 1), res.min_loc);
+	assert_eq!(Point::new(0, 2), res.max_loc);
+
+	let m = Mat::new_rows_cols_with_default(3, 3, Vec3b::typ(), Scalar::new(1., 2., 3., 0.))?;
+	let (mean, stddev) = core::mean_std_dev_values(&m, None)?;
+	assert_eq!(Scalar::new(1., 2., 3., 0.), mean);
+	assert_eq!(Scalar::all(0.), stddev);
+
+	let m = Mat::new_rows_cols_with_default(3, 3, core::CV_MAKETYPE(core::CV_64F, 5), Scalar::all(1.))?;
+	assert!(core::mean_std_dev_values(&m, None).is_err());
+	Ok(())
+}
+
+/// Minimize a function implemented in Rust with the C++ solver
+#[test]
+fn min_problem_solver_function_impl() -> Result<()> {
+	use opencv::core::{
+		DownhillSolver, MinProblemSolverFunctionImpl, MinProblemSolver_Function, Ptr, TermCriteria, TermCriteria_Type,
+	};
+
+	struct Paraboloid;
+
+	impl MinProblemSolverFunctionImpl for Paraboloid {
+		fn dims(&self) -> i32 {
+			2
+		}
+
+		fn calc(&self, x: &[f64]) -> f64 {
+			(x[0] - 1.).powi(2) + (x[1] + 2.).powi(2)
+		}
+	}
+
+	let f = Ptr::<dyn MinProblemSolver_Function>::from_impl(Paraboloid)?;
+	assert_eq!(2, f.get_dims()?);
+	let init_step = Mat::from_slice(&[0.5f64, 0.5])?;
+	let termcrit = TermCriteria::new(TermCriteria_Type::COUNT as i32 + TermCriteria_Type::EPS as i32, 5000, 1e-8)?;
+	let mut solver = <dyn DownhillSolver>::create(&f, &init_step, termcrit)?;
+	let mut x = Mat::from_slice(&[0f64, 0.])?;
+	let res = solver.minimize(&mut x)?;
+	assert!(res < 1e-6);
+	let x = x.data_typed::<f64>()?;
+	assert!((x[0] - 1.).abs() < 1e-3);
+	assert!((x[1] + 2.).abs() < 1e-3);
+	Ok(())
+}
+
+/// Panic in the Rust function called by the C++ solver fails the call that invoked the function
+#[test]
+fn min_problem_solver_function_panic() -> Result<()> {
+	use opencv::core::{MinProblemSolverFunctionImpl, MinProblemSolver_Function, Ptr};
+
+	struct Panicking;
+
+	impl MinProblemSolverFunctionImpl for Panicking {
+		fn dims(&self) -> i32 {
+			2
+		}
+
+		fn calc(&self, _x: &[f64]) -> f64 {
+			panic!("calc")
+		}
+	}
+
+	let f = Ptr::<dyn MinProblemSolver_Function>::from_impl(Panicking)?;
+	let x = [0., 0.];
+	assert_eq!(core::StsError, f.calc(&x[0]).unwrap_err().code());
+	// the function is not called again after the panic
+	assert_eq!(core::StsError, f.get_dims().unwrap_err().code());
+	Ok(())
+}
+
+/// Negative number of dimensions is reported as an error instead of being used as the length of the argument
+#[test]
+fn min_problem_solver_function_negative_dims() -> Result<()> {
+	use opencv::core::{MinProblemSolverFunctionImpl, MinProblemSolver_Function, Ptr};
+
+	struct Negative;
+
+	impl MinProblemSolverFunctionImpl for Negative {
+		fn dims(&self) -> i32 {
+			-1
+		}
+
+		fn calc(&self, _x: &[f64]) -> f64 {
+			0.
+		}
+	}
+
+	let f = Ptr::<dyn MinProblemSolver_Function>::from_impl(Negative)?;
+	let x = [0.];
+	assert_eq!(core::StsOutOfRange, f.get_dims().unwrap_err().code());
+	assert_eq!(core::StsOutOfRange, f.calc(&x[0]).unwrap_err().code());
+	Ok(())
+}
+
+#[test]
+fn parallel_for_fn() -> Result<()> {
+	use std::{
+		panic,
+		sync::atomic::{AtomicI32, Ordering},
+	};
+
+	let sum = AtomicI32::new(0);
+	core::parallel_for_fn(
+		&core::Range::new(0, 100)?,
+		|range| {
+			sum.fetch_add((range.start()..range.end()).sum(), Ordering::Relaxed);
+		},
+		-1.,
+	)?;
+	assert_eq!(4950, sum.into_inner());
+
+	let res = panic::catch_unwind(|| core::parallel_for_fn(&core::Range::new(0, 10).unwrap(), |_| panic!("stripe"), 4.));
+	assert_eq!(Some(&"stripe"), res.unwrap_err().downcast_ref::<&str>());
+	Ok(())
+}
+
+#[test]
+fn redirect_error() -> Result<()> {
+	use std::sync::{Arc, Mutex};
+
+	let errors = Arc::new(Mutex::new(vec![]));
+	let nested_reset = Arc::new(Mutex::new(None));
+	core::redirect_error({
+		let errors = Arc::clone(&errors);
+		let nested_reset = Arc::clone(&nested_reset);
+		move |info| {
+			errors.lock().unwrap().push(info.clone());
+			if info.line == 42 {
+				// the errors raised inside the handler are not passed to it again, the handler can't be changed there
+				let _ = core::error(core::StsBadArg, "redirect_error test", "test_func", "test_file.cpp", 44);
+				*nested_reset.lock().unwrap() = Some(core::reset_error_handler().is_err());
+			}
+		}
+	})?;
+	assert!(core::error(core::StsBadArg, "redirect_error test", "test_func", "test_file.cpp", 42).is_err());
+	core::reset_error_handler()?;
+	assert!(core::error(core::StsBadArg, "redirect_error test", "test_func", "test_file.cpp", 43).is_err());
+	let errors = errors.lock().unwrap();
+	let info = errors.iter().find(|info| info.message == "redirect_error test").unwrap();
+	assert_eq!(core::StsBadArg, info.status);
+	assert_eq!("test_func", info.func);
+	assert_eq!("test_file.cpp", info.file);
+	assert_eq!(42, info.line);
+	assert!(!errors.iter().any(|info| info.line == 43 || info.line == 44));
+	assert_eq!(Some(true), *nested_reset.lock().unwrap());
+	Ok(())
+}
+
+#[test]
+fn error_details() -> Result<()> {
+	let err = core::error(core::StsOutOfRange, "error_details test", "test_func", "test_file.cpp", 42).unwrap_err();
+	assert_eq!(core::StsOutOfRange, err.code());
+	assert_eq!(ErrorKind::Code(core::Code::StsOutOfRange), err.kind);
+	assert_eq!(Some("test_func"), err.function.as_deref());
+	assert!(err.message.contains("error_details test"));
+
+	let err = Error::new(-12345, "custom");
+	assert_eq!(ErrorKind::Unknown(-12345), err.kind);
+	assert_eq!(None, err.function);
+	assert_eq!(Some("rust_func"), err.with_function("rust_func").function.as_deref());
+	let err = Error::from(core::Code::GpuApiCallError);
+	assert_eq!(core::GpuApiCallError, err.code());
+	assert_eq!("GpuApiCallError", err.message);
+
+	let boxed: Box<dyn std::error::Error + Send + Sync + 'static> = Box::new(err);
+	assert_eq!("GpuApiCallError (code: -217)", boxed.to_string());
+	Ok(())
+}
+
+#[test]
+fn error_context() -> Result<()> {
+	let res: Result<()> = Err(Error::new(core::StsBadArg, "bad input"));
+	let err = res
+		.cv_context("cvt_color")
+		.with_cv_context(|| format!("frame {}", 12))
+		.unwrap_err();
+	assert_eq!(["cvt_color", "frame 12"], err.context());
+	assert_eq!("frame 12: cvt_color: bad input (code: -5)", err.to_string());
+	assert_eq!(core::StsBadArg, err.code());
+	assert_eq!(42, Ok(42).cv_context("unused")?);
+	Ok(())
+}
+
+#[test]
+fn module_availability() -> Result<()> {
+	assert!(core::compiled_modules().any(|m| m == "core"));
+	assert!(core::is_module_available("core"));
+	core::ensure_module_available("core")?;
+	assert!(!core::is_module_available("no_such_module"));
+	let err = core::ensure_module_available("no_such_module").unwrap_err();
+	assert_eq!(core::StsNotImplemented, err.code());
+	assert_eq!(
+		ErrorKind::ModuleUnavailable {
+			module: "no_such_module".to_string()
+		},
+		err.kind
+	);
+	assert!(err.message.contains("no_such_module"));
+	assert_eq!(
+		ErrorKind::Code(core::Code::StsNotImplemented),
+		Error::new(core::StsNotImplemented, "other").kind
+	);
+	Ok(())
+}
+
+#[test]
+fn frame_cache() -> Result<()> {
+	use opencv::core::{content_hash, FrameCache};
+
+	let a = Mat::new_rows_cols_with_default(4, 4, CV_8U, Scalar::all(1.))?;
+	let a_copy = a.try_clone()?;
+	let b = Mat::new_rows_cols_with_default(4, 4, CV_8U, Scalar::all(2.))?;
+	assert_eq!(content_hash(&a)?, content_hash(&a_copy)?);
+	assert_ne!(content_hash(&a)?, content_hash(&b)?);
+	// same bytes with a different shape
+	assert_ne!(content_hash(&a)?, content_hash(&a.reshape(1, 2)?)?);
+	// non-continuous roi hashes the same as its continuous copy
+	let roi = Mat::roi(&b, core::Rect::new(1, 1, 2, 2))?;
+	assert_eq!(content_hash(&roi)?, content_hash(&roi.try_clone()?)?);
+
+	let mut cache = FrameCache::new(2);
+	let mut computed = 0;
+	let mut sum = |m: &Mat, scale: i32| -> Result<f64> {
+		computed += 1;
+		Ok(core::sum_elems(m)?[0] * f64::from(scale))
+	};
+	assert_eq!(16., *cache.get_or_try_insert_with(&a, 1, || sum(&a, 1))?);
+	assert_eq!(16., *cache.get_or_try_insert_with(&a_copy, 1, || sum(&a_copy, 1))?);
+	assert_eq!(32., *cache.get_or_try_insert_with(&a, 2, || sum(&a, 2))?);
+	// `a` with scale 1 is the least recently used entry
+	assert_eq!(32., *cache.get_or_try_insert_with(&b, 1, || sum(&b, 1))?);
+	assert!(cache.peek(&a, 1)?.is_none());
+	assert_eq!(Some(&32.), cache.peek(&a, 2)?);
+	assert!(cache
+		.get_or_try_insert_with(&a, 3, || Err(Error::new(core::StsError, "fail")))
+		.is_err());
+
+	let metrics = cache.metrics();
+	assert_eq!(
+		(1, 4, 1, 2),
+		(metrics.hits, metrics.misses, metrics.evictions, metrics.entries)
+	);
+	assert_eq!(3, computed);
+	Ok(())
+}
+
+#[test]
+fn hash_feature_types() -> Result<()> {
+	use std::collections::HashSet;
+
+	use opencv::core::{BitwiseKey, DMatch, KeyPoint};
+
+	let m = DMatch::new_index(1, 2, 0, 0.)?;
+	let matches = vec![m, DMatch::new_index(1, 2, 0, -0.)?, DMatch::new_index(1, 2, 0, 0.)?]
+		.into_iter()
+		.map(BitwiseKey)
+		.collect::<HashSet<_>>();
+	// 0. and -0. have different bit patterns
+	assert_eq!(2, matches.len());
+	assert!(matches.contains(&BitwiseKey(m)));
+
+	let kp = KeyPoint::new_point(Point2f::new(1., f32::NAN), 3., -1., 0., 0, -1)?;
+	let keypoints = vec![kp, kp].into_iter().map(BitwiseKey).collect::<HashSet<_>>();
+	// NaN is equal to itself when compared bitwise
+	assert_eq!(1, keypoints.len());
+	assert!(keypoints.contains(&BitwiseKey(kp)));
+	Ok(())
+}
@@ -0,0 +1,46 @@
+#![cfg(all(ocvrs_has_module_cudafeatures2d, ocvrs_has_module_cudaoptflow))]
+
+use opencv::{
+	core::{self, GpuMat, Mat, Scalar, Size, CV_32FC2, CV_8UC1},
+	cudafeatures2d, cudaoptflow,
+	cudaoptflow::CudaDenseFlowKind,
+	prelude::*,
+	Result,
+};
+
+fn random_image() -> Result<GpuMat> {
+	let mut image = Mat::new_rows_cols_with_default(240, 320, CV_8UC1, Scalar::all(0.))?;
+	core::randu(&mut image, &Scalar::all(0.), &Scalar::all(255.))?;
+	GpuMat::from_mat(&image)
+}
+
+#[test]
+fn gpu_features_and_flow() -> Result<()> {
+	if core::cuda_devices()?.is_empty() {
+		eprintln!("Skipping test, no CUDA device available");
+		return Ok(());
+	}
+	let image = random_image()?;
+
+	let mut orb = cudafeatures2d::create_cuda_orb(200)?;
+	let features = orb.detect_and_compute_gpu(&image, None)?;
+	assert!(!features.keypoints.is_empty());
+	assert_eq!(features.keypoints.len() as i32, features.descriptors.rows());
+
+	let mut matcher = cudafeatures2d::create_cuda_hamming_matcher()?;
+	let matches = matcher.knn_match_ratio(&features.descriptors, &features.descriptors, 0.8)?;
+	// every descriptor matches itself at the zero distance, duplicates are rejected by the ratio test
+	assert!(matches.iter().all(|m| m.query_idx == m.train_idx && m.distance == 0.));
+
+	for kind in [
+		CudaDenseFlowKind::Farneback,
+		CudaDenseFlowKind::TVL1,
+		CudaDenseFlowKind::PyrLK,
+	] {
+		let mut flow = cudaoptflow::create_cuda_dense_optical_flow(kind)?;
+		let field = flow.calc_flow(&image, &image)?;
+		assert_eq!(Size::new(320, 240), field.size()?);
+		assert_eq!(CV_32FC2, field.typ()?);
+	}
+	Ok(())
+}
@@ -0,0 +1,43 @@
+#![cfg(all(ocvrs_has_module_cudaarithm, ocvrs_has_module_cudafilters))]
+
+use opencv::{
+	core::{self, GpuMat, Mat, Point, Scalar, Size, CV_16S, CV_8UC1, NORM_L1},
+	cudaarithm, cudafilters,
+	imgproc::MorphTypes,
+	prelude::*,
+	Result,
+};
+
+#[test]
+fn gpu_arithm_and_filters() -> Result<()> {
+	if core::cuda_devices()?.is_empty() {
+		eprintln!("Skipping test, no CUDA device available");
+		return Ok(());
+	}
+	let mut mat = Mat::new_rows_cols_with_default(32, 32, CV_8UC1, Scalar::all(10.))?;
+	*mat.at_2d_mut::<u8>(5, 7)? = 200;
+	let image = GpuMat::from_mat(&mat)?;
+	let ones = GpuMat::from_mat(&Mat::new_rows_cols_with_default(32, 32, CV_8UC1, Scalar::all(1.))?)?;
+
+	let sum = image.add_gpu(&ones)?;
+	assert_eq!(201, *sum.to_mat()?.at_2d::<u8>(5, 7)?);
+	assert_eq!(0., image.subtract_gpu(&image)?.sum_gpu()?[0]);
+	assert_eq!(20, *image.multiply_gpu(&ones, 2.)?.to_mat()?.at_2d::<u8>(0, 0)?);
+	assert_eq!(5, *image.divide_gpu(&ones, 0.5)?.to_mat()?.at_2d::<u8>(0, 0)?);
+	assert_eq!((10., 200.), image.min_max_gpu()?);
+	assert_eq!(1023. * 10. + 200., image.norm_gpu(NORM_L1)?);
+	let extremums = cudaarithm::cuda_min_max_loc_values(&image, None)?;
+	assert_eq!(Point::new(7, 5), extremums.max_loc);
+
+	let mut gaussian = cudafilters::create_cuda_gaussian_filter(CV_8UC1, Size::new(5, 5), 1.)?;
+	let blurred = gaussian.apply_gpu(&image)?;
+	assert!(blurred.min_max_gpu()?.1 < 200.);
+
+	let mut sobel = cudafilters::create_cuda_sobel_filter(CV_8UC1, CV_16S, 1, 0, 3)?;
+	assert_eq!(CV_16S, sobel.apply_gpu(&image)?.typ()?);
+
+	let kernel = Mat::new_rows_cols_with_default(3, 3, CV_8UC1, Scalar::all(1.))?;
+	let mut erode = cudafilters::create_cuda_morphology_filter(MorphTypes::MORPH_ERODE, CV_8UC1, &kernel, 1)?;
+	assert_eq!((10., 10.), erode.apply_gpu(&image)?.min_max_gpu()?);
+	Ok(())
+}
@@ -0,0 +1,43 @@
+#![cfg(all(ocvrs_has_module_cudabgsegm, ocvrs_has_module_cudacodec))]
+
+use opencv::{
+	core::{self, GpuMat, Mat, Scalar, Vec3b, CV_8UC3},
+	cudabgsegm, cudacodec,
+	prelude::*,
+	Result,
+};
+
+#[test]
+fn gpu_background_subtraction() -> Result<()> {
+	if core::cuda_devices()?.is_empty() {
+		eprintln!("Skipping test, no CUDA device available");
+		return Ok(());
+	}
+	let background = GpuMat::from_mat(&Mat::new_rows_cols_with_default(64, 64, CV_8UC3, Scalar::all(50.))?)?;
+	let mut mog2 = cudabgsegm::create_cuda_mog2()?;
+	for _ in 0..20 {
+		mog2.apply_gpu(&background, -1.)?;
+	}
+	let mut frame = Mat::new_rows_cols_with_default(64, 64, CV_8UC3, Scalar::all(50.))?;
+	for row in 16..32 {
+		for col in 16..32 {
+			*frame.at_2d_mut::<Vec3b>(row, col)? = Vec3b::all(250);
+		}
+	}
+	let mask = mog2.apply_gpu(&GpuMat::from_mat(&frame)?, -1.)?.to_mat()?;
+	assert_eq!(255, *mask.at_2d::<u8>(24, 24)?);
+	assert_eq!(0, *mask.at_2d::<u8>(0, 0)?);
+	let bg_image = mog2.background_image_gpu()?.to_mat()?;
+	assert_eq!(50, bg_image.at_2d::<Vec3b>(0, 0)?[0]);
+	Ok(())
+}
+
+#[test]
+fn gpu_video_reader_missing_source() -> Result<()> {
+	if core::cuda_devices()?.is_empty() {
+		eprintln!("Skipping test, no CUDA device available");
+		return Ok(());
+	}
+	assert!(cudacodec::open_cuda_video_reader("does_not_exist.mp4").is_err());
+	Ok(())
+}
@@ -7,7 +7,7 @@ use opencv::{
 	dnn::{DictValue, LayerParams, Net},
 	prelude::*,
 	types::VectorOfMat,
-	Error, Result,
+	Error, ErrorKind, Result,
 };
 
 /// Specialization, passing Vector of boxed objects
@@ -24,11 +24,15 @@ fn net() -> Result<()> {
 	params.set_type("param type");
 	assert_eq!(params.name(), "param name");
 	assert_eq!(params.typ(), "param type");
+	assert_eq!(params.name_ref()?, "param name");
+	assert_eq!(params.typ_ref()?, "param type");
 	assert!(!params.has("test")?);
 	params.set("test", &DictValue::from_f64(345.9)?)?;
 	assert!(params.has("test")?);
 	let v = params.get("test")?;
 	assert_eq!(345.9, v.get_real_value(-1)?);
+	assert_eq!(345.9, params.get_ref("test")?.get_real_value(-1)?);
+	assert!(params.get_ref("missing").is_err());
 	params.set_f64("test", &98.71)?;
 	assert_eq!(98.71, params.get("test")?.get_real_value(-1)?);
 	params.set("test_str", &DictValue::from_str("test string".to_string().as_str())?)?;
@@ -44,6 +48,9 @@ fn net() -> Result<()> {
 	params.set_blobs(blobs);
 	let blobs = params.blobs();
 	assert_eq!(1, blobs.len());
+	let blobs = params.blobs_ref();
+	assert_eq!(1, blobs.len());
+	assert!(blobs.get(0)?.empty());
 	Ok(())
 }
 
@@ -59,7 +66,7 @@ fn dict_value() -> Result<()> {
 		assert_matches!(
 			v.get_i64(-1),
 			Err(Error {
-				code: core::StsAssert,
+				kind: ErrorKind::Code(core::Code::StsAssert),
 				..
 			})
 		);
@@ -68,14 +75,14 @@ fn dict_value() -> Result<()> {
 		assert_matches!(
 			v.get_int_value(-1),
 			Err(Error {
-				code: core::StsAssert,
+				kind: ErrorKind::Code(core::Code::StsAssert),
 				..
 			})
 		);
 		assert_matches!(
 			v.get_i32(-1),
 			Err(Error {
-				code: core::StsAssert,
+				kind: ErrorKind::Code(core::Code::StsAssert),
 				..
 			})
 		);
@@ -94,14 +101,14 @@ fn dict_value() -> Result<()> {
 		assert_matches!(
 			v.get_string_value(-1),
 			Err(Error {
-				code: core::StsAssert,
+				kind: ErrorKind::Code(core::Code::StsAssert),
 				..
 			})
 		);
 		assert_matches!(
 			v.get_str(-1),
 			Err(Error {
-				code: core::StsAssert,
+				kind: ErrorKind::Code(core::Code::StsAssert),
 				..
 			})
 		);
@@ -123,3 +130,69 @@ fn dict_value() -> Result<()> {
 	}
 	Ok(())
 }
+
+#[test]
+#[cfg(not(ocvrs_opencv_branch_32))]
+fn net_manual() -> Result<()> {
+	use opencv::{
+		core::{Scalar, Size, Vector, CV_8U, CV_8UC3},
+		dnn::{self, Backend, BlobParams, LayerInfo, LayerShapes, Target},
+	};
+
+	let mut net = Net::default()?;
+	net.set_inputs_names(&Vector::from_iter(["data".to_string()]))?;
+	net.add_layer_to_prev("relu", "ReLU", &mut LayerParams::default()?)?;
+	net.add_layer_to_prev("sigmoid", "Sigmoid", &mut LayerParams::default()?)?;
+	assert_eq!(
+		vec![
+			LayerInfo {
+				id: 1,
+				name: "relu".to_string(),
+				typ: "ReLU".to_string()
+			},
+			LayerInfo {
+				id: 2,
+				name: "sigmoid".to_string(),
+				typ: "Sigmoid".to_string()
+			},
+		],
+		net.layers()?
+	);
+	assert_eq!(
+		vec![LayerShapes {
+			id: 2,
+			name: "sigmoid".to_string(),
+			shapes: vec![vec![1, 3, 4, 6]],
+		}],
+		net.output_shapes(&[&[1, 3, 4, 6]])?
+	);
+
+	let image = Mat::new_rows_cols_with_default(4, 6, CV_8UC3, Scalar::all(2.))?;
+	let params = BlobParams {
+		scale_factor: 0.5,
+		..BlobParams::default()
+	};
+	let blob = dnn::blob_from_image_with_params(&image, &params)?;
+	assert_eq!(&[1, 3, 4, 6], &*blob.mat_size());
+	assert!(blob.data_typed::<f32>()?.iter().all(|&v| v == 1.));
+	let invalid = BlobParams { ddepth: CV_8U, ..params };
+	assert!(dnn::blob_from_image_with_params(&image, &invalid).is_err());
+	let batch = dnn::blob_from_images_with_params(
+		&VectorOfMat::from_iter([image.try_clone()?, image.try_clone()?]),
+		&BlobParams {
+			size: Size::new(3, 2),
+			..params
+		},
+	)?;
+	assert_eq!(&[2, 3, 2, 3], &*batch.mat_size());
+
+	net.set_backend(Backend::DNN_BACKEND_OPENCV)?;
+	net.set_target(Target::DNN_TARGET_CPU)?;
+	net.set_input(&blob, "", 1., Scalar::default())?;
+	let out = net.forward_single("")?;
+	let expected = 1. / (1. + (-1f32).exp());
+	assert!(out.data_typed::<f32>()?.iter().all(|&v| (v - expected).abs() < 1e-5));
+	// asynchronous forward is only supported by the Inference Engine backend
+	assert!(net.forward_async_output("").is_err());
+	Ok(())
+}
@@ -0,0 +1,38 @@
+#![cfg(ocvrs_has_module_freetype)]
+
+use std::path::Path;
+
+use opencv::{
+	core::{self, Mat, Point, Scalar, CV_8UC3},
+	freetype::{self, TextStyle},
+	prelude::*,
+	Result,
+};
+
+const FONT: &str = "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf";
+
+#[test]
+fn missing_font() {
+	let err = freetype::load_font("/nonexistent/font.ttf", 0)
+		.err()
+		.expect("Font must not be loaded");
+	assert_eq!(core::StsObjectNotFound, err.code());
+}
+
+#[test]
+fn draw_utf8_text() -> Result<()> {
+	if !Path::new(FONT).is_file() {
+		eprintln!("Skipping test, font not found: {}", FONT);
+		return Ok(());
+	}
+	let mut font = freetype::load_font(FONT, 0)?;
+	let style = TextStyle::default().with_font_height(32).with_color(Scalar::all(255.));
+	let text = "Grüße, Ωμέγα";
+	let (size, _baseline) = font.measure_text(text, &style)?;
+	assert!(size.width > 0 && size.height > 0, "{:?}", size);
+
+	let mut img = Mat::new_rows_cols_with_default(64, size.width + 20, CV_8UC3, Scalar::all(0.))?;
+	font.draw_text(&mut img, text, Point::new(10, 48), &style)?;
+	assert!(core::count_non_zero(&img.reshape(1, 0)?)? > 0);
+	Ok(())
+}
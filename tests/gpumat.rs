@@ -0,0 +1,76 @@
+use std::{
+	future::Future,
+	sync::{mpsc, Arc},
+	task::{Context, Poll, Wake, Waker},
+	thread,
+};
+
+use opencv::{
+	core::{self, GpuMat, Rect, Scalar, Size, Stream, CV_8UC1},
+	prelude::*,
+	Result,
+};
+
+#[test]
+fn gpumat_upload_download() -> Result<()> {
+	let devices = core::cuda_devices()?;
+	for device in &devices {
+		let (major, _minor) = device.compute_capability()?;
+		assert!(major > 0);
+	}
+	if devices.is_empty() {
+		eprintln!("Skipping test, no CUDA device available");
+		return Ok(());
+	}
+
+	let mut mat = Mat::new_rows_cols_with_default(4, 6, CV_8UC1, Scalar::all(0.))?;
+	*mat.at_2d_mut::<u8>(2, 3)? = 7;
+	let gpu = GpuMat::from_mat(&mat)?;
+	assert_eq!(Size::new(6, 4), gpu.size()?);
+	assert_eq!(7, *gpu.to_mat()?.at_2d::<u8>(2, 3)?);
+
+	let roi = GpuMat::roi(&gpu, Rect::new(2, 1, 3, 2))?;
+	assert_eq!(Size::new(3, 2), roi.size()?);
+	assert_eq!(7, *roi.to_mat()?.at_2d::<u8>(1, 1)?);
+	Ok(())
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+	struct ThreadWaker(thread::Thread);
+
+	impl Wake for ThreadWaker {
+		fn wake(self: Arc<Self>) {
+			self.0.unpark();
+		}
+	}
+
+	let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+	let mut cx = Context::from_waker(&waker);
+	let mut future = Box::pin(future);
+	loop {
+		if let Poll::Ready(out) = future.as_mut().poll(&mut cx) {
+			break out;
+		}
+		thread::park();
+	}
+}
+
+#[test]
+fn stream_async_roundtrip() -> Result<()> {
+	if core::cuda_devices()?.is_empty() {
+		eprintln!("Skipping test, no CUDA device available");
+		return Ok(());
+	}
+
+	let mut stream = Stream::default()?;
+	let mat = Mat::new_rows_cols_with_default(16, 16, CV_8UC1, Scalar::all(3.))?;
+	let gpu = GpuMat::from_mat_async(&mat, &mut stream)?;
+	let mut out = Mat::default();
+	gpu.download_async(&mut out, &mut stream)?;
+	let (sender, receiver) = mpsc::channel();
+	stream.on_completion(move |res| sender.send(res.is_ok()).unwrap())?;
+	block_on(stream.completion()?)?;
+	assert!(receiver.recv().unwrap());
+	assert_eq!(3, *out.at_2d::<u8>(15, 15)?);
+	Ok(())
+}
@@ -0,0 +1,41 @@
+#![cfg(ocvrs_has_module_highgui)]
+
+use opencv::{
+	core::Point,
+	highgui::{MouseEvent, SegmentSelection, SelectionKey},
+};
+
+#[test]
+fn selection_key() {
+	assert_eq!(SelectionKey::Confirm, SelectionKey::from_key(13));
+	assert_eq!(SelectionKey::Confirm, SelectionKey::from_key(i32::from(b' ')));
+	assert_eq!(SelectionKey::Cancel, SelectionKey::from_key(27));
+	assert_eq!(SelectionKey::Cancel, SelectionKey::from_key(i32::from(b'c')));
+	assert_eq!(SelectionKey::Reset, SelectionKey::from_key(i32::from(b'r')));
+	assert_eq!(SelectionKey::Other, SelectionKey::from_key(-1));
+	// modifier bits above the key code are ignored
+	assert_eq!(SelectionKey::Confirm, SelectionKey::from_key(0x10000 | 13));
+}
+
+#[test]
+fn segment_selection() {
+	let mut selection = SegmentSelection::new();
+	assert_eq!(None, selection.preview());
+	selection.handle_mouse(MouseEvent::LeftButtonDown, Point::new(1, 2));
+	assert_eq!(None, selection.preview());
+	selection.handle_mouse(MouseEvent::Move, Point::new(5, 5));
+	assert_eq!(Some((Point::new(1, 2), Point::new(5, 5))), selection.preview());
+	assert_eq!(None, selection.segment());
+	selection.handle_mouse(MouseEvent::LeftButtonDown, Point::new(10, 20));
+	assert_eq!(Some((Point::new(1, 2), Point::new(10, 20))), selection.segment());
+	assert_eq!(selection.segment(), selection.preview());
+
+	// next click starts a new segment
+	selection.handle_mouse(MouseEvent::LeftButtonDown, Point::new(7, 7));
+	assert_eq!(None, selection.segment());
+	selection.handle_mouse(MouseEvent::RightButtonDown, Point::new(8, 8));
+	assert_eq!(None, selection.segment());
+	selection.reset();
+	assert_eq!(None, selection.preview());
+	assert_eq!(Some(Point::new(5, 5)), selection.hover());
+}
@@ -41,3 +41,18 @@ fn decode() -> Result<()> {
 
 	Ok(())
 }
+
+#[test]
+fn path() -> Result<()> {
+	let path = std::env::temp_dir().join(format!("ocvrs-pixel-ünïcödé-{}.png", std::process::id()));
+	let src = imgcodecs::imdecode(&Mat::from_slice::<u8>(PIXEL)?, imgcodecs::IMREAD_COLOR)?;
+	assert!(imgcodecs::imwrite(&path, &src, &core::Vector::new())?);
+	let dest = imgcodecs::imread(&path, imgcodecs::IMREAD_COLOR)?;
+	std::fs::remove_file(&path).ok();
+	assert_eq!(dest.size()?, Size::new(1, 1));
+	assert_eq!(*dest.at_2d::<Vec3b>(0, 0)?, Vec3b::from([56u8, 56, 191]));
+
+	let missing = std::path::Path::new("ocvrs-missing-file.png");
+	assert!(imgcodecs::imread(missing, imgcodecs::IMREAD_COLOR)?.empty());
+	Ok(())
+}
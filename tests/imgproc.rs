@@ -1,8 +1,8 @@
 #![cfg(ocvrs_has_module_imgproc)]
 
 use opencv::{
-	core::{Mat_AUTO_STEP, Point, Point2f, Scalar, Size, Vec2f},
-	imgproc,
+	core::{self, Mat_AUTO_STEP, Point, Point2f, Rect, Scalar, Size, Vec2f, Vec3b},
+	imgproc::{self, Corner, DrawColor, OverlayStyle, Palette, SheetGrid, SheetTile, WorldScale},
 	prelude::*,
 	types::VectorOfPoint,
 	Result,
@@ -58,3 +58,109 @@ fn line_iterator() -> Result<()> {
 	assert_eq!(9, unsafe { *line_iter.try_deref_mut()?.as_ref().unwrap() });
 	Ok(())
 }
+
+#[test]
+fn contact_sheet() -> Result<()> {
+	let gray = Mat::new_rows_cols_with_default(100, 200, core::CV_8UC1, Scalar::all(128.))?;
+	let color = Mat::new_rows_cols_with_default(50, 50, core::CV_8UC3, Scalar::new(255., 0., 0., 0.))?;
+	let tiles = [
+		SheetTile::new(&gray).with_label("gray"),
+		SheetTile::new(&color).with_overlay(|tile, scale| {
+			assert_eq!(2., scale);
+			imgproc::rectangle(
+				tile,
+				Rect::new(0, 0, 10, 10),
+				Scalar::new(0., 0., 255., 0.),
+				-1,
+				imgproc::LINE_8,
+				0,
+			)
+		}),
+		SheetTile::from(&gray),
+	];
+	let grid = SheetGrid {
+		tile_size: Size::new(100, 100),
+		spacing: 2,
+		..SheetGrid::default()
+	};
+	let sheet = imgproc::contact_sheet(&tiles, grid)?;
+	assert_eq!(core::CV_8UC3, sheet.typ());
+	// 2x2 grid, the cells are taller than the tiles by the caption height
+	assert_eq!(2 * 100 + 3 * 2, sheet.cols());
+	assert!(sheet.rows() > 2 * 100 + 3 * 2);
+	// gray image is scaled to 100x50 and centered vertically
+	assert_eq!(Vec3b::from([0, 0, 0]), *sheet.at_2d::<Vec3b>(2 + 10, 2 + 50)?);
+	assert_eq!(Vec3b::from([128, 128, 128]), *sheet.at_2d::<Vec3b>(2 + 50, 2 + 50)?);
+	// color image is scaled up to 100x100 with the overlay in the top left corner
+	assert_eq!(Vec3b::from([0, 0, 255]), *sheet.at_2d::<Vec3b>(2 + 5, 104 + 5)?);
+	assert_eq!(Vec3b::from([255, 0, 0]), *sheet.at_2d::<Vec3b>(2 + 50, 104 + 50)?);
+
+	assert!(imgproc::contact_sheet(&[], SheetGrid::default()).is_err());
+	let float = Mat::new_rows_cols_with_default(10, 10, core::CV_32FC1, Scalar::all(0.))?;
+	assert!(imgproc::contact_sheet(&[SheetTile::new(&float)], SheetGrid::default()).is_err());
+	Ok(())
+}
+
+#[test]
+fn palette() {
+	assert_eq!(Palette::Default, Palette::global());
+	assert_eq!(Some(7), Palette::ColorBlindSafe.period());
+	assert_eq!(None, Palette::Default.period());
+	// Okabe-Ito orange in BGR
+	assert_eq!(Scalar::new(0., 159., 230., 0.), Palette::ColorBlindSafe.color(0));
+	assert_eq!(Palette::ColorBlindSafe.color(1), Palette::ColorBlindSafe.color(8));
+	assert_ne!(Palette::Default.color(0), Palette::Default.color(1));
+	assert_eq!(Scalar::all(255.), Palette::HighContrastLight.background());
+	assert_eq!(Scalar::all(0.), Palette::HighContrastLight.foreground());
+	assert_eq!(Scalar::new(0., 255., 0., 0.), Palette::Default.accent());
+
+	let fixed = DrawColor::from(Scalar::all(7.));
+	assert_eq!(Scalar::all(7.), fixed.color(3));
+	assert_eq!(
+		Palette::HighContrastDark.color(2),
+		DrawColor::from(Palette::HighContrastDark).color(2)
+	);
+
+	let grid = SheetGrid::default().with_palette(Palette::HighContrastLight);
+	assert_eq!(Scalar::all(255.), grid.background);
+	assert_eq!(Scalar::all(0.), grid.label_color);
+}
+
+#[test]
+fn overlays() -> Result<()> {
+	let scale = WorldScale::new(3., "mm");
+	assert_eq!(Some(20.), scale.nice_length(100.));
+	assert_eq!(Some(50.), scale.nice_length(150.));
+	assert_eq!(Some(0.1), WorldScale::new(300., "m").nice_length(50.));
+	assert_eq!(None, WorldScale::new(0., "m").nice_length(50.));
+
+	let camera_matrix = Mat::from_slice_2d(&[[800f64, 0., 320.], [0., 600., 240.], [0., 0., 1.]])?;
+	let calibrated = WorldScale::from_camera_matrix(&camera_matrix, 2., "m")?;
+	assert_eq!(350., calibrated.pixels_per_unit);
+	assert!(WorldScale::from_camera_matrix(&camera_matrix, 0., "m").is_err());
+
+	let style = OverlayStyle {
+		color: Scalar::all(255.),
+		..OverlayStyle::default()
+	};
+	let mut img = Mat::new_rows_cols_with_default(100, 200, core::CV_8UC3, Scalar::all(0.))?;
+	imgproc::draw_grid(&mut img, 50, style)?;
+	assert_eq!(Vec3b::from([255, 255, 255]), *img.at_2d::<Vec3b>(20, 50)?);
+	assert_eq!(Vec3b::from([255, 255, 255]), *img.at_2d::<Vec3b>(50, 120)?);
+	assert_eq!(Vec3b::from([0, 0, 0]), *img.at_2d::<Vec3b>(20, 20)?);
+	assert!(imgproc::draw_grid(&mut img, 0, style).is_err());
+
+	let mut img = Mat::new_rows_cols_with_default(100, 200, core::CV_8UC3, Scalar::all(0.))?;
+	imgproc::draw_crosshair(&mut img, Point2f::new(100., 50.), 10, style)?;
+	assert_eq!(Vec3b::from([255, 255, 255]), *img.at_2d::<Vec3b>(50, 92)?);
+	assert_eq!(Vec3b::from([255, 255, 255]), *img.at_2d::<Vec3b>(58, 100)?);
+	imgproc::draw_axes(&mut img, 10, 5, style)?;
+	assert_eq!(Vec3b::from([255, 255, 255]), *img.at_2d::<Vec3b>(2, 30)?);
+
+	// 200 px wide image fits a 50 px bar, that is 10 mm at 3 px/mm
+	let mut img = Mat::new_rows_cols_with_default(100, 200, core::CV_8UC3, Scalar::all(0.))?;
+	assert_eq!(10., imgproc::draw_scale_bar(&mut img, &scale, Corner::BottomLeft, style)?);
+	assert_eq!(Vec3b::from([255, 255, 255]), *img.at_2d::<Vec3b>(90, 25)?);
+	assert_eq!(Vec3b::from([0, 0, 0]), *img.at_2d::<Vec3b>(90, 45)?);
+	Ok(())
+}
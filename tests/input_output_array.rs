@@ -71,7 +71,7 @@ fn input_output_array() -> Result<()> {
 		let umat = mat.get_umat(ACCESS_READ, UMatUsageFlags::USAGE_DEFAULT)?;
 		{
 			let mut trg = VectorOfu8::new();
-			core::add(&mat_expr, &umat, &mut trg, &core::no_array(), -1)?;
+			core::add(&mat_expr, &umat, &mut trg, None::<&Mat>, -1)?;
 			assert_eq!(3, trg.len());
 			assert_eq!(4, trg.get(0)?);
 			assert_eq!(4, trg.get(1)?);
@@ -80,7 +80,7 @@ fn input_output_array() -> Result<()> {
 
 		{
 			let mut trg = VectorOfu8::new();
-			core::add(&&mat_expr, &&umat, &mut &mut trg, &core::no_array(), -1)?;
+			core::add(&&mat_expr, &&umat, &mut &mut trg, None::<&Mat>, -1)?;
 			assert_eq!(3, trg.len());
 			assert_eq!(4, trg.get(0)?);
 			assert_eq!(4, trg.get(1)?);
@@ -90,7 +90,7 @@ fn input_output_array() -> Result<()> {
 
 	{
 		let mut t = VectorOff64::new();
-		core::add(&2.5, &4., &mut t, &core::no_array(), -1)?;
+		core::add(&2.5, &4., &mut t, None::<&Mat>, -1)?;
 		assert_eq!(6.5, t.get(0)?);
 	}
 
@@ -112,7 +112,31 @@ fn no_array() -> Result<()> {
 
 	{
 		let m = Mat::new_rows_cols_with_default(1, 1, u16::typ(), Scalar::all(0.))?;
-		assert_matches!(core::mean_std_dev(&m, &mut no_array(), &mut no_array(), &no_array()), Ok(()));
+		assert_matches!(core::mean_std_dev(&m, &mut no_array(), &mut no_array(), None::<&Mat>), Ok(()));
 	}
 	Ok(())
 }
+
+#[test]
+fn input_output_array_slices() -> Result<()> {
+	let src = [1u8, 2, 3];
+	let mut dst = vec![0u8; 3];
+	core::add(&src, &src[..], &mut dst, None::<&Mat>, -1)?;
+	assert_eq!(vec![2, 4, 6], dst);
+
+	let mask = vec![1u8, 0, 1];
+	let mut dst = vec![0u8; 3];
+	core::add(&src, &src, &mut dst, Some(&mask[..]), -1)?;
+	assert_eq!(vec![2, 0, 6], dst);
+
+	let mut dst = [0f32; 3];
+	core::multiply(&[1f32, 2., 3.], &vec![2f32, 2., 2.], &mut dst[..], 1., -1)?;
+	assert_eq!([2., 4., 6.], dst);
+
+	assert_eq!(Scalar::from(6.), core::sum_elems(&[1i32, 2, 3])?);
+	assert_eq!(Scalar::from(5.), core::sum_elems(&5u16)?);
+
+	assert!(src.input_array()?.is_matx()?);
+	assert!(dst.input_output_array()?.is_matx()?);
+	Ok(())
+}
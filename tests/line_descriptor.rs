@@ -0,0 +1,1998 @@
+#![cfg(ocvrs_has_module_line_descriptor)]
+
+use opencv::{
+	core::{self, DMatch, KeyPoint, Point, Point2d, Point2f, Scalar, Size, Vec4f, Vector, CV_8UC1},
+	features2d::{BFMatcher, DescriptorMatcher, DescriptorMatcherConst},
+	imgproc::{self, DrawColor, Palette},
+	line_descriptor::{
+		self, AnnotatedLine, BinaryDescriptor, BinaryDescriptorMatcher, BinaryDescriptor_Params, CourtTemplate, CrossingDirection,
+		DescriptorBinarizer, DescriptorCompatibility, DescriptorMetadata, DescriptorSample, DetectionEvent, DetectionSink,
+		DrawLinesMatchesFlags, FilterCriteria, KeyLine, KeyLineGeometryCheck, LSDDetector, LineAnnotations,
+		LineDescriptorExtractor, LineEndpoint, LineHomographyMethod, LineNms, LineSnapping, LineStyle, LineTracker,
+		LineTrackerParams, MatchDrawOptions, MatchGraph, MatchLayout, MatchPropagator, MixedPrecisionMatcher, ModelFitParams,
+		PatchOrientation, PatchParams, PolyLine, PolyLineLinkParams, PropagationParams, RadiusMatchPaging, TrackStats, Tripwire,
+		WireDetectorParams, Zone, ZoneAnalytics,
+	},
+	prelude::*,
+	Result,
+};
+
+fn keyline(start: (f32, f32), end: (f32, f32)) -> KeyLine {
+	let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+	KeyLine {
+		angle: dy.atan2(dx),
+		class_id: 0,
+		octave: 0,
+		pt: Point2f::new((start.0 + end.0) / 2., (start.1 + end.1) / 2.),
+		response: 0.,
+		size: dx.abs() * dy.abs(),
+		start_point_x: start.0,
+		start_point_y: start.1,
+		end_point_x: end.0,
+		end_point_y: end.1,
+		s_point_in_octave_x: start.0,
+		s_point_in_octave_y: start.1,
+		e_point_in_octave_x: end.0,
+		e_point_in_octave_y: end.1,
+		line_length: dx.hypot(dy),
+		num_of_pixels: dx.abs().max(dy.abs()) as i32,
+	}
+}
+
+#[test]
+fn keyline_geometry() {
+	let horizontal = keyline((0., 0.), (4., 0.));
+	let vertical = keyline((2., -1.), (2., 3.));
+	assert_eq!((Point2f::new(0., 0.), Point2f::new(4., 0.)), horizontal.as_segment());
+	assert_eq!(4., horizontal.length());
+	assert_eq!(Some(Point2f::new(1., 0.)), horizontal.direction());
+	assert_eq!(None, keyline((1., 1.), (1., 1.)).direction());
+
+	assert_eq!(3., horizontal.distance_to_point(Point2f::new(2., 3.)));
+	// beyond the end of the segment the distance is measured to the endpoint
+	assert_eq!(5., horizontal.distance_to_point(Point2f::new(7., 4.)));
+
+	assert_eq!(Some(Point2f::new(2., 0.)), horizontal.intersection_with(&vertical));
+	// the supporting lines intersect outside of the segments
+	assert_eq!(
+		Some(Point2f::new(10., 0.)),
+		horizontal.intersection_with(&keyline((10., 5.), (10., 8.)))
+	);
+	assert_eq!(None, horizontal.intersection_with(&keyline((0., 1.), (4., 1.))));
+
+	assert!((horizontal.angle_between(&vertical) - std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+	assert!(horizontal.angle_between(&keyline((4., 2.), (0., 2.))) < 1e-6);
+	assert!((horizontal.angle_between(&keyline((0., 0.), (-1., 1.))) - std::f32::consts::FRAC_PI_4).abs() < 1e-6);
+}
+
+#[test]
+fn keyline_octave_coords() {
+	let detected = KeyLine {
+		octave: 2,
+		response: 0.7,
+		class_id: 5,
+		..keyline((8., 4.), (24., 16.))
+	}
+	.to_octave_coords(2.);
+	assert_eq!(4., detected.octave_scale(2.));
+	assert_eq!((2., 1.), (detected.s_point_in_octave_x, detected.s_point_in_octave_y));
+	assert_eq!((6., 4.), (detected.e_point_in_octave_x, detected.e_point_in_octave_y));
+	assert_eq!(5., detected.line_length);
+	assert_eq!(5, detected.num_of_pixels);
+	assert_eq!((2, 0.7, 5), (detected.octave, detected.response, detected.class_id));
+
+	// the endpoints are moved in the image, e.g. by undistortion
+	let moved = KeyLine {
+		start_point_x: 12.,
+		end_point_x: 28.,
+		..detected
+	}
+	.to_octave_coords(2.);
+	assert_eq!((3., 1.), (moved.s_point_in_octave_x, moved.s_point_in_octave_y));
+	assert_eq!(Point2f::new(20., 10.), moved.pt);
+	assert_eq!(5., moved.line_length);
+
+	// and back from the octave coordinates
+	let restored = KeyLine {
+		s_point_in_octave_x: 2.,
+		e_point_in_octave_x: 6.,
+		..moved
+	}
+	.to_image_coords(2.);
+	assert_eq!((Point2f::new(8., 4.), Point2f::new(24., 16.)), restored.as_segment());
+	assert_eq!(detected, restored);
+}
+
+#[test]
+fn vanishing_points() {
+	let vp = (1200f32, 300f32);
+	let mut keylines = vec![];
+	// lines converging to vp
+	for &y in &[0f32, 100., 200., 400., 500., 600.] {
+		let t = 0.5;
+		keylines.push(keyline((0., y), (vp.0 * t, y + (vp.1 - y) * t)));
+	}
+	// vertical parallel lines
+	for &x in &[50f32, 150., 250., 350.] {
+		keylines.push(keyline((x, 20.), (x, 580.)));
+	}
+	let vps = line_descriptor::detect_vanishing_points(&keylines, Size::new(640, 640), 3);
+	assert_eq!(2, vps.len());
+	let finite = vps[0].to_point().unwrap();
+	assert!((finite.x - f64::from(vp.0)).abs() < 1.);
+	assert!((finite.y - f64::from(vp.1)).abs() < 1.);
+	assert_eq!(vec![0, 1, 2, 3, 4, 5], vps[0].inliers);
+	assert!(vps[1].is_at_infinity());
+	assert!(vps[1].point.x.abs() < 1e-6);
+	assert_eq!(vec![6, 7, 8, 9], vps[1].inliers);
+}
+
+#[test]
+fn parallel_line_pair() {
+	let mut keylines = vec![];
+	for &x in &[0f32, 100., 250., 400.] {
+		keylines.push(keyline((x, 0.1 * x + 100.), (x + 80., 0.1 * (x + 80.) + 100.)));
+		keylines.push(keyline((x + 20., 0.1 * (x + 20.) + 150.), (x + 90., 0.1 * (x + 90.) + 150.)));
+	}
+	keylines.push(keyline((10., 300.), (60., 400.)));
+	keylines.push(keyline((300., 20.), (320., 300.)));
+	let pair = line_descriptor::fit_parallel_line_pair(&keylines, 10., &ModelFitParams::default()).unwrap();
+	assert!((pair.angle() - 0.1f64.atan()).abs() < 1e-4);
+	assert!((pair.width() - 50. * 0.1f64.atan().cos()).abs() < 1e-2);
+	assert_eq!(8, pair.inliers.iter().map(Vec::len).sum::<usize>());
+}
+
+#[test]
+fn lanes() {
+	let lane_x = |lane: usize, y: f64| {
+		if lane == 0 {
+			300. - 0.3 * y + 4e-4 * y * y
+		} else {
+			500. + 0.25 * y
+		}
+	};
+	let mut keylines = vec![];
+	for lane in 0..2 {
+		for y in (100..600).step_by(50) {
+			let (y1, y2) = (f64::from(y), f64::from(y + 30));
+			keylines.push(keyline(
+				(lane_x(lane, y1) as f32, y1 as f32),
+				(lane_x(lane, y2) as f32, y2 as f32),
+			));
+		}
+	}
+	keylines.push(keyline((0., 400.), (700., 410.)));
+	let lanes = line_descriptor::fit_lanes(&keylines, 3, &ModelFitParams::default());
+	assert_eq!(2, lanes.len());
+	for lane in &lanes {
+		assert_eq!(10, lane.inliers.len());
+		let truth = lane.inliers[0] / 10;
+		for &y in &[100., 350., 630.] {
+			assert!((lane.x_at(y) - lane_x(truth, y)).abs() < 1.);
+		}
+	}
+}
+
+#[test]
+fn court() {
+	let h = [[40., 8., 300.], [0., 12., 200.], [0., 0.02, 1.]];
+	let project = |p: Point2d| {
+		let w = h[2][0] * p.x + h[2][1] * p.y + h[2][2];
+		(
+			((h[0][0] * p.x + h[0][1] * p.y + h[0][2]) / w) as f32,
+			((h[1][0] * p.x + h[1][1] * p.y + h[1][2]) / w) as f32,
+		)
+	};
+	let template = CourtTemplate::tennis();
+	let keylines = template
+		.lines
+		.iter()
+		.map(|&[a, b]| keyline(project(a), project(b)))
+		.collect::<Vec<_>>();
+	let court = line_descriptor::fit_court(&keylines, Size::new(1000, 600), &template, &ModelFitParams::default()).unwrap();
+	assert!(court.inliers.iter().all(|inl| !inl.is_empty()));
+	// the court is symmetric so the template can be matched flipped, compare the projected segments as sets
+	for &[a, b] in &template.lines {
+		let (a, b) = (court.project(a).unwrap(), court.project(b).unwrap());
+		assert!(keylines.iter().any(|kl| {
+			let (s, e) = (
+				Point2d::new(f64::from(kl.start_point_x), f64::from(kl.start_point_y)),
+				Point2d::new(f64::from(kl.end_point_x), f64::from(kl.end_point_y)),
+			);
+			let close = |p: Point2d, q: Point2d| (p - q).norm() < 1.;
+			close(a, s) && close(b, e) || close(a, e) && close(b, s)
+		}));
+	}
+}
+
+#[test]
+fn homography_from_lines() -> Result<()> {
+	let h = [[0.9, -0.1, 30.], [0.05, 1.1, -20.], [0.0002, -0.0001, 1.]];
+	let project = |(x, y): (f32, f32)| {
+		let (x, y) = (f64::from(x), f64::from(y));
+		let w = h[2][0] * x + h[2][1] * y + h[2][2];
+		(
+			((h[0][0] * x + h[0][1] * y + h[0][2]) / w) as f32,
+			((h[1][0] * x + h[1][1] * y + h[1][2]) / w) as f32,
+		)
+	};
+	let lerp = |a: (f32, f32), b: (f32, f32), t: f32| (a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t);
+	let segments = [
+		((10., 10.), (300., 20.)),
+		((20., 50.), (40., 400.)),
+		((100., 300.), (350., 250.)),
+		((300., 50.), (280., 380.)),
+		((50., 200.), (250., 100.)),
+		((150., 30.), (200., 350.)),
+		((60., 380.), (330., 330.)),
+		((30., 120.), (320., 180.)),
+	];
+	let query = segments.iter().map(|&(a, b)| keyline(a, b)).collect::<Vec<_>>();
+	// the train segments cover different parts of the same lines
+	let mut train = segments
+		.iter()
+		.map(|&(a, b)| keyline(project(lerp(a, b, 0.1)), project(lerp(a, b, 0.8))))
+		.collect::<Vec<_>>();
+	train.push(keyline((0., 0.), (400., 400.)));
+	train.push(keyline((400., 0.), (380., 50.)));
+	let dmatch = |query_idx, train_idx| DMatch {
+		query_idx,
+		train_idx,
+		img_idx: 0,
+		distance: 0.,
+	};
+	let mut matches = (0..segments.len() as i32).map(|i| dmatch(i, i)).collect::<Vec<_>>();
+	matches.push(dmatch(0, 8));
+	matches.push(dmatch(3, 9));
+
+	let res = line_descriptor::estimate_homography_from_lines(
+		&query,
+		&train,
+		&matches,
+		LineHomographyMethod::Ransac(ModelFitParams::default()),
+	)?
+	.unwrap();
+	assert_eq!(segments.len(), res.inlier_count());
+	assert!(!res.inliers[segments.len()] && !res.inliers[segments.len() + 1]);
+	for &(a, _) in &segments {
+		let expected = project(a);
+		let actual = res.project(Point2d::new(f64::from(a.0), f64::from(a.1))).unwrap();
+		assert!((actual - Point2d::new(f64::from(expected.0), f64::from(expected.1))).norm() < 0.5);
+	}
+
+	let res = line_descriptor::estimate_homography_from_lines(
+		&query,
+		&train,
+		&matches[..segments.len()],
+		LineHomographyMethod::LeastSquares,
+	)?
+	.unwrap();
+	for (actual, expected) in res.homography.iter().flatten().zip(h.iter().flatten()) {
+		assert!((actual - expected).abs() < 1e-3);
+	}
+	assert!(
+		line_descriptor::estimate_homography_from_lines(&query, &train, &matches[..3], LineHomographyMethod::LeastSquares)?
+			.is_none()
+	);
+	assert!(
+		line_descriptor::estimate_homography_from_lines(&query, &train, &[dmatch(0, 20)], LineHomographyMethod::LeastSquares)
+			.is_err()
+	);
+	Ok(())
+}
+
+#[test]
+fn line_match_evaluation() -> Result<()> {
+	use line_descriptor::evaluation::{self, EvaluationParams, LineError};
+
+	let dmatch = |query_idx: i32, train_idx: i32, distance: f32| DMatch {
+		query_idx,
+		train_idx,
+		img_idx: 0,
+		distance,
+	};
+	// translation by (10, 5)
+	let h = [[1., 0., 10.], [0., 1., 5.], [0., 0., 1.]];
+	let keylines1 = [
+		keyline((0., 0.), (40., 0.)),
+		keyline((0., 10.), (0., 50.)),
+		keyline((20., 20.), (50., 50.)),
+		// mapped outside of the second image
+		keyline((200., 200.), (220., 200.)),
+	];
+	let keylines2 = [
+		// reversed, the keylines are undirected
+		keyline((50., 6.), (10., 6.)),
+		// same line as the second keyline, but only its half is detected
+		keyline((10., 35.), (10., 55.)),
+		keyline((80., 10.), (80., 40.)),
+	];
+	let params = EvaluationParams::default();
+	assert!((evaluation::line_error(&keylines1[0], &keylines2[0], &h, LineError::Endpoint).unwrap() - 1.).abs() < 1e-9);
+	assert!(evaluation::line_error(&keylines1[1], &keylines2[1], &h, LineError::Endpoint).unwrap() > 5.);
+	assert!(evaluation::line_error(&keylines1[1], &keylines2[1], &h, LineError::Sampson).unwrap() < 1e-9);
+
+	let matches = [dmatch(0, 0, 10.), dmatch(1, 1, 20.), dmatch(2, 2, 5.)];
+	assert_eq!(
+		vec![true, false, false],
+		evaluation::correct_matches_mask(&keylines1, &keylines2, &matches, &h, &params)?
+	);
+	let sampson = EvaluationParams {
+		error: LineError::Sampson,
+		..params
+	};
+	let result = evaluation::evaluate_line_matches(&keylines1, &keylines2, &matches, &h, &sampson)?;
+	assert_eq!(3, result.matches);
+	assert_eq!(2, result.correct_matches);
+	assert_eq!(2, result.correspondences);
+	assert!((result.precision() - 2. / 3.).abs() < 1e-9);
+	assert_eq!(1., result.recall());
+
+	let detector = evaluation::evaluate_line_detector(&keylines1, &keylines2, &h, Size::new(100, 100), &sampson)?;
+	assert_eq!(2, detector.correspondences);
+	assert!((detector.repeatability - 2. / 3.).abs() < 1e-9);
+
+	let curve = evaluation::recall_precision_curve(&matches, &[true, true, false])?;
+	assert_eq!(
+		vec![Point2f::new(1., 0.), Point2f::new(0.5, 0.5), Point2f::new(1. / 3., 1.)],
+		curve
+	);
+	assert_eq!(1., evaluation::recall_at(&curve, 0.4));
+	assert_eq!(-1., evaluation::recall_at(&curve, 0.2));
+
+	assert!(evaluation::correct_matches_mask(&keylines1, &keylines2, &[dmatch(0, 3, 0.)], &h, &params).is_err());
+	assert!(evaluation::evaluate_line_matches(&keylines1, &keylines2, &matches, &[[0.; 3]; 3], &params).is_err());
+	assert!(evaluation::recall_precision_curve(&matches, &[true]).is_err());
+	Ok(())
+}
+
+#[test]
+fn horizon() -> Result<()> {
+	let mut image = Mat::new_rows_cols_with_default(480, 640, CV_8UC1, Scalar::all(60.))?;
+	for row in 0..image.rows() {
+		for col in 0..image.cols() {
+			if f64::from(row) < 200. + 0.1 * f64::from(col) {
+				*image.at_2d_mut::<u8>(row, col)? = 200;
+			}
+		}
+	}
+	let horizon = line_descriptor::detect_horizon(&image)?;
+	assert!(horizon.confidence > 0.5);
+	let line = horizon.line;
+	let y_at = |x: f32| {
+		line.start_point_y
+			+ (line.end_point_y - line.start_point_y) * (x - line.start_point_x) / (line.end_point_x - line.start_point_x)
+	};
+	assert!((y_at(320.) - 232.).abs() < 3.);
+	assert!((line.angle - 0.1f32.atan()).abs() < 0.01);
+	Ok(())
+}
+
+#[test]
+fn wires() -> Result<()> {
+	let mut image = Mat::new_rows_cols_with_default(200, 300, CV_8UC1, Scalar::all(220.))?;
+	// a wire interrupted by an occlusion
+	imgproc::line(
+		&mut image,
+		Point::new(10, 100),
+		Point::new(120, 110),
+		Scalar::all(40.),
+		2,
+		imgproc::LINE_AA,
+		0,
+	)?;
+	imgproc::line(
+		&mut image,
+		Point::new(150, 113),
+		Point::new(290, 126),
+		Scalar::all(40.),
+		2,
+		imgproc::LINE_AA,
+		0,
+	)?;
+	let wires = line_descriptor::detect_wires(&image, &WireDetectorParams::default())?;
+	let longest = wires
+		.iter()
+		.max_by(|a, b| a.length().partial_cmp(&b.length()).unwrap())
+		.unwrap();
+	assert!(longest.length() > 250.);
+	assert!(longest.keylines.len() >= 2);
+	let (first, last) = (longest.points[0], longest.points[longest.points.len() - 1]);
+	assert!((first.x - last.x).abs() > 250.);
+	Ok(())
+}
+
+#[test]
+fn patches() -> Result<()> {
+	let mut image = Mat::new_rows_cols_with_default(200, 200, CV_8UC1, Scalar::all(220.))?;
+	imgproc::line(
+		&mut image,
+		Point::new(50, 50),
+		Point::new(150, 150),
+		Scalar::all(20.),
+		3,
+		imgproc::LINE_8,
+		0,
+	)?;
+	let params = PatchParams {
+		size: Size::new(16, 9),
+		scale: 0.5,
+		..PatchParams::default()
+	};
+	let keylines = [keyline((50., 50.), (150., 150.)), keyline((150., 150.), (50., 50.))];
+	let patches = line_descriptor::extract_keyline_patches(&image, &keylines, &params)?;
+	assert_eq!(2, patches.rows());
+	assert_eq!(16 * 9, patches.cols());
+	assert_eq!(image.typ(), patches.typ());
+	// the line is horizontal in the middle row of the patch
+	for i in 0..2 {
+		let patch = patches.row(i)?.reshape(1, 9)?;
+		for col in 0..16 {
+			assert!(*patch.at_2d::<u8>(4, col)? < 60);
+			assert!(*patch.at_2d::<u8>(0, col)? > 180);
+			assert!(*patch.at_2d::<u8>(8, col)? > 180);
+		}
+	}
+
+	let upright = PatchParams {
+		orientation: PatchOrientation::Upright,
+		..params
+	};
+	let patches = line_descriptor::extract_keyline_patches(&image, &keylines[..1], &upright)?.reshape(1, 9)?;
+	assert!(*patches.at_2d::<u8>(4, 7)? < 60);
+	assert!(*patches.at_2d::<u8>(8, 0)? > 180);
+
+	// bright half disc on the right side of the keypoint, moments rotate it to the positive x axis
+	let mut image = Mat::new_rows_cols_with_default(100, 100, CV_8UC1, Scalar::all(0.))?;
+	imgproc::rectangle_points(
+		&mut image,
+		Point::new(50, 0),
+		Point::new(99, 99),
+		Scalar::all(255.),
+		imgproc::FILLED,
+		imgproc::LINE_8,
+		0,
+	)?;
+	let angle = line_descriptor::intensity_centroid_angle(&image, Point2f::new(50., 50.), 20.)?;
+	assert!(angle.abs() < 0.05);
+	let keypoints = [KeyPoint::new_point(Point2f::new(50., 50.), 40., 90., 0., 0, -1)?];
+	let moments = PatchParams {
+		size: Size::new(8, 8),
+		orientation: PatchOrientation::Moments,
+		..PatchParams::default()
+	};
+	let patches = line_descriptor::extract_keypoint_patches(&image, &keypoints, &moments)?.reshape(1, 8)?;
+	assert!(*patches.at_2d::<u8>(4, 7)? > 200);
+	assert!(*patches.at_2d::<u8>(4, 0)? < 50);
+
+	assert!(line_descriptor::extract_keypoint_patches(&image, &[], &moments)?.empty());
+	Ok(())
+}
+
+#[test]
+fn descriptor_extractor() -> Result<()> {
+	fn describe(extractor: &mut dyn LineDescriptorExtractor, image: &Mat, keylines: &mut Vector<KeyLine>) -> Result<Mat> {
+		let mut descriptors = Mat::default();
+		extractor.compute(image, keylines, &mut descriptors)?;
+		Ok(descriptors)
+	}
+
+	let mut image = Mat::new_rows_cols_with_default(200, 200, CV_8UC1, Scalar::all(0.))?;
+	imgproc::rectangle_points(
+		&mut image,
+		Point::new(50, 60),
+		Point::new(150, 140),
+		Scalar::all(255.),
+		imgproc::FILLED,
+		imgproc::LINE_8,
+		0,
+	)?;
+	let mut bd = BinaryDescriptor::create_binary_descriptor()?;
+	let mut keylines = Vector::new();
+	bd.detect_1(&image, &mut keylines, None)?;
+	assert!(!keylines.is_empty());
+	let mut extractor: Box<dyn LineDescriptorExtractor> = Box::new(bd);
+	let descriptors = describe(&mut extractor, &image, &mut keylines)?;
+	assert_eq!(keylines.len(), descriptors.rows() as usize);
+	assert_eq!(extractor.descriptor_type()?, descriptors.depth());
+	assert_eq!(core::NORM_HAMMING, extractor.default_norm()?);
+	Ok(())
+}
+
+#[test]
+fn detect_and_compute() -> Result<()> {
+	let mut image = Mat::new_rows_cols_with_default(200, 200, CV_8UC1, Scalar::all(0.))?;
+	imgproc::rectangle_points(
+		&mut image,
+		Point::new(50, 60),
+		Point::new(150, 140),
+		Scalar::all(255.),
+		imgproc::FILLED,
+		imgproc::LINE_8,
+		0,
+	)?;
+	let bd = BinaryDescriptor::create_binary_descriptor()?;
+	let (keylines, descriptors) = bd.detect_and_compute(&image, None)?;
+	assert!(!keylines.is_empty());
+	assert_eq!(keylines.len(), descriptors.rows() as usize);
+	assert_eq!(32, descriptors.cols());
+
+	// the mask excluding the whole image leaves nothing to detect
+	let mask = Mat::new_rows_cols_with_default(200, 200, CV_8UC1, Scalar::all(0.))?;
+	let (keylines, descriptors) = bd.detect_and_compute(&image, Some(&mask))?;
+	assert!(keylines.is_empty());
+	assert_eq!(0, descriptors.rows());
+	Ok(())
+}
+
+#[test]
+fn binary_descriptor_pyramid() -> Result<()> {
+	let mut image = Mat::new_rows_cols_with_default(200, 200, CV_8UC1, Scalar::all(0.))?;
+	imgproc::rectangle_points(
+		&mut image,
+		Point::new(50, 60),
+		Point::new(150, 140),
+		Scalar::all(255.),
+		imgproc::FILLED,
+		imgproc::LINE_8,
+		0,
+	)?;
+	let bd = BinaryDescriptor_Params::builder().num_octaves(2).build_descriptor()?;
+	let pyramid = bd.compute_pyramid(&image)?;
+	assert_eq!(bd.get_num_of_octaves()? as usize, pyramid.num_octaves());
+	assert_eq!(Size::new(200, 200), pyramid.images.get(0)?.size()?);
+	assert_eq!(vec![1., 2.], pyramid.scale_factors());
+	assert_eq!(Size::new(100, 100), pyramid.images.get(1)?.size()?);
+	assert!(pyramid.images.iter().all(|level| level.typ() == CV_8UC1));
+
+	// color images are converted to grayscale first
+	let mut color = Mat::default();
+	imgproc::cvt_color(&image, &mut color, imgproc::COLOR_GRAY2BGR, 0)?;
+	let color_pyramid = bd.compute_pyramid(&color)?;
+	assert_eq!(
+		pyramid.images.get(1)?.data_bytes()?,
+		color_pyramid.images.get(1)?.data_bytes()?
+	);
+	Ok(())
+}
+
+#[test]
+fn mixed_precision() -> Result<()> {
+	let codes = DescriptorBinarizer::threshold(0.).binarize(&Mat::from_slice_2d(&[[1f32, -1., 2.], [-1., 0.5, -2.]])?)?;
+	assert_eq!(32, codes.cols());
+	assert_eq!(&[0b101, 0], &codes.at_row::<u8>(0)?[..2]);
+	assert_eq!(&[0b010, 0], &codes.at_row::<u8>(1)?[..2]);
+
+	// deterministic pseudo-random descriptors
+	let mut state = 12345u32;
+	let mut rand = || {
+		state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+		f32::from((state >> 16) as u16) / 65535. - 0.5
+	};
+	let train = (0..200)
+		.map(|_| (0..32).map(|_| rand()).collect::<Vec<_>>())
+		.collect::<Vec<_>>();
+	let query = train
+		.iter()
+		.step_by(10)
+		.map(|row| row.iter().map(|x| x + rand() * 0.02).collect::<Vec<_>>())
+		.collect::<Vec<_>>();
+	let train = Mat::from_slice_2d(&train)?;
+	let query = Mat::from_slice_2d(&query)?;
+
+	let binarizer = DescriptorBinarizer::itq(&train, 16, 20)?;
+	assert_eq!(16, binarizer.bits());
+	let mut matcher = MixedPrecisionMatcher::new(binarizer)?;
+	matcher.add(&train)?;
+	assert_eq!(1, matcher.train_descriptors().len());
+	let mut matches = Vector::new();
+	matcher.match_query(&query, &mut matches, &Vector::new())?;
+	assert_eq!(query.rows() as usize, matches.len());
+	for m in matches {
+		assert_eq!(m.query_idx * 10, m.train_idx);
+		assert_eq!(0, m.img_idx);
+		assert!(m.distance < 0.1);
+	}
+	Ok(())
+}
+
+#[test]
+fn rerank() -> Result<()> {
+	let query = Mat::from_slice_2d(&[[0b1111_0000u8, 0], [0, 0xff]])?;
+	let train = [
+		Mat::from_slice_2d(&[[0b1111_0001u8, 0], [0, 0]])?,
+		Mat::from_slice_2d(&[[0b1111_0000u8, 1], [0, 0xfe]])?,
+	];
+	let candidate = |query_idx, img_idx, train_idx| DMatch {
+		query_idx,
+		train_idx,
+		img_idx,
+		distance: 0.,
+	};
+	let candidates: Vector<Vector<DMatch>> = vec![
+		vec![candidate(0, 0, 1), candidate(0, 0, 0), candidate(0, 1, 0)]
+			.into_iter()
+			.collect(),
+		vec![candidate(1, 0, 1), candidate(1, 1, 1)].into_iter().collect(),
+	]
+	.into_iter()
+	.collect();
+	let reranked = line_descriptor::rerank(&candidates, &query, &train, core::NORM_HAMMING, 2, |_| true)?;
+	assert_eq!(2, reranked.len());
+	let first = reranked.get(0)?;
+	assert_eq!(2, first.len());
+	assert_eq!(
+		(0, 0, 1.),
+		(first.get(0)?.img_idx, first.get(0)?.train_idx, first.get(0)?.distance)
+	);
+	assert_eq!(
+		(1, 0, 1.),
+		(first.get(1)?.img_idx, first.get(1)?.train_idx, first.get(1)?.distance)
+	);
+	let second = reranked.get(1)?;
+	assert_eq!(
+		(1, 1, 1.),
+		(second.get(0)?.img_idx, second.get(0)?.train_idx, second.get(0)?.distance)
+	);
+	assert_eq!(8., second.get(1)?.distance);
+
+	// geometric verification rejects the crossing keylines
+	let query_keylines = [keyline((0., 0.), (10., 0.)), keyline((0., 0.), (0., 10.))];
+	let train_keylines = vec![
+		vec![keyline((5., 5.), (15., 6.)), keyline((5., 5.), (15., 5.))],
+		vec![keyline((0., 0.), (1., 0.)), keyline((3., 0.), (3., 12.))],
+	];
+	let check = KeyLineGeometryCheck::default();
+	let reranked = line_descriptor::rerank(
+		&candidates,
+		&query,
+		&train,
+		core::NORM_HAMMING,
+		5,
+		check.verifier(&query_keylines, &train_keylines),
+	)?;
+	let first = reranked.get(0)?;
+	assert_eq!(2, first.len());
+	assert!(first.iter().all(|m| m.img_idx == 0));
+	let second = reranked.get(1)?;
+	assert_eq!(1, second.len());
+	assert_eq!((1, 1), (second.get(0)?.img_idx, second.get(0)?.train_idx));
+
+	assert!(line_descriptor::rerank(&candidates, &query, &train, core::NORM_MINMAX, 1, |_| true).is_err());
+	Ok(())
+}
+
+#[test]
+fn polyline() {
+	// an arc split into 3 keylines with gaps, one of them reversed, and an unrelated perpendicular keyline
+	let keylines = vec![
+		keyline((0., 0.), (100., 0.)),
+		keyline((205., 11.), (110., 1.)),
+		keyline((215., 13.), (300., 35.)),
+		keyline((150., 50.), (150., 150.)),
+	];
+	let polylines = line_descriptor::link_keylines(&keylines, &PolyLineLinkParams::default());
+	assert_eq!(polylines.len(), 2);
+	let arc = polylines.iter().find(|p| p.keylines.len() == 3).unwrap();
+	assert_eq!(arc.points.len(), 6);
+	assert!((arc.length() - 303.6).abs() < 1.);
+	assert!(arc.keylines_length() < arc.length());
+	for pair in arc.keylines.windows(2) {
+		let (prev_end, next_start) = (
+			Point2f::new(pair[0].end_point_x, pair[0].end_point_y),
+			Point2f::new(pair[1].start_point_x, pair[1].start_point_y),
+		);
+		assert!((prev_end.x - next_start.x).hypot(prev_end.y - next_start.y) < 20.);
+	}
+	let curvature = arc.curvature();
+	assert_eq!(curvature.len(), 4);
+	assert!(curvature.iter().all(|&c| c >= 0.));
+	assert!(arc.mean_curvature() > 0.);
+	assert!(arc.straightness() > 0.95 && arc.straightness() < 1.);
+
+	let min_length = PolyLineLinkParams {
+		min_length: 150.,
+		..PolyLineLinkParams::default()
+	};
+	assert_eq!(line_descriptor::link_keylines(&keylines, &min_length).len(), 1);
+
+	let square = PolyLine::from_points(vec![Point2f::new(0., 0.), Point2f::new(10., 0.), Point2f::new(10., 10.)]);
+	assert_eq!(square.keylines.len(), 2);
+	assert_eq!(square.point_at(15.), Some(Point2f::new(10., 5.)));
+	assert_eq!(square.point_at(21.), None);
+	let resampled = square.resample(3.);
+	assert_eq!(resampled.points.len(), 8);
+	assert_eq!(resampled.points[7], Point2f::new(10., 10.));
+	assert_eq!(resampled.points[4], Point2f::new(10., 2.));
+	assert!((resampled.length() - square.length()).abs() < 2.);
+}
+
+#[test]
+fn track_stats() {
+	// uniformly accelerated motion with the keyline rotating through the ±π/2 wrap point
+	let mut stats = TrackStats::new(5, std::f64::consts::PI);
+	assert!(stats.velocity().is_none());
+	for frame in 0..10 {
+		let t = f64::from(frame);
+		let (x, y) = (10. + 2. * t + 0.5 * t * t, 50. - 3. * t);
+		let angle = (1.4 + 0.05 * t) as f32;
+		let (dx, dy) = (20. * angle.cos(), 20. * angle.sin());
+		let kl = keyline((x as f32 - dx, y as f32 - dy), (x as f32 + dx, y as f32 + dy));
+		stats.update_keyline(t, &kl);
+		if frame == 0 {
+			assert!(stats.velocity().is_none());
+		} else if frame == 1 {
+			assert!(stats.acceleration().is_none());
+		}
+	}
+	assert_eq!(stats.observations(), 10);
+	let velocity = stats.velocity().unwrap();
+	assert!((velocity.x - 11.).abs() < 1e-3);
+	assert!((velocity.y + 3.).abs() < 1e-3);
+	assert!((stats.speed().unwrap() - 130f64.sqrt()).abs() < 1e-3);
+	let acceleration = stats.acceleration().unwrap();
+	assert!((acceleration.x - 1.).abs() < 1e-3);
+	assert!(acceleration.y.abs() < 1e-3);
+	assert!((stats.orientation_rate().unwrap() - 0.05).abs() < 1e-4);
+	assert!((stats.orientation_drift() - 0.45).abs() < 1e-4);
+
+	// repeated timestamp replaces the previous observation
+	stats.update(9., Point2d::new(0., 0.), 0.);
+	assert_eq!(stats.observations(), 10);
+	assert_eq!(stats.position(), Some(Point2d::new(0., 0.)));
+}
+
+#[test]
+fn detection_sink() -> Result<()> {
+	let events = vec![
+		DetectionEvent::LineAppeared {
+			track_id: 1,
+			timestamp: 0.5,
+			keyline: keyline((0., 0.), (10., 0.)),
+		},
+		DetectionEvent::ZoneCrossed {
+			track_id: 1,
+			timestamp: 2.,
+			zone: "gate \"A\"".to_string(),
+			direction: CrossingDirection::In,
+			position: Point2f::new(5., 1.5),
+		},
+	];
+	let mut collected = Vec::<DetectionEvent>::new();
+	{
+		let sink: &mut dyn DetectionSink = &mut collected;
+		for event in &events {
+			sink.push(event)?;
+		}
+		sink.flush()?;
+	}
+	assert_eq!(collected, events);
+	assert_eq!(
+		events[0].to_json(),
+		r#"{"event":"line_appeared","track_id":1,"timestamp":0.5,"keyline":{"start":[0,0],"end":[10,0],"angle":0,"response":0}}"#
+	);
+	assert_eq!(
+		events[1].to_json(),
+		r#"{"event":"zone_crossed","track_id":1,"timestamp":2,"zone":"gate \"A\"","direction":"in","position":[5,1.5]}"#
+	);
+
+	#[cfg(feature = "sink-jsonl")]
+	{
+		let mut sink = line_descriptor::JsonLinesSink::new(vec![]);
+		for event in &events {
+			sink.push(event)?;
+		}
+		let out = String::from_utf8(sink.into_inner()).unwrap();
+		assert_eq!(out.lines().count(), 2);
+		assert!(out.lines().all(|line| line.starts_with("{\"event\":")));
+	}
+	Ok(())
+}
+
+#[test]
+fn zones() -> Result<()> {
+	let zone = Zone::new(
+		"square",
+		vec![
+			Point2f::new(0., 0.),
+			Point2f::new(10., 0.),
+			Point2f::new(10., 10.),
+			Point2f::new(0., 10.),
+		],
+	);
+	assert!(zone.contains(Point2f::new(5., 5.)));
+	assert!(!zone.contains(Point2f::new(15., 5.)));
+	assert!(zone.intersects(&keyline((-5., 5.), (15., 5.))));
+	assert!(!zone.intersects(&keyline((-5., -5.), (15., -5.))));
+
+	// vertical tripwire pointing down, its right side is to the left in the image
+	let tripwire = Tripwire::from_points("gate", Point2f::new(20., 0.), Point2f::new(20., 10.));
+	assert!(tripwire.side(Point2f::new(15., 5.)) > 0.);
+	assert!(tripwire.intersects(&keyline((15., 5.), (25., 5.))));
+	assert!(!tripwire.intersects(&keyline((15., 15.), (25., 15.))));
+	assert_eq!(
+		tripwire.crossing(Point2f::new(25., 4.), Point2f::new(15., 6.)),
+		Some((CrossingDirection::In, Point2f::new(20., 5.)))
+	);
+	assert_eq!(tripwire.crossing(Point2f::new(25., 15.), Point2f::new(15., 15.)), None);
+
+	let mut analytics = ZoneAnalytics::new(2);
+	analytics.add_zone(zone);
+	analytics.add_tripwire(tripwire);
+	let mut events = Vec::<DetectionEvent>::new();
+	// moves left along y = 5 from x = 30 to x = 0 with a jitter back over the tripwire at x = 20
+	for (t, x) in [30., 25., 19., 21., 18., 16., 12., 9., 11., 8., 5.].iter().enumerate() {
+		analytics.update(7, t as f64, Point2f::new(*x, 5.), &mut events)?;
+	}
+	assert_eq!(
+		events,
+		vec![
+			DetectionEvent::ZoneCrossed {
+				track_id: 7,
+				timestamp: 5.,
+				zone: "gate".to_string(),
+				direction: CrossingDirection::In,
+				position: Point2f::new(20., 5.),
+			},
+			DetectionEvent::ZoneCrossed {
+				track_id: 7,
+				timestamp: 10.,
+				zone: "square".to_string(),
+				direction: CrossingDirection::In,
+				position: Point2f::new(8., 5.),
+			},
+		]
+	);
+	assert_eq!(analytics.track_zones(7), vec!["square"]);
+	analytics.remove_track(7);
+	assert!(analytics.track_zones(7).is_empty());
+	Ok(())
+}
+
+#[test]
+fn line_tracker() -> Result<()> {
+	// every line has its own byte set, so the distance between the different lines is 16
+	let frame = |lines: &[(usize, f32)]| -> Result<(Vec<KeyLine>, Mat)> {
+		let keylines = lines
+			.iter()
+			.map(|&(i, shift)| {
+				let y = i as f32 * 50.;
+				keyline((10. + shift, y), (100. + shift, y + 5.))
+			})
+			.collect();
+		let rows = lines
+			.iter()
+			.map(|&(i, _)| {
+				let mut row = [0u8; 32];
+				row[i] = 0xff;
+				row
+			})
+			.collect::<Vec<_>>();
+		Ok((keylines, Mat::from_slice_2d(&rows)?))
+	};
+	let mut tracker = LineTracker::new(LineTrackerParams {
+		max_distance: 4.,
+		max_missed: 1,
+		..LineTrackerParams::default()
+	})?;
+	let mut events = vec![];
+
+	let (keylines, descriptors) = frame(&[(0, 0.), (1, 0.), (2, 0.)])?;
+	let tracks = tracker.update_keylines_with_sink(&keylines, &descriptors, 0., &mut events)?;
+	assert!(tracks.is_empty());
+	assert_eq!(3, tracker.tracks().count());
+	assert!(events.is_empty());
+
+	// the lines moved and were detected in the different order
+	let (keylines, descriptors) = frame(&[(2, 5.), (0, 5.), (1, 5.)])?;
+	let tracks = tracker.update_keylines_with_sink(&keylines, &descriptors, 1., &mut events)?;
+	assert_eq!(3, tracks.len());
+	assert_eq!(vec![0, 1, 2], tracks.iter().map(|t| t.id).collect::<Vec<_>>());
+	assert_eq!(
+		vec![Some(1), Some(2), Some(0)],
+		tracks.iter().map(|t| t.keyline_idx).collect::<Vec<_>>()
+	);
+	assert!(tracks.iter().all(|t| t.velocity == Point2f::new(5., 0.) && t.hits == 2));
+	assert_eq!(3, events.iter().filter(|e| e.kind() == "line_appeared").count());
+
+	// line 1 is occluded and the new line 3 appears, the motion continues
+	let (keylines, descriptors) = frame(&[(0, 10.), (2, 10.), (3, 10.)])?;
+	let tracks = tracker.update_keylines(&keylines, &descriptors)?;
+	assert_eq!(3, tracks.len());
+	let occluded = tracks.iter().find(|t| t.id == 1).unwrap();
+	assert_eq!((None, 1), (occluded.keyline_idx, occluded.missed));
+	assert_eq!(4, tracker.tracks().count());
+
+	events.clear();
+	let (keylines, descriptors) = frame(&[(0, 15.), (2, 15.), (3, 15.)])?;
+	let tracks = tracker.update_keylines_with_sink(&keylines, &descriptors, 3., &mut events)?;
+	assert_eq!(vec![0, 2, 3], tracks.iter().map(|t| t.id).collect::<Vec<_>>());
+	assert_eq!(
+		vec!["track_lost", "line_appeared"],
+		events.iter().map(|e| e.kind()).collect::<Vec<_>>()
+	);
+	assert_eq!(1, events[0].track_id());
+	assert_eq!(3, events[1].track_id());
+	Ok(())
+}
+
+#[test]
+fn match_propagation() -> Result<()> {
+	// every descriptor has its own byte set, so the distance between the different keylines is 16
+	let descriptors = |order: &[usize]| -> Result<Mat> {
+		let rows = order
+			.iter()
+			.map(|&i| {
+				let mut row = [0u8; 32];
+				row[i] = 0xff;
+				row
+			})
+			.collect::<Vec<_>>();
+		Mat::from_slice_2d(&rows)
+	};
+	let train_keylines = (0..20)
+		.map(|i| {
+			let (x, y) = ((i % 5) as f32 * 100., (i / 5) as f32 * 100.);
+			keyline((x, y), (x + 40., y + (i % 3) as f32 * 10.))
+		})
+		.collect::<Vec<_>>();
+	let train_order = (0..20).collect::<Vec<_>>();
+	let params = PropagationParams {
+		max_distance: 4.,
+		reseed_interval: 0,
+		..PropagationParams::default()
+	};
+	let mut propagator = MatchPropagator::new(train_keylines.clone(), &descriptors(&train_order)?, params)?;
+	// frame keylines are shifted by the constant motion and come in the reverse order
+	let frame_order = (0..20).rev().collect::<Vec<_>>();
+	for t in 0..4 {
+		let shift = t as f32 * 4.;
+		let keylines = frame_order
+			.iter()
+			.map(|&i| {
+				let kl = &train_keylines[i];
+				keyline(
+					(kl.start_point_x + shift, kl.start_point_y + shift / 2.),
+					(kl.end_point_x + shift, kl.end_point_y + shift / 2.),
+				)
+			})
+			.collect::<Vec<_>>();
+		let matches = propagator.match_frame(&keylines, &descriptors(&frame_order)?)?;
+		assert_eq!(20, matches.len());
+		for m in &matches {
+			assert_eq!(frame_order[m.query_idx as usize], m.train_idx as usize);
+			assert_eq!(0., m.distance);
+		}
+		let stats = propagator.stats();
+		if t == 0 {
+			assert!(stats.fallback);
+			assert_eq!(20, stats.global);
+			assert_eq!(400, stats.distance_computations);
+		} else {
+			assert!(!stats.fallback);
+			assert_eq!(20, stats.propagated);
+			assert!(stats.distance_computations < 60);
+		}
+	}
+	assert_eq!(20, propagator.track_count());
+
+	// frame without the keylines drops the tracks after `max_missed` frames and falls back to the global matching
+	let empty = Mat::default();
+	for _ in 0..3 {
+		assert!(propagator.match_frame(&[], &empty)?.is_empty());
+	}
+	assert_eq!(0, propagator.track_count());
+	Ok(())
+}
+
+#[test]
+fn match_batch() -> Result<()> {
+	let mut matcher = BinaryDescriptorMatcher::create_binary_descriptor_matcher()?;
+	let train = Mat::from_slice_2d(&[[0u8; 32], [0x0f; 32], [0xff; 32]])?;
+	matcher.add(&vec![train].into_iter().collect())?;
+	matcher.train()?;
+
+	let queries = vec![
+		Mat::from_slice_2d(&[[0xffu8; 32], [1; 32]])?,
+		Mat::default(),
+		Mat::from_slice_2d(&[[0x0fu8; 32]])?,
+	];
+	let matches = matcher.match_batch(&queries)?;
+	assert_eq!(3, matches.len());
+	let pairs =
+		|set: usize| -> Result<Vec<(i32, i32)>> { Ok(matches.get(set)?.iter().map(|m| (m.query_idx, m.train_idx)).collect()) };
+	assert_eq!(vec![(0, 2), (1, 0)], pairs(0)?);
+	assert!(pairs(1)?.is_empty());
+	assert_eq!(vec![(0, 1)], pairs(2)?);
+
+	assert!(matcher
+		.match_batch(&[Mat::from_slice_2d(&[[0u8; 16]])?, Mat::from_slice_2d(&[[0u8; 32]])?])
+		.is_err());
+	Ok(())
+}
+
+#[test]
+fn binary_descriptor_const_getters() -> Result<()> {
+	fn describe(bd: &impl BinaryDescriptorTraitConst) -> Result<(i32, i32, i32, i32)> {
+		Ok((
+			bd.get_num_of_octaves()?,
+			bd.get_width_of_band()?,
+			bd.get_reduction_ratio()?,
+			bd.descriptor_size()?,
+		))
+	}
+	let mut bd = BinaryDescriptor::create_binary_descriptor()?;
+	bd.set_num_of_octaves(2)?;
+	bd.set_width_of_band(9)?;
+	assert_eq!((2, 9, 2, 32), describe(&bd)?);
+	Ok(())
+}
+
+#[test]
+fn radius_match_pages() -> Result<()> {
+	let mut matcher = BinaryDescriptorMatcher::create_binary_descriptor_matcher()?;
+	let train = Mat::from_slice_2d(&[[0u8; 32], [1; 32], [3; 32], [0xff; 32]])?;
+	matcher.add(&vec![train].into_iter().collect())?;
+	matcher.train()?;
+	// the first 3 queries are within the radius of the first 3 train descriptors, the last one has no neighbors
+	let queries = Mat::from_slice_2d(&[[0u8; 32], [1; 32], [0; 32], [0x55; 32]])?;
+	let paging = RadiusMatchPaging {
+		max_rows: 4,
+		max_matches: 4,
+	};
+	let pages = matcher
+		.radius_match_iter(&queries, 70., paging)?
+		.collect::<Result<Vec<_>>>()?;
+	assert!(pages.len() > 1);
+	let mut next_query = 0;
+	for page in &pages {
+		assert_eq!(next_query, page.first_query);
+		assert!(page.match_count() <= paging.max_matches || page.matches.len() == 1);
+		for (i, query_matches) in page.matches.iter().enumerate() {
+			for m in query_matches {
+				assert_eq!(page.first_query + i as i32, m.query_idx);
+			}
+		}
+		next_query += page.matches.len() as i32;
+	}
+	assert_eq!(4, next_query);
+	assert_eq!(9, pages.iter().map(|p| p.match_count()).sum::<usize>());
+
+	assert!(matcher
+		.radius_match_iter(&queries, 70., RadiusMatchPaging { max_rows: 0, ..paging })
+		.is_err());
+	Ok(())
+}
+
+#[test]
+fn params_builder() -> Result<()> {
+	let params = BinaryDescriptor_Params::builder()
+		.num_octaves(2)
+		.width_of_band(9)
+		.ksize(7)
+		.build()?;
+	assert_eq!(
+		(2, 9, 2, 7),
+		(
+			params.num_of_octave_(),
+			params.width_of_band_(),
+			params.reduction_ratio(),
+			params.ksize_()
+		)
+	);
+	let bd = BinaryDescriptor_Params::builder().num_octaves(3).build_descriptor()?;
+	assert_eq!(3, bd.get_num_of_octaves()?);
+
+	assert!(BinaryDescriptor_Params::builder().ksize(4).build().is_err());
+	assert!(BinaryDescriptor_Params::builder().num_octaves(0).build_descriptor().is_err());
+	Ok(())
+}
+
+#[test]
+fn clone_algorithm() -> Result<()> {
+	let mut bd = BinaryDescriptor::create_binary_descriptor()?;
+	bd.set_num_of_octaves(3)?;
+	bd.set_width_of_band(9)?;
+	let mut copy = core::clone_algorithm(&bd, BinaryDescriptor::create_binary_descriptor)?;
+	assert_eq!(3, copy.get_num_of_octaves()?);
+	assert_eq!(9, copy.get_width_of_band()?);
+
+	// the copy is independent of the source
+	copy.set_num_of_octaves(1)?;
+	assert_eq!(3, bd.get_num_of_octaves()?);
+	Ok(())
+}
+
+#[test]
+fn detector_pool() -> Result<()> {
+	let mut prototype = BinaryDescriptor::create_binary_descriptor()?;
+	prototype.set_num_of_octaves(2)?;
+	let pool = core::DetectorPool::new(prototype, BinaryDescriptor::create_binary_descriptor);
+	{
+		let first = pool.checkout()?;
+		let second = pool.checkout()?;
+		assert_eq!(2, first.get_num_of_octaves()?);
+		assert_eq!(2, second.get_num_of_octaves()?);
+		assert_eq!(2, pool.created());
+	}
+	// the returned instances are reused
+	assert_eq!(2, pool.checkout()?.get_num_of_octaves()?);
+	assert_eq!(2, pool.created());
+
+	core::parallel_for_fn(
+		&core::Range::new(0, 16)?,
+		|range| {
+			let image = Mat::new_rows_cols_with_default(64, 64, CV_8UC1, Scalar::all(0.)).unwrap();
+			for _ in range.start()..range.end() {
+				let mut detector = pool.checkout().unwrap();
+				let mut keylines = Vector::new();
+				detector.detect_1(&image, &mut keylines, None).unwrap();
+			}
+		},
+		-1.,
+	)?;
+	assert!(pool.created() >= 2);
+	Ok(())
+}
+
+#[test]
+fn filter_keylines() {
+	let with = |kl: KeyLine, response: f32, octave: i32| KeyLine { response, octave, ..kl };
+	let keylines = [
+		with(keyline((10., 10.), (50., 10.)), 0.9, 0),
+		// nearly collinear with the first one and weaker
+		with(keyline((12., 11.), (48., 11.5)), 0.5, 0),
+		with(keyline((10., 30.), (10., 80.)), 0.7, 1),
+		// too short
+		with(keyline((60., 60.), (62., 60.)), 0.8, 0),
+		// touches the border
+		with(keyline((0., 90.), (40., 90.)), 0.6, 0),
+	];
+	let filtered = |criteria: FilterCriteria| {
+		let mut out = keylines.iter().copied().collect::<Vector<_>>();
+		line_descriptor::filter_keylines(&mut out, criteria);
+		out.iter().map(|kl| kl.response).collect::<Vec<_>>()
+	};
+	assert_eq!(vec![0.9, 0.5, 0.7, 0.8, 0.6], filtered(FilterCriteria::default()));
+	assert_eq!(
+		vec![0.9, 0.5, 0.7, 0.6],
+		filtered(FilterCriteria {
+			min_length: 5.,
+			..FilterCriteria::default()
+		})
+	);
+	assert_eq!(
+		vec![0.7],
+		filtered(FilterCriteria {
+			octaves: Some((1, 2)),
+			..FilterCriteria::default()
+		})
+	);
+	assert_eq!(
+		vec![0.9, 0.5, 0.7, 0.8],
+		filtered(FilterCriteria {
+			image_size: Size::new(100, 100),
+			border_margin: 2.,
+			..FilterCriteria::default()
+		})
+	);
+	assert_eq!(
+		vec![0.9, 0.7, 0.8, 0.6],
+		filtered(FilterCriteria {
+			nms: Some(LineNms::default()),
+			..FilterCriteria::default()
+		})
+	);
+	// the order is preserved, the strongest are retained
+	assert_eq!(
+		vec![0.9, 0.7, 0.8],
+		filtered(FilterCriteria {
+			nms: Some(LineNms::default()),
+			max_count: Some(3),
+			min_response: 0.55,
+			..FilterCriteria::default()
+		})
+	);
+}
+
+#[test]
+fn cluster_keylines() {
+	let keylines = [
+		keyline((10., 10.), (40., 10.)),
+		// vertical line far from the others
+		keyline((100., 0.), (100., 50.)),
+		// overlaps the first one and is reversed
+		keyline((60., 10.5), (35., 10.)),
+		// continues the previous one after a small gap
+		keyline((62., 11.), (90., 11.)),
+		// parallel to the first one, but too far across
+		keyline((10., 50.), (40., 50.)),
+		// collinear with the first one, but too far along
+		keyline((120., 10.), (150., 10.)),
+	]
+	.iter()
+	.copied()
+	.collect::<Vector<_>>();
+	let angle_tol = 3f64.to_radians();
+	assert_eq!(
+		vec![vec![0, 2, 3], vec![1], vec![4], vec![5]],
+		line_descriptor::cluster_keylines(&keylines, angle_tol, 2.)
+	);
+	assert_eq!(
+		vec![vec![0, 2, 3, 5], vec![1], vec![4]],
+		line_descriptor::cluster_keylines(&keylines, angle_tol, 30.)
+	);
+
+	let merged = line_descriptor::merge_keyline_cluster(&keylines, &[0, 2, 3]).unwrap();
+	let (start, end) = merged.as_segment();
+	assert!((start.x - 10.).abs() < 0.5 && (start.y - 10.5).abs() < 1.);
+	assert!((end.x - 90.).abs() < 0.5 && (end.y - 10.5).abs() < 1.);
+	assert_eq!(None, line_descriptor::merge_keyline_cluster(&keylines, &[]));
+	assert_eq!(
+		Some(keylines.get(1).unwrap()),
+		line_descriptor::merge_keyline_cluster(&keylines, &[1])
+	);
+	assert_eq!(4, line_descriptor::merge_keylines(&keylines, angle_tol, 2.).len());
+}
+
+#[test]
+fn draw_line_matches_checked() -> Result<()> {
+	let img = Mat::new_rows_cols_with_default(40, 60, CV_8UC1, Scalar::all(0.))?;
+	let keylines = [keyline((5., 5.), (30., 5.)), keyline((5., 20.), (5., 35.))]
+		.iter()
+		.copied()
+		.collect::<Vector<_>>();
+	let matches = [DMatch::new_index(0, 1, 0, 0.)?, DMatch::new_index(1, 0, 0, 0.)?]
+		.iter()
+		.copied()
+		.collect::<Vector<_>>();
+	let draw = |img2: &Mat, matches: &Vector<DMatch>, mask: Option<&[bool]>| -> Result<Mat> {
+		let mut out = Mat::default();
+		line_descriptor::draw_line_matches_checked(
+			&img,
+			&keylines,
+			img2,
+			&keylines,
+			matches,
+			&mut out,
+			Scalar::all(-1.),
+			Scalar::all(-1.),
+			mask,
+			DrawLinesMatchesFlags::DEFAULT,
+		)?;
+		Ok(out)
+	};
+	let out = draw(&img, &matches, None)?;
+	assert_eq!((40, 120), (out.rows(), out.cols()));
+
+	let out_of_range = [DMatch::new_index(0, 2, 0, 0.)?].iter().copied().collect();
+	assert!(draw(&img, &out_of_range, None).is_err());
+	assert!(draw(&img, &matches, Some(&[true])).is_err());
+	let float_img = Mat::new_rows_cols_with_default(40, 60, core::CV_32FC1, Scalar::all(0.))?;
+	assert!(draw(&float_img, &matches, None).is_err());
+
+	let mut over = draw(&img, &matches, Some(&[true, false]))?;
+	line_descriptor::draw_line_matches(
+		&img,
+		&keylines,
+		&img,
+		&keylines,
+		&matches,
+		&mut over,
+		Scalar::all(-1.),
+		Scalar::all(-1.),
+		None,
+		DrawLinesMatchesFlags::DRAW_OVER_OUTIMG | DrawLinesMatchesFlags::NOT_DRAW_SINGLE_LINES,
+	)?;
+	assert_eq!((40, 120), (over.rows(), over.cols()));
+	let flags = DrawLinesMatchesFlags::DRAW_OVER_OUTIMG | DrawLinesMatchesFlags::NOT_DRAW_SINGLE_LINES;
+	assert_eq!(3, flags.bits());
+	assert!(flags.contains(DrawLinesMatchesFlags::DRAW_OVER_OUTIMG));
+	assert!(!DrawLinesMatchesFlags::DEFAULT.contains(DrawLinesMatchesFlags::NOT_DRAW_SINGLE_LINES));
+	Ok(())
+}
+
+#[test]
+fn draw_matches_layout() -> Result<()> {
+	let img1 = Mat::new_rows_cols_with_default(40, 60, CV_8UC1, Scalar::all(200.))?;
+	let img2 = Mat::new_rows_cols_with_default(30, 50, core::CV_8UC3, Scalar::all(100.))?;
+	let keylines = [keyline((5., 5.), (30., 5.)), keyline((5., 20.), (5., 28.))];
+	let keypoints = [
+		KeyPoint::new_point(Point2f::new(10., 10.), 6., -1., 0., 0, -1)?,
+		KeyPoint::new_point(Point2f::new(20., 15.), 6., -1., 0., 0, -1)?,
+	];
+	let matches = [DMatch::new_index(0, 1, 0, 0.)?, DMatch::new_index(1, 0, 0, 0.)?]
+		.iter()
+		.copied()
+		.collect::<Vector<_>>();
+
+	let layouts = [
+		(MatchLayout::SideBySide, (40, 110)),
+		(MatchLayout::Vertical, (70, 60)),
+		(MatchLayout::Blend { alpha: 0.5 }, (40, 60)),
+		(MatchLayout::Anaglyph, (40, 60)),
+	];
+	for &(layout, size) in &layouts {
+		let mut out = Mat::default();
+		line_descriptor::draw_matches_layout(
+			&img1,
+			&keylines,
+			&img2,
+			&keylines,
+			&matches,
+			&mut out,
+			layout,
+			DrawColor::GlobalPalette,
+		)?;
+		assert_eq!(size, (out.rows(), out.cols()));
+		assert_eq!(core::CV_8UC3, out.typ());
+		line_descriptor::draw_matches_layout(
+			&img1,
+			&keypoints,
+			&img2,
+			&keypoints,
+			&matches,
+			&mut out,
+			layout,
+			DrawColor::GlobalPalette,
+		)?;
+		assert_eq!(size, (out.rows(), out.cols()));
+	}
+
+	// first image goes into the red channel, second one into green and blue, the corner is clear of the drawing
+	let mut out = Mat::default();
+	line_descriptor::draw_matches_layout(
+		&img1,
+		&keylines,
+		&img2,
+		&keylines,
+		&Vector::new(),
+		&mut out,
+		MatchLayout::Anaglyph,
+		Palette::ColorBlindSafe.into(),
+	)?;
+	assert_eq!(core::Vec3b::from([100, 100, 200]), *out.at_2d::<core::Vec3b>(0, 0)?);
+	assert_eq!(core::Vec3b::from([0, 0, 200]), *out.at_2d::<core::Vec3b>(39, 59)?);
+
+	let blend = MatchLayout::Blend { alpha: 1.5 };
+	assert!(line_descriptor::draw_matches_layout(
+		&img1,
+		&keylines,
+		&img2,
+		&keylines,
+		&matches,
+		&mut out,
+		blend,
+		DrawColor::default()
+	)
+	.is_err());
+	let out_of_range = [DMatch::new_index(0, 2, 0, 0.)?].iter().copied().collect();
+	assert!(line_descriptor::draw_matches_layout(
+		&img1,
+		&keylines,
+		&img2,
+		&keylines,
+		&out_of_range,
+		&mut out,
+		MatchLayout::Vertical,
+		Scalar::new(0., 255., 0., 0.).into(),
+	)
+	.is_err());
+	Ok(())
+}
+
+#[test]
+fn draw_line_matches_annotated() -> Result<()> {
+	let img1 = Mat::new_rows_cols_with_default(40, 60, CV_8UC1, Scalar::all(0.))?;
+	let img2 = Mat::new_rows_cols_with_default(40, 60, CV_8UC1, Scalar::all(0.))?;
+	let keylines = [
+		keyline((5., 5.), (30., 5.)),
+		keyline((5., 20.), (5., 35.)),
+		keyline((40., 30.), (55., 30.)),
+	]
+	.iter()
+	.copied()
+	.collect::<Vector<_>>();
+	let matches = [DMatch::new_index(0, 1, 0, 12.)?, DMatch::new_index(1, 0, 0, 3.5)?]
+		.iter()
+		.copied()
+		.collect::<Vector<_>>();
+	let green = Scalar::new(0., 255., 0., 0.);
+	let count_color = |img: &Mat, color: Scalar| -> Result<usize> {
+		let color = core::Vec3b::from([color[0] as u8, color[1] as u8, color[2] as u8]);
+		Ok(img.data_typed::<core::Vec3b>()?.iter().filter(|&&px| px == color).count())
+	};
+
+	let mut plain = Mat::default();
+	let options = MatchDrawOptions {
+		layout: MatchLayout::Vertical,
+		color: DrawColor::Fixed(green),
+		..MatchDrawOptions::default()
+	};
+	line_descriptor::draw_line_matches_annotated(&img1, &keylines, &img2, &keylines, &matches, &mut plain, &options)?;
+	assert_eq!((80, 60), (plain.rows(), plain.cols()));
+	assert_eq!(core::CV_8UC3, plain.typ());
+
+	let mut thick = Mat::default();
+	line_descriptor::draw_line_matches_annotated(
+		&img1,
+		&keylines,
+		&img2,
+		&keylines,
+		&matches,
+		&mut thick,
+		&MatchDrawOptions { thickness: 3, ..options },
+	)?;
+	assert!(count_color(&thick, green)? > count_color(&plain, green)?);
+
+	// the unmatched third keyline is drawn in both images
+	let mut single = Mat::default();
+	let red = Scalar::new(0., 0., 255., 0.);
+	line_descriptor::draw_line_matches_annotated(
+		&img1,
+		&keylines,
+		&img2,
+		&keylines,
+		&matches,
+		&mut single,
+		&MatchDrawOptions {
+			single_line_color: Some(red),
+			..options
+		},
+	)?;
+	assert!(count_color(&single, red)? > 0);
+	assert_eq!(0, count_color(&plain, red)?);
+
+	let mut labeled = Mat::default();
+	line_descriptor::draw_line_matches_annotated(
+		&img1,
+		&keylines,
+		&img2,
+		&keylines,
+		&matches,
+		&mut labeled,
+		&MatchDrawOptions {
+			show_distance: true,
+			show_indices: true,
+			..options
+		},
+	)?;
+	let mut diff = Mat::default();
+	core::absdiff(&labeled, &plain, &mut diff)?;
+	assert!(core::count_non_zero(&diff.reshape(1, 0)?)? > 0);
+
+	assert!(line_descriptor::draw_line_matches_annotated(
+		&img1,
+		&keylines,
+		&img2,
+		&keylines,
+		&matches,
+		&mut labeled,
+		&MatchDrawOptions { thickness: 0, ..options },
+	)
+	.is_err());
+	Ok(())
+}
+
+#[test]
+fn match_graph() -> Result<()> {
+	let keylines = [keyline((0., 0.), (10., 0.)), keyline((0., 10.), (0., 20.))]
+		.iter()
+		.copied()
+		.collect::<Vector<_>>();
+	let mut graph = MatchGraph::new();
+	let left = graph.add_keylines("left \"cam\"", &keylines);
+	let right = graph.add_keylines("right", &keylines);
+	let third = graph.add_image("third", vec![Point2f::new(1., 2.)]);
+	assert_eq!(Point2f::new(5., 0.), graph.images()[left].features[0]);
+
+	let pair = [DMatch::new_index(0, 1, 0, 12.)?].iter().copied().collect::<Vector<_>>();
+	graph.add_matches(left, &pair, &[right])?;
+	let multi = [DMatch::new_index(1, 0, 1, 3.5)?, DMatch::new_index(0, 0, 0, 7.)?]
+		.iter()
+		.copied()
+		.collect::<Vector<_>>();
+	graph.add_matches(right, &multi, &[left, third])?;
+	assert_eq!(3, graph.edges().len());
+	assert_eq!((right, 1, third, 0), {
+		let e = graph.edges()[1];
+		(e.image1, e.feature1, e.image2, e.feature2)
+	});
+
+	let invalid_feature = [DMatch::new_index(0, 5, 0, 1.)?].iter().copied().collect::<Vector<_>>();
+	assert!(graph.add_matches(left, &invalid_feature, &[right]).is_err());
+	let invalid_image = [DMatch::new_index(0, 0, 2, 1.)?].iter().copied().collect::<Vector<_>>();
+	assert!(graph.add_matches(left, &invalid_image, &[right, third]).is_err());
+	assert!(graph.add_matches(5, &pair, &[right]).is_err());
+	assert_eq!(3, graph.edges().len());
+
+	let json = graph.to_json();
+	assert!(json.starts_with(r#"{"images":[{"id":0,"name":"left \"cam\"","features":[[5,0],[0,15]]}"#));
+	assert!(json.contains(r#"{"image1":0,"feature1":0,"image2":1,"feature2":1,"distance":12}"#));
+	assert!(json.ends_with(r#""distance":7}]}"#));
+
+	let dot = graph.to_dot();
+	assert!(dot.starts_with("graph matches {\n"));
+	assert!(dot.contains("label=\"left \\\"cam\\\"\";"));
+	assert!(dot.contains("\ti1_f1 -- i2_f0 [label=\"3.5\", distance=3.5];\n"));
+	assert!(dot.contains("i2_f0 [label=\"0 (1.0, 2.0)\"]"));
+	Ok(())
+}
+
+#[test]
+fn draw_keylines_styled() -> Result<()> {
+	let img = Mat::new_rows_cols_with_default(40, 60, CV_8UC1, Scalar::all(0.))?;
+	let red = Scalar::new(0., 0., 255., 0.);
+	let keylines = [
+		(keyline((5., 5.), (50., 5.)), LineStyle::with_color(red)),
+		(
+			keyline((5., 20.), (50., 20.)),
+			LineStyle {
+				thickness: 3,
+				arrow: true,
+				label: true,
+				..LineStyle::default()
+			},
+		),
+	];
+	let mut out = Mat::default();
+	line_descriptor::draw_keylines_styled(&img, &keylines, &mut out)?;
+	assert_eq!((40, 60, core::CV_8UC3), (out.rows(), out.cols(), out.typ()));
+	assert_eq!(core::Vec3b::from([0, 0, 255]), *out.at_2d::<core::Vec3b>(5, 20)?);
+	assert_eq!(core::Vec3b::from([0, 255, 0]), *out.at_2d::<core::Vec3b>(21, 20)?);
+	assert_eq!(core::Vec3b::from([0, 0, 0]), *out.at_2d::<core::Vec3b>(35, 5)?);
+
+	let invalid = [(
+		keyline((5., 5.), (50., 5.)),
+		LineStyle {
+			thickness: 0,
+			..LineStyle::default()
+		},
+	)];
+	assert!(line_descriptor::draw_keylines_styled(&img, &invalid, &mut out).is_err());
+	Ok(())
+}
+
+#[test]
+fn feature2d_adapter() -> Result<()> {
+	let mut image = Mat::new_rows_cols_with_default(200, 200, CV_8UC1, Scalar::all(0.))?;
+	imgproc::rectangle_points(
+		&mut image,
+		Point::new(50, 60),
+		Point::new(150, 140),
+		Scalar::all(255.),
+		imgproc::FILLED,
+		imgproc::LINE_8,
+		0,
+	)?;
+	let bd = BinaryDescriptor::create_binary_descriptor()?;
+	let mut feature2d = line_descriptor::binary_descriptor_to_feature2d(&bd)?;
+	assert_eq!(32, feature2d.descriptor_size()?);
+	assert_eq!(core::CV_8U, feature2d.descriptor_type()?);
+	assert_eq!(core::NORM_HAMMING, feature2d.default_norm()?);
+
+	let mut keypoints = Vector::<KeyPoint>::new();
+	feature2d.detect(&image, &mut keypoints, None)?;
+	assert!(!keypoints.is_empty());
+	// keypoints describe the keylines of the rectangle edges
+	for kp in &keypoints {
+		assert!(kp.size > 10.);
+		assert!((0. ..360.).contains(&kp.angle));
+	}
+	let mut descriptors = Mat::default();
+	feature2d.compute(&image, &mut keypoints, &mut descriptors)?;
+	assert_eq!(keypoints.len(), descriptors.rows() as usize);
+	assert_eq!(32, descriptors.cols());
+
+	let lsd = LSDDetector::create_lsd_detector()?;
+	let mut lsd_feature2d = line_descriptor::lsd_detector_to_feature2d(&lsd, 2, 1)?;
+	let mut lsd_keypoints = Vector::<KeyPoint>::new();
+	lsd_feature2d.detect(&image, &mut lsd_keypoints, None)?;
+	assert!(!lsd_keypoints.is_empty());
+	assert!(lsd_feature2d.compute(&image, &mut lsd_keypoints, &mut descriptors).is_err());
+	assert!(line_descriptor::lsd_detector_to_feature2d(&lsd, 0, 1).is_err());
+	Ok(())
+}
+
+#[test]
+fn descriptor_matcher_adapter() -> Result<()> {
+	// generic code written once for the point and line matchers
+	fn best_train_indices(matcher: &mut impl DescriptorMatcher, query: &Mat) -> Result<Vec<i32>> {
+		let mut matches = Vector::new();
+		matcher.match_(query, &mut matches, None)?;
+		Ok(matches.iter().map(|m| m.train_idx).collect())
+	}
+
+	let train = Mat::from_slice_2d(&[[0u8; 32], [0x0f; 32], [0xff; 32]])?;
+	let query = Mat::from_slice_2d(&[[0xffu8; 32], [1; 32]])?;
+	let binary = BinaryDescriptorMatcher::create_binary_descriptor_matcher()?;
+	let mut matcher = line_descriptor::binary_descriptor_matcher_to_descriptor_matcher(&binary)?;
+	assert!(DescriptorMatcherConst::empty(&matcher)?);
+	assert!(matcher.is_mask_supported()?);
+	matcher.add(&Vector::<Mat>::from_iter([train.try_clone()?]))?;
+	assert!(!DescriptorMatcherConst::empty(&matcher)?);
+	assert_eq!(vec![2, 0], best_train_indices(&mut matcher, &query)?);
+	let mut bf = BFMatcher::create(core::NORM_HAMMING, false)?;
+	bf.add(&Vector::<Mat>::from_iter([train.try_clone()?]))?;
+	assert_eq!(vec![2, 0], best_train_indices(&mut bf, &query)?);
+
+	let mut knn = Vector::new();
+	matcher.knn_match(&query, &mut knn, 2, None, false)?;
+	assert_eq!(2, knn.len());
+	assert_eq!(2, knn.get(0)?.len());
+	let mut radius = Vector::new();
+	matcher.radius_match(&query, &mut radius, 40., None, false)?;
+	assert_eq!(vec![1, 1], radius.iter().map(|m| m.len()).collect::<Vec<_>>());
+
+	// variant taking the train descriptors directly matches on a clone
+	let mut direct = Vector::new();
+	matcher.train_match(&query, &train, &mut direct, None)?;
+	assert_eq!(vec![2, 0], direct.iter().map(|m| m.train_idx).collect::<Vec<_>>());
+	let copy = matcher.clone(true)?;
+	assert!(DescriptorMatcherConst::empty(&copy)?);
+
+	DescriptorMatcher::clear(&mut matcher)?;
+	assert!(DescriptorMatcherConst::empty(&matcher)?);
+	Ok(())
+}
+
+#[test]
+fn match_filter() -> Result<()> {
+	fn pairs(matches: &Vector<DMatch>) -> Vec<(i32, i32)> {
+		matches.iter().map(|m| (m.query_idx, m.train_idx)).collect()
+	}
+
+	let train = Mat::from_slice_2d(&[[0u8; 32], [0x0f; 32], [0xff; 32]])?;
+	let binary = BinaryDescriptorMatcher::create_binary_descriptor_matcher()?;
+	let bf = BFMatcher::create(core::NORM_HAMMING, false)?;
+
+	// the last query is equally far from the first two train descriptors
+	let query = Mat::from_slice_2d(&[[0xffu8; 32], [1; 32], [0x03; 32]])?;
+	assert_eq!(vec![(0, 2), (1, 0)], pairs(&binary.knn_match_ratio(&query, &train, 0.8)?));
+	assert_eq!(vec![(0, 2), (1, 0)], pairs(&bf.knn_match_ratio(&query, &train, 0.8)?));
+	assert!(binary.knn_match_ratio(&query, &train, 0.).is_err());
+	assert!(bf.knn_match_ratio(&query, &train, 1.5).is_err());
+
+	// the first train descriptor is closer to the second query than to the last one
+	let query = Mat::from_slice_2d(&[[0xffu8; 32], [1; 32], [0x11; 32]])?;
+	assert_eq!(vec![(0, 2), (1, 0)], pairs(&binary.cross_check_match(&query, &train)?));
+	assert_eq!(vec![(0, 2), (1, 0)], pairs(&bf.cross_check_match(&query, &train)?));
+
+	let mut knn = Vector::<Vector<DMatch>>::new();
+	knn.push(Vector::from_iter([DMatch::new(0, 1, 10.)?, DMatch::new(0, 2, 11.)?]));
+	knn.push(Vector::from_iter([DMatch::new(1, 0, 5.)?]));
+	knn.push(Vector::new());
+	assert_eq!(vec![(1, 0)], pairs(&core::ratio_test(&knn, 0.75)?));
+	assert_eq!(vec![(0, 1), (1, 0)], pairs(&core::ratio_test(&knn, 1.)?));
+	Ok(())
+}
+
+#[test]
+fn match_images() -> Result<()> {
+	let scene = |offset: Point| -> Result<Mat> {
+		let mut image = Mat::new_rows_cols_with_default(240, 320, CV_8UC1, Scalar::all(0.))?;
+		for (tl, br, color) in [
+			((40, 40), (120, 100), 255.),
+			((150, 60), (260, 180), 160.),
+			((60, 130), (110, 200), 90.),
+		] {
+			imgproc::rectangle_points(
+				&mut image,
+				Point::new(tl.0, tl.1) + offset,
+				Point::new(br.0, br.1) + offset,
+				Scalar::all(color),
+				imgproc::FILLED,
+				imgproc::LINE_8,
+				0,
+			)?;
+		}
+		Ok(image)
+	};
+	let image1 = scene(Point::new(0, 0))?;
+	let image2 = scene(Point::new(12, 7))?;
+
+	let params = line_descriptor::LineMatchParams::default();
+	let result = line_descriptor::match_images(&image1, &image2, &params)?;
+	assert!(!result.keylines1.is_empty());
+	assert!(!result.keylines2.is_empty());
+	assert_eq!(result.keylines1.len(), result.descriptors1.rows() as usize);
+	assert_eq!(result.keylines2.len(), result.descriptors2.rows() as usize);
+	assert!(!result.matches.is_empty());
+	let shifted = result
+		.matches
+		.iter()
+		.filter(|m| {
+			let kl1 = result.keylines1.get(m.query_idx as usize).unwrap();
+			let kl2 = result.keylines2.get(m.train_idx as usize).unwrap();
+			(kl2.pt.x - kl1.pt.x - 12.).abs() < 2. && (kl2.pt.y - kl1.pt.y - 7.).abs() < 2.
+		})
+		.count();
+	assert!(shifted * 2 > result.matches.len());
+	assert!(result.timings.total() >= result.timings.matching);
+
+	let radius = line_descriptor::LineMatchParams {
+		mode: line_descriptor::LineMatchMode::Radius(30.),
+		..params
+	};
+	let result = line_descriptor::match_images(&image1, &image2, &radius)?;
+	assert!(!result.matches.is_empty());
+	assert!(result.matches.iter().all(|m| m.distance <= 30.));
+
+	let invalid = line_descriptor::LineMatchParams { ratio: 0., ..params };
+	assert!(line_descriptor::match_images(&image1, &image2, &invalid).is_err());
+	Ok(())
+}
+
+#[test]
+fn line_annotations() -> Result<()> {
+	assert!(LineAnnotations::new(Vec::<String>::new()).is_err());
+	let mut annotations = LineAnnotations::new(["a.png", "b.png", "c.png"])?;
+	assert_eq!(0, annotations.current_index());
+	assert!(!annotations.prev_frame());
+
+	// snapping to the detected keylines, the whole segment first, then the separate endpoints
+	let keylines = [keyline((10., 10.), (100., 10.)), keyline((50., 50.), (50., 150.))];
+	let snapping = LineSnapping::default();
+	let i = annotations.add_line_snapped(Point2f::new(98., 13.), Point2f::new(12., 8.), "edge", &keylines, snapping);
+	assert_eq!(
+		AnnotatedLine::new(Point2f::new(100., 10.), Point2f::new(10., 10.), "edge"),
+		annotations.current().lines[i]
+	);
+	annotations.add_line_snapped(Point2f::new(52., 48.), Point2f::new(200., 200.), "", &keylines, snapping);
+	assert_eq!(
+		(Point2f::new(50., 50.), Point2f::new(200., 200.)),
+		(annotations.current().lines[1].start, annotations.current().lines[1].end)
+	);
+	let off = LineSnapping { max_distance: 0. };
+	assert_eq!(Point2f::new(52., 48.), off.snap_point(Point2f::new(52., 48.), &keylines));
+
+	// picking and editing
+	assert_eq!(Some(0), annotations.line_at(Point2f::new(55., 12.), 5.));
+	assert_eq!(None, annotations.line_at(Point2f::new(55., 30.), 5.));
+	assert_eq!(
+		Some((1, LineEndpoint::End)),
+		annotations.endpoint_at(Point2f::new(198., 201.), 5.)
+	);
+	annotations.move_endpoint(1, LineEndpoint::End, Point2f::new(50., 150.))?;
+	annotations.set_label(1, "pole \"1\"")?;
+	assert!(annotations.move_endpoint(2, LineEndpoint::Start, Point2f::default()).is_err());
+
+	// navigation
+	assert!(annotations.next_unannotated());
+	assert_eq!(1, annotations.current_index());
+	annotations.add_line(AnnotatedLine::from_keyline(&keylines[1], "pole"));
+	assert!(annotations.next_unannotated());
+	assert_eq!(2, annotations.current_index());
+	assert!(!annotations.next_unannotated());
+	assert!(!annotations.next_frame());
+	assert!(annotations.go_to_frame(3).is_err());
+	annotations.go_to_frame(1)?;
+	assert_eq!("pole", annotations.delete_line(0)?.label);
+	assert!(annotations.delete_line(0).is_err());
+	annotations.add_line(AnnotatedLine::new(Point2f::new(1.5, 2.), Point2f::new(3., 4.25), ""));
+
+	// persistence
+	let json = annotations.to_json();
+	assert_eq!(annotations, LineAnnotations::from_json(&json)?);
+	let loaded = LineAnnotations::from_json(
+		r#" { "frames" : [ { "name" : "xA.png", "lines" : [ { "start" : [1, 2e1], "end" : [-3.5, 4] } ] } ] } "#,
+	)?;
+	assert_eq!("xA.png", loaded.current().name);
+	assert_eq!(
+		vec![AnnotatedLine::new(Point2f::new(1., 20.), Point2f::new(-3.5, 4.), "")],
+		loaded.current().lines
+	);
+	assert!(LineAnnotations::from_json("{\"frames\":[]}").is_err());
+	assert!(LineAnnotations::from_json("{\"frames\":[{\"name\":\"a\",\"lines\":[{\"start\":[1],\"end\":[1,2]}]}]}").is_err());
+	assert!(LineAnnotations::from_json("{\"frames\":[").is_err());
+
+	let path = std::env::temp_dir().join("ocvrs_line_annotations.json");
+	annotations.save(&path)?;
+	let reloaded = LineAnnotations::load(&path)?;
+	std::fs::remove_file(&path).ok();
+	assert_eq!(annotations, reloaded);
+	assert_eq!(1, reloaded.current_index());
+	Ok(())
+}
+
+#[test]
+fn keyline_keypoint_conversion() -> Result<()> {
+	let mut kl = keyline((10., 20.), (10., 0.));
+	kl.response = 0.5;
+	kl.octave = 1;
+	kl.class_id = 7;
+	kl.s_point_in_octave_x = 5.;
+	kl.s_point_in_octave_y = 10.;
+	kl.e_point_in_octave_x = 5.;
+	kl.e_point_in_octave_y = 0.;
+	kl.line_length = 10.;
+	let kp = line_descriptor::keyline_to_keypoint(&kl);
+	assert_eq!(Point2f::new(10., 10.), kp.pt);
+	assert_eq!(20., kp.size);
+	assert!((kp.angle - 270.).abs() < 1e-4);
+	assert_eq!((0.5, 1, 7), (kp.response, kp.octave, kp.class_id));
+
+	let back = line_descriptor::keypoint_to_keyline(&kp, 2);
+	let (start, end) = back.as_segment();
+	assert!((start - Point2f::new(10., 20.)).norm() < 1e-4);
+	assert!((end - Point2f::new(10., 0.)).norm() < 1e-4);
+	assert!((back.s_point_in_octave_y - 10.).abs() < 1e-4);
+	assert!((back.line_length - 10.).abs() < 1e-4);
+	assert_eq!((0.5, 1, 7), (back.response, back.octave, back.class_id));
+	// same size as for the keylines created from the endpoints, the segment going left doesn't make it negative
+	let diagonal = keyline((3., 0.), (0., 4.));
+	let back = line_descriptor::keypoint_to_keyline(&line_descriptor::keyline_to_keypoint(&diagonal), 2);
+	assert!((back.size - diagonal.size).abs() < 1e-3);
+	assert!(back.size > 0.);
+
+	let keylines = Vector::from_iter([kl, keyline((0., 0.), (3., 4.))]);
+	let keypoints = line_descriptor::keylines_to_keypoints(&keylines);
+	assert_eq!(vec![20., 5.], keypoints.iter().map(|kp| kp.size).collect::<Vec<_>>());
+	assert_eq!(2, line_descriptor::keypoints_to_keylines(&keypoints, 2).len());
+
+	let (starts, ends) = line_descriptor::keylines_to_endpoint_keypoints(&keylines);
+	assert_eq!(Point2f::new(3., 4.), ends.get(1)?.pt);
+	assert_eq!(7, starts.get(0)?.class_id);
+	let joined = line_descriptor::keylines_from_endpoint_keypoints(&starts, &ends)?;
+	assert_eq!((Point2f::new(10., 20.), Point2f::new(10., 0.)), joined.get(0)?.as_segment());
+	assert_eq!(5., joined.get(1)?.length());
+	let mut short = ends.clone();
+	short.remove(0)?;
+	assert!(line_descriptor::keylines_from_endpoint_keypoints(&starts, &short).is_err());
+	Ok(())
+}
+
+#[test]
+fn descriptor_migration() -> Result<()> {
+	let mut image = Mat::new_rows_cols_with_default(200, 200, CV_8UC1, Scalar::all(0.))?;
+	imgproc::rectangle_points(
+		&mut image,
+		Point::new(50, 60),
+		Point::new(150, 140),
+		Scalar::all(255.),
+		imgproc::FILLED,
+		imgproc::LINE_8,
+		0,
+	)?;
+	let mut bd = BinaryDescriptor::create_binary_descriptor()?;
+	let mut keylines = Vector::new();
+	bd.detect_1(&image, &mut keylines, None)?;
+	assert!(!keylines.is_empty());
+
+	let params = BinaryDescriptor_Params::default()?;
+	let stored = DescriptorMetadata::current("BinaryDescriptor", &bd)?.with_binary_descriptor_params(&params);
+	assert_eq!(core::get_version_string()?, stored.opencv_version);
+	assert_eq!(Some(2.), stored.param("reduction_ratio"));
+	let loaded = DescriptorMetadata::from_json(&stored.to_json())?;
+	assert_eq!(stored, loaded);
+	assert_eq!(DescriptorCompatibility::Compatible, stored.compatibility(&loaded));
+	let upgraded = DescriptorMetadata {
+		opencv_version: "99.0.0".to_string(),
+		..loaded.clone()
+	};
+	assert_eq!(DescriptorCompatibility::NeedsVerification, stored.compatibility(&upgraded));
+	let reconfigured = loaded.clone().with_param("ksize", 3.);
+	assert!(matches!(
+		stored.compatibility(&reconfigured),
+		DescriptorCompatibility::Incompatible(reason) if reason.contains("ksize")
+	));
+	assert!(DescriptorMetadata::from_json(&stored.to_json().replacen("\"format\":1", "\"format\":2", 1)).is_err());
+
+	let sample = DescriptorSample::capture(&mut bd, image.try_clone()?, keylines)?;
+	let drift = line_descriptor::verify_descriptors(&mut bd, &[sample])?;
+	assert!(drift.compared > 0);
+	assert!(drift.is_identical());
+	assert!(!drift.needs_rebuild(0.));
+
+	// simulate the stored descriptors from another version
+	let mut sample = DescriptorSample::capture(&mut bd, image, Vector::from_iter([keyline((50., 60.), (150., 60.))]))?;
+	if !sample.keylines.is_empty() {
+		let mut inverted = Mat::default();
+		core::bitwise_not(&sample.descriptors, &mut inverted, None::<&Mat>)?;
+		sample.descriptors = inverted;
+		let drift = line_descriptor::verify_descriptors(&mut bd, &[sample])?;
+		assert_eq!(drift.compared, drift.changed);
+		assert_eq!(256., drift.max_distance);
+		assert!(drift.needs_rebuild(10.));
+	}
+	Ok(())
+}
+
+#[test]
+#[cfg(ocvrs_has_module_ximgproc)]
+fn fast_line_detector() -> Result<()> {
+	use opencv::ximgproc;
+
+	let segments = Vector::from_iter([Vec4f::from([10., 20., 40., 60.]), Vec4f::from([0., 0., 0., 50.])]);
+	let keylines = line_descriptor::keylines_from_segments(&segments, Size::new(100, 50));
+	assert_eq!(2, keylines.len());
+	let kl = keylines.get(0)?;
+	assert_eq!((Point2f::new(10., 20.), Point2f::new(40., 60.)), kl.as_segment());
+	assert_eq!((0, 0, 50.), (kl.class_id, kl.octave, kl.line_length));
+	assert_eq!(0.5, kl.response);
+	assert_eq!(1, keylines.get(1)?.class_id);
+	assert_eq!(segments.to_vec(), line_descriptor::keylines_to_segments(&keylines).to_vec());
+
+	let mut image = Mat::new_rows_cols_with_default(200, 200, CV_8UC1, Scalar::all(0.))?;
+	imgproc::rectangle_points(
+		&mut image,
+		Point::new(50, 60),
+		Point::new(150, 140),
+		Scalar::all(255.),
+		imgproc::FILLED,
+		imgproc::LINE_8,
+		0,
+	)?;
+	let mut fld = ximgproc::create_fast_line_detector(10, 1.414, 50., 50., 3, false)?;
+	let keylines = fld.detect_keylines(&image)?;
+	assert!(!keylines.is_empty());
+	assert!(keylines.iter().all(|kl| kl.length() >= 10.));
+	let bd = BinaryDescriptor::create_binary_descriptor()?;
+	let mut keylines = keylines;
+	let mut descriptors = Mat::default();
+	bd.compute(&image, &mut keylines, &mut descriptors, false)?;
+	assert_eq!(keylines.len(), descriptors.rows() as usize);
+	Ok(())
+}
+
+#[test]
+#[cfg(ocvrs_has_module_ximgproc)]
+fn edge_drawing() -> Result<()> {
+	use std::convert::TryFrom;
+
+	use opencv::ximgproc::{self, EdgeDrawing_GradientOperator, EdgeDrawing_Params};
+
+	let params = line_descriptor::EdgeDrawingParams {
+		edge_detection_operator: EdgeDrawing_GradientOperator::SOBEL,
+		min_line_length: 20,
+		..line_descriptor::EdgeDrawingParams::default()
+	};
+	params.validate()?;
+	let raw = EdgeDrawing_Params::from(params);
+	assert_eq!(1, raw.edge_detection_operator);
+	assert_eq!(params, line_descriptor::EdgeDrawingParams::try_from(raw)?);
+	assert!(line_descriptor::EdgeDrawingParams::try_from(EdgeDrawing_Params {
+		edge_detection_operator: 10,
+		..raw
+	})
+	.is_err());
+	assert!(line_descriptor::EdgeDrawingParams::parameter_free().pf_mode);
+	assert!(line_descriptor::EdgeDrawingParams {
+		scan_interval: 0,
+		..params
+	}
+	.validate()
+	.is_err());
+
+	let mut image = Mat::new_rows_cols_with_default(200, 200, CV_8UC1, Scalar::all(0.))?;
+	imgproc::rectangle_points(
+		&mut image,
+		Point::new(50, 60),
+		Point::new(150, 140),
+		Scalar::all(255.),
+		imgproc::FILLED,
+		imgproc::LINE_8,
+		0,
+	)?;
+	let mut ed = line_descriptor::create_edge_drawing_with_params(params)?;
+	assert_eq!(params, ed.edge_drawing_params()?);
+	let keylines = ed.detect_keylines(&image)?;
+	assert!(!keylines.is_empty());
+	assert!(keylines.iter().all(|kl| kl.length() >= 20.));
+
+	let mut image = Mat::new_rows_cols_with_default(200, 200, CV_8UC1, Scalar::all(0.))?;
+	imgproc::circle(
+		&mut image,
+		Point::new(100, 100),
+		50,
+		Scalar::all(255.),
+		imgproc::FILLED,
+		imgproc::LINE_8,
+		0,
+	)?;
+	let mut ed = ximgproc::create_edge_drawing()?;
+	ed.set_edge_drawing_params(line_descriptor::EdgeDrawingParams::parameter_free())?;
+	let circles = ed.detect_circles(&image)?;
+	assert!(!circles.is_empty());
+	Ok(())
+}
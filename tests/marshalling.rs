@@ -54,13 +54,14 @@ fn callback() -> Result<()> {
 	#![cfg(ocvrs_has_module_highgui)]
 	use std::sync::{Arc, Mutex};
 
-	use opencv::{core, highgui, Error};
+	use opencv::{core, highgui, Error, ErrorKind};
 
 	// only run under X11 on linux
 	if cfg!(target_os = "linux") && option_env!("DISPLAY").is_some() {
 		{
 			if let Err(Error {
-				code: core::StsError, ..
+				kind: ErrorKind::Code(core::Code::StsError),
+				..
 			}) = highgui::named_window("test_1", 0)
 			{
 				// means that OpenCV is not built with GUI support, just skip the test
@@ -68,18 +69,20 @@ fn callback() -> Result<()> {
 			}
 			let mut value = 50;
 			let cb_value = Arc::new(Mutex::new(0));
-			highgui::create_trackbar(
-				"test_track_1",
-				"test_1",
-				Some(&mut value),
-				100,
-				Some(Box::new({
-					let cb_value = cb_value.clone();
-					move |s| {
-						*cb_value.lock().unwrap() = s;
-					}
-				})),
-			)?;
+			unsafe {
+				highgui::create_trackbar(
+					"test_track_1",
+					"test_1",
+					Some(&mut value),
+					100,
+					Some(Box::new({
+						let cb_value = cb_value.clone();
+						move |s| {
+							*cb_value.lock().unwrap() = s;
+						}
+					})),
+				)
+			}?;
 			assert_eq!(value, 50);
 			highgui::set_trackbar_pos("test_track_1", "test_1", 10)?;
 			assert_eq!(value, 10);
@@ -89,21 +92,34 @@ fn callback() -> Result<()> {
 		{
 			highgui::named_window("test_2", 0)?;
 			let cb_value = Arc::new(Mutex::new(0));
-			highgui::create_trackbar(
-				"test_track_2",
-				"test_2",
-				None,
-				100,
-				Some(Box::new({
-					let cb_value = cb_value.clone();
-					move |s| {
-						*cb_value.lock().unwrap() = s;
-					}
-				})),
-			)?;
+			unsafe {
+				highgui::create_trackbar(
+					"test_track_2",
+					"test_2",
+					None,
+					100,
+					Some(Box::new({
+						let cb_value = cb_value.clone();
+						move |s| {
+							*cb_value.lock().unwrap() = s;
+						}
+					})),
+				)
+			}?;
 			highgui::set_trackbar_pos("test_track_2", "test_2", 10)?;
 			assert_eq!(*cb_value.lock().unwrap(), 10);
 		}
+
+		{
+			use std::sync::atomic::{AtomicI32, Ordering};
+
+			highgui::named_window("test_3", 0)?;
+			let value = Arc::new(AtomicI32::new(30));
+			highgui::create_trackbar_bound("test_track_3", "test_3", value.clone(), 100)?;
+			assert_eq!(highgui::get_trackbar_pos("test_track_3", "test_3")?, 30);
+			highgui::set_trackbar_pos("test_track_3", "test_3", 10)?;
+			assert_eq!(value.load(Ordering::Relaxed), 10);
+		}
 	}
 	Ok(())
 }
@@ -132,12 +148,32 @@ fn string_return() -> Result<()> {
 	Ok(())
 }
 
+/// Passing Cow<str> argument, the nul-terminated borrowed string is not copied
+#[test]
+fn cow_str_arg() -> Result<()> {
+	use std::borrow::Cow;
+
+	use opencv::traits::{OpenCVTypeArg, OpenCVTypeExternContainer};
+
+	let s = "nul-terminated\0";
+	let container = Cow::Borrowed(s).opencv_into_extern_container()?;
+	assert!(matches!(container, Cow::Borrowed(_)));
+	assert_eq!(s.as_ptr().cast(), container.opencv_as_extern());
+
+	let container = Cow::<str>::Borrowed("plain").opencv_into_extern_container()?;
+	assert_eq!(b"plain", container.to_bytes());
+	let container = Cow::<str>::Owned("owned".to_string()).opencv_into_extern_container()?;
+	assert_eq!(b"owned", container.to_bytes());
+	assert!(Cow::<str>::Borrowed("interior\0nul").opencv_into_extern_container().is_err());
+	Ok(())
+}
+
 /// Return String via a mutable argument
 #[test]
 fn string_out_argument() -> Result<()> {
 	#![cfg(ocvrs_opencv_branch_4)]
 	use opencv::core::{FileNode, FileStorage, FileStorage_Mode};
-	use opencv::Error;
+	use opencv::{Error, ErrorKind};
 
 	use matches::assert_matches;
 
@@ -160,7 +196,7 @@ fn string_out_argument() -> Result<()> {
 		assert_matches!(
 			core::read_str(&node, &mut out, "123"),
 			Err(Error {
-				code: core::StsAssert,
+				kind: ErrorKind::Code(core::Code::StsAssert),
 				..
 			})
 		);
@@ -314,6 +350,8 @@ fn tuple() -> Result<()> {
 		let src_tuple = (10, 20.);
 		let tuple = TupleOfi32_f32::new(src_tuple);
 		assert_eq!(src_tuple, tuple.into_tuple());
+		let (a, b): (i32, f32) = TupleOfi32_f32::from(src_tuple).into();
+		assert_eq!(src_tuple, (a, b));
 	}
 
 	#[cfg(ocvrs_has_module_objdetect)]
@@ -6,7 +6,7 @@ use opencv::{
 	core::{self, MatConstIterator, Point, Rect, Scalar, Size, Vec2b, Vec3d, Vec3f, Vec4w},
 	prelude::*,
 	types::{VectorOfMat, VectorOfi32},
-	Error, Result,
+	Error, ErrorKind, Result,
 };
 
 const PIXEL: &[u8] = include_bytes!("pixel.png");
@@ -103,7 +103,7 @@ fn mat_nd() -> Result<()> {
 		assert_matches!(
 			mat.at::<Vec4w>(0),
 			Err(Error {
-				code: core::StsUnmatchedSizes,
+				kind: ErrorKind::Code(core::Code::StsUnmatchedSizes),
 				..
 			})
 		);
@@ -123,42 +123,42 @@ fn mat_nd() -> Result<()> {
 		assert_matches!(
 			mat.at_nd::<Vec4w>(&[1, 1, 1]),
 			Err(Error {
-				code: core::StsUnmatchedSizes,
+				kind: ErrorKind::Code(core::Code::StsUnmatchedSizes),
 				..
 			})
 		);
 		assert_matches!(
 			mat.at_nd::<Vec4w>(&[1, 1, 1, 1, 1, 1, 1]),
 			Err(Error {
-				code: core::StsUnmatchedSizes,
+				kind: ErrorKind::Code(core::Code::StsUnmatchedSizes),
 				..
 			})
 		);
 		assert_matches!(
 			mat.at_nd::<Vec4w>(&[10, 10, 10, 10, 10, 10]),
 			Err(Error {
-				code: core::StsOutOfRange,
+				kind: ErrorKind::Code(core::Code::StsOutOfRange),
 				..
 			})
 		);
 		assert_matches!(
 			mat.at_nd::<Vec4w>(&[-1, 10, 10, 10, 10, 10]),
 			Err(Error {
-				code: core::StsOutOfRange,
+				kind: ErrorKind::Code(core::Code::StsOutOfRange),
 				..
 			})
 		);
 		assert_matches!(
 			mat.at_nd::<Vec4w>(&[2, 3, 4, 5, 10, 10]),
 			Err(Error {
-				code: core::StsOutOfRange,
+				kind: ErrorKind::Code(core::Code::StsOutOfRange),
 				..
 			})
 		);
 		assert_matches!(
 			mat.at_nd::<Vec4w>(&[2, 3, 4, 5, 6, 10]),
 			Err(Error {
-				code: core::StsOutOfRange,
+				kind: ErrorKind::Code(core::Code::StsOutOfRange),
 				..
 			})
 		);
@@ -181,28 +181,28 @@ fn mat_at_1d() -> Result<()> {
 		assert_matches!(
 			mat.at::<f32>(-1),
 			Err(Error {
-				code: core::StsOutOfRange,
+				kind: ErrorKind::Code(core::Code::StsOutOfRange),
 				..
 			})
 		);
 		assert_matches!(
 			mat.at::<f32>(10),
 			Err(Error {
-				code: core::StsOutOfRange,
+				kind: ErrorKind::Code(core::Code::StsOutOfRange),
 				..
 			})
 		);
 		assert_matches!(
 			mat.at_mut::<f32>(-1),
 			Err(Error {
-				code: core::StsOutOfRange,
+				kind: ErrorKind::Code(core::Code::StsOutOfRange),
 				..
 			})
 		);
 		assert_matches!(
 			mat.at_mut::<f32>(10),
 			Err(Error {
-				code: core::StsOutOfRange,
+				kind: ErrorKind::Code(core::Code::StsOutOfRange),
 				..
 			})
 		);
@@ -221,28 +221,28 @@ fn mat_at_1d() -> Result<()> {
 		assert_matches!(
 			mat.at::<f32>(-1),
 			Err(Error {
-				code: core::StsOutOfRange,
+				kind: ErrorKind::Code(core::Code::StsOutOfRange),
 				..
 			})
 		);
 		assert_matches!(
 			mat.at::<f32>(10),
 			Err(Error {
-				code: core::StsOutOfRange,
+				kind: ErrorKind::Code(core::Code::StsOutOfRange),
 				..
 			})
 		);
 		assert_matches!(
 			mat.at_mut::<f32>(-1),
 			Err(Error {
-				code: core::StsOutOfRange,
+				kind: ErrorKind::Code(core::Code::StsOutOfRange),
 				..
 			})
 		);
 		assert_matches!(
 			mat.at_mut::<f32>(10),
 			Err(Error {
-				code: core::StsOutOfRange,
+				kind: ErrorKind::Code(core::Code::StsOutOfRange),
 				..
 			})
 		);
@@ -258,28 +258,28 @@ fn mat_at_1d() -> Result<()> {
 		assert_matches!(
 			mat.at::<f32>(-1),
 			Err(Error {
-				code: core::StsOutOfRange,
+				kind: ErrorKind::Code(core::Code::StsOutOfRange),
 				..
 			})
 		);
 		assert_matches!(
 			mat.at::<f32>(10),
 			Err(Error {
-				code: core::StsOutOfRange,
+				kind: ErrorKind::Code(core::Code::StsOutOfRange),
 				..
 			})
 		);
 		assert_matches!(
 			mat.at_mut::<f32>(-1),
 			Err(Error {
-				code: core::StsOutOfRange,
+				kind: ErrorKind::Code(core::Code::StsOutOfRange),
 				..
 			})
 		);
 		assert_matches!(
 			mat.at_mut::<f32>(10),
 			Err(Error {
-				code: core::StsOutOfRange,
+				kind: ErrorKind::Code(core::Code::StsOutOfRange),
 				..
 			})
 		);
@@ -311,14 +311,14 @@ fn mat_at_2d() -> Result<()> {
 	assert_matches!(
 		mat.at::<i32>(0),
 		Err(Error {
-			code: core::StsUnmatchedFormats,
+			kind: ErrorKind::Code(core::Code::StsUnmatchedFormats),
 			..
 		})
 	);
 	assert_matches!(
 		mat.at::<f32>(10000),
 		Err(Error {
-			code: core::StsOutOfRange,
+			kind: ErrorKind::Code(core::Code::StsOutOfRange),
 			..
 		})
 	);
@@ -343,21 +343,21 @@ fn mat_at_2d_multichannel() -> Result<()> {
 	assert_matches!(
 		mat.at_2d::<i32>(0, 0),
 		Err(Error {
-			code: core::StsUnmatchedFormats,
+			kind: ErrorKind::Code(core::Code::StsUnmatchedFormats),
 			..
 		})
 	);
 	assert_matches!(
 		mat.at_2d::<Vec3f>(100, 1),
 		Err(Error {
-			code: core::StsOutOfRange,
+			kind: ErrorKind::Code(core::Code::StsOutOfRange),
 			..
 		})
 	);
 	assert_matches!(
 		mat.at_2d::<Vec3f>(1, 100),
 		Err(Error {
-			code: core::StsOutOfRange,
+			kind: ErrorKind::Code(core::Code::StsOutOfRange),
 			..
 		})
 	);
@@ -385,28 +385,28 @@ fn mat_at_row() -> Result<()> {
 	assert_matches!(
 		mat.at_row::<i32>(0),
 		Err(Error {
-			code: core::StsUnmatchedFormats,
+			kind: ErrorKind::Code(core::Code::StsUnmatchedFormats),
 			..
 		})
 	);
 	assert_matches!(
 		mat.at_row::<f32>(100),
 		Err(Error {
-			code: core::StsOutOfRange,
+			kind: ErrorKind::Code(core::Code::StsOutOfRange),
 			..
 		})
 	);
 	assert_matches!(
 		mat.at_row_mut::<i32>(0),
 		Err(Error {
-			code: core::StsUnmatchedFormats,
+			kind: ErrorKind::Code(core::Code::StsUnmatchedFormats),
 			..
 		})
 	);
 	assert_matches!(
 		mat.at_row_mut::<f32>(100),
 		Err(Error {
-			code: core::StsOutOfRange,
+			kind: ErrorKind::Code(core::Code::StsOutOfRange),
 			..
 		})
 	);
@@ -424,14 +424,14 @@ fn mat_at_pt() -> Result<()> {
 	assert_matches!(
 		m.at_pt::<f32>(Point::new(-1, -3)),
 		Err(Error {
-			code: core::StsOutOfRange,
+			kind: ErrorKind::Code(core::Code::StsOutOfRange),
 			..
 		})
 	);
 	assert_matches!(
 		m.at_pt::<f32>(Point::new(3, -3)),
 		Err(Error {
-			code: core::StsOutOfRange,
+			kind: ErrorKind::Code(core::Code::StsOutOfRange),
 			..
 		})
 	);
@@ -528,14 +528,14 @@ fn mat_continuous() -> Result<()> {
 		assert_matches!(
 			sub_mat_non_cont.data_typed::<f32>(),
 			Err(Error {
-				code: core::StsUnmatchedSizes,
+				kind: ErrorKind::Code(core::Code::StsUnmatchedSizes),
 				..
 			})
 		);
 		assert_matches!(
 			sub_mat_non_cont.data_typed_mut::<f32>(),
 			Err(Error {
-				code: core::StsUnmatchedSizes,
+				kind: ErrorKind::Code(core::Code::StsUnmatchedSizes),
 				..
 			})
 		);
@@ -684,7 +684,7 @@ fn mat_iterator() -> Result<()> {
 		assert_matches!(
 			iter.current::<i32>(),
 			Err(Error {
-				code: core::StsOutOfRange,
+				kind: ErrorKind::Code(core::Code::StsOutOfRange),
 				..
 			})
 		);
@@ -694,7 +694,7 @@ fn mat_iterator() -> Result<()> {
 		assert_matches!(
 			iter.current::<i32>(),
 			Err(Error {
-				code: core::StsOutOfRange,
+				kind: ErrorKind::Code(core::Code::StsOutOfRange),
 				..
 			})
 		);
@@ -790,7 +790,7 @@ fn mat_data() -> Result<()> {
 		assert_matches!(
 			roi.data_bytes(),
 			Err(Error {
-				code: core::StsUnmatchedSizes,
+				kind: ErrorKind::Code(core::Code::StsUnmatchedSizes),
 				..
 			})
 		);
@@ -806,6 +806,76 @@ fn mat_data() -> Result<()> {
 	Ok(())
 }
 
+#[test]
+fn mat_npy() -> Result<()> {
+	{
+		let mat = Mat::from_slice_2d(&[[1.5f32, -2.], [3., 4.25], [0., 1.]])?;
+		let npy = mat.to_npy()?;
+		assert!(npy.starts_with(b"\x93NUMPY\x01\x00"));
+		let header_len = usize::from(u16::from_le_bytes([npy[8], npy[9]]));
+		assert_eq!(0, (10 + header_len) % 64);
+		let header = std::str::from_utf8(&npy[10..10 + header_len]).unwrap();
+		assert!(header.contains("'descr': '<f4'"));
+		assert!(header.contains("'shape': (3, 2)"));
+		assert!(header.ends_with('\n'));
+		let read = Mat::from_npy(&npy)?;
+		assert_eq!(mat.typ(), read.typ());
+		assert_eq!(mat.data_typed::<f32>()?, read.data_typed::<f32>()?);
+	}
+
+	{
+		// non-continuous multichannel matrix
+		let mat = Mat::from_slice_2d(&[
+			[Vec2b::from([1, 2]), Vec2b::from([3, 4])],
+			[Vec2b::from([5, 6]), Vec2b::from([7, 8])],
+		])?;
+		let roi = Mat::roi(&mat, Rect::new(1, 0, 1, 2))?;
+		let read = Mat::from_npy(&roi.to_npy()?)?;
+		assert_eq!(Vec2b::typ(), read.typ());
+		assert_eq!(Size::new(1, 2), read.size()?);
+		assert_eq!(&[3, 4, 7, 8], read.data_bytes()?);
+	}
+
+	{
+		// 1D big endian array as written by numpy
+		let mut npy = b"\x93NUMPY\x01\x00".to_vec();
+		let mut header = "{'descr': '>i2', 'fortran_order': False, 'shape': (3,), }".to_string();
+		header.push_str(&" ".repeat(63 - (10 + header.len()) % 64));
+		header.push('\n');
+		npy.extend_from_slice(&(header.len() as u16).to_le_bytes());
+		npy.extend_from_slice(header.as_bytes());
+		npy.extend_from_slice(&[0, 1, 1, 0, 0xff, 0xfe]);
+		let read = Mat::from_npy(&npy)?;
+		assert_eq!(i16::typ(), read.typ());
+		assert_eq!(Size::new(1, 3), read.size()?);
+		assert_eq!(&[1, 256, -2], read.data_typed::<i16>()?);
+		npy.pop();
+		assert_matches!(
+			Mat::from_npy(&npy),
+			Err(Error {
+				kind: ErrorKind::Code(core::Code::StsUnmatchedSizes),
+				..
+			})
+		);
+	}
+
+	let path = std::env::temp_dir().join("opencv_rust_mat_npy.npy");
+	let mat = Mat::from_slice_2d(&[[1u8, 2, 3], [4, 5, 6]])?;
+	mat.write_npy(&path)?;
+	let read = Mat::read_npy(&path)?;
+	std::fs::remove_file(&path).ok();
+	assert_eq!(Size::new(3, 2), read.size()?);
+	assert_eq!(&[1, 2, 3, 4, 5, 6], read.data_bytes()?);
+	assert_matches!(
+		Mat::from_npy(b"not npy"),
+		Err(Error {
+			kind: ErrorKind::Code(core::Code::StsParseError),
+			..
+		})
+	);
+	Ok(())
+}
+
 #[test]
 fn mat_equals() -> Result<()> {
 	let mat1 = Mat::new_rows_cols_with_default(3, 3, i32::typ(), Scalar::all(0.))?;
@@ -826,7 +896,7 @@ fn mat_rgb() -> Result<()> {
 	assert_matches!(
 		m.at_2d::<rgb::RGB8>(1, 1),
 		Err(Error {
-			code: core::StsUnmatchedFormats,
+			kind: ErrorKind::Code(core::Code::StsUnmatchedFormats),
 			..
 		})
 	);
@@ -0,0 +1,18 @@
+#![cfg(ocvrs_has_module_mcc)]
+
+use opencv::{
+	core::{Mat, Scalar, CV_8UC1, CV_8UC3},
+	mcc::{self, MCC_TYPECHART},
+	Result,
+};
+
+#[test]
+fn no_chart() -> Result<()> {
+	let image = Mat::new_rows_cols_with_default(240, 320, CV_8UC3, Scalar::all(128.))?;
+	assert!(mcc::detect_color_checkers(&image, MCC_TYPECHART::MCC24, 1)?.is_empty());
+	assert!(mcc::calibrate_colors(&image)?.is_none());
+
+	let gray = Mat::new_rows_cols_with_default(240, 320, CV_8UC1, Scalar::all(128.))?;
+	assert!(mcc::detect_color_checkers(&gray, MCC_TYPECHART::MCC24, 1).is_err());
+	Ok(())
+}
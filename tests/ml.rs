@@ -2,7 +2,7 @@
 
 use opencv::{
 	core::{Scalar, Size},
-	ml,
+	ml::{self, SampleTypes, StatModelKind},
 	prelude::*,
 	Result,
 };
@@ -17,7 +17,7 @@ fn knn() -> Result<()> {
 	let mut resp = Mat::default();
 	let mut neigh = Mat::default();
 	let mut dist = Mat::default();
-	knn.find_nearest(&samp, 3, &mut resp, &mut neigh, &mut dist)?;
+	knn.find_nearest(&samp, 3, &mut resp, Some(&mut neigh), Some(&mut dist))?;
 	assert_eq!(2., *resp.at_2d::<f32>(0, 0)?);
 	assert_eq!(Size::new(1, 1), resp.size()?);
 	let width = if cfg!(ocvrs_opencv_branch_32) {
@@ -31,3 +31,34 @@ fn knn() -> Result<()> {
 	assert_eq!(Size::new(width, 1), dist.size()?);
 	Ok(())
 }
+
+#[test]
+fn svm_train_save_load() -> Result<()> {
+	// 2 well separated clusters, one sample per row
+	let samples = Mat::from_slice_2d(&[[0f32, 0.], [1., 0.], [0., 1.], [10., 10.], [11., 10.], [10., 11.]])?;
+	let responses = Mat::from_slice_2d(&[[0i32], [0], [0], [1], [1], [1]])?;
+	let mut svm = <dyn SVM>::create()?;
+	svm.set_kernel(ml::SVM_KernelTypes::LINEAR as i32)?;
+	svm.train_samples(&samples, SampleTypes::ROW_SAMPLE, &responses)?;
+	let queries = Mat::from_slice_2d(&[[0.5f32, 0.5], [10.5, 10.5]])?;
+	let predicted = svm.predict_all(&queries)?;
+	assert_eq!(0., *predicted.at_2d::<f32>(0, 0)?);
+	assert_eq!(1., *predicted.at_2d::<f32>(1, 0)?);
+
+	// the column layout is the transposed one
+	let mut transposed = Mat::default();
+	opencv::core::transpose(&samples, &mut transposed)?;
+	let train_data = ml::create_train_data(&transposed, SampleTypes::COL_SAMPLE, &responses)?;
+	assert_eq!(6, train_data.get_n_samples()?);
+	assert_eq!(2, train_data.get_n_vars()?);
+
+	let path = std::env::temp_dir().join(format!("ocvrs_svm_{}.yml", std::process::id()));
+	svm.save(&path.to_string_lossy())?;
+	let loaded = ml::load_stat_model(StatModelKind::SVM, &path);
+	std::fs::remove_file(&path).expect("Can't remove the model file");
+	let loaded_predicted = loaded?.predict_all(&queries)?;
+	assert_eq!(predicted.data_typed::<f32>()?, loaded_predicted.data_typed::<f32>()?);
+
+	assert!(ml::load_stat_model(StatModelKind::SVM, "missing_model.yml").is_err());
+	Ok(())
+}
@@ -16,7 +16,7 @@ fn qr_code() -> Result<()> {
 		let src = imgcodecs::imread(qr_path.to_str().unwrap(), imgcodecs::IMREAD_COLOR)?;
 		let mut pts = VectorOfPoint::new();
 		let mut straight = Mat::default();
-		let res = detector.detect_and_decode(&src, &mut pts, &mut straight)?;
+		let res = detector.detect_and_decode(&src, Some(&mut pts), Some(&mut straight))?;
 		assert_eq!(4, pts.len());
 		if objdetect_missing_quirc {
 			assert_eq!(res, b"");
@@ -35,7 +35,7 @@ fn qr_code() -> Result<()> {
 		assert!(res);
 		assert_eq!(4, pts.len());
 		let mut straight = Mat::default();
-		let res = detector.decode(&src, &pts, &mut straight)?;
+		let res = detector.decode(&src, &pts, Some(&mut straight))?;
 		if objdetect_missing_quirc {
 			assert_eq!(res, b"");
 			assert!(straight.empty());
@@ -52,7 +52,7 @@ fn qr_code() -> Result<()> {
 		let src = imgcodecs::imread(binary_qr_path.to_str().unwrap(), imgcodecs::IMREAD_COLOR)?;
 		let mut pts = VectorOfPoint::new();
 		let mut straight = Mat::default();
-		let res = detector.detect_and_decode(&src, &mut pts, &mut straight)?;
+		let res = detector.detect_and_decode(&src, Some(&mut pts), Some(&mut straight))?;
 		assert_eq!(4, pts.len());
 		if objdetect_missing_quirc {
 			assert_eq!(res, b"");
@@ -86,3 +86,38 @@ fn output_byte_string() -> Result<()> {
 
 	Ok(())
 }
+
+/// Passing string binary data containing 0 bytes as &[u8] argument with the byte-taking encoder methods
+#[test]
+#[cfg(all(ocvrs_opencv_branch_4, ocvrs_has_module_imgproc))]
+fn input_byte_string() -> Result<()> {
+	use opencv::{
+		core::{Scalar, Size},
+		imgproc,
+		objdetect::{QRCodeEncoder, QRCodeEncoder_EncodeMode, QRCodeEncoder_Params},
+	};
+
+	let objdetect_missing_quirc = cfg!(target_os = "linux") && core::get_build_information()?.contains("/opencv-4.2.0+dfsg/");
+
+	let data = [0, 1, 2, 0, 3, 4, 5];
+	let mut params = QRCodeEncoder_Params::default()?;
+	params.mode = QRCodeEncoder_EncodeMode::MODE_BYTE;
+	let mut encoder = <dyn QRCodeEncoder>::create(params)?;
+	let mut qr = Mat::default();
+	encoder.encode_bytes(&data, &mut qr)?;
+	assert!(!qr.empty());
+	let mut text_qr = Mat::default();
+	encoder.encode("text", &mut text_qr)?;
+	assert!(!text_qr.empty());
+
+	let mut scaled = Mat::default();
+	imgproc::resize(&qr, &mut scaled, Size::default(), 8., 8., imgproc::INTER_NEAREST)?;
+	let mut src = Mat::default();
+	core::copy_make_border(&scaled, &mut src, 32, 32, 32, 32, core::BORDER_CONSTANT, Scalar::all(255.))?;
+	let mut detector = objdetect::QRCodeDetector::default()?;
+	let res = detector.detect_and_decode(&src, None, None)?;
+	if !objdetect_missing_quirc {
+		assert_eq!(res, data);
+	}
+	Ok(())
+}
@@ -0,0 +1,99 @@
+#![cfg(ocvrs_has_module_optflow)]
+
+use opencv::{
+	core::{self, Mat, Point2f, Rect, Scalar, Size, Vec2f, Vec3b, Vector, CV_32FC2, CV_8UC3},
+	imgproc,
+	optflow::{self, DenseRLOFParams, PCAFlowParams, SparseRLOFParams, SparseToDenseParams},
+	prelude::*,
+	video::DenseOpticalFlow,
+	Result,
+};
+
+/// 2 color frames of a random texture, the second one moved by 3 px to the right
+fn frames() -> Result<(Mat, Mat)> {
+	let mut noise = Mat::new_rows_cols_with_default(140, 180, CV_8UC3, Scalar::all(0.))?;
+	core::set_rng_seed(11)?;
+	core::randu(&mut noise, &Scalar::all(0.), &Scalar::all(255.))?;
+	let mut texture = Mat::default();
+	imgproc::gaussian_blur(&noise, &mut texture, Size::new(7, 7), 0., 0., core::BORDER_DEFAULT)?;
+	let frame0 = Mat::roi(&texture, Rect::new(10, 10, 160, 120))?.try_clone()?;
+	let frame1 = Mat::roi(&texture, Rect::new(7, 10, 160, 120))?.try_clone()?;
+	Ok((frame0, frame1))
+}
+
+fn gray(frame: &Mat) -> Result<Mat> {
+	let mut out = Mat::default();
+	imgproc::cvt_color(frame, &mut out, imgproc::COLOR_BGR2GRAY, 0)?;
+	Ok(out)
+}
+
+/// Flow in the middle of the frame where the interpolation artifacts at the borders don't reach
+fn center_flow(flow: &Mat) -> Result<Vec2f> {
+	assert_eq!(CV_32FC2, flow.typ());
+	Ok(*flow.at_2d::<Vec2f>(flow.rows() / 2, flow.cols() / 2)?)
+}
+
+#[test]
+fn dense_flows() -> Result<()> {
+	let (frame0, frame1) = frames()?;
+	let (gray0, gray1) = (gray(&frame0)?, gray(&frame1)?);
+
+	let mut flow = Mat::default();
+	optflow::create_opt_flow_deep_flow()?.calc(&gray0, &gray1, &mut flow)?;
+	assert!((center_flow(&flow)?[0] - 3.).abs() < 1.);
+
+	let mut flow = Mat::default();
+	let mut pca_flow = PCAFlowParams::default().create()?;
+	DenseOpticalFlow::calc(&mut pca_flow, &gray0, &gray1, &mut flow)?;
+	assert!((center_flow(&flow)?[0] - 3.).abs() < 1.);
+	assert!(PCAFlowParams {
+		prior: Some("missing-prior.bin".to_string()),
+		..PCAFlowParams::default()
+	}
+	.create()
+	.is_err());
+
+	let mut flow = Mat::default();
+	let mut rlof = DenseRLOFParams::default().create()?;
+	assert_eq!(Size::new(6, 6), rlof.get_grid_step()?);
+	rlof.calc(&frame0, &frame1, &mut flow)?;
+	assert!((center_flow(&flow)?[0] - 3.).abs() < 1.);
+
+	let flow = SparseToDenseParams::default().calc(&gray0, &gray1)?;
+	assert!((center_flow(&flow)?[0] - 3.).abs() < 1.);
+	Ok(())
+}
+
+#[test]
+fn sparse_rlof() -> Result<()> {
+	let (frame0, frame1) = frames()?;
+	let prev_pts = Vector::<Point2f>::from_iter([Point2f::new(60., 50.), Point2f::new(100., 70.)]);
+	let mut next_pts = Vector::<Point2f>::new();
+	let mut status = Vector::<u8>::new();
+	let mut rlof = SparseRLOFParams::default().create()?;
+	rlof.calc(&frame0, &frame1, &prev_pts, &mut next_pts, &mut status, None)?;
+	assert_eq!(prev_pts.len(), next_pts.len());
+	for (prev, next) in prev_pts.iter().zip(next_pts) {
+		assert!((next.x - prev.x - 3.).abs() < 1.);
+	}
+	Ok(())
+}
+
+#[test]
+fn flow_to_bgr() -> Result<()> {
+	let mut flow = Mat::new_rows_cols_with_default(4, 4, CV_32FC2, Scalar::all(0.))?;
+	*flow.at_2d_mut::<Vec2f>(0, 0)? = Vec2f::from([2., 0.]);
+	*flow.at_2d_mut::<Vec2f>(0, 1)? = Vec2f::from([1., 0.]);
+	let bgr = optflow::flow_to_bgr(&flow, None)?;
+	assert_eq!(CV_8UC3, bgr.typ());
+	assert_eq!(flow.size()?, bgr.size()?);
+	// zero flow is black, the largest magnitude is the brightest, rightwards flow is red
+	assert_eq!(Vec3b::all(0), *bgr.at_2d::<Vec3b>(3, 3)?);
+	assert_eq!(Vec3b::from([0, 0, 255]), *bgr.at_2d::<Vec3b>(0, 0)?);
+	assert!(bgr.at_2d::<Vec3b>(0, 1)?[2] < 255);
+	let bgr = optflow::flow_to_bgr(&flow, Some(1.))?;
+	assert_eq!(255, bgr.at_2d::<Vec3b>(0, 1)?[2]);
+
+	assert!(optflow::flow_to_bgr(&Mat::new_rows_cols_with_default(4, 4, CV_8UC3, Scalar::all(0.))?, None).is_err());
+	Ok(())
+}
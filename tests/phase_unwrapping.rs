@@ -0,0 +1,66 @@
+#![cfg(ocvrs_has_module_phase_unwrapping)]
+
+use std::f32::consts::PI;
+
+use opencv::{
+	core::{Mat, Scalar, Size, CV_32FC1, CV_8UC1},
+	phase_unwrapping::{self, HistogramPhaseUnwrapping_Params},
+	prelude::*,
+	Result,
+};
+
+const SLOPE: f32 = 0.1;
+
+/// Phase growing linearly along the rows, wrapped into [-pi, pi]
+fn wrapped_ramp(size: Size) -> Result<Mat> {
+	let mut out = Mat::new_size_with_default(size, CV_32FC1, Scalar::all(0.))?;
+	for row in 0..size.height {
+		for col in 0..size.width {
+			let phase = col as f32 * SLOPE;
+			*out.at_2d_mut::<f32>(row, col)? = phase.sin().atan2(phase.cos());
+		}
+	}
+	Ok(out)
+}
+
+#[test]
+fn params() -> Result<()> {
+	let params = HistogramPhaseUnwrapping_Params::for_size(Size::new(64, 48))?;
+	assert_eq!(64, params.width);
+	assert_eq!(48, params.height);
+	assert!((params.hist_thresh - 3. * PI * PI).abs() < 1e-3);
+	params.validate()?;
+	assert!(params.create_unwrapping().is_ok());
+
+	let invalid = HistogramPhaseUnwrapping_Params { width: 0, ..params };
+	assert!(invalid.validate().is_err());
+	let invalid = HistogramPhaseUnwrapping_Params {
+		hist_thresh: 40. * PI * PI,
+		..params
+	};
+	assert!(invalid.create_unwrapping().is_err());
+	let invalid = HistogramPhaseUnwrapping_Params {
+		nbr_of_large_bins: 0,
+		..params
+	};
+	assert!(invalid.validate().is_err());
+	Ok(())
+}
+
+#[test]
+fn unwrap_ramp() -> Result<()> {
+	let size = Size::new(120, 40);
+	let wrapped = wrapped_ramp(size)?;
+	let unwrapped = phase_unwrapping::unwrap_phase(&wrapped, None)?;
+	assert_eq!(size, unwrapped.phase.size()?);
+	assert_eq!(size, unwrapped.inverse_reliability.size()?);
+	// the unwrapped phase is continuous, only the constant offset is unknown
+	let row = size.height / 2;
+	let span = unwrapped.phase.at_2d::<f32>(row, size.width - 1)? - unwrapped.phase.at_2d::<f32>(row, 0)?;
+	assert!((span - (size.width - 1) as f32 * SLOPE).abs() < 0.1, "{}", span);
+
+	let mask = Mat::new_size_with_default(size, CV_8UC1, Scalar::all(255.))?;
+	assert!(phase_unwrapping::unwrap_phase(&wrapped, Some(&mask)).is_ok());
+	assert!(phase_unwrapping::unwrap_phase(&mask, None).is_err());
+	Ok(())
+}
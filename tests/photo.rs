@@ -0,0 +1,77 @@
+#![cfg(ocvrs_has_module_photo)]
+
+use opencv::{
+	core::{self, Mat, Point, Scalar, Vector, CV_32FC3, CV_8UC1, CV_8UC3},
+	photo::{self, CloneMode, DebevecHdr, DenoisingParams, InpaintMethod, MertensFusion, TonemapOperator},
+	prelude::*,
+	Result,
+};
+
+fn gradient(brightness: f64) -> Result<Mat> {
+	let mut image = Mat::new_rows_cols_with_default(32, 32, CV_8UC3, Scalar::all(0.))?;
+	for row in 0..32 {
+		for col in 0..32 {
+			let value = ((row * 4 + col * 2) as f64 * brightness).min(255.) as u8;
+			*image.at_2d_mut::<core::Vec3b>(row, col)? = core::Vec3b::from([value, value / 2, 255 - value]);
+		}
+	}
+	Ok(image)
+}
+
+#[test]
+fn denoise_inpaint_clone() -> Result<()> {
+	let clean = gradient(1.)?;
+	let mut noise = Mat::new_rows_cols_with_default(32, 32, CV_8UC3, Scalar::all(0.))?;
+	core::randu(&mut noise, &Scalar::all(0.), &Scalar::all(20.))?;
+	let mut noisy = Mat::default();
+	core::add(&clean, &noise, &mut noisy, None::<&Mat>, -1)?;
+
+	let params = DenoisingParams::default().with_strength(10.);
+	assert_eq!(10., params.h_color);
+	let denoised = params.denoise(&noisy)?;
+	assert_eq!(CV_8UC3, denoised.typ());
+	assert_eq!(noisy.size()?, denoised.size()?);
+
+	let mut mask = Mat::new_rows_cols_with_default(32, 32, CV_8UC1, Scalar::all(0.))?;
+	*mask.at_2d_mut::<u8>(16, 16)? = 255;
+	for method in [InpaintMethod::NavierStokes, InpaintMethod::Telea] {
+		let restored = photo::inpaint_image(&clean, &mask, 3., method)?;
+		assert_eq!(clean.size()?, restored.size()?);
+	}
+
+	let cloned = photo::seamless_clone_into(
+		&gradient(0.5)?,
+		&clean,
+		&Mat::new_rows_cols_with_default(16, 16, CV_8UC1, Scalar::all(255.))?,
+		Point::new(16, 16),
+		CloneMode::Normal,
+	)?;
+	assert_eq!(clean.size()?, cloned.size()?);
+	Ok(())
+}
+
+#[test]
+fn hdr_pipeline() -> Result<()> {
+	let mut exposures = Vector::<Mat>::new();
+	for brightness in [0.25, 0.5, 1.] {
+		exposures.push(gradient(brightness)?);
+	}
+
+	let fused = MertensFusion::default().fuse(&exposures)?;
+	assert_eq!(CV_32FC3, fused.typ());
+	assert_eq!(CV_8UC3, photo::to_8bit(&fused)?.typ());
+
+	assert!(DebevecHdr::default().merge(&exposures, &[1.]).is_err());
+	let hdr = DebevecHdr::default().with_samples(50).merge(&exposures, &[0.25, 0.5, 1.])?;
+	assert_eq!(CV_32FC3, hdr.typ());
+	for operator in [
+		TonemapOperator::gamma(),
+		TonemapOperator::drago(),
+		TonemapOperator::mantiuk(),
+		TonemapOperator::reinhard(),
+	] {
+		let ldr = operator.with_gamma(2.2).apply(&hdr)?;
+		assert_eq!(hdr.size()?, ldr.size()?);
+	}
+	Ok(())
+}
@@ -0,0 +1,47 @@
+#![cfg(ocvrs_has_module_line_descriptor)]
+
+use opencv::{
+	core::{Mat, Scalar, Vector, CV_8UC1},
+	imgproc,
+	line_descriptor::{BinaryDescriptor, BinaryDescriptorMatcher},
+	prelude::v2::{geometry::Point, line_descriptor::*, traits::*},
+	Result,
+};
+
+#[test]
+fn module_prelude() -> Result<()> {
+	let mut image = Mat::new_rows_cols_with_default(200, 200, CV_8UC1, Scalar::all(0.))?;
+	imgproc::rectangle_points(
+		&mut image,
+		Point::new(50, 60),
+		Point::new(150, 140),
+		Scalar::all(255.),
+		imgproc::FILLED,
+		imgproc::LINE_8,
+		0,
+	)?;
+	// generated and manual traits of line_descriptor are both in scope
+	let mut bd = BinaryDescriptor::create_binary_descriptor()?;
+	let mut keylines = Vector::new();
+	bd.detect_1(&image, &mut keylines, None)?;
+	let mut descriptors = Mat::default();
+	bd.compute(&image, &mut keylines, &mut descriptors, false)?;
+	assert_eq!(keylines.len(), descriptors.rows() as usize);
+	let matcher = BinaryDescriptorMatcher::create_binary_descriptor_matcher()?;
+	let matches = matcher.cross_check_match(&descriptors, &descriptors)?;
+	assert_eq!(keylines.len(), matches.len());
+	Ok(())
+}
+
+#[test]
+fn pipelines_prelude() -> Result<()> {
+	use opencv::{core, features2d::BFMatcher, prelude::v2::pipelines::*};
+
+	let train = Mat::from_slice_2d(&[[0u8; 32], [0xff; 32]])?;
+	let query = Mat::from_slice_2d(&[[0xffu8; 32]])?;
+	let bf = BFMatcher::create(core::NORM_HAMMING, false)?;
+	let matches = bf.knn_match_ratio(&query, &train, 0.8)?;
+	assert_eq!(1, matches.len());
+	assert_eq!(1, matches.get(0)?.train_idx);
+	Ok(())
+}
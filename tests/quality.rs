@@ -0,0 +1,44 @@
+#![cfg(ocvrs_has_module_quality)]
+
+use opencv::{
+	core::{self, Mat, Scalar, CV_8UC3},
+	imgproc,
+	prelude::*,
+	quality::{self, QualityMetric},
+	Result,
+};
+
+fn reference() -> Result<Mat> {
+	let mut image = Mat::new_rows_cols_with_default(64, 64, CV_8UC3, Scalar::all(0.))?;
+	core::randu(&mut image, &Scalar::all(0.), &Scalar::all(255.))?;
+	Ok(image)
+}
+
+#[test]
+fn full_reference_metrics() -> Result<()> {
+	let reference = reference()?;
+	let mut blurred = Mat::default();
+	imgproc::gaussian_blur(&reference, &mut blurred, core::Size::new(5, 5), 1.5, 0., core::BORDER_DEFAULT)?;
+
+	let mut ssim = quality::create_quality_metric(QualityMetric::SSIM, &reference)?;
+	assert!((ssim.compute_mean(&reference)? - 1.).abs() < 1e-4);
+	assert!(ssim.compute_mean(&blurred)? < 0.9);
+
+	let mut gmsd = quality::create_quality_metric(QualityMetric::GMSD, &reference)?;
+	assert!(gmsd.compute_mean(&reference)?.abs() < 1e-4);
+	assert!(gmsd.compute_mean(&blurred)? > 0.);
+
+	let mut mse = quality::create_quality_metric(QualityMetric::MSE, &reference)?;
+	assert_eq!(0., mse.compute_mean(&reference)?);
+	assert!(mse.compute_mean(&blurred)? > 0.);
+
+	let mut psnr = quality::create_quality_metric(QualityMetric::PSNR { max_pixel_value: 255. }, &reference)?;
+	let score = psnr.compute(&blurred)?;
+	assert!(score[0] > 0. && score[0] < 40., "{:?}", score);
+	Ok(())
+}
+
+#[test]
+fn brisque_missing_model() {
+	assert!(quality::load_brisque("missing_model.yml", "missing_range.yml").is_err());
+}
@@ -0,0 +1,77 @@
+#![cfg(ocvrs_has_module_rapid)]
+
+use opencv::{
+	core::{Mat, Point3f, Vec3i, Vector},
+	prelude::*,
+	rapid::{self, Mesh, TrackerKind},
+	Result,
+};
+
+/// Unit cube centered at the origin
+fn cube() -> Result<Mesh> {
+	let vertices = (0..8)
+		.map(|i| {
+			Point3f::new(
+				if i & 1 == 0 {
+					-0.5
+				} else {
+					0.5
+				},
+				if i & 2 == 0 {
+					-0.5
+				} else {
+					0.5
+				},
+				if i & 4 == 0 {
+					-0.5
+				} else {
+					0.5
+				},
+			)
+		})
+		.collect();
+	let triangles = [
+		[0, 2, 1],
+		[1, 2, 3],
+		[4, 5, 6],
+		[5, 7, 6],
+		[0, 1, 4],
+		[1, 5, 4],
+		[2, 6, 3],
+		[3, 6, 7],
+		[0, 4, 2],
+		[2, 4, 6],
+		[1, 3, 5],
+		[3, 7, 5],
+	]
+	.iter()
+	.map(|&triangle| Vec3i::from(triangle))
+	.collect();
+	Mesh::new(vertices, triangles)
+}
+
+#[test]
+fn mesh() -> Result<()> {
+	let cube = cube()?;
+	assert_eq!(8, cube.vertices.len());
+	assert_eq!(12, cube.triangles.len());
+	assert!(Mesh::new(cube()?.vertices, Vector::from_iter([Vec3i::from([0, 1, 8])])).is_err());
+	assert!(Mesh::new(cube()?.vertices, Vector::from_iter([Vec3i::from([-1, 1, 2])])).is_err());
+
+	let vertices = Mat::from_slice_2d(&[[0f32, 0., 0.], [1., 0., 0.], [0., 1., 0.]])?;
+	let triangles = Mat::from_slice_2d(&[[0i32, 1, 2]])?;
+	let mesh = Mesh::from_mats(&vertices, &triangles)?;
+	assert_eq!(Point3f::new(1., 0., 0.), mesh.vertices.get(1)?);
+	assert_eq!(Vec3i::from([0, 1, 2]), mesh.triangles.get(0)?);
+	Ok(())
+}
+
+#[test]
+fn create_tracker() -> Result<()> {
+	let cube = cube()?;
+	for kind in [TrackerKind::Rapid, TrackerKind::OLS] {
+		let mut tracker = rapid::create_tracker(kind, &cube)?;
+		tracker.clear_state()?;
+	}
+	Ok(())
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "unsafe-raw")]
+
+use opencv::{
+	core::{self, Mat, CV_8UC3},
+	prelude::*,
+	raw, raw_call, Result,
+};
+
+#[test]
+fn raw_call() -> Result<()> {
+	let cpus = unsafe { raw_call!(raw::cv_getNumberOfCPUs()) }?;
+	assert_eq!(core::get_number_of_cpus()?, cpus);
+
+	let mat = unsafe { raw_call!(raw::cv_Mat_Mat_int_int_int(10, 20, CV_8UC3) => Mat) }?;
+	assert_eq!((10, 20, CV_8UC3), (mat.rows(), mat.cols(), mat.typ()));
+	assert_eq!(10, unsafe { raw::cv_Mat_getPropRows_const(mat.as_raw()) });
+
+	let res = unsafe { raw_call!(raw::cv_Mat_Mat_int_int_int(-1, 20, CV_8UC3) => Mat) };
+	assert!(res.is_err());
+	Ok(())
+}
+
+extern "C" {
+	fn cv_Mat_Mat_int_int_int(rows: i32, cols: i32, typ: i32, ocvrs_return: *mut raw::Result<*mut std::ffi::c_void>);
+}
+
+opencv::opencv_plugin_class! {
+	/// `cv::Mat` registered as if it came from a plugin
+	pub struct PluginMat: cv_Mat_delete + Send;
+}
+
+#[test]
+fn plugin_class() -> Result<()> {
+	let mat = unsafe { raw_call!(cv_Mat_Mat_int_int_int(3, 4, CV_8UC3) => PluginMat) }?;
+	assert!(!mat.as_raw().is_null());
+	let rows = unsafe { raw::cv_Mat_getPropRows_const(mat.as_raw()) };
+	assert_eq!(3, rows);
+	let mat = std::thread::spawn(move || mat).join().unwrap();
+	let mat = unsafe { Mat::from_raw(mat.into_raw()) };
+	assert_eq!((3, 4), (mat.rows(), mat.cols()));
+	Ok(())
+}
@@ -0,0 +1,83 @@
+#![cfg(ocvrs_has_module_rgbd)]
+
+use opencv::{
+	core::{Mat, Scalar, Vec3f, CV_32F, CV_32FC1, CV_64F},
+	prelude::*,
+	rgbd::{self, OdometryKind, RgbdNormals, RgbdNormals_RGBD_NORMALS_METHOD, RgbdPlane, RgbdPlane_RGBD_PLANE_METHOD},
+	Result,
+};
+
+const ROWS: i32 = 48;
+const COLS: i32 = 64;
+
+fn camera_matrix() -> Result<Mat> {
+	Mat::from_slice_2d(&[[50f32, 0., 32.], [0., 50., 24.], [0., 0., 1.]])
+}
+
+/// Fronto-parallel wall 1.5m away from the camera
+fn wall_points() -> Result<Mat> {
+	let depth = Mat::new_rows_cols_with_default(ROWS, COLS, CV_32FC1, Scalar::all(1.5))?;
+	let mut points = Mat::default();
+	rgbd::depth_to3d(&depth, &camera_matrix()?, &mut points, None::<&Mat>)?;
+	Ok(points)
+}
+
+#[test]
+fn normals_and_planes() -> Result<()> {
+	let points = wall_points()?;
+	let normals_alg = RgbdNormals::create(
+		ROWS,
+		COLS,
+		CV_32F,
+		&camera_matrix()?,
+		5,
+		RgbdNormals_RGBD_NORMALS_METHOD::RGBD_NORMALS_METHOD_FALS as i32,
+	)?;
+	let normals = normals_alg.compute_normals(&points)?;
+	assert_eq!(points.size()?, normals.size()?);
+	let center = *normals.at_2d::<Vec3f>(ROWS / 2, COLS / 2)?;
+	assert!(center[2].abs() > 0.99, "{:?}", center);
+
+	let mut plane_alg = RgbdPlane::create(
+		RgbdPlane_RGBD_PLANE_METHOD::RGBD_PLANE_METHOD_DEFAULT as i32,
+		8,
+		100,
+		0.01,
+		0.,
+		0.,
+		0.,
+	)?;
+	let segmentation = plane_alg.segment_planes(&points, Some(&normals))?;
+	assert_eq!(1, segmentation.coefficients.len());
+	assert_eq!(0, *segmentation.mask.at_2d::<u8>(ROWS / 2, COLS / 2)?);
+	let plane = segmentation.coefficients.get(0)?;
+	// distance of the camera from the plane
+	assert!((plane[3].abs() - 1.5).abs() < 0.01, "{:?}", plane);
+
+	let without_normals = plane_alg.segment_planes(&points, None)?;
+	assert_eq!(1, without_normals.coefficients.len());
+	Ok(())
+}
+
+#[test]
+fn odometry_identity() -> Result<()> {
+	// slanted surface so that the alignment is not degenerate
+	let mut depth = Mat::new_rows_cols_with_default(ROWS, COLS, CV_32FC1, Scalar::all(0.))?;
+	for row in 0..ROWS {
+		for col in 0..COLS {
+			*depth.at_2d_mut::<f32>(row, col)? = 1. + 0.01 * col as f32 + 0.005 * row as f32;
+		}
+	}
+	let no_image = Mat::default();
+	for kind in [OdometryKind::ICP, OdometryKind::FastICP] {
+		let odometry = rgbd::create_odometry(kind, &camera_matrix()?)?;
+		if let Some(rt) = odometry.compute_transform(&no_image, &depth, &no_image, &depth)? {
+			assert_eq!(4, rt.rows(), "{:?}", kind);
+			assert_eq!(4, rt.cols(), "{:?}", kind);
+			assert_eq!(CV_64F, rt.depth(), "{:?}", kind);
+			assert!((*rt.at_2d::<f64>(0, 3)?).abs() < 1e-3, "{:?}", kind);
+		}
+	}
+	assert!(rgbd::create_odometry(OdometryKind::Rgbd, &camera_matrix()?).is_ok());
+	Ok(())
+}
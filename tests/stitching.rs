@@ -0,0 +1,83 @@
+#![cfg(ocvrs_has_module_stitching)]
+
+use opencv::{
+	core::{self, Mat, Rect, Scalar, Size, Vector, CV_8UC3},
+	imgproc,
+	prelude::*,
+	stitching::{
+		self, BlenderKind, ExposureCompensatorKind, SeamFinderKind, Stitcher, Stitcher_Mode, Stitcher_Status, WarperKind,
+	},
+	Result,
+};
+
+/// 2 overlapping views of the same random texture
+fn views() -> Result<Vector<Mat>> {
+	let mut noise = Mat::new_rows_cols_with_default(300, 400, CV_8UC3, Scalar::all(0.))?;
+	core::set_rng_seed(42)?;
+	core::randu(&mut noise, &Scalar::all(0.), &Scalar::all(255.))?;
+	let mut texture = Mat::default();
+	imgproc::gaussian_blur(&noise, &mut texture, Size::new(5, 5), 0., 0., core::BORDER_DEFAULT)?;
+	let left = Mat::roi(&texture, Rect::new(0, 0, 260, 300))?.try_clone()?;
+	let right = Mat::roi(&texture, Rect::new(140, 0, 260, 300))?.try_clone()?;
+	Ok(Vector::from_iter([left, right]))
+}
+
+#[test]
+fn stitch_panorama() -> Result<()> {
+	let images = views()?;
+	let mut stitcher = Stitcher::create(Stitcher_Mode::SCANS)?;
+	stitcher.set_warper_kind(WarperKind::Affine)?;
+	stitcher.set_blender_kind(BlenderKind::Feather { sharpness: 0.02 })?;
+	stitcher.set_seam_finder_kind(SeamFinderKind::Voronoi)?;
+	stitcher.set_exposure_compensator_kind(ExposureCompensatorKind::No)?;
+	let pano = stitcher.stitch_panorama(&images)?;
+	assert_eq!(CV_8UC3, pano.typ());
+	assert!(pano.cols() > 260);
+
+	let pano = stitching::stitch_images(&images, Stitcher_Mode::SCANS)?;
+	assert!(pano.cols() > 260);
+	Ok(())
+}
+
+#[test]
+fn stitch_status() -> Result<()> {
+	assert!(Stitcher_Status::OK.into_result().is_ok());
+	assert!(Stitcher_Status::ERR_HOMOGRAPHY_EST_FAIL.into_result().is_err());
+
+	let single = Vector::<Mat>::from_iter([views()?.get(0)?]);
+	let mut stitcher = Stitcher::create(Stitcher_Mode::PANORAMA)?;
+	assert!(stitcher.stitch_panorama(&single).is_err());
+	Ok(())
+}
+
+#[test]
+fn component_kinds() -> Result<()> {
+	let mut stitcher = Stitcher::create(Stitcher_Mode::PANORAMA)?;
+	for kind in [
+		WarperKind::Plane,
+		WarperKind::Cylindrical,
+		WarperKind::Spherical,
+		WarperKind::Fisheye,
+		WarperKind::Stereographic,
+		WarperKind::CompressedRectilinear { a: 2., b: 1. },
+		WarperKind::Panini { a: 2., b: 1. },
+		WarperKind::Mercator,
+		WarperKind::TransverseMercator,
+	] {
+		stitcher.set_warper_kind(kind)?;
+	}
+	stitcher.set_blender_kind(BlenderKind::No)?;
+	stitcher.set_blender_kind(BlenderKind::MultiBand { num_bands: 5 })?;
+	assert!(stitching::create_blender(BlenderKind::MultiBand { num_bands: 0 }).is_err());
+	for kind in [
+		SeamFinderKind::No,
+		SeamFinderKind::DpColor,
+		SeamFinderKind::DpColorGrad,
+		SeamFinderKind::GraphCutColor,
+		SeamFinderKind::GraphCutColorGrad,
+	] {
+		stitcher.set_seam_finder_kind(kind)?;
+	}
+	stitcher.set_exposure_compensator_kind(ExposureCompensatorKind::ChannelsBlocks)?;
+	Ok(())
+}
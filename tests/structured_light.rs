@@ -0,0 +1,53 @@
+#![cfg(ocvrs_has_module_structured_light)]
+
+use opencv::{
+	core::{Point, Size, Vector, CV_8UC1},
+	prelude::*,
+	structured_light::{self, GrayCodePattern, SinusoidalMethod},
+	Result,
+};
+
+#[test]
+fn gray_code() -> Result<()> {
+	let size = Size::new(64, 32);
+	let mut pattern = <dyn GrayCodePattern>::create_1(size.width, size.height)?;
+	let images = pattern.generate_patterns()?;
+	assert_eq!(pattern.get_number_of_pattern_images()?, images.len());
+	for image in &images {
+		assert_eq!(size, image.size()?);
+		assert_eq!(CV_8UC1, image.typ());
+	}
+
+	let (black, white) = pattern.shadow_mask_images()?;
+	assert_eq!(size, black.size()?);
+	assert_eq!(size, white.size()?);
+
+	// camera seeing the projector image as is decodes every pixel to itself
+	pattern.set_white_threshold(1)?;
+	pattern.set_black_threshold(1)?;
+	assert_eq!(Some(Point::new(10, 20)), pattern.proj_pixel(&images, 10, 20)?);
+	assert_eq!(Some(Point::new(63, 0)), pattern.proj_pixel(&images, 63, 0)?);
+
+	// the same view from both cameras of the stereo pair
+	let captured = Vector::<Vector<_>>::from_iter([images, pattern.generate_patterns()?]);
+	let (black2, white2) = pattern.shadow_mask_images()?;
+	let disparity = pattern.decode_disparity(
+		&captured,
+		Some(&Vector::from_iter([black, black2])),
+		Some(&Vector::from_iter([white, white2])),
+	)?;
+	assert_eq!(size, disparity.size()?);
+	Ok(())
+}
+
+#[test]
+fn sinusoidal() -> Result<()> {
+	let size = Size::new(120, 80);
+	let mut pattern = structured_light::create_sinusoidal_pattern(size, 10, SinusoidalMethod::PSP, false)?;
+	let images = pattern.generate_patterns()?;
+	assert!(!images.is_empty());
+	for image in &images {
+		assert_eq!(size, image.size()?);
+	}
+	Ok(())
+}
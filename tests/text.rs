@@ -0,0 +1,54 @@
+#![cfg(ocvrs_has_module_text)]
+
+use opencv::{
+	core::{Mat, Point, Scalar, CV_8UC3},
+	imgproc,
+	prelude::*,
+	text::{self, OCRLevel, OCRTesseract, TextDetectionParams},
+	Result,
+};
+
+fn text_image() -> Result<Mat> {
+	let mut image = Mat::new_rows_cols_with_default(80, 320, CV_8UC3, Scalar::all(255.))?;
+	imgproc::put_text(
+		&mut image,
+		"HELLO WORLD",
+		Point::new(10, 55),
+		imgproc::FONT_HERSHEY_SIMPLEX,
+		1.5,
+		Scalar::all(0.),
+		3,
+		imgproc::LINE_AA,
+		false,
+	)?;
+	Ok(image)
+}
+
+#[test]
+fn detect_text_regions_errors() -> Result<()> {
+	let image = text_image()?;
+	let params = TextDetectionParams {
+		min_area: 0.5,
+		max_area: 0.1,
+		..TextDetectionParams::default()
+	};
+	assert!(text::detect_text_regions(&image, "missing-nm1.xml", "missing-nm2.xml", &params).is_err());
+	assert!(text::detect_text_regions(&image, "missing-nm1.xml", "missing-nm2.xml", &TextDetectionParams::default()).is_err());
+	Ok(())
+}
+
+#[test]
+fn tesseract_recognize() -> Result<()> {
+	// OpenCV can be built without Tesseract, there is nothing to check in this case
+	let mut ocr = match <dyn OCRTesseract>::create("", "eng", "", text::OEM_DEFAULT, text::PSM_SINGLE_LINE) {
+		Ok(ocr) => ocr,
+		Err(_) => return Ok(()),
+	};
+	let mut image = text_image()?;
+	let result = ocr.recognize(&mut image, OCRLevel::Word)?;
+	assert!(result.text.contains("HELLO"), "{:?}", result);
+	assert_eq!(2, result.components.len());
+	assert_eq!("HELLO", result.components[0].text);
+	assert!(result.components[0].rect.x < result.components[1].rect.x);
+	Ok(())
+}
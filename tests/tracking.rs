@@ -0,0 +1,90 @@
+#![cfg(ocvrs_has_module_tracking)]
+
+use opencv::{
+	core::{Mat, Point, Rect, Scalar, CV_8UC3},
+	imgproc,
+	prelude::*,
+	tracking::{self, MultiTracker, TrackerKind},
+	Result,
+};
+
+/// Frame with the textured square objects at the passed positions
+fn frame(objects: &[Point]) -> Result<Mat> {
+	let mut frame = Mat::new_rows_cols_with_default(240, 320, CV_8UC3, Scalar::all(40.))?;
+	for x in (0..320).step_by(40) {
+		imgproc::line(
+			&mut frame,
+			Point::new(x, 0),
+			Point::new(x, 239),
+			Scalar::all(70.),
+			1,
+			imgproc::LINE_8,
+			0,
+		)?;
+	}
+	for &tl in objects {
+		for (i, j) in (0..4).flat_map(|i| (0..4).map(move |j| (i, j))) {
+			let color = if (i + j) % 2 == 0 {
+				Scalar::new(255., 200., 0., 0.)
+			} else {
+				Scalar::new(0., 50., 255., 0.)
+			};
+			imgproc::rectangle(
+				&mut frame,
+				Rect::new(tl.x + i * 10, tl.y + j * 10, 10, 10),
+				color,
+				imgproc::FILLED,
+				imgproc::LINE_8,
+				0,
+			)?;
+		}
+	}
+	Ok(frame)
+}
+
+fn near(expected: Point, found: Option<Rect>) -> bool {
+	found.map_or(false, |rect| {
+		let center = Point::new(rect.x + rect.width / 2, rect.y + rect.height / 2);
+		(center.x - expected.x - 20).abs() <= 6 && (center.y - expected.y - 20).abs() <= 6
+	})
+}
+
+#[test]
+fn single_object() -> Result<()> {
+	for kind in [TrackerKind::CSRT, TrackerKind::KCF, TrackerKind::MIL] {
+		let mut tracker = tracking::create_tracker(kind)?;
+		let start = Point::new(60, 50);
+		tracker.init_tracking(&frame(&[start])?, Rect::new(start.x, start.y, 40, 40))?;
+		for i in 1..=10 {
+			let pos = start + Point::new(3 * i, 2 * i);
+			let found = tracker.track(&frame(&[pos])?)?;
+			assert!(near(pos, found), "{:?} lost the object in frame {}: {:?}", kind, i, found);
+		}
+	}
+	Ok(())
+}
+
+#[test]
+fn multi_tracker() -> Result<()> {
+	let starts = [Point::new(40, 40), Point::new(220, 150)];
+	let first = frame(&starts)?;
+	let mut tracker = MultiTracker::new();
+	assert!(tracker.is_empty());
+	for &start in &starts {
+		tracker.add(
+			tracking::create_tracker(TrackerKind::KCF)?,
+			&first,
+			Rect::new(start.x, start.y, 40, 40),
+		)?;
+	}
+	assert_eq!(2, tracker.len());
+	for i in 1..=5 {
+		let positions = [starts[0] + Point::new(3 * i, 2 * i), starts[1] - Point::new(2 * i, 3 * i)];
+		let objects = tracker.update(&frame(&positions)?)?;
+		assert_eq!(2, objects.len());
+		assert!(near(positions[0], objects[0]));
+		assert!(near(positions[1], objects[1]));
+	}
+	assert_eq!(2, tracker.objects().len());
+	Ok(())
+}
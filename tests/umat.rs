@@ -3,7 +3,7 @@ use opencv::core::AccessFlag::ACCESS_READ;
 #[cfg(not(ocvrs_opencv_branch_4))]
 use opencv::core::ACCESS_READ;
 use opencv::{
-	core::{Rect, Size, UMat, UMatUsageFlags, Vec3d},
+	core::{self, Rect, Size, UMat, UMatUsageFlags, Vec3d},
 	prelude::*,
 	types::VectorOfi32,
 	Result,
@@ -122,3 +122,34 @@ fn umat_continuous() -> Result<()> {
 
 	Ok(())
 }
+
+#[test]
+fn umat_mat_conversions() -> Result<()> {
+	let mut mat = Mat::from_slice_2d(&[[1u8, 2, 3], [4, 5, 6]])?;
+
+	let umat = UMat::from_mat(&mat)?;
+	assert_eq!(Size::new(3, 2), umat.size()?);
+	let copy = umat.to_mat()?;
+	assert_eq!(6, *copy.at_2d::<u8>(1, 2)?);
+
+	{
+		let mapped = UMat::map_mat(&mat)?;
+		let mut sum = UMat::new(UMatUsageFlags::USAGE_DEFAULT);
+		core::add(&mapped, &umat, &mut sum, None::<&UMat>, -1)?;
+		assert_eq!(12, *sum.map()?.at_2d::<u8>(1, 2)?);
+	}
+
+	{
+		let mut mapped = UMat::map_mat_mut(&mut mat)?;
+		core::add(&umat, &umat, &mut mapped, None::<&UMat>, -1)?;
+	}
+	assert_eq!(12, *mat.at_2d::<u8>(1, 2)?);
+	// the copies are independent of the source
+	assert_eq!(6, *copy.at_2d::<u8>(1, 2)?);
+	assert_eq!(6, *umat.map()?.at_2d::<u8>(1, 2)?);
+
+	let mut umat = umat;
+	*umat.map_mut()?.at_2d_mut::<u8>(0, 0)? = 42;
+	assert_eq!(42, *umat.to_mat()?.at_2d::<u8>(0, 0)?);
+	Ok(())
+}
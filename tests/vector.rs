@@ -5,9 +5,9 @@ use opencv::{
 	prelude::*,
 	types::{
 		VectorOfDMatch, VectorOfMat, VectorOfPoint2d, VectorOfPoint2f, VectorOfRange, VectorOfString, VectorOfVec4i,
-		VectorOfVectorOfPoint2f, VectorOfbool, VectorOff64, VectorOfi32, VectorOfi8, VectorOfu8,
+		VectorOfVectorOfPoint2f, VectorOfbool, VectorOff64, VectorOfi32, VectorOfi8, VectorOfu8, VectorOfusize,
 	},
-	Error, Result,
+	Error, ErrorKind, Result,
 };
 
 #[test]
@@ -279,7 +279,7 @@ fn insert() -> Result<()> {
 	assert_matches!(
 		vec.insert(10, 10),
 		Err(Error {
-			code: core::StsOutOfRange,
+			kind: ErrorKind::Code(core::Code::StsOutOfRange),
 			..
 		})
 	);
@@ -310,7 +310,7 @@ fn remove() -> Result<()> {
 	assert_matches!(
 		vec.remove(0),
 		Err(Error {
-			code: core::StsOutOfRange,
+			kind: ErrorKind::Code(core::Code::StsOutOfRange),
 			..
 		})
 	);
@@ -333,14 +333,14 @@ fn swap() -> Result<()> {
 		assert_matches!(
 			vec.swap(0, 4),
 			Err(Error {
-				code: core::StsOutOfRange,
+				kind: ErrorKind::Code(core::Code::StsOutOfRange),
 				..
 			})
 		);
 		assert_matches!(
 			vec.swap(6, 1),
 			Err(Error {
-				code: core::StsOutOfRange,
+				kind: ErrorKind::Code(core::Code::StsOutOfRange),
 				..
 			})
 		);
@@ -402,7 +402,7 @@ fn out_of_bounds() -> Result<()> {
 	assert_matches!(
 		vec.get(0),
 		Err(Error {
-			code: core::StsOutOfRange,
+			kind: ErrorKind::Code(core::Code::StsOutOfRange),
 			..
 		})
 	);
@@ -411,14 +411,14 @@ fn out_of_bounds() -> Result<()> {
 	assert_matches!(
 		vec.get(3),
 		Err(Error {
-			code: core::StsOutOfRange,
+			kind: ErrorKind::Code(core::Code::StsOutOfRange),
 			..
 		})
 	);
 	assert_matches!(
 		vec.set(3, 5.),
 		Err(Error {
-			code: core::StsOutOfRange,
+			kind: ErrorKind::Code(core::Code::StsOutOfRange),
 			..
 		})
 	);
@@ -778,3 +778,19 @@ fn sync() {
 	must_be_sync(VectorOfString::new());
 	must_be_sync(VectorOfPoint2d::new());
 }
+
+/// size_t elements are marshalled as usize without the truncation
+#[test]
+fn usize_elements() -> Result<()> {
+	let mut vec = VectorOfusize::new();
+	vec.push(0);
+	vec.push(usize::MAX);
+	assert_eq!(2, vec.len());
+	assert_eq!(usize::MAX, vec.get(1)?);
+	assert_eq!(&[0, usize::MAX], vec.as_slice());
+	let m = Mat::new_rows_cols_with_default(3, 4, f64::typ(), Scalar::all(0.))?;
+	let total: usize = m.total();
+	assert_eq!(12, total);
+	assert_eq!(std::mem::size_of::<f64>(), m.elem_size()?);
+	Ok(())
+}
@@ -2,7 +2,7 @@
 
 use matches::assert_matches;
 
-use opencv::{core, videoio::VideoWriter, Error, Result};
+use opencv::{core, videoio::VideoWriter, Error, ErrorKind, Result};
 
 #[test]
 fn fourcc() -> Result<()> {
@@ -12,7 +12,7 @@ fn fourcc() -> Result<()> {
 	assert_matches!(
 		fourcc_error,
 		Err(Error {
-			code: core::StsBadArg,
+			kind: ErrorKind::Code(core::Code::StsBadArg),
 			..
 		})
 	);
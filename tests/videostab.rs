@@ -0,0 +1,85 @@
+#![cfg(ocvrs_has_module_videostab)]
+
+use opencv::{
+	core::{self, Mat, Ptr, Rect, Scalar, Size, CV_8UC3},
+	imgproc,
+	prelude::*,
+	videostab::{
+		FrameSourceImpl, IFrameSource, KeypointBasedMotionEstimator, MotionEstimatorRansacL2, MotionModel, OnePassStabilizer,
+		TwoPassStabilizer,
+	},
+	Result,
+};
+
+const FRAME_COUNT: usize = 12;
+
+/// Frames of a random texture viewed through the shaking window
+fn shaky_frames() -> Result<Vec<Mat>> {
+	let mut noise = Mat::new_rows_cols_with_default(200, 240, CV_8UC3, Scalar::all(0.))?;
+	core::set_rng_seed(7)?;
+	core::randu(&mut noise, &Scalar::all(0.), &Scalar::all(255.))?;
+	let mut texture = Mat::default();
+	imgproc::gaussian_blur(&noise, &mut texture, Size::new(5, 5), 0., 0., core::BORDER_DEFAULT)?;
+	(0..FRAME_COUNT as i32)
+		.map(|i| {
+			let shift = i % 2 * 6;
+			Mat::roi(&texture, Rect::new(20 + shift, 20 + shift / 2, 160, 120))?.try_clone()
+		})
+		.collect()
+}
+
+/// Frame source that can be replayed, required by `TwoPassStabilizer`
+struct Replay {
+	frames: Vec<Mat>,
+	pos: usize,
+}
+
+impl FrameSourceImpl for Replay {
+	fn next_frame(&mut self) -> Option<Mat> {
+		let frame = self.frames.get(self.pos)?.try_clone().ok()?;
+		self.pos += 1;
+		Some(frame)
+	}
+
+	fn reset(&mut self) {
+		self.pos = 0;
+	}
+}
+
+#[test]
+fn closure_frame_source() -> Result<()> {
+	let mut frames = shaky_frames()?.into_iter();
+	let mut source = Ptr::<dyn IFrameSource>::from_impl(move || frames.next())?;
+	let frames = source.frames().collect::<Result<Vec<_>>>()?;
+	assert_eq!(FRAME_COUNT, frames.len());
+	assert_eq!(Size::new(160, 120), frames[0].size()?);
+	assert!(source.next_frame()?.empty());
+	Ok(())
+}
+
+#[test]
+fn one_pass_stabilizer() -> Result<()> {
+	let mut frames = shaky_frames()?.into_iter();
+	let mut stabilizer = OnePassStabilizer::default()?;
+	stabilizer.set_frame_source(Ptr::<dyn IFrameSource>::from_impl(move || frames.next())?)?;
+	let estimator = Ptr::new(MotionEstimatorRansacL2::new(MotionModel::MM_TRANSLATION)?);
+	stabilizer.set_motion_estimator(Ptr::new(KeypointBasedMotionEstimator::new(estimator.into())?).into())?;
+	stabilizer.set_radius(3)?;
+	let stabilized = stabilizer.frames().collect::<Result<Vec<_>>>()?;
+	assert_eq!(FRAME_COUNT, stabilized.len());
+	assert!(stabilized.iter().all(|frame| frame.typ() == CV_8UC3));
+	Ok(())
+}
+
+#[test]
+fn two_pass_stabilizer() -> Result<()> {
+	let mut stabilizer = TwoPassStabilizer::default()?;
+	stabilizer.set_frame_source(Ptr::<dyn IFrameSource>::from_impl(Replay {
+		frames: shaky_frames()?,
+		pos: 0,
+	})?)?;
+	stabilizer.set_radius(3)?;
+	let stabilized = stabilizer.frames().collect::<Result<Vec<_>>>()?;
+	assert_eq!(FRAME_COUNT, stabilized.len());
+	Ok(())
+}
@@ -0,0 +1,30 @@
+#![cfg(ocvrs_has_module_viz)]
+
+use opencv::{
+	core::{Mat, Matx33d, Point3d, Scalar, CV_32FC3},
+	prelude::*,
+	viz::{Color, WCameraPosition, WCloud, WLine, Widget},
+	Result,
+};
+
+// showing the widgets requires a display, only their creation is checked
+
+#[test]
+fn widgets() -> Result<()> {
+	let cloud = Mat::new_rows_cols_with_default(4, 4, CV_32FC3, Scalar::new(0., 0., 1., 0.))?;
+	let widgets: Vec<Widget> = vec![
+		WCloud::new_1(&cloud, &Color::white()?)?.into(),
+		WLine::new(Point3d::new(0., 0., 0.), Point3d::new(1., 1., 1.), &Color::red()?)?.into(),
+		WCameraPosition::new_1(
+			Matx33d::from([500., 0., 320., 0., 500., 240., 0., 0., 1.]),
+			0.5,
+			&Color::green()?,
+		)?
+		.into(),
+	];
+	for mut widget in widgets {
+		widget.set_rendering_property(opencv::viz::OPACITY, 0.5)?;
+		assert_eq!(0.5, widget.get_rendering_property(opencv::viz::OPACITY)?);
+	}
+	Ok(())
+}
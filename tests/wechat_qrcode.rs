@@ -0,0 +1,33 @@
+#![cfg(ocvrs_has_module_wechat_qrcode)]
+
+use std::path::Path;
+
+use opencv::{
+	core::{Mat, Scalar, CV_8UC1},
+	imgcodecs,
+	prelude::*,
+	wechat_qrcode::WeChatQRCode,
+	Result,
+};
+
+#[test]
+fn detect_without_models() -> Result<()> {
+	let qr_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/qr.png");
+	let src = imgcodecs::imread(qr_path.to_str().unwrap(), imgcodecs::IMREAD_COLOR)?;
+	let mut detector = WeChatQRCode::without_models()?;
+	let codes = detector.detect_and_decode_with_points(&src)?;
+	assert_eq!(1, codes.len());
+	let (text, corners) = &codes[0];
+	assert_eq!("https://crates.io/crates/opencv", text);
+	assert_eq!(4, corners.rows());
+	assert_eq!(2, corners.cols());
+
+	let blank = Mat::new_rows_cols_with_default(100, 100, CV_8UC1, Scalar::all(255.))?;
+	assert!(detector.detect_and_decode_with_points(&blank)?.is_empty());
+	Ok(())
+}
+
+#[test]
+fn missing_models() {
+	assert!(WeChatQRCode::from_model_dir("missing-model-dir").is_err());
+}
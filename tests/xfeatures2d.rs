@@ -0,0 +1,86 @@
+#![cfg(ocvrs_has_module_xfeatures2d)]
+
+use std::path::PathBuf;
+
+use opencv::{
+	core::{self, KeyPoint, Ptr, Vector},
+	features2d::{Feature2D, ORB},
+	imgcodecs,
+	prelude::v2::pipelines::*,
+	xfeatures2d::{BEBLID_BeblidSize, StarDetector, TEBLID_TeblidSize, BEBLID, LATCH, TEBLID},
+	Result,
+};
+
+fn blox() -> Result<Mat> {
+	let blox_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/blox.jpg");
+	imgcodecs::imread(blox_path.to_str().unwrap(), imgcodecs::IMREAD_GRAYSCALE)
+}
+
+#[test]
+fn descriptors() -> Result<()> {
+	// the extra descriptors are used through the same trait as the core ones
+	fn describe(extractor: &mut impl Feature2DTrait, img: &Mat, keypoints: &mut Vector<KeyPoint>) -> Result<Mat> {
+		let mut descriptors = Mat::default();
+		extractor.compute(img, keypoints, &mut descriptors)?;
+		assert_eq!(keypoints.len(), descriptors.rows() as usize);
+		assert_eq!(extractor.descriptor_size()?, descriptors.cols());
+		Ok(descriptors)
+	}
+
+	let img = blox()?;
+	let mut keypoints = Vector::new();
+	<dyn ORB>::default()?.detect(&img, &mut keypoints, None)?;
+	assert!(!keypoints.is_empty());
+
+	let mut beblid = BEBLID::create(0.75, BEBLID_BeblidSize::SIZE_256_BITS as i32)?;
+	assert_eq!(32, beblid.descriptor_size()?);
+	assert_eq!(core::NORM_HAMMING, beblid.default_norm()?);
+	describe(&mut beblid, &img, &mut keypoints.clone())?;
+	let mut latch = LATCH::create(32, true, 3, 2.)?;
+	assert_eq!(32, latch.descriptor_size()?);
+	describe(&mut latch, &img, &mut keypoints.clone())?;
+	Ok(())
+}
+
+#[test]
+fn feature2d_ptr() -> Result<()> {
+	let img = blox()?;
+	let mut detector: Ptr<Feature2D> = StarDetector::create(45, 30, 10, 8, 5)?.into();
+	let mut keypoints = Vector::new();
+	detector.detect(&img, &mut keypoints, None)?;
+	assert!(!keypoints.is_empty());
+
+	let mut extractors: Vec<Ptr<Feature2D>> = vec![
+		BEBLID::create(0.75, BEBLID_BeblidSize::SIZE_512_BITS as i32)?.into(),
+		LATCH::create(32, true, 3, 2.)?.into(),
+	];
+	for extractor in &mut extractors {
+		let mut keypoints = keypoints.clone();
+		let mut descriptors = Mat::default();
+		extractor.compute(&img, &mut keypoints, &mut descriptors)?;
+		assert_eq!(keypoints.len(), descriptors.rows() as usize);
+		assert_eq!(extractor.descriptor_size()?, descriptors.cols());
+	}
+	Ok(())
+}
+
+#[test]
+fn teblid() -> Result<()> {
+	let img = blox()?;
+	let mut keypoints = Vector::new();
+	<dyn ORB>::default()?.detect(&img, &mut keypoints, None)?;
+
+	// TEBLID is only available since OpenCV 4.7.0
+	let mut teblid = match TEBLID::create(0.75, TEBLID_TeblidSize::SIZE_256_BITS as i32) {
+		Ok(teblid) => teblid,
+		Err(e) if e.code() == core::StsNotImplemented => return Ok(()),
+		Err(e) => return Err(e),
+	};
+	assert_eq!(32, teblid.descriptor_size()?);
+	assert_eq!(core::NORM_HAMMING, teblid.default_norm()?);
+	let mut descriptors = Mat::default();
+	teblid.compute(&img, &mut keypoints, &mut descriptors)?;
+	assert_eq!(keypoints.len(), descriptors.rows() as usize);
+	assert_eq!(32, descriptors.cols());
+	Ok(())
+}
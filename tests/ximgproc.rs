@@ -0,0 +1,46 @@
+#![cfg(ocvrs_has_module_ximgproc)]
+
+use opencv::{
+	core::{self, Mat, Rect, Scalar, CV_32S, CV_8U, CV_8UC3},
+	imgproc,
+	prelude::*,
+	ximgproc::{self, SLICType, Superpixels},
+	Result,
+};
+
+/// 4 quadrants of different colors
+fn quadrants() -> Result<Mat> {
+	let mut image = Mat::new_rows_cols_with_default(120, 160, CV_8UC3, Scalar::new(200., 30., 30., 0.))?;
+	for (rect, color) in [
+		(Rect::new(80, 0, 80, 60), Scalar::new(30., 200., 30., 0.)),
+		(Rect::new(0, 60, 80, 60), Scalar::new(30., 30., 200., 0.)),
+		(Rect::new(80, 60, 80, 60), Scalar::all(240.)),
+	] {
+		imgproc::rectangle(&mut image, rect, color, imgproc::FILLED, imgproc::LINE_8, 0)?;
+	}
+	Ok(image)
+}
+
+fn check_superpixels(image: &Mat, superpixels: &Superpixels) -> Result<()> {
+	assert!(superpixels.count > 1);
+	assert_eq!(CV_32S, superpixels.labels.typ());
+	assert_eq!(image.size()?, superpixels.labels.size()?);
+	let (mut min, mut max) = (0., 0.);
+	core::min_max_loc(&superpixels.labels, Some(&mut min), Some(&mut max), None, None, None::<&Mat>)?;
+	assert!(min >= 0.);
+	assert!(max < f64::from(superpixels.count));
+	assert_eq!(CV_8U, superpixels.contour_mask.typ());
+	assert!(core::count_non_zero(&superpixels.contour_mask)? > 0);
+	Ok(())
+}
+
+#[test]
+fn superpixels() -> Result<()> {
+	let image = quadrants()?;
+	for algorithm in [SLICType::SLIC, SLICType::SLICO, SLICType::MSLIC] {
+		check_superpixels(&image, &ximgproc::superpixels_slic(&image, algorithm, 20, 10., 10)?)?;
+	}
+	check_superpixels(&image, &ximgproc::superpixels_seeds(&image, 50, 4, 4)?)?;
+	check_superpixels(&image, &ximgproc::superpixels_lsc(&image, 20, 0.075, 10)?)?;
+	Ok(())
+}